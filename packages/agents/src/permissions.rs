@@ -0,0 +1,285 @@
+// ABOUTME: Per-agent tool permission policies enforced when agents run in sandboxes
+// ABOUTME: Defaults come from the agent registry; user overrides are stored in agent_tool_policies
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+
+use super::registry::AgentRegistry;
+use orkee_storage::StorageError;
+
+/// Tools an agent may use inside its sandbox
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolPermissions {
+    /// Write to mounted project files; without it mounts are read-only
+    pub filesystem_write: bool,
+    /// Reach the network; without it the sandbox has no network at all
+    pub network: bool,
+    /// Run shell commands in the sandbox
+    pub shell_exec: bool,
+    /// Push to git remotes
+    pub git_push: bool,
+}
+
+impl Default for ToolPermissions {
+    /// Everything but pushing, which publishes work outside the sandbox
+    fn default() -> Self {
+        Self {
+            filesystem_write: true,
+            network: true,
+            shell_exec: true,
+            git_push: false,
+        }
+    }
+}
+
+impl ToolPermissions {
+    /// Check a command about to run in the agent's sandbox
+    pub fn check_command(&self, command: &[String]) -> Result<(), String> {
+        if !self.shell_exec {
+            return Err("shell commands are not permitted for this agent".to_string());
+        }
+        if !self.git_push && is_git_push(command) {
+            return Err("git push is not permitted for this agent".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Characters that end a word in a shell command line
+const SHELL_SEPARATORS: &[char] = &[';', '&', '|', '(', ')', '"', '\''];
+
+/// Whether a command pushes to a git remote, including through `sh -c` and
+/// command chains like `git add . && git push`
+fn is_git_push(command: &[String]) -> bool {
+    let joined = command.join(" ");
+    let tokens: Vec<&str> = joined
+        .split(|c: char| c.is_whitespace() || SHELL_SEPARATORS.contains(&c))
+        .filter(|token| !token.is_empty())
+        .collect();
+
+    tokens.iter().enumerate().any(|(i, token)| {
+        if token.rsplit('/').next() != Some("git") {
+            return false;
+        }
+        // Skip global options like `-C dir` or `-c key=value` to find the subcommand
+        let mut rest = tokens[i + 1..].iter();
+        while let Some(arg) = rest.next() {
+            match *arg {
+                "-C" | "-c" | "--git-dir" | "--work-tree" => {
+                    rest.next();
+                }
+                arg if arg.starts_with('-') => {}
+                arg => return arg == "push",
+            }
+        }
+        false
+    })
+}
+
+/// An agent's effective tool permissions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentToolPolicy {
+    pub agent_id: String,
+    pub permissions: ToolPermissions,
+    /// True when the agent uses its registry defaults
+    pub is_default: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+pub struct ToolPolicyStorage {
+    pool: SqlitePool,
+    registry: AgentRegistry,
+}
+
+impl ToolPolicyStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            registry: AgentRegistry::default(),
+        }
+    }
+
+    fn validate_agent(&self, agent_id: &str) -> Result<(), StorageError> {
+        self.registry
+            .validate_agent_id(agent_id)
+            .map_err(|_| StorageError::InvalidAgent(agent_id.to_string()))
+    }
+
+    /// Policies of every registered agent, sorted by agent ID
+    pub async fn list_policies(&self) -> Result<Vec<AgentToolPolicy>, StorageError> {
+        let mut agent_ids: Vec<&str> = self
+            .registry
+            .list()
+            .into_iter()
+            .map(|agent| agent.id.as_str())
+            .collect();
+        agent_ids.sort_unstable();
+
+        let mut policies = Vec::with_capacity(agent_ids.len());
+        for agent_id in agent_ids {
+            policies.push(self.get_policy(agent_id).await?);
+        }
+        Ok(policies)
+    }
+
+    /// The agent's override, or its registry defaults
+    pub async fn get_policy(&self, agent_id: &str) -> Result<AgentToolPolicy, StorageError> {
+        self.validate_agent(agent_id)?;
+
+        let row = sqlx::query("SELECT * FROM agent_tool_policies WHERE agent_id = ?")
+            .bind(agent_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        match row {
+            Some(row) => Ok(AgentToolPolicy {
+                agent_id: agent_id.to_string(),
+                permissions: ToolPermissions {
+                    filesystem_write: row.try_get("filesystem_write")?,
+                    network: row.try_get("network")?,
+                    shell_exec: row.try_get("shell_exec")?,
+                    git_push: row.try_get("git_push")?,
+                },
+                is_default: false,
+                updated_at: row.try_get("updated_at")?,
+            }),
+            None => Ok(AgentToolPolicy {
+                agent_id: agent_id.to_string(),
+                permissions: self.registry.tool_permissions(agent_id),
+                is_default: true,
+                updated_at: None,
+            }),
+        }
+    }
+
+    /// Override an agent's permissions
+    pub async fn set_policy(
+        &self,
+        agent_id: &str,
+        permissions: ToolPermissions,
+    ) -> Result<AgentToolPolicy, StorageError> {
+        self.validate_agent(agent_id)?;
+        debug!("Setting tool permissions for agent {}", agent_id);
+
+        sqlx::query(
+            r#"
+            INSERT INTO agent_tool_policies (agent_id, filesystem_write, network, shell_exec, git_push)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(agent_id) DO UPDATE SET
+                filesystem_write = excluded.filesystem_write,
+                network = excluded.network,
+                shell_exec = excluded.shell_exec,
+                git_push = excluded.git_push,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            "#,
+        )
+        .bind(agent_id)
+        .bind(permissions.filesystem_write)
+        .bind(permissions.network)
+        .bind(permissions.shell_exec)
+        .bind(permissions.git_push)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_policy(agent_id).await
+    }
+
+    /// Drop an agent's override so it uses its registry defaults again
+    pub async fn reset_policy(&self, agent_id: &str) -> Result<AgentToolPolicy, StorageError> {
+        self.validate_agent(agent_id)?;
+        debug!("Resetting tool permissions for agent {}", agent_id);
+
+        sqlx::query("DELETE FROM agent_tool_policies WHERE agent_id = ?")
+            .bind(agent_id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        self.get_policy(agent_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(parts: &[&str]) -> Vec<String> {
+        parts.iter().map(|part| part.to_string()).collect()
+    }
+
+    #[test]
+    fn test_git_push_detection() {
+        assert!(is_git_push(&command(&["git", "push", "origin", "main"])));
+        assert!(is_git_push(&command(&[
+            "/usr/bin/git",
+            "-C",
+            "/workspace",
+            "push"
+        ])));
+        assert!(is_git_push(&command(&[
+            "sh",
+            "-c",
+            "git add . && git commit -m 'wip' && git push"
+        ])));
+        assert!(!is_git_push(&command(&["git", "commit", "-m", "push it"])));
+        assert!(!is_git_push(&command(&["echo", "git", "status"])));
+    }
+
+    #[test]
+    fn test_check_command() {
+        let permissions = ToolPermissions::default();
+        assert!(permissions
+            .check_command(&command(&["npm", "test"]))
+            .is_ok());
+        assert!(permissions
+            .check_command(&command(&["git", "push"]))
+            .is_err());
+
+        let no_shell = ToolPermissions {
+            shell_exec: false,
+            ..permissions
+        };
+        assert!(no_shell.check_command(&command(&["ls"])).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_overrides_and_reset() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        let storage = ToolPolicyStorage::new(pool);
+
+        let codex = storage.get_policy("codex").await.unwrap();
+        assert!(codex.is_default);
+        assert!(!codex.permissions.network);
+
+        let updated = storage
+            .set_policy(
+                "codex",
+                ToolPermissions {
+                    network: true,
+                    ..codex.permissions
+                },
+            )
+            .await
+            .unwrap();
+        assert!(!updated.is_default);
+        assert!(updated.permissions.network);
+        assert!(updated.updated_at.is_some());
+
+        let reset = storage.reset_policy("codex").await.unwrap();
+        assert_eq!(reset, codex);
+
+        assert!(matches!(
+            storage.get_policy("unknown-agent").await,
+            Err(StorageError::InvalidAgent(_))
+        ));
+        assert_eq!(storage.list_policies().await.unwrap().len(), 5);
+    }
+}
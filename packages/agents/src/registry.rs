@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::permissions::ToolPermissions;
+
 #[derive(Error, Debug)]
 pub enum AgentError {
     #[error("Failed to load agents config: {0}")]
@@ -43,6 +45,9 @@ pub struct Agent {
     pub required_providers: Vec<String>,
     pub supported_models: Vec<AgentModel>,
     pub default_config: AgentDefaultConfig,
+    /// Tools the agent may use in its sandbox unless overridden
+    #[serde(default)]
+    pub tool_permissions: ToolPermissions,
     pub is_available: bool,
 }
 
@@ -102,6 +107,14 @@ impl AgentRegistry {
             .map(|m| m.model_id.as_str())
     }
 
+    /// Default tool permissions for an agent; unknown agents get the global defaults
+    pub fn tool_permissions(&self, agent_id: &str) -> ToolPermissions {
+        self.agents
+            .get(agent_id)
+            .map(|agent| agent.tool_permissions)
+            .unwrap_or_default()
+    }
+
     /// Validate that an agent ID references a valid agent
     pub fn validate_agent_id(&self, agent_id: &str) -> Result<()> {
         if self.exists(agent_id) {
@@ -173,6 +186,17 @@ mod tests {
         assert!(registry.validate_agent_id("invalid").is_err());
     }
 
+    #[test]
+    fn test_tool_permission_defaults() {
+        let registry = AgentRegistry::new().unwrap();
+        assert!(!registry.tool_permissions("codex").network);
+        assert!(!registry.tool_permissions("claude-code").git_push);
+        assert_eq!(
+            registry.tool_permissions("unknown"),
+            ToolPermissions::default()
+        );
+    }
+
     #[test]
     fn test_validate_model_for_agent() {
         let registry = AgentRegistry::new().unwrap();
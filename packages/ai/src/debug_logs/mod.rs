@@ -0,0 +1,13 @@
+// ABOUTME: AI proxy debug log module for inspecting provider traffic
+// ABOUTME: Provides redaction, types, and storage for opt-in request and response capture
+
+pub mod redaction;
+pub mod storage;
+pub mod types;
+
+pub use redaction::{redact_body, redact_headers, redact_text};
+pub use storage::AiDebugLogStorage;
+pub use types::{
+    AiDebugLog, NewAiDebugLog, DEBUG_LOGGING_SETTING, DEBUG_LOG_RETENTION_SETTING,
+    DEFAULT_DEBUG_LOG_RETENTION_HOURS,
+};
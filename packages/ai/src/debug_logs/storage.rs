@@ -0,0 +1,165 @@
+// ABOUTME: AI proxy debug log storage layer using SQLite
+// ABOUTME: Records redacted proxy exchanges and prunes them once their retention expires
+
+use chrono::{DateTime, Duration, SubsecRound, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+
+use super::types::{AiDebugLog, NewAiDebugLog};
+use orkee_storage::StorageError;
+
+/// Timestamps are stored in this form so they compare correctly as text
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+pub struct AiDebugLogStorage {
+    pool: SqlitePool,
+}
+
+impl AiDebugLogStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Record a proxy exchange, kept for `retention_hours`. Expired entries are
+    /// pruned on each write.
+    pub async fn record(
+        &self,
+        log: &NewAiDebugLog,
+        retention_hours: i64,
+    ) -> Result<AiDebugLog, StorageError> {
+        if retention_hours < 1 {
+            return Err(StorageError::InvalidInput(
+                "Debug log retention must be at least one hour".to_string(),
+            ));
+        }
+        self.prune_expired().await?;
+
+        let created_at = Utc::now().trunc_subsecs(0);
+        let expires_at = created_at + Duration::hours(retention_hours);
+        let request_headers = log.request_headers.to_string();
+        let response_headers = log.response_headers.as_ref().map(|h| h.to_string());
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO ai_debug_logs (
+                correlation_id, user_id, provider, method, path, model, status_code,
+                duration_ms, request_headers, request_body, response_headers,
+                response_body, error, created_at, expires_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&log.correlation_id)
+        .bind(&log.user_id)
+        .bind(&log.provider)
+        .bind(&log.method)
+        .bind(&log.path)
+        .bind(&log.model)
+        .bind(log.status_code)
+        .bind(log.duration_ms)
+        .bind(&request_headers)
+        .bind(&log.request_body)
+        .bind(&response_headers)
+        .bind(&log.response_body)
+        .bind(&log.error)
+        .bind(created_at.format(TIMESTAMP_FORMAT).to_string())
+        .bind(expires_at.format(TIMESTAMP_FORMAT).to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(AiDebugLog {
+            id: result.last_insert_rowid(),
+            correlation_id: log.correlation_id.clone(),
+            user_id: log.user_id.clone(),
+            provider: log.provider.clone(),
+            method: log.method.clone(),
+            path: log.path.clone(),
+            model: log.model.clone(),
+            status_code: log.status_code,
+            duration_ms: log.duration_ms,
+            request_headers: log.request_headers.clone(),
+            request_body: log.request_body.clone(),
+            response_headers: log.response_headers.clone(),
+            response_body: log.response_body.clone(),
+            error: log.error.clone(),
+            created_at,
+            expires_at,
+        })
+    }
+
+    /// Unexpired entries for a correlation ID recorded for this user, oldest first
+    pub async fn get(
+        &self,
+        user_id: &str,
+        correlation_id: &str,
+    ) -> Result<Vec<AiDebugLog>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT * FROM ai_debug_logs
+             WHERE correlation_id = ? AND user_id = ? AND expires_at > ?
+             ORDER BY id",
+        )
+        .bind(correlation_id)
+        .bind(user_id)
+        .bind(Utc::now().format(TIMESTAMP_FORMAT).to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(Self::row_to_log).collect()
+    }
+
+    /// Delete expired entries, returning how many were removed
+    pub async fn prune_expired(&self) -> Result<u64, StorageError> {
+        let result = sqlx::query("DELETE FROM ai_debug_logs WHERE expires_at <= ?")
+            .bind(Utc::now().format(TIMESTAMP_FORMAT).to_string())
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        if result.rows_affected() > 0 {
+            debug!(
+                "Pruned {} expired AI debug log entries",
+                result.rows_affected()
+            );
+        }
+        Ok(result.rows_affected())
+    }
+
+    fn row_to_log(row: &SqliteRow) -> Result<AiDebugLog, StorageError> {
+        let parse_time = |column: &str| -> Result<DateTime<Utc>, StorageError> {
+            let value: String = row.try_get(column).map_err(StorageError::Sqlx)?;
+            DateTime::parse_from_rfc3339(&value)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| StorageError::Database(format!("Invalid {}: {}", column, e)))
+        };
+        let parse_json = |value: String| {
+            serde_json::from_str(&value)
+                .map_err(|e| StorageError::Database(format!("Invalid debug log headers: {}", e)))
+        };
+
+        let request_headers: String = row.try_get("request_headers").map_err(StorageError::Sqlx)?;
+        let response_headers: Option<String> = row
+            .try_get("response_headers")
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(AiDebugLog {
+            id: row.try_get("id").map_err(StorageError::Sqlx)?,
+            correlation_id: row.try_get("correlation_id").map_err(StorageError::Sqlx)?,
+            user_id: row.try_get("user_id").map_err(StorageError::Sqlx)?,
+            provider: row.try_get("provider").map_err(StorageError::Sqlx)?,
+            method: row.try_get("method").map_err(StorageError::Sqlx)?,
+            path: row.try_get("path").map_err(StorageError::Sqlx)?,
+            model: row.try_get("model").map_err(StorageError::Sqlx)?,
+            status_code: row.try_get("status_code").map_err(StorageError::Sqlx)?,
+            duration_ms: row.try_get("duration_ms").map_err(StorageError::Sqlx)?,
+            request_headers: parse_json(request_headers)?,
+            request_body: row.try_get("request_body").map_err(StorageError::Sqlx)?,
+            response_headers: response_headers.map(parse_json).transpose()?,
+            response_body: row.try_get("response_body").map_err(StorageError::Sqlx)?,
+            error: row.try_get("error").map_err(StorageError::Sqlx)?,
+            created_at: parse_time("created_at")?,
+            expires_at: parse_time("expires_at")?,
+        })
+    }
+}
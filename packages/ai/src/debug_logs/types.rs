@@ -0,0 +1,51 @@
+// ABOUTME: AI proxy debug log type definitions
+// ABOUTME: Structures for redacted request/response captures keyed by correlation ID
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Setting that turns debug capture on or off
+pub const DEBUG_LOGGING_SETTING: &str = "ai_debug_logging_enabled";
+/// Setting for how many hours captures are kept
+pub const DEBUG_LOG_RETENTION_SETTING: &str = "ai_debug_log_retention_hours";
+/// Retention used when the setting is missing or invalid
+pub const DEFAULT_DEBUG_LOG_RETENTION_HOURS: i64 = 24;
+
+/// A recorded proxy exchange. Headers and bodies are redacted before storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiDebugLog {
+    pub id: i64,
+    pub correlation_id: String,
+    pub user_id: String,
+    pub provider: String,
+    pub method: String,
+    pub path: String,
+    pub model: Option<String>,
+    pub status_code: Option<i64>,
+    pub duration_ms: i64,
+    pub request_headers: serde_json::Value,
+    pub request_body: Option<String>,
+    pub response_headers: Option<serde_json::Value>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// A proxy exchange to record; the caller is responsible for redaction
+#[derive(Debug, Clone)]
+pub struct NewAiDebugLog {
+    pub correlation_id: String,
+    pub user_id: String,
+    pub provider: String,
+    pub method: String,
+    pub path: String,
+    pub model: Option<String>,
+    pub status_code: Option<i64>,
+    pub duration_ms: i64,
+    pub request_headers: serde_json::Value,
+    pub request_body: Option<String>,
+    pub response_headers: Option<serde_json::Value>,
+    pub response_body: Option<String>,
+    pub error: Option<String>,
+}
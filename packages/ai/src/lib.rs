@@ -1,10 +1,17 @@
 // ABOUTME: AI usage tracking and telemetry
-// ABOUTME: Usage log management for AI operations
+// ABOUTME: Usage log management and proxy debug capture for AI operations
 
+pub mod debug_logs;
 pub mod usage_logs;
 
+// Re-export debug log types
+pub use debug_logs::{
+    AiDebugLog, AiDebugLogStorage, NewAiDebugLog, DEBUG_LOGGING_SETTING,
+    DEBUG_LOG_RETENTION_SETTING, DEFAULT_DEBUG_LOG_RETENTION_HOURS,
+};
+
 // Re-export usage log types
 pub use usage_logs::{
     AiUsageLog, AiUsageLogStorage, AiUsageQuery, AiUsageStats, ModelStats, OperationStats,
-    ProviderStats,
+    ProjectUsageStats, PromptUsageRecord, PromptUsageStats, ProviderStats,
 };
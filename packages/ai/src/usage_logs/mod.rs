@@ -6,6 +6,7 @@ pub mod types;
 
 pub use storage::AiUsageLogStorage;
 pub use types::{
-    AiUsageLog, AiUsageQuery, AiUsageStats, ModelStats, OperationStats, ProviderStats,
+    AiUsageLog, AiUsageQuery, AiUsageStats, ModelMixEntry, ModelStats, OperationStats,
+    PeriodComparison, ProjectUsageStats, PromptUsageRecord, PromptUsageStats, ProviderStats,
     TimeSeriesDataPoint, ToolCallDetail, ToolUsageStats,
 };
@@ -2,14 +2,18 @@
 // ABOUTME: Handles querying AI usage logs with filtering and aggregation
 
 use chrono::{DateTime, Utc};
+use sqlx::query::Query;
+use sqlx::sqlite::{Sqlite, SqliteArguments};
 use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
 use tracing::debug;
 
 use super::types::{
-    AiUsageLog, AiUsageQuery, AiUsageStats, ModelStats, OperationStats, ProviderStats,
+    AiUsageLog, AiUsageQuery, AiUsageStats, ModelMixEntry, ModelStats, OperationStats,
+    PeriodComparison, ProjectUsageStats, PromptUsageRecord, PromptUsageStats, ProviderStats,
     TimeSeriesDataPoint, ToolCallDetail, ToolUsageStats,
 };
-use orkee_storage::StorageError;
+use orkee_storage::{KeysetOrder, KeysetPage, KeysetPosition, StorageError};
 
 pub struct AiUsageLogStorage {
     pool: SqlitePool,
@@ -59,7 +63,80 @@ impl AiUsageLogStorage {
 
     /// List AI usage logs with optional filtering
     pub async fn list_logs(&self, query: AiUsageQuery) -> Result<Vec<AiUsageLog>, StorageError> {
-        let mut sql = String::from("SELECT * FROM ai_usage_logs WHERE 1=1");
+        let sql = format!(
+            "SELECT * FROM ai_usage_logs WHERE 1=1{} ORDER BY created_at DESC LIMIT ? OFFSET ?",
+            Self::filter_sql(&query)
+        );
+
+        debug!("Fetching AI usage logs with query: {}", sql);
+
+        // Bind LIMIT and OFFSET - use defaults if not provided
+        let limit = query.limit.unwrap_or(100);
+        let offset = query.offset.unwrap_or(0);
+        let rows = Self::bind_filters(sqlx::query(&sql), &query)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let logs = rows
+            .iter()
+            .map(|row| self.row_to_log(row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(logs)
+    }
+
+    /// List AI usage logs newest first, starting after `after`
+    ///
+    /// Keyset pagination on (created_at, id); `limit` and `offset` on the
+    /// query are ignored in favour of the explicit `limit`.
+    pub async fn list_logs_after(
+        &self,
+        query: &AiUsageQuery,
+        after: Option<&KeysetPosition>,
+        limit: i64,
+    ) -> Result<KeysetPage<AiUsageLog>, StorageError> {
+        let filters = Self::filter_sql(query);
+
+        let count_sql = format!("SELECT COUNT(*) FROM ai_usage_logs WHERE 1=1{}", filters);
+        let count: i64 = Self::bind_filters(sqlx::query(&count_sql), query)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?
+            .try_get(0)
+            .map_err(StorageError::Sqlx)?;
+
+        let order = KeysetOrder::Descending;
+        let mut sql = format!("SELECT * FROM ai_usage_logs WHERE 1=1{}", filters);
+        if after.is_some() {
+            sql.push_str(&format!(" AND {}", order.after_clause("")));
+        }
+        sql.push_str(&format!(" ORDER BY {} LIMIT ?", order.order_by("")));
+
+        debug!("Fetching AI usage logs with query: {}", sql);
+
+        let mut db_query = Self::bind_filters(sqlx::query(&sql), query);
+        if let Some(position) = after {
+            db_query = db_query.bind(&position.created_at).bind(&position.id);
+        }
+        let rows = db_query
+            .bind(limit + 1)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let logs = rows
+            .iter()
+            .map(|row| Ok((self.row_to_log(row)?, KeysetPosition::from_row(row)?)))
+            .collect::<Result<Vec<_>, StorageError>>()?;
+
+        Ok(KeysetPage::from_rows(logs, limit, count))
+    }
+
+    /// `AND ...` conditions for the filters set on a query
+    fn filter_sql(query: &AiUsageQuery) -> String {
         let mut conditions = Vec::new();
 
         if query.project_id.is_some() {
@@ -81,17 +158,17 @@ impl AiUsageLogStorage {
             conditions.push("provider = ?");
         }
 
-        for condition in conditions {
-            sql.push_str(&format!(" AND {}", condition));
-        }
-
-        sql.push_str(" ORDER BY created_at DESC LIMIT ? OFFSET ?");
-
-        debug!("Fetching AI usage logs with query: {}", sql);
-
-        let mut db_query = sqlx::query(&sql);
+        conditions
+            .into_iter()
+            .map(|condition| format!(" AND {}", condition))
+            .collect()
+    }
 
-        // Bind parameters in the same order as conditions
+    /// Bind filter values in the same order as [`filter_sql`](Self::filter_sql)
+    fn bind_filters<'q>(
+        mut db_query: Query<'q, Sqlite, SqliteArguments<'q>>,
+        query: &'q AiUsageQuery,
+    ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
         if let Some(project_id) = &query.project_id {
             db_query = db_query.bind(project_id);
         }
@@ -110,23 +187,50 @@ impl AiUsageLogStorage {
         if let Some(provider) = &query.provider {
             db_query = db_query.bind(provider);
         }
+        db_query
+    }
 
-        // Bind LIMIT and OFFSET - use defaults if not provided
-        let limit = query.limit.unwrap_or(100);
-        let offset = query.offset.unwrap_or(0);
-        db_query = db_query.bind(limit).bind(offset);
+    /// [`filter_sql`](Self::filter_sql) for the prompt usage side of a prompt/log join
+    fn prompt_filter_sql(query: &AiUsageQuery) -> String {
+        let mut conditions = Vec::new();
 
-        let rows = db_query
-            .fetch_all(&self.pool)
-            .await
-            .map_err(StorageError::Sqlx)?;
+        if query.project_id.is_some() {
+            conditions.push("p.project_id = ?");
+        }
+        if query.start_date.is_some() {
+            conditions.push("p.created_at >= ?");
+        }
+        if query.end_date.is_some() {
+            conditions.push("p.created_at <= ?");
+        }
+        if query.operation.is_some() {
+            conditions.push("p.operation = ?");
+        }
 
-        let logs = rows
-            .iter()
-            .map(|row| self.row_to_log(row))
-            .collect::<Result<Vec<_>, _>>()?;
+        conditions
+            .into_iter()
+            .map(|condition| format!(" AND {}", condition))
+            .collect()
+    }
 
-        Ok(logs)
+    /// Bind filter values in the same order as [`prompt_filter_sql`](Self::prompt_filter_sql)
+    fn bind_prompt_filters<'q>(
+        mut db_query: Query<'q, Sqlite, SqliteArguments<'q>>,
+        query: &'q AiUsageQuery,
+    ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+        if let Some(project_id) = &query.project_id {
+            db_query = db_query.bind(project_id);
+        }
+        if let Some(start_date) = &query.start_date {
+            db_query = db_query.bind(start_date);
+        }
+        if let Some(end_date) = &query.end_date {
+            db_query = db_query.bind(end_date);
+        }
+        if let Some(operation) = &query.operation {
+            db_query = db_query.bind(operation);
+        }
+        db_query
     }
 
     /// Get aggregate statistics for AI usage
@@ -578,22 +682,7 @@ impl AiUsageLogStorage {
             format!("WHERE {}", where_conditions.join(" AND "))
         };
 
-        // SQLite date/time grouping based on interval
-        // Validate interval to prevent SQL injection
-        // Note: For 'week', we use %Y-W%W which gives week number (0-53)
-        // This is parsed using ISO week format %G-W%V for correct year boundary handling
-        let date_format = match interval {
-            "hour" => "%Y-%m-%d %H:00:00",
-            "day" => "%Y-%m-%d 00:00:00",
-            "week" => "%Y-W%W",
-            "month" => "%Y-%m-01 00:00:00",
-            _ => {
-                return Err(StorageError::Database(format!(
-                    "Invalid interval: {}. Must be 'hour', 'day', 'week', or 'month'",
-                    interval
-                )))
-            }
-        };
+        let date_format = interval_date_format(interval)?;
 
         let sql = format!(
             r#"
@@ -631,31 +720,7 @@ impl AiUsageLogStorage {
             .iter()
             .filter_map(|row| {
                 let time_bucket: String = row.try_get("time_bucket").ok()?;
-                let timestamp = if interval == "week" {
-                    // Parse week format using ISO 8601 week date format
-                    // Convert %Y-W%W format to %G-W%V for proper ISO week handling
-                    // This handles year boundaries correctly (e.g., 2024-W01 belongs to 2024 even if Dec 31 2023 is in that week)
-
-                    // Parse the week string (format: "2024-W01")
-                    let parts: Vec<&str> = time_bucket.split("-W").collect();
-                    if parts.len() != 2 {
-                        return None;
-                    }
-                    let year: i32 = parts[0].parse().ok()?;
-                    let week: u32 = parts[1].parse().ok()?;
-
-                    // Get Monday of the ISO week (ISO weeks start on Monday)
-                    chrono::NaiveDate::from_isoywd_opt(year, week, chrono::Weekday::Mon)?
-                        .and_hms_opt(0, 0, 0)?
-                        .and_utc()
-                } else {
-                    DateTime::parse_from_str(
-                        &format!("{}+00:00", time_bucket),
-                        "%Y-%m-%d %H:%M:%S%z",
-                    )
-                    .ok()?
-                    .with_timezone(&Utc)
-                };
+                let timestamp = parse_time_bucket(&time_bucket, interval)?;
 
                 Some(TimeSeriesDataPoint {
                     timestamp,
@@ -669,4 +734,312 @@ impl AiUsageLogStorage {
 
         Ok(data_points)
     }
+
+    /// Cost dashboard for one project between `start` and `end`.
+    ///
+    /// The comparison covers the period of the same length ending at `start`.
+    pub async fn get_project_stats(
+        &self,
+        project_id: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        top_operations: usize,
+    ) -> Result<ProjectUsageStats, StorageError> {
+        let query = AiUsageQuery {
+            project_id: Some(project_id.to_string()),
+            start_date: Some(start),
+            end_date: Some(end),
+            operation: None,
+            model: None,
+            provider: None,
+            limit: None,
+            offset: None,
+        };
+
+        let totals = self.get_stats(query.clone()).await?;
+        let daily = self.get_time_series(&query, "day").await?;
+        let weekly = self.get_time_series(&query, "week").await?;
+
+        let previous_start = start - (end - start);
+        let previous = self
+            .get_stats(AiUsageQuery {
+                start_date: Some(previous_start),
+                // The previous period ends where this one starts, exclusive
+                end_date: Some(start - chrono::Duration::milliseconds(1)),
+                ..query
+            })
+            .await?;
+
+        let model_mix = totals
+            .by_model
+            .iter()
+            .map(|model| ModelMixEntry {
+                model: model.model.clone(),
+                count: model.count,
+                total_tokens: model.total_tokens,
+                total_cost: model.total_cost,
+                request_share: share(model.count as f64, totals.total_requests as f64),
+                cost_share: share(model.total_cost, totals.total_cost),
+            })
+            .collect();
+
+        let previous_period = PeriodComparison {
+            previous_start,
+            previous_end: start,
+            previous_requests: previous.total_requests,
+            previous_tokens: previous.total_tokens,
+            previous_cost: previous.total_cost,
+            request_change_percent: percent_change(
+                previous.total_requests as f64,
+                totals.total_requests as f64,
+            ),
+            token_change_percent: percent_change(
+                previous.total_tokens as f64,
+                totals.total_tokens as f64,
+            ),
+            cost_change_percent: percent_change(previous.total_cost, totals.total_cost),
+        };
+
+        // Operations are already ordered by cost, most expensive first
+        let top_operations = totals
+            .by_operation
+            .iter()
+            .take(top_operations)
+            .cloned()
+            .collect();
+
+        Ok(ProjectUsageStats {
+            project_id: project_id.to_string(),
+            period_start: start,
+            period_end: end,
+            totals,
+            daily,
+            weekly,
+            top_operations,
+            model_mix,
+            previous_period,
+        })
+    }
+
+    /// Record the prompts AI requests were built from
+    pub async fn record_prompt_usage(
+        &self,
+        records: &[PromptUsageRecord],
+    ) -> Result<(), StorageError> {
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+        for record in records {
+            sqlx::query(
+                r#"
+                INSERT INTO prompt_usage (
+                    id, prompt_id, prompt_version, request_id, operation, project_id, created_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(&record.id)
+            .bind(&record.prompt_id)
+            .bind(&record.prompt_version)
+            .bind(&record.request_id)
+            .bind(&record.operation)
+            .bind(&record.project_id)
+            .bind(record.created_at.to_rfc3339())
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        }
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+        Ok(())
+    }
+
+    /// Tokens and cost attributed to each prompt version, most expensive first.
+    /// With an `interval`, each entry also carries a time series.
+    pub async fn get_prompt_stats(
+        &self,
+        query: &AiUsageQuery,
+        interval: Option<&str>,
+    ) -> Result<Vec<PromptUsageStats>, StorageError> {
+        let where_clause = format!("WHERE 1 = 1{}", Self::prompt_filter_sql(query));
+        let sql = format!(
+            r#"
+            SELECT
+                p.prompt_id,
+                p.prompt_version,
+                COUNT(DISTINCT p.id) as usage_count,
+                COUNT(l.id) as request_count,
+                COALESCE(SUM(l.input_tokens), 0) as total_input_tokens,
+                COALESCE(SUM(l.output_tokens), 0) as total_output_tokens,
+                COALESCE(SUM(l.total_tokens), 0) as total_tokens,
+                COALESCE(SUM(l.estimated_cost), 0.0) as total_cost,
+                MAX(p.created_at) as last_used_at
+            FROM prompt_usage p
+            LEFT JOIN ai_usage_logs l ON l.request_id = p.request_id
+            {}
+            GROUP BY p.prompt_id, p.prompt_version
+            ORDER BY total_cost DESC, total_tokens DESC, p.prompt_id ASC
+            "#,
+            where_clause
+        );
+
+        let rows = Self::bind_prompt_filters(sqlx::query(&sql), query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let mut stats = rows
+            .iter()
+            .map(|row| {
+                let request_count: i64 = row.try_get("request_count").unwrap_or(0);
+                let total_tokens: i64 = row.try_get("total_tokens").unwrap_or(0);
+                let last_used_at: Option<String> = row.try_get("last_used_at").ok().flatten();
+                Ok(PromptUsageStats {
+                    prompt_id: row.try_get("prompt_id")?,
+                    prompt_version: row.try_get("prompt_version")?,
+                    usage_count: row.try_get("usage_count").unwrap_or(0),
+                    request_count,
+                    total_input_tokens: row.try_get("total_input_tokens").unwrap_or(0),
+                    total_output_tokens: row.try_get("total_output_tokens").unwrap_or(0),
+                    total_tokens,
+                    total_cost: row.try_get("total_cost").unwrap_or(0.0),
+                    average_tokens_per_request: if request_count > 0 {
+                        total_tokens as f64 / request_count as f64
+                    } else {
+                        0.0
+                    },
+                    last_used_at: last_used_at
+                        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                        .map(|dt| dt.with_timezone(&Utc)),
+                    time_series: None,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(StorageError::Sqlx)?;
+
+        if let Some(interval) = interval {
+            let mut series = self.get_prompt_time_series(query, interval).await?;
+            for stat in &mut stats {
+                let key = (stat.prompt_id.clone(), stat.prompt_version.clone());
+                stat.time_series = Some(series.remove(&key).unwrap_or_default());
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Time series per prompt version, keyed by (prompt_id, prompt_version)
+    async fn get_prompt_time_series(
+        &self,
+        query: &AiUsageQuery,
+        interval: &str,
+    ) -> Result<HashMap<(String, Option<String>), Vec<TimeSeriesDataPoint>>, StorageError> {
+        let date_format = interval_date_format(interval)?;
+        let where_clause = format!("WHERE 1 = 1{}", Self::prompt_filter_sql(query));
+        let sql = format!(
+            r#"
+            SELECT
+                p.prompt_id,
+                p.prompt_version,
+                strftime('{}', p.created_at) as time_bucket,
+                COUNT(l.id) as request_count,
+                COALESCE(SUM(l.total_tokens), 0) as token_count,
+                COALESCE(SUM(l.estimated_cost), 0.0) as cost,
+                COALESCE(SUM(l.tool_calls_count), 0) as tool_call_count
+            FROM prompt_usage p
+            LEFT JOIN ai_usage_logs l ON l.request_id = p.request_id
+            {}
+            GROUP BY p.prompt_id, p.prompt_version, time_bucket
+            ORDER BY time_bucket ASC
+            "#,
+            date_format, where_clause
+        );
+
+        let rows = Self::bind_prompt_filters(sqlx::query(&sql), query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let mut series: HashMap<(String, Option<String>), Vec<TimeSeriesDataPoint>> =
+            HashMap::new();
+        for row in &rows {
+            let Ok(time_bucket) = row.try_get::<String, _>("time_bucket") else {
+                continue;
+            };
+            let Some(timestamp) = parse_time_bucket(&time_bucket, interval) else {
+                continue;
+            };
+            let key = (
+                row.try_get("prompt_id").map_err(StorageError::Sqlx)?,
+                row.try_get("prompt_version").map_err(StorageError::Sqlx)?,
+            );
+            series.entry(key).or_default().push(TimeSeriesDataPoint {
+                timestamp,
+                request_count: row.try_get("request_count").unwrap_or(0),
+                token_count: row.try_get("token_count").unwrap_or(0),
+                cost: row.try_get("cost").unwrap_or(0.0),
+                tool_call_count: row.try_get("tool_call_count").unwrap_or(0),
+            });
+        }
+
+        Ok(series)
+    }
+}
+
+/// `part` as a fraction of `whole`, or 0 when there is nothing to divide
+fn share(part: f64, whole: f64) -> f64 {
+    if whole > 0.0 {
+        part / whole
+    } else {
+        0.0
+    }
+}
+
+/// Percent change from `previous` to `current`; `None` when there is no baseline
+fn percent_change(previous: f64, current: f64) -> Option<f64> {
+    (previous > 0.0).then(|| (current - previous) / previous * 100.0)
+}
+
+/// SQLite `strftime` format that buckets timestamps by `interval`
+fn interval_date_format(interval: &str) -> Result<&'static str, StorageError> {
+    // SQLite date/time grouping based on interval
+    // Validate interval to prevent SQL injection
+    // Note: For 'week', we use %Y-W%W which gives week number (0-53)
+    // This is parsed using ISO week format %G-W%V for correct year boundary handling
+    match interval {
+        "hour" => Ok("%Y-%m-%d %H:00:00"),
+        "day" => Ok("%Y-%m-%d 00:00:00"),
+        "week" => Ok("%Y-W%W"),
+        "month" => Ok("%Y-%m-01 00:00:00"),
+        _ => Err(StorageError::Database(format!(
+            "Invalid interval: {}. Must be 'hour', 'day', 'week', or 'month'",
+            interval
+        ))),
+    }
+}
+
+/// Start of a bucket produced by [`interval_date_format`]
+fn parse_time_bucket(time_bucket: &str, interval: &str) -> Option<DateTime<Utc>> {
+    if interval == "week" {
+        // Parse week format using ISO 8601 week date format
+        // Convert %Y-W%W format to %G-W%V for proper ISO week handling
+        // This handles year boundaries correctly (e.g., 2024-W01 belongs to 2024 even if Dec 31 2023 is in that week)
+
+        // Parse the week string (format: "2024-W01")
+        let parts: Vec<&str> = time_bucket.split("-W").collect();
+        if parts.len() != 2 {
+            return None;
+        }
+        let year: i32 = parts[0].parse().ok()?;
+        let week: u32 = parts[1].parse().ok()?;
+
+        // Get Monday of the ISO week (ISO weeks start on Monday)
+        Some(
+            chrono::NaiveDate::from_isoywd_opt(year, week, chrono::Weekday::Mon)?
+                .and_hms_opt(0, 0, 0)?
+                .and_utc(),
+        )
+    } else {
+        Some(
+            DateTime::parse_from_str(&format!("{}+00:00", time_bucket), "%Y-%m-%d %H:%M:%S%z")
+                .ok()?
+                .with_timezone(&Utc),
+        )
+    }
 }
@@ -100,6 +100,85 @@ pub struct TimeSeriesDataPoint {
     pub tool_call_count: i64,
 }
 
+/// Share of a project's usage that went to one model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelMixEntry {
+    pub model: String,
+    pub count: i64,
+    pub total_tokens: i64,
+    pub total_cost: f64,
+    /// Fraction of the period's requests, 0.0 to 1.0
+    pub request_share: f64,
+    /// Fraction of the period's cost, 0.0 to 1.0
+    pub cost_share: f64,
+}
+
+/// Totals for the period of the same length just before the requested one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PeriodComparison {
+    pub previous_start: DateTime<Utc>,
+    pub previous_end: DateTime<Utc>,
+    pub previous_requests: i64,
+    pub previous_tokens: i64,
+    pub previous_cost: f64,
+    /// Percent change from the previous period; absent when it had no usage
+    pub request_change_percent: Option<f64>,
+    pub token_change_percent: Option<f64>,
+    pub cost_change_percent: Option<f64>,
+}
+
+/// Cost dashboard for a single project over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUsageStats {
+    pub project_id: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub totals: AiUsageStats,
+    pub daily: Vec<TimeSeriesDataPoint>,
+    pub weekly: Vec<TimeSeriesDataPoint>,
+    #[serde(rename = "topOperations")]
+    pub top_operations: Vec<OperationStats>,
+    #[serde(rename = "modelMix")]
+    pub model_mix: Vec<ModelMixEntry>,
+    #[serde(rename = "previousPeriod")]
+    pub previous_period: PeriodComparison,
+}
+
+/// A centralized prompt that an AI request was built from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptUsageRecord {
+    pub id: String,
+    pub prompt_id: String,
+    pub prompt_version: Option<String>,
+    /// Joins with `AiUsageLog::request_id`
+    pub request_id: String,
+    pub operation: Option<String>,
+    pub project_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Tokens and cost of the AI requests built from one prompt version.
+///
+/// A request built from several prompts counts toward each of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptUsageStats {
+    pub prompt_id: String,
+    pub prompt_version: Option<String>,
+    /// Times the prompt was rendered
+    pub usage_count: i64,
+    /// Logged AI requests matched to those renders
+    pub request_count: i64,
+    pub total_input_tokens: i64,
+    pub total_output_tokens: i64,
+    pub total_tokens: i64,
+    pub total_cost: f64,
+    pub average_tokens_per_request: f64,
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Per-interval breakdown, present when an interval was requested
+    #[serde(rename = "timeSeries", skip_serializing_if = "Option::is_none")]
+    pub time_series: Option<Vec<TimeSeriesDataPoint>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolCallDetail {
     pub name: String,
@@ -0,0 +1,50 @@
+// ABOUTME: HTTP request handlers for database administration
+// ABOUTME: Exposes the storage integrity check, size statistics, and maintenance runs
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::ok_or_internal_error;
+use orkee_projects::DbState;
+use orkee_storage::{integrity, maintenance};
+
+#[derive(Deserialize)]
+pub struct IntegrityQuery {
+    /// Repair the issues that can be fixed automatically
+    #[serde(default)]
+    pub repair: bool,
+}
+
+/// Run the database integrity check and return a structured report
+pub async fn check_integrity(
+    State(db): State<DbState>,
+    Query(params): Query<IntegrityQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Running database integrity check (repair: {})",
+        params.repair
+    );
+
+    let result = integrity::check_integrity(&db.pool, params.repair).await;
+    ok_or_internal_error(result, "Failed to check database integrity")
+}
+
+/// Return database size statistics with a per-table breakdown
+pub async fn get_storage_stats(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Getting database storage statistics");
+
+    let result = maintenance::database_stats(&db.pool).await;
+    ok_or_internal_error(result, "Failed to get storage statistics")
+}
+
+/// Run database maintenance (optimize, vacuum, WAL checkpoint) immediately
+pub async fn run_maintenance(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Running database maintenance on demand");
+
+    let result = maintenance::run_maintenance(&db.pool).await;
+    ok_or_internal_error(result, "Failed to run database maintenance")
+}
@@ -22,6 +22,7 @@ use tokio_stream::StreamExt;
 use tracing::{error, info, warn};
 
 use super::response::{created_or_internal_error, ok_or_internal_error};
+use orkee_notifications::{NotificationEventType, NotificationInput, NotificationSeverity};
 use orkee_projects::DbState;
 
 /// Removes a temporary file when dropped unless ownership is explicitly released.
@@ -222,14 +223,13 @@ pub async fn start_run(
     }
 
     // Look up the project path
-    let project_path: Option<String> = sqlx::query_scalar(
-        "SELECT project_root FROM projects WHERE id = ?",
-    )
-    .bind(&request.project_id)
-    .fetch_optional(&state.db.pool)
-    .await
-    .ok()
-    .flatten();
+    let project_path: Option<String> =
+        sqlx::query_scalar("SELECT project_root FROM projects WHERE id = ?")
+            .bind(&request.project_id)
+            .fetch_optional(&state.db.pool)
+            .await
+            .ok()
+            .flatten();
 
     let project_dir = match project_path {
         Some(path) => path,
@@ -351,7 +351,11 @@ pub async fn list_runs(
     let result = q
         .fetch_all(&state.db.pool)
         .await
-        .map(|rows| rows.into_iter().map(|r| r.into()).collect::<Vec<AgentRun>>())
+        .map(|rows| {
+            rows.into_iter()
+                .map(|r| r.into())
+                .collect::<Vec<AgentRun>>()
+        })
         .map_err(|e| format!("Database error: {}", e));
 
     ok_or_internal_error(result, "Failed to list agent runs")
@@ -409,7 +413,10 @@ pub async fn run_events(
             Some(Ok(Event::default().data(json)))
         }
         Err(BroadcastStreamRecvError::Lagged(n)) => {
-            warn!("SSE client lagged {} events for run {}", n, run_id_for_stream);
+            warn!(
+                "SSE client lagged {} events for run {}",
+                n, run_id_for_stream
+            );
             None
         }
     });
@@ -472,7 +479,9 @@ async fn spawn_runner(
         cmd.env("CLAUDE_CODE_OAUTH_TOKEN", token);
     }
 
-    let mut child = cmd.spawn().map_err(|e| format!("Failed to spawn runner: {}", e))?;
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to spawn runner: {}", e))?;
 
     // Take stdout before wrapping child in Arc<Mutex<>> since the reader needs it separately.
     let stdout = child
@@ -515,7 +524,10 @@ async fn read_runner_output(
         }
         match serde_json::from_str::<RunnerEvent>(&line) {
             Ok(event) => {
-                if matches!(event, RunnerEvent::RunCompleted { .. } | RunnerEvent::RunFailed { .. }) {
+                if matches!(
+                    event,
+                    RunnerEvent::RunCompleted { .. } | RunnerEvent::RunFailed { .. }
+                ) {
                     saw_terminal_event = true;
                 }
                 let _ = tx.send(event);
@@ -569,32 +581,25 @@ async fn read_runner_output(
 }
 
 /// Background task that monitors a runner and updates DB on events.
-async fn monitor_runner(
-    state: &AgentRunsState,
-    run_id: &str,
-    tx: broadcast::Sender<RunnerEvent>,
-) {
+async fn monitor_runner(state: &AgentRunsState, run_id: &str, tx: broadcast::Sender<RunnerEvent>) {
     let mut rx = tx.subscribe();
 
     while let Ok(event) = rx.recv().await {
         match &event {
             RunnerEvent::IterationStarted { iteration, .. } => {
-                let _ = sqlx::query(
-                    "UPDATE agent_runs SET current_iteration = ? WHERE id = ?",
-                )
-                .bind(*iteration)
-                .bind(run_id)
-                .execute(&state.db.pool)
-                .await;
+                let _ = sqlx::query("UPDATE agent_runs SET current_iteration = ? WHERE id = ?")
+                    .bind(*iteration)
+                    .bind(run_id)
+                    .execute(&state.db.pool)
+                    .await;
             }
             RunnerEvent::IterationCompleted { cost, .. } => {
-                let _ = sqlx::query(
-                    "UPDATE agent_runs SET total_cost = total_cost + ? WHERE id = ?",
-                )
-                .bind(*cost)
-                .bind(run_id)
-                .execute(&state.db.pool)
-                .await;
+                let _ =
+                    sqlx::query("UPDATE agent_runs SET total_cost = total_cost + ? WHERE id = ?")
+                        .bind(*cost)
+                        .bind(run_id)
+                        .execute(&state.db.pool)
+                        .await;
             }
             RunnerEvent::StoryCompleted { passed, total, .. } => {
                 let _ = sqlx::query(
@@ -620,6 +625,17 @@ async fn monitor_runner(
                 .bind(run_id)
                 .execute(&state.db.pool)
                 .await;
+                notify_run_finished(
+                    &state.db,
+                    run_id,
+                    NotificationSeverity::Success,
+                    "Agent run finished",
+                    format!(
+                        "Run {} completed {} stories (${:.2})",
+                        run_id, stories_completed, total_cost
+                    ),
+                )
+                .await;
                 break;
             }
             RunnerEvent::RunFailed { error, .. } => {
@@ -631,6 +647,14 @@ async fn monitor_runner(
                 .bind(run_id)
                 .execute(&state.db.pool)
                 .await;
+                notify_run_finished(
+                    &state.db,
+                    run_id,
+                    NotificationSeverity::Error,
+                    "Agent run failed",
+                    format!("Run {} failed: {}", run_id, error),
+                )
+                .await;
                 break;
             }
             _ => {}
@@ -638,6 +662,26 @@ async fn monitor_runner(
     }
 }
 
+/// Send an execution-finished notification for a run that reached a terminal state.
+async fn notify_run_finished(
+    db: &DbState,
+    run_id: &str,
+    severity: NotificationSeverity,
+    title: &str,
+    message: String,
+) {
+    let mut input = NotificationInput::new(
+        NotificationEventType::ExecutionFinished,
+        severity,
+        title,
+        message,
+    );
+    if let Ok(run) = get_run_from_db(db, run_id).await {
+        input = input.with_project(run.project_id);
+    }
+    db.notifications.notify_in_background(input);
+}
+
 /// Retrieve a run from the database.
 async fn get_run_from_db(db: &DbState, run_id: &str) -> Result<AgentRun, String> {
     sqlx::query_as::<_, AgentRunRow>("SELECT * FROM agent_runs WHERE id = ?")
@@ -660,7 +704,9 @@ async fn get_claude_oauth_token(db: &DbState) -> Option<String> {
             {
                 Ok(Some(token)) if token.is_valid() => Some(token.access_token),
                 Ok(_) => {
-                    warn!("Claude OAuth token not found or expired. Run `orkee auth login claude`.");
+                    warn!(
+                        "Claude OAuth token not found or expired. Run `orkee auth login claude`."
+                    );
                     None
                 }
                 Err(e) => {
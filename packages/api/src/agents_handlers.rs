@@ -1,5 +1,5 @@
 // ABOUTME: HTTP request handlers for agent operations
-// ABOUTME: Handles agent listing (from JSON) and user-agent management (from DB)
+// ABOUTME: Handles agent listing (from JSON), user-agent management and tool permission policies (from DB)
 
 use axum::{
     extract::{Path, Query, State},
@@ -10,10 +10,12 @@ use axum::{
 use serde::Deserialize;
 use tracing::info;
 
-use super::response::ok_or_internal_error;
+use super::response::{ok_or_internal_error, ok_or_not_found};
+use orkee_agents::ToolPermissions;
 use orkee_models::REGISTRY;
 use orkee_projects::pagination::{PaginatedResponse, PaginationParams};
 use orkee_projects::DbState;
+use orkee_storage::StorageError;
 
 /// List all available agents from JSON configuration
 pub async fn list_agents(Query(pagination): Query<PaginationParams>) -> impl IntoResponse {
@@ -121,3 +123,59 @@ pub async fn update_agent_activation(
         ok_or_internal_error(result, "Failed to deactivate agent")
     }
 }
+
+/// Map tool policy errors, answering 404 for agents that aren't registered
+fn tool_policy_response<T: serde::Serialize>(
+    result: Result<T, StorageError>,
+    context: &str,
+) -> axum::response::Response {
+    match result {
+        Err(StorageError::InvalidAgent(_)) => ok_or_not_found(result, context),
+        result => ok_or_internal_error(result, context),
+    }
+}
+
+/// List the tool permissions of every agent
+pub async fn list_tool_policies(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Listing agent tool permissions");
+
+    let result = db.tool_policy_storage.list_policies().await;
+    ok_or_internal_error(result, "Failed to list tool permissions")
+}
+
+/// Get an agent's tool permissions
+pub async fn get_tool_policy(
+    State(db): State<DbState>,
+    Path(agent_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting tool permissions for agent: {}", agent_id);
+
+    let result = db.tool_policy_storage.get_policy(&agent_id).await;
+    tool_policy_response(result, "Failed to get tool permissions")
+}
+
+/// Override an agent's tool permissions
+pub async fn update_tool_policy(
+    State(db): State<DbState>,
+    Path(agent_id): Path<String>,
+    Json(permissions): Json<ToolPermissions>,
+) -> impl IntoResponse {
+    info!("Updating tool permissions for agent: {}", agent_id);
+
+    let result = db
+        .tool_policy_storage
+        .set_policy(&agent_id, permissions)
+        .await;
+    tool_policy_response(result, "Failed to update tool permissions")
+}
+
+/// Reset an agent's tool permissions to its defaults
+pub async fn reset_tool_policy(
+    State(db): State<DbState>,
+    Path(agent_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Resetting tool permissions for agent: {}", agent_id);
+
+    let result = db.tool_policy_storage.reset_policy(&agent_id).await;
+    tool_policy_response(result, "Failed to reset tool permissions")
+}
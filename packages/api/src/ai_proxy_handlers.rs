@@ -1,19 +1,30 @@
 // ABOUTME: AI proxy handlers for secure API key management
-// ABOUTME: Proxies requests to AI providers using database-stored credentials
+// ABOUTME: Proxies requests to AI providers using database-stored credentials, with per-task model fallbacks
+// ABOUTME: Optionally records redacted request/response captures by correlation ID for debugging
 
 use axum::{
     body::Body,
-    extract::State,
-    http::{Request, Response, StatusCode},
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, Request, Response, StatusCode},
     response::IntoResponse,
 };
 use reqwest::Client;
+use std::time::Instant;
 use tracing::{debug, error, trace, warn};
 use url::Url;
 
 use super::auth::CurrentUser;
+use super::response::{ok_or_internal_error, ok_or_not_found};
+use orkee_ai::debug_logs::{redact_body, redact_headers};
+use orkee_ai::{
+    NewAiDebugLog, DEBUG_LOGGING_SETTING, DEBUG_LOG_RETENTION_SETTING,
+    DEFAULT_DEBUG_LOG_RETENTION_HOURS,
+};
 use orkee_auth::oauth::OAuthProvider;
+use orkee_models::REGISTRY;
 use orkee_projects::DbState;
+use orkee_storage::model_preferences::TaskModelPolicy;
+use orkee_storage::StorageError;
 
 // Request body size limit: 10MB
 const MAX_REQUEST_SIZE: usize = 10_485_760;
@@ -40,6 +51,22 @@ const OPENAI_ALLOWED_PATHS: &[&str] = &[
 const GOOGLE_ALLOWED_PATHS: &[&str] = &["/v1beta/models", "/v1/models"];
 const XAI_ALLOWED_PATHS: &[&str] = &["/v1/chat/completions", "/v1/completions", "/v1/models"];
 
+// Names the model preference task type a request is for. Task-typed requests
+// follow that task's fallback chain and cost cap.
+const TASK_TYPE_HEADER: &str = "x-orkee-task-type";
+// Reports which model served a task-typed request
+const MODEL_USED_HEADER: &str = "x-orkee-model";
+// Rough size of a token, used to estimate input tokens from the request body
+const BYTES_PER_TOKEN: u64 = 4;
+// Output tokens assumed when a request doesn't set a limit
+const DEFAULT_MAX_OUTPUT_TOKENS: u64 = 4096;
+// Upstream statuses worth retrying with the next model in the chain
+const RETRYABLE_STATUSES: &[u16] = &[408, 429, 500, 502, 503, 504, 529];
+// Groups debug captures; sent by the client or generated, and echoed in the response
+const CORRELATION_ID_HEADER: &str = "x-orkee-correlation-id";
+// Longest client-supplied correlation ID that is accepted
+const MAX_CORRELATION_ID_LEN: usize = 128;
+
 /// Validates that the API path is safe and matches known provider endpoints
 fn validate_api_path(path: &str, provider: &str) -> Result<(), String> {
     // Check for path traversal attempts
@@ -128,6 +155,321 @@ fn build_error_response(status: StatusCode, message: String) -> Response<Body> {
         })
 }
 
+/// The model a request targets. Google names it in the path, the others in the body.
+fn request_model(provider: &str, target_path: &str, body: &serde_json::Value) -> Option<String> {
+    match provider {
+        "google" => target_path
+            .split_once("/models/")
+            .and_then(|(_, rest)| rest.split(':').next())
+            .filter(|model| !model.is_empty())
+            .map(str::to_string),
+        _ => body.get("model")?.as_str().map(str::to_string),
+    }
+}
+
+/// The output token limit a request sets, across provider request formats
+fn max_output_tokens(body: &serde_json::Value) -> u64 {
+    ["max_tokens", "max_completion_tokens", "max_output_tokens"]
+        .iter()
+        .find_map(|key| body.get(key)?.as_u64())
+        .or_else(|| {
+            body.get("generationConfig")?
+                .get("maxOutputTokens")?
+                .as_u64()
+        })
+        .unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS)
+}
+
+/// Point a request at another model, returning the new path and body
+fn retarget_request(
+    provider: &str,
+    target_path: &str,
+    body: &[u8],
+    model: &str,
+) -> Result<(String, Vec<u8>), String> {
+    match provider {
+        "google" => {
+            let (prefix, rest) = target_path
+                .split_once("/models/")
+                .ok_or_else(|| "request path names no model".to_string())?;
+            let method = rest.split_once(':').map(|(_, method)| method);
+            let path = match method {
+                Some(method) => format!("{}/models/{}:{}", prefix, model, method),
+                None => format!("{}/models/{}", prefix, model),
+            };
+            Ok((path, body.to_vec()))
+        }
+        _ => {
+            let mut request: serde_json::Value =
+                serde_json::from_slice(body).map_err(|e| e.to_string())?;
+            let fields = request
+                .as_object_mut()
+                .ok_or_else(|| "request body is not a JSON object".to_string())?;
+            fields.insert("model".to_string(), serde_json::json!(model));
+            let body = serde_json::to_vec(&request).map_err(|e| e.to_string())?;
+            Ok((target_path.to_string(), body))
+        }
+    }
+}
+
+/// Models to try for a task-typed request, in order: the requested model, then
+/// the task's chain. Models from other providers are skipped since request
+/// formats differ, as are models whose estimated cost exceeds the task's cap.
+fn plan_model_attempts(
+    policy: &TaskModelPolicy,
+    provider: &str,
+    requested: &str,
+    input_tokens: u64,
+    output_tokens: u64,
+) -> Result<Vec<String>, String> {
+    let chain = policy
+        .chain()
+        .into_iter()
+        .filter(|config| config.provider == provider)
+        .map(|config| config.model.as_str());
+
+    let mut attempts: Vec<String> = Vec::new();
+    let mut cheapest: Option<f64> = None;
+    for model in std::iter::once(requested).chain(chain) {
+        if attempts.iter().any(|attempt| attempt == model) {
+            continue;
+        }
+        if let Some(cap) = policy.max_cost_per_call {
+            let Some(registered) = REGISTRY.get_model(model) else {
+                debug!(
+                    "Skipping {}: no pricing to check against the cost cap",
+                    model
+                );
+                continue;
+            };
+            let cost = registered.pricing.cost(input_tokens, output_tokens);
+            if cost > cap {
+                debug!(
+                    "Skipping {}: estimated ${:.4} exceeds the ${:.4} cap",
+                    model, cost, cap
+                );
+                cheapest = Some(cheapest.map_or(cost, |c| c.min(cost)));
+                continue;
+            }
+        }
+        attempts.push(model.to_string());
+    }
+
+    if attempts.is_empty() {
+        let cap = policy.max_cost_per_call.unwrap_or_default();
+        return Err(match cheapest {
+            Some(cheapest) => format!(
+                "No {} model for {} fits the ${:.4} cost cap (cheapest estimate ${:.4})",
+                provider, policy.task_type, cap, cheapest
+            ),
+            None => format!(
+                "No {} model for {} has pricing to check against the ${:.4} cost cap",
+                provider, policy.task_type, cap
+            ),
+        });
+    }
+    Ok(attempts)
+}
+
+/// Models to try for a request of the given task type. Requests that don't
+/// name a model are forwarded unchanged.
+async fn task_model_attempts(
+    db: &DbState,
+    user_id: &str,
+    provider: &str,
+    task_type: &str,
+    target_path: &str,
+    body: &[u8],
+) -> Result<Vec<String>, Response<Body>> {
+    let policy = match db
+        .model_preferences_storage
+        .get_task_policy(user_id, task_type)
+        .await
+    {
+        Ok(policy) => policy,
+        Err(StorageError::InvalidInput(message)) => {
+            return Err(build_error_response(StatusCode::BAD_REQUEST, message))
+        }
+        Err(e) => {
+            error!("Failed to load {} model policy: {}", task_type, e);
+            return Err(build_error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to load model preferences. Please check server logs for details."
+                    .to_string(),
+            ));
+        }
+    };
+
+    let request: serde_json::Value =
+        serde_json::from_slice(body).unwrap_or(serde_json::Value::Null);
+    let Some(requested) = request_model(provider, target_path, &request) else {
+        return Ok(Vec::new());
+    };
+    let input_tokens = body.len() as u64 / BYTES_PER_TOKEN;
+
+    plan_model_attempts(
+        &policy,
+        provider,
+        &requested,
+        input_tokens,
+        max_output_tokens(&request),
+    )
+    .map_err(|e| build_error_response(StatusCode::BAD_REQUEST, e))
+}
+
+/// Debug capture of one proxied exchange, present when `ai_debug_logging_enabled` is on
+struct DebugCapture {
+    retention_hours: i64,
+    started: Instant,
+    log: NewAiDebugLog,
+}
+
+impl DebugCapture {
+    /// Start a capture if debug logging is enabled, redacting the request up front
+    async fn start(
+        db: &DbState,
+        user_id: &str,
+        provider: &str,
+        method: &axum::http::Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> Option<Self> {
+        let enabled = db
+            .settings_storage
+            .get(DEBUG_LOGGING_SETTING)
+            .await
+            .map(|setting| setting.value == "true")
+            .unwrap_or(false);
+        if !enabled {
+            return None;
+        }
+        let retention_hours = db
+            .settings_storage
+            .get(DEBUG_LOG_RETENTION_SETTING)
+            .await
+            .ok()
+            .and_then(|setting| setting.value.parse::<i64>().ok())
+            .filter(|hours| *hours >= 1)
+            .unwrap_or(DEFAULT_DEBUG_LOG_RETENTION_HOURS);
+
+        Some(Self {
+            retention_hours,
+            started: Instant::now(),
+            log: NewAiDebugLog {
+                correlation_id: correlation_id(headers),
+                user_id: user_id.to_string(),
+                provider: provider.to_string(),
+                method: method.to_string(),
+                path: path.to_string(),
+                model: None,
+                status_code: None,
+                duration_ms: 0,
+                request_headers: redact_headers(header_pairs(headers)),
+                request_body: redact_body(body),
+                response_headers: None,
+                response_body: None,
+                error: None,
+            },
+        })
+    }
+
+    /// Record the upstream response
+    async fn record_response(
+        mut self,
+        db: &DbState,
+        model: Option<String>,
+        status: StatusCode,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) {
+        self.log.model = model;
+        self.log.status_code = Some(i64::from(status.as_u16()));
+        self.log.response_headers = Some(redact_headers(header_pairs(headers)));
+        self.log.response_body = redact_body(body);
+        self.record(db).await;
+    }
+
+    /// Record a request that got no usable upstream response
+    async fn record_error(mut self, db: &DbState, model: Option<String>, error: String) {
+        self.log.model = model;
+        self.log.error = Some(error);
+        self.record(db).await;
+    }
+
+    async fn record(mut self, db: &DbState) {
+        self.log.duration_ms = self.started.elapsed().as_millis() as i64;
+        // A failed capture must not fail the proxied request
+        if let Err(e) = db
+            .ai_debug_log_storage
+            .record(&self.log, self.retention_hours)
+            .await
+        {
+            warn!(
+                "Failed to record AI debug log {}: {}",
+                self.log.correlation_id, e
+            );
+        }
+    }
+}
+
+/// The client's correlation ID when it is usable, otherwise a new one
+fn correlation_id(headers: &HeaderMap) -> String {
+    headers
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= MAX_CORRELATION_ID_LEN
+                && id
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+        })
+        .map(str::to_string)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string())
+}
+
+/// Header name/value pairs, skipping values that aren't visible ASCII
+fn header_pairs(headers: &HeaderMap) -> impl Iterator<Item = (&str, &str)> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)))
+}
+
+/// Echo the correlation ID so the client can look up the capture
+fn with_correlation_id(mut response: Response<Body>, correlation_id: &str) -> Response<Body> {
+    if let Ok(value) = HeaderValue::from_str(correlation_id) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+    response
+}
+
+/// Return the debug captures recorded under a correlation ID
+pub async fn get_debug_log(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(correlation_id): Path<String>,
+) -> impl IntoResponse {
+    let entries = match db
+        .ai_debug_log_storage
+        .get(&current_user.id, &correlation_id)
+        .await
+    {
+        Ok(entries) => entries,
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to load AI debug log"),
+    };
+    let result = if entries.is_empty() {
+        Err(format!(
+            "no unexpired captures for correlation ID '{}'",
+            correlation_id
+        ))
+    } else {
+        Ok(entries)
+    };
+    ok_or_not_found(result, "AI debug log not found")
+}
+
 /// Try to get OAuth token for a provider
 /// Returns Some(token) if OAuth token exists and is valid, None otherwise
 async fn try_get_oauth_token(db: &DbState, user_id: &str, provider: &str) -> Option<String> {
@@ -229,6 +571,143 @@ pub async fn proxy_xai(
     proxy_ai_request(&db, &current_user.id, "xai", "https://api.x.ai", req).await
 }
 
+/// Send a single-turn prompt through the proxy using the user's preferred
+/// model for `task_type`, following its fallback chain, and return the
+/// reply text. Google models are not supported.
+pub(crate) async fn complete_text(
+    db: &DbState,
+    user_id: &str,
+    task_type: &str,
+    system: &str,
+    prompt: &str,
+    max_tokens: u64,
+) -> Result<String, String> {
+    let policy = db
+        .model_preferences_storage
+        .get_task_policy(user_id, task_type)
+        .await
+        .map_err(|e| format!("Failed to load {} model preference: {}", task_type, e))?;
+    let model = &policy.primary;
+
+    let (base_url, path, body) = match model.provider.as_str() {
+        "anthropic" => (
+            "https://api.anthropic.com",
+            "/v1/messages",
+            serde_json::json!({
+                "model": model.model,
+                "max_tokens": max_tokens,
+                "system": system,
+                "messages": [{ "role": "user", "content": prompt }],
+            }),
+        ),
+        "openai" | "xai" => (
+            if model.provider == "openai" {
+                "https://api.openai.com"
+            } else {
+                "https://api.x.ai"
+            },
+            "/v1/chat/completions",
+            serde_json::json!({
+                "model": model.model,
+                "max_tokens": max_tokens,
+                "messages": [
+                    { "role": "system", "content": system },
+                    { "role": "user", "content": prompt },
+                ],
+            }),
+        ),
+        provider => return Err(format!("Provider {} is not supported here", provider)),
+    };
+
+    let request = Request::builder()
+        .method(axum::http::Method::POST)
+        .uri(format!("/ai/{}{}", model.provider, path))
+        .header("content-type", "application/json")
+        .header("accept", "application/json")
+        .header(TASK_TYPE_HEADER, task_type)
+        .body(Body::from(body.to_string()))
+        .map_err(|e| e.to_string())?;
+
+    let response = proxy_ai_request(db, user_id, &model.provider, base_url, request).await;
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), MAX_RESPONSE_SIZE)
+        .await
+        .map_err(|e| format!("Failed to read {} response: {}", model.provider, e))?;
+    if !status.is_success() {
+        let message = String::from_utf8_lossy(&bytes);
+        return Err(format!(
+            "{} returned {}: {}",
+            model.provider,
+            status,
+            message.chars().take(200).collect::<String>()
+        ));
+    }
+
+    let reply: serde_json::Value = serde_json::from_slice(&bytes)
+        .map_err(|e| format!("Invalid {} response: {}", model.provider, e))?;
+    let text = match model.provider.as_str() {
+        "anthropic" => reply["content"][0]["text"].as_str(),
+        _ => reply["choices"][0]["message"]["content"].as_str(),
+    };
+    text.map(str::to_string)
+        .ok_or_else(|| format!("{} response contained no text", model.provider))
+}
+
+/// Build the upstream request with the user's credentials
+fn build_proxy_request(
+    client: &Client,
+    method: axum::http::Method,
+    url: &str,
+    headers: &axum::http::HeaderMap,
+    body: Vec<u8>,
+    provider: &str,
+    api_key: &str,
+) -> reqwest::RequestBuilder {
+    debug!("Building proxy request - body size: {} bytes", body.len());
+    let mut proxy_req = client.request(method, url).body(body);
+
+    // Copy relevant headers (exclude host, connection, browser-specific headers, etc.)
+    for (key, value) in headers.iter() {
+        let key_str = key.as_str().to_lowercase();
+        if !matches!(
+            key_str.as_str(),
+            "host"
+                | "connection"
+                | "content-length"
+                | "x-api-key"
+                | "authorization"
+                | "origin"
+                | "referer"
+                | "user-agent"
+                | "sec-fetch-site"
+                | "sec-fetch-mode"
+                | "sec-fetch-dest"
+                | "sec-ch-ua"
+                | "sec-ch-ua-mobile"
+                | "sec-ch-ua-platform"
+                | TASK_TYPE_HEADER
+                | CORRELATION_ID_HEADER
+        ) {
+            proxy_req = proxy_req.header(key, value);
+        }
+    }
+
+    // Add custom User-Agent header for proper API behavior
+    proxy_req = proxy_req.header("User-Agent", "Orkee/1.0");
+
+    // Add the API key header and provider-specific headers
+    debug!("Adding provider-specific headers for: {}", provider);
+    match provider {
+        "anthropic" => proxy_req
+            .header("x-api-key", api_key)
+            .header("anthropic-version", "2023-06-01"),
+        "openai" => proxy_req.header("Authorization", format!("Bearer {}", api_key)),
+        "google" => proxy_req.header("x-goog-api-key", api_key),
+        "xai" => proxy_req.header("Authorization", format!("Bearer {}", api_key)),
+        _ => proxy_req,
+    }
+}
+
 /// Generic AI request proxy handler
 async fn proxy_ai_request(
     db: &DbState,
@@ -286,10 +765,13 @@ async fn proxy_ai_request(
     // Remove the /ai/{provider} prefix from the path
     // Note: /api prefix is already handled by Axum router
     let provider_prefix = format!("/ai/{}", provider);
-    let target_path = path.strip_prefix(&provider_prefix).unwrap_or(path);
+    let target_path = path
+        .strip_prefix(&provider_prefix)
+        .unwrap_or(path)
+        .to_string();
 
     // Validate the API path against provider whitelist
-    if let Err(e) = validate_api_path(target_path, provider) {
+    if let Err(e) = validate_api_path(&target_path, provider) {
         error!(
             "Invalid API path for {} proxy: {} (path: {})",
             provider, e, target_path
@@ -383,76 +865,115 @@ async fn proxy_ai_request(
         );
     }
 
-    // Build the request to the AI provider
-    debug!(
-        "Building proxy request - body size: {} bytes",
-        body_bytes.len()
-    );
-    let mut proxy_req = client
-        .request(method, &target_url)
-        .body(body_bytes.to_vec());
-
-    // Copy relevant headers (exclude host, connection, browser-specific headers, etc.)
-    for (key, value) in headers.iter() {
-        let key_str = key.as_str().to_lowercase();
-        if !matches!(
-            key_str.as_str(),
-            "host"
-                | "connection"
-                | "content-length"
-                | "x-api-key"
-                | "authorization"
-                | "origin"
-                | "referer"
-                | "user-agent"
-                | "sec-fetch-site"
-                | "sec-fetch-mode"
-                | "sec-fetch-dest"
-                | "sec-ch-ua"
-                | "sec-ch-ua-mobile"
-                | "sec-ch-ua-platform"
-        ) {
-            proxy_req = proxy_req.header(key, value);
-        }
-    }
-
-    // Add custom User-Agent header for proper API behavior
-    proxy_req = proxy_req.header("User-Agent", "Orkee/1.0");
-
-    // Add the API key header and provider-specific headers
-    debug!("Adding provider-specific headers for: {}", provider);
-    proxy_req = match provider {
-        "anthropic" => {
-            proxy_req
-                .header("x-api-key", api_key)
-                .header("anthropic-version", "2023-06-01")
+    let capture = DebugCapture::start(
+        db,
+        user_id,
+        provider,
+        &method,
+        &format!("{}{}", target_path, query),
+        &headers,
+        &body_bytes,
+    )
+    .await;
+    let correlation_id = capture
+        .as_ref()
+        .map(|capture| capture.log.correlation_id.clone());
+
+    // Task-typed requests follow the task's fallback chain and cost cap
+    let task_type = headers
+        .get(TASK_TYPE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let models = match &task_type {
+        Some(task_type) => {
+            match task_model_attempts(db, user_id, provider, task_type, &target_path, &body_bytes)
+                .await
+            {
+                Ok(models) => models,
+                Err(response) => return response,
+            }
         }
-        "openai" => proxy_req.header("Authorization", format!("Bearer {}", api_key)),
-        "google" => proxy_req.header("x-goog-api-key", api_key),
-        "xai" => proxy_req.header("Authorization", format!("Bearer {}", api_key)),
-        _ => proxy_req,
+        None => Vec::new(),
+    };
+    // None forwards the request as sent
+    let attempts: Vec<Option<&str>> = if models.is_empty() {
+        vec![None]
+    } else {
+        models.iter().map(|model| Some(model.as_str())).collect()
     };
 
-    // Send the request
-    debug!("Sending request to {}", target_url);
-    let response = match proxy_req.send().await {
-        Ok(resp) => {
-            debug!("Request sent successfully");
-            resp
-        }
-        Err(e) => {
-            error!("Failed to proxy request to {}: {}", provider, e);
-            error!("Error details: {:?}", e);
-            return build_error_response(
-                StatusCode::BAD_GATEWAY,
-                format!(
-                    "Failed to connect to {} API. Please check server logs for details.",
-                    provider
-                ),
-            );
+    let mut attempt = 0;
+    let (response, model_used) = loop {
+        let model = attempts[attempt];
+        let is_last = attempt + 1 == attempts.len();
+
+        let (url, body) = match model {
+            Some(model) => match retarget_request(provider, &target_path, &body_bytes, model) {
+                Ok((path, body)) => (format!("{}{}{}", base_url, path, query), body),
+                Err(e) => {
+                    return build_error_response(
+                        StatusCode::BAD_REQUEST,
+                        format!("Cannot switch request to model {}: {}", model, e),
+                    );
+                }
+            },
+            None => (target_url.clone(), body_bytes.to_vec()),
+        };
+
+        // Send the request
+        debug!("Sending request to {} ({:?})", url, model);
+        let proxy_req = build_proxy_request(
+            &client,
+            method.clone(),
+            &url,
+            &headers,
+            body,
+            provider,
+            &api_key,
+        );
+        match proxy_req.send().await {
+            Ok(resp) if !is_last && RETRYABLE_STATUSES.contains(&resp.status().as_u16()) => {
+                warn!(
+                    "{} returned {} for model {:?}, trying the next fallback",
+                    provider,
+                    resp.status(),
+                    model
+                );
+            }
+            Ok(resp) => {
+                debug!("Request sent successfully");
+                break (resp, model.map(str::to_string));
+            }
+            Err(e) if !is_last => {
+                warn!(
+                    "Failed to reach {} with model {:?}, trying the next fallback: {}",
+                    provider, model, e
+                );
+            }
+            Err(e) => {
+                error!("Failed to proxy request to {}: {}", provider, e);
+                error!("Error details: {:?}", e);
+                let response = build_error_response(
+                    StatusCode::BAD_GATEWAY,
+                    format!(
+                        "Failed to connect to {} API. Please check server logs for details.",
+                        provider
+                    ),
+                );
+                return match capture {
+                    Some(capture) => {
+                        let correlation_id = capture.log.correlation_id.clone();
+                        capture
+                            .record_error(db, model.map(str::to_string), e.to_string())
+                            .await;
+                        with_correlation_id(response, &correlation_id)
+                    }
+                    None => response,
+                };
+            }
         }
+        attempt += 1;
     };
-
     // Build response
     let status = response.status();
     let headers = response.headers().clone();
@@ -474,13 +995,27 @@ async fn proxy_ai_request(
         Err(e) => {
             error!("Failed to read response body: {}", e);
             error!("Error details: {:?}", e);
-            return build_error_response(
+            let response = build_error_response(
                 StatusCode::BAD_GATEWAY,
                 "Failed to read AI provider response".to_string(),
             );
+            return match capture {
+                Some(capture) => {
+                    let correlation_id = capture.log.correlation_id.clone();
+                    capture.record_error(db, model_used, e.to_string()).await;
+                    with_correlation_id(response, &correlation_id)
+                }
+                None => response,
+            };
         }
     };
 
+    if let Some(capture) = capture {
+        capture
+            .record_response(db, model_used.clone(), status, &headers, &body_bytes)
+            .await;
+    }
+
     // Validate response body size
     if body_bytes.len() > MAX_RESPONSE_SIZE {
         error!(
@@ -497,6 +1032,12 @@ async fn proxy_ai_request(
     // Build the final response
     debug!("Building final response with status: {}", status);
     let mut builder = Response::builder().status(status);
+    if let Some(model) = &model_used {
+        builder = builder.header(MODEL_USED_HEADER, model.as_str());
+    }
+    if let Some(correlation_id) = &correlation_id {
+        builder = builder.header(CORRELATION_ID_HEADER, correlation_id.as_str());
+    }
 
     // Copy response headers (excluding hop-by-hop headers)
     // We've already read and decoded the full body, so we shouldn't copy
@@ -663,4 +1204,118 @@ mod tests {
     fn test_validate_api_path_unknown_provider() {
         assert!(validate_api_path("/v1/messages", "unknown-provider").is_err());
     }
+
+    fn chat_policy(fallbacks: &[(&str, &str)], max_cost_per_call: Option<f64>) -> TaskModelPolicy {
+        let config = |provider: &str, model: &str| orkee_storage::model_preferences::ModelConfig {
+            provider: provider.to_string(),
+            model: model.to_string(),
+        };
+        TaskModelPolicy {
+            task_type: "chat".to_string(),
+            primary: config("anthropic", "claude-sonnet-4-5-20250929"),
+            fallbacks: fallbacks
+                .iter()
+                .map(|(provider, model)| config(provider, model))
+                .collect(),
+            max_cost_per_call,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_request_model_and_output_tokens() {
+        let body = serde_json::json!({"model": "gpt-5", "max_completion_tokens": 500});
+        assert_eq!(
+            request_model("openai", "/v1/chat/completions", &body).as_deref(),
+            Some("gpt-5")
+        );
+        assert_eq!(max_output_tokens(&body), 500);
+        assert_eq!(
+            request_model(
+                "google",
+                "/v1beta/models/gemini-2.5-pro:generateContent",
+                &serde_json::Value::Null
+            )
+            .as_deref(),
+            Some("gemini-2.5-pro")
+        );
+        assert_eq!(
+            max_output_tokens(&serde_json::json!({})),
+            DEFAULT_MAX_OUTPUT_TOKENS
+        );
+    }
+
+    #[test]
+    fn test_retarget_request() {
+        let (path, body) = retarget_request(
+            "anthropic",
+            "/v1/messages",
+            br#"{"model":"claude-opus-4-1-20250805","max_tokens":100}"#,
+            "claude-haiku-4-5-20251001",
+        )
+        .unwrap();
+        assert_eq!(path, "/v1/messages");
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["model"], "claude-haiku-4-5-20251001");
+        assert_eq!(body["max_tokens"], 100);
+
+        let (path, _) = retarget_request(
+            "google",
+            "/v1beta/models/gemini-2.5-pro:generateContent",
+            b"{}",
+            "gemini-2.5-flash",
+        )
+        .unwrap();
+        assert_eq!(path, "/v1beta/models/gemini-2.5-flash:generateContent");
+    }
+
+    #[test]
+    fn test_plan_model_attempts() {
+        let policy = chat_policy(
+            &[
+                ("openai", "gpt-5"),
+                ("anthropic", "claude-haiku-4-5-20251001"),
+                ("anthropic", "claude-sonnet-4-5-20250929"),
+            ],
+            None,
+        );
+        // Other providers and repeats are skipped
+        assert_eq!(
+            plan_model_attempts(&policy, "anthropic", "claude-opus-4-1-20250805", 1000, 1000)
+                .unwrap(),
+            vec![
+                "claude-opus-4-1-20250805",
+                "claude-sonnet-4-5-20250929",
+                "claude-haiku-4-5-20251001"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_model_attempts_applies_cost_cap() {
+        // 10k in / 1k out: Opus $0.225, Sonnet $0.045, Haiku $0.015
+        let policy = chat_policy(&[("anthropic", "claude-haiku-4-5-20251001")], Some(0.02));
+        assert_eq!(
+            plan_model_attempts(
+                &policy,
+                "anthropic",
+                "claude-opus-4-1-20250805",
+                10_000,
+                1_000
+            )
+            .unwrap(),
+            vec!["claude-haiku-4-5-20251001"]
+        );
+
+        let policy = chat_policy(&[], Some(0.001));
+        let err = plan_model_attempts(
+            &policy,
+            "anthropic",
+            "claude-sonnet-4-5-20250929",
+            10_000,
+            1_000,
+        )
+        .unwrap_err();
+        assert!(err.contains("cost cap"));
+    }
 }
@@ -2,16 +2,17 @@
 // ABOUTME: Provides endpoints for querying AI usage statistics and cost tracking
 
 use axum::{
-    extract::{Json, Query, State},
+    extract::{Json, Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::{error, info};
 
-use super::response::{ok_or_internal_error, ApiResponse};
-use orkee_ai::usage_logs::{AiUsageLog, AiUsageQuery};
+use super::response::{bad_request, created_or_internal_error, ok_or_internal_error, ApiResponse};
+use orkee_ai::usage_logs::{AiUsageLog, AiUsageQuery, PromptUsageRecord};
+use orkee_projects::pagination::{PaginatedResponse, PaginationParams, DEFAULT_PAGE_SIZE};
 use orkee_projects::DbState;
 
 #[derive(Deserialize)]
@@ -27,9 +28,14 @@ pub struct ListLogsQuery {
     pub provider: Option<String>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
+    /// Opt into cursor pagination; empty for the first page
+    pub cursor: Option<String>,
 }
 
 /// List AI usage logs with optional filtering
+///
+/// Returns a plain array paged by `limit`/`offset` by default. Passing
+/// `cursor` returns a paginated response with a `nextCursor` instead.
 pub async fn list_logs(
     State(db): State<DbState>,
     Query(params): Query<ListLogsQuery>,
@@ -50,6 +56,24 @@ pub async fn list_logs(
         offset: params.offset,
     };
 
+    if let Some(cursor) = params.cursor {
+        let pagination = PaginationParams {
+            limit: params.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+            cursor: Some(cursor),
+            ..PaginationParams::default()
+        };
+        let after = match pagination.keyset_position() {
+            Ok(after) => after,
+            Err(e) => return bad_request(e, "Failed to list AI usage logs"),
+        };
+        let result = db
+            .ai_usage_log_storage
+            .list_logs_after(&query, after.as_ref(), pagination.limit())
+            .await
+            .map(|page| PaginatedResponse::from_keyset(page, &pagination));
+        return ok_or_internal_error(result, "Failed to list AI usage logs");
+    }
+
     let result = db.ai_usage_log_storage.list_logs(query).await;
     ok_or_internal_error(result, "Failed to list AI usage logs")
 }
@@ -89,6 +113,56 @@ pub async fn get_stats(
     ok_or_internal_error(result, "Failed to get AI usage stats")
 }
 
+/// Period covered by the project dashboard when no start date is given
+const DEFAULT_PROJECT_STATS_DAYS: i64 = 30;
+
+/// Operations listed in the project dashboard unless `top` is given
+const DEFAULT_TOP_OPERATIONS: usize = 5;
+
+#[derive(Deserialize)]
+pub struct GetProjectStatsQuery {
+    #[serde(rename = "startDate")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<DateTime<Utc>>,
+    /// How many of the most expensive operations to list
+    pub top: Option<usize>,
+}
+
+/// Get a project's AI spend with trends, top operations, model mix, and
+/// a comparison against the previous period of the same length
+///
+/// Defaults to the last 30 days.
+pub async fn get_project_stats(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Query(params): Query<GetProjectStatsQuery>,
+) -> impl IntoResponse {
+    info!("Getting AI usage stats for project: {}", project_id);
+
+    let end = params.end_date.unwrap_or_else(Utc::now);
+    let start = params
+        .start_date
+        .unwrap_or(end - Duration::days(DEFAULT_PROJECT_STATS_DAYS));
+    if start >= end {
+        return bad_request(
+            "startDate must be before endDate",
+            "Failed to get project AI usage stats",
+        );
+    }
+
+    let result = db
+        .ai_usage_log_storage
+        .get_project_stats(
+            &project_id,
+            start,
+            end,
+            params.top.unwrap_or(DEFAULT_TOP_OPERATIONS),
+        )
+        .await;
+    ok_or_internal_error(result, "Failed to get project AI usage stats")
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateLogRequest {
     #[serde(rename = "projectId")]
@@ -251,6 +325,16 @@ pub async fn create_log(
     match db.ai_usage_log_storage.create_log(&usage_log).await {
         Ok(_) => {
             info!("Successfully created AI usage log: {}", log_id);
+
+            // Check the monthly budget without delaying the response
+            let notifications = db.notifications.clone();
+            let added_cost = request.estimated_cost;
+            tokio::spawn(async move {
+                if let Err(e) = notifications.check_budget(added_cost).await {
+                    error!("Failed to check AI budget: {}", e);
+                }
+            });
+
             let response = CreateLogResponse {
                 id: log_id,
                 created_at: now,
@@ -339,3 +423,113 @@ pub async fn get_time_series(
         .await;
     ok_or_internal_error(result, "Failed to get time-series data")
 }
+
+#[derive(Deserialize)]
+pub struct GetPromptStatsQuery {
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<DateTime<Utc>>,
+    pub operation: Option<String>,
+    /// Adds a per-prompt time series: 'hour', 'day', 'week', or 'month'
+    pub interval: Option<String>,
+}
+
+/// Get tokens and cost attributed to each prompt, most expensive first
+pub async fn get_prompt_stats(
+    State(db): State<DbState>,
+    Query(params): Query<GetPromptStatsQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Getting prompt usage stats (project_id: {:?}, interval: {:?})",
+        params.project_id, params.interval
+    );
+
+    if let Some(interval) = &params.interval {
+        if !matches!(interval.as_str(), "hour" | "day" | "week" | "month") {
+            return bad_request(
+                format!("invalid interval '{}'", interval),
+                "Failed to get prompt usage stats",
+            );
+        }
+    }
+
+    let query = AiUsageQuery {
+        project_id: params.project_id,
+        start_date: params.start_date,
+        end_date: params.end_date,
+        operation: params.operation,
+        model: None,
+        provider: None,
+        limit: None,
+        offset: None,
+    };
+
+    let result = db
+        .ai_usage_log_storage
+        .get_prompt_stats(&query, params.interval.as_deref())
+        .await;
+    ok_or_internal_error(result, "Failed to get prompt usage stats")
+}
+
+/// One prompt rendered for an AI request, as recorded by `PromptManager`
+#[derive(Debug, Deserialize)]
+pub struct PromptUsageEntry {
+    #[serde(rename = "promptId")]
+    pub prompt_id: String,
+    #[serde(rename = "promptVersion")]
+    pub prompt_version: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: String,
+    pub operation: Option<String>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecordPromptUsageRequest {
+    pub usage: Vec<PromptUsageEntry>,
+}
+
+/// Record which prompts AI requests were built from, for joining with usage logs
+pub async fn record_prompt_usage(
+    State(db): State<DbState>,
+    Json(request): Json<RecordPromptUsageRequest>,
+) -> impl IntoResponse {
+    info!("Recording {} prompt usage entries", request.usage.len());
+
+    if request
+        .usage
+        .iter()
+        .any(|entry| entry.prompt_id.is_empty() || entry.request_id.is_empty())
+    {
+        return bad_request(
+            "promptId and requestId are required",
+            "Failed to record prompt usage",
+        );
+    }
+
+    let now = Utc::now();
+    let records: Vec<PromptUsageRecord> = request
+        .usage
+        .into_iter()
+        .map(|entry| PromptUsageRecord {
+            id: nanoid::nanoid!(10),
+            prompt_id: entry.prompt_id,
+            prompt_version: entry.prompt_version,
+            request_id: entry.request_id,
+            operation: entry.operation,
+            project_id: entry.project_id,
+            created_at: now,
+        })
+        .collect();
+
+    let result = db
+        .ai_usage_log_storage
+        .record_prompt_usage(&records)
+        .await
+        .map(|_| records.len());
+    created_or_internal_error(result, "Failed to record prompt usage")
+}
@@ -0,0 +1,287 @@
+// ABOUTME: HTTP request handlers for managing API tokens under /api/security/tokens
+// ABOUTME: Creates, rotates, expires, and revokes tokens; plaintext tokens are only returned when issued
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::auth::CurrentUser;
+use super::response::{bad_request, created_or_internal_error, ok_or_internal_error, ApiResponse};
+use orkee_projects::{ApiToken, DbState, TokenGeneration, TokenStorage};
+
+/// How long a rotated token keeps working when the request doesn't say
+const DEFAULT_ROTATION_GRACE_MINUTES: u32 = 60;
+
+/// Longest grace period a rotated token can get (one week)
+const MAX_ROTATION_GRACE_MINUTES: u32 = 7 * 24 * 60;
+
+/// API token as listed; the hash is never returned
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTokenResponse {
+    pub id: String,
+    pub name: String,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+    pub expires_at: Option<String>,
+    pub replaced_by: Option<String>,
+    pub is_active: bool,
+    pub is_expired: bool,
+}
+
+impl From<ApiToken> for ApiTokenResponse {
+    fn from(token: ApiToken) -> Self {
+        let is_expired = token.is_expired(Utc::now());
+        Self {
+            id: token.id,
+            name: token.name,
+            created_at: token.created_at,
+            last_used_at: token.last_used_at,
+            expires_at: token.expires_at,
+            replaced_by: token.replaced_by,
+            is_active: token.is_active,
+            is_expired,
+        }
+    }
+}
+
+/// A newly issued token. This is the only time the plaintext token is returned.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedTokenResponse {
+    pub id: String,
+    pub token: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl IssuedTokenResponse {
+    fn new(generation: TokenGeneration, expires_at: Option<DateTime<Utc>>) -> Self {
+        Self {
+            id: generation.id,
+            token: generation.token,
+            expires_at,
+        }
+    }
+}
+
+/// Request body for creating a token
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTokenRequest {
+    pub name: String,
+    /// Days until the token expires; omit for a token that never expires
+    pub expires_in_days: Option<u32>,
+}
+
+/// Request body for rotating a token
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RotateTokenRequest {
+    /// Minutes the old token keeps working; 0 revokes it immediately
+    pub grace_minutes: Option<u32>,
+    /// Days until the new token expires; omit for a token that never expires
+    pub expires_in_days: Option<u32>,
+}
+
+/// Request body for changing a token's expiry
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTokenRequest {
+    /// New expiry, or null to never expire
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+fn expiry_in_days(days: Option<u32>) -> Result<Option<DateTime<Utc>>, String> {
+    match days {
+        Some(0) => Err("expiresInDays must be at least 1".to_string()),
+        Some(days) => Ok(Some(Utc::now() + Duration::days(i64::from(days)))),
+        None => Ok(None),
+    }
+}
+
+fn token_not_found(id: &str) -> axum::response::Response {
+    (
+        StatusCode::NOT_FOUND,
+        ResponseJson(ApiResponse::<()>::error(format!(
+            "API token not found: {}",
+            id
+        ))),
+    )
+        .into_response()
+}
+
+/// List all API tokens, newest first
+pub async fn list_tokens(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+) -> impl IntoResponse {
+    info!("Listing API tokens");
+
+    let result = db.token_storage.list_tokens().await.map(|tokens| {
+        tokens
+            .into_iter()
+            .map(ApiTokenResponse::from)
+            .collect::<Vec<_>>()
+    });
+    ok_or_internal_error(result, "Failed to list API tokens")
+}
+
+/// Create an API token
+pub async fn create_token(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Json(request): Json<CreateTokenRequest>,
+) -> impl IntoResponse {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return bad_request("name is required", "Invalid API token");
+    }
+    let expires_at = match expiry_in_days(request.expires_in_days) {
+        Ok(expires_at) => expires_at,
+        Err(e) => return bad_request(e, "Invalid API token"),
+    };
+
+    info!("Creating API token: {}", name);
+    let result = db
+        .token_storage
+        .create_token_with_expiry(name, expires_at)
+        .await
+        .map(|generation| IssuedTokenResponse::new(generation, expires_at));
+    created_or_internal_error(result, "Failed to create API token")
+}
+
+/// Issue a replacement token while the old one keeps working for a grace period
+pub async fn rotate_token(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(id): Path<String>,
+    request: Option<Json<RotateTokenRequest>>,
+) -> impl IntoResponse {
+    let request = request.map(|Json(request)| request).unwrap_or_default();
+    let grace_minutes = request
+        .grace_minutes
+        .unwrap_or(DEFAULT_ROTATION_GRACE_MINUTES);
+    if grace_minutes > MAX_ROTATION_GRACE_MINUTES {
+        return bad_request(
+            format!("graceMinutes can be at most {}", MAX_ROTATION_GRACE_MINUTES),
+            "Invalid rotation",
+        );
+    }
+    let expires_at = match expiry_in_days(request.expires_in_days) {
+        Ok(expires_at) => expires_at,
+        Err(e) => return bad_request(e, "Invalid rotation"),
+    };
+
+    let old = match db.token_storage.get_token(&id).await {
+        Ok(Some(token)) => token,
+        Ok(None) => return token_not_found(&id),
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to rotate API token"),
+    };
+
+    info!(
+        "Rotating API token {} with a {} minute grace period",
+        id, grace_minutes
+    );
+    let generation = match db
+        .token_storage
+        .rotate_token(&id, Duration::minutes(i64::from(grace_minutes)), expires_at)
+        .await
+    {
+        Ok(Some(generation)) => generation,
+        Ok(None) => {
+            return bad_request("token is revoked or expired", "API token can't be rotated")
+        }
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to rotate API token"),
+    };
+
+    replace_token_file(&old, &generation.token);
+
+    created_or_internal_error::<_, String>(
+        Ok(IssuedTokenResponse::new(generation, expires_at)),
+        "Failed to rotate API token",
+    )
+}
+
+/// Keep ~/.orkee/api-token working after the token stored there is rotated,
+/// since the dashboard and CLI read their token from it
+fn replace_token_file(old: &ApiToken, new_token: &str) {
+    let token_file = orkee_projects::orkee_dir().join("api-token");
+    let Ok(stored) = std::fs::read_to_string(&token_file) else {
+        return;
+    };
+    if !TokenStorage::verify_token_hash(stored.trim(), &old.token_hash) {
+        return;
+    }
+
+    // Overwriting keeps the file's owner-only permissions
+    match std::fs::write(&token_file, new_token) {
+        Ok(()) => info!("Updated {} with the rotated token", token_file.display()),
+        Err(e) => warn!(
+            "Failed to update {} with the rotated token: {}",
+            token_file.display(),
+            e
+        ),
+    }
+}
+
+/// Set or clear a token's expiry
+pub async fn update_token(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(id): Path<String>,
+    Json(request): Json<UpdateTokenRequest>,
+) -> impl IntoResponse {
+    info!("Updating expiry of API token {}", id);
+
+    match db.token_storage.set_expiry(&id, request.expires_at).await {
+        Ok(false) => token_not_found(&id),
+        Ok(true) => {
+            let result = db
+                .token_storage
+                .get_token(&id)
+                .await
+                .map(|token| token.map(ApiTokenResponse::from));
+            ok_or_internal_error(result, "Failed to update API token")
+        }
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to update API token"),
+    }
+}
+
+/// Revoke a token immediately
+pub async fn revoke_token(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    info!("Revoking API token {}", id);
+
+    match db.token_storage.get_token(&id).await {
+        Ok(Some(_)) => {
+            let result = db.token_storage.revoke_token(&id).await;
+            ok_or_internal_error(result, "Failed to revoke API token")
+        }
+        Ok(None) => token_not_found(&id),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to revoke API token"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry_in_days() {
+        assert_eq!(expiry_in_days(None), Ok(None));
+        assert!(expiry_in_days(Some(0)).is_err());
+
+        let expires_at = expiry_in_days(Some(30)).unwrap().unwrap();
+        let days = (expires_at - Utc::now()).num_days();
+        assert!((29..=30).contains(&days));
+    }
+}
@@ -10,7 +10,7 @@
 // - `get_current_user` (users_handlers.rs) does NOT use CurrentUser extractor
 //
 // For localhost-only APIs in desktop mode, this inconsistency has no security impact
-// since all requests are treated as the default user. However, for consistency and
+// since all requests act as the single active user. However, for consistency and
 // future-proofing (e.g., if authentication is added), ALL user endpoints should use
 // the CurrentUser extractor pattern.
 //
@@ -25,9 +25,11 @@
 // ```
 
 use axum::{
-    extract::FromRequestParts,
+    extract::{FromRef, FromRequestParts},
     http::{request::Parts, StatusCode},
 };
+use orkee_projects::DbState;
+use tracing::error;
 
 /// Current authenticated user
 #[derive(Debug, Clone)]
@@ -35,22 +37,25 @@ pub struct CurrentUser {
     pub id: String,
 }
 
-impl CurrentUser {
-    /// Get the default user ID for single-user desktop application
-    fn default_user() -> Self {
-        Self {
-            id: "default-user".to_string(),
-        }
-    }
-}
-
 impl<S> FromRequestParts<S> for CurrentUser
 where
+    DbState: FromRef<S>,
     S: Send + Sync,
 {
     type Rejection = (StatusCode, &'static str);
 
-    async fn from_request_parts(_parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        Ok(Self::default_user())
+    /// Resolves to the active user chosen through the user switcher
+    async fn from_request_parts(_parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let db = DbState::from_ref(state);
+        match db.user_storage.get_current_user().await {
+            Ok(user) => Ok(Self { id: user.id }),
+            Err(e) => {
+                error!("Failed to resolve current user: {}", e);
+                Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Failed to resolve current user",
+                ))
+            }
+        }
     }
 }
@@ -0,0 +1,46 @@
+// ABOUTME: HTTP handler for the change data capture feed
+// ABOUTME: Pages through project, task, PRD, and tag mutations in sequence order
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+use tracing::debug;
+
+use super::response::{bad_request, ok_or_internal_error};
+use orkee_projects::DbState;
+use orkee_storage::change_journal::{ChangeEntity, DEFAULT_CHANGE_LIMIT};
+
+#[derive(Deserialize)]
+pub struct ChangesQuery {
+    /// Return changes with a sequence number above this; 0 reads from the start
+    #[serde(default)]
+    pub since: i64,
+    pub limit: Option<i64>,
+    /// Only changes to one kind of entity: project, task, prd, or tag
+    pub entity_type: Option<String>,
+}
+
+/// GET /api/changes?since=seq - ordered feed of entity mutations
+pub async fn list_changes(
+    State(db): State<DbState>,
+    Query(query): Query<ChangesQuery>,
+) -> impl IntoResponse {
+    let entity_type = match query.entity_type.as_deref().map(str::parse::<ChangeEntity>) {
+        Some(Err(e)) => return bad_request(e, "Invalid entity type"),
+        Some(Ok(entity_type)) => Some(entity_type),
+        None => None,
+    };
+    debug!("Listing changes since {}", query.since);
+
+    let result = db
+        .change_journal
+        .list_since(
+            query.since.max(0),
+            query.limit.unwrap_or(DEFAULT_CHANGE_LIMIT),
+            entity_type,
+        )
+        .await;
+    ok_or_internal_error(result, "Failed to list changes")
+}
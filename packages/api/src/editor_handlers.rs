@@ -0,0 +1,199 @@
+// ABOUTME: HTTP handlers for editor integrations
+// ABOUTME: Lists installed editors, opens projects or files at a line, and manages per-project editor overrides
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson, Response},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use super::response::{bad_request, ok_or_internal_error, ApiResponse};
+use orkee_projects::editors::{
+    detect_editors, find_editor, open_in_editor, DetectedEditor, EditorError, EditorPreferences,
+    EditorTarget,
+};
+use orkee_projects::{get_project as manager_get_project, DbState};
+
+/// Supported editors with installation status, plus the user's editor choices
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorsResponse {
+    pub editors: Vec<DetectedEditor>,
+    pub preferences: EditorPreferences,
+}
+
+/// List supported editors and which of them are installed
+pub async fn list_editors(State(db): State<DbState>) -> Response {
+    let preferences = match EditorPreferences::load(&db.settings_storage).await {
+        Ok(preferences) => preferences,
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to load editor settings"),
+    };
+
+    let result = tokio::task::spawn_blocking(detect_editors)
+        .await
+        .map(|editors| EditorsResponse {
+            editors,
+            preferences,
+        });
+    ok_or_internal_error(result, "Failed to detect editors")
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenEditorRequest {
+    pub project_id: Option<String>,
+    pub project_path: Option<String>,
+    /// File to open, relative to the project root
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    /// Overrides the project's and the user's editor choice
+    pub editor_id: Option<String>,
+}
+
+/// Open a project, or a file at a line within it, in the resolved editor
+pub async fn open_editor(
+    State(db): State<DbState>,
+    Json(request): Json<OpenEditorRequest>,
+) -> Response {
+    info!("Opening in editor: {:?}", request);
+
+    let project_root = match (&request.project_id, request.project_path) {
+        (Some(project_id), _) => match manager_get_project(project_id).await {
+            Ok(Some(project)) => project.project_root,
+            Ok(None) => return project_not_found(project_id),
+            Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to fetch project"),
+        },
+        (None, Some(project_path)) => project_path,
+        (None, None) => {
+            return bad_request(
+                "Either projectId or projectPath must be provided",
+                "Invalid request",
+            )
+        }
+    };
+
+    let preferences = match EditorPreferences::load(&db.settings_storage).await {
+        Ok(preferences) => preferences,
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to load editor settings"),
+    };
+    let editor_id =
+        preferences.editor_for(request.project_id.as_deref(), request.editor_id.as_deref());
+
+    let launch = find_editor(editor_id).and_then(|editor| {
+        let target = EditorTarget::new(
+            project_root,
+            request.file.as_deref(),
+            request.line,
+            request.column,
+        )?;
+        Ok((editor, target))
+    });
+    let (editor, target) = match launch {
+        Ok(launch) => launch,
+        Err(e) => return editor_error(e),
+    };
+
+    match tokio::task::spawn_blocking(move || open_in_editor(editor, &target)).await {
+        Ok(Ok(launch)) => {
+            (StatusCode::OK, ResponseJson(ApiResponse::success(launch))).into_response()
+        }
+        Ok(Err(e)) => editor_error(e),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Editor launch task failed"),
+    }
+}
+
+/// Get the editor choices that apply to a project
+pub async fn get_project_editor(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+) -> Response {
+    let result = EditorPreferences::load(&db.settings_storage)
+        .await
+        .map(|preferences| ProjectEditorResponse::new(&preferences, &project_id));
+    ok_or_internal_error(result, "Failed to load editor settings")
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetProjectEditorRequest {
+    /// Editor for this project, or `null` to use the preferred editor
+    pub editor_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectEditorResponse {
+    /// Editor that opens this project
+    pub editor_id: String,
+    /// The project's override, if it has one
+    pub override_editor: Option<String>,
+}
+
+impl ProjectEditorResponse {
+    fn new(preferences: &EditorPreferences, project_id: &str) -> Self {
+        Self {
+            editor_id: preferences.editor_for(Some(project_id), None).to_string(),
+            override_editor: preferences.project_overrides.get(project_id).cloned(),
+        }
+    }
+}
+
+/// Set or clear a project's editor override
+pub async fn set_project_editor(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Json(request): Json<SetProjectEditorRequest>,
+) -> Response {
+    info!(
+        "Setting editor for project {}: {:?}",
+        project_id, request.editor_id
+    );
+
+    match manager_get_project(&project_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return project_not_found(&project_id),
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to fetch project"),
+    }
+
+    match EditorPreferences::set_project_override(
+        &db.settings_storage,
+        &project_id,
+        request.editor_id.as_deref(),
+    )
+    .await
+    {
+        Ok(preferences) => {
+            let response = ProjectEditorResponse::new(&preferences, &project_id);
+            (StatusCode::OK, ResponseJson(ApiResponse::success(response))).into_response()
+        }
+        Err(e) => editor_error(e),
+    }
+}
+
+fn project_not_found(project_id: &str) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        ResponseJson(ApiResponse::<()>::error(format!(
+            "Project not found: {}",
+            project_id
+        ))),
+    )
+        .into_response()
+}
+
+/// Caller mistakes are 400s; launch and storage failures are 500s
+fn editor_error(error: EditorError) -> Response {
+    match error {
+        EditorError::UnknownEditor(_)
+        | EditorError::NotInstalled(_)
+        | EditorError::InvalidPath(_) => bad_request(error, "Cannot open editor"),
+        EditorError::Launch(_) | EditorError::Storage(_) => {
+            error!("Editor integration failed: {}", error);
+            ok_or_internal_error::<(), _>(Err(error), "Failed to open editor")
+        }
+    }
+}
@@ -8,8 +8,8 @@ use axum::{
     Json,
 };
 use orkee_ideate::{
-    ApproachComparison, ApproachGenerator, CodebaseAnalyzer, CodebaseContext, EpicManager,
-    TechnicalApproach,
+    AiApproachScores, ApproachComparison, ApproachGenerator, ApproachScorer, CodebaseAnalyzer,
+    CodebaseContext, EpicManager, IdeateError, ScoringWeights, TechnicalApproach,
 };
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -34,6 +34,16 @@ pub struct SelectApproachRequest {
     pub rationale: Option<String>,
 }
 
+/// Request body for scoring approaches
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScoreApproachesRequest {
+    /// Criterion weights; all criteria count equally when omitted
+    pub weights: Option<ScoringWeights>,
+    /// Ratings the client obtained from the AI proxy, overriding the heuristics
+    pub ai_scores: Vec<AiApproachScores>,
+}
+
 /// POST /api/epics/:id/generate-alternatives
 /// Generate 2-3 alternative technical approaches with trade-off analysis
 pub async fn generate_alternatives(
@@ -169,6 +179,98 @@ pub async fn get_alternatives(
     generate_alternatives(State(db), Path((project_id, epic_id))).await
 }
 
+/// POST /api/epics/:id/approach-matrix
+/// Score the generated approaches on effort, risk, maintainability, and dependencies
+pub async fn score_approaches(
+    State(db): State<DbState>,
+    Path((project_id, epic_id)): Path<(String, String)>,
+    Json(request): Json<ScoreApproachesRequest>,
+) -> impl IntoResponse {
+    info!("Scoring approaches for epic: {}", epic_id);
+
+    let epic_manager = EpicManager::new(db.pool.clone());
+
+    let epic = match epic_manager.get_epic(&project_id, &epic_id).await {
+        Ok(Some(e)) => e,
+        Ok(None) => {
+            error!("Epic not found: {}", epic_id);
+            return (
+                StatusCode::NOT_FOUND,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": "Epic not found"
+                })),
+            )
+                .into_response();
+        }
+        Err(e) => {
+            error!("Failed to fetch epic: {:?}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!("Failed to fetch epic: {}", e)
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    // Score against the same alternatives the selection endpoint validates
+    let codebase_context = epic
+        .codebase_context
+        .as_ref()
+        .and_then(|v| serde_json::from_value::<CodebaseContext>(v.clone()).ok())
+        .unwrap_or_default();
+
+    let generator = ApproachGenerator::new(epic, codebase_context.clone());
+    let approaches = match generator.generate_alternatives().await {
+        Ok(a) => a,
+        Err(e) => {
+            error!("Failed to generate alternatives: {:?}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!("Failed to generate alternatives: {}", e)
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let weights = request.weights.unwrap_or_default();
+    match ApproachScorer::new(&codebase_context).score(&approaches, &request.ai_scores, weights) {
+        Ok(matrix) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "success": true,
+                "data": matrix
+            })),
+        )
+            .into_response(),
+        Err(e @ IdeateError::InvalidInput(_)) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "success": false,
+                "error": format!("Invalid scoring request: {}", e)
+            })),
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to score approaches: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!("Failed to score approaches: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    }
+}
+
 /// PUT /api/epics/:id/select-approach
 /// Select a preferred technical approach and update epic
 pub async fn select_approach(
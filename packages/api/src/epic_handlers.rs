@@ -1,20 +1,26 @@
 // ABOUTME: HTTP request handlers for Epic operations (CCPM workflow)
 // ABOUTME: Handles CRUD operations, generation, task decomposition, and progress tracking for Epics
 
+use async_trait::async_trait;
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
     Json,
 };
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use std::sync::Arc;
+use tracing::{error, info};
 
-use super::response::{created_or_internal_error, ok_or_internal_error, ok_or_not_found};
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
 use orkee_ideate::{
-    ComplexityAnalyzer, CreateEpicInput, Epic, EpicComplexity, EpicManager, EpicStatus,
-    EstimatedEffort, ExecutionTracker, UpdateEpicInput,
+    CheckpointRunner, CommandOutput, ComplexityAnalyzer, CreateEpicInput, Epic, EpicComplexity,
+    EpicManager, EpicStatus, EstimatedEffort, ExecutionTracker, IdeateError, UpdateEpicInput,
+    ValidationCommandExecutor,
 };
 use orkee_projects::DbState;
+use orkee_storage::StorageError;
 
 /// List all Epics for a project
 pub async fn list_epics(
@@ -58,6 +64,26 @@ pub async fn list_epics_by_prd(
     ok_or_internal_error(result, "Failed to list epics for PRD")
 }
 
+/// Map each requirement of a PRD to the epics and tasks implementing it,
+/// flagging requirements nothing implements
+pub async fn get_prd_traceability(
+    State(db): State<DbState>,
+    Path((project_id, prd_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Building traceability matrix for PRD: {} in project: {}",
+        prd_id, project_id
+    );
+
+    let manager = EpicManager::new(db.pool.clone());
+    let result = manager.traceability_matrix(&project_id, &prd_id).await;
+
+    if matches!(result, Err(IdeateError::NotFound(_))) {
+        return ok_or_not_found(result, "PRD not found");
+    }
+    ok_or_internal_error(result, "Failed to build traceability matrix")
+}
+
 /// Request body for creating an Epic
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -214,6 +240,25 @@ pub async fn calculate_epic_progress(
     ok_or_internal_error(result, "Failed to calculate epic progress")
 }
 
+/// Get burndown, velocity, and projected completion metrics for an Epic
+pub async fn get_epic_metrics(
+    State(db): State<DbState>,
+    Path((project_id, epic_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Calculating metrics for epic: {} in project: {}",
+        epic_id, project_id
+    );
+
+    let manager = EpicManager::new(db.pool.clone());
+    let result = manager.calculate_metrics(&project_id, &epic_id).await;
+
+    if matches!(result, Err(IdeateError::NotFound(_))) {
+        return ok_or_not_found(result, "Epic not found");
+    }
+    ok_or_internal_error(result, "Failed to calculate epic metrics")
+}
+
 /// Request body for generating an Epic from a PRD
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -619,3 +664,137 @@ pub async fn generate_epic_checkpoints(
 
     ok_or_internal_error(result, "Failed to generate checkpoints")
 }
+
+/// Request body for running a checkpoint's validation commands
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunCheckpointRequest {
+    /// Sandbox the commands run in; it must already be running
+    pub sandbox_id: String,
+    /// Shell commands to run in order, e.g. `cargo test`, `cargo clippy`, `cargo build`
+    pub commands: Vec<String>,
+}
+
+/// Runs validation commands inside a sandbox container via `sh -c`
+struct SandboxCommandExecutor {
+    manager: Arc<orkee_sandbox::SandboxManager>,
+    sandbox_id: String,
+}
+
+#[async_trait]
+impl ValidationCommandExecutor for SandboxCommandExecutor {
+    async fn execute(&self, command: &str) -> Result<CommandOutput, String> {
+        let result = self
+            .manager
+            .exec_sandbox_command(
+                &self.sandbox_id,
+                vec!["sh".to_string(), "-c".to_string(), command.to_string()],
+                None,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(CommandOutput {
+            exit_code: result.exit_code,
+            stdout: String::from_utf8_lossy(&result.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&result.stderr).into_owned(),
+        })
+    }
+}
+
+/// Start a sandboxed validation run for a checkpoint.
+///
+/// The run is returned in the `running` state and executes in the background;
+/// poll the runs endpoint for its outcome. A passing run completes the
+/// checkpoint and opens the parallel groups it was holding back.
+pub async fn run_checkpoint_validation(
+    State(db): State<DbState>,
+    Path((project_id, epic_id, checkpoint_id)): Path<(String, String, String)>,
+    Json(request): Json<RunCheckpointRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Running validation for checkpoint: {} of epic: {} in project: {}",
+        checkpoint_id, epic_id, project_id
+    );
+
+    let tracker = ExecutionTracker::new(db.pool.clone());
+    match tracker.get_checkpoint(&checkpoint_id).await {
+        Ok(checkpoint) if checkpoint.epic_id == epic_id => {}
+        Ok(_) | Err(StorageError::Sqlx(sqlx::Error::RowNotFound)) => {
+            return ok_or_not_found(
+                Err::<(), _>(&checkpoint_id),
+                "Checkpoint not found for epic",
+            );
+        }
+        Err(e) => {
+            return ok_or_internal_error(Err::<(), _>(e), "Failed to get checkpoint");
+        }
+    }
+
+    let runner = CheckpointRunner::new(db.pool.clone());
+    let run = match runner
+        .start_run(&checkpoint_id, &request.sandbox_id, request.commands)
+        .await
+    {
+        Ok(run) => run,
+        Err(e @ StorageError::Validation(_)) => {
+            return bad_request(e, "Failed to start checkpoint validation");
+        }
+        Err(e) => {
+            return created_or_internal_error(
+                Err::<(), _>(e),
+                "Failed to start checkpoint validation",
+            );
+        }
+    };
+
+    let executor = SandboxCommandExecutor {
+        manager: db.sandbox_manager.clone(),
+        sandbox_id: request.sandbox_id,
+    };
+    let background_run = run.clone();
+    tokio::spawn(async move {
+        let run_id = background_run.id.clone();
+        match runner.execute_run(background_run, &executor).await {
+            Ok(run) => info!(
+                "Checkpoint validation run {} finished: {:?}",
+                run.id, run.status
+            ),
+            Err(e) => error!(
+                "Failed to record checkpoint validation run {}: {}",
+                run_id, e
+            ),
+        }
+    });
+
+    created_or_internal_error(
+        Ok::<_, StorageError>(run),
+        "Failed to start checkpoint validation",
+    )
+}
+
+/// List validation runs for a checkpoint, newest first
+pub async fn list_checkpoint_runs(
+    State(db): State<DbState>,
+    Path((_project_id, _epic_id, checkpoint_id)): Path<(String, String, String)>,
+) -> impl IntoResponse {
+    info!("Listing validation runs for checkpoint: {}", checkpoint_id);
+
+    let runner = CheckpointRunner::new(db.pool.clone());
+    let result = runner.list_runs(&checkpoint_id).await;
+
+    ok_or_internal_error(result, "Failed to list checkpoint validation runs")
+}
+
+/// Get which parallel groups of an Epic are open and which checkpoints block the rest
+pub async fn get_parallel_group_gates(
+    State(db): State<DbState>,
+    Path((_project_id, epic_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!("Getting parallel group gates for epic: {}", epic_id);
+
+    let runner = CheckpointRunner::new(db.pool.clone());
+    let result = runner.parallel_group_gates(&epic_id).await;
+
+    ok_or_internal_error(result, "Failed to get parallel group gates")
+}
@@ -0,0 +1,164 @@
+// ABOUTME: Conditional request support with entity tags
+// ABOUTME: Derives ETags from id and updated_at, answers If-None-Match with 304 and rejects stale If-Match with 412
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json as ResponseJson, Response},
+};
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use super::response::ApiResponse;
+
+/// Incremental ETag builder over `(id, updated_at)` pairs
+#[derive(Default)]
+pub struct ETagBuilder {
+    hasher: Sha256,
+}
+
+impl ETagBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn entity(mut self, id: &str, updated_at: &DateTime<Utc>) -> Self {
+        self.hasher.update(id.as_bytes());
+        self.hasher.update([0]);
+        self.hasher.update(updated_at.to_rfc3339().as_bytes());
+        self.hasher.update([0]);
+        self
+    }
+
+    /// Mix in anything else the response depends on, such as a list total
+    pub fn extra(mut self, value: impl std::fmt::Display) -> Self {
+        self.hasher.update(value.to_string().as_bytes());
+        self.hasher.update([0]);
+        self
+    }
+
+    /// Quoted strong ETag
+    pub fn finish(self) -> String {
+        let digest = self.hasher.finalize();
+        let hex: String = digest[..16].iter().map(|b| format!("{:02x}", b)).collect();
+        format!("\"{}\"", hex)
+    }
+}
+
+/// ETag of a single resource
+pub fn entity_etag(id: &str, updated_at: &DateTime<Utc>) -> String {
+    ETagBuilder::new().entity(id, updated_at).finish()
+}
+
+/// Whether a conditional header value lists `etag`, ignoring weak prefixes
+fn header_matches(value: &str, etag: &str) -> bool {
+    value.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+fn header_str(headers: &HeaderMap, name: header::HeaderName) -> Option<&str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+/// Answer with 304 Not Modified when the client already has `etag`, otherwise
+/// tag `response` so the client can revalidate next time
+pub fn conditional_get(headers: &HeaderMap, etag: &str, response: Response) -> Response {
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let mut response = match header_str(headers, header::IF_NONE_MATCH) {
+        Some(value) if header_matches(value, etag) => StatusCode::NOT_MODIFIED.into_response(),
+        _ => response,
+    };
+    set_etag(&mut response, etag);
+    response
+}
+
+/// A 412 response when `If-Match` is present and does not name the current
+/// version of the resource, so a stale edit is not applied over a newer one
+pub fn check_if_match(headers: &HeaderMap, current_etag: &str) -> Option<Response> {
+    let value = header_str(headers, header::IF_MATCH)?;
+    if header_matches(value, current_etag) {
+        return None;
+    }
+
+    let mut response = (
+        StatusCode::PRECONDITION_FAILED,
+        ResponseJson(ApiResponse::<()>::error(
+            "Resource was modified by another request; reload it and try again".to_string(),
+        )),
+    )
+        .into_response();
+    set_etag(&mut response, current_etag);
+    Some(response)
+}
+
+/// Attach `etag` to a successful response
+pub fn with_etag(mut response: Response, etag: &str) -> Response {
+    if response.status().is_success() {
+        set_etag(&mut response, etag);
+    }
+    response
+}
+
+fn set_etag(response: &mut Response, etag: &str) {
+    if let Ok(value) = HeaderValue::from_str(etag) {
+        let headers = response.headers_mut();
+        headers.insert(header::ETAG, value);
+        // Cached copies must be revalidated rather than reused blindly
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(name: header::HeaderName, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_etag_changes_with_updated_at() {
+        let now = Utc::now();
+        let later = now + chrono::Duration::seconds(1);
+        assert_eq!(entity_etag("p1", &now), entity_etag("p1", &now));
+        assert_ne!(entity_etag("p1", &now), entity_etag("p1", &later));
+        assert_ne!(entity_etag("p1", &now), entity_etag("p2", &now));
+        assert!(entity_etag("p1", &now).starts_with('"'));
+    }
+
+    #[test]
+    fn test_conditional_get_returns_not_modified() {
+        let etag = entity_etag("p1", &Utc::now());
+        let fresh = conditional_get(
+            &headers(header::IF_NONE_MATCH, &format!("\"other\", W/{}", etag)),
+            &etag,
+            StatusCode::OK.into_response(),
+        );
+        assert_eq!(fresh.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(fresh.headers()[header::ETAG], etag.as_str());
+
+        let stale = conditional_get(
+            &headers(header::IF_NONE_MATCH, "\"other\""),
+            &etag,
+            StatusCode::OK.into_response(),
+        );
+        assert_eq!(stale.status(), StatusCode::OK);
+        assert_eq!(stale.headers()[header::ETAG], etag.as_str());
+    }
+
+    #[test]
+    fn test_if_match_rejects_stale_versions() {
+        let etag = entity_etag("t1", &Utc::now());
+        assert!(check_if_match(&HeaderMap::new(), &etag).is_none());
+        assert!(check_if_match(&headers(header::IF_MATCH, &etag), &etag).is_none());
+        assert!(check_if_match(&headers(header::IF_MATCH, "*"), &etag).is_none());
+
+        let rejected = check_if_match(&headers(header::IF_MATCH, "\"stale\""), &etag).unwrap();
+        assert_eq!(rejected.status(), StatusCode::PRECONDITION_FAILED);
+    }
+}
@@ -3,24 +3,31 @@
 
 use axum::{
     extract::{Path, Query, State},
-    response::IntoResponse,
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
     Json,
 };
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use tracing::info;
 
-use super::response::{created_or_internal_error, ok_or_internal_error};
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found, ApiResponse,
+};
 use orkee_executions::{
     AgentExecutionCreateInput, AgentExecutionUpdateInput, ExecutionStatus, PrReviewCreateInput,
     PrReviewUpdateInput, PrStatus, ReviewStatus, ReviewerType,
 };
 use orkee_projects::pagination::{PaginatedResponse, PaginationParams};
 use orkee_projects::DbState;
+use orkee_storage::StorageError;
 
 // ==================== Agent Executions ====================
 
 /// List all executions for a task
+///
+/// Pages by `page` by default; passing `cursor` (empty for the first page)
+/// switches to cursor pagination, newest first.
 pub async fn list_executions(
     State(db): State<DbState>,
     Path(task_id): Path<String>,
@@ -32,6 +39,19 @@ pub async fn list_executions(
         pagination.page()
     );
 
+    if pagination.is_cursor_mode() {
+        let after = match pagination.keyset_position() {
+            Ok(after) => after,
+            Err(e) => return bad_request(e, "Failed to list executions"),
+        };
+        let result = db
+            .execution_storage
+            .list_executions_after(&task_id, after.as_ref(), pagination.limit())
+            .await
+            .map(|page| PaginatedResponse::from_keyset(page, &pagination));
+        return ok_or_internal_error(result, "Failed to list executions");
+    }
+
     let result = db
         .execution_storage
         .list_executions_paginated(
@@ -56,6 +76,37 @@ pub async fn get_execution(
     ok_or_internal_error(result, "Failed to get execution")
 }
 
+/// Query parameters for comparing two executions
+#[derive(Deserialize)]
+pub struct CompareExecutionsQuery {
+    pub a: String,
+    pub b: String,
+}
+
+/// Compare two executions' produced files, token usage, duration, and review findings
+pub async fn compare_executions(
+    State(db): State<DbState>,
+    Query(query): Query<CompareExecutionsQuery>,
+) -> impl IntoResponse {
+    info!("Comparing executions {} and {}", query.a, query.b);
+
+    if query.a == query.b {
+        return bad_request(
+            "both sides are the same execution",
+            "Failed to compare executions",
+        );
+    }
+
+    let result = db
+        .execution_storage
+        .compare_executions(&query.a, &query.b)
+        .await;
+    if matches!(result, Err(StorageError::Sqlx(sqlx::Error::RowNotFound))) {
+        return ok_or_not_found(result, "Execution not found");
+    }
+    ok_or_internal_error(result, "Failed to compare executions")
+}
+
 /// Request body for creating an execution
 #[derive(Deserialize)]
 pub struct CreateExecutionRequest {
@@ -67,27 +118,111 @@ pub struct CreateExecutionRequest {
     pub prompt: Option<String>,
     #[serde(rename = "retryAttempt")]
     pub retry_attempt: Option<i32>,
+    /// RFC 3339 time before which the execution stays queued
+    #[serde(rename = "notBefore")]
+    pub not_before: Option<String>,
+    /// Executions sharing a class run one at a time
+    #[serde(rename = "concurrencyClass")]
+    pub concurrency_class: Option<String>,
 }
 
 /// Create a new execution
+///
+/// With `notBefore` or `concurrencyClass` the execution is queued and starts
+/// once its time has come and its class is free.
 pub async fn create_execution(
     State(db): State<DbState>,
     Json(request): Json<CreateExecutionRequest>,
 ) -> impl IntoResponse {
     info!("Creating execution for task: {}", request.task_id);
 
+    let not_before = match request
+        .not_before
+        .as_deref()
+        .map(DateTime::parse_from_rfc3339)
+    {
+        Some(Ok(time)) => Some(time.with_timezone(&Utc)),
+        Some(Err(e)) => return bad_request(e, "Invalid notBefore time"),
+        None => None,
+    };
+    if request
+        .concurrency_class
+        .as_deref()
+        .is_some_and(|class| class.trim().is_empty())
+    {
+        return bad_request("must not be blank", "Invalid concurrencyClass");
+    }
+
     let input = AgentExecutionCreateInput {
         task_id: request.task_id,
         agent_id: request.agent_id,
         model: request.model,
         prompt: request.prompt,
         retry_attempt: request.retry_attempt,
+        not_before,
+        concurrency_class: request.concurrency_class,
     };
 
     let result = db.execution_storage.create_execution(input).await;
     created_or_internal_error(result, "Failed to create execution")
 }
 
+/// Query parameters for inspecting the execution queue
+#[derive(Deserialize)]
+pub struct QueueQuery {
+    #[serde(rename = "concurrencyClass")]
+    pub concurrency_class: Option<String>,
+}
+
+/// List executions waiting to start, soonest first
+pub async fn list_queue(
+    State(db): State<DbState>,
+    Query(query): Query<QueueQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Listing queued executions (class: {:?})",
+        query.concurrency_class
+    );
+
+    let result = db
+        .execution_storage
+        .list_queued_executions(query.concurrency_class.as_deref())
+        .await;
+    ok_or_internal_error(result, "Failed to list queued executions")
+}
+
+/// Cancel a queued or running execution
+pub async fn cancel_execution(
+    State(db): State<DbState>,
+    Path(execution_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Cancelling execution: {}", execution_id);
+
+    match db.execution_storage.get_execution(&execution_id).await {
+        Ok(_) => {}
+        Err(StorageError::Sqlx(sqlx::Error::RowNotFound)) => {
+            return ok_or_not_found::<(), _>(Err(execution_id), "Execution not found")
+        }
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to cancel execution"),
+    }
+
+    match db.execution_storage.cancel_execution(&execution_id).await {
+        Ok(true) => {
+            let result = db.execution_storage.get_execution(&execution_id).await;
+            ok_or_internal_error(result, "Failed to cancel execution")
+        }
+        Ok(false) => (
+            StatusCode::CONFLICT,
+            ResponseJson(ApiResponse::<()>::error(format!(
+                "Execution {} has already finished",
+                execution_id
+            ))),
+        )
+            .into_response(),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to cancel execution"),
+    }
+}
+
 /// Request body for updating an execution
 #[derive(Deserialize)]
 pub struct UpdateExecutionRequest {
@@ -1,17 +1,25 @@
 // ABOUTME: API handlers for GitHub synchronization
 // ABOUTME: Endpoints for syncing Epics and Tasks with GitHub Issues
 
+use async_trait::async_trait;
 use axum::{
     extract::{Path, State},
-    response::IntoResponse,
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
     Json,
 };
 use serde::{Deserialize, Serialize};
-use tracing::info;
+use serde_json::json;
+use tracing::{info, warn};
 
-use super::response::ok_or_internal_error;
-use orkee_ideate::{EpicManager, GitHubConfig, GitHubSyncService, SyncResult};
-use orkee_projects::DbState;
+use super::response::{ok_or_internal_error, ApiResponse};
+use orkee_ideate::{
+    Epic, EpicManager, GitHubConfig, GitHubSyncError, GitHubSyncService, SyncResult,
+};
+use orkee_projects::sync_queue::enqueue_operation;
+use orkee_projects::{
+    DbState, EnqueueInput, OperationExecutor, QueuedOperation, ReplayOutcome, SyncOperationType,
+};
 
 /// Request to sync an Epic to GitHub
 #[derive(Debug, Deserialize)]
@@ -91,6 +99,124 @@ async fn get_github_config(
     })
 }
 
+/// Why a sync did not produce results
+enum SyncError {
+    /// Local lookup or configuration failed before contacting GitHub
+    Setup {
+        context: &'static str,
+        message: String,
+    },
+    GitHub(GitHubSyncError),
+}
+
+/// Connection and timeout failures mean GitHub was unreachable, so the sync
+/// can be queued and replayed later instead of failing outright
+fn is_offline_error(error: &GitHubSyncError) -> bool {
+    matches!(error, GitHubSyncError::RequestFailed(e) if e.is_connect() || e.is_timeout())
+}
+
+/// Look up the project that owns an Epic
+async fn epic_project_id(pool: &sqlx::SqlitePool, epic_id: &str) -> Result<String, SyncError> {
+    use sqlx::Row;
+    match sqlx::query("SELECT project_id FROM epics WHERE id = ?")
+        .bind(epic_id)
+        .fetch_optional(pool)
+        .await
+    {
+        Ok(Some(row)) => Ok(row.get("project_id")),
+        Ok(None) => Err(SyncError::Setup {
+            context: "Epic not found",
+            message: "Epic not found".to_string(),
+        }),
+        Err(e) => Err(SyncError::Setup {
+            context: "Failed to fetch Epic",
+            message: format!("Database error: {}", e),
+        }),
+    }
+}
+
+async fn load_config(pool: &sqlx::SqlitePool, project_id: &str) -> Result<GitHubConfig, SyncError> {
+    get_github_config(pool, project_id)
+        .await
+        .map_err(|message| SyncError::Setup {
+            context: "Failed to get GitHub configuration",
+            message,
+        })
+}
+
+async fn load_epic(
+    pool: &sqlx::SqlitePool,
+    project_id: &str,
+    epic_id: &str,
+) -> Result<Epic, SyncError> {
+    match EpicManager::new(pool.clone())
+        .get_epic(project_id, epic_id)
+        .await
+    {
+        Ok(Some(epic)) => Ok(epic),
+        Ok(None) => Err(SyncError::Setup {
+            context: "Epic not found",
+            message: "Epic not found".to_string(),
+        }),
+        Err(e) => Err(SyncError::Setup {
+            context: "Failed to fetch Epic",
+            message: e.to_string(),
+        }),
+    }
+}
+
+async fn run_epic_sync(
+    pool: &sqlx::SqlitePool,
+    project_id: &str,
+    epic: &Epic,
+    create_new: bool,
+) -> Result<SyncResult, SyncError> {
+    let config = load_config(pool, project_id).await?;
+    let service = GitHubSyncService::new();
+
+    let result = if create_new || epic.github_issue_number.is_none() {
+        service.create_epic_issue(epic, &config, pool).await
+    } else {
+        service.sync_epic_to_github(epic, &config, pool).await
+    };
+    result.map_err(SyncError::GitHub)
+}
+
+async fn run_tasks_sync(
+    pool: &sqlx::SqlitePool,
+    project_id: &str,
+    epic_id: &str,
+) -> Result<Vec<SyncResult>, SyncError> {
+    let config = load_config(pool, project_id).await?;
+    GitHubSyncService::new()
+        .create_task_issues(epic_id, project_id, &config, pool)
+        .await
+        .map_err(SyncError::GitHub)
+}
+
+/// Queue a sync that failed for lack of connectivity and answer 202 Accepted
+async fn queue_offline_sync(
+    db: &DbState,
+    input: EnqueueInput,
+    error: &GitHubSyncError,
+) -> axum::response::Response {
+    warn!(
+        "GitHub unreachable, queueing {:?} for {}: {}",
+        input.operation_type, input.target_id, error
+    );
+    match enqueue_operation(&db.pool, input).await {
+        Ok(operation) => (
+            StatusCode::ACCEPTED,
+            ResponseJson(ApiResponse::success(operation)),
+        )
+            .into_response(),
+        Err(e) => ok_or_internal_error::<QueuedOperation, String>(
+            Err(e.to_string()),
+            "GitHub is unreachable and the sync could not be queued",
+        ),
+    }
+}
+
 /// POST /api/github/sync/epic/:epic_id
 /// Sync an Epic to GitHub (create or update issue)
 pub async fn sync_epic(
@@ -100,71 +226,28 @@ pub async fn sync_epic(
 ) -> impl IntoResponse {
     info!("Syncing Epic {} to GitHub", epic_id);
 
-    // Use EpicManager to get the Epic
-    let epic_manager = EpicManager::new(db.pool.clone());
-
-    // First, get just the project_id to avoid fetching full Epic if config fails
-    use sqlx::Row;
-    let project_id_result = sqlx::query("SELECT project_id FROM epics WHERE id = ?")
-        .bind(&epic_id)
-        .fetch_optional(&db.pool)
-        .await;
-
-    let project_id: String = match project_id_result {
-        Ok(Some(row)) => row.get("project_id"),
-        Ok(None) => {
-            return ok_or_internal_error::<SyncResult, String>(
-                Err("Epic not found".to_string()),
-                "Epic not found",
-            )
-        }
-        Err(e) => {
-            return ok_or_internal_error::<SyncResult, String>(
-                Err(format!("Database error: {}", e)),
-                "Failed to fetch Epic",
-            )
-        }
-    };
-
-    // Get GitHub config first
-    let config = match get_github_config(&db.pool, &project_id).await {
-        Ok(cfg) => cfg,
-        Err(e) => {
-            return ok_or_internal_error::<SyncResult, String>(
-                Err(e),
-                "Failed to get GitHub configuration",
-            )
-        }
-    };
-
-    // Now get the full Epic using EpicManager
-    let epic = match epic_manager.get_epic(&project_id, &epic_id).await {
-        Ok(Some(e)) => e,
-        Ok(None) => {
-            return ok_or_internal_error::<SyncResult, String>(
-                Err("Epic not found".to_string()),
-                "Epic not found",
-            )
+    let prepared = async {
+        // Get just the project_id first to avoid fetching the full Epic if config fails
+        let project_id = epic_project_id(&db.pool, &epic_id).await?;
+        load_config(&db.pool, &project_id).await?;
+        let epic = load_epic(&db.pool, &project_id, &epic_id).await?;
+        Ok::<_, SyncError>((project_id, epic))
+    }
+    .await;
+    let (project_id, epic) = match prepared {
+        Ok(prepared) => prepared,
+        Err(SyncError::Setup { context, message }) => {
+            return ok_or_internal_error::<SyncResult, String>(Err(message), context)
         }
-        Err(e) => {
+        Err(SyncError::GitHub(e)) => {
             return ok_or_internal_error::<SyncResult, String>(
                 Err(e.to_string()),
-                "Failed to fetch Epic",
+                "Failed to sync Epic to GitHub",
             )
         }
     };
 
-    // Create GitHub service
-    let service = GitHubSyncService::new();
-
-    // Sync to GitHub
-    let result = if request.create_new || epic.github_issue_number.is_none() {
-        service.create_epic_issue(&epic, &config, &db.pool).await
-    } else {
-        service.sync_epic_to_github(&epic, &config, &db.pool).await
-    };
-
-    match result {
+    match run_epic_sync(&db.pool, &project_id, &epic, request.create_new).await {
         Ok(sync_result) => {
             info!(
                 "Successfully synced Epic {} to GitHub issue #{}",
@@ -172,10 +255,25 @@ pub async fn sync_epic(
             );
             ok_or_internal_error::<SyncResult, String>(Ok(sync_result), "")
         }
-        Err(e) => ok_or_internal_error::<SyncResult, String>(
+        Err(SyncError::GitHub(e)) if is_offline_error(&e) => {
+            let input = EnqueueInput {
+                operation_type: SyncOperationType::GithubSyncEpic,
+                target_id: epic_id,
+                project_id: Some(project_id),
+                payload: json!({
+                    "createNew": request.create_new,
+                    "issueNumber": epic.github_issue_number,
+                }),
+            };
+            queue_offline_sync(&db, input, &e).await
+        }
+        Err(SyncError::GitHub(e)) => ok_or_internal_error::<SyncResult, String>(
             Err(e.to_string()),
             "Failed to sync Epic to GitHub",
         ),
+        Err(SyncError::Setup { context, message }) => {
+            ok_or_internal_error::<SyncResult, String>(Err(message), context)
+        }
     }
 }
 
@@ -187,57 +285,121 @@ pub async fn sync_tasks(
 ) -> impl IntoResponse {
     info!("Syncing tasks for Epic {} to GitHub", epic_id);
 
-    // Get project ID for Epic
-    use sqlx::Row;
-    let epic_result = sqlx::query("SELECT project_id FROM epics WHERE id = ?")
-        .bind(&epic_id)
-        .fetch_optional(&db.pool)
-        .await;
-
-    let project_id: String = match epic_result {
-        Ok(Some(row)) => row.get("project_id"),
-        Ok(None) => {
-            return ok_or_internal_error::<SyncResponse, String>(
-                Err("Epic not found".to_string()),
-                "Epic not found",
-            )
+    let project_id = match epic_project_id(&db.pool, &epic_id).await {
+        Ok(id) => id,
+        Err(SyncError::Setup { context, message }) => {
+            return ok_or_internal_error::<SyncResponse, String>(Err(message), context)
         }
-        Err(e) => {
+        Err(SyncError::GitHub(e)) => {
             return ok_or_internal_error::<SyncResponse, String>(
-                Err(format!("Database error: {}", e)),
+                Err(e.to_string()),
                 "Failed to fetch Epic",
             )
         }
     };
 
-    // Get GitHub config
-    let config = match get_github_config(&db.pool, &project_id).await {
-        Ok(cfg) => cfg,
-        Err(e) => {
-            return ok_or_internal_error::<SyncResponse, String>(
-                Err(e),
-                "Failed to get GitHub configuration",
-            )
-        }
-    };
-
-    // Create GitHub service
-    let service = GitHubSyncService::new();
-
-    // Sync tasks
-    let result = service
-        .create_task_issues(&epic_id, &project_id, &config, &db.pool)
-        .await;
-
-    match result {
+    match run_tasks_sync(&db.pool, &project_id, &epic_id).await {
         Ok(results) => {
             info!("Successfully synced {} tasks to GitHub", results.len());
             ok_or_internal_error::<SyncResponse, String>(Ok(SyncResponse { results }), "")
         }
-        Err(e) => ok_or_internal_error::<SyncResponse, String>(
+        Err(SyncError::GitHub(e)) if is_offline_error(&e) => {
+            let input = EnqueueInput {
+                operation_type: SyncOperationType::GithubSyncTasks,
+                target_id: epic_id,
+                project_id: Some(project_id),
+                payload: json!({}),
+            };
+            queue_offline_sync(&db, input, &e).await
+        }
+        Err(SyncError::GitHub(e)) => ok_or_internal_error::<SyncResponse, String>(
             Err(e.to_string()),
             "Failed to sync tasks to GitHub",
         ),
+        Err(SyncError::Setup { context, message }) => {
+            ok_or_internal_error::<SyncResponse, String>(Err(message), context)
+        }
+    }
+}
+
+/// Replays queued GitHub syncs once connectivity returns
+pub struct GitHubSyncExecutor {
+    pool: sqlx::SqlitePool,
+}
+
+impl GitHubSyncExecutor {
+    pub fn new(pool: sqlx::SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+impl From<SyncError> for ReplayOutcome {
+    fn from(error: SyncError) -> Self {
+        match error {
+            SyncError::GitHub(e) if is_offline_error(&e) => ReplayOutcome::Offline(e.to_string()),
+            SyncError::GitHub(e) => ReplayOutcome::Failed(e.to_string()),
+            SyncError::Setup { context, message } => {
+                ReplayOutcome::Failed(format!("{}: {}", context, message))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl OperationExecutor for GitHubSyncExecutor {
+    fn handles(&self, operation_type: SyncOperationType) -> bool {
+        matches!(
+            operation_type,
+            SyncOperationType::GithubSyncEpic | SyncOperationType::GithubSyncTasks
+        )
+    }
+
+    async fn execute(&self, operation: &QueuedOperation) -> ReplayOutcome {
+        let epic_id = &operation.target_id;
+        let project_id = match epic_project_id(&self.pool, epic_id).await {
+            Ok(id) => id,
+            Err(e) => return e.into(),
+        };
+
+        match operation.operation_type {
+            SyncOperationType::GithubSyncEpic => {
+                let epic = match load_epic(&self.pool, &project_id, epic_id).await {
+                    Ok(epic) => epic,
+                    Err(e) => return e.into(),
+                };
+
+                // The Epic was linked to a different issue while we were offline;
+                // replaying could create a duplicate or overwrite newer remote edits
+                let queued_issue = operation
+                    .payload
+                    .get("issueNumber")
+                    .and_then(|n| n.as_i64())
+                    .map(|n| n as i32);
+                if epic.github_issue_number != queued_issue {
+                    return ReplayOutcome::Conflict(format!(
+                        "Epic is now linked to GitHub issue {:?} (was {:?} when queued)",
+                        epic.github_issue_number, queued_issue
+                    ));
+                }
+
+                let create_new = operation
+                    .payload
+                    .get("createNew")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(false);
+                match run_epic_sync(&self.pool, &project_id, &epic, create_new).await {
+                    Ok(_) => ReplayOutcome::Completed,
+                    Err(e) => e.into(),
+                }
+            }
+            SyncOperationType::GithubSyncTasks => {
+                match run_tasks_sync(&self.pool, &project_id, epic_id).await {
+                    Ok(_) => ReplayOutcome::Completed,
+                    Err(e) => e.into(),
+                }
+            }
+            other => ReplayOutcome::Failed(format!("Unsupported operation type: {:?}", other)),
+        }
     }
 }
 
@@ -2,28 +2,66 @@
 // ABOUTME: Generates dependency, symbol, and module graphs for projects.
 
 use axum::{
-    extract::{Path, State},
-    response::Json,
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Json, Response},
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use tokio::time::timeout;
 use tracing::info;
 
-use orkee_context::{graph_builder::GraphBuilder, graph_types::CodeGraph};
+use orkee_context::{
+    graph_builder::GraphBuilder,
+    graph_export::{export_graph, filter_graph, ExportFormat, GraphFilter},
+    graph_types::CodeGraph,
+};
 use orkee_projects::{get_project as manager_get_project, DbState};
 
 // Timeout configuration
 const DEFAULT_GRAPH_GENERATION_TIMEOUT_SECS: u64 = 30;
 
 /// Get graph generation timeout from environment or use default
-fn get_graph_timeout() -> u64 {
+pub(crate) fn get_graph_timeout() -> u64 {
     std::env::var("ORKEE_GRAPH_TIMEOUT_SECS")
         .ok()
         .and_then(|v| v.parse().ok())
         .unwrap_or(DEFAULT_GRAPH_GENERATION_TIMEOUT_SECS)
 }
 
+/// Query parameters shared by the graph endpoints
+#[derive(Debug, Default, Deserialize)]
+pub struct GraphQuery {
+    /// Export as dot, mermaid, or graphml text instead of JSON
+    pub format: Option<ExportFormat>,
+    /// Node id, path, or label to root a subgraph at
+    pub root: Option<String>,
+    /// Maximum number of nodes to return
+    pub max_nodes: Option<usize>,
+}
+
+/// Apply the subgraph/limit options and render the graph as JSON or in the
+/// requested export format
+fn graph_response(graph: CodeGraph, query: &GraphQuery) -> Response {
+    let filter = GraphFilter {
+        root: query.root.clone(),
+        max_nodes: query.max_nodes,
+    };
+    let graph = match filter_graph(graph, &filter) {
+        Ok(graph) => graph,
+        Err(e) => return Json(GraphResponse::error(e)).into_response(),
+    };
+
+    match query.format {
+        Some(format) => (
+            [(header::CONTENT_TYPE, format.content_type())],
+            export_graph(&graph, format),
+        )
+            .into_response(),
+        None => Json(GraphResponse::success(graph)).into_response(),
+    }
+}
+
 /// Response format for graph API
 #[derive(Debug, Serialize)]
 pub struct GraphResponse {
@@ -56,7 +94,8 @@ impl GraphResponse {
 pub async fn get_dependency_graph(
     Path(project_id): Path<String>,
     State(_db): State<DbState>,
-) -> Json<GraphResponse> {
+    Query(query): Query<GraphQuery>,
+) -> Response {
     info!("Generating dependency graph for project: {}", project_id);
 
     // Fetch project from database
@@ -67,12 +106,14 @@ pub async fn get_dependency_graph(
                 "Project not found: {}",
                 project_id
             )))
+            .into_response()
         }
         Err(e) => {
             return Json(GraphResponse::error(format!(
                 "Failed to fetch project: {}",
                 e
             )))
+            .into_response()
         }
     };
 
@@ -96,20 +137,23 @@ pub async fn get_dependency_graph(
                 "Generated dependency graph with {} nodes and {} edges",
                 graph.metadata.total_nodes, graph.metadata.total_edges
             );
-            Json(GraphResponse::success(graph))
+            graph_response(graph, &query)
         }
         Ok(Ok(Err(e))) => Json(GraphResponse::error(format!(
             "Failed to generate dependency graph: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Ok(Err(e)) => Json(GraphResponse::error(format!(
             "Graph generation task failed: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Err(_) => Json(GraphResponse::error(format!(
             "Graph generation timed out after {} seconds. Large projects may need more time. Try: (1) excluding node_modules with .gitignore, (2) using path filters, or (3) increasing timeout with ORKEE_GRAPH_TIMEOUT_SECS environment variable.",
             timeout_secs
-        ))),
+        )))
+        .into_response(),
     }
 }
 
@@ -117,7 +161,8 @@ pub async fn get_dependency_graph(
 pub async fn get_symbol_graph(
     Path(project_id): Path<String>,
     State(_db): State<DbState>,
-) -> Json<GraphResponse> {
+    Query(query): Query<GraphQuery>,
+) -> Response {
     info!("Generating symbol graph for project: {}", project_id);
 
     // Fetch project from database
@@ -128,12 +173,14 @@ pub async fn get_symbol_graph(
                 "Project not found: {}",
                 project_id
             )))
+            .into_response()
         }
         Err(e) => {
             return Json(GraphResponse::error(format!(
                 "Failed to fetch project: {}",
                 e
             )))
+            .into_response()
         }
     };
 
@@ -157,20 +204,23 @@ pub async fn get_symbol_graph(
                 "Generated symbol graph with {} nodes and {} edges",
                 graph.metadata.total_nodes, graph.metadata.total_edges
             );
-            Json(GraphResponse::success(graph))
+            graph_response(graph, &query)
         }
         Ok(Ok(Err(e))) => Json(GraphResponse::error(format!(
             "Failed to generate symbol graph: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Ok(Err(e)) => Json(GraphResponse::error(format!(
             "Graph generation task failed: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Err(_) => Json(GraphResponse::error(format!(
             "Graph generation timed out after {} seconds. Large projects may need more time. Try: (1) excluding node_modules with .gitignore, (2) using path filters, or (3) increasing timeout with ORKEE_GRAPH_TIMEOUT_SECS environment variable.",
             timeout_secs
-        ))),
+        )))
+        .into_response(),
     }
 }
 
@@ -178,7 +228,8 @@ pub async fn get_symbol_graph(
 pub async fn get_module_graph(
     Path(project_id): Path<String>,
     State(_db): State<DbState>,
-) -> Json<GraphResponse> {
+    Query(query): Query<GraphQuery>,
+) -> Response {
     info!("Generating module graph for project: {}", project_id);
 
     // Fetch project from database
@@ -189,12 +240,14 @@ pub async fn get_module_graph(
                 "Project not found: {}",
                 project_id
             )))
+            .into_response()
         }
         Err(e) => {
             return Json(GraphResponse::error(format!(
                 "Failed to fetch project: {}",
                 e
             )))
+            .into_response()
         }
     };
 
@@ -218,20 +271,23 @@ pub async fn get_module_graph(
                 "Generated module graph with {} nodes and {} edges",
                 graph.metadata.total_nodes, graph.metadata.total_edges
             );
-            Json(GraphResponse::success(graph))
+            graph_response(graph, &query)
         }
         Ok(Ok(Err(e))) => Json(GraphResponse::error(format!(
             "Failed to generate module graph: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Ok(Err(e)) => Json(GraphResponse::error(format!(
             "Graph generation task failed: {}",
             e
-        ))),
+        )))
+        .into_response(),
         Err(_) => Json(GraphResponse::error(format!(
             "Graph generation timed out after {} seconds. Large projects may need more time. Try: (1) excluding node_modules with .gitignore, (2) using path filters, or (3) increasing timeout with ORKEE_GRAPH_TIMEOUT_SECS environment variable.",
             timeout_secs
-        ))),
+        )))
+        .into_response(),
     }
 }
 
@@ -269,4 +325,32 @@ mod tests {
         assert!(response.data.is_none());
         assert_eq!(response.error, Some("Test error".to_string()));
     }
+
+    #[test]
+    fn test_graph_response_exports_requested_format() {
+        use chrono::Utc;
+        use orkee_context::graph_types::GraphMetadata;
+
+        let graph = CodeGraph {
+            nodes: vec![],
+            edges: vec![],
+            metadata: GraphMetadata {
+                total_nodes: 0,
+                total_edges: 0,
+                graph_type: "modules".to_string(),
+                generated_at: Utc::now(),
+                project_id: "test-project".to_string(),
+            },
+        };
+        let query = GraphQuery {
+            format: Some(ExportFormat::Dot),
+            ..Default::default()
+        };
+
+        let response = graph_response(graph, &query);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            ExportFormat::Dot.content_type()
+        );
+    }
 }
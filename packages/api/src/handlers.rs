@@ -1,7 +1,7 @@
 use axum::{
     body::Body,
     extract::{Json, Path},
-    http::{header, StatusCode},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Json as ResponseJson, Response},
 };
 use orkee_core::types::{ProjectCreateInput, ProjectUpdateInput};
@@ -12,10 +12,14 @@ use orkee_projects::{
     get_project_by_path as manager_get_project_by_path, import_database as manager_import_database,
     update_project as manager_update_project, ManagerError,
 };
+use orkee_storage::StorageError;
 use serde::{Deserialize, Serialize};
 use std::process::Command;
 use tracing::{error, info, warn};
 
+use super::etag::{check_if_match, conditional_get, entity_etag, with_etag, ETagBuilder};
+use super::response::validation_failed;
+
 /// Standard API response wrapper
 #[derive(Serialize)]
 struct ApiResponse<T> {
@@ -42,6 +46,15 @@ impl<T> ApiResponse<T> {
     }
 }
 
+/// Request body for updating a project
+#[derive(Deserialize)]
+pub struct UpdateProjectRequest {
+    /// Version of the project the client last read
+    version: Option<i64>,
+    #[serde(flatten)]
+    updates: ProjectUpdateInput,
+}
+
 /// Request body for getting project by path
 #[derive(Deserialize)]
 pub struct GetProjectByPathRequest {
@@ -91,7 +104,10 @@ fn manager_error_to_response(error: ManagerError) -> axum::response::Response {
         ManagerError::DuplicateName(_) | ManagerError::DuplicatePath(_) => {
             (StatusCode::CONFLICT, error.to_string())
         }
-        ManagerError::Validation(_) => (StatusCode::BAD_REQUEST, error.to_string()),
+        ManagerError::Validation(report) => return validation_failed(report),
+        ManagerError::Storage(e @ StorageError::VersionConflict { .. }) => {
+            (StatusCode::CONFLICT, e.to_string())
+        }
         ManagerError::Storage(e) => {
             error!("Storage error details: {:?}", e);
             (
@@ -105,14 +121,23 @@ fn manager_error_to_response(error: ManagerError) -> axum::response::Response {
     (status, ResponseJson(response)).into_response()
 }
 
-/// List all projects
-pub async fn list_projects() -> impl IntoResponse {
+/// List all projects, answering `If-None-Match` with 304 when none changed
+pub async fn list_projects(headers: HeaderMap) -> impl IntoResponse {
     info!("Listing all projects");
 
     match get_all_projects().await {
         Ok(projects) => {
             info!("Retrieved {} projects", projects.len());
-            (StatusCode::OK, ResponseJson(ApiResponse::success(projects))).into_response()
+            let etag = projects
+                .iter()
+                .fold(ETagBuilder::new(), |etag, project| {
+                    etag.entity(&project.id, &project.updated_at)
+                })
+                .extra(projects.len())
+                .finish();
+            let response =
+                (StatusCode::OK, ResponseJson(ApiResponse::success(projects))).into_response();
+            conditional_get(&headers, &etag, response)
         }
         Err(e) => {
             error!("Failed to list projects: {}", e);
@@ -121,14 +146,17 @@ pub async fn list_projects() -> impl IntoResponse {
     }
 }
 
-/// Get a specific project by ID
-pub async fn get_project(Path(id): Path<String>) -> impl IntoResponse {
+/// Get a specific project by ID, answering `If-None-Match` with 304 when unchanged
+pub async fn get_project(Path(id): Path<String>, headers: HeaderMap) -> impl IntoResponse {
     info!("Getting project with ID: {}", id);
 
     match manager_get_project(&id).await {
         Ok(Some(project)) => {
             info!("Found project: {}", project.name);
-            (StatusCode::OK, ResponseJson(ApiResponse::success(project))).into_response()
+            let etag = entity_etag(&project.id, &project.updated_at);
+            let response =
+                (StatusCode::OK, ResponseJson(ApiResponse::success(project))).into_response();
+            conditional_get(&headers, &etag, response)
         }
         Ok(None) => {
             info!("Project not found: {}", id);
@@ -219,16 +247,76 @@ pub async fn create_project(Json(input): Json<ProjectCreateInput>) -> impl IntoR
 }
 
 /// Update an existing project
+///
+/// The request must carry the project `version` the client last read; if the
+/// project has changed since, the update is rejected with 409 and the current
+/// project. With `If-Match`, it is likewise rejected with 412 on a stale ETag.
 pub async fn update_project(
     Path(id): Path<String>,
-    Json(updates): Json<ProjectUpdateInput>,
+    headers: HeaderMap,
+    Json(request): Json<UpdateProjectRequest>,
 ) -> impl IntoResponse {
     info!("Updating project: {}", id);
 
+    let Some(expected_version) = request.version else {
+        return (
+            StatusCode::BAD_REQUEST,
+            ResponseJson(ApiResponse::<()>::error(
+                "version is required; send the version of the project being edited".to_string(),
+            )),
+        )
+            .into_response();
+    };
+    let updates = ProjectUpdateInput {
+        expected_version: Some(expected_version),
+        ..request.updates
+    };
+
+    if headers.contains_key(header::IF_MATCH) {
+        match manager_get_project(&id).await {
+            Ok(Some(current)) => {
+                let etag = entity_etag(&current.id, &current.updated_at);
+                if let Some(rejected) = check_if_match(&headers, &etag) {
+                    info!("Rejected stale update to project {}", id);
+                    return rejected;
+                }
+            }
+            Ok(None) => {
+                return (
+                    StatusCode::NOT_FOUND,
+                    ResponseJson(ApiResponse::<()>::error("Project not found".to_string())),
+                )
+                    .into_response()
+            }
+            Err(e) => {
+                error!("Failed to get project {}: {}", id, e);
+                return manager_error_to_response(e);
+            }
+        }
+    }
+
     match manager_update_project(&id, updates).await {
         Ok(project) => {
             info!("Updated project: {} (ID: {})", project.name, project.id);
-            (StatusCode::OK, ResponseJson(ApiResponse::success(project))).into_response()
+            let etag = entity_etag(&project.id, &project.updated_at);
+            let response =
+                (StatusCode::OK, ResponseJson(ApiResponse::success(project))).into_response();
+            with_etag(response, &etag)
+        }
+        Err(ManagerError::Storage(conflict @ StorageError::VersionConflict { .. })) => {
+            info!("Rejected stale update to project {}: {}", id, conflict);
+            match manager_get_project(&id).await {
+                Ok(Some(current)) => (
+                    StatusCode::CONFLICT,
+                    ResponseJson(ApiResponse {
+                        success: false,
+                        data: Some(current),
+                        error: Some(conflict.to_string()),
+                    }),
+                )
+                    .into_response(),
+                _ => manager_error_to_response(ManagerError::Storage(conflict)),
+            }
         }
         Err(e) => {
             error!("Failed to update project {}: {}", id, e);
@@ -9,11 +9,11 @@ use axum::{
 use serde::Deserialize;
 use tracing::{error, info, warn};
 
-use super::response::ok_or_internal_error;
+use super::response::{bad_request, ok_or_internal_error};
 use orkee_ideate::{
-    ChatManager, CreateInsightInput, DiscoveryQuestion, DiscoveryStatus, GeneratePRDFromChatInput,
-    GeneratePRDFromChatResult, MessageRole, QualityMetrics, QuestionCategory, SendMessageInput,
-    TopicCoverage, ValidationResult,
+    ChatManager, CreateInsightInput, CreateSummaryInput, DiscoveryQuestion, DiscoveryStatus,
+    GeneratePRDFromChatInput, GeneratePRDFromChatResult, MessageRole, QualityMetrics,
+    QuestionCategory, SendMessageInput, SummarySource, TopicCoverage, ValidationResult,
 };
 use orkee_projects::DbState;
 
@@ -62,9 +62,89 @@ pub async fn send_message(
     // Note: Insight extraction is now handled by the frontend after AI streaming completes
     // This ensures the user's selected model is used for extraction (Phase 6)
 
+    // Compress older turns once the conversation nears the model's context window.
+    // A failed checkpoint shouldn't fail the message that was already stored.
+    if message_result.is_ok() {
+        if let Err(e) = manager
+            .checkpoint_if_needed(&session_id, input.model.as_deref())
+            .await
+        {
+            warn!(
+                "Failed to create chat summary checkpoint for session {}: {}",
+                session_id, e
+            );
+        }
+    }
+
     ok_or_internal_error(message_result, "Failed to send message")
 }
 
+/// Query parameters for the chat context
+#[derive(Debug, Deserialize)]
+pub struct ContextQuery {
+    model: Option<String>,
+}
+
+/// Get the context to send to a model: latest summary, recent messages, and token budget
+pub async fn get_context(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+    Query(query): Query<ContextQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Getting chat context for session: {} (model: {:?})",
+        session_id, query.model
+    );
+
+    let manager = ChatManager::new(db.pool.clone());
+    let result = manager
+        .get_context(&session_id, query.model.as_deref())
+        .await;
+
+    ok_or_internal_error(result, "Failed to get chat context")
+}
+
+/// Get all summarization checkpoints for a session
+pub async fn get_summaries(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting chat summaries for session: {}", session_id);
+
+    let manager = ChatManager::new(db.pool.clone());
+    let result = manager.get_summaries(&session_id).await;
+
+    ok_or_internal_error(result, "Failed to get chat summaries")
+}
+
+/// Store an AI-written summary checkpoint
+pub async fn create_summary(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+    Json(input): Json<CreateSummaryInput>,
+) -> impl IntoResponse {
+    info!(
+        "Creating chat summary for session: {} (through message {})",
+        session_id, input.through_message_order
+    );
+
+    let manager = ChatManager::new(db.pool.clone());
+    match manager
+        .add_summary(
+            &session_id,
+            input.through_message_order,
+            input.summary,
+            SummarySource::Ai,
+        )
+        .await
+    {
+        Err(e @ orkee_ideate::IdeateError::InvalidInput(_)) => {
+            bad_request(e, "Invalid chat summary")
+        }
+        result => ok_or_internal_error(result, "Failed to create chat summary"),
+    }
+}
+
 /// Query parameters for suggested questions
 #[derive(Debug, Deserialize)]
 pub struct QuestionsQuery {
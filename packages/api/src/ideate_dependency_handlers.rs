@@ -7,8 +7,8 @@ use axum::{
     Json,
 };
 use orkee_ideate::{
-    BuildOptimizer, CreateDependencyInput, DependencyAnalyzer, DependencyStrength, DependencyType,
-    OptimizationStrategy,
+    BuildOptimizer, BuildSimulationInput, CreateDependencyInput, DependencyAnalyzer,
+    DependencyStrength, DependencyType, OptimizationStrategy,
 };
 use orkee_projects::DbState;
 use serde::Deserialize;
@@ -37,6 +37,14 @@ pub struct OptimizeBuildOrderRequest {
     pub strategy: OptimizationStrategy,
 }
 
+/// Request body for a what-if build order simulation
+#[derive(Deserialize)]
+pub struct SimulateBuildOrderRequest {
+    pub strategy: OptimizationStrategy,
+    #[serde(flatten)]
+    pub adjustments: BuildSimulationInput,
+}
+
 /// Get all dependencies for a session
 pub async fn get_dependencies(
     State(db): State<DbState>,
@@ -104,6 +112,24 @@ pub async fn optimize_build_order(
     ok_or_internal_error(result, "Failed to optimize build order")
 }
 
+/// Simulate build order with features toggled or estimates changed (not persisted)
+pub async fn simulate_build_order(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+    Json(request): Json<SimulateBuildOrderRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Simulating build order for session: {} (strategy: {:?})",
+        session_id, request.strategy
+    );
+
+    let optimizer = BuildOptimizer::new(db.pool.clone());
+    let result = optimizer
+        .simulate(&session_id, request.strategy, &request.adjustments)
+        .await;
+    ok_or_internal_error(result, "Failed to simulate build order")
+}
+
 /// Get build order
 pub async fn get_build_order(
     State(db): State<DbState>,
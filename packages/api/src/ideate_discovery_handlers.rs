@@ -7,7 +7,7 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use orkee_ideate::{CodebaseAnalyzer, CodebaseContext, DiscoveryManager};
+use orkee_ideate::{CodebaseAnalyzer, CodebaseCache, CodebaseContext, DiscoveryManager};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tracing::{error, info};
@@ -19,6 +19,9 @@ use orkee_projects::DbState;
 pub struct AnalyzeCodebaseRequest {
     #[serde(rename = "projectPath")]
     pub project_path: Option<String>,
+    /// Ignore the cached analysis and rescan the whole project
+    #[serde(rename = "forceRefresh", default)]
+    pub force_refresh: bool,
 }
 
 /// Request body for answering discovery question
@@ -47,8 +50,8 @@ pub async fn analyze_codebase(
     Json(request): Json<AnalyzeCodebaseRequest>,
 ) -> impl IntoResponse {
     info!(
-        "Analyzing codebase for session: {} with path: {:?}",
-        session_id, request.project_path
+        "Analyzing codebase for session: {} with path: {:?} (force refresh: {})",
+        session_id, request.project_path, request.force_refresh
     );
 
     // Get the session to retrieve project ID
@@ -114,19 +117,20 @@ pub async fn analyze_codebase(
 
     // Analyze the codebase
     let analyzer = CodebaseAnalyzer::new(project_path);
-    match analyzer.analyze_for_session(&session).await {
-        Ok(context) => {
-            // Store the codebase context in the session
-            // For now, we'll just return it. In the future, we might want to persist it.
-            (
-                StatusCode::OK,
-                Json(serde_json::json!({
-                    "success": true,
-                    "data": context
-                })),
-            )
-                .into_response()
-        }
+    let cache = CodebaseCache::new(db.pool.clone());
+    match analyzer
+        .analyze_with_cache(&session, &cache, request.force_refresh)
+        .await
+    {
+        Ok(analysis) => (
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "success": true,
+                "data": analysis.context,
+                "cache": analysis.cache
+            })),
+        )
+            .into_response(),
         Err(e) => {
             error!(
                 "Failed to analyze codebase for session '{}': {:?}",
@@ -4,15 +4,23 @@
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use serde::Deserialize;
-use tracing::info;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tracing::{info, warn};
 
-use super::response::{created_or_internal_error, ok_or_internal_error, ok_or_not_found};
+use super::auth::CurrentUser;
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
 use orkee_ideate::{
-    CreateIdeateSessionInput, CreateTemplateInput, IdeateManager, IdeateMode, IdeateStatus,
-    SkipSectionRequest, TemplateManager, UpdateIdeateSessionInput,
+    CitationInput, CitationTracker, CreateIdeateSessionInput, CreateTemplateInput,
+    DocumentImporter, DocumentSource, IdeateError, IdeateManager, IdeateMode, IdeateStatus,
+    QuestionBankManager, SkipSectionRequest, StartFromDocumentInput, TemplateManager,
+    UpdateIdeateSessionInput,
 };
 use orkee_projects::{self as projects, DbState};
 
@@ -31,11 +39,15 @@ pub struct StartIdeateRequest {
     pub template_id: Option<String>,
     #[serde(rename = "researchToolsEnabled")]
     pub research_tools_enabled: Option<bool>,
+    /// Custom discovery question bank to ask from
+    #[serde(rename = "questionBankId")]
+    pub question_bank_id: Option<String>,
 }
 
 /// Start a new ideateing session
 pub async fn start_ideate(
     State(db): State<DbState>,
+    current_user: CurrentUser,
     Json(request): Json<StartIdeateRequest>,
 ) -> impl IntoResponse {
     info!(
@@ -43,6 +55,16 @@ pub async fn start_ideate(
         request.project_id, request.mode, request.template_id
     );
 
+    if let Some(bank_id) = &request.question_bank_id {
+        let banks = QuestionBankManager::new(db.pool.clone());
+        if let Err(e) = banks.get(&current_user.id, bank_id).await {
+            return match e {
+                IdeateError::NotFound(_) => bad_request(e, "Failed to start ideate session"),
+                _ => ok_or_internal_error(Err::<(), _>(e), "Failed to start ideate session"),
+            };
+        }
+    }
+
     let manager = IdeateManager::new(db.pool.clone());
     let input = CreateIdeateSessionInput {
         project_id: request.project_id,
@@ -50,12 +72,107 @@ pub async fn start_ideate(
         mode: request.mode,
         template_id: request.template_id,
         research_tools_enabled: request.research_tools_enabled.unwrap_or(false),
+        question_bank_id: request.question_bank_id,
     };
 
     let result = manager.create_session(input).await;
     created_or_internal_error(result, "Failed to start ideate session")
 }
 
+/// Request body for starting a session from an existing document.
+///
+/// Provide either `repoPath` (a repository directory or README/PRD file on
+/// this machine, subject to the allowed browse paths) or an uploaded file via `fileName` plus `content` (Markdown text)
+/// or `contentBase64` (required for .docx).
+#[derive(Deserialize)]
+pub struct StartFromDocumentRequest {
+    #[serde(rename = "projectId")]
+    pub project_id: String,
+    pub mode: Option<IdeateMode>,
+    #[serde(rename = "templateId")]
+    pub template_id: Option<String>,
+    #[serde(rename = "researchToolsEnabled")]
+    pub research_tools_enabled: Option<bool>,
+    #[serde(rename = "fileName")]
+    pub file_name: Option<String>,
+    pub content: Option<String>,
+    #[serde(rename = "contentBase64")]
+    pub content_base64: Option<String>,
+    #[serde(rename = "repoPath")]
+    pub repo_path: Option<String>,
+}
+
+/// Checks a client-supplied `repoPath` against the server's browse policy and
+/// returns the resolved path. The CLI installs one backed by its
+/// `PathValidator`; without it, `repoPath` imports are refused.
+pub type RepoPathGuard = Arc<dyn Fn(&str) -> Result<PathBuf, String> + Send + Sync>;
+
+impl StartFromDocumentRequest {
+    fn document_source(&mut self, guard: Option<&RepoPathGuard>) -> Result<DocumentSource, String> {
+        if let Some(path) = self.repo_path.take() {
+            let guard = guard.ok_or("Importing from repoPath is not enabled on this server")?;
+            return guard(&path).map(DocumentSource::Path).map_err(|e| {
+                warn!("Rejected ideate document path {}: {}", path, e);
+                format!("repoPath is not allowed: {}", e)
+            });
+        }
+
+        let name = self
+            .file_name
+            .take()
+            .unwrap_or_else(|| "document.md".to_string());
+        let bytes = match (self.content.take(), self.content_base64.take()) {
+            (_, Some(encoded)) => BASE64
+                .decode(encoded.trim())
+                .map_err(|e| format!("contentBase64 is not valid base64: {}", e))?,
+            (Some(content), None) => content.into_bytes(),
+            (None, None) => {
+                return Err("Provide repoPath, content, or contentBase64".to_string());
+            }
+        };
+
+        if name.to_lowercase().ends_with(".docx") {
+            Ok(DocumentSource::Docx { name, bytes })
+        } else {
+            let content = String::from_utf8(bytes)
+                .map_err(|_| format!("{} is not valid UTF-8 text", name))?;
+            Ok(DocumentSource::Markdown { name, content })
+        }
+    }
+}
+
+/// Start a new ideate session pre-filled from a README/PRD document
+pub async fn start_ideate_from_document(
+    State(db): State<DbState>,
+    guard: Option<Extension<RepoPathGuard>>,
+    Json(mut request): Json<StartFromDocumentRequest>,
+) -> impl IntoResponse {
+    let source = match request.document_source(guard.as_ref().map(|Extension(g)| g)) {
+        Ok(source) => source,
+        Err(e) => return bad_request(e, "Invalid document"),
+    };
+    info!(
+        "Starting ideate session for project {} from document",
+        request.project_id
+    );
+
+    let importer = DocumentImporter::new(db.pool.clone());
+    let result = importer
+        .start_session(StartFromDocumentInput {
+            project_id: request.project_id,
+            mode: request.mode.unwrap_or(IdeateMode::Guided),
+            template_id: request.template_id,
+            research_tools_enabled: request.research_tools_enabled.unwrap_or(false),
+            source,
+        })
+        .await;
+
+    match result {
+        Err(e @ IdeateError::InvalidInput(_)) => bad_request(e, "Failed to import document"),
+        result => created_or_internal_error(result, "Failed to start ideate session from document"),
+    }
+}
+
 /// Get a ideateing session by ID
 pub async fn get_ideate(
     State(db): State<DbState>,
@@ -440,6 +557,10 @@ pub struct SaveAsPRDRequest {
     pub title: String,
     #[serde(rename = "contentMarkdown")]
     pub content_markdown: String,
+    /// Research sources the generator used, by section; sources mentioned in
+    /// the content are cited automatically as well
+    #[serde(default)]
+    pub citations: Vec<CitationInput>,
 }
 
 /// Save the generated PRD to the OpenSpec system
@@ -503,6 +624,23 @@ pub async fn save_as_prd(
         .execute(&db.pool)
         .await;
 
+    let tracker = CitationTracker::new(db.pool.clone());
+    if let Err(e) = tracker
+        .record_for_prd(
+            &prd.id,
+            &session_id,
+            &request.content_markdown,
+            request.citations,
+        )
+        .await
+    {
+        tracing::warn!(
+            "Failed to record research citations for PRD {}: {}",
+            prd.id,
+            e
+        );
+    }
+
     // Return the created PRD
     let response = serde_json::to_value(&prd).unwrap_or_else(|_| serde_json::json!({}));
     ok_or_internal_error::<_, String>(Ok(response), "Failed to save PRD")
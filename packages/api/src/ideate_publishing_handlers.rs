@@ -0,0 +1,216 @@
+// ABOUTME: HTTP request handlers for publishing ideate PRDs to Notion and Confluence
+// ABOUTME: Manages per-user publishing credentials and starts and reports background export jobs
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::auth::CurrentUser;
+use super::response::{bad_request, ok_or_internal_error, ApiResponse};
+use orkee_config::constants;
+use orkee_ideate::{
+    ExportFormat, IdeateError, PublishOptions, PublishingCredentials, PublishingService,
+};
+use orkee_projects::DbState;
+
+/// Request body for connecting a publishing target
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetCredentialsRequest {
+    pub api_token: String,
+    pub site_url: Option<String>,
+    pub account_email: Option<String>,
+    pub default_parent: Option<String>,
+}
+
+/// Request body for publishing a session's PRD
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishPrdRequest {
+    pub format: ExportFormat,
+    /// Page title; defaults to the PRD's title
+    pub title: Option<String>,
+    /// Notion parent page or Confluence parent page, overriding the default
+    pub parent_id: Option<String>,
+    /// Dashboard URL the page links back to; defaults to the local dashboard
+    pub orkee_url: Option<String>,
+}
+
+#[allow(clippy::result_large_err)]
+fn publishing_service(db: &DbState) -> Result<PublishingService, axum::response::Response> {
+    PublishingService::new(db.pool.clone())
+        .map_err(|e| ok_or_internal_error::<(), _>(Err(e), "Publishing is unavailable"))
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_target(target: &str) -> Result<ExportFormat, axum::response::Response> {
+    serde_json::from_value::<ExportFormat>(serde_json::Value::String(target.to_string()))
+        .ok()
+        .filter(ExportFormat::is_publishing_target)
+        .ok_or_else(|| {
+            bad_request(
+                format!(
+                    "{} is not a publishing target; use notion or confluence",
+                    target
+                ),
+                "Invalid target",
+            )
+        })
+}
+
+fn default_orkee_url() -> String {
+    let port = std::env::var(constants::ORKEE_UI_PORT).unwrap_or_else(|_| "5173".to_string());
+    format!("http://localhost:{}", port)
+}
+
+fn not_found(message: String) -> axum::response::Response {
+    (
+        StatusCode::NOT_FOUND,
+        ResponseJson(ApiResponse::<()>::error(message)),
+    )
+        .into_response()
+}
+
+/// List the current user's connected publishing targets, without tokens
+pub async fn list_credentials(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+) -> impl IntoResponse {
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+    let result = service.credentials().list(&current_user.id).await;
+    ok_or_internal_error(result, "Failed to list publishing credentials")
+}
+
+/// Connect or update a publishing target for the current user
+pub async fn set_credentials(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(target): Path<String>,
+    Json(request): Json<SetCredentialsRequest>,
+) -> impl IntoResponse {
+    let target = match parse_target(&target) {
+        Ok(target) => target,
+        Err(response) => return response,
+    };
+    let credentials = PublishingCredentials {
+        target,
+        api_token: request.api_token,
+        site_url: request.site_url,
+        account_email: request.account_email,
+        default_parent: request.default_parent,
+    };
+    if let Err(e) = credentials.validate() {
+        return bad_request(e, "Invalid publishing credentials");
+    }
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+
+    info!("Connecting {} for user {}", target, current_user.id);
+    let result = service
+        .credentials()
+        .set(&current_user.id, &credentials)
+        .await;
+    ok_or_internal_error(result, "Failed to save publishing credentials")
+}
+
+/// Disconnect a publishing target for the current user
+pub async fn delete_credentials(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(target): Path<String>,
+) -> impl IntoResponse {
+    let target = match parse_target(&target) {
+        Ok(target) => target,
+        Err(response) => return response,
+    };
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+
+    info!("Disconnecting {} for user {}", target, current_user.id);
+    match service.credentials().delete(&current_user.id, target).await {
+        Ok(true) => ok_or_internal_error::<_, String>(Ok(()), ""),
+        Ok(false) => not_found(format!("{} is not connected", target)),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to delete publishing credentials"),
+    }
+}
+
+/// Publish a session's saved PRD. Publishing runs in the background; the
+/// returned job reports the page URL once it completes.
+pub async fn publish_prd(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(session_id): Path<String>,
+    Json(request): Json<PublishPrdRequest>,
+) -> impl IntoResponse {
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+
+    info!(
+        "Publishing PRD for session {} to {}",
+        session_id, request.format
+    );
+    let options = PublishOptions {
+        format: request.format,
+        title: request.title,
+        parent_id: request.parent_id,
+        orkee_url: request.orkee_url.unwrap_or_else(default_orkee_url),
+    };
+    match service
+        .start_publish(&session_id, &current_user.id, options)
+        .await
+    {
+        Ok(job) => (
+            StatusCode::ACCEPTED,
+            ResponseJson(ApiResponse::success(job)),
+        )
+            .into_response(),
+        Err(e @ IdeateError::InvalidInput(_)) => bad_request(e, "Failed to publish PRD"),
+        Err(IdeateError::NotFound(message)) => not_found(message),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to publish PRD"),
+    }
+}
+
+/// List a session's export jobs, newest first
+pub async fn list_export_jobs(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse {
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+    let result = service.jobs().list_for_session(&session_id).await;
+    ok_or_internal_error(result, "Failed to list export jobs")
+}
+
+/// Get an export job, to poll for its status and page URL
+pub async fn get_export_job(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(job_id): Path<String>,
+) -> impl IntoResponse {
+    let service = match publishing_service(&db) {
+        Ok(service) => service,
+        Err(response) => return response,
+    };
+    match service.jobs().get(&job_id).await {
+        Ok(Some(job)) => ok_or_internal_error::<_, String>(Ok(job), ""),
+        Ok(None) => not_found(format!("Export job not found: {}", job_id)),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to get export job"),
+    }
+}
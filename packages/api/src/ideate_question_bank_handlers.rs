@@ -0,0 +1,116 @@
+// ABOUTME: HTTP request handlers for custom discovery question banks
+// ABOUTME: CRUD for a user's own and organization-shared intake questionnaires
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use tracing::info;
+
+use super::auth::CurrentUser;
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found, ApiResponse,
+};
+use orkee_ideate::{IdeateError, QuestionBank, QuestionBankInput, QuestionBankManager};
+use orkee_projects::DbState;
+
+/// Map a question bank error to the right status code
+fn bank_error(error: IdeateError, context: &str) -> axum::response::Response {
+    match error {
+        IdeateError::NotFound(_) => ok_or_not_found(Err::<(), _>(error), context),
+        IdeateError::ValidationError(_) => bad_request(error, context),
+        IdeateError::Forbidden(_) => (
+            StatusCode::FORBIDDEN,
+            ResponseJson(ApiResponse::<()>::error(format!("{}: {}", context, error))),
+        )
+            .into_response(),
+        _ => ok_or_internal_error(Err::<(), _>(error), context),
+    }
+}
+
+fn respond(result: Result<QuestionBank, IdeateError>, context: &str) -> axum::response::Response {
+    match result {
+        Ok(bank) => ok_or_internal_error(Ok::<_, IdeateError>(bank), context),
+        Err(e) => bank_error(e, context),
+    }
+}
+
+/// List the current user's question banks and those shared across the organization
+pub async fn list_question_banks(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+) -> impl IntoResponse {
+    info!("Listing question banks for user: {}", current_user.id);
+
+    let manager = QuestionBankManager::new(db.pool.clone());
+    let result = manager.list(&current_user.id).await;
+    ok_or_internal_error(result, "Failed to list question banks")
+}
+
+/// Get a question bank the current user owns or can see
+pub async fn get_question_bank(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(bank_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting question bank: {}", bank_id);
+
+    let manager = QuestionBankManager::new(db.pool.clone());
+    respond(
+        manager.get(&current_user.id, &bank_id).await,
+        "Failed to get question bank",
+    )
+}
+
+/// Create a question bank owned by the current user
+pub async fn create_question_bank(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Json(input): Json<QuestionBankInput>,
+) -> impl IntoResponse {
+    info!("Creating question bank: {}", input.name);
+
+    let manager = QuestionBankManager::new(db.pool.clone());
+    match manager.create(&current_user.id, input).await {
+        Ok(bank) => {
+            created_or_internal_error(Ok::<_, IdeateError>(bank), "Failed to create question bank")
+        }
+        Err(e) => bank_error(e, "Failed to create question bank"),
+    }
+}
+
+/// Replace a question bank's details and questions; only its owner may edit it
+pub async fn update_question_bank(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(bank_id): Path<String>,
+    Json(input): Json<QuestionBankInput>,
+) -> impl IntoResponse {
+    info!("Updating question bank: {}", bank_id);
+
+    let manager = QuestionBankManager::new(db.pool.clone());
+    respond(
+        manager.update(&current_user.id, &bank_id, input).await,
+        "Failed to update question bank",
+    )
+}
+
+/// Delete a question bank; sessions using it fall back to the built-in questions
+pub async fn delete_question_bank(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(bank_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Deleting question bank: {}", bank_id);
+
+    let manager = QuestionBankManager::new(db.pool.clone());
+    match manager.delete(&current_user.id, &bank_id).await {
+        Ok(()) => ok_or_internal_error(
+            Ok::<_, IdeateError>(serde_json::json!({ "deleted": true })),
+            "Failed to delete question bank",
+        ),
+        Err(e) => bank_error(e, "Failed to delete question bank"),
+    }
+}
@@ -1,17 +1,19 @@
 // ABOUTME: HTTP request handlers for PRD research and competitor analysis
-// ABOUTME: Handles competitor analysis, gap analysis, similar projects, and pattern extraction
+// ABOUTME: Handles competitor analysis, gap analysis, similar projects, web search sources, and pattern extraction
 
 use axum::{
     extract::{Path, State},
     response::IntoResponse,
     Json,
 };
-use orkee_ideate::{ResearchAnalyzer, SimilarProject};
+use orkee_ideate::{IdeateError, ResearchAnalyzer, SimilarProject};
 use orkee_projects::DbState;
 use serde::Deserialize;
 use tracing::info;
 
-use super::response::{created_or_internal_error, ok_or_internal_error};
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
 
 /// Request body for analyzing a competitor
 #[derive(Deserialize)]
@@ -58,6 +60,21 @@ pub struct ExtractLessonsRequest {
     pub project_description: Option<String>,
 }
 
+/// Request body for searching the web for research sources
+#[derive(Deserialize)]
+pub struct SearchSourcesRequest {
+    pub query: String,
+    #[serde(rename = "maxResults")]
+    pub max_results: Option<usize>,
+    /// Fetch each result page so its text can feed the research prompts
+    #[serde(rename = "fetchContent", default = "default_fetch_content")]
+    pub fetch_content: bool,
+}
+
+fn default_fetch_content() -> bool {
+    true
+}
+
 /// Get all competitors for a session
 pub async fn get_competitors(
     State(db): State<DbState>,
@@ -106,3 +123,60 @@ pub async fn get_similar_projects(
     let result = analyzer.get_similar_projects(&session_id).await;
     ok_or_internal_error(result, "Failed to get similar projects")
 }
+
+/// Search the web with the configured provider and store the results as sources
+pub async fn search_sources(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+    Json(request): Json<SearchSourcesRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Searching web sources for session {}: {}",
+        session_id, request.query
+    );
+
+    let analyzer = ResearchAnalyzer::new(db.pool.clone()).with_search_provider_from_env();
+    let result = analyzer
+        .search_and_store(
+            &session_id,
+            &request.query,
+            request.max_results.unwrap_or(5),
+            request.fetch_content,
+        )
+        .await;
+
+    match result {
+        Err(e @ IdeateError::InvalidInput(_)) => bad_request(e, "Failed to search web sources"),
+        result => created_or_internal_error(result, "Failed to search web sources"),
+    }
+}
+
+/// Get stored web sources for a session
+pub async fn get_sources(
+    State(db): State<DbState>,
+    Path(session_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting web sources for session: {}", session_id);
+
+    let analyzer = ResearchAnalyzer::new(db.pool.clone());
+    let result = analyzer.get_sources(&session_id).await;
+    ok_or_internal_error(result, "Failed to get web sources")
+}
+
+/// Delete a stored web source
+pub async fn delete_source(
+    State(db): State<DbState>,
+    Path((session_id, source_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Deleting web source {} for session: {}",
+        source_id, session_id
+    );
+
+    let analyzer = ResearchAnalyzer::new(db.pool.clone());
+    let result = analyzer.delete_source(&session_id, &source_id).await;
+    if matches!(result, Err(IdeateError::NotFound(_))) {
+        return ok_or_not_found(result, "Failed to delete web source");
+    }
+    ok_or_internal_error(result, "Failed to delete web source")
+}
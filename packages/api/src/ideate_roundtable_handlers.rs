@@ -12,11 +12,12 @@ use axum::{
 use futures::stream::{self, Stream};
 use orkee_config::constants;
 use orkee_ideate::{
-    CreateExpertPersonaInput, ExpertModerator, RoundtableEvent, RoundtableManager,
-    UserInterjectionInput,
+    CreateExpertPersonaInput, ExpertModerator, ModerationDecision, RoundtableEvent,
+    RoundtableManager, StartRoundtableRequest, UserInterjectionInput,
 };
 use orkee_projects::DbState;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::convert::Infallible;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -187,10 +188,63 @@ pub async fn add_participants(
     .into_response()
 }
 
+/// POST /api/ideate/roundtable/:roundtable_id/start - Start discussion with a moderation strategy
+pub async fn start_discussion(
+    State(db): State<DbState>,
+    Path(roundtable_id): Path<String>,
+    Json(request): Json<StartRoundtableRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Starting discussion for roundtable: {} (strategy: {:?})",
+        roundtable_id, request.strategy
+    );
+
+    let manager = RoundtableManager::new(db.pool.clone());
+    let moderator = ExpertModerator::new(manager);
+
+    let result = moderator.start_discussion(&roundtable_id, &request).await;
+
+    ok_or_internal_error(result, "Failed to start discussion")
+}
+
+/// GET /api/ideate/roundtable/:roundtable_id/next-turn - Moderator's next turn or conclusion
+pub async fn get_next_turn(
+    State(db): State<DbState>,
+    Path(roundtable_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting next turn for roundtable: {}", roundtable_id);
+
+    let manager = RoundtableManager::new(db.pool.clone());
+    let moderator = ExpertModerator::new(manager);
+
+    let result = moderator.next_decision(&roundtable_id).await;
+
+    ok_or_internal_error(result, "Failed to get next turn")
+}
+
+/// Convert a moderation decision into the stream event that announces it
+fn decision_event(roundtable_id: &str, decision: ModerationDecision) -> RoundtableEvent {
+    match decision {
+        ModerationDecision::NextTurn { turn } => RoundtableEvent::TurnAssigned { turn },
+        ModerationDecision::Conclude {
+            reason,
+            convergence_score,
+        } => RoundtableEvent::Concluding {
+            roundtable_id: roundtable_id.to_string(),
+            reason,
+            convergence_score,
+        },
+    }
+}
+
 /// Stream state tracking for resource management
 struct StreamState {
     manager: RoundtableManager,
+    moderator: ExpertModerator,
     roundtable_id: String,
+    /// Events queued for delivery before polling again
+    pending: VecDeque<RoundtableEvent>,
+    announced: bool,
     last_order: i32,
     start_time: Instant,
     max_duration: Duration,
@@ -218,7 +272,10 @@ pub async fn stream_discussion(
 
     let state = StreamState {
         manager,
+        moderator: ExpertModerator::new(RoundtableManager::new(db.pool.clone())),
         roundtable_id: roundtable_id.clone(),
+        pending: VecDeque::new(),
+        announced: false,
         last_order: 0i32,
         start_time: Instant::now(),
         max_duration: Duration::from_secs(max_duration_minutes * 60),
@@ -256,6 +313,32 @@ pub async fn stream_discussion(
             return None;
         }
 
+        // Deliver queued events before polling again
+        if let Some(event) = state.pending.pop_front() {
+            let data = serde_json::to_string(&event).unwrap_or_default();
+            return Some((Ok(Event::default().data(data)), state));
+        }
+
+        // Announce the participants and moderation strategy once the discussion is running
+        if !state.announced {
+            if let Ok(roundtable) = state
+                .manager
+                .get_roundtable_with_participants(&state.roundtable_id)
+                .await
+            {
+                if roundtable.session.is_active() {
+                    state.announced = true;
+                    let event = RoundtableEvent::Started {
+                        roundtable_id: state.roundtable_id.clone(),
+                        strategy: roundtable.session.moderation_strategy,
+                        participants: roundtable.participants,
+                    };
+                    let data = serde_json::to_string(&event).unwrap_or_default();
+                    return Some((Ok(Event::default().data(data)), state));
+                }
+            }
+        }
+
         // Poll for new messages
         match state
             .manager
@@ -276,19 +359,37 @@ pub async fn stream_discussion(
                         .map(|m| m.message_order)
                         .unwrap_or(state.last_order);
 
-                    // Send first message and queue the rest
-                    if let Some(first_message) = messages.into_iter().next() {
-                        let event = RoundtableEvent::Message {
-                            message: first_message,
-                        };
-                        let data = serde_json::to_string(&event).unwrap_or_default();
-                        Some((Ok(Event::default().data(data)), state))
-                    } else {
-                        // Fallback to heartbeat if no messages after all
-                        tokio::time::sleep(state.poll_interval).await;
-                        let event = RoundtableEvent::Heartbeat;
-                        let data = serde_json::to_string(&event).unwrap_or_default();
-                        Some((Ok(Event::default().data(data)), state))
+                    // Queue every new message, then the moderator's decision for what comes next
+                    state.pending.extend(
+                        messages
+                            .into_iter()
+                            .map(|message| RoundtableEvent::Message { message }),
+                    );
+                    if state.announced {
+                        match state.moderator.next_decision(&state.roundtable_id).await {
+                            Ok(Some(decision)) => state
+                                .pending
+                                .push_back(decision_event(&state.roundtable_id, decision)),
+                            Ok(None) => {}
+                            Err(e) => warn!(
+                                "Failed to compute next turn for roundtable {}: {}",
+                                state.roundtable_id, e
+                            ),
+                        }
+                    }
+
+                    match state.pending.pop_front() {
+                        Some(event) => {
+                            let data = serde_json::to_string(&event).unwrap_or_default();
+                            Some((Ok(Event::default().data(data)), state))
+                        }
+                        None => {
+                            // Fallback to heartbeat if no messages after all
+                            tokio::time::sleep(state.poll_interval).await;
+                            let event = RoundtableEvent::Heartbeat;
+                            let data = serde_json::to_string(&event).unwrap_or_default();
+                            Some((Ok(Event::default().data(data)), state))
+                        }
                     }
                 }
             }
@@ -8,13 +8,18 @@ use axum::{
 
 use orkee_projects::DbState;
 
+pub mod admin_handlers;
 pub mod agent_runs_handlers;
 pub mod agents_handlers;
 pub mod ai_proxy_handlers;
 pub mod ai_usage_log_handlers;
+pub mod api_tokens_handlers;
 pub mod auth;
+pub mod changes_handlers;
+pub mod editor_handlers;
 pub mod epic_approaches_handlers;
 pub mod epic_handlers;
+pub mod etag;
 pub mod executions_handlers;
 pub mod github_sync_handlers;
 pub mod graph_handlers;
@@ -24,21 +29,36 @@ pub mod ideate_dependency_handlers;
 pub mod ideate_discovery_handlers;
 pub mod ideate_generation_handlers;
 pub mod ideate_handlers;
+pub mod ideate_publishing_handlers;
+pub mod ideate_question_bank_handlers;
 pub mod ideate_research_handlers;
 pub mod ideate_roundtable_handlers;
 pub mod ideate_validation_handlers;
+pub mod mcp_servers_handlers;
 pub mod model_preferences_handlers;
 pub mod models_handlers;
+pub mod notifications_handlers;
 pub mod oauth_handlers;
+pub mod openspec_handlers;
 pub mod prd_handlers;
+pub mod project_docs_handlers;
+pub mod project_scripts_handlers;
 pub mod response;
 pub mod sandbox_handlers;
+pub mod scheduler_handlers;
+pub mod search_handlers;
+pub mod secrets_handlers;
+pub mod security_alerts_handlers;
 pub mod security_handlers;
 pub mod tags_handlers;
 pub mod task_decomposition_handlers;
+pub mod task_sources_handlers;
+pub mod taskmaster_sync_handlers;
 pub mod tasks_handlers;
 pub mod template_handlers;
 pub mod users_handlers;
+pub mod view_preferences_handlers;
+pub mod webhooks_handlers;
 
 /// Creates the projects API router
 pub fn create_projects_router() -> Router {
@@ -68,6 +88,8 @@ pub fn create_tasks_router() -> Router<DbState> {
     Router::new()
         .route("/", get(tasks_handlers::list_tasks))
         .route("/", post(tasks_handlers::create_task))
+        .route("/parse", post(tasks_handlers::parse_task))
+        .route("/activity", get(tasks_handlers::list_project_activity))
         .route("/{task_id}", get(tasks_handlers::get_task))
         .route("/{task_id}", put(tasks_handlers::update_task))
         .route("/{task_id}", delete(tasks_handlers::delete_task))
@@ -76,6 +98,19 @@ pub fn create_tasks_router() -> Router<DbState> {
             "/{task_id}/generate-steps",
             post(tasks_handlers::generate_task_steps),
         )
+        .route("/{task_id}/steps", get(tasks_handlers::list_task_steps))
+        .route(
+            "/{task_id}/activity",
+            get(tasks_handlers::list_task_activity),
+        )
+        .route(
+            "/{task_id}/steps/{step_id}",
+            put(tasks_handlers::update_task_step),
+        )
+        .route(
+            "/{task_id}/test-plan",
+            get(tasks_handlers::get_task_test_plan).put(tasks_handlers::save_task_test_plan),
+        )
         .route(
             "/{task_id}/append-progress",
             post(tasks_handlers::append_task_progress),
@@ -94,7 +129,17 @@ pub fn create_tasks_router() -> Router<DbState> {
 pub fn create_agents_router() -> Router<DbState> {
     Router::new()
         .route("/", get(agents_handlers::list_agents))
+        .route(
+            "/tool-permissions",
+            get(agents_handlers::list_tool_policies),
+        )
         .route("/{agent_id}", get(agents_handlers::get_agent))
+        .route(
+            "/{agent_id}/tool-permissions",
+            get(agents_handlers::get_tool_policy)
+                .put(agents_handlers::update_tool_policy)
+                .delete(agents_handlers::reset_tool_policy),
+        )
         .route("/users/{user_id}", get(agents_handlers::list_user_agents))
         .route(
             "/users/{user_id}/{agent_id}",
@@ -120,7 +165,14 @@ pub fn create_models_router() -> Router<DbState> {
 /// Creates the users API router
 pub fn create_users_router() -> Router<DbState> {
     Router::new()
-        .route("/current", get(users_handlers::get_current_user))
+        .route(
+            "/",
+            get(users_handlers::list_users).post(users_handlers::create_user),
+        )
+        .route(
+            "/current",
+            get(users_handlers::get_current_user).put(users_handlers::switch_current_user),
+        )
         .route("/{user_id}", get(users_handlers::get_user))
         .route(
             "/{user_id}/default-agent",
@@ -142,6 +194,26 @@ pub fn create_users_router() -> Router<DbState> {
             "/{user_id}/model-preferences/{task_type}",
             put(model_preferences_handlers::update_task_model),
         )
+        .route(
+            "/{user_id}/model-preferences/policies",
+            get(model_preferences_handlers::list_task_policies),
+        )
+        .route(
+            "/{user_id}/model-preferences/{task_type}/policy",
+            get(model_preferences_handlers::get_task_policy)
+                .put(model_preferences_handlers::update_task_policy),
+        )
+        // Table view preferences routes
+        .route(
+            "/{user_id}/view-preferences",
+            get(view_preferences_handlers::list_view_preferences),
+        )
+        .route(
+            "/{user_id}/view-preferences/{view_key}",
+            get(view_preferences_handlers::get_view_preference)
+                .put(view_preferences_handlers::save_view_preference)
+                .delete(view_preferences_handlers::delete_view_preference),
+        )
 }
 
 /// Creates the tags API router
@@ -156,6 +228,42 @@ pub fn create_tags_router() -> Router<DbState> {
         .route("/{tag_id}/unarchive", post(tags_handlers::unarchive_tag))
 }
 
+/// Creates the change feed API router
+pub fn create_changes_router() -> Router<DbState> {
+    Router::new().route("/", get(changes_handlers::list_changes))
+}
+
+/// Creates the search API router for query-language search and saved searches
+pub fn create_search_router() -> Router<DbState> {
+    Router::new()
+        .route("/", get(search_handlers::search))
+        .route(
+            "/saved",
+            get(search_handlers::list_saved_searches).post(search_handlers::create_saved_search),
+        )
+        .route(
+            "/saved/{search_id}",
+            get(search_handlers::get_saved_search)
+                .put(search_handlers::update_saved_search)
+                .delete(search_handlers::delete_saved_search),
+        )
+        .route(
+            "/saved/{search_id}/results",
+            get(search_handlers::run_saved_search),
+        )
+}
+
+/// Creates the admin API router for database maintenance
+pub fn create_admin_router() -> Router<DbState> {
+    Router::new()
+        .route("/integrity", get(admin_handlers::check_integrity))
+        .route("/storage", get(admin_handlers::get_storage_stats))
+        .route(
+            "/storage/maintenance",
+            post(admin_handlers::run_maintenance),
+        )
+}
+
 /// Creates the executions API router for task executions
 pub fn create_executions_router() -> Router<DbState> {
     Router::new()
@@ -164,6 +272,11 @@ pub fn create_executions_router() -> Router<DbState> {
             get(executions_handlers::list_executions),
         )
         .route("/executions", post(executions_handlers::create_execution))
+        .route(
+            "/executions/compare",
+            get(executions_handlers::compare_executions),
+        )
+        .route("/executions/queue", get(executions_handlers::list_queue))
         .route(
             "/executions/{execution_id}",
             get(executions_handlers::get_execution),
@@ -176,6 +289,10 @@ pub fn create_executions_router() -> Router<DbState> {
             "/executions/{execution_id}",
             delete(executions_handlers::delete_execution),
         )
+        .route(
+            "/executions/{execution_id}/cancel",
+            post(executions_handlers::cancel_execution),
+        )
         .route(
             "/executions/{execution_id}/reviews",
             get(executions_handlers::list_reviews),
@@ -207,6 +324,130 @@ pub fn create_prds_router() -> Router<DbState> {
             "/{project_id}/prds/{prd_id}/epics",
             get(epic_handlers::list_epics_by_prd),
         )
+        .route(
+            "/{project_id}/prds/{prd_id}/traceability",
+            get(epic_handlers::get_prd_traceability),
+        )
+        .route(
+            "/{project_id}/prds/{prd_id}/citations",
+            get(prd_handlers::get_prd_citations),
+        )
+}
+
+/// Creates the project secrets API router (nested under /api/projects)
+pub fn create_secrets_router() -> Router<DbState> {
+    Router::new()
+        .route("/{project_id}/secrets", get(secrets_handlers::list_secrets))
+        .route(
+            "/{project_id}/secrets/{name}",
+            put(secrets_handlers::set_secret),
+        )
+        .route(
+            "/{project_id}/secrets/{name}",
+            delete(secrets_handlers::delete_secret),
+        )
+}
+
+/// Creates the project README and docs API router (nested under /api/projects)
+pub fn create_project_docs_router() -> Router<DbState> {
+    Router::new()
+        .route(
+            "/{project_id}/readme",
+            get(project_docs_handlers::get_readme),
+        )
+        .route(
+            "/{project_id}/docs/assets/{*path}",
+            get(project_docs_handlers::get_doc_asset),
+        )
+}
+
+/// Creates the project scripts API router (nested under /api/projects)
+pub fn create_project_scripts_router() -> Router<DbState> {
+    Router::new()
+        .route(
+            "/{project_id}/run-script/{kind}",
+            post(project_scripts_handlers::run_script),
+        )
+        .route(
+            "/{project_id}/script-runs",
+            get(project_scripts_handlers::list_script_runs),
+        )
+        .route(
+            "/{project_id}/script-runs/{run_id}",
+            get(project_scripts_handlers::get_script_run),
+        )
+        .route(
+            "/{project_id}/script-runs/{run_id}/events",
+            get(project_scripts_handlers::script_run_events),
+        )
+        .route(
+            "/{project_id}/script-runs/{run_id}/cancel",
+            post(project_scripts_handlers::cancel_script_run),
+        )
+}
+
+/// Creates the MCP servers API router for per-project MCP server configuration
+pub fn create_mcp_servers_router() -> Router<DbState> {
+    Router::new()
+        .route(
+            "/{project_id}/mcp-servers",
+            get(mcp_servers_handlers::list_mcp_servers),
+        )
+        .route(
+            "/{project_id}/mcp-servers",
+            post(mcp_servers_handlers::create_mcp_server),
+        )
+        .route(
+            "/{project_id}/mcp-servers/export",
+            get(mcp_servers_handlers::export_mcp_servers),
+        )
+        .route(
+            "/{project_id}/mcp-servers/{server_id}",
+            get(mcp_servers_handlers::get_mcp_server),
+        )
+        .route(
+            "/{project_id}/mcp-servers/{server_id}",
+            put(mcp_servers_handlers::update_mcp_server),
+        )
+        .route(
+            "/{project_id}/mcp-servers/{server_id}",
+            delete(mcp_servers_handlers::delete_mcp_server),
+        )
+        .route(
+            "/{project_id}/mcp-servers/{server_id}/validate",
+            post(mcp_servers_handlers::validate_mcp_server),
+        )
+}
+
+/// Creates the task sources API router for Linear and Jira issue imports
+pub fn create_task_sources_router() -> Router<DbState> {
+    Router::new()
+        .route(
+            "/{project_id}/task-sources",
+            get(task_sources_handlers::list_task_sources)
+                .post(task_sources_handlers::create_task_source),
+        )
+        .route(
+            "/{project_id}/task-sources/{source_id}",
+            get(task_sources_handlers::get_task_source)
+                .put(task_sources_handlers::update_task_source)
+                .delete(task_sources_handlers::delete_task_source),
+        )
+        .route(
+            "/{project_id}/task-sources/{source_id}/import",
+            post(task_sources_handlers::import_task_source),
+        )
+}
+
+/// Creates the Taskmaster sync API router for two-way tasks.json sync and its conflicts
+pub fn create_taskmaster_sync_router() -> Router<DbState> {
+    Router::new()
+        .route("/sync", post(taskmaster_sync_handlers::sync_taskmaster))
+        .route("/conflicts", get(taskmaster_sync_handlers::list_conflicts))
+        .route(
+            "/conflicts/{conflict_id}/resolve",
+            post(taskmaster_sync_handlers::resolve_conflict),
+        )
 }
 
 /// Creates the Epic API router for Epic management (CCPM workflow)
@@ -238,6 +479,10 @@ pub fn create_epics_router() -> Router<DbState> {
             "/{project_id}/epics/{epic_id}/progress",
             get(epic_handlers::calculate_epic_progress),
         )
+        .route(
+            "/{project_id}/epics/{epic_id}/metrics",
+            get(epic_handlers::get_epic_metrics),
+        )
         .route(
             "/{project_id}/epics/{epic_id}/analyze-work",
             post(task_decomposition_handlers::analyze_work_streams),
@@ -272,6 +517,10 @@ pub fn create_epics_router() -> Router<DbState> {
             "/{project_id}/epics/{epic_id}/alternatives",
             get(epic_approaches_handlers::get_alternatives),
         )
+        .route(
+            "/{project_id}/epics/{epic_id}/approach-matrix",
+            post(epic_approaches_handlers::score_approaches),
+        )
         .route(
             "/{project_id}/epics/{epic_id}/select-approach",
             put(epic_approaches_handlers::select_approach),
@@ -294,12 +543,24 @@ pub fn create_epics_router() -> Router<DbState> {
             "/{project_id}/epics/{epic_id}/checkpoints",
             post(epic_handlers::generate_epic_checkpoints),
         )
+        .route(
+            "/{project_id}/epics/{epic_id}/checkpoints/gates",
+            get(epic_handlers::get_parallel_group_gates),
+        )
+        .route(
+            "/{project_id}/epics/{epic_id}/checkpoints/{checkpoint_id}/runs",
+            get(epic_handlers::list_checkpoint_runs).post(epic_handlers::run_checkpoint_validation),
+        )
 }
 
 /// Creates the Brainstorm API router for PRD ideation and ideateing
 pub fn create_ideate_router() -> Router<DbState> {
     Router::new()
         .route("/ideate/start", post(ideate_handlers::start_ideate))
+        .route(
+            "/ideate/start-from-document",
+            post(ideate_handlers::start_ideate_from_document),
+        )
         .route("/ideate/{session_id}", get(ideate_handlers::get_ideate))
         .route("/ideate/{session_id}", put(ideate_handlers::update_ideate))
         .route(
@@ -458,6 +719,10 @@ pub fn create_ideate_router() -> Router<DbState> {
             "/ideate/{session_id}/dependencies/optimize",
             post(ideate_dependency_handlers::optimize_build_order),
         )
+        .route(
+            "/ideate/{session_id}/dependencies/simulate",
+            post(ideate_dependency_handlers::simulate_build_order),
+        )
         .route(
             "/ideate/{session_id}/dependencies/build-order",
             get(ideate_dependency_handlers::get_build_order),
@@ -499,6 +764,18 @@ pub fn create_ideate_router() -> Router<DbState> {
             "/ideate/{session_id}/research/similar-projects",
             get(ideate_research_handlers::get_similar_projects),
         )
+        .route(
+            "/ideate/{session_id}/research/sources",
+            get(ideate_research_handlers::get_sources),
+        )
+        .route(
+            "/ideate/{session_id}/research/sources/search",
+            post(ideate_research_handlers::search_sources),
+        )
+        .route(
+            "/ideate/{session_id}/research/sources/{source_id}",
+            delete(ideate_research_handlers::delete_source),
+        )
         // AI operations moved to frontend - use research-ai.ts
         // .route(
         //     "/ideate/{session_id}/research/lessons/extract",
@@ -540,12 +817,15 @@ pub fn create_ideate_router() -> Router<DbState> {
             "/ideate/roundtable/{roundtable_id}/participants",
             post(ideate_roundtable_handlers::add_participants),
         )
-        // Discussion operations
-        // AI operations moved to frontend - use roundtable-ai.ts
-        // .route(
-        //     "/ideate/roundtable/{roundtable_id}/start",
-        //     post(ideate_roundtable_handlers::start_discussion),
-        // )
+        // Discussion operations (expert responses are generated in roundtable-ai.ts)
+        .route(
+            "/ideate/roundtable/{roundtable_id}/start",
+            post(ideate_roundtable_handlers::start_discussion),
+        )
+        .route(
+            "/ideate/roundtable/{roundtable_id}/next-turn",
+            get(ideate_roundtable_handlers::get_next_turn),
+        )
         .route(
             "/ideate/roundtable/{roundtable_id}/stream",
             get(ideate_roundtable_handlers::stream_discussion),
@@ -607,6 +887,43 @@ pub fn create_ideate_router() -> Router<DbState> {
         //     "/ideate/{session_id}/prd/export",
         //     post(ideate_generation_handlers::export_prd),
         // )
+        // Publishing PRDs to Notion and Confluence runs as background export jobs
+        .route(
+            "/ideate/{session_id}/prd/publish",
+            post(ideate_publishing_handlers::publish_prd),
+        )
+        .route(
+            "/ideate/{session_id}/export-jobs",
+            get(ideate_publishing_handlers::list_export_jobs),
+        )
+        .route(
+            "/ideate/export-jobs/{job_id}",
+            get(ideate_publishing_handlers::get_export_job),
+        )
+        // Custom discovery question banks, selectable when starting a session
+        .route(
+            "/ideate/question-banks",
+            get(ideate_question_bank_handlers::list_question_banks)
+                .post(ideate_question_bank_handlers::create_question_bank),
+        )
+        .route(
+            "/ideate/question-banks/{bank_id}",
+            get(ideate_question_bank_handlers::get_question_bank)
+                .put(ideate_question_bank_handlers::update_question_bank)
+                .delete(ideate_question_bank_handlers::delete_question_bank),
+        )
+        .route(
+            "/ideate/publishing/credentials",
+            get(ideate_publishing_handlers::list_credentials),
+        )
+        .route(
+            "/ideate/publishing/credentials/{target}",
+            put(ideate_publishing_handlers::set_credentials),
+        )
+        .route(
+            "/ideate/publishing/credentials/{target}",
+            delete(ideate_publishing_handlers::delete_credentials),
+        )
         .route(
             "/ideate/{session_id}/prd/completeness",
             get(ideate_generation_handlers::get_completeness),
@@ -688,6 +1005,14 @@ pub fn create_ideate_router() -> Router<DbState> {
             "/ideate/chat/{session_id}/validate",
             get(ideate_chat_handlers::validate_for_prd),
         )
+        .route(
+            "/ideate/chat/{session_id}/context",
+            get(ideate_chat_handlers::get_context),
+        )
+        .route(
+            "/ideate/chat/{session_id}/summaries",
+            get(ideate_chat_handlers::get_summaries).post(ideate_chat_handlers::create_summary),
+        )
         // Discovery & Codebase Analysis routes (Phase 6A.1 CCPM)
         .route(
             "/ideate/sessions/{id}/analyze-codebase",
@@ -726,8 +1051,54 @@ pub fn create_ai_usage_router() -> Router<DbState> {
         .route("/", post(ai_usage_log_handlers::create_log))
         .route("/logs", get(ai_usage_log_handlers::list_logs))
         .route("/stats", get(ai_usage_log_handlers::get_stats))
+        .route(
+            "/projects/{project_id}/stats",
+            get(ai_usage_log_handlers::get_project_stats),
+        )
         .route("/tools", get(ai_usage_log_handlers::get_tool_stats))
         .route("/time-series", get(ai_usage_log_handlers::get_time_series))
+        .route(
+            "/prompts",
+            get(ai_usage_log_handlers::get_prompt_stats)
+                .post(ai_usage_log_handlers::record_prompt_usage),
+        )
+}
+
+/// Creates the notifications API router for the notification feed
+pub fn create_notifications_router() -> Router<DbState> {
+    Router::new()
+        .route("/", get(notifications_handlers::list_notifications))
+        .route(
+            "/test",
+            post(notifications_handlers::send_test_notification),
+        )
+}
+
+/// Creates the webhooks API router for inbound CI deliveries and webhook secrets
+pub fn create_webhooks_router() -> Router<DbState> {
+    Router::new()
+        .route(
+            "/secrets",
+            get(webhooks_handlers::list_secrets).post(webhooks_handlers::create_secret),
+        )
+        .route(
+            "/secrets/{secret_id}",
+            delete(webhooks_handlers::delete_secret),
+        )
+        .route("/deliveries", get(webhooks_handlers::list_deliveries))
+        .route("/{provider}", post(webhooks_handlers::receive_webhook))
+}
+
+/// Creates the scheduler API router for recurring background jobs
+pub fn create_scheduler_router() -> Router<DbState> {
+    Router::new()
+        .route("/jobs", get(scheduler_handlers::list_jobs))
+        .route(
+            "/jobs/{job_id}",
+            get(scheduler_handlers::get_job).put(scheduler_handlers::update_job),
+        )
+        .route("/jobs/{job_id}/run", post(scheduler_handlers::run_job))
+        .route("/jobs/{job_id}/runs", get(scheduler_handlers::list_runs))
 }
 
 /// Creates the AI proxy API router for secure credential management
@@ -740,6 +1111,10 @@ pub fn create_ai_proxy_router() -> Router<DbState> {
         .route("/ai/openai/{*path}", post(ai_proxy_handlers::proxy_openai))
         .route("/ai/google/{*path}", post(ai_proxy_handlers::proxy_google))
         .route("/ai/xai/{*path}", post(ai_proxy_handlers::proxy_xai))
+        .route(
+            "/ai/debug/{correlation_id}",
+            get(ai_proxy_handlers::get_debug_log),
+        )
 }
 
 /// Creates the security API router for encryption and key management
@@ -765,6 +1140,30 @@ pub fn create_security_router() -> Router<DbState> {
             "/security/remove-password",
             post(security_handlers::remove_password),
         )
+        .route(
+            "/security/tokens",
+            get(api_tokens_handlers::list_tokens).post(api_tokens_handlers::create_token),
+        )
+        .route(
+            "/security/tokens/{id}",
+            put(api_tokens_handlers::update_token).delete(api_tokens_handlers::revoke_token),
+        )
+        .route(
+            "/security/tokens/{id}/rotate",
+            post(api_tokens_handlers::rotate_token),
+        )
+        .route(
+            "/security/alerts",
+            get(security_alerts_handlers::list_alerts),
+        )
+        .route(
+            "/security/lockouts",
+            get(security_alerts_handlers::list_lockouts),
+        )
+        .route(
+            "/security/lockouts/{ip}",
+            delete(security_alerts_handlers::unlock_address),
+        )
 }
 
 /// Creates the graph API router for code visualization
@@ -784,11 +1183,34 @@ pub fn create_graph_router() -> Router<DbState> {
         )
 }
 
+/// Creates the OpenSpec API router for spec analysis
+pub fn create_openspec_router() -> Router<DbState> {
+    Router::new().route(
+        "/{project_id}/specs/drift",
+        get(openspec_handlers::get_spec_drift),
+    )
+}
+
+/// Creates the editor integrations API router (nested under /api/projects)
+pub fn create_editors_router() -> Router<DbState> {
+    Router::new()
+        .route("/editors", get(editor_handlers::list_editors))
+        .route("/editors/open", post(editor_handlers::open_editor))
+        .route(
+            "/{project_id}/editor",
+            get(editor_handlers::get_project_editor).put(editor_handlers::set_project_editor),
+        )
+}
+
 /// Creates the templates API router for PRD output template management
 pub fn create_templates_router() -> Router<DbState> {
     Router::new()
         .route("/templates", get(template_handlers::list_templates))
         .route("/templates", post(template_handlers::create_template))
+        .route(
+            "/templates/validate",
+            post(template_handlers::validate_template_content),
+        )
         .route(
             "/templates/{template_id}",
             get(template_handlers::get_template),
@@ -801,6 +1223,10 @@ pub fn create_templates_router() -> Router<DbState> {
             "/templates/{template_id}",
             delete(template_handlers::delete_template),
         )
+        .route(
+            "/templates/{template_id}/render",
+            post(template_handlers::render_template_content),
+        )
 }
 
 /// Creates the GitHub sync API router for syncing Epics and Tasks to GitHub
@@ -833,7 +1259,7 @@ pub fn create_oauth_router() -> Router<DbState> {
 
 /// Creates the Sandbox API router for sandbox settings management
 pub fn create_sandbox_router() -> Router<DbState> {
-    use axum::routing::{delete, get, put};
+    use axum::routing::{delete, get, post, put};
     Router::new()
         // Sandbox settings endpoints
         .route("/settings", get(sandbox_handlers::get_sandbox_settings))
@@ -852,6 +1278,50 @@ pub fn create_sandbox_router() -> Router<DbState> {
             "/providers/{provider}",
             delete(sandbox_handlers::delete_provider_settings),
         )
+        .route(
+            "/providers/{provider}/test",
+            post(sandbox_handlers::test_provider_connection),
+        )
+        .route(
+            "/providers/{provider}/info",
+            get(sandbox_handlers::get_provider_info),
+        )
+        // Execution template endpoints
+        .route("/templates", get(sandbox_handlers::list_templates))
+        .route("/templates", post(sandbox_handlers::create_template))
+        .route("/templates/{id}", get(sandbox_handlers::get_template))
+        .route("/templates/{id}", put(sandbox_handlers::update_template))
+        .route("/templates/{id}", delete(sandbox_handlers::delete_template))
+        // Execution log retention endpoints
+        .route(
+            "/retention-policies",
+            get(sandbox_handlers::list_retention_policies),
+        )
+        .route(
+            "/retention-policies",
+            post(sandbox_handlers::create_retention_policy),
+        )
+        .route(
+            "/retention-policies/{id}",
+            put(sandbox_handlers::update_retention_policy),
+        )
+        .route(
+            "/retention-policies/{id}",
+            delete(sandbox_handlers::delete_retention_policy),
+        )
+        .route(
+            "/retention-policies/{id}/usage",
+            get(sandbox_handlers::get_retention_usage),
+        )
+        .route(
+            "/retention/enforce",
+            post(sandbox_handlers::enforce_retention),
+        )
+        .route("/archives", get(sandbox_handlers::list_execution_archives))
+        .route(
+            "/archives/{execution_id}/restore",
+            post(sandbox_handlers::restore_execution_archive),
+        )
 }
 
 /// Creates the Agent Runs API router for autonomous agent management
@@ -0,0 +1,182 @@
+// ABOUTME: HTTP request handlers for per-project MCP server configuration
+// ABOUTME: Handles CRUD, handshake validation, and export to Claude Desktop / Cursor formats
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info, warn};
+
+use super::response::{ok_or_internal_error, ApiResponse};
+use orkee_projects::mcp::{self, DEFAULT_HANDSHAKE_TIMEOUT};
+use orkee_projects::{
+    get_project as manager_get_project, DbState, McpDbError, McpExportFormat, McpServerCreateInput,
+    McpServerUpdateInput,
+};
+
+/// Convert MCP database errors to HTTP responses
+fn mcp_error_to_response(error: McpDbError) -> axum::response::Response {
+    let (status, message) = match &error {
+        McpDbError::NotFound(_) => (StatusCode::NOT_FOUND, error.to_string()),
+        McpDbError::InvalidInput(_) => (StatusCode::BAD_REQUEST, error.to_string()),
+        McpDbError::SqlxError(sqlx::Error::Database(db_err)) if db_err.is_unique_violation() => (
+            StatusCode::CONFLICT,
+            "An MCP server with this name already exists for the project".to_string(),
+        ),
+        _ => {
+            error!("MCP server storage error: {:?}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Database error".to_string(),
+            )
+        }
+    };
+
+    (status, ResponseJson(ApiResponse::<()>::error(message))).into_response()
+}
+
+/// Wrap a successful payload with the given status
+fn success_response<T: Serialize>(status: StatusCode, data: T) -> axum::response::Response {
+    (status, ResponseJson(ApiResponse::success(data))).into_response()
+}
+
+/// List all MCP servers configured for a project
+pub async fn list_mcp_servers(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Listing MCP servers for project: {}", project_id);
+
+    let result = mcp::list_mcp_servers(&db.pool, &project_id).await;
+    ok_or_internal_error(
+        result,
+        &format!("Failed to list MCP servers for project {}", project_id),
+    )
+}
+
+/// Get a single MCP server configuration
+pub async fn get_mcp_server(
+    State(db): State<DbState>,
+    Path((project_id, server_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Getting MCP server {} for project: {}",
+        server_id, project_id
+    );
+
+    match mcp::get_mcp_server(&db.pool, &project_id, &server_id).await {
+        Ok(server) => success_response(StatusCode::OK, server),
+        Err(e) => mcp_error_to_response(e),
+    }
+}
+
+/// Create a new MCP server configuration
+pub async fn create_mcp_server(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Json(input): Json<McpServerCreateInput>,
+) -> impl IntoResponse {
+    info!(
+        "Creating MCP server '{}' for project: {}",
+        input.name, project_id
+    );
+
+    match mcp::create_mcp_server(&db.pool, &project_id, input).await {
+        Ok(server) => success_response(StatusCode::CREATED, server),
+        Err(e) => mcp_error_to_response(e),
+    }
+}
+
+/// Update an MCP server configuration
+pub async fn update_mcp_server(
+    State(db): State<DbState>,
+    Path((project_id, server_id)): Path<(String, String)>,
+    Json(input): Json<McpServerUpdateInput>,
+) -> impl IntoResponse {
+    info!(
+        "Updating MCP server {} for project: {}",
+        server_id, project_id
+    );
+
+    match mcp::update_mcp_server(&db.pool, &project_id, &server_id, input).await {
+        Ok(server) => success_response(StatusCode::OK, server),
+        Err(e) => mcp_error_to_response(e),
+    }
+}
+
+/// Delete an MCP server configuration
+pub async fn delete_mcp_server(
+    State(db): State<DbState>,
+    Path((project_id, server_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Deleting MCP server {} from project: {}",
+        server_id, project_id
+    );
+
+    match mcp::delete_mcp_server(&db.pool, &project_id, &server_id).await {
+        Ok(()) => success_response(StatusCode::OK, "MCP server deleted successfully"),
+        Err(e) => mcp_error_to_response(e),
+    }
+}
+
+/// Launch the server and attempt an MCP initialize handshake
+pub async fn validate_mcp_server(
+    State(db): State<DbState>,
+    Path((project_id, server_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Validating MCP server {} for project: {}",
+        server_id, project_id
+    );
+
+    let server = match mcp::get_mcp_server(&db.pool, &project_id, &server_id).await {
+        Ok(server) => server,
+        Err(e) => return mcp_error_to_response(e),
+    };
+
+    // Run the server from the project root so relative paths in args resolve
+    let project_root = match manager_get_project(&project_id).await {
+        Ok(Some(project)) => Some(std::path::PathBuf::from(project.project_root)),
+        _ => None,
+    };
+
+    let result =
+        mcp::perform_handshake(&server, project_root.as_deref(), DEFAULT_HANDSHAKE_TIMEOUT).await;
+
+    if let Err(e) =
+        mcp::record_validation_result(&db.pool, &server.id, result.error.as_deref()).await
+    {
+        warn!("Failed to record MCP validation result: {}", e);
+    }
+
+    success_response(StatusCode::OK, result)
+}
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    pub format: McpExportFormat,
+}
+
+/// Export enabled servers as a Claude Desktop or Cursor configuration document
+pub async fn export_mcp_servers(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Query(query): Query<ExportQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Exporting MCP servers for project {} as {:?}",
+        project_id, query.format
+    );
+
+    let result = mcp::list_mcp_servers(&db.pool, &project_id)
+        .await
+        .map(|servers| mcp::export_mcp_config(&servers, query.format));
+    ok_or_internal_error(
+        result,
+        &format!("Failed to export MCP servers for project {}", project_id),
+    )
+}
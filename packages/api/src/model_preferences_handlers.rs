@@ -1,5 +1,5 @@
 // ABOUTME: HTTP request handlers for model preferences operations
-// ABOUTME: Handles per-task AI model configuration for Ideate/PRD/Task features
+// ABOUTME: Handles per-task AI model configuration, fallback chains and cost caps for Ideate/PRD/Task features
 
 use axum::{
     extract::{Path, State},
@@ -10,10 +10,18 @@ use axum::{
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
-use super::response::ok_or_internal_error;
+use super::response::{bad_request, ok_or_internal_error};
 use orkee_models::REGISTRY;
 use orkee_projects::DbState;
-use orkee_storage::model_preferences::{ModelPreferences, UpdateTaskModelRequest};
+use orkee_storage::model_preferences::{
+    ModelConfig, ModelPreferences, UpdateTaskModelRequest, UpdateTaskPolicyRequest,
+};
+use orkee_storage::StorageError;
+
+/// Most fallback models a task type can have
+const MAX_FALLBACKS: usize = 5;
+/// Input and output tokens of the smallest call a cost cap must allow
+const REFERENCE_CALL_TOKENS: (u64, u64) = (1_000, 1_000);
 
 /// Get model preferences for a user
 pub async fn get_model_preferences(
@@ -306,3 +314,138 @@ pub async fn update_task_model(
         }
     }
 }
+
+/// Validate a fallback chain and cost cap against the model registry
+fn validate_task_policy(
+    primary: &ModelConfig,
+    request: &UpdateTaskPolicyRequest,
+) -> Result<(), String> {
+    if request.fallbacks.len() > MAX_FALLBACKS {
+        return Err(format!(
+            "At most {} fallback models are allowed",
+            MAX_FALLBACKS
+        ));
+    }
+
+    for fallback in &request.fallbacks {
+        validate_string_length("model", &fallback.model, 100)?;
+        validate_string_length("provider", &fallback.provider, 50)?;
+
+        let model = REGISTRY
+            .get_model(&fallback.model)
+            .ok_or_else(|| format!("Invalid model ID: {}", fallback.model))?;
+        if model.provider != fallback.provider {
+            return Err(format!(
+                "Model {} is served by {}, not {}",
+                fallback.model, model.provider, fallback.provider
+            ));
+        }
+    }
+
+    if let Some(cap) = request.max_cost_per_call {
+        if !cap.is_finite() || cap <= 0.0 {
+            return Err("max_cost_per_call must be a positive number".to_string());
+        }
+
+        // A cap no model in the chain can meet would reject every call
+        let (input_tokens, output_tokens) = REFERENCE_CALL_TOKENS;
+        let cheapest = std::iter::once(primary)
+            .chain(&request.fallbacks)
+            .filter_map(|config| REGISTRY.get_model(&config.model))
+            .map(|model| model.pricing.cost(input_tokens, output_tokens))
+            .fold(f64::INFINITY, f64::min);
+        if cap < cheapest {
+            return Err(format!(
+                "max_cost_per_call ${} is below the cheapest model in the chain (${:.4} for {} input and {} output tokens)",
+                cap, cheapest, input_tokens, output_tokens
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Map task policy errors, answering 400 for unknown task types
+fn task_policy_error(error: StorageError, context: &str) -> axum::response::Response {
+    match error {
+        StorageError::InvalidInput(_) => bad_request(error, context),
+        error => ok_or_internal_error::<(), _>(Err(error), context),
+    }
+}
+
+/// Get the fallback chains and cost caps of every task type
+pub async fn list_task_policies(
+    State(db): State<DbState>,
+    Path(user_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Listing model task policies for user: {}", user_id);
+
+    let result = db
+        .model_preferences_storage
+        .list_task_policies(&user_id)
+        .await;
+    ok_or_internal_error(result, "Failed to list model task policies")
+}
+
+/// Get the fallback chain and cost cap for a task type
+pub async fn get_task_policy(
+    State(db): State<DbState>,
+    Path((user_id, task_type)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Getting {} model task policy for user: {}",
+        task_type, user_id
+    );
+
+    match db
+        .model_preferences_storage
+        .get_task_policy(&user_id, &task_type)
+        .await
+    {
+        Ok(policy) => ok_or_internal_error::<_, StorageError>(Ok(policy), ""),
+        Err(e) => task_policy_error(e, "Failed to get model task policy"),
+    }
+}
+
+/// Replace the fallback chain and cost cap for a task type
+pub async fn update_task_policy(
+    State(db): State<DbState>,
+    Path((user_id, task_type)): Path<(String, String)>,
+    Json(request): Json<UpdateTaskPolicyRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Updating {} model task policy for user: {} ({} fallbacks, cap {:?})",
+        task_type,
+        user_id,
+        request.fallbacks.len(),
+        request.max_cost_per_call
+    );
+
+    let current = match db
+        .model_preferences_storage
+        .get_task_policy(&user_id, &task_type)
+        .await
+    {
+        Ok(policy) => policy,
+        Err(e) => return task_policy_error(e, "Failed to get model task policy"),
+    };
+
+    if let Err(err) = validate_task_policy(&current.primary, &request) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": err
+            })),
+        )
+            .into_response();
+    }
+
+    match db
+        .model_preferences_storage
+        .update_task_policy(&user_id, &task_type, &request)
+        .await
+    {
+        Ok(policy) => ok_or_internal_error::<_, StorageError>(Ok(policy), ""),
+        Err(e) => task_policy_error(e, "Failed to update model task policy"),
+    }
+}
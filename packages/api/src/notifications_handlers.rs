@@ -0,0 +1,74 @@
+// ABOUTME: HTTP request handlers for the notification feed
+// ABOUTME: Lets desktop and dashboard clients poll notifications and send a test notification
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::{bad_request, created_or_internal_error, ok_or_internal_error};
+use orkee_notifications::{
+    NotificationChannel, NotificationEventType, NotificationInput, NotificationSeverity,
+};
+use orkee_projects::DbState;
+use orkee_storage::StorageError;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+#[derive(Deserialize)]
+pub struct ListNotificationsQuery {
+    /// Only notifications routed to this channel (desktop, webhook, tui)
+    pub channel: Option<String>,
+    /// Only notifications created after this RFC 3339 timestamp
+    pub since: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
+/// List notifications newest first
+pub async fn list_notifications(
+    State(db): State<DbState>,
+    Query(query): Query<ListNotificationsQuery>,
+) -> impl IntoResponse {
+    let channel = match query
+        .channel
+        .as_deref()
+        .map(str::parse::<NotificationChannel>)
+        .transpose()
+    {
+        Ok(channel) => channel,
+        Err(e) => return bad_request(e, "Invalid channel"),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let result = db
+        .notifications
+        .storage()
+        .list_notifications(channel, query.since, limit)
+        .await;
+    ok_or_internal_error(result, "Failed to list notifications")
+}
+
+/// Send a test notification to every channel
+pub async fn send_test_notification(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Sending test notification");
+
+    let result = db
+        .notifications
+        .notify(NotificationInput::new(
+            NotificationEventType::Test,
+            NotificationSeverity::Info,
+            "Test notification",
+            "Notifications from Orkee are working",
+        ))
+        .await
+        .and_then(|notification| {
+            notification.ok_or_else(|| {
+                StorageError::Database("No notification channels available".to_string())
+            })
+        });
+    created_or_internal_error(result, "Failed to send test notification")
+}
@@ -282,9 +282,9 @@ pub async fn import_token(
         }
     };
 
-    let result = manager.import_token(oauth_token).await.map(|_| {
-        serde_json::json!({ "message": format!("Successfully imported {} token", provider) })
-    });
+    let result = manager.import_token(oauth_token).await.map(
+        |_| serde_json::json!({ "message": format!("Successfully imported {} token", provider) }),
+    );
 
     ok_or_internal_error(result, "Failed to import token")
 }
@@ -0,0 +1,58 @@
+// ABOUTME: HTTP handlers for OpenSpec spec analysis endpoints
+// ABOUTME: Reports drift between a project's capability specs and its code
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson, Response},
+};
+use std::time::Duration;
+use tokio::time::timeout;
+use tracing::info;
+
+use super::graph_handlers::get_graph_timeout;
+use super::response::{ok_or_internal_error, ApiResponse};
+use orkee_projects::{get_project as manager_get_project, openspec::detect_drift, DbState};
+
+/// Compare the project's OpenSpec requirements with its code symbols and tests
+pub async fn get_spec_drift(
+    State(_db): State<DbState>,
+    Path(project_id): Path<String>,
+) -> Response {
+    info!("Detecting spec drift for project: {}", project_id);
+
+    let project = match manager_get_project(&project_id).await {
+        Ok(Some(project)) => project,
+        Ok(None) => {
+            return (
+                StatusCode::NOT_FOUND,
+                ResponseJson(ApiResponse::<()>::error(format!(
+                    "Project not found: {}",
+                    project_id
+                ))),
+            )
+                .into_response()
+        }
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to fetch project"),
+    };
+
+    // Drift detection builds a symbol graph, so it shares the graph timeout
+    let timeout_secs = get_graph_timeout();
+    let result = timeout(
+        Duration::from_secs(timeout_secs),
+        tokio::task::spawn_blocking(move || detect_drift(&project.project_root, &project.id)),
+    )
+    .await;
+
+    match result {
+        Ok(Ok(result)) => ok_or_internal_error(result, "Failed to detect spec drift"),
+        Ok(Err(e)) => ok_or_internal_error::<(), _>(Err(e), "Spec drift task failed"),
+        Err(_) => ok_or_internal_error::<(), _>(
+            Err(format!(
+                "timed out after {} seconds; increase ORKEE_GRAPH_TIMEOUT_SECS for large projects",
+                timeout_secs
+            )),
+            "Failed to detect spec drift",
+        ),
+    }
+}
@@ -10,6 +10,7 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::response::{created_or_internal_error, ok_or_internal_error, ok_or_not_found};
+use orkee_ideate::CitationTracker;
 use orkee_projects::{
     self as projects,
     pagination::{PaginatedResponse, PaginationParams},
@@ -151,3 +152,25 @@ pub async fn delete_prd(
         ),
     )
 }
+
+/// List the research sources cited by each section of a PRD
+pub async fn get_prd_citations(
+    State(db): State<DbState>,
+    Path((project_id, prd_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Getting citations for PRD: {} in project: {}",
+        prd_id, project_id
+    );
+
+    if let Err(e) = projects::get_prd(&db.pool, &prd_id).await {
+        return ok_or_not_found::<(), _>(
+            Err(e),
+            &format!("PRD {} not found in project {}", prd_id, project_id),
+        );
+    }
+
+    let tracker = CitationTracker::new(db.pool.clone());
+    let result = tracker.list_for_prd(&prd_id).await;
+    ok_or_internal_error(result, "Failed to get PRD citations")
+}
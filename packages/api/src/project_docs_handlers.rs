@@ -0,0 +1,119 @@
+// ABOUTME: HTTP handlers for rendering a project's README and docs/ pages
+// ABOUTME: Returns sanitized HTML and a Markdown AST, and serves images the docs reference
+
+use axum::{
+    extract::{Path, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json as ResponseJson, Response},
+};
+use serde::Deserialize;
+use std::path::PathBuf;
+use tracing::{error, info};
+
+use super::response::{bad_request, ok_or_internal_error, ok_or_not_found, ApiResponse};
+use orkee_projects::get_project as manager_get_project;
+use orkee_projects::readme::{self, DocsError};
+use orkee_storage::StorageError;
+
+#[derive(Debug, Deserialize)]
+pub struct ReadmeQuery {
+    /// Project-relative Markdown file to render instead of the README
+    pub path: Option<String>,
+}
+
+/// GET /api/projects/{project_id}/readme - the README and docs/ index, or
+/// the Markdown file given by `path`
+pub async fn get_readme(
+    Path(project_id): Path<String>,
+    Query(query): Query<ReadmeQuery>,
+) -> Response {
+    let root = match project_root(&project_id).await {
+        Ok(root) => root,
+        Err(response) => return response,
+    };
+    let base_url = format!("/api/projects/{}", project_id);
+
+    match query.path {
+        Some(path) => {
+            info!("Rendering {} for project {}", path, project_id);
+            let result =
+                tokio::task::spawn_blocking(move || readme::render_doc(&root, &path, &base_url))
+                    .await;
+            match result {
+                Ok(Ok(doc)) => ok_or_internal_error::<_, StorageError>(Ok(doc), ""),
+                Ok(Err(e)) => docs_error(e),
+                Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to render document"),
+            }
+        }
+        None => {
+            info!("Rendering README for project {}", project_id);
+            let result =
+                tokio::task::spawn_blocking(move || readme::project_docs(&root, &base_url)).await;
+            match result {
+                Ok(Ok(docs)) => ok_or_internal_error::<_, StorageError>(Ok(docs), ""),
+                Ok(Err(e)) => docs_error(e),
+                Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to render README"),
+            }
+        }
+    }
+}
+
+/// GET /api/projects/{project_id}/docs/assets/{*path} - an image referenced
+/// from the project's Markdown
+pub async fn get_doc_asset(Path((project_id, path)): Path<(String, String)>) -> Response {
+    let root = match project_root(&project_id).await {
+        Ok(root) => root,
+        Err(response) => return response,
+    };
+
+    match tokio::task::spawn_blocking(move || readme::read_asset(&root, &path)).await {
+        Ok(Ok((bytes, content_type))) => (
+            [
+                (header::CONTENT_TYPE, content_type),
+                (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+                // SVGs may carry scripts; never let them run
+                (
+                    header::CONTENT_SECURITY_POLICY,
+                    "default-src 'none'; style-src 'unsafe-inline'; sandbox",
+                ),
+                (header::CACHE_CONTROL, "no-cache"),
+            ],
+            bytes,
+        )
+            .into_response(),
+        Ok(Err(e)) => docs_error(e),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to read asset"),
+    }
+}
+
+async fn project_root(project_id: &str) -> Result<PathBuf, Response> {
+    match manager_get_project(project_id).await {
+        Ok(Some(project)) => Ok(PathBuf::from(project.project_root)),
+        Ok(None) => Err(ok_or_not_found::<(), _>(
+            Err(project_id),
+            "Project not found",
+        )),
+        Err(e) => Err(ok_or_internal_error::<(), _>(
+            Err(e),
+            "Failed to fetch project",
+        )),
+    }
+}
+
+fn docs_error(error: DocsError) -> Response {
+    match error {
+        DocsError::OutsideProject(_) | DocsError::UnsupportedType(_) => {
+            bad_request(error, "Invalid document path")
+        }
+        DocsError::NotFound(_) => ok_or_not_found::<(), _>(Err(error), "Document not found"),
+        DocsError::TooLarge { .. } => (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            ResponseJson(ApiResponse::<()>::error(error.to_string())),
+        )
+            .into_response(),
+        DocsError::Io(e) => {
+            error!("Failed to read project docs: {}", e);
+            ok_or_internal_error::<(), _>(Err(e), "Failed to read project docs")
+        }
+    }
+}
@@ -0,0 +1,209 @@
+// ABOUTME: HTTP handlers for running a project's setup, dev, and cleanup scripts
+// ABOUTME: Streams script output over SSE and exposes each project's run history
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Json as ResponseJson, Response,
+    },
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tracing::{error, info, warn};
+
+use super::response::{bad_request, ok_or_internal_error, ApiResponse};
+use orkee_projects::scripts::{ScriptError, ScriptEvent, ScriptKind};
+use orkee_projects::{get_project as manager_get_project, DbState};
+use orkee_storage::StorageError;
+
+const DEFAULT_HISTORY_LIMIT: i64 = 20;
+const MAX_HISTORY_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct RunScriptQuery {
+    /// Overrides the script kind's default timeout
+    pub timeout_secs: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ScriptRunsQuery {
+    pub kind: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// POST /api/projects/{project_id}/run-script/{kind} - run a script and stream
+/// its output. The run keeps going if the client disconnects.
+pub async fn run_script(
+    State(db): State<DbState>,
+    Path((project_id, kind)): Path<(String, String)>,
+    Query(query): Query<RunScriptQuery>,
+) -> Response {
+    let kind: ScriptKind = match kind.parse() {
+        Ok(kind) => kind,
+        Err(e) => return bad_request(e, "Invalid script kind"),
+    };
+    info!("Running {} script for project {}", kind, project_id);
+
+    let project = match manager_get_project(&project_id).await {
+        Ok(Some(project)) => project,
+        Ok(None) => return project_not_found(&project_id),
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to fetch project"),
+    };
+    let env = load_project_secrets(&db, &project_id).await;
+
+    match db
+        .script_runner
+        .start(&project, kind, query.timeout_secs, env)
+        .await
+    {
+        Ok((_, events)) => event_stream(events).into_response(),
+        Err(e) => script_error(e),
+    }
+}
+
+/// GET /api/projects/{project_id}/script-runs - exit status history, newest first
+pub async fn list_script_runs(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Query(query): Query<ScriptRunsQuery>,
+) -> Response {
+    let kind = match query.kind.as_deref().map(str::parse::<ScriptKind>) {
+        Some(Err(e)) => return bad_request(e, "Invalid script kind"),
+        Some(Ok(kind)) => Some(kind),
+        None => None,
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT);
+
+    match db.script_runner.list_runs(&project_id, kind, limit).await {
+        Ok(runs) => ok_or_internal_error::<_, StorageError>(Ok(runs), ""),
+        Err(e) => script_error(e),
+    }
+}
+
+/// GET /api/projects/{project_id}/script-runs/{run_id}
+pub async fn get_script_run(
+    State(db): State<DbState>,
+    Path((project_id, run_id)): Path<(String, String)>,
+) -> Response {
+    match db.script_runner.get_run(&run_id).await {
+        Ok(run) if run.project_id == project_id => {
+            ok_or_internal_error::<_, StorageError>(Ok(run), "")
+        }
+        Ok(_) => script_error(ScriptError::RunNotFound(run_id)),
+        Err(e) => script_error(e),
+    }
+}
+
+/// GET /api/projects/{project_id}/script-runs/{run_id}/events - reattach to a
+/// run's output. A finished run yields only its `finished` event.
+pub async fn script_run_events(
+    State(db): State<DbState>,
+    Path((project_id, run_id)): Path<(String, String)>,
+) -> Response {
+    match db.script_runner.get_run(&run_id).await {
+        Ok(run) if run.project_id == project_id => {}
+        Ok(_) => return script_error(ScriptError::RunNotFound(run_id)),
+        Err(e) => return script_error(e),
+    }
+
+    if let Some(events) = db.script_runner.subscribe(&run_id) {
+        return event_stream(events).into_response();
+    }
+    // Re-read the run: it may have finished since the lookup above
+    match db.script_runner.get_run(&run_id).await {
+        Ok(run) => {
+            let (tx, rx) = broadcast::channel(1);
+            let _ = tx.send(ScriptEvent::Finished { run });
+            event_stream(rx).into_response()
+        }
+        Err(e) => script_error(e),
+    }
+}
+
+/// POST /api/projects/{project_id}/script-runs/{run_id}/cancel
+pub async fn cancel_script_run(
+    State(db): State<DbState>,
+    Path((project_id, run_id)): Path<(String, String)>,
+) -> Response {
+    match db.script_runner.get_run(&run_id).await {
+        Ok(run) if run.project_id == project_id => {}
+        Ok(_) => return script_error(ScriptError::RunNotFound(run_id)),
+        Err(e) => return script_error(e),
+    }
+
+    match db.script_runner.cancel(&run_id).await {
+        Ok(run) => ok_or_internal_error::<_, StorageError>(Ok(run), ""),
+        Err(e) => script_error(e),
+    }
+}
+
+/// Relay run events to an SSE client. The stream ends after the `finished`
+/// event, when the runner drops the run's channel.
+fn event_stream(
+    events: broadcast::Receiver<ScriptEvent>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = BroadcastStream::new(events).filter_map(|result| match result {
+        Ok(event) => {
+            let json = serde_json::to_string(&event).unwrap_or_default();
+            Some(Ok(Event::default().data(json)))
+        }
+        Err(BroadcastStreamRecvError::Lagged(n)) => {
+            warn!("SSE client lagged {} script output events", n);
+            None
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Decrypt a project's secrets for the script's environment.
+/// Failures are logged and the script runs without them.
+async fn load_project_secrets(db: &DbState, project_id: &str) -> HashMap<String, String> {
+    match db.secret_storage.environment(project_id).await {
+        Ok(secrets) => secrets,
+        Err(e) => {
+            warn!("Failed to load secrets for project {}: {}", project_id, e);
+            HashMap::new()
+        }
+    }
+}
+
+fn project_not_found(project_id: &str) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        ResponseJson(ApiResponse::<()>::error(format!(
+            "Project not found: {}",
+            project_id
+        ))),
+    )
+        .into_response()
+}
+
+fn script_error(error: ScriptError) -> Response {
+    let status = match error {
+        ScriptError::UnknownKind(_)
+        | ScriptError::NotConfigured(_)
+        | ScriptError::InvalidTimeout => return bad_request(error, "Cannot run script"),
+        ScriptError::RunNotFound(_) => StatusCode::NOT_FOUND,
+        ScriptError::AlreadyRunning { .. } | ScriptError::NotRunning(_) => StatusCode::CONFLICT,
+        ScriptError::Spawn(_) | ScriptError::Storage(_) => {
+            error!("Project script failed: {}", error);
+            return ok_or_internal_error::<(), _>(Err(error), "Failed to run script");
+        }
+    };
+    (
+        status,
+        ResponseJson(ApiResponse::<()>::error(error.to_string())),
+    )
+        .into_response()
+}
@@ -49,6 +49,7 @@ impl IntoResponse for StorageErrorResponse {
     fn into_response(self) -> axum::response::Response {
         let (status, message) = match &self.0 {
             StorageError::NotFound => (StatusCode::NOT_FOUND, self.0.to_string()),
+            StorageError::VersionConflict { .. } => (StatusCode::CONFLICT, self.0.to_string()),
             StorageError::Database(_) | StorageError::Sqlx(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Database error".to_string(),
@@ -149,6 +150,37 @@ where
         .into_response()
 }
 
+/// Create a CONFLICT (409) response for a stale write, carrying the entity's
+/// current state so the client can merge and retry against its new version
+pub fn version_conflict<T>(current: T, error: &StorageError) -> axum::response::Response
+where
+    T: serde::Serialize,
+{
+    (
+        StatusCode::CONFLICT,
+        ResponseJson(ApiResponse {
+            success: false,
+            data: Some(current),
+            error: Some(error.to_string()),
+        }),
+    )
+        .into_response()
+}
+
+/// Create a BAD_REQUEST (400) response listing every invalid field, so forms
+/// can show each error next to its input
+pub fn validation_failed(report: &orkee_core::ValidationReport) -> axum::response::Response {
+    (
+        StatusCode::BAD_REQUEST,
+        ResponseJson(ApiResponse {
+            success: false,
+            data: Some(report),
+            error: Some(format!("Validation failed: {}", report)),
+        }),
+    )
+        .into_response()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
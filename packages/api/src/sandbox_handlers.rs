@@ -8,12 +8,18 @@ use axum::{
 };
 use serde::Deserialize;
 use std::collections::HashMap;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 use super::auth::CurrentUser;
-use super::response::ok_or_internal_error;
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
 use orkee_projects::DbState;
-use orkee_sandbox::{CreateSandboxRequest, ProviderSettings, Sandbox, SandboxSettings};
+use orkee_sandbox::{
+    CacheVolume, ConnectionTester, CreateSandboxRequest, ManagerError, ProviderSettings,
+    RetentionPolicyInput, Sandbox, SandboxSettings, SandboxTemplate, PROVIDER_REGISTRY,
+};
+use orkee_storage::StorageError;
 
 /// Get sandbox settings
 pub async fn get_sandbox_settings(State(db): State<DbState>) -> impl IntoResponse {
@@ -123,6 +129,308 @@ pub async fn delete_provider_settings(
     ok_or_internal_error(result, "Failed to delete provider settings")
 }
 
+/// Test a provider's saved settings by calling its API (or Docker for the local
+/// provider) and record the outcome on the provider settings
+pub async fn test_provider_connection(
+    State(db): State<DbState>,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    info!("Testing connection for provider: {}", provider);
+
+    if let Err(e) = PROVIDER_REGISTRY.validate_provider_id(&provider) {
+        return ok_or_not_found::<(), _>(Err(e), "Unknown sandbox provider");
+    }
+
+    let settings = match db.sandbox_settings.get_provider_settings(&provider).await {
+        Ok(settings) => Some(settings),
+        Err(StorageError::Sqlx(sqlx::Error::RowNotFound)) => None,
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to load provider settings"),
+    };
+    let docker = db.sandbox_manager.get_provider("local").await.ok();
+
+    let report = ConnectionTester::new()
+        .test(&provider, settings.as_ref(), docker)
+        .await;
+
+    let error = report
+        .failure
+        .as_ref()
+        .map(|failure| failure.message.as_str());
+    if let Err(e) = db
+        .sandbox_settings
+        .record_provider_validation(&provider, error)
+        .await
+    {
+        warn!("Failed to record connection test for {}: {}", provider, e);
+    }
+
+    ok_or_internal_error::<_, StorageError>(Ok(report), "Failed to test provider connection")
+}
+
+/// Get a registered provider's runtime info, including GPUs available for passthrough
+pub async fn get_provider_info(
+    State(db): State<DbState>,
+    Path(provider): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting runtime info for provider: {}", provider);
+
+    let registered = match db.sandbox_manager.get_provider(&provider).await {
+        Ok(registered) => registered,
+        Err(e) => return ok_or_not_found::<(), _>(Err(e), "Sandbox provider not registered"),
+    };
+
+    let result = registered.get_info().await;
+    ok_or_internal_error(result, "Failed to get provider info")
+}
+
+// ============================================================================
+// EXECUTION TEMPLATE OPERATIONS
+// ============================================================================
+
+/// Request body for creating or updating an execution template
+#[derive(Deserialize)]
+pub struct SandboxTemplateRequest {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub image: String,
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+    #[serde(default)]
+    pub cache_volumes: Vec<CacheVolume>,
+    #[serde(default)]
+    pub setup_commands: Vec<String>,
+}
+
+impl SandboxTemplateRequest {
+    fn into_template(self, id: String) -> SandboxTemplate {
+        let now = chrono::Utc::now();
+        SandboxTemplate {
+            id,
+            name: self.name,
+            description: self.description,
+            image: self.image,
+            env_vars: self.env_vars,
+            cache_volumes: self.cache_volumes,
+            setup_commands: self.setup_commands,
+            is_builtin: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Map template errors to 404 for unknown templates and 400 for rejected ones
+fn template_error_response(error: ManagerError, context: &str) -> axum::response::Response {
+    use orkee_sandbox::StorageError as SandboxStorageError;
+
+    match error {
+        ManagerError::Storage(SandboxStorageError::TemplateNotFound(_)) => {
+            ok_or_not_found::<(), _>(Err(error), context)
+        }
+        ManagerError::Storage(SandboxStorageError::InvalidTemplate(_))
+        | ManagerError::ConfigError(_) => bad_request(error, context),
+        error => ok_or_internal_error::<(), _>(Err(error), context),
+    }
+}
+
+/// List execution templates
+pub async fn list_templates(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Listing sandbox templates");
+
+    let result = db.sandbox_manager.list_templates().await;
+    ok_or_internal_error(result, "Failed to list templates")
+}
+
+/// Get an execution template
+pub async fn get_template(State(db): State<DbState>, Path(id): Path<String>) -> impl IntoResponse {
+    info!("Getting sandbox template: {}", id);
+
+    match db.sandbox_manager.get_template(&id).await {
+        Err(e) => template_error_response(e, "Failed to get template"),
+        result => ok_or_internal_error(result, "Failed to get template"),
+    }
+}
+
+/// Create an execution template
+pub async fn create_template(
+    State(db): State<DbState>,
+    Json(body): Json<SandboxTemplateRequest>,
+) -> impl IntoResponse {
+    info!("Creating sandbox template: {}", body.name);
+
+    let template = body.into_template(String::new());
+    match db.sandbox_manager.create_template(template).await {
+        Err(e) => template_error_response(e, "Failed to create template"),
+        result => created_or_internal_error(result, "Failed to create template"),
+    }
+}
+
+/// Update an execution template
+pub async fn update_template(
+    State(db): State<DbState>,
+    Path(id): Path<String>,
+    Json(body): Json<SandboxTemplateRequest>,
+) -> impl IntoResponse {
+    info!("Updating sandbox template: {}", id);
+
+    let template = body.into_template(id);
+    match db.sandbox_manager.update_template(template).await {
+        Err(e) => template_error_response(e, "Failed to update template"),
+        result => ok_or_internal_error(result, "Failed to update template"),
+    }
+}
+
+/// Delete a user-created execution template
+pub async fn delete_template(
+    State(db): State<DbState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    info!("Deleting sandbox template: {}", id);
+
+    let result = db
+        .sandbox_manager
+        .delete_template(&id)
+        .await
+        .map(|_| serde_json::json!({"message": "Template deleted successfully"}));
+
+    match result {
+        Err(e) => template_error_response(e, "Failed to delete template"),
+        result => ok_or_internal_error(result, "Failed to delete template"),
+    }
+}
+
+// ============================================================================
+// LOG RETENTION
+// ============================================================================
+
+/// Map retention errors to 404 for unknown policies, archives, and sandboxes,
+/// and 400 for rejected policies
+fn retention_error_response(
+    error: orkee_sandbox::StorageError,
+    context: &str,
+) -> axum::response::Response {
+    use orkee_sandbox::StorageError as SandboxStorageError;
+
+    match error {
+        SandboxStorageError::PolicyNotFound(_)
+        | SandboxStorageError::ArchiveNotFound(_)
+        | SandboxStorageError::NotFound(_) => ok_or_not_found::<(), _>(Err(error), context),
+        SandboxStorageError::InvalidPolicy(_) | SandboxStorageError::InvalidStatus(_) => {
+            bad_request(error, context)
+        }
+        error => ok_or_internal_error::<(), _>(Err(error), context),
+    }
+}
+
+/// List execution log retention policies
+pub async fn list_retention_policies(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Listing sandbox retention policies");
+
+    let result = db.log_retention.list_policies().await;
+    ok_or_internal_error(result, "Failed to list retention policies")
+}
+
+/// Create a retention policy
+pub async fn create_retention_policy(
+    State(db): State<DbState>,
+    Json(body): Json<RetentionPolicyInput>,
+) -> impl IntoResponse {
+    info!("Creating sandbox retention policy: {}", body.name);
+
+    match db.log_retention.create_policy(body).await {
+        Err(e) => retention_error_response(e, "Failed to create retention policy"),
+        result => created_or_internal_error(result, "Failed to create retention policy"),
+    }
+}
+
+/// Replace a retention policy
+pub async fn update_retention_policy(
+    State(db): State<DbState>,
+    Path(id): Path<String>,
+    Json(body): Json<RetentionPolicyInput>,
+) -> impl IntoResponse {
+    info!("Updating sandbox retention policy: {}", id);
+
+    match db.log_retention.update_policy(&id, body).await {
+        Err(e) => retention_error_response(e, "Failed to update retention policy"),
+        result => ok_or_internal_error(result, "Failed to update retention policy"),
+    }
+}
+
+/// Delete a retention policy; its archives stay restorable
+pub async fn delete_retention_policy(
+    State(db): State<DbState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    info!("Deleting sandbox retention policy: {}", id);
+
+    let result = db
+        .log_retention
+        .delete_policy(&id)
+        .await
+        .map(|_| serde_json::json!({"message": "Retention policy deleted successfully"}));
+
+    match result {
+        Err(e) => retention_error_response(e, "Failed to delete retention policy"),
+        result => ok_or_internal_error(result, "Failed to delete retention policy"),
+    }
+}
+
+/// Storage used by the executions a policy governs
+pub async fn get_retention_usage(
+    State(db): State<DbState>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    info!("Getting storage usage for retention policy: {}", id);
+
+    match db.log_retention.usage(&id).await {
+        Err(e) => retention_error_response(e, "Failed to get retention usage"),
+        result => ok_or_internal_error(result, "Failed to get retention usage"),
+    }
+}
+
+/// Apply retention policies now instead of waiting for the hourly pass
+pub async fn enforce_retention(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Enforcing sandbox retention policies");
+
+    let result = db.log_retention.enforce().await;
+    ok_or_internal_error(result, "Failed to enforce retention policies")
+}
+
+/// Query parameters for listing archived executions
+#[derive(Deserialize)]
+pub struct ListArchivesQuery {
+    pub sandbox_id: Option<String>,
+}
+
+/// List archived executions
+pub async fn list_execution_archives(
+    State(db): State<DbState>,
+    Query(query): Query<ListArchivesQuery>,
+) -> impl IntoResponse {
+    info!("Listing archived sandbox executions");
+
+    let result = db
+        .log_retention
+        .list_archives(query.sandbox_id.as_deref())
+        .await;
+    ok_or_internal_error(result, "Failed to list archived executions")
+}
+
+/// Restore an archived execution to its sandbox's history
+pub async fn restore_execution_archive(
+    State(db): State<DbState>,
+    Path(execution_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Restoring archived sandbox execution: {}", execution_id);
+
+    match db.log_retention.restore(&execution_id).await {
+        Err(e) => retention_error_response(e, "Failed to restore execution"),
+        result => ok_or_internal_error(result, "Failed to restore execution"),
+    }
+}
+
 // ============================================================================
 // SANDBOX INSTANCE OPERATIONS
 // ============================================================================
@@ -132,6 +440,8 @@ pub async fn delete_provider_settings(
 pub struct CreateSandboxRequestBody {
     pub name: String,
     #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
     pub provider: Option<String>,
     #[serde(default)]
     pub image: Option<String>,
@@ -143,6 +453,9 @@ pub struct CreateSandboxRequestBody {
     pub disk_gb: Option<u32>,
     #[serde(default)]
     pub gpu_type: Option<String>,
+    /// GPUs to pass through; implies a GPU request even without `gpu_type`
+    #[serde(default)]
+    pub gpu_count: Option<u32>,
     #[serde(default)]
     pub network_mode: Option<String>,
     #[serde(default)]
@@ -228,20 +541,38 @@ pub async fn create_sandbox(
         }
     };
 
+    // Project secrets are injected into the container but not stored with it
+    let secret_env_vars = match &body.project_id {
+        Some(project_id) => match db.secret_storage.environment(project_id).await {
+            Ok(secrets) => secrets,
+            Err(e) => {
+                error!("Failed to load secrets for project {}: {}", project_id, e);
+                return ok_or_internal_error::<Sandbox, _>(
+                    Err(e),
+                    "Failed to load project secrets",
+                );
+            }
+        },
+        None => HashMap::new(),
+    };
+
     // Build the sandbox request
     let request = CreateSandboxRequest {
         name: body.name,
         provider: body.provider.unwrap_or(settings.default_provider),
         agent_id: body.agent_id.unwrap_or_else(|| "claude".to_string()),
         user_id: current_user.id.clone(),
-        project_id: None, // TODO: Get from request if available
+        project_id: body.project_id,
         image: body.image,
+        template_id: body.template_id,
         cpu_cores: body.cpu_cores,
         memory_mb: body.memory_mb,
         storage_gb: body.disk_gb,
-        gpu_enabled: body.gpu_type.is_some(),
+        gpu_enabled: body.gpu_type.is_some() || body.gpu_count.is_some(),
         gpu_model: body.gpu_type,
+        gpu_count: body.gpu_count,
         env_vars: body.env_vars.unwrap_or_default(),
+        secret_env_vars,
         volumes: body
             .volumes
             .unwrap_or_default()
@@ -0,0 +1,84 @@
+// ABOUTME: HTTP request handlers for scheduled background jobs
+// ABOUTME: Lists jobs with their last results, updates schedules, and runs jobs on demand
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::{bad_request, ok_or_internal_error, ok_or_not_found};
+use orkee_projects::DbState;
+use orkee_scheduler::JobUpdateInput;
+use orkee_storage::StorageError;
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 50;
+
+fn job_response<T: serde::Serialize>(
+    result: Result<T, StorageError>,
+    error_context: &str,
+) -> axum::response::Response {
+    match result {
+        Err(StorageError::Sqlx(sqlx::Error::RowNotFound)) => {
+            ok_or_not_found(result, "Scheduled job not found")
+        }
+        Err(StorageError::Validation(message)) => bad_request(message, error_context),
+        result => ok_or_internal_error(result, error_context),
+    }
+}
+
+/// List scheduled jobs with their next run time and last result
+pub async fn list_jobs(State(db): State<DbState>) -> impl IntoResponse {
+    let result = db.scheduler.storage().list_jobs().await;
+    ok_or_internal_error(result, "Failed to list scheduled jobs")
+}
+
+/// Get a scheduled job
+pub async fn get_job(State(db): State<DbState>, Path(job_id): Path<String>) -> impl IntoResponse {
+    let result = db.scheduler.storage().get_job(&job_id).await;
+    job_response(result, "Failed to get scheduled job")
+}
+
+/// Enable/disable a job or change its cron expression
+pub async fn update_job(
+    State(db): State<DbState>,
+    Path(job_id): Path<String>,
+    Json(input): Json<JobUpdateInput>,
+) -> impl IntoResponse {
+    info!("Updating scheduled job: {}", job_id);
+
+    let result = db.scheduler.storage().update_job(&job_id, input).await;
+    job_response(result, "Failed to update scheduled job")
+}
+
+/// Run a job immediately and return the finished run
+pub async fn run_job(State(db): State<DbState>, Path(job_id): Path<String>) -> impl IntoResponse {
+    info!("Running scheduled job on demand: {}", job_id);
+
+    let result = db.scheduler.run_job(&job_id).await;
+    job_response(result, "Failed to run scheduled job")
+}
+
+#[derive(Deserialize)]
+pub struct ListRunsQuery {
+    pub limit: Option<i64>,
+}
+
+/// List a job's recent runs newest first
+pub async fn list_runs(
+    State(db): State<DbState>,
+    Path(job_id): Path<String>,
+    Query(query): Query<ListRunsQuery>,
+) -> impl IntoResponse {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let storage = db.scheduler.storage();
+    if let Err(e) = storage.get_job(&job_id).await {
+        return job_response::<()>(Err(e), "Failed to list job runs");
+    }
+    let result = storage.list_runs(&job_id, limit).await;
+    ok_or_internal_error(result, "Failed to list job runs")
+}
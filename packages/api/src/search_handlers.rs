@@ -0,0 +1,166 @@
+// ABOUTME: HTTP request handlers for cross-project search and saved searches
+// ABOUTME: Runs query-language searches over projects and tasks and manages a user's saved searches
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::auth::CurrentUser;
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
+use orkee_projects::DbState;
+use orkee_storage::search::{SavedSearchInput, DEFAULT_SEARCH_LIMIT};
+use orkee_storage::StorageError;
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    /// Query such as `status:active tag:backend priority:high "auth"`
+    #[serde(default)]
+    pub q: String,
+    /// Maximum projects and maximum tasks to return
+    pub limit: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct RunSavedSearchParams {
+    pub limit: Option<i64>,
+}
+
+/// Search projects and tasks with the query language
+pub async fn search(
+    State(db): State<DbState>,
+    Query(params): Query<SearchParams>,
+) -> impl IntoResponse {
+    info!("Searching: {}", params.q);
+
+    let result = db
+        .search_storage
+        .search(&params.q, params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+        .await;
+
+    match result {
+        Err(StorageError::Validation(message)) => bad_request(message, "Invalid search query"),
+        result => ok_or_internal_error(result, "Failed to search"),
+    }
+}
+
+/// List the current user's saved searches in display order
+pub async fn list_saved_searches(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+) -> impl IntoResponse {
+    info!("Listing saved searches for user: {}", current_user.id);
+
+    let result = db.search_storage.list_saved(&current_user.id).await;
+    ok_or_internal_error(result, "Failed to list saved searches")
+}
+
+/// Get one of the current user's saved searches
+pub async fn get_saved_search(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(search_id): Path<String>,
+) -> impl IntoResponse {
+    let result = db
+        .search_storage
+        .get_saved(&current_user.id, &search_id)
+        .await;
+
+    match result {
+        Ok(None) => ok_or_not_found::<(), _>(Err(search_id), "Saved search not found"),
+        result => ok_or_internal_error(result, "Failed to get saved search"),
+    }
+}
+
+/// Save a search for the current user
+pub async fn create_saved_search(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Json(input): Json<SavedSearchInput>,
+) -> impl IntoResponse {
+    info!("Creating saved search: {}", input.name);
+
+    let result = db
+        .search_storage
+        .create_saved(&current_user.id, &input)
+        .await;
+
+    match result {
+        Err(StorageError::Validation(message)) => bad_request(message, "Invalid saved search"),
+        result => created_or_internal_error(result, "Failed to create saved search"),
+    }
+}
+
+/// Replace one of the current user's saved searches
+pub async fn update_saved_search(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(search_id): Path<String>,
+    Json(input): Json<SavedSearchInput>,
+) -> impl IntoResponse {
+    info!("Updating saved search: {}", search_id);
+
+    let result = db
+        .search_storage
+        .update_saved(&current_user.id, &search_id, &input)
+        .await;
+
+    match result {
+        Ok(None) => ok_or_not_found::<(), _>(Err(search_id), "Saved search not found"),
+        Err(StorageError::Validation(message)) => bad_request(message, "Invalid saved search"),
+        result => ok_or_internal_error(result, "Failed to update saved search"),
+    }
+}
+
+/// Delete one of the current user's saved searches
+pub async fn delete_saved_search(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(search_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Deleting saved search: {}", search_id);
+
+    let result = db
+        .search_storage
+        .delete_saved(&current_user.id, &search_id)
+        .await;
+
+    match result {
+        Ok(false) => ok_or_not_found::<(), _>(Err(search_id), "Saved search not found"),
+        result => ok_or_internal_error(
+            result.map(|_| serde_json::json!({ "deleted": true })),
+            "Failed to delete saved search",
+        ),
+    }
+}
+
+/// Run one of the current user's saved searches
+pub async fn run_saved_search(
+    State(db): State<DbState>,
+    current_user: CurrentUser,
+    Path(search_id): Path<String>,
+    Query(params): Query<RunSavedSearchParams>,
+) -> impl IntoResponse {
+    let saved = match db
+        .search_storage
+        .get_saved(&current_user.id, &search_id)
+        .await
+    {
+        Ok(Some(saved)) => saved,
+        Ok(None) => return ok_or_not_found::<(), _>(Err(search_id), "Saved search not found"),
+        Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to get saved search"),
+    };
+
+    info!("Running saved search {}: {}", saved.name, saved.query);
+
+    let result = db
+        .search_storage
+        .search(&saved.query, params.limit.unwrap_or(DEFAULT_SEARCH_LIMIT))
+        .await;
+    ok_or_internal_error(result, "Failed to run saved search")
+}
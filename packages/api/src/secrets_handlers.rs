@@ -0,0 +1,83 @@
+// ABOUTME: HTTP request handlers for per-project secrets
+// ABOUTME: Stores encrypted secret values; responses only ever include secret names and timestamps
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::{bad_request, ok_or_internal_error, ApiResponse};
+use orkee_projects::DbState;
+use orkee_storage::StorageError;
+
+/// Request body for setting a secret value
+#[derive(Deserialize)]
+pub struct SetSecretRequest {
+    pub value: String,
+}
+
+/// List secret names for a project (values are never returned)
+pub async fn list_secrets(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Listing secrets for project: {}", project_id);
+
+    let result = db.secret_storage.list_secrets(&project_id).await;
+    ok_or_internal_error(result, "Failed to list secrets")
+}
+
+/// Create or replace a secret
+pub async fn set_secret(
+    State(db): State<DbState>,
+    Path((project_id, name)): Path<(String, String)>,
+    Json(request): Json<SetSecretRequest>,
+) -> impl IntoResponse {
+    info!("Setting secret {} for project: {}", name, project_id);
+
+    let result = db
+        .secret_storage
+        .set_secret(&project_id, &name, &request.value)
+        .await;
+
+    match result {
+        Err(e @ StorageError::Validation(_)) => bad_request(e, "Invalid secret"),
+        Err(StorageError::Sqlx(sqlx::Error::Database(db_err)))
+            if db_err.is_foreign_key_violation() =>
+        {
+            (
+                StatusCode::NOT_FOUND,
+                ResponseJson(ApiResponse::<()>::error(format!(
+                    "Project not found: {}",
+                    project_id
+                ))),
+            )
+                .into_response()
+        }
+        result => ok_or_internal_error(result, "Failed to set secret"),
+    }
+}
+
+/// Delete a secret
+pub async fn delete_secret(
+    State(db): State<DbState>,
+    Path((project_id, name)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!("Deleting secret {} for project: {}", name, project_id);
+
+    match db.secret_storage.delete_secret(&project_id, &name).await {
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            ResponseJson(ApiResponse::<()>::error(format!(
+                "Secret not found: {}",
+                name
+            ))),
+        )
+            .into_response(),
+        result => ok_or_internal_error(result, "Failed to delete secret"),
+    }
+}
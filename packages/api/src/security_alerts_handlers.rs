@@ -0,0 +1,65 @@
+// ABOUTME: HTTP request handlers for the security alert feed and authentication lockouts
+// ABOUTME: Lists brute-force alerts raised by the API token middleware and lifts IP lockouts
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::auth::CurrentUser;
+use super::response::{ok_or_internal_error, ApiResponse};
+use orkee_projects::DbState;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+#[derive(Deserialize)]
+pub struct ListAlertsQuery {
+    pub limit: Option<i64>,
+}
+
+/// List security alerts newest first
+pub async fn list_alerts(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Query(query): Query<ListAlertsQuery>,
+) -> impl IntoResponse {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let result = db.intrusion_detector.list_alerts(limit).await;
+    ok_or_internal_error(result, "Failed to list security alerts")
+}
+
+/// List IP addresses currently locked out after repeated authentication failures
+pub async fn list_lockouts(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+) -> impl IntoResponse {
+    let result = db.intrusion_detector.list_lockouts().await;
+    ok_or_internal_error(result, "Failed to list lockouts")
+}
+
+/// Lift the lockout of an IP address before it expires
+pub async fn unlock_address(
+    State(db): State<DbState>,
+    _current_user: CurrentUser,
+    Path(ip): Path<String>,
+) -> impl IntoResponse {
+    info!("Lifting authentication lockout of {}", ip);
+
+    match db.intrusion_detector.unlock(&ip).await {
+        Ok(true) => ok_or_internal_error::<_, String>(Ok(()), "Failed to lift lockout"),
+        Ok(false) => (
+            StatusCode::NOT_FOUND,
+            ResponseJson(ApiResponse::<()>::error(format!(
+                "IP address is not locked out: {}",
+                ip
+            ))),
+        )
+            .into_response(),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to lift lockout"),
+    }
+}
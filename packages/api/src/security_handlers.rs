@@ -8,8 +8,9 @@ use tracing::{error, info};
 use super::auth::CurrentUser;
 use super::response::{ok_or_internal_error, ApiResponse};
 use orkee_projects::DbState;
-use orkee_security::encryption::ApiKeyEncryption;
-use orkee_storage::StorageError;
+use orkee_security::encryption::{ApiKeyEncryption, EncryptionMode};
+use orkee_storage::{field_encryption, StorageError};
+use std::sync::Arc;
 
 // Password validation constants
 const MIN_PASSWORD_LENGTH: usize = 8;
@@ -446,49 +447,29 @@ pub async fn set_password(
         }
     };
 
-    // Re-encrypt all API keys with new password-based encryption
+    // Re-encrypt API keys and sensitive fields and save the settings in one transaction
     if let Err(e) = db
-        .user_storage
-        .rotate_encryption_keys(&current_user.id, &old_encryption, &new_encryption)
+        .change_encryption_password_atomic(
+            &current_user.id,
+            &old_encryption,
+            &new_encryption,
+            EncryptionMode::Password,
+            &salt,
+            &password_hash,
+        )
         .await
     {
-        error!("Failed to rotate encryption keys: {}", e);
-        return (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::<()>::error(
-                "Failed to re-encrypt API keys".to_string(),
-            )),
-        )
-            .into_response();
-    }
-
-    // Save encryption settings to database
-    let result = sqlx::query(
-        r#"
-        UPDATE encryption_settings
-        SET encryption_mode = 'password',
-            password_salt = ?,
-            password_hash = ?,
-            updated_at = datetime('now')
-        WHERE id = 1
-        "#,
-    )
-    .bind(&salt)
-    .bind(&password_hash)
-    .execute(&db.pool)
-    .await;
-
-    if let Err(e) = result {
-        error!("Failed to save encryption settings: {}", e);
+        error!("Failed to enable password-based encryption: {}", e);
         return (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ApiResponse::<()>::error(
-                "Failed to save encryption settings".to_string(),
+                "Failed to re-encrypt data with the new password".to_string(),
             )),
         )
             .into_response();
     }
 
+    field_encryption::replace(Arc::new(new_encryption));
     info!("Successfully upgraded to password-based encryption");
 
     let response = serde_json::json!({
@@ -790,6 +771,21 @@ pub async fn change_password(
             .into_response();
     }
 
+    // Re-encrypt sensitive fields within the transaction
+    if let Err(e) =
+        field_encryption::rotate_existing_rows(&mut tx, &old_encryption, &new_encryption).await
+    {
+        error!("Failed to rotate sensitive fields: {}", e);
+        let _ = tx.rollback().await;
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error(
+                "Failed to re-encrypt sensitive fields".to_string(),
+            )),
+        )
+            .into_response();
+    }
+
     // Save new encryption settings within the transaction
     let result = sqlx::query(
         r#"
@@ -829,6 +825,7 @@ pub async fn change_password(
             .into_response();
     }
 
+    field_encryption::replace(Arc::new(new_encryption));
     info!("Successfully changed encryption password");
 
     let response = serde_json::json!({
@@ -1086,6 +1083,21 @@ pub async fn remove_password(
             .into_response();
     }
 
+    // Re-encrypt sensitive fields within the transaction
+    if let Err(e) =
+        field_encryption::rotate_existing_rows(&mut tx, &old_encryption, &new_encryption).await
+    {
+        error!("Failed to rotate sensitive fields: {}", e);
+        let _ = tx.rollback().await;
+        return (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::<()>::error(
+                "Failed to re-encrypt sensitive fields".to_string(),
+            )),
+        )
+            .into_response();
+    }
+
     // Update encryption settings to machine-based within the transaction
     let result = sqlx::query(
         r#"
@@ -1124,6 +1136,7 @@ pub async fn remove_password(
             .into_response();
     }
 
+    field_encryption::replace(Arc::new(new_encryption));
     info!("Successfully downgraded to machine-based encryption");
 
     let response = serde_json::json!({
@@ -2,7 +2,7 @@
 // ABOUTME: Endpoints for breaking down epics into tasks with dependency analysis
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
@@ -270,11 +270,20 @@ pub async fn update_parent_tasks(
     }
 }
 
+/// Query parameters for Phase 2 expansion
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DecomposePhase2Query {
+    /// Store a generated test plan for each subtask
+    #[serde(default)]
+    pub generate_test_plans: bool,
+}
+
 /// POST /api/projects/:project_id/epics/:epic_id/decompose-phase2
 /// Phase 2 of two-phase task generation: Expand parent tasks into detailed subtasks
 pub async fn decompose_phase2(
     State(db): State<DbState>,
     Path((project_id, epic_id)): Path<(String, String)>,
+    Query(query): Query<DecomposePhase2Query>,
 ) -> impl IntoResponse {
     info!(
         "Phase 2: Expanding parent tasks to subtasks for epic {} in project {}",
@@ -323,6 +332,7 @@ pub async fn decompose_phase2(
             &epic_id,
             &parent_tasks,
             codebase_context,
+            query.generate_test_plans,
         )
         .await
     {
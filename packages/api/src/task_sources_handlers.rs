@@ -0,0 +1,171 @@
+// ABOUTME: HTTP request handlers for per-project Linear and Jira task sources
+// ABOUTME: Handles source CRUD and on-demand imports of tracker issues into tasks
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use super::response::ApiResponse;
+use orkee_projects::DbState;
+use orkee_storage::StorageError;
+use orkee_task_sources::{TaskSourceCreateInput, TaskSourceError, TaskSourceUpdateInput};
+
+/// Convert task source errors to HTTP responses
+fn task_source_error_to_response(error: TaskSourceError) -> axum::response::Response {
+    let (status, message) = match &error {
+        TaskSourceError::NotFound => (StatusCode::NOT_FOUND, error.to_string()),
+        TaskSourceError::InvalidInput(_) => (StatusCode::BAD_REQUEST, error.to_string()),
+        TaskSourceError::Provider { .. } => (StatusCode::BAD_GATEWAY, error.to_string()),
+        TaskSourceError::Storage(StorageError::Sqlx(sqlx::Error::Database(db_err)))
+            if db_err.is_unique_violation() =>
+        {
+            (
+                StatusCode::CONFLICT,
+                "This tracker project is already configured as a task source".to_string(),
+            )
+        }
+        _ => {
+            error!("Task source storage error: {:?}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Database error".to_string(),
+            )
+        }
+    };
+
+    (status, ResponseJson(ApiResponse::<()>::error(message))).into_response()
+}
+
+/// Wrap a successful payload with the given status
+fn success_response<T: Serialize>(status: StatusCode, data: T) -> axum::response::Response {
+    (status, ResponseJson(ApiResponse::success(data))).into_response()
+}
+
+/// List the task sources configured for a project
+pub async fn list_task_sources(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Listing task sources for project: {}", project_id);
+
+    match db.task_sources.storage().list_sources(&project_id).await {
+        Ok(sources) => success_response(StatusCode::OK, sources),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
+
+/// Get a single task source
+pub async fn get_task_source(
+    State(db): State<DbState>,
+    Path((project_id, source_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    match db
+        .task_sources
+        .storage()
+        .get_source(&project_id, &source_id)
+        .await
+    {
+        Ok(source) => success_response(StatusCode::OK, source),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
+
+/// Configure a Linear team or Jira project as a task source
+pub async fn create_task_source(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Json(input): Json<TaskSourceCreateInput>,
+) -> impl IntoResponse {
+    info!(
+        "Creating {} task source '{}' for project: {}",
+        input.provider.as_str(),
+        input.external_project,
+        project_id
+    );
+
+    match db
+        .task_sources
+        .storage()
+        .create_source(&project_id, input)
+        .await
+    {
+        Ok(source) => success_response(StatusCode::CREATED, source),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
+
+/// Update a task source's connection, filter, or status mapping
+pub async fn update_task_source(
+    State(db): State<DbState>,
+    Path((project_id, source_id)): Path<(String, String)>,
+    Json(input): Json<TaskSourceUpdateInput>,
+) -> impl IntoResponse {
+    info!(
+        "Updating task source {} for project: {}",
+        source_id, project_id
+    );
+
+    match db
+        .task_sources
+        .storage()
+        .update_source(&project_id, &source_id, input)
+        .await
+    {
+        Ok(source) => success_response(StatusCode::OK, source),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
+
+/// Delete a task source. Tasks it imported are kept.
+pub async fn delete_task_source(
+    State(db): State<DbState>,
+    Path((project_id, source_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Deleting task source {} from project: {}",
+        source_id, project_id
+    );
+
+    match db
+        .task_sources
+        .storage()
+        .delete_source(&project_id, &source_id)
+        .await
+    {
+        Ok(()) => success_response(StatusCode::OK, "Task source deleted successfully"),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ImportQuery {
+    /// Re-fetch every issue instead of only those updated since the last import
+    #[serde(default)]
+    pub full: bool,
+}
+
+/// Import issues from a task source now
+pub async fn import_task_source(
+    State(db): State<DbState>,
+    Path((project_id, source_id)): Path<(String, String)>,
+    Query(query): Query<ImportQuery>,
+) -> impl IntoResponse {
+    info!(
+        "Importing task source {} for project {} (full: {})",
+        source_id, project_id, query.full
+    );
+
+    match db
+        .task_sources
+        .import_source(&project_id, &source_id, query.full)
+        .await
+    {
+        Ok(summary) => success_response(StatusCode::OK, summary),
+        Err(e) => task_source_error_to_response(e),
+    }
+}
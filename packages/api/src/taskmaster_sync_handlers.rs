@@ -0,0 +1,124 @@
+// ABOUTME: HTTP request handlers for two-way sync between Orkee tasks and .taskmaster/tasks/tasks.json
+// ABOUTME: Handles running a sync, listing the conflicts it records, and resolving them
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json as ResponseJson},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use tracing::{error, info};
+
+use super::response::ApiResponse;
+use orkee_projects::DbState;
+use orkee_storage::StorageError;
+use orkee_task_sources::taskmaster::{ConflictResolution, ConflictStatus, SyncedFields};
+use orkee_task_sources::{MergeStrategy, TaskSourceError};
+
+/// Convert Taskmaster sync errors to HTTP responses
+fn sync_error_to_response(error: TaskSourceError) -> axum::response::Response {
+    let (status, message) = match &error {
+        TaskSourceError::NotFound
+        | TaskSourceError::Storage(StorageError::Sqlx(sqlx::Error::RowNotFound)) => {
+            (StatusCode::NOT_FOUND, "Conflict not found".to_string())
+        }
+        TaskSourceError::InvalidInput(_) => (StatusCode::BAD_REQUEST, error.to_string()),
+        TaskSourceError::ConcurrentEdit(_) => (StatusCode::CONFLICT, error.to_string()),
+        _ => {
+            error!("Taskmaster sync error: {:?}", error);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Database error".to_string(),
+            )
+        }
+    };
+
+    (status, ResponseJson(ApiResponse::<()>::error(message))).into_response()
+}
+
+/// Wrap a successful payload with the given status
+fn success_response<T: Serialize>(status: StatusCode, data: T) -> axum::response::Response {
+    (status, ResponseJson(ApiResponse::success(data))).into_response()
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncRequest {
+    pub project_id: String,
+    /// How to settle tasks edited on both sides; defaults to recording a conflict
+    #[serde(default)]
+    pub strategy: MergeStrategy,
+}
+
+/// Sync a project's tasks with its tasks.json in both directions
+pub async fn sync_taskmaster(
+    State(db): State<DbState>,
+    Json(request): Json<SyncRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Syncing tasks.json for project {} ({:?})",
+        request.project_id, request.strategy
+    );
+
+    match db
+        .taskmaster_sync
+        .sync(&request.project_id, request.strategy)
+        .await
+    {
+        Ok(report) => success_response(StatusCode::OK, report),
+        Err(e) => sync_error_to_response(e),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConflictsQuery {
+    pub project_id: String,
+    pub status: Option<ConflictStatus>,
+}
+
+/// List a project's sync conflicts, newest first
+pub async fn list_conflicts(
+    State(db): State<DbState>,
+    Query(query): Query<ConflictsQuery>,
+) -> impl IntoResponse {
+    match db
+        .taskmaster_sync
+        .storage()
+        .list_conflicts(&query.project_id, query.status)
+        .await
+    {
+        Ok(conflicts) => success_response(StatusCode::OK, conflicts),
+        Err(e) => sync_error_to_response(e),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ResolveConflictRequest {
+    pub resolution: ConflictResolution,
+    /// Fields both sides take for a custom resolution
+    pub fields: Option<SyncedFields>,
+}
+
+/// Resolve a conflict by writing the chosen version to Orkee and tasks.json
+pub async fn resolve_conflict(
+    State(db): State<DbState>,
+    Path(conflict_id): Path<String>,
+    Json(request): Json<ResolveConflictRequest>,
+) -> impl IntoResponse {
+    info!(
+        "Resolving Taskmaster conflict {} with {}",
+        conflict_id,
+        request.resolution.as_str()
+    );
+
+    match db
+        .taskmaster_sync
+        .resolve_conflict(&conflict_id, request.resolution, request.fields)
+        .await
+    {
+        Ok(conflict) => success_response(StatusCode::OK, conflict),
+        Err(e) => sync_error_to_response(e),
+    }
+}
@@ -3,20 +3,27 @@
 
 use axum::{
     extract::{Path, Query, State},
+    http::HeaderMap,
     response::IntoResponse,
     Json,
 };
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
-use tracing::info;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
 
 use super::auth::CurrentUser;
-use super::response::{created_or_internal_error, ok_or_internal_error};
-use orkee_ideate::{AppendProgressInput, ExecutionTracker};
+use super::etag::{check_if_match, conditional_get, with_etag, ETagBuilder};
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found, version_conflict,
+};
+use orkee_ideate::{AppendProgressInput, CheckpointRunner, ExecutionTracker};
 use orkee_projects::pagination::{PaginatedResponse, PaginationParams};
 use orkee_projects::DbState;
-use orkee_tasks::{TaskCreateInput, TaskPriority, TaskStatus, TaskUpdateInput};
-use serde::Serialize;
+use orkee_tasks::parse::{parse_task_text, ParsedTask};
+use orkee_tasks::{
+    StorageError, Task, TaskCreateInput, TaskPriority, TaskStatus, TaskStepCreateInput,
+    TaskStepStatus, TaskStepUpdateInput, TaskTestPlanInput, TaskUpdateInput,
+};
 
 /// Helper function to parse ISO 8601 date string
 fn parse_due_date(date_str: &str) -> Option<DateTime<Utc>> {
@@ -25,11 +32,42 @@ fn parse_due_date(date_str: &str) -> Option<DateTime<Utc>> {
         .map(|dt| dt.with_timezone(&Utc))
 }
 
+/// Adds a task to an ETag. Step progress and CI checks are tracked outside
+/// `updated_at`, so they are mixed in to keep cached copies honest.
+fn tag_task(builder: ETagBuilder, task: &Task) -> ETagBuilder {
+    builder
+        .entity(&task.id, &task.updated_at)
+        .extra(task.steps_completed)
+        .extra(task.steps_total)
+        .extra(
+            task.checks_updated_at
+                .map(|at| at.to_rfc3339())
+                .unwrap_or_default(),
+        )
+}
+
+fn task_etag(task: &Task) -> String {
+    tag_task(ETagBuilder::new(), task).finish()
+}
+
+fn task_list_etag(tasks: &[Task], total: i64) -> String {
+    tasks
+        .iter()
+        .fold(ETagBuilder::new(), tag_task)
+        .extra(total)
+        .finish()
+}
+
 /// List all tasks for a project
+///
+/// Pages by `page` by default; passing `cursor` (empty for the first page)
+/// switches to cursor pagination ordered by creation time. Responses carry an
+/// ETag, and `If-None-Match` yields 304 when the page is unchanged.
 pub async fn list_tasks(
     State(db): State<DbState>,
     Path(project_id): Path<String>,
     Query(pagination): Query<PaginationParams>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
     info!(
         "Listing tasks for project: {} (page: {})",
@@ -37,6 +75,29 @@ pub async fn list_tasks(
         pagination.page()
     );
 
+    if pagination.is_cursor_mode() {
+        let after = match pagination.keyset_position() {
+            Ok(after) => after,
+            Err(e) => return bad_request(e, "Failed to list tasks"),
+        };
+        let page = db
+            .task_storage
+            .list_tasks_after(&project_id, after.as_ref(), pagination.limit())
+            .await;
+        let etag = page
+            .as_ref()
+            .ok()
+            .map(|page| task_list_etag(&page.items, page.total));
+        let response = ok_or_internal_error(
+            page.map(|page| PaginatedResponse::from_keyset(page, &pagination)),
+            "Failed to list tasks",
+        );
+        return match etag {
+            Some(etag) => conditional_get(&headers, &etag, response),
+            None => response,
+        };
+    }
+
     let result = db
         .task_storage
         .list_tasks_paginated(
@@ -44,21 +105,36 @@ pub async fn list_tasks(
             Some(pagination.limit()),
             Some(pagination.offset()),
         )
-        .await
-        .map(|(tasks, total)| PaginatedResponse::new(tasks, &pagination, total));
-
-    ok_or_internal_error(result, "Failed to list tasks")
+        .await;
+    let etag = result
+        .as_ref()
+        .ok()
+        .map(|(tasks, total)| task_list_etag(tasks, *total));
+    let response = ok_or_internal_error(
+        result.map(|(tasks, total)| PaginatedResponse::new(tasks, &pagination, total)),
+        "Failed to list tasks",
+    );
+    match etag {
+        Some(etag) => conditional_get(&headers, &etag, response),
+        None => response,
+    }
 }
 
-/// Get a single task by ID
+/// Get a single task by ID, answering `If-None-Match` with 304 when unchanged
 pub async fn get_task(
     State(db): State<DbState>,
     Path((_project_id, task_id)): Path<(String, String)>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
     info!("Getting task: {}", task_id);
 
     let result = db.task_storage.get_task(&task_id).await;
-    ok_or_internal_error(result, "Failed to get task")
+    let etag = result.as_ref().ok().map(task_etag);
+    let response = ok_or_internal_error(result, "Failed to get task");
+    match etag {
+        Some(etag) => conditional_get(&headers, &etag, response),
+        None => response,
+    }
 }
 
 /// Request body for creating a task
@@ -146,6 +222,112 @@ pub async fn create_task(
     created_or_internal_error(result, "Failed to create task")
 }
 
+/// Model preference used to read free-text task descriptions
+const TASK_PARSE_MODEL_TASK: &str = "task_analysis";
+/// Longest free-text description accepted for parsing
+const MAX_PARSE_TEXT_LEN: usize = 2000;
+
+/// Request body for parsing a free-text task description
+#[derive(Deserialize)]
+pub struct ParseTaskRequest {
+    pub text: String,
+    /// Skip the model and use only the built-in parser
+    #[serde(default, rename = "parserOnly")]
+    pub parser_only: bool,
+}
+
+/// A task preview parsed from free text. Nothing is created until the client
+/// sends `task` to `POST /api/projects/{project_id}/tasks`.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParseTaskResponse {
+    pub task: ParsedTask,
+    /// `ai` when a model read the text, `parser` when the built-in parser did
+    pub source: &'static str,
+    /// Why the built-in parser was used instead of the model
+    pub fallback_reason: Option<String>,
+}
+
+/// Turn text like "fix login redirect bug, high priority, tag auth, due
+/// Friday" into task fields. The user's task analysis model reads it through
+/// the AI proxy; the built-in parser fills gaps and takes over when no model
+/// is available or its reply is unusable.
+pub async fn parse_task(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    current_user: CurrentUser,
+    Json(request): Json<ParseTaskRequest>,
+) -> impl IntoResponse {
+    let text = request.text.trim();
+    if text.is_empty() {
+        return bad_request("text is required", "Failed to parse task");
+    }
+    if text.len() > MAX_PARSE_TEXT_LEN {
+        return bad_request(
+            format!("text exceeds {} characters", MAX_PARSE_TEXT_LEN),
+            "Failed to parse task",
+        );
+    }
+    info!("Parsing task text for project: {}", project_id);
+
+    let today = Utc::now().date_naive();
+    let parsed = parse_task_text(text, today);
+
+    let model_result = if request.parser_only {
+        None
+    } else {
+        let system = format!(
+            "You turn a short task description into JSON for a project task tracker. \
+             Today is {} ({}). Reply with only a JSON object with these keys: \
+             \"title\" (short imperative summary), \"description\" (extra detail or null), \
+             \"priority\" (\"low\", \"medium\", \"high\", \"critical\", or null), \
+             \"tags\" (array of lowercase strings), \"dueDate\" (YYYY-MM-DD or null), \
+             \"estimatedHours\" (number or null). Only fill fields the text mentions.",
+            today,
+            today.format("%A")
+        );
+        let reply = super::ai_proxy_handlers::complete_text(
+            &db,
+            &current_user.id,
+            TASK_PARSE_MODEL_TASK,
+            &system,
+            text,
+            512,
+        )
+        .await;
+        Some(reply.and_then(|reply| ParsedTask::from_model_reply(&reply)))
+    };
+
+    let response = match model_result {
+        Some(Ok(mut task)) => {
+            task.fill_from(parsed);
+            ParseTaskResponse {
+                task,
+                source: "ai",
+                fallback_reason: None,
+            }
+        }
+        Some(Err(reason)) => {
+            warn!("Falling back to the built-in task parser: {}", reason);
+            ParseTaskResponse {
+                task: parsed,
+                source: "parser",
+                fallback_reason: Some(reason),
+            }
+        }
+        None => ParseTaskResponse {
+            task: parsed,
+            source: "parser",
+            fallback_reason: None,
+        },
+    };
+
+    if response.task.title.is_empty() {
+        return bad_request("no task title found in text", "Failed to parse task");
+    }
+    ok_or_internal_error::<_, StorageError>(Ok(response), "")
+}
+
 /// Request body for updating a task
 #[derive(Deserialize)]
 pub struct UpdateTaskRequest {
@@ -172,16 +354,59 @@ pub struct UpdateTaskRequest {
     pub acceptance_criteria: Option<String>,
     pub tags: Option<Vec<String>>,
     pub category: Option<String>,
+    /// Version of the task the client last read
+    pub version: Option<i64>,
 }
 
 /// Update an existing task
+///
+/// The request must carry the task `version` the client last read; if the task
+/// has changed since, the update is rejected with 409 and the current task. With
+/// `If-Match`, it is likewise rejected with 412 on a stale ETag.
 pub async fn update_task(
     State(db): State<DbState>,
     Path((_project_id, task_id)): Path<(String, String)>,
+    current_user: CurrentUser,
+    headers: HeaderMap,
     Json(request): Json<UpdateTaskRequest>,
 ) -> impl IntoResponse {
     info!("Updating task: {}", task_id);
 
+    if headers.contains_key(axum::http::header::IF_MATCH) {
+        match db.task_storage.get_task(&task_id).await {
+            Ok(current) => {
+                if let Some(rejected) = check_if_match(&headers, &task_etag(&current)) {
+                    return rejected;
+                }
+            }
+            Err(e) => return ok_or_not_found(Err::<Task, _>(e), "Failed to update task"),
+        }
+    }
+
+    let Some(expected_version) = request.version else {
+        return bad_request(
+            "version is required",
+            "Failed to update task; send the version of the task being edited",
+        );
+    };
+
+    if matches!(request.status, Some(TaskStatus::InProgress)) {
+        let runner = CheckpointRunner::new(db.pool.clone());
+        match runner.blocking_checkpoints(&task_id).await {
+            Ok(blocked_by) if !blocked_by.is_empty() => {
+                return bad_request(
+                    format!(
+                        "waiting on checkpoint validation: {}",
+                        blocked_by.join(", ")
+                    ),
+                    "Failed to start task; its parallel group is gated",
+                );
+            }
+            Ok(_) => {}
+            Err(e) => return ok_or_internal_error(Err::<(), _>(e), "Failed to update task"),
+        }
+    }
+
     let due_date = request.due_date.as_deref().and_then(parse_due_date);
 
     let input = TaskUpdateInput {
@@ -210,10 +435,58 @@ pub async fn update_task(
         technical_details: None,
         effort_hours: None,
         can_parallel: None,
+        expected_version: Some(expected_version),
     };
 
-    let result = db.task_storage.update_task(&task_id, input).await;
-    ok_or_internal_error(result, "Failed to update task")
+    let result = db
+        .task_storage
+        .update_task_as(&task_id, Some(&current_user.id), input)
+        .await;
+    if let Err(conflict @ StorageError::VersionConflict { .. }) = &result {
+        if let Ok(current) = db.task_storage.get_task(&task_id).await {
+            return version_conflict(current, conflict);
+        }
+    }
+    let etag = result.as_ref().ok().map(task_etag);
+    let response = ok_or_internal_error(result, "Failed to update task");
+    match etag {
+        Some(etag) => with_etag(response, &etag),
+        None => response,
+    }
+}
+
+/// List status, assignee, and priority changes for a task, newest first
+pub async fn list_task_activity(
+    State(db): State<DbState>,
+    Path((_project_id, task_id)): Path<(String, String)>,
+    Query(pagination): Query<PaginationParams>,
+) -> impl IntoResponse {
+    info!("Listing activity for task: {}", task_id);
+
+    let result = db
+        .task_storage
+        .list_task_activity(&task_id, pagination.limit(), pagination.offset())
+        .await
+        .map(|(activity, total)| PaginatedResponse::new(activity, &pagination, total));
+
+    ok_or_internal_error(result, "Failed to list task activity")
+}
+
+/// Activity feed across all tasks in a project, newest first
+pub async fn list_project_activity(
+    State(db): State<DbState>,
+    Path(project_id): Path<String>,
+    Query(pagination): Query<PaginationParams>,
+) -> impl IntoResponse {
+    info!("Listing task activity for project: {}", project_id);
+
+    let result = db
+        .task_storage
+        .list_project_activity(&project_id, pagination.limit(), pagination.offset())
+        .await
+        .map(|(activity, total)| PaginatedResponse::new(activity, &pagination, total));
+
+    ok_or_internal_error(result, "Failed to list project activity")
 }
 
 /// Delete a task
@@ -232,84 +505,180 @@ pub async fn delete_task(
     ok_or_internal_error(result, "Failed to delete task")
 }
 
-/// Response for task execution steps
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
-pub struct TaskStep {
-    step_number: usize,
-    action: String,
-    test_command: Option<String>,
-    expected_output: String,
-    estimated_minutes: u8,
+fn tdd_step(
+    action: &str,
+    test_command: Option<&str>,
+    expected_output: &str,
+    minutes: i32,
+) -> TaskStepCreateInput {
+    TaskStepCreateInput {
+        action: action.to_string(),
+        test_command: test_command.map(str::to_string),
+        expected_output: Some(expected_output.to_string()),
+        estimated_minutes: Some(minutes),
+    }
 }
 
-/// Generate TDD execution steps for a task
+/// Query parameters for step generation
+#[derive(Deserialize)]
+pub struct GenerateStepsQuery {
+    /// Discard existing steps (and their progress) and generate a new checklist
+    #[serde(default)]
+    pub regenerate: bool,
+}
+
+/// Generate TDD execution steps for a task and store them as its checklist.
+///
+/// Existing steps are returned unchanged unless `?regenerate=true` is passed,
+/// so generating twice doesn't wipe recorded progress.
 pub async fn generate_task_steps(
-    State(_db): State<DbState>,
+    State(db): State<DbState>,
     Path((_project_id, task_id)): Path<(String, String)>,
+    Query(query): Query<GenerateStepsQuery>,
 ) -> impl IntoResponse {
     info!("Generating execution steps for task: {}", task_id);
 
+    if let Err(e) = db.task_storage.get_task(&task_id).await {
+        return ok_or_not_found::<(), _>(Err(e), "Task not found");
+    }
+
+    if !query.regenerate {
+        match db.task_storage.list_task_steps(&task_id).await {
+            Ok(steps) if !steps.is_empty() => {
+                return ok_or_internal_error::<_, StorageError>(Ok(steps), "Failed to get steps")
+            }
+            Ok(_) => {}
+            Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to get steps"),
+        }
+    }
+
     // This is a placeholder - in a real implementation, this would use AI to generate
     // execution steps based on the task description
     // For now, we return a standard TDD workflow
 
     let steps = vec![
-        TaskStep {
-            step_number: 1,
-            action: "Write failing test for the functionality".to_string(),
-            test_command: Some("cargo test <test_name>".to_string()),
-            expected_output: "Test fails as expected".to_string(),
-            estimated_minutes: 5,
-        },
-        TaskStep {
-            step_number: 2,
-            action: "Create minimal implementation stub".to_string(),
-            test_command: None,
-            expected_output: "Function signature created".to_string(),
-            estimated_minutes: 3,
-        },
-        TaskStep {
-            step_number: 3,
-            action: "Verify test still fails correctly".to_string(),
-            test_command: Some("cargo test <test_name>".to_string()),
-            expected_output: "Test fails with correct assertion message".to_string(),
-            estimated_minutes: 2,
-        },
-        TaskStep {
-            step_number: 4,
-            action: "Implement core functionality".to_string(),
-            test_command: None,
-            expected_output: "Implementation complete".to_string(),
-            estimated_minutes: 15,
-        },
-        TaskStep {
-            step_number: 5,
-            action: "Run test to verify success".to_string(),
-            test_command: Some("cargo test <test_name>".to_string()),
-            expected_output: "Test passes".to_string(),
-            estimated_minutes: 2,
-        },
-        TaskStep {
-            step_number: 6,
-            action: "Refactor if needed".to_string(),
-            test_command: Some("cargo test".to_string()),
-            expected_output: "All tests still pass".to_string(),
-            estimated_minutes: 5,
-        },
-        TaskStep {
-            step_number: 7,
-            action: "Commit changes".to_string(),
-            test_command: Some("git add . && git commit -m 'message'".to_string()),
-            expected_output: "Changes committed".to_string(),
-            estimated_minutes: 2,
-        },
+        tdd_step(
+            "Write failing test for the functionality",
+            Some("cargo test <test_name>"),
+            "Test fails as expected",
+            5,
+        ),
+        tdd_step(
+            "Create minimal implementation stub",
+            None,
+            "Function signature created",
+            3,
+        ),
+        tdd_step(
+            "Verify test still fails correctly",
+            Some("cargo test <test_name>"),
+            "Test fails with correct assertion message",
+            2,
+        ),
+        tdd_step(
+            "Implement core functionality",
+            None,
+            "Implementation complete",
+            15,
+        ),
+        tdd_step(
+            "Run test to verify success",
+            Some("cargo test <test_name>"),
+            "Test passes",
+            2,
+        ),
+        tdd_step(
+            "Refactor if needed",
+            Some("cargo test"),
+            "All tests still pass",
+            5,
+        ),
+        tdd_step(
+            "Commit changes",
+            Some("git add . && git commit -m 'message'"),
+            "Changes committed",
+            2,
+        ),
     ];
 
-    ok_or_internal_error::<Vec<TaskStep>, orkee_storage::StorageError>(
-        Ok(steps),
-        "Failed to generate execution steps",
-    )
+    let result = db.task_storage.replace_task_steps(&task_id, steps).await;
+    ok_or_internal_error(result, "Failed to generate execution steps")
+}
+
+/// List the execution checklist for a task
+pub async fn list_task_steps(
+    State(db): State<DbState>,
+    Path((_project_id, task_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!("Listing steps for task: {}", task_id);
+
+    let result = db.task_storage.list_task_steps(&task_id).await;
+    ok_or_internal_error(result, "Failed to list task steps")
+}
+
+/// Request body for updating a checklist step
+#[derive(Deserialize)]
+pub struct UpdateTaskStepRequest {
+    pub status: Option<TaskStepStatus>,
+    pub notes: Option<String>,
+}
+
+/// Update a checklist step's status or notes
+pub async fn update_task_step(
+    State(db): State<DbState>,
+    Path((_project_id, task_id, step_id)): Path<(String, String, String)>,
+    Json(request): Json<UpdateTaskStepRequest>,
+) -> impl IntoResponse {
+    info!("Updating step {} for task: {}", step_id, task_id);
+
+    let input = TaskStepUpdateInput {
+        status: request.status,
+        notes: request.notes,
+    };
+
+    let result = db
+        .task_storage
+        .update_task_step(&task_id, &step_id, input)
+        .await;
+    if matches!(result, Err(StorageError::Sqlx(sqlx::Error::RowNotFound))) {
+        return ok_or_not_found(result, "Task step not found");
+    }
+    ok_or_internal_error(result, "Failed to update task step")
+}
+
+/// Get the test plan an agent should verify before finishing the task
+pub async fn get_task_test_plan(
+    State(db): State<DbState>,
+    Path((_project_id, task_id)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!("Getting test plan for task: {}", task_id);
+
+    match db.task_storage.get_task_test_plan(&task_id).await {
+        Ok(Some(plan)) => {
+            ok_or_internal_error::<_, StorageError>(Ok(plan), "Failed to get test plan")
+        }
+        Ok(None) => ok_or_not_found::<(), _>(
+            Err(format!("No test plan for task {}", task_id)),
+            "Test plan not found",
+        ),
+        Err(e) => ok_or_internal_error::<(), _>(Err(e), "Failed to get test plan"),
+    }
+}
+
+/// Create or replace a task's test plan
+pub async fn save_task_test_plan(
+    State(db): State<DbState>,
+    Path((_project_id, task_id)): Path<(String, String)>,
+    Json(input): Json<TaskTestPlanInput>,
+) -> impl IntoResponse {
+    info!("Saving test plan for task: {}", task_id);
+
+    if let Err(e) = db.task_storage.get_task(&task_id).await {
+        return ok_or_not_found::<(), _>(Err(e), "Task not found");
+    }
+
+    let result = db.task_storage.save_task_test_plan(&task_id, input).await;
+    ok_or_internal_error(result, "Failed to save test plan")
 }
 
 /// Append progress to a task (append-only)
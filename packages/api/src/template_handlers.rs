@@ -7,12 +7,16 @@ use axum::{
     Json,
 };
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use sqlx::SqlitePool;
 use tracing::info;
 
 use super::response::{
     bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
 };
+use orkee_ideate::{
+    render_template, validate_template, with_default_variables, TemplateError, TEMPLATE_VARIABLES,
+};
 use orkee_projects::DbState;
 
 /// PRD output template structure
@@ -46,6 +50,44 @@ pub struct UpdateTemplateRequest {
     pub is_default: Option<bool>,
 }
 
+/// Request body for checking template syntax without saving it
+#[derive(Deserialize)]
+pub struct ValidateTemplateRequest {
+    pub content: String,
+}
+
+/// Result of validating a template
+#[derive(Serialize)]
+pub struct ValidateTemplateResponse {
+    pub valid: bool,
+    pub errors: Vec<TemplateError>,
+    pub variables: &'static [&'static str],
+}
+
+/// Request body for rendering a template
+#[derive(Deserialize)]
+pub struct RenderTemplateRequest {
+    #[serde(default)]
+    pub variables: Value,
+    /// Used to fill `project_name` when the caller does not provide it
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+/// Rendered template output
+#[derive(Serialize)]
+pub struct RenderTemplateResponse {
+    pub content: String,
+}
+
+fn join_template_errors(errors: &[TemplateError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 /// List all PRD output templates
 pub async fn list_templates(State(db): State<DbState>) -> impl IntoResponse {
     info!("Listing all PRD output templates");
@@ -81,6 +123,11 @@ pub async fn create_template(
         return bad_request("Content cannot be empty", "Invalid template content");
     }
 
+    let errors = validate_template(&request.content);
+    if !errors.is_empty() {
+        return bad_request(join_template_errors(&errors), "Invalid template content");
+    }
+
     // Generate ID
     let template_id = format!("template-{}", chrono::Utc::now().timestamp_millis());
 
@@ -128,6 +175,11 @@ pub async fn update_template(
         if content.trim().is_empty() {
             return bad_request("Content cannot be empty", "Invalid template content");
         }
+
+        let errors = validate_template(content);
+        if !errors.is_empty() {
+            return bad_request(join_template_errors(&errors), "Invalid template content");
+        }
     }
 
     // If setting as default, unset other defaults first
@@ -179,8 +231,88 @@ pub async fn delete_template(
     ok_or_internal_error(result, "Failed to delete template")
 }
 
+/// Check template syntax and variable names without saving
+pub async fn validate_template_content(
+    Json(request): Json<ValidateTemplateRequest>,
+) -> impl IntoResponse {
+    let errors = validate_template(&request.content);
+    let response = ValidateTemplateResponse {
+        valid: errors.is_empty(),
+        errors,
+        variables: TEMPLATE_VARIABLES,
+    };
+    ok_or_internal_error::<_, String>(Ok(response), "Failed to validate template")
+}
+
+/// Render a PRD output template with the given variables.
+///
+/// `date`, `author`, and (when `projectId` is given) `project_name` are
+/// filled in server-side unless the request provides them.
+pub async fn render_template_content(
+    State(db): State<DbState>,
+    Path(template_id): Path<String>,
+    Json(request): Json<RenderTemplateRequest>,
+) -> impl IntoResponse {
+    info!("Rendering template: {}", template_id);
+
+    let template = match fetch_template_by_id(&db.pool, &template_id).await {
+        Ok(template) => template,
+        Err(e) => return ok_or_not_found::<PRDTemplate, sqlx::Error>(Err(e), "Template not found"),
+    };
+
+    let mut variables = with_default_variables(request.variables);
+    if let Some(map) = variables.as_object_mut() {
+        if !map.contains_key("project_name") {
+            if let Some(project_id) = request.project_id.as_deref() {
+                match fetch_project_name(&db.pool, project_id).await {
+                    Ok(Some(name)) => {
+                        map.insert("project_name".to_string(), Value::String(name));
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        return ok_or_internal_error::<(), _>(Err(e), "Failed to load project")
+                    }
+                }
+            }
+        }
+        if !map.contains_key("author") {
+            match fetch_current_author(&db.pool).await {
+                Ok(Some(name)) => {
+                    map.insert("author".to_string(), Value::String(name));
+                }
+                Ok(None) => {}
+                Err(e) => return ok_or_internal_error::<(), _>(Err(e), "Failed to load author"),
+            }
+        }
+    }
+
+    match render_template(&template.content, &variables) {
+        Ok(content) => ok_or_internal_error::<_, String>(
+            Ok(RenderTemplateResponse { content }),
+            "Failed to render template",
+        ),
+        Err(errors) => bad_request(join_template_errors(&errors), "Invalid template content"),
+    }
+}
+
 // Database operations
 
+async fn fetch_project_name(
+    pool: &SqlitePool,
+    project_id: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT name FROM projects WHERE id = ?")
+        .bind(project_id)
+        .fetch_optional(pool)
+        .await
+}
+
+async fn fetch_current_author(pool: &SqlitePool) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT name FROM users WHERE is_current = 1")
+        .fetch_optional(pool)
+        .await
+}
+
 async fn fetch_all_templates(pool: &SqlitePool) -> Result<Vec<PRDTemplate>, sqlx::Error> {
     sqlx::query_as::<_, PRDTemplate>(
         r#"
@@ -10,9 +10,11 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::auth::CurrentUser;
-use super::response::ok_or_internal_error;
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found,
+};
 use orkee_projects::DbState;
-use orkee_security::users::{MaskedUser, UserUpdateInput};
+use orkee_security::users::{MaskedUser, StorageError, UserCreateInput, UserUpdateInput};
 
 /// Get current user (with masked credentials)
 pub async fn get_current_user(State(db): State<DbState>) -> impl IntoResponse {
@@ -26,6 +28,89 @@ pub async fn get_current_user(State(db): State<DbState>) -> impl IntoResponse {
     ok_or_internal_error(result, "Failed to get current user")
 }
 
+/// List all users (with masked credentials)
+pub async fn list_users(State(db): State<DbState>) -> impl IntoResponse {
+    info!("Listing users");
+
+    let result = db
+        .user_storage
+        .list_users()
+        .await
+        .map(|users| users.into_iter().map(MaskedUser::from).collect::<Vec<_>>());
+
+    ok_or_internal_error(result, "Failed to list users")
+}
+
+/// Request body for creating a user
+#[derive(Deserialize)]
+pub struct CreateUserRequest {
+    pub email: String,
+    pub name: String,
+    #[serde(rename = "avatarUrl")]
+    pub avatar_url: Option<String>,
+    #[serde(rename = "defaultAgentId")]
+    pub default_agent_id: Option<String>,
+    pub theme: Option<String>,
+}
+
+/// Create a new user
+pub async fn create_user(
+    State(db): State<DbState>,
+    Json(request): Json<CreateUserRequest>,
+) -> impl IntoResponse {
+    info!("Creating user {}", request.email);
+
+    if request.email.trim().is_empty() || request.name.trim().is_empty() {
+        return bad_request("Email and name are required", "Invalid user");
+    }
+
+    let input = UserCreateInput {
+        email: request.email.trim().to_string(),
+        name: request.name.trim().to_string(),
+        avatar_url: request.avatar_url,
+        default_agent_id: request.default_agent_id,
+        theme: request.theme,
+    };
+
+    let result = db
+        .user_storage
+        .create_user(input)
+        .await
+        .map(MaskedUser::from);
+    if let Err(StorageError::Sqlx(sqlx::Error::Database(e))) = &result {
+        if e.is_unique_violation() {
+            return bad_request("A user with this email already exists", "Invalid user");
+        }
+    }
+    created_or_internal_error(result, "Failed to create user")
+}
+
+/// Request body for switching the active user
+#[derive(Deserialize)]
+pub struct SwitchUserRequest {
+    #[serde(rename = "userId")]
+    pub user_id: String,
+}
+
+/// Switch the active user that subsequent requests act on behalf of
+pub async fn switch_current_user(
+    State(db): State<DbState>,
+    Json(request): Json<SwitchUserRequest>,
+) -> impl IntoResponse {
+    info!("Switching current user to {}", request.user_id);
+
+    let result = db
+        .user_storage
+        .switch_current_user(&request.user_id)
+        .await
+        .map(MaskedUser::from);
+
+    if matches!(result, Err(StorageError::Sqlx(sqlx::Error::RowNotFound))) {
+        return ok_or_not_found(result, "User not found");
+    }
+    ok_or_internal_error(result, "Failed to switch user")
+}
+
 /// Get user by ID
 pub async fn get_user(State(db): State<DbState>, Path(user_id): Path<String>) -> impl IntoResponse {
     info!("Getting user: {}", user_id);
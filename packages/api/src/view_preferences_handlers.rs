@@ -0,0 +1,89 @@
+// ABOUTME: HTTP request handlers for per-user table view preferences
+// ABOUTME: Saves column layouts, sort orders, and filters server-side so they follow the user
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::{bad_request, ok_or_internal_error, ok_or_not_found};
+use orkee_projects::DbState;
+use orkee_storage::view_preferences::ViewPreferenceInput;
+use orkee_storage::StorageError;
+
+#[derive(Deserialize)]
+pub struct ListViewPreferencesQuery {
+    /// Only return views updated after this timestamp (used by cloud sync)
+    pub since: Option<String>,
+}
+
+/// List a user's saved table views
+pub async fn list_view_preferences(
+    State(db): State<DbState>,
+    Path(user_id): Path<String>,
+    Query(query): Query<ListViewPreferencesQuery>,
+) -> impl IntoResponse {
+    info!("Listing view preferences for user: {}", user_id);
+
+    let result = db
+        .view_preferences_storage
+        .list_preferences(&user_id, query.since.as_deref())
+        .await;
+    ok_or_internal_error(result, "Failed to list view preferences")
+}
+
+/// Get a user's saved layout for one view
+pub async fn get_view_preference(
+    State(db): State<DbState>,
+    Path((user_id, view_key)): Path<(String, String)>,
+) -> impl IntoResponse {
+    let result = db
+        .view_preferences_storage
+        .get_preference(&user_id, &view_key)
+        .await;
+
+    match result {
+        Ok(None) => ok_or_not_found::<(), _>(Err(view_key), "View preference not found"),
+        result => ok_or_internal_error(result, "Failed to get view preference"),
+    }
+}
+
+/// Save a user's layout for one view, replacing any previous one
+pub async fn save_view_preference(
+    State(db): State<DbState>,
+    Path((user_id, view_key)): Path<(String, String)>,
+    Json(input): Json<ViewPreferenceInput>,
+) -> impl IntoResponse {
+    info!("Saving view preference {} for user: {}", view_key, user_id);
+
+    let result = db
+        .view_preferences_storage
+        .save_preference(&user_id, &view_key, &input)
+        .await;
+
+    match result {
+        Err(StorageError::Validation(message)) => bad_request(message, "Invalid view preference"),
+        result => ok_or_internal_error(result, "Failed to save view preference"),
+    }
+}
+
+/// Delete a user's saved layout for one view, restoring client defaults
+pub async fn delete_view_preference(
+    State(db): State<DbState>,
+    Path((user_id, view_key)): Path<(String, String)>,
+) -> impl IntoResponse {
+    info!(
+        "Deleting view preference {} for user: {}",
+        view_key, user_id
+    );
+
+    let result = db
+        .view_preferences_storage
+        .delete_preference(&user_id, &view_key)
+        .await
+        .map(|deleted| serde_json::json!({ "deleted": deleted }));
+    ok_or_internal_error(result, "Failed to delete view preference")
+}
@@ -0,0 +1,148 @@
+// ABOUTME: HTTP request handlers for inbound CI webhooks
+// ABOUTME: Receives signed GitHub/generic deliveries and manages webhook secrets and the delivery log
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::Deserialize;
+use tracing::info;
+
+use super::response::{
+    bad_request, created_or_internal_error, ok_or_internal_error, ok_or_not_found, ApiResponse,
+};
+use orkee_projects::DbState;
+use orkee_webhooks::{DeliveryStatus, WebhookProvider, WebhookRequest};
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 200;
+
+fn header_value(headers: &HeaderMap, name: Option<&str>) -> Option<String> {
+    name.and_then(|name| headers.get(name))
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Receive a CI webhook delivery. Authenticated by HMAC signature, not API token.
+pub async fn receive_webhook(
+    State(db): State<DbState>,
+    Path(provider): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let provider = match provider.parse::<WebhookProvider>() {
+        Ok(provider) => provider,
+        Err(e) => return bad_request(e, "Invalid provider"),
+    };
+
+    let request = WebhookRequest {
+        provider,
+        event_type: header_value(&headers, provider.event_header()),
+        delivery_id: header_value(&headers, provider.delivery_header()),
+        signature: header_value(&headers, Some(provider.signature_header())),
+        body: body.to_vec(),
+    };
+
+    // Don't echo the payload back to the CI system
+    let result = db.webhooks.receive(request).await.map(|mut delivery| {
+        delivery.payload = None;
+        delivery
+    });
+
+    match result {
+        Ok(delivery) if delivery.status == DeliveryStatus::Rejected => (
+            StatusCode::UNAUTHORIZED,
+            Json(ApiResponse::<()>::error(
+                delivery
+                    .message
+                    .unwrap_or_else(|| "Webhook rejected".to_string()),
+            )),
+        )
+            .into_response(),
+        Ok(delivery) if delivery.status == DeliveryStatus::Failed => bad_request(
+            delivery.message.unwrap_or_default(),
+            "Failed to process webhook",
+        ),
+        result => ok_or_internal_error(result, "Failed to process webhook"),
+    }
+}
+
+/// List configured webhook secrets (values are never returned)
+pub async fn list_secrets(State(db): State<DbState>) -> impl IntoResponse {
+    let result = db.webhooks.secrets().list_secrets().await;
+    ok_or_internal_error(result, "Failed to list webhook secrets")
+}
+
+#[derive(Deserialize)]
+pub struct CreateSecretRequest {
+    pub provider: String,
+    pub name: String,
+}
+
+/// Create a webhook secret. The plaintext secret is only included in this response.
+pub async fn create_secret(
+    State(db): State<DbState>,
+    Json(request): Json<CreateSecretRequest>,
+) -> impl IntoResponse {
+    let provider = match request.provider.parse::<WebhookProvider>() {
+        Ok(provider) => provider,
+        Err(e) => return bad_request(e, "Invalid provider"),
+    };
+    let name = request.name.trim();
+    if name.is_empty() {
+        return bad_request("name must not be empty", "Invalid webhook secret");
+    }
+
+    info!("Creating {} webhook secret: {}", provider.as_str(), name);
+    let result = db.webhooks.secrets().create_secret(provider, name).await;
+    created_or_internal_error(result, "Failed to create webhook secret")
+}
+
+/// Delete a webhook secret; deliveries signed with it are rejected afterwards
+pub async fn delete_secret(
+    State(db): State<DbState>,
+    Path(secret_id): Path<String>,
+) -> impl IntoResponse {
+    info!("Deleting webhook secret: {}", secret_id);
+
+    let result = db
+        .webhooks
+        .secrets()
+        .delete_secret(&secret_id)
+        .await
+        .map(|_| "Webhook secret deleted successfully");
+    ok_or_not_found(result, "Failed to delete webhook secret")
+}
+
+#[derive(Deserialize)]
+pub struct ListDeliveriesQuery {
+    pub provider: Option<String>,
+    pub limit: Option<i64>,
+}
+
+/// List webhook deliveries newest first
+pub async fn list_deliveries(
+    State(db): State<DbState>,
+    Query(query): Query<ListDeliveriesQuery>,
+) -> impl IntoResponse {
+    let provider = match query
+        .provider
+        .as_deref()
+        .map(str::parse::<WebhookProvider>)
+        .transpose()
+    {
+        Ok(provider) => provider,
+        Err(e) => return bad_request(e, "Invalid provider"),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let result = db
+        .webhooks
+        .deliveries()
+        .list_deliveries(provider, limit)
+        .await;
+    ok_or_internal_error(result, "Failed to list webhook deliveries")
+}
@@ -6,4 +6,7 @@ pub mod oauth;
 
 // Re-export main types
 pub use error::{AuthError, AuthResult};
-pub use oauth::{OAuthManager, OAuthStorage, OAuthToken, ProviderStatus};
+pub use oauth::{
+    BackgroundRefresher, OAuthManager, OAuthStorage, OAuthToken, ProviderStatus, RefreshConfig,
+    RefreshEvent, TokenRefresher,
+};
@@ -2,7 +2,7 @@
 // ABOUTME: Handles token import, logout, retrieval, and status for all AI providers
 
 use sqlx::SqlitePool;
-use tracing::{debug, info};
+use tracing::{debug, error, info, warn};
 
 use crate::{
     error::{AuthError, AuthResult},
@@ -40,6 +40,20 @@ impl OAuthManager {
             .await
             .map_err(|e| AuthError::Storage(format!("Failed to connect to database: {}", e)))?;
 
+        if let Err(e) = orkee_security::encryption::install_field_cipher(&pool).await {
+            if orkee_storage::field_encryption::is_locked() {
+                error!(
+                    "Field encryption is locked; OAuth metadata cannot be read or saved: {}. Set {} to the encryption password.",
+                    e,
+                    orkee_security::encryption::PASSWORD_ENV_VAR
+                );
+            } else {
+                warn!(
+                    "Field encryption unavailable, storing OAuth metadata unencrypted: {}",
+                    e
+                );
+            }
+        }
         Self::new(pool)
     }
 
@@ -1,10 +1,12 @@
 // ABOUTME: OAuth module for token management and storage
-// ABOUTME: Provides direct token import and encrypted storage for AI provider tokens
+// ABOUTME: Provides direct token import, encrypted storage, and background refresh for AI provider tokens
 
 pub mod manager;
+pub mod refresher;
 pub mod storage;
 pub mod types;
 
 pub use manager::{OAuthManager, ProviderStatus};
+pub use refresher::{BackgroundRefresher, RefreshConfig, RefreshEvent, TokenRefresher};
 pub use storage::OAuthStorage;
 pub use types::{OAuthProvider, OAuthToken};
@@ -0,0 +1,264 @@
+// ABOUTME: Background task that refreshes OAuth tokens ahead of expiry
+// ABOUTME: Schedules jittered refreshes per token and broadcasts failures so users can re-authenticate
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use rand::Rng;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tokio::sync::{broadcast, Mutex};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use crate::{
+    error::{AuthError, AuthResult},
+    oauth::{
+        storage::OAuthStorage,
+        types::{OAuthProvider, OAuthToken},
+    },
+};
+
+/// Exchanges a token's refresh token for a new access token.
+/// Implemented per provider; providers without one need manual re-authentication.
+#[async_trait]
+pub trait TokenRefresher: Send + Sync {
+    async fn refresh(&self, token: &OAuthToken) -> AuthResult<OAuthToken>;
+}
+
+/// Outcome of a background refresh attempt
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RefreshEvent {
+    Refreshed {
+        user_id: String,
+        provider: String,
+        expires_at: i64,
+    },
+    RefreshFailed {
+        user_id: String,
+        provider: String,
+        expires_at: i64,
+        error: String,
+        /// The token can't be refreshed automatically and the user must log in again
+        reauth_required: bool,
+    },
+}
+
+/// Scheduling configuration for [`BackgroundRefresher`]
+#[derive(Debug, Clone)]
+pub struct RefreshConfig {
+    /// How long before expiry a token is refreshed
+    pub refresh_ahead: Duration,
+    /// Upper bound of the random extra lead added per token, so tokens issued
+    /// together are not all refreshed at the same moment
+    pub jitter: Duration,
+    /// Longest sleep between checks, so newly imported tokens get picked up
+    pub max_interval: Duration,
+    /// Delay before retrying a refresh that failed transiently
+    pub retry_delay: Duration,
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            refresh_ahead: Duration::from_secs(10 * 60),
+            jitter: Duration::from_secs(2 * 60),
+            max_interval: Duration::from_secs(15 * 60),
+            retry_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Refresh plan for one user/provider token
+struct Scheduled {
+    expires_at: i64,
+    refresh_at: i64,
+    /// Refresh failed permanently; waiting for the user to log in again
+    awaiting_reauth: bool,
+}
+
+/// Tracks expiry for every stored token and refreshes each one ahead of time
+pub struct BackgroundRefresher {
+    storage: OAuthStorage,
+    refreshers: HashMap<OAuthProvider, Arc<dyn TokenRefresher>>,
+    config: RefreshConfig,
+    events: broadcast::Sender<RefreshEvent>,
+    schedule: Mutex<HashMap<String, Scheduled>>,
+}
+
+impl BackgroundRefresher {
+    /// Create a refresher with database pool. Register provider refreshers
+    /// with [`with_refresher`](Self::with_refresher).
+    pub fn new(pool: SqlitePool, config: RefreshConfig) -> AuthResult<Self> {
+        let (events, _) = broadcast::channel(64);
+        Ok(Self {
+            storage: OAuthStorage::new(pool)?,
+            refreshers: HashMap::new(),
+            config,
+            events,
+            schedule: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Register the refresh implementation for a provider
+    pub fn with_refresher(
+        mut self,
+        provider: OAuthProvider,
+        refresher: Arc<dyn TokenRefresher>,
+    ) -> Self {
+        self.refreshers.insert(provider, refresher);
+        self
+    }
+
+    /// Subscribe to refresh outcomes
+    pub fn subscribe(&self) -> broadcast::Receiver<RefreshEvent> {
+        self.events.subscribe()
+    }
+
+    /// Run the refresh loop until the task is aborted
+    pub fn spawn(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            info!("OAuth token refresher started");
+            loop {
+                let delay = match self.check_tokens().await {
+                    Ok(delay) => delay,
+                    Err(e) => {
+                        error!(error = %e, "Failed to check OAuth tokens for refresh");
+                        self.config.retry_delay
+                    }
+                };
+                tokio::time::sleep(delay).await;
+            }
+        })
+    }
+
+    /// Refresh every token that is due and return how long to wait before the next check
+    pub async fn check_tokens(&self) -> AuthResult<Duration> {
+        let tokens = self.storage.list_tokens().await?;
+        let now = Utc::now().timestamp();
+        let mut next_check = now + self.config.max_interval.as_secs() as i64;
+
+        let mut schedule = self.schedule.lock().await;
+        schedule.retain(|key, _| tokens.iter().any(|t| &schedule_key(t) == key));
+
+        for token in tokens {
+            let entry = schedule
+                .entry(schedule_key(&token))
+                .or_insert_with(|| self.plan(&token));
+
+            // A new expiry means the token was re-imported or refreshed elsewhere
+            if entry.expires_at != token.expires_at {
+                *entry = self.plan(&token);
+            }
+            if entry.awaiting_reauth {
+                continue;
+            }
+
+            if entry.refresh_at <= now {
+                match self.refresh(&token).await {
+                    Ok(refreshed) => {
+                        info!(
+                            user_id = %refreshed.user_id,
+                            provider = %refreshed.provider,
+                            expires_at = refreshed.expires_at,
+                            "Refreshed OAuth token"
+                        );
+                        *entry = self.plan(&refreshed);
+                        let _ = self.events.send(RefreshEvent::Refreshed {
+                            user_id: refreshed.user_id,
+                            provider: refreshed.provider,
+                            expires_at: refreshed.expires_at,
+                        });
+                    }
+                    Err((e, reauth_required)) => {
+                        let reauth_required = reauth_required || token.expires_at <= now;
+                        warn!(
+                            user_id = %token.user_id,
+                            provider = %token.provider,
+                            expires_at = token.expires_at,
+                            reauth_required,
+                            error = %e,
+                            "OAuth token refresh failed"
+                        );
+                        if reauth_required {
+                            entry.awaiting_reauth = true;
+                        } else {
+                            entry.refresh_at = now + self.config.retry_delay.as_secs() as i64;
+                        }
+                        let _ = self.events.send(RefreshEvent::RefreshFailed {
+                            user_id: token.user_id.clone(),
+                            provider: token.provider.clone(),
+                            expires_at: token.expires_at,
+                            error: e.to_string(),
+                            reauth_required,
+                        });
+                    }
+                }
+            }
+
+            if !entry.awaiting_reauth {
+                next_check = next_check.min(entry.refresh_at);
+            }
+        }
+
+        let delay = (next_check - now).max(1) as u64;
+        debug!("Next OAuth token refresh check in {}s", delay);
+        Ok(Duration::from_secs(delay))
+    }
+
+    /// Pick a refresh time ahead of expiry with random jitter
+    fn plan(&self, token: &OAuthToken) -> Scheduled {
+        let jitter = rand::thread_rng().gen_range(0..=self.config.jitter.as_secs()) as i64;
+        Scheduled {
+            expires_at: token.expires_at,
+            refresh_at: token.expires_at - self.config.refresh_ahead.as_secs() as i64 - jitter,
+            awaiting_reauth: false,
+        }
+    }
+
+    /// Refresh and store a token. On error, the flag says whether only
+    /// re-authentication can fix it (as opposed to retrying later).
+    async fn refresh(&self, token: &OAuthToken) -> Result<OAuthToken, (AuthError, bool)> {
+        let provider: OAuthProvider = token.provider.parse().map_err(|e| (e, true))?;
+
+        let refresher = self.refreshers.get(&provider).ok_or_else(|| {
+            (
+                AuthError::Provider(format!(
+                    "{} tokens cannot be refreshed automatically",
+                    provider
+                )),
+                true,
+            )
+        })?;
+
+        if token.refresh_token.is_none() {
+            return Err((
+                AuthError::TokenNotFound("No refresh token stored".into()),
+                true,
+            ));
+        }
+
+        let refreshed = refresher.refresh(token).await.map_err(|e| {
+            let reauth_required = matches!(
+                e,
+                AuthError::TokenExpired | AuthError::InvalidToken(_) | AuthError::OAuthFailed(_)
+            );
+            (e, reauth_required)
+        })?;
+
+        self.storage
+            .store_token(&refreshed)
+            .await
+            .map_err(|e| (e, false))?;
+
+        Ok(refreshed)
+    }
+}
+
+fn schedule_key(token: &OAuthToken) -> String {
+    format!("{}:{}", token.user_id, token.provider)
+}
@@ -2,6 +2,7 @@
 // ABOUTME: Handles encrypted storage and retrieval of OAuth credentials using SQLx
 
 use orkee_security::ApiKeyEncryption;
+use orkee_storage::{field_encryption, StorageError};
 use sqlx::{Row, SqlitePool};
 use tracing::{debug, error};
 
@@ -10,6 +11,11 @@ use crate::{
     oauth::types::{OAuthProvider, OAuthToken},
 };
 
+fn seal_error(e: StorageError) -> AuthError {
+    error!("Failed to encrypt OAuth token metadata: {}", e);
+    AuthError::Storage(format!("Token metadata encryption failed: {}", e))
+}
+
 /// OAuth storage manager for database operations
 pub struct OAuthStorage {
     pool: SqlitePool,
@@ -23,6 +29,8 @@ impl OAuthStorage {
         // Users can upgrade to password-based via `orkee security set-password`
         let encryption = ApiKeyEncryption::new()
             .map_err(|e| AuthError::Storage(format!("Failed to initialize encryption: {}", e)))?;
+        // Scope and account email are sealed through the shared field cipher,
+        // installed by whoever opened the database
 
         Ok(Self { pool, encryption })
     }
@@ -44,6 +52,9 @@ impl OAuthStorage {
             })?),
             None => None,
         };
+        let scope = field_encryption::seal_opt(token.scope.as_ref()).map_err(seal_error)?;
+        let account_email =
+            field_encryption::seal_opt(token.account_email.as_ref()).map_err(seal_error)?;
 
         sqlx::query(
             r#"
@@ -71,9 +82,9 @@ impl OAuthStorage {
         .bind(&encrypted_refresh_token)
         .bind(token.expires_at)
         .bind(&token.token_type)
-        .bind(&token.scope)
+        .bind(&scope)
         .bind(&token.subscription_type)
-        .bind(&token.account_email)
+        .bind(&account_email)
         .execute(&self.pool)
         .await
         .map_err(|e| {
@@ -111,38 +122,7 @@ impl OAuthStorage {
 
         match row {
             Some(row) => {
-                // Decrypt access token
-                let encrypted_access_token: String = row.try_get("access_token")?;
-                let access_token =
-                    self.encryption
-                        .decrypt(&encrypted_access_token)
-                        .map_err(|e| {
-                            error!("Failed to decrypt access token: {}", e);
-                            AuthError::Storage(format!("Token decryption failed: {}", e))
-                        })?;
-
-                // Decrypt refresh token if present
-                let encrypted_refresh_token: Option<String> = row.try_get("refresh_token")?;
-                let refresh_token = match encrypted_refresh_token {
-                    Some(encrypted) => Some(self.encryption.decrypt(&encrypted).map_err(|e| {
-                        error!("Failed to decrypt refresh token: {}", e);
-                        AuthError::Storage(format!("Token decryption failed: {}", e))
-                    })?),
-                    None => None,
-                };
-
-                let token = OAuthToken {
-                    id: row.try_get("id")?,
-                    user_id: row.try_get("user_id")?,
-                    provider: row.try_get("provider")?,
-                    access_token,
-                    refresh_token,
-                    expires_at: row.try_get("expires_at")?,
-                    token_type: row.try_get("token_type")?,
-                    scope: row.try_get("scope")?,
-                    subscription_type: row.try_get("subscription_type")?,
-                    account_email: row.try_get("account_email")?,
-                };
+                let token = self.row_to_token(&row)?;
                 debug!("Found and decrypted OAuth token");
                 Ok(Some(token))
             }
@@ -153,6 +133,59 @@ impl OAuthStorage {
         }
     }
 
+    /// List every stored token across users and providers (decrypted)
+    pub async fn list_tokens(&self) -> AuthResult<Vec<OAuthToken>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, user_id, provider, access_token, refresh_token,
+                   expires_at, token_type, scope, subscription_type, account_email
+            FROM oauth_tokens
+            ORDER BY expires_at
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(|row| self.row_to_token(row)).collect()
+    }
+
+    /// Decrypt a token row
+    fn row_to_token(&self, row: &sqlx::sqlite::SqliteRow) -> AuthResult<OAuthToken> {
+        // Decrypt access token
+        let encrypted_access_token: String = row.try_get("access_token")?;
+        let access_token = self
+            .encryption
+            .decrypt(&encrypted_access_token)
+            .map_err(|e| {
+                error!("Failed to decrypt access token: {}", e);
+                AuthError::Storage(format!("Token decryption failed: {}", e))
+            })?;
+
+        // Decrypt refresh token if present
+        let encrypted_refresh_token: Option<String> = row.try_get("refresh_token")?;
+        let refresh_token = match encrypted_refresh_token {
+            Some(encrypted) => Some(self.encryption.decrypt(&encrypted).map_err(|e| {
+                error!("Failed to decrypt refresh token: {}", e);
+                AuthError::Storage(format!("Token decryption failed: {}", e))
+            })?),
+            None => None,
+        };
+
+        Ok(OAuthToken {
+            id: row.try_get("id")?,
+            user_id: row.try_get("user_id")?,
+            provider: row.try_get("provider")?,
+            access_token,
+            refresh_token,
+            expires_at: row.try_get("expires_at")?,
+            token_type: row.try_get("token_type")?,
+            scope: field_encryption::open_opt(row.try_get("scope")?).map_err(seal_error)?,
+            subscription_type: row.try_get("subscription_type")?,
+            account_email: field_encryption::open_opt(row.try_get("account_email")?)
+                .map_err(seal_error)?,
+        })
+    }
+
     /// Delete OAuth token
     pub async fn delete_token(&self, user_id: &str, provider: OAuthProvider) -> AuthResult<()> {
         debug!(
@@ -0,0 +1,260 @@
+// ABOUTME: Integration tests for the background OAuth token refresher
+// ABOUTME: Tests refresh scheduling, storage of refreshed tokens, and failure events
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use nanoid::nanoid;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use tempfile::TempDir;
+
+use orkee_auth::oauth::{
+    storage::OAuthStorage,
+    types::{OAuthProvider, OAuthToken},
+    BackgroundRefresher, RefreshConfig, RefreshEvent, TokenRefresher,
+};
+use orkee_auth::{AuthError, AuthResult};
+
+/// Helper to create a test database with schema
+async fn setup_test_db() -> (SqlitePool, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let db_path = temp_dir.path().join("test.db");
+    let database_url = format!("sqlite://{}?mode=rwc", db_path.display());
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        r#"
+        CREATE TABLE oauth_tokens (
+            id TEXT PRIMARY KEY,
+            user_id TEXT NOT NULL,
+            provider TEXT NOT NULL,
+            access_token TEXT NOT NULL,
+            refresh_token TEXT,
+            expires_at INTEGER NOT NULL,
+            token_type TEXT DEFAULT 'Bearer',
+            scope TEXT,
+            subscription_type TEXT,
+            account_email TEXT,
+            created_at INTEGER NOT NULL DEFAULT (unixepoch()),
+            updated_at INTEGER NOT NULL DEFAULT (unixepoch()),
+            UNIQUE(user_id, provider)
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    (pool, temp_dir)
+}
+
+/// Helper to create a token expiring in the given number of seconds
+fn create_test_token(provider: OAuthProvider, expires_in_seconds: i64) -> OAuthToken {
+    OAuthToken {
+        id: nanoid!(),
+        user_id: "user-1".to_string(),
+        provider: provider.to_string(),
+        access_token: format!("test_access_token_{}", nanoid!()),
+        refresh_token: Some(format!("test_refresh_token_{}", nanoid!())),
+        expires_at: Utc::now().timestamp() + expires_in_seconds,
+        token_type: "Bearer".to_string(),
+        scope: None,
+        subscription_type: None,
+        account_email: None,
+    }
+}
+
+fn test_config() -> RefreshConfig {
+    RefreshConfig {
+        refresh_ahead: Duration::from_secs(600),
+        jitter: Duration::ZERO,
+        max_interval: Duration::from_secs(900),
+        retry_delay: Duration::from_secs(60),
+    }
+}
+
+/// Refresher that extends tokens by an hour, or fails with the given error
+struct MockRefresher {
+    calls: AtomicUsize,
+    error: Option<fn() -> AuthError>,
+}
+
+impl MockRefresher {
+    fn succeeding() -> Arc<Self> {
+        Arc::new(Self {
+            calls: AtomicUsize::new(0),
+            error: None,
+        })
+    }
+
+    fn failing(error: fn() -> AuthError) -> Arc<Self> {
+        Arc::new(Self {
+            calls: AtomicUsize::new(0),
+            error: Some(error),
+        })
+    }
+}
+
+#[async_trait]
+impl TokenRefresher for MockRefresher {
+    async fn refresh(&self, token: &OAuthToken) -> AuthResult<OAuthToken> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if let Some(error) = self.error {
+            return Err(error());
+        }
+        Ok(OAuthToken {
+            access_token: "refreshed_access_token".to_string(),
+            expires_at: Utc::now().timestamp() + 3600,
+            ..token.clone()
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_refreshes_tokens_ahead_of_expiry() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+    storage
+        .store_token(&create_test_token(OAuthProvider::OpenAI, 300))
+        .await
+        .unwrap();
+
+    let mock = MockRefresher::succeeding();
+    let refresher = BackgroundRefresher::new(pool, test_config())
+        .unwrap()
+        .with_refresher(OAuthProvider::OpenAI, mock.clone());
+    let mut events = refresher.subscribe();
+
+    let delay = refresher.check_tokens().await.unwrap();
+
+    assert_eq!(mock.calls.load(Ordering::SeqCst), 1);
+    let stored = storage
+        .get_token("user-1", OAuthProvider::OpenAI)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.access_token, "refreshed_access_token");
+    assert!(matches!(
+        events.try_recv().unwrap(),
+        RefreshEvent::Refreshed { .. }
+    ));
+
+    // Next refresh is 10 minutes before the new expiry, capped by max_interval
+    assert!(delay <= Duration::from_secs(900));
+    assert!(delay >= Duration::from_secs(899));
+}
+
+#[tokio::test]
+async fn test_tokens_far_from_expiry_are_not_refreshed() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+    storage
+        .store_token(&create_test_token(OAuthProvider::OpenAI, 1200))
+        .await
+        .unwrap();
+
+    let mock = MockRefresher::succeeding();
+    let refresher = BackgroundRefresher::new(pool, test_config())
+        .unwrap()
+        .with_refresher(OAuthProvider::OpenAI, mock.clone());
+
+    let delay = refresher.check_tokens().await.unwrap();
+
+    assert_eq!(mock.calls.load(Ordering::SeqCst), 0);
+    // Wakes up when the token enters the refresh window (~600s from now)
+    assert!(delay <= Duration::from_secs(600));
+    assert!(delay >= Duration::from_secs(598));
+}
+
+#[tokio::test]
+async fn test_provider_without_refresher_requires_reauth_once() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+    storage
+        .store_token(&create_test_token(OAuthProvider::Claude, 300))
+        .await
+        .unwrap();
+
+    let refresher = BackgroundRefresher::new(pool, test_config()).unwrap();
+    let mut events = refresher.subscribe();
+
+    refresher.check_tokens().await.unwrap();
+    refresher.check_tokens().await.unwrap();
+
+    match events.try_recv().unwrap() {
+        RefreshEvent::RefreshFailed {
+            provider,
+            reauth_required,
+            ..
+        } => {
+            assert_eq!(provider, "claude");
+            assert!(reauth_required);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+    // Not reported again while waiting for the user to log in
+    assert!(events.try_recv().is_err());
+}
+
+#[tokio::test]
+async fn test_transient_failures_are_retried() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+    storage
+        .store_token(&create_test_token(OAuthProvider::Google, 300))
+        .await
+        .unwrap();
+
+    let mock = MockRefresher::failing(|| AuthError::Provider("connection reset".into()));
+    let refresher = BackgroundRefresher::new(pool, test_config())
+        .unwrap()
+        .with_refresher(OAuthProvider::Google, mock.clone());
+    let mut events = refresher.subscribe();
+
+    let delay = refresher.check_tokens().await.unwrap();
+
+    assert!(matches!(
+        events.try_recv().unwrap(),
+        RefreshEvent::RefreshFailed {
+            reauth_required: false,
+            ..
+        }
+    ));
+    assert_eq!(delay, Duration::from_secs(60));
+}
+
+#[tokio::test]
+async fn test_rejected_refresh_token_requires_reauth() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+    storage
+        .store_token(&create_test_token(OAuthProvider::XAI, 300))
+        .await
+        .unwrap();
+
+    let mock = MockRefresher::failing(|| AuthError::InvalidToken("revoked".into()));
+    let refresher = BackgroundRefresher::new(pool, test_config())
+        .unwrap()
+        .with_refresher(OAuthProvider::XAI, mock.clone());
+    let mut events = refresher.subscribe();
+
+    refresher.check_tokens().await.unwrap();
+    refresher.check_tokens().await.unwrap();
+
+    assert_eq!(mock.calls.load(Ordering::SeqCst), 1);
+    assert!(matches!(
+        events.try_recv().unwrap(),
+        RefreshEvent::RefreshFailed {
+            reauth_required: true,
+            ..
+        }
+    ));
+}
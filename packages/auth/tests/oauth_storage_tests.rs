@@ -92,6 +92,34 @@ async fn test_store_and_retrieve_token() {
     assert_eq!(retrieved.account_email, token.account_email);
 }
 
+#[tokio::test]
+async fn test_token_metadata_encrypted_at_rest() {
+    let (pool, _temp_dir) = setup_test_db().await;
+    orkee_storage::field_encryption::install(std::sync::Arc::new(
+        orkee_security::ApiKeyEncryption::with_machine_key().unwrap(),
+    ));
+    let storage = OAuthStorage::new(pool.clone()).unwrap();
+
+    let token = create_test_token("user-1", OAuthProvider::Claude);
+    storage.store_token(&token).await.unwrap();
+
+    let (scope, account_email): (String, String) =
+        sqlx::query_as("SELECT scope, account_email FROM oauth_tokens WHERE user_id = 'user-1'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(scope.starts_with("enc:v1:"));
+    assert!(!account_email.contains("test@example.com"));
+
+    let retrieved = storage
+        .get_token("user-1", OAuthProvider::Claude)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(retrieved.scope, token.scope);
+    assert_eq!(retrieved.account_email, token.account_email);
+}
+
 #[tokio::test]
 async fn test_store_token_upsert() {
     let (pool, _temp_dir) = setup_test_db().await;
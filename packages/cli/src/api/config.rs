@@ -1,8 +1,13 @@
-use axum::{extract::State, http::StatusCode, response::Json};
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    response::Json,
+};
+use orkee_config::{ConfigService, ConfigServiceError, ReloadOutcome};
 use orkee_projects::db::DbState;
 use serde::Serialize;
 use std::env;
-use tracing::warn;
+use tracing::{info, warn};
 
 #[derive(Serialize)]
 pub struct ConfigResponse {
@@ -40,3 +45,43 @@ pub async fn get_config(State(db): State<DbState>) -> Result<Json<ConfigResponse
         error: None,
     }))
 }
+
+#[derive(Serialize)]
+pub struct ReloadResponse {
+    pub success: bool,
+    pub data: Option<ReloadOutcome>,
+    pub error: Option<String>,
+}
+
+/// Re-read config.toml, database settings, and environment, notifying subsystems of changes
+pub async fn reload_config(
+    Extension(config_service): Extension<ConfigService>,
+) -> (StatusCode, Json<ReloadResponse>) {
+    info!("Reloading configuration on request");
+
+    match config_service.reload().await {
+        Ok(outcome) => (
+            StatusCode::OK,
+            Json(ReloadResponse {
+                success: true,
+                data: Some(outcome),
+                error: None,
+            }),
+        ),
+        Err(e) => {
+            warn!("Configuration reload failed: {}", e);
+            let status = match e {
+                ConfigServiceError::FileParse { .. } => StatusCode::BAD_REQUEST,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (
+                status,
+                Json(ReloadResponse {
+                    success: false,
+                    data: None,
+                    error: Some(e.to_string()),
+                }),
+            )
+        }
+    }
+}
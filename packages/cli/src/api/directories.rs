@@ -202,7 +202,7 @@ pub async fn browse_directories(
     }
 
     // Sort directories alphabetically
-    directories.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+    directories.sort_by_key(|d| d.name.to_lowercase());
 
     let current_path_str = validated_path.to_string_lossy().to_string();
 
@@ -1,9 +1,26 @@
-use axum::{extract::Extension, response::Result, Json};
+use axum::{
+    extract::{Extension, State},
+    http::StatusCode,
+    response::{IntoResponse, Result},
+    Json,
+};
+use orkee_projects::DbState;
+use serde::Serialize;
 use serde_json::{json, Value};
-use std::time::{SystemTime, UNIX_EPOCH};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use crate::middleware::CsrfLayer;
 
+/// Below this much free space the database can no longer be written reliably
+const DISK_SPACE_CRITICAL_BYTES: u64 = 100 * 1024 * 1024;
+/// Below this much free space readiness reports a warning
+const DISK_SPACE_WARNING_BYTES: u64 = 1024 * 1024 * 1024;
+/// How long to wait for the Docker daemon to answer a ping
+const DOCKER_PING_TIMEOUT: Duration = Duration::from_secs(2);
+
 pub async fn health_check() -> Result<Json<Value>> {
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -48,3 +65,285 @@ pub async fn get_csrf_token(Extension(csrf_layer): Extension<CsrfLayer>) -> Resu
         "csrf_token": token
     })))
 }
+
+/// Status of a single readiness dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    /// Working, but needs attention soon
+    Warning,
+    Error,
+    /// Not applicable in the current configuration
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyCheck {
+    pub status: CheckStatus,
+    pub message: String,
+    /// Whether a failure makes the server not ready
+    pub critical: bool,
+    pub latency_ms: u64,
+}
+
+impl DependencyCheck {
+    fn new(status: CheckStatus, message: impl Into<String>, critical: bool) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            critical,
+            latency_ms: 0,
+        }
+    }
+
+    fn timed(mut self, started: Instant) -> Self {
+        self.latency_ms = started.elapsed().as_millis() as u64;
+        self
+    }
+}
+
+/// Overall readiness derived from the dependency checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadinessStatus {
+    Ready,
+    /// Serving requests, but an optional dependency is unavailable
+    Degraded,
+    NotReady,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessReport {
+    pub status: ReadinessStatus,
+    pub timestamp: u64,
+    pub version: &'static str,
+    pub service: &'static str,
+    pub checks: BTreeMap<&'static str, DependencyCheck>,
+}
+
+/// Readiness probe for service managers and the tray app.
+///
+/// Returns 200 when the server can handle requests and 503 when a critical
+/// dependency (database, migrations, disk space) is failing.
+pub async fn readiness_check(State(db): State<DbState>) -> impl IntoResponse {
+    let report = readiness_report(&db.pool).await;
+    let status = if report.status == ReadinessStatus::NotReady {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(report))
+}
+
+pub async fn readiness_report(pool: &SqlitePool) -> ReadinessReport {
+    let mut checks = BTreeMap::new();
+    checks.insert("database", check_database(pool).await);
+    checks.insert("migrations", check_migrations(pool).await);
+    checks.insert("disk_space", check_disk_space(pool).await);
+    checks.insert("docker", check_docker(pool).await);
+    checks.insert("cloud_auth", check_cloud_auth().await);
+
+    let failing = checks
+        .values()
+        .filter(|c| matches!(c.status, CheckStatus::Error));
+    let status = match failing.map(|c| c.critical).max() {
+        Some(true) => ReadinessStatus::NotReady,
+        Some(false) => ReadinessStatus::Degraded,
+        None => ReadinessStatus::Ready,
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    ReadinessReport {
+        status,
+        timestamp,
+        version: env!("CARGO_PKG_VERSION"),
+        service: "orkee-cli",
+        checks,
+    }
+}
+
+async fn check_database(pool: &SqlitePool) -> DependencyCheck {
+    let started = Instant::now();
+    match sqlx::query("SELECT 1").execute(pool).await {
+        Ok(_) => DependencyCheck::new(CheckStatus::Ok, "Database is reachable", true),
+        Err(e) => DependencyCheck::new(
+            CheckStatus::Error,
+            format!("Database query failed: {}", e),
+            true,
+        ),
+    }
+    .timed(started)
+}
+
+async fn check_migrations(pool: &SqlitePool) -> DependencyCheck {
+    let started = Instant::now();
+    let applied: Vec<i64> =
+        match sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = 1")
+            .fetch_all(pool)
+            .await
+        {
+            Ok(versions) => versions,
+            Err(e) => {
+                return DependencyCheck::new(
+                    CheckStatus::Error,
+                    format!("Failed to read migration history: {}", e),
+                    true,
+                )
+                .timed(started)
+            }
+        };
+    let applied: HashSet<i64> = applied.into_iter().collect();
+
+    let pending: Vec<i64> = sqlx::migrate!("../storage/migrations")
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| m.version)
+        .filter(|version| !applied.contains(version))
+        .collect();
+
+    if pending.is_empty() {
+        DependencyCheck::new(CheckStatus::Ok, "All migrations applied", true)
+    } else {
+        DependencyCheck::new(
+            CheckStatus::Error,
+            format!("{} pending migration(s): {:?}", pending.len(), pending),
+            true,
+        )
+    }
+    .timed(started)
+}
+
+async fn check_disk_space(pool: &SqlitePool) -> DependencyCheck {
+    let started = Instant::now();
+    let path = match orkee_storage::maintenance::database_path(pool).await {
+        Ok(Some(path)) => path,
+        Ok(None) => {
+            return DependencyCheck::new(CheckStatus::Skipped, "Database is in memory", true)
+                .timed(started)
+        }
+        Err(e) => {
+            return DependencyCheck::new(
+                CheckStatus::Error,
+                format!("Failed to locate database file: {}", e),
+                true,
+            )
+            .timed(started)
+        }
+    };
+
+    let Some(available) = available_space(&path) else {
+        return DependencyCheck::new(
+            CheckStatus::Warning,
+            format!("Could not determine free space for {}", path.display()),
+            true,
+        )
+        .timed(started);
+    };
+
+    let free_mb = available / (1024 * 1024);
+    let check = if available < DISK_SPACE_CRITICAL_BYTES {
+        DependencyCheck::new(
+            CheckStatus::Error,
+            format!("Only {} MB free on the database volume", free_mb),
+            true,
+        )
+    } else if available < DISK_SPACE_WARNING_BYTES {
+        DependencyCheck::new(
+            CheckStatus::Warning,
+            format!("{} MB free on the database volume", free_mb),
+            true,
+        )
+    } else {
+        DependencyCheck::new(
+            CheckStatus::Ok,
+            format!("{} MB free on the database volume", free_mb),
+            true,
+        )
+    };
+    check.timed(started)
+}
+
+/// Free space on the volume holding `path`, from the most specific mount point
+fn available_space(path: &Path) -> Option<u64> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+async fn check_docker(pool: &SqlitePool) -> DependencyCheck {
+    use orkee_sandbox::{DockerProvider, SandboxProvider};
+
+    let started = Instant::now();
+    let settings = match orkee_sandbox::SettingsManager::new(pool.clone()) {
+        Ok(manager) => manager.get_sandbox_settings().await.ok(),
+        Err(_) => None,
+    };
+    match settings {
+        Some(settings) if settings.enabled && settings.default_provider == "local" => {}
+        Some(settings) if settings.enabled => {
+            return DependencyCheck::new(
+                CheckStatus::Skipped,
+                format!("Sandboxes use the '{}' provider", settings.default_provider),
+                false,
+            )
+            .timed(started)
+        }
+        _ => {
+            return DependencyCheck::new(CheckStatus::Skipped, "Sandboxes are disabled", false)
+                .timed(started)
+        }
+    }
+
+    let reachable = match DockerProvider::new() {
+        Ok(docker) => tokio::time::timeout(DOCKER_PING_TIMEOUT, docker.is_available())
+            .await
+            .ok()
+            .and_then(|result| result.ok())
+            .unwrap_or(false),
+        Err(_) => false,
+    };
+
+    if reachable {
+        DependencyCheck::new(CheckStatus::Ok, "Docker daemon is reachable", false)
+    } else {
+        DependencyCheck::new(CheckStatus::Error, "Docker daemon is not reachable", false)
+    }
+    .timed(started)
+}
+
+async fn check_cloud_auth() -> DependencyCheck {
+    #[cfg(not(feature = "cloud"))]
+    {
+        DependencyCheck::new(CheckStatus::Skipped, "Cloud feature not enabled", false)
+    }
+
+    #[cfg(feature = "cloud")]
+    {
+        let started = Instant::now();
+        let api_url = std::env::var("ORKEE_CLOUD_API_URL")
+            .unwrap_or_else(|_| "https://api.orkee.ai".to_string());
+        match orkee_cloud::CloudClient::new(api_url).await {
+            Ok(client) if client.is_authenticated() => {
+                DependencyCheck::new(CheckStatus::Ok, "Signed in to Orkee Cloud", false)
+            }
+            Ok(_) => {
+                DependencyCheck::new(CheckStatus::Skipped, "Not signed in to Orkee Cloud", false)
+            }
+            Err(e) => DependencyCheck::new(
+                CheckStatus::Error,
+                format!("Failed to load cloud credentials: {}", e),
+                false,
+            ),
+        }
+        .timed(started)
+    }
+}
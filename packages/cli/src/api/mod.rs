@@ -1,8 +1,9 @@
 use axum::{
-    routing::{get, post},
+    routing::{any, get, post},
     Router,
 };
-use tracing::error;
+use orkee_config::ConfigService;
+use tracing::{error, info};
 
 pub mod cloud;
 pub mod config;
@@ -10,21 +11,24 @@ pub mod directories;
 pub mod git;
 pub mod health;
 pub mod path_validator;
+pub mod plugins;
 pub mod preview;
+pub mod scheduled_jobs;
 pub mod settings_handlers;
 pub mod taskmaster;
 pub mod telemetry;
 pub mod telemetry_middleware;
+pub mod tls;
 
 pub async fn create_router() -> Router {
-    let (router, _db_state) = create_router_with_options(None, None).await;
+    let (router, _db_state, _config_service) = create_router_with_options(None, None).await;
     router
 }
 
 pub async fn create_router_with_options(
     dashboard_path: Option<std::path::PathBuf>,
     database_path: Option<std::path::PathBuf>,
-) -> (Router, orkee_projects::DbState) {
+) -> (Router, orkee_projects::DbState, ConfigService) {
     use crate::config::Config;
     use path_validator::PathValidator;
     use preview::PreviewState;
@@ -50,6 +54,7 @@ pub async fn create_router_with_options(
             let minimal_db = orkee_projects::DbState::new(pool)
                 .expect("Failed to create minimal DbState for error fallback");
 
+            let config_service = crate::config_service::create_config_service(None).await;
             // Return a router without project manager functionality rather than panicking
             let router = Router::new()
                 .route("/api/health", get(health::health_check))
@@ -58,8 +63,12 @@ pub async fn create_router_with_options(
                     "/api/browse-directories",
                     post(directories::browse_directories),
                 )
+                .route(
+                    "/api/config/reload",
+                    post(config::reload_config).layer(axum::Extension(config_service.clone())),
+                )
                 .nest("/api/projects", orkee_api::create_projects_router());
-            return (router, minimal_db);
+            return (router, minimal_db, config_service);
         }
     };
 
@@ -88,6 +97,7 @@ pub async fn create_router_with_options(
             let minimal_db = orkee_projects::DbState::new(pool)
                 .expect("Failed to create minimal DbState for error fallback");
 
+            let config_service = crate::config_service::create_config_service(None).await;
             // Return a router without task/agent/user functionality
             let router = Router::new()
                 .route("/api/health", get(health::health_check))
@@ -96,11 +106,19 @@ pub async fn create_router_with_options(
                     "/api/browse-directories",
                     post(directories::browse_directories),
                 )
+                .route(
+                    "/api/config/reload",
+                    post(config::reload_config).layer(axum::Extension(config_service.clone())),
+                )
                 .nest("/api/projects", orkee_api::create_projects_router());
-            return (router, minimal_db);
+            return (router, minimal_db, config_service);
         }
     };
 
+    // Layered runtime configuration (config file, database settings, environment)
+    let config_service =
+        crate::config_service::create_config_service(Some(db_state.settings_storage.clone())).await;
+
     // Create the preview manager with crash recovery
     // Reuse the existing db_state.pool instead of creating a new SqliteStorage instance
     let preview_manager = match orkee_preview::init_from_pool(db_state.pool.clone()).await {
@@ -123,6 +141,16 @@ pub async fn create_router_with_options(
         }
     };
 
+    spawn_server_crash_notifications(&preview_manager, &db_state);
+    spawn_oauth_token_refresher(&db_state);
+    spawn_log_retention(&db_state);
+    spawn_execution_queue(&db_state);
+    if let Err(e) = db_state.script_runner.recover_interrupted_runs().await {
+        error!("Failed to recover interrupted script runs: {}", e);
+    }
+    let plugin_host = Arc::new(plugins::load_plugins(&db_state).await);
+    scheduled_jobs::start_scheduler(&db_state, preview_manager.clone(), &plugin_host).await;
+
     // Create preview state
     let preview_state = PreviewState {
         preview_manager: preview_manager.clone(),
@@ -145,6 +173,11 @@ pub async fn create_router_with_options(
             get(preview::get_server_status),
         )
         .route("/servers/{project_id}/logs", get(preview::get_server_logs))
+        .route(
+            "/servers/{project_id}/metrics",
+            get(preview::get_server_metrics),
+        )
+        .route("/servers/{project_id}/qr", get(preview::get_server_qr))
         .route(
             "/servers/{project_id}/logs/clear",
             post(preview::clear_server_logs),
@@ -176,6 +209,12 @@ pub async fn create_router_with_options(
         )
         .layer(axum::Extension(project_manager.clone()));
 
+    // Create plugins router; plugin routes live under /api/plugins/{plugin}
+    let plugins_router = Router::new()
+        .route("/", get(plugins::list_plugins))
+        .route("/{plugin}/{*path}", any(plugins::dispatch))
+        .layer(axum::Extension(plugin_host.clone()));
+
     // Create taskmaster router
     let taskmaster_router = Router::new()
         .route("/tasks", post(taskmaster::get_tasks))
@@ -187,6 +226,12 @@ pub async fn create_router_with_options(
         .route("/auth/callback", post(cloud::handle_oauth_callback))
         .route("/auth/status", get(cloud::get_auth_status))
         .route("/auth/logout", post(cloud::logout))
+        .route("/accounts", get(cloud::list_accounts))
+        .route("/accounts/active", post(cloud::switch_account))
+        .route(
+            "/accounts/{name}",
+            axum::routing::delete(cloud::remove_account),
+        )
         .route("/sync/status", get(cloud::get_global_sync_status))
         .route(
             "/projects/{project_id}/status",
@@ -195,58 +240,111 @@ pub async fn create_router_with_options(
         .route("/projects", get(cloud::list_cloud_projects))
         .route("/projects/sync-all", post(cloud::sync_all_projects))
         .route("/projects/{project_id}/sync", post(cloud::sync_project))
+        .route(
+            "/projects/{project_id}/snapshots",
+            get(cloud::list_project_snapshots),
+        )
+        .route(
+            "/projects/{project_id}/snapshots/{snapshot_id}/diff",
+            get(cloud::diff_project_snapshot),
+        )
+        .route(
+            "/projects/{project_id}/snapshots/{snapshot_id}/restore",
+            post(cloud::restore_project_snapshot),
+        )
+        .route(
+            "/projects/{project_id}/account",
+            axum::routing::put(cloud::pin_project_account).delete(cloud::unpin_project_account),
+        )
+        .route("/projects/{project_id}/share", post(cloud::share_project))
+        .route(
+            "/projects/{project_id}/share/{org_id}",
+            axum::routing::delete(cloud::unshare_project),
+        )
+        .route("/orgs", get(cloud::list_orgs))
+        .route("/orgs/{org_id}/projects", get(cloud::list_org_projects))
         .route("/usage", get(cloud::get_usage_stats))
-        .layer(axum::Extension(cloud::CloudState::new()));
+        .route("/queue", get(cloud::list_queue))
+        .route("/queue/replay", post(cloud::replay_queue))
+        .route("/queue/{id}/retry", post(cloud::retry_queued_operation))
+        .route(
+            "/queue/{id}",
+            axum::routing::delete(cloud::delete_queued_operation),
+        )
+        .layer(axum::Extension(cloud::CloudState::new(
+            db_state.pool.clone(),
+        )));
+    cloud::spawn_queue_replay(db_state.pool.clone());
+
+    // Periodically optimize, vacuum, and checkpoint the database
+    orkee_storage::maintenance::spawn_maintenance_task(
+        db_state.pool.clone(),
+        orkee_storage::maintenance::MAINTENANCE_INTERVAL,
+    );
+
+    // Record CI status for agent pull requests when CI can't reach us with webhooks
+    orkee_webhooks::spawn_pr_check_poller(
+        db_state.pool.clone(),
+        orkee_webhooks::PR_CHECK_POLL_INTERVAL,
+    );
 
     // Initialize telemetry manager
     // If it fails, log the error but continue without telemetry endpoints
-    let telemetry_router = match crate::telemetry::init_telemetry_manager().await {
-        Ok(manager) => {
-            let telemetry_manager = Arc::new(manager);
+    let (telemetry_router, telemetry_manager) =
+        match crate::telemetry::init_telemetry_manager().await {
+            Ok(manager) => {
+                let telemetry_manager = Arc::new(manager);
+                crate::config_service::watch_telemetry(&config_service, telemetry_manager.clone());
 
-            // Start background collector task
-            // This spawns an async task that periodically sends buffered telemetry events to PostHog
-            let pool = crate::telemetry::get_database_pool()
-                .await
-                .expect("Failed to get database pool for telemetry collector");
-            let endpoint = telemetry_manager.get_endpoint();
-            let collector = Arc::new(crate::telemetry::TelemetryCollector::new(
-                telemetry_manager.clone(),
-                pool,
-                endpoint,
-            ));
-
-            // Spawn the background task
-            tokio::spawn(async move {
-                use tracing::info;
-                info!("Starting telemetry background collector task");
-                collector.start_background_task().await;
-            });
-
-            Router::new()
-                .route("/status", get(telemetry::get_telemetry_status))
-                .route("/settings", get(telemetry::get_telemetry_settings))
-                .route(
-                    "/settings",
-                    axum::routing::put(telemetry::update_telemetry_settings),
-                )
-                .route(
-                    "/onboarding/complete",
-                    post(telemetry::complete_telemetry_onboarding),
-                )
-                .route(
-                    "/data",
-                    axum::routing::delete(telemetry::delete_telemetry_data),
-                )
-                .route("/track", post(telemetry::track_event))
-                .layer(axum::Extension(telemetry_manager))
-        }
-        Err(e) => {
-            error!("Failed to initialize telemetry manager: {}", e);
-            // Return empty router - telemetry endpoints won't be available
-            Router::new()
-        }
-    };
+                // Start background collector task
+                // This spawns an async task that periodically sends buffered telemetry events to PostHog
+                let pool = crate::telemetry::get_database_pool()
+                    .await
+                    .expect("Failed to get database pool for telemetry collector");
+                let endpoint = telemetry_manager.get_endpoint();
+                let collector = Arc::new(crate::telemetry::TelemetryCollector::new(
+                    telemetry_manager.clone(),
+                    pool,
+                    endpoint,
+                ));
+
+                // Spawn the background task
+                tokio::spawn(async move {
+                    use tracing::info;
+                    info!("Starting telemetry background collector task");
+                    collector.start_background_task().await;
+                });
+
+                let router = Router::new()
+                    .route("/status", get(telemetry::get_telemetry_status))
+                    .route("/settings", get(telemetry::get_telemetry_settings))
+                    .route(
+                        "/settings",
+                        axum::routing::put(telemetry::update_telemetry_settings),
+                    )
+                    .route(
+                        "/onboarding/complete",
+                        post(telemetry::complete_telemetry_onboarding),
+                    )
+                    .route(
+                        "/data",
+                        axum::routing::delete(telemetry::delete_telemetry_data),
+                    )
+                    .route("/track", post(telemetry::track_event))
+                    .route("/errors", get(telemetry::get_error_groups))
+                    .route(
+                        "/test-connection",
+                        post(telemetry::test_telemetry_connection),
+                    )
+                    .layer(axum::Extension(telemetry_manager.clone()));
+                (router, Some(telemetry_manager))
+            }
+            Err(e) => {
+                error!("Failed to initialize telemetry manager: {}", e);
+                // Return empty router - telemetry endpoints won't be available
+                (Router::new(), None)
+            }
+        };
 
     // Create settings router
     let settings_router = Router::new()
@@ -263,16 +361,33 @@ pub async fn create_router_with_options(
             "/bulk",
             axum::routing::put(settings_handlers::bulk_update_settings),
         )
+        .route("/export", get(settings_handlers::export_settings))
+        .route("/import", post(settings_handlers::import_settings))
+        .layer(axum::Extension(telemetry_manager))
         .with_state(db_state.clone());
 
     let mut router = Router::new()
         .route("/api/health", get(health::health_check))
+        .route(
+            "/api/health/ready",
+            get(health::readiness_check).with_state(db_state.clone()),
+        )
         .route("/api/status", get(health::status_check))
         .route("/api/csrf-token", get(health::get_csrf_token))
         .route(
             "/api/config",
             get(config::get_config).with_state(db_state.clone()),
         )
+        .route(
+            "/api/config/reload",
+            post(config::reload_config).layer(axum::Extension(config_service.clone())),
+        )
+        .route(
+            "/api/security/tls",
+            get(tls::get_tls_status).layer(axum::Extension(crate::tls::TlsManager::new(
+                config.tls.clone(),
+            ))),
+        )
         .route(
             "/api/browse-directories",
             post(directories::browse_directories),
@@ -299,6 +414,26 @@ pub async fn create_router_with_options(
             "/api/projects",
             orkee_api::create_epics_router().with_state(db_state.clone()),
         )
+        .nest(
+            "/api/projects",
+            orkee_api::create_mcp_servers_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/projects",
+            orkee_api::create_secrets_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/projects",
+            orkee_api::create_project_scripts_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/projects",
+            orkee_api::create_project_docs_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/projects",
+            orkee_api::create_task_sources_router().with_state(db_state.clone()),
+        )
         .nest(
             "/api",
             orkee_api::create_github_sync_router().with_state(db_state.clone()),
@@ -307,6 +442,9 @@ pub async fn create_router_with_options(
             "/api",
             orkee_api::create_ideate_router()
                 .with_state(db_state.clone())
+                .layer(axum::Extension(ideate_repo_path_guard(
+                    path_validator.clone(),
+                )))
                 .layer(axum::middleware::from_fn(
                     telemetry_middleware::track_api_calls,
                 )),
@@ -319,6 +457,14 @@ pub async fn create_router_with_options(
             "/api/projects",
             orkee_api::create_graph_router().with_state(db_state.clone()),
         )
+        .nest(
+            "/api/projects",
+            orkee_api::create_openspec_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/projects",
+            orkee_api::create_editors_router().with_state(db_state.clone()),
+        )
         .nest("/api/git", git_router)
         .nest(
             "/api/preview",
@@ -328,6 +474,10 @@ pub async fn create_router_with_options(
         )
         .nest("/api/cloud", cloud_router)
         .nest("/api/taskmaster", taskmaster_router)
+        .nest(
+            "/api/taskmaster",
+            orkee_api::create_taskmaster_sync_router().with_state(db_state.clone()),
+        )
         .nest("/api/telemetry", telemetry_router)
         .nest("/api/settings", settings_router)
         .nest(
@@ -379,6 +529,31 @@ pub async fn create_router_with_options(
             "/api/ai-usage",
             orkee_api::create_ai_usage_router().with_state(db_state.clone()),
         )
+        .nest(
+            "/api/notifications",
+            orkee_api::create_notifications_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/webhooks",
+            orkee_api::create_webhooks_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/scheduler",
+            orkee_api::create_scheduler_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/admin",
+            orkee_api::create_admin_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/changes",
+            orkee_api::create_changes_router().with_state(db_state.clone()),
+        )
+        .nest(
+            "/api/search",
+            orkee_api::create_search_router().with_state(db_state.clone()),
+        )
+        .nest("/api/plugins", plugins_router)
         .layer(axum::Extension(path_validator));
 
     // If dashboard path is provided, serve static files
@@ -396,5 +571,120 @@ pub async fn create_router_with_options(
         }
     }
 
-    (router, db_state)
+    (router, db_state, config_service)
+}
+
+/// Validate ideate `repoPath` imports against the same sandbox as directory browsing
+fn ideate_repo_path_guard(
+    validator: std::sync::Arc<path_validator::PathValidator>,
+) -> orkee_api::ideate_handlers::RepoPathGuard {
+    std::sync::Arc::new(move |path: &str| validator.validate_path(path).map_err(|e| e.to_string()))
+}
+
+/// Refresh OAuth tokens in the background and notify when one needs a new login
+fn spawn_oauth_token_refresher(db_state: &orkee_projects::DbState) {
+    use orkee_auth::oauth::{BackgroundRefresher, RefreshConfig, RefreshEvent};
+    use orkee_notifications::{NotificationEventType, NotificationInput, NotificationSeverity};
+    use tokio::sync::broadcast::error::RecvError;
+
+    let refresher = match BackgroundRefresher::new(db_state.pool.clone(), RefreshConfig::default())
+    {
+        Ok(refresher) => std::sync::Arc::new(refresher),
+        Err(e) => {
+            error!("Failed to start OAuth token refresher: {}", e);
+            return;
+        }
+    };
+
+    let mut events = refresher.subscribe();
+    let notifications = db_state.notifications.clone();
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(RefreshEvent::RefreshFailed {
+                    provider,
+                    error,
+                    reauth_required: true,
+                    ..
+                }) => {
+                    notifications.notify_in_background(NotificationInput::new(
+                        NotificationEventType::ReauthRequired,
+                        NotificationSeverity::Warning,
+                        format!("Log in to {} again", provider),
+                        format!(
+                            "Your {} token could not be refreshed: {}. Run `orkee auth login {}` or re-import it in Settings.",
+                            provider, error, provider
+                        ),
+                    ));
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    refresher.spawn();
+}
+
+/// Apply sandbox execution log retention policies every hour
+fn spawn_log_retention(db_state: &orkee_projects::DbState) {
+    let log_retention = db_state.log_retention.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            if let Err(e) = log_retention.enforce().await {
+                error!("Failed to enforce sandbox log retention: {}", e);
+            }
+        }
+    });
+}
+
+/// Start queued agent executions once their not-before time passes
+fn spawn_execution_queue(db_state: &orkee_projects::DbState) {
+    let executions = db_state.execution_storage.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+        loop {
+            interval.tick().await;
+            match executions.start_due_executions().await {
+                Ok(started) if !started.is_empty() => {
+                    info!("Started {} queued execution(s)", started.len())
+                }
+                Ok(_) => {}
+                Err(e) => error!("Failed to start queued executions: {}", e),
+            }
+        }
+    });
+}
+
+/// Forward preview server errors to the notification dispatcher
+fn spawn_server_crash_notifications(
+    preview_manager: &orkee_preview::PreviewManager,
+    db_state: &orkee_projects::DbState,
+) {
+    use orkee_notifications::{NotificationEventType, NotificationInput, NotificationSeverity};
+    use tokio::sync::broadcast::error::RecvError;
+
+    let mut events = preview_manager.subscribe();
+    let notifications = db_state.notifications.clone();
+    tokio::spawn(async move {
+        loop {
+            match events.recv().await {
+                Ok(orkee_preview::ServerEvent::ServerError { project_id, error }) => {
+                    notifications.notify_in_background(
+                        NotificationInput::new(
+                            NotificationEventType::ServerCrash,
+                            NotificationSeverity::Error,
+                            "Preview server crashed",
+                            error,
+                        )
+                        .with_project(project_id),
+                    );
+                }
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
 }
@@ -183,21 +183,14 @@ impl PathValidator {
     fn check_path_components(&self, path: &Path) -> Result<(), ValidationError> {
         for component in path.components() {
             match component {
-                Component::ParentDir => {
-                    // Allow parent dir navigation only in relaxed/disabled modes
-                    if self.sandbox_mode == SandboxMode::Strict {
-                        return Err(ValidationError::PathTraversal);
-                    }
-                }
-                Component::RootDir => {
-                    // In strict mode, root access is only blocked if the path is NOT in allowed paths
-                    // Since we check allowed paths first in strict mode, if we reach here, it means
-                    // the path was approved or we're not in strict mode
-                    if self.sandbox_mode == SandboxMode::Strict {
-                        // If we reached here, the path was already approved by check_allowed_paths
-                        // so root access is OK
-                    }
+                // Allow parent dir navigation only in relaxed/disabled modes
+                Component::ParentDir if self.sandbox_mode == SandboxMode::Strict => {
+                    return Err(ValidationError::PathTraversal);
                 }
+                // In strict mode, root access is only blocked if the path is NOT in allowed paths
+                // Since we check allowed paths first in strict mode, if we reach here, it means
+                // the path was approved by check_allowed_paths or we're not in strict mode
+                Component::RootDir => {}
                 Component::Normal(name) => {
                     if let Some(name_str) = name.to_str() {
                         if self.is_sensitive_directory(name_str) {
@@ -358,11 +351,14 @@ mod tests {
     fn create_test_config(mode: SandboxMode, allowed: Vec<&str>) -> Config {
         Config {
             port: 4001,
+            host: std::net::IpAddr::V4(std::net::Ipv4Addr::LOCALHOST),
             cors_origin: "http://localhost:5173".to_string(),
             cors_allow_any_localhost: true,
+            cors_remote_origins: Vec::new(),
             allowed_browse_paths: allowed.iter().map(|s| s.to_string()).collect(),
             browse_sandbox_mode: mode,
             rate_limit: crate::middleware::RateLimitConfig::default(),
+            request_limits: crate::middleware::RequestLimitsConfig::default(),
             security_headers_enabled: true,
             enable_hsts: false,
             enable_request_id: true,
@@ -371,6 +367,8 @@ mod tests {
                 cert_path: "/tmp/cert.pem".into(),
                 key_path: "/tmp/key.pem".into(),
                 auto_generate: false,
+                acme: crate::tls::acme::AcmeConfig::default(),
+                client_auth: crate::tls::mtls::ClientAuthConfig::default(),
             },
         }
     }
@@ -0,0 +1,97 @@
+// ABOUTME: API endpoints for WASM plugins loaded from ~/.orkee/plugins
+// ABOUTME: Lists loaded plugins and forwards /api/plugins/{plugin}/... requests to the plugin's routes
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query},
+    http::{Method, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use orkee_api::response::{bad_request, ApiResponse};
+use orkee_plugins::{PluginError, PluginHost, PluginLoadFailure, PluginSummary, ProjectServices};
+use orkee_projects::DbState;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{error, info};
+
+/// Load plugins unless the `plugins_enabled` setting turns them off
+pub async fn load_plugins(db_state: &DbState) -> PluginHost {
+    if !orkee_plugins::plugins_enabled(&db_state.pool).await {
+        info!("Plugins are disabled; not loading ~/.orkee/plugins");
+        return PluginHost::empty();
+    }
+    PluginHost::load_dir(&orkee_plugins::plugins_dir(), Arc::new(ProjectServices)).await
+}
+
+#[derive(Serialize)]
+pub struct PluginsResponse {
+    pub plugins: Vec<PluginSummary>,
+    /// Plugin directories that failed to load, with the reason
+    pub failures: Vec<PluginLoadFailure>,
+}
+
+/// GET /api/plugins - loaded plugins and what they registered
+pub async fn list_plugins(
+    Extension(host): Extension<Arc<PluginHost>>,
+) -> Json<ApiResponse<PluginsResponse>> {
+    Json(ApiResponse::success(PluginsResponse {
+        plugins: host.summaries(),
+        failures: host.failures().to_vec(),
+    }))
+}
+
+/// Any method on /api/plugins/{plugin}/{*path} - run the plugin's matching route.
+/// The plugin chooses the status code and JSON body of the response.
+pub async fn dispatch(
+    Extension(host): Extension<Arc<PluginHost>>,
+    method: Method,
+    Path((plugin_name, path)): Path<(String, String)>,
+    Query(query): Query<HashMap<String, String>>,
+    body: Bytes,
+) -> Response {
+    let Some(plugin) = host.get(&plugin_name) else {
+        return not_found(format!("Plugin not found: {}", plugin_name));
+    };
+
+    let body = if body.is_empty() {
+        None
+    } else {
+        match serde_json::from_slice(&body) {
+            Ok(body) => Some(body),
+            Err(e) => return bad_request(e, "Invalid JSON body"),
+        }
+    };
+
+    match plugin
+        .handle_route(method.as_str(), &format!("/{}", path), query, body)
+        .await
+    {
+        Ok(response) => {
+            let status =
+                StatusCode::from_u16(response.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            (status, Json(response.body)).into_response()
+        }
+        Err(PluginError::NotFound(e)) => not_found(format!("Route not found: {}", e)),
+        Err(e) => {
+            error!(
+                "Plugin {} failed to handle {} /{}: {}",
+                plugin_name, method, path, e
+            );
+            (
+                StatusCode::BAD_GATEWAY,
+                Json(ApiResponse::<()>::error(e.to_string())),
+            )
+                .into_response()
+        }
+    }
+}
+
+fn not_found(message: String) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Json(ApiResponse::<()>::error(message)),
+    )
+        .into_response()
+}
@@ -1,17 +1,20 @@
 use axum::{
     extract::{ConnectInfo, Path, Query, State},
-    http::StatusCode,
+    http::{header, HeaderName, StatusCode},
     response::{
         sse::{Event, KeepAlive, Sse},
-        Json,
+        IntoResponse, Json, Response,
     },
 };
 use chrono::{DateTime, Utc};
 use futures::stream::{self, Stream};
 use orkee_preview::{
+    metrics::METRICS_SAMPLE_INTERVAL,
+    qr::{self, QrFormat},
     types::{
-        ApiResponse, ServerEvent, ServerLogsResponse, ServerStatusInfo, ServerStatusResponse,
-        ServersResponse, StartServerRequest, StartServerResponse,
+        ApiResponse, PreviewError, ServerEvent, ServerLogsResponse, ServerMetricsResponse,
+        ServerStatusInfo, ServerStatusResponse, ServersResponse, StartServerRequest,
+        StartServerResponse,
     },
     PreviewManager, ServerInfo,
 };
@@ -196,18 +199,20 @@ pub async fn start_server(
     };
 
     let project_root = std::path::PathBuf::from(&project.project_root);
+    let secrets = load_project_secrets(&state, &project_id).await;
 
     // Start the simplified server
     match state
         .preview_manager
-        .start_server(project_id.clone(), project_root)
+        .start_server_with_env(project_id.clone(), project_root, &secrets)
         .await
     {
         Ok(server_info) => {
             info!("Successfully started server: {}", server_info.id);
 
             // Convert ServerInfo to DevServerInstance for compatibility
-            let instance = convert_server_info_to_instance(server_info);
+            let mut instance = convert_server_info_to_instance(server_info);
+            instance.config.services = state.preview_manager.service_configs(&project_id).await;
             Ok(Json(ApiResponse::success(StartServerResponse { instance })))
         }
         Err(e) => {
@@ -220,6 +225,18 @@ pub async fn start_server(
     }
 }
 
+/// Decrypt a project's secrets for injection into a preview server.
+/// Failures are logged and the server starts without them.
+async fn load_project_secrets(state: &PreviewState, project_id: &str) -> HashMap<String, String> {
+    match state.db_state.secret_storage.environment(project_id).await {
+        Ok(secrets) => secrets,
+        Err(e) => {
+            warn!("Failed to load secrets for project {}: {}", project_id, e);
+            HashMap::new()
+        }
+    }
+}
+
 /// Convert ServerInfo to DevServerInstance for API compatibility
 fn convert_server_info_to_instance(info: ServerInfo) -> orkee_preview::types::DevServerInstance {
     use chrono::Utc;
@@ -257,6 +274,7 @@ fn convert_server_info_to_instance(info: ServerInfo) -> orkee_preview::types::De
                 name: framework_name,
                 version: None,
             }),
+            services: Vec::new(),
         },
         status: info.status,
         preview_url: info.preview_url,
@@ -346,7 +364,10 @@ pub async fn get_server_status(
     State(state): State<PreviewState>,
 ) -> Json<ApiResponse<ServerStatusResponse>> {
     let server_info = state.preview_manager.get_server_status(&project_id).await;
-    let instance = server_info.map(convert_server_info_to_instance);
+    let mut instance = server_info.map(convert_server_info_to_instance);
+    if let Some(instance) = instance.as_mut() {
+        instance.config.services = state.preview_manager.service_configs(&project_id).await;
+    }
     Json(ApiResponse::success(ServerStatusResponse { instance }))
 }
 
@@ -379,6 +400,78 @@ pub async fn get_server_logs(
     Json(ApiResponse::success(ServerLogsResponse { logs }))
 }
 
+/// Get the rolling CPU/memory history of a project's server
+pub async fn get_server_metrics(
+    Path(project_id): Path<String>,
+    State(state): State<PreviewState>,
+) -> Json<ApiResponse<ServerMetricsResponse>> {
+    let samples = state.preview_manager.get_server_metrics(&project_id).await;
+    Json(ApiResponse::success(ServerMetricsResponse {
+        project_id,
+        interval_secs: METRICS_SAMPLE_INTERVAL.as_secs(),
+        samples,
+    }))
+}
+
+/// Query parameters for the QR code endpoint
+#[derive(Debug, Deserialize)]
+pub struct QrQuery {
+    #[serde(default)]
+    format: QrFormat,
+}
+
+/// QR code encoding the LAN URL of a project's server, for opening it on a phone
+pub async fn get_server_qr(
+    Path(project_id): Path<String>,
+    Query(query): Query<QrQuery>,
+    State(state): State<PreviewState>,
+) -> Response {
+    let Some(server) = state.preview_manager.get_server_status(&project_id).await else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::<()>::error(format!(
+                "No server running for project: {}",
+                project_id
+            ))),
+        )
+            .into_response();
+    };
+    let Some(ip) = qr::lan_ip() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ApiResponse::<()>::error(PreviewError::NoLanAddress)),
+        )
+            .into_response();
+    };
+
+    let url = qr::lan_url(ip, server.port);
+    let rendered = match query.format {
+        QrFormat::Png => qr::render_png(&url).map(|png| ("image/png", png)),
+        QrFormat::Ascii => {
+            qr::render_ascii(&url).map(|text| ("text/plain; charset=utf-8", text.into_bytes()))
+        }
+    };
+
+    match rendered {
+        Ok((content_type, body)) => (
+            [
+                (header::CONTENT_TYPE, content_type.to_string()),
+                (HeaderName::from_static("x-preview-url"), url),
+            ],
+            body,
+        )
+            .into_response(),
+        Err(e) => {
+            error!("Failed to render QR code for {}: {}", url, e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error(e)),
+            )
+                .into_response()
+        }
+    }
+}
+
 /// Clear server logs
 pub async fn clear_server_logs(
     Path(project_id): Path<String>,
@@ -402,6 +495,7 @@ pub async fn list_active_servers(
     State(state): State<PreviewState>,
 ) -> Json<ApiResponse<ServersResponse>> {
     let servers = state.preview_manager.list_servers().await;
+    let mut resource_usage = state.preview_manager.latest_resource_usage().await;
 
     // Fetch all projects in a single batch to get project names
     // This avoids N+1 query problem where each server would require a separate project lookup
@@ -420,40 +514,52 @@ pub async fn list_active_servers(
     };
 
     // Convert ServerInfo to a format suitable for the tray menu
-    let server_list: Vec<ServerStatusInfo> = servers
-        .into_iter()
-        .map(|info| {
-            // Get the project name from the batch-fetched map
-            let project_name = project_names.get(&info.project_id).cloned();
-
-            ServerStatusInfo {
-                id: info.id.to_string(),
-                project_id: info.project_id.clone(),
-                project_name,
-                port: info.port,
-                url: info
-                    .preview_url
-                    .clone()
-                    .unwrap_or_else(|| format!("http://localhost:{}", info.port)),
-                status: format!("{:?}", info.status), // Convert enum to string
-                framework_name: info.framework_name.clone(),
-                started_at: None, // Could add timestamp tracking if needed
-                source: info.source,
-            }
-        })
-        .collect();
+    let mut server_list: Vec<ServerStatusInfo> = Vec::with_capacity(servers.len());
+    for info in servers {
+        // Get the project name from the batch-fetched map
+        let project_name = project_names.get(&info.project_id).cloned();
+
+        // Projects with a service group report the status of the group as a whole
+        let services = state
+            .preview_manager
+            .service_statuses(&info.project_id)
+            .await;
+        let status = if services.is_empty() {
+            info.status.clone()
+        } else {
+            orkee_preview::services::aggregate_status(services.iter().map(|s| &s.status))
+        };
+
+        server_list.push(ServerStatusInfo {
+            id: info.id.to_string(),
+            project_id: info.project_id.clone(),
+            project_name,
+            port: info.port,
+            url: info
+                .preview_url
+                .clone()
+                .unwrap_or_else(|| format!("http://localhost:{}", info.port)),
+            status: format!("{:?}", status), // Convert enum to string
+            framework_name: info.framework_name.clone(),
+            started_at: None, // Could add timestamp tracking if needed
+            source: info.source,
+            resource_usage: resource_usage.remove(&info.project_id),
+            services,
+        });
+    }
 
     Json(ApiResponse::success(ServersResponse {
         servers: server_list,
     }))
 }
 
-/// Discover external servers running on common development ports
+/// Discover external servers on the ports configured in the discovery settings
 pub async fn discover_servers(State(state): State<PreviewState>) -> Json<ApiResponse<Vec<String>>> {
     info!("Triggering external server discovery");
 
     // Run discovery
-    let discovered = orkee_preview::discover_external_servers().await;
+    let config = state.preview_manager.discovery_config().await;
+    let discovered = orkee_preview::discover_external_servers(&config).await;
 
     let mut registered_ids = Vec::new();
 
@@ -556,7 +662,8 @@ pub async fn restart_external_server(
 
     // Load environment variables from project directory
     let project_root = std::path::PathBuf::from(&project.project_root);
-    let env_vars = orkee_preview::load_env_from_directory(&project_root);
+    let mut env_vars = orkee_preview::load_env_from_directory(&project_root);
+    env_vars.extend(load_project_secrets(&state, project_id).await);
 
     // Restart the server
     match state
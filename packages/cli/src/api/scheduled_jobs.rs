@@ -0,0 +1,265 @@
+// ABOUTME: Handlers for the built-in scheduled jobs run by the server's scheduler
+// ABOUTME: Database backups, cloud sync, model catalog reconciliation, stale servers, task source imports, and plugin jobs
+
+use async_trait::async_trait;
+use axum::{Extension, Json};
+use orkee_plugins::{Plugin, PluginHost};
+use orkee_preview::PreviewManager;
+use orkee_projects::DbState;
+use orkee_scheduler::JobHandler;
+use orkee_task_sources::TaskImporter;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::cloud::{self, CloudState, SyncAllRequest};
+
+/// Number of nightly backups kept in ~/.orkee/backups
+const BACKUPS_TO_KEEP: usize = 7;
+const BACKUP_PREFIX: &str = "orkee-backup-";
+
+/// Register the built-in and plugin job handlers and start the scheduler loop
+pub async fn start_scheduler(
+    db_state: &DbState,
+    preview_manager: Arc<PreviewManager>,
+    plugins: &PluginHost,
+) {
+    let scheduler = &db_state.scheduler;
+    scheduler
+        .register("database_backup", Arc::new(DatabaseBackupJob))
+        .await;
+    scheduler
+        .register(
+            "cloud_sync",
+            Arc::new(CloudSyncJob {
+                state: CloudState::new(db_state.pool.clone()),
+            }),
+        )
+        .await;
+    scheduler
+        .register(
+            "model_catalog_refresh",
+            Arc::new(ModelCatalogRefreshJob {
+                pool: db_state.pool.clone(),
+            }),
+        )
+        .await;
+    scheduler
+        .register(
+            "stale_server_cleanup",
+            Arc::new(StaleServerCleanupJob { preview_manager }),
+        )
+        .await;
+    scheduler
+        .register(
+            "task_source_import",
+            Arc::new(TaskSourceImportJob {
+                importer: db_state.task_sources.clone(),
+            }),
+        )
+        .await;
+
+    // Plugin jobs are added to scheduled_jobs on first load; users manage them like built-ins
+    for (job_id, plugin, job) in plugins.jobs() {
+        if let Err(e) = scheduler
+            .storage()
+            .ensure_job(
+                &job_id,
+                &job.name,
+                job.description.as_deref(),
+                &job.cron_expression,
+            )
+            .await
+        {
+            tracing::warn!("Not scheduling plugin job {}: {}", job_id, e);
+            continue;
+        }
+        scheduler
+            .register(
+                &job_id,
+                Arc::new(PluginJob {
+                    plugin,
+                    job: job.id,
+                }),
+            )
+            .await;
+    }
+
+    scheduler.start();
+}
+
+fn backups_dir() -> PathBuf {
+    orkee_projects::orkee_dir().join("backups")
+}
+
+/// Write a compressed database snapshot and prune old backups
+struct DatabaseBackupJob;
+
+#[async_trait]
+impl JobHandler for DatabaseBackupJob {
+    async fn run(&self) -> Result<String, String> {
+        let data = orkee_projects::export_database()
+            .await
+            .map_err(|e| format!("Failed to export database: {}", e))?;
+
+        let dir = backups_dir();
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+        let timestamp = chrono::Utc::now().format("%Y-%m-%d-%H%M%S");
+        let path = dir.join(format!("{}{}.gz", BACKUP_PREFIX, timestamp));
+        tokio::fs::write(&path, &data)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+
+        let pruned = prune_backups(&dir).await;
+        Ok(format!(
+            "Wrote {} ({} bytes), pruned {} old backups",
+            path.display(),
+            data.len(),
+            pruned
+        ))
+    }
+}
+
+/// Remove all but the newest `BACKUPS_TO_KEEP` backups. Timestamped names sort chronologically.
+async fn prune_backups(dir: &Path) -> usize {
+    let mut backups = Vec::new();
+    if let Ok(mut entries) = tokio::fs::read_dir(dir).await {
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with(BACKUP_PREFIX) && name.ends_with(".gz") {
+                backups.push(entry.path());
+            }
+        }
+    }
+    backups.sort();
+
+    let excess = backups.len().saturating_sub(BACKUPS_TO_KEEP);
+    let mut pruned = 0;
+    for path in backups.into_iter().take(excess) {
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => pruned += 1,
+            Err(e) => tracing::warn!("Failed to remove old backup {}: {}", path.display(), e),
+        }
+    }
+    pruned
+}
+
+/// Sync all local projects to Orkee Cloud; unreachable syncs are queued for replay
+struct CloudSyncJob {
+    state: CloudState,
+}
+
+#[async_trait]
+impl JobHandler for CloudSyncJob {
+    async fn run(&self) -> Result<String, String> {
+        let request = SyncAllRequest {
+            force: None,
+            exclude_projects: None,
+        };
+        let Json(response) = cloud::sync_all_projects(Extension(self.state.clone()), Json(request))
+            .await
+            .map_err(|status| format!("Cloud sync failed: {}", status))?;
+
+        if !response.success {
+            return Err(response
+                .error
+                .unwrap_or_else(|| "Cloud sync failed".to_string()));
+        }
+
+        let results = response.data.unwrap_or_default();
+        let synced = results.iter().filter(|result| result.success).count();
+        let failed = results.len() - synced;
+        if failed > 0 && synced == 0 {
+            return Err(format!("Failed to sync {} projects", failed));
+        }
+        Ok(format!("Synced {} projects, {} not synced", synced, failed))
+    }
+}
+
+/// Clear saved agent/model references that are no longer in the model catalog
+struct ModelCatalogRefreshJob {
+    pool: SqlitePool,
+}
+
+#[async_trait]
+impl JobHandler for ModelCatalogRefreshJob {
+    async fn run(&self) -> Result<String, String> {
+        let cleared = orkee_projects::clear_orphaned_model_references(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(format!(
+            "Cleared {} orphaned agent/model references",
+            cleared
+        ))
+    }
+}
+
+/// Remove preview servers that stopped running or have not been seen recently
+struct StaleServerCleanupJob {
+    preview_manager: Arc<PreviewManager>,
+}
+
+#[async_trait]
+impl JobHandler for StaleServerCleanupJob {
+    async fn run(&self) -> Result<String, String> {
+        let removed = self.preview_manager.cleanup_stale_servers().await?;
+        Ok(format!("Removed {} stale servers", removed))
+    }
+}
+
+/// Import new and updated issues from every enabled Linear and Jira task source
+struct TaskSourceImportJob {
+    importer: Arc<TaskImporter>,
+}
+
+#[async_trait]
+impl JobHandler for TaskSourceImportJob {
+    async fn run(&self) -> Result<String, String> {
+        let results = self
+            .importer
+            .import_all()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let (mut created, mut updated, mut failed) = (0, 0, 0);
+        for (_, result) in &results {
+            match result {
+                Ok(summary) => {
+                    created += summary.created;
+                    updated += summary.updated;
+                }
+                Err(_) => failed += 1,
+            }
+        }
+        if failed > 0 && failed == results.len() {
+            return Err(format!("Failed to import {} task sources", failed));
+        }
+        Ok(format!(
+            "Imported {} task sources: {} tasks created, {} updated, {} sources failed",
+            results.len() - failed,
+            created,
+            updated,
+            failed
+        ))
+    }
+}
+
+/// Run a job registered by a WASM plugin
+struct PluginJob {
+    plugin: Arc<Plugin>,
+    job: String,
+}
+
+#[async_trait]
+impl JobHandler for PluginJob {
+    async fn run(&self) -> Result<String, String> {
+        self.plugin
+            .run_job(&self.job)
+            .await
+            .map(|response| response.message)
+            .map_err(|e| e.to_string())
+    }
+}
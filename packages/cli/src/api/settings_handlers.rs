@@ -1,18 +1,30 @@
 // ABOUTME: API handlers for system settings management
-// ABOUTME: REST endpoints for runtime configuration
+// ABOUTME: REST endpoints for runtime configuration and instance configuration bundles
 
 use crate::error::AppError;
+use crate::telemetry::TelemetryManager;
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
-    Json,
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Extension, Json,
 };
+use chrono::{DateTime, Utc};
 use orkee_projects::{
-    db::DbState, BulkSettingUpdate, SettingUpdate, SettingsResponse, StorageError,
+    db::DbState, BulkSettingUpdate, SettingUpdate, SettingUpdateItem, SettingsResponse,
+    StorageError,
 };
+use orkee_sandbox::SandboxSettings;
+use orkee_storage::model_preferences::ModelPreferences;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::Arc;
 use tracing::info;
 
+/// Bundle layout version, bumped when fields are removed or change meaning
+const SETTINGS_BUNDLE_VERSION: u32 = 1;
+
 /// Get all settings
 pub async fn get_settings(State(db): State<DbState>) -> Result<Json<Value>, StatusCode> {
     info!("Getting all system settings");
@@ -125,3 +137,310 @@ pub async fn bulk_update_settings(
         }
     }
 }
+
+/// A configured instance's settings, without secrets, for replicating onto another machine
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettingsBundle {
+    pub version: u32,
+    pub orkee_version: String,
+    pub exported_at: DateTime<Utc>,
+    /// Runtime system settings by key, including the editor category. Secret and
+    /// environment-only settings are left out.
+    #[serde(default)]
+    pub system_settings: BTreeMap<String, String>,
+    pub model_preferences: Option<ModelPreferences>,
+    pub sandbox_settings: Option<SandboxSettings>,
+    pub telemetry: Option<TelemetryChoices>,
+}
+
+/// The user's telemetry opt-ins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryChoices {
+    pub error_reporting: bool,
+    pub usage_metrics: bool,
+    pub non_anonymous_metrics: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleFormat {
+    #[default]
+    Json,
+    Toml,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BundleFormatQuery {
+    pub format: Option<BundleFormat>,
+}
+
+/// What an import changed
+#[derive(Debug, Default, Serialize)]
+pub struct SettingsImportSummary {
+    pub system_settings_applied: usize,
+    /// Keys that are unknown here, secret, or environment-only
+    pub system_settings_skipped: Vec<String>,
+    pub model_preferences_applied: bool,
+    pub sandbox_settings_applied: bool,
+    pub telemetry_applied: bool,
+    pub requires_restart: bool,
+}
+
+impl SettingsBundle {
+    fn to_format(&self, format: BundleFormat) -> Result<String, String> {
+        match format {
+            BundleFormat::Json => serde_json::to_string_pretty(self).map_err(|e| e.to_string()),
+            BundleFormat::Toml => toml::to_string_pretty(self).map_err(|e| e.to_string()),
+        }
+    }
+
+    /// Parse a bundle, trying JSON then TOML when no format is given
+    fn parse(body: &str, format: Option<BundleFormat>) -> Result<Self, String> {
+        let bundle: Self = match format {
+            Some(BundleFormat::Json) => serde_json::from_str(body).map_err(|e| e.to_string())?,
+            Some(BundleFormat::Toml) => toml::from_str(body).map_err(|e| e.to_string())?,
+            None => match serde_json::from_str(body) {
+                Ok(bundle) => bundle,
+                Err(_) => toml::from_str(body)
+                    .map_err(|e| format!("Bundle is neither valid JSON nor TOML: {}", e))?,
+            },
+        };
+
+        if bundle.version > SETTINGS_BUNDLE_VERSION {
+            return Err(format!(
+                "Bundle version {} is newer than this Orkee supports ({})",
+                bundle.version, SETTINGS_BUNDLE_VERSION
+            ));
+        }
+        Ok(bundle)
+    }
+}
+
+/// Export system settings, model preferences, sandbox settings, telemetry choices,
+/// and editor config as one JSON or TOML bundle
+pub async fn export_settings(
+    State(db): State<DbState>,
+    Extension(telemetry): Extension<Option<Arc<TelemetryManager>>>,
+    Query(query): Query<BundleFormatQuery>,
+) -> Result<Response, AppError> {
+    let format = query.format.unwrap_or_default();
+    info!("Exporting settings bundle as {:?}", format);
+
+    let system_settings = db
+        .settings_storage
+        .get_all()
+        .await
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?
+        .into_iter()
+        .filter(|s| !s.is_secret && !s.is_env_only)
+        .map(|s| (s.key, s.value))
+        .collect();
+
+    let user = db
+        .user_storage
+        .get_current_user()
+        .await
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Failed to resolve user: {}", e)))?;
+    let model_preferences = db
+        .model_preferences_storage
+        .get_preferences(&user.id)
+        .await
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?;
+
+    let sandbox_settings = db
+        .sandbox_settings
+        .get_sandbox_settings()
+        .await
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?;
+
+    let telemetry = match telemetry {
+        Some(manager) => {
+            let settings = manager.get_settings().await;
+            Some(TelemetryChoices {
+                error_reporting: settings.error_reporting,
+                usage_metrics: settings.usage_metrics,
+                non_anonymous_metrics: settings.non_anonymous_metrics,
+            })
+        }
+        None => None,
+    };
+
+    let bundle = SettingsBundle {
+        version: SETTINGS_BUNDLE_VERSION,
+        orkee_version: env!("CARGO_PKG_VERSION").to_string(),
+        exported_at: Utc::now(),
+        system_settings,
+        model_preferences: Some(model_preferences),
+        sandbox_settings: Some(sandbox_settings),
+        telemetry,
+    };
+
+    let body = bundle
+        .to_format(format)
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Failed to serialize bundle: {}", e)))?;
+    let (content_type, extension) = match format {
+        BundleFormat::Json => ("application/json", "json"),
+        BundleFormat::Toml => ("application/toml", "toml"),
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"orkee-settings.{}\"", extension),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+/// Apply a bundle produced by [`export_settings`]. Sections missing from the bundle
+/// are left untouched.
+pub async fn import_settings(
+    State(db): State<DbState>,
+    Extension(telemetry): Extension<Option<Arc<TelemetryManager>>>,
+    Query(query): Query<BundleFormatQuery>,
+    body: String,
+) -> Result<Json<Value>, AppError> {
+    let bundle = SettingsBundle::parse(&body, query.format).map_err(AppError::Validation)?;
+    info!(
+        "Importing settings bundle exported by Orkee {} at {}",
+        bundle.orkee_version, bundle.exported_at
+    );
+
+    if let Some(choices) = &bundle.telemetry {
+        if choices.non_anonymous_metrics && !choices.error_reporting && !choices.usage_metrics {
+            return Err(AppError::Validation(
+                "Non-anonymous metrics require either error_reporting or usage_metrics to be enabled"
+                    .to_string(),
+            ));
+        }
+    }
+
+    let mut summary = SettingsImportSummary::default();
+
+    // Only apply settings this instance knows and allows changing at runtime
+    let current: BTreeMap<String, _> = db
+        .settings_storage
+        .get_all()
+        .await
+        .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?
+        .into_iter()
+        .map(|s| (s.key.clone(), s))
+        .collect();
+    let mut updates = Vec::new();
+    for (key, value) in bundle.system_settings {
+        match current.get(&key) {
+            Some(setting) if !setting.is_secret && !setting.is_env_only => {
+                if setting.value != value {
+                    updates.push(SettingUpdateItem { key, value });
+                }
+            }
+            _ => summary.system_settings_skipped.push(key),
+        }
+    }
+    if !updates.is_empty() {
+        let updated = db
+            .settings_storage
+            .bulk_update(BulkSettingUpdate { settings: updates }, "import")
+            .await
+            .map_err(|e| match e {
+                StorageError::Validation(msg) => AppError::Validation(msg),
+                e => AppError::internal(anyhow::anyhow!("Storage error: {}", e)),
+            })?;
+        summary.requires_restart = updated.iter().any(|s| s.requires_restart);
+        summary.system_settings_applied = updated.len();
+    }
+
+    if let Some(mut preferences) = bundle.model_preferences {
+        let user =
+            db.user_storage.get_current_user().await.map_err(|e| {
+                AppError::internal(anyhow::anyhow!("Failed to resolve user: {}", e))
+            })?;
+        preferences.user_id = user.id;
+        db.model_preferences_storage
+            .update_preferences(&preferences)
+            .await
+            .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?;
+        summary.model_preferences_applied = true;
+    }
+
+    if let Some(mut sandbox) = bundle.sandbox_settings {
+        let current = db
+            .sandbox_settings
+            .get_sandbox_settings()
+            .await
+            .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?;
+        // Updates are optimistically locked on the stored timestamp
+        sandbox.updated_at = current.updated_at;
+        db.sandbox_settings
+            .update_sandbox_settings(&sandbox, Some("import"))
+            .await
+            .map_err(|e| AppError::internal(anyhow::anyhow!("Storage error: {}", e)))?;
+        summary.sandbox_settings_applied = true;
+    }
+
+    if let (Some(choices), Some(manager)) = (bundle.telemetry, telemetry) {
+        manager
+            .update_settings_atomic(
+                choices.error_reporting,
+                choices.usage_metrics,
+                choices.non_anonymous_metrics,
+            )
+            .await
+            .map_err(|e| AppError::internal(anyhow::anyhow!("Telemetry error: {}", e)))?;
+        summary.telemetry_applied = true;
+    }
+
+    Ok(Json(json!({
+        "success": true,
+        "data": summary,
+        "error": null
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundle() -> SettingsBundle {
+        SettingsBundle {
+            version: SETTINGS_BUNDLE_VERSION,
+            orkee_version: "0.0.0".to_string(),
+            exported_at: Utc::now(),
+            system_settings: BTreeMap::from([
+                ("editor_preferred".to_string(), "zed".to_string()),
+                ("rate_limit_enabled".to_string(), "true".to_string()),
+            ]),
+            model_preferences: None,
+            sandbox_settings: None,
+            telemetry: Some(TelemetryChoices {
+                error_reporting: true,
+                usage_metrics: false,
+                non_anonymous_metrics: false,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_bundle_round_trips_through_json_and_toml() {
+        for format in [BundleFormat::Json, BundleFormat::Toml] {
+            let text = bundle().to_format(format).unwrap();
+            // Format detection picks the right parser when none is given
+            let parsed = SettingsBundle::parse(&text, None).unwrap();
+            assert_eq!(parsed.system_settings, bundle().system_settings);
+            assert!(parsed.telemetry.unwrap().error_reporting);
+        }
+    }
+
+    #[test]
+    fn test_newer_bundle_versions_are_rejected() {
+        let mut newer = bundle();
+        newer.version = SETTINGS_BUNDLE_VERSION + 1;
+        let text = newer.to_format(BundleFormat::Json).unwrap();
+        assert!(SettingsBundle::parse(&text, None).is_err());
+        assert!(SettingsBundle::parse("not a bundle", None).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 // ABOUTME: Provides REST API for frontend to manage telemetry preferences
 
 use axum::{
-    extract::{Extension, Json},
+    extract::{Extension, Json, Query},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -10,7 +10,7 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tracing::{error, info};
 
-use crate::telemetry::TelemetryManager;
+use crate::telemetry::{ErrorGroup, TelemetryCollector, TelemetryManager};
 
 #[derive(Debug, Serialize)]
 pub struct TelemetryStatusResponse {
@@ -282,6 +282,58 @@ pub async fn track_event(
     }
 }
 
+/// Query parameters for listing grouped errors
+#[derive(Debug, Deserialize)]
+pub struct ErrorGroupsQuery {
+    #[serde(default = "default_error_groups_limit")]
+    pub limit: usize,
+}
+
+fn default_error_groups_limit() -> usize {
+    50
+}
+
+/// GET /api/telemetry/errors
+/// Returns recorded errors grouped by fingerprint for local inspection
+pub async fn get_error_groups(
+    Extension(telemetry_manager): Extension<Arc<TelemetryManager>>,
+    Query(query): Query<ErrorGroupsQuery>,
+) -> Result<Json<ApiResponse<Vec<ErrorGroup>>>, (StatusCode, Json<ApiResponse<()>>)> {
+    match telemetry_manager.get_error_groups(query.limit).await {
+        Ok(groups) => Ok(Json(ApiResponse::success(groups))),
+        Err(e) => {
+            error!("Failed to load telemetry error groups: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::<()>::error(format!(
+                    "Failed to load error groups: {}",
+                    e
+                ))),
+            ))
+        }
+    }
+}
+
+/// POST /api/telemetry/test-connection
+/// Sends a probe to the configured sink (PostHog or a self-hosted collector)
+/// and reports whether it was accepted
+pub async fn test_telemetry_connection(
+    Extension(telemetry_manager): Extension<Arc<TelemetryManager>>,
+) -> impl IntoResponse {
+    let pool = telemetry_manager.pool().clone();
+    let endpoint = telemetry_manager.get_endpoint();
+    let collector = TelemetryCollector::new(telemetry_manager, pool, endpoint);
+    let result = collector.test_connection().await;
+    info!(
+        "Telemetry connection test to {} ({}): {}",
+        result.endpoint,
+        result.sink.as_str(),
+        if result.success { "ok" } else { "failed" }
+    );
+
+    Json(ApiResponse::success(result))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,23 @@
+// ABOUTME: API endpoint reporting TLS configuration and certificate details
+// ABOUTME: Exposes expiry, SANs, and fingerprint of the certificate the HTTPS listener serves
+
+use axum::{extract::Extension, response::Json};
+use serde::Serialize;
+
+use crate::tls::{TlsManager, TlsStatus};
+
+#[derive(Serialize)]
+pub struct TlsStatusResponse {
+    pub success: bool,
+    pub data: Option<TlsStatus>,
+    pub error: Option<String>,
+}
+
+/// Report the TLS configuration and the served certificate's expiry, SANs, and fingerprint
+pub async fn get_tls_status(Extension(tls): Extension<TlsManager>) -> Json<TlsStatusResponse> {
+    Json(TlsStatusResponse {
+        success: true,
+        data: Some(tls.status()),
+        error: None,
+    })
+}
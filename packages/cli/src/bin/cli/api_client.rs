@@ -0,0 +1,200 @@
+// ABOUTME: Authenticated client for the local Orkee API used by scripting commands
+// ABOUTME: Discovers the API port and sends the stored API token so scripts don't handle auth
+
+use colored::*;
+use serde_json::Value;
+use std::error::Error;
+
+use orkee_cli::middleware::API_TOKEN_HEADER;
+
+/// Client for the running Orkee server's HTTP API
+pub struct ApiClient {
+    client: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl ApiClient {
+    /// Connect to the local API using the discovered port and the token in ~/.orkee/api-token
+    pub fn connect() -> Result<Self, Box<dyn Error>> {
+        let port = discover_api_port()?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            base_url: format!("http://localhost:{}", port),
+            token: read_api_token(),
+        })
+    }
+
+    /// GET an endpoint and return the `data` field of the response
+    pub async fn get(&self, path: &str) -> Result<Value, Box<dyn Error>> {
+        self.send(self.client.get(self.url(path))).await
+    }
+
+    /// POST a JSON body to an endpoint and return the `data` field of the response
+    pub async fn post(&self, path: &str, body: &Value) -> Result<Value, Box<dyn Error>> {
+        self.send(self.client.post(self.url(path)).json(body)).await
+    }
+
+    /// POST to a streaming endpoint and return the response for the caller to read
+    /// incrementally. Errors are reported the same way as for [`ApiClient::post`].
+    pub async fn post_stream(&self, path: &str) -> Result<reqwest::Response, Box<dyn Error>> {
+        let response = self.dispatch(self.client.post(self.url(path))).await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        Err(error_message(status, &body).into())
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    async fn dispatch(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, Box<dyn Error>> {
+        let request = match &self.token {
+            Some(token) => request.header(API_TOKEN_HEADER, token),
+            None => request,
+        };
+
+        let response = request.send().await.map_err(|e| {
+            format!(
+                "Failed to connect to Orkee server at {}: {}. Make sure the dashboard is running.",
+                self.base_url, e
+            )
+        })?;
+        Ok(response)
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<Value, Box<dyn Error>> {
+        let response = self.dispatch(request).await?;
+        let status = response.status();
+        let body: Value = response
+            .json()
+            .await
+            .map_err(|_| format!("Unexpected response from Orkee server (HTTP {})", status))?;
+
+        if !status.is_success() || body["success"] == Value::Bool(false) {
+            return Err(error_message(status, &body).into());
+        }
+
+        Ok(body.get("data").cloned().unwrap_or(Value::Null))
+    }
+}
+
+/// The error message of a failed API response
+fn error_message(status: reqwest::StatusCode, body: &Value) -> String {
+    body["error"]
+        .as_str()
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Request failed with HTTP {}", status))
+}
+
+/// Find a project by ID or name, or by the current directory when none is given.
+///
+/// Without an explicit project, the project whose root is the closest ancestor
+/// of the working directory is used.
+pub async fn resolve_project(
+    client: &ApiClient,
+    project: Option<&str>,
+) -> Result<Value, Box<dyn Error>> {
+    let projects = client.get("/api/projects").await?;
+    let projects = projects.as_array().cloned().unwrap_or_default();
+
+    let found = match project {
+        Some(key) => projects
+            .into_iter()
+            .find(|p| p["id"].as_str() == Some(key) || p["name"].as_str() == Some(key)),
+        None => {
+            let cwd = std::env::current_dir()?;
+            projects
+                .into_iter()
+                .filter(|p| {
+                    p["projectRoot"]
+                        .as_str()
+                        .is_some_and(|root| cwd.starts_with(root))
+                })
+                .max_by_key(|p| p["projectRoot"].as_str().map_or(0, str::len))
+        }
+    };
+
+    found.ok_or_else(|| match project {
+        Some(key) => format!("Project not found: {}", key).into(),
+        None => "No project contains the current directory. Use --project to choose one.".into(),
+    })
+}
+
+/// Read the API token the server writes on startup
+fn read_api_token() -> Option<String> {
+    let token = std::fs::read_to_string(orkee_projects::orkee_dir().join("api-token")).ok()?;
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+/// Print a value as pretty JSON on stdout
+pub fn print_json(value: &Value) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+pub fn discover_api_port() -> Result<u16, Box<dyn std::error::Error>> {
+    // Priority 1: Check environment variable
+    if let Ok(port_str) = std::env::var("ORKEE_API_PORT") {
+        if let Ok(port) = port_str.parse::<u16>() {
+            return Ok(port);
+        }
+    }
+
+    // Priority 2: Read from saved port info file
+    let home_dir_result = dirs::home_dir();
+    if let Some(home_dir) = home_dir_result {
+        let ports_file = home_dir.join(".orkee").join("ports.json");
+        if let Ok(contents) = std::fs::read_to_string(&ports_file) {
+            if let Ok(port_info) = serde_json::from_str::<serde_json::Value>(&contents) {
+                if let Some(api_port) = port_info["api_port"].as_u64() {
+                    return Ok(api_port as u16);
+                }
+            }
+        }
+    } else {
+        eprintln!(
+            "{} Could not determine home directory for port discovery",
+            "⚠️".yellow()
+        );
+    }
+
+    // Priority 3: Try to detect running server by checking common ports
+    let common_ports = vec![4001, 4000, 4002, 3000, 8000, 8080, 9000];
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::Duration;
+
+    for port in common_ports {
+        // Try to connect to the port with a short timeout
+        let addr: SocketAddr = match format!("127.0.0.1:{}", port).parse() {
+            Ok(a) => a,
+            Err(_) => continue, // Skip this port if parsing fails
+        };
+        if TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_ok() {
+            eprintln!(
+                "{} Found service running on port {} - assuming it's Orkee server",
+                "🔍".cyan(),
+                port
+            );
+            return Ok(port);
+        }
+    }
+
+    // Priority 4: Fall back to default port with better error message
+    Err(
+        "Could not discover API port. Please ensure the Orkee server is running.\n\
+        You can specify the port using:\n\
+        - ORKEE_API_PORT environment variable\n\
+        - Or start the server with: orkee dashboard --api-port <PORT>"
+            .to_string()
+            .into(),
+    )
+}
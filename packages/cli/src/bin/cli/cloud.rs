@@ -21,13 +21,51 @@ pub enum CloudCommands {
     /// Show cloud status
     Status,
     /// Login to Orkee Cloud
-    Login,
+    Login(LoginArgs),
     /// Logout from Orkee Cloud
     Logout,
     /// Check for sync conflicts
     Conflicts(ConflictsArgs),
     /// Push incremental changes
     Push(PushArgs),
+    /// List stored cloud accounts
+    Accounts,
+    /// Switch the active cloud account
+    Switch(SwitchArgs),
+    /// Pin a project to a cloud account
+    Pin(PinArgs),
+    /// Let a pinned project use the active account again
+    Unpin(UnpinArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct LoginArgs {
+    /// Name to store the login under, e.g. "work" or "personal"
+    #[arg(long)]
+    pub account: Option<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct SwitchArgs {
+    /// Account to make active
+    pub account: String,
+}
+
+#[derive(Debug, Args)]
+pub struct PinArgs {
+    /// Project ID to pin
+    #[arg(long)]
+    pub project: String,
+    /// Account the project should always sync as
+    #[arg(long)]
+    pub account: String,
+}
+
+#[derive(Debug, Args)]
+pub struct UnpinArgs {
+    /// Project ID to unpin
+    #[arg(long)]
+    pub project: String,
 }
 
 #[derive(Debug, Args)]
@@ -38,6 +76,12 @@ pub struct SyncArgs {
     /// Force sync even if no changes
     #[arg(long)]
     pub force: bool,
+    /// Cap snapshot upload bandwidth in KB/s (0 for unlimited)
+    #[arg(long, value_name = "KBPS")]
+    pub limit_kbps: Option<u64>,
+    /// Upload snapshots to the S3 bucket set by ORKEE_S3_BUCKET instead of Orkee Cloud
+    #[arg(long)]
+    pub s3: bool,
 }
 
 #[derive(Debug, Args)]
@@ -96,10 +140,14 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
         };
 
         match command {
-            CloudCommands::Login => {
+            CloudCommands::Login(args) => {
                 println!("🔐 {}", "Orkee Cloud Authentication".bold());
 
-                match cloud_client.login().await {
+                let result = match &args.account {
+                    Some(account) => cloud_client.login_as(account).await,
+                    None => cloud_client.login().await,
+                };
+                match result {
                     Ok(token_info) => {
                         println!(
                             "✅ Successfully logged in as {}",
@@ -127,6 +175,60 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                 }
             }
 
+            CloudCommands::Accounts => {
+                println!("👥 {}", "Orkee Cloud Accounts".bold());
+                println!();
+
+                let accounts = cloud_client.accounts();
+                if accounts.is_empty() {
+                    println!("No accounts stored.");
+                    println!(
+                        "Run {} to add one",
+                        "orkee cloud login --account <name>".yellow()
+                    );
+                }
+                for account in accounts {
+                    let marker = if account.active { "*" } else { " " };
+                    let expired = if account.expired {
+                        " (expired)".red().to_string()
+                    } else {
+                        String::new()
+                    };
+                    println!(
+                        "{} {} - {} <{}>{}",
+                        marker,
+                        account.name.bold(),
+                        account.user_name,
+                        account.user_email,
+                        expired
+                    );
+                }
+            }
+
+            CloudCommands::Switch(args) => match cloud_client.switch_account(&args.account).await {
+                Ok(()) => println!("✅ Now using account {}", args.account.green()),
+                Err(e) => println!("❌ Failed to switch account: {}", e),
+            },
+
+            CloudCommands::Pin(args) => {
+                match cloud_client.pin_project(&args.project, &args.account).await {
+                    Ok(()) => println!(
+                        "📌 Project {} will sync as {}",
+                        args.project,
+                        args.account.green()
+                    ),
+                    Err(e) => println!("❌ Failed to pin project: {}", e),
+                }
+            }
+
+            CloudCommands::Unpin(args) => match cloud_client.unpin_project(&args.project).await {
+                Ok(()) => println!(
+                    "✅ Project {} will sync as the active account",
+                    args.project
+                ),
+                Err(e) => println!("❌ Failed to unpin project: {}", e),
+            },
+
             CloudCommands::Status => {
                 println!("☁️  {}", "Orkee Cloud Status".bold());
                 println!();
@@ -141,10 +243,27 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                             if let Some(name) = &status.user_name {
                                 println!("Name: {}", name);
                             }
+                            if let Some(account) = cloud_client.active_account() {
+                                println!("Account: {}", account);
+                            }
                             println!("Projects: {}", status.projects_count);
                             if let Some(tier) = &status.subscription_tier {
                                 println!("Tier: {}", tier);
                             }
+                            if status.connection.total_retries > 0 {
+                                println!(
+                                    "Connection: {} retries this session",
+                                    status.connection.total_retries
+                                );
+                            }
+                            if let Ok(uploads) = cloud_client.pending_uploads().await {
+                                for upload in uploads {
+                                    println!(
+                                        "Interrupted upload: {} ({} of {} bytes, resumes on next sync)",
+                                        upload.project_id, upload.bytes_sent, upload.total_bytes
+                                    );
+                                }
+                            }
                         } else {
                             println!("Status: {}", "❌ Not authenticated".red());
                             println!("Run {} to get started", "orkee cloud login".yellow());
@@ -194,6 +313,10 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                 }
             }
 
+            CloudCommands::Sync(args) if args.s3 => {
+                sync_to_s3(args).await?;
+            }
+
             CloudCommands::Sync(args) => {
                 println!("🔄 {}", "Orkee Cloud Sync".bold());
 
@@ -205,6 +328,12 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                     return Ok(());
                 }
 
+                if let Some(kbps) = args.limit_kbps {
+                    cloud_client.set_upload_options(
+                        orkee_cloud::UploadOptions::from_env().with_limit_kbps(kbps),
+                    );
+                }
+
                 // Initialize project manager
                 let project_manager = ProjectsManager::new().await?;
 
@@ -268,6 +397,10 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                                 created_at: project.created_at,
                                 updated_at: project.updated_at,
                                 last_sync: None,
+                                org_id: None,
+                                owner: None,
+                                last_synced_by: None,
+                                read_only: false,
                             };
 
                             // Serialize project for sync
@@ -370,6 +503,10 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                                         created_at: project.created_at,
                                         updated_at: project.updated_at,
                                         last_sync: None,
+                                        org_id: None,
+                                        owner: None,
+                                        last_synced_by: None,
+                                        read_only: false,
                                     };
 
                                     let project_data = serde_json::to_value(&project)?;
@@ -611,6 +748,10 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
                                 created_at: project.created_at,
                                 updated_at: project.updated_at,
                                 last_sync: None,
+                                org_id: None,
+                                owner: None,
+                                last_synced_by: None,
+                                read_only: false,
                             };
 
                             let project_data = serde_json::to_value(&project)?;
@@ -641,6 +782,72 @@ pub async fn handle_cloud_command(command: CloudCommands) -> anyhow::Result<()>
     }
 }
 
+/// Upload snapshots of local projects to S3; needs no Orkee Cloud account
+#[cfg(feature = "cloud")]
+async fn sync_to_s3(args: SyncArgs) -> anyhow::Result<()> {
+    use orkee_cloud::{S3Config, S3Uploader, UploadOptions};
+
+    println!("🔄 {}", "S3 Snapshot Sync".bold());
+
+    let config = match S3Config::from_env() {
+        Ok(config) => config,
+        Err(e) => {
+            println!("❌ {}", e);
+            return Ok(());
+        }
+    };
+    let bucket = config.bucket.clone();
+    let mut options = UploadOptions::from_env();
+    if let Some(kbps) = args.limit_kbps {
+        options = options.with_limit_kbps(kbps);
+    }
+    let uploader = S3Uploader::new(config, options)?;
+
+    let project_manager = ProjectsManager::new().await?;
+    let projects = match args.project {
+        Some(project_id) => match project_manager.get_project(&project_id).await? {
+            Some(project) => vec![project],
+            None => {
+                println!("❌ Project '{}' not found", project_id);
+                return Ok(());
+            }
+        },
+        None => project_manager.list_projects().await?,
+    };
+    if projects.is_empty() {
+        println!("No local projects to sync.");
+        return Ok(());
+    }
+
+    println!(
+        "Uploading {} projects to {}...",
+        projects.len(),
+        bucket.cyan()
+    );
+    let mut synced = 0;
+    let mut failed = 0;
+    for project in projects {
+        let snapshot = serde_json::to_value(&project)?;
+        match uploader.upload_snapshot(&project.id, &snapshot).await {
+            Ok(key) => {
+                println!("  ✅ {} ({})", project.name, key.dimmed());
+                synced += 1;
+            }
+            Err(e) => {
+                println!("  ❌ {}: {}", project.name, e);
+                failed += 1;
+            }
+        }
+    }
+
+    println!();
+    println!("Sync complete: {} succeeded, {} failed", synced, failed);
+    if failed > 0 {
+        println!("Run the same command again to resume interrupted uploads.");
+    }
+    Ok(())
+}
+
 /// Print cloud help information
 #[allow(dead_code)]
 pub fn print_help() {
@@ -671,6 +878,10 @@ pub fn print_help() {
         "  {} - Sync specific project",
         "orkee cloud sync --project <id>".yellow()
     );
+    println!(
+        "  {} - Upload snapshots to your own S3 bucket",
+        "orkee cloud sync --s3".yellow()
+    );
     println!(
         "  {} - Restore from cloud",
         "orkee cloud restore --project <id>".yellow()
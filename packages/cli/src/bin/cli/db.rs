@@ -0,0 +1,261 @@
+// ABOUTME: CLI commands for inspecting and maintaining the local database
+// ABOUTME: Runs the storage integrity check and rolls back to pre-migration backups
+
+use clap::Subcommand;
+use colored::*;
+use inquire::Confirm;
+use orkee_projects::DbState;
+use orkee_storage::integrity::{check_integrity, IntegrityIssue, IntegrityReport};
+use orkee_storage::migration_backup::{
+    list_migration_backups, restore_migration_backup, MigrationBackup,
+};
+use std::process;
+
+#[derive(Subcommand)]
+pub enum DbCommands {
+    /// Check the database for orphaned rows, index drift, and corrupt data
+    Check {
+        /// Repair the issues that can be fixed automatically
+        #[arg(long)]
+        repair: bool,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Restore the database snapshot taken before the last schema migration
+    Rollback {
+        /// Restore this backup instead of the most recent one
+        #[arg(long)]
+        backup: Option<String>,
+        /// List the available migration backups without restoring
+        #[arg(long)]
+        list: bool,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+impl DbCommands {
+    pub async fn execute(&self) {
+        match self {
+            DbCommands::Check { repair, json } => check_command(*repair, *json).await,
+            DbCommands::Rollback { backup, list, yes } => {
+                rollback_command(backup.as_deref(), *list, *yes)
+            }
+        }
+    }
+}
+
+async fn check_command(repair: bool, json: bool) {
+    let db = match DbState::init().await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("{} Failed to open database: {}", "✗".red().bold(), e);
+            process::exit(1);
+        }
+    };
+
+    let report = match check_integrity(&db.pool, repair).await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{} Integrity check failed: {}", "✗".red().bold(), e);
+            process::exit(1);
+        }
+    };
+
+    if json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(output) => println!("{}", output),
+            Err(e) => {
+                eprintln!("{} Failed to serialize report: {}", "✗".red().bold(), e);
+                process::exit(1);
+            }
+        }
+    } else {
+        print_report(&report);
+    }
+
+    if !report.healthy {
+        process::exit(1);
+    }
+}
+
+fn print_report(report: &IntegrityReport) {
+    println!();
+    println!("{}", "Database Integrity Check".bold().cyan());
+    println!("{}", "════════════════════════".cyan());
+    println!();
+
+    if report.issues.is_empty() {
+        println!("{} No issues found", "✓".green().bold());
+        println!();
+        return;
+    }
+
+    for issue in &report.issues {
+        print_issue(issue);
+    }
+
+    println!();
+    println!(
+        "{} {} issue(s), {} repaired, {} unresolved",
+        "Summary:".bold(),
+        report.summary.total,
+        report.summary.repaired,
+        report.summary.unresolved
+    );
+
+    if report.summary.unresolved > 0 && !report.repair_requested {
+        println!();
+        println!(
+            "  Run {} to fix what can be repaired automatically",
+            "orkee db check --repair".yellow()
+        );
+    }
+    println!();
+}
+
+fn print_issue(issue: &IntegrityIssue) {
+    let marker = if issue.repaired {
+        "✓".green().bold()
+    } else {
+        "✗".red().bold()
+    };
+
+    let mut location = issue.table.clone();
+    if let Some(column) = &issue.column {
+        location = format!("{}.{}", location, column);
+    }
+    if let Some(row_id) = issue.row_id {
+        location = format!("{} (row {})", location, row_id);
+    }
+
+    let kind = serde_json::to_value(issue.kind)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default();
+
+    println!(
+        "{} [{}] {} {}",
+        marker,
+        kind.yellow(),
+        location.bold(),
+        issue.detail
+    );
+    if let Some(note) = &issue.repair_note {
+        println!("    {}", note.dimmed());
+    }
+}
+
+fn rollback_command(backup_id: Option<&str>, list: bool, yes: bool) {
+    // Opening the database through DbState would apply the migrations being rolled back
+    let db_path = orkee_projects::orkee_dir().join("orkee.db");
+    let backups = match list_migration_backups(&db_path) {
+        Ok(backups) => backups,
+        Err(e) => {
+            eprintln!(
+                "{} Failed to read migration backups: {}",
+                "✗".red().bold(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    if list {
+        print_backups(&backups);
+        return;
+    }
+
+    let backup = match backup_id {
+        Some(id) => backups.iter().find(|b| b.id == id),
+        None => backups.first(),
+    };
+    let Some(backup) = backup else {
+        match backup_id {
+            Some(id) => eprintln!("{} No migration backup named {}", "✗".red().bold(), id),
+            None => eprintln!("{} No migration backups found", "✗".red().bold()),
+        }
+        process::exit(1);
+    };
+
+    println!();
+    println!(
+        "Restoring {} taken by Orkee {} on {}",
+        backup.id.bold(),
+        backup.orkee_version,
+        backup.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+    );
+    println!(
+        "  Undoes migration(s) {}",
+        format_versions(&backup.pending_versions)
+    );
+    println!(
+        "  {}",
+        "Changes made since the backup will be lost. Stop Orkee before continuing.".yellow()
+    );
+
+    let confirmed = yes
+        || Confirm::new("Restore this backup?")
+            .with_default(false)
+            .prompt()
+            .unwrap_or(false);
+    if !confirmed {
+        println!("Cancelled");
+        return;
+    }
+
+    match restore_migration_backup(&db_path, backup) {
+        Ok(pre_rollback) => {
+            println!("{} Database restored", "✓".green().bold());
+            println!(
+                "  The replaced database was saved as {}",
+                pre_rollback.display()
+            );
+            println!(
+                "  Install the previous release before starting Orkee, or the migrations will run again"
+            );
+        }
+        Err(e) => {
+            eprintln!("{} Rollback failed: {}", "✗".red().bold(), e);
+            process::exit(1);
+        }
+    }
+}
+
+fn format_versions(versions: &[i64]) -> String {
+    versions
+        .iter()
+        .map(|v| format!("{:03}", v))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_backups(backups: &[MigrationBackup]) {
+    println!();
+    println!("{}", "Migration Backups".bold().cyan());
+    println!("{}", "═════════════════".cyan());
+    println!();
+
+    if backups.is_empty() {
+        println!("No migration backups found");
+        println!();
+        return;
+    }
+
+    for backup in backups {
+        println!(
+            "{}  {}  Orkee {}  {:.1} MB",
+            backup.id.bold(),
+            backup.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+            backup.orkee_version,
+            backup.size_bytes as f64 / (1024.0 * 1024.0)
+        );
+        println!(
+            "    before migration(s) {}",
+            format_versions(&backup.pending_versions).dimmed()
+        );
+    }
+    println!();
+}
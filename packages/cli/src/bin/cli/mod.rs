@@ -1,7 +1,11 @@
+pub mod api_client;
 pub mod auth;
 #[cfg(feature = "cloud")]
 pub mod cloud;
+pub mod db;
 pub mod projects;
+pub mod run;
 pub mod sandbox;
 pub mod security;
+pub mod tasks;
 pub mod utils;
@@ -3,18 +3,37 @@ use colored::*;
 use comfy_table::{modifiers::UTF8_ROUND_CORNERS, presets::UTF8_FULL, ContentArrangement, Table};
 use inquire::{Confirm, Select, Text};
 use orkee_projects::{
-    create_project, delete_project, get_all_projects, get_project, update_project, Priority,
-    Project, ProjectCreateInput, ProjectStatus, ProjectUpdateInput,
+    create_project, delete_project, format_project, format_projects, get_all_projects, get_project,
+    parse_columns, update_project, OutputFormat, Priority, Project, ProjectCreateInput,
+    ProjectStatus, ProjectUpdateInput,
 };
 
+use super::api_client::{print_json, ApiClient};
+
 #[derive(Subcommand)]
 pub enum ProjectsCommands {
     /// List all projects
-    List,
+    List {
+        /// Print projects as JSON from the running server's API
+        #[arg(long, conflicts_with_all = ["format", "columns"])]
+        json: bool,
+        /// Output format: table, json, yaml, csv, tsv
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+        /// Comma-separated columns to include (e.g. id,name,project_root)
+        #[arg(short, long)]
+        columns: Option<String>,
+    },
     /// Show project details
     Show {
         /// Project ID to show
         id: String,
+        /// Output format: table, json, yaml, csv, tsv
+        #[arg(short, long, default_value = "table")]
+        format: OutputFormat,
+        /// Comma-separated columns to include (e.g. id,name,project_root)
+        #[arg(short, long)]
+        columns: Option<String>,
     },
     /// Add a new project
     Add {
@@ -47,8 +66,25 @@ pub async fn handle_projects_command(
     command: ProjectsCommands,
 ) -> Result<(), Box<dyn std::error::Error>> {
     match command {
-        ProjectsCommands::List => list_projects().await,
-        ProjectsCommands::Show { id } => show_project(&id).await,
+        ProjectsCommands::List { json: true, .. } => list_projects_json().await,
+        ProjectsCommands::List {
+            format: OutputFormat::Table,
+            columns: None,
+            ..
+        } => list_projects().await,
+        ProjectsCommands::List {
+            format, columns, ..
+        } => print_projects(format, columns.as_deref()).await,
+        ProjectsCommands::Show {
+            id,
+            format: OutputFormat::Table,
+            columns: None,
+        } => show_project(&id).await,
+        ProjectsCommands::Show {
+            id,
+            format,
+            columns,
+        } => print_project(&id, format, columns.as_deref()).await,
         ProjectsCommands::Add {
             name,
             path,
@@ -59,6 +95,24 @@ pub async fn handle_projects_command(
     }
 }
 
+async fn list_projects_json() -> Result<(), Box<dyn std::error::Error>> {
+    let projects = ApiClient::connect()?.get("/api/projects").await?;
+    print_json(&projects)
+}
+
+async fn print_projects(
+    format: OutputFormat,
+    columns: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let columns = columns.map(parse_columns).transpose()?;
+    let projects = get_all_projects().await?;
+    println!(
+        "{}",
+        format_projects(&projects, format, columns.as_deref())?
+    );
+    Ok(())
+}
+
 async fn list_projects() -> Result<(), Box<dyn std::error::Error>> {
     let projects = get_all_projects().await?;
 
@@ -130,6 +184,19 @@ async fn list_projects() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+async fn print_project(
+    id: &str,
+    format: OutputFormat,
+    columns: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let columns = columns.map(parse_columns).transpose()?;
+    let project = get_project(id)
+        .await?
+        .ok_or_else(|| format!("Project with ID '{}' not found", id))?;
+    println!("{}", format_project(&project, format, columns.as_deref())?);
+    Ok(())
+}
+
 async fn show_project(id: &str) -> Result<(), Box<dyn std::error::Error>> {
     match get_project(id).await? {
         Some(project) => {
@@ -366,6 +433,8 @@ async fn edit_project(id: &str) -> Result<(), Box<dyn std::error::Error>> {
         task_source: project.task_source.clone(),
         manual_tasks: project.manual_tasks.clone(),
         mcp_servers: project.mcp_servers.clone(),
+        // Reject the edit if the project changed while the prompts were open
+        expected_version: Some(project.version),
     };
 
     match update_project(id, updates).await {
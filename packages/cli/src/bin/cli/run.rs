@@ -0,0 +1,129 @@
+// ABOUTME: Runs a project's configured setup, dev, or cleanup script through the Orkee server
+// ABOUTME: Streams the script's output from the server's SSE endpoint and exits with its status
+
+use colored::*;
+use serde_json::{json, Value};
+use std::error::Error;
+
+use super::api_client::{resolve_project, ApiClient};
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum ProjectScript {
+    Setup,
+    Dev,
+    Cleanup,
+}
+
+impl ProjectScript {
+    fn name(self) -> &'static str {
+        match self {
+            ProjectScript::Setup => "setup",
+            ProjectScript::Dev => "dev",
+            ProjectScript::Cleanup => "cleanup",
+        }
+    }
+}
+
+pub async fn run_project_script(
+    script: ProjectScript,
+    project: Option<&str>,
+    timeout_secs: Option<u64>,
+) -> Result<(), Box<dyn Error>> {
+    let client = ApiClient::connect()?;
+    let project = resolve_project(&client, project).await?;
+    let project_id = project["id"]
+        .as_str()
+        .ok_or("Project response is missing an id")?;
+    let name = project["name"].as_str().unwrap_or_default();
+
+    let mut path = format!("/api/projects/{}/run-script/{}", project_id, script.name());
+    if let Some(timeout_secs) = timeout_secs {
+        path.push_str(&format!("?timeout_secs={}", timeout_secs));
+    }
+    let mut response = client.post_stream(&path).await?;
+
+    let mut buffer = String::new();
+    let mut run_id: Option<String> = None;
+    let mut cancelled = false;
+    let finished = 'stream: loop {
+        tokio::select! {
+            chunk = response.chunk() => {
+                let Some(chunk) = chunk? else { break 'stream None };
+                buffer.push_str(&String::from_utf8_lossy(&chunk));
+
+                // SSE frames end with a blank line
+                while let Some(end) = buffer.find("\n\n") {
+                    let frame: String = buffer.drain(..end + 2).collect();
+                    let Some(event) = parse_frame(&frame) else { continue };
+                    match event["type"].as_str() {
+                        Some("started") => {
+                            run_id = event["run"]["id"].as_str().map(str::to_string);
+                            // Keep stdout for the script itself so its output can be piped
+                            eprintln!(
+                                "{} Running {} script for {}: {}",
+                                "▶".cyan(),
+                                script.name(),
+                                name.bold(),
+                                event["run"]["command"].as_str().unwrap_or_default().dimmed()
+                            );
+                        }
+                        Some("output") => {
+                            let line = event["line"].as_str().unwrap_or_default();
+                            if event["stream"] == "stderr" {
+                                eprintln!("{}", line);
+                            } else {
+                                println!("{}", line);
+                            }
+                        }
+                        Some("finished") => break 'stream Some(event["run"].clone()),
+                        _ => {}
+                    }
+                }
+            }
+            _ = tokio::signal::ctrl_c() => {
+                let Some(run_id) = run_id.as_deref().filter(|_| !cancelled) else {
+                    std::process::exit(130);
+                };
+                eprintln!("{} Cancelling {} script...", "■".yellow(), script.name());
+                client
+                    .post(
+                        &format!("/api/projects/{}/script-runs/{}/cancel", project_id, run_id),
+                        &json!({}),
+                    )
+                    .await?;
+                cancelled = true;
+            }
+        }
+    };
+
+    let run = finished.ok_or("Lost connection to the Orkee server before the script finished")?;
+    let status = run["status"].as_str().unwrap_or_default();
+    if status == "succeeded" {
+        return Ok(());
+    }
+
+    let reason = match status {
+        "timed_out" => format!("timed out after {}s", run["timeoutSecs"]),
+        "cancelled" => "was cancelled".to_string(),
+        _ => match run["exitCode"].as_i64() {
+            Some(code) => format!("exited with status {}", code),
+            None => "failed".to_string(),
+        },
+    };
+    eprintln!("{} {} script {}", "✗".red(), script.name(), reason);
+    let code = run["exitCode"].as_i64().filter(|&code| code != 0);
+    std::process::exit(code.map_or(1, |code| code as i32));
+}
+
+/// The JSON payload of an SSE frame; keep-alive comments have none
+fn parse_frame(frame: &str) -> Option<Value> {
+    let data: Vec<&str> = frame
+        .lines()
+        .filter_map(|line| line.strip_prefix("data:"))
+        .map(str::trim_start)
+        .collect();
+    if data.is_empty() {
+        return None;
+    }
+    serde_json::from_str(&data.join("\n")).ok()
+}
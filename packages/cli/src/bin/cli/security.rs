@@ -6,6 +6,7 @@ use colored::*;
 use inquire::{Password, PasswordDisplayMode};
 use orkee_projects::orkee_security::encryption::{ApiKeyEncryption, EncryptionMode};
 use orkee_projects::ProjectManager;
+use orkee_storage::field_encryption;
 use std::process;
 
 #[derive(Subcommand)]
@@ -18,6 +19,8 @@ pub enum SecurityCommands {
     RemovePassword,
     /// Show current encryption mode and security status
     Status,
+    /// Encrypt sensitive fields (scripts, MCP env values, OAuth metadata) stored before encryption
+    EncryptFields,
 }
 
 impl SecurityCommands {
@@ -27,10 +30,29 @@ impl SecurityCommands {
             SecurityCommands::ChangePassword => change_password_command().await,
             SecurityCommands::RemovePassword => remove_password_command().await,
             SecurityCommands::Status => status_command().await,
+            SecurityCommands::EncryptFields => encrypt_fields_command().await,
         }
     }
 }
 
+/// Re-encrypt sensitive fields (scripts, MCP env values, OAuth metadata) under
+/// a new key so they stay readable after the encryption mode or password changes
+async fn rotate_sensitive_fields(
+    old_encryption: &ApiKeyEncryption,
+    new_encryption: &ApiKeyEncryption,
+) -> Result<u64, String> {
+    use orkee_projects::DbState;
+    let db_state = DbState::init()
+        .await
+        .map_err(|e| format!("Failed to initialize database: {}", e))?;
+    let mut tx = db_state.pool.begin().await.map_err(|e| e.to_string())?;
+    let report = field_encryption::rotate_existing_rows(&mut tx, old_encryption, new_encryption)
+        .await
+        .map_err(|e| e.to_string())?;
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok(report.total())
+}
+
 async fn set_password_command() {
     println!(
         "{}",
@@ -137,6 +159,26 @@ async fn set_password_command() {
         }
     };
 
+    // Re-encrypt sensitive fields with the password-derived key
+    let rotation = match (
+        ApiKeyEncryption::with_machine_key(),
+        ApiKeyEncryption::with_password(&password, &salt),
+    ) {
+        (Ok(old_encryption), Ok(new_encryption)) => {
+            rotate_sensitive_fields(&old_encryption, &new_encryption).await
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e.to_string()),
+    };
+    if let Err(e) = rotation {
+        eprintln!(
+            "{} Failed to re-encrypt sensitive fields: {}",
+            "✗".red().bold(),
+            e
+        );
+        eprintln!("  No changes were made");
+        process::exit(1);
+    }
+
     // Save encryption settings to database
     match manager
         .set_encryption_mode(
@@ -156,6 +198,10 @@ async fn set_password_command() {
             println!("{}", "Next steps:".bold());
             println!("  • Your API keys are now encrypted with your password");
             println!("  • Keep your password secure - it cannot be recovered if lost");
+            println!(
+                "  • Set {} when starting Orkee so it can read encrypted project fields",
+                orkee_projects::orkee_security::encryption::PASSWORD_ENV_VAR.cyan()
+            );
             println!(
                 "  • Use {} to check encryption status",
                 "orkee security status".cyan()
@@ -418,16 +464,21 @@ async fn remove_password_command() {
     };
 
     // Check current encryption mode
-    match manager.get_encryption_mode().await {
-        Ok(Some(EncryptionMode::Machine)) | Ok(None) => {
+    let (salt, hash) = match manager.get_encryption_settings().await {
+        Ok(Some((EncryptionMode::Password, Some(salt), Some(hash)))) => (salt, hash),
+        Ok(Some((EncryptionMode::Password, _, _))) => {
             eprintln!(
-                "{} Password-based encryption is not enabled.",
+                "{} No password found in encryption settings",
                 "✗".red().bold()
             );
             process::exit(1);
         }
-        Ok(Some(EncryptionMode::Password)) => {
-            // Continue with removal
+        Ok(_) => {
+            eprintln!(
+                "{} Password-based encryption is not enabled.",
+                "✗".red().bold()
+            );
+            process::exit(1);
         }
         Err(e) => {
             eprintln!(
@@ -437,7 +488,7 @@ async fn remove_password_command() {
             );
             process::exit(1);
         }
-    }
+    };
 
     println!("{}", "⚠ SECURITY WARNING:".yellow().bold());
     println!("  • This will downgrade to machine-based encryption");
@@ -463,6 +514,71 @@ async fn remove_password_command() {
         process::exit(0);
     }
 
+    // Check if account is locked due to too many failed attempts
+    if let Err(e) = manager.check_password_lockout().await {
+        eprintln!("{} {}", "✗".red().bold(), e);
+        process::exit(1);
+    }
+
+    // The current password is needed to decrypt the sensitive fields
+    let current_password = match Password::new("Enter current password:")
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .prompt()
+    {
+        Ok(p) => p,
+        Err(_) => {
+            eprintln!("{} Password input cancelled", "✗".red().bold());
+            process::exit(1);
+        }
+    };
+
+    match ApiKeyEncryption::verify_password(&current_password, &salt, &hash) {
+        Ok(true) => {
+            if let Err(e) = manager.reset_password_attempts().await {
+                eprintln!(
+                    "{} Warning: Failed to reset password attempts: {}",
+                    "⚠".yellow().bold(),
+                    e
+                );
+            }
+        }
+        Ok(false) => {
+            if let Err(e) = manager.record_failed_password_attempt().await {
+                eprintln!(
+                    "{} Warning: Failed to record attempt: {}",
+                    "⚠".yellow().bold(),
+                    e
+                );
+            }
+            eprintln!("{} Current password is incorrect", "✗".red().bold());
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{} Password verification failed: {}", "✗".red().bold(), e);
+            process::exit(1);
+        }
+    }
+
+    // Re-encrypt sensitive fields with the machine key
+    let rotation = match (
+        ApiKeyEncryption::with_password(&current_password, &salt),
+        ApiKeyEncryption::with_machine_key(),
+    ) {
+        (Ok(old_encryption), Ok(new_encryption)) => {
+            rotate_sensitive_fields(&old_encryption, &new_encryption).await
+        }
+        (Err(e), _) | (_, Err(e)) => Err(e.to_string()),
+    };
+    if let Err(e) = rotation {
+        eprintln!(
+            "{} Failed to re-encrypt sensitive fields: {}",
+            "✗".red().bold(),
+            e
+        );
+        eprintln!("  No changes were made");
+        process::exit(1);
+    }
+
     // Downgrade to machine-based encryption
     match manager
         .set_encryption_mode(EncryptionMode::Machine, None, None)
@@ -554,3 +670,47 @@ async fn status_command() {
         }
     }
 }
+
+async fn encrypt_fields_command() {
+    println!("{}", "Encrypting sensitive fields...".bold().cyan());
+    println!();
+
+    // Opening the database installs the field cipher
+    use orkee_projects::DbState;
+    let db_state = match DbState::init().await {
+        Ok(db) => db,
+        Err(e) => {
+            eprintln!("{} Failed to initialize database: {}", "✗".red().bold(), e);
+            process::exit(1);
+        }
+    };
+
+    let report = match field_encryption::encrypt_existing_rows(&db_state.pool).await {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("{} Failed to encrypt fields: {}", "✗".red().bold(), e);
+            eprintln!(
+                "  With password-based encryption, set {} to your password",
+                orkee_projects::orkee_security::encryption::PASSWORD_ENV_VAR.yellow()
+            );
+            eprintln!("  No changes were made");
+            process::exit(1);
+        }
+    };
+
+    for field in &report.fields {
+        println!(
+            "  • {}.{}: {} rows encrypted",
+            field.table, field.column, field.rows_encrypted
+        );
+    }
+    println!();
+    if report.total() == 0 {
+        println!(
+            "{} All sensitive fields were already encrypted",
+            "✓".green().bold()
+        );
+    } else {
+        println!("{} Encrypted {} values", "✓".green().bold(), report.total());
+    }
+}
@@ -0,0 +1,73 @@
+// ABOUTME: Task commands that talk to the running Orkee server and print JSON
+// ABOUTME: Lets shell scripts list and create tasks for a project without handling auth
+
+use clap::Subcommand;
+use serde_json::json;
+use std::error::Error;
+
+use super::api_client::{print_json, resolve_project, ApiClient};
+
+#[derive(Subcommand)]
+pub enum TasksCommands {
+    /// List a project's tasks as JSON
+    List {
+        /// Project ID or name (defaults to the project containing the current directory)
+        #[arg(short, long)]
+        project: Option<String>,
+    },
+    /// Add a task to a project and print it as JSON
+    Add {
+        /// Task title
+        title: String,
+        /// Project ID or name (defaults to the project containing the current directory)
+        #[arg(short, long)]
+        project: Option<String>,
+        /// Task description
+        #[arg(short, long)]
+        description: Option<String>,
+        /// Task priority
+        #[arg(long, value_parser = ["low", "medium", "high", "critical"])]
+        priority: Option<String>,
+    },
+}
+
+pub async fn handle_tasks_command(command: TasksCommands) -> Result<(), Box<dyn Error>> {
+    let client = ApiClient::connect()?;
+
+    match command {
+        TasksCommands::List { project } => {
+            let project = resolve_project(&client, project.as_deref()).await?;
+            let page = client
+                .get(&format!("/api/projects/{}/tasks", project_id(&project)?))
+                .await?;
+            // The list endpoint is paginated; scripts only need the tasks
+            print_json(page.get("data").unwrap_or(&page))
+        }
+        TasksCommands::Add {
+            title,
+            project,
+            description,
+            priority,
+        } => {
+            let project = resolve_project(&client, project.as_deref()).await?;
+            let body = json!({
+                "title": title,
+                "description": description,
+                "priority": priority,
+            });
+            let task = client
+                .post(
+                    &format!("/api/projects/{}/tasks", project_id(&project)?),
+                    &body,
+                )
+                .await?;
+            print_json(&task)
+        }
+    }
+}
+
+fn project_id(project: &serde_json::Value) -> Result<&str, Box<dyn Error>> {
+    project["id"]
+        .as_str()
+        .ok_or_else(|| "Project response is missing an id".into())
+}
@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand};
 use colored::*;
+use std::net::IpAddr;
 use std::path::PathBuf;
 use std::process;
 
@@ -8,11 +9,15 @@ mod cli;
 use cli::auth::AuthCommands;
 #[cfg(feature = "cloud")]
 use cli::cloud::CloudCommands;
+use cli::db::DbCommands;
 use cli::projects::ProjectsCommands;
+use cli::run::ProjectScript;
 use cli::sandbox::SandboxCommands;
 use cli::security::SecurityCommands;
+use cli::tasks::TasksCommands;
 use orkee_cli::dashboard::downloader::ensure_dashboard;
 use orkee_cli::dashboard::DashboardMode;
+use orkee_cli::network;
 
 /// Maximum number of parent directories to search when looking for monorepo root
 const MAX_PARENT_SEARCH_DEPTH: usize = 5;
@@ -69,6 +74,12 @@ enum Commands {
         restart: bool,
         #[arg(long, help = "Use local development dashboard from packages/dashboard")]
         dev: bool,
+        #[arg(
+            long,
+            default_value = "127.0.0.1",
+            help = "Address to bind the API server to (non-loopback requires HTTPS and token auth)"
+        )]
+        host: IpAddr,
     },
     /// Launch the terminal user interface
     Tui {
@@ -83,6 +94,21 @@ enum Commands {
     /// Manage projects
     #[command(subcommand)]
     Projects(ProjectsCommands),
+    /// Manage project tasks through the running server (JSON output)
+    #[command(subcommand)]
+    Tasks(TasksCommands),
+    /// Run a project's setup, dev, or cleanup script
+    Run {
+        /// Script to run
+        #[arg(value_enum)]
+        script: ProjectScript,
+        /// Project ID or name (defaults to the project containing the current directory)
+        #[arg(short, long)]
+        project: Option<String>,
+        /// Stop the script after this many seconds (defaults to 10 minutes, 1 hour for dev)
+        #[arg(long)]
+        timeout: Option<u64>,
+    },
     /// Manage cloud sync
     #[command(subcommand)]
     Cloud(CloudCommands),
@@ -98,6 +124,9 @@ enum Commands {
     /// Manage API key encryption security
     #[command(subcommand)]
     Security(SecurityCommands),
+    /// Inspect and maintain the local database
+    #[command(subcommand)]
+    Db(DbCommands),
 }
 
 #[cfg(not(feature = "cloud"))]
@@ -121,6 +150,12 @@ enum Commands {
         restart: bool,
         #[arg(long, help = "Use local development dashboard from packages/dashboard")]
         dev: bool,
+        #[arg(
+            long,
+            default_value = "127.0.0.1",
+            help = "Address to bind the API server to (non-loopback requires HTTPS and token auth)"
+        )]
+        host: IpAddr,
     },
     /// Launch the terminal user interface
     Tui {
@@ -135,6 +170,21 @@ enum Commands {
     /// Manage projects
     #[command(subcommand)]
     Projects(ProjectsCommands),
+    /// Manage project tasks through the running server (JSON output)
+    #[command(subcommand)]
+    Tasks(TasksCommands),
+    /// Run a project's setup, dev, or cleanup script
+    Run {
+        /// Script to run
+        #[arg(value_enum)]
+        script: ProjectScript,
+        /// Project ID or name (defaults to the project containing the current directory)
+        #[arg(short, long)]
+        project: Option<String>,
+        /// Stop the script after this many seconds (defaults to 10 minutes, 1 hour for dev)
+        #[arg(long)]
+        timeout: Option<u64>,
+    },
     /// Manage preview servers
     #[command(subcommand)]
     Preview(PreviewCommands),
@@ -147,6 +197,9 @@ enum Commands {
     /// Manage API key encryption security
     #[command(subcommand)]
     Security(SecurityCommands),
+    /// Inspect and maintain the local database
+    #[command(subcommand)]
+    Db(DbCommands),
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -175,6 +228,7 @@ async fn handle_command(command: Commands) -> Result<(), Box<dyn std::error::Err
             ui_port,
             restart,
             dev,
+            host,
         } => {
             // Determine ports: specified > environment > dynamic
             // Hold listeners to prevent TOCTOU race when using dynamic ports
@@ -230,10 +284,14 @@ async fn handle_command(command: Commands) -> Result<(), Box<dyn std::error::Err
             drop(_api_listener_guard);
             drop(_ui_listener_guard);
 
+            if network::is_remote_bind(host) {
+                prepare_remote_access(host, final_api_port, dev)?;
+            }
+
             if restart {
-                restart_dashboard(final_api_port, final_ui_port, dev).await
+                restart_dashboard(final_api_port, final_ui_port, dev, host).await
             } else {
-                start_full_dashboard(final_api_port, final_ui_port, dev).await
+                start_full_dashboard(final_api_port, final_ui_port, dev, host).await
             }
         }
         Commands::Tui {
@@ -243,6 +301,12 @@ async fn handle_command(command: Commands) -> Result<(), Box<dyn std::error::Err
         Commands::Projects(projects_cmd) => {
             cli::projects::handle_projects_command(projects_cmd).await
         }
+        Commands::Tasks(tasks_cmd) => cli::tasks::handle_tasks_command(tasks_cmd).await,
+        Commands::Run {
+            script,
+            project,
+            timeout,
+        } => cli::run::run_project_script(script, project.as_deref(), timeout).await,
         #[cfg(feature = "cloud")]
         Commands::Cloud(cloud_cmd) => cli::cloud::handle_cloud_command(cloud_cmd)
             .await
@@ -260,6 +324,10 @@ async fn handle_command(command: Commands) -> Result<(), Box<dyn std::error::Err
             security_cmd.execute().await;
             Ok(())
         }
+        Commands::Db(db_cmd) => {
+            db_cmd.execute().await;
+            Ok(())
+        }
     }
 }
 
@@ -275,7 +343,7 @@ async fn stop_all_preview_servers() -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", "🛑 Stopping all preview servers...".yellow().bold());
 
     // Discover the actual API port being used
-    let api_port = discover_api_port()?;
+    let api_port = cli::api_client::discover_api_port()?;
 
     // Get list of active servers
     let client = reqwest::Client::new();
@@ -351,6 +419,98 @@ async fn stop_all_preview_servers() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Check and configure a dashboard bound beyond loopback.
+///
+/// Remote access always runs over HTTPS with API token authentication, so
+/// development mode (which disables authentication) and an explicit
+/// `TLS_ENABLED=false` are refused. The server's LAN origin is added to the
+/// CORS allow list and printed so other machines know where to connect.
+fn prepare_remote_access(
+    host: IpAddr,
+    api_port: u16,
+    dev: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let dev_env = std::env::var("ORKEE_DEV_MODE")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false);
+    if dev || dev_env {
+        return Err(format!(
+            "--host {} cannot be combined with development mode, which disables API token authentication",
+            host
+        )
+        .into());
+    }
+
+    match std::env::var("TLS_ENABLED") {
+        Ok(value) if !value.eq_ignore_ascii_case("true") => {
+            return Err(format!(
+                "--host {} requires HTTPS, but TLS_ENABLED is set to '{}'",
+                host, value
+            )
+            .into());
+        }
+        _ => std::env::set_var("TLS_ENABLED", "true"),
+    }
+
+    let lan_ip = network::detect_lan_ip();
+    let origins = network::remote_origins(host, lan_ip, &[api_port]);
+    std::env::set_var(network::HOST_ENV, host.to_string());
+    std::env::set_var(network::REMOTE_ORIGINS_ENV, origins.join(","));
+
+    eprintln!(
+        "\n{}",
+        "╔══════════════════════════════════════════════════════════════╗"
+            .yellow()
+            .bold()
+    );
+    eprintln!(
+        "{}",
+        "║  ⚠️  REMOTE ACCESS — the dashboard is reachable on your LAN ║"
+            .yellow()
+            .bold()
+    );
+    eprintln!(
+        "{}",
+        "║  Every request needs your API token (~/.orkee/api-token)     ║"
+            .yellow()
+            .bold()
+    );
+    eprintln!(
+        "{}",
+        "║  Only use this on networks you trust                         ║"
+            .yellow()
+            .bold()
+    );
+    eprintln!(
+        "{}",
+        "╚══════════════════════════════════════════════════════════════╝"
+            .yellow()
+            .bold()
+    );
+    eprintln!();
+
+    match network::public_address(host, lan_ip) {
+        Some(ip) => println!(
+            "{} {}",
+            "🏠 LAN address:".cyan(),
+            network::https_url(ip, api_port)
+        ),
+        None => println!(
+            "{} Could not detect a LAN address; connect using this machine's IP on port {}",
+            "⚠️".yellow(),
+            api_port
+        ),
+    }
+    Ok(())
+}
+
+/// Whether the server will run over HTTPS
+fn tls_enabled() -> bool {
+    std::env::var("TLS_ENABLED")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
 async fn start_server_with_options(
     api_port: u16,
     cors_origin: String,
@@ -359,8 +519,9 @@ async fn start_server_with_options(
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", "🚀 Starting Orkee CLI server...".green().bold());
     println!(
-        "{} http://localhost:{}",
+        "{} {}://localhost:{}",
         "📡 Server will run on".cyan(),
+        if tls_enabled() { "https" } else { "http" },
         api_port
     );
     println!("{} {}", "🔗 CORS origin:".cyan(), cors_origin);
@@ -373,10 +534,30 @@ async fn start_server_with_options(
     // Set dev mode to bypass API token authentication in development
     if dev_mode {
         std::env::set_var("ORKEE_DEV_MODE", "true");
-        eprintln!("\n{}", "╔══════════════════════════════════════════════════════════════╗".yellow().bold());
-        eprintln!("{}", "║  ⚠️  DEVELOPMENT MODE — API authentication is DISABLED      ║".yellow().bold());
-        eprintln!("{}", "║  Do not use --dev in production or on shared networks        ║".yellow().bold());
-        eprintln!("{}", "╚══════════════════════════════════════════════════════════════╝".yellow().bold());
+        eprintln!(
+            "\n{}",
+            "╔══════════════════════════════════════════════════════════════╗"
+                .yellow()
+                .bold()
+        );
+        eprintln!(
+            "{}",
+            "║  ⚠️  DEVELOPMENT MODE — API authentication is DISABLED      ║"
+                .yellow()
+                .bold()
+        );
+        eprintln!(
+            "{}",
+            "║  Do not use --dev in production or on shared networks        ║"
+                .yellow()
+                .bold()
+        );
+        eprintln!(
+            "{}",
+            "╚══════════════════════════════════════════════════════════════╝"
+                .yellow()
+                .bold()
+        );
         eprintln!();
     }
 
@@ -428,6 +609,7 @@ async fn start_full_dashboard(
     api_port: u16,
     ui_port: u16,
     dev: bool,
+    host: IpAddr,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!(
         "{}",
@@ -508,7 +690,14 @@ async fn start_full_dashboard(
     };
 
     // Wait for backend to be ready with health check retry loop
-    wait_for_backend_ready(api_port).await?;
+    wait_for_backend_ready(api_port, host).await?;
+
+    if network::is_remote_bind(host) && matches!(dashboard_mode, DashboardMode::Source) {
+        println!(
+            "{} The source dashboard's dev server only listens on localhost; remote machines can use the API only",
+            "⚠️".yellow()
+        );
+    }
 
     async fn find_local_dashboard() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
         // Try to find dashboard by walking up directories (for monorepo)
@@ -562,8 +751,9 @@ async fn start_full_dashboard(
             );
             println!("{}", "✅ Dashboard and API started!".green());
             println!(
-                "{} http://localhost:{}",
+                "{} {}://localhost:{}",
                 "🌐 Access the dashboard at:".cyan(),
+                if tls_enabled() { "https" } else { "http" },
                 api_port
             );
             println!(
@@ -621,6 +811,7 @@ async fn restart_dashboard(
     api_port: u16,
     ui_port: u16,
     dev: bool,
+    host: IpAddr,
 ) -> Result<(), Box<dyn std::error::Error>> {
     println!(
         "{}",
@@ -645,7 +836,7 @@ async fn restart_dashboard(
     wait_for_port_available(ui_port).await?;
 
     // Start fresh
-    start_full_dashboard(api_port, ui_port, dev).await
+    start_full_dashboard(api_port, ui_port, dev, host).await
 }
 
 async fn kill_port(port: u16) -> Result<(), Box<dyn std::error::Error>> {
@@ -814,64 +1005,6 @@ fn save_port_info(api_port: u16, ui_port: u16) -> Result<(), Box<dyn std::error:
     Ok(())
 }
 
-fn discover_api_port() -> Result<u16, Box<dyn std::error::Error>> {
-    // Priority 1: Check environment variable
-    if let Ok(port_str) = std::env::var("ORKEE_API_PORT") {
-        if let Ok(port) = port_str.parse::<u16>() {
-            return Ok(port);
-        }
-    }
-
-    // Priority 2: Read from saved port info file
-    let home_dir_result = dirs::home_dir();
-    if let Some(home_dir) = home_dir_result {
-        let ports_file = home_dir.join(".orkee").join("ports.json");
-        if let Ok(contents) = std::fs::read_to_string(&ports_file) {
-            if let Ok(port_info) = serde_json::from_str::<serde_json::Value>(&contents) {
-                if let Some(api_port) = port_info["api_port"].as_u64() {
-                    return Ok(api_port as u16);
-                }
-            }
-        }
-    } else {
-        eprintln!(
-            "{} Could not determine home directory for port discovery",
-            "⚠️".yellow()
-        );
-    }
-
-    // Priority 3: Try to detect running server by checking common ports
-    let common_ports = vec![4001, 4000, 4002, 3000, 8000, 8080, 9000];
-    use std::net::{SocketAddr, TcpStream};
-    use std::time::Duration;
-
-    for port in common_ports {
-        // Try to connect to the port with a short timeout
-        let addr: SocketAddr = match format!("127.0.0.1:{}", port).parse() {
-            Ok(a) => a,
-            Err(_) => continue, // Skip this port if parsing fails
-        };
-        if TcpStream::connect_timeout(&addr, Duration::from_millis(100)).is_ok() {
-            eprintln!(
-                "{} Found service running on port {} - assuming it's Orkee server",
-                "🔍".cyan(),
-                port
-            );
-            return Ok(port);
-        }
-    }
-
-    // Priority 4: Fall back to default port with better error message
-    Err(
-        "Could not discover API port. Please ensure the Orkee server is running.\n\
-        You can specify the port using:\n\
-        - ORKEE_API_PORT environment variable\n\
-        - Or start the server with: orkee dashboard --api-port <PORT>"
-            .to_string()
-            .into(),
-    )
-}
-
 async fn wait_for_port_available(port: u16) -> Result<(), Box<dyn std::error::Error>> {
     let max_retries = 20;
     let retry_interval = tokio::time::Duration::from_millis(100);
@@ -892,9 +1025,26 @@ async fn wait_for_port_available(port: u16) -> Result<(), Box<dyn std::error::Er
     .into())
 }
 
-async fn wait_for_backend_ready(api_port: u16) -> Result<(), Box<dyn std::error::Error>> {
-    let client = reqwest::Client::new();
-    let health_url = format!("http://localhost:{}/api/health", api_port);
+async fn wait_for_backend_ready(
+    api_port: u16,
+    host: IpAddr,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let tls = tls_enabled();
+    // The server may use a self-signed certificate; this only checks that it is up
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(tls)
+        .build()?;
+    let scheme = if tls { "https" } else { "http" };
+    // A server bound to one specific address isn't reachable through localhost
+    let health_url = if host.is_loopback() || host.is_unspecified() {
+        format!("{}://localhost:{}/api/health", scheme, api_port)
+    } else {
+        format!(
+            "{}://{}/api/health",
+            scheme,
+            std::net::SocketAddr::new(host, api_port)
+        )
+    };
     let max_retries = 30;
     let retry_interval = tokio::time::Duration::from_millis(500);
 
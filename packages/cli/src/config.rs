@@ -1,8 +1,13 @@
-use crate::middleware::RateLimitConfig;
+use crate::middleware::{RateLimitConfig, RequestLimitsConfig, RouteLimits};
+use crate::network::{self, HOST_ENV, REMOTE_ORIGINS_ENV};
+use crate::tls::acme::{AcmeChallenge, AcmeConfig};
+use crate::tls::mtls::{parse_identity_map, ClientAuthConfig};
 use crate::tls::TlsConfig;
 use std::env;
+use std::net::{IpAddr, Ipv4Addr};
 use std::num::ParseIntError;
 use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -13,6 +18,12 @@ pub enum ConfigError {
     PortOutOfRange(u16),
     #[error("Invalid sandbox mode: {0}")]
     InvalidSandboxMode(String),
+    #[error("Invalid TLS configuration: {0}")]
+    InvalidTls(String),
+    #[error("Invalid bind host: {0}")]
+    InvalidHost(String),
+    #[error("Refusing to bind to {0}: {1}")]
+    InsecureRemoteBind(IpAddr, String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -38,13 +49,18 @@ impl FromStr for SandboxMode {
 #[derive(Debug, Clone)]
 pub struct Config {
     pub port: u16,
+    /// Address the API server binds to; anything but loopback exposes it to the network
+    pub host: IpAddr,
     pub cors_origin: String,
     pub cors_allow_any_localhost: bool,
+    /// Extra origins allowed when the server is reachable from other machines
+    pub cors_remote_origins: Vec<String>,
     pub allowed_browse_paths: Vec<String>,
     pub browse_sandbox_mode: SandboxMode,
 
     // Middleware configuration
     pub rate_limit: RateLimitConfig,
+    pub request_limits: RequestLimitsConfig,
     pub security_headers_enabled: bool,
     pub enable_hsts: bool,
     pub enable_request_id: bool,
@@ -77,6 +93,21 @@ impl Config {
             .parse::<bool>()
             .unwrap_or(true);
 
+        let host = match env::var(HOST_ENV) {
+            Ok(value) if !value.trim().is_empty() => value
+                .trim()
+                .parse::<IpAddr>()
+                .map_err(|_| ConfigError::InvalidHost(value))?,
+            _ => IpAddr::V4(Ipv4Addr::LOCALHOST),
+        };
+
+        let cors_remote_origins = env::var(REMOTE_ORIGINS_ENV)
+            .unwrap_or_default()
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+
         // Parse allowed browse paths from environment
         let allowed_browse_paths = env::var("ALLOWED_BROWSE_PATHS")
             .unwrap_or_else(|_| "~/Documents,~/Projects,~/Desktop,~/Downloads".to_string())
@@ -145,6 +176,14 @@ impl Config {
                 .unwrap_or(5),
         };
 
+        // Parse request timeout and body size limits per route group
+        let default_limits = RequestLimitsConfig::default();
+        let request_limits = RequestLimitsConfig {
+            crud: route_limits_from_env("", default_limits.crud),
+            import: route_limits_from_env("IMPORT_", default_limits.import),
+            ai: route_limits_from_env("AI_", default_limits.ai),
+        };
+
         // Parse security headers configuration
         let security_headers_enabled = env::var("SECURITY_HEADERS_ENABLED")
             .unwrap_or_else(|_| "true".to_string())
@@ -192,24 +231,179 @@ impl Config {
             .parse::<bool>()
             .unwrap_or(true);
 
+        // ACME (Let's Encrypt) certificates for public hostnames
+        let default_acme = AcmeConfig::default();
+        let acme = AcmeConfig {
+            enabled: env::var("TLS_ACME_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false),
+            domains: env::var("TLS_ACME_DOMAINS")
+                .unwrap_or_default()
+                .split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            contact_email: env::var("TLS_ACME_EMAIL").ok().filter(|s| !s.is_empty()),
+            challenge: match env::var("TLS_ACME_CHALLENGE") {
+                Ok(value) => value
+                    .parse::<AcmeChallenge>()
+                    .map_err(|e| ConfigError::InvalidTls(e.to_string()))?,
+                Err(_) => default_acme.challenge,
+            },
+            dns_hook: env::var("TLS_ACME_DNS_HOOK").ok().filter(|s| !s.is_empty()),
+            dns_propagation: env::var("TLS_ACME_DNS_PROPAGATION_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(default_acme.dns_propagation),
+            staging: env::var("TLS_ACME_STAGING")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false),
+            storage_dir: env::var("TLS_ACME_DIR")
+                .map(Into::into)
+                .unwrap_or(default_acme.storage_dir),
+        };
+
+        // Mutual TLS: client certificates are required and their CNs map to API tokens
+        let client_auth = ClientAuthConfig {
+            enabled: env::var("TLS_CLIENT_AUTH_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse::<bool>()
+                .unwrap_or(false),
+            ca_bundle_path: env::var("TLS_CLIENT_CA_PATH")
+                .ok()
+                .filter(|s| !s.is_empty())
+                .map(Into::into)
+                .unwrap_or_else(|| ClientAuthConfig::default().ca_bundle_path),
+            identities: parse_identity_map(&env::var("TLS_CLIENT_IDENTITIES").unwrap_or_default())
+                .map_err(ConfigError::InvalidTls)?,
+        };
+
         let tls = TlsConfig {
             enabled: tls_enabled,
             cert_path: tls_cert_path,
             key_path: tls_key_path,
             auto_generate: auto_generate_cert,
+            acme,
+            client_auth,
         };
 
-        Ok(Config {
+        let config = Config {
             port,
+            host,
             cors_origin,
             cors_allow_any_localhost,
+            cors_remote_origins,
             allowed_browse_paths,
             browse_sandbox_mode,
             rate_limit,
+            request_limits,
             security_headers_enabled,
             enable_hsts,
             enable_request_id,
             tls,
-        })
+        };
+        config.validate_remote_access()?;
+        Ok(config)
+    }
+
+    /// Whether the server accepts connections from other machines
+    pub fn is_remote(&self) -> bool {
+        network::is_remote_bind(self.host)
+    }
+
+    /// Exposing the server beyond loopback requires HTTPS and token authentication,
+    /// so credentials and API keys never cross the network in the clear
+    fn validate_remote_access(&self) -> Result<(), ConfigError> {
+        if !self.is_remote() {
+            return Ok(());
+        }
+        if !self.tls.enabled {
+            return Err(ConfigError::InsecureRemoteBind(
+                self.host,
+                "remote access requires HTTPS (set TLS_ENABLED=true)".to_string(),
+            ));
+        }
+        let dev_mode = env::var("ORKEE_DEV_MODE")
+            .map(|v| v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if dev_mode {
+            return Err(ConfigError::InsecureRemoteBind(
+                self.host,
+                "ORKEE_DEV_MODE disables API token authentication".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Overlay the reloadable settings from a merged configuration snapshot.
+    /// Values that are absent or fail to parse keep their current setting.
+    pub fn apply_snapshot(&mut self, snapshot: &orkee_config::ConfigSnapshot) {
+        if let Some(origin) = snapshot.get_first(&["orkee_cors_origin", "cors_origin"]) {
+            self.cors_origin = origin.to_string();
+        }
+        if let Some(allow) = snapshot.get_bool("cors_allow_any_localhost") {
+            self.cors_allow_any_localhost = allow;
+        }
+
+        let rate_limit = &mut self.rate_limit;
+        if let Some(enabled) = snapshot.get_bool("rate_limit_enabled") {
+            rate_limit.enabled = enabled;
+        }
+        for (key, field) in [
+            ("rate_limit_health_rpm", &mut rate_limit.health_rpm),
+            ("rate_limit_browse_rpm", &mut rate_limit.browse_rpm),
+            ("rate_limit_projects_rpm", &mut rate_limit.projects_rpm),
+            ("rate_limit_preview_rpm", &mut rate_limit.preview_rpm),
+            ("rate_limit_telemetry_rpm", &mut rate_limit.telemetry_rpm),
+            ("rate_limit_ai_rpm", &mut rate_limit.ai_rpm),
+            ("rate_limit_users_rpm", &mut rate_limit.users_rpm),
+            ("rate_limit_security_rpm", &mut rate_limit.security_rpm),
+            ("rate_limit_oauth_rpm", &mut rate_limit.oauth_rpm),
+            ("rate_limit_sandbox_rpm", &mut rate_limit.sandbox_rpm),
+            ("rate_limit_global_rpm", &mut rate_limit.global_rpm),
+            ("rate_limit_burst_size", &mut rate_limit.burst_size),
+        ] {
+            if let Some(value) = snapshot.parse::<u32>(key).filter(|v| *v > 0) {
+                *field = value;
+            }
+        }
+
+        // Client certificate settings are only read when the HTTPS server starts
+        let client_auth = &mut self.tls.client_auth;
+        if let Some(enabled) = snapshot.get_bool("tls_client_auth_enabled") {
+            client_auth.enabled = enabled;
+        }
+        if let Some(path) = snapshot.get("tls_client_ca_path").filter(|p| !p.is_empty()) {
+            client_auth.ca_bundle_path = path.into();
+        }
+        if let Some(raw) = snapshot.get("tls_client_identities") {
+            match parse_identity_map(raw) {
+                Ok(identities) => client_auth.identities = identities,
+                Err(e) => tracing::warn!("Ignoring invalid tls_client_identities: {}", e),
+            }
+        }
+    }
+}
+
+/// Read `{prefix}REQUEST_TIMEOUT_SECS` and `{prefix}MAX_BODY_SIZE_BYTES`,
+/// keeping the defaults for unset, invalid, or zero values
+fn route_limits_from_env(prefix: &str, defaults: RouteLimits) -> RouteLimits {
+    let read = |name: &str| {
+        env::var(format!("{}{}", prefix, name))
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|v| *v > 0)
+    };
+
+    RouteLimits {
+        timeout: read("REQUEST_TIMEOUT_SECS")
+            .map(Duration::from_secs)
+            .unwrap_or(defaults.timeout),
+        max_body_bytes: read("MAX_BODY_SIZE_BYTES")
+            .map(|v| v as usize)
+            .unwrap_or(defaults.max_body_bytes),
     }
 }
@@ -0,0 +1,176 @@
+// ABOUTME: Wires the layered configuration service into the running server
+// ABOUTME: Loads database settings, reloads on SIGHUP, and pushes changes to rate limiting, CORS, and telemetry
+
+//! Configuration keys are environment variable names in lowercase. In
+//! `~/.orkee/config.toml` nested tables are flattened with `_`, so
+//! `[rate_limit] global_rpm = 60` is equivalent to `RATE_LIMIT_GLOBAL_RPM=60`.
+
+use async_trait::async_trait;
+use orkee_config::constants::*;
+use orkee_config::service::default_config_path;
+use orkee_config::{ConfigService, ConfigSnapshot, SettingsSource};
+use orkee_projects::SettingsStorage;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use crate::config::Config;
+use crate::middleware::RateLimitLayer;
+use crate::telemetry::TelemetryManager;
+use crate::SharedCorsPolicy;
+
+/// Environment variables that participate in runtime reloads
+const RELOADABLE_ENV_KEYS: &[&str] = &[
+    ORKEE_CORS_ORIGIN,
+    CORS_ORIGIN,
+    CORS_ALLOW_ANY_LOCALHOST,
+    RATE_LIMIT_ENABLED,
+    RATE_LIMIT_HEALTH_RPM,
+    RATE_LIMIT_BROWSE_RPM,
+    RATE_LIMIT_PROJECTS_RPM,
+    RATE_LIMIT_PREVIEW_RPM,
+    RATE_LIMIT_TELEMETRY_RPM,
+    RATE_LIMIT_AI_RPM,
+    RATE_LIMIT_USERS_RPM,
+    RATE_LIMIT_SECURITY_RPM,
+    RATE_LIMIT_OAUTH_RPM,
+    RATE_LIMIT_SANDBOX_RPM,
+    RATE_LIMIT_GLOBAL_RPM,
+    RATE_LIMIT_BURST_SIZE,
+    ORKEE_TELEMETRY_ENABLED,
+];
+
+/// Environment variables read from the snapshot at startup so they take
+/// precedence over the database settings of the same name
+const STARTUP_ENV_KEYS: &[&str] = &[
+    TLS_CLIENT_AUTH_ENABLED,
+    TLS_CLIENT_CA_PATH,
+    TLS_CLIENT_IDENTITIES,
+];
+
+/// Exposes `system_settings` rows to the configuration service
+pub struct DatabaseSettingsSource {
+    storage: Arc<SettingsStorage>,
+}
+
+impl DatabaseSettingsSource {
+    pub fn new(storage: Arc<SettingsStorage>) -> Self {
+        Self { storage }
+    }
+}
+
+#[async_trait]
+impl SettingsSource for DatabaseSettingsSource {
+    async fn load_settings(&self) -> Result<Vec<(String, String)>, String> {
+        let settings = self.storage.get_all().await.map_err(|e| e.to_string())?;
+
+        // Env-only rows are informational mirrors of .env values; secrets never
+        // belong in the shared snapshot
+        Ok(settings
+            .into_iter()
+            .filter(|s| !s.is_env_only && !s.is_secret)
+            .map(|s| (s.key, s.value))
+            .collect())
+    }
+}
+
+/// Build the configuration service and perform the initial load.
+/// A failed initial load is logged and the server continues with env-only values.
+pub async fn create_config_service(settings: Option<Arc<SettingsStorage>>) -> ConfigService {
+    let mut builder = ConfigService::builder()
+        .track_env_keys(RELOADABLE_ENV_KEYS.iter().chain(STARTUP_ENV_KEYS).copied());
+
+    if let Some(path) = default_config_path() {
+        builder = builder.config_file(path);
+    }
+    if let Some(storage) = settings {
+        builder = builder.settings_source(Arc::new(DatabaseSettingsSource::new(storage)));
+    }
+
+    let service = builder.build();
+    if let Err(e) = service.reload().await {
+        warn!(
+            "Failed to load configuration, using environment defaults: {}",
+            e
+        );
+    }
+    service
+}
+
+/// Reload configuration whenever the process receives SIGHUP
+#[cfg(unix)]
+pub fn spawn_reload_signal_handler(service: ConfigService) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("Failed to install SIGHUP handler: {}", e);
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            info!("Received SIGHUP, reloading configuration");
+            match service.reload().await {
+                Ok(outcome) => info!(
+                    "Configuration reload complete (version {}, changed: {:?})",
+                    outcome.version, outcome.changed_keys
+                ),
+                Err(e) => warn!("Configuration reload failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(not(unix))]
+pub fn spawn_reload_signal_handler(_service: ConfigService) {
+    debug!("SIGHUP reload is not supported on this platform");
+}
+
+/// Apply the current snapshot, then re-apply on every change
+fn spawn_subscriber<F>(service: &ConfigService, subsystem: &'static str, apply: F)
+where
+    F: Fn(&ConfigSnapshot) + Send + 'static,
+{
+    let mut receiver = service.subscribe();
+    apply(&receiver.borrow_and_update());
+
+    tokio::spawn(async move {
+        while receiver.changed().await.is_ok() {
+            let snapshot = receiver.borrow_and_update().clone();
+            debug!(
+                "Applying configuration version {} to {}",
+                snapshot.version(),
+                subsystem
+            );
+            apply(&snapshot);
+        }
+    });
+}
+
+pub fn watch_rate_limit(service: &ConfigService, layer: RateLimitLayer, base: Config) {
+    spawn_subscriber(service, "rate limiting", move |snapshot| {
+        let mut config = base.clone();
+        config.apply_snapshot(snapshot);
+        layer.update_config(config.rate_limit);
+    });
+}
+
+pub fn watch_cors(service: &ConfigService, policy: SharedCorsPolicy, base: Config) {
+    spawn_subscriber(service, "CORS", move |snapshot| {
+        let mut config = base.clone();
+        config.apply_snapshot(snapshot);
+        if let Ok(mut current) = policy.write() {
+            current.allow_any_localhost = config.cors_allow_any_localhost;
+            current.configured_origin = config.cors_origin;
+        }
+    });
+}
+
+pub fn watch_telemetry(service: &ConfigService, manager: Arc<TelemetryManager>) {
+    spawn_subscriber(service, "telemetry", move |snapshot| {
+        let enabled = snapshot.get_bool(ORKEE_TELEMETRY_ENABLED).unwrap_or(true);
+        manager.set_enabled(enabled);
+    });
+}
@@ -21,9 +21,18 @@ pub enum AppError {
     #[error("Rate limit exceeded")]
     RateLimitExceeded { retry_after: u64, limit: u32 },
 
+    #[error("Request timed out after {timeout_secs}s")]
+    RequestTimeout { timeout_secs: u64 },
+
+    #[error("Request body exceeds {limit} bytes")]
+    PayloadTooLarge { limit: usize },
+
     #[error("Unauthorized: {message}")]
     Unauthorized { message: String },
 
+    #[error("Locked out after repeated authentication failures")]
+    AuthLockedOut { retry_after: u64 },
+
     #[error("Forbidden: {message}")]
     Forbidden { message: String },
 
@@ -79,7 +88,12 @@ impl AppError {
             AppError::RateLimitExceeded { .. } => {
                 (StatusCode::TOO_MANY_REQUESTS, "RATE_LIMIT_EXCEEDED")
             }
+            AppError::RequestTimeout { .. } => (StatusCode::REQUEST_TIMEOUT, "REQUEST_TIMEOUT"),
+            AppError::PayloadTooLarge { .. } => {
+                (StatusCode::PAYLOAD_TOO_LARGE, "PAYLOAD_TOO_LARGE")
+            }
             AppError::Unauthorized { .. } => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+            AppError::AuthLockedOut { .. } => (StatusCode::TOO_MANY_REQUESTS, "AUTH_LOCKED_OUT"),
             AppError::Forbidden { .. } => (StatusCode::FORBIDDEN, "FORBIDDEN"),
             AppError::PathAccessDenied(_) => (StatusCode::FORBIDDEN, "PATH_ACCESS_DENIED"),
             AppError::PathTraversal => (StatusCode::FORBIDDEN, "PATH_TRAVERSAL"),
@@ -145,7 +159,16 @@ impl AppError {
             AppError::RateLimitExceeded { .. } => {
                 "Too many requests. Please try again later".to_string()
             }
+            AppError::RequestTimeout { timeout_secs } => {
+                format!("Request did not complete within {} seconds", timeout_secs)
+            }
+            AppError::PayloadTooLarge { limit } => {
+                format!("Request body exceeds the maximum size of {} bytes", limit)
+            }
             AppError::Unauthorized { message } => message.clone(),
+            AppError::AuthLockedOut { .. } => {
+                "Too many failed authentication attempts. Please try again later".to_string()
+            }
             AppError::Forbidden { message } => message.clone(),
             AppError::PathAccessDenied(_) => "Access to this path is not allowed".to_string(),
             AppError::PathTraversal => "Path traversal detected and blocked".to_string(),
@@ -162,8 +185,8 @@ impl AppError {
                 orkee_projects::manager::ManagerError::DuplicatePath(path) => {
                     format!("A project already exists at path '{}'", path)
                 }
-                orkee_projects::manager::ManagerError::Validation(errors) => {
-                    format!("Validation failed: {} error(s)", errors.len())
+                orkee_projects::manager::ManagerError::Validation(report) => {
+                    format!("Validation failed: {} error(s)", report.errors.len())
                 }
                 orkee_projects::manager::ManagerError::Storage(_) => {
                     "Data storage error".to_string()
@@ -279,11 +302,25 @@ impl IntoResponse for AppError {
             details: None,
         };
 
-        // Add retry_after for rate limiting
-        if let AppError::RateLimitExceeded { retry_after, .. } = &self {
+        // Add retry_after for rate limiting and lockouts
+        if let AppError::RateLimitExceeded { retry_after, .. }
+        | AppError::AuthLockedOut { retry_after } = &self
+        {
             error_detail.retry_after = Some(*retry_after);
         }
 
+        // Report each invalid field so forms can show errors next to their inputs
+        if let AppError::Storage(orkee_projects::manager::ManagerError::Validation(report)) = &self
+        {
+            error_detail.details = Some(
+                report
+                    .by_field()
+                    .into_iter()
+                    .map(|(field, messages)| (field.to_string(), messages.join("; ")))
+                    .collect(),
+            );
+        }
+
         let error_response = ErrorResponse {
             success: false,
             error: error_detail,
@@ -304,6 +341,11 @@ impl IntoResponse for AppError {
                 retry_after.to_string().parse().unwrap(),
             );
         }
+        if let AppError::AuthLockedOut { retry_after } = &self {
+            response
+                .headers_mut()
+                .insert("Retry-After", retry_after.to_string().parse().unwrap());
+        }
 
         response
     }
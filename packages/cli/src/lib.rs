@@ -1,7 +1,11 @@
 use axum::http::{header, HeaderValue, Method};
 use axum_server::tls_rustls::RustlsConfig;
 use colored::Colorize;
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 use tower_http::{
     cors::{AllowHeaders, AllowOrigin, CorsLayer},
     trace::TraceLayer,
@@ -11,9 +15,11 @@ use tracing::{error, info};
 pub mod api;
 pub mod auth;
 pub mod config;
+pub mod config_service;
 pub mod dashboard;
 pub mod error;
 pub mod middleware;
+pub mod network;
 pub mod telemetry;
 pub mod tls;
 
@@ -22,50 +28,78 @@ mod tests;
 
 use config::Config;
 
-fn create_cors_origin(allow_any_localhost: bool, configured_origin: &str) -> AllowOrigin {
-    if allow_any_localhost {
-        // Allow any localhost origin for development flexibility
-        AllowOrigin::predicate(|origin: &HeaderValue, _: &_| {
-            if let Ok(origin_str) = origin.to_str() {
-                let allowed = origin_str.starts_with("http://localhost:")
-                    || origin_str.starts_with("http://127.0.0.1:")
-                    || origin_str.starts_with("http://[::1]:")
-                    || origin_str.starts_with("https://localhost:")
-                    || origin_str.starts_with("https://127.0.0.1:")
-                    || origin_str.starts_with("https://[::1]:")
-                    || origin_str == "tauri://localhost"
-                    || origin_str == "http://tauri.localhost"
-                    || origin_str == "https://tauri.localhost";
-
-                if !allowed {
-                    error!("CORS blocked origin: {}", origin_str);
-                }
-                allowed
-            } else {
-                false
-            }
-        })
-    } else {
-        // Strict mode: only allow the configured origin and Tauri origins
-        let allowed = configured_origin.to_string();
-        AllowOrigin::predicate(move |origin: &HeaderValue, _: &_| {
-            if let Ok(origin_str) = origin.to_str() {
-                let is_allowed = origin_str == allowed
-                    || origin_str == "tauri://localhost"
-                    || origin_str == "http://tauri.localhost"
-                    || origin_str == "https://tauri.localhost";
-
-                if !is_allowed {
-                    error!("CORS blocked origin: {}", origin_str);
-                }
-                is_allowed
-            } else {
-                false
-            }
-        })
+/// Origins always accepted regardless of configuration (desktop app webviews)
+const TAURI_ORIGINS: [&str; 3] = [
+    "tauri://localhost",
+    "http://tauri.localhost",
+    "https://tauri.localhost",
+];
+
+/// CORS origin policy, shared with the config service so it can change at runtime
+#[derive(Debug, Clone)]
+pub struct CorsPolicy {
+    pub allow_any_localhost: bool,
+    pub configured_origin: String,
+    /// Origins of the server's LAN address when bound beyond loopback
+    pub remote_origins: Vec<String>,
+}
+
+impl CorsPolicy {
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            allow_any_localhost: config.cors_allow_any_localhost,
+            configured_origin: config.cors_origin.clone(),
+            remote_origins: config.cors_remote_origins.clone(),
+        }
+    }
+
+    fn allows(&self, origin_str: &str) -> bool {
+        if TAURI_ORIGINS.contains(&origin_str) {
+            return true;
+        }
+
+        if self
+            .remote_origins
+            .iter()
+            .any(|origin| origin == origin_str)
+        {
+            return true;
+        }
+
+        if self.allow_any_localhost {
+            // Allow any localhost origin for development flexibility
+            origin_str.starts_with("http://localhost:")
+                || origin_str.starts_with("http://127.0.0.1:")
+                || origin_str.starts_with("http://[::1]:")
+                || origin_str.starts_with("https://localhost:")
+                || origin_str.starts_with("https://127.0.0.1:")
+                || origin_str.starts_with("https://[::1]:")
+        } else {
+            // Strict mode: only allow the configured origin and Tauri origins
+            origin_str == self.configured_origin
+        }
     }
 }
 
+pub type SharedCorsPolicy = Arc<RwLock<CorsPolicy>>;
+
+fn create_cors_origin(policy: SharedCorsPolicy) -> AllowOrigin {
+    AllowOrigin::predicate(move |origin: &HeaderValue, _: &_| {
+        let Ok(origin_str) = origin.to_str() else {
+            return false;
+        };
+        let allowed = policy
+            .read()
+            .map(|policy| policy.allows(origin_str))
+            .unwrap_or(false);
+
+        if !allowed {
+            error!("CORS blocked origin: {}", origin_str);
+        }
+        allowed
+    })
+}
+
 pub async fn run_server() -> Result<(), Box<dyn std::error::Error>> {
     run_server_with_options(None).await
 }
@@ -98,8 +132,8 @@ pub async fn run_server_with_options(
     );
     info!("  Directory sandbox: {:?}", config.browse_sandbox_mode);
     info!(
-        "  TLS: {} (auto-generate: {})",
-        config.tls.enabled, config.tls.auto_generate
+        "  TLS: {} (auto-generate: {}, client certificates: {})",
+        config.tls.enabled, config.tls.auto_generate, config.tls.client_auth.enabled
     );
 
     if config.tls.enabled {
@@ -251,7 +285,8 @@ async fn run_http_server(
     config: Config,
     dashboard_path: Option<std::path::PathBuf>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let app = create_application_router(config.clone(), dashboard_path).await?;
+    let (app, _config_service, _db_state) =
+        create_application_router(config.clone(), dashboard_path).await?;
 
     // Initialize API token if needed
     initialize_api_token().await;
@@ -259,7 +294,7 @@ async fn run_http_server(
     // Check for API key migration from environment variables to database
     check_api_key_migration().await;
 
-    let addr = SocketAddr::from(([127, 0, 0, 1], config.port));
+    let addr = SocketAddr::new(config.host, config.port);
 
     info!("Starting HTTP server on {}", addr);
     println!("✅ HTTP server listening on {}", addr);
@@ -278,12 +313,16 @@ async fn run_dual_server_mode(
     config: Config,
     dashboard_path: Option<std::path::PathBuf>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    // Create main application router
+    let (app, config_service, db_state) =
+        create_application_router(config.clone(), dashboard_path).await?;
+
+    // Client certificate settings may also come from the security settings
+    let mut config = config;
+    config.apply_snapshot(&config_service.current());
+
     // Initialize TLS manager
     let tls_manager = tls::TlsManager::new(config.tls.clone());
-    let rustls_config = tls_manager.initialize().await?;
-
-    // Create main application router
-    let app = create_application_router(config.clone(), dashboard_path).await?;
 
     // Initialize API token if needed
     initialize_api_token().await;
@@ -291,11 +330,12 @@ async fn run_dual_server_mode(
     // Check for API key migration from environment variables to database
     check_api_key_migration().await;
 
-    // Create HTTP redirect router (simpler router that just redirects to HTTPS)
-    let redirect_app = create_redirect_router(config.clone()).await?;
+    // Create HTTP redirect router (redirects to HTTPS and answers ACME challenges)
+    let redirect_app =
+        create_redirect_router(config.clone(), tls_manager.http01_challenges()).await?;
 
     // HTTPS server (main application) on configured port
-    let https_addr = SocketAddr::from(([127, 0, 0, 1], config.port));
+    let https_addr = SocketAddr::new(config.host, config.port);
 
     // HTTP server (redirects only) on port 4000 (or port - 1 if custom port)
     let http_port = if config.port == 4001 {
@@ -303,18 +343,31 @@ async fn run_dual_server_mode(
     } else {
         config.port.saturating_sub(1)
     };
-    let http_addr = SocketAddr::from(([127, 0, 0, 1], http_port));
+    let http_addr = SocketAddr::new(config.host, http_port);
 
     info!("Starting dual server mode:");
     info!("  HTTPS server (main): {}", https_addr);
     info!("  HTTP server (redirect): {}", http_addr);
 
-    println!("✅ HTTPS server listening on {}", https_addr);
     println!("✅ HTTP redirect server listening on {}", http_addr);
-
-    // Start both servers concurrently
-    let https_server = start_https_server(https_addr, app, rustls_config);
     let http_server = start_http_redirect_server(http_addr, redirect_app);
+    tokio::pin!(http_server);
+
+    // Certificates are loaded while the HTTP server runs so ACME HTTP-01
+    // challenges can be answered during provisioning
+    let rustls_config = tokio::select! {
+        result = &mut http_server => {
+            error!("HTTP redirect server stopped: {:?}", result);
+            return result;
+        }
+        result = tls_manager.initialize() => result?,
+    };
+    let _renewal = tls_manager.spawn_acme_renewal(rustls_config.clone());
+    let _expiry_monitor =
+        tls_manager.spawn_expiry_monitor(rustls_config.clone(), db_state.notifications.clone());
+
+    println!("✅ HTTPS server listening on {}", https_addr);
+    let https_server = start_https_server(https_addr, app, rustls_config, &config.tls);
 
     // Use tokio::select to run both servers and return if either fails
     tokio::select! {
@@ -333,10 +386,25 @@ async fn start_https_server(
     addr: SocketAddr,
     app: axum::Router,
     rustls_config: RustlsConfig,
+    tls_config: &tls::TlsConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    axum_server::bind_rustls(addr, rustls_config)
-        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
-        .await?;
+    let make_service = app.into_make_service_with_connect_info::<SocketAddr>();
+
+    if tls_config.client_auth.enabled {
+        // Record each connection's client certificate so its CN can act as an API identity
+        let acceptor = tls::mtls::ClientCertAcceptor::new(
+            rustls_config,
+            tls_config.client_auth.identities.clone(),
+        );
+        axum_server::bind(addr)
+            .acceptor(acceptor)
+            .serve(make_service)
+            .await?;
+    } else {
+        axum_server::bind_rustls(addr, rustls_config)
+            .serve(make_service)
+            .await?;
+    }
     Ok(())
 }
 
@@ -356,13 +424,22 @@ async fn start_http_redirect_server(
 async fn create_application_router(
     config: Config,
     dashboard_path: Option<std::path::PathBuf>,
-) -> Result<axum::Router, Box<dyn std::error::Error>> {
+) -> Result<
+    (
+        axum::Router,
+        orkee_config::ConfigService,
+        orkee_projects::DbState,
+    ),
+    Box<dyn std::error::Error>,
+> {
     // Create CORS layer with specific headers only
     let allowed_headers = AllowHeaders::list([
         header::CONTENT_TYPE,
         header::ACCEPT,
         header::AUTHORIZATION, // For future API key
         header::USER_AGENT,
+        header::IF_MATCH,      // Optimistic concurrency on project and task updates
+        header::IF_NONE_MATCH, // Conditional GETs
         header::HeaderName::from_static("x-api-key"),
         header::HeaderName::from_static("x-api-token"), // API token authentication
         header::HeaderName::from_static("x-csrf-token"), // CSRF protection
@@ -370,22 +447,28 @@ async fn create_application_router(
         header::HeaderName::from_static("anthropic-dangerous-direct-browser-access"), // For Anthropic SDK browser safety check
     ]);
 
-    // Create CORS origin configuration
-    let cors_origin = create_cors_origin(config.cors_allow_any_localhost, &config.cors_origin);
+    // Create the router with all middleware layers
+    // IMPORTANT: In Axum, layers are applied in REVERSE order (last added = first executed)
+    // Execution order: CORS → Security → Tracing → Rate Limit → Request Limits → Auth → CSRF → Handlers
+    let (mut app_builder, db_state, config_service) =
+        crate::api::create_router_with_options(dashboard_path, None).await;
+
+    // Reload configuration on SIGHUP in addition to POST /api/config/reload
+    config_service::spawn_reload_signal_handler(config_service.clone());
+
+    // Create CORS origin configuration, kept in sync with the config service
+    let cors_policy: SharedCorsPolicy = Arc::new(RwLock::new(CorsPolicy::from_config(&config)));
+    config_service::watch_cors(&config_service, cors_policy.clone(), config.clone());
+    let cors_origin = create_cors_origin(cors_policy);
 
     let cors = CorsLayer::new()
         .allow_origin(cors_origin)
         .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
         .allow_headers(allowed_headers)
+        .expose_headers([header::ETAG])
         .allow_credentials(false) // Explicitly disable credentials for local use
         .max_age(Duration::from_secs(3600));
 
-    // Create the router with all middleware layers
-    // IMPORTANT: In Axum, layers are applied in REVERSE order (last added = first executed)
-    // Execution order: CORS → Security → Tracing → Rate Limit → Auth → CSRF → Handlers
-    let (mut app_builder, db_state) =
-        crate::api::create_router_with_options(dashboard_path, None).await;
-
     // Create CSRF layer for CSRF protection
     let csrf_layer = middleware::CsrfLayer::new();
     info!("CSRF protection enabled");
@@ -405,34 +488,50 @@ async fn create_application_router(
     ));
     info!("API token authentication middleware enabled");
 
-    // Add rate limiting if enabled
+    // Add per-route-group request timeouts and body size limits. Axum's default
+    // 2MB extractor limit is disabled so the configured limits apply instead.
+    app_builder = app_builder.layer(axum::middleware::from_fn_with_state(
+        config.request_limits.clone(),
+        middleware::request_limits::request_limits_middleware,
+    ));
+    app_builder = app_builder.layer(axum::extract::DefaultBodyLimit::disable());
+    info!(
+        "Request limits enabled (timeouts: {}s CRUD, {}s import, {}s AI)",
+        config.request_limits.crud.timeout.as_secs(),
+        config.request_limits.import.timeout.as_secs(),
+        config.request_limits.ai.timeout.as_secs()
+    );
+
+    // Add rate limiting. The layer is always installed so it can be enabled,
+    // disabled, or retuned at runtime; the middleware skips work when disabled.
     if config.rate_limit.enabled {
         info!(
             "Rate limiting enabled with {} global requests/minute (per-IP)",
             config.rate_limit.global_rpm
         );
+    }
 
-        let rate_limit_layer = middleware::RateLimitLayer::new(config.rate_limit.clone());
-
-        // Spawn background task to evict stale IP entries every 5 minutes
-        let limiters_for_cleanup = rate_limit_layer.limiters();
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(Duration::from_secs(300));
-            loop {
-                interval.tick().await;
-                if let Ok(limiters) = limiters_for_cleanup.lock() {
-                    for limiter in limiters.values() {
-                        limiter.retain_recent();
-                    }
+    let rate_limit_layer = middleware::RateLimitLayer::new(config.rate_limit.clone());
+    config_service::watch_rate_limit(&config_service, rate_limit_layer.clone(), config.clone());
+
+    // Spawn background task to evict stale IP entries every 5 minutes
+    let limiters_for_cleanup = rate_limit_layer.limiters();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(300));
+        loop {
+            interval.tick().await;
+            if let Ok(limiters) = limiters_for_cleanup.lock() {
+                for limiter in limiters.values() {
+                    limiter.retain_recent();
                 }
             }
-        });
+        }
+    });
 
-        app_builder = app_builder.layer(axum::Extension(rate_limit_layer));
-        app_builder = app_builder.layer(axum::middleware::from_fn(
-            middleware::rate_limit::rate_limit_middleware,
-        ));
-    }
+    app_builder = app_builder.layer(axum::Extension(rate_limit_layer));
+    app_builder = app_builder.layer(axum::middleware::from_fn(
+        middleware::rate_limit::rate_limit_middleware,
+    ));
 
     // Add tracing layer for request logging
     app_builder = app_builder.layer(TraceLayer::new_for_http());
@@ -454,11 +553,12 @@ async fn create_application_router(
     // Add panic handler (outermost layer)
     let app = app_builder.layer(middleware::create_panic_handler());
 
-    Ok(app)
+    Ok((app, config_service, db_state))
 }
 
 async fn create_redirect_router(
     config: Config,
+    acme_challenges: tls::acme::Http01Challenges,
 ) -> Result<axum::Router, Box<dyn std::error::Error>> {
     use middleware::https_redirect::{https_redirect_middleware, HttpsRedirectConfig};
 
@@ -482,7 +582,9 @@ async fn create_redirect_router(
             },
         ))
         .layer(TraceLayer::new_for_http())
-        .layer(middleware::create_panic_handler());
+        .layer(middleware::create_panic_handler())
+        // ACME challenges must be answered over plain HTTP, not redirected
+        .merge(tls::acme::challenge_router(acme_challenges));
 
     Ok(redirect_router)
 }
@@ -1,16 +1,20 @@
 // ABOUTME: API token authentication middleware for request authorization
 // ABOUTME: Validates API tokens before allowing access to protected endpoints
+// ABOUTME: Counts failed attempts per client IP and refuses addresses locked out for brute forcing
 
 use axum::{
-    extract::{Request, State},
+    extract::{ConnectInfo, Request, State},
     middleware::Next,
     response::Response,
 };
+use std::net::{IpAddr, SocketAddr};
 use tracing::{debug, warn};
 
+use orkee_notifications::{NotificationEventType, NotificationInput, NotificationSeverity};
 use orkee_projects::DbState;
 
 use crate::error::AppError;
+use crate::tls::mtls::ClientIdentity;
 
 /// Header name for API token
 pub const API_TOKEN_HEADER: &str = "X-API-Token";
@@ -18,23 +22,29 @@ pub const API_TOKEN_HEADER: &str = "X-API-Token";
 /// Paths that don't require authentication via header
 /// Note: /api/preview/events performs its own token validation via query parameter
 /// because the EventSource API does not support custom headers
-/// Note: /api/ai/ proxy endpoints handle authentication by fetching user's API key from database
+/// Note: inbound CI webhooks are called by external CI systems and verify HMAC signatures instead
 const WHITELISTED_PATHS: &[&str] = &[
     "/api/health",
     "/api/status",
     "/api/csrf-token",
     "/api/preview/events",
-    "/api/ai/",
+    "/api/webhooks/github",
+    "/api/webhooks/generic",
 ];
 
+/// Paths that skip token validation only for clients on this machine
+/// Note: /api/ai/ proxy endpoints spend the user's stored provider keys, so when the
+/// server is reachable from the network, remote callers must present a token
+const LOCAL_ONLY_WHITELISTED_PATHS: &[&str] = &["/api/ai/"];
+
 /// Extension key for storing authentication status in request
 pub const AUTHENTICATED_EXTENSION: &str = "api_token_authenticated";
 
-/// Check if a path requires authentication
-fn requires_authentication(path: &str) -> bool {
-    !WHITELISTED_PATHS
-        .iter()
-        .any(|&whitelisted| path.starts_with(whitelisted))
+/// Check if a path requires authentication for a local or remote client
+fn requires_authentication(path: &str, remote_client: bool) -> bool {
+    let whitelisted = |paths: &[&str]| paths.iter().any(|&prefix| path.starts_with(prefix));
+    !(whitelisted(WHITELISTED_PATHS)
+        || (!remote_client && whitelisted(LOCAL_ONLY_WHITELISTED_PATHS)))
 }
 
 /// Check if dev mode is enabled based on environment variable value
@@ -46,6 +56,58 @@ fn is_dev_mode_enabled(env_value: Option<String>) -> bool {
         .unwrap_or(false)
 }
 
+/// Address of the client whose failed attempts count towards a lockout.
+///
+/// Loopback clients are never locked out, so a misconfigured local tool can't
+/// lock the dashboard out of its own server.
+fn lockout_address(request: &Request) -> Option<IpAddr> {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+        .filter(|ip| !ip.is_loopback())
+}
+
+/// Count a failed attempt against the client and notify when it gets locked out
+async fn record_auth_failure(db: &DbState, client_ip: Option<IpAddr>, path: &str, reason: &str) {
+    let Some(ip) = client_ip else {
+        return;
+    };
+
+    match db
+        .intrusion_detector
+        .record_failure(&ip.to_string(), path, reason)
+        .await
+    {
+        Ok(Some(alert)) => {
+            warn!(
+                ip = %ip,
+                failures = alert.failure_count,
+                audit = true,
+                "IP address locked out after repeated authentication failures"
+            );
+            db.notifications
+                .notify_in_background(NotificationInput::new(
+                    NotificationEventType::AuthLockout,
+                    NotificationSeverity::Warning,
+                    format!("Blocked repeated failed logins from {}", ip),
+                    alert.message,
+                ));
+        }
+        Ok(None) => {}
+        Err(e) => warn!(error = %e, "Failed to record authentication failure"),
+    }
+}
+
+/// Forget the client's failed attempts once it authenticates
+async fn clear_auth_failures(db: &DbState, client_ip: Option<IpAddr>) {
+    if let Some(ip) = client_ip {
+        if let Err(e) = db.intrusion_detector.clear_failures(&ip.to_string()).await {
+            warn!(error = %e, "Failed to clear authentication failures");
+        }
+    }
+}
+
 /// API token validation middleware
 pub async fn api_token_middleware(
     State(db): State<DbState>,
@@ -62,7 +124,8 @@ pub async fn api_token_middleware(
     }
 
     // Skip authentication for whitelisted paths
-    if !requires_authentication(path) {
+    let client_ip = lockout_address(&request);
+    if !requires_authentication(path, client_ip.is_some()) {
         debug!(path = %path, "Path whitelisted, skipping token validation");
         return Ok(next.run(request).await);
     }
@@ -73,6 +136,50 @@ pub async fn api_token_middleware(
         return Ok(next.run(request).await);
     }
 
+    // Refuse clients locked out after repeated failures before checking credentials
+    if let Some(ip) = client_ip {
+        match db.intrusion_detector.locked_until(&ip.to_string()).await {
+            Ok(Some(locked_until)) => {
+                warn!(ip = %ip, path = %path, audit = true, "Request from locked out IP address refused");
+                let retry_after = (locked_until - chrono::Utc::now()).num_seconds().max(1);
+                return Err(AppError::AuthLockedOut {
+                    retry_after: retry_after as u64,
+                });
+            }
+            Ok(None) => {}
+            Err(e) => warn!(error = %e, "Failed to check authentication lockout"),
+        }
+    }
+
+    // A client certificate mapped to an active API token authenticates the request
+    let certificate_token = request
+        .extensions()
+        .get::<ClientIdentity>()
+        .and_then(|identity| identity.token_id.clone());
+    if let Some(token_id) = certificate_token {
+        match db.token_storage.get_token(&token_id).await {
+            Ok(Some(token_info)) if token_info.is_usable(chrono::Utc::now()) => {
+                if let Err(e) = db
+                    .token_storage
+                    .update_last_used(&token_info.token_hash)
+                    .await
+                {
+                    warn!(error = %e, "Failed to update token last_used timestamp");
+                }
+
+                debug!(path = %path, token = %token_info.name, "Client certificate authenticated");
+                clear_auth_failures(&db, client_ip).await;
+                let mut request = request;
+                request.extensions_mut().insert(true);
+                return Ok(next.run(request).await);
+            }
+            Ok(_) => {
+                warn!(token_id = %token_id, "Client certificate maps to an unknown, revoked or expired API token")
+            }
+            Err(e) => warn!(error = %e, "Client certificate identity lookup failed"),
+        }
+    }
+
     // Extract token from header
     let token = request
         .headers()
@@ -83,6 +190,7 @@ pub async fn api_token_middleware(
         Some(t) => t,
         None => {
             warn!(path = %path, "Missing API token");
+            record_auth_failure(&db, client_ip, path, "missing token").await;
             return Err(AppError::Unauthorized {
                 message: "API token required. Please include X-API-Token header.".to_string(),
             });
@@ -99,6 +207,7 @@ pub async fn api_token_middleware(
 
     if token_info.is_none() {
         warn!(path = %path, "Invalid API token provided");
+        record_auth_failure(&db, client_ip, path, "invalid token").await;
         return Err(AppError::Unauthorized {
             message: "Invalid API token".to_string(),
         });
@@ -112,6 +221,7 @@ pub async fn api_token_middleware(
         warn!(error = %e, "Failed to update token last_used timestamp");
     }
 
+    clear_auth_failures(&db, client_ip).await;
     debug!(path = %path, "API token validated successfully");
 
     // Store authentication status in request extensions for downstream handlers
@@ -277,6 +387,170 @@ mod tests {
         );
     }
 
+    fn certificate_request(token_id: &str) -> Request {
+        let mut request = Request::builder()
+            .uri("/api/test")
+            .body(Body::empty())
+            .unwrap();
+        request.extensions_mut().insert(ClientIdentity {
+            common_name: Some("laptop".to_string()),
+            token_id: Some(token_id.to_string()),
+        });
+        request
+    }
+
+    #[tokio::test]
+    async fn test_client_certificate_identity_allows_access() {
+        let db = setup_test_db().await;
+        create_test_token(&db).await;
+        let token_id = db.token_storage.list_tokens().await.unwrap()[0].id.clone();
+
+        let app = create_test_app(db.clone());
+        let response = app.oneshot(certificate_request(&token_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // Revoked tokens no longer authenticate their certificates
+        db.token_storage.revoke_token(&token_id).await.unwrap();
+        let app = create_test_app(db);
+        let response = app.oneshot(certificate_request(&token_id)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    fn token_request(token: &str) -> Request {
+        Request::builder()
+            .uri("/api/test")
+            .header(API_TOKEN_HEADER, token)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_expired_token_returns_401() {
+        let db = setup_test_db().await;
+        let token_gen = db
+            .token_storage
+            .create_token_with_expiry(
+                "expired",
+                Some(chrono::Utc::now() - chrono::Duration::minutes(1)),
+            )
+            .await
+            .unwrap();
+
+        let app = create_test_app(db.clone());
+        let response = app.oneshot(token_request(&token_gen.token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // Expired tokens no longer authenticate their certificates either
+        let app = create_test_app(db);
+        let response = app
+            .oneshot(certificate_request(&token_gen.id))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_rotated_token_works_during_grace_period() {
+        let db = setup_test_db().await;
+        let old_token = create_test_token(&db).await;
+        let token_id = db.token_storage.list_tokens().await.unwrap()[0].id.clone();
+
+        let rotated = db
+            .token_storage
+            .rotate_token(&token_id, chrono::Duration::minutes(5), None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        for token in [&old_token, &rotated.token] {
+            let app = create_test_app(db.clone());
+            let response = app.oneshot(token_request(token)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        // Ending the grace period early locks the old token out
+        db.token_storage
+            .set_expiry(&token_id, Some(chrono::Utc::now()))
+            .await
+            .unwrap();
+        let app = create_test_app(db);
+        let response = app.oneshot(token_request(&old_token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    fn remote_request(token: &str, ip: [u8; 4]) -> Request {
+        let mut request = token_request(token);
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::from((ip, 51234))));
+        request
+    }
+
+    #[tokio::test]
+    async fn test_repeated_failures_lock_out_remote_address() {
+        let db = setup_test_db().await;
+        let token = create_test_token(&db).await;
+
+        // The default policy locks an address out after 5 failures
+        for _ in 0..5 {
+            let app = create_test_app(db.clone());
+            let response = app
+                .oneshot(remote_request("wrong-token", [203, 0, 113, 7]))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        // Even a valid token is refused while the address is locked out
+        let app = create_test_app(db.clone());
+        let response = app
+            .oneshot(remote_request(&token, [203, 0, 113, 7]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key("Retry-After"));
+
+        // Other addresses are unaffected
+        let app = create_test_app(db.clone());
+        let response = app
+            .oneshot(remote_request(&token, [203, 0, 113, 8]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let alerts = db.intrusion_detector.list_alerts(10).await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].ip_address, "203.0.113.7");
+    }
+
+    #[tokio::test]
+    async fn test_loopback_failures_do_not_lock_out() {
+        let db = setup_test_db().await;
+        let token = create_test_token(&db).await;
+
+        for _ in 0..10 {
+            let app = create_test_app(db.clone());
+            let response = app
+                .oneshot(remote_request("wrong-token", [127, 0, 0, 1]))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        let app = create_test_app(db.clone());
+        let response = app
+            .oneshot(remote_request(&token, [127, 0, 0, 1]))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(db
+            .intrusion_detector
+            .list_alerts(10)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
     #[tokio::test]
     async fn test_options_request_bypasses_auth() {
         let db = setup_test_db().await;
@@ -296,13 +570,24 @@ mod tests {
 
     #[tokio::test]
     async fn test_requires_authentication_logic() {
-        assert!(!requires_authentication("/api/health"));
-        assert!(!requires_authentication("/api/status"));
-        assert!(!requires_authentication("/api/csrf-token"));
-        assert!(!requires_authentication("/api/preview/events"));
-        assert!(requires_authentication("/api/projects"));
-        assert!(requires_authentication("/api/test"));
-        assert!(requires_authentication("/api/settings"));
+        for remote in [false, true] {
+            assert!(!requires_authentication("/api/health", remote));
+            assert!(!requires_authentication("/api/health/ready", remote));
+            assert!(!requires_authentication("/api/status", remote));
+            assert!(!requires_authentication("/api/csrf-token", remote));
+            assert!(!requires_authentication("/api/preview/events", remote));
+            assert!(!requires_authentication("/api/webhooks/github", remote));
+            assert!(!requires_authentication("/api/webhooks/generic", remote));
+            assert!(requires_authentication("/api/webhooks/secrets", remote));
+            assert!(requires_authentication("/api/webhooks/deliveries", remote));
+            assert!(requires_authentication("/api/projects", remote));
+            assert!(requires_authentication("/api/test", remote));
+            assert!(requires_authentication("/api/settings", remote));
+        }
+
+        // The AI proxy is only open to clients on this machine
+        assert!(!requires_authentication("/api/ai/openai/v1/chat", false));
+        assert!(requires_authentication("/api/ai/openai/v1/chat", true));
     }
 
     #[test]
@@ -5,16 +5,14 @@ use axum::{
     response::Response,
 };
 use governor::{
-    clock::DefaultClock,
-    middleware::NoOpMiddleware,
-    state::keyed::DashMapStateStore,
-    Quota, RateLimiter,
+    clock::DefaultClock, middleware::NoOpMiddleware, state::keyed::DashMapStateStore, Quota,
+    RateLimiter,
 };
 use std::{
     collections::HashMap,
     net::{IpAddr, SocketAddr},
     num::NonZeroU32,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, RwLock},
 };
 use tracing::{debug, warn};
 
@@ -70,34 +68,40 @@ impl Default for RateLimitConfig {
 /// Rate limiter with per-endpoint configuration
 #[derive(Clone)]
 pub struct RateLimitLayer {
-    config: RateLimitConfig,
+    config: Arc<RwLock<RateLimitConfig>>,
     limiters: RateLimiterStorage,
 }
 
 impl RateLimitLayer {
     pub fn new(config: RateLimitConfig) -> Self {
         Self {
-            config,
+            config: Arc::new(RwLock::new(config)),
             limiters: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
+    /// Current configuration
+    pub fn config(&self) -> RateLimitConfig {
+        self.config
+            .read()
+            .map(|config| config.clone())
+            .unwrap_or_default()
+    }
+
+    /// Replace the configuration at runtime. Existing limiters are dropped so
+    /// new quotas take effect on the next request.
+    pub fn update_config(&self, config: RateLimitConfig) {
+        if let Ok(mut current) = self.config.write() {
+            *current = config;
+        }
+        if let Ok(mut limiters) = self.limiters.lock() {
+            limiters.clear();
+        }
+    }
+
     /// Get rate limit for specific endpoint category
     fn get_rate_limit_for_path(&self, path: &str) -> u32 {
-        let category = categorize_endpoint(path);
-        match category {
-            EndpointCategory::Health => self.config.health_rpm,
-            EndpointCategory::Browse => self.config.browse_rpm,
-            EndpointCategory::Projects => self.config.projects_rpm,
-            EndpointCategory::Preview => self.config.preview_rpm,
-            EndpointCategory::Telemetry => self.config.telemetry_rpm,
-            EndpointCategory::AI => self.config.ai_rpm,
-            EndpointCategory::Users => self.config.users_rpm,
-            EndpointCategory::Security => self.config.security_rpm,
-            EndpointCategory::OAuth => self.config.oauth_rpm,
-            EndpointCategory::Sandbox => self.config.sandbox_rpm,
-            EndpointCategory::Other => self.config.global_rpm,
-        }
+        rpm_for_category(&self.config(), categorize_endpoint(path))
     }
 
     /// Get all active limiters for cleanup
@@ -107,20 +111,9 @@ impl RateLimitLayer {
 
     /// Get or create rate limiter for specific endpoint category
     fn get_limiter_for_path(&self, path: &str) -> RateLimiterInstance {
+        let config = self.config();
         let category = categorize_endpoint(path);
-        let rpm = match category {
-            EndpointCategory::Health => self.config.health_rpm,
-            EndpointCategory::Browse => self.config.browse_rpm,
-            EndpointCategory::Projects => self.config.projects_rpm,
-            EndpointCategory::Preview => self.config.preview_rpm,
-            EndpointCategory::Telemetry => self.config.telemetry_rpm,
-            EndpointCategory::AI => self.config.ai_rpm,
-            EndpointCategory::Users => self.config.users_rpm,
-            EndpointCategory::Security => self.config.security_rpm,
-            EndpointCategory::OAuth => self.config.oauth_rpm,
-            EndpointCategory::Sandbox => self.config.sandbox_rpm,
-            EndpointCategory::Other => self.config.global_rpm,
-        };
+        let rpm = rpm_for_category(&config, category);
 
         let mut limiters = self.limiters.lock().unwrap();
         let key = format!("{}:{}", category.as_str(), rpm);
@@ -131,7 +124,7 @@ impl RateLimitLayer {
             let quota =
                 Quota::per_minute(NonZeroU32::new(rpm).unwrap_or(NonZeroU32::new(30).unwrap()))
                     .allow_burst(
-                        NonZeroU32::new(rpm * self.config.burst_size / 10)
+                        NonZeroU32::new(rpm * config.burst_size / 10)
                             .unwrap_or(NonZeroU32::new(5).unwrap()),
                     );
 
@@ -141,7 +134,7 @@ impl RateLimitLayer {
             debug!(
                 endpoint_category = %category.as_str(),
                 rpm = %rpm,
-                burst = %(rpm * self.config.burst_size / 10),
+                burst = %(rpm * config.burst_size / 10),
                 "Created rate limiter for endpoint category"
             );
 
@@ -150,6 +143,23 @@ impl RateLimitLayer {
     }
 }
 
+/// Requests-per-minute limit configured for an endpoint category
+fn rpm_for_category(config: &RateLimitConfig, category: EndpointCategory) -> u32 {
+    match category {
+        EndpointCategory::Health => config.health_rpm,
+        EndpointCategory::Browse => config.browse_rpm,
+        EndpointCategory::Projects => config.projects_rpm,
+        EndpointCategory::Preview => config.preview_rpm,
+        EndpointCategory::Telemetry => config.telemetry_rpm,
+        EndpointCategory::AI => config.ai_rpm,
+        EndpointCategory::Users => config.users_rpm,
+        EndpointCategory::Security => config.security_rpm,
+        EndpointCategory::OAuth => config.oauth_rpm,
+        EndpointCategory::Sandbox => config.sandbox_rpm,
+        EndpointCategory::Other => config.global_rpm,
+    }
+}
+
 /// Endpoint categories for different rate limiting rules
 #[derive(Debug, Clone, Copy)]
 enum EndpointCategory {
@@ -230,7 +240,7 @@ pub async fn rate_limit_middleware(
         .unwrap_or_else(|| RateLimitLayer::new(RateLimitConfig::default()));
 
     // Skip rate limiting if disabled
-    if !layer.config.enabled {
+    if !layer.config().enabled {
         return Ok(next.run(request).await);
     }
 
@@ -394,6 +404,22 @@ mod tests {
         assert!(Arc::ptr_eq(&health_limiter, &health_limiter2));
     }
 
+    #[tokio::test]
+    async fn test_update_config_replaces_limiters() {
+        let layer = RateLimitLayer::new(RateLimitConfig::default());
+        let before = layer.get_limiter_for_path("/api/health");
+        assert_eq!(layer.get_rate_limit_for_path("/api/health"), 60);
+
+        layer.update_config(RateLimitConfig {
+            health_rpm: 120,
+            ..RateLimitConfig::default()
+        });
+
+        assert_eq!(layer.get_rate_limit_for_path("/api/health"), 120);
+        let after = layer.get_limiter_for_path("/api/health");
+        assert!(!Arc::ptr_eq(&before, &after));
+    }
+
     #[tokio::test]
     async fn test_rate_limit_enforcement() {
         let quota = Quota::per_minute(NonZeroU32::new(2).unwrap());
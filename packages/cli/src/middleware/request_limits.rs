@@ -0,0 +1,229 @@
+// ABOUTME: Request timeout and body size limits per route group
+// ABOUTME: Rejects oversized bodies with 413 and slow handlers with 408 using the standard error envelope
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::header::CONTENT_LENGTH,
+    middleware::Next,
+    response::Response,
+};
+use std::time::Duration;
+use tracing::warn;
+
+use crate::error::AppError;
+
+/// Timeout and maximum body size for one group of routes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RouteLimits {
+    pub timeout: Duration,
+    pub max_body_bytes: usize,
+}
+
+/// Request limits for each route group
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestLimitsConfig {
+    pub crud: RouteLimits,   // Regular API requests
+    pub import: RouteLimits, // Database, task source, and token imports
+    pub ai: RouteLimits,     // AI proxy and ideation requests (timeout covers time to first byte)
+}
+
+impl Default for RequestLimitsConfig {
+    fn default() -> Self {
+        Self {
+            crud: RouteLimits {
+                timeout: Duration::from_secs(60),
+                max_body_bytes: 2 * 1024 * 1024,
+            },
+            import: RouteLimits {
+                timeout: Duration::from_secs(300),
+                max_body_bytes: 100 * 1024 * 1024,
+            },
+            ai: RouteLimits {
+                timeout: Duration::from_secs(120),
+                max_body_bytes: 10 * 1024 * 1024,
+            },
+        }
+    }
+}
+
+impl RequestLimitsConfig {
+    /// Limits that apply to a request path
+    pub fn for_path(&self, path: &str) -> RouteLimits {
+        match categorize_route(path) {
+            RouteGroup::Import => self.import,
+            RouteGroup::Ai => self.ai,
+            RouteGroup::Crud => self.crud,
+        }
+    }
+}
+
+/// Route groups with distinct request limits
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RouteGroup {
+    Crud,
+    Import,
+    Ai,
+}
+
+/// Categorize a request path into its route group
+fn categorize_route(path: &str) -> RouteGroup {
+    if path.starts_with("/api/ai/") || path.starts_with("/api/ideate/") {
+        RouteGroup::Ai
+    } else if path.split('/').any(|segment| segment == "import") {
+        RouteGroup::Import
+    } else {
+        RouteGroup::Crud
+    }
+}
+
+/// Enforce the body size and timeout for the request's route group.
+///
+/// The body is buffered up to the limit so oversized chunked uploads are
+/// rejected before reaching a handler. For streaming responses the timeout
+/// only bounds the time until the response starts; the stream itself is not cut off.
+pub async fn request_limits_middleware(
+    State(config): State<RequestLimitsConfig>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let path = request.uri().path().to_string();
+    let limits = config.for_path(&path);
+
+    let declared_length = request
+        .headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared_length.is_some_and(|length| length > limits.max_body_bytes) {
+        warn!(path = %path, limit = limits.max_body_bytes, "Request body too large");
+        return Err(AppError::PayloadTooLarge {
+            limit: limits.max_body_bytes,
+        });
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = axum::body::to_bytes(body, limits.max_body_bytes)
+        .await
+        .map_err(|_| {
+            warn!(path = %path, limit = limits.max_body_bytes, "Request body too large");
+            AppError::PayloadTooLarge {
+                limit: limits.max_body_bytes,
+            }
+        })?;
+    let request = Request::from_parts(parts, Body::from(bytes));
+
+    tokio::time::timeout(limits.timeout, next.run(request))
+        .await
+        .map_err(|_| {
+            warn!(path = %path, timeout_secs = limits.timeout.as_secs(), "Request timed out");
+            AppError::RequestTimeout {
+                timeout_secs: limits.timeout.as_secs(),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, routing::post, Router};
+    use tower::ServiceExt;
+
+    fn test_config() -> RequestLimitsConfig {
+        let limits = RouteLimits {
+            timeout: Duration::from_millis(50),
+            max_body_bytes: 16,
+        };
+        RequestLimitsConfig {
+            crud: limits,
+            import: RouteLimits {
+                max_body_bytes: 64,
+                ..limits
+            },
+            ai: limits,
+        }
+    }
+
+    fn test_app() -> Router {
+        Router::new()
+            .route("/api/projects", post(|body: String| async move { body }))
+            .route(
+                "/api/projects/import",
+                post(|body: String| async move { body }),
+            )
+            .route(
+                "/api/slow",
+                post(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "done"
+                }),
+            )
+            .layer(axum::middleware::from_fn_with_state(
+                test_config(),
+                request_limits_middleware,
+            ))
+    }
+
+    async fn post_body(path: &str, body: &'static str) -> Response {
+        test_app()
+            .oneshot(
+                Request::post(path)
+                    .header(CONTENT_LENGTH, body.len())
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_route_categorization() {
+        assert_eq!(
+            categorize_route("/api/ai/anthropic/v1/messages"),
+            RouteGroup::Ai
+        );
+        assert_eq!(categorize_route("/api/projects/import"), RouteGroup::Import);
+        assert_eq!(
+            categorize_route("/api/projects/p1/task-sources/s1/import"),
+            RouteGroup::Import
+        );
+        assert_eq!(
+            categorize_route("/api/ideate/s1/prd/generate"),
+            RouteGroup::Ai
+        );
+        assert_eq!(categorize_route("/api/ai-usage/stats"), RouteGroup::Crud);
+        assert_eq!(
+            categorize_route("/api/projects/important"),
+            RouteGroup::Crud
+        );
+    }
+
+    #[tokio::test]
+    async fn test_body_within_limit_reaches_handler() {
+        let response = post_body("/api/projects", "small").await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected_per_group() {
+        let body = "this body is longer than sixteen bytes";
+
+        let response = post_body("/api/projects", body).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"]["code"], "PAYLOAD_TOO_LARGE");
+
+        let response = post_body("/api/projects/import", body).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler_times_out() {
+        let response = post_body("/api/slow", "").await;
+        assert_eq!(response.status(), StatusCode::REQUEST_TIMEOUT);
+    }
+}
@@ -227,7 +227,10 @@ mod tests {
             .to_str()
             .unwrap();
         assert!(csp.contains("default-src 'self'"));
-        assert!(!csp.contains("unsafe-eval"), "Production CSP must not allow unsafe-eval");
+        assert!(
+            !csp.contains("unsafe-eval"),
+            "Production CSP must not allow unsafe-eval"
+        );
         assert!(
             !csp.contains("script-src 'self' 'unsafe-inline'"),
             "Production CSP must not allow unsafe-inline in script-src"
@@ -0,0 +1,94 @@
+// ABOUTME: Network helpers for exposing the dashboard beyond localhost
+// ABOUTME: Detects the machine's LAN address and derives the browser origins for a bind host
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
+
+/// Environment variable holding the address the API server binds to
+pub const HOST_ENV: &str = "ORKEE_HOST";
+
+/// Environment variable listing extra CORS origins allowed for remote access
+pub const REMOTE_ORIGINS_ENV: &str = "ORKEE_CORS_REMOTE_ORIGINS";
+
+/// Whether binding to this address exposes the server to other machines
+pub fn is_remote_bind(host: IpAddr) -> bool {
+    !host.is_loopback()
+}
+
+/// Detect the address other machines on the LAN can reach this one at.
+///
+/// Connecting a UDP socket sends no packets; it only asks the OS which local
+/// address it would route through, which is the primary LAN interface.
+pub fn detect_lan_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind(SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0))).ok()?;
+    socket
+        .connect(SocketAddr::from((Ipv4Addr::new(192, 0, 2, 1), 80)))
+        .ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_loopback() && !ip.is_unspecified()).then_some(ip)
+}
+
+/// The address browsers on other machines use to reach a server bound to `host`.
+///
+/// A wildcard bind is reachable through the detected LAN address; a specific
+/// address is used as-is.
+pub fn public_address(host: IpAddr, lan_ip: Option<IpAddr>) -> Option<IpAddr> {
+    if host.is_unspecified() {
+        lan_ip
+    } else {
+        Some(host)
+    }
+}
+
+/// Format an `https://` URL for an address and port, bracketing IPv6 hosts
+pub fn https_url(ip: IpAddr, port: u16) -> String {
+    format!("https://{}", SocketAddr::new(ip, port))
+}
+
+/// HTTPS origins for each port at the public address of `host`
+pub fn remote_origins(host: IpAddr, lan_ip: Option<IpAddr>, ports: &[u16]) -> Vec<String> {
+    let Some(ip) = public_address(host, lan_ip) else {
+        return Vec::new();
+    };
+    let mut origins: Vec<String> = ports.iter().map(|port| https_url(ip, *port)).collect();
+    origins.dedup();
+    origins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn test_is_remote_bind() {
+        assert!(!is_remote_bind(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+        assert!(!is_remote_bind(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert!(is_remote_bind(IpAddr::V4(Ipv4Addr::UNSPECIFIED)));
+        assert!(is_remote_bind("192.168.1.20".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_remote_origins() {
+        let lan: IpAddr = "192.168.1.20".parse().unwrap();
+        let wildcard = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+
+        assert_eq!(
+            remote_origins(wildcard, Some(lan), &[4001, 5173]),
+            vec!["https://192.168.1.20:4001", "https://192.168.1.20:5173"]
+        );
+        assert_eq!(
+            remote_origins(wildcard, Some(lan), &[4001, 4001]),
+            vec!["https://192.168.1.20:4001"]
+        );
+        assert!(remote_origins(wildcard, None, &[4001]).is_empty());
+
+        let specific: IpAddr = "10.0.0.5".parse().unwrap();
+        assert_eq!(
+            remote_origins(specific, Some(lan), &[4001]),
+            vec!["https://10.0.0.5:4001"]
+        );
+
+        let v6: IpAddr = "fd00::1".parse().unwrap();
+        assert_eq!(https_url(v6, 4001), "https://[fd00::1]:4001");
+    }
+}
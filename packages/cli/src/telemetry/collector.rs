@@ -1,17 +1,17 @@
 // ABOUTME: Telemetry collector for batching and sending events
 // ABOUTME: Handles event collection, buffering, and transmission to telemetry endpoint
 
-use super::config::TelemetryManager;
+use super::config::{TelemetryManager, TelemetrySink};
 use super::events::{
     cleanup_old_events, cleanup_old_unsent_events, get_unsent_events, increment_retry_count,
-    mark_events_as_sent, mark_failed_events_as_sent,
+    mark_events_as_sent, mark_failed_events_as_sent, CollectorBatch, EventType, TelemetryEvent,
 };
 use super::posthog::create_posthog_batch;
-use reqwest::Client;
-use serde::Deserialize;
+use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use std::sync::Arc;
-use tokio::time::{interval, Duration};
+use tokio::time::{interval, Duration, Instant};
 use tracing::{debug, error, info, warn};
 
 #[derive(Debug, Deserialize)]
@@ -182,29 +182,10 @@ impl TelemetryCollector {
             return Ok(());
         }
 
-        // Create PostHog batch
-        let batch = create_posthog_batch(filtered_events.clone());
-
-        // Send to PostHog endpoint
-        // PostHog uses /batch endpoint for batch events
-        let endpoint = self.endpoint.trim_end_matches('/');
-        let batch_endpoint = if endpoint.ends_with("/capture") {
-            endpoint.replace("/capture", "/batch")
-        } else {
-            format!("{}/batch", endpoint)
-        };
-
-        let timeout_secs = self.manager.get_http_timeout_secs();
-        let response = self
-            .client
-            .post(&batch_endpoint)
-            .json(&batch)
-            .header("Content-Type", "application/json")
-            .timeout(Duration::from_secs(timeout_secs))
-            .send()
-            .await;
-
         let event_ids: Vec<String> = filtered_events.iter().map(|e| e.id.clone()).collect();
+        let event_count = filtered_events.len();
+        let sink = self.manager.get_sink().as_str();
+        let response = self.batch_request(filtered_events).send().await;
 
         match response {
             Ok(resp) => {
@@ -212,24 +193,121 @@ impl TelemetryCollector {
                     // Mark events as sent on success
                     mark_events_as_sent(&self.pool, &event_ids).await?;
                     info!(
-                        "Successfully sent {} telemetry events to PostHog",
-                        filtered_events.len()
+                        "Successfully sent {} telemetry events to {}",
+                        event_count, sink
                     );
                 } else {
                     // Increment retry count on HTTP error
-                    error!("PostHog endpoint returned error: {}", resp.status());
+                    error!(
+                        "Telemetry {} endpoint returned error: {}",
+                        sink,
+                        resp.status()
+                    );
                     increment_retry_count(&self.pool, &event_ids).await?;
                 }
             }
             Err(e) => {
                 // Increment retry count on network error
-                warn!("Failed to send telemetry to PostHog: {}", e);
+                warn!("Failed to send telemetry to {}: {}", sink, e);
                 increment_retry_count(&self.pool, &event_ids).await?;
             }
         }
 
         Ok(())
     }
+
+    /// Build the POST that delivers `events` in the configured sink's format
+    fn batch_request(&self, events: Vec<TelemetryEvent>) -> RequestBuilder {
+        let endpoint = self.endpoint.trim_end_matches('/');
+        let request = match self.manager.get_sink() {
+            TelemetrySink::PostHog => {
+                // PostHog uses /batch endpoint for batch events
+                let batch_endpoint = if endpoint.ends_with("/capture") {
+                    endpoint.replace("/capture", "/batch")
+                } else {
+                    format!("{}/batch", endpoint)
+                };
+                self.client
+                    .post(&batch_endpoint)
+                    .json(&create_posthog_batch(events))
+            }
+            TelemetrySink::Http => {
+                // Self-hosted collectors receive the batch at the endpoint as given
+                let request = self
+                    .client
+                    .post(endpoint)
+                    .json(&CollectorBatch::new(events));
+                match self.manager.get_auth_token() {
+                    Some(token) => request.bearer_auth(token),
+                    None => request,
+                }
+            }
+        };
+
+        request
+            .header("Content-Type", "application/json")
+            .timeout(Duration::from_secs(self.manager.get_http_timeout_secs()))
+    }
+
+    /// Send a probe to the configured sink without touching buffered events
+    ///
+    /// Self-hosted collectors get an empty batch; PostHog, which rejects empty
+    /// batches, gets a single anonymous `telemetry_connection_test` event.
+    pub async fn test_connection(&self) -> ConnectionTestResult {
+        let sink = self.manager.get_sink();
+        let mut result = ConnectionTestResult {
+            sink,
+            endpoint: self.endpoint.clone(),
+            success: false,
+            status_code: None,
+            latency_ms: 0,
+            error: None,
+        };
+
+        if !self.manager.is_sink_configured() {
+            result.error = Some(match sink {
+                TelemetrySink::PostHog => "POSTHOG_API_KEY is not set".to_string(),
+                TelemetrySink::Http => "ORKEE_TELEMETRY_ENDPOINT is not set".to_string(),
+            });
+            return result;
+        }
+
+        let events = match sink {
+            TelemetrySink::PostHog => vec![TelemetryEvent::new(
+                EventType::Usage,
+                "telemetry_connection_test".to_string(),
+            )],
+            TelemetrySink::Http => Vec::new(),
+        };
+
+        let started = Instant::now();
+        let response = self.batch_request(events).send().await;
+        result.latency_ms = started.elapsed().as_millis() as u64;
+
+        match response {
+            Ok(resp) => {
+                result.status_code = Some(resp.status().as_u16());
+                result.success = resp.status().is_success();
+                if !result.success {
+                    result.error = Some(format!("Endpoint returned {}", resp.status()));
+                }
+            }
+            Err(e) => result.error = Some(e.to_string()),
+        }
+
+        result
+    }
+}
+
+/// Outcome of [`TelemetryCollector::test_connection`]
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionTestResult {
+    pub sink: TelemetrySink,
+    pub endpoint: String,
+    pub success: bool,
+    pub status_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
 }
 
 /// Public function to manually trigger sending of buffered events
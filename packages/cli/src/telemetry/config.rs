@@ -5,6 +5,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
@@ -38,10 +39,45 @@ impl Default for TelemetrySettings {
     }
 }
 
+/// Where buffered telemetry events are delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TelemetrySink {
+    /// PostHog's batch API (default); needs `POSTHOG_API_KEY`
+    PostHog,
+    /// An organization's own collector receiving [`super::events::CollectorBatch`]
+    /// bodies; needs `ORKEE_TELEMETRY_ENDPOINT`
+    Http,
+}
+
+impl TelemetrySink {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TelemetrySink::PostHog => "posthog",
+            TelemetrySink::Http => "http",
+        }
+    }
+}
+
+impl std::str::FromStr for TelemetrySink {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "posthog" => Ok(TelemetrySink::PostHog),
+            "http" => Ok(TelemetrySink::Http),
+            other => Err(format!("Invalid telemetry sink: {}", other)),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TelemetryConfig {
     pub enabled: bool,
+    pub sink: TelemetrySink,
     pub endpoint: String,
+    /// Sent as a bearer token to self-hosted collectors
+    pub auth_token: Option<String>,
     pub debug_mode: bool,
     pub batch_size: usize,
     pub flush_interval_secs: u64,
@@ -52,9 +88,24 @@ pub struct TelemetryConfig {
 
 impl TelemetryConfig {
     pub fn from_env() -> Self {
-        // Check if PostHog API key is available
-        // If no key, disable telemetry gracefully
-        let has_api_key = super::posthog::get_posthog_api_key().is_some();
+        let sink = match env::var("ORKEE_TELEMETRY_SINK") {
+            Ok(value) => value.parse().unwrap_or_else(|e| {
+                tracing::warn!("{}, falling back to PostHog", e);
+                TelemetrySink::PostHog
+            }),
+            Err(_) => TelemetrySink::PostHog,
+        };
+
+        // PostHog endpoint - can be overridden for self-hosted instances
+        // Defaults to PostHog Cloud for privacy-focused analytics.
+        // A self-hosted collector has no default and stays off until set
+        let endpoint = env::var("ORKEE_TELEMETRY_ENDPOINT")
+            .ok()
+            .filter(|e| !e.trim().is_empty())
+            .unwrap_or_else(|| match sink {
+                TelemetrySink::PostHog => "https://app.posthog.com/capture".to_string(),
+                TelemetrySink::Http => String::new(),
+            });
 
         // Check if telemetry is globally disabled via environment variable
         let env_enabled = env::var("ORKEE_TELEMETRY_ENABLED")
@@ -62,13 +113,13 @@ impl TelemetryConfig {
             .parse::<bool>()
             .unwrap_or(true);
 
-        // Telemetry is only enabled if BOTH the env var is true AND we have an API key
-        let enabled = env_enabled && has_api_key;
+        // Telemetry is only enabled if BOTH the env var is true AND the sink is usable:
+        // PostHog needs an API key, a self-hosted collector needs an endpoint
+        let enabled = env_enabled && Self::sink_configured(sink, &endpoint);
 
-        // PostHog endpoint - can be overridden for self-hosted instances
-        // Defaults to PostHog Cloud for privacy-focused analytics
-        let endpoint = env::var("ORKEE_TELEMETRY_ENDPOINT")
-            .unwrap_or_else(|_| "https://app.posthog.com/capture".to_string());
+        let auth_token = env::var("ORKEE_TELEMETRY_AUTH_TOKEN")
+            .ok()
+            .filter(|t| !t.trim().is_empty());
 
         let debug_mode = env::var("ORKEE_TELEMETRY_DEBUG")
             .unwrap_or_else(|_| "false".to_string())
@@ -77,7 +128,9 @@ impl TelemetryConfig {
 
         Self {
             enabled,
+            sink,
             endpoint,
+            auth_token,
             debug_mode,
             batch_size: 50,
             flush_interval_secs: 300, // 5 minutes
@@ -86,12 +139,26 @@ impl TelemetryConfig {
             http_timeout_secs: 10,    // HTTP request timeout - PostHog should respond quickly
         }
     }
+
+    fn sink_configured(sink: TelemetrySink, endpoint: &str) -> bool {
+        match sink {
+            TelemetrySink::PostHog => super::posthog::get_posthog_api_key().is_some(),
+            TelemetrySink::Http => !endpoint.is_empty(),
+        }
+    }
+
+    /// Whether the configured sink has what it needs to deliver events
+    pub fn is_sink_configured(&self) -> bool {
+        Self::sink_configured(self.sink, &self.endpoint)
+    }
 }
 
 #[derive(Clone)]
 pub struct TelemetryManager {
     settings: Arc<RwLock<TelemetrySettings>>,
     config: TelemetryConfig,
+    // Global kill switch, reloadable at runtime via the configuration service
+    enabled: Arc<AtomicBool>,
     pool: SqlitePool,
 }
 
@@ -102,6 +169,7 @@ impl TelemetryManager {
 
         Ok(Self {
             settings: Arc::new(RwLock::new(settings)),
+            enabled: Arc::new(AtomicBool::new(config.enabled)),
             config,
             pool,
         })
@@ -262,15 +330,37 @@ impl TelemetryManager {
     }
 
     pub fn is_telemetry_enabled(&self) -> bool {
-        self.config.enabled
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Toggle telemetry globally. Telemetry stays off while the sink is unconfigured.
+    pub fn set_enabled(&self, enabled: bool) {
+        let enabled = enabled && self.config.is_sink_configured();
+        self.enabled.store(enabled, Ordering::Relaxed);
     }
 
     pub fn get_endpoint(&self) -> String {
         self.config.endpoint.clone()
     }
 
+    pub fn get_sink(&self) -> TelemetrySink {
+        self.config.sink
+    }
+
+    pub fn get_auth_token(&self) -> Option<String> {
+        self.config.auth_token.clone()
+    }
+
+    pub fn is_sink_configured(&self) -> bool {
+        self.config.is_sink_configured()
+    }
+
+    pub fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
     pub async fn is_any_telemetry_enabled(&self) -> bool {
-        if !self.config.enabled {
+        if !self.is_telemetry_enabled() {
             return false;
         }
 
@@ -334,6 +424,13 @@ impl TelemetryManager {
         super::events::track_event(&self.pool, event_name, properties, session_id).await
     }
 
+    pub async fn get_error_groups(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<super::events::ErrorGroup>, Box<dyn std::error::Error + Send + Sync>> {
+        super::events::get_error_groups(&self.pool, limit).await
+    }
+
     #[cfg(test)]
     pub async fn new_with_test_db() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
         use sqlx::sqlite::SqlitePoolOptions;
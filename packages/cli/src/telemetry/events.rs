@@ -4,8 +4,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use sqlx::SqlitePool;
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
 use tracing::warn;
 use uuid::Uuid;
 
@@ -105,6 +106,77 @@ impl TelemetryEvent {
     }
 }
 
+/// Version of the [`CollectorBatch`] wire format, bumped on breaking changes
+pub const COLLECTOR_SCHEMA_VERSION: u32 = 1;
+
+/// Body POSTed to a self-hosted collector (`ORKEE_TELEMETRY_SINK=http`)
+///
+/// Sent as `application/json`. Any 2xx response marks the events as delivered;
+/// anything else is retried up to three times on later flushes, so collectors
+/// should deduplicate on [`CollectorEvent::id`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectorBatch {
+    /// Always [`COLLECTOR_SCHEMA_VERSION`] for batches built by this release
+    pub schema_version: u32,
+    /// When this batch left the client
+    pub sent_at: DateTime<Utc>,
+    /// Orkee version that produced the batch
+    pub app_version: String,
+    /// Operating system (`linux`, `macos`, `windows`)
+    pub platform: String,
+    /// May be empty, e.g. for connection tests
+    pub events: Vec<CollectorEvent>,
+}
+
+/// One event inside a [`CollectorBatch`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectorEvent {
+    /// Unique per event and stable across retries
+    pub id: String,
+    /// `usage`, `error` or `performance`
+    pub event_type: EventType,
+    /// Name as recorded, without any sink-specific prefix
+    pub event_name: String,
+    /// When the event was recorded, not when it was sent
+    pub timestamp: DateTime<Utc>,
+    pub session_id: Option<String>,
+    /// Random per-install ID; present once the user opts in to any telemetry
+    pub machine_id: Option<String>,
+    /// Only set when the user allows non-anonymous metrics
+    pub user_id: Option<String>,
+    pub anonymous: bool,
+    /// Free-form event data; error events carry `message` and `stack_trace`
+    pub properties: Option<Value>,
+}
+
+impl From<TelemetryEvent> for CollectorEvent {
+    fn from(event: TelemetryEvent) -> Self {
+        Self {
+            id: event.id,
+            event_type: event.event_type,
+            event_name: event.event_name,
+            timestamp: event.timestamp,
+            session_id: event.session_id,
+            machine_id: event.machine_id,
+            user_id: event.user_id,
+            anonymous: event.anonymous,
+            properties: event.event_data,
+        }
+    }
+}
+
+impl CollectorBatch {
+    pub fn new(events: Vec<TelemetryEvent>) -> Self {
+        Self {
+            schema_version: COLLECTOR_SCHEMA_VERSION,
+            sent_at: Utc::now(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: std::env::consts::OS.to_string(),
+            events: events.into_iter().map(CollectorEvent::from).collect(),
+        }
+    }
+}
+
 /// Track a usage event (e.g., feature used, button clicked)
 pub async fn track_event(
     pool: &SqlitePool,
@@ -141,6 +213,14 @@ pub async fn track_error(
         "message".to_string(),
         Value::String(error_message.to_string()),
     );
+    error_data.insert(
+        "fingerprint".to_string(),
+        Value::String(fingerprint_error(
+            error_name,
+            error_message,
+            stack_trace.as_deref(),
+        )),
+    );
     if let Some(trace) = stack_trace {
         error_data.insert("stack_trace".to_string(), Value::String(trace));
     }
@@ -155,6 +235,239 @@ pub async fn track_error(
     Ok(())
 }
 
+/// Maximum number of stack frames that contribute to an error fingerprint
+const MAX_FINGERPRINT_FRAMES: usize = 32;
+
+/// Compute a stable fingerprint for an error so repeated crashes group together
+///
+/// When a stack trace is present the fingerprint is derived from the error name
+/// and the normalized shape of the stack (function names only, with paths, line
+/// numbers and addresses stripped). Without a stack trace the normalized message
+/// is used instead.
+pub fn fingerprint_error(
+    error_name: &str,
+    error_message: &str,
+    stack_trace: Option<&str>,
+) -> String {
+    let frames = stack_trace.map(stack_shape).unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(error_name.as_bytes());
+    hasher.update(b"\n");
+    if frames.is_empty() {
+        hasher.update(normalize_error_message(error_message).as_bytes());
+    } else {
+        for frame in &frames {
+            hasher.update(frame.as_bytes());
+            hasher.update(b"\n");
+        }
+    }
+
+    // 64 bits is plenty to keep distinct local error groups apart
+    let digest = hasher.finalize();
+    digest[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Normalize an error message by replacing volatile tokens (paths, numbers,
+/// UUIDs and addresses) with placeholders
+pub fn normalize_error_message(message: &str) -> String {
+    message
+        .split_whitespace()
+        .map(normalize_token)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reduce a stack trace to its shape: one normalized entry per frame, with
+/// location-only lines dropped so the same crash from different checkouts or
+/// builds produces the same frames
+fn stack_shape(stack_trace: &str) -> Vec<String> {
+    stack_trace
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            // Rust backtraces prefix frames with their index ("12: crate::func")
+            let line = match line.split_once(": ") {
+                Some((index, rest)) if index.chars().all(|c| c.is_ascii_digit()) => rest,
+                _ => line,
+            };
+            let frame = normalize_error_message(&strip_symbol_hash(line));
+            let is_location_only = frame
+                .split_whitespace()
+                .all(|token| token == "at" || token == "<path>");
+            (!is_location_only).then_some(frame)
+        })
+        .take(MAX_FINGERPRINT_FRAMES)
+        .collect()
+}
+
+/// Strip the trailing `::h<hash>` that rustc appends to mangled symbol names
+fn strip_symbol_hash(frame: &str) -> String {
+    frame
+        .split_whitespace()
+        .map(|token| match token.rsplit_once("::h") {
+            Some((symbol, hash))
+                if hash.len() == 16 && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+            {
+                symbol
+            }
+            _ => token,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn normalize_token(token: &str) -> String {
+    const WRAPPERS: &[char] = &[
+        '\'', '"', '`', '(', ')', '[', ']', '{', '}', '<', '>', ',', ';',
+    ];
+
+    // Sentence punctuation is only stripped from the end so `file:///` URLs and
+    // `::` paths keep their shape
+    let core = token
+        .trim_start_matches(WRAPPERS)
+        .trim_end_matches(|c| WRAPPERS.contains(&c) || c == '.' || c == ':');
+    if core.is_empty() {
+        return token.to_string();
+    }
+    let start = token.len() - token.trim_start_matches(WRAPPERS).len();
+    let (prefix, rest) = token.split_at(start);
+    let suffix = &rest[core.len()..];
+
+    let normalized = if core.contains('/') || core.contains('\\') {
+        "<path>".to_string()
+    } else if core.len() > 2
+        && core.starts_with("0x")
+        && core[2..].chars().all(|c| c.is_ascii_hexdigit())
+    {
+        "<addr>".to_string()
+    } else if Uuid::parse_str(core).is_ok() {
+        "<uuid>".to_string()
+    } else {
+        replace_digit_runs(core)
+    };
+
+    format!("{}{}{}", prefix, normalized, suffix)
+}
+
+fn replace_digit_runs(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut in_digits = false;
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                result.push_str("<n>");
+                in_digits = true;
+            }
+        } else {
+            result.push(c);
+            in_digits = false;
+        }
+    }
+    result
+}
+
+/// A group of error events sharing the same fingerprint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorGroup {
+    pub fingerprint: String,
+    pub error_name: String,
+    /// Normalized message of the most recent occurrence
+    pub message: String,
+    pub occurrences: u64,
+    /// Number of distinct sessions the error was seen in
+    pub sessions: u64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+/// Group recorded error events by fingerprint, most frequent first
+///
+/// Events recorded before fingerprinting was introduced are fingerprinted on
+/// the fly from their stored message and stack trace.
+pub async fn get_error_groups(
+    pool: &SqlitePool,
+    limit: usize,
+) -> Result<Vec<ErrorGroup>, Box<dyn std::error::Error + Send + Sync>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT event_name, event_data, session_id, created_at
+        FROM telemetry_events
+        WHERE event_type = 'error'
+        ORDER BY created_at ASC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut groups: HashMap<String, (ErrorGroup, HashSet<String>)> = HashMap::new();
+
+    for row in rows {
+        let error_name: String = row.try_get("event_name")?;
+        let event_data: Option<String> = row.try_get("event_data")?;
+        let session_id: Option<String> = row.try_get("session_id")?;
+        let created_at: String = row.try_get("created_at")?;
+
+        let data: Option<Value> = event_data
+            .as_deref()
+            .and_then(|json_str| serde_json::from_str(json_str).ok());
+        let field = |key: &str| {
+            data.as_ref()
+                .and_then(|d| d.get(key))
+                .and_then(|v| v.as_str())
+        };
+        let message = field("message").unwrap_or_default();
+        let fingerprint = match field("fingerprint") {
+            Some(fingerprint) => fingerprint.to_string(),
+            None => fingerprint_error(&error_name, message, field("stack_trace")),
+        };
+        let seen_at = DateTime::parse_from_rfc3339(&created_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|_| Utc::now());
+
+        let (group, sessions) = groups.entry(fingerprint.clone()).or_insert_with(|| {
+            (
+                ErrorGroup {
+                    fingerprint,
+                    error_name: error_name.clone(),
+                    message: String::new(),
+                    occurrences: 0,
+                    sessions: 0,
+                    first_seen: seen_at,
+                    last_seen: seen_at,
+                },
+                HashSet::new(),
+            )
+        });
+
+        group.occurrences += 1;
+        group.first_seen = group.first_seen.min(seen_at);
+        if seen_at >= group.last_seen {
+            group.last_seen = seen_at;
+            group.message = normalize_error_message(message);
+        }
+        if let Some(sid) = session_id {
+            sessions.insert(sid);
+        }
+    }
+
+    let mut groups: Vec<ErrorGroup> = groups
+        .into_values()
+        .map(|(mut group, sessions)| {
+            group.sessions = sessions.len() as u64;
+            group
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then_with(|| b.last_seen.cmp(&a.last_seen))
+    });
+    groups.truncate(limit);
+
+    Ok(groups)
+}
+
 /// Get unsent events from the database, excluding those that have exceeded max retry attempts
 pub async fn get_unsent_events(
     pool: &SqlitePool,
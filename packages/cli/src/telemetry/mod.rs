@@ -9,9 +9,12 @@ pub mod config;
 pub mod events;
 pub mod posthog;
 
-pub use collector::{send_buffered_events, TelemetryCollector};
-pub use config::{TelemetryConfig, TelemetryManager, TelemetrySettings};
-pub use events::{track_error, track_event, EventType, TelemetryEvent};
+pub use collector::{send_buffered_events, ConnectionTestResult, TelemetryCollector};
+pub use config::{TelemetryConfig, TelemetryManager, TelemetrySettings, TelemetrySink};
+pub use events::{
+    fingerprint_error, track_error, track_event, CollectorBatch, CollectorEvent, ErrorGroup,
+    EventType, TelemetryEvent, COLLECTOR_SCHEMA_VERSION,
+};
 
 /// Initialize the telemetry manager with the shared database connection
 pub async fn init_telemetry_manager(
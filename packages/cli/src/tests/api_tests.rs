@@ -35,6 +35,61 @@ async fn test_status_endpoint() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn test_config_reload_endpoint() {
+    let app = api::create_router().await;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/api/config/reload")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["success"], true);
+    assert!(json["data"]["version"].is_u64());
+    assert!(json["data"]["changed_keys"].is_array());
+}
+
+#[tokio::test]
+async fn test_cloud_queue_endpoint() {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+
+    let app = axum::Router::new()
+        .route("/queue", axum::routing::get(api::cloud::list_queue))
+        .layer(axum::Extension(api::cloud::CloudState::new(pool)));
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri("/queue?status=conflict")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["success"], true);
+    assert!(json["data"]["summary"]["pending"].is_i64());
+    assert!(json["data"]["operations"].is_array());
+}
+
 #[tokio::test]
 async fn test_projects_list_endpoint() {
     // Reset the global singleton for testing
@@ -131,3 +131,113 @@ fn test_config_error_display() {
     let error = ConfigError::InvalidPort(parse_error);
     assert!(error.to_string().contains("Invalid port number"));
 }
+
+#[test]
+#[serial]
+fn test_config_acme_from_env() {
+    env::set_var("TLS_ACME_ENABLED", "true");
+    env::set_var(
+        "TLS_ACME_DOMAINS",
+        "orkee.example.com, www.orkee.example.com",
+    );
+    env::set_var("TLS_ACME_EMAIL", "ops@example.com");
+    env::set_var("TLS_ACME_CHALLENGE", "dns-01");
+    env::set_var("TLS_ACME_DNS_HOOK", "./update-dns.sh");
+
+    let config = Config::from_env().unwrap();
+    let acme = &config.tls.acme;
+
+    assert!(acme.enabled);
+    assert_eq!(
+        acme.domains,
+        vec!["orkee.example.com", "www.orkee.example.com"]
+    );
+    assert_eq!(acme.contact_email.as_deref(), Some("ops@example.com"));
+    assert_eq!(acme.challenge, crate::tls::acme::AcmeChallenge::Dns01);
+    assert_eq!(acme.dns_hook.as_deref(), Some("./update-dns.sh"));
+    assert!(!acme.staging);
+
+    env::set_var("TLS_ACME_CHALLENGE", "carrier-pigeon");
+    assert!(matches!(
+        Config::from_env().unwrap_err(),
+        ConfigError::InvalidTls(_)
+    ));
+
+    for var in [
+        "TLS_ACME_ENABLED",
+        "TLS_ACME_DOMAINS",
+        "TLS_ACME_EMAIL",
+        "TLS_ACME_CHALLENGE",
+        "TLS_ACME_DNS_HOOK",
+    ] {
+        env::remove_var(var);
+    }
+}
+
+#[test]
+#[serial]
+fn test_config_client_auth_from_env() {
+    env::set_var("TLS_CLIENT_AUTH_ENABLED", "true");
+    env::set_var("TLS_CLIENT_CA_PATH", "/etc/orkee/client-ca.pem");
+    env::set_var("TLS_CLIENT_IDENTITIES", "laptop=tok-1,ci-runner=tok-2");
+
+    let config = Config::from_env().unwrap();
+    let client_auth = &config.tls.client_auth;
+
+    assert!(client_auth.enabled);
+    assert_eq!(
+        client_auth.ca_bundle_path,
+        std::path::PathBuf::from("/etc/orkee/client-ca.pem")
+    );
+    assert_eq!(client_auth.identities.len(), 2);
+    assert_eq!(client_auth.identities["ci-runner"], "tok-2");
+
+    env::set_var("TLS_CLIENT_IDENTITIES", "laptop");
+    assert!(matches!(
+        Config::from_env().unwrap_err(),
+        ConfigError::InvalidTls(_)
+    ));
+
+    for var in [
+        "TLS_CLIENT_AUTH_ENABLED",
+        "TLS_CLIENT_CA_PATH",
+        "TLS_CLIENT_IDENTITIES",
+    ] {
+        env::remove_var(var);
+    }
+}
+
+#[test]
+#[serial]
+fn test_config_remote_host_requires_tls() {
+    env::remove_var("TLS_ENABLED");
+    assert!(!Config::from_env().unwrap().is_remote());
+
+    env::set_var("ORKEE_HOST", "0.0.0.0");
+    assert!(matches!(
+        Config::from_env().unwrap_err(),
+        ConfigError::InsecureRemoteBind(_, _)
+    ));
+
+    env::set_var("TLS_ENABLED", "true");
+    env::set_var(
+        "ORKEE_CORS_REMOTE_ORIGINS",
+        "https://192.168.1.20:4001, https://orkee.lan:4001",
+    );
+    let config = Config::from_env().unwrap();
+    assert!(config.is_remote());
+    assert_eq!(
+        config.cors_remote_origins,
+        vec!["https://192.168.1.20:4001", "https://orkee.lan:4001"]
+    );
+
+    env::set_var("ORKEE_HOST", "not-an-ip");
+    assert!(matches!(
+        Config::from_env().unwrap_err(),
+        ConfigError::InvalidHost(_)
+    ));
+
+    for var in ["ORKEE_HOST", "ORKEE_CORS_REMOTE_ORIGINS", "TLS_ENABLED"] {
+        env::remove_var(var);
+    }
+}
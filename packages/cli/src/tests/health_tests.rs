@@ -1,4 +1,6 @@
-use crate::api::health::{health_check, status_check};
+use crate::api::health::{
+    health_check, readiness_report, status_check, CheckStatus, ReadinessStatus,
+};
 
 #[tokio::test]
 async fn test_health_check_returns_ok() {
@@ -126,3 +128,42 @@ async fn test_concurrent_status_checks() {
         assert!(status_result.is_ok());
     }
 }
+
+async fn create_test_pool(migrate: bool) -> sqlx::SqlitePool {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    if migrate {
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+    }
+    pool
+}
+
+#[tokio::test]
+async fn test_readiness_with_migrated_database() {
+    let pool = create_test_pool(true).await;
+    let report = readiness_report(&pool).await;
+
+    // Docker and cloud auth depend on the host, but are never critical
+    assert_ne!(report.status, ReadinessStatus::NotReady);
+    assert_eq!(report.checks["database"].status, CheckStatus::Ok);
+    assert_eq!(report.checks["migrations"].status, CheckStatus::Ok);
+    assert_eq!(report.checks["disk_space"].status, CheckStatus::Skipped);
+    assert!(!report.checks["docker"].critical);
+    assert!(!report.checks["cloud_auth"].critical);
+}
+
+#[tokio::test]
+async fn test_readiness_reports_missing_migrations() {
+    let pool = create_test_pool(false).await;
+    let report = readiness_report(&pool).await;
+
+    assert_eq!(report.status, ReadinessStatus::NotReady);
+    assert_eq!(report.checks["database"].status, CheckStatus::Ok);
+    assert_eq!(report.checks["migrations"].status, CheckStatus::Error);
+    assert!(report.checks["migrations"].critical);
+}
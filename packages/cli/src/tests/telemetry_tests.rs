@@ -5,10 +5,11 @@ use std::env;
 use tempfile::TempDir;
 
 use crate::telemetry::{
-    config::{TelemetryConfig, TelemetryManager},
+    config::{TelemetryConfig, TelemetryManager, TelemetrySink},
     events::{
-        cleanup_old_events, cleanup_old_unsent_events, get_unsent_events, mark_events_as_sent,
-        track_error, track_event, EventType, TelemetryEvent,
+        cleanup_old_events, cleanup_old_unsent_events, fingerprint_error, get_error_groups,
+        get_unsent_events, mark_events_as_sent, normalize_error_message, track_error, track_event,
+        CollectorBatch, EventType, TelemetryEvent, COLLECTOR_SCHEMA_VERSION,
     },
 };
 
@@ -597,6 +598,125 @@ fn test_telemetry_config_debug_mode() {
     env::remove_var("ORKEE_TELEMETRY_DEBUG");
 }
 
+#[test]
+#[serial]
+fn test_telemetry_config_http_sink_requires_endpoint() {
+    env::remove_var("POSTHOG_API_KEY");
+    env::remove_var("ORKEE_TELEMETRY_ENABLED");
+    env::remove_var("ORKEE_TELEMETRY_ENDPOINT");
+    env::set_var("ORKEE_TELEMETRY_SINK", "http");
+
+    // No collector configured: nothing to send to
+    let config = TelemetryConfig::from_env();
+    assert_eq!(config.sink, TelemetrySink::Http);
+    assert!(!config.enabled);
+    assert_eq!(config.endpoint, "");
+
+    // A collector endpoint is enough, no PostHog key needed
+    env::set_var(
+        "ORKEE_TELEMETRY_ENDPOINT",
+        "https://telemetry.example.com/v1/events",
+    );
+    let config = TelemetryConfig::from_env();
+    assert!(config.enabled);
+    assert_eq!(config.endpoint, "https://telemetry.example.com/v1/events");
+
+    env::remove_var("ORKEE_TELEMETRY_SINK");
+    env::remove_var("ORKEE_TELEMETRY_ENDPOINT");
+}
+
+#[test]
+#[serial]
+fn test_telemetry_config_invalid_sink_falls_back_to_posthog() {
+    env::set_var("ORKEE_TELEMETRY_SINK", "statsd");
+
+    let config = TelemetryConfig::from_env();
+    assert_eq!(config.sink, TelemetrySink::PostHog);
+
+    env::remove_var("ORKEE_TELEMETRY_SINK");
+}
+
+#[tokio::test]
+#[serial]
+async fn test_http_sink_delivers_collector_batches() {
+    use crate::telemetry::collector::{send_buffered_events, TelemetryCollector};
+    use std::sync::Arc;
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/ingest"))
+        .and(header("authorization", "Bearer collector-secret"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&server)
+        .await;
+
+    env::set_var("ORKEE_TELEMETRY_SINK", "http");
+    env::set_var(
+        "ORKEE_TELEMETRY_ENDPOINT",
+        format!("{}/ingest", server.uri()),
+    );
+    env::set_var("ORKEE_TELEMETRY_AUTH_TOKEN", "collector-secret");
+
+    let (pool, _temp_dir) = setup_test_db().await;
+    let manager = Arc::new(TelemetryManager::new(pool.clone()).await.unwrap());
+    manager
+        .complete_onboarding(true, true, false)
+        .await
+        .unwrap();
+
+    track_event(&pool, "self_hosted_test", None, None)
+        .await
+        .unwrap();
+    send_buffered_events(manager.clone(), pool.clone())
+        .await
+        .unwrap();
+    assert!(get_unsent_events(&pool, 10).await.unwrap().is_empty());
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    let batch: CollectorBatch = serde_json::from_slice(&requests[0].body).unwrap();
+    assert_eq!(batch.schema_version, COLLECTOR_SCHEMA_VERSION);
+    assert_eq!(batch.events.len(), 1);
+    assert_eq!(batch.events[0].event_name, "self_hosted_test");
+    assert!(batch.events[0].machine_id.is_some());
+
+    // The connection test sends an empty batch and leaves the buffer alone
+    let collector = TelemetryCollector::new(manager.clone(), pool.clone(), manager.get_endpoint());
+    let result = collector.test_connection().await;
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(result.status_code, Some(202));
+    let requests = server.received_requests().await.unwrap();
+    let probe: CollectorBatch = serde_json::from_slice(&requests[1].body).unwrap();
+    assert!(probe.events.is_empty());
+
+    env::remove_var("ORKEE_TELEMETRY_SINK");
+    env::remove_var("ORKEE_TELEMETRY_ENDPOINT");
+    env::remove_var("ORKEE_TELEMETRY_AUTH_TOKEN");
+}
+
+#[tokio::test]
+#[serial]
+async fn test_connection_test_reports_unconfigured_sink() {
+    use crate::telemetry::collector::TelemetryCollector;
+    use std::sync::Arc;
+
+    env::set_var("ORKEE_TELEMETRY_SINK", "http");
+    env::remove_var("ORKEE_TELEMETRY_ENDPOINT");
+
+    let (pool, _temp_dir) = setup_test_db().await;
+    let manager = Arc::new(TelemetryManager::new(pool.clone()).await.unwrap());
+    let collector = TelemetryCollector::new(manager.clone(), pool, manager.get_endpoint());
+    let result = collector.test_connection().await;
+
+    assert!(!result.success);
+    assert_eq!(result.status_code, None);
+    assert!(result.error.unwrap().contains("ORKEE_TELEMETRY_ENDPOINT"));
+
+    env::remove_var("ORKEE_TELEMETRY_SINK");
+}
+
 // ============================================================================
 // Event Structure Tests
 // ============================================================================
@@ -647,6 +767,118 @@ async fn test_error_event_with_stack_trace() {
     );
 }
 
+// ============================================================================
+// Error Fingerprinting Tests
+// ============================================================================
+
+#[test]
+fn test_normalize_error_message_replaces_volatile_tokens() {
+    let normalized = normalize_error_message(
+        "Failed to open '/home/alice/project/config.json': error 42 at 0x7ffe3a2c for 123e4567-e89b-12d3-a456-426614174000.",
+    );
+    assert_eq!(
+        normalized,
+        "Failed to open '<path>': error <n> at <addr> for <uuid>."
+    );
+}
+
+#[test]
+fn test_fingerprint_ignores_paths_and_line_numbers() {
+    let trace_a = "   0: orkee_cli::api::handler::h0123456789abcdef\n             at /home/alice/orkee/src/api/mod.rs:10:5\n   1: tokio::runtime::task::poll";
+    let trace_b = "   0: orkee_cli::api::handler::hfedcba9876543210\n             at /Users/bob/code/orkee/src/api/mod.rs:14:9\n   1: tokio::runtime::task::poll";
+
+    let a = fingerprint_error("panic", "index out of bounds: 3", Some(trace_a));
+    let b = fingerprint_error("panic", "index out of bounds: 7", Some(trace_b));
+    assert_eq!(a, b);
+    assert_eq!(a.len(), 16);
+
+    // A different stack shape is a different issue
+    let c = fingerprint_error(
+        "panic",
+        "index out of bounds: 3",
+        Some("   0: orkee_cli::api::other_handler"),
+    );
+    assert_ne!(a, c);
+}
+
+#[test]
+fn test_fingerprint_without_stack_uses_normalized_message() {
+    let a = fingerprint_error("io_error", "cannot read /tmp/a.txt (os error 2)", None);
+    let b = fingerprint_error("io_error", "cannot read /var/b.txt (os error 2)", None);
+    let c = fingerprint_error("io_error", "permission denied", None);
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_ne!(
+        a,
+        fingerprint_error("other_error", "cannot read /tmp/a.txt (os error 2)", None)
+    );
+}
+
+#[tokio::test]
+#[serial]
+async fn test_error_groups_collapse_repeated_crashes() {
+    let (pool, _temp_dir) = setup_test_db().await;
+
+    for (i, path) in ["/tmp/one.db", "/tmp/two.db", "/tmp/three.db"]
+        .iter()
+        .enumerate()
+    {
+        track_error(
+            &pool,
+            "db_open_failed",
+            &format!("unable to open {}", path),
+            None,
+            Some(format!("session_{}", i % 2)),
+        )
+        .await
+        .unwrap();
+    }
+    track_error(&pool, "network_error", "connection refused", None, None)
+        .await
+        .unwrap();
+    track_event(&pool, "button_click", None, None)
+        .await
+        .unwrap();
+
+    let groups = get_error_groups(&pool, 10).await.unwrap();
+    assert_eq!(groups.len(), 2);
+
+    assert_eq!(groups[0].error_name, "db_open_failed");
+    assert_eq!(groups[0].occurrences, 3);
+    assert_eq!(groups[0].sessions, 2);
+    assert_eq!(groups[0].message, "unable to open <path>");
+    assert!(groups[0].first_seen <= groups[0].last_seen);
+
+    assert_eq!(groups[1].error_name, "network_error");
+    assert_eq!(groups[1].occurrences, 1);
+    assert_eq!(groups[1].sessions, 0);
+
+    let limited = get_error_groups(&pool, 1).await.unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_error_groups_fingerprint_legacy_events() {
+    let (pool, _temp_dir) = setup_test_db().await;
+
+    // Error events recorded before fingerprinting have no fingerprint property
+    let legacy = TelemetryEvent::new(EventType::Error, "legacy_error".to_string())
+        .with_data(serde_json::json!({ "message": "failed after 3 attempts" }));
+    legacy.save_to_db(&pool).await.unwrap();
+    track_error(&pool, "legacy_error", "failed after 5 attempts", None, None)
+        .await
+        .unwrap();
+
+    let groups = get_error_groups(&pool, 10).await.unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].occurrences, 2);
+    assert_eq!(
+        groups[0].fingerprint,
+        fingerprint_error("legacy_error", "failed after 3 attempts", None)
+    );
+}
+
 #[tokio::test]
 #[serial]
 async fn test_event_timestamps() {
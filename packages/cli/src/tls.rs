@@ -1,23 +1,47 @@
 use std::fs;
 use std::io::BufReader;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
 use axum_server::tls_rustls::RustlsConfig;
+use chrono::{Datelike, Utc};
+use orkee_notifications::{
+    NotificationDispatcher, NotificationEventType, NotificationInput, NotificationSeverity,
+};
 use rcgen::{Certificate as RcgenCertificate, CertificateParams, DistinguishedName};
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 use rustls::ServerConfig;
 use rustls_pemfile::{certs, pkcs8_private_keys};
+use serde::Serialize;
 use thiserror::Error;
+use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
 
 use crate::error::AppError;
 
+pub mod acme;
+pub mod expiry;
+pub mod mtls;
+
+use acme::{AcmeConfig, AcmeManager, Http01Challenges};
+use expiry::{inspect_certificate, CertificateInfo, ExpiryAlerts};
+use mtls::ClientAuthConfig;
+
+/// Self-signed certificates are regenerated once they are this close to expiry
+const SELF_SIGNED_RENEWAL_DAYS: i64 = 30;
+
+/// Validity period of generated self-signed certificates
+const SELF_SIGNED_VALIDITY_DAYS: i64 = 365;
+
+/// How often the running server checks its certificate's expiry
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
 /// TLS configuration and certificate management
 #[derive(Clone)]
 pub struct TlsManager {
     config: TlsConfig,
+    challenges: Http01Challenges,
 }
 
 /// TLS configuration settings
@@ -27,6 +51,10 @@ pub struct TlsConfig {
     pub cert_path: PathBuf,
     pub key_path: PathBuf,
     pub auto_generate: bool,
+    /// Obtain certificates from Let's Encrypt instead of using cert_path/key_path
+    pub acme: AcmeConfig,
+    /// Require client certificates (mutual TLS) on the HTTPS listener
+    pub client_auth: ClientAuthConfig,
 }
 
 /// TLS-related errors
@@ -55,6 +83,12 @@ pub enum TlsError {
 
     #[error("TLS configuration error: {0}")]
     ConfigError(String),
+
+    #[error("ACME error: {0}")]
+    Acme(String),
+
+    #[error("Client certificate authentication error: {0}")]
+    ClientAuth(String),
 }
 
 impl From<TlsError> for AppError {
@@ -66,7 +100,15 @@ impl From<TlsError> for AppError {
 impl TlsManager {
     /// Create a new TLS manager with the given configuration
     pub fn new(config: TlsConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            challenges: Http01Challenges::default(),
+        }
+    }
+
+    /// Pending ACME HTTP-01 challenges, served by the plain HTTP server
+    pub fn http01_challenges(&self) -> Http01Challenges {
+        self.challenges.clone()
     }
 
     /// Initialize TLS configuration, generating certificates if needed
@@ -76,6 +118,11 @@ impl TlsManager {
         }
 
         info!("Initializing TLS configuration");
+
+        if self.config.acme.enabled {
+            return self.initialize_acme().await;
+        }
+
         debug!("Certificate path: {}", self.config.cert_path.display());
         debug!("Private key path: {}", self.config.key_path.display());
 
@@ -98,6 +145,39 @@ impl TlsManager {
         Ok(rustls_config)
     }
 
+    /// Obtain (or reuse) an ACME certificate and load it. The HTTP server must
+    /// already be running when using the HTTP-01 challenge.
+    async fn initialize_acme(&self) -> Result<RustlsConfig, TlsError> {
+        let manager = AcmeManager::new(self.config.acme.clone(), self.challenges.clone());
+        manager.ensure_certificate().await?;
+
+        let rustls_config = self
+            .load_certificate_files(&self.config.acme.cert_path(), &self.config.acme.key_path())
+            .await?;
+        info!(
+            "TLS configuration initialized with ACME certificate for {}",
+            self.config.acme.domains.join(", ")
+        );
+        Ok(rustls_config)
+    }
+
+    /// Start renewing the ACME certificate in the background, reloading it
+    /// into the running server. Returns None when ACME is not enabled.
+    pub fn spawn_acme_renewal(&self, rustls_config: RustlsConfig) -> Option<JoinHandle<()>> {
+        if !self.config.enabled || !self.config.acme.enabled {
+            return None;
+        }
+        let manager = Arc::new(AcmeManager::new(
+            self.config.acme.clone(),
+            self.challenges.clone(),
+        ));
+        let tls = self.clone();
+        let (cert_path, key_path) = (self.config.acme.cert_path(), self.config.acme.key_path());
+        Some(manager.spawn_renewal(rustls_config, move || {
+            tls.server_config(&cert_path, &key_path)
+        }))
+    }
+
     /// Check if we should generate a new certificate
     fn should_generate_certificate(&self) -> Result<bool, TlsError> {
         // Check if certificate files exist
@@ -126,34 +206,26 @@ impl TlsManager {
         }
     }
 
-    /// Check if the certificate is valid and not expiring soon (within 30 days)
+    /// Check if the certificate is valid and not expiring soon (within 30 days).
+    ///
+    /// Certificates issued by a CA are never replaced with a self-signed one,
+    /// so they count as valid here and only trigger expiry alerts.
     fn check_certificate_validity(&self) -> Result<bool, TlsError> {
-        let cert_data = fs::read(&self.config.cert_path)?;
-
-        // Parse the certificate to check expiry
-        let mut cursor = std::io::Cursor::new(cert_data);
-        let cert_results: Result<Vec<_>, _> = certs(&mut cursor).collect();
-        let certs = cert_results.map_err(|e| TlsError::InvalidCertificate(e.to_string()))?;
+        let info = inspect_certificate(&self.config.cert_path)?;
 
-        if certs.is_empty() {
-            return Err(TlsError::InvalidCertificate(
-                "No certificates found".to_string(),
-            ));
+        if !info.self_signed {
+            if info.days_remaining <= SELF_SIGNED_RENEWAL_DAYS {
+                warn!(
+                    "Certificate {} expires in {} days; replace it before {}",
+                    self.config.cert_path.display(),
+                    info.days_remaining,
+                    info.not_after
+                );
+            }
+            return Ok(true);
         }
 
-        // For self-signed certificates, we'll do a basic check
-        // In a real implementation, you might want to parse the X.509 certificate
-        // and check the actual expiry date
-
-        // For now, assume certificates older than 30 days should be regenerated
-        let metadata = fs::metadata(&self.config.cert_path)?;
-        let created = metadata.created().or_else(|_| metadata.modified())?;
-        let age = created.elapsed().unwrap_or(Duration::from_secs(0));
-
-        // Consider certificates older than 335 days (30 days before 365 day expiry) as needing renewal
-        const RENEWAL_THRESHOLD: Duration = Duration::from_secs(335 * 24 * 60 * 60);
-
-        Ok(age < RENEWAL_THRESHOLD)
+        Ok(info.days_remaining > SELF_SIGNED_RENEWAL_DAYS)
     }
 
     /// Generate a self-signed certificate for development use
@@ -183,9 +255,12 @@ impl TlsManager {
             .distinguished_name
             .push(rcgen::DnType::CountryName, "US");
 
-        // Set validity period to 1 year
-        params.not_before = rcgen::date_time_ymd(2024, 1, 1);
-        params.not_after = rcgen::date_time_ymd(2025, 12, 31);
+        // Valid for a year from today
+        let start = Utc::now().date_naive();
+        let end = start + chrono::Duration::days(SELF_SIGNED_VALIDITY_DAYS);
+        params.not_before =
+            rcgen::date_time_ymd(start.year(), start.month() as u8, start.day() as u8);
+        params.not_after = rcgen::date_time_ymd(end.year(), end.month() as u8, end.day() as u8);
 
         let cert = RcgenCertificate::from_params(params)
             .map_err(|e| TlsError::GenerationFailed(e.to_string()))?;
@@ -220,8 +295,29 @@ impl TlsManager {
 
     /// Load certificates from files and create Rustls configuration
     async fn load_certificates(&self) -> Result<RustlsConfig, TlsError> {
+        self.load_certificate_files(&self.config.cert_path, &self.config.key_path)
+            .await
+    }
+
+    /// Load a certificate chain and private key from PEM files
+    async fn load_certificate_files(
+        &self,
+        cert_path: &Path,
+        key_path: &Path,
+    ) -> Result<RustlsConfig, TlsError> {
+        let config = self.server_config(cert_path, key_path)?;
+        Ok(RustlsConfig::from_config(config))
+    }
+
+    /// Build the rustls server configuration for a certificate and key,
+    /// requiring client certificates when mutual TLS is enabled
+    fn server_config(
+        &self,
+        cert_path: &Path,
+        key_path: &Path,
+    ) -> Result<Arc<ServerConfig>, TlsError> {
         // Load certificate file
-        let cert_file = fs::File::open(&self.config.cert_path)?;
+        let cert_file = fs::File::open(cert_path)?;
         let mut cert_reader = BufReader::new(cert_file);
         let cert_results: Result<Vec<_>, _> = certs(&mut cert_reader).collect();
         let cert_chain: Vec<CertificateDer> =
@@ -234,7 +330,7 @@ impl TlsManager {
         }
 
         // Load private key file
-        let key_file = fs::File::open(&self.config.key_path)?;
+        let key_file = fs::File::open(key_path)?;
         let mut key_reader = BufReader::new(key_file);
         let key_results: Result<Vec<_>, _> = pkcs8_private_keys(&mut key_reader).collect();
         let mut keys: Vec<PrivateKeyDer> = key_results
@@ -253,14 +349,164 @@ impl TlsManager {
         let private_key = keys.remove(0);
 
         // Create Rustls configuration with rustls 0.23 API
-        let config = ServerConfig::builder()
-            .with_no_client_auth()
+        let builder = ServerConfig::builder();
+        let builder = if self.config.client_auth.enabled {
+            info!(
+                "Requiring client certificates issued by {}",
+                self.config.client_auth.ca_bundle_path.display()
+            );
+            builder.with_client_cert_verifier(self.config.client_auth.verifier()?)
+        } else {
+            builder.with_no_client_auth()
+        };
+        let config = builder
             .with_single_cert(cert_chain, private_key)
             .map_err(|e| TlsError::ConfigError(e.to_string()))?;
 
         debug!("Loaded certificate(s) and private key successfully");
 
-        Ok(RustlsConfig::from_config(Arc::new(config)))
+        Ok(Arc::new(config))
+    }
+
+    /// Path of the certificate the HTTPS listener serves
+    fn active_cert_path(&self) -> PathBuf {
+        if self.config.acme.enabled {
+            self.config.acme.cert_path()
+        } else {
+            self.config.cert_path.clone()
+        }
+    }
+
+    /// Expiry, SANs, and fingerprint of the certificate being served
+    pub fn certificate_info(&self) -> Result<CertificateInfo, TlsError> {
+        inspect_certificate(&self.active_cert_path())
+    }
+
+    /// TLS settings and current certificate details for the API
+    pub fn status(&self) -> TlsStatus {
+        let (certificate, error) = if self.config.enabled {
+            match self.certificate_info() {
+                Ok(info) => (Some(info), None),
+                Err(e) => (None, Some(e.to_string())),
+            }
+        } else {
+            (None, None)
+        };
+
+        TlsStatus {
+            enabled: self.config.enabled,
+            source: if self.config.acme.enabled {
+                "acme"
+            } else {
+                "file"
+            },
+            auto_generate: self.config.auto_generate,
+            client_auth: self.config.client_auth.enabled,
+            certificate,
+            error,
+        }
+    }
+
+    /// Check the served certificate's expiry in the background, notifying
+    /// 14, 7, and 1 days out and regenerating self-signed certificates that
+    /// are close to expiry. Returns None when TLS is not enabled.
+    pub fn spawn_expiry_monitor(
+        &self,
+        rustls_config: RustlsConfig,
+        notifications: Arc<NotificationDispatcher>,
+    ) -> Option<JoinHandle<()>> {
+        if !self.config.enabled {
+            return None;
+        }
+        let tls = self.clone();
+        Some(tokio::spawn(async move {
+            let mut alerts = ExpiryAlerts::default();
+            let mut interval = tokio::time::interval(EXPIRY_CHECK_INTERVAL);
+            loop {
+                interval.tick().await;
+                tls.check_expiry(&rustls_config, &notifications, &mut alerts)
+                    .await;
+            }
+        }))
+    }
+
+    async fn check_expiry(
+        &self,
+        rustls_config: &RustlsConfig,
+        notifications: &Arc<NotificationDispatcher>,
+        alerts: &mut ExpiryAlerts,
+    ) {
+        let info = match self.certificate_info() {
+            Ok(info) => info,
+            Err(e) => {
+                warn!("Failed to inspect TLS certificate: {}", e);
+                return;
+            }
+        };
+
+        let regenerate = self.config.auto_generate
+            && !self.config.acme.enabled
+            && info.self_signed
+            && info.days_remaining <= SELF_SIGNED_RENEWAL_DAYS;
+        if regenerate {
+            match self.regenerate_self_signed(rustls_config).await {
+                Ok(renewed) => {
+                    notifications.notify_in_background(NotificationInput::new(
+                        NotificationEventType::CertificateExpiring,
+                        NotificationSeverity::Info,
+                        "HTTPS certificate regenerated",
+                        format!(
+                            "The self-signed certificate expiring {} was replaced with one valid until {}.",
+                            info.not_after.format("%Y-%m-%d"),
+                            renewed.not_after.format("%Y-%m-%d")
+                        ),
+                    ));
+                    return;
+                }
+                Err(e) => error!("Failed to regenerate self-signed certificate: {}", e),
+            }
+        }
+
+        if let Some(days) = alerts.due(&info) {
+            let (severity, title) = if days == 0 {
+                (
+                    NotificationSeverity::Error,
+                    "HTTPS certificate expired".to_string(),
+                )
+            } else {
+                (
+                    NotificationSeverity::Warning,
+                    format!(
+                        "HTTPS certificate expires in {} day{}",
+                        days,
+                        if days == 1 { "" } else { "s" }
+                    ),
+                )
+            };
+            notifications.notify_in_background(NotificationInput::new(
+                NotificationEventType::CertificateExpiring,
+                severity,
+                title,
+                format!(
+                    "The certificate for {} at {} expires {}.",
+                    info.subject_alt_names.join(", "),
+                    info.path.display(),
+                    info.not_after.format("%Y-%m-%d %H:%M UTC")
+                ),
+            ));
+        }
+    }
+
+    /// Replace the self-signed certificate and load it into the running server
+    async fn regenerate_self_signed(
+        &self,
+        rustls_config: &RustlsConfig,
+    ) -> Result<CertificateInfo, TlsError> {
+        info!("Self-signed certificate is close to expiry, regenerating");
+        self.generate_self_signed_certificate().await?;
+        let config = self.server_config(&self.config.cert_path, &self.config.key_path)?;
+        rustls_config.reload_from_config(config);
+        inspect_certificate(&self.config.cert_path)
     }
 
     /// Get the default certificate directory
@@ -289,6 +535,19 @@ impl TlsManager {
     }
 }
 
+/// TLS settings and the served certificate, as reported by `/api/security/tls`
+#[derive(Debug, Clone, Serialize)]
+pub struct TlsStatus {
+    pub enabled: bool,
+    /// `acme` for Let's Encrypt certificates, `file` for cert_path/key_path
+    pub source: &'static str,
+    pub auto_generate: bool,
+    pub client_auth: bool,
+    pub certificate: Option<CertificateInfo>,
+    /// Why the certificate could not be read
+    pub error: Option<String>,
+}
+
 /// Information about TLS configuration for logging/debugging
 #[derive(Debug)]
 pub struct TlsConfigInfo {
@@ -344,6 +603,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -368,6 +629,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -402,6 +665,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -428,6 +693,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -454,6 +721,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -470,6 +739,34 @@ mod tests {
         assert!(!should_generate, "Should not regenerate valid certificate");
     }
 
+    #[tokio::test]
+    async fn test_tls_status_reports_certificate() {
+        let temp_dir = tempdir().unwrap();
+        let config = TlsConfig {
+            enabled: true,
+            cert_path: temp_dir.path().join("cert.pem"),
+            key_path: temp_dir.path().join("key.pem"),
+            auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
+        };
+        let manager = TlsManager::new(config);
+
+        let status = manager.status();
+        assert!(status.certificate.is_none());
+        assert!(status.error.is_some());
+
+        manager.generate_self_signed_certificate().await.unwrap();
+        let status = manager.status();
+        assert_eq!(status.source, "file");
+        let certificate = status.certificate.unwrap();
+        assert!(certificate.self_signed);
+        assert!(certificate.days_remaining > SELF_SIGNED_RENEWAL_DAYS);
+        assert!(certificate
+            .subject_alt_names
+            .contains(&"localhost".to_string()));
+    }
+
     #[tokio::test]
     async fn test_missing_certificate_files() {
         let temp_dir = tempdir().unwrap();
@@ -481,6 +778,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: false, // Disable auto-generation
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -510,6 +809,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: false,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -530,6 +831,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -557,6 +860,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -597,6 +902,8 @@ mod tests {
             cert_path: cert_path.clone(),
             key_path: key_path.clone(),
             auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig::default(),
         };
 
         let manager = TlsManager::new(config);
@@ -614,4 +921,35 @@ mod tests {
             "Private key should have 600 permissions"
         );
     }
+
+    #[tokio::test]
+    async fn test_client_auth_requires_ca_bundle() {
+        init_crypto_provider();
+        let temp_dir = tempdir().unwrap();
+        let ca_path = temp_dir.path().join("client-ca.pem");
+
+        let mut config = TlsConfig {
+            enabled: true,
+            cert_path: temp_dir.path().join("cert.pem"),
+            key_path: temp_dir.path().join("key.pem"),
+            auto_generate: true,
+            acme: AcmeConfig::default(),
+            client_auth: ClientAuthConfig {
+                enabled: true,
+                ca_bundle_path: ca_path.clone(),
+                identities: Default::default(),
+            },
+        };
+
+        let result = TlsManager::new(config.clone()).initialize().await;
+        assert!(matches!(result, Err(TlsError::ClientAuth(_))));
+
+        let mut params = CertificateParams::new(vec![]);
+        params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+        let ca = RcgenCertificate::from_params(params).unwrap();
+        fs::write(&ca_path, ca.serialize_pem().unwrap()).unwrap();
+
+        config.client_auth.ca_bundle_path = ca_path;
+        assert!(TlsManager::new(config).initialize().await.is_ok());
+    }
 }
@@ -0,0 +1,681 @@
+// ABOUTME: ACME (Let's Encrypt) certificate provisioning for public hostnames
+// ABOUTME: Orders certificates via HTTP-01 or DNS-01 challenges, persists them, and renews them before expiry
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use axum::{
+    extract::{Path as UrlPath, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Router,
+};
+use axum_server::tls_rustls::RustlsConfig;
+use chrono::{DateTime, Utc};
+use instant_acme::{
+    Account, AccountCredentials, AuthorizationStatus, ChallengeType, Identifier, LetsEncrypt,
+    NewAccount, NewOrder, Order, OrderStatus,
+};
+use rcgen::{Certificate as RcgenCertificate, CertificateParams, DistinguishedName};
+use rustls::ServerConfig;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use tracing::{debug, error, info, warn};
+
+use super::TlsError;
+
+/// Let's Encrypt certificates are valid for 90 days; renew once two thirds have passed
+const RENEW_AFTER_DAYS: i64 = 60;
+
+/// How often the background task checks whether renewal is due
+const RENEWAL_CHECK_INTERVAL: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// How long to wait for the CA to validate challenges and issue the certificate
+const ORDER_POLL_ATTEMPTS: u32 = 30;
+const ORDER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How the CA verifies control of the domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcmeChallenge {
+    /// Serve a token at `http://<domain>/.well-known/acme-challenge/<token>`
+    Http01,
+    /// Publish a TXT record at `_acme-challenge.<domain>` through the DNS hook
+    Dns01,
+}
+
+impl FromStr for AcmeChallenge {
+    type Err = TlsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "http-01" | "http01" | "http" => Ok(AcmeChallenge::Http01),
+            "dns-01" | "dns01" | "dns" => Ok(AcmeChallenge::Dns01),
+            _ => Err(TlsError::ConfigError(format!(
+                "Invalid ACME challenge type: {} (expected http-01 or dns-01)",
+                s
+            ))),
+        }
+    }
+}
+
+/// ACME client settings from the TLS section of the server config
+#[derive(Debug, Clone)]
+pub struct AcmeConfig {
+    pub enabled: bool,
+    pub domains: Vec<String>,
+    pub contact_email: Option<String>,
+    pub challenge: AcmeChallenge,
+    /// Command run with `ACME_ACTION=present|cleanup`, `ACME_DOMAIN`,
+    /// `ACME_TXT_NAME`, and `ACME_TXT_VALUE` to manage DNS-01 TXT records
+    pub dns_hook: Option<String>,
+    /// Time to wait after publishing TXT records before asking the CA to validate
+    pub dns_propagation: Duration,
+    /// Use the Let's Encrypt staging environment (untrusted certs, generous rate limits)
+    pub staging: bool,
+    /// Directory holding the account credentials and issued certificate
+    pub storage_dir: PathBuf,
+}
+
+impl Default for AcmeConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            domains: Vec::new(),
+            contact_email: None,
+            challenge: AcmeChallenge::Http01,
+            dns_hook: None,
+            dns_propagation: Duration::from_secs(60),
+            staging: false,
+            storage_dir: default_acme_dir(),
+        }
+    }
+}
+
+impl AcmeConfig {
+    pub fn cert_path(&self) -> PathBuf {
+        self.storage_dir.join("cert.pem")
+    }
+
+    pub fn key_path(&self) -> PathBuf {
+        self.storage_dir.join("key.pem")
+    }
+
+    fn account_path(&self) -> PathBuf {
+        self.storage_dir.join("account.json")
+    }
+
+    fn metadata_path(&self) -> PathBuf {
+        self.storage_dir.join("certificate.json")
+    }
+
+    fn directory_url(&self) -> &'static str {
+        if self.staging {
+            LetsEncrypt::Staging.url()
+        } else {
+            LetsEncrypt::Production.url()
+        }
+    }
+
+    /// Check the settings before contacting the CA
+    pub fn validate(&self) -> Result<(), TlsError> {
+        if self.domains.is_empty() {
+            return Err(TlsError::ConfigError(
+                "ACME requires at least one domain (TLS_ACME_DOMAINS)".to_string(),
+            ));
+        }
+        match self.challenge {
+            AcmeChallenge::Http01 => {
+                if let Some(domain) = self.domains.iter().find(|d| d.starts_with("*.")) {
+                    return Err(TlsError::ConfigError(format!(
+                        "Wildcard domain {} requires the dns-01 challenge",
+                        domain
+                    )));
+                }
+            }
+            AcmeChallenge::Dns01 => {
+                if self.dns_hook.is_none() {
+                    return Err(TlsError::ConfigError(
+                        "The dns-01 challenge requires a DNS hook (TLS_ACME_DNS_HOOK)".to_string(),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Default ACME storage directory (`~/.orkee/tls`)
+pub fn default_acme_dir() -> PathBuf {
+    super::TlsManager::default_cert_dir()
+        .parent()
+        .map(|orkee_dir| orkee_dir.join("tls"))
+        .unwrap_or_else(|| PathBuf::from(".orkee/tls"))
+}
+
+/// Record of the certificate currently stored on disk
+#[derive(Debug, Serialize, Deserialize)]
+struct CertificateMetadata {
+    domains: Vec<String>,
+    issued_at: DateTime<Utc>,
+}
+
+/// Pending HTTP-01 challenge responses, keyed by token
+#[derive(Debug, Clone, Default)]
+pub struct Http01Challenges(Arc<RwLock<HashMap<String, String>>>);
+
+impl Http01Challenges {
+    fn insert(&self, token: &str, key_authorization: &str) {
+        if let Ok(mut challenges) = self.0.write() {
+            challenges.insert(token.to_string(), key_authorization.to_string());
+        }
+    }
+
+    fn remove(&self, token: &str) {
+        if let Ok(mut challenges) = self.0.write() {
+            challenges.remove(token);
+        }
+    }
+
+    fn get(&self, token: &str) -> Option<String> {
+        self.0.read().ok()?.get(token).cloned()
+    }
+}
+
+/// Router answering HTTP-01 challenges, merged into the plain HTTP server
+pub fn challenge_router(challenges: Http01Challenges) -> Router {
+    Router::new()
+        .route(
+            "/.well-known/acme-challenge/{token}",
+            get(serve_http01_challenge),
+        )
+        .with_state(challenges)
+}
+
+async fn serve_http01_challenge(
+    State(challenges): State<Http01Challenges>,
+    UrlPath(token): UrlPath<String>,
+) -> impl IntoResponse {
+    match challenges.get(&token) {
+        Some(key_authorization) => {
+            debug!("Answering ACME HTTP-01 challenge");
+            (StatusCode::OK, key_authorization)
+        }
+        None => (StatusCode::NOT_FOUND, String::new()),
+    }
+}
+
+/// Obtains and renews certificates from an ACME CA
+pub struct AcmeManager {
+    config: AcmeConfig,
+    challenges: Http01Challenges,
+}
+
+impl AcmeManager {
+    pub fn new(config: AcmeConfig, challenges: Http01Challenges) -> Self {
+        Self { config, challenges }
+    }
+
+    /// Whether the stored certificate is missing, for different domains, or due for renewal
+    pub fn needs_renewal(&self) -> bool {
+        if !self.config.cert_path().exists() || !self.config.key_path().exists() {
+            return true;
+        }
+        let metadata = fs::read_to_string(self.config.metadata_path())
+            .ok()
+            .and_then(|content| serde_json::from_str::<CertificateMetadata>(&content).ok());
+        match metadata {
+            Some(metadata) => {
+                metadata.domains != self.config.domains
+                    || Utc::now() - metadata.issued_at >= chrono::Duration::days(RENEW_AFTER_DAYS)
+            }
+            None => true,
+        }
+    }
+
+    /// Order a new certificate if needed. Returns whether one was issued.
+    pub async fn ensure_certificate(&self) -> Result<bool, TlsError> {
+        self.config.validate()?;
+        if !self.needs_renewal() {
+            debug!("ACME certificate is current");
+            return Ok(false);
+        }
+
+        info!(
+            "Requesting ACME certificate for {}",
+            self.config.domains.join(", ")
+        );
+        fs::create_dir_all(&self.config.storage_dir)?;
+        let account = self.load_or_create_account().await?;
+        self.order_certificate(&account).await?;
+        Ok(true)
+    }
+
+    /// Check for renewal periodically and hot-reload the served certificate.
+    /// `server_config` rebuilds the rustls configuration from the renewed files.
+    pub fn spawn_renewal<F>(
+        self: Arc<Self>,
+        rustls_config: RustlsConfig,
+        server_config: F,
+    ) -> JoinHandle<()>
+    where
+        F: Fn() -> Result<Arc<ServerConfig>, TlsError> + Send + 'static,
+    {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(RENEWAL_CHECK_INTERVAL).await;
+                match self.ensure_certificate().await {
+                    Ok(true) => match server_config() {
+                        Ok(config) => {
+                            rustls_config.reload_from_config(config);
+                            info!("Renewed ACME certificate is now being served");
+                        }
+                        Err(e) => error!("Failed to reload renewed certificate: {}", e),
+                    },
+                    Ok(false) => {}
+                    Err(e) => error!("ACME certificate renewal failed: {}", e),
+                }
+            }
+        })
+    }
+
+    async fn load_or_create_account(&self) -> Result<Account, TlsError> {
+        let account_path = self.config.account_path();
+        if let Ok(content) = fs::read_to_string(&account_path) {
+            let credentials: AccountCredentials = serde_json::from_str(&content)
+                .map_err(|e| acme_error("Invalid stored ACME account", e))?;
+            return Account::from_credentials(credentials)
+                .await
+                .map_err(|e| acme_error("Failed to load ACME account", e));
+        }
+
+        let contact: Vec<String> = self
+            .config
+            .contact_email
+            .iter()
+            .map(|email| format!("mailto:{}", email))
+            .collect();
+        let contact: Vec<&str> = contact.iter().map(String::as_str).collect();
+
+        let (account, credentials) = Account::create(
+            &NewAccount {
+                contact: &contact,
+                terms_of_service_agreed: true,
+                only_return_existing: false,
+            },
+            self.config.directory_url(),
+            None,
+        )
+        .await
+        .map_err(|e| acme_error("Failed to create ACME account", e))?;
+
+        let content = serde_json::to_string_pretty(&credentials)
+            .map_err(|e| acme_error("Failed to serialize ACME account", e))?;
+        write_private(&account_path, &content)?;
+        info!("Created ACME account");
+
+        Ok(account)
+    }
+
+    async fn order_certificate(&self, account: &Account) -> Result<(), TlsError> {
+        let identifiers: Vec<Identifier> = self
+            .config
+            .domains
+            .iter()
+            .map(|domain| Identifier::Dns(domain.clone()))
+            .collect();
+        let mut order = account
+            .new_order(&NewOrder {
+                identifiers: &identifiers,
+            })
+            .await
+            .map_err(|e| acme_error("Failed to create ACME order", e))?;
+
+        let mut published = Vec::new();
+        let result = match self.publish_challenges(&mut order, &mut published).await {
+            Ok(()) => self.complete_order(&mut order).await,
+            Err(e) => Err(e),
+        };
+        self.cleanup_challenges(&published).await;
+        let (cert_chain, key_pem) = result?;
+
+        write_private(&self.config.key_path(), &key_pem)?;
+        fs::write(self.config.cert_path(), cert_chain)?;
+        let metadata = CertificateMetadata {
+            domains: self.config.domains.clone(),
+            issued_at: Utc::now(),
+        };
+        let metadata = serde_json::to_string_pretty(&metadata)
+            .map_err(|e| acme_error("Failed to serialize certificate metadata", e))?;
+        fs::write(self.config.metadata_path(), metadata)?;
+
+        info!(
+            "Issued ACME certificate for {}",
+            self.config.domains.join(", ")
+        );
+        Ok(())
+    }
+
+    /// Publish a response for every pending authorization and tell the CA it can validate.
+    /// Published responses are recorded even on failure so they can be cleaned up.
+    async fn publish_challenges(
+        &self,
+        order: &mut Order,
+        published: &mut Vec<PublishedChallenge>,
+    ) -> Result<(), TlsError> {
+        let authorizations = order
+            .authorizations()
+            .await
+            .map_err(|e| acme_error("Failed to fetch ACME authorizations", e))?;
+        let challenge_type = match self.config.challenge {
+            AcmeChallenge::Http01 => ChallengeType::Http01,
+            AcmeChallenge::Dns01 => ChallengeType::Dns01,
+        };
+
+        let mut ready_urls = Vec::new();
+        for authorization in &authorizations {
+            match authorization.status {
+                AuthorizationStatus::Pending => {}
+                AuthorizationStatus::Valid => continue,
+                status => {
+                    return Err(TlsError::Acme(format!(
+                        "ACME authorization is {:?}",
+                        status
+                    )))
+                }
+            }
+
+            #[allow(unreachable_patterns)]
+            let domain = match &authorization.identifier {
+                Identifier::Dns(domain) => domain.clone(),
+                _ => {
+                    return Err(TlsError::Acme(
+                        "Unsupported ACME identifier type".to_string(),
+                    ))
+                }
+            };
+            let challenge = authorization
+                .challenges
+                .iter()
+                .find(|c| c.r#type == challenge_type)
+                .ok_or_else(|| {
+                    TlsError::Acme(format!(
+                        "CA offered no {:?} challenge for {}",
+                        challenge_type, domain
+                    ))
+                })?;
+            let key_authorization = order.key_authorization(challenge);
+
+            let entry = match self.config.challenge {
+                AcmeChallenge::Http01 => {
+                    self.challenges
+                        .insert(&challenge.token, key_authorization.as_str());
+                    PublishedChallenge::Http01 {
+                        token: challenge.token.clone(),
+                    }
+                }
+                AcmeChallenge::Dns01 => {
+                    let value = key_authorization.dns_value();
+                    self.run_dns_hook("present", &domain, &value).await?;
+                    PublishedChallenge::Dns01 { domain, value }
+                }
+            };
+            published.push(entry);
+            ready_urls.push(challenge.url.clone());
+        }
+
+        if self.config.challenge == AcmeChallenge::Dns01 && !published.is_empty() {
+            info!(
+                "Waiting {}s for DNS records to propagate",
+                self.config.dns_propagation.as_secs()
+            );
+            tokio::time::sleep(self.config.dns_propagation).await;
+        }
+
+        for url in &ready_urls {
+            order
+                .set_challenge_ready(url)
+                .await
+                .map_err(|e| acme_error("Failed to submit ACME challenge", e))?;
+        }
+        Ok(())
+    }
+
+    /// Wait for validation, submit the CSR, and download the certificate chain.
+    /// Returns the chain and the PEM-encoded private key.
+    async fn complete_order(&self, order: &mut Order) -> Result<(String, String), TlsError> {
+        let mut status = OrderStatus::Pending;
+        for _ in 0..ORDER_POLL_ATTEMPTS {
+            tokio::time::sleep(ORDER_POLL_INTERVAL).await;
+            status = order
+                .refresh()
+                .await
+                .map_err(|e| acme_error("Failed to refresh ACME order", e))?
+                .status;
+            if matches!(status, OrderStatus::Ready | OrderStatus::Invalid) {
+                break;
+            }
+        }
+        if status != OrderStatus::Ready {
+            return Err(TlsError::Acme(format!(
+                "ACME order was not validated (status: {:?})",
+                status
+            )));
+        }
+
+        let mut params = CertificateParams::new(self.config.domains.clone());
+        params.distinguished_name = DistinguishedName::new();
+        let cert = RcgenCertificate::from_params(params)
+            .map_err(|e| TlsError::GenerationFailed(e.to_string()))?;
+        let csr = cert
+            .serialize_request_der()
+            .map_err(|e| TlsError::GenerationFailed(e.to_string()))?;
+        order
+            .finalize(&csr)
+            .await
+            .map_err(|e| acme_error("Failed to finalize ACME order", e))?;
+
+        for _ in 0..ORDER_POLL_ATTEMPTS {
+            if let Some(cert_chain) = order
+                .certificate()
+                .await
+                .map_err(|e| acme_error("Failed to download ACME certificate", e))?
+            {
+                return Ok((cert_chain, cert.serialize_private_key_pem()));
+            }
+            tokio::time::sleep(ORDER_POLL_INTERVAL).await;
+        }
+        Err(TlsError::Acme(
+            "Timed out waiting for the certificate to be issued".to_string(),
+        ))
+    }
+
+    async fn cleanup_challenges(&self, published: &[PublishedChallenge]) {
+        for challenge in published {
+            match challenge {
+                PublishedChallenge::Http01 { token } => self.challenges.remove(token),
+                PublishedChallenge::Dns01 { domain, value } => {
+                    if let Err(e) = self.run_dns_hook("cleanup", domain, value).await {
+                        warn!("Failed to clean up DNS challenge for {}: {}", domain, e);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn run_dns_hook(&self, action: &str, domain: &str, value: &str) -> Result<(), TlsError> {
+        let hook = self.config.dns_hook.as_deref().ok_or_else(|| {
+            TlsError::ConfigError("No DNS hook configured for dns-01".to_string())
+        })?;
+        let record_domain = domain.trim_start_matches("*.");
+
+        let status = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg(hook)
+            .env("ACME_ACTION", action)
+            .env("ACME_DOMAIN", record_domain)
+            .env(
+                "ACME_TXT_NAME",
+                format!("_acme-challenge.{}", record_domain),
+            )
+            .env("ACME_TXT_VALUE", value)
+            .status()
+            .await?;
+        if !status.success() {
+            return Err(TlsError::Acme(format!(
+                "DNS hook failed for {} ({}): {}",
+                domain, action, status
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Challenge response that must be withdrawn once the order completes
+enum PublishedChallenge {
+    Http01 { token: String },
+    Dns01 { domain: String, value: String },
+}
+
+fn acme_error(context: &str, err: impl std::fmt::Display) -> TlsError {
+    TlsError::Acme(format!("{}: {}", context, err))
+}
+
+/// Write a file readable only by the owner (Unix only)
+fn write_private(path: &Path, content: &str) -> Result<(), TlsError> {
+    fs::write(path, content)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use tempfile::tempdir;
+    use tower::ServiceExt;
+
+    fn test_config(storage_dir: &Path) -> AcmeConfig {
+        AcmeConfig {
+            enabled: true,
+            domains: vec!["orkee.example.com".to_string()],
+            storage_dir: storage_dir.to_path_buf(),
+            ..Default::default()
+        }
+    }
+
+    fn write_certificate(config: &AcmeConfig, domains: Vec<String>, issued_at: DateTime<Utc>) {
+        fs::write(config.cert_path(), "cert").unwrap();
+        fs::write(config.key_path(), "key").unwrap();
+        let metadata = CertificateMetadata { domains, issued_at };
+        fs::write(
+            config.metadata_path(),
+            serde_json::to_string(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_challenge_type_parsing() {
+        assert_eq!(
+            "http-01".parse::<AcmeChallenge>().unwrap(),
+            AcmeChallenge::Http01
+        );
+        assert_eq!(
+            "DNS-01".parse::<AcmeChallenge>().unwrap(),
+            AcmeChallenge::Dns01
+        );
+        assert!("tls-alpn-01".parse::<AcmeChallenge>().is_err());
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let dir = tempdir().unwrap();
+        let mut config = test_config(dir.path());
+        assert!(config.validate().is_ok());
+
+        config.domains = vec!["*.example.com".to_string()];
+        assert!(config.validate().is_err());
+
+        config.challenge = AcmeChallenge::Dns01;
+        assert!(config.validate().is_err());
+
+        config.dns_hook = Some("./update-dns.sh".to_string());
+        assert!(config.validate().is_ok());
+
+        config.domains.clear();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_default_acme_dir() {
+        assert!(default_acme_dir().ends_with(".orkee/tls"));
+    }
+
+    #[test]
+    fn test_needs_renewal() {
+        let dir = tempdir().unwrap();
+        let config = test_config(dir.path());
+        let manager = AcmeManager::new(config.clone(), Http01Challenges::default());
+
+        // No certificate yet
+        assert!(manager.needs_renewal());
+
+        write_certificate(&config, config.domains.clone(), Utc::now());
+        assert!(!manager.needs_renewal());
+
+        // Old certificates are renewed ahead of the 90 day expiry
+        write_certificate(
+            &config,
+            config.domains.clone(),
+            Utc::now() - chrono::Duration::days(61),
+        );
+        assert!(manager.needs_renewal());
+
+        // Changing the configured domains requires a new certificate
+        write_certificate(&config, vec!["old.example.com".to_string()], Utc::now());
+        assert!(manager.needs_renewal());
+    }
+
+    #[tokio::test]
+    async fn test_challenge_router_serves_pending_tokens() {
+        let challenges = Http01Challenges::default();
+        challenges.insert("token-1", "token-1.thumbprint");
+        let app = challenge_router(challenges.clone());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::get("/.well-known/acme-challenge/token-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"token-1.thumbprint");
+
+        challenges.remove("token-1");
+        let response = app
+            .oneshot(
+                Request::get("/.well-known/acme-challenge/token-1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
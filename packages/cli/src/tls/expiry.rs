@@ -0,0 +1,215 @@
+// ABOUTME: Certificate introspection and expiry monitoring for the HTTPS listener
+// ABOUTME: Reads expiry, SANs, and fingerprint from PEM files and alerts 14, 7, and 1 days before expiry
+
+use std::fs;
+use std::io::BufReader;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use rustls_pemfile::certs;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use x509_parser::extensions::GeneralName;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use super::TlsError;
+
+/// Days before expiry at which a notification is sent
+pub const EXPIRY_ALERT_DAYS: [i64; 3] = [14, 7, 1];
+
+/// Details of the certificate the HTTPS listener serves
+#[derive(Debug, Clone, Serialize)]
+pub struct CertificateInfo {
+    pub path: PathBuf,
+    pub subject: String,
+    pub issuer: String,
+    pub serial: String,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    /// Whole days until `not_after`; negative once expired
+    pub days_remaining: i64,
+    /// DNS names and IP addresses the certificate is valid for
+    pub subject_alt_names: Vec<String>,
+    /// SHA-256 of the DER encoding, as colon-separated hex
+    pub fingerprint_sha256: String,
+    pub self_signed: bool,
+}
+
+impl CertificateInfo {
+    pub fn is_expired(&self) -> bool {
+        self.not_after <= Utc::now()
+    }
+}
+
+/// Read the first (leaf) certificate from a PEM file
+pub fn inspect_certificate(path: &Path) -> Result<CertificateInfo, TlsError> {
+    let file = fs::File::open(path)
+        .map_err(|_| TlsError::CertificateNotFound(path.display().to_string()))?;
+    let der = certs(&mut BufReader::new(file))
+        .next()
+        .ok_or_else(|| TlsError::InvalidCertificate("No certificates found".to_string()))?
+        .map_err(|e| TlsError::InvalidCertificate(e.to_string()))?;
+
+    let (_, cert) = X509Certificate::from_der(der.as_ref())
+        .map_err(|e| TlsError::InvalidCertificate(e.to_string()))?;
+
+    let not_before = timestamp(cert.validity().not_before.timestamp())?;
+    let not_after = timestamp(cert.validity().not_after.timestamp())?;
+
+    let subject_alt_names = cert
+        .subject_alternative_name()
+        .map_err(|e| TlsError::InvalidCertificate(e.to_string()))?
+        .map(|san| {
+            san.value
+                .general_names
+                .iter()
+                .filter_map(|name| match name {
+                    GeneralName::DNSName(dns) => Some(dns.to_string()),
+                    GeneralName::IPAddress(bytes) => ip_from_bytes(bytes).map(|ip| ip.to_string()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let fingerprint_sha256 = Sha256::digest(der.as_ref())
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(":");
+
+    Ok(CertificateInfo {
+        path: path.to_path_buf(),
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        serial: cert.raw_serial_as_string(),
+        not_before,
+        not_after,
+        days_remaining: (not_after - Utc::now()).num_days(),
+        subject_alt_names,
+        fingerprint_sha256,
+        self_signed: cert.subject().as_raw() == cert.issuer().as_raw(),
+    })
+}
+
+fn timestamp(seconds: i64) -> Result<DateTime<Utc>, TlsError> {
+    DateTime::from_timestamp(seconds, 0)
+        .ok_or_else(|| TlsError::InvalidCertificate("Validity date out of range".to_string()))
+}
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => <[u8; 4]>::try_from(bytes).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(bytes).ok().map(IpAddr::from),
+        _ => None,
+    }
+}
+
+/// Tracks which expiry alerts were already sent so each threshold fires once per certificate
+#[derive(Debug, Default)]
+pub struct ExpiryAlerts {
+    /// Fingerprint and threshold of the last alert sent
+    last: Option<(String, i64)>,
+}
+
+impl ExpiryAlerts {
+    /// The alert threshold a certificate has newly crossed, if any.
+    ///
+    /// Returns the smallest of [`EXPIRY_ALERT_DAYS`] at or above the days
+    /// remaining, or 0 once the certificate has expired. A replaced
+    /// certificate starts over.
+    pub fn due(&mut self, info: &CertificateInfo) -> Option<i64> {
+        let threshold = if info.is_expired() {
+            0
+        } else {
+            EXPIRY_ALERT_DAYS
+                .iter()
+                .rev()
+                .copied()
+                .find(|days| info.days_remaining <= *days)?
+        };
+
+        match &self.last {
+            Some((fingerprint, last))
+                if *fingerprint == info.fingerprint_sha256 && *last <= threshold =>
+            {
+                None
+            }
+            _ => {
+                self.last = Some((info.fingerprint_sha256.clone(), threshold));
+                Some(threshold)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{Certificate, CertificateParams};
+    use tempfile::tempdir;
+
+    fn write_certificate(dir: &Path, days_valid: i64) -> PathBuf {
+        use chrono::Datelike;
+
+        let mut params = CertificateParams::new(vec!["localhost".to_string()]);
+        params
+            .subject_alt_names
+            .push(rcgen::SanType::IpAddress("127.0.0.1".parse().unwrap()));
+        let start = (Utc::now() - chrono::Duration::days(30)).date_naive();
+        let end = (Utc::now() + chrono::Duration::days(days_valid)).date_naive();
+        params.not_before =
+            rcgen::date_time_ymd(start.year(), start.month() as u8, start.day() as u8);
+        params.not_after = rcgen::date_time_ymd(end.year(), end.month() as u8, end.day() as u8);
+
+        let path = dir.join(format!("cert-{}.pem", days_valid));
+        let cert = Certificate::from_params(params).unwrap();
+        fs::write(&path, cert.serialize_pem().unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_inspect_certificate() {
+        let dir = tempdir().unwrap();
+        let info = inspect_certificate(&write_certificate(dir.path(), 100)).unwrap();
+
+        assert!(info.self_signed);
+        assert!((98..=100).contains(&info.days_remaining));
+        assert_eq!(info.subject_alt_names, vec!["localhost", "127.0.0.1"]);
+        assert_eq!(info.fingerprint_sha256.len(), 32 * 3 - 1);
+        assert!(!info.is_expired());
+
+        let missing = inspect_certificate(&dir.path().join("missing.pem"));
+        assert!(matches!(missing, Err(TlsError::CertificateNotFound(_))));
+    }
+
+    #[test]
+    fn test_expiry_alerts_fire_once_per_threshold() {
+        let dir = tempdir().unwrap();
+        let info = inspect_certificate(&write_certificate(dir.path(), 100)).unwrap();
+        let mut alerts = ExpiryAlerts::default();
+
+        assert_eq!(alerts.due(&info), None);
+
+        let mut expiring = CertificateInfo {
+            days_remaining: 10,
+            ..info.clone()
+        };
+        assert_eq!(alerts.due(&expiring), Some(14));
+        assert_eq!(alerts.due(&expiring), None);
+
+        expiring.days_remaining = 6;
+        assert_eq!(alerts.due(&expiring), Some(7));
+        expiring.days_remaining = 0;
+        assert_eq!(alerts.due(&expiring), Some(1));
+        assert_eq!(alerts.due(&expiring), None);
+
+        // A replacement certificate close to expiry alerts again
+        expiring.fingerprint_sha256 = "AA:BB".to_string();
+        assert_eq!(alerts.due(&expiring), Some(1));
+
+        expiring.not_after = Utc::now() - chrono::Duration::days(1);
+        assert_eq!(alerts.due(&expiring), Some(0));
+    }
+}
@@ -0,0 +1,245 @@
+// ABOUTME: Mutual TLS client-certificate authentication for the HTTPS listener
+// ABOUTME: Verifies client certificates against a CA bundle and maps certificate CNs to API token identities
+
+use std::collections::HashMap;
+use std::fs;
+use std::future::Future;
+use std::io::{self, BufReader};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use axum::middleware::AddExtension;
+use axum::Extension;
+use axum_server::accept::Accept;
+use axum_server::tls_rustls::{RustlsAcceptor, RustlsConfig};
+use rustls::server::danger::ClientCertVerifier;
+use rustls::server::WebPkiClientVerifier;
+use rustls::RootCertStore;
+use rustls_pemfile::certs;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tower::Layer;
+use tracing::{debug, warn};
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use super::{TlsError, TlsManager};
+
+/// Client certificate requirements for the HTTPS listener
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientAuthConfig {
+    pub enabled: bool,
+    /// PEM bundle of the CAs allowed to issue client certificates
+    pub ca_bundle_path: PathBuf,
+    /// Certificate common name -> API token ID
+    pub identities: HashMap<String, String>,
+}
+
+impl Default for ClientAuthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ca_bundle_path: TlsManager::default_cert_dir().join("client-ca.pem"),
+            identities: HashMap::new(),
+        }
+    }
+}
+
+impl ClientAuthConfig {
+    /// Build a verifier that requires a client certificate issued by the CA bundle
+    pub fn verifier(&self) -> Result<Arc<dyn ClientCertVerifier>, TlsError> {
+        let file = fs::File::open(&self.ca_bundle_path).map_err(|e| {
+            TlsError::ClientAuth(format!(
+                "Failed to open client CA bundle {}: {}",
+                self.ca_bundle_path.display(),
+                e
+            ))
+        })?;
+
+        let mut roots = RootCertStore::empty();
+        for cert in certs(&mut BufReader::new(file)) {
+            let cert = cert.map_err(|e| TlsError::InvalidCertificate(e.to_string()))?;
+            roots
+                .add(cert)
+                .map_err(|e| TlsError::InvalidCertificate(e.to_string()))?;
+        }
+        if roots.is_empty() {
+            return Err(TlsError::ClientAuth(format!(
+                "No CA certificates found in {}",
+                self.ca_bundle_path.display()
+            )));
+        }
+
+        WebPkiClientVerifier::builder(Arc::new(roots))
+            .build()
+            .map_err(|e| TlsError::ClientAuth(e.to_string()))
+    }
+}
+
+/// Parse `CN=token-id` pairs separated by commas into a CN -> token ID map
+pub fn parse_identity_map(value: &str) -> Result<HashMap<String, String>, String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (cn, token_id) = entry
+                .rsplit_once('=')
+                .ok_or_else(|| format!("Expected CN=token-id, got '{}'", entry))?;
+            let (cn, token_id) = (cn.trim(), token_id.trim());
+            if cn.is_empty() || token_id.is_empty() {
+                return Err(format!("Expected CN=token-id, got '{}'", entry));
+            }
+            Ok((cn.to_string(), token_id.to_string()))
+        })
+        .collect()
+}
+
+/// Verified client certificate identity, attached to every request on the connection
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientIdentity {
+    pub common_name: Option<String>,
+    /// API token the common name is mapped to, if any
+    pub token_id: Option<String>,
+}
+
+/// Rustls acceptor that records the verified client certificate of each connection
+#[derive(Clone)]
+pub struct ClientCertAcceptor {
+    inner: RustlsAcceptor,
+    identities: Arc<HashMap<String, String>>,
+}
+
+impl ClientCertAcceptor {
+    pub fn new(rustls_config: RustlsConfig, identities: HashMap<String, String>) -> Self {
+        Self {
+            inner: RustlsAcceptor::new(rustls_config),
+            identities: Arc::new(identities),
+        }
+    }
+}
+
+impl<I, S> Accept<I, S> for ClientCertAcceptor
+where
+    I: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    S: Send + 'static,
+{
+    type Stream = <RustlsAcceptor as Accept<I, S>>::Stream;
+    type Service = AddExtension<S, ClientIdentity>;
+    type Future = Pin<Box<dyn Future<Output = io::Result<(Self::Stream, Self::Service)>> + Send>>;
+
+    fn accept(&self, stream: I, service: S) -> Self::Future {
+        let handshake = self.inner.accept(stream, service);
+        let identities = self.identities.clone();
+
+        Box::pin(async move {
+            let (stream, service) = handshake.await?;
+            let common_name = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(|chain| chain.first())
+                .and_then(|cert| common_name(cert));
+            let token_id = common_name
+                .as_ref()
+                .and_then(|cn| identities.get(cn).cloned());
+
+            match (&common_name, &token_id) {
+                (Some(cn), Some(_)) => debug!(cn = %cn, "Client certificate mapped to API token"),
+                (Some(cn), None) => warn!(cn = %cn, "Client certificate CN has no API identity"),
+                (None, _) => warn!("Client certificate has no common name"),
+            }
+
+            let identity = ClientIdentity {
+                common_name,
+                token_id,
+            };
+            Ok((stream, Extension(identity).layer(service)))
+        })
+    }
+}
+
+/// Subject common name of a DER-encoded X.509 certificate
+pub fn common_name(der: &[u8]) -> Option<String> {
+    let (_, certificate) = X509Certificate::from_der(der).ok()?;
+    let common_name = certificate
+        .tbs_certificate
+        .subject()
+        .iter_common_name()
+        .next()?
+        .as_str()
+        .ok()?
+        .to_string();
+    Some(common_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rcgen::{BasicConstraints, Certificate, CertificateParams, DnType, IsCa};
+
+    fn init_crypto_provider() {
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+    }
+
+    fn ca_certificate() -> Certificate {
+        let mut params = CertificateParams::new(vec![]);
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "Orkee Test CA");
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        Certificate::from_params(params).unwrap()
+    }
+
+    #[test]
+    fn test_common_name_from_signed_client_certificate() {
+        let ca = ca_certificate();
+        let mut params = CertificateParams::new(vec![]);
+        params
+            .distinguished_name
+            .push(DnType::OrganizationName, "Orkee");
+        params.distinguished_name.push(DnType::CommonName, "laptop");
+        let client = Certificate::from_params(params).unwrap();
+        let der = client.serialize_der_with_signer(&ca).unwrap();
+
+        assert_eq!(common_name(&der), Some("laptop".to_string()));
+        assert_eq!(
+            common_name(&ca.serialize_der().unwrap()),
+            Some("Orkee Test CA".to_string())
+        );
+        assert_eq!(common_name(&der[..der.len() / 2]), None);
+        assert_eq!(common_name(b"not a certificate"), None);
+    }
+
+    #[test]
+    fn test_parse_identity_map() {
+        let map = parse_identity_map("laptop=tok-1, ci runner = tok-2,").unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["laptop"], "tok-1");
+        assert_eq!(map["ci runner"], "tok-2");
+
+        assert!(parse_identity_map("").unwrap().is_empty());
+        assert!(parse_identity_map("laptop").is_err());
+        assert!(parse_identity_map("=tok-1").is_err());
+    }
+
+    #[test]
+    fn test_verifier_requires_ca_bundle() {
+        init_crypto_provider();
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = ClientAuthConfig {
+            enabled: true,
+            ca_bundle_path: dir.path().join("client-ca.pem"),
+            identities: HashMap::new(),
+        };
+        assert!(matches!(config.verifier(), Err(TlsError::ClientAuth(_))));
+
+        fs::write(&config.ca_bundle_path, "").unwrap();
+        assert!(matches!(config.verifier(), Err(TlsError::ClientAuth(_))));
+
+        let ca_path = dir.path().join("ca.pem");
+        fs::write(&ca_path, ca_certificate().serialize_pem().unwrap()).unwrap();
+        config.ca_bundle_path = ca_path;
+        let verifier = config.verifier().unwrap();
+        assert!(verifier.client_auth_mandatory());
+    }
+}
@@ -73,6 +73,37 @@ pub struct CloudProject {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_sync: Option<DateTime<Utc>>,
+    /// Organization the project belongs to, if it is shared with a team
+    #[serde(default)]
+    pub org_id: Option<String>,
+    /// User who first synced the project to the cloud
+    #[serde(default)]
+    pub owner: Option<ProjectAuthor>,
+    /// User who pushed the latest snapshot
+    #[serde(default)]
+    pub last_synced_by: Option<ProjectAuthor>,
+    /// Set when the project was shared with read-only permission
+    #[serde(default)]
+    pub read_only: bool,
+}
+
+impl CloudProject {
+    /// Whether the project was synced by someone other than the given user
+    pub fn is_from_teammate(&self, user_id: &str) -> bool {
+        self.owner.as_ref().is_some_and(|o| o.user_id != user_id)
+            || self
+                .last_synced_by
+                .as_ref()
+                .is_some_and(|a| a.user_id != user_id)
+    }
+}
+
+/// Author metadata attached to shared projects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectAuthor {
+    pub user_id: String,
+    pub name: String,
+    pub email: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +129,58 @@ pub struct RestoreResponse {
     pub created_at: DateTime<Utc>,
 }
 
+/// Organization the user belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    pub slug: String,
+    pub role: OrgRole,
+    pub member_count: u32,
+    pub created_at: DateTime<Utc>,
+}
+
+/// The user's role within an organization
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrgRole {
+    Owner,
+    Admin,
+    Member,
+    Viewer,
+}
+
+/// List organizations response
+#[derive(Debug, Deserialize)]
+pub struct ListOrganizationsResponse {
+    pub organizations: Vec<Organization>,
+}
+
+/// Access granted to an organization for a shared project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SharePermission {
+    Read,
+    Write,
+}
+
+/// Share project request
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShareProjectRequest {
+    pub org_id: String,
+    pub permission: SharePermission,
+}
+
+/// A project shared with an organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectShare {
+    pub project_id: String,
+    pub org_id: String,
+    pub permission: SharePermission,
+    pub shared_by: ProjectAuthor,
+    pub shared_at: DateTime<Utc>,
+}
+
 /// Usage statistics
 #[derive(Debug, Deserialize)]
 pub struct Usage {
@@ -2,6 +2,7 @@
 
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use tokio::fs;
 
@@ -39,11 +40,70 @@ impl TokenInfo {
     }
 }
 
+/// Account name used for logins that don't name one, and for migrated single-token files
+pub const DEFAULT_ACCOUNT: &str = "default";
+
+/// A stored Orkee Cloud login, as listed to users
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountSummary {
+    pub name: String,
+    pub user_email: String,
+    pub user_name: String,
+    pub active: bool,
+    pub expired: bool,
+}
+
+/// Everything persisted in `~/.orkee/auth.toml`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CredentialStore {
+    /// Name of the account used when a project isn't pinned to one
+    active: Option<String>,
+    #[serde(default)]
+    accounts: BTreeMap<String, TokenInfo>,
+    /// Project ID to the account its sync must run as
+    #[serde(default)]
+    project_accounts: BTreeMap<String, String>,
+}
+
+impl CredentialStore {
+    fn parse(content: &str) -> CloudResult<Self> {
+        // Files written before named accounts held a single bare token
+        if let Ok(token_info) = toml::from_str::<TokenInfo>(content) {
+            let mut store = Self::default();
+            store
+                .accounts
+                .insert(DEFAULT_ACCOUNT.to_string(), token_info);
+            store.active = Some(DEFAULT_ACCOUNT.to_string());
+            return Ok(store);
+        }
+
+        toml::from_str(content)
+            .map_err(|e| CloudError::config(format!("Invalid auth configuration: {}", e)))
+    }
+}
+
+/// Account names end up in file contents and CLI arguments, so keep them simple
+fn validate_account_name(name: &str) -> CloudResult<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(CloudError::config(format!(
+            "Invalid account name '{}': use letters, digits, '-' or '_'",
+            name
+        )))
+    }
+}
+
 /// Authentication manager
 #[derive(Clone)]
 pub struct AuthManager {
     config_path: PathBuf,
-    token_info: Option<TokenInfo>,
+    credentials: CredentialStore,
 }
 
 impl AuthManager {
@@ -52,7 +112,7 @@ impl AuthManager {
         let config_path = Self::config_file_path()?;
         Ok(Self {
             config_path,
-            token_info: None,
+            credentials: CredentialStore::default(),
         })
     }
 
@@ -74,37 +134,47 @@ impl AuthManager {
         Ok(config_dir.join("auth.toml"))
     }
 
-    /// Load token from file
+    /// Load stored accounts from file
     async fn load_token(&mut self) -> CloudResult<()> {
         if !self.config_path.exists() {
             return Err(CloudError::config("No auth configuration found"));
         }
 
         let content = fs::read_to_string(&self.config_path).await?;
-        let token_info: TokenInfo = toml::from_str(&content)
-            .map_err(|e| CloudError::config(format!("Invalid auth configuration: {}", e)))?;
-
-        self.token_info = Some(token_info);
+        self.credentials = CredentialStore::parse(&content)?;
         Ok(())
     }
 
-    /// Save token to file
-    async fn save_token(&self, token_info: &TokenInfo) -> CloudResult<()> {
+    /// Save stored accounts to file, removing it once no account is left
+    async fn save_credentials(&self) -> CloudResult<()> {
+        if self.credentials.accounts.is_empty() {
+            if self.config_path.exists() {
+                fs::remove_file(&self.config_path).await?;
+            }
+            return Ok(());
+        }
+
         // Ensure config directory exists
         if let Some(parent) = self.config_path.parent() {
             fs::create_dir_all(parent).await?;
         }
 
-        let toml_content = toml::to_string_pretty(token_info)
+        let toml_content = toml::to_string_pretty(&self.credentials)
             .map_err(|e| CloudError::config(format!("Failed to serialize token: {}", e)))?;
 
         fs::write(&self.config_path, toml_content).await?;
         Ok(())
     }
 
+    /// Token of the active account
+    fn token_info(&self) -> Option<&TokenInfo> {
+        let active = self.credentials.active.as_ref()?;
+        self.credentials.accounts.get(active)
+    }
+
     /// Get a valid token, refreshing if necessary
     pub async fn get_valid_token(&self) -> CloudResult<String> {
-        match &self.token_info {
+        match self.token_info() {
             Some(token_info) if token_info.is_valid() => Ok(token_info.token.clone()),
             Some(token_info) if !token_info.is_expired() => {
                 // Token is in the buffer zone, try to refresh
@@ -118,15 +188,12 @@ impl AuthManager {
 
     /// Check if user is authenticated
     pub fn is_authenticated(&self) -> bool {
-        self.token_info
-            .as_ref()
-            .map(|t| t.is_valid())
-            .unwrap_or(false)
+        self.token_info().map(|t| t.is_valid()).unwrap_or(false)
     }
 
     /// Get user information if authenticated
     pub fn user_info(&self) -> Option<(&str, &str, &str)> {
-        self.token_info.as_ref().filter(|t| t.is_valid()).map(|t| {
+        self.token_info().filter(|t| t.is_valid()).map(|t| {
             (
                 t.user_id.as_str(),
                 t.user_email.as_str(),
@@ -135,6 +202,97 @@ impl AuthManager {
         })
     }
 
+    /// Name of the account requests are made as
+    pub fn active_account(&self) -> Option<&str> {
+        self.credentials.active.as_deref()
+    }
+
+    /// All stored accounts, sorted by name
+    pub fn accounts(&self) -> Vec<AccountSummary> {
+        self.credentials
+            .accounts
+            .iter()
+            .map(|(name, token)| AccountSummary {
+                name: name.clone(),
+                user_email: token.user_email.clone(),
+                user_name: token.user_name.clone(),
+                active: self.credentials.active.as_deref() == Some(name.as_str()),
+                expired: token.is_expired(),
+            })
+            .collect()
+    }
+
+    /// Make a stored account the active one
+    pub async fn switch_account(&mut self, name: &str) -> CloudResult<()> {
+        if !self.credentials.accounts.contains_key(name) {
+            return Err(CloudError::auth(format!("No account named '{}'", name)));
+        }
+        self.credentials.active = Some(name.to_string());
+        self.save_credentials().await
+    }
+
+    /// Forget an account and unpin the projects that used it
+    pub async fn remove_account(&mut self, name: &str) -> CloudResult<()> {
+        if self.credentials.accounts.remove(name).is_none() {
+            return Err(CloudError::auth(format!("No account named '{}'", name)));
+        }
+        self.credentials
+            .project_accounts
+            .retain(|_, account| account != name);
+        if self.credentials.active.as_deref() == Some(name) {
+            self.credentials.active = self.credentials.accounts.keys().next().cloned();
+        }
+        self.save_credentials().await
+    }
+
+    /// Always sync a project as the given account, whichever account is active
+    pub async fn pin_project(&mut self, project_id: &str, account: &str) -> CloudResult<()> {
+        if !self.credentials.accounts.contains_key(account) {
+            return Err(CloudError::auth(format!("No account named '{}'", account)));
+        }
+        self.credentials
+            .project_accounts
+            .insert(project_id.to_string(), account.to_string());
+        self.save_credentials().await
+    }
+
+    /// Let a project follow the active account again
+    pub async fn unpin_project(&mut self, project_id: &str) -> CloudResult<()> {
+        if self
+            .credentials
+            .project_accounts
+            .remove(project_id)
+            .is_some()
+        {
+            self.save_credentials().await?;
+        }
+        Ok(())
+    }
+
+    /// Account a project is pinned to, if any
+    pub fn project_account(&self, project_id: &str) -> Option<&str> {
+        self.credentials
+            .project_accounts
+            .get(project_id)
+            .map(String::as_str)
+    }
+
+    /// An auth manager acting as the account a project is pinned to, or as the
+    /// active account when it isn't pinned. Nothing is persisted.
+    pub fn for_project(&self, project_id: &str) -> CloudResult<AuthManager> {
+        let mut manager = self.clone();
+        if let Some(account) = self.project_account(project_id) {
+            if !self.credentials.accounts.contains_key(account) {
+                return Err(CloudError::auth(format!(
+                    "Project {} is pinned to missing account '{}'",
+                    project_id, account
+                )));
+            }
+            manager.credentials.active = Some(account.to_string());
+        }
+        Ok(manager)
+    }
+
     /// Start OAuth flow by opening browser
     pub async fn start_oauth_flow(&self, api_base_url: &str) -> CloudResult<String> {
         // Generate a random state parameter for CSRF protection
@@ -162,13 +320,28 @@ impl AuthManager {
         Ok(state)
     }
 
-    /// Exchange authorization code for token
+    /// Exchange authorization code for a token of the active account
     pub async fn exchange_code(
         &mut self,
         auth_code: String,
         http_client: &reqwest::Client,
         api_base_url: &str,
     ) -> CloudResult<TokenInfo> {
+        let account = self.active_account().unwrap_or(DEFAULT_ACCOUNT).to_string();
+        self.exchange_code_for(&account, auth_code, http_client, api_base_url)
+            .await
+    }
+
+    /// Exchange authorization code for a token stored under `account`, which
+    /// becomes the active account
+    pub async fn exchange_code_for(
+        &mut self,
+        account: &str,
+        auth_code: String,
+        http_client: &reqwest::Client,
+        api_base_url: &str,
+    ) -> CloudResult<TokenInfo> {
+        validate_account_name(account)?;
         let url = format!("{}{}", api_base_url, TOKEN_EXCHANGE_URL);
         let request = AuthRequest { auth_code };
 
@@ -191,8 +364,7 @@ impl AuthManager {
         };
 
         // Save the token
-        self.save_token(&token_info).await?;
-        self.token_info = Some(token_info.clone());
+        self.set_account_token(account, token_info.clone()).await?;
 
         Ok(token_info)
     }
@@ -206,20 +378,38 @@ impl AuthManager {
         ))
     }
 
-    /// Clear stored token (logout)
+    /// Clear the active account's stored token (logout); another stored
+    /// account becomes active if there is one
     pub async fn logout(&mut self) -> CloudResult<()> {
-        if self.config_path.exists() {
-            fs::remove_file(&self.config_path).await?;
+        match self.credentials.active.clone() {
+            Some(active) if self.credentials.accounts.contains_key(&active) => {
+                self.remove_account(&active).await
+            }
+            _ => {
+                self.credentials.active = self.credentials.accounts.keys().next().cloned();
+                self.save_credentials().await
+            }
         }
-        self.token_info = None;
-        Ok(())
     }
 
     /// Update token information after successful auth
     pub async fn set_token(&mut self, token_info: TokenInfo) -> CloudResult<()> {
-        self.save_token(&token_info).await?;
-        self.token_info = Some(token_info);
-        Ok(())
+        let account = self.active_account().unwrap_or(DEFAULT_ACCOUNT).to_string();
+        self.set_account_token(&account, token_info).await
+    }
+
+    /// Store a token under a named account and make it the active account
+    pub async fn set_account_token(
+        &mut self,
+        account: &str,
+        token_info: TokenInfo,
+    ) -> CloudResult<()> {
+        validate_account_name(account)?;
+        self.credentials
+            .accounts
+            .insert(account.to_string(), token_info);
+        self.credentials.active = Some(account.to_string());
+        self.save_credentials().await
     }
 }
 
@@ -328,6 +518,79 @@ mod tests {
         assert!(valid_token.is_valid());
     }
 
+    fn token(email: &str) -> TokenInfo {
+        TokenInfo {
+            token: format!("token-{}", email),
+            expires_at: Utc::now() + Duration::hours(1),
+            user_email: email.to_string(),
+            user_name: "Test User".to_string(),
+            user_id: "123".to_string(),
+        }
+    }
+
+    fn manager_in(dir: &std::path::Path) -> AuthManager {
+        AuthManager {
+            config_path: dir.join("auth.toml"),
+            credentials: CredentialStore::default(),
+        }
+    }
+
+    #[test]
+    fn test_legacy_single_token_file_migrates_to_default_account() {
+        let content = toml::to_string_pretty(&token("me@example.com")).unwrap();
+        let store = CredentialStore::parse(&content).unwrap();
+
+        assert_eq!(store.active.as_deref(), Some(DEFAULT_ACCOUNT));
+        assert_eq!(store.accounts[DEFAULT_ACCOUNT].user_email, "me@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_switch_accounts_and_pin_projects() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manager = manager_in(dir.path());
+
+        manager
+            .set_account_token("work", token("me@work.com"))
+            .await
+            .unwrap();
+        manager
+            .set_account_token("personal", token("me@home.com"))
+            .await
+            .unwrap();
+        assert_eq!(manager.active_account(), Some("personal"));
+
+        manager.switch_account("work").await.unwrap();
+        assert_eq!(
+            manager.get_valid_token().await.unwrap(),
+            "token-me@work.com"
+        );
+        assert!(manager.switch_account("missing").await.is_err());
+
+        manager.pin_project("proj-1", "personal").await.unwrap();
+        let pinned = manager.for_project("proj-1").unwrap();
+        assert_eq!(pinned.get_valid_token().await.unwrap(), "token-me@home.com");
+        let unpinned = manager.for_project("proj-2").unwrap();
+        assert_eq!(
+            unpinned.get_valid_token().await.unwrap(),
+            "token-me@work.com"
+        );
+
+        // Reloading from disk keeps accounts, the active one, and pins
+        let mut reloaded = manager_in(dir.path());
+        reloaded.init().await.unwrap();
+        assert_eq!(reloaded.active_account(), Some("work"));
+        assert_eq!(reloaded.project_account("proj-1"), Some("personal"));
+        assert_eq!(reloaded.accounts().len(), 2);
+
+        // Removing an account unpins its projects
+        reloaded.remove_account("personal").await.unwrap();
+        assert_eq!(reloaded.project_account("proj-1"), None);
+        assert!(reloaded
+            .set_account_token("bad name", token("x"))
+            .await
+            .is_err());
+    }
+
     #[test]
     fn test_extract_auth_code() {
         let request =
@@ -1,20 +1,31 @@
 //! HTTP client implementation for Orkee Cloud API
 
-use reqwest::{header, Client, Response, StatusCode};
+use reqwest::{header, Client, Method, Response, StatusCode};
 use serde::de::DeserializeOwned;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::{
     api::*,
     auth::AuthManager,
     error::{CloudError, CloudResult},
+    retry::{is_idempotent, is_transient_status, CircuitBreaker, RetryPolicy},
 };
 
+/// Body sent with a request
+enum RequestBody {
+    Json(serde_json::Value),
+    Bytes(Vec<u8>),
+}
+
 /// HTTP client for Orkee Cloud API
+#[derive(Clone)]
 pub struct HttpClient {
     client: Client,
     base_url: String,
     auth_manager: AuthManager,
+    retry_policy: RetryPolicy,
+    circuit_breaker: Arc<CircuitBreaker>,
 }
 
 impl HttpClient {
@@ -30,26 +41,34 @@ impl HttpClient {
             client,
             base_url,
             auth_manager,
+            retry_policy: RetryPolicy::from_env(),
+            circuit_breaker: Arc::new(CircuitBreaker::default()),
         })
     }
 
+    /// Use a specific retry policy instead of the environment-derived default
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Share a circuit breaker with other clients for the same service
+    pub fn with_circuit_breaker(mut self, circuit_breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = circuit_breaker;
+        self
+    }
+
+    /// The circuit breaker guarding this client's requests
+    pub fn circuit_breaker(&self) -> &Arc<CircuitBreaker> {
+        &self.circuit_breaker
+    }
+
     /// Make an authenticated GET request
     pub async fn get<T>(&self, path: &str) -> CloudResult<T>
     where
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let token = self.auth_manager.get_valid_token().await?;
-
-        let response = self
-            .client
-            .get(&url)
-            .header(header::AUTHORIZATION, format!("Bearer {}", token))
-            .header(header::CONTENT_TYPE, "application/json")
-            .send()
-            .await
-            .map_err(CloudError::http)?;
-
+        let response = self.send(Method::GET, path, None).await?;
         self.handle_response(response).await
     }
 
@@ -59,19 +78,8 @@ impl HttpClient {
         B: serde::Serialize,
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let token = self.auth_manager.get_valid_token().await?;
-
-        let response = self
-            .client
-            .post(&url)
-            .header(header::AUTHORIZATION, format!("Bearer {}", token))
-            .header(header::CONTENT_TYPE, "application/json")
-            .json(body)
-            .send()
-            .await
-            .map_err(CloudError::http)?;
-
+        let body = RequestBody::Json(serde_json::to_value(body)?);
+        let response = self.send(Method::POST, path, Some(body)).await?;
         self.handle_response(response).await
     }
 
@@ -81,19 +89,19 @@ impl HttpClient {
         B: serde::Serialize,
         T: DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let token = self.auth_manager.get_valid_token().await?;
+        let body = RequestBody::Json(serde_json::to_value(body)?);
+        let response = self.send(Method::PATCH, path, Some(body)).await?;
+        self.handle_response(response).await
+    }
 
+    /// Make an authenticated PUT request with a raw binary body
+    pub async fn put_bytes<T>(&self, path: &str, bytes: Vec<u8>) -> CloudResult<T>
+    where
+        T: DeserializeOwned,
+    {
         let response = self
-            .client
-            .patch(&url)
-            .header(header::AUTHORIZATION, format!("Bearer {}", token))
-            .header(header::CONTENT_TYPE, "application/json")
-            .json(body)
-            .send()
-            .await
-            .map_err(CloudError::http)?;
-
+            .send(Method::PUT, path, Some(RequestBody::Bytes(bytes)))
+            .await?;
         self.handle_response(response).await
     }
 
@@ -102,19 +110,85 @@ impl HttpClient {
     where
         T: DeserializeOwned,
     {
+        let response = self.send(Method::DELETE, path, None).await?;
+        self.handle_response(response).await
+    }
+
+    /// Send a request through the circuit breaker, retrying idempotent methods on
+    /// network errors, rate limits, and server errors
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<RequestBody>,
+    ) -> CloudResult<Response> {
         let url = format!("{}{}", self.base_url, path);
-        let token = self.auth_manager.get_valid_token().await?;
+        let max_retries = if is_idempotent(&method) {
+            self.retry_policy.max_retries
+        } else {
+            0
+        };
+        let mut attempt = 0;
 
-        let response = self
-            .client
-            .delete(&url)
-            .header(header::AUTHORIZATION, format!("Bearer {}", token))
-            .header(header::CONTENT_TYPE, "application/json")
-            .send()
-            .await
-            .map_err(CloudError::http)?;
+        loop {
+            // Fetch the token first so an auth failure never claims a half-open probe
+            let token = self.auth_manager.get_valid_token().await?;
+            if !self.circuit_breaker.allow_request() {
+                return Err(CloudError::Unavailable(
+                    "too many recent failures, requests are paused".to_string(),
+                ));
+            }
 
-        self.handle_response(response).await
+            let request = self
+                .client
+                .request(method.clone(), &url)
+                .header(header::AUTHORIZATION, format!("Bearer {}", token));
+            let request = match &body {
+                Some(RequestBody::Json(body)) => request.json(body),
+                Some(RequestBody::Bytes(bytes)) => request
+                    .header(header::CONTENT_TYPE, "application/octet-stream")
+                    .body(bytes.clone()),
+                None => request.header(header::CONTENT_TYPE, "application/json"),
+            };
+
+            let (failure, retry_after) = match request.send().await {
+                Ok(response) if is_transient_status(response.status()) => {
+                    let retry_after = retry_after(&response);
+                    if attempt >= max_retries {
+                        self.circuit_breaker
+                            .record_failure(format!("HTTP {}", response.status()));
+                        return Ok(response);
+                    }
+                    (format!("HTTP {}", response.status()), retry_after)
+                }
+                Ok(response) => {
+                    self.circuit_breaker.record_success();
+                    return Ok(response);
+                }
+                Err(err) => {
+                    if attempt >= max_retries {
+                        self.circuit_breaker.record_failure(err.to_string());
+                        return Err(CloudError::http(err));
+                    }
+                    (err.to_string(), None)
+                }
+            };
+
+            self.circuit_breaker.record_failure(failure.as_str());
+            let delay = retry_after
+                .map(|delay| delay.min(self.retry_policy.max_delay))
+                .unwrap_or_else(|| self.retry_policy.backoff(attempt));
+            tracing::debug!(
+                "{} {} failed ({}), retrying in {:?}",
+                method,
+                path,
+                failure,
+                delay
+            );
+            self.circuit_breaker.record_retry(delay);
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
     }
 
     /// Handle HTTP response and parse JSON
@@ -138,6 +212,7 @@ impl HttpClient {
                 }
             }
             StatusCode::UNAUTHORIZED => Err(CloudError::TokenExpired),
+            StatusCode::FORBIDDEN => Err(CloudError::api("Permission denied")),
             StatusCode::NOT_FOUND => Err(CloudError::api("Resource not found")),
             StatusCode::TOO_MANY_REQUESTS => Err(CloudError::api(
                 "Rate limit exceeded. Please try again later",
@@ -171,6 +246,19 @@ impl HttpClient {
     }
 }
 
+/// Delay requested by a `Retry-After` header given in seconds
+fn retry_after(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
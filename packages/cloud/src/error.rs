@@ -30,6 +30,12 @@ pub enum CloudError {
 
     #[error("Project not found: {0}")]
     ProjectNotFound(String),
+
+    #[error("Project is read-only: {0}")]
+    ReadOnly(String),
+
+    #[error("Orkee Cloud unavailable: {0}")]
+    Unavailable(String),
 }
 
 impl CloudError {
@@ -55,7 +61,7 @@ impl CloudError {
 
     /// Check if this is a network-related error
     pub fn is_network_error(&self) -> bool {
-        matches!(self, CloudError::Network(_))
+        matches!(self, CloudError::Network(_) | CloudError::Unavailable(_))
     }
 
     /// Check if this is an authentication error
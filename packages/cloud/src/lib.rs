@@ -5,6 +5,8 @@
 //! - OAuth authentication with browser-based flow
 //! - Project synchronization and backup
 //! - Token management and automatic refresh
+//! - Chunked, resumable snapshot uploads with an optional bandwidth cap
+//! - Resumable multipart snapshot uploads to S3-compatible buckets
 //! - Simple, direct API integration
 
 pub mod api;
@@ -13,26 +15,47 @@ pub mod client;
 pub mod config;
 pub mod encryption;
 pub mod error;
+pub mod merge;
+pub mod retry;
+pub mod s3;
+pub mod snapshot;
 pub mod types;
+pub mod upload;
 
 // Re-export main types
 pub use api::{
     ApiError, AuthResponse, CloudProject, ConflictReport, ConflictResolution, ConflictStrategy,
-    FieldConflict, FieldResolution, GitRepositoryInfo, ProjectDiff, Usage, User,
+    FieldConflict, FieldResolution, GitRepositoryInfo, OrgRole, Organization, ProjectAuthor,
+    ProjectDiff, ProjectShare, SharePermission, Usage, User,
 };
-pub use auth::{AuthManager, CallbackServer, TokenInfo};
+pub use auth::{AccountSummary, AuthManager, CallbackServer, TokenInfo, DEFAULT_ACCOUNT};
 pub use client::HttpClient;
 pub use error::{CloudError, CloudResult};
+pub use merge::{three_way_merge, MergeConflict, MergeOutcome};
+pub use retry::{
+    CircuitBreaker, CircuitBreakerConfig, CircuitState, ConnectionHealth, RetryPolicy,
+};
+pub use s3::{S3Config, S3Credentials, S3Uploader};
+pub use snapshot::{
+    FieldChange, RestoreSelection, SelectiveRestore, SnapshotDiff, SnapshotInfo, TaskChange,
+    TaskChangeKind,
+};
 pub use types::*;
+pub use upload::{UploadOptions, UploadState, UploadStateStore};
 
-use api::{ListProjectsResponse, RestoreResponse};
+use api::{ListOrganizationsResponse, ListProjectsResponse, RestoreResponse, ShareProjectRequest};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use snapshot::ListSnapshotsResponse;
+use std::borrow::Cow;
+use std::sync::Arc;
 
 /// Main cloud client for interacting with Orkee Cloud
 pub struct CloudClient {
     http_client: HttpClient,
     auth_manager: AuthManager,
     api_base_url: String,
+    upload_options: UploadOptions,
+    upload_store: UploadStateStore,
 }
 
 impl CloudClient {
@@ -46,9 +69,32 @@ impl CloudClient {
             http_client,
             auth_manager,
             api_base_url,
+            upload_options: UploadOptions::from_env(),
+            upload_store: UploadStateStore::default_location()?,
         })
     }
 
+    /// Use specific snapshot upload settings instead of the environment-derived defaults
+    pub fn set_upload_options(&mut self, upload_options: UploadOptions) {
+        self.upload_options = upload_options;
+    }
+
+    /// Create a cloud client whose requests share an existing circuit breaker, so
+    /// outage state survives across short-lived clients
+    pub async fn with_circuit_breaker(
+        api_base_url: String,
+        circuit_breaker: Arc<CircuitBreaker>,
+    ) -> CloudResult<Self> {
+        let mut client = Self::new(api_base_url).await?;
+        client.http_client = client.http_client.with_circuit_breaker(circuit_breaker);
+        Ok(client)
+    }
+
+    /// Current retry and circuit breaker state
+    pub fn connection_health(&self) -> ConnectionHealth {
+        self.http_client.circuit_breaker().health()
+    }
+
     /// Check if user is authenticated
     pub fn is_authenticated(&self) -> bool {
         self.auth_manager.is_authenticated()
@@ -61,8 +107,19 @@ impl CloudClient {
             .map(|(id, email, name)| (id.to_string(), email.to_string(), name.to_string()))
     }
 
-    /// Perform OAuth login flow
+    /// Perform OAuth login flow for the active account
     pub async fn login(&mut self) -> CloudResult<TokenInfo> {
+        let account = self
+            .auth_manager
+            .active_account()
+            .unwrap_or(DEFAULT_ACCOUNT)
+            .to_string();
+        self.login_as(&account).await
+    }
+
+    /// Perform OAuth login flow, storing the credentials under a named account
+    /// that becomes the active one
+    pub async fn login_as(&mut self, account: &str) -> CloudResult<TokenInfo> {
         println!("🚀 Starting Orkee Cloud authentication...");
 
         // Start OAuth flow
@@ -81,32 +138,98 @@ impl CloudClient {
         let http_client = reqwest::Client::new();
         let token_info = self
             .auth_manager
-            .exchange_code(auth_code, &http_client, &self.api_base_url)
+            .exchange_code_for(account, auth_code, &http_client, &self.api_base_url)
             .await?;
+        self.refresh_http_auth();
 
-        println!("🎉 Successfully authenticated as {}", token_info.user_name);
+        println!(
+            "🎉 Successfully authenticated as {} ({})",
+            token_info.user_name, account
+        );
         Ok(token_info)
     }
 
-    /// Logout and clear stored token
+    /// Logout the active account and clear its stored token
     pub async fn logout(&mut self) -> CloudResult<()> {
         self.auth_manager.logout().await?;
+        self.refresh_http_auth();
         println!("👋 Logged out from Orkee Cloud");
         Ok(())
     }
 
+    /// Stored accounts, sorted by name
+    pub fn accounts(&self) -> Vec<AccountSummary> {
+        self.auth_manager.accounts()
+    }
+
+    /// Name of the account unpinned requests are made as
+    pub fn active_account(&self) -> Option<&str> {
+        self.auth_manager.active_account()
+    }
+
+    /// Make a stored account the active one
+    pub async fn switch_account(&mut self, account: &str) -> CloudResult<()> {
+        self.auth_manager.switch_account(account).await?;
+        self.refresh_http_auth();
+        Ok(())
+    }
+
+    /// Forget a stored account
+    pub async fn remove_account(&mut self, account: &str) -> CloudResult<()> {
+        self.auth_manager.remove_account(account).await?;
+        self.refresh_http_auth();
+        Ok(())
+    }
+
+    /// Always sync a project as the given account
+    pub async fn pin_project(&mut self, project_id: &str, account: &str) -> CloudResult<()> {
+        self.auth_manager.pin_project(project_id, account).await
+    }
+
+    /// Let a project sync as the active account again
+    pub async fn unpin_project(&mut self, project_id: &str) -> CloudResult<()> {
+        self.auth_manager.unpin_project(project_id).await
+    }
+
+    /// Account a project is pinned to, if any
+    pub fn project_account(&self, project_id: &str) -> Option<&str> {
+        self.auth_manager.project_account(project_id)
+    }
+
+    /// Keep the HTTP client's credentials in step with account changes
+    fn refresh_http_auth(&mut self) {
+        *self.http_client.auth_manager_mut() = self.auth_manager.clone();
+    }
+
+    /// HTTP client authenticated as the account a project is pinned to
+    fn http_client_for(&self, project_id: &str) -> CloudResult<Cow<'_, HttpClient>> {
+        if self.auth_manager.project_account(project_id).is_none() {
+            return Ok(Cow::Borrowed(&self.http_client));
+        }
+        let mut client = self.http_client.clone();
+        *client.auth_manager_mut() = self.auth_manager.for_project(project_id)?;
+        Ok(Cow::Owned(client))
+    }
+
     /// List all projects in the cloud
     pub async fn list_projects(&self) -> CloudResult<Vec<CloudProject>> {
         let response: ListProjectsResponse = self.http_client.get("/api/projects").await?;
         Ok(response.projects)
     }
 
-    /// Sync a project to the cloud
+    /// Sync a project to the cloud, uploading `project_data` as a new snapshot
+    ///
+    /// Returns the snapshot ID, or the project ID when `project_data` is null.
     pub async fn sync_project(
         &self,
         cloud_project: CloudProject,
-        _project_data: serde_json::Value,
+        project_data: serde_json::Value,
     ) -> CloudResult<String> {
+        // Teammates' read-only shares must not be overwritten from this machine
+        if cloud_project.read_only {
+            return Err(CloudError::ReadOnly(cloud_project.name));
+        }
+
         // Use the sync endpoint directly with full project data
         #[derive(serde::Serialize)]
         struct SyncRequest {
@@ -157,17 +280,46 @@ impl CloudClient {
         }
 
         let response: SyncResponse = self
-            .http_client
+            .http_client_for(&cloud_project.id)?
             .post("/api/projects/sync", &request)
             .await?;
 
-        Ok(response.project_id)
+        if project_data.is_null() {
+            return Ok(response.project_id);
+        }
+        self.upload_snapshot(&response.project_id, &project_data)
+            .await
+    }
+
+    /// Upload a project snapshot in chunks, resuming an interrupted upload of
+    /// the same snapshot and pacing it to the configured bandwidth cap
+    pub async fn upload_snapshot(
+        &self,
+        project_id: &str,
+        snapshot: &serde_json::Value,
+    ) -> CloudResult<String> {
+        let data = serde_json::to_vec(snapshot)?;
+        let http_client = self.http_client_for(project_id)?;
+        let response = upload::upload_snapshot(
+            &http_client,
+            &self.upload_store,
+            &self.upload_options,
+            project_id,
+            &data,
+        )
+        .await?;
+        Ok(response.snapshot_id)
+    }
+
+    /// Snapshot uploads that were interrupted and will resume on the next sync
+    pub async fn pending_uploads(&self) -> CloudResult<Vec<UploadState>> {
+        self.upload_store.list().await
     }
 
     /// Check for sync conflicts
     pub async fn check_conflicts(&self, project_id: &str) -> CloudResult<ConflictReport> {
         let response: ConflictReport = self
-            .http_client
+            .http_client_for(project_id)?
             .get(&format!("/api/projects/{}/conflicts", project_id))
             .await?;
         Ok(response)
@@ -179,7 +331,7 @@ impl CloudClient {
         project_id: &str,
         resolution: ConflictResolution,
     ) -> CloudResult<()> {
-        self.http_client
+        self.http_client_for(project_id)?
             .post::<_, ()>(
                 &format!("/api/projects/{}/resolve", project_id),
                 &resolution,
@@ -190,7 +342,7 @@ impl CloudClient {
 
     /// Incremental sync for changes only
     pub async fn sync_incremental(&self, project_id: &str, diff: ProjectDiff) -> CloudResult<()> {
-        self.http_client
+        self.http_client_for(project_id)?
             .patch::<_, ()>(&format!("/api/projects/{}/delta", project_id), &diff)
             .await?;
         Ok(())
@@ -199,7 +351,7 @@ impl CloudClient {
     /// Get full project with all fields
     pub async fn get_full_project(&self, project_id: &str) -> CloudResult<CloudProject> {
         let response: CloudProject = self
-            .http_client
+            .http_client_for(project_id)?
             .get(&format!("/api/projects/{}/full", project_id))
             .await?;
         Ok(response)
@@ -208,17 +360,9 @@ impl CloudClient {
     /// Restore a project from the cloud
     pub async fn restore_project(&self, project_id: &str) -> CloudResult<serde_json::Value> {
         let path = format!("/api/projects/{}", project_id);
-        let response: RestoreResponse = self.http_client.get(&path).await?;
-
-        // Decode project data
-        let project_bytes = BASE64
-            .decode(&response.snapshot_data)
-            .map_err(|e| CloudError::api(format!("Invalid snapshot data: {}", e)))?;
+        let response: RestoreResponse = self.http_client_for(project_id)?.get(&path).await?;
 
-        let project_json = String::from_utf8(project_bytes)
-            .map_err(|e| CloudError::api(format!("Invalid UTF-8 in snapshot: {}", e)))?;
-
-        let project_data: serde_json::Value = serde_json::from_str(&project_json)?;
+        let project_data = decode_snapshot_data(&response.snapshot_data)?;
 
         println!(
             "📥 Project '{}' restored successfully",
@@ -227,6 +371,125 @@ impl CloudClient {
         Ok(project_data)
     }
 
+    /// List the snapshot versions stored for a project, newest first
+    pub async fn list_snapshots(&self, project_id: &str) -> CloudResult<Vec<SnapshotInfo>> {
+        let response: ListSnapshotsResponse = self
+            .http_client_for(project_id)?
+            .get(&format!("/api/projects/{}/snapshots", project_id))
+            .await?;
+        Ok(response.snapshots)
+    }
+
+    /// Fetch and decode a specific snapshot version
+    pub async fn get_snapshot(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+    ) -> CloudResult<serde_json::Value> {
+        let response: RestoreResponse = self
+            .http_client_for(project_id)?
+            .get(&format!(
+                "/api/projects/{}/snapshots/{}",
+                project_id,
+                urlencoding::encode(snapshot_id)
+            ))
+            .await?;
+        decode_snapshot_data(&response.snapshot_data)
+    }
+
+    /// Diff a snapshot version against local project state (field-level)
+    pub async fn diff_snapshot(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+        local_state: &serde_json::Value,
+    ) -> CloudResult<SnapshotDiff> {
+        let snapshot = self.get_snapshot(project_id, snapshot_id).await?;
+        snapshot::diff_snapshot(&snapshot, local_state)
+    }
+
+    /// Fetch only the selected fields and tasks of a snapshot version so the
+    /// caller can apply them without replacing the whole project
+    pub async fn restore_selected(
+        &self,
+        project_id: &str,
+        snapshot_id: &str,
+        selection: &RestoreSelection,
+    ) -> CloudResult<SelectiveRestore> {
+        let snapshot = self.get_snapshot(project_id, snapshot_id).await?;
+        snapshot::select_from_snapshot(&snapshot, selection)
+    }
+
+    /// List the organizations the user belongs to
+    pub async fn list_organizations(&self) -> CloudResult<Vec<Organization>> {
+        let response: ListOrganizationsResponse = self.http_client.get("/api/orgs").await?;
+        Ok(response.organizations)
+    }
+
+    /// List projects shared with an organization, including ones synced by teammates
+    pub async fn list_org_projects(&self, org_id: &str) -> CloudResult<Vec<CloudProject>> {
+        let response: ListProjectsResponse = self
+            .http_client
+            .get(&format!(
+                "/api/orgs/{}/projects",
+                urlencoding::encode(org_id)
+            ))
+            .await?;
+        Ok(response.projects)
+    }
+
+    /// Share a project with an organization
+    pub async fn share_project(
+        &self,
+        project_id: &str,
+        org_id: &str,
+        permission: SharePermission,
+    ) -> CloudResult<ProjectShare> {
+        let request = ShareProjectRequest {
+            org_id: org_id.to_string(),
+            permission,
+        };
+        self.http_client_for(project_id)?
+            .post(&format!("/api/projects/{}/shares", project_id), &request)
+            .await
+    }
+
+    /// Stop sharing a project with an organization
+    pub async fn unshare_project(&self, project_id: &str, org_id: &str) -> CloudResult<()> {
+        self.http_client_for(project_id)?
+            .delete::<serde_json::Value>(&format!(
+                "/api/projects/{}/shares/{}",
+                project_id,
+                urlencoding::encode(org_id)
+            ))
+            .await?;
+        Ok(())
+    }
+
+    /// Three-way merge local state with the project's latest cloud snapshot,
+    /// using the snapshot local state was last synced from as the base
+    pub async fn merge_with_cloud(
+        &self,
+        project_id: &str,
+        base_snapshot_id: &str,
+        local_state: &serde_json::Value,
+    ) -> CloudResult<MergeOutcome> {
+        let latest = self
+            .list_snapshots(project_id)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| CloudError::ProjectNotFound(project_id.to_string()))?;
+
+        let base = self.get_snapshot(project_id, base_snapshot_id).await?;
+        let remote = if latest.snapshot_id == base_snapshot_id {
+            base.clone()
+        } else {
+            self.get_snapshot(project_id, &latest.snapshot_id).await?
+        };
+        merge::three_way_merge(&base, local_state, &remote)
+    }
+
     /// Get usage statistics
     pub async fn get_usage(&self) -> CloudResult<Usage> {
         self.http_client.get("/api/usage").await
@@ -242,6 +505,7 @@ impl CloudClient {
                 projects_count: 0,
                 last_sync: None,
                 subscription_tier: None,
+                connection: self.connection_health(),
             });
         }
 
@@ -255,12 +519,25 @@ impl CloudClient {
             projects_count: usage.projects_count,
             last_sync: None, // TODO: Track last sync time
             subscription_tier: Some(usage.subscription_tier),
+            connection: self.connection_health(),
         })
     }
 }
 
+/// Decode base64-encoded snapshot JSON
+fn decode_snapshot_data(snapshot_data: &str) -> CloudResult<serde_json::Value> {
+    let project_bytes = BASE64
+        .decode(snapshot_data)
+        .map_err(|e| CloudError::api(format!("Invalid snapshot data: {}", e)))?;
+
+    let project_json = String::from_utf8(project_bytes)
+        .map_err(|e| CloudError::api(format!("Invalid UTF-8 in snapshot: {}", e)))?;
+
+    Ok(serde_json::from_str(&project_json)?)
+}
+
 /// Cloud sync status information
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct CloudStatus {
     pub authenticated: bool,
     pub user_email: Option<String>,
@@ -268,6 +545,7 @@ pub struct CloudStatus {
     pub projects_count: usize,
     pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
     pub subscription_tier: Option<String>,
+    pub connection: ConnectionHealth,
 }
 
 /// Legacy compatibility functions (from old CloudConfigBuilder)
@@ -0,0 +1,547 @@
+//! Three-way merge of project documents for sync
+//!
+//! Compares the local and remote project documents against the snapshot they
+//! both started from (the base). Fields changed on only one side are taken
+//! from that side, tasks are merged by id, and only fields changed differently
+//! on both sides are reported as conflicts. Conflicts are addressed by path:
+//! a project field name, `tasks.<id>.<field>` for a task field, or
+//! `tasks.<id>` when one side deleted a task the other side modified.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::api::{ConflictResolution, ConflictStrategy};
+use crate::error::{CloudError, CloudResult};
+use crate::snapshot::{as_object, is_diffable, tasks_by_id, TASKS_KEY};
+
+/// A field changed differently on both sides since the base.
+/// Absent values are represented as null.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeConflict {
+    pub field: String,
+    #[serde(default)]
+    pub task_id: Option<String>,
+    pub base_value: Value,
+    pub local_value: Value,
+    pub remote_value: Value,
+}
+
+/// Result of a three-way merge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MergeOutcome {
+    /// Merged document. Conflicting paths hold the local value until resolved.
+    pub merged: Value,
+    /// Paths taken from the remote side without conflict
+    pub auto_merged: Vec<String>,
+    pub conflicts: Vec<MergeConflict>,
+    /// Remote document, kept so conflicts can be resolved in its favour
+    #[serde(skip)]
+    remote: Value,
+}
+
+impl MergeOutcome {
+    pub fn has_conflicts(&self) -> bool {
+        !self.conflicts.is_empty()
+    }
+
+    /// Apply a conflict resolution and return the final document.
+    ///
+    /// `LocalWins`, `CloudWins` and `Newest` settle every conflict at once;
+    /// `Merge` and `Manual` require a field resolution for each conflict.
+    /// Field resolutions always take precedence over the strategy.
+    pub fn resolve(&self, resolution: &ConflictResolution) -> CloudResult<Value> {
+        let field_resolutions = resolution.field_resolutions.as_deref().unwrap_or_default();
+        let default_side = match resolution.strategy {
+            ConflictStrategy::LocalWins => Some(Side::Local),
+            ConflictStrategy::CloudWins => Some(Side::Remote),
+            ConflictStrategy::Newest => Some(newest_side(&self.merged, &self.remote)),
+            ConflictStrategy::Merge | ConflictStrategy::Manual => None,
+        };
+
+        let mut merged = self.merged.clone();
+        for conflict in &self.conflicts {
+            let side = match field_resolutions.iter().find(|r| r.field == conflict.field) {
+                Some(r) => Side::parse(&r.use_value)?,
+                None => default_side.ok_or_else(|| {
+                    CloudError::api(format!("No resolution for conflict '{}'", conflict.field))
+                })?,
+            };
+            if side == Side::Remote {
+                apply_remote(&mut merged, conflict)?;
+            }
+        }
+
+        for resolution in field_resolutions {
+            if !self.conflicts.iter().any(|c| c.field == resolution.field) {
+                return Err(CloudError::api(format!(
+                    "'{}' is not a conflicting field",
+                    resolution.field
+                )));
+            }
+        }
+
+        Ok(merged)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Local,
+    Remote,
+}
+
+impl Side {
+    fn parse(value: &str) -> CloudResult<Self> {
+        match value {
+            "local" => Ok(Side::Local),
+            "cloud" => Ok(Side::Remote),
+            other => Err(CloudError::api(format!(
+                "Unknown resolution '{}', expected 'local' or 'cloud'",
+                other
+            ))),
+        }
+    }
+}
+
+/// Merge the local and remote documents against their common base
+pub fn three_way_merge(base: &Value, local: &Value, remote: &Value) -> CloudResult<MergeOutcome> {
+    let base = as_object(base, "Base snapshot")?;
+    let local = as_object(local, "Local project state")?;
+    let remote = as_object(remote, "Remote project state")?;
+
+    let mut merged = local.clone();
+    let mut auto_merged = Vec::new();
+    let mut conflicts = Vec::new();
+
+    merge_fields(
+        base,
+        local,
+        remote,
+        &mut merged,
+        None,
+        &mut auto_merged,
+        &mut conflicts,
+    );
+
+    let tasks = merge_tasks(base, local, remote, &mut auto_merged, &mut conflicts);
+    if !tasks.is_empty() || merged.contains_key(TASKS_KEY) || remote.contains_key(TASKS_KEY) {
+        merged.insert(TASKS_KEY.to_string(), Value::Array(tasks));
+    }
+
+    Ok(MergeOutcome {
+        merged: Value::Object(merged),
+        auto_merged,
+        conflicts,
+        remote: Value::Object(remote.clone()),
+    })
+}
+
+/// Merge the diffable fields of one object into `merged`, which starts as the local copy
+fn merge_fields(
+    base: &Map<String, Value>,
+    local: &Map<String, Value>,
+    remote: &Map<String, Value>,
+    merged: &mut Map<String, Value>,
+    task_id: Option<&str>,
+    auto_merged: &mut Vec<String>,
+    conflicts: &mut Vec<MergeConflict>,
+) {
+    let mut names: Vec<&String> = base
+        .keys()
+        .chain(local.keys())
+        .chain(remote.keys())
+        .filter(|k| is_diffable(k))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    for name in names {
+        let base_value = base.get(name);
+        let local_value = local.get(name);
+        let remote_value = remote.get(name);
+
+        if local_value == remote_value || remote_value == base_value {
+            continue;
+        }
+
+        let path = match task_id {
+            Some(id) => format!("{}.{}.{}", TASKS_KEY, id, name),
+            None => name.clone(),
+        };
+
+        if local_value == base_value {
+            match remote_value {
+                Some(value) => merged.insert(name.clone(), value.clone()),
+                None => merged.remove(name),
+            };
+            auto_merged.push(path);
+        } else {
+            conflicts.push(MergeConflict {
+                field: path,
+                task_id: task_id.map(String::from),
+                base_value: base_value.cloned().unwrap_or(Value::Null),
+                local_value: local_value.cloned().unwrap_or(Value::Null),
+                remote_value: remote_value.cloned().unwrap_or(Value::Null),
+            });
+        }
+    }
+}
+
+/// Merge task lists by id. Local order is kept, with remote-only tasks appended.
+fn merge_tasks(
+    base: &Map<String, Value>,
+    local: &Map<String, Value>,
+    remote: &Map<String, Value>,
+    auto_merged: &mut Vec<String>,
+    conflicts: &mut Vec<MergeConflict>,
+) -> Vec<Value> {
+    let base_tasks = tasks_by_id(base);
+    let local_tasks = tasks_by_id(local);
+    let remote_tasks = tasks_by_id(remote);
+    let find = |tasks: &[(String, &Map<String, Value>)], id: &str| {
+        tasks
+            .iter()
+            .find(|(task_id, _)| task_id == id)
+            .map(|(_, task)| (*task).clone())
+    };
+
+    let mut ids: Vec<&String> = local_tasks.iter().map(|(id, _)| id).collect();
+    for (id, _) in remote_tasks.iter().chain(base_tasks.iter()) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    let mut tasks = Vec::new();
+    for id in ids {
+        let base_task = find(&base_tasks, id);
+        let local_task = find(&local_tasks, id);
+        let remote_task = find(&remote_tasks, id);
+
+        match (local_task, remote_task) {
+            (Some(local_task), Some(remote_task)) => {
+                let mut merged = local_task.clone();
+                merge_fields(
+                    &base_task.unwrap_or_default(),
+                    &local_task,
+                    &remote_task,
+                    &mut merged,
+                    Some(id),
+                    auto_merged,
+                    conflicts,
+                );
+                tasks.push(Value::Object(merged));
+            }
+            (Some(local_task), None) => match base_task {
+                // Created locally
+                None => tasks.push(Value::Object(local_task)),
+                // Deleted remotely and untouched locally
+                Some(base_task) if same_content(&base_task, &local_task) => {
+                    auto_merged.push(format!("{}.{}", TASKS_KEY, id));
+                }
+                Some(base_task) => {
+                    conflicts.push(task_conflict(id, base_task, Some(local_task.clone()), None));
+                    tasks.push(Value::Object(local_task));
+                }
+            },
+            (None, Some(remote_task)) => match base_task {
+                // Created remotely
+                None => {
+                    auto_merged.push(format!("{}.{}", TASKS_KEY, id));
+                    tasks.push(Value::Object(remote_task));
+                }
+                // Deleted locally and untouched remotely
+                Some(base_task) if same_content(&base_task, &remote_task) => {}
+                Some(base_task) => {
+                    conflicts.push(task_conflict(id, base_task, None, Some(remote_task)));
+                }
+            },
+            // Deleted on both sides
+            (None, None) => {}
+        }
+    }
+
+    tasks
+}
+
+/// Tasks are equal when their diffable fields match; timestamps are ignored
+fn same_content(a: &Map<String, Value>, b: &Map<String, Value>) -> bool {
+    a.keys()
+        .chain(b.keys())
+        .filter(|k| is_diffable(k))
+        .all(|k| a.get(k) == b.get(k))
+}
+
+/// A task deleted on one side and modified on the other
+fn task_conflict(
+    id: &str,
+    base: Map<String, Value>,
+    local: Option<Map<String, Value>>,
+    remote: Option<Map<String, Value>>,
+) -> MergeConflict {
+    MergeConflict {
+        field: format!("{}.{}", TASKS_KEY, id),
+        task_id: Some(id.to_string()),
+        base_value: Value::Object(base),
+        local_value: local.map(Value::Object).unwrap_or(Value::Null),
+        remote_value: remote.map(Value::Object).unwrap_or(Value::Null),
+    }
+}
+
+/// Replace the conflicting path in the merged document with the remote value
+fn apply_remote(merged: &mut Value, conflict: &MergeConflict) -> CloudResult<()> {
+    let document = merged
+        .as_object_mut()
+        .ok_or_else(|| CloudError::api("Merged document is not a JSON object"))?;
+
+    let Some(task_id) = &conflict.task_id else {
+        set_or_remove(document, &conflict.field, &conflict.remote_value);
+        return Ok(());
+    };
+
+    let tasks = document
+        .entry(TASKS_KEY)
+        .or_insert_with(|| Value::Array(Vec::new()))
+        .as_array_mut()
+        .ok_or_else(|| CloudError::api("Merged tasks are not a JSON array"))?;
+    let position = tasks
+        .iter()
+        .position(|t| t.get("id").and_then(|id| id.as_str()) == Some(task_id));
+
+    let task_prefix = format!("{}.{}", TASKS_KEY, task_id);
+    if conflict.field == task_prefix {
+        // Whole-task conflict: keep the remote task, or drop it if remote deleted it
+        match (position, &conflict.remote_value) {
+            (Some(i), Value::Null) => {
+                tasks.remove(i);
+            }
+            (Some(i), remote) => tasks[i] = remote.clone(),
+            (None, Value::Null) => {}
+            (None, remote) => tasks.push(remote.clone()),
+        }
+        return Ok(());
+    }
+
+    let field = &conflict.field[task_prefix.len() + 1..];
+    let task = position
+        .and_then(|i| tasks[i].as_object_mut())
+        .ok_or_else(|| CloudError::api(format!("Merged document has no task '{}'", task_id)))?;
+    set_or_remove(task, field, &conflict.remote_value);
+    Ok(())
+}
+
+fn set_or_remove(object: &mut Map<String, Value>, field: &str, value: &Value) {
+    if value.is_null() {
+        object.remove(field);
+    } else {
+        object.insert(field.to_string(), value.clone());
+    }
+}
+
+/// Pick the side whose project document was updated most recently; ties go to local
+fn newest_side(local: &Value, remote: &Value) -> Side {
+    match (updated_at(local), updated_at(remote)) {
+        (Some(local), Some(remote)) if remote > local => Side::Remote,
+        (None, Some(_)) => Side::Remote,
+        _ => Side::Local,
+    }
+}
+
+fn updated_at(document: &Value) -> Option<DateTime<Utc>> {
+    document
+        .get("updatedAt")
+        .or_else(|| document.get("updated_at"))
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::FieldResolution;
+    use serde_json::json;
+
+    fn base() -> Value {
+        json!({
+            "id": "proj1234",
+            "name": "Orkee",
+            "description": "Original",
+            "devScript": "pnpm dev",
+            "tags": ["rust"],
+            "updatedAt": "2025-01-01T00:00:00Z",
+            "tasks": [
+                { "id": "task-one", "title": "Write docs", "status": "pending" },
+                { "id": "task-two", "title": "Ship it", "status": "pending" },
+                { "id": "task-three", "title": "Refactor", "status": "pending" }
+            ]
+        })
+    }
+
+    fn resolution(strategy: ConflictStrategy, fields: Vec<(&str, &str)>) -> ConflictResolution {
+        ConflictResolution {
+            strategy,
+            field_resolutions: Some(
+                fields
+                    .into_iter()
+                    .map(|(field, use_value)| FieldResolution {
+                        field: field.to_string(),
+                        use_value: use_value.to_string(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_non_overlapping_changes_merge_cleanly() {
+        let mut local = base();
+        local["description"] = json!("Local description");
+        local["tasks"][0]["status"] = json!("done");
+        local["tasks"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({ "id": "task-local", "title": "Local task" }));
+
+        let mut remote = base();
+        remote["devScript"] = json!("bun dev");
+        remote["tasks"][1]["title"] = json!("Ship it today");
+        remote["tasks"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({ "id": "task-remote", "title": "Remote task" }));
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+        assert!(!outcome.has_conflicts());
+
+        let merged = &outcome.merged;
+        assert_eq!(merged["description"], "Local description");
+        assert_eq!(merged["devScript"], "bun dev");
+        let ids: Vec<&str> = merged["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "task-one",
+                "task-two",
+                "task-three",
+                "task-local",
+                "task-remote"
+            ]
+        );
+        assert_eq!(merged["tasks"][0]["status"], "done");
+        assert_eq!(merged["tasks"][1]["title"], "Ship it today");
+        assert_eq!(
+            outcome.auto_merged,
+            vec!["devScript", "tasks.task-two.title", "tasks.task-remote"]
+        );
+    }
+
+    #[test]
+    fn test_same_change_on_both_sides_is_not_a_conflict() {
+        let mut local = base();
+        local["tags"] = json!(["rust", "cli"]);
+        let remote = local.clone();
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+        assert!(!outcome.has_conflicts());
+        assert_eq!(outcome.merged["tags"], json!(["rust", "cli"]));
+    }
+
+    #[test]
+    fn test_overlapping_changes_are_conflicts() {
+        let mut local = base();
+        local["description"] = json!("Local");
+        local["tasks"][0]["status"] = json!("done");
+
+        let mut remote = base();
+        remote["description"] = json!("Remote");
+        remote["tasks"][0]["status"] = json!("in-progress");
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+        let fields: Vec<&str> = outcome.conflicts.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["description", "tasks.task-one.status"]);
+        assert_eq!(outcome.conflicts[0].base_value, "Original");
+        assert_eq!(outcome.conflicts[1].task_id.as_deref(), Some("task-one"));
+        // Local values hold the conflicting paths until resolved
+        assert_eq!(outcome.merged["description"], "Local");
+    }
+
+    #[test]
+    fn test_task_deletions() {
+        // Local deletes an untouched task; remote deletes a task local modified
+        let mut local = base();
+        local["tasks"].as_array_mut().unwrap().remove(2);
+        local["tasks"][1]["status"] = json!("done");
+
+        let mut remote = base();
+        remote["tasks"].as_array_mut().unwrap().remove(1);
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+        assert_eq!(outcome.conflicts.len(), 1);
+        assert_eq!(outcome.conflicts[0].field, "tasks.task-two");
+        assert_eq!(outcome.conflicts[0].remote_value, Value::Null);
+
+        let ids: Vec<&str> = outcome.merged["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["task-one", "task-two"]);
+
+        let resolved = outcome
+            .resolve(&resolution(ConflictStrategy::CloudWins, vec![]))
+            .unwrap();
+        assert_eq!(resolved["tasks"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_resolve_with_field_resolutions() {
+        let mut local = base();
+        local["description"] = json!("Local");
+        local["name"] = json!("Local name");
+
+        let mut remote = base();
+        remote["description"] = json!("Remote");
+        remote["name"] = json!("Remote name");
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+
+        let manual = resolution(ConflictStrategy::Manual, vec![("description", "cloud")]);
+        assert!(outcome.resolve(&manual).is_err());
+
+        let manual = resolution(
+            ConflictStrategy::Manual,
+            vec![("description", "cloud"), ("name", "local")],
+        );
+        let resolved = outcome.resolve(&manual).unwrap();
+        assert_eq!(resolved["description"], "Remote");
+        assert_eq!(resolved["name"], "Local name");
+
+        let unknown = resolution(ConflictStrategy::LocalWins, vec![("tags", "cloud")]);
+        assert!(outcome.resolve(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_newest_strategy_uses_updated_at() {
+        let mut local = base();
+        local["description"] = json!("Local");
+        local["updatedAt"] = json!("2025-02-01T00:00:00Z");
+
+        let mut remote = base();
+        remote["description"] = json!("Remote");
+        remote["updatedAt"] = json!("2025-03-01T00:00:00Z");
+
+        let outcome = three_way_merge(&base(), &local, &remote).unwrap();
+        let resolved = outcome
+            .resolve(&resolution(ConflictStrategy::Newest, vec![]))
+            .unwrap();
+        assert_eq!(resolved["description"], "Remote");
+    }
+}
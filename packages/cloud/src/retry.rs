@@ -0,0 +1,307 @@
+//! Retry with exponential backoff and a circuit breaker for Orkee Cloud requests
+//!
+//! Idempotent requests that fail with a network error, a 5xx, or a 429 are retried
+//! after an exponentially growing, jittered delay. Consecutive failures open the
+//! circuit, after which requests fail fast until a cool-down has passed and a
+//! single probe request succeeds.
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How failed requests are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt; 0 disables retrying
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for each one after
+    pub base_delay: Duration,
+    /// Upper bound on any single delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Default policy, overridden by `ORKEE_CLOUD_MAX_RETRIES` and `ORKEE_CLOUD_RETRY_BASE_MS`
+    pub fn from_env() -> Self {
+        let mut policy = Self::default();
+        if let Some(retries) = env_number("ORKEE_CLOUD_MAX_RETRIES") {
+            policy.max_retries = retries as u32;
+        }
+        if let Some(base_ms) = env_number("ORKEE_CLOUD_RETRY_BASE_MS") {
+            policy.base_delay = Duration::from_millis(base_ms);
+        }
+        policy
+    }
+
+    /// Delay before retry number `attempt` (0-based): half of the exponential
+    /// backoff plus a random share of the other half, so clients don't retry in step
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        let half = exponential / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+pub(crate) fn env_number(name: &str) -> Option<u64> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
+
+/// When the circuit opens and how long it stays open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long requests fail fast before a probe is let through
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+        }
+    }
+}
+
+/// State of the circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// Requests flow normally
+    Closed,
+    /// Requests fail fast
+    Open,
+    /// One probe request is allowed to test whether the service recovered
+    HalfOpen,
+}
+
+/// Retry and circuit breaker state, for status indicators
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionHealth {
+    pub circuit: CircuitState,
+    pub consecutive_failures: u32,
+    /// Retries performed since the client was created
+    pub total_retries: u64,
+    pub last_retry_at: Option<DateTime<Utc>>,
+    /// When the next retry or probe is due, while backing off or open
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    /// Set while open or half-open
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+    total_retries: u64,
+    last_retry_at: Option<DateTime<Utc>>,
+    next_attempt_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+}
+
+/// Circuit breaker shared by every request to one Orkee Cloud instance
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        // The state stays consistent even if a holder panicked
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn circuit(&self, state: &BreakerState) -> CircuitState {
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(opened) if opened.elapsed() < self.config.open_duration => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Whether a request may be sent now. While half-open only one probe is allowed.
+    pub fn allow_request(&self) -> bool {
+        let mut state = self.lock();
+        match self.circuit(&state) {
+            CircuitState::Closed => true,
+            CircuitState::Open => false,
+            CircuitState::HalfOpen if state.probe_in_flight => false,
+            CircuitState::HalfOpen => {
+                state.probe_in_flight = true;
+                true
+            }
+        }
+    }
+
+    /// The service answered; close the circuit
+    pub fn record_success(&self) {
+        let mut state = self.lock();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+        state.probe_in_flight = false;
+        state.next_attempt_at = None;
+        state.last_error = None;
+    }
+
+    /// The service failed or was unreachable; opens the circuit at the threshold
+    /// or when a half-open probe fails
+    pub fn record_failure(&self, error: impl Into<String>) {
+        let mut state = self.lock();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        state.last_error = Some(error.into());
+
+        let probe_failed = state.probe_in_flight;
+        state.probe_in_flight = false;
+        if probe_failed || state.consecutive_failures >= self.config.failure_threshold {
+            state.opened_at = Some(Instant::now());
+            state.next_attempt_at = Some(Utc::now() + to_chrono(self.config.open_duration));
+        }
+    }
+
+    /// A retry is about to be made after `delay`
+    pub fn record_retry(&self, delay: Duration) {
+        let mut state = self.lock();
+        let now = Utc::now();
+        state.total_retries += 1;
+        state.last_retry_at = Some(now);
+        state.next_attempt_at = Some(now + to_chrono(delay));
+    }
+
+    pub fn state(&self) -> CircuitState {
+        self.circuit(&self.lock())
+    }
+
+    pub fn health(&self) -> ConnectionHealth {
+        let state = self.lock();
+        let circuit = self.circuit(&state);
+        ConnectionHealth {
+            circuit,
+            consecutive_failures: state.consecutive_failures,
+            total_retries: state.total_retries,
+            last_retry_at: state.last_retry_at,
+            // A past time means the delay elapsed and nothing is pending
+            next_attempt_at: state.next_attempt_at.filter(|at| *at > Utc::now()),
+            last_error: state.last_error.clone(),
+        }
+    }
+}
+
+fn to_chrono(duration: Duration) -> chrono::Duration {
+    chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX)
+}
+
+/// Whether a response status is worth retrying: rate limits and server errors
+pub fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Whether a method may be sent more than once without changing the outcome
+pub fn is_idempotent(method: &reqwest::Method) -> bool {
+    matches!(
+        *method,
+        reqwest::Method::GET
+            | reqwest::Method::HEAD
+            | reqwest::Method::PUT
+            | reqwest::Method::DELETE
+            | reqwest::Method::OPTIONS
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(1000),
+        };
+
+        for _ in 0..20 {
+            let first = policy.backoff(0);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let third = policy.backoff(2);
+            assert!(third >= Duration::from_millis(200) && third <= Duration::from_millis(400));
+            let capped = policy.backoff(10);
+            assert!(capped >= Duration::from_millis(500) && capped <= Duration::from_millis(1000));
+        }
+    }
+
+    #[test]
+    fn test_circuit_opens_after_threshold() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_secs(60),
+        });
+
+        assert!(breaker.allow_request());
+        breaker.record_failure("HTTP 503");
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.record_failure("HTTP 503");
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!breaker.allow_request());
+
+        let health = breaker.health();
+        assert_eq!(health.consecutive_failures, 2);
+        assert_eq!(health.last_error.as_deref(), Some("HTTP 503"));
+        assert!(health.next_attempt_at.is_some());
+    }
+
+    #[test]
+    fn test_half_open_allows_one_probe() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::ZERO,
+        });
+
+        breaker.record_failure("timeout");
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.allow_request());
+        assert!(!breaker.allow_request());
+
+        // A failed probe reopens the circuit; a successful one closes it
+        breaker.record_failure("timeout");
+        assert!(breaker.allow_request());
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.allow_request());
+        assert!(breaker.allow_request());
+        assert_eq!(breaker.health().consecutive_failures, 0);
+    }
+}
@@ -0,0 +1,382 @@
+//! Snapshot browsing, field-level diffing, and selective restore
+//!
+//! Snapshots are stored as the project JSON that was uploaded at sync time,
+//! with the project's tasks under a `tasks` array. Diffs compare that document
+//! against the same shape built from local state, so a restore can pick
+//! individual project fields and tasks instead of replacing everything.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+use crate::error::{CloudError, CloudResult};
+
+/// Key holding the task list inside a snapshot document
+pub const TASKS_KEY: &str = "tasks";
+
+/// Fields that identify, version, or timestamp a record rather than describe
+/// it; these are never diffed or restored
+const IGNORED_FIELDS: &[&str] = &[
+    "id",
+    "createdAt",
+    "updatedAt",
+    "created_at",
+    "updated_at",
+    "version",
+];
+
+/// Snapshot version metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    pub snapshot_id: String,
+    pub project_id: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub size_bytes: Option<u64>,
+    #[serde(default)]
+    pub tasks_count: Option<usize>,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// List snapshots response
+#[derive(Debug, Deserialize)]
+pub struct ListSnapshotsResponse {
+    pub snapshots: Vec<SnapshotInfo>,
+}
+
+/// A project field whose snapshot value differs from local state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub snapshot_value: Value,
+    pub local_value: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskChangeKind {
+    /// Only present in the snapshot (deleted locally since)
+    Removed,
+    /// Only present locally (created since the snapshot)
+    Added,
+    /// Present in both with differing fields
+    Modified,
+}
+
+/// A task that differs between the snapshot and local state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskChange {
+    pub task_id: String,
+    pub title: Option<String>,
+    pub change: TaskChangeKind,
+    pub fields: Vec<FieldChange>,
+    /// Version of the local task the diff was taken against, if it exists locally
+    #[serde(default)]
+    pub local_version: Option<i64>,
+}
+
+/// Field-level differences between a snapshot and local state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SnapshotDiff {
+    pub fields: Vec<FieldChange>,
+    pub tasks: Vec<TaskChange>,
+    /// Version of the local project the diff was taken against
+    #[serde(default)]
+    pub local_version: Option<i64>,
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.tasks.is_empty()
+    }
+}
+
+/// Which parts of a snapshot to restore
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RestoreSelection {
+    #[serde(default)]
+    pub fields: Vec<String>,
+    #[serde(default)]
+    pub task_ids: Vec<String>,
+    /// `local_version` of the diff the selection was made from; the restore
+    /// fails if the local project has changed since
+    #[serde(default)]
+    pub project_version: Option<i64>,
+    /// Per-task `local_version` from the same diff; changed tasks are skipped
+    #[serde(default)]
+    pub task_versions: HashMap<String, i64>,
+}
+
+/// The selected snapshot values, ready to be applied to local state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SelectiveRestore {
+    pub fields: Map<String, Value>,
+    pub tasks: Vec<Value>,
+    /// Local versions the restore may overwrite, carried over from the selection
+    #[serde(default)]
+    pub project_version: Option<i64>,
+    #[serde(default)]
+    pub task_versions: HashMap<String, i64>,
+}
+
+pub(crate) fn as_object<'a>(
+    document: &'a Value,
+    what: &str,
+) -> CloudResult<&'a Map<String, Value>> {
+    document
+        .as_object()
+        .ok_or_else(|| CloudError::api(format!("{} is not a JSON object", what)))
+}
+
+pub(crate) fn is_diffable(field: &str) -> bool {
+    field != TASKS_KEY && !IGNORED_FIELDS.contains(&field)
+}
+
+/// Compare the diffable fields of two objects. Missing keys compare as null.
+fn diff_fields(snapshot: &Map<String, Value>, local: &Map<String, Value>) -> Vec<FieldChange> {
+    let mut names: Vec<&String> = snapshot
+        .keys()
+        .chain(local.keys())
+        .filter(|k| is_diffable(k))
+        .collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let snapshot_value = snapshot.get(name).cloned().unwrap_or(Value::Null);
+            let local_value = local.get(name).cloned().unwrap_or(Value::Null);
+            (snapshot_value != local_value).then(|| FieldChange {
+                field: name.clone(),
+                snapshot_value,
+                local_value,
+            })
+        })
+        .collect()
+}
+
+/// Index a document's tasks by id, preserving order
+pub(crate) fn tasks_by_id(document: &Map<String, Value>) -> Vec<(String, &Map<String, Value>)> {
+    document
+        .get(TASKS_KEY)
+        .and_then(|t| t.as_array())
+        .map(|tasks| {
+            tasks
+                .iter()
+                .filter_map(|task| {
+                    let task = task.as_object()?;
+                    let id = task.get("id")?.as_str()?.to_string();
+                    Some((id, task))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn task_title(task: &Map<String, Value>) -> Option<String> {
+    task.get("title").and_then(|t| t.as_str()).map(String::from)
+}
+
+fn record_version(record: &Map<String, Value>) -> Option<i64> {
+    record.get("version").and_then(|v| v.as_i64())
+}
+
+/// Compute field-level and per-task differences between a snapshot and local state
+pub fn diff_snapshot(snapshot: &Value, local: &Value) -> CloudResult<SnapshotDiff> {
+    let snapshot = as_object(snapshot, "Snapshot")?;
+    let local = as_object(local, "Local project state")?;
+
+    let snapshot_tasks = tasks_by_id(snapshot);
+    let local_tasks = tasks_by_id(local);
+
+    let mut tasks = Vec::new();
+    for (id, snapshot_task) in &snapshot_tasks {
+        match local_tasks.iter().find(|(local_id, _)| local_id == id) {
+            Some((_, local_task)) => {
+                let fields = diff_fields(snapshot_task, local_task);
+                if !fields.is_empty() {
+                    tasks.push(TaskChange {
+                        task_id: id.clone(),
+                        title: task_title(snapshot_task),
+                        change: TaskChangeKind::Modified,
+                        fields,
+                        local_version: record_version(local_task),
+                    });
+                }
+            }
+            None => tasks.push(TaskChange {
+                task_id: id.clone(),
+                title: task_title(snapshot_task),
+                change: TaskChangeKind::Removed,
+                fields: Vec::new(),
+                local_version: None,
+            }),
+        }
+    }
+    for (id, local_task) in &local_tasks {
+        if !snapshot_tasks
+            .iter()
+            .any(|(snapshot_id, _)| snapshot_id == id)
+        {
+            tasks.push(TaskChange {
+                task_id: id.clone(),
+                title: task_title(local_task),
+                change: TaskChangeKind::Added,
+                fields: Vec::new(),
+                local_version: record_version(local_task),
+            });
+        }
+    }
+
+    Ok(SnapshotDiff {
+        fields: diff_fields(snapshot, local),
+        tasks,
+        local_version: record_version(local),
+    })
+}
+
+/// Extract the selected fields and tasks from a snapshot.
+///
+/// Unknown fields and task ids are rejected so a stale selection from the UI
+/// cannot silently restore less than the user asked for.
+pub fn select_from_snapshot(
+    snapshot: &Value,
+    selection: &RestoreSelection,
+) -> CloudResult<SelectiveRestore> {
+    let document = as_object(snapshot, "Snapshot")?;
+    let mut restore = SelectiveRestore {
+        project_version: selection.project_version,
+        task_versions: selection.task_versions.clone(),
+        ..Default::default()
+    };
+
+    for field in &selection.fields {
+        if !is_diffable(field) {
+            return Err(CloudError::api(format!(
+                "Field '{}' cannot be restored",
+                field
+            )));
+        }
+        let value = document
+            .get(field)
+            .ok_or_else(|| CloudError::api(format!("Snapshot has no field '{}'", field)))?;
+        restore.fields.insert(field.clone(), value.clone());
+    }
+
+    let tasks = tasks_by_id(document);
+    for task_id in &selection.task_ids {
+        let (_, task) = tasks
+            .iter()
+            .find(|(id, _)| id == task_id)
+            .ok_or_else(|| CloudError::api(format!("Snapshot has no task '{}'", task_id)))?;
+        restore.tasks.push(Value::Object((*task).clone()));
+    }
+
+    Ok(restore)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn snapshot() -> Value {
+        json!({
+            "id": "proj1234",
+            "name": "Orkee",
+            "description": "Old description",
+            "tags": ["rust"],
+            "updatedAt": "2025-01-01T00:00:00Z",
+            "tasks": [
+                { "id": "task-one", "title": "Write docs", "status": "pending" },
+                { "id": "task-two", "title": "Ship it", "status": "done" }
+            ]
+        })
+    }
+
+    fn local() -> Value {
+        json!({
+            "id": "proj1234",
+            "name": "Orkee",
+            "description": "New description",
+            "tags": ["rust"],
+            "devScript": "pnpm dev",
+            "updatedAt": "2025-02-01T00:00:00Z",
+            "version": 4,
+            "tasks": [
+                { "id": "task-one", "title": "Write docs", "status": "in-progress", "version": 2 },
+                { "id": "task-new", "title": "Fresh work", "status": "pending" }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_diff_reports_changed_fields_only() {
+        let diff = diff_snapshot(&snapshot(), &local()).unwrap();
+        let fields: Vec<&str> = diff.fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, vec!["description", "devScript"]);
+        assert_eq!(diff.local_version, Some(4));
+        assert_eq!(diff.fields[1].snapshot_value, Value::Null);
+    }
+
+    #[test]
+    fn test_diff_classifies_tasks() {
+        let diff = diff_snapshot(&snapshot(), &local()).unwrap();
+        let changes: Vec<(&str, TaskChangeKind)> = diff
+            .tasks
+            .iter()
+            .map(|t| (t.task_id.as_str(), t.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("task-one", TaskChangeKind::Modified),
+                ("task-two", TaskChangeKind::Removed),
+                ("task-new", TaskChangeKind::Added),
+            ]
+        );
+        assert_eq!(diff.tasks[0].fields.len(), 1);
+        assert_eq!(diff.tasks[0].fields[0].field, "status");
+        assert_eq!(diff.tasks[0].local_version, Some(2));
+    }
+
+    #[test]
+    fn test_identical_documents_have_empty_diff() {
+        assert!(diff_snapshot(&snapshot(), &snapshot()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_select_from_snapshot() {
+        let selection = RestoreSelection {
+            fields: vec!["description".to_string()],
+            task_ids: vec!["task-two".to_string()],
+            project_version: Some(3),
+            ..Default::default()
+        };
+        let restore = select_from_snapshot(&snapshot(), &selection).unwrap();
+        assert_eq!(restore.fields["description"], "Old description");
+        assert_eq!(restore.tasks.len(), 1);
+        assert_eq!(restore.tasks[0]["title"], "Ship it");
+        assert_eq!(restore.project_version, Some(3));
+    }
+
+    #[test]
+    fn test_select_rejects_unknown_and_protected_entries() {
+        let unknown_task = RestoreSelection {
+            fields: vec![],
+            task_ids: vec!["missing".to_string()],
+            ..Default::default()
+        };
+        assert!(select_from_snapshot(&snapshot(), &unknown_task).is_err());
+
+        let protected_field = RestoreSelection {
+            fields: vec!["id".to_string()],
+            task_ids: vec![],
+            ..Default::default()
+        };
+        assert!(select_from_snapshot(&snapshot(), &protected_field).is_err());
+    }
+}
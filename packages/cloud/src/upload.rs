@@ -0,0 +1,400 @@
+//! Chunked, resumable, bandwidth-limited snapshot uploads
+//!
+//! A snapshot is sent to an upload session in fixed-size chunks. After every
+//! chunk the transfer state is written to `~/.orkee/uploads/<project_id>.json`,
+//! so when an interrupted upload of the same snapshot is retried it continues
+//! from the offset the server last acknowledged instead of starting over. An
+//! optional bandwidth cap paces the chunks so the average rate stays below it.
+//!
+//! Uploads to an S3 bucket use multipart uploads instead and live in
+//! [`crate::s3`]; their state is kept apart in `~/.orkee/uploads/s3`.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::fs;
+
+use crate::api::ProjectSyncResponse;
+use crate::client::HttpClient;
+use crate::error::{CloudError, CloudResult};
+use crate::retry::env_number;
+
+/// Chunk size used unless configured otherwise
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Smallest chunk size accepted, so a misconfiguration can't flood the API
+const MIN_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How snapshots are uploaded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadOptions {
+    /// Bytes sent per request
+    pub chunk_size: usize,
+    /// Average upload rate cap in bytes per second; `None` is unlimited
+    pub max_bytes_per_sec: Option<u64>,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_bytes_per_sec: None,
+        }
+    }
+}
+
+impl UploadOptions {
+    /// Default options, overridden by `ORKEE_CLOUD_UPLOAD_CHUNK_KB` and
+    /// `ORKEE_CLOUD_UPLOAD_LIMIT_KBPS`
+    pub fn from_env() -> Self {
+        let mut options = Self::default();
+        if let Some(chunk_kb) = env_number("ORKEE_CLOUD_UPLOAD_CHUNK_KB") {
+            options.chunk_size = (chunk_kb as usize * 1024).max(MIN_CHUNK_SIZE);
+        }
+        if let Some(kbps) = env_number("ORKEE_CLOUD_UPLOAD_LIMIT_KBPS") {
+            options = options.with_limit_kbps(kbps);
+        }
+        options
+    }
+
+    /// Cap the upload rate at `kbps` kilobytes per second; 0 removes the cap
+    pub fn with_limit_kbps(mut self, kbps: u64) -> Self {
+        self.max_bytes_per_sec = (kbps > 0).then(|| kbps * 1024);
+        self
+    }
+}
+
+/// Progress of a snapshot upload, persisted between attempts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadState {
+    pub project_id: String,
+    pub upload_id: String,
+    /// Hex SHA-256 of the snapshot being uploaded
+    pub sha256: String,
+    pub total_bytes: u64,
+    /// Bytes the server has acknowledged
+    pub bytes_sent: u64,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UploadState {
+    /// Whether this transfer was for the same snapshot content
+    pub fn is_for(&self, sha256: &str, total_bytes: u64) -> bool {
+        self.sha256 == sha256 && self.total_bytes == total_bytes
+    }
+}
+
+/// Transfer state files, one per project
+#[derive(Debug, Clone)]
+pub struct UploadStateStore {
+    dir: PathBuf,
+}
+
+impl UploadStateStore {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Store under `~/.orkee/uploads`
+    pub fn default_location() -> CloudResult<Self> {
+        let home_dir = dirs::home_dir()
+            .ok_or_else(|| CloudError::config("Could not determine home directory"))?;
+        Ok(Self::new(home_dir.join(".orkee").join("uploads")))
+    }
+
+    /// Separate store in a subdirectory, for uploads to another backend
+    pub fn subdirectory(&self, name: &str) -> Self {
+        Self::new(self.dir.join(name))
+    }
+
+    fn path(&self, project_id: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}.json", urlencoding::encode(project_id)))
+    }
+
+    /// Saved state of a project's unfinished upload
+    pub async fn load(&self, project_id: &str) -> CloudResult<Option<UploadState>> {
+        let path = self.path(project_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path).await?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    pub async fn save(&self, state: &UploadState) -> CloudResult<()> {
+        fs::create_dir_all(&self.dir).await?;
+        let content = serde_json::to_string_pretty(state)?;
+        fs::write(self.path(&state.project_id), content).await?;
+        Ok(())
+    }
+
+    pub async fn remove(&self, project_id: &str) -> CloudResult<()> {
+        let path = self.path(project_id);
+        if path.exists() {
+            fs::remove_file(path).await?;
+        }
+        Ok(())
+    }
+
+    /// All unfinished uploads, oldest first
+    pub async fn list(&self) -> CloudResult<Vec<UploadState>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+        let mut states = Vec::new();
+        let mut entries = fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                let content = fs::read_to_string(&path).await?;
+                match serde_json::from_str::<UploadState>(&content) {
+                    Ok(state) => states.push(state),
+                    Err(e) => tracing::warn!("Ignoring unreadable upload state {:?}: {}", path, e),
+                }
+            }
+        }
+        states.sort_by_key(|state| state.started_at);
+        Ok(states)
+    }
+}
+
+/// Start upload request
+#[derive(Debug, Serialize)]
+struct StartUploadRequest<'a> {
+    total_bytes: u64,
+    sha256: &'a str,
+}
+
+/// Upload session as the server sees it
+#[derive(Debug, Deserialize)]
+struct UploadSession {
+    upload_id: String,
+    bytes_received: u64,
+}
+
+/// How long to wait before sending more so that `bytes_sent` over `elapsed`
+/// stays at or under `max_bytes_per_sec` on average
+pub fn throttle_delay(bytes_sent: u64, elapsed: Duration, max_bytes_per_sec: u64) -> Duration {
+    if max_bytes_per_sec == 0 {
+        return Duration::ZERO;
+    }
+    let earliest = Duration::from_secs_f64(bytes_sent as f64 / max_bytes_per_sec as f64);
+    earliest.saturating_sub(elapsed)
+}
+
+/// Upload a snapshot, resuming an interrupted transfer of the same content
+pub(crate) async fn upload_snapshot(
+    http_client: &HttpClient,
+    store: &UploadStateStore,
+    options: &UploadOptions,
+    project_id: &str,
+    data: &[u8],
+) -> CloudResult<ProjectSyncResponse> {
+    let sha256 = hex::encode(Sha256::digest(data));
+    let total_bytes = data.len() as u64;
+    let uploads_path = format!("/api/projects/{}/uploads", project_id);
+
+    let mut state = match resume(
+        http_client,
+        store,
+        &uploads_path,
+        project_id,
+        &sha256,
+        total_bytes,
+    )
+    .await?
+    {
+        Some(state) => state,
+        None => {
+            let session: UploadSession = http_client
+                .post(
+                    &uploads_path,
+                    &StartUploadRequest {
+                        total_bytes,
+                        sha256: &sha256,
+                    },
+                )
+                .await?;
+            let now = Utc::now();
+            let state = UploadState {
+                project_id: project_id.to_string(),
+                upload_id: session.upload_id,
+                sha256,
+                total_bytes,
+                bytes_sent: session.bytes_received,
+                started_at: now,
+                updated_at: now,
+            };
+            store.save(&state).await?;
+            state
+        }
+    };
+
+    let session_path = format!("{}/{}", uploads_path, urlencoding::encode(&state.upload_id));
+    let chunk_size = options.chunk_size.max(MIN_CHUNK_SIZE);
+    let started = Instant::now();
+    let mut sent_this_run = 0u64;
+
+    while state.bytes_sent < total_bytes {
+        if let Some(limit) = options.max_bytes_per_sec {
+            tokio::time::sleep(throttle_delay(sent_this_run, started.elapsed(), limit)).await;
+        }
+
+        let offset = state.bytes_sent as usize;
+        let end = (offset + chunk_size).min(data.len());
+        let session: UploadSession = http_client
+            .put_bytes(
+                &format!("{}?offset={}", session_path, offset),
+                data[offset..end].to_vec(),
+            )
+            .await?;
+        if session.bytes_received <= state.bytes_sent || session.bytes_received > total_bytes {
+            return Err(CloudError::api(format!(
+                "Upload {} acknowledged {} of {} bytes after sending up to {}",
+                state.upload_id, session.bytes_received, total_bytes, end
+            )));
+        }
+
+        sent_this_run += session.bytes_received - state.bytes_sent;
+        state.bytes_sent = session.bytes_received;
+        state.updated_at = Utc::now();
+        store.save(&state).await?;
+        tracing::debug!(
+            "Uploaded {}/{} bytes of snapshot for project {}",
+            state.bytes_sent,
+            total_bytes,
+            project_id
+        );
+    }
+
+    let response: ProjectSyncResponse = http_client
+        .post(
+            &format!("{}/complete", session_path),
+            &serde_json::json!({}),
+        )
+        .await?;
+    store.remove(project_id).await?;
+    Ok(response)
+}
+
+/// Saved state of an unfinished upload of this snapshot, refreshed with the
+/// offset the server has actually received
+async fn resume(
+    http_client: &HttpClient,
+    store: &UploadStateStore,
+    uploads_path: &str,
+    project_id: &str,
+    sha256: &str,
+    total_bytes: u64,
+) -> CloudResult<Option<UploadState>> {
+    let Some(mut state) = store.load(project_id).await? else {
+        return Ok(None);
+    };
+    if !state.is_for(sha256, total_bytes) {
+        tracing::debug!(
+            "Snapshot for project {} changed since upload {}, starting over",
+            project_id,
+            state.upload_id
+        );
+        store.remove(project_id).await?;
+        return Ok(None);
+    }
+
+    let session: UploadSession = match http_client
+        .get(&format!(
+            "{}/{}",
+            uploads_path,
+            urlencoding::encode(&state.upload_id)
+        ))
+        .await
+    {
+        Ok(session) => session,
+        // The server no longer knows the session (expired or completed)
+        Err(CloudError::Api(e)) => {
+            tracing::debug!("Upload {} cannot be resumed: {}", state.upload_id, e);
+            store.remove(project_id).await?;
+            return Ok(None);
+        }
+        Err(e) => return Err(e),
+    };
+
+    tracing::info!(
+        "Resuming upload {} for project {} at {} of {} bytes",
+        state.upload_id,
+        project_id,
+        session.bytes_received,
+        total_bytes
+    );
+    state.bytes_sent = session.bytes_received.min(total_bytes);
+    Ok(Some(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(project_id: &str, started_at: DateTime<Utc>) -> UploadState {
+        UploadState {
+            project_id: project_id.to_string(),
+            upload_id: format!("upload-{}", project_id),
+            sha256: "abc123".to_string(),
+            total_bytes: 4096,
+            bytes_sent: 1024,
+            started_at,
+            updated_at: started_at,
+        }
+    }
+
+    #[test]
+    fn test_throttle_delay_keeps_average_under_cap() {
+        // 2 MiB at 1 MiB/s may not finish before 2 seconds
+        let delay = throttle_delay(2 * 1024 * 1024, Duration::from_millis(500), 1024 * 1024);
+        assert_eq!(delay, Duration::from_millis(1500));
+        // Already slower than the cap
+        assert_eq!(
+            throttle_delay(1024, Duration::from_secs(5), 1024),
+            Duration::ZERO
+        );
+        assert_eq!(throttle_delay(1024, Duration::ZERO, 0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_limit_kbps() {
+        let options = UploadOptions::default().with_limit_kbps(256);
+        assert_eq!(options.max_bytes_per_sec, Some(256 * 1024));
+        assert_eq!(options.with_limit_kbps(0).max_bytes_per_sec, None);
+    }
+
+    #[tokio::test]
+    async fn test_state_store_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = UploadStateStore::new(dir.path().join("uploads"));
+        assert!(store.load("proj1234").await.unwrap().is_none());
+        assert!(store.list().await.unwrap().is_empty());
+
+        let earlier = state("proj5678", Utc::now() - chrono::Duration::minutes(5));
+        let later = state("proj1234", Utc::now());
+        store.save(&later).await.unwrap();
+        store.save(&earlier).await.unwrap();
+
+        let loaded = store.load("proj1234").await.unwrap().unwrap();
+        assert_eq!(loaded, later);
+        assert!(loaded.is_for("abc123", 4096));
+        assert!(!loaded.is_for("abc123", 4097));
+        let projects: Vec<String> = store
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|s| s.project_id)
+            .collect();
+        assert_eq!(projects, vec!["proj5678", "proj1234"]);
+
+        store.remove("proj1234").await.unwrap();
+        assert!(store.load("proj1234").await.unwrap().is_none());
+    }
+}
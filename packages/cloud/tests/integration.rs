@@ -204,6 +204,10 @@ fn test_cloud_project_conversion() {
         created_at: Utc::now(),
         updated_at: Utc::now(),
         last_sync: None,
+        org_id: None,
+        owner: None,
+        last_synced_by: None,
+        read_only: false,
     };
 
     assert_eq!(cloud_project.id, "test-123");
@@ -4,7 +4,7 @@
 mod cloud_unit_tests {
     use chrono::Utc;
     use orkee_cloud::{
-        api::{ApiError, ApiResponse, CloudProject},
+        api::{ApiError, ApiResponse, CloudProject, OrgRole, Organization},
         CloudError,
     };
 
@@ -83,6 +83,10 @@ mod cloud_unit_tests {
             created_at: Utc::now(),
             updated_at: Utc::now(),
             last_sync: None,
+            org_id: None,
+            owner: None,
+            last_synced_by: None,
+            read_only: false,
         };
 
         assert_eq!(project.id, "test-123");
@@ -104,4 +108,84 @@ mod cloud_unit_tests {
         let display = format!("{}", error);
         assert_eq!(display, "API error: Server unavailable");
     }
+
+    #[test]
+    fn test_teammate_project_metadata() {
+        let json = serde_json::json!({
+            "id": "shared-1",
+            "name": "Team Project",
+            "path": "/home/alex/team",
+            "description": null,
+            "setup_script": null,
+            "dev_script": null,
+            "cleanup_script": null,
+            "tags": [],
+            "status": "active",
+            "priority": "medium",
+            "rank": null,
+            "task_source": null,
+            "mcp_servers": {},
+            "git_repository": null,
+            "manual_tasks": null,
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-02T00:00:00Z",
+            "last_sync": null,
+            "org_id": "org-1",
+            "owner": { "user_id": "user-alex", "name": "Alex", "email": "alex@example.com" },
+            "last_synced_by": { "user_id": "user-sam", "name": "Sam", "email": "sam@example.com" },
+            "read_only": true
+        });
+
+        let project: CloudProject = serde_json::from_value(json).unwrap();
+        assert!(project.read_only);
+        assert_eq!(project.org_id.as_deref(), Some("org-1"));
+        assert!(project.is_from_teammate("user-me"));
+        assert!(project.is_from_teammate("user-alex"));
+    }
+
+    #[test]
+    fn test_personal_project_defaults() {
+        // Responses from before org support have no sharing fields
+        let json = serde_json::json!({
+            "id": "mine-1",
+            "name": "Mine",
+            "path": "/tmp/mine",
+            "description": null,
+            "setup_script": null,
+            "dev_script": null,
+            "cleanup_script": null,
+            "tags": [],
+            "status": "active",
+            "priority": "low",
+            "rank": null,
+            "task_source": null,
+            "mcp_servers": {},
+            "git_repository": null,
+            "manual_tasks": null,
+            "created_at": "2025-01-01T00:00:00Z",
+            "updated_at": "2025-01-01T00:00:00Z",
+            "last_sync": null
+        });
+
+        let project: CloudProject = serde_json::from_value(json).unwrap();
+        assert!(!project.read_only);
+        assert!(project.org_id.is_none());
+        assert!(!project.is_from_teammate("user-me"));
+    }
+
+    #[test]
+    fn test_organization_deserialization() {
+        let json = serde_json::json!({
+            "id": "org-1",
+            "name": "Acme",
+            "slug": "acme",
+            "role": "admin",
+            "member_count": 4,
+            "created_at": "2025-01-01T00:00:00Z"
+        });
+
+        let org: Organization = serde_json::from_value(json).unwrap();
+        assert_eq!(org.role, OrgRole::Admin);
+        assert_eq!(org.member_count, 4);
+    }
 }
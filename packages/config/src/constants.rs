@@ -1,6 +1,9 @@
 // ABOUTME: Environment variable name constants
 // ABOUTME: Centralized definitions of all environment variable names used across Orkee
 
+// Configuration File
+pub const ORKEE_CONFIG_FILE: &str = "ORKEE_CONFIG_FILE";
+
 // Port Configuration
 pub const ORKEE_API_PORT: &str = "ORKEE_API_PORT";
 pub const ORKEE_UI_PORT: &str = "ORKEE_UI_PORT";
@@ -24,6 +27,9 @@ pub const TLS_ENABLED: &str = "TLS_ENABLED";
 pub const TLS_CERT_PATH: &str = "TLS_CERT_PATH";
 pub const TLS_KEY_PATH: &str = "TLS_KEY_PATH";
 pub const AUTO_GENERATE_CERT: &str = "AUTO_GENERATE_CERT";
+pub const TLS_CLIENT_AUTH_ENABLED: &str = "TLS_CLIENT_AUTH_ENABLED";
+pub const TLS_CLIENT_CA_PATH: &str = "TLS_CLIENT_CA_PATH";
+pub const TLS_CLIENT_IDENTITIES: &str = "TLS_CLIENT_IDENTITIES";
 pub const ENABLE_HSTS: &str = "ENABLE_HSTS";
 
 // Rate Limiting
@@ -36,6 +42,8 @@ pub const RATE_LIMIT_TELEMETRY_RPM: &str = "RATE_LIMIT_TELEMETRY_RPM";
 pub const RATE_LIMIT_AI_RPM: &str = "RATE_LIMIT_AI_RPM";
 pub const RATE_LIMIT_USERS_RPM: &str = "RATE_LIMIT_USERS_RPM";
 pub const RATE_LIMIT_SECURITY_RPM: &str = "RATE_LIMIT_SECURITY_RPM";
+pub const RATE_LIMIT_OAUTH_RPM: &str = "RATE_LIMIT_OAUTH_RPM";
+pub const RATE_LIMIT_SANDBOX_RPM: &str = "RATE_LIMIT_SANDBOX_RPM";
 pub const RATE_LIMIT_GLOBAL_RPM: &str = "RATE_LIMIT_GLOBAL_RPM";
 pub const RATE_LIMIT_BURST_SIZE: &str = "RATE_LIMIT_BURST_SIZE";
 
@@ -48,6 +56,9 @@ pub const ORKEE_CLOUD_TOKEN: &str = "ORKEE_CLOUD_TOKEN";
 pub const ORKEE_CLOUD_API_URL: &str = "ORKEE_CLOUD_API_URL";
 pub const ORKEE_CLOUD_ENABLED: &str = "ORKEE_CLOUD_ENABLED";
 
+// Telemetry Configuration
+pub const ORKEE_TELEMETRY_ENABLED: &str = "ORKEE_TELEMETRY_ENABLED";
+
 // Dashboard Tauri Configuration
 pub const ORKEE_TRAY_POLL_INTERVAL_SECS: &str = "ORKEE_TRAY_POLL_INTERVAL_SECS";
 pub const ORKEE_API_HOST: &str = "ORKEE_API_HOST";
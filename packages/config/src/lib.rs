@@ -3,3 +3,9 @@
 
 pub mod constants;
 pub mod env;
+pub mod service;
+
+pub use service::{
+    ConfigEntry, ConfigLayer, ConfigService, ConfigServiceBuilder, ConfigServiceError,
+    ConfigSnapshot, ReloadOutcome, SettingsSource,
+};
@@ -0,0 +1,553 @@
+// ABOUTME: Runtime-reloadable configuration service merging config file, database, and environment
+// ABOUTME: Publishes immutable snapshots so subsystems can react to changes without a restart
+
+use async_trait::async_trait;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::{watch, Mutex};
+use tracing::{debug, info, warn};
+
+use crate::constants::ORKEE_CONFIG_FILE;
+
+/// Name of the user configuration file inside `~/.orkee`
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+
+#[derive(Error, Debug)]
+pub enum ConfigServiceError {
+    #[error("Failed to read config file {path}: {source}")]
+    FileRead {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Invalid config file {path}: {message}")]
+    FileParse { path: PathBuf, message: String },
+    #[error("Failed to load database settings: {0}")]
+    Database(String),
+}
+
+/// Where a configuration value came from, in increasing order of precedence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigLayer {
+    File,
+    Database,
+    Environment,
+}
+
+/// A single resolved configuration value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigEntry {
+    pub value: String,
+    pub source: ConfigLayer,
+}
+
+/// Immutable view of the merged configuration at a point in time
+#[derive(Debug, Clone, Default)]
+pub struct ConfigSnapshot {
+    version: u64,
+    entries: BTreeMap<String, ConfigEntry>,
+}
+
+impl ConfigSnapshot {
+    /// Monotonic version, bumped every time a reload changes at least one value
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .get(&normalize_key(key))
+            .map(|entry| entry.value.as_str())
+    }
+
+    pub fn entry(&self, key: &str) -> Option<&ConfigEntry> {
+        self.entries.get(&normalize_key(key))
+    }
+
+    /// Return the first key that has a value, for settings with legacy aliases
+    pub fn get_first(&self, keys: &[&str]) -> Option<&str> {
+        keys.iter().find_map(|key| self.get(key))
+    }
+
+    /// Parse a value, logging and ignoring it when it is not valid for the target type
+    pub fn parse<T: FromStr>(&self, key: &str) -> Option<T> {
+        let raw = self.get(key)?;
+        match raw.trim().parse::<T>() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                warn!(
+                    "Ignoring invalid configuration value for {}: '{}'",
+                    key, raw
+                );
+                None
+            }
+        }
+    }
+
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key)
+            .and_then(|raw| match raw.trim().to_lowercase().as_str() {
+                "true" | "1" | "yes" | "on" => Some(true),
+                "false" | "0" | "no" | "off" => Some(false),
+                _ => {
+                    warn!("Ignoring invalid boolean for {}: '{}'", key, raw);
+                    None
+                }
+            })
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Keys whose value was added, removed, or modified relative to `previous`
+    pub fn changed_keys(&self, previous: &ConfigSnapshot) -> Vec<String> {
+        let all_keys: BTreeSet<&String> =
+            self.entries.keys().chain(previous.entries.keys()).collect();
+
+        all_keys
+            .into_iter()
+            .filter(|key| {
+                self.entries.get(*key).map(|e| &e.value)
+                    != previous.entries.get(*key).map(|e| &e.value)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Result of a reload request
+#[derive(Debug, Clone, Serialize)]
+pub struct ReloadOutcome {
+    pub version: u64,
+    pub changed_keys: Vec<String>,
+}
+
+/// Supplies key/value settings persisted in the database
+#[async_trait]
+pub trait SettingsSource: Send + Sync {
+    async fn load_settings(&self) -> Result<Vec<(String, String)>, String>;
+}
+
+/// Normalize keys so `RATE_LIMIT_GLOBAL_RPM`, `rate_limit_global_rpm` and
+/// `[rate_limit] global_rpm` all resolve to the same entry
+pub fn normalize_key(key: &str) -> String {
+    key.trim().to_ascii_lowercase().replace(['.', '-'], "_")
+}
+
+/// Default location of the user configuration file (`~/.orkee/config.toml`),
+/// overridable with `ORKEE_CONFIG_FILE`
+pub fn default_config_path() -> Option<PathBuf> {
+    if let Ok(path) = std::env::var(ORKEE_CONFIG_FILE) {
+        if !path.trim().is_empty() {
+            return Some(PathBuf::from(path));
+        }
+    }
+    dirs::home_dir().map(|home| home.join(".orkee").join(CONFIG_FILE_NAME))
+}
+
+fn flatten_toml(prefix: &str, value: &toml::Value, out: &mut BTreeMap<String, String>) {
+    let key = |name: &str| {
+        if prefix.is_empty() {
+            normalize_key(name)
+        } else {
+            format!("{}_{}", prefix, normalize_key(name))
+        }
+    };
+
+    match value {
+        toml::Value::Table(table) => {
+            for (name, child) in table {
+                flatten_toml(&key(name), child, out);
+            }
+        }
+        toml::Value::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        toml::Value::Array(items) => {
+            // Arrays map onto the comma-separated form used by environment variables
+            let joined = items
+                .iter()
+                .map(|item| match item {
+                    toml::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            out.insert(prefix.to_string(), joined);
+        }
+        other => {
+            out.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+fn load_file_layer(path: &Path) -> Result<BTreeMap<String, String>, ConfigServiceError> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            debug!("No config file at {}, skipping", path.display());
+            return Ok(BTreeMap::new());
+        }
+        Err(e) => {
+            return Err(ConfigServiceError::FileRead {
+                path: path.to_path_buf(),
+                source: e,
+            })
+        }
+    };
+
+    let table: toml::Table =
+        toml::from_str(&contents).map_err(|e| ConfigServiceError::FileParse {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+
+    let mut values = BTreeMap::new();
+    flatten_toml("", &toml::Value::Table(table), &mut values);
+    Ok(values)
+}
+
+/// Builder for [`ConfigService`]
+#[derive(Default)]
+pub struct ConfigServiceBuilder {
+    config_file: Option<PathBuf>,
+    settings_source: Option<Arc<dyn SettingsSource>>,
+    env_keys: BTreeSet<String>,
+}
+
+impl ConfigServiceBuilder {
+    pub fn config_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.config_file = Some(path.into());
+        self
+    }
+
+    pub fn settings_source(mut self, source: Arc<dyn SettingsSource>) -> Self {
+        self.settings_source = Some(source);
+        self
+    }
+
+    /// Environment variables to read on every reload. Only tracked variables
+    /// are merged so unrelated process environment never leaks into config.
+    pub fn track_env_keys<'a>(mut self, keys: impl IntoIterator<Item = &'a str>) -> Self {
+        self.env_keys.extend(keys.into_iter().map(String::from));
+        self
+    }
+
+    pub fn build(self) -> ConfigService {
+        let (sender, _) = watch::channel(Arc::new(ConfigSnapshot::default()));
+        ConfigService {
+            inner: Arc::new(ConfigServiceInner {
+                config_file: self.config_file,
+                settings_source: self.settings_source,
+                env_keys: self.env_keys,
+                sender,
+                reload_lock: Mutex::new(()),
+            }),
+        }
+    }
+}
+
+struct ConfigServiceInner {
+    config_file: Option<PathBuf>,
+    settings_source: Option<Arc<dyn SettingsSource>>,
+    env_keys: BTreeSet<String>,
+    sender: watch::Sender<Arc<ConfigSnapshot>>,
+    reload_lock: Mutex<()>,
+}
+
+/// Centralized configuration with layered sources.
+///
+/// Precedence (lowest to highest): config file, database settings, environment.
+/// Environment wins so that explicit process-level overrides keep behaving the
+/// way `Config::from_env` always has.
+#[derive(Clone)]
+pub struct ConfigService {
+    inner: Arc<ConfigServiceInner>,
+}
+
+impl ConfigService {
+    pub fn builder() -> ConfigServiceBuilder {
+        ConfigServiceBuilder::default()
+    }
+
+    /// Current merged configuration
+    pub fn current(&self) -> Arc<ConfigSnapshot> {
+        self.inner.sender.borrow().clone()
+    }
+
+    /// Subscribe to configuration changes. Receivers are only woken when a
+    /// reload actually changes a value.
+    pub fn subscribe(&self) -> watch::Receiver<Arc<ConfigSnapshot>> {
+        self.inner.sender.subscribe()
+    }
+
+    pub fn config_file(&self) -> Option<&Path> {
+        self.inner.config_file.as_deref()
+    }
+
+    /// Re-read every source and publish a new snapshot if anything changed.
+    /// On error the previous snapshot stays in effect.
+    pub async fn reload(&self) -> Result<ReloadOutcome, ConfigServiceError> {
+        let _guard = self.inner.reload_lock.lock().await;
+
+        let mut entries = BTreeMap::new();
+
+        if let Some(path) = &self.inner.config_file {
+            for (key, value) in load_file_layer(path)? {
+                entries.insert(
+                    key,
+                    ConfigEntry {
+                        value,
+                        source: ConfigLayer::File,
+                    },
+                );
+            }
+        }
+
+        if let Some(source) = &self.inner.settings_source {
+            let settings = source
+                .load_settings()
+                .await
+                .map_err(ConfigServiceError::Database)?;
+            for (key, value) in settings {
+                entries.insert(
+                    normalize_key(&key),
+                    ConfigEntry {
+                        value,
+                        source: ConfigLayer::Database,
+                    },
+                );
+            }
+        }
+
+        for name in &self.inner.env_keys {
+            if let Ok(value) = std::env::var(name) {
+                entries.insert(
+                    normalize_key(name),
+                    ConfigEntry {
+                        value,
+                        source: ConfigLayer::Environment,
+                    },
+                );
+            }
+        }
+
+        let previous = self.current();
+        let mut next = ConfigSnapshot {
+            version: previous.version,
+            entries,
+        };
+        let changed_keys = next.changed_keys(&previous);
+
+        if changed_keys.is_empty() {
+            debug!("Configuration reload found no changes");
+            return Ok(ReloadOutcome {
+                version: previous.version,
+                changed_keys,
+            });
+        }
+
+        next.version = previous.version + 1;
+        let version = next.version;
+        self.inner.sender.send_replace(Arc::new(next));
+        info!(
+            "Configuration reloaded (version {}, {} key(s) changed)",
+            version,
+            changed_keys.len()
+        );
+
+        Ok(ReloadOutcome {
+            version,
+            changed_keys,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    struct StaticSettings(Vec<(String, String)>);
+
+    #[async_trait]
+    impl SettingsSource for StaticSettings {
+        async fn load_settings(&self) -> Result<Vec<(String, String)>, String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct FailingSettings;
+
+    #[async_trait]
+    impl SettingsSource for FailingSettings {
+        async fn load_settings(&self) -> Result<Vec<(String, String)>, String> {
+            Err("database unavailable".to_string())
+        }
+    }
+
+    fn write_config(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(
+            normalize_key("RATE_LIMIT_GLOBAL_RPM"),
+            "rate_limit_global_rpm"
+        );
+        assert_eq!(normalize_key("ideate.max_tokens"), "ideate_max_tokens");
+        assert_eq!(normalize_key(" cors-origin "), "cors_origin");
+    }
+
+    #[test]
+    fn test_flatten_nested_tables_and_arrays() {
+        let file = write_config(
+            r#"
+            cors_origin = "http://localhost:3000"
+
+            [rate_limit]
+            enabled = false
+            global_rpm = 120
+
+            [browse]
+            allowed_paths = ["~/Code", "~/Projects"]
+            "#,
+        );
+
+        let values = load_file_layer(file.path()).unwrap();
+        assert_eq!(values["cors_origin"], "http://localhost:3000");
+        assert_eq!(values["rate_limit_enabled"], "false");
+        assert_eq!(values["rate_limit_global_rpm"], "120");
+        assert_eq!(values["browse_allowed_paths"], "~/Code,~/Projects");
+    }
+
+    #[test]
+    fn test_missing_file_is_empty_layer() {
+        let values = load_file_layer(Path::new("/nonexistent/orkee/config.toml")).unwrap();
+        assert!(values.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_layer_precedence() {
+        let file = write_config("[rate_limit]\nglobal_rpm = 10\nhealth_rpm = 11\nai_rpm = 12\n");
+        std::env::set_var("ORKEE_TEST_SVC_RATE_LIMIT_AI_RPM", "99");
+
+        let service = ConfigService::builder()
+            .config_file(file.path())
+            .settings_source(Arc::new(StaticSettings(vec![
+                ("rate_limit_health_rpm".into(), "21".into()),
+                ("orkee_test_svc_rate_limit_ai_rpm".into(), "22".into()),
+            ])))
+            .track_env_keys(["ORKEE_TEST_SVC_RATE_LIMIT_AI_RPM"])
+            .build();
+
+        service.reload().await.unwrap();
+        let snapshot = service.current();
+
+        assert_eq!(snapshot.parse::<u32>("rate_limit_global_rpm"), Some(10));
+        assert_eq!(
+            snapshot.entry("rate_limit_health_rpm").unwrap().source,
+            ConfigLayer::Database
+        );
+        assert_eq!(snapshot.parse::<u32>("RATE_LIMIT_HEALTH_RPM"), Some(21));
+        assert_eq!(
+            snapshot.parse::<u32>("ORKEE_TEST_SVC_RATE_LIMIT_AI_RPM"),
+            Some(99)
+        );
+        assert_eq!(
+            snapshot
+                .entry("orkee_test_svc_rate_limit_ai_rpm")
+                .unwrap()
+                .source,
+            ConfigLayer::Environment
+        );
+
+        std::env::remove_var("ORKEE_TEST_SVC_RATE_LIMIT_AI_RPM");
+    }
+
+    #[tokio::test]
+    async fn test_reload_notifies_only_on_change() {
+        let file = write_config("telemetry_enabled = true\n");
+        let service = ConfigService::builder().config_file(file.path()).build();
+        let mut rx = service.subscribe();
+
+        let first = service.reload().await.unwrap();
+        assert_eq!(first.version, 1);
+        assert_eq!(first.changed_keys, vec!["telemetry_enabled".to_string()]);
+        assert!(rx.has_changed().unwrap());
+        rx.borrow_and_update();
+
+        let unchanged = service.reload().await.unwrap();
+        assert_eq!(unchanged.version, 1);
+        assert!(unchanged.changed_keys.is_empty());
+        assert!(!rx.has_changed().unwrap());
+
+        std::fs::write(file.path(), "telemetry_enabled = false\n").unwrap();
+        let changed = service.reload().await.unwrap();
+        assert_eq!(changed.version, 2);
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(
+            rx.borrow_and_update().get_bool("telemetry_enabled"),
+            Some(false)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_reload_keeps_previous_snapshot() {
+        let file = write_config("cors_origin = \"http://localhost:5173\"\n");
+        let service = ConfigService::builder().config_file(file.path()).build();
+        service.reload().await.unwrap();
+
+        let broken = write_config("cors_origin = \n");
+        let broken_service = ConfigService::builder().config_file(broken.path()).build();
+        assert!(matches!(
+            broken_service.reload().await,
+            Err(ConfigServiceError::FileParse { .. })
+        ));
+
+        let failing = ConfigService::builder()
+            .settings_source(Arc::new(FailingSettings))
+            .build();
+        assert!(matches!(
+            failing.reload().await,
+            Err(ConfigServiceError::Database(_))
+        ));
+        assert_eq!(failing.current().version(), 0);
+        assert_eq!(
+            service.current().get("cors_origin"),
+            Some("http://localhost:5173")
+        );
+    }
+
+    #[test]
+    fn test_snapshot_value_helpers() {
+        let mut entries = BTreeMap::new();
+        for (key, value) in [("a", "yes"), ("b", "nope"), ("c", "abc"), ("d", "42")] {
+            entries.insert(
+                key.to_string(),
+                ConfigEntry {
+                    value: value.to_string(),
+                    source: ConfigLayer::File,
+                },
+            );
+        }
+        let snapshot = ConfigSnapshot {
+            version: 1,
+            entries,
+        };
+
+        assert_eq!(snapshot.get_bool("a"), Some(true));
+        assert_eq!(snapshot.get_bool("b"), None);
+        assert_eq!(snapshot.parse::<u32>("c"), None);
+        assert_eq!(snapshot.parse::<u32>("d"), Some(42));
+        assert_eq!(snapshot.get_first(&["missing", "d"]), Some("42"));
+    }
+}
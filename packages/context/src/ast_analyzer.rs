@@ -99,6 +99,72 @@ impl AstAnalyzer {
         // })
     }
 
+    /// Create a new analyzer for Go
+    #[cfg(feature = "lang-go")]
+    pub fn new_go() -> Result<Self, String> {
+        Self::with_language(tree_sitter_go::language(), "go", "Go")
+    }
+
+    #[cfg(not(feature = "lang-go"))]
+    pub fn new_go() -> Result<Self, String> {
+        Err(language_disabled("Go", "lang-go"))
+    }
+
+    /// Create a new analyzer for Java
+    #[cfg(feature = "lang-java")]
+    pub fn new_java() -> Result<Self, String> {
+        Self::with_language(tree_sitter_java::language(), "java", "Java")
+    }
+
+    #[cfg(not(feature = "lang-java"))]
+    pub fn new_java() -> Result<Self, String> {
+        Err(language_disabled("Java", "lang-java"))
+    }
+
+    /// Create a new analyzer for Ruby
+    #[cfg(feature = "lang-ruby")]
+    pub fn new_ruby() -> Result<Self, String> {
+        Self::with_language(tree_sitter_ruby::language(), "ruby", "Ruby")
+    }
+
+    #[cfg(not(feature = "lang-ruby"))]
+    pub fn new_ruby() -> Result<Self, String> {
+        Err(language_disabled("Ruby", "lang-ruby"))
+    }
+
+    /// Create a new analyzer for C#
+    #[cfg(feature = "lang-csharp")]
+    pub fn new_csharp() -> Result<Self, String> {
+        Self::with_language(tree_sitter_c_sharp::language(), "csharp", "C#")
+    }
+
+    #[cfg(not(feature = "lang-csharp"))]
+    pub fn new_csharp() -> Result<Self, String> {
+        Err(language_disabled("C#", "lang-csharp"))
+    }
+
+    #[cfg(any(
+        feature = "lang-go",
+        feature = "lang-java",
+        feature = "lang-ruby",
+        feature = "lang-csharp"
+    ))]
+    fn with_language(
+        language: tree_sitter::Language,
+        language_name: &str,
+        display_name: &str,
+    ) -> Result<Self, String> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(language)
+            .map_err(|e| format!("Failed to set {} language: {:?}", display_name, e))?;
+        Ok(Self {
+            parser,
+            language,
+            language_name: language_name.to_string(),
+        })
+    }
+
     /// Create an analyzer for a specific language by file extension
     pub fn from_extension(ext: &str) -> Result<Self, String> {
         match ext {
@@ -107,10 +173,37 @@ impl AstAnalyzer {
             "py" => Self::new_python(),
             // "rs" => Self::new_rust(),  // Disabled
             "rs" => Err("Rust support temporarily disabled".to_string()),
+            "go" => Self::new_go(),
+            "java" => Self::new_java(),
+            "rb" => Self::new_ruby(),
+            "cs" => Self::new_csharp(),
             _ => Err(format!("Unsupported file extension: {}", ext)),
         }
     }
 
+    /// File extensions with symbol extraction available in this build
+    pub fn supported_extensions() -> Vec<&'static str> {
+        let mut extensions = vec!["ts", "tsx", "js", "jsx", "py"];
+        if cfg!(feature = "lang-go") {
+            extensions.push("go");
+        }
+        if cfg!(feature = "lang-java") {
+            extensions.push("java");
+        }
+        if cfg!(feature = "lang-ruby") {
+            extensions.push("rb");
+        }
+        if cfg!(feature = "lang-csharp") {
+            extensions.push("cs");
+        }
+        extensions
+    }
+
+    /// Language this analyzer parses
+    pub fn language_name(&self) -> &str {
+        &self.language_name
+    }
+
     /// Extract all symbols from source code
     pub fn extract_symbols(&mut self, source_code: &str) -> Result<Vec<Symbol>, String> {
         let tree = self
@@ -132,6 +225,10 @@ impl AstAnalyzer {
             "typescript" | "javascript" => self.extract_ts_js_symbol(node, source),
             "python" => self.extract_python_symbol(node, source),
             "rust" => self.extract_rust_symbol(node, source),
+            "go" => self.extract_go_symbol(node, source),
+            "java" => self.extract_java_symbol(node, source),
+            "ruby" => self.extract_ruby_symbol(node, source),
+            "csharp" => self.extract_csharp_symbol(node, source),
             _ => None,
         };
 
@@ -248,6 +345,62 @@ impl AstAnalyzer {
         })
     }
 
+    /// Extract symbol from Go node
+    fn extract_go_symbol(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match node.kind() {
+            "function_declaration" => SymbolKind::Function,
+            "method_declaration" => SymbolKind::Method,
+            "type_spec" => match node.child_by_field_name("type")?.kind() {
+                "struct_type" => SymbolKind::Struct,
+                // Named non-struct types are interface-like, as with TS type aliases
+                _ => SymbolKind::Interface,
+            },
+            _ => return None,
+        };
+
+        named_symbol(node, source, kind)
+    }
+
+    /// Extract symbol from Java node
+    fn extract_java_symbol(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match node.kind() {
+            "class_declaration" | "record_declaration" => SymbolKind::Class,
+            "interface_declaration" | "annotation_type_declaration" => SymbolKind::Interface,
+            "enum_declaration" => SymbolKind::Enum,
+            "method_declaration" | "constructor_declaration" => SymbolKind::Method,
+            _ => return None,
+        };
+
+        named_symbol(node, source, kind)
+    }
+
+    /// Extract symbol from Ruby node
+    fn extract_ruby_symbol(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match node.kind() {
+            "class" => SymbolKind::Class,
+            "module" => SymbolKind::Module,
+            "method" | "singleton_method" => SymbolKind::Method,
+            _ => return None,
+        };
+
+        named_symbol(node, source, kind)
+    }
+
+    /// Extract symbol from C# node
+    fn extract_csharp_symbol(&self, node: &Node, source: &str) -> Option<Symbol> {
+        let kind = match node.kind() {
+            "class_declaration" | "record_declaration" => SymbolKind::Class,
+            "struct_declaration" => SymbolKind::Struct,
+            "interface_declaration" => SymbolKind::Interface,
+            "enum_declaration" => SymbolKind::Enum,
+            "method_declaration" | "constructor_declaration" => SymbolKind::Method,
+            "namespace_declaration" | "file_scoped_namespace_declaration" => SymbolKind::Module,
+            _ => return None,
+        };
+
+        named_symbol(node, source, kind)
+    }
+
     /// Build a dependency graph by extracting imports
     pub fn extract_imports(&mut self, source_code: &str) -> Result<Vec<String>, String> {
         let tree = self
@@ -266,30 +419,66 @@ impl AstAnalyzer {
     /// Recursively collect import statements
     fn collect_imports(&self, node: &Node, source: &str, imports: &mut Vec<String>) {
         match self.language_name.as_str() {
-            "typescript" | "javascript" => {
-                // Handle import statements and export...from statements (re-exports are dependencies too)
-                if node.kind() == "import_statement" || node.kind() == "export_statement" {
-                    if let Some(source_node) = node.child_by_field_name("source") {
-                        let import_path = source[source_node.byte_range()].to_string();
-                        // Remove quotes
-                        let cleaned = import_path.trim_matches('"').trim_matches('\'');
-                        imports.push(cleaned.to_string());
-                    }
+            // Handle import statements and export...from statements (re-exports are dependencies too)
+            "typescript" | "javascript"
+                if node.kind() == "import_statement" || node.kind() == "export_statement" =>
+            {
+                if let Some(source_node) = node.child_by_field_name("source") {
+                    let import_path = source[source_node.byte_range()].to_string();
+                    // Remove quotes
+                    let cleaned = import_path.trim_matches('"').trim_matches('\'');
+                    imports.push(cleaned.to_string());
+                }
+            }
+            "python"
+                if node.kind() == "import_statement" || node.kind() == "import_from_statement" =>
+            {
+                if let Some(name_node) = node.child_by_field_name("name") {
+                    let import_path = source[name_node.byte_range()].to_string();
+                    imports.push(import_path);
                 }
             }
-            "python" => {
-                if node.kind() == "import_statement" || node.kind() == "import_from_statement" {
-                    if let Some(name_node) = node.child_by_field_name("name") {
-                        let import_path = source[name_node.byte_range()].to_string();
-                        imports.push(import_path);
+            // Extract use path from Rust
+            "rust" if node.kind() == "use_declaration" => {
+                let use_text = source[node.byte_range()].to_string();
+                imports.push(use_text);
+            }
+            "go" if node.kind() == "import_spec" => {
+                if let Some(path_node) = node.child_by_field_name("path") {
+                    let import_path = source[path_node.byte_range()].trim_matches(['"', '`']);
+                    imports.push(import_path.to_string());
+                }
+            }
+            // `import [static] com.example.Foo;`
+            "java" if node.kind() == "import_declaration" => {
+                let text = source[node.byte_range()]
+                    .trim_start_matches("import")
+                    .trim_end_matches(';')
+                    .trim();
+                let text = text.strip_prefix("static ").unwrap_or(text);
+                imports.push(text.trim().to_string());
+            }
+            // `require 'foo'` and `require_relative './bar'`
+            "ruby" if node.kind() == "call" => {
+                let method = node
+                    .child_by_field_name("method")
+                    .map(|m| &source[m.byte_range()]);
+                if matches!(method, Some("require" | "require_relative")) {
+                    if let Some(argument) = node
+                        .child_by_field_name("arguments")
+                        .and_then(|args| args.named_child(0))
+                    {
+                        let import_path = source[argument.byte_range()].trim_matches(['"', '\'']);
+                        imports.push(import_path.to_string());
                     }
                 }
             }
-            "rust" => {
-                if node.kind() == "use_declaration" {
-                    // Extract use path from Rust
-                    let use_text = source[node.byte_range()].to_string();
-                    imports.push(use_text);
+            // `using [global] [static] [Alias =] System.Text;`
+            "csharp" if node.kind() == "using_directive" => {
+                let text = source[node.byte_range()].trim_end_matches(';');
+                let name = text.rsplit(['=', ' ']).next().unwrap_or_default().trim();
+                if !name.is_empty() {
+                    imports.push(name.to_string());
                 }
             }
             _ => {}
@@ -336,6 +525,34 @@ impl AstAnalyzer {
     }
 }
 
+/// Build a symbol from a node whose name is in its `name` field
+fn named_symbol(node: &Node, source: &str, kind: SymbolKind) -> Option<Symbol> {
+    let name_node = node.child_by_field_name("name")?;
+    let name = source[name_node.byte_range()].to_string();
+
+    Some(Symbol {
+        name,
+        kind,
+        line_start: node.start_position().row + 1,
+        line_end: node.end_position().row + 1,
+        children: Vec::new(),
+        doc_comment: None,
+    })
+}
+
+#[cfg(not(all(
+    feature = "lang-go",
+    feature = "lang-java",
+    feature = "lang-ruby",
+    feature = "lang-csharp"
+)))]
+fn language_disabled(display_name: &str, feature: &str) -> String {
+    format!(
+        "{} support not enabled. Build with --features {}",
+        display_name, feature
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,4 +610,160 @@ mod tests {
         assert!(AstAnalyzer::from_extension("rs").is_ok());
         assert!(AstAnalyzer::from_extension("unknown").is_err());
     }
+
+    #[cfg(any(
+        feature = "lang-go",
+        feature = "lang-java",
+        feature = "lang-ruby",
+        feature = "lang-csharp"
+    ))]
+    fn symbol_names(symbols: &[Symbol], kind: SymbolKind) -> Vec<&str> {
+        symbols
+            .iter()
+            .filter(|s| s.kind == kind)
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "lang-go")]
+    fn test_go_extraction() {
+        let mut analyzer = AstAnalyzer::from_extension("go").unwrap();
+        let source = r#"
+            package server
+
+            import (
+                "fmt"
+                "github.com/acme/app/store"
+            )
+
+            type Server struct{ name string }
+            type Handler interface{ Serve() }
+
+            func New() *Server { return &Server{} }
+            func (s *Server) Start() { fmt.Println(s.name) }
+        "#;
+
+        let symbols = analyzer.extract_symbols(source).unwrap();
+        assert_eq!(symbol_names(&symbols, SymbolKind::Struct), vec!["Server"]);
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Interface),
+            vec!["Handler"]
+        );
+        assert_eq!(symbol_names(&symbols, SymbolKind::Function), vec!["New"]);
+        assert_eq!(symbol_names(&symbols, SymbolKind::Method), vec!["Start"]);
+
+        let imports = analyzer.extract_imports(source).unwrap();
+        assert_eq!(imports, vec!["fmt", "github.com/acme/app/store"]);
+    }
+
+    #[test]
+    #[cfg(feature = "lang-java")]
+    fn test_java_extraction() {
+        let mut analyzer = AstAnalyzer::from_extension("java").unwrap();
+        let source = r#"
+            import java.util.List;
+            import static org.junit.Assert.assertEquals;
+
+            public class UserService {
+                public UserService() {}
+                public List<User> findAll() { return null; }
+            }
+
+            interface Repository {}
+            enum Role { ADMIN, MEMBER }
+        "#;
+
+        let symbols = analyzer.extract_symbols(source).unwrap();
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Class),
+            vec!["UserService"]
+        );
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Method),
+            vec!["UserService", "findAll"]
+        );
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Interface),
+            vec!["Repository"]
+        );
+        assert_eq!(symbol_names(&symbols, SymbolKind::Enum), vec!["Role"]);
+
+        let imports = analyzer.extract_imports(source).unwrap();
+        assert_eq!(
+            imports,
+            vec!["java.util.List", "org.junit.Assert.assertEquals"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lang-ruby")]
+    fn test_ruby_extraction() {
+        let mut analyzer = AstAnalyzer::from_extension("rb").unwrap();
+        let source = r#"
+            require 'json'
+            require_relative './models/user'
+
+            module Billing
+              class Invoice
+                def total; end
+                def self.build; end
+              end
+            end
+        "#;
+
+        let symbols = analyzer.extract_symbols(source).unwrap();
+        assert_eq!(symbol_names(&symbols, SymbolKind::Module), vec!["Billing"]);
+        assert_eq!(symbol_names(&symbols, SymbolKind::Class), vec!["Invoice"]);
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Method),
+            vec!["total", "build"]
+        );
+
+        let imports = analyzer.extract_imports(source).unwrap();
+        assert_eq!(imports, vec!["json", "./models/user"]);
+    }
+
+    #[test]
+    #[cfg(feature = "lang-csharp")]
+    fn test_csharp_extraction() {
+        let mut analyzer = AstAnalyzer::from_extension("cs").unwrap();
+        let source = r#"
+            using System.Text;
+            using Json = System.Text.Json;
+
+            namespace Acme.Orders
+            {
+                public interface IOrderStore {}
+                public struct OrderId {}
+                public class OrderService
+                {
+                    public OrderService() {}
+                    public void Place() {}
+                }
+            }
+        "#;
+
+        let symbols = analyzer.extract_symbols(source).unwrap();
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Module),
+            vec!["Acme.Orders"]
+        );
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Interface),
+            vec!["IOrderStore"]
+        );
+        assert_eq!(symbol_names(&symbols, SymbolKind::Struct), vec!["OrderId"]);
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Class),
+            vec!["OrderService"]
+        );
+        assert_eq!(
+            symbol_names(&symbols, SymbolKind::Method),
+            vec!["OrderService", "Place"]
+        );
+
+        let imports = analyzer.extract_imports(source).unwrap();
+        assert_eq!(imports, vec!["System.Text", "System.Text.Json"]);
+    }
 }
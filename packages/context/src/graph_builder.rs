@@ -7,6 +7,7 @@ use super::graph_types::{
     CodeGraph, EdgeType, GraphEdge, GraphMetadata, GraphNode, NodeMetadata, NodeType,
 };
 use chrono::Utc;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -168,11 +169,11 @@ impl GraphBuilder {
         let mut nodes = Vec::new();
         let mut edges = Vec::new();
         let mut file_node_map: HashMap<String, String> = HashMap::new();
-        let files = self.find_source_files(&root_path)?;
+        let files =
+            self.find_files_with_extensions(&root_path, &AstAnalyzer::supported_extensions())?;
 
-        // Analyze each file for symbols
-        let mut analyzer = AstAnalyzer::new_typescript()
-            .map_err(|e| format!("Failed to create AST analyzer: {}", e))?;
+        // One analyzer per language, created on first use
+        let mut analyzers: HashMap<String, AstAnalyzer> = HashMap::new();
 
         for (file_idx, file_path) in files.iter().enumerate() {
             // Validate file is within project bounds
@@ -207,6 +208,21 @@ impl GraphBuilder {
             });
 
             // Extract and process symbols from file
+            let extension = file_path
+                .extension()
+                .map(|e| e.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let analyzer = match analyzers.entry(extension) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => match AstAnalyzer::from_extension(entry.key()) {
+                    Ok(analyzer) => entry.insert(analyzer),
+                    Err(e) => {
+                        warn!("Failed to create AST analyzer for {:?}: {}", file_path, e);
+                        continue;
+                    }
+                },
+            };
+
             match fs::read_to_string(file_path) {
                 Ok(content) => match analyzer.extract_symbols(&content) {
                     Ok(symbols) => {
@@ -343,6 +359,15 @@ impl GraphBuilder {
 
     /// Find all source files in the project
     fn find_source_files(&self, root_path: &Path) -> Result<Vec<PathBuf>, String> {
+        self.find_files_with_extensions(root_path, &["ts", "tsx", "js", "jsx"])
+    }
+
+    /// Find project files with any of the given extensions
+    fn find_files_with_extensions(
+        &self,
+        root_path: &Path,
+        extensions: &[&str],
+    ) -> Result<Vec<PathBuf>, String> {
         let mut files = Vec::new();
 
         for entry in WalkDir::new(root_path)
@@ -353,7 +378,7 @@ impl GraphBuilder {
         {
             if entry.path().is_file() {
                 if let Some(ext) = entry.path().extension() {
-                    if matches!(ext.to_string_lossy().as_ref(), "ts" | "tsx" | "js" | "jsx") {
+                    if extensions.contains(&ext.to_string_lossy().as_ref()) {
                         files.push(entry.path().to_path_buf());
                     }
                 }
@@ -583,6 +608,7 @@ fn process_symbols_recursive_impl(
             crate::SymbolKind::Class => NodeType::Class,
             crate::SymbolKind::Interface => NodeType::Class, // Treat interfaces as classes in graph
             crate::SymbolKind::Method => NodeType::Function, // Treat methods as functions in graph
+            crate::SymbolKind::Struct => NodeType::Class,
             _ => NodeType::Module,
         };
 
@@ -666,6 +692,31 @@ mod tests {
         assert!(files.iter().any(|f| f.ends_with("utils.js")));
     }
 
+    #[test]
+    #[cfg(feature = "lang-go")]
+    fn test_symbol_graph_covers_other_languages() {
+        let temp_dir = TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        fs::write(root.join("index.ts"), "export class App {}").unwrap();
+        fs::write(
+            root.join("main.go"),
+            "package main\n\nfunc main() {}\n\ntype Config struct{}\n",
+        )
+        .unwrap();
+
+        let mut builder = GraphBuilder::new();
+        let graph = builder
+            .build_symbol_graph(root.to_str().unwrap(), "test-project")
+            .unwrap();
+
+        let labels: Vec<&str> = graph.nodes.iter().map(|n| n.label.as_str()).collect();
+        assert!(labels.contains(&"main.go"));
+        assert!(labels.contains(&"main"));
+        assert!(labels.contains(&"Config"));
+        assert!(labels.contains(&"App"));
+    }
+
     #[test]
     fn test_is_ignored_dir() {
         let builder = GraphBuilder::new();
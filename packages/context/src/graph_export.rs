@@ -0,0 +1,400 @@
+// ABOUTME: Export of code graphs to DOT, Mermaid, and GraphML for docs and external tools.
+// ABOUTME: Also trims graphs to a subgraph root and node limit so exported output stays readable.
+
+use super::graph_types::CodeGraph;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+
+/// Text formats a graph can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Dot,
+    Mermaid,
+    Graphml,
+}
+
+impl ExportFormat {
+    /// MIME type for serving the exported text
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Dot => "text/vnd.graphviz; charset=utf-8",
+            ExportFormat::Mermaid => "text/plain; charset=utf-8",
+            ExportFormat::Graphml => "application/graphml+xml; charset=utf-8",
+        }
+    }
+
+    /// File extension for downloads
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Dot => "dot",
+            ExportFormat::Mermaid => "mmd",
+            ExportFormat::Graphml => "graphml",
+        }
+    }
+}
+
+/// Options for trimming a graph before it is returned or exported
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GraphFilter {
+    /// Node id, path, or label to root the subgraph at. Only nodes reachable
+    /// from it along edge direction are kept.
+    pub root: Option<String>,
+    /// Maximum number of nodes to keep
+    pub max_nodes: Option<usize>,
+}
+
+/// Trim a graph to the subgraph reachable from `filter.root` (breadth-first,
+/// so the closest nodes survive the node limit) and to at most
+/// `filter.max_nodes` nodes. Edges to dropped nodes are removed.
+pub fn filter_graph(graph: CodeGraph, filter: &GraphFilter) -> Result<CodeGraph, String> {
+    if filter.root.is_none() && filter.max_nodes.is_none() {
+        return Ok(graph);
+    }
+    let limit = filter.max_nodes.unwrap_or(usize::MAX);
+
+    let keep: HashSet<String> = match &filter.root {
+        Some(root) => {
+            let root_id = graph
+                .nodes
+                .iter()
+                .find(|n| &n.id == root || n.metadata.path.as_ref() == Some(root))
+                .or_else(|| graph.nodes.iter().find(|n| &n.label == root))
+                .map(|n| n.id.clone())
+                .ok_or_else(|| format!("Subgraph root not found: {}", root))?;
+
+            let mut outgoing: HashMap<&str, Vec<&str>> = HashMap::new();
+            for edge in &graph.edges {
+                outgoing
+                    .entry(edge.source.as_str())
+                    .or_default()
+                    .push(edge.target.as_str());
+            }
+
+            let mut keep = HashSet::new();
+            let mut queue = VecDeque::from([root_id]);
+            while let Some(id) = queue.pop_front() {
+                if keep.len() >= limit {
+                    break;
+                }
+                if !keep.insert(id.clone()) {
+                    continue;
+                }
+                for target in outgoing.get(id.as_str()).into_iter().flatten() {
+                    if !keep.contains(*target) {
+                        queue.push_back(target.to_string());
+                    }
+                }
+            }
+            keep
+        }
+        None => graph
+            .nodes
+            .iter()
+            .take(limit)
+            .map(|n| n.id.clone())
+            .collect(),
+    };
+
+    let nodes: Vec<_> = graph
+        .nodes
+        .into_iter()
+        .filter(|n| keep.contains(&n.id))
+        .collect();
+    let edges: Vec<_> = graph
+        .edges
+        .into_iter()
+        .filter(|e| keep.contains(&e.source) && keep.contains(&e.target))
+        .collect();
+
+    let mut metadata = graph.metadata;
+    metadata.total_nodes = nodes.len();
+    metadata.total_edges = edges.len();
+
+    Ok(CodeGraph {
+        nodes,
+        edges,
+        metadata,
+    })
+}
+
+/// Render a graph in the given text format
+pub fn export_graph(graph: &CodeGraph, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Dot => export_dot(graph),
+        ExportFormat::Mermaid => export_mermaid(graph),
+        ExportFormat::Graphml => export_graphml(graph),
+    }
+}
+
+fn export_dot(graph: &CodeGraph) -> String {
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "digraph \"{}\" {{",
+        escape_dot(&graph.metadata.graph_type)
+    );
+    let _ = writeln!(out, "  rankdir=LR;");
+    for node in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "  \"{}\" [label=\"{}\", type=\"{}\"];",
+            escape_dot(&node.id),
+            escape_dot(&node.label),
+            type_name(&node.node_type)
+        );
+    }
+    for edge in &graph.edges {
+        let _ = writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [type=\"{}\"];",
+            escape_dot(&edge.source),
+            escape_dot(&edge.target),
+            type_name(&edge.edge_type)
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn export_mermaid(graph: &CodeGraph) -> String {
+    // Mermaid ids only allow a restricted character set, so nodes are renumbered
+    let ids: HashMap<&str, String> = graph
+        .nodes
+        .iter()
+        .enumerate()
+        .map(|(idx, node)| (node.id.as_str(), format!("n{}", idx)))
+        .collect();
+
+    let mut out = String::from("graph LR\n");
+    for node in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "  {}[\"{}\"]",
+            ids[node.id.as_str()],
+            escape_mermaid(&node.label)
+        );
+    }
+    for edge in &graph.edges {
+        if let (Some(source), Some(target)) =
+            (ids.get(edge.source.as_str()), ids.get(edge.target.as_str()))
+        {
+            let _ = writeln!(
+                out,
+                "  {} -->|{}| {}",
+                source,
+                type_name(&edge.edge_type),
+                target
+            );
+        }
+    }
+    out
+}
+
+fn export_graphml(graph: &CodeGraph) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n\
+         \x20 <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"node_type\" for=\"node\" attr.name=\"node_type\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"path\" for=\"node\" attr.name=\"path\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"edge_type\" for=\"edge\" attr.name=\"edge_type\" attr.type=\"string\"/>\n\
+         \x20 <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+    );
+    let _ = writeln!(
+        out,
+        "  <graph id=\"{}\" edgedefault=\"directed\">",
+        escape_xml(&graph.metadata.graph_type)
+    );
+    for node in &graph.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", escape_xml(&node.id));
+        let _ = writeln!(
+            out,
+            "      <data key=\"label\">{}</data>",
+            escape_xml(&node.label)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"node_type\">{}</data>",
+            type_name(&node.node_type)
+        );
+        if let Some(path) = &node.metadata.path {
+            let _ = writeln!(out, "      <data key=\"path\">{}</data>", escape_xml(path));
+        }
+        out.push_str("    </node>\n");
+    }
+    for edge in &graph.edges {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\">",
+            escape_xml(&edge.id),
+            escape_xml(&edge.source),
+            escape_xml(&edge.target)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"edge_type\">{}</data>",
+            type_name(&edge.edge_type)
+        );
+        if let Some(weight) = edge.weight {
+            let _ = writeln!(out, "      <data key=\"weight\">{}</data>", weight);
+        }
+        out.push_str("    </edge>\n");
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+/// Serialized (snake_case) name of a node or edge type
+fn type_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+fn escape_dot(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph_types::{
+        EdgeType, GraphEdge, GraphMetadata, GraphNode, NodeMetadata, NodeType,
+    };
+    use chrono::Utc;
+
+    fn node(id: &str, label: &str) -> GraphNode {
+        GraphNode {
+            id: id.to_string(),
+            label: label.to_string(),
+            node_type: NodeType::File,
+            metadata: NodeMetadata {
+                path: Some(format!("src/{}", label)),
+                line_start: None,
+                line_end: None,
+                token_count: None,
+                complexity: None,
+                spec_id: None,
+            },
+        }
+    }
+
+    fn edge(source: &str, target: &str) -> GraphEdge {
+        GraphEdge {
+            id: format!("edge_{}_{}", source, target),
+            source: source.to_string(),
+            target: target.to_string(),
+            edge_type: EdgeType::Import,
+            weight: Some(1.0),
+        }
+    }
+
+    /// a -> b -> c, d -> a
+    fn sample_graph() -> CodeGraph {
+        CodeGraph {
+            nodes: vec![
+                node("a", "index.ts"),
+                node("b", "app.ts"),
+                node("c", "util.ts"),
+                node("d", "main \"entry\".ts"),
+            ],
+            edges: vec![edge("a", "b"), edge("b", "c"), edge("d", "a")],
+            metadata: GraphMetadata {
+                total_nodes: 4,
+                total_edges: 3,
+                graph_type: "dependencies".to_string(),
+                generated_at: Utc::now(),
+                project_id: "test-project".to_string(),
+            },
+        }
+    }
+
+    fn node_ids(graph: &CodeGraph) -> Vec<&str> {
+        graph.nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_filter_by_root_keeps_reachable_nodes() {
+        let filter = GraphFilter {
+            root: Some("src/app.ts".to_string()),
+            max_nodes: None,
+        };
+        let graph = filter_graph(sample_graph(), &filter).unwrap();
+        assert_eq!(node_ids(&graph), vec!["b", "c"]);
+        assert_eq!(graph.edges.len(), 1);
+        assert_eq!(graph.metadata.total_nodes, 2);
+        assert_eq!(graph.metadata.total_edges, 1);
+    }
+
+    #[test]
+    fn test_filter_node_limit() {
+        let filter = GraphFilter {
+            root: Some("d".to_string()),
+            max_nodes: Some(2),
+        };
+        let graph = filter_graph(sample_graph(), &filter).unwrap();
+        assert_eq!(node_ids(&graph), vec!["a", "d"]);
+
+        let filter = GraphFilter {
+            root: None,
+            max_nodes: Some(3),
+        };
+        let graph = filter_graph(sample_graph(), &filter).unwrap();
+        assert_eq!(node_ids(&graph), vec!["a", "b", "c"]);
+        assert_eq!(graph.edges.len(), 2);
+    }
+
+    #[test]
+    fn test_filter_unknown_root() {
+        let filter = GraphFilter {
+            root: Some("missing.ts".to_string()),
+            max_nodes: None,
+        };
+        assert!(filter_graph(sample_graph(), &filter).is_err());
+    }
+
+    #[test]
+    fn test_export_dot() {
+        let dot = export_graph(&sample_graph(), ExportFormat::Dot);
+        assert!(dot.starts_with("digraph \"dependencies\" {"));
+        assert!(dot.contains("\"a\" [label=\"index.ts\", type=\"file\"];"));
+        assert!(dot.contains("\"d\" [label=\"main \\\"entry\\\".ts\", type=\"file\"];"));
+        assert!(dot.contains("\"a\" -> \"b\" [type=\"import\"];"));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_export_mermaid() {
+        let mermaid = export_graph(&sample_graph(), ExportFormat::Mermaid);
+        assert!(mermaid.starts_with("graph LR\n"));
+        assert!(mermaid.contains("  n0[\"index.ts\"]"));
+        assert!(mermaid.contains("  n3[\"main #quot;entry#quot;.ts\"]"));
+        assert!(mermaid.contains("  n0 -->|import| n1"));
+    }
+
+    #[test]
+    fn test_export_graphml() {
+        let graphml = export_graph(&sample_graph(), ExportFormat::Graphml);
+        assert!(graphml.contains("<graph id=\"dependencies\" edgedefault=\"directed\">"));
+        assert!(graphml.contains("<data key=\"label\">main &quot;entry&quot;.ts</data>"));
+        assert!(graphml.contains("<edge id=\"edge_a_b\" source=\"a\" target=\"b\">"));
+        assert_eq!(graphml.matches("<node ").count(), 4);
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+    }
+}
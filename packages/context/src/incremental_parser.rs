@@ -272,23 +272,17 @@ fn extract_dependencies_recursive(
     let node = cursor.node();
 
     match language {
-        "typescript" | "javascript" => {
-            if node.kind() == "import_statement" {
-                let text = &source[node.byte_range()];
-                dependencies.push(text.to_string());
-            }
+        "typescript" | "javascript" if node.kind() == "import_statement" => {
+            let text = &source[node.byte_range()];
+            dependencies.push(text.to_string());
         }
-        "rust" => {
-            if node.kind() == "use_declaration" {
-                let text = &source[node.byte_range()];
-                dependencies.push(text.to_string());
-            }
+        "rust" if node.kind() == "use_declaration" => {
+            let text = &source[node.byte_range()];
+            dependencies.push(text.to_string());
         }
-        "python" => {
-            if node.kind() == "import_statement" || node.kind() == "import_from_statement" {
-                let text = &source[node.byte_range()];
-                dependencies.push(text.to_string());
-            }
+        "python" if node.kind() == "import_statement" || node.kind() == "import_from_statement" => {
+            let text = &source[node.byte_range()];
+            dependencies.push(text.to_string());
         }
         _ => {}
     }
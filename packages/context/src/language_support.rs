@@ -76,6 +76,26 @@ lazy_static! {
             token_multiplier: 0.40,
         });
 
+        // Ruby
+        configs.insert("ruby".to_string(), LanguageConfig {
+            name: "Ruby".to_string(),
+            file_extensions: vec!["rb"],
+            comment_single: "#".to_string(),
+            comment_multi_start: "=begin".to_string(),
+            comment_multi_end: "=end".to_string(),
+            token_multiplier: 0.30,
+        });
+
+        // C#
+        configs.insert("csharp".to_string(), LanguageConfig {
+            name: "C#".to_string(),
+            file_extensions: vec!["cs"],
+            comment_single: "//".to_string(),
+            comment_multi_start: "/*".to_string(),
+            comment_multi_end: "*/".to_string(),
+            token_multiplier: 0.40,
+        });
+
         configs
     };
 }
@@ -116,6 +136,41 @@ impl MultiLanguageParser {
             .unwrap();
         parsers.insert("python".to_string(), python_parser);
 
+        // Optional grammars, each behind its own feature flag
+        #[cfg(feature = "lang-go")]
+        {
+            let mut go_parser = Parser::new();
+            go_parser.set_language(tree_sitter_go::language()).unwrap();
+            parsers.insert("go".to_string(), go_parser);
+        }
+
+        #[cfg(feature = "lang-java")]
+        {
+            let mut java_parser = Parser::new();
+            java_parser
+                .set_language(tree_sitter_java::language())
+                .unwrap();
+            parsers.insert("java".to_string(), java_parser);
+        }
+
+        #[cfg(feature = "lang-ruby")]
+        {
+            let mut ruby_parser = Parser::new();
+            ruby_parser
+                .set_language(tree_sitter_ruby::language())
+                .unwrap();
+            parsers.insert("ruby".to_string(), ruby_parser);
+        }
+
+        #[cfg(feature = "lang-csharp")]
+        {
+            let mut csharp_parser = Parser::new();
+            csharp_parser
+                .set_language(tree_sitter_c_sharp::language())
+                .unwrap();
+            parsers.insert("csharp".to_string(), csharp_parser);
+        }
+
         Self { parsers }
     }
 
@@ -272,12 +327,16 @@ pub fn analyze_language_stats(content: &str, language: &str) -> LanguageStats {
         "python" => content.contains("def "),
         "rust" => content.contains("fn "),
         "go" => content.contains("func "),
-        "java" => content.contains("public ") || content.contains("private "),
+        "java" | "csharp" => content.contains("public ") || content.contains("private "),
+        "ruby" => content.contains("def "),
         _ => false,
     };
 
     let has_classes = match language {
-        "typescript" | "javascript" | "python" | "java" => content.contains("class "),
+        "typescript" | "javascript" | "python" | "java" | "ruby" | "csharp" => {
+            content.contains("class ")
+        }
+        "go" => content.contains("struct {") || content.contains("struct{"),
         "rust" => content.contains("struct ") || content.contains("impl "),
         _ => false,
     };
@@ -319,6 +378,12 @@ mod tests {
             parser.detect_language("test.py"),
             Some("python".to_string())
         );
+        assert_eq!(parser.detect_language("main.go"), Some("go".to_string()));
+        assert_eq!(parser.detect_language("app.rb"), Some("ruby".to_string()));
+        assert_eq!(
+            parser.detect_language("Program.cs"),
+            Some("csharp".to_string())
+        );
         assert_eq!(parser.detect_language("test.unknown"), None);
     }
 
@@ -3,6 +3,7 @@ pub mod batch_processor;
 pub mod dependency_graph;
 pub mod formatter;
 pub mod graph_builder;
+pub mod graph_export;
 pub mod graph_types;
 pub mod incremental_parser;
 pub mod language_support;
@@ -13,6 +14,7 @@ pub use batch_processor::*;
 pub use dependency_graph::*;
 pub use orkee_formatter::*;
 pub use graph_builder::*;
+pub use graph_export::*;
 pub use graph_types::*;
 pub use incremental_parser::*;
 pub use language_support::*;
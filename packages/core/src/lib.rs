@@ -19,4 +19,7 @@ pub use constants::{orkee_dir, projects_file, PROJECTS_VERSION};
 pub use utils::{compress_data, decompress_data, generate_project_id, path_exists};
 
 // Re-export validation
-pub use validation::{truncate, validate_project_data, validate_project_update, ValidationError};
+pub use validation::{
+    truncate, validate_project_data, validate_project_update, ValidationError, ValidationReport,
+    ValidationRules,
+};
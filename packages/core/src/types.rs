@@ -155,6 +155,16 @@ pub struct Project {
     pub mcp_servers: Option<HashMap<String, bool>>,
     #[serde(rename = "gitRepository")]
     pub git_repository: Option<GitRepositoryInfo>,
+    /// User who owns the project; `None` for projects shared with every user
+    #[serde(rename = "ownerUserId", default)]
+    pub owner_user_id: Option<String>,
+    /// Incremented on every update; writers send it back to detect lost updates
+    #[serde(default = "default_version")]
+    pub version: i64,
+}
+
+fn default_version() -> i64 {
+    1
 }
 
 /// Configuration structure for projects.json
@@ -221,4 +231,7 @@ pub struct ProjectUpdateInput {
     pub manual_tasks: Option<Vec<ManualTask>>,
     #[serde(rename = "mcpServers")]
     pub mcp_servers: Option<HashMap<String, bool>>,
+    /// Version the caller last read; the update is rejected if the project has changed since
+    #[serde(skip)]
+    pub expected_version: Option<i64>,
 }
@@ -1,9 +1,18 @@
 // ABOUTME: Validation functions for project data
-// ABOUTME: Input validation, path safety checks, script validation
+// ABOUTME: Input validation, path safety checks, script validation, and multi-field error reports
+
+pub mod rules;
+
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
 
 use crate::types::{ProjectCreateInput, ProjectUpdateInput};
 use crate::utils::path_exists;
 
+pub use rules::ValidationRules;
+
 // Validation limits
 const MAX_NAME_LENGTH: usize = 100;
 const MAX_DESCRIPTION_LENGTH: usize = 1000;
@@ -28,7 +37,7 @@ const DANGEROUS_COMMANDS: &[&str] = &[
 ];
 
 /// Validation errors for project data
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ValidationError {
     pub field: String,
     pub message: String,
@@ -43,6 +52,75 @@ impl ValidationError {
     }
 }
 
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Every validation failure for one input, in the order the checks ran.
+///
+/// Serializes as `{"errors": [...], "fields": {"name": ["..."]}}` so clients
+/// can either list the errors or show them next to each form field.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn push(&mut self, error: ValidationError) {
+        self.errors.push(error);
+    }
+
+    /// Whether any check already failed for the field
+    pub fn has_field(&self, field: &str) -> bool {
+        self.errors.iter().any(|error| error.field == field)
+    }
+
+    /// Error messages grouped by field
+    pub fn by_field(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut fields: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for error in &self.errors {
+            fields
+                .entry(error.field.as_str())
+                .or_default()
+                .push(error.message.as_str());
+        }
+        fields
+    }
+}
+
+impl From<Vec<ValidationError>> for ValidationReport {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Self { errors }
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ValidationReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut report = serializer.serialize_struct("ValidationReport", 2)?;
+        report.serialize_field("errors", &self.errors)?;
+        report.serialize_field("fields", &self.by_field())?;
+        report.end()
+    }
+}
+
 use regex::Regex;
 use std::path::{Component, Path};
 
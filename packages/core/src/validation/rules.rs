@@ -0,0 +1,303 @@
+// ABOUTME: Configurable project validation rules layered on top of the built-in checks
+// ABOUTME: Name patterns, required tags, and project root allowlists loaded from system settings
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::{validate_project_data, validate_project_update, ValidationError, ValidationReport};
+use crate::types::{ProjectCreateInput, ProjectUpdateInput};
+
+/// Extra pattern project names must match; empty for none
+pub const NAME_PATTERN_SETTING: &str = "project_name_pattern";
+/// Comma-separated tags every project must carry; empty for none
+pub const REQUIRED_TAGS_SETTING: &str = "project_required_tags";
+/// In `strict` mode project roots must be inside the allowed browse paths
+pub const SANDBOX_MODE_SETTING: &str = "browse_sandbox_mode";
+pub const ALLOWED_PATHS_SETTING: &str = "allowed_browse_paths";
+
+/// Settings the rules are loaded from
+pub const RULE_SETTINGS: &[&str] = &[
+    NAME_PATTERN_SETTING,
+    REQUIRED_TAGS_SETTING,
+    SANDBOX_MODE_SETTING,
+    ALLOWED_PATHS_SETTING,
+];
+
+/// Configurable constraints checked after the built-in project validation.
+/// The default has no extra constraints.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationRules {
+    pub name_pattern: Option<Regex>,
+    pub required_tags: Vec<String>,
+    /// Directories project roots must be inside; `None` allows any directory
+    pub allowed_roots: Option<Vec<String>>,
+}
+
+impl ValidationRules {
+    /// Build rules from setting values keyed by setting name. Missing or empty
+    /// settings add no constraint.
+    pub fn from_settings(settings: &HashMap<String, String>) -> Result<Self, ValidationError> {
+        let setting = |key: &str| {
+            settings
+                .get(key)
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+        };
+
+        let name_pattern = setting(NAME_PATTERN_SETTING)
+            .map(parse_name_pattern)
+            .transpose()?;
+        let required_tags = setting(REQUIRED_TAGS_SETTING)
+            .map(split_list)
+            .unwrap_or_default();
+        let strict =
+            setting(SANDBOX_MODE_SETTING).is_some_and(|mode| mode.eq_ignore_ascii_case("strict"));
+        let allowed_roots = strict.then(|| {
+            setting(ALLOWED_PATHS_SETTING)
+                .map(split_list)
+                .unwrap_or_default()
+        });
+
+        Ok(Self {
+            name_pattern,
+            required_tags,
+            allowed_roots,
+        })
+    }
+
+    /// Built-in checks plus the configured rules for a new project
+    pub async fn validate_create(
+        &self,
+        data: &ProjectCreateInput,
+        allow_nonexistent_path: bool,
+    ) -> ValidationReport {
+        let mut report =
+            ValidationReport::from(validate_project_data(data, allow_nonexistent_path).await);
+        self.check_name(&data.name, &mut report);
+        self.check_root(&data.project_root, &mut report);
+        self.check_tags(data.tags.as_deref().unwrap_or_default(), &mut report);
+        report
+    }
+
+    /// Built-in checks plus the configured rules for the fields an update changes
+    pub async fn validate_update(
+        &self,
+        data: &ProjectUpdateInput,
+        allow_nonexistent_path: bool,
+    ) -> ValidationReport {
+        let mut report =
+            ValidationReport::from(validate_project_update(data, allow_nonexistent_path).await);
+        if let Some(ref name) = data.name {
+            self.check_name(name, &mut report);
+        }
+        if let Some(ref project_root) = data.project_root {
+            self.check_root(project_root, &mut report);
+        }
+        if let Some(ref tags) = data.tags {
+            self.check_tags(tags, &mut report);
+        }
+        report
+    }
+
+    // Field checks skip fields that already failed a built-in check, so each
+    // field reports its most basic problem first
+
+    fn check_name(&self, name: &str, report: &mut ValidationReport) {
+        let Some(ref pattern) = self.name_pattern else {
+            return;
+        };
+        if report.has_field("name") || pattern.is_match(name) {
+            return;
+        }
+        report.push(ValidationError::new(
+            "name",
+            format!(
+                "Name does not match the required pattern '{}'",
+                pattern.as_str()
+            ),
+        ));
+    }
+
+    fn check_root(&self, project_root: &str, report: &mut ValidationReport) {
+        let Some(ref allowed_roots) = self.allowed_roots else {
+            return;
+        };
+        if report.has_field("projectRoot") {
+            return;
+        }
+
+        let root = expand_home(project_root);
+        if allowed_roots
+            .iter()
+            .any(|allowed| root.starts_with(expand_home(allowed)))
+        {
+            return;
+        }
+
+        let message = if allowed_roots.is_empty() {
+            "Strict sandbox mode is on and no project directories are allowed. Add directories to allowed_browse_paths.".to_string()
+        } else {
+            format!(
+                "Strict sandbox mode only allows projects inside: {}",
+                allowed_roots.join(", ")
+            )
+        };
+        report.push(ValidationError::new("projectRoot", message));
+    }
+
+    fn check_tags(&self, tags: &[String], report: &mut ValidationReport) {
+        let missing: Vec<&str> = self
+            .required_tags
+            .iter()
+            .filter(|required| !tags.iter().any(|tag| tag.eq_ignore_ascii_case(required)))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            report.push(ValidationError::new(
+                "tags",
+                format!("Missing required tags: {}", missing.join(", ")),
+            ));
+        }
+    }
+}
+
+/// Compile a configured name pattern, reporting problems against its setting
+pub fn parse_name_pattern(pattern: &str) -> Result<Regex, ValidationError> {
+    Regex::new(pattern).map_err(|e| {
+        ValidationError::new(
+            NAME_PATTERN_SETTING,
+            format!("Invalid name pattern '{}': {}", pattern, e),
+        )
+    })
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix('~'), dirs::home_dir()) {
+        (Some(rest), Some(home)) if rest.is_empty() || rest.starts_with('/') => {
+            home.join(rest.trim_start_matches('/'))
+        }
+        _ => Path::new(path).to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn project(name: &str, root: &str, tags: &[&str]) -> ProjectCreateInput {
+        ProjectCreateInput {
+            name: name.to_string(),
+            project_root: root.to_string(),
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            tags: Some(tags.iter().map(|tag| tag.to_string()).collect()),
+            description: None,
+            status: None,
+            rank: None,
+            priority: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_configured_rules_report_every_field() {
+        let rules = ValidationRules::from_settings(&settings(&[
+            (NAME_PATTERN_SETTING, "^team-"),
+            (REQUIRED_TAGS_SETTING, "owner, tier"),
+            (SANDBOX_MODE_SETTING, "strict"),
+            (ALLOWED_PATHS_SETTING, "/srv/projects"),
+        ]))
+        .unwrap();
+
+        let report = rules
+            .validate_create(&project("web-app", "/home/me/web", &["tier"]), true)
+            .await;
+        let fields = report.by_field();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields["tags"], vec!["Missing required tags: owner"]);
+        assert!(fields["name"][0].contains("^team-"));
+        assert!(fields["projectRoot"][0].contains("/srv/projects"));
+
+        let report = rules
+            .validate_create(
+                &project("team-web", "/srv/projects/web", &["Owner", "tier"]),
+                true,
+            )
+            .await;
+        assert!(report.is_empty(), "{}", report);
+    }
+
+    #[tokio::test]
+    async fn test_relaxed_mode_and_empty_settings_add_no_rules() {
+        let rules = ValidationRules::from_settings(&settings(&[
+            (NAME_PATTERN_SETTING, ""),
+            (SANDBOX_MODE_SETTING, "relaxed"),
+            (ALLOWED_PATHS_SETTING, "/srv/projects"),
+        ]))
+        .unwrap();
+        assert!(rules.name_pattern.is_none());
+        assert!(rules.allowed_roots.is_none());
+
+        let report = rules
+            .validate_create(&project("web-app", "/home/me/web", &[]), true)
+            .await;
+        assert!(report.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_updates_only_check_changed_fields() {
+        let rules =
+            ValidationRules::from_settings(&settings(&[(REQUIRED_TAGS_SETTING, "owner")])).unwrap();
+        let update: ProjectUpdateInput =
+            serde_json::from_value(serde_json::json!({ "description": "New description" }))
+                .unwrap();
+        assert!(rules.validate_update(&update, true).await.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_name_pattern_is_rejected() {
+        let error =
+            ValidationRules::from_settings(&settings(&[(NAME_PATTERN_SETTING, "(unclosed")]))
+                .unwrap_err();
+        assert_eq!(error.field, NAME_PATTERN_SETTING);
+    }
+
+    #[test]
+    fn test_report_serializes_errors_by_field() {
+        let report = ValidationReport::from(vec![
+            ValidationError::new("name", "too short"),
+            ValidationError::new("name", "bad pattern"),
+            ValidationError::new("tags", "missing owner"),
+        ]);
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["errors"].as_array().unwrap().len(), 3);
+        assert_eq!(
+            json["fields"]["name"],
+            serde_json::json!(["too short", "bad pattern"])
+        );
+        assert_eq!(
+            report.to_string(),
+            "name: too short; name: bad pattern; tags: missing owner"
+        );
+    }
+}
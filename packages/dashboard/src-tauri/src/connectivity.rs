@@ -0,0 +1,330 @@
+// ABOUTME: Connectivity monitor for the Orkee CLI server sidecar
+// ABOUTME: Health-checks the API, emits online/offline events, defers tray refreshes, and reconnects on demand
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, error, info, warn};
+
+use crate::tray::{get_api_host, TrayManager};
+use crate::{sidecar, CliServerState, CLEANUP_DONE};
+
+const CONNECTIVITY_POLL_INTERVAL_SECS: u64 = 3;
+const HEALTH_CHECK_TIMEOUT_SECS: u64 = 2;
+/// Consecutive failed health checks before the API is reported offline, so a
+/// single slow response doesn't flip the UI into degraded mode
+const OFFLINE_FAILURE_THRESHOLD: u32 = 2;
+/// How long `reconnect` waits for a relaunched server to answer
+const RECONNECT_WAIT_SECS: u64 = 15;
+
+/// Event emitted whenever the API goes offline or comes back online
+pub const CONNECTIVITY_CHANGED_EVENT: &str = "connectivity-changed";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectivityStatus {
+    Online,
+    Offline,
+}
+
+/// Payload of [`CONNECTIVITY_CHANGED_EVENT`] and the result of the connectivity commands
+#[derive(Clone, Debug, Serialize)]
+pub struct ConnectivitySnapshot {
+    pub status: ConnectivityStatus,
+    pub api_port: u16,
+    /// Unix timestamp (seconds) of the last status change
+    pub since: u64,
+    pub consecutive_failures: u32,
+    /// Why the API was last considered unreachable
+    pub reason: Option<String>,
+}
+
+/// Shared connectivity state, managed by Tauri
+pub struct ConnectivityState {
+    online: AtomicBool,
+    consecutive_failures: AtomicU32,
+    since: Mutex<u64>,
+    reason: Mutex<Option<String>>,
+}
+
+impl ConnectivityState {
+    /// The sidecar was just started, so assume it's reachable until a check says otherwise
+    pub fn new() -> Self {
+        Self {
+            online: AtomicBool::new(true),
+            consecutive_failures: AtomicU32::new(0),
+            since: Mutex::new(unix_now()),
+            reason: Mutex::new(None),
+        }
+    }
+
+    pub fn is_online(&self) -> bool {
+        self.online.load(Ordering::SeqCst)
+    }
+
+    fn snapshot(&self, api_port: u16) -> ConnectivitySnapshot {
+        ConnectivitySnapshot {
+            status: if self.is_online() {
+                ConnectivityStatus::Online
+            } else {
+                ConnectivityStatus::Offline
+            },
+            api_port,
+            since: *lock(&self.since),
+            consecutive_failures: self.consecutive_failures.load(Ordering::SeqCst),
+            reason: lock(&self.reason).clone(),
+        }
+    }
+
+    /// Record a successful health check. Returns true if this brought the API back online.
+    fn record_success(&self) -> bool {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+        *lock(&self.reason) = None;
+        let changed = !self.online.swap(true, Ordering::SeqCst);
+        if changed {
+            *lock(&self.since) = unix_now();
+        }
+        changed
+    }
+
+    /// Record a failed health check. Returns true if this took the API offline.
+    ///
+    /// `immediate` skips the failure threshold, for failures that are known to
+    /// be real (e.g. the sidecar process exited).
+    fn record_failure(&self, reason: String, immediate: bool) -> bool {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+        *lock(&self.reason) = Some(reason);
+        if !immediate && failures < OFFLINE_FAILURE_THRESHOLD {
+            return false;
+        }
+        let changed = self.online.swap(false, Ordering::SeqCst);
+        if changed {
+            *lock(&self.since) = unix_now();
+        }
+        changed
+    }
+}
+
+impl Default for ConnectivityState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Start health-checking the CLI server in the background.
+///
+/// The first check runs after one poll interval, giving the freshly spawned
+/// sidecar time to bind its port.
+pub fn start_monitoring(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = match health_client() {
+            Ok(client) => client,
+            Err(e) => {
+                warn!(
+                    "Failed to create HTTP client for connectivity checks: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        info!("Connectivity monitoring started");
+        loop {
+            tokio::time::sleep(Duration::from_secs(CONNECTIVITY_POLL_INTERVAL_SECS)).await;
+            if CLEANUP_DONE.load(Ordering::SeqCst) {
+                info!("Connectivity monitoring stopped");
+                break;
+            }
+            check_now(&app, &client).await;
+        }
+    });
+}
+
+/// Mark the API offline right away, without waiting for health checks to fail.
+///
+/// Used when the sidecar is known to be gone, so the frontend can switch to
+/// degraded mode instead of waiting on requests that will never complete.
+pub fn report_unreachable(app: &AppHandle, reason: &str) {
+    let Some(state) = app.try_state::<ConnectivityState>() else {
+        return;
+    };
+    if state.record_failure(reason.to_string(), true) {
+        went_offline(app, &state);
+    }
+}
+
+/// Run one health check and update the connectivity state.
+///
+/// # Returns
+///
+/// Returns `true` if the API answered the health check.
+async fn check_now(app: &AppHandle, client: &reqwest::Client) -> bool {
+    let (Some(state), Some(api_port)) =
+        (app.try_state::<ConnectivityState>(), current_api_port(app))
+    else {
+        return false;
+    };
+
+    match check_health(client, api_port).await {
+        Ok(()) => {
+            if state.record_success() {
+                info!("CLI server on port {} is reachable again", api_port);
+                if let Some(tray_manager) = app.try_state::<TrayManager>() {
+                    tray_manager.set_offline(false);
+                }
+                emit(app, state.snapshot(api_port));
+            }
+            true
+        }
+        Err(e) => {
+            debug!("Health check on port {} failed: {}", api_port, e);
+            if state.record_failure(e.to_string(), false) {
+                went_offline(app, &state);
+            }
+            false
+        }
+    }
+}
+
+fn went_offline(app: &AppHandle, state: &ConnectivityState) {
+    let api_port = current_api_port(app).unwrap_or_default();
+    warn!(
+        "CLI server on port {} is unreachable, entering degraded mode",
+        api_port
+    );
+    if let Some(tray_manager) = app.try_state::<TrayManager>() {
+        tray_manager.set_offline(true);
+    }
+    emit(app, state.snapshot(api_port));
+}
+
+async fn check_health(
+    client: &reqwest::Client,
+    api_port: u16,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let url = format!("http://{}:{}/api/health", get_api_host(), api_port);
+    let response = client.get(&url).send().await?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP error: {}", response.status()).into());
+    }
+    Ok(())
+}
+
+fn health_client() -> Result<reqwest::Client, reqwest::Error> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(HEALTH_CHECK_TIMEOUT_SECS))
+        .build()
+}
+
+fn current_api_port(app: &AppHandle) -> Option<u16> {
+    app.try_state::<CliServerState>()
+        .map(|state| state.api_port.load(Ordering::SeqCst))
+}
+
+fn emit(app: &AppHandle, snapshot: ConnectivitySnapshot) {
+    if let Err(e) = app.emit(CONNECTIVITY_CHANGED_EVENT, snapshot) {
+        error!("Failed to emit {} event: {}", CONNECTIVITY_CHANGED_EVENT, e);
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Get the current connectivity status of the CLI server.
+///
+/// # Returns
+///
+/// Returns the latest [`ConnectivitySnapshot`] without running a new check.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if connectivity monitoring has not been set up.
+#[tauri::command]
+pub fn get_connectivity_status(app_handle: AppHandle) -> Result<ConnectivitySnapshot, String> {
+    let state = app_handle
+        .try_state::<ConnectivityState>()
+        .ok_or_else(|| "Connectivity monitor not available".to_string())?;
+    Ok(state.snapshot(current_api_port(&app_handle).unwrap_or_default()))
+}
+
+/// Check the CLI server immediately and relaunch it if it has been given up on.
+///
+/// While the sidecar supervisor is still retrying, this only re-checks health;
+/// once it has abandoned the server, a fresh process is started and awaited.
+///
+/// # Returns
+///
+/// Returns the resulting [`ConnectivitySnapshot`]. A `connectivity-changed`
+/// event is also emitted if the status changed.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if monitoring is not set up, the HTTP client cannot
+/// be created, or the CLI server cannot be relaunched.
+#[tauri::command]
+pub async fn reconnect(app_handle: AppHandle) -> Result<ConnectivitySnapshot, String> {
+    let client = health_client().map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    if !check_now(&app_handle, &client).await && !sidecar::is_supervised() {
+        info!("Reconnect requested with no running CLI server, relaunching it");
+        sidecar::relaunch(&app_handle)?;
+
+        for _ in 0..RECONNECT_WAIT_SECS {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            if check_now(&app_handle, &client).await {
+                break;
+            }
+        }
+    }
+
+    get_connectivity_status(app_handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_after_failure_threshold() {
+        let state = ConnectivityState::new();
+        assert!(state.is_online());
+
+        assert!(!state.record_failure("refused".to_string(), false));
+        assert!(state.is_online());
+        assert!(state.record_failure("refused".to_string(), false));
+        assert!(!state.is_online());
+
+        // Further failures don't report another transition
+        assert!(!state.record_failure("refused".to_string(), false));
+        let snapshot = state.snapshot(4001);
+        assert_eq!(snapshot.status, ConnectivityStatus::Offline);
+        assert_eq!(snapshot.consecutive_failures, 3);
+        assert_eq!(snapshot.reason.as_deref(), Some("refused"));
+    }
+
+    #[test]
+    fn test_immediate_failure_and_recovery() {
+        let state = ConnectivityState::new();
+        assert!(state.record_failure("exited".to_string(), true));
+        assert!(!state.is_online());
+
+        assert!(state.record_success());
+        assert!(!state.record_success());
+        let snapshot = state.snapshot(4001);
+        assert_eq!(snapshot.status, ConnectivityStatus::Online);
+        assert_eq!(snapshot.consecutive_failures, 0);
+        assert!(snapshot.reason.is_none());
+    }
+}
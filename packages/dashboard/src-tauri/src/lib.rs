@@ -1,14 +1,15 @@
 use orkee_config::constants;
 use orkee_config::env::parse_env_with_fallback;
 use std::panic::{catch_unwind, AssertUnwindSafe};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
 use std::sync::Mutex;
 use tauri::Manager;
-use tauri_plugin_shell::process::CommandEvent;
-use tauri_plugin_shell::ShellExt;
 use tracing::{debug, error, info, warn};
 
+mod connectivity;
+mod notifications;
 mod server_restart;
+mod sidecar;
 mod tray;
 use tray::TrayManager;
 
@@ -18,7 +19,9 @@ static CLEANUP_DONE: AtomicBool = AtomicBool::new(false);
 // Store the CLI server process handle and ports globally
 struct CliServerState {
     process: Mutex<Option<tauri_plugin_shell::process::CommandChild>>,
-    api_port: u16,
+    // Atomic because the sidecar supervisor may rebind to a new port on respawn
+    api_port: AtomicU16,
+    ui_port: u16,
 }
 
 /// Terminate the Orkee CLI server process.
@@ -197,7 +200,7 @@ fn perform_cleanup_once(app_handle: &tauri::AppHandle, context: &str) {
 /// Returns the API port number as a `u16`.
 #[tauri::command]
 fn get_api_port(state: tauri::State<CliServerState>) -> u16 {
-    state.api_port
+    state.api_port.load(Ordering::SeqCst)
 }
 
 /// Get the API token for authenticating with the CLI server.
@@ -398,6 +401,18 @@ fn get_cli_prompt_preference() -> String {
 /// Returns `Ok(())` on success, or `Err(String)` with error details.
 #[tauri::command]
 fn set_cli_prompt_preference(preference: String) -> Result<(), String> {
+    write_config_value(
+        "cli_prompt_preference",
+        serde_json::Value::String(preference),
+    )
+}
+
+/// Set one key in ~/.orkee/config.json, preserving the other keys.
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or `Err(String)` with error details.
+fn write_config_value(key: &str, value: serde_json::Value) -> Result<(), String> {
     let home_dir =
         dirs::home_dir().ok_or_else(|| "Could not determine home directory".to_string())?;
 
@@ -417,12 +432,9 @@ fn set_cli_prompt_preference(preference: String) -> Result<(), String> {
         serde_json::json!({})
     };
 
-    // Update the preference
+    // Update the value
     if let Some(obj) = config.as_object_mut() {
-        obj.insert(
-            "cli_prompt_preference".to_string(),
-            serde_json::Value::String(preference),
-        );
+        obj.insert(key.to_string(), value);
     }
 
     // Write back to file
@@ -478,60 +490,64 @@ fn find_available_port() -> Result<u16, String> {
         .ok_or_else(|| "Failed to find an available port in the system".to_string())
 }
 
-/// Log output from the CLI server sidecar process.
+/// Read the API port used by the previous launch from ~/.orkee/config.json.
+fn load_last_api_port() -> Option<u16> {
+    let config_path = dirs::home_dir()?.join(".orkee").join("config.json");
+    let contents = std::fs::read_to_string(config_path).ok()?;
+    let config = serde_json::from_str::<serde_json::Value>(&contents).ok()?;
+    config
+        .get("last_api_port")
+        .and_then(|v| v.as_u64())
+        .and_then(|port| u16::try_from(port).ok())
+        .filter(|port| *port != 0)
+}
+
+/// Remember the API port so the next launch can rebind it.
 ///
-/// Spawns a background task that listens to the sidecar's stdout and stderr streams
-/// and logs them to the console. This is essential for debugging server startup issues
-/// and monitoring server behavior.
+/// Failures are logged rather than returned: losing the saved port only
+/// means the next launch picks a new one.
+fn save_api_port(port: u16) {
+    match write_config_value("last_api_port", serde_json::Value::from(port)) {
+        Ok(()) => debug!("Saved API port {} for the next launch", port),
+        Err(e) => warn!("Failed to save API port {}: {}", port, e),
+    }
+}
+
+/// Choose the API port for the CLI server.
 ///
-/// # Arguments
+/// Reuses the port from the previous launch when it is free, so saved dashboard
+/// URLs and OAuth callbacks keep working across restarts, and falls back to a
+/// random available port otherwise.
 ///
-/// * `rx` - Receiver for command events (stdout, stderr, errors, termination)
+/// # Returns
 ///
-/// # Notes
+/// Returns `Ok(u16)` with the port to bind.
 ///
-/// The task runs until the receiver is closed (when the sidecar process exits).
-/// Output is logged with appropriate prefixes to distinguish stdout from stderr.
-fn log_sidecar_output(mut rx: tauri::async_runtime::Receiver<CommandEvent>) {
-    tauri::async_runtime::spawn(async move {
-        while let Some(event) = rx.recv().await {
-            match event {
-                CommandEvent::Stdout(line) => {
-                    if let Ok(output) = String::from_utf8(line) {
-                        info!("[CLI Server] {}", output.trim_end());
-                    }
-                }
-                CommandEvent::Stderr(line) => {
-                    if let Ok(output) = String::from_utf8(line) {
-                        warn!("[CLI Server Error] {}", output.trim_end());
-                    }
-                }
-                CommandEvent::Error(err) => {
-                    error!("[CLI Server] Command error: {}", err);
-                }
-                CommandEvent::Terminated(payload) => {
-                    if let Some(code) = payload.code {
-                        info!("[CLI Server] Process terminated with exit code: {}", code);
-                    } else {
-                        info!("[CLI Server] Process terminated");
-                    }
-                }
-                _ => {}
-            }
+/// # Errors
+///
+/// Returns `Err(String)` if the saved port is busy and no other port is available.
+fn choose_api_port() -> Result<u16, String> {
+    if let Some(port) = load_last_api_port() {
+        if portpicker::is_free(port) {
+            info!("Reusing API port {} from the previous launch", port);
+            return Ok(port);
         }
-    });
+        warn!("Previous API port {} is in use, picking a new one", port);
+    }
+    find_available_port()
 }
 
 /// Main entry point for the Tauri application.
 ///
 /// Initializes and runs the Orkee dashboard application with the following features:
-/// - Spawns the Orkee CLI server as a sidecar process
+/// - Spawns the Orkee CLI server as a sidecar process and respawns it if it dies
 /// - Manages system tray with server status
+/// - Reports when the CLI server becomes unreachable and supports reconnecting
 /// - Handles graceful shutdown and cleanup
 /// - Configures window behavior (minimize to tray, macOS activation policy)
 ///
 /// The application performs these key operations on startup:
-/// 1. Finds an available port for the API server
+/// 1. Reuses the previous launch's API port, or finds an available one
 /// 2. Spawns the CLI server with appropriate flags (dev mode in debug builds)
 /// 3. Initializes the system tray
 /// 4. Shows and focuses the main window
@@ -595,8 +611,8 @@ pub fn run() {
             #[cfg(target_os = "macos")]
             app.set_activation_policy(tauri::ActivationPolicy::Regular);
 
-            // Find available port dynamically
-            let api_port = match find_available_port() {
+            // Prefer the port from the previous launch, falling back to a random one
+            let api_port = match choose_api_port() {
                 Ok(port) => port,
                 Err(e) => {
                     error!("Critical error: {}", e);
@@ -607,52 +623,26 @@ pub fn run() {
             // Get UI port from environment or use default
             let ui_port: u16 = parse_env_with_fallback(constants::ORKEE_UI_PORT, "VITE_PORT", 5173);
 
-            info!("Using dynamic API port: {} and UI port: {}", api_port, ui_port);
-
-            // Start the Orkee CLI server as a sidecar
-            let shell = app.shell();
-
-            // Get the sidecar command for the orkee binary
-            let sidecar_command = match shell.sidecar("orkee") {
-                Ok(cmd) => cmd,
-                Err(e) => {
-                    error!("Failed to create sidecar command for orkee binary: {}", e);
-                    error!("This usually means the orkee binary is not found or not properly configured");
-                    return Err(Box::new(e));
-                }
-            };
+            info!("Using API port: {} and UI port: {}", api_port, ui_port);
 
-            // Build args dynamically based on build profile
-            let mut args = vec!["dashboard"];
-            #[cfg(debug_assertions)]
-            args.push("--dev");  // Use local dashboard in dev mode
-            let api_port_str = api_port.to_string();
-            let ui_port_str = ui_port.to_string();
-            args.extend(["--api-port", &api_port_str, "--ui-port", &ui_port_str]);
-
-            // Spawn the CLI server with dashboard command and log its output
-            let child = match sidecar_command
-                .args(args)
-                .env("ORKEE_DEV_MODE", std::env::var("ORKEE_DEV_MODE").unwrap_or_default())
-                .spawn()
-            {
-                Ok((rx, child)) => {
-                    log_sidecar_output(rx);
-                    child
-                }
+            // Start the Orkee CLI server as a sidecar and log its output
+            let (rx, child) = match sidecar::spawn_cli_server(app.handle(), api_port, ui_port) {
+                Ok(spawned) => spawned,
                 Err(e) => {
                     error!("Failed to spawn orkee CLI server process: {}", e);
-                    error!("Check that the orkee binary has execute permissions and is not corrupted");
+                    error!("Check that the orkee binary is present, has execute permissions, and is not corrupted");
                     return Err(Box::new(e));
                 }
             };
 
             info!("Started Orkee CLI server on port {}", api_port);
+            save_api_port(api_port);
 
-            // Store the process handle and port so we can access them later
+            // Store the process handle and ports so we can access them later
             app.manage(CliServerState {
                 process: Mutex::new(Some(child)),
-                api_port,
+                api_port: AtomicU16::new(api_port),
+                ui_port,
             });
 
             // Initialize the tray
@@ -663,6 +653,16 @@ pub fn run() {
             }
             app.manage(tray_manager);
 
+            // Show notifications routed to the desktop channel
+            notifications::start_polling(app.handle().clone());
+
+            // Tell the frontend when the API becomes unreachable instead of leaving requests hanging
+            app.manage(connectivity::ConnectivityState::new());
+            connectivity::start_monitoring(app.handle().clone());
+
+            // Watch the sidecar once its state and the tray exist, so respawns can update both
+            sidecar::supervise(app.handle().clone(), rx);
+
             // Show and focus the main window on startup
             if let Some(window) = app.get_webview_window("main") {
                 let _ = window.show();
@@ -687,7 +687,9 @@ pub fn run() {
             install_cli_macos,
             get_cli_prompt_preference,
             set_cli_prompt_preference,
-            force_refresh_tray
+            force_refresh_tray,
+            connectivity::get_connectivity_status,
+            connectivity::reconnect
         ])
         .on_window_event(|window, event| {
             match event {
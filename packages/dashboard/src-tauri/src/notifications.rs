@@ -0,0 +1,121 @@
+// ABOUTME: Desktop notification poller for the Orkee notification feed
+// ABOUTME: Polls the API for notifications routed to the desktop channel and shows them natively
+
+use orkee_preview::types::ApiResponse;
+use serde::Deserialize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+use tracing::{debug, info, warn};
+
+use crate::tray::get_api_host;
+use crate::{get_api_token, CliServerState, CLEANUP_DONE};
+
+const NOTIFICATION_POLL_INTERVAL_SECS: u64 = 5;
+const NOTIFICATION_REQUEST_TIMEOUT_SECS: u64 = 5;
+/// Upper bound on notifications shown per poll, so a backlog doesn't flood the desktop
+const MAX_NOTIFICATIONS_PER_POLL: usize = 5;
+
+#[derive(Debug, Deserialize)]
+struct FeedNotification {
+    title: String,
+    message: String,
+    created_at: String,
+}
+
+/// Start polling the notification feed in the background.
+///
+/// Only notifications created after the poller starts are shown; the first
+/// successful poll records the newest existing notification as the baseline.
+pub fn start_polling(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let client = match reqwest::Client::builder()
+            .timeout(Duration::from_secs(NOTIFICATION_REQUEST_TIMEOUT_SECS))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("Failed to create HTTP client for notifications: {}", e);
+                return;
+            }
+        };
+
+        info!("Desktop notification polling started");
+        let mut since: Option<String> = None;
+        let mut has_baseline = false;
+
+        loop {
+            tokio::time::sleep(Duration::from_secs(NOTIFICATION_POLL_INTERVAL_SECS)).await;
+            if CLEANUP_DONE.load(Ordering::SeqCst) {
+                info!("Desktop notification polling stopped");
+                break;
+            }
+
+            let Some(api_port) = app
+                .try_state::<CliServerState>()
+                .map(|state| state.api_port.load(Ordering::SeqCst))
+            else {
+                continue;
+            };
+
+            let notifications = match fetch_notifications(&client, api_port, since.as_deref()).await
+            {
+                Ok(notifications) => notifications,
+                Err(e) => {
+                    debug!("Failed to poll notifications: {}", e);
+                    continue;
+                }
+            };
+
+            // The feed is newest first
+            if let Some(newest) = notifications.first() {
+                since = Some(newest.created_at.clone());
+            }
+
+            if !has_baseline {
+                has_baseline = true;
+                continue;
+            }
+
+            for notification in notifications.iter().take(MAX_NOTIFICATIONS_PER_POLL).rev() {
+                if let Err(e) = app
+                    .notification()
+                    .builder()
+                    .title(&notification.title)
+                    .body(&notification.message)
+                    .show()
+                {
+                    warn!("Failed to show desktop notification: {}", e);
+                }
+            }
+        }
+    });
+}
+
+async fn fetch_notifications(
+    client: &reqwest::Client,
+    api_port: u16,
+    since: Option<&str>,
+) -> Result<Vec<FeedNotification>, Box<dyn std::error::Error + Send + Sync>> {
+    let url = format!("http://{}:{}/api/notifications", get_api_host(), api_port);
+    let mut query = vec![("channel", "desktop")];
+    if let Some(since) = since {
+        query.push(("since", since));
+    }
+
+    let mut request = client.get(&url).query(&query);
+    if let Ok(token) = get_api_token() {
+        request = request.header("X-API-Token", token);
+    }
+
+    let response = request.send().await?;
+    if !response.status().is_success() {
+        return Err(format!("HTTP error: {}", response.status()).into());
+    }
+
+    let api_response: ApiResponse<Vec<FeedNotification>> = response.json().await?;
+    api_response
+        .data
+        .ok_or_else(|| "API response missing data field".into())
+}
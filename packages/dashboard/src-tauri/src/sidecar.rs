@@ -0,0 +1,361 @@
+// ABOUTME: Supervisor for the Orkee CLI server sidecar process
+// ABOUTME: Detects unexpected termination, respawns with backoff, rebinds the port, and notifies the frontend
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent, TerminatedPayload};
+use tauri_plugin_shell::ShellExt;
+use tracing::{error, info, warn};
+
+use crate::connectivity;
+use crate::tray::TrayManager;
+use crate::{CliServerState, CLEANUP_DONE};
+
+// Respawn backoff constants
+const SIDECAR_RESPAWN_MAX_ATTEMPTS: u32 = 5;
+const SIDECAR_RESPAWN_INITIAL_DELAY_MS: u64 = 500;
+const SIDECAR_RESPAWN_MAX_DELAY_MS: u64 = 10_000;
+/// A server that stayed up this long is considered healthy, so the attempt counter resets
+const SIDECAR_STABLE_RUN_SECS: u64 = 60;
+
+/// Set while a supervisor task is following a CLI server process
+static SUPERVISED: AtomicBool = AtomicBool::new(false);
+
+/// Event emitted when the CLI server exits unexpectedly
+pub const SIDECAR_TERMINATED_EVENT: &str = "sidecar-terminated";
+/// Event emitted after the CLI server has been respawned
+pub const SIDECAR_RESTARTED_EVENT: &str = "sidecar-restarted";
+/// Event emitted when respawning is abandoned
+pub const SIDECAR_FAILED_EVENT: &str = "sidecar-failed";
+
+#[derive(Clone, Serialize)]
+struct SidecarTerminated {
+    code: Option<i32>,
+    signal: Option<i32>,
+    will_restart: bool,
+}
+
+#[derive(Clone, Serialize)]
+struct SidecarRestarted {
+    api_port: u16,
+    previous_port: u16,
+    attempt: u32,
+}
+
+#[derive(Clone, Serialize)]
+struct SidecarFailed {
+    attempts: u32,
+    message: String,
+}
+
+/// Spawn the Orkee CLI server sidecar on the given ports.
+///
+/// # Returns
+///
+/// Returns the event receiver and process handle. The receiver must be passed
+/// to [`supervise`] so output is logged and termination is detected.
+pub fn spawn_cli_server(
+    app: &AppHandle,
+    api_port: u16,
+    ui_port: u16,
+) -> Result<(tauri::async_runtime::Receiver<CommandEvent>, CommandChild), tauri_plugin_shell::Error>
+{
+    let sidecar_command = app.shell().sidecar("orkee")?;
+
+    // Build args dynamically based on build profile
+    let mut args = vec!["dashboard"];
+    #[cfg(debug_assertions)]
+    args.push("--dev"); // Use local dashboard in dev mode
+    let api_port_str = api_port.to_string();
+    let ui_port_str = ui_port.to_string();
+    args.extend(["--api-port", &api_port_str, "--ui-port", &ui_port_str]);
+
+    sidecar_command
+        .args(args)
+        .env(
+            "ORKEE_DEV_MODE",
+            std::env::var("ORKEE_DEV_MODE").unwrap_or_default(),
+        )
+        .spawn()
+}
+
+/// Log sidecar output and respawn the CLI server if it exits unexpectedly.
+///
+/// Spawns a background task that follows the sidecar's event stream. When the
+/// process terminates outside of app shutdown, the server is restarted with
+/// exponential backoff. The same port is reused when it is free; otherwise a
+/// new port is picked and the tray and frontend are pointed at it.
+pub fn supervise(app: AppHandle, rx: tauri::async_runtime::Receiver<CommandEvent>) {
+    SUPERVISED.store(true, Ordering::SeqCst);
+    tauri::async_runtime::spawn(async move {
+        follow_restarts(&app, rx).await;
+        SUPERVISED.store(false, Ordering::SeqCst);
+    });
+}
+
+/// Whether a supervisor is still following (or respawning) the CLI server
+pub fn is_supervised() -> bool {
+    SUPERVISED.load(Ordering::SeqCst)
+}
+
+/// Start a fresh CLI server after the supervisor has given up on the last one.
+///
+/// The previous port is reused when free. The new process is supervised like
+/// the original, and the tray and frontend are pointed at it.
+///
+/// # Errors
+///
+/// Returns `Err(String)` if the app state is unavailable, no port is free, or
+/// the sidecar cannot be spawned.
+pub fn relaunch(app: &AppHandle) -> Result<u16, String> {
+    // Claim supervision up front so concurrent reconnects don't spawn twice
+    if SUPERVISED.swap(true, Ordering::SeqCst) {
+        return Err("CLI server is already running or restarting".to_string());
+    }
+    let spawned = app
+        .try_state::<CliServerState>()
+        .ok_or_else(|| "CLI server state not available".to_string())
+        .and_then(|state| {
+            let previous_port = state.api_port.load(Ordering::SeqCst);
+            let api_port = if portpicker::is_free(previous_port) {
+                previous_port
+            } else {
+                crate::find_available_port()?
+            };
+            let (rx, child) = spawn_cli_server(app, api_port, state.ui_port)
+                .map_err(|e| format!("Failed to start CLI server: {}", e))?;
+            Ok((state, rx, child, api_port, previous_port))
+        });
+    let (state, rx, child, api_port, previous_port) = match spawned {
+        Ok(spawned) => spawned,
+        Err(e) => {
+            SUPERVISED.store(false, Ordering::SeqCst);
+            return Err(e);
+        }
+    };
+
+    adopt(app, &state, child, api_port, previous_port);
+    info!("CLI server relaunched on port {}", api_port);
+    emit(
+        app,
+        SIDECAR_RESTARTED_EVENT,
+        SidecarRestarted {
+            api_port,
+            previous_port,
+            attempt: 0,
+        },
+    );
+
+    supervise(app.clone(), rx);
+    Ok(api_port)
+}
+
+/// Follow the sidecar's events, respawning it each time it exits unexpectedly.
+///
+/// Returns once the app shuts down or respawning is abandoned.
+async fn follow_restarts(app: &AppHandle, mut rx: tauri::async_runtime::Receiver<CommandEvent>) {
+    let mut attempt: u32 = 0;
+    let mut started_at = Instant::now();
+
+    loop {
+        let Some(payload) = follow_output(&mut rx).await else {
+            // Channel closed without a termination event; nothing left to supervise
+            return;
+        };
+
+        if CLEANUP_DONE.load(Ordering::SeqCst) {
+            info!("[CLI Server] Stopped during shutdown, not restarting");
+            return;
+        }
+
+        if started_at.elapsed() >= Duration::from_secs(SIDECAR_STABLE_RUN_SECS) {
+            attempt = 0;
+        }
+        let will_restart = attempt < SIDECAR_RESPAWN_MAX_ATTEMPTS;
+
+        error!(
+            "[CLI Server] Exited unexpectedly (code: {:?}, signal: {:?})",
+            payload.code, payload.signal
+        );
+        connectivity::report_unreachable(app, "CLI server exited");
+        emit(
+            app,
+            SIDECAR_TERMINATED_EVENT,
+            SidecarTerminated {
+                code: payload.code,
+                signal: payload.signal,
+                will_restart,
+            },
+        );
+
+        if !will_restart {
+            give_up(app, attempt);
+            return;
+        }
+
+        match respawn(app, &mut attempt).await {
+            Some(next_rx) => {
+                rx = next_rx;
+                started_at = Instant::now();
+            }
+            None => {
+                if !CLEANUP_DONE.load(Ordering::SeqCst) {
+                    give_up(app, attempt);
+                }
+                return;
+            }
+        }
+    }
+}
+
+/// Log sidecar output until the process terminates.
+///
+/// Returns the termination payload, or `None` if the channel closed first.
+async fn follow_output(
+    rx: &mut tauri::async_runtime::Receiver<CommandEvent>,
+) -> Option<TerminatedPayload> {
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(line) => {
+                if let Ok(output) = String::from_utf8(line) {
+                    info!("[CLI Server] {}", output.trim_end());
+                }
+            }
+            CommandEvent::Stderr(line) => {
+                if let Ok(output) = String::from_utf8(line) {
+                    warn!("[CLI Server Error] {}", output.trim_end());
+                }
+            }
+            CommandEvent::Error(err) => {
+                error!("[CLI Server] Command error: {}", err);
+            }
+            CommandEvent::Terminated(payload) => {
+                if let Some(code) = payload.code {
+                    info!("[CLI Server] Process terminated with exit code: {}", code);
+                } else {
+                    info!("[CLI Server] Process terminated");
+                }
+                return Some(payload);
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Respawn the CLI server, retrying with exponential backoff.
+///
+/// Returns the new event receiver once a process is running, or `None` when
+/// all attempts are used up or the app started shutting down meanwhile.
+async fn respawn(
+    app: &AppHandle,
+    attempt: &mut u32,
+) -> Option<tauri::async_runtime::Receiver<CommandEvent>> {
+    let state = app.try_state::<CliServerState>()?;
+    let previous_port = state.api_port.load(Ordering::SeqCst);
+
+    while *attempt < SIDECAR_RESPAWN_MAX_ATTEMPTS {
+        // Exponential backoff: 500ms → 1s → 2s → 4s → 8s (capped at 10s)
+        let delay_ms =
+            (SIDECAR_RESPAWN_INITIAL_DELAY_MS << *attempt).min(SIDECAR_RESPAWN_MAX_DELAY_MS);
+        *attempt += 1;
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+
+        if CLEANUP_DONE.load(Ordering::SeqCst) {
+            return None;
+        }
+
+        // Reuse the port if the dead process released it, so open dashboards keep working
+        let api_port = if portpicker::is_free(previous_port) {
+            previous_port
+        } else {
+            match crate::find_available_port() {
+                Ok(port) => {
+                    warn!(
+                        "Port {} is still in use, rebinding CLI server to port {}",
+                        previous_port, port
+                    );
+                    port
+                }
+                Err(e) => {
+                    error!("Cannot respawn CLI server: {}", e);
+                    continue;
+                }
+            }
+        };
+
+        info!(
+            "Respawning CLI server on port {} (attempt {}/{})",
+            api_port, attempt, SIDECAR_RESPAWN_MAX_ATTEMPTS
+        );
+
+        match spawn_cli_server(app, api_port, state.ui_port) {
+            Ok((rx, child)) => {
+                adopt(app, &state, child, api_port, previous_port);
+                info!("CLI server respawned on port {}", api_port);
+                emit(
+                    app,
+                    SIDECAR_RESTARTED_EVENT,
+                    SidecarRestarted {
+                        api_port,
+                        previous_port,
+                        attempt: *attempt,
+                    },
+                );
+                return Some(rx);
+            }
+            Err(e) => {
+                error!("Failed to respawn CLI server (attempt {}): {}", attempt, e);
+            }
+        }
+    }
+
+    None
+}
+
+/// Store a newly spawned CLI server and point the tray at its port
+fn adopt(
+    app: &AppHandle,
+    state: &CliServerState,
+    child: CommandChild,
+    api_port: u16,
+    previous_port: u16,
+) {
+    match state.process.lock() {
+        Ok(mut process) => *process = Some(child),
+        Err(poisoned) => {
+            warn!("Process mutex was poisoned, storing respawned process anyway");
+            *poisoned.into_inner() = Some(child);
+        }
+    }
+    state.api_port.store(api_port, Ordering::SeqCst);
+    if api_port != previous_port {
+        crate::save_api_port(api_port);
+    }
+
+    if let Some(tray_manager) = app.try_state::<TrayManager>() {
+        tray_manager.set_api_port(api_port);
+        tray_manager.force_refresh();
+    }
+}
+
+fn give_up(app: &AppHandle, attempts: u32) {
+    let message = format!(
+        "The Orkee server stopped and could not be restarted after {} attempts. Please restart the app.",
+        attempts
+    );
+    error!("{}", message);
+    emit(
+        app,
+        SIDECAR_FAILED_EVENT,
+        SidecarFailed { attempts, message },
+    );
+}
+
+fn emit<S: Serialize + Clone>(app: &AppHandle, event: &str, payload: S) {
+    if let Err(e) = app.emit(event, payload) {
+        error!("Failed to emit {} event: {}", event, e);
+    }
+}
@@ -5,7 +5,7 @@ use orkee_config::constants;
 use orkee_config::env::parse_env_or_default_with_validation;
 use orkee_preview::types::{ApiResponse, ServerSource, ServerStatusInfo, ServersResponse};
 use orkee_preview::validation::validate_project_id;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 use tauri::{
@@ -128,7 +128,7 @@ fn validate_api_host(host: &str) -> Result<(), String> {
     ))
 }
 
-fn get_api_host() -> String {
+pub(crate) fn get_api_host() -> String {
     let host = std::env::var(constants::ORKEE_API_HOST).unwrap_or_else(|_| "localhost".to_string());
 
     // Validate the host before using it
@@ -207,10 +207,14 @@ fn sanitize_menu_text(text: &str) -> String {
 #[derive(Clone)]
 pub struct TrayManager {
     pub app_handle: AppHandle,
-    pub api_port: u16,
+    /// Shared so a respawned CLI server on a new port is picked up everywhere
+    api_port: Arc<AtomicU16>,
     tray_icon: Arc<Mutex<Option<TrayIcon>>>,
     shutdown_signal: Arc<AtomicBool>,
     http_client: Arc<reqwest::Client>,
+    /// Set while the CLI server is unreachable; refreshes are deferred until it returns
+    offline: Arc<AtomicBool>,
+    refresh_pending: Arc<AtomicBool>,
 }
 
 impl TrayManager {
@@ -226,13 +230,41 @@ impl TrayManager {
 
         Self {
             app_handle,
-            api_port,
+            api_port: Arc::new(AtomicU16::new(api_port)),
             tray_icon: Arc::new(Mutex::new(None)),
             shutdown_signal: Arc::new(AtomicBool::new(false)),
             http_client: Arc::new(http_client),
+            offline: Arc::new(AtomicBool::new(false)),
+            refresh_pending: Arc::new(AtomicBool::new(false)),
         }
     }
 
+    /// The port the CLI server is currently listening on
+    pub fn api_port(&self) -> u16 {
+        self.api_port.load(Ordering::Relaxed)
+    }
+
+    /// Point the tray at a CLI server that was respawned on a different port
+    pub fn set_api_port(&self, api_port: u16) {
+        self.api_port.store(api_port, Ordering::Relaxed);
+    }
+
+    /// Mark the CLI server as unreachable or reachable again
+    ///
+    /// While offline, refreshes are queued instead of hitting a dead API. Coming
+    /// back online flushes a queued refresh so the menu catches up at once.
+    pub fn set_offline(&self, offline: bool) {
+        self.offline.store(offline, Ordering::SeqCst);
+        if !offline && self.refresh_pending.swap(false, Ordering::SeqCst) {
+            debug!("CLI server reachable again, running queued tray refresh");
+            self.force_refresh();
+        }
+    }
+
+    fn is_offline(&self) -> bool {
+        self.offline.load(Ordering::SeqCst)
+    }
+
     /// Create an HTTP client with configured timeouts to prevent hangs
     ///
     /// Timeouts can be configured via environment variables:
@@ -267,16 +299,17 @@ impl TrayManager {
         info!("Icon loaded successfully");
 
         // Build the tray icon
-        let api_port = self.api_port;
+        let api_port = self.api_port.clone();
         let http_client = self.http_client.clone();
 
         let tray = TrayIconBuilder::new()
             .icon(icon)
             .icon_as_template(true) // Enable macOS template mode for automatic color adaptation
             .menu(&menu)
-            .tooltip("Orkee - Development Server Manager")
+            .tooltip(tray_tooltip(0))
             .show_menu_on_left_click(true)
             .on_menu_event(move |app, event| {
+                let api_port = api_port.load(Ordering::Relaxed);
                 Self::handle_menu_event(app, event, api_port, http_client.clone());
             })
             .build(app)?;
@@ -346,13 +379,18 @@ impl TrayManager {
                     ServerSource::External => " (External)",
                     ServerSource::Discovered => " (Auto-detected)",
                 };
+                let status_indicator = if server.status == "starting" {
+                    " (Starting)"
+                } else {
+                    ""
+                };
 
                 // Build submenu for this server
                 let mut submenu_builder = SubmenuBuilder::new(
                     app_handle,
                     format!(
-                        "{} - Port {}{}",
-                        sanitized_name, server.port, source_indicator
+                        "{} - Port {}{}{}",
+                        sanitized_name, server.port, source_indicator, status_indicator
                     ),
                 );
 
@@ -370,6 +408,14 @@ impl TrayManager {
                 .build(app_handle)?;
                 submenu_builder = submenu_builder.item(&copy_item);
 
+                // Copy port - for pasting into configs and proxies
+                let copy_port_item = MenuItemBuilder::with_id(
+                    format!("copy_port_{}", server.id),
+                    format!("Copy Port ({})", server.port),
+                )
+                .build(app_handle)?;
+                submenu_builder = submenu_builder.item(&copy_port_item);
+
                 submenu_builder = submenu_builder.separator();
 
                 // For external servers, restart uses a different API endpoint
@@ -463,9 +509,20 @@ impl TrayManager {
                     error!("Invalid menu event ID format: {}", id);
                 }
             }
+            id if id.starts_with("copy_port_") => {
+                if let Some(server_id) = id.strip_prefix("copy_port_") {
+                    Self::copy_server_text(app.clone(), api_port, server_id.to_string(), |s| {
+                        s.port.to_string()
+                    });
+                } else {
+                    error!("Invalid menu event ID format: {}", id);
+                }
+            }
             id if id.starts_with("copy_") => {
                 if let Some(server_id) = id.strip_prefix("copy_") {
-                    Self::copy_server_url(app.clone(), api_port, server_id.to_string());
+                    Self::copy_server_text(app.clone(), api_port, server_id.to_string(), |s| {
+                        s.url.clone()
+                    });
                 } else {
                     error!("Invalid menu event ID format: {}", id);
                 }
@@ -529,16 +586,23 @@ impl TrayManager {
         });
     }
 
-    fn copy_server_url(app: AppHandle, api_port: u16, server_id: String) {
+    /// Copy a detail of a server, such as its URL or port, looked up fresh from the preview API
+    fn copy_server_text(
+        app: AppHandle,
+        api_port: u16,
+        server_id: String,
+        text_of: fn(&ServerStatusInfo) -> String,
+    ) {
         tauri::async_runtime::spawn(async move {
             match Self::fetch_servers_static(api_port).await {
                 Ok(servers) => {
                     if let Some(server) = servers.iter().find(|s| s.id == server_id) {
-                        // Use the clipboard plugin to copy the URL
+                        // Use the clipboard plugin to copy the text
                         use tauri_plugin_clipboard_manager::ClipboardExt;
-                        match app.clipboard().write_text(&server.url) {
-                            Ok(_) => info!("Copied URL to clipboard: {}", server.url),
-                            Err(e) => error!("Failed to copy URL to clipboard: {}", e),
+                        let text = text_of(server);
+                        match app.clipboard().write_text(&text) {
+                            Ok(_) => info!("Copied to clipboard: {}", text),
+                            Err(e) => error!("Failed to copy to clipboard: {}", e),
                         }
                     } else {
                         warn!("Server {} no longer exists", server_id);
@@ -679,7 +743,7 @@ impl TrayManager {
         let url = format!(
             "http://{}:{}/api/preview/servers",
             get_api_host(),
-            self.api_port
+            self.api_port()
         );
         let response = self.http_client.get(&url).send().await?;
 
@@ -747,6 +811,20 @@ impl TrayManager {
         )
     }
 
+    /// Show how many dev servers are running on the tray icon
+    ///
+    /// The count is shown as the tray title next to the icon on macOS and Linux;
+    /// Windows has no tray titles, so there it only appears in the tooltip.
+    fn update_badge(tray: &TrayIcon, servers: &[ServerStatusInfo]) {
+        let running = running_server_count(servers);
+        if let Err(e) = tray.set_title(badge_title(running)) {
+            debug!("Failed to set tray badge: {}", e);
+        }
+        if let Err(e) = tray.set_tooltip(Some(tray_tooltip(running))) {
+            debug!("Failed to set tray tooltip: {}", e);
+        }
+    }
+
     /// Stop the server polling loop
     pub fn stop_polling(&self) {
         self.shutdown_signal.store(true, Ordering::Relaxed);
@@ -756,9 +834,16 @@ impl TrayManager {
     ///
     /// Fetches the latest server list and updates the tray menu immediately,
     /// bypassing the polling interval. Useful for instant updates when servers
-    /// start or stop.
+    /// start or stop. While the CLI server is offline the refresh is queued and
+    /// runs once it is reachable again.
     pub fn force_refresh(&self) {
-        let api_port = self.api_port;
+        if self.is_offline() {
+            debug!("CLI server offline, queueing tray refresh");
+            self.refresh_pending.store(true, Ordering::SeqCst);
+            return;
+        }
+
+        let api_port = self.api_port();
         let app_handle = self.app_handle.clone();
         let tray_icon = self.tray_icon.clone();
 
@@ -769,7 +854,7 @@ impl TrayManager {
                 Ok(servers) => {
                     debug!("Force refresh: Found {} servers", servers.len());
 
-                    match Self::build_menu(&app_handle, servers) {
+                    match Self::build_menu(&app_handle, servers.clone()) {
                         Ok(new_menu) => match tray_icon.lock() {
                             Ok(tray_guard) => {
                                 if let Some(tray) = tray_guard.as_ref() {
@@ -777,6 +862,7 @@ impl TrayManager {
                                         error!("Force refresh: Failed to update tray menu: {}", e);
                                     } else {
                                         info!("Force refresh: Tray menu updated successfully");
+                                        Self::update_badge(tray, &servers);
                                     }
                                 }
                             }
@@ -802,7 +888,7 @@ impl TrayManager {
 
         loop {
             attempt += 1;
-            let url = format!("http://{}:{}/api/health", get_api_host(), self.api_port);
+            let url = format!("http://{}:{}/api/health", get_api_host(), self.api_port());
 
             match self.http_client.get(&url).send().await {
                 Ok(response) if response.status().is_success() => {
@@ -877,6 +963,11 @@ impl TrayManager {
                     }
                 }
 
+                // Skip polling while the connectivity monitor reports the server unreachable
+                if manager.is_offline() {
+                    continue;
+                }
+
                 // Circuit breaker: check if we should attempt API call
                 if circuit_breaker_open {
                     if let Some(opened_at) = circuit_breaker_opened_at {
@@ -938,6 +1029,7 @@ impl TrayManager {
                                                         error!("Failed to update tray menu: {}", e);
                                                     } else {
                                                         info!("Tray menu updated successfully");
+                                                        Self::update_badge(tray, &servers);
                                                         last_servers_hash = current_hash; // Update cached hash
                                                         last_rebuild_time = now;
                                                     }
@@ -994,6 +1086,24 @@ impl TrayManager {
     }
 }
 
+/// Number of servers that have finished starting and are serving
+fn running_server_count(servers: &[ServerStatusInfo]) -> usize {
+    servers.iter().filter(|s| s.status == "running").count()
+}
+
+/// Tray title shown as a badge, hidden when nothing is running
+fn badge_title(running: usize) -> Option<String> {
+    (running > 0).then(|| running.to_string())
+}
+
+fn tray_tooltip(running: usize) -> String {
+    match running {
+        0 => "Orkee - Development Server Manager".to_string(),
+        1 => "Orkee - 1 dev server running".to_string(),
+        n => format!("Orkee - {} dev servers running", n),
+    }
+}
+
 /// Compute a hash of the server list for efficient comparison.
 ///
 /// This function computes a stable hash based on server id, status, and port.
@@ -1038,6 +1148,8 @@ mod tests {
             framework_name: Some("test-framework".to_string()),
             started_at: None,
             source: ServerSource::Orkee,
+            resource_usage: None,
+            services: Vec::new(),
         }
     }
 
@@ -1376,4 +1488,20 @@ mod tests {
         assert!(validate_api_host("fe80::1").is_err());
         assert!(validate_api_host("::ffff:127.0.0.1").is_err()); // IPv4-mapped IPv6
     }
+
+    #[test]
+    fn test_badge_counts_running_servers_only() {
+        let servers = vec![
+            create_test_server("1", "p1", "running", 3000),
+            create_test_server("2", "p2", "starting", 3001),
+            create_test_server("3", "p3", "running", 3002),
+        ];
+        let running = running_server_count(&servers);
+        assert_eq!(running, 2);
+        assert_eq!(badge_title(running).as_deref(), Some("2"));
+        assert_eq!(tray_tooltip(running), "Orkee - 2 dev servers running");
+
+        assert_eq!(badge_title(0), None);
+        assert_eq!(tray_tooltip(1), "Orkee - 1 dev server running");
+    }
 }
@@ -108,6 +108,7 @@ export function ProjectEditDialog({ project, open, onOpenChange, onProjectUpdate
       setupScript: formData.setupScript?.trim() || '',
       devScript: formData.devScript?.trim() || '',
       cleanupScript: formData.cleanupScript?.trim() || '',
+      version: project.version,
     };
 
     try {
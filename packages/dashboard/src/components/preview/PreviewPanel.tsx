@@ -20,7 +20,7 @@ export function PreviewPanel({ projectId, projectName }: PreviewPanelProps) {
   const [terminalAutoOpened, setTerminalAutoOpened] = useState(false);
 
   // Use SSE for real-time server state updates
-  const { activeServers, serverErrors } = useServerEvents();
+  const { activeServers, readyServers, serverErrors } = useServerEvents();
 
   // Poll for server status
   const checkServerStatus = useCallback(async () => {
@@ -54,7 +54,7 @@ export function PreviewPanel({ projectId, projectName }: PreviewPanelProps) {
       setServerInstance(instance);
 
       // SSE will automatically notify us when the server reaches 'running' state
-      console.log(`[PreviewPanel] Server start initiated, waiting for SSE 'server_started' event`);
+      console.log(`[PreviewPanel] Server start initiated, waiting for SSE 'server_ready' event`);
 
     } catch (err) {
       const errorMessage = err instanceof Error ? err.message : 'Failed to start server';
@@ -103,13 +103,14 @@ export function PreviewPanel({ projectId, projectName }: PreviewPanelProps) {
   // Listen for SSE updates and refresh server status when this project's state changes
   useEffect(() => {
     const isActiveNow = activeServers.has(projectId);
+    const isReadyNow = readyServers.has(projectId);
     const hasError = serverErrors.has(projectId);
 
-    if (isActiveNow || hasError) {
+    if (isActiveNow || isReadyNow || hasError) {
       console.log(`[PreviewPanel] SSE detected state change for project ${projectId}, refreshing status`);
       checkServerStatus();
     }
-  }, [activeServers, serverErrors, projectId, checkServerStatus]);
+  }, [activeServers, readyServers, serverErrors, projectId, checkServerStatus]);
 
   // Auto-close terminal when server finishes starting (only if auto-opened)
   useEffect(() => {
@@ -1,12 +1,135 @@
-// ABOUTME: Template management UI for sandbox configuration templates
-// ABOUTME: Placeholder component for Phase 6 full implementation
+// ABOUTME: Template management UI for sandbox execution templates
+// ABOUTME: Lists built-in and custom toolchain templates and creates or deletes custom ones
 
+import { useState } from 'react'
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card'
 import { Button } from '@/components/ui/button'
-import { FileText, Plus } from 'lucide-react'
-import { Alert, AlertDescription } from '@/components/ui/alert'
+import { Badge } from '@/components/ui/badge'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import { Textarea } from '@/components/ui/textarea'
+import { Dialog, DialogContent, DialogDescription, DialogFooter, DialogHeader, DialogTitle } from '@/components/ui/dialog'
+import { useToast } from '@/hooks/use-toast'
+import {
+  createTemplate,
+  deleteTemplate,
+  type CacheVolume,
+  type SandboxTemplate,
+} from '@/services/sandbox'
+import { FileText, HardDrive, Plus, Trash2 } from 'lucide-react'
+
+interface TemplateManagementProps {
+  templates: SandboxTemplate[]
+  allowCustomTemplates: boolean
+  onChange: () => void
+}
+
+interface TemplateForm {
+  name: string
+  description: string
+  image: string
+  envVars: string
+  cacheVolumes: string
+  setupCommands: string
+}
+
+const EMPTY_FORM: TemplateForm = {
+  name: '',
+  description: '',
+  image: '',
+  envVars: '',
+  cacheVolumes: '',
+  setupCommands: '',
+}
+
+function nonEmptyLines(text: string): string[] {
+  return text
+    .split('\n')
+    .map((line) => line.trim())
+    .filter(Boolean)
+}
+
+// Parse KEY=value lines
+function parseEnvVars(text: string): Record<string, string> {
+  return Object.fromEntries(
+    nonEmptyLines(text).map((line) => {
+      const index = line.indexOf('=')
+      return index === -1 ? [line, ''] : [line.slice(0, index).trim(), line.slice(index + 1).trim()]
+    })
+  )
+}
+
+// Parse name:/container/path lines
+function parseCacheVolumes(text: string): CacheVolume[] {
+  return nonEmptyLines(text).map((line) => {
+    const index = line.indexOf(':')
+    return index === -1
+      ? { name: line, container_path: '' }
+      : { name: line.slice(0, index).trim(), container_path: line.slice(index + 1).trim() }
+  })
+}
+
+export function TemplateManagement({ templates, allowCustomTemplates, onChange }: TemplateManagementProps) {
+  const { toast } = useToast()
+  const [dialogOpen, setDialogOpen] = useState(false)
+  const [form, setForm] = useState<TemplateForm>(EMPTY_FORM)
+  const [saving, setSaving] = useState(false)
+
+  const handleCreate = async () => {
+    if (!form.name.trim() || !form.image.trim()) {
+      toast({
+        title: 'Name and image required',
+        description: 'Please enter a name and a container image for the template',
+        variant: 'destructive',
+      })
+      return
+    }
+
+    setSaving(true)
+    try {
+      await createTemplate({
+        name: form.name.trim(),
+        description: form.description.trim() || null,
+        image: form.image.trim(),
+        env_vars: parseEnvVars(form.envVars),
+        cache_volumes: parseCacheVolumes(form.cacheVolumes),
+        setup_commands: nonEmptyLines(form.setupCommands),
+      })
+      toast({
+        title: 'Template created',
+        description: `${form.name} is now available when creating sandboxes`,
+      })
+      setDialogOpen(false)
+      setForm(EMPTY_FORM)
+      onChange()
+    } catch (error) {
+      toast({
+        title: 'Failed to create template',
+        description: error instanceof Error ? error.message : 'Unknown error',
+        variant: 'destructive',
+      })
+    } finally {
+      setSaving(false)
+    }
+  }
+
+  const handleDelete = async (template: SandboxTemplate) => {
+    try {
+      await deleteTemplate(template.id)
+      toast({
+        title: 'Template deleted',
+        description: `${template.name} has been deleted`,
+      })
+      onChange()
+    } catch (error) {
+      toast({
+        title: 'Failed to delete template',
+        description: error instanceof Error ? error.message : 'Unknown error',
+        variant: 'destructive',
+      })
+    }
+  }
 
-export function TemplateManagement() {
   return (
     <Card>
       <CardHeader>
@@ -15,27 +138,140 @@ export function TemplateManagement() {
           Sandbox Templates
         </CardTitle>
         <CardDescription>
-          Save and reuse sandbox configurations
+          Pre-configured toolchains with cached dependencies
         </CardDescription>
       </CardHeader>
-      <CardContent>
-        <Alert>
-          <AlertDescription>
-            <p className="font-medium mb-2">Template Management - Coming Soon</p>
-            <p className="text-sm">
-              Template management will be fully implemented in Phase 6 Advanced Features.
-              For now, you can create sandboxes with custom configurations directly.
-            </p>
-          </AlertDescription>
-        </Alert>
-
-        <div className="mt-4">
-          <Button variant="outline" disabled>
-            <Plus className="h-4 w-4 mr-2" />
-            Create Template
-          </Button>
-        </div>
+      <CardContent className="space-y-3">
+        {templates.length === 0 ? (
+          <p className="text-sm text-muted-foreground">No templates available</p>
+        ) : (
+          templates.map((template) => (
+            <div key={template.id} className="rounded-md border p-3 space-y-1">
+              <div className="flex items-center justify-between gap-2">
+                <span className="font-medium text-sm">{template.name}</span>
+                {template.is_builtin ? (
+                  <Badge variant="secondary">Built-in</Badge>
+                ) : (
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => handleDelete(template)}
+                    aria-label={`Delete ${template.name}`}
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </Button>
+                )}
+              </div>
+              <div className="text-xs text-muted-foreground font-mono">{template.image}</div>
+              {template.description && (
+                <p className="text-xs text-muted-foreground">{template.description}</p>
+              )}
+              {template.cache_volumes.length > 0 && (
+                <div className="flex items-center gap-1 text-xs text-muted-foreground">
+                  <HardDrive className="h-3 w-3" />
+                  {template.cache_volumes.map((volume) => volume.container_path).join(', ')}
+                </div>
+              )}
+            </div>
+          ))
+        )}
+
+        <Button
+          variant="outline"
+          onClick={() => setDialogOpen(true)}
+          disabled={!allowCustomTemplates}
+          title={allowCustomTemplates ? undefined : 'Custom templates are disabled in sandbox settings'}
+        >
+          <Plus className="h-4 w-4 mr-2" />
+          Create Template
+        </Button>
       </CardContent>
+
+      <Dialog open={dialogOpen} onOpenChange={setDialogOpen}>
+        <DialogContent className="max-w-2xl">
+          <DialogHeader>
+            <DialogTitle>Create Template</DialogTitle>
+            <DialogDescription>
+              Define an image, environment, cache volumes and setup commands to reuse across sandboxes
+            </DialogDescription>
+          </DialogHeader>
+
+          <div className="space-y-4 py-4">
+            <div className="grid grid-cols-2 gap-4">
+              <div className="space-y-2">
+                <Label htmlFor="template-name">Name *</Label>
+                <Input
+                  id="template-name"
+                  value={form.name}
+                  onChange={(e) => setForm({ ...form, name: e.target.value })}
+                  placeholder="Go toolchain"
+                />
+              </div>
+              <div className="space-y-2">
+                <Label htmlFor="template-image">Image *</Label>
+                <Input
+                  id="template-image"
+                  value={form.image}
+                  onChange={(e) => setForm({ ...form, image: e.target.value })}
+                  placeholder="golang:1.23-bookworm"
+                />
+              </div>
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="template-description">Description</Label>
+              <Input
+                id="template-description"
+                value={form.description}
+                onChange={(e) => setForm({ ...form, description: e.target.value })}
+              />
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="template-env">Environment variables (one KEY=value per line)</Label>
+              <Textarea
+                id="template-env"
+                value={form.envVars}
+                onChange={(e) => setForm({ ...form, envVars: e.target.value })}
+                placeholder="GOFLAGS=-mod=mod"
+                className="font-mono text-sm"
+              />
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="template-caches">Cache volumes (one name:/container/path per line)</Label>
+              <Textarea
+                id="template-caches"
+                value={form.cacheVolumes}
+                onChange={(e) => setForm({ ...form, cacheVolumes: e.target.value })}
+                placeholder="go-mod:/go/pkg/mod"
+                className="font-mono text-sm"
+              />
+            </div>
+
+            <div className="space-y-2">
+              <Label htmlFor="template-setup">Setup commands (run in order after the sandbox starts)</Label>
+              <Textarea
+                id="template-setup"
+                value={form.setupCommands}
+                onChange={(e) => setForm({ ...form, setupCommands: e.target.value })}
+                placeholder="go install golang.org/x/tools/gopls@latest"
+                className="font-mono text-sm"
+              />
+            </div>
+          </div>
+
+          <DialogFooter>
+            <Button variant="outline" onClick={() => setDialogOpen(false)}>
+              Cancel
+            </Button>
+            <Button onClick={handleCreate} disabled={saving}>
+              <Plus className="h-4 w-4 mr-2" />
+              Create Template
+            </Button>
+          </DialogFooter>
+        </DialogContent>
+      </Dialog>
     </Card>
   )
 }
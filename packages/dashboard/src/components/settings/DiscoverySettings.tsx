@@ -0,0 +1,96 @@
+// ABOUTME: External dev server discovery settings component
+// ABOUTME: Edits the scanned port ranges, scan root directories, and ignore patterns
+
+import { useState, useEffect } from 'react'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import { useToast } from '@/hooks/use-toast'
+import { updateSetting, getSettingsByCategory } from '@/services/settings'
+
+const FIELDS: Array<{ key: string; label: string; placeholder: string; help: string }> = [
+  {
+    key: 'discovery_port_ranges',
+    label: 'Port Ranges',
+    placeholder: '3000-3003,5173,8080',
+    help: 'Comma-separated ports or ranges to scan. Ports below 1024 are not allowed.',
+  },
+  {
+    key: 'discovery_scan_roots',
+    label: 'Scan Roots',
+    placeholder: '~/code,~/work',
+    help: 'Only track servers running from these directories. Leave empty to track servers anywhere.',
+  },
+  {
+    key: 'discovery_ignore_patterns',
+    label: 'Ignore Patterns',
+    placeholder: 'storybook,*/legacy/*',
+    help: 'Skip servers whose command or directory matches a pattern. * matches anything.',
+  },
+]
+
+export function DiscoverySettings() {
+  const { toast } = useToast()
+  const [values, setValues] = useState<Record<string, string>>({})
+  const [isLoading, setIsLoading] = useState(true)
+  const [isSaving, setIsSaving] = useState(false)
+
+  useEffect(() => {
+    loadSettings()
+  }, [])
+
+  const loadSettings = async () => {
+    try {
+      const response = await getSettingsByCategory('discovery')
+      setValues(Object.fromEntries(response.settings.map((s) => [s.key, s.value])))
+    } catch (error) {
+      console.error('Failed to load discovery settings:', error)
+    } finally {
+      setIsLoading(false)
+    }
+  }
+
+  const handleUpdateSetting = async (key: string, value: string) => {
+    setIsSaving(true)
+    try {
+      await updateSetting(key, value)
+    } catch (error) {
+      toast({
+        title: 'Failed to update setting',
+        description: error instanceof Error ? error.message : 'Unknown error',
+        variant: 'destructive',
+      })
+    } finally {
+      await loadSettings()
+      setIsSaving(false)
+    }
+  }
+
+  if (isLoading) {
+    return <div className="text-muted-foreground">Loading...</div>
+  }
+
+  return (
+    <div className="space-y-4">
+      <p className="text-sm text-muted-foreground">
+        Orkee periodically scans these ports for dev servers started outside Orkee. Changes apply on the next scan.
+      </p>
+
+      {FIELDS.map((field) => (
+        <div key={field.key} className="space-y-2">
+          <Label htmlFor={field.key} className="text-sm font-medium">
+            {field.label}
+          </Label>
+          <Input
+            id={field.key}
+            placeholder={field.placeholder}
+            value={values[field.key] ?? ''}
+            onChange={(e) => setValues((prev) => ({ ...prev, [field.key]: e.target.value }))}
+            onBlur={() => handleUpdateSetting(field.key, (values[field.key] ?? '').trim())}
+            disabled={isSaving}
+          />
+          <p className="text-xs text-muted-foreground">{field.help}</p>
+        </div>
+      ))}
+    </div>
+  )
+}
@@ -0,0 +1,180 @@
+// ABOUTME: Notification preferences settings component
+// ABOUTME: Chooses delivery channels per event type, the webhook URL, and the monthly AI budget
+
+import { useState, useEffect } from 'react'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import { Switch } from '@/components/ui/switch'
+import { Button } from '@/components/ui/button'
+import { useToast } from '@/hooks/use-toast'
+import { Send } from 'lucide-react'
+import { updateSetting, getSettingsByCategory, type SystemSetting } from '@/services/settings'
+import { sendTestNotification, type NotificationChannel } from '@/services/notifications'
+
+const EVENT_LABELS: Record<string, string> = {
+  notify_execution_finished: 'Agent run finished',
+  notify_sync_failed: 'Sync failed',
+  notify_budget_threshold: 'AI budget reached',
+  notify_server_crash: 'Preview server crashed',
+  notify_reauth_required: 'AI provider login expired',
+  notify_auth_lockout: 'Repeated failed logins',
+  notify_certificate_expiring: 'HTTPS certificate expiring',
+}
+
+const CHANNELS: Array<{ value: NotificationChannel; label: string }> = [
+  { value: 'desktop', label: 'Desktop' },
+  { value: 'webhook', label: 'Webhook' },
+  { value: 'tui', label: 'TUI' },
+]
+
+function parseChannels(value: string): NotificationChannel[] {
+  return value
+    .split(',')
+    .map((channel) => channel.trim())
+    .filter((channel): channel is NotificationChannel =>
+      CHANNELS.some((c) => c.value === channel)
+    )
+}
+
+export function NotificationSettings() {
+  const { toast } = useToast()
+  const [settings, setSettings] = useState<SystemSetting[]>([])
+  const [webhookUrl, setWebhookUrl] = useState('')
+  const [budget, setBudget] = useState('0')
+  const [isLoading, setIsLoading] = useState(true)
+  const [isSaving, setIsSaving] = useState(false)
+
+  useEffect(() => {
+    loadSettings()
+  }, [])
+
+  const loadSettings = async () => {
+    try {
+      const response = await getSettingsByCategory('notifications')
+      setSettings(response.settings)
+      setWebhookUrl(response.settings.find((s) => s.key === 'notification_webhook_url')?.value ?? '')
+      setBudget(response.settings.find((s) => s.key === 'notification_budget_monthly_usd')?.value ?? '0')
+    } catch (error) {
+      console.error('Failed to load notification settings:', error)
+    } finally {
+      setIsLoading(false)
+    }
+  }
+
+  const handleUpdateSetting = async (key: string, value: string) => {
+    setIsSaving(true)
+    try {
+      await updateSetting(key, value)
+      await loadSettings()
+    } catch (error) {
+      toast({
+        title: 'Failed to update setting',
+        description: error instanceof Error ? error.message : 'Unknown error',
+        variant: 'destructive',
+      })
+    } finally {
+      setIsSaving(false)
+    }
+  }
+
+  const handleToggleChannel = async (setting: SystemSetting, channel: NotificationChannel, enabled: boolean) => {
+    const channels = parseChannels(setting.value).filter((c) => c !== channel)
+    if (enabled) {
+      channels.push(channel)
+    }
+    await handleUpdateSetting(setting.key, channels.length > 0 ? channels.join(',') : 'none')
+  }
+
+  const handleSendTest = async () => {
+    try {
+      await sendTestNotification()
+      toast({ title: 'Test notification sent' })
+    } catch (error) {
+      toast({
+        title: 'Failed to send test notification',
+        description: error instanceof Error ? error.message : 'Unknown error',
+        variant: 'destructive',
+      })
+    }
+  }
+
+  if (isLoading) {
+    return <div className="text-muted-foreground">Loading...</div>
+  }
+
+  const eventSettings = settings.filter((setting) => setting.key in EVENT_LABELS)
+
+  return (
+    <div className="space-y-4">
+      {eventSettings.map((setting) => {
+        const enabled = parseChannels(setting.value)
+        return (
+          <div key={setting.key} className="flex items-center justify-between p-3 border rounded-md">
+            <div className="space-y-0.5">
+              <Label className="text-sm font-medium">{EVENT_LABELS[setting.key]}</Label>
+              {setting.description && (
+                <p className="text-xs text-muted-foreground">{setting.description}</p>
+              )}
+            </div>
+            <div className="flex items-center gap-4">
+              {CHANNELS.map((channel) => (
+                <div key={channel.value} className="flex items-center gap-2">
+                  <Switch
+                    id={`${setting.key}-${channel.value}`}
+                    checked={enabled.includes(channel.value)}
+                    onCheckedChange={(checked) => handleToggleChannel(setting, channel.value, checked)}
+                    disabled={isSaving}
+                  />
+                  <Label htmlFor={`${setting.key}-${channel.value}`} className="text-xs">
+                    {channel.label}
+                  </Label>
+                </div>
+              ))}
+            </div>
+          </div>
+        )
+      })}
+
+      <div className="space-y-2">
+        <Label htmlFor="notification_webhook_url" className="text-sm font-medium">
+          Webhook URL
+        </Label>
+        <Input
+          id="notification_webhook_url"
+          type="url"
+          placeholder="https://hooks.slack.com/services/..."
+          value={webhookUrl}
+          onChange={(e) => setWebhookUrl(e.target.value)}
+          onBlur={() => handleUpdateSetting('notification_webhook_url', webhookUrl.trim())}
+          disabled={isSaving}
+        />
+        <p className="text-xs text-muted-foreground">
+          Slack and Discord incoming webhooks are supported. Leave empty to disable webhook delivery.
+        </p>
+      </div>
+
+      <div className="space-y-2">
+        <Label htmlFor="notification_budget_monthly_usd" className="text-sm font-medium">
+          Monthly AI Budget (USD)
+        </Label>
+        <Input
+          id="notification_budget_monthly_usd"
+          type="number"
+          min={0}
+          value={budget}
+          onChange={(e) => setBudget(e.target.value)}
+          onBlur={() => handleUpdateSetting('notification_budget_monthly_usd', budget || '0')}
+          disabled={isSaving}
+        />
+        <p className="text-xs text-muted-foreground">
+          Notify once when this month's AI spend crosses the budget. Set to 0 to disable.
+        </p>
+      </div>
+
+      <Button variant="outline" size="sm" onClick={handleSendTest} disabled={isSaving}>
+        <Send className="mr-2 h-4 w-4" />
+        Send Test Notification
+      </Button>
+    </div>
+  )
+}
@@ -4,6 +4,7 @@ import { Button } from "@/components/ui/button";
 import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from "@/components/ui/tooltip";
 import { cn } from "@/lib/utils";
 import { getEditorSettings } from "@/lib/editor-utils";
+import { isIntegratedEditor, openInEditor } from "@/services/editors";
 
 interface OpenInEditorButtonProps {
   projectId?: string;
@@ -47,6 +48,15 @@ export function OpenInEditorButton({
 
     try {
       const editorSettings = getEditorSettings();
+
+      // Integrated editors are resolved server-side so per-project overrides apply
+      if (isIntegratedEditor(editorSettings.defaultEditor)) {
+        const launch = await openInEditor({ projectId, projectPath });
+        onSuccess?.();
+        console.log(`✅ ${launch.message}`);
+        return;
+      }
+
       const response = await fetch('/api/projects/open-in-editor', {
         method: 'POST',
         headers: {
@@ -14,6 +14,7 @@ export const epicKeys = {
   details: () => [...epicKeys.all, 'detail'] as const,
   detail: (projectId: string, epicId: string) => [...epicKeys.details(), projectId, epicId] as const,
   progress: (projectId: string, epicId: string) => [...epicKeys.all, 'progress', projectId, epicId] as const,
+  metrics: (projectId: string, epicId: string) => [...epicKeys.all, 'metrics', projectId, epicId] as const,
 };
 
 /**
@@ -146,3 +147,14 @@ export function useEpicProgress(projectId: string, epicId: string) {
     enabled: !!projectId && !!epicId,
   });
 }
+
+/**
+ * Hook to fetch epic burndown, velocity, and projected completion
+ */
+export function useEpicMetrics(projectId: string, epicId: string) {
+  return useQuery({
+    queryKey: epicKeys.metrics(projectId, epicId),
+    queryFn: () => epicsService.getMetrics(projectId, epicId),
+    enabled: !!projectId && !!epicId,
+  });
+}
@@ -7,6 +7,7 @@ import { ideateService } from '@/services/ideate';
 import { queryKeys } from '@/lib/queryClient';
 import type {
   CreateIdeateInput,
+  StartFromDocumentInput,
   UpdateIdeateInput,
   SkipSectionInput,
   QuickGenerateInput,
@@ -21,6 +22,7 @@ import type {
   CreateFeatureDependencyInput,
   OptimizationStrategy,
   SimilarProject,
+  SearchResearchSourcesInput,
   CreateExpertPersonaInput,
   SuggestExpertsRequest,
   CreateRoundtableRequest,
@@ -96,6 +98,20 @@ export function useCreateIdeateSession(projectId: string) {
   });
 }
 
+/**
+ * Create a new ideate session pre-filled from a README/PRD document
+ */
+export function useStartIdeateFromDocument(projectId: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (input: StartFromDocumentInput) => ideateService.startFromDocument(input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.ideateList(projectId) });
+    },
+  });
+}
+
 /**
  * Update a ideate session
  */
@@ -731,6 +747,47 @@ export function useSimilarProjects(sessionId: string) {
   });
 }
 
+/**
+ * Get web sources gathered for a session
+ */
+export function useResearchSources(sessionId: string) {
+  return useQuery({
+    queryKey: queryKeys.ideateResearchSources(sessionId),
+    queryFn: () => ideateService.getResearchSources(sessionId),
+    enabled: !!sessionId,
+    staleTime: 5 * 60 * 1000, // 5 minutes
+  });
+}
+
+/**
+ * Search the web and store the results as research sources
+ */
+export function useSearchResearchSources(sessionId: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (input: SearchResearchSourcesInput) =>
+      ideateService.searchResearchSources(sessionId, input),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.ideateResearchSources(sessionId) });
+    },
+  });
+}
+
+/**
+ * Delete a stored research source
+ */
+export function useDeleteResearchSource(sessionId: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (sourceId: string) => ideateService.deleteResearchSource(sessionId, sourceId),
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: queryKeys.ideateResearchSources(sessionId) });
+    },
+  });
+}
+
 /**
  * Extract lessons from a similar project
  */
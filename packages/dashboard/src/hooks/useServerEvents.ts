@@ -9,7 +9,7 @@ import { getApiToken } from '@/lib/platform';
 const DEBUG = import.meta.env.DEV;
 
 interface ServerEvent {
-  type: 'server_started' | 'server_stopped' | 'server_error' | 'initial_state';
+  type: 'server_started' | 'server_ready' | 'server_stopped' | 'server_error' | 'initial_state';
   project_id?: string;
   active_servers?: string[];
   pid?: number;
@@ -91,11 +91,13 @@ const POLLING_INTERVAL = (() => {
  *
  * @returns Server state and connection info
  * @returns activeServers - Set of active project IDs
+ * @returns readyServers - Set of project IDs whose servers finished starting up
  * @returns connectionMode - Current connection mode (sse/polling/connecting)
  * @returns isConnected - Whether any connection is established
  */
 export function useServerEvents() {
   const [activeServers, setActiveServers] = useState<Set<string>>(new Set());
+  const [readyServers, setReadyServers] = useState<Set<string>>(new Set());
   const [connectionMode, setConnectionMode] = useState<'sse' | 'polling' | 'connecting'>('connecting');
   const [serverErrors, setServerErrors] = useState<Map<string, string>>(new Map());
 
@@ -120,8 +122,11 @@ export function useServerEvents() {
           const data = await response.json();
 
           if (data.success && data.data?.servers) {
-            const serverIds = data.data.servers.map((s: { project_id: string }) => s.project_id);
-            setActiveServers(new Set(serverIds));
+            const servers: { project_id: string; status: string }[] = data.data.servers;
+            setActiveServers(new Set(servers.map((s) => s.project_id)));
+            setReadyServers(
+              new Set(servers.filter((s) => s.status === 'running').map((s) => s.project_id))
+            );
           }
         } catch (error) {
           console.error('[Polling] Failed to fetch servers:', error);
@@ -184,6 +189,15 @@ export function useServerEvents() {
                   });
                 }
                 break;
+              case 'server_ready':
+                if (serverEvent.project_id) {
+                  setReadyServers((prev) => {
+                    const next = new Set(prev);
+                    next.add(serverEvent.project_id!);
+                    return next;
+                  });
+                }
+                break;
               case 'server_stopped':
                 if (serverEvent.project_id) {
                   setActiveServers((prev) => {
@@ -191,6 +205,11 @@ export function useServerEvents() {
                     next.delete(serverEvent.project_id!);
                     return next;
                   });
+                  setReadyServers((prev) => {
+                    const next = new Set(prev);
+                    next.delete(serverEvent.project_id!);
+                    return next;
+                  });
                   // Clear any errors for stopped server
                   setServerErrors((prev) => {
                     const next = new Map(prev);
@@ -286,6 +305,7 @@ export function useServerEvents() {
 
   return {
     activeServers,
+    readyServers,
     connectionMode,
     isConnected: connectionMode !== 'connecting',
     serverErrors,
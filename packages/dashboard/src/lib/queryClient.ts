@@ -80,6 +80,7 @@ export const queryKeys = {
   // Phase 5: Research Analysis keys
   ideateCompetitors: (sessionId: string) => [...queryKeys.ideate, 'competitors', sessionId] as const,
   ideateSimilarProjects: (sessionId: string) => [...queryKeys.ideate, 'similar-projects', sessionId] as const,
+  ideateResearchSources: (sessionId: string) => [...queryKeys.ideate, 'research-sources', sessionId] as const,
   // Phase 6: Expert Roundtable keys
   ideateExperts: (sessionId: string) => [...queryKeys.ideate, 'experts', sessionId] as const,
   ideateRoundtables: (sessionId: string) => [...queryKeys.ideate, 'roundtables', sessionId] as const,
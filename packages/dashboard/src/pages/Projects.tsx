@@ -422,7 +422,7 @@ export function Projects() {
         for (const project of updatedProjects) {
           await updateProjectMutation.mutateAsync({
             id: project.id,
-            input: { rank: project.rank }
+            input: { rank: project.rank, version: project.version }
           });
         }
       } catch (err) {
@@ -15,10 +15,12 @@ import {
   getSandbox,
   getSandboxSettings,
   getAllProviderSettings,
+  listTemplates,
   type Sandbox,
   type CreateSandboxRequest,
   type SandboxSettings as SandboxSettingsType,
   type ProviderSettings,
+  type SandboxTemplate,
 } from '@/services/sandbox'
 import { SandboxCard } from '@/components/sandbox/SandboxCard'
 import { Terminal } from '@/components/sandbox/Terminal'
@@ -47,12 +49,14 @@ export default function Sandboxes() {
   const [createDialogOpen, setCreateDialogOpen] = useState(false)
   const [settings, setSettings] = useState<SandboxSettingsType | null>(null)
   const [providers, setProviders] = useState<ProviderSettings[]>([])
+  const [templates, setTemplates] = useState<SandboxTemplate[]>([])
 
   // Create sandbox form state
   const [newSandbox, setNewSandbox] = useState<CreateSandboxRequest>({
     name: '',
     provider: undefined,
     image: undefined,
+    template_id: null,
     cpu_cores: undefined,
     memory_mb: undefined,
     agent_id: null,
@@ -66,6 +70,7 @@ export default function Sandboxes() {
         listSandboxes(),
         getSandboxSettings(),
         getAllProviderSettings(),
+        listTemplates(),
       ])
 
       // Handle sandboxes result
@@ -93,6 +98,13 @@ export default function Sandboxes() {
           variant: 'destructive',
         })
       }
+
+      // Handle templates result
+      if (results[3].status === 'fulfilled') {
+        setTemplates(results[3].value)
+      } else {
+        console.error('Failed to load templates:', results[3].reason)
+      }
     } catch (error) {
       const errorMessage = error instanceof Error ? error.message : 'Failed to load data'
       toast({
@@ -120,10 +132,10 @@ export default function Sandboxes() {
     }
 
     try {
-      // Use default image from settings if not specified
+      // Use default image from settings if not specified; a template brings its own
       const sandboxToCreate = {
         ...newSandbox,
-        image: newSandbox.image || settings?.default_image,
+        image: newSandbox.image || (newSandbox.template_id ? undefined : settings?.default_image),
       }
       await createSandbox(sandboxToCreate)
       toast({
@@ -135,6 +147,7 @@ export default function Sandboxes() {
         name: '',
         provider: undefined,
         image: undefined,
+        template_id: null,
         cpu_cores: undefined,
         memory_mb: undefined,
         agent_id: null,
@@ -326,7 +339,11 @@ export default function Sandboxes() {
             costAlertThreshold={settings.cost_alert_threshold}
           />
         )}
-        <TemplateManagement />
+        <TemplateManagement
+          templates={templates}
+          allowCustomTemplates={settings?.allow_custom_templates ?? true}
+          onChange={loadData}
+        />
       </div>
 
       {/* Create Sandbox Dialog */}
@@ -369,6 +386,33 @@ export default function Sandboxes() {
               </Select>
             </div>
 
+            <div className="space-y-2">
+              <Label htmlFor="template">Template</Label>
+              <Select
+                value={newSandbox.template_id || 'none'}
+                onValueChange={(value) =>
+                  setNewSandbox({ ...newSandbox, template_id: value === 'none' ? null : value })
+                }
+              >
+                <SelectTrigger id="template">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="none">No template</SelectItem>
+                  {templates.map((t) => (
+                    <SelectItem key={t.id} value={t.id}>
+                      {t.name}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+              {newSandbox.template_id && (
+                <p className="text-xs text-muted-foreground">
+                  {templates.find((t) => t.id === newSandbox.template_id)?.description}
+                </p>
+              )}
+            </div>
+
             <div className="grid grid-cols-2 gap-4">
               <div className="space-y-2">
                 <Label htmlFor="cpu">CPU Cores</Label>
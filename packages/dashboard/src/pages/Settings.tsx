@@ -4,10 +4,11 @@ import { useCloudAuth, useCloudSync } from '@/hooks/useCloud'
 import { cloudService, formatLastSync } from '@/services/cloud'
 import { fetchConfig } from '@/services/config'
 import { exportDatabase, importDatabase, type ImportResult } from '@/services/database'
-import { Cloud, User, RefreshCw, Download, Upload, Code2, ExternalLink, Database, AlertTriangle, Shield, Trash2, Key, Check, Terminal, Sliders, LayoutGrid, Brain, Lock, Server, Settings as SettingsIcon, Gauge, ShieldCheck, Eye, Box } from 'lucide-react'
+import { Cloud, User, RefreshCw, Download, Upload, Code2, ExternalLink, Database, AlertTriangle, Shield, Trash2, Key, Check, Terminal, Sliders, LayoutGrid, Brain, Lock, Server, Settings as SettingsIcon, Gauge, ShieldCheck, Eye, Box, Bell, Radar } from 'lucide-react'
 import { useState, useEffect, useRef, useMemo } from 'react'
 import { SUPPORTED_EDITORS, getDefaultEditorSettings } from '@/lib/editor-utils'
 import type { EditorSettings } from '@/lib/editor-utils'
+import { listEditors, isIntegratedEditor, type DetectedEditor } from '@/services/editors'
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select'
 import { Switch } from '@/components/ui/switch'
 import { Input } from '@/components/ui/input'
@@ -27,6 +28,8 @@ import { clearConfigCache } from '@/services/config'
 import { AIModelsSettings } from '@/components/settings/AIModelsSettings'
 import { OAuthSettings } from '@/components/settings/OAuthSettings'
 import { SandboxSettings } from '@/components/settings/SandboxSettings'
+import { NotificationSettings } from '@/components/settings/NotificationSettings'
+import { DiscoverySettings } from '@/components/settings/DiscoverySettings'
 import { useToast } from '@/hooks/use-toast'
 import { TelemetryErrorBoundary } from '@/components/TelemetryErrorBoundary'
 
@@ -125,10 +128,16 @@ function GeneralSettings() {
   const [editorSettings, setEditorSettings] = useState<EditorSettings>(getDefaultEditorSettings());
   const [isTestingEditor, setIsTestingEditor] = useState(false);
   const [isSaving, setIsSaving] = useState(false);
+  const [detectedEditors, setDetectedEditors] = useState<Record<string, DetectedEditor>>({});
 
   // Load editor settings on mount
   useEffect(() => {
     loadEditorSettings();
+    listEditors()
+      .then(({ editors }) => {
+        setDetectedEditors(Object.fromEntries(editors.map((editor) => [editor.id, editor])));
+      })
+      .catch((error) => console.error('Failed to detect installed editors:', error));
   }, []);
 
   const loadEditorSettings = async () => {
@@ -161,6 +170,13 @@ function GeneralSettings() {
       defaultEditor: editorId,
     };
     saveEditorSettings(newSettings);
+
+    // The server opens integrated editors itself, so it needs to know the preference too
+    if (isIntegratedEditor(editorId)) {
+      updateSetting('preferred_editor', editorId).catch((error) =>
+        console.error('Failed to save preferred editor:', error)
+      );
+    }
   };
 
   const handleCustomCommandChange = (command: string) => {
@@ -230,6 +246,9 @@ function GeneralSettings() {
                     <div className="flex items-center gap-2">
                       <span>{editor.icon}</span>
                       <span>{editor.name}</span>
+                      {detectedEditors[editor.id]?.installed && (
+                        <Badge variant="secondary" className="text-xs">Installed</Badge>
+                      )}
                     </div>
                   </SelectItem>
                 ))}
@@ -1401,7 +1420,7 @@ function AdvancedSettings() {
           </div>
 
           <Tabs defaultValue="server" className="w-full">
-            <TabsList className="grid w-full grid-cols-5">
+            <TabsList className="grid w-full grid-cols-7">
               <TabsTrigger value="server" className="flex items-center gap-2">
                 <Server className="h-3.5 w-3.5" />
                 Server
@@ -1422,6 +1441,14 @@ function AdvancedSettings() {
                 <Box className="h-3.5 w-3.5" />
                 Sandboxes
               </TabsTrigger>
+              <TabsTrigger value="notifications" className="flex items-center gap-2">
+                <Bell className="h-3.5 w-3.5" />
+                Notifications
+              </TabsTrigger>
+              <TabsTrigger value="discovery" className="flex items-center gap-2">
+                <Radar className="h-3.5 w-3.5" />
+                Discovery
+              </TabsTrigger>
             </TabsList>
 
             <TabsContent value="server" className="space-y-4 mt-4">
@@ -1443,6 +1470,14 @@ function AdvancedSettings() {
             <TabsContent value="sandboxes" className="space-y-4 mt-4">
               <SandboxSettings />
             </TabsContent>
+
+            <TabsContent value="notifications" className="space-y-4 mt-4">
+              <NotificationSettings />
+            </TabsContent>
+
+            <TabsContent value="discovery" className="space-y-4 mt-4">
+              <DiscoverySettings />
+            </TabsContent>
           </Tabs>
         </TabsContent>
 
@@ -22,6 +22,20 @@ export interface UserAgent {
   agent: Agent;
 }
 
+export interface ToolPermissions {
+  filesystem_write: boolean;
+  network: boolean;
+  shell_exec: boolean;
+  git_push: boolean;
+}
+
+export interface AgentToolPolicy {
+  agent_id: string;
+  permissions: ToolPermissions;
+  is_default: boolean;
+  updated_at: string | null;
+}
+
 interface ApiResponse<T> {
   success: boolean;
   data: T | null;
@@ -121,6 +135,62 @@ export class AgentsService {
       throw new Error(response.data.error || 'Failed to deactivate agent');
     }
   }
+
+  async listToolPolicies(): Promise<AgentToolPolicy[]> {
+    const response = await apiRequest<ApiResponse<AgentToolPolicy[]>>(
+      '/api/agents/tool-permissions'
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to fetch tool permissions');
+    }
+
+    return response.data;
+  }
+
+  async getToolPolicy(agentId: string): Promise<AgentToolPolicy> {
+    const response = await apiRequest<ApiResponse<AgentToolPolicy>>(
+      `/api/agents/${agentId}/tool-permissions`
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to fetch tool permissions');
+    }
+
+    return response.data;
+  }
+
+  async updateToolPolicy(
+    agentId: string,
+    permissions: ToolPermissions
+  ): Promise<AgentToolPolicy> {
+    const response = await apiRequest<ApiResponse<AgentToolPolicy>>(
+      `/api/agents/${agentId}/tool-permissions`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(permissions),
+      }
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to update tool permissions');
+    }
+
+    return response.data;
+  }
+
+  async resetToolPolicy(agentId: string): Promise<AgentToolPolicy> {
+    const response = await apiRequest<ApiResponse<AgentToolPolicy>>(
+      `/api/agents/${agentId}/tool-permissions`,
+      { method: 'DELETE' }
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to reset tool permissions');
+    }
+
+    return response.data;
+  }
 }
 
 export const agentsService = new AgentsService();
@@ -10,6 +10,20 @@ const DEFAULT_API_BASE_URL = import.meta.env.VITE_API_URL || `http://localhost:$
 // Cache for the dynamically determined API base URL
 let cachedApiBaseUrl: string | null = null;
 
+// The desktop app respawns the CLI server if it dies, possibly on a new port
+if (isTauriApp()) {
+  import('@tauri-apps/api/event')
+    .then(({ listen }) =>
+      listen<{ api_port: number }>('sidecar-restarted', (event) => {
+        cachedApiBaseUrl = `http://localhost:${event.payload.api_port}`;
+        console.log(`[API] CLI server restarted, now using ${cachedApiBaseUrl}`);
+      })
+    )
+    .catch((error) => {
+      console.error('[API] Failed to listen for CLI server restarts:', error);
+    });
+}
+
 /**
  * Get the appropriate API base URL based on the platform
  * In web mode: uses generated port config or env var (direct connection)
@@ -54,10 +68,15 @@ export interface ApiResponse<T> {
 }
 
 export class ApiClient {
-  private baseURL: Promise<string>;
+  private explicitBaseURL?: string;
 
   constructor(baseURL?: string) {
-    this.baseURL = baseURL ? Promise.resolve(baseURL) : getApiBaseUrl();
+    this.explicitBaseURL = baseURL;
+  }
+
+  // Resolved per request so a respawned server on a new port is picked up
+  private get baseURL(): Promise<string> {
+    return this.explicitBaseURL ? Promise.resolve(this.explicitBaseURL) : getApiBaseUrl();
   }
 
   async get<T>(endpoint: string): Promise<ApiResponse<T>> {
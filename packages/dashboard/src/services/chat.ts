@@ -66,6 +66,33 @@ export interface QualityMetrics {
   is_ready_for_prd: boolean;
 }
 
+export type SummarySource = 'auto' | 'ai';
+
+export interface ChatSummary {
+  id: string;
+  session_id: string;
+  through_message_order: number;
+  summary: string;
+  token_estimate: number;
+  source: SummarySource;
+  created_at: string;
+}
+
+export interface TokenBudget {
+  model_id: string;
+  max_context_tokens: number;
+  reserved_output_tokens: number;
+  used_tokens: number;
+  remaining_tokens: number;
+  usage_ratio: number;
+}
+
+export interface ChatContext {
+  summary: ChatSummary | null;
+  messages: ChatMessage[];
+  budget: TokenBudget;
+}
+
 export interface GeneratePRDFromChatInput {
   title: string;
 }
@@ -108,6 +135,57 @@ class ChatService {
     return response.data.data;
   }
 
+  /**
+   * Get the model context: latest summary, unsummarized messages, and token budget
+   */
+  async getContext(sessionId: string, model?: string): Promise<ChatContext> {
+    const url = model
+      ? `/api/ideate/chat/${sessionId}/context?model=${encodeURIComponent(model)}`
+      : `/api/ideate/chat/${sessionId}/context`;
+
+    const response = await apiClient.get<{ success: boolean; data: ChatContext }>(url);
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to fetch chat context');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Get all summarization checkpoints for a session
+   */
+  async getSummaries(sessionId: string): Promise<ChatSummary[]> {
+    const response = await apiClient.get<{ success: boolean; data: ChatSummary[] }>(
+      `/api/ideate/chat/${sessionId}/summaries`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to fetch chat summaries');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Store an AI-written summary covering messages up to throughMessageOrder
+   */
+  async createSummary(
+    sessionId: string,
+    summary: string,
+    throughMessageOrder: number
+  ): Promise<ChatSummary> {
+    const response = await apiClient.post<{ success: boolean; data: ChatSummary }>(
+      `/api/ideate/chat/${sessionId}/summaries`,
+      { summary, through_message_order: throughMessageOrder }
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to create chat summary');
+    }
+
+    return response.data.data;
+  }
 
   /**
    * Get discovery questions (optionally filtered by category)
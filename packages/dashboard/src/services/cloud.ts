@@ -1,12 +1,22 @@
 import { apiRequest } from './api';
 
 // Type definitions matching the Rust API
+export interface ConnectionHealth {
+  circuit: 'closed' | 'open' | 'half_open';
+  consecutive_failures: number;
+  total_retries: number;
+  last_retry_at?: string | null;
+  next_attempt_at?: string | null;
+  last_error?: string | null;
+}
+
 export interface CloudAuthStatus {
   authenticated: boolean;
   user_id?: string;
   user_email?: string;
   user_name?: string;
   subscription_tier?: string;
+  connection?: ConnectionHealth | null;
 }
 
 export interface GlobalSyncStatus {
@@ -44,9 +54,39 @@ export interface CloudProject {
   created_at: string;
   updated_at: string;
   owner_id: string;
+  org_id?: string | null;
+  owner?: ProjectAuthor | null;
+  last_synced_by?: ProjectAuthor | null;
+  read_only?: boolean;
   // Add other fields as needed
 }
 
+export interface ProjectAuthor {
+  user_id: string;
+  name: string;
+  email: string;
+}
+
+export type OrgRole = 'owner' | 'admin' | 'member' | 'viewer';
+export type SharePermission = 'read' | 'write';
+
+export interface Organization {
+  id: string;
+  name: string;
+  slug: string;
+  role: OrgRole;
+  member_count: number;
+  created_at: string;
+}
+
+export interface ProjectShare {
+  project_id: string;
+  org_id: string;
+  permission: SharePermission;
+  shared_by: ProjectAuthor;
+  shared_at: string;
+}
+
 export interface OAuthInitResponse {
   auth_url: string;
   state: string;
@@ -220,6 +260,53 @@ export class CloudService {
     return response.data.data;
   }
 
+  // Organization methods
+  async listOrganizations(): Promise<Organization[]> {
+    const response = await apiRequest<ApiResponse<Organization[]>>('/api/cloud/orgs');
+
+    if (!response.success || !response.data?.success) {
+      throw new Error(response.error || response.data?.error || 'Failed to list organizations');
+    }
+
+    return response.data.data || [];
+  }
+
+  async listOrgProjects(orgId: string): Promise<CloudProject[]> {
+    const response = await apiRequest<ApiResponse<CloudProject[]>>(
+      `/api/cloud/orgs/${encodeURIComponent(orgId)}/projects`
+    );
+
+    if (!response.success || !response.data?.success) {
+      throw new Error(response.error || response.data?.error || 'Failed to list organization projects');
+    }
+
+    return response.data.data || [];
+  }
+
+  async shareProject(projectId: string, orgId: string, permission: SharePermission = 'read'): Promise<ProjectShare> {
+    const response = await apiRequest<ApiResponse<ProjectShare>>(`/api/cloud/projects/${projectId}/share`, {
+      method: 'POST',
+      body: JSON.stringify({ org_id: orgId, permission }),
+    });
+
+    if (!response.success || !response.data?.success || !response.data.data) {
+      throw new Error(response.error || response.data?.error || 'Failed to share project');
+    }
+
+    return response.data.data;
+  }
+
+  async unshareProject(projectId: string, orgId: string): Promise<void> {
+    const response = await apiRequest<ApiResponse<null>>(
+      `/api/cloud/projects/${projectId}/share/${encodeURIComponent(orgId)}`,
+      { method: 'DELETE' }
+    );
+
+    if (!response.success || !response.data?.success) {
+      throw new Error(response.error || response.data?.error || 'Failed to unshare project');
+    }
+  }
+
   // Usage and subscription methods
   async getUsageStats(): Promise<string> {
     const response = await apiRequest<ApiResponse<string>>('/api/cloud/usage');
@@ -0,0 +1,113 @@
+// ABOUTME: Editor integrations API client
+// ABOUTME: Detects installed editors, opens projects or files at a line, and manages per-project editor overrides
+
+import { apiRequest } from './api'
+
+/** Editors the server can launch directly; mirrors the server's editor registry */
+export const INTEGRATED_EDITOR_IDS = [
+  'vscode',
+  'cursor',
+  'zed',
+  'intellij',
+  'webstorm',
+  'pycharm',
+  'goland',
+  'rustrover',
+  'rubymine',
+  'phpstorm',
+  'clion',
+  'rider',
+]
+
+export function isIntegratedEditor(editorId: string): boolean {
+  return INTEGRATED_EDITOR_IDS.includes(editorId)
+}
+
+export type EditorFamily = 'vscode' | 'zed' | 'jetbrains'
+
+export interface DetectedEditor {
+  id: string
+  name: string
+  family: EditorFamily
+  installed: boolean
+  /** Launcher found on the server's PATH */
+  command: string | null
+  supportsDeepLink: boolean
+}
+
+export interface EditorPreferences {
+  preferredEditor: string
+  /** Project ID -> editor ID */
+  projectOverrides: Record<string, string>
+}
+
+export interface EditorsResponse {
+  editors: DetectedEditor[]
+  preferences: EditorPreferences
+}
+
+export interface OpenEditorInput {
+  projectId?: string
+  projectPath?: string
+  /** File path relative to the project root */
+  file?: string
+  line?: number
+  column?: number
+  /** Overrides the project's and the user's editor choice */
+  editorId?: string
+}
+
+export interface EditorLaunch {
+  editorId: string
+  message: string
+  command: string
+  /** URL that opens the target through the editor's URL scheme, when supported */
+  deepLink: string | null
+}
+
+export interface ProjectEditor {
+  editorId: string
+  overrideEditor: string | null
+}
+
+export async function listEditors(): Promise<EditorsResponse> {
+  const response = await apiRequest<EditorsResponse>('/api/projects/editors')
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list editors')
+}
+
+export async function openInEditor(input: OpenEditorInput): Promise<EditorLaunch> {
+  const response = await apiRequest<EditorLaunch>('/api/projects/editors/open', {
+    method: 'POST',
+    body: JSON.stringify(input),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to open editor')
+}
+
+export async function getProjectEditor(projectId: string): Promise<ProjectEditor> {
+  const response = await apiRequest<ProjectEditor>(`/api/projects/${projectId}/editor`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to get project editor')
+}
+
+/** Set the editor for a project, or pass null to use the preferred editor */
+export async function setProjectEditor(
+  projectId: string,
+  editorId: string | null
+): Promise<ProjectEditor> {
+  const response = await apiRequest<ProjectEditor>(`/api/projects/${projectId}/editor`, {
+    method: 'PUT',
+    body: JSON.stringify({ editorId }),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to set project editor')
+}
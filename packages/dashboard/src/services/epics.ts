@@ -245,6 +245,28 @@ export interface GitHubSyncRecord {
   updated_at: string;
 }
 
+export interface BurndownPoint {
+  date: string;
+  open: number;
+  completed: number;
+}
+
+export interface WeeklyVelocity {
+  week_start: string;
+  completed: number;
+}
+
+export interface EpicMetrics {
+  epic_id: string;
+  total_tasks: number;
+  open_tasks: number;
+  completed_tasks: number;
+  burndown: BurndownPoint[];
+  velocity: WeeklyVelocity[];
+  average_velocity: number;
+  projected_completion_date: string | null;
+}
+
 interface ApiResponse<T> {
   success: boolean;
   data: T | null;
@@ -407,6 +429,18 @@ export class EpicsService {
     return response.data.data?.progress || 0;
   }
 
+  async getMetrics(projectId: string, epicId: string): Promise<EpicMetrics> {
+    const response = await apiClient.get<ApiResponse<EpicMetrics>>(
+      `/api/projects/${projectId}/epics/${epicId}/metrics`
+    );
+
+    if (response.error || !response.data?.success) {
+      throw new Error(response.data?.error || response.error || 'Failed to fetch epic metrics');
+    }
+
+    return response.data.data!;
+  }
+
   async decomposeEpic(projectId: string, epicId: string, input: DecomposeEpicInput): Promise<DecompositionResult> {
     const response = await apiClient.post<ApiResponse<DecompositionResult>>(
       `/api/projects/${projectId}/epics/${epicId}/decompose`,
@@ -1,7 +1,8 @@
 // ABOUTME: API service for code graph visualization
 // ABOUTME: Provides methods for fetching dependency, symbol, module, and spec-mapping graphs
 
-import { ApiClient } from './api';
+import { ApiClient, getApiBaseUrl } from './api';
+import { platformFetch, getApiToken } from '@/lib/platform';
 
 const api = new ApiClient();
 
@@ -96,8 +97,15 @@ export interface GraphQueryOptions {
   max_depth?: number;
   filter?: string;
   layout?: string;
+  /** Node id, path, or label to root a subgraph at */
+  root?: string;
+  /** Maximum number of nodes to return */
+  max_nodes?: number;
 }
 
+export const EXPORT_FORMATS = ['dot', 'mermaid', 'graphml'] as const;
+export type ExportFormat = typeof EXPORT_FORMATS[number];
+
 export const graphService = {
   /**
    * Get dependency graph for a project
@@ -112,6 +120,8 @@ export const graphService = {
       if (options.max_depth) params.append('max_depth', options.max_depth.toString());
       if (options.filter) params.append('filter', options.filter);
       if (options.layout) params.append('layout', options.layout);
+      if (options.root) params.append('root', options.root);
+      if (options.max_nodes) params.append('max_nodes', options.max_nodes.toString());
       const queryString = params.toString();
       if (queryString) endpoint += `?${queryString}`;
     }
@@ -138,6 +148,8 @@ export const graphService = {
       if (options.max_depth) params.append('max_depth', options.max_depth.toString());
       if (options.filter) params.append('filter', options.filter);
       if (options.layout) params.append('layout', options.layout);
+      if (options.root) params.append('root', options.root);
+      if (options.max_nodes) params.append('max_nodes', options.max_nodes.toString());
       const queryString = params.toString();
       if (queryString) endpoint += `?${queryString}`;
     }
@@ -164,6 +176,8 @@ export const graphService = {
       if (options.max_depth) params.append('max_depth', options.max_depth.toString());
       if (options.filter) params.append('filter', options.filter);
       if (options.layout) params.append('layout', options.layout);
+      if (options.root) params.append('root', options.root);
+      if (options.max_nodes) params.append('max_nodes', options.max_nodes.toString());
       const queryString = params.toString();
       if (queryString) endpoint += `?${queryString}`;
     }
@@ -190,6 +204,8 @@ export const graphService = {
       if (options.max_depth) params.append('max_depth', options.max_depth.toString());
       if (options.filter) params.append('filter', options.filter);
       if (options.layout) params.append('layout', options.layout);
+      if (options.root) params.append('root', options.root);
+      if (options.max_nodes) params.append('max_nodes', options.max_nodes.toString());
       const queryString = params.toString();
       if (queryString) endpoint += `?${queryString}`;
     }
@@ -217,6 +233,8 @@ export const graphService = {
       if (options.max_depth) params.append('max_depth', options.max_depth.toString());
       if (options.filter) params.append('filter', options.filter);
       if (options.layout) params.append('layout', options.layout);
+      if (options.root) params.append('root', options.root);
+      if (options.max_nodes) params.append('max_nodes', options.max_nodes.toString());
       const queryString = params.toString();
       if (queryString) endpoint += `?${queryString}`;
     }
@@ -229,4 +247,42 @@ export const graphService = {
 
     return response.data.data;
   },
+
+  /**
+   * Export a graph as DOT, Mermaid, or GraphML text
+   */
+  async exportGraph(
+    projectId: string,
+    graphType: GraphType,
+    format: ExportFormat,
+    options?: Pick<GraphQueryOptions, 'root' | 'max_nodes'>
+  ): Promise<string> {
+    const params = new URLSearchParams({ format });
+    if (options?.root) params.append('root', options.root);
+    if (options?.max_nodes) params.append('max_nodes', options.max_nodes.toString());
+
+    const baseUrl = await getApiBaseUrl();
+    const token = await getApiToken();
+    const headers: Record<string, string> = {};
+    if (token) {
+      headers['X-API-Token'] = token;
+    }
+
+    const response = await platformFetch(
+      `${baseUrl}/api/projects/${projectId}/graph/${graphType}?${params.toString()}`,
+      { method: 'GET', headers }
+    );
+
+    if (!response.ok) {
+      throw new Error(`Failed to export ${graphType} graph: HTTP ${response.status}`);
+    }
+
+    // Errors are still reported as JSON
+    if (response.headers.get('content-type')?.includes('application/json')) {
+      const json: GraphResponse = await response.json();
+      throw new Error(json.error || `Failed to export ${graphType} graph`);
+    }
+
+    return response.text();
+  },
 };
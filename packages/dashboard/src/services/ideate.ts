@@ -28,6 +28,27 @@ export interface CreateIdeateInput {
   templateId?: string;
 }
 
+export interface StartFromDocumentInput {
+  projectId: string;
+  mode?: IdeateMode;
+  templateId?: string;
+  researchToolsEnabled?: boolean;
+  /** Repository directory or document file on the machine running Orkee */
+  repoPath?: string;
+  fileName?: string;
+  /** Markdown text of an uploaded file */
+  content?: string;
+  /** Base64 file contents; required for .docx uploads */
+  contentBase64?: string;
+}
+
+export interface DocumentImportResult {
+  session: IdeateSession;
+  source_name: string;
+  imported_sections: string[];
+  unmatched_headings: string[];
+}
+
 export interface UpdateIdeateInput {
   initialDescription?: string;
   mode?: IdeateMode;
@@ -237,6 +258,24 @@ export interface SimilarProject {
   patterns_to_adopt: string[];
 }
 
+export interface ResearchSource {
+  id: string;
+  session_id: string;
+  provider: string;
+  query: string;
+  title: string;
+  url: string;
+  snippet: string | null;
+  content: string | null;
+  fetched_at: string;
+}
+
+export interface SearchResearchSourcesInput {
+  query: string;
+  maxResults?: number;
+  fetchContent?: boolean;
+}
+
 export interface Reference {
   title: string;
   url: string;
@@ -339,6 +378,39 @@ export interface BuildOrderResult {
   optimization_notes: string;
 }
 
+export interface BuildSimulationInput {
+  disabled_features?: string[];
+  estimates?: Record<string, number>;
+  default_estimate_days?: number;
+}
+
+export interface BrokenDependency {
+  feature_id: string;
+  missing_dependency_id: string;
+  strength: DependencyStrength;
+}
+
+export interface TimelinePhase {
+  phase: number;
+  feature_ids: string[];
+  start_day: number;
+  end_day: number;
+}
+
+export interface BuildSimulationResult {
+  session_id: string;
+  build_sequence: string[];
+  parallel_groups: string[][];
+  critical_path: string[];
+  estimated_phases: number;
+  optimization_strategy: OptimizationStrategy;
+  excluded_features: string[];
+  broken_dependencies: BrokenDependency[];
+  timeline: TimelinePhase[];
+  total_days: number;
+  computed_at: string;
+}
+
 export interface CircularDependency {
   cycle: string[];
   severity: string;
@@ -351,6 +423,9 @@ export interface CircularDependency {
 
 export type RoundtableStatus = 'setup' | 'discussing' | 'completed' | 'cancelled';
 export type MessageRole = 'expert' | 'user' | 'moderator' | 'system';
+export type ModerationStrategy = 'round_robin' | 'debate' | 'devils_advocate' | 'convergence';
+export type TurnStance = 'open' | 'pro' | 'con' | 'devils_advocate';
+export type ConclusionReason = 'max_turns_reached' | 'converged';
 export type InsightPriority = 'low' | 'medium' | 'high' | 'critical';
 
 export interface ExpertPersona {
@@ -396,6 +471,8 @@ export interface RoundtableSession {
   topic: string;
   num_experts: number;
   moderator_persona: string | null;
+  moderation_strategy: ModerationStrategy;
+  max_turns: number | null;
   started_at: string | null;
   completed_at: string | null;
   created_at: string;
@@ -419,6 +496,8 @@ export interface StartRoundtableRequest {
   topic: string;
   expertIds: string[];
   durationMinutes?: number;
+  strategy?: ModerationStrategy;
+  maxTurns?: number;
 }
 
 export interface RoundtableMessage {
@@ -478,8 +557,29 @@ export interface RoundtableStatistics {
   completed_at: string | null;
 }
 
+export interface ModeratorTurn {
+  expert_id: string;
+  expert_name: string;
+  stance: TurnStance;
+  directive: string | null;
+}
+
+export type ModerationDecision =
+  | { action: 'next_turn'; turn: ModeratorTurn }
+  | { action: 'conclude'; reason: ConclusionReason; convergence_score: number | null };
+
 export interface RoundtableEvent {
-  type: 'connected' | 'started' | 'message' | 'typing' | 'interjection_acknowledged' | 'completed' | 'error' | 'heartbeat';
+  type:
+    | 'connected'
+    | 'started'
+    | 'message'
+    | 'typing'
+    | 'turn_assigned'
+    | 'concluding'
+    | 'interjection_acknowledged'
+    | 'completed'
+    | 'error'
+    | 'heartbeat';
   data?: unknown;
 }
 
@@ -524,7 +624,45 @@ export interface TechnicalApproach {
 // Phase 7: PRD Generation & Export Types
 // =============================================================================
 
-export type ExportFormat = 'markdown' | 'html' | 'pdf' | 'docx';
+export type ExportFormat = 'markdown' | 'html' | 'pdf' | 'docx' | 'notion' | 'confluence';
+
+export type PublishingTarget = 'notion' | 'confluence';
+
+export interface PublishingCredentialsInput {
+  apiToken: string;
+  siteUrl?: string;
+  accountEmail?: string;
+  defaultParent?: string;
+}
+
+export interface PublishingConnection {
+  target: PublishingTarget;
+  siteUrl: string | null;
+  accountEmail: string | null;
+  defaultParent: string | null;
+  updatedAt: string;
+}
+
+export interface PublishPRDInput {
+  format: PublishingTarget;
+  title?: string;
+  parentId?: string;
+  orkeeUrl?: string;
+}
+
+export type ExportJobStatus = 'queued' | 'running' | 'completed' | 'failed';
+
+export interface ExportJob {
+  id: string;
+  sessionId: string;
+  prdId: string | null;
+  format: PublishingTarget;
+  status: ExportJobStatus;
+  pageUrl: string | null;
+  error: string | null;
+  createdAt: string;
+  completedAt: string | null;
+}
 
 export interface ExportOptions {
   format: ExportFormat;
@@ -723,6 +861,22 @@ class IdeateService {
     return response.data.data;
   }
 
+  /**
+   * Create a new ideate session pre-filled from a README/PRD document
+   */
+  async startFromDocument(input: StartFromDocumentInput): Promise<DocumentImportResult> {
+    const response = await apiClient.post<{ success: boolean; data: DocumentImportResult }>(
+      '/api/ideate/start-from-document',
+      input
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to import document');
+    }
+
+    return response.data.data;
+  }
+
   /**
    * Get a ideate session by ID
    */
@@ -904,12 +1058,12 @@ class IdeateService {
 
     if (input?.templateId) {
       try {
-        console.log(`[quickGenerateStreaming] Fetching template ${input.templateId}`);
-        const template = await templatesService.getById(input.templateId);
-
-        // Apply template to format sections
-        content = applyTemplateToSections(template.content, sections);
-        console.log(`[quickGenerateStreaming] Applied template "${template.name}" to sections`);
+        console.log(`[quickGenerateStreaming] Rendering template ${input.templateId}`);
+        content = await templatesService.render(input.templateId, {
+          variables: buildTemplateVariables(sections, result.data.features),
+          projectId: session.project_id,
+        });
+        console.log(`[quickGenerateStreaming] Applied template ${input.templateId} to sections`);
       } catch (error) {
         console.error('[quickGenerateStreaming] Failed to apply template, using default formatting:', error);
         content = Object.entries(sections)
@@ -1501,6 +1655,50 @@ class IdeateService {
     return response.data.data;
   }
 
+  /**
+   * Search the web with the server's configured provider and store results as sources
+   */
+  async searchResearchSources(sessionId: string, input: SearchResearchSourcesInput): Promise<ResearchSource[]> {
+    const response = await apiClient.post<{ success: boolean; data: ResearchSource[] }>(
+      `/api/ideate/${sessionId}/research/sources/search`,
+      input
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to search web sources');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Get stored web sources for a session
+   */
+  async getResearchSources(sessionId: string): Promise<ResearchSource[]> {
+    const response = await apiClient.get<{ success: boolean; data: ResearchSource[] }>(
+      `/api/ideate/${sessionId}/research/sources`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to get web sources');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Delete a stored web source
+   */
+  async deleteResearchSource(sessionId: string, sourceId: string): Promise<void> {
+    const response = await apiClient.delete<{ success: boolean }>(
+      `/api/ideate/${sessionId}/research/sources/${sourceId}`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to delete web source');
+    }
+  }
+
   /**
    * Extract lessons from a similar project
    */
@@ -1611,6 +1809,26 @@ class IdeateService {
     return response.data.data;
   }
 
+  /**
+   * Simulate build order with features toggled off or estimates changed (nothing is saved)
+   */
+  async simulateBuildOrder(
+    sessionId: string,
+    strategy: OptimizationStrategy,
+    adjustments: BuildSimulationInput = {}
+  ): Promise<BuildSimulationResult> {
+    const response = await apiClient.post<{ success: boolean; data: BuildSimulationResult }>(
+      `/api/ideate/${sessionId}/dependencies/simulate`,
+      { strategy, ...adjustments }
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to simulate build order');
+    }
+
+    return response.data.data;
+  }
+
   /**
    * Get current build order
    */
@@ -1829,6 +2047,21 @@ class IdeateService {
     }
   }
 
+  /**
+   * Ask the moderator who speaks next (or whether to conclude) under the roundtable's strategy
+   */
+  async getNextTurn(roundtableId: string): Promise<ModerationDecision | null> {
+    const response = await apiClient.get<{ success: boolean; data: ModerationDecision | null }>(
+      `/api/ideate/roundtable/${roundtableId}/next-turn`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to get next turn');
+    }
+
+    return response.data.data;
+  }
+
   /**
    * Get SSE stream URL for real-time discussion updates
    * Note: This returns the URL, actual SSE connection should be managed by React hook
@@ -2115,6 +2348,97 @@ class IdeateService {
     return response.data.data;
   }
 
+  /**
+   * Publish the session's saved PRD to Notion or Confluence as a background job
+   */
+  async publishPRD(sessionId: string, input: PublishPRDInput): Promise<ExportJob> {
+    const response = await apiClient.post<{ success: boolean; data: ExportJob }>(
+      `/api/ideate/${sessionId}/prd/publish`,
+      input
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to publish PRD');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Get an export job to check on publishing
+   */
+  async getExportJob(jobId: string): Promise<ExportJob> {
+    const response = await apiClient.get<{ success: boolean; data: ExportJob }>(
+      `/api/ideate/export-jobs/${jobId}`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to get export job');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * List the session's export jobs, newest first
+   */
+  async listExportJobs(sessionId: string): Promise<ExportJob[]> {
+    const response = await apiClient.get<{ success: boolean; data: ExportJob[] }>(
+      `/api/ideate/${sessionId}/export-jobs`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to list export jobs');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * List the current user's connected publishing targets
+   */
+  async listPublishingConnections(): Promise<PublishingConnection[]> {
+    const response = await apiClient.get<{ success: boolean; data: PublishingConnection[] }>(
+      '/api/ideate/publishing/credentials'
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to list publishing connections');
+    }
+
+    return response.data.data;
+  }
+
+  /**
+   * Connect a publishing target, replacing any existing token
+   */
+  async setPublishingCredentials(
+    target: PublishingTarget,
+    input: PublishingCredentialsInput
+  ): Promise<void> {
+    const response = await apiClient.put<{ success: boolean }>(
+      `/api/ideate/publishing/credentials/${target}`,
+      input
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to save publishing credentials');
+    }
+  }
+
+  /**
+   * Disconnect a publishing target
+   */
+  async deletePublishingCredentials(target: PublishingTarget): Promise<void> {
+    const response = await apiClient.delete<{ success: boolean }>(
+      `/api/ideate/publishing/credentials/${target}`
+    );
+
+    if (response.error || !response.data.success) {
+      throw new Error(response.error || 'Failed to delete publishing credentials');
+    }
+  }
+
   /**
    * Get completeness metrics for session
    */
@@ -2323,12 +2647,17 @@ class IdeateService {
   // ===========================================================================
 
   /**
-   * Trigger codebase analysis for a session
+   * Trigger codebase analysis for a session.
+   * Results are cached per commit; pass forceRefresh to rescan the whole project.
    */
-  async analyzeCodebase(sessionId: string, projectPath: string): Promise<void> {
+  async analyzeCodebase(
+    sessionId: string,
+    projectPath: string,
+    forceRefresh = false
+  ): Promise<void> {
     const response = await apiClient.post<{ success: boolean }>(
       `/api/ideate/sessions/${sessionId}/analyze-codebase`,
-      { project_path: projectPath }
+      { project_path: projectPath, forceRefresh }
     );
 
     if (response.error || !response.data.success) {
@@ -2629,32 +2958,24 @@ function formatSectionData(section: string, jsonData: string): string {
 }
 
 /**
- * Apply template formatting to sections
- * Parses JSON section data and replaces template placeholders with formatted markdown
+ * Build the variables passed to the server-side template renderer
+ * Sections are formatted as markdown; missing sections are empty so {{#if}} blocks skip them
  */
-function applyTemplateToSections(
-  templateContent: string,
-  sections: Record<string, string>
-): string {
-  let formattedContent = templateContent;
+function buildTemplateVariables(
+  sections: Record<string, string>,
+  features: unknown[] | undefined
+): Record<string, unknown> {
+  const variables: Record<string, unknown> = {};
 
-  // Parse and format each section, then replace in template
   for (const [sectionKey, sectionData] of Object.entries(sections)) {
-    const formattedData = formatSectionData(sectionKey, sectionData);
-
-    // Try multiple placeholder patterns for flexibility
-    // 1. {{sectionName}} pattern
-    const pattern1 = `{{${sectionKey}}}`;
-    formattedContent = formattedContent.replace(
-      new RegExp(pattern1.replace(/[.*+?^${}()|[\]\\]/g, '\\$&'), 'g'),
-      formattedData
-    );
-
-    // 2. [Description-style] patterns for human-readable placeholders
-    // These will be replaced by the formatted content already inserted above
+    variables[sectionKey] =
+      sectionData && sectionData !== 'null' ? formatSectionData(sectionKey, sectionData) : '';
   }
 
-  return formattedContent;
+  // Raw feature objects for {{#each feature_list}} loops
+  variables.feature_list = features ?? [];
+
+  return variables;
 }
 
 
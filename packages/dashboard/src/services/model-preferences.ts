@@ -3,7 +3,14 @@
 
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { apiClient } from './api';
-import type { ModelPreferences, ModelInfo, TaskType, ModelConfig, Provider } from '@/types/models';
+import type {
+  ModelPreferences,
+  ModelInfo,
+  TaskType,
+  ModelConfig,
+  Provider,
+  TaskModelPolicy,
+} from '@/types/models';
 import { DEFAULT_MODEL_CONFIG, TASK_TYPE_TO_FIELD } from '@/types/models';
 
 /**
@@ -174,6 +181,86 @@ export function useUpdateTaskModelPreference(userId: string) {
   });
 }
 
+/**
+ * API response type for a task's fallback chain and cost cap
+ */
+interface TaskModelPolicyResponse {
+  task_type: string;
+  primary: ModelConfig;
+  fallbacks: ModelConfig[];
+  max_cost_per_call: number | null;
+  updated_at: string | null;
+}
+
+function convertToTaskModelPolicy(response: TaskModelPolicyResponse): TaskModelPolicy {
+  return {
+    taskType: response.task_type as TaskType,
+    primary: response.primary,
+    fallbacks: response.fallbacks,
+    maxCostPerCall: response.max_cost_per_call,
+    updatedAt: response.updated_at,
+  };
+}
+
+/**
+ * React Query hook to fetch the fallback chains and cost caps of every task type
+ */
+export function useTaskModelPolicies(userId: string | undefined) {
+  return useQuery({
+    queryKey: ['model-task-policies', userId],
+    queryFn: async () => {
+      const response = await apiClient.get<{ success: boolean; data: TaskModelPolicyResponse[]; error: string | null }>(
+        `/api/users/${userId}/model-preferences/policies`
+      );
+
+      if (response.error) {
+        throw new Error(response.error);
+      }
+
+      return response.data.data.map(convertToTaskModelPolicy);
+    },
+    enabled: !!userId,
+    staleTime: 5 * 60 * 1000, // 5 minutes
+    retry: 1,
+  });
+}
+
+/**
+ * React Query mutation hook to replace a task's fallback chain and cost cap
+ */
+export function useUpdateTaskModelPolicy(userId: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async ({
+      taskType,
+      fallbacks,
+      maxCostPerCall,
+    }: {
+      taskType: TaskType;
+      fallbacks: ModelConfig[];
+      maxCostPerCall: number | null;
+    }) => {
+      const response = await apiClient.put<{ success: boolean; data: TaskModelPolicyResponse; error: string | null }>(
+        `/api/users/${userId}/model-preferences/${taskType}/policy`,
+        {
+          fallbacks,
+          max_cost_per_call: maxCostPerCall,
+        }
+      );
+
+      if (response.error) {
+        throw new Error(response.error);
+      }
+
+      return convertToTaskModelPolicy(response.data.data);
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: ['model-task-policies', userId] });
+    },
+  });
+}
+
 /**
  * Get model configuration for a specific task type
  * Returns default config if preferences not loaded
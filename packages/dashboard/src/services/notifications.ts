@@ -0,0 +1,57 @@
+// ABOUTME: Notification feed API client
+// ABOUTME: Lists dispatched notifications and sends test notifications to all channels
+
+import { apiRequest } from './api'
+
+export type NotificationEventType =
+  | 'execution_finished'
+  | 'sync_failed'
+  | 'budget_threshold'
+  | 'server_crash'
+  | 'reauth_required'
+  | 'auth_lockout'
+  | 'certificate_expiring'
+  | 'test'
+
+export type NotificationChannel = 'desktop' | 'webhook' | 'tui'
+
+export type NotificationSeverity = 'info' | 'success' | 'warning' | 'error'
+
+export interface Notification {
+  id: string
+  event_type: NotificationEventType
+  severity: NotificationSeverity
+  title: string
+  message: string
+  project_id: string | null
+  channels: NotificationChannel[]
+  created_at: string
+}
+
+export async function listNotifications(options: {
+  channel?: NotificationChannel
+  since?: string
+  limit?: number
+} = {}): Promise<Notification[]> {
+  const params = new URLSearchParams()
+  if (options.channel) params.set('channel', options.channel)
+  if (options.since) params.set('since', options.since)
+  if (options.limit) params.set('limit', String(options.limit))
+  const query = params.toString()
+
+  const response = await apiRequest<Notification[]>(`/api/notifications${query ? `?${query}` : ''}`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list notifications')
+}
+
+export async function sendTestNotification(): Promise<Notification> {
+  const response = await apiRequest<Notification>('/api/notifications/test', {
+    method: 'POST',
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to send test notification')
+}
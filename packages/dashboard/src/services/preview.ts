@@ -57,6 +57,19 @@ export interface ServerLogsResponse {
   logs: DevServerLog[];
 }
 
+export interface ResourceSample {
+  timestamp: string;
+  /** Summed across the server's process tree, so it can exceed 100 on multi-core machines */
+  cpu_percent: number;
+  memory_bytes: number;
+}
+
+export interface ServerMetricsResponse {
+  project_id: string;
+  interval_secs: number;
+  samples: ResourceSample[];
+}
+
 export interface ApiResponse<T> {
   success: boolean;
   data?: T;
@@ -162,6 +175,27 @@ class PreviewService {
     return apiResponse.data.logs;
   }
 
+  /**
+   * Get the rolling CPU/memory history of a server, oldest sample first
+   */
+  async getServerMetrics(projectId: string): Promise<ResourceSample[]> {
+    const response = await apiClient.get<ApiResponse<ServerMetricsResponse>>(
+      `${this.baseUrl}/servers/${projectId}/metrics`
+    );
+
+    if (response.error) {
+      console.warn('Failed to get server metrics:', response.error);
+      return [];
+    }
+
+    const apiResponse = response.data;
+    if (!apiResponse.success || !apiResponse.data) {
+      return [];
+    }
+
+    return apiResponse.data.samples;
+  }
+
   /**
    * Clear server logs
    */
@@ -0,0 +1,67 @@
+// ABOUTME: Project script runner API client
+// ABOUTME: Lists and cancels setup/dev/cleanup script runs and builds SSE URLs for their output
+
+import { apiRequest, getApiBaseUrl } from './api'
+import { getApiToken } from '@/lib/platform'
+
+export type ScriptKind = 'setup' | 'dev' | 'cleanup'
+
+export type ScriptRunStatus = 'running' | 'succeeded' | 'failed' | 'timed_out' | 'cancelled'
+
+export interface ScriptRun {
+  id: string
+  projectId: string
+  kind: ScriptKind
+  command: string
+  status: ScriptRunStatus
+  exitCode: number | null
+  timeoutSecs: number
+  outputTail: string
+  startedAt: string
+  finishedAt: string | null
+  durationMs: number | null
+}
+
+export type ScriptEvent =
+  | { type: 'started'; run: ScriptRun }
+  | { type: 'output'; stream: 'stdout' | 'stderr'; line: string }
+  | { type: 'finished'; run: ScriptRun }
+
+export async function listScriptRuns(
+  projectId: string,
+  kind?: ScriptKind,
+  limit?: number
+): Promise<ScriptRun[]> {
+  const params = new URLSearchParams()
+  if (kind) params.set('kind', kind)
+  if (limit) params.set('limit', String(limit))
+  const query = params.toString() ? `?${params}` : ''
+
+  const response = await apiRequest<ScriptRun[]>(
+    `/api/projects/${projectId}/script-runs${query}`
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list script runs')
+}
+
+/** Stop a running script. It gets SIGTERM, then SIGKILL after a grace period. */
+export async function cancelScriptRun(projectId: string, runId: string): Promise<ScriptRun> {
+  const response = await apiRequest<ScriptRun>(
+    `/api/projects/${projectId}/script-runs/${runId}/cancel`,
+    { method: 'POST' }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to cancel script run')
+}
+
+/** EventSource URL streaming a run's output; a finished run sends only its `finished` event */
+export async function scriptRunEventsUrl(projectId: string, runId: string): Promise<string> {
+  const baseUrl = await getApiBaseUrl()
+  const apiToken = await getApiToken()
+  const url = `${baseUrl}/api/projects/${projectId}/script-runs/${runId}/events`
+  return apiToken ? `${url}?token=${encodeURIComponent(apiToken)}` : url
+}
@@ -64,6 +64,9 @@ export interface Project {
   manualTasks?: ManualTask[];
   mcpServers?: Record<string, boolean>;
   gitRepository?: GitRepositoryInfo;
+  ownerUserId?: string | null;
+  // Incremented on every update; send it back when updating
+  version: number;
   // GitHub integration
   githubOwner?: string;
   githubRepo?: string;
@@ -103,6 +106,9 @@ export interface ProjectUpdateInput {
   taskSource?: TaskSource;
   manualTasks?: ManualTask[];
   mcpServers?: Record<string, boolean>;
+  // Version of the project being edited; required by the server, which
+  // rejects the update with 409 if the project has changed since
+  version?: number;
 }
 
 // API Response format from Rust server
@@ -240,7 +246,12 @@ export class ProjectsService {
     if (config.githubLabelsConfig !== undefined) updates.github_labels_config = JSON.stringify(config.githubLabelsConfig);
     if (config.githubDefaultAssignee !== undefined) updates.github_default_assignee = config.githubDefaultAssignee;
 
-    return this.updateProject(projectId, updates as ProjectUpdateInput);
+    const project = await this.getProject(projectId);
+    if (!project) {
+      throw new Error('Project not found');
+    }
+
+    return this.updateProject(projectId, { ...updates, version: project.version } as ProjectUpdateInput);
   }
 
   async getGitHubConfig(projectId: string): Promise<GitHubConfig | null> {
@@ -6,7 +6,7 @@ import { getModelInstance, calculateCost } from '@/lib/ai/config';
 import { getModelForTask } from './model-preferences';
 import { trackAIOperationWithCost } from '@/lib/ai/telemetry';
 import { z } from 'zod';
-import type { Competitor, SimilarProject } from './ideate';
+import type { Competitor, ResearchSource, SimilarProject } from './ideate';
 
 /**
  * Schema for competitor analysis
@@ -335,7 +335,8 @@ export async function synthesizeResearch(
   competitors: Competitor[],
   similarProjects: SimilarProject[],
   modelPreferences?: ReturnType<typeof getModelForTask>,
-  projectId?: string | null
+  projectId?: string | null,
+  webSources: ResearchSource[] = []
 ): Promise<z.infer<typeof ResearchSynthesisSchema>> {
   const modelConfig = modelPreferences || { provider: 'anthropic' as const, model: 'claude-sonnet-4-5-20250929' };
   const model = getModelInstance(modelConfig.provider, modelConfig.model);
@@ -348,6 +349,15 @@ export async function synthesizeResearch(
     )
     .join('\n\n');
 
+  const sourcesSummary = webSources.length
+    ? webSources
+        .map(
+          (s, i) => `[${i + 1}] ${s.title} (${s.url}, via ${s.provider})
+${(s.content || s.snippet || '').slice(0, 1500).trim()}`
+        )
+        .join('\n\n')
+    : 'None';
+
   const prompt = `Synthesize all research findings into strategic insights and recommendations.
 
 PROJECT DESCRIPTION:
@@ -358,6 +368,9 @@ ${competitorSummary}
 
 SIMILAR PROJECTS REVIEWED: ${similarProjects.length}
 
+WEB SOURCES (${webSources.length} gathered):
+${sourcesSummary}
+
 Provide a comprehensive synthesis including:
 
 1. **Key Findings** - 5-7 most important insights from the research
@@ -366,7 +379,7 @@ Provide a comprehensive synthesis including:
 4. **Risks** - 3-5 competitive or market risks to be aware of
 5. **Recommendations** - 5-7 strategic recommendations based on the research
 
-Be specific and actionable. Base recommendations on concrete findings from the competitor and project analysis.`;
+Be specific and actionable. Base recommendations on concrete findings from the competitor and project analysis. When a finding relies on a web source, cite it by its number, e.g. [2].`;
 
   const result = await trackAIOperationWithCost(
     'synthesize_research',
@@ -111,6 +111,27 @@ export function buildModeratorOpening(topic: string, participants: ExpertPersona
   return `Welcome everyone! Today we're discussing: "${topic}". Our panel includes: ${expertList}. Let's begin by having each expert share their initial thoughts on this topic.`;
 }
 
+/**
+ * Build the prompt for an expert's turn, including any moderator directive
+ * (e.g. a debate side or devil's-advocate challenge)
+ */
+export function buildExpertResponsePrompt(
+  expert: ExpertPersona,
+  topic: string,
+  messages: RoundtableMessage[],
+  directive?: string | null
+): string {
+  const conversationContext = formatConversationHistory(messages);
+
+  let prompt = `Topic: ${topic}\n\nPrevious discussion:\n${conversationContext}\n\nAs ${expert.name}, provide your perspective on this topic. Consider what other experts have said and add your unique insights. Keep your response focused and under 250 words.`;
+
+  if (directive) {
+    prompt += `\n\nModerator instruction for this turn: ${directive}`;
+  }
+
+  return prompt;
+}
+
 /**
  * Build expert suggestion prompt
  */
@@ -193,14 +214,13 @@ export async function generateExpertResponse(
   messages: RoundtableMessage[],
   allExperts: ExpertPersona[],
   modelPreferences?: ReturnType<typeof getModelForTask>,
-  projectId?: string | null
+  projectId?: string | null,
+  directive?: string | null
 ): Promise<string> {
   const modelConfig = modelPreferences || { provider: 'anthropic' as const, model: 'claude-sonnet-4-5-20250929' };
   const model = getModelInstance(modelConfig.provider, modelConfig.model);
 
-  const conversationContext = formatConversationHistory(messages);
-
-  const prompt = `Topic: ${topic}\n\nPrevious discussion:\n${conversationContext}\n\nAs ${expert.name}, provide your perspective on this topic. Consider what other experts have said and add your unique insights. Keep your response focused and under 250 words.`;
+  const prompt = buildExpertResponsePrompt(expert, topic, messages, directive);
 
   const systemPrompt = `${EXPERT_RESPONSE_SYSTEM_PROMPT_PREFIX}\n\n${expert.system_prompt}`;
 
@@ -239,15 +259,14 @@ export async function streamExpertResponse(
   onError: (error: Error) => void,
   abortSignal?: AbortSignal,
   modelPreferences?: ReturnType<typeof getModelForTask>,
-  projectId?: string | null
+  projectId?: string | null,
+  directive?: string | null
 ): Promise<void> {
   try {
     const modelConfig = modelPreferences || { provider: 'anthropic' as const, model: 'claude-sonnet-4-5-20250929' };
     const model = getModelInstance(modelConfig.provider, modelConfig.model);
 
-    const conversationContext = formatConversationHistory(messages);
-
-    const prompt = `Topic: ${topic}\n\nPrevious discussion:\n${conversationContext}\n\nAs ${expert.name}, provide your perspective on this topic. Consider what other experts have said and add your unique insights. Keep your response focused and under 250 words.`;
+    const prompt = buildExpertResponsePrompt(expert, topic, messages, directive);
 
     const systemPrompt = `${EXPERT_RESPONSE_SYSTEM_PROMPT_PREFIX}\n\n${expert.system_prompt}`;
 
@@ -128,6 +128,43 @@ export interface ValidationResult {
   details?: Record<string, unknown>
 }
 
+export type ConnectionCheckStatus = 'passed' | 'failed' | 'skipped'
+
+export interface ConnectionCheck {
+  name: string
+  status: ConnectionCheckStatus
+  message: string
+}
+
+export type ConnectionFailureKind =
+  | 'unknown_provider'
+  | 'missing_credentials'
+  | 'invalid_settings'
+  | 'unreachable'
+  | 'timeout'
+  | 'invalid_credentials'
+  | 'permission_denied'
+  | 'resource_not_found'
+  | 'rate_limited'
+  | 'provider_error'
+  | 'not_supported'
+
+export interface ConnectionFailure {
+  kind: ConnectionFailureKind
+  message: string
+  hint?: string
+  http_status?: number
+}
+
+export interface ConnectionTestReport {
+  provider: string
+  success: boolean
+  checks: ConnectionCheck[]
+  failure?: ConnectionFailure
+  duration_ms: number
+  tested_at: string
+}
+
 // Get sandbox settings
 export async function getSandboxSettings(): Promise<SandboxSettings> {
   const response = await apiRequest<SandboxSettings>('/api/sandbox/settings')
@@ -207,9 +244,9 @@ export async function updateProviderCredentials(
 }
 
 // Validate provider configuration
-export async function validateProvider(provider: string): Promise<ValidationResult> {
-  const response = await apiRequest<ValidationResult>(
-    `/api/sandbox/providers/${provider}/validate`,
+export async function testProviderConnection(provider: string): Promise<ConnectionTestReport> {
+  const response = await apiRequest<ConnectionTestReport>(
+    `/api/sandbox/providers/${provider}/test`,
     {
       method: 'POST',
     }
@@ -217,7 +254,53 @@ export async function validateProvider(provider: string): Promise<ValidationResu
   if (response.success && response.data) {
     return response.data
   }
-  throw new Error(response.error || 'Failed to validate provider')
+  throw new Error(response.error || 'Failed to test provider connection')
+}
+
+export interface GpuDevice {
+  index: number
+  uuid: string
+  name: string
+  memory_mb: number | null
+}
+
+export interface ProviderInfo {
+  name: string
+  version: string
+  provider_type: string
+  capabilities: {
+    gpu_support: boolean
+    persistent_storage: boolean
+    network_isolation: boolean
+    resource_limits: boolean
+    exec_support: boolean
+    file_transfer: boolean
+    metrics: boolean
+  }
+  /** GPUs available for passthrough */
+  gpus: GpuDevice[]
+  status: { state: 'ready' } | { state: 'not_available' | 'degraded'; reason: string }
+}
+
+// Get a registered provider's runtime info, including detected GPUs
+export async function getProviderInfo(provider: string): Promise<ProviderInfo> {
+  const response = await apiRequest<ProviderInfo>(`/api/sandbox/providers/${provider}/info`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to get provider info')
+}
+
+export async function validateProvider(provider: string): Promise<ValidationResult> {
+  const report = await testProviderConnection(provider)
+  const { failure } = report
+  return {
+    valid: report.success,
+    message: failure
+      ? [failure.message, failure.hint].filter(Boolean).join(' ')
+      : 'Connection successful',
+    details: { checks: report.checks, failure, duration_ms: report.duration_ms },
+  }
 }
 
 // Toggle provider enabled status
@@ -225,6 +308,89 @@ export async function toggleProvider(provider: string, enabled: boolean): Promis
   return updateProviderSettings(provider, { enabled })
 }
 
+// ============================================================================
+// Execution Templates
+// ============================================================================
+
+export interface CacheVolume {
+  name: string
+  container_path: string
+}
+
+export interface SandboxTemplate {
+  id: string
+  name: string
+  description: string | null
+  image: string
+  env_vars: Record<string, string>
+  cache_volumes: CacheVolume[]
+  setup_commands: string[]
+  is_builtin: boolean
+  created_at: string
+  updated_at: string
+}
+
+export interface SandboxTemplateInput {
+  name: string
+  description?: string | null
+  image: string
+  env_vars?: Record<string, string>
+  cache_volumes?: CacheVolume[]
+  setup_commands?: string[]
+}
+
+// List execution templates
+export async function listTemplates(): Promise<SandboxTemplate[]> {
+  const response = await apiRequest<SandboxTemplate[]>('/api/sandbox/templates')
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list templates')
+}
+
+// Create an execution template
+export async function createTemplate(template: SandboxTemplateInput): Promise<SandboxTemplate> {
+  const response = await apiRequest<SandboxTemplate>(
+    '/api/sandbox/templates',
+    {
+      method: 'POST',
+      body: JSON.stringify(template),
+    }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to create template')
+}
+
+// Update an execution template
+export async function updateTemplate(id: string, template: SandboxTemplateInput): Promise<SandboxTemplate> {
+  const response = await apiRequest<SandboxTemplate>(
+    `/api/sandbox/templates/${id}`,
+    {
+      method: 'PUT',
+      body: JSON.stringify(template),
+    }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to update template')
+}
+
+// Delete an execution template
+export async function deleteTemplate(id: string): Promise<void> {
+  const response = await apiRequest<void>(
+    `/api/sandbox/templates/${id}`,
+    {
+      method: 'DELETE',
+    }
+  )
+  if (!response.success) {
+    throw new Error(response.error || 'Failed to delete template')
+  }
+}
+
 // ============================================================================
 // Sandbox Instance Management (Phase 5)
 // ============================================================================
@@ -318,10 +484,14 @@ export interface CreateSandboxRequest {
   memory_mb?: number
   disk_gb?: number
   gpu_type?: string | null
+  /** GPUs to pass through; local Docker validates this against detected hardware */
+  gpu_count?: number | null
   network_mode?: string
   agent_id?: string | null
   model?: string | null
   template_id?: string | null
+  /** Project whose secrets are injected into the sandbox environment */
+  project_id?: string | null
   env_vars?: Record<string, string>
   volumes?: { host_path: string; container_path: string; read_only: boolean }[]
 }
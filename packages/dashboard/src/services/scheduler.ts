@@ -0,0 +1,78 @@
+// ABOUTME: Scheduled job API client
+// ABOUTME: Lists recurring background jobs, updates their schedules, and runs them on demand
+
+import { apiRequest } from './api'
+
+export type JobRunStatus = 'running' | 'success' | 'failed'
+
+export interface ScheduledJob {
+  id: string
+  name: string
+  description: string | null
+  /** Standard 5-field cron expression, evaluated in the server's local time */
+  cron_expression: string
+  enabled: boolean
+  next_run_at: string | null
+  last_run_at: string | null
+  last_status: JobRunStatus | null
+  last_message: string | null
+  last_duration_ms: number | null
+  created_at: string
+  updated_at: string
+}
+
+export interface JobRun {
+  id: string
+  job_id: string
+  status: JobRunStatus
+  message: string | null
+  started_at: string
+  finished_at: string | null
+  duration_ms: number | null
+}
+
+export interface JobUpdateInput {
+  enabled?: boolean
+  cron_expression?: string
+}
+
+export async function listScheduledJobs(): Promise<ScheduledJob[]> {
+  const response = await apiRequest<ScheduledJob[]>('/api/scheduler/jobs')
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list scheduled jobs')
+}
+
+export async function updateScheduledJob(
+  jobId: string,
+  input: JobUpdateInput
+): Promise<ScheduledJob> {
+  const response = await apiRequest<ScheduledJob>(`/api/scheduler/jobs/${jobId}`, {
+    method: 'PUT',
+    body: JSON.stringify(input),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to update scheduled job')
+}
+
+export async function runScheduledJob(jobId: string): Promise<JobRun> {
+  const response = await apiRequest<JobRun>(`/api/scheduler/jobs/${jobId}/run`, {
+    method: 'POST',
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to run scheduled job')
+}
+
+export async function listJobRuns(jobId: string, limit?: number): Promise<JobRun[]> {
+  const query = limit ? `?limit=${limit}` : ''
+  const response = await apiRequest<JobRun[]>(`/api/scheduler/jobs/${jobId}/runs${query}`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list job runs')
+}
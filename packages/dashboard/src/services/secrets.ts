@@ -0,0 +1,49 @@
+// ABOUTME: Per-project secret store API client
+// ABOUTME: Sets and deletes encrypted project secrets; listing returns names only, never values
+
+import { apiRequest } from './api'
+
+export interface ProjectSecret {
+  id: string
+  project_id: string
+  name: string
+  created_at: string
+  updated_at: string
+}
+
+export async function listSecrets(projectId: string): Promise<ProjectSecret[]> {
+  const response = await apiRequest<ProjectSecret[]>(`/api/projects/${projectId}/secrets`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list secrets')
+}
+
+/** Create or replace a secret. The value is write-only and cannot be read back. */
+export async function setSecret(
+  projectId: string,
+  name: string,
+  value: string
+): Promise<ProjectSecret> {
+  const response = await apiRequest<ProjectSecret>(
+    `/api/projects/${projectId}/secrets/${encodeURIComponent(name)}`,
+    {
+      method: 'PUT',
+      body: JSON.stringify({ value }),
+    }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to set secret')
+}
+
+export async function deleteSecret(projectId: string, name: string): Promise<void> {
+  const response = await apiRequest<boolean>(
+    `/api/projects/${projectId}/secrets/${encodeURIComponent(name)}`,
+    { method: 'DELETE' }
+  )
+  if (!response.success) {
+    throw new Error(response.error || 'Failed to delete secret')
+  }
+}
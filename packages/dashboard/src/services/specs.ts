@@ -67,6 +67,42 @@ export interface SpecValidationResult {
   };
 }
 
+export type RequirementDriftStatus = 'implemented' | 'untested' | 'unimplemented';
+
+export interface SpecDriftSymbol {
+  name: string;
+  path: string;
+  line?: number;
+}
+
+export interface RequirementDrift {
+  capability: string;
+  requirement: string;
+  status: RequirementDriftStatus;
+  symbols: SpecDriftSymbol[];
+  tests: string[];
+}
+
+export interface OrphanedFile {
+  path: string;
+  symbols: string[];
+}
+
+export interface SpecDriftReport {
+  projectId: string;
+  summary: {
+    capabilities: number;
+    requirements: number;
+    implemented: number;
+    untested: number;
+    unimplemented: number;
+    orphanedFiles: number;
+  };
+  requirements: RequirementDrift[];
+  orphanedFiles: OrphanedFile[];
+  generatedAt: string;
+}
+
 interface ApiResponse<T> {
   success: boolean;
   data: T | null;
@@ -215,6 +251,20 @@ export class SpecsService {
 
     return response.data.data!;
   }
+
+  async getSpecDrift(projectId: string): Promise<SpecDriftReport> {
+    const response = await apiClient.get<ApiResponse<SpecDriftReport>>(
+      `/api/projects/${projectId}/specs/drift`
+    );
+
+    if (response.error || !response.data?.success) {
+      throw new Error(
+        response.data?.error || response.error || 'Failed to detect spec drift'
+      );
+    }
+
+    return response.data.data!;
+  }
 }
 
 export const specsService = new SpecsService();
@@ -0,0 +1,111 @@
+// ABOUTME: Linear and Jira task source API client
+// ABOUTME: Manages per-project task sources and triggers issue imports
+
+import { apiRequest } from './api'
+import type { TaskStatus } from './tasks'
+
+export type TaskSourceProvider = 'linear' | 'jira'
+
+export interface TaskSource {
+  id: string
+  project_id: string
+  provider: TaskSourceProvider
+  base_url: string | null
+  email: string | null
+  external_project: string
+  filter: string | null
+  status_mapping: Record<string, TaskStatus>
+  enabled: boolean
+  last_synced_at: string | null
+  last_import_at: string | null
+  last_import_error: string | null
+  created_at: string
+  updated_at: string
+}
+
+export interface TaskSourceCreateInput {
+  provider: TaskSourceProvider
+  base_url?: string
+  email?: string
+  api_token: string
+  external_project: string
+  filter?: string
+  status_mapping?: Record<string, TaskStatus>
+  enabled?: boolean
+}
+
+export type TaskSourceUpdateInput = Partial<Omit<TaskSourceCreateInput, 'provider'>>
+
+export interface ImportSummary {
+  fetched: number
+  created: number
+  updated: number
+  unchanged: number
+  last_synced_at: string | null
+}
+
+export async function listTaskSources(projectId: string): Promise<TaskSource[]> {
+  const response = await apiRequest<TaskSource[]>(`/api/projects/${projectId}/task-sources`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list task sources')
+}
+
+export async function createTaskSource(
+  projectId: string,
+  input: TaskSourceCreateInput
+): Promise<TaskSource> {
+  const response = await apiRequest<TaskSource>(`/api/projects/${projectId}/task-sources`, {
+    method: 'POST',
+    body: JSON.stringify(input),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to create task source')
+}
+
+export async function updateTaskSource(
+  projectId: string,
+  sourceId: string,
+  input: TaskSourceUpdateInput
+): Promise<TaskSource> {
+  const response = await apiRequest<TaskSource>(
+    `/api/projects/${projectId}/task-sources/${sourceId}`,
+    {
+      method: 'PUT',
+      body: JSON.stringify(input),
+    }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to update task source')
+}
+
+export async function deleteTaskSource(projectId: string, sourceId: string): Promise<void> {
+  const response = await apiRequest<string>(
+    `/api/projects/${projectId}/task-sources/${sourceId}`,
+    { method: 'DELETE' }
+  )
+  if (!response.success) {
+    throw new Error(response.error || 'Failed to delete task source')
+  }
+}
+
+/** Import now; `full` re-fetches every issue instead of only recently updated ones */
+export async function importTaskSource(
+  projectId: string,
+  sourceId: string,
+  full = false
+): Promise<ImportSummary> {
+  const response = await apiRequest<ImportSummary>(
+    `/api/projects/${projectId}/task-sources/${sourceId}/import${full ? '?full=true' : ''}`,
+    { method: 'POST' }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to import task source')
+}
@@ -0,0 +1,93 @@
+// ABOUTME: Taskmaster sync API client
+// ABOUTME: Runs two-way syncs with .taskmaster/tasks/tasks.json and lists and resolves their conflicts
+
+import { apiRequest } from './api'
+
+export type MergeStrategy = 'manual' | 'merge' | 'prefer_orkee' | 'prefer_taskmaster'
+export type ConflictStatus = 'open' | 'resolved'
+export type ConflictResolution = 'orkee' | 'taskmaster' | 'custom' | 'auto'
+
+export interface SyncedFields {
+  title: string
+  description: string
+  details: string
+  test_strategy: string
+  status: string
+  priority: string
+  dependencies: string[]
+}
+
+export interface SyncReport {
+  imported: number
+  exported: number
+  updated_in_orkee: number
+  updated_in_file: number
+  deleted_in_orkee: number
+  deleted_in_file: number
+  merged: number
+  conflicts: number
+  unchanged: number
+  file_changed: boolean
+}
+
+export interface TaskmasterConflict {
+  id: string
+  project_id: string
+  task_id: string
+  taskmaster_id: string
+  fields: string[]
+  base: SyncedFields
+  orkee: SyncedFields
+  taskmaster: SyncedFields
+  status: ConflictStatus
+  resolution: ConflictResolution | null
+  created_at: string
+  resolved_at: string | null
+}
+
+export async function syncTaskmaster(
+  projectId: string,
+  strategy: MergeStrategy = 'manual'
+): Promise<SyncReport> {
+  const response = await apiRequest<SyncReport>('/api/taskmaster/sync', {
+    method: 'POST',
+    body: JSON.stringify({ projectId, strategy }),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to sync tasks.json')
+}
+
+export async function listTaskmasterConflicts(
+  projectId: string,
+  status?: ConflictStatus
+): Promise<TaskmasterConflict[]> {
+  const params = new URLSearchParams({ projectId })
+  if (status) {
+    params.set('status', status)
+  }
+  const response = await apiRequest<TaskmasterConflict[]>(`/api/taskmaster/conflicts?${params}`)
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list Taskmaster conflicts')
+}
+
+export async function resolveTaskmasterConflict(
+  conflictId: string,
+  resolution: Exclude<ConflictResolution, 'auto'>,
+  fields?: SyncedFields
+): Promise<TaskmasterConflict> {
+  const response = await apiRequest<TaskmasterConflict>(
+    `/api/taskmaster/conflicts/${conflictId}/resolve`,
+    {
+      method: 'POST',
+      body: JSON.stringify({ resolution, fields }),
+    }
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to resolve Taskmaster conflict')
+}
@@ -7,6 +7,7 @@ import { buildPaginationQuery } from '@/types/pagination';
 
 export type TaskStatus = 'pending' | 'in-progress' | 'review' | 'done' | 'deferred' | 'cancelled';
 export type TaskPriority = 'low' | 'medium' | 'high';
+export type CheckStatus = 'pending' | 'passed' | 'failed' | 'cancelled';
 
 export interface Task {
   id: string;
@@ -22,6 +23,16 @@ export interface Task {
   parentTaskId: string | null;
   complexityScore: number;
   specRequirementId: string | null;
+  // Checklist progress computed from task steps (null when the task has no steps)
+  steps_total: number;
+  steps_completed: number;
+  progress_percentage: number | null;
+  // Latest CI result reported through inbound webhooks
+  checks_status: CheckStatus | null;
+  checks_url: string | null;
+  checks_updated_at: string | null;
+  // Incremented on every update; send it back when updating
+  version: number;
 }
 
 export interface TaskCreateInput {
@@ -35,6 +46,22 @@ export interface TaskCreateInput {
   specRequirementId?: string;
 }
 
+/** Task fields parsed from free text; POST it to the tasks endpoint to create the task */
+export interface ParsedTask {
+  title: string;
+  description: string | null;
+  priority: TaskPriority | 'critical' | null;
+  tags: string[];
+  dueDate: string | null;
+  estimatedHours: number | null;
+}
+
+export interface ParseTaskResult {
+  task: ParsedTask;
+  source: 'ai' | 'parser';
+  fallbackReason: string | null;
+}
+
 export interface TaskUpdateInput {
   title?: string;
   description?: string;
@@ -45,6 +72,9 @@ export interface TaskUpdateInput {
   complexityScore?: number;
   specRequirementId?: string;
   completedAt?: string;
+  // Version of the task being edited; required by the server, which rejects
+  // the update with 409 if the task has changed since
+  version?: number;
 }
 
 // TDD Task Execution Types
@@ -56,6 +86,46 @@ export interface TaskStep {
   estimatedMinutes: number;
 }
 
+export type TaskStepStatus = 'pending' | 'in-progress' | 'completed' | 'skipped';
+
+// Persisted checklist step with execution state
+export interface TaskChecklistStep {
+  id: string;
+  task_id: string;
+  step_number: number;
+  action: string;
+  test_command: string | null;
+  expected_output: string | null;
+  estimated_minutes: number | null;
+  status: TaskStepStatus;
+  notes: string | null;
+  started_at: string | null;
+  completed_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface TaskStepUpdateInput {
+  status?: TaskStepStatus;
+  notes?: string;
+}
+
+export type TaskActivityField = 'status' | 'assignee' | 'priority';
+
+// A recorded status, assignee, or priority change
+export interface TaskActivity {
+  id: string;
+  task_id: string;
+  task_title: string;
+  project_id: string;
+  field: TaskActivityField;
+  old_value: string | null;
+  new_value: string | null;
+  actor_id: string | null;
+  actor_name: string | null;
+  created_at: string;
+}
+
 export interface FileReference {
   path: string;
   operation: 'create' | 'modify' | 'delete';
@@ -154,6 +224,30 @@ export class TasksService {
     return response.data.data!;
   }
 
+  async parseTask(
+    projectId: string,
+    text: string,
+    parserOnly = false
+  ): Promise<ParseTaskResult> {
+    const response = await apiRequest<ApiResponse<ParseTaskResult>>(
+      `/api/projects/${projectId}/tasks/parse`,
+      {
+        method: 'POST',
+        body: JSON.stringify({ text, parserOnly }),
+      }
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to parse task');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to parse task');
+    }
+
+    return response.data.data!;
+  }
+
   async updateTask(
     projectId: string,
     taskId: string,
@@ -195,6 +289,114 @@ export class TasksService {
     }
   }
 
+  // Activity Feed Operations (newest first)
+  async listTaskActivity(
+    projectId: string,
+    taskId: string,
+    pagination?: PaginationParams
+  ): Promise<PaginatedResponse<TaskActivity>> {
+    const query = pagination ? buildPaginationQuery(pagination) : '';
+    const response = await apiRequest<ApiResponse<PaginatedResponse<TaskActivity>>>(
+      `/api/projects/${projectId}/tasks/${taskId}/activity${query}`
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to fetch task activity');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to fetch task activity');
+    }
+
+    return response.data.data!;
+  }
+
+  async listProjectActivity(
+    projectId: string,
+    pagination?: PaginationParams
+  ): Promise<PaginatedResponse<TaskActivity>> {
+    const query = pagination ? buildPaginationQuery(pagination) : '';
+    const response = await apiRequest<ApiResponse<PaginatedResponse<TaskActivity>>>(
+      `/api/projects/${projectId}/tasks/activity${query}`
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to fetch project activity');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to fetch project activity');
+    }
+
+    return response.data.data!;
+  }
+
+  // Task Checklist Operations
+  async generateTaskSteps(
+    projectId: string,
+    taskId: string,
+    regenerate = false
+  ): Promise<TaskChecklistStep[]> {
+    const query = regenerate ? '?regenerate=true' : '';
+    const response = await apiRequest<ApiResponse<TaskChecklistStep[]>>(
+      `/api/projects/${projectId}/tasks/${taskId}/generate-steps${query}`,
+      {
+        method: 'POST',
+      }
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to generate task steps');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to generate task steps');
+    }
+
+    return response.data.data!;
+  }
+
+  async listTaskSteps(projectId: string, taskId: string): Promise<TaskChecklistStep[]> {
+    const response = await apiRequest<ApiResponse<TaskChecklistStep[]>>(
+      `/api/projects/${projectId}/tasks/${taskId}/steps`
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to fetch task steps');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to fetch task steps');
+    }
+
+    return response.data.data!;
+  }
+
+  async updateTaskStep(
+    projectId: string,
+    taskId: string,
+    stepId: string,
+    input: TaskStepUpdateInput
+  ): Promise<TaskChecklistStep> {
+    const response = await apiRequest<ApiResponse<TaskChecklistStep>>(
+      `/api/projects/${projectId}/tasks/${taskId}/steps/${stepId}`,
+      {
+        method: 'PUT',
+        body: JSON.stringify(input),
+      }
+    );
+
+    if (!response.success || !response.data) {
+      throw new Error(response.error || 'Failed to update task step');
+    }
+
+    if (!response.data.success) {
+      throw new Error(response.data.error || 'Failed to update task step');
+    }
+
+    return response.data.data!;
+  }
+
   // Task Execution Tracking Operations
   async generateExecutionSteps(taskId: string): Promise<TaskExecutionSteps> {
     const response = await apiRequest<ApiResponse<TaskExecutionSteps>>(
@@ -26,6 +26,23 @@ export interface UpdateTemplateInput {
   is_default?: boolean;
 }
 
+export interface TemplateError {
+  line: number;
+  column: number;
+  message: string;
+}
+
+export interface TemplateValidationResult {
+  valid: boolean;
+  errors: TemplateError[];
+  variables: string[];
+}
+
+export interface RenderTemplateInput {
+  variables: Record<string, unknown>;
+  projectId?: string;
+}
+
 export const templatesService = {
   async getAll(): Promise<PRDTemplate[]> {
     const response = await apiClient.get<{ success: boolean; data: PRDTemplate[] }>('/api/templates');
@@ -59,6 +76,28 @@ export const templatesService = {
     return response.data.data;
   },
 
+  async validate(content: string): Promise<TemplateValidationResult> {
+    const response = await apiClient.post<{ success: boolean; data: TemplateValidationResult }>(
+      '/api/templates/validate',
+      { content }
+    );
+    if (response.error) {
+      throw new Error(response.error);
+    }
+    return response.data.data;
+  },
+
+  async render(id: string, input: RenderTemplateInput): Promise<string> {
+    const response = await apiClient.post<{ success: boolean; data: { content: string } }>(
+      `/api/templates/${id}/render`,
+      input
+    );
+    if (response.error) {
+      throw new Error(response.error);
+    }
+    return response.data.data.content;
+  },
+
   async delete(id: string): Promise<void> {
     const response = await apiClient.delete<{ success: boolean }>(`/api/templates/${id}`);
     if (response.error) {
@@ -19,11 +19,20 @@ export interface MaskedUser {
   has_ai_gateway_key: boolean;
   ai_gateway_url: string | null;
   preferences: unknown | null;
+  is_current: boolean;
   created_at: string;
   updated_at: string;
   last_login_at: string | null;
 }
 
+export interface UserCreateInput {
+  email: string;
+  name: string;
+  avatarUrl?: string;
+  defaultAgentId?: string;
+  theme?: string;
+}
+
 export interface UserCredentialsUpdate {
   openai_api_key?: string;
   anthropic_api_key?: string;
@@ -61,6 +70,52 @@ export class UsersService {
     return response.data.data;
   }
 
+  /**
+   * List all users with masked credentials
+   */
+  async listUsers(): Promise<MaskedUser[]> {
+    const result = await apiRequest<MaskedUser[]>('/api/users');
+
+    if (!result.success || !result.data) {
+      throw new Error(result.error || 'Failed to list users');
+    }
+
+    return result.data;
+  }
+
+  /**
+   * Create a new user
+   */
+  async createUser(input: UserCreateInput): Promise<MaskedUser> {
+    const result = await apiRequest<MaskedUser>('/api/users', {
+      method: 'POST',
+      body: JSON.stringify(input),
+    });
+
+    if (!result.success || !result.data) {
+      throw new Error(result.error || 'Failed to create user');
+    }
+
+    return result.data;
+  }
+
+  /**
+   * Switch the active user
+   * Subsequent requests act on behalf of this user, and only their projects are listed
+   */
+  async switchUser(userId: string): Promise<MaskedUser> {
+    const result = await apiRequest<MaskedUser>('/api/users/current', {
+      method: 'PUT',
+      body: JSON.stringify({ userId }),
+    });
+
+    if (!result.success || !result.data) {
+      throw new Error(result.error || 'Failed to switch user');
+    }
+
+    return result.data;
+  }
+
   /**
    * Update user credentials
    * Accepts partial updates - only provided fields will be updated
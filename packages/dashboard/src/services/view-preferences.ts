@@ -0,0 +1,138 @@
+// ABOUTME: Table view preferences service with React Query hooks for saved column layouts
+// ABOUTME: Persists columns, sort order, and filters server-side so they follow the user across machines
+
+import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
+import { apiClient } from './api';
+
+export interface ColumnPreference {
+  id: string;
+  visible: boolean;
+  width?: number;
+}
+
+export interface SortPreference {
+  column: string;
+  direction: 'asc' | 'desc';
+}
+
+export interface ViewPreference {
+  user_id: string;
+  view_key: string;
+  columns: ColumnPreference[];
+  sort: SortPreference[];
+  filters: Record<string, unknown>;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface ViewPreferenceInput {
+  columns: ColumnPreference[];
+  sort: SortPreference[];
+  filters: Record<string, unknown>;
+}
+
+interface ViewPreferenceApiResponse<T> {
+  success: boolean;
+  data: T;
+  error: string | null;
+}
+
+/**
+ * React Query hook to fetch a user's saved layout for one view
+ * Resolves to null when the user hasn't customized the view
+ */
+export function useViewPreference(userId: string | undefined, viewKey: string) {
+  return useQuery({
+    queryKey: ['view-preferences', userId, viewKey],
+    queryFn: async (): Promise<ViewPreference | null> => {
+      const response = await apiClient.get<ViewPreferenceApiResponse<ViewPreference>>(
+        `/api/users/${userId}/view-preferences/${encodeURIComponent(viewKey)}`
+      );
+
+      if (response.error) {
+        if (response.error.includes('404')) {
+          return null;
+        }
+        throw new Error(response.error);
+      }
+
+      return response.data.data;
+    },
+    enabled: !!userId,
+    staleTime: 5 * 60 * 1000, // 5 minutes
+    retry: 1,
+  });
+}
+
+/**
+ * React Query hook to fetch all of a user's saved views
+ */
+export function useViewPreferences(userId: string | undefined) {
+  return useQuery({
+    queryKey: ['view-preferences', userId],
+    queryFn: async () => {
+      const response = await apiClient.get<ViewPreferenceApiResponse<ViewPreference[]>>(
+        `/api/users/${userId}/view-preferences`
+      );
+
+      if (response.error) {
+        throw new Error(response.error);
+      }
+
+      return response.data.data;
+    },
+    enabled: !!userId,
+    staleTime: 5 * 60 * 1000, // 5 minutes
+  });
+}
+
+/**
+ * React Query mutation hook to save a view's layout
+ */
+export function useSaveViewPreference(userId: string, viewKey: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async (input: ViewPreferenceInput) => {
+      const response = await apiClient.put<ViewPreferenceApiResponse<ViewPreference>>(
+        `/api/users/${userId}/view-preferences/${encodeURIComponent(viewKey)}`,
+        input
+      );
+
+      if (response.error) {
+        throw new Error(response.error);
+      }
+
+      return response.data.data;
+    },
+    onSuccess: (data) => {
+      queryClient.setQueryData(['view-preferences', userId, viewKey], data);
+      queryClient.invalidateQueries({ queryKey: ['view-preferences', userId], exact: true });
+    },
+  });
+}
+
+/**
+ * React Query mutation hook to reset a view to its default layout
+ */
+export function useResetViewPreference(userId: string, viewKey: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: async () => {
+      const response = await apiClient.delete<ViewPreferenceApiResponse<{ deleted: boolean }>>(
+        `/api/users/${userId}/view-preferences/${encodeURIComponent(viewKey)}`
+      );
+
+      if (response.error) {
+        throw new Error(response.error);
+      }
+
+      return response.data.data.deleted;
+    },
+    onSuccess: () => {
+      queryClient.setQueryData(['view-preferences', userId, viewKey], null);
+      queryClient.invalidateQueries({ queryKey: ['view-preferences', userId], exact: true });
+    },
+  });
+}
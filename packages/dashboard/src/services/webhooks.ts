@@ -0,0 +1,84 @@
+// ABOUTME: Inbound CI webhook API client
+// ABOUTME: Manages per-provider webhook secrets and lists the webhook delivery log
+
+import { apiRequest } from './api'
+
+export type WebhookProvider = 'github' | 'generic'
+
+export type DeliveryStatus = 'processed' | 'ignored' | 'rejected' | 'failed'
+
+export interface WebhookSecret {
+  id: string
+  provider: WebhookProvider
+  name: string
+  is_active: boolean
+  created_at: string
+  last_used_at: string | null
+}
+
+/** Returned once on creation; `secret` cannot be retrieved again */
+export interface WebhookSecretGeneration extends WebhookSecret {
+  secret: string
+}
+
+export interface WebhookDelivery {
+  id: string
+  provider: WebhookProvider
+  event_type: string | null
+  delivery_id: string | null
+  status: DeliveryStatus
+  message: string | null
+  payload: string | null
+  updated_tasks: number
+  updated_executions: number
+  received_at: string
+}
+
+export async function listWebhookSecrets(): Promise<WebhookSecret[]> {
+  const response = await apiRequest<WebhookSecret[]>('/api/webhooks/secrets')
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list webhook secrets')
+}
+
+export async function createWebhookSecret(
+  provider: WebhookProvider,
+  name: string
+): Promise<WebhookSecretGeneration> {
+  const response = await apiRequest<WebhookSecretGeneration>('/api/webhooks/secrets', {
+    method: 'POST',
+    body: JSON.stringify({ provider, name }),
+  })
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to create webhook secret')
+}
+
+export async function deleteWebhookSecret(secretId: string): Promise<void> {
+  const response = await apiRequest<string>(`/api/webhooks/secrets/${secretId}`, {
+    method: 'DELETE',
+  })
+  if (!response.success) {
+    throw new Error(response.error || 'Failed to delete webhook secret')
+  }
+}
+
+export async function listWebhookDeliveries(options: {
+  provider?: WebhookProvider
+  limit?: number
+} = {}): Promise<WebhookDelivery[]> {
+  const params = new URLSearchParams()
+  if (options.provider) params.set('provider', options.provider)
+  if (options.limit) params.set('limit', String(options.limit))
+  const query = params.toString()
+
+  const response = await apiRequest<WebhookDelivery[]>(
+    `/api/webhooks/deliveries${query ? `?${query}` : ''}`
+  )
+  if (response.success && response.data) {
+    return response.data
+  }
+  throw new Error(response.error || 'Failed to list webhook deliveries')
+}
@@ -29,6 +29,19 @@ export interface ModelConfig {
   model: string;
 }
 
+/**
+ * Fallback chain and per-call cost cap for a task type.
+ * The AI proxy applies these to requests sent with the x-orkee-task-type header.
+ */
+export interface TaskModelPolicy {
+  taskType: TaskType;
+  primary: ModelConfig;
+  fallbacks: ModelConfig[];
+  /** USD cap on the estimated cost of one call */
+  maxCostPerCall: number | null;
+  updatedAt: string | null;
+}
+
 /**
  * Complete model preferences for all task types
  */
@@ -8,6 +8,8 @@ export interface PaginationMetadata {
   totalPages: number;
   hasNextPage: boolean;
   hasPreviousPage: boolean;
+  /** Cursor for the next page; only set in cursor mode */
+  nextCursor?: string;
 }
 
 export interface PaginatedResponse<T> {
@@ -18,6 +20,8 @@ export interface PaginatedResponse<T> {
 export interface PaginationParams {
   page?: number;
   limit?: number;
+  /** Opaque cursor from `nextCursor`; pass '' for the first page in cursor mode */
+  cursor?: string;
 }
 
 /**
@@ -31,6 +35,9 @@ export function buildPaginationQuery(params: PaginationParams): string {
   if (params.limit !== undefined) {
     query.push(`limit=${params.limit}`);
   }
+  if (params.cursor !== undefined) {
+    query.push(`cursor=${encodeURIComponent(params.cursor)}`);
+  }
   return query.length > 0 ? `?${query.join('&')}` : '';
 }
 
@@ -0,0 +1,395 @@
+// ABOUTME: Side-by-side comparison of two agent executions
+// ABOUTME: Diffs produced files, token usage, cost, duration, and review findings between attempts
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::types::{AgentExecution, ExecutionStatus, PrReview, ReviewStatus};
+
+/// The identifying details of one side of a comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionSide {
+    pub id: String,
+    pub task_id: String,
+    pub status: ExecutionStatus,
+    pub agent_id: Option<String>,
+    pub model: Option<String>,
+    pub retry_attempt: i32,
+    pub started_at: DateTime<Utc>,
+}
+
+impl From<&AgentExecution> for ExecutionSide {
+    fn from(execution: &AgentExecution) -> Self {
+        Self {
+            id: execution.id.clone(),
+            task_id: execution.task_id.clone(),
+            status: execution.status.clone(),
+            agent_id: execution.agent_id.clone(),
+            model: execution.model.clone(),
+            retry_attempt: execution.retry_attempt,
+            started_at: execution.started_at,
+        }
+    }
+}
+
+/// A metric recorded on both executions; `delta` is `b - a` when both are known
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta<T> {
+    pub a: Option<T>,
+    pub b: Option<T>,
+    pub delta: Option<T>,
+}
+
+impl<T: Copy + std::ops::Sub<Output = T>> MetricDelta<T> {
+    fn new(a: Option<T>, b: Option<T>) -> Self {
+        let delta = a.zip(b).map(|(a, b)| b - a);
+        Self { a, b, delta }
+    }
+}
+
+/// How an execution touched a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileChangeKind {
+    Created,
+    Modified,
+    Deleted,
+}
+
+/// One file touched by either execution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileComparison {
+    pub path: String,
+    pub a: Option<FileChangeKind>,
+    pub b: Option<FileChangeKind>,
+}
+
+/// Files produced by each execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactComparison {
+    /// Every touched file, sorted by path
+    pub files: Vec<FileComparison>,
+    pub files_changed: MetricDelta<i32>,
+    pub lines_added: MetricDelta<i32>,
+    pub lines_removed: MetricDelta<i32>,
+}
+
+/// Token usage and cost of each execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageComparison {
+    pub tokens_input: MetricDelta<i32>,
+    pub tokens_output: MetricDelta<i32>,
+    pub total_cost: MetricDelta<f64>,
+}
+
+/// Review outcome of one execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSummary {
+    pub review_status: Option<ReviewStatus>,
+    pub reviews: usize,
+    pub approvals: usize,
+    pub changes_requested: usize,
+}
+
+/// Review findings raised on each execution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewComparison {
+    pub a: ReviewSummary,
+    pub b: ReviewSummary,
+    /// Findings raised only on `a`, e.g. fixed by the later attempt
+    pub only_in_a: Vec<String>,
+    /// Findings raised only on `b`, e.g. introduced by the later attempt
+    pub only_in_b: Vec<String>,
+    pub in_both: Vec<String>,
+}
+
+/// Structured diff between two executions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionComparison {
+    pub a: ExecutionSide,
+    pub b: ExecutionSide,
+    pub same_task: bool,
+    pub artifacts: ArtifactComparison,
+    pub usage: UsageComparison,
+    pub duration_seconds: MetricDelta<i64>,
+    pub reviews: ReviewComparison,
+}
+
+/// Compare two executions and the reviews recorded on each
+pub fn compare_executions(
+    a: &AgentExecution,
+    b: &AgentExecution,
+    a_reviews: &[PrReview],
+    b_reviews: &[PrReview],
+) -> ExecutionComparison {
+    ExecutionComparison {
+        a: ExecutionSide::from(a),
+        b: ExecutionSide::from(b),
+        same_task: a.task_id == b.task_id,
+        artifacts: ArtifactComparison {
+            files: compare_files(a, b),
+            files_changed: MetricDelta::new(a.files_changed, b.files_changed),
+            lines_added: MetricDelta::new(a.lines_added, b.lines_added),
+            lines_removed: MetricDelta::new(a.lines_removed, b.lines_removed),
+        },
+        usage: UsageComparison {
+            tokens_input: MetricDelta::new(a.tokens_input, b.tokens_input),
+            tokens_output: MetricDelta::new(a.tokens_output, b.tokens_output),
+            total_cost: MetricDelta::new(a.total_cost, b.total_cost),
+        },
+        duration_seconds: MetricDelta::new(duration_seconds(a), duration_seconds(b)),
+        reviews: compare_reviews(a, b, a_reviews, b_reviews),
+    }
+}
+
+/// Recorded execution time, falling back to the span between start and completion
+fn duration_seconds(execution: &AgentExecution) -> Option<i64> {
+    execution.execution_time_seconds.map(i64::from).or_else(|| {
+        execution
+            .completed_at
+            .map(|completed| (completed - execution.started_at).num_seconds())
+    })
+}
+
+fn file_changes(execution: &AgentExecution) -> Vec<(&str, FileChangeKind)> {
+    let kinds = [
+        (&execution.files_created, FileChangeKind::Created),
+        (&execution.files_modified, FileChangeKind::Modified),
+        (&execution.files_deleted, FileChangeKind::Deleted),
+    ];
+    kinds
+        .into_iter()
+        .flat_map(|(files, kind)| {
+            files
+                .iter()
+                .flatten()
+                .map(move |path| (path.as_str(), kind))
+        })
+        .collect()
+}
+
+fn compare_files(a: &AgentExecution, b: &AgentExecution) -> Vec<FileComparison> {
+    let mut files: std::collections::BTreeMap<&str, FileComparison> = Default::default();
+    for (path, kind) in file_changes(a) {
+        files
+            .entry(path)
+            .or_insert_with(|| FileComparison {
+                path: path.to_string(),
+                a: None,
+                b: None,
+            })
+            .a = Some(kind);
+    }
+    for (path, kind) in file_changes(b) {
+        files
+            .entry(path)
+            .or_insert_with(|| FileComparison {
+                path: path.to_string(),
+                a: None,
+                b: None,
+            })
+            .b = Some(kind);
+    }
+    files.into_values().collect()
+}
+
+fn summarize_reviews(execution: &AgentExecution, reviews: &[PrReview]) -> ReviewSummary {
+    let count = |status: fn(&ReviewStatus) -> bool| {
+        reviews
+            .iter()
+            .filter(|review| status(&review.review_status))
+            .count()
+    };
+    ReviewSummary {
+        review_status: execution.review_status.clone(),
+        reviews: reviews.len(),
+        approvals: count(|s| matches!(s, ReviewStatus::Approved)),
+        changes_requested: count(|s| matches!(s, ReviewStatus::ChangesRequested)),
+    }
+}
+
+/// Review comments as plain text. Comments may be strings or objects with a `body`
+/// (or `message`) and an optional `path`.
+fn review_findings(reviews: &[PrReview]) -> Vec<String> {
+    let mut findings: Vec<String> = reviews
+        .iter()
+        .filter_map(|review| review.comments.as_ref()?.as_array())
+        .flatten()
+        .filter_map(|comment| match comment {
+            serde_json::Value::String(text) => Some(text.trim().to_string()),
+            serde_json::Value::Object(fields) => {
+                let body = fields
+                    .get("body")
+                    .or_else(|| fields.get("message"))?
+                    .as_str()?
+                    .trim();
+                Some(match fields.get("path").and_then(|path| path.as_str()) {
+                    Some(path) => format!("{}: {}", path, body),
+                    None => body.to_string(),
+                })
+            }
+            _ => None,
+        })
+        .filter(|finding| !finding.is_empty())
+        .collect();
+    findings.sort();
+    findings.dedup();
+    findings
+}
+
+fn compare_reviews(
+    a: &AgentExecution,
+    b: &AgentExecution,
+    a_reviews: &[PrReview],
+    b_reviews: &[PrReview],
+) -> ReviewComparison {
+    let a_findings = review_findings(a_reviews);
+    let b_findings = review_findings(b_reviews);
+
+    let (in_both, only_in_a): (Vec<String>, Vec<String>) = a_findings
+        .into_iter()
+        .partition(|finding| b_findings.binary_search(finding).is_ok());
+    let only_in_b = b_findings
+        .into_iter()
+        .filter(|finding| !in_both.contains(finding))
+        .collect();
+
+    ReviewComparison {
+        a: summarize_reviews(a, a_reviews),
+        b: summarize_reviews(b, b_reviews),
+        only_in_a,
+        only_in_b,
+        in_both,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ReviewerType;
+    use serde_json::json;
+
+    fn execution(id: &str) -> AgentExecution {
+        serde_json::from_value(json!({
+            "id": id,
+            "task_id": "task1234",
+            "agent_id": null,
+            "model": "claude-sonnet",
+            "started_at": "2026-01-01T10:00:00Z",
+            "completed_at": null,
+            "status": "Completed",
+            "execution_time_seconds": null,
+            "tokens_input": null,
+            "tokens_output": null,
+            "total_cost": null,
+            "prompt": null,
+            "response": null,
+            "error_message": null,
+            "retry_attempt": 0,
+            "files_changed": null,
+            "lines_added": null,
+            "lines_removed": null,
+            "files_created": null,
+            "files_modified": null,
+            "files_deleted": null,
+            "branch_name": null,
+            "commit_hash": null,
+            "commit_message": null,
+            "pr_number": null,
+            "pr_url": null,
+            "pr_title": null,
+            "pr_status": null,
+            "pr_created_at": null,
+            "pr_merged_at": null,
+            "pr_merge_commit": null,
+            "review_status": null,
+            "review_comments": null,
+            "test_results": null,
+            "performance_metrics": null,
+            "metadata": null,
+            "created_at": "2026-01-01T10:00:00Z",
+            "updated_at": "2026-01-01T10:00:00Z"
+        }))
+        .unwrap()
+    }
+
+    fn review(execution_id: &str, status: ReviewStatus, comments: serde_json::Value) -> PrReview {
+        PrReview {
+            id: format!("review-{}", execution_id),
+            execution_id: execution_id.to_string(),
+            reviewer_id: None,
+            reviewer_type: ReviewerType::Ai,
+            review_status: status,
+            review_body: None,
+            comments: Some(comments),
+            suggested_changes: None,
+            approval_date: None,
+            dismissal_reason: None,
+            reviewed_at: Utc::now(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_compare_executions() {
+        let mut a = execution("exec-a");
+        a.tokens_input = Some(1000);
+        a.total_cost = Some(0.5);
+        a.execution_time_seconds = Some(300);
+        a.files_created = Some(vec!["src/new.rs".to_string()]);
+        a.files_modified = Some(vec!["src/lib.rs".to_string()]);
+
+        let mut b = execution("exec-b");
+        b.retry_attempt = 1;
+        b.tokens_input = Some(800);
+        b.total_cost = Some(0.25);
+        b.completed_at = Some("2026-01-01T10:02:00Z".parse().unwrap());
+        b.files_modified = Some(vec!["src/lib.rs".to_string(), "src/new.rs".to_string()]);
+
+        let a_reviews = [review(
+            "exec-a",
+            ReviewStatus::ChangesRequested,
+            json!([{"path": "src/lib.rs", "body": "Missing error handling"}, "Add tests"]),
+        )];
+        let b_reviews = [review(
+            "exec-b",
+            ReviewStatus::Approved,
+            json!(["Add tests"]),
+        )];
+
+        let comparison = compare_executions(&a, &b, &a_reviews, &b_reviews);
+
+        assert!(comparison.same_task);
+        assert_eq!(comparison.usage.tokens_input.delta, Some(-200));
+        assert_eq!(comparison.usage.tokens_output.delta, None);
+        assert_eq!(comparison.usage.total_cost.delta, Some(-0.25));
+        assert_eq!(comparison.duration_seconds.b, Some(120));
+        assert_eq!(comparison.duration_seconds.delta, Some(-180));
+
+        assert_eq!(
+            comparison.artifacts.files,
+            vec![
+                FileComparison {
+                    path: "src/lib.rs".to_string(),
+                    a: Some(FileChangeKind::Modified),
+                    b: Some(FileChangeKind::Modified),
+                },
+                FileComparison {
+                    path: "src/new.rs".to_string(),
+                    a: Some(FileChangeKind::Created),
+                    b: Some(FileChangeKind::Modified),
+                },
+            ]
+        );
+
+        assert_eq!(
+            comparison.reviews.only_in_a,
+            ["src/lib.rs: Missing error handling"]
+        );
+        assert!(comparison.reviews.only_in_b.is_empty());
+        assert_eq!(comparison.reviews.in_both, ["Add tests"]);
+        assert_eq!(comparison.reviews.a.changes_requested, 1);
+        assert_eq!(comparison.reviews.b.approvals, 1);
+    }
+}
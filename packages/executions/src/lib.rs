@@ -1,9 +1,14 @@
 // ABOUTME: Agent execution and PR review tracking
 // ABOUTME: Runtime observability for AI agent work and code reviews
 
+pub mod compare;
 pub mod storage;
 pub mod types;
 
+pub use compare::{
+    compare_executions, ArtifactComparison, ExecutionComparison, ExecutionSide, FileChangeKind,
+    FileComparison, MetricDelta, ReviewComparison, ReviewSummary, UsageComparison,
+};
 pub use storage::ExecutionStorage;
 pub use types::{
     AgentExecution, AgentExecutionCreateInput, AgentExecutionUpdateInput, ExecutionStatus,
@@ -1,16 +1,18 @@
 // ABOUTME: Agent execution and PR review storage layer using SQLite
 // ABOUTME: Handles CRUD operations for execution tracking and code reviews
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
 use tracing::debug;
 
+use super::compare::{compare_executions, ExecutionComparison};
 use super::types::{
     AgentExecution, AgentExecutionCreateInput, AgentExecutionUpdateInput, ExecutionStatus,
     PrReview, PrReviewCreateInput, PrReviewUpdateInput,
 };
 use orkee_models::REGISTRY;
-use orkee_storage::StorageError;
+use orkee_storage::{KeysetOrder, KeysetPage, KeysetPosition, StorageError};
 
 pub struct ExecutionStorage {
     pool: SqlitePool,
@@ -78,6 +80,69 @@ impl ExecutionStorage {
         Ok((executions, count))
     }
 
+    /// List executions for a task, newest first, starting after `after`
+    ///
+    /// Keyset pagination on (created_at, id), for clients paging through long
+    /// execution histories.
+    pub async fn list_executions_after(
+        &self,
+        task_id: &str,
+        after: Option<&KeysetPosition>,
+        limit: i64,
+    ) -> Result<KeysetPage<AgentExecution>, StorageError> {
+        debug!(
+            "Fetching executions for task: {} (after: {:?}, limit: {})",
+            task_id, after, limit
+        );
+
+        let count: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM agent_executions WHERE task_id = ?")
+                .bind(task_id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(StorageError::Sqlx)?;
+
+        let order = KeysetOrder::Descending;
+        let mut query_str = String::from("SELECT * FROM agent_executions WHERE task_id = ?");
+        if after.is_some() {
+            query_str.push_str(&format!(" AND {}", order.after_clause("")));
+        }
+        query_str.push_str(&format!(" ORDER BY {} LIMIT ?", order.order_by("")));
+
+        let mut query = sqlx::query(&query_str).bind(task_id);
+        if let Some(position) = after {
+            query = query.bind(&position.created_at).bind(&position.id);
+        }
+        let rows = query
+            .bind(limit + 1)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let executions = rows
+            .iter()
+            .map(|row| Ok((self.row_to_execution(row)?, KeysetPosition::from_row(row)?)))
+            .collect::<Result<Vec<_>, StorageError>>()?;
+
+        Ok(KeysetPage::from_rows(executions, limit, count))
+    }
+
+    /// List the most recent executions across all tasks, newest first
+    pub async fn list_recent_executions(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<AgentExecution>, StorageError> {
+        debug!("Fetching {} most recent executions", limit);
+
+        let rows = sqlx::query("SELECT * FROM agent_executions ORDER BY started_at DESC LIMIT ?")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(|row| self.row_to_execution(row)).collect()
+    }
+
     /// Get a single execution by ID
     pub async fn get_execution(&self, execution_id: &str) -> Result<AgentExecution, StorageError> {
         debug!("Fetching execution: {}", execution_id);
@@ -128,12 +193,23 @@ impl ExecutionStorage {
             }
         }
 
+        if let Some(class) = &input.concurrency_class {
+            if class.trim().is_empty() {
+                return Err(StorageError::Validation(
+                    "Concurrency class cannot be blank".to_string(),
+                ));
+            }
+        }
+
+        let status = initial_status(&input, now);
+
         sqlx::query(
             r#"
             INSERT INTO agent_executions (
                 id, task_id, agent_id, model, started_at, status,
-                prompt, retry_attempt, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                prompt, retry_attempt, not_before, concurrency_class,
+                created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(&execution_id)
@@ -141,18 +217,130 @@ impl ExecutionStorage {
         .bind(&input.agent_id)
         .bind(&input.model)
         .bind(now)
-        .bind(ExecutionStatus::Running)
+        .bind(&status)
         .bind(&input.prompt)
         .bind(input.retry_attempt.unwrap_or(0))
+        .bind(input.not_before)
+        .bind(&input.concurrency_class)
         .bind(now)
         .bind(now)
         .execute(&self.pool)
         .await
         .map_err(StorageError::Sqlx)?;
 
+        // A class-only execution may start straight away if its class is idle
+        if matches!(status, ExecutionStatus::Queued) && input.not_before.is_none_or(|t| t <= now) {
+            self.start_due_executions().await?;
+        }
+
         self.get_execution(&execution_id).await
     }
 
+    /// List executions waiting to start, soonest first
+    pub async fn list_queued_executions(
+        &self,
+        concurrency_class: Option<&str>,
+    ) -> Result<Vec<AgentExecution>, StorageError> {
+        debug!(
+            "Fetching queued executions (class: {:?})",
+            concurrency_class
+        );
+
+        let mut query_str = String::from("SELECT * FROM agent_executions WHERE status = ?");
+        if concurrency_class.is_some() {
+            query_str.push_str(" AND concurrency_class = ?");
+        }
+        query_str.push_str(" ORDER BY COALESCE(not_before, created_at), created_at");
+
+        let mut query = sqlx::query(&query_str).bind(ExecutionStatus::Queued);
+        if let Some(class) = concurrency_class {
+            query = query.bind(class);
+        }
+        let rows = query
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(|row| self.row_to_execution(row)).collect()
+    }
+
+    /// Start queued executions whose not-before time has passed
+    ///
+    /// Executions sharing a concurrency class start one at a time: a class
+    /// with a running execution keeps the rest of its queue waiting. Returns
+    /// the executions that were started.
+    pub async fn start_due_executions(&self) -> Result<Vec<AgentExecution>, StorageError> {
+        let now = Utc::now();
+
+        let due = sqlx::query(
+            r#"
+            SELECT id, concurrency_class FROM agent_executions
+            WHERE status = ? AND (not_before IS NULL OR not_before <= ?)
+            ORDER BY COALESCE(not_before, created_at), created_at
+            "#,
+        )
+        .bind(ExecutionStatus::Queued)
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut busy: HashSet<String> = sqlx::query_scalar(
+            "SELECT DISTINCT concurrency_class FROM agent_executions
+             WHERE status = ? AND concurrency_class IS NOT NULL",
+        )
+        .bind(ExecutionStatus::Running)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?
+        .into_iter()
+        .collect();
+
+        let mut started = Vec::new();
+        for row in &due {
+            let id: String = row.try_get("id").map_err(StorageError::Sqlx)?;
+            let class: Option<String> = row
+                .try_get("concurrency_class")
+                .map_err(StorageError::Sqlx)?;
+            if let Some(class) = &class {
+                if busy.contains(class) {
+                    continue;
+                }
+            }
+
+            // Guard on the status so a run cancelled meanwhile stays cancelled
+            let result = sqlx::query(
+                r#"
+                UPDATE agent_executions
+                SET status = ?, started_at = ?, updated_at = ?
+                WHERE id = ? AND status = ?
+                "#,
+            )
+            .bind(ExecutionStatus::Running)
+            .bind(now)
+            .bind(now)
+            .bind(&id)
+            .bind(ExecutionStatus::Queued)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+            if result.rows_affected() > 0 {
+                debug!("Started queued execution: {}", id);
+                if let Some(class) = class {
+                    busy.insert(class);
+                }
+                started.push(self.get_execution(&id).await?);
+            }
+        }
+
+        Ok(started)
+    }
+
     /// Update an execution
     pub async fn update_execution(
         &self,
@@ -161,6 +349,10 @@ impl ExecutionStorage {
     ) -> Result<AgentExecution, StorageError> {
         debug!("Updating execution: {}", execution_id);
 
+        let finished = matches!(
+            input.status,
+            Some(ExecutionStatus::Completed | ExecutionStatus::Failed | ExecutionStatus::Cancelled)
+        );
         let now = Utc::now();
         let mut updates = vec!["updated_at = ?"];
         let mut query_str = String::from("UPDATE agent_executions SET ");
@@ -355,9 +547,73 @@ impl ExecutionStorage {
             .await
             .map_err(StorageError::Sqlx)?;
 
+        // A finished execution frees its concurrency class for the next in line
+        if finished {
+            self.start_due_executions().await?;
+        }
+
         self.get_execution(execution_id).await
     }
 
+    /// Mark a running or queued execution as cancelled
+    ///
+    /// Returns false if the execution had already finished.
+    pub async fn cancel_execution(&self, execution_id: &str) -> Result<bool, StorageError> {
+        debug!("Cancelling execution: {}", execution_id);
+
+        let now = Utc::now();
+        let result = sqlx::query(
+            r#"
+            UPDATE agent_executions
+            SET status = ?, completed_at = ?, updated_at = ?
+            WHERE id = ? AND status IN (?, ?)
+            "#,
+        )
+        .bind(ExecutionStatus::Cancelled)
+        .bind(now)
+        .bind(now)
+        .bind(execution_id)
+        .bind(ExecutionStatus::Running)
+        .bind(ExecutionStatus::Queued)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let cancelled = result.rows_affected() > 0;
+        if cancelled {
+            self.start_due_executions().await?;
+        }
+        Ok(cancelled)
+    }
+
+    /// Start a new attempt of a finished execution with the same task, agent, model, and prompt
+    pub async fn retry_execution(
+        &self,
+        execution_id: &str,
+    ) -> Result<AgentExecution, StorageError> {
+        let previous = self.get_execution(execution_id).await?;
+        if matches!(
+            previous.status,
+            ExecutionStatus::Running | ExecutionStatus::Queued
+        ) {
+            return Err(StorageError::Validation(format!(
+                "Execution {} has not finished",
+                execution_id
+            )));
+        }
+
+        self.create_execution(AgentExecutionCreateInput {
+            task_id: previous.task_id,
+            agent_id: previous.agent_id,
+            model: previous.model,
+            prompt: previous.prompt,
+            retry_attempt: Some(previous.retry_attempt + 1),
+            not_before: None,
+            concurrency_class: previous.concurrency_class,
+        })
+        .await
+    }
+
     /// Delete an execution
     pub async fn delete_execution(&self, execution_id: &str) -> Result<(), StorageError> {
         debug!("Deleting execution: {}", execution_id);
@@ -371,6 +627,22 @@ impl ExecutionStorage {
         Ok(())
     }
 
+    /// Compare two executions, e.g. attempts at the same task
+    pub async fn compare_executions(
+        &self,
+        a_id: &str,
+        b_id: &str,
+    ) -> Result<ExecutionComparison, StorageError> {
+        debug!("Comparing executions {} and {}", a_id, b_id);
+
+        let a = self.get_execution(a_id).await?;
+        let b = self.get_execution(b_id).await?;
+        let a_reviews = self.list_reviews(a_id).await?;
+        let b_reviews = self.list_reviews(b_id).await?;
+
+        Ok(compare_executions(&a, &b, &a_reviews, &b_reviews))
+    }
+
     // ==================== PR Reviews ====================
 
     /// List all reviews for an execution
@@ -584,6 +856,10 @@ impl ExecutionStorage {
             review_comments: row.try_get("review_comments").map_err(StorageError::Sqlx)?,
             test_results: test_results.and_then(|s| serde_json::from_str(&s).ok()),
             performance_metrics: performance_metrics.and_then(|s| serde_json::from_str(&s).ok()),
+            not_before: row.try_get("not_before").map_err(StorageError::Sqlx)?,
+            concurrency_class: row
+                .try_get("concurrency_class")
+                .map_err(StorageError::Sqlx)?,
             metadata: metadata.and_then(|s| serde_json::from_str(&s).ok()),
             created_at: row.try_get("created_at").map_err(StorageError::Sqlx)?,
             updated_at: row.try_get("updated_at").map_err(StorageError::Sqlx)?,
@@ -615,3 +891,105 @@ impl ExecutionStorage {
         })
     }
 }
+
+/// Executions with a future start time or a concurrency class go through the
+/// queue; everything else starts immediately
+fn initial_status(input: &AgentExecutionCreateInput, now: DateTime<Utc>) -> ExecutionStatus {
+    let deferred = input.not_before.is_some_and(|t| t > now);
+    if deferred || input.concurrency_class.is_some() {
+        ExecutionStatus::Queued
+    } else {
+        ExecutionStatus::Running
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    async fn setup() -> ExecutionStorage {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+             VALUES ('project-1', 'Test Project', '/test/path', datetime('now'), datetime('now'))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO tasks (id, project_id, title, created_at, updated_at)
+             VALUES ('task-0001', 'project-1', 'Queue work', datetime('now'), datetime('now'))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        ExecutionStorage::new(pool)
+    }
+
+    fn input(
+        not_before: Option<DateTime<Utc>>,
+        concurrency_class: Option<&str>,
+    ) -> AgentExecutionCreateInput {
+        AgentExecutionCreateInput {
+            task_id: "task-0001".to_string(),
+            agent_id: None,
+            model: None,
+            prompt: None,
+            retry_attempt: None,
+            not_before,
+            concurrency_class: concurrency_class.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_queued_executions_start_one_per_class() {
+        let storage = setup().await;
+
+        let first = storage
+            .create_execution(input(None, Some("gpu")))
+            .await
+            .unwrap();
+        let second = storage
+            .create_execution(input(None, Some("gpu")))
+            .await
+            .unwrap();
+        let later = storage
+            .create_execution(input(Some(Utc::now() + Duration::hours(6)), None))
+            .await
+            .unwrap();
+        assert!(matches!(first.status, ExecutionStatus::Running));
+        assert!(matches!(second.status, ExecutionStatus::Queued));
+        assert!(matches!(later.status, ExecutionStatus::Queued));
+        assert_eq!(storage.list_queued_executions(None).await.unwrap().len(), 2);
+        assert_eq!(
+            storage.list_queued_executions(Some("gpu")).await.unwrap()[0].id,
+            second.id
+        );
+
+        storage
+            .update_execution(
+                &first.id,
+                AgentExecutionUpdateInput {
+                    status: Some(ExecutionStatus::Completed),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let second = storage.get_execution(&second.id).await.unwrap();
+        assert!(matches!(second.status, ExecutionStatus::Running));
+
+        assert!(storage.cancel_execution(&later.id).await.unwrap());
+        assert!(storage
+            .list_queued_executions(None)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(storage.start_due_executions().await.unwrap().is_empty());
+    }
+}
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "TEXT", rename_all = "lowercase")]
 pub enum ExecutionStatus {
+    /// Waiting for its not-before time or a free slot in its concurrency class
+    Queued,
     Running,
     Completed,
     Failed,
@@ -88,6 +90,10 @@ pub struct AgentExecution {
     pub test_results: Option<serde_json::Value>,
     pub performance_metrics: Option<serde_json::Value>,
 
+    // Scheduling
+    pub not_before: Option<DateTime<Utc>>,
+    pub concurrency_class: Option<String>,
+
     pub metadata: Option<serde_json::Value>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -100,9 +106,13 @@ pub struct AgentExecutionCreateInput {
     pub model: Option<String>,
     pub prompt: Option<String>,
     pub retry_attempt: Option<i32>,
+    /// Queue the execution until this time instead of starting it now
+    pub not_before: Option<DateTime<Utc>>,
+    /// Executions sharing a class run one at a time
+    pub concurrency_class: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AgentExecutionUpdateInput {
     pub status: Option<ExecutionStatus>,
     pub completed_at: Option<DateTime<Utc>>,
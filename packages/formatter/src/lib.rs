@@ -1,6 +1,13 @@
 // ABOUTME: Output formatting utilities for Orkee projects.
 // ABOUTME: Provides table and detail views for displaying project information in CLI/TUI.
 
+mod output;
+
+pub use output::{
+    format_project, format_projects, parse_columns, FormatError, FormatResult, OutputFormat,
+    DEFAULT_PROJECT_COLUMNS, PROJECT_COLUMNS,
+};
+
 use orkee_core::truncate;
 use orkee_core::types::Project;
 
@@ -187,6 +194,8 @@ mod tests {
             manual_tasks: None,
             mcp_servers: None,
             git_repository: None,
+            owner_user_id: None,
+            version: 1,
         }
     }
 
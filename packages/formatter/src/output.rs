@@ -0,0 +1,524 @@
+// ABOUTME: Machine-readable output formats (JSON, YAML, CSV, TSV) for project listings
+// ABOUTME: Supports selecting which project fields are emitted so scripts get exactly what they need
+
+use std::fmt;
+use std::str::FromStr;
+
+use orkee_core::types::Project;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::format_projects_table;
+
+#[derive(Error, Debug)]
+pub enum FormatError {
+    #[error("Unknown output format '{0}'. Expected one of: table, json, yaml, csv, tsv")]
+    UnknownFormat(String),
+
+    #[error("Unknown column '{0}'. Available columns: {columns}", columns = PROJECT_COLUMNS.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", "))]
+    UnknownColumn(String),
+
+    #[error("No columns selected")]
+    NoColumns,
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+pub type FormatResult<T> = Result<T, FormatError>;
+
+/// Output format for project listings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+    Csv,
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "csv" => Ok(Self::Csv),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(FormatError::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Table => "table",
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Selectable project columns as (column name, serialized field name)
+pub const PROJECT_COLUMNS: &[(&str, &str)] = &[
+    ("id", "id"),
+    ("name", "name"),
+    ("project_root", "projectRoot"),
+    ("status", "status"),
+    ("priority", "priority"),
+    ("rank", "rank"),
+    ("description", "description"),
+    ("tags", "tags"),
+    ("setup_script", "setupScript"),
+    ("dev_script", "devScript"),
+    ("cleanup_script", "cleanupScript"),
+    ("created_at", "createdAt"),
+    ("updated_at", "updatedAt"),
+];
+
+/// Columns used for CSV/TSV output when none are selected
+pub const DEFAULT_PROJECT_COLUMNS: &[&str] = &["id", "name", "project_root", "status"];
+
+/// Parse a comma-separated column list such as `id,name,project_root`.
+///
+/// Both the column names and the serialized field names (`projectRoot`) are accepted.
+pub fn parse_columns(spec: &str) -> FormatResult<Vec<String>> {
+    let columns = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|column| !column.is_empty())
+        .map(|column| {
+            PROJECT_COLUMNS
+                .iter()
+                .find(|(name, field)| {
+                    name.eq_ignore_ascii_case(column) || field.eq_ignore_ascii_case(column)
+                })
+                .map(|(name, _)| name.to_string())
+                .ok_or_else(|| FormatError::UnknownColumn(column.to_string()))
+        })
+        .collect::<FormatResult<Vec<_>>>()?;
+
+    if columns.is_empty() {
+        return Err(FormatError::NoColumns);
+    }
+    Ok(columns)
+}
+
+/// Format projects in the given output format.
+///
+/// Without a column selection, JSON and YAML include every project field and
+/// CSV/TSV use [`DEFAULT_PROJECT_COLUMNS`]. JSON and YAML keep the API's field
+/// names so the output matches what the HTTP API returns.
+pub fn format_projects(
+    projects: &[Project],
+    format: OutputFormat,
+    columns: Option<&[String]>,
+) -> FormatResult<String> {
+    let values = projects
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match (format, columns) {
+        (OutputFormat::Table, None) => Ok(format_projects_table(projects, None)),
+        (OutputFormat::Table, Some(columns)) => {
+            Ok(columns_table(&values, &resolve_columns(columns)?))
+        }
+        (OutputFormat::Json, None) => Ok(serde_json::to_string_pretty(&values)?),
+        (OutputFormat::Yaml, None) => Ok(to_yaml(&Value::Array(values))),
+        (OutputFormat::Json | OutputFormat::Yaml, Some(columns)) => {
+            let columns = resolve_columns(columns)?;
+            let rows = values
+                .iter()
+                .map(|value| select_fields(value, &columns))
+                .collect::<Vec<_>>();
+            if format == OutputFormat::Json {
+                Ok(serde_json::to_string_pretty(&rows)?)
+            } else {
+                Ok(to_yaml(&Value::Array(rows)))
+            }
+        }
+        (OutputFormat::Csv | OutputFormat::Tsv, columns) => {
+            let columns = match columns {
+                Some(columns) => resolve_columns(columns)?,
+                None => resolve_columns(DEFAULT_PROJECT_COLUMNS)?,
+            };
+            let (separator, escape): (&str, fn(&str) -> String) = if format == OutputFormat::Csv {
+                (",", csv_escape)
+            } else {
+                ("\t", tsv_escape)
+            };
+            Ok(delimited(&values, &columns, separator, escape))
+        }
+    }
+}
+
+/// Format a single project in the given output format.
+///
+/// JSON and YAML emit an object rather than a one-element list.
+pub fn format_project(
+    project: &Project,
+    format: OutputFormat,
+    columns: Option<&[String]>,
+) -> FormatResult<String> {
+    match (format, columns) {
+        (OutputFormat::Table, None) => Ok(crate::format_project_details(project)),
+        (OutputFormat::Json | OutputFormat::Yaml, _) => {
+            let mut value = serde_json::to_value(project)?;
+            if let Some(columns) = columns {
+                value = select_fields(&value, &resolve_columns(columns)?);
+            }
+            if format == OutputFormat::Json {
+                Ok(serde_json::to_string_pretty(&value)?)
+            } else {
+                Ok(to_yaml(&value))
+            }
+        }
+        _ => format_projects(std::slice::from_ref(project), format, columns),
+    }
+}
+
+/// Look up the (column name, field name) pairs for the selected columns
+fn resolve_columns<S: AsRef<str>>(
+    columns: &[S],
+) -> FormatResult<Vec<(&'static str, &'static str)>> {
+    if columns.is_empty() {
+        return Err(FormatError::NoColumns);
+    }
+    columns
+        .iter()
+        .map(|column| {
+            let column = column.as_ref();
+            PROJECT_COLUMNS
+                .iter()
+                .find(|(name, field)| *name == column || *field == column)
+                .copied()
+                .ok_or_else(|| FormatError::UnknownColumn(column.to_string()))
+        })
+        .collect()
+}
+
+fn select_fields(value: &Value, columns: &[(&str, &str)]) -> Value {
+    let fields = columns
+        .iter()
+        .map(|(_, field)| (field.to_string(), value[field].clone()))
+        .collect::<Map<_, _>>();
+    Value::Object(fields)
+}
+
+/// Plain-text rendering of a field for tables and delimited output
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items.iter().map(cell_text).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+fn columns_table(values: &[Value], columns: &[(&str, &str)]) -> String {
+    if values.is_empty() {
+        return "No projects found".to_string();
+    }
+
+    let rows: Vec<Vec<String>> = values
+        .iter()
+        .map(|value| {
+            columns
+                .iter()
+                .map(|(_, field)| cell_text(&value[field]))
+                .collect()
+        })
+        .collect();
+    let widths: Vec<usize> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| {
+            rows.iter()
+                .map(|row| row[i].chars().count())
+                .chain(std::iter::once(name.len()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let render = |cells: Vec<String>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let header = render(
+        columns
+            .iter()
+            .map(|(name, _)| name.to_uppercase())
+            .collect(),
+    );
+    let separator = "-".repeat(header.chars().count());
+    let mut lines = vec![header, separator];
+    lines.extend(rows.into_iter().map(render));
+    lines.join("\n")
+}
+
+fn delimited(
+    values: &[Value],
+    columns: &[(&str, &str)],
+    separator: &str,
+    escape: fn(&str) -> String,
+) -> String {
+    let header = columns
+        .iter()
+        .map(|(name, _)| escape(name))
+        .collect::<Vec<_>>()
+        .join(separator);
+    let rows = values.iter().map(|value| {
+        columns
+            .iter()
+            .map(|(_, field)| escape(&cell_text(&value[field])))
+            .collect::<Vec<_>>()
+            .join(separator)
+    });
+
+    std::iter::once(header)
+        .chain(rows)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break (RFC 4180)
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// TSV has no quoting, so tabs and line breaks are written as escape sequences
+fn tsv_escape(field: &str) -> String {
+    field
+        .replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+/// Render a JSON value as block-style YAML.
+///
+/// Strings are written as double-quoted scalars, which YAML reads with the
+/// same escapes as JSON, so values like `yes` or `1.0` keep their string type.
+fn to_yaml(value: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(value, 0, &mut out);
+    out
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Array(items) if !items.is_empty() => {
+            for item in items {
+                match yaml_inline(item) {
+                    Some(scalar) => out.push_str(&format!("{}- {}\n", pad, scalar)),
+                    None => {
+                        // Nested block starts on the dash line: "- key: value"
+                        let mut nested = String::new();
+                        write_yaml(item, indent + 2, &mut nested);
+                        out.push_str(&format!("{}- {}", pad, &nested[indent + 2..]));
+                    }
+                }
+            }
+        }
+        Value::Object(map) if !map.is_empty() => {
+            for (key, item) in map {
+                let key = yaml_key(key);
+                match yaml_inline(item) {
+                    Some(scalar) => out.push_str(&format!("{}{}: {}\n", pad, key, scalar)),
+                    None => {
+                        out.push_str(&format!("{}{}:\n", pad, key));
+                        write_yaml(item, indent + 2, out);
+                    }
+                }
+            }
+        }
+        scalar => {
+            let scalar = yaml_inline(scalar).unwrap_or_default();
+            out.push_str(&format!("{}{}\n", pad, scalar));
+        }
+    }
+}
+
+/// Inline form of scalars and empty collections; `None` for values needing a block
+fn yaml_inline(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => Some("null".to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(Value::String(s.clone()).to_string()),
+        Value::Array(items) if items.is_empty() => Some("[]".to_string()),
+        Value::Object(map) if map.is_empty() => Some("{}".to_string()),
+        _ => None,
+    }
+}
+
+fn yaml_key(key: &str) -> String {
+    let plain = !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        && !key.starts_with('-');
+    if plain {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use orkee_core::types::{Priority, ProjectStatus};
+
+    fn create_test_project(id: &str, name: &str) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            project_root: format!("/projects/{}", id),
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            tags: Some(vec!["rust".to_string(), "cli".to_string()]),
+            description: None,
+            status: ProjectStatus::Planning,
+            rank: None,
+            priority: Priority::High,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            git_repository: None,
+            owner_user_id: None,
+            version: 1,
+        }
+    }
+
+    fn columns(spec: &str) -> Vec<String> {
+        parse_columns(spec).unwrap()
+    }
+
+    #[test]
+    fn test_parse_format_and_columns() {
+        assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert_eq!("yml".parse::<OutputFormat>().unwrap(), OutputFormat::Yaml);
+        assert!("xml".parse::<OutputFormat>().is_err());
+
+        assert_eq!(
+            columns("id, projectRoot,status"),
+            ["id", "project_root", "status"]
+        );
+        assert!(matches!(
+            parse_columns("id,owner"),
+            Err(FormatError::UnknownColumn(column)) if column == "owner"
+        ));
+        assert!(matches!(parse_columns(" , "), Err(FormatError::NoColumns)));
+    }
+
+    #[test]
+    fn test_json_output() {
+        let projects = vec![create_test_project("abc123", "Test Project")];
+
+        let full: Value =
+            serde_json::from_str(&format_projects(&projects, OutputFormat::Json, None).unwrap())
+                .unwrap();
+        assert_eq!(full[0]["projectRoot"], "/projects/abc123");
+        assert_eq!(full[0]["priority"], "high");
+
+        let selected =
+            format_projects(&projects, OutputFormat::Json, Some(&columns("id,tags"))).unwrap();
+        let selected: Value = serde_json::from_str(&selected).unwrap();
+        assert_eq!(
+            selected,
+            serde_json::json!([{"id": "abc123", "tags": ["rust", "cli"]}])
+        );
+    }
+
+    #[test]
+    fn test_csv_and_tsv_output() {
+        let mut project = create_test_project("abc123", "Test, \"Quoted\" Project");
+        project.description = Some("line one\tand\nline two".to_string());
+        let projects = vec![project];
+
+        let csv = format_projects(&projects, OutputFormat::Csv, None).unwrap();
+        assert_eq!(
+            csv,
+            "id,name,project_root,status\n\
+             abc123,\"Test, \"\"Quoted\"\" Project\",/projects/abc123,planning"
+        );
+
+        let tsv = format_projects(
+            &projects,
+            OutputFormat::Tsv,
+            Some(&columns("id,description,tags,rank")),
+        )
+        .unwrap();
+        assert_eq!(
+            tsv,
+            "id\tdescription\ttags\trank\nabc123\tline one\\tand\\nline two\trust,cli\t"
+        );
+    }
+
+    #[test]
+    fn test_yaml_output() {
+        let projects = vec![create_test_project("abc123", "yes")];
+
+        let yaml = format_projects(
+            &projects,
+            OutputFormat::Yaml,
+            Some(&columns("id,name,rank,tags")),
+        )
+        .unwrap();
+        assert_eq!(
+            yaml,
+            "- id: \"abc123\"\n  name: \"yes\"\n  rank: null\n  tags:\n    - \"rust\"\n    - \"cli\"\n"
+        );
+        assert_eq!(
+            format_projects(&[], OutputFormat::Yaml, None).unwrap(),
+            "[]\n"
+        );
+    }
+
+    #[test]
+    fn test_single_project_and_column_table() {
+        let project = create_test_project("abc123", "Test Project");
+
+        let json = format_project(&project, OutputFormat::Json, Some(&columns("name"))).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap(),
+            serde_json::json!({"name": "Test Project"})
+        );
+
+        let table = format_projects(
+            std::slice::from_ref(&project),
+            OutputFormat::Table,
+            Some(&columns("name,status")),
+        )
+        .unwrap();
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "NAME         | STATUS");
+        assert_eq!(lines[2], "Test Project | planning");
+    }
+}
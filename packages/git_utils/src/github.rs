@@ -37,6 +37,18 @@ pub struct GhIssue {
     pub updated_at: String,
 }
 
+/// A check run on a pull request, as reported by `gh pr checks`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GhCheck {
+    pub name: String,
+    /// Raw check state, e.g. `SUCCESS`, `FAILURE`, `IN_PROGRESS`
+    pub state: String,
+    /// gh's grouping of the state: `pass`, `fail`, `pending`, `skipping`, or `cancel`
+    pub bucket: String,
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
 /// Parameters for updating a GitHub issue
 #[derive(Debug, Default)]
 pub struct UpdateIssueParams {
@@ -273,6 +285,29 @@ impl GitHubCli {
         Ok(())
     }
 
+    /// List the check runs on a pull request
+    ///
+    /// # Arguments
+    /// * `pr` - Pull request number, URL, or branch; URLs also identify the repository
+    pub async fn pr_checks(&self, pr: &str) -> Result<Vec<GhCheck>> {
+        let output = Command::new(&self.gh_path)
+            .args(["pr", "checks", pr, "--json", "name,state,bucket,link"])
+            .output()?;
+
+        // gh exits non-zero while checks are pending or failing but still prints them
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim().is_empty() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if output.status.success() || stderr.contains("no checks reported") {
+                return Ok(Vec::new());
+            }
+            return Err(GitHubCliError::CommandFailed(stderr.to_string()));
+        }
+
+        serde_json::from_str(&stdout)
+            .map_err(|e| GitHubCliError::ParseError(format!("Failed to parse checks JSON: {}", e)))
+    }
+
     /// Check if the current user has the specified scopes
     pub fn check_scopes(&self, required_scopes: &[&str]) -> Result<bool> {
         let output = Command::new(&self.gh_path)
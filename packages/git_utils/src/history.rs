@@ -0,0 +1,98 @@
+// ABOUTME: Commit history listing for project repositories
+// ABOUTME: Lightweight commit summaries for the TUI and other callers without diff stats
+
+use git2::{Repository, Sort};
+use std::path::Path;
+use tracing::debug;
+
+/// One commit in a project's history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSummary {
+    pub id: String,
+    pub short_id: String,
+    /// First line of the commit message
+    pub summary: String,
+    pub author: String,
+    /// Commit time as seconds since the Unix epoch
+    pub timestamp: i64,
+}
+
+/// List the most recent commits reachable from HEAD, newest first
+///
+/// Returns `None` if the path isn't inside a git repository or HEAD has no commits.
+pub fn recent_commits(project_path: &Path, limit: usize) -> Option<Vec<CommitSummary>> {
+    let repo = match Repository::discover(project_path) {
+        Ok(repo) => repo,
+        Err(e) => {
+            debug!("No git repository found at {:?}: {}", project_path, e);
+            return None;
+        }
+    };
+
+    let mut revwalk = repo.revwalk().ok()?;
+    revwalk.push_head().ok()?;
+    // Topological order keeps parents after children when commit times tie
+    revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME).ok()?;
+
+    let commits = revwalk
+        .filter_map(|oid| oid.ok())
+        .filter_map(|oid| repo.find_commit(oid).ok())
+        .take(limit)
+        .map(|commit| {
+            let id = commit.id().to_string();
+            CommitSummary {
+                short_id: id[..7].to_string(),
+                id,
+                summary: commit.summary().unwrap_or("").to_string(),
+                author: commit.author().name().unwrap_or("").to_string(),
+                timestamp: commit.time().seconds(),
+            }
+        })
+        .collect();
+
+    Some(commits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_commits_newest_first() {
+        let root = std::env::temp_dir().join(format!("orkee-history-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let repo = Repository::init(&root).unwrap();
+        let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+
+        let mut parent: Option<git2::Oid> = None;
+        for message in ["first", "second\n\nbody", "third"] {
+            let tree_id = repo.index().unwrap().write_tree().unwrap();
+            let tree = repo.find_tree(tree_id).unwrap();
+            let parents: Vec<git2::Commit> = parent
+                .map(|oid| repo.find_commit(oid).unwrap())
+                .into_iter()
+                .collect();
+            let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+            parent = Some(
+                repo.commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    message,
+                    &tree,
+                    &parent_refs,
+                )
+                .unwrap(),
+            );
+        }
+
+        let commits = recent_commits(&root, 2).unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].summary, "third");
+        assert_eq!(commits[1].summary, "second");
+        assert_eq!(commits[0].short_id.len(), 7);
+        assert_eq!(commits[0].author, "Test");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+}
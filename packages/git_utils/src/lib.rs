@@ -2,13 +2,17 @@
 // ABOUTME: Provides git repository introspection and GitHub CLI operations
 
 pub mod github;
+pub mod history;
+pub mod repo_state;
 
 use git2::Repository;
 use orkee_core::types::GitRepositoryInfo;
 use tracing::debug;
 
 // Re-export GitHub CLI types
-pub use github::{GhIssue, GitHubCli, GitHubCliError, UpdateIssueParams};
+pub use github::{GhCheck, GhIssue, GitHubCli, GitHubCliError, UpdateIssueParams};
+pub use history::{recent_commits, CommitSummary};
+pub use repo_state::{changed_paths_since, get_working_tree_state, WorkingTreeState};
 
 pub fn get_git_repository_info(project_path: &str) -> Option<GitRepositoryInfo> {
     debug!("Getting git repository info for path: {}", project_path);
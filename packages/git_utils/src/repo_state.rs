@@ -0,0 +1,240 @@
+// ABOUTME: Working tree snapshots for change detection between analysis runs
+// ABOUTME: Reports HEAD commit, dirty files with a content hash, and paths changed since a commit
+
+use git2::{DiffOptions, ObjectType, Oid, Repository, StatusOptions};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// State of a project's working tree relative to its git repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkingTreeState {
+    /// Full SHA of the commit HEAD points at
+    pub head_commit: String,
+    /// Uncommitted (modified, staged, or untracked) paths, relative to the project directory
+    pub dirty_paths: Vec<String>,
+    /// Hash over the dirty paths and their current contents; stable while nothing changes
+    pub dirty_hash: String,
+}
+
+/// Snapshot the working tree containing `project_path`
+///
+/// Only paths inside `project_path` are reported, so a project inside a monorepo
+/// is unaffected by uncommitted changes elsewhere in the repository.
+/// Returns `None` if the path isn't inside a git repository with at least one commit.
+pub fn get_working_tree_state(project_path: &Path) -> Option<WorkingTreeState> {
+    let (repo, prefix) = open_repository(project_path)?;
+
+    let head_commit = match repo.head().and_then(|head| head.peel_to_commit()) {
+        Ok(commit) => commit.id().to_string(),
+        Err(e) => {
+            debug!("No HEAD commit for {:?}: {}", project_path, e);
+            return None;
+        }
+    };
+
+    let mut options = StatusOptions::new();
+    options
+        .include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false);
+    if let Some(prefix) = &prefix {
+        options.pathspec(prefix.as_str());
+    }
+
+    let statuses = match repo.statuses(Some(&mut options)) {
+        Ok(statuses) => statuses,
+        Err(e) => {
+            debug!("Failed to read git status for {:?}: {}", project_path, e);
+            return None;
+        }
+    };
+
+    let mut dirty_paths: Vec<String> = statuses
+        .iter()
+        .filter_map(|entry| entry.path().and_then(|p| strip_prefix(p, &prefix)))
+        .collect();
+    dirty_paths.sort();
+    dirty_paths.dedup();
+
+    // Deleted files can't be hashed; they still contribute their path
+    let mut manifest = String::new();
+    for path in &dirty_paths {
+        let content_id = Oid::hash_file(ObjectType::Blob, project_path.join(path))
+            .map(|oid| oid.to_string())
+            .unwrap_or_else(|_| "deleted".to_string());
+        manifest.push_str(path);
+        manifest.push('\0');
+        manifest.push_str(&content_id);
+        manifest.push('\n');
+    }
+
+    let dirty_hash = Oid::hash_object(ObjectType::Blob, manifest.as_bytes())
+        .map(|oid| oid.to_string())
+        .ok()?;
+
+    Some(WorkingTreeState {
+        head_commit,
+        dirty_paths,
+        dirty_hash,
+    })
+}
+
+/// List paths inside `project_path` that differ between `from_commit` and HEAD
+///
+/// Returns `None` if either commit can't be resolved (e.g. history was rewritten),
+/// in which case callers should treat every path as changed.
+pub fn changed_paths_since(project_path: &Path, from_commit: &str) -> Option<Vec<String>> {
+    let (repo, prefix) = open_repository(project_path)?;
+
+    let old_tree = Oid::from_str(from_commit)
+        .and_then(|oid| repo.find_commit(oid))
+        .and_then(|commit| commit.tree())
+        .ok()?;
+    let new_tree = repo.head().and_then(|head| head.peel_to_tree()).ok()?;
+
+    let mut options = DiffOptions::new();
+    if let Some(prefix) = &prefix {
+        options.pathspec(prefix.as_str());
+    }
+
+    let diff = repo
+        .diff_tree_to_tree(Some(&old_tree), Some(&new_tree), Some(&mut options))
+        .ok()?;
+
+    // Renames touch both the old and the new location
+    let mut paths = Vec::new();
+    for delta in diff.deltas() {
+        for file in [delta.old_file(), delta.new_file()] {
+            if let Some(path) = file.path().and_then(|p| p.to_str()) {
+                paths.extend(strip_prefix(path, &prefix));
+            }
+        }
+    }
+    paths.sort();
+    paths.dedup();
+
+    Some(paths)
+}
+
+/// Open the repository containing `project_path` and compute the project's
+/// directory relative to the repository root (`None` when they're the same)
+fn open_repository(project_path: &Path) -> Option<(Repository, Option<String>)> {
+    let repo = match Repository::discover(project_path) {
+        Ok(repo) => repo,
+        Err(e) => {
+            debug!("No git repository found at {:?}: {}", project_path, e);
+            return None;
+        }
+    };
+
+    let workdir = repo.workdir()?.canonicalize().ok()?;
+    let project = project_path.canonicalize().ok()?;
+    let relative: PathBuf = project.strip_prefix(&workdir).ok()?.to_path_buf();
+
+    let prefix = if relative.as_os_str().is_empty() {
+        None
+    } else {
+        // git paths always use forward slashes
+        let joined = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        Some(format!("{}/", joined))
+    };
+
+    Some((repo, prefix))
+}
+
+fn strip_prefix(path: &str, prefix: &Option<String>) -> Option<String> {
+    match prefix {
+        Some(prefix) => path.strip_prefix(prefix.as_str()).map(str::to_string),
+        None => Some(path.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_prefix() {
+        let prefix = Some("packages/app/".to_string());
+        assert_eq!(
+            strip_prefix("packages/app/src/main.rs", &prefix),
+            Some("src/main.rs".to_string())
+        );
+        assert_eq!(strip_prefix("packages/other/Cargo.toml", &prefix), None);
+        assert_eq!(
+            strip_prefix("Cargo.toml", &None),
+            Some("Cargo.toml".to_string())
+        );
+    }
+
+    fn commit_all(repo: &Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(
+            Some("HEAD"),
+            &signature,
+            &signature,
+            message,
+            &tree,
+            &parents,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_working_tree_state_and_changed_paths() {
+        let root = std::env::temp_dir().join(format!("orkee-repo-state-{}", std::process::id()));
+        let project = root.join("packages").join("app");
+        std::fs::create_dir_all(&project).unwrap();
+        let repo = Repository::init(&root).unwrap();
+
+        std::fs::write(project.join("Cargo.toml"), "[package]").unwrap();
+        std::fs::write(root.join("README.md"), "readme").unwrap();
+        commit_all(&repo, "initial");
+
+        let clean = get_working_tree_state(&project).unwrap();
+        assert!(clean.dirty_paths.is_empty());
+
+        // Changes outside the project don't affect its state
+        std::fs::write(root.join("README.md"), "changed").unwrap();
+        assert_eq!(get_working_tree_state(&project).unwrap(), clean);
+
+        std::fs::write(project.join("Cargo.toml"), "[package]\nname = \"app\"").unwrap();
+        let dirty = get_working_tree_state(&project).unwrap();
+        assert_eq!(dirty.head_commit, clean.head_commit);
+        assert_eq!(dirty.dirty_paths, vec!["Cargo.toml".to_string()]);
+        assert_ne!(dirty.dirty_hash, clean.dirty_hash);
+
+        commit_all(&repo, "update manifest");
+        let changed = changed_paths_since(&project, &clean.head_commit).unwrap();
+        assert_eq!(changed, vec!["Cargo.toml".to_string()]);
+        assert!(
+            changed_paths_since(&project, "0000000000000000000000000000000000000000").is_none()
+        );
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[test]
+    fn test_non_repository_has_no_state() {
+        let dir = std::env::temp_dir().join(format!("orkee-no-git-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        // temp_dir may itself live inside a repository on some machines; only
+        // assert when discovery genuinely fails
+        if Repository::discover(&dir).is_err() {
+            assert!(get_working_tree_state(&dir).is_none());
+        }
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
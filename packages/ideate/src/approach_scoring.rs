@@ -0,0 +1,495 @@
+// ABOUTME: Cost/risk scoring of alternative technical approaches
+// ABOUTME: Rates effort, risk, maintainability, and dependencies, then builds a weighted comparison matrix
+
+use crate::approach_generator::{ComplexityLevel, TechnicalApproach};
+use crate::codebase_analyzer::CodebaseContext;
+use crate::error::{IdeateError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Highest rating on any criterion
+const MAX_RATING: u8 = 10;
+
+/// Criteria each approach is rated on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoringCriterion {
+    Effort,
+    Risk,
+    Maintainability,
+    Dependencies,
+}
+
+impl ScoringCriterion {
+    pub const ALL: [ScoringCriterion; 4] = [
+        ScoringCriterion::Effort,
+        ScoringCriterion::Risk,
+        ScoringCriterion::Maintainability,
+        ScoringCriterion::Dependencies,
+    ];
+}
+
+/// Where a rating came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreSource {
+    /// Derived from the approach's estimate, complexity, and trade-offs
+    Heuristic,
+    /// Assessed by the AI through the proxy and submitted by the client
+    Ai,
+}
+
+/// Relative importance of each criterion, set by the user
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ScoringWeights {
+    pub effort: f64,
+    pub risk: f64,
+    pub maintainability: f64,
+    pub dependencies: f64,
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        Self {
+            effort: 1.0,
+            risk: 1.0,
+            maintainability: 1.0,
+            dependencies: 1.0,
+        }
+    }
+}
+
+impl ScoringWeights {
+    pub fn weight(&self, criterion: ScoringCriterion) -> f64 {
+        match criterion {
+            ScoringCriterion::Effort => self.effort,
+            ScoringCriterion::Risk => self.risk,
+            ScoringCriterion::Maintainability => self.maintainability,
+            ScoringCriterion::Dependencies => self.dependencies,
+        }
+    }
+
+    /// Weights must be finite, non-negative, and not all zero
+    pub fn validate(&self) -> Result<()> {
+        let weights = ScoringCriterion::ALL.map(|criterion| self.weight(criterion));
+        if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+            return Err(IdeateError::InvalidInput(
+                "Scoring weights must be non-negative numbers".to_string(),
+            ));
+        }
+        if weights.iter().sum::<f64>() == 0.0 {
+            return Err(IdeateError::InvalidInput(
+                "At least one scoring weight must be greater than zero".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// AI assessment of one approach, obtained by the client through the AI proxy.
+/// Any criterion left out falls back to its heuristic rating.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AiApproachScores {
+    pub approach_name: String,
+    /// 1-10, higher means more work
+    pub effort: Option<u8>,
+    /// 1-10, higher means riskier
+    pub risk: Option<u8>,
+    /// 1-10, higher means easier to maintain
+    pub maintainability: Option<u8>,
+    /// Number of new or existing dependencies the approach relies on
+    pub dependency_count: Option<u32>,
+    pub rationale: Option<String>,
+}
+
+impl AiApproachScores {
+    fn validate(&self) -> Result<()> {
+        let ratings = [
+            ("effort", self.effort),
+            ("risk", self.risk),
+            ("maintainability", self.maintainability),
+        ];
+        for (name, rating) in ratings {
+            if rating.is_some_and(|r| !(1..=MAX_RATING).contains(&r)) {
+                return Err(IdeateError::InvalidInput(format!(
+                    "{} rating for '{}' must be between 1 and {}",
+                    name, self.approach_name, MAX_RATING
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// One cell of the matrix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CriterionScore {
+    pub criterion: ScoringCriterion,
+    /// Rating as assessed: effort, risk, and maintainability are 1-10, dependencies a count
+    pub value: u32,
+    /// Normalized 0-10 score where higher is always better
+    pub score: f64,
+    pub source: ScoreSource,
+}
+
+/// One row of the matrix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApproachScore {
+    pub approach_name: String,
+    /// One entry per criterion, in [`ScoringCriterion::ALL`] order
+    pub scores: Vec<CriterionScore>,
+    /// Weighted average of the scores, 0-10
+    pub weighted_total: f64,
+    /// 1 for the highest weighted total
+    pub rank: usize,
+    pub recommended: bool,
+    pub rationale: Option<String>,
+}
+
+impl ApproachScore {
+    pub fn score(&self, criterion: ScoringCriterion) -> Option<&CriterionScore> {
+        self.scores.iter().find(|s| s.criterion == criterion)
+    }
+}
+
+/// Side-by-side comparison of approaches, ordered by rank
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringMatrix {
+    pub criteria: Vec<ScoringCriterion>,
+    pub weights: ScoringWeights,
+    pub rows: Vec<ApproachScore>,
+    /// Highest-ranked approach
+    pub best_overall: Option<String>,
+    /// Best approach on each criterion, in [`ScoringCriterion::ALL`] order
+    pub best_by_criterion: Vec<CriterionLeader>,
+}
+
+/// Approach with the highest score on one criterion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CriterionLeader {
+    pub criterion: ScoringCriterion,
+    pub approach_name: String,
+}
+
+/// Scores approaches against the codebase they'd be built in
+pub struct ApproachScorer<'a> {
+    codebase_context: &'a CodebaseContext,
+}
+
+impl<'a> ApproachScorer<'a> {
+    pub fn new(codebase_context: &'a CodebaseContext) -> Self {
+        Self { codebase_context }
+    }
+
+    /// Build the comparison matrix, preferring AI ratings over heuristics where given
+    pub fn score(
+        &self,
+        approaches: &[TechnicalApproach],
+        ai_scores: &[AiApproachScores],
+        weights: ScoringWeights,
+    ) -> Result<ScoringMatrix> {
+        weights.validate()?;
+        for ai in ai_scores {
+            ai.validate()?;
+            if !approaches.iter().any(|a| a.name == ai.approach_name) {
+                return Err(IdeateError::InvalidInput(format!(
+                    "AI scores given for unknown approach '{}'",
+                    ai.approach_name
+                )));
+            }
+        }
+
+        let mut rows: Vec<ApproachScore> = approaches
+            .iter()
+            .map(|approach| {
+                let ai = ai_scores
+                    .iter()
+                    .find(|ai| ai.approach_name == approach.name);
+                self.score_approach(approach, ai, &weights)
+            })
+            .collect();
+
+        // Stable sort keeps generation order for ties
+        rows.sort_by(|a, b| b.weighted_total.total_cmp(&a.weighted_total));
+        for (index, row) in rows.iter_mut().enumerate() {
+            row.rank = index + 1;
+        }
+
+        let best_by_criterion = ScoringCriterion::ALL
+            .iter()
+            .filter_map(|&criterion| {
+                rows.iter()
+                    .filter_map(|row| Some((row, row.score(criterion)?.score)))
+                    .reduce(|best, next| if next.1 > best.1 { next } else { best })
+                    .map(|(row, _)| CriterionLeader {
+                        criterion,
+                        approach_name: row.approach_name.clone(),
+                    })
+            })
+            .collect();
+
+        Ok(ScoringMatrix {
+            criteria: ScoringCriterion::ALL.to_vec(),
+            weights,
+            best_overall: rows.first().map(|row| row.approach_name.clone()),
+            rows,
+            best_by_criterion,
+        })
+    }
+
+    fn score_approach(
+        &self,
+        approach: &TechnicalApproach,
+        ai: Option<&AiApproachScores>,
+        weights: &ScoringWeights,
+    ) -> ApproachScore {
+        let rated = |value: Option<u32>, heuristic: u32| match value {
+            Some(value) => (value, ScoreSource::Ai),
+            None => (heuristic, ScoreSource::Heuristic),
+        };
+
+        let scores: Vec<CriterionScore> = ScoringCriterion::ALL
+            .iter()
+            .map(|&criterion| {
+                let (value, source) = match criterion {
+                    ScoringCriterion::Effort => rated(
+                        ai.and_then(|ai| ai.effort).map(u32::from),
+                        heuristic_effort(approach),
+                    ),
+                    ScoringCriterion::Risk => rated(
+                        ai.and_then(|ai| ai.risk).map(u32::from),
+                        heuristic_risk(approach),
+                    ),
+                    ScoringCriterion::Maintainability => rated(
+                        ai.and_then(|ai| ai.maintainability).map(u32::from),
+                        heuristic_maintainability(approach),
+                    ),
+                    ScoringCriterion::Dependencies => rated(
+                        ai.and_then(|ai| ai.dependency_count),
+                        self.heuristic_dependencies(approach),
+                    ),
+                };
+                CriterionScore {
+                    criterion,
+                    value,
+                    score: normalize(criterion, value),
+                    source,
+                }
+            })
+            .collect();
+
+        let total_weight: f64 = ScoringCriterion::ALL
+            .iter()
+            .map(|&c| weights.weight(c))
+            .sum();
+        let weighted_total = scores
+            .iter()
+            .map(|s| s.score * weights.weight(s.criterion))
+            .sum::<f64>()
+            / total_weight;
+
+        ApproachScore {
+            approach_name: approach.name.clone(),
+            scores,
+            weighted_total: round_tenth(weighted_total),
+            rank: 0,
+            recommended: approach.recommended,
+            rationale: ai.and_then(|ai| ai.rationale.clone()),
+        }
+    }
+
+    /// Existing components an approach builds on, or new pieces it has to integrate
+    fn heuristic_dependencies(&self, approach: &TechnicalApproach) -> u32 {
+        let reuses_existing = mentions(approach, &["existing", "reuse", "leverage"]);
+        let integrations = approach
+            .cons
+            .iter()
+            .filter(|con| contains_any(con, &["integration", "both old and new"]))
+            .count() as u32;
+
+        if reuses_existing {
+            self.codebase_context.reusable_components.len() as u32 + integrations
+        } else {
+            integrations
+        }
+    }
+}
+
+/// Effort from the estimate: two days per point, capped at 10
+fn heuristic_effort(approach: &TechnicalApproach) -> u32 {
+    let days = approach.estimated_days.max(1) as u32;
+    days.div_ceil(2).clamp(1, MAX_RATING as u32)
+}
+
+/// Risk from complexity, raised by each con that calls out risk or a learning curve
+fn heuristic_risk(approach: &TechnicalApproach) -> u32 {
+    let base = match approach.complexity {
+        ComplexityLevel::Low => 3,
+        ComplexityLevel::Medium => 5,
+        ComplexityLevel::High => 7,
+    };
+    let flagged = approach
+        .cons
+        .iter()
+        .filter(|con| contains_any(con, &["risk", "unproven", "learning curve"]))
+        .count() as u32;
+    (base + flagged).clamp(1, MAX_RATING as u32)
+}
+
+/// Maintainability from complexity, adjusted by trade-offs that mention it
+fn heuristic_maintainability(approach: &TechnicalApproach) -> u32 {
+    let base: i32 = match approach.complexity {
+        ComplexityLevel::Low => 7,
+        ComplexityLevel::Medium => 6,
+        ComplexityLevel::High => 5,
+    };
+    let gains = approach
+        .pros
+        .iter()
+        .filter(|pro| contains_any(pro, &["maintainable", "best practices", "clean"]))
+        .count() as i32;
+    let losses = approach
+        .cons
+        .iter()
+        .filter(|con| contains_any(con, &["technical debt", "inconsistent", "legacy"]))
+        .count() as i32;
+    (base + gains - losses).clamp(1, MAX_RATING as i32) as u32
+}
+
+/// Turn a rating into a 0-10 score where higher is better
+fn normalize(criterion: ScoringCriterion, value: u32) -> f64 {
+    let max = MAX_RATING as f64;
+    let value = value as f64;
+    match criterion {
+        // Ratings run 1-10; the lowest effort or risk scores a full 10
+        ScoringCriterion::Effort | ScoringCriterion::Risk => {
+            (max - value.clamp(1.0, max)) * max / (max - 1.0)
+        }
+        ScoringCriterion::Maintainability => (value.clamp(1.0, max) - 1.0) * max / (max - 1.0),
+        // Every dependency costs a point
+        ScoringCriterion::Dependencies => (max - value).max(0.0),
+    }
+    .clamp(0.0, max)
+}
+
+fn round_tenth(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+fn contains_any(text: &str, needles: &[&str]) -> bool {
+    let text = text.to_lowercase();
+    needles.iter().any(|needle| text.contains(needle))
+}
+
+fn mentions(approach: &TechnicalApproach, needles: &[&str]) -> bool {
+    contains_any(&approach.name, needles)
+        || contains_any(&approach.description, needles)
+        || approach.pros.iter().any(|pro| contains_any(pro, needles))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approach(name: &str, days: i32, complexity: ComplexityLevel) -> TechnicalApproach {
+        TechnicalApproach {
+            name: name.to_string(),
+            description: String::new(),
+            pros: Vec::new(),
+            cons: Vec::new(),
+            estimated_days: days,
+            complexity,
+            recommended: false,
+            reasoning: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_heuristic_matrix_ranks_cheaper_approach_first() {
+        let context = CodebaseContext::default();
+        let approaches = vec![
+            approach("Slow", 20, ComplexityLevel::High),
+            approach("Quick", 4, ComplexityLevel::Low),
+        ];
+
+        let matrix = ApproachScorer::new(&context)
+            .score(&approaches, &[], ScoringWeights::default())
+            .unwrap();
+
+        assert_eq!(matrix.best_overall.as_deref(), Some("Quick"));
+        assert_eq!(matrix.rows[0].rank, 1);
+        assert_eq!(matrix.rows[1].approach_name, "Slow");
+        let effort = matrix.rows[1].score(ScoringCriterion::Effort).unwrap();
+        assert_eq!(effort.value, 10);
+        assert_eq!(effort.score, 0.0);
+        assert_eq!(effort.source, ScoreSource::Heuristic);
+    }
+
+    #[test]
+    fn test_ai_scores_and_weights_change_ranking() {
+        let context = CodebaseContext::default();
+        let approaches = vec![
+            approach("Quick", 4, ComplexityLevel::Medium),
+            approach("Solid", 12, ComplexityLevel::Medium),
+        ];
+        let ai_scores = vec![AiApproachScores {
+            approach_name: "Solid".to_string(),
+            maintainability: Some(10),
+            ..Default::default()
+        }];
+        let weights = ScoringWeights {
+            effort: 0.0,
+            risk: 0.0,
+            maintainability: 1.0,
+            dependencies: 0.0,
+        };
+
+        let matrix = ApproachScorer::new(&context)
+            .score(&approaches, &ai_scores, weights)
+            .unwrap();
+
+        assert_eq!(matrix.best_overall.as_deref(), Some("Solid"));
+        assert_eq!(matrix.rows[0].weighted_total, 10.0);
+        let maintainability = matrix.rows[0]
+            .score(ScoringCriterion::Maintainability)
+            .unwrap();
+        assert_eq!(maintainability.source, ScoreSource::Ai);
+    }
+
+    #[test]
+    fn test_invalid_input_is_rejected() {
+        let context = CodebaseContext::default();
+        let scorer = ApproachScorer::new(&context);
+        let approaches = vec![approach("Quick", 4, ComplexityLevel::Low)];
+
+        let zero = ScoringWeights {
+            effort: 0.0,
+            risk: 0.0,
+            maintainability: 0.0,
+            dependencies: 0.0,
+        };
+        assert!(scorer.score(&approaches, &[], zero).is_err());
+
+        let out_of_range = AiApproachScores {
+            approach_name: "Quick".to_string(),
+            risk: Some(11),
+            ..Default::default()
+        };
+        assert!(scorer
+            .score(&approaches, &[out_of_range], ScoringWeights::default())
+            .is_err());
+
+        let unknown = AiApproachScores {
+            approach_name: "Other".to_string(),
+            ..Default::default()
+        };
+        assert!(scorer
+            .score(&approaches, &[unknown], ScoringWeights::default())
+            .is_err());
+    }
+}
@@ -45,6 +45,59 @@ pub struct BuildOrderResult {
     pub is_valid: bool,
 }
 
+/// What-if adjustments applied on top of a session's stored features
+///
+/// Simulations never persist anything, so they can be re-run freely while
+/// cutting scope during PRD review.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BuildSimulationInput {
+    /// Feature IDs to leave out of the simulated build
+    #[serde(default)]
+    pub disabled_features: Vec<String>,
+    /// Per-feature effort estimates in days, overriding the default estimate
+    #[serde(default)]
+    pub estimates: HashMap<String, f64>,
+    /// Estimate used for features without an explicit estimate
+    #[serde(default)]
+    pub default_estimate_days: Option<f64>,
+}
+
+/// A dependency that points at a feature disabled in the simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BrokenDependency {
+    pub feature_id: String,
+    pub missing_dependency_id: String,
+    pub strength: DependencyStrength,
+}
+
+/// One phase of a projected build timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelinePhase {
+    pub phase: usize,
+    pub feature_ids: Vec<String>,
+    pub start_day: f64,
+    pub end_day: f64,
+}
+
+/// Result of a what-if build order simulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildSimulationResult {
+    pub session_id: String,
+    pub build_sequence: Vec<String>,
+    pub parallel_groups: Vec<Vec<String>>,
+    pub critical_path: Vec<String>, // Longest path weighted by estimates
+    pub estimated_phases: usize,
+    pub optimization_strategy: OptimizationStrategy,
+    pub excluded_features: Vec<String>,
+    pub broken_dependencies: Vec<BrokenDependency>,
+    pub timeline: Vec<TimelinePhase>,
+    pub total_days: f64,
+    pub computed_at: chrono::DateTime<Utc>,
+}
+
+/// Estimate used when neither the feature nor the simulation supplies one
+const DEFAULT_FEATURE_ESTIMATE_DAYS: f64 = 1.0;
+
 /// Circular dependency detection result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CircularDependency {
@@ -154,6 +207,149 @@ impl BuildOptimizer {
         Ok(result)
     }
 
+    /// Simulate a build order with features toggled off or estimates changed,
+    /// without persisting the result or any detected cycles
+    pub async fn simulate(
+        &self,
+        session_id: &str,
+        strategy: OptimizationStrategy,
+        input: &BuildSimulationInput,
+    ) -> Result<BuildSimulationResult> {
+        info!(
+            "Simulating build order for session: {} (strategy: {:?}, {} disabled)",
+            session_id,
+            strategy,
+            input.disabled_features.len()
+        );
+
+        let features = self.get_features(session_id).await?;
+        let dependencies = self.get_dependencies(session_id).await?;
+
+        self.simulate_with(session_id, &features, &dependencies, strategy, input)
+    }
+
+    /// Compute a simulation from already-loaded features and dependencies
+    fn simulate_with(
+        &self,
+        session_id: &str,
+        features: &[IdeateFeature],
+        dependencies: &[FeatureDependency],
+        strategy: OptimizationStrategy,
+        input: &BuildSimulationInput,
+    ) -> Result<BuildSimulationResult> {
+        let known: HashSet<&str> = features.iter().map(|f| f.id.as_str()).collect();
+        if let Some(unknown) = input
+            .disabled_features
+            .iter()
+            .chain(input.estimates.keys())
+            .find(|id| !known.contains(id.as_str()))
+        {
+            return Err(IdeateError::InvalidInput(format!(
+                "Unknown feature in simulation: {}",
+                unknown
+            )));
+        }
+
+        let default_estimate = input
+            .default_estimate_days
+            .unwrap_or(DEFAULT_FEATURE_ESTIMATE_DAYS);
+        if let Some(estimate) = input
+            .estimates
+            .values()
+            .chain(std::iter::once(&default_estimate))
+            .find(|days| !days.is_finite() || **days < 0.0)
+        {
+            return Err(IdeateError::InvalidInput(format!(
+                "Estimates must be non-negative numbers of days, got {}",
+                estimate
+            )));
+        }
+
+        let disabled: HashSet<&str> = input.disabled_features.iter().map(String::as_str).collect();
+        let enabled: Vec<IdeateFeature> = features
+            .iter()
+            .filter(|f| !disabled.contains(f.id.as_str()))
+            .cloned()
+            .collect();
+        let excluded_features: Vec<String> = features
+            .iter()
+            .filter(|f| disabled.contains(f.id.as_str()))
+            .map(|f| f.id.clone())
+            .collect();
+
+        // Dependencies on disabled features vanish from the graph; report them
+        // so the user can see what a scope cut breaks
+        let broken_dependencies: Vec<BrokenDependency> = dependencies
+            .iter()
+            .filter(|dep| {
+                dep.strength != DependencyStrength::Optional
+                    && !disabled.contains(dep.from_feature_id.as_str())
+                    && disabled.contains(dep.to_feature_id.as_str())
+            })
+            .map(|dep| BrokenDependency {
+                feature_id: dep.from_feature_id.clone(),
+                missing_dependency_id: dep.to_feature_id.clone(),
+                strength: dep.strength,
+            })
+            .collect();
+
+        let estimate_for = |feature_id: &str| {
+            input
+                .estimates
+                .get(feature_id)
+                .copied()
+                .unwrap_or(default_estimate)
+        };
+
+        let (graph, _node_map, reverse_map) = self.build_graph(&enabled, dependencies)?;
+
+        let cycles = self.detect_cycles(&graph, &reverse_map)?;
+        if !cycles.is_empty() {
+            return Err(IdeateError::ValidationError(format!(
+                "Circular dependencies detected: {} cycles found",
+                cycles.len()
+            )));
+        }
+
+        let topo_order = self.topological_sort(&graph, &reverse_map)?;
+        let parallel_groups =
+            self.identify_parallel_groups(&graph, &topo_order, &reverse_map, strategy)?;
+        let critical_path =
+            self.compute_weighted_critical_path(&graph, &reverse_map, &estimate_for)?;
+
+        // Phases run back to back; features within a phase run in parallel, so
+        // each phase lasts as long as its largest estimate
+        let mut timeline = Vec::with_capacity(parallel_groups.len());
+        let mut elapsed = 0.0;
+        for (index, group) in parallel_groups.iter().enumerate() {
+            let duration = group
+                .iter()
+                .map(|id| estimate_for(id.as_str()))
+                .fold(0.0, f64::max);
+            timeline.push(TimelinePhase {
+                phase: index + 1,
+                feature_ids: group.clone(),
+                start_day: elapsed,
+                end_day: elapsed + duration,
+            });
+            elapsed += duration;
+        }
+
+        Ok(BuildSimulationResult {
+            session_id: session_id.to_string(),
+            build_sequence: topo_order,
+            estimated_phases: parallel_groups.len(),
+            parallel_groups,
+            critical_path,
+            optimization_strategy: strategy,
+            excluded_features,
+            broken_dependencies,
+            timeline,
+            total_days: elapsed,
+            computed_at: Utc::now(),
+        })
+    }
+
     /// Build dependency graph using petgraph
     fn build_graph(
         &self,
@@ -412,6 +608,67 @@ impl BuildOptimizer {
         }
     }
 
+    /// Compute the critical path weighted by per-feature estimates
+    fn compute_weighted_critical_path(
+        &self,
+        graph: &DiGraph<String, ()>,
+        reverse_map: &HashMap<NodeIndex, String>,
+        estimate_for: &dyn Fn(&str) -> f64,
+    ) -> Result<Vec<String>> {
+        if graph.node_count() == 0 {
+            return Ok(vec![]);
+        }
+
+        let sorted = toposort(graph, None).map_err(|_| {
+            IdeateError::ValidationError("Cannot compute critical path with cycles".to_string())
+        })?;
+
+        let weight = |node: NodeIndex| {
+            reverse_map
+                .get(&node)
+                .map(|id| estimate_for(id.as_str()))
+                .unwrap_or(0.0)
+        };
+
+        // Each node's distance includes its own estimate
+        let mut finish: HashMap<NodeIndex, f64> =
+            graph.node_indices().map(|n| (n, weight(n))).collect();
+        let mut predecessor = HashMap::new();
+
+        for &node in &sorted {
+            let node_finish = finish[&node];
+            for neighbor in graph.neighbors(node) {
+                let candidate = node_finish + weight(neighbor);
+                if candidate > finish[&neighbor] {
+                    finish.insert(neighbor, candidate);
+                    predecessor.insert(neighbor, node);
+                }
+            }
+        }
+
+        // Walk the sorted order so ties resolve deterministically
+        let Some(mut node) =
+            sorted
+                .iter()
+                .copied()
+                .reduce(|best, n| if finish[&n] > finish[&best] { n } else { best })
+        else {
+            return Ok(vec![]);
+        };
+
+        let mut path = vec![node];
+        while let Some(&pred) = predecessor.get(&node) {
+            path.push(pred);
+            node = pred;
+        }
+        path.reverse();
+
+        Ok(path
+            .into_iter()
+            .filter_map(|idx| reverse_map.get(&idx).cloned())
+            .collect())
+    }
+
     /// Get features for a session
     async fn get_features(&self, session_id: &str) -> Result<Vec<IdeateFeature>> {
         let rows = sqlx::query(
@@ -647,3 +904,158 @@ impl BuildOptimizer {
         Ok(cycles)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependency_analyzer::DependencyType;
+
+    fn feature(id: &str) -> IdeateFeature {
+        IdeateFeature {
+            id: id.to_string(),
+            session_id: "session".to_string(),
+            feature_name: id.to_string(),
+            what_it_does: None,
+            why_important: None,
+            how_it_works: None,
+            depends_on: None,
+            enables: None,
+            build_phase: 1,
+            is_visible: true,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// `from` depends on `to`
+    fn dependency(from: &str, to: &str, strength: DependencyStrength) -> FeatureDependency {
+        FeatureDependency {
+            id: format!("{}-{}", from, to),
+            session_id: "session".to_string(),
+            from_feature_id: from.to_string(),
+            to_feature_id: to.to_string(),
+            dependency_type: DependencyType::Technical,
+            strength,
+            reason: None,
+            auto_detected: false,
+        }
+    }
+
+    fn optimizer() -> BuildOptimizer {
+        BuildOptimizer::new(SqlitePool::connect_lazy("sqlite::memory:").unwrap())
+    }
+
+    /// auth <- api <- ui, plus an independent docs feature
+    fn fixture() -> (Vec<IdeateFeature>, Vec<FeatureDependency>) {
+        let features = vec![
+            feature("auth"),
+            feature("api"),
+            feature("ui"),
+            feature("docs"),
+        ];
+        let dependencies = vec![
+            dependency("api", "auth", DependencyStrength::Required),
+            dependency("ui", "api", DependencyStrength::Required),
+        ];
+        (features, dependencies)
+    }
+
+    #[tokio::test]
+    async fn test_simulation_projects_timeline_from_estimates() {
+        let (features, dependencies) = fixture();
+        let input = BuildSimulationInput {
+            estimates: HashMap::from([
+                ("auth".to_string(), 2.0),
+                ("api".to_string(), 3.0),
+                ("docs".to_string(), 4.0),
+            ]),
+            ..Default::default()
+        };
+
+        let result = optimizer()
+            .simulate_with(
+                "session",
+                &features,
+                &dependencies,
+                OptimizationStrategy::Fastest,
+                &input,
+            )
+            .unwrap();
+
+        assert_eq!(result.estimated_phases, 3);
+        // Phase 1 runs auth and docs in parallel, so it lasts as long as docs
+        assert_eq!(result.timeline[0].end_day, 4.0);
+        assert_eq!(result.timeline[1].start_day, 4.0);
+        assert_eq!(result.timeline[1].end_day, 7.0);
+        // ui falls back to the default one-day estimate
+        assert_eq!(result.total_days, 8.0);
+        assert_eq!(result.critical_path, vec!["auth", "api", "ui"]);
+        assert!(result.excluded_features.is_empty());
+        assert!(result.broken_dependencies.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_simulation_disabling_feature_reports_broken_dependencies() {
+        let (features, dependencies) = fixture();
+        let input = BuildSimulationInput {
+            disabled_features: vec!["api".to_string()],
+            ..Default::default()
+        };
+
+        let result = optimizer()
+            .simulate_with(
+                "session",
+                &features,
+                &dependencies,
+                OptimizationStrategy::Fastest,
+                &input,
+            )
+            .unwrap();
+
+        assert_eq!(result.excluded_features, vec!["api"]);
+        assert!(!result.build_sequence.contains(&"api".to_string()));
+        assert_eq!(result.build_sequence.len(), 3);
+        assert_eq!(result.estimated_phases, 1);
+        assert_eq!(
+            result.broken_dependencies,
+            vec![BrokenDependency {
+                feature_id: "ui".to_string(),
+                missing_dependency_id: "api".to_string(),
+                strength: DependencyStrength::Required,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_simulation_rejects_invalid_input() {
+        let (features, dependencies) = fixture();
+        let optimizer = optimizer();
+
+        let unknown = BuildSimulationInput {
+            disabled_features: vec!["missing".to_string()],
+            ..Default::default()
+        };
+        assert!(optimizer
+            .simulate_with(
+                "session",
+                &features,
+                &dependencies,
+                OptimizationStrategy::Balanced,
+                &unknown
+            )
+            .is_err());
+
+        let negative = BuildSimulationInput {
+            estimates: HashMap::from([("ui".to_string(), -1.0)]),
+            ..Default::default()
+        };
+        assert!(optimizer
+            .simulate_with(
+                "session",
+                &features,
+                &dependencies,
+                OptimizationStrategy::Balanced,
+                &negative
+            )
+            .is_err());
+    }
+}
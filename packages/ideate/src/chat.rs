@@ -82,6 +82,9 @@ pub struct SendMessageInput {
     pub content: String,
     pub message_type: Option<MessageType>,
     pub role: Option<MessageRole>,
+    /// Model the conversation targets, used for token budgeting
+    #[serde(default)]
+    pub model: Option<String>,
 }
 
 /// Discovery question for guiding chat
@@ -163,3 +166,56 @@ pub struct CreateInsightInput {
     pub confidence_score: Option<f64>,
     pub source_message_ids: Option<Vec<String>>,
 }
+
+/// Summarization checkpoint compressing older chat messages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSummary {
+    pub id: String,
+    pub session_id: String,
+    /// Messages with `message_order` up to and including this value are covered
+    pub through_message_order: i32,
+    pub summary: String,
+    pub token_estimate: i64,
+    pub source: SummarySource,
+    pub created_at: String,
+}
+
+/// Who produced a chat summary
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SummarySource {
+    /// Extractive summary created automatically by the chat manager
+    Auto,
+    /// Summary written by the frontend AI model
+    Ai,
+}
+
+/// Input for storing an AI-written summary checkpoint
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateSummaryInput {
+    pub summary: String,
+    pub through_message_order: i32,
+}
+
+/// Token budget for a chat against a model's context window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenBudget {
+    pub model_id: String,
+    pub max_context_tokens: u64,
+    /// Tokens held back for the model's response
+    pub reserved_output_tokens: u64,
+    /// Estimated tokens used by the latest summary plus unsummarized messages
+    pub used_tokens: u64,
+    pub remaining_tokens: u64,
+    /// Fraction of the usable budget consumed (0.0 - 1.0+)
+    pub usage_ratio: f64,
+}
+
+/// The context to send to a model: the latest summary plus the messages after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatContext {
+    pub summary: Option<ChatSummary>,
+    pub messages: Vec<ChatMessage>,
+    pub budget: TokenBudget,
+}
@@ -0,0 +1,259 @@
+// ABOUTME: Token budgeting and summarization checkpoints for chat-based PRD discovery
+// ABOUTME: Estimates context usage per model and compresses older turns into rolling summaries
+
+use crate::chat::*;
+use orkee_models::REGISTRY;
+
+/// Model id reported when the caller doesn't name a model
+pub const DEFAULT_BUDGET_MODEL: &str = "default";
+
+/// Context window assumed when the model is unknown to the registry
+pub const DEFAULT_CONTEXT_TOKENS: u64 = 128_000;
+
+/// Tokens held back for the model's response
+pub const RESERVED_OUTPUT_TOKENS: u64 = 8_192;
+
+/// Share of the usable budget that triggers a summarization checkpoint
+pub const SUMMARY_TRIGGER_RATIO: f64 = 0.6;
+
+/// Most recent messages that are always kept verbatim
+pub const KEEP_RECENT_MESSAGES: usize = 6;
+
+/// Rough characters-per-token ratio for English prose
+const CHARS_PER_TOKEN: u64 = 4;
+
+/// Per-message overhead for role and formatting tokens
+const MESSAGE_OVERHEAD_TOKENS: u64 = 4;
+
+/// Longest line kept per message in an automatic summary
+const MAX_SUMMARY_LINE_CHARS: usize = 240;
+
+/// Upper bound on an automatic summary; the oldest lines are dropped first
+const MAX_SUMMARY_TOKENS: u64 = 4_000;
+
+/// Estimate the number of tokens in a piece of text
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Estimate the tokens a message contributes to the model context
+pub fn estimate_message_tokens(message: &ChatMessage) -> u64 {
+    estimate_tokens(&message.content) + MESSAGE_OVERHEAD_TOKENS
+}
+
+/// Look up a model's context window, falling back to a conservative default
+pub fn context_window_for(model_id: &str) -> u64 {
+    REGISTRY
+        .get_model(model_id)
+        .map(|model| model.max_context_tokens)
+        .unwrap_or(DEFAULT_CONTEXT_TOKENS)
+}
+
+/// Compute how much of a model's context the summary and messages consume
+pub fn compute_budget(
+    model_id: &str,
+    max_context_tokens: u64,
+    summary: Option<&ChatSummary>,
+    messages: &[ChatMessage],
+) -> TokenBudget {
+    // Small context windows can't afford the full reservation
+    let reserved_output_tokens = RESERVED_OUTPUT_TOKENS.min(max_context_tokens / 4);
+    let usable = max_context_tokens - reserved_output_tokens;
+
+    let used_tokens = summary.map(|s| s.token_estimate.max(0) as u64).unwrap_or(0)
+        + messages.iter().map(estimate_message_tokens).sum::<u64>();
+
+    TokenBudget {
+        model_id: model_id.to_string(),
+        max_context_tokens,
+        reserved_output_tokens,
+        used_tokens,
+        remaining_tokens: usable.saturating_sub(used_tokens),
+        usage_ratio: if usable == 0 {
+            1.0
+        } else {
+            used_tokens as f64 / usable as f64
+        },
+    }
+}
+
+/// Pick the last message order to fold into a new checkpoint, if the budget
+/// calls for one
+///
+/// `messages` are the messages not yet covered by a summary, in order.
+pub fn checkpoint_boundary(budget: &TokenBudget, messages: &[ChatMessage]) -> Option<i32> {
+    if budget.usage_ratio < SUMMARY_TRIGGER_RATIO || messages.len() <= KEEP_RECENT_MESSAGES {
+        return None;
+    }
+
+    messages
+        .get(messages.len() - KEEP_RECENT_MESSAGES - 1)
+        .map(|m| m.message_order)
+}
+
+/// Build an extractive rolling summary: the previous summary followed by the
+/// opening sentence of each newly covered message
+pub fn extractive_summary(previous: Option<&str>, messages: &[ChatMessage]) -> String {
+    let mut lines: Vec<String> = previous
+        .map(|p| p.lines().map(str::to_string).collect())
+        .unwrap_or_default();
+
+    for message in messages {
+        let Some(gist) = first_sentence(&message.content) else {
+            continue;
+        };
+        let speaker = match message.role {
+            MessageRole::User => "User",
+            MessageRole::Assistant => "Assistant",
+            MessageRole::System => "System",
+        };
+        lines.push(format!("{}: {}", speaker, gist));
+    }
+
+    let mut tokens: u64 = lines.iter().map(|l| estimate_tokens(l) + 1).sum();
+    while tokens > MAX_SUMMARY_TOKENS && lines.len() > 1 {
+        tokens -= estimate_tokens(&lines[0]) + 1;
+        lines.remove(0);
+    }
+
+    lines.join("\n")
+}
+
+fn first_sentence(content: &str) -> Option<String> {
+    let content = content.trim();
+    if content.is_empty() {
+        return None;
+    }
+
+    let end = content
+        .char_indices()
+        .find(|&(i, c)| {
+            c == '\n'
+                || (matches!(c, '.' | '?' | '!')
+                    && content[i + c.len_utf8()..].starts_with(char::is_whitespace))
+        })
+        .map(|(i, c)| if c == '\n' { i } else { i + c.len_utf8() })
+        .unwrap_or(content.len());
+    let sentence = content[..end].trim();
+
+    if sentence.chars().count() > MAX_SUMMARY_LINE_CHARS {
+        let truncated: String = sentence.chars().take(MAX_SUMMARY_LINE_CHARS).collect();
+        Some(format!("{}…", truncated.trim_end()))
+    } else {
+        Some(sentence.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(order: i32, role: MessageRole, content: &str) -> ChatMessage {
+        ChatMessage {
+            id: format!("message_{}", order),
+            session_id: "session".to_string(),
+            prd_id: None,
+            message_order: order,
+            role,
+            content: content.to_string(),
+            message_type: None,
+            metadata: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+    }
+
+    #[test]
+    fn test_context_window_from_registry() {
+        assert_eq!(context_window_for("gpt-4o"), 128_000);
+        assert_eq!(context_window_for("not-a-model"), DEFAULT_CONTEXT_TOKENS);
+    }
+
+    #[test]
+    fn test_budget_accounts_for_summary_and_reserve() {
+        let summary = ChatSummary {
+            id: "summary_1".to_string(),
+            session_id: "session".to_string(),
+            through_message_order: 3,
+            summary: String::new(),
+            token_estimate: 100,
+            source: SummarySource::Auto,
+            created_at: String::new(),
+        };
+        let messages = vec![message(4, MessageRole::User, &"x".repeat(400))];
+
+        let budget = compute_budget("model", 10_000, Some(&summary), &messages);
+        assert_eq!(budget.reserved_output_tokens, 2_500);
+        assert_eq!(budget.used_tokens, 100 + 100 + MESSAGE_OVERHEAD_TOKENS);
+        assert_eq!(budget.remaining_tokens, 7_500 - budget.used_tokens);
+    }
+
+    #[test]
+    fn test_checkpoint_keeps_recent_messages() {
+        let messages: Vec<ChatMessage> = (0..10)
+            .map(|i| message(i, MessageRole::User, &"y".repeat(800)))
+            .collect();
+
+        let roomy = compute_budget("model", 200_000, None, &messages);
+        assert_eq!(checkpoint_boundary(&roomy, &messages), None);
+
+        let tight = compute_budget("model", 4_000, None, &messages);
+        assert_eq!(
+            checkpoint_boundary(&tight, &messages),
+            Some(9 - KEEP_RECENT_MESSAGES as i32)
+        );
+        assert_eq!(
+            checkpoint_boundary(&tight, &messages[..KEEP_RECENT_MESSAGES]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extractive_summary_rolls_forward() {
+        let first = extractive_summary(
+            None,
+            &[
+                message(
+                    0,
+                    MessageRole::User,
+                    "We need an invoicing tool. It should be simple.",
+                ),
+                message(
+                    1,
+                    MessageRole::Assistant,
+                    "Who are the users?\nFreelancers?",
+                ),
+                message(2, MessageRole::User, "   "),
+            ],
+        );
+        assert_eq!(
+            first,
+            "User: We need an invoicing tool.\nAssistant: Who are the users?"
+        );
+
+        let second = extractive_summary(
+            Some(&first),
+            &[message(3, MessageRole::User, "Freelancers in the EU.")],
+        );
+        assert!(second.starts_with(&first));
+        assert!(second.ends_with("User: Freelancers in the EU."));
+    }
+
+    #[test]
+    fn test_extractive_summary_is_bounded() {
+        let messages: Vec<ChatMessage> = (0..500)
+            .map(|i| message(i, MessageRole::User, &"word ".repeat(100)))
+            .collect();
+        let summary = extractive_summary(None, &messages);
+        assert!(estimate_tokens(&summary) <= MAX_SUMMARY_TOKENS);
+        assert!(summary
+            .lines()
+            .all(|l| l.chars().count() <= MAX_SUMMARY_LINE_CHARS + 10));
+    }
+}
@@ -2,10 +2,11 @@
 // ABOUTME: Handles message storage, retrieval, insight extraction, and quality tracking
 
 use crate::chat::*;
+use crate::chat_context;
 use crate::error::{IdeateError, Result};
 use nanoid::nanoid;
 use sqlx::{Row, SqlitePool};
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 pub struct ChatManager {
     pool: SqlitePool,
@@ -354,4 +355,203 @@ impl ChatManager {
 
         Ok(())
     }
+
+    /// Get the most recent summarization checkpoint for a session
+    pub async fn get_latest_summary(&self, session_id: &str) -> Result<Option<ChatSummary>> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, session_id, through_message_order, summary, token_estimate, source, created_at
+            FROM prd_chat_summaries
+            WHERE session_id = ?
+            ORDER BY through_message_order DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(session_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to get latest chat summary: {}", e);
+            IdeateError::Database(e)
+        })?;
+
+        Ok(row.map(|row| Self::summary_from_row(&row)))
+    }
+
+    /// Get all summarization checkpoints for a session, oldest first
+    pub async fn get_summaries(&self, session_id: &str) -> Result<Vec<ChatSummary>> {
+        info!("Getting chat summaries for session: {}", session_id);
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, session_id, through_message_order, summary, token_estimate, source, created_at
+            FROM prd_chat_summaries
+            WHERE session_id = ?
+            ORDER BY through_message_order ASC
+            "#,
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to get chat summaries: {}", e);
+            IdeateError::Database(e)
+        })?;
+
+        Ok(rows.iter().map(Self::summary_from_row).collect())
+    }
+
+    /// Store a summarization checkpoint covering messages up to `through_message_order`
+    pub async fn add_summary(
+        &self,
+        session_id: &str,
+        through_message_order: i32,
+        summary: String,
+        source: SummarySource,
+    ) -> Result<ChatSummary> {
+        if summary.trim().is_empty() {
+            return Err(IdeateError::InvalidInput(
+                "Summary cannot be empty".to_string(),
+            ));
+        }
+
+        if let Some(latest) = self.get_latest_summary(session_id).await? {
+            if through_message_order <= latest.through_message_order {
+                return Err(IdeateError::InvalidInput(format!(
+                    "Summary must cover messages after order {}",
+                    latest.through_message_order
+                )));
+            }
+        }
+
+        info!(
+            "Adding {:?} summary to session: {} (through message {})",
+            source, session_id, through_message_order
+        );
+
+        let id = nanoid!(12);
+        let created_at = chrono::Utc::now().to_rfc3339();
+        let token_estimate = chat_context::estimate_tokens(&summary) as i64;
+
+        sqlx::query(
+            r#"
+            INSERT INTO prd_chat_summaries (
+                id, session_id, through_message_order, summary, token_estimate, source, created_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(session_id)
+        .bind(through_message_order)
+        .bind(&summary)
+        .bind(token_estimate)
+        .bind(source)
+        .bind(&created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to insert chat summary: {}", e);
+            IdeateError::Database(e)
+        })?;
+
+        Ok(ChatSummary {
+            id,
+            session_id: session_id.to_string(),
+            through_message_order,
+            summary,
+            token_estimate,
+            source,
+            created_at,
+        })
+    }
+
+    /// Get the context to send to a model: the latest summary, the messages
+    /// after it, and how much of the model's context window they use
+    ///
+    /// Unknown or missing models fall back to a default context window.
+    pub async fn get_context(
+        &self,
+        session_id: &str,
+        model_id: Option<&str>,
+    ) -> Result<ChatContext> {
+        let summary = self.get_latest_summary(session_id).await?;
+        let messages = self.messages_after(session_id, summary.as_ref()).await?;
+
+        let model_id = model_id.unwrap_or(chat_context::DEFAULT_BUDGET_MODEL);
+        let budget = chat_context::compute_budget(
+            model_id,
+            chat_context::context_window_for(model_id),
+            summary.as_ref(),
+            &messages,
+        );
+
+        Ok(ChatContext {
+            summary,
+            messages,
+            budget,
+        })
+    }
+
+    /// Compress older messages into an automatic summary once the chat uses
+    /// enough of the model's context window
+    ///
+    /// Returns the new checkpoint, or `None` if the budget still has room.
+    pub async fn checkpoint_if_needed(
+        &self,
+        session_id: &str,
+        model_id: Option<&str>,
+    ) -> Result<Option<ChatSummary>> {
+        let context = self.get_context(session_id, model_id).await?;
+
+        let Some(through) = chat_context::checkpoint_boundary(&context.budget, &context.messages)
+        else {
+            debug!(
+                "No chat checkpoint needed for session {} ({:.0}% of budget used)",
+                session_id,
+                context.budget.usage_ratio * 100.0
+            );
+            return Ok(None);
+        };
+
+        let covered: Vec<ChatMessage> = context
+            .messages
+            .into_iter()
+            .take_while(|m| m.message_order <= through)
+            .collect();
+        let summary = chat_context::extractive_summary(
+            context.summary.as_ref().map(|s| s.summary.as_str()),
+            &covered,
+        );
+
+        self.add_summary(session_id, through, summary, SummarySource::Auto)
+            .await
+            .map(Some)
+    }
+
+    async fn messages_after(
+        &self,
+        session_id: &str,
+        summary: Option<&ChatSummary>,
+    ) -> Result<Vec<ChatMessage>> {
+        let history = self.get_history(session_id).await?;
+        Ok(match summary {
+            Some(summary) => history
+                .into_iter()
+                .filter(|m| m.message_order > summary.through_message_order)
+                .collect(),
+            None => history,
+        })
+    }
+
+    fn summary_from_row(row: &sqlx::sqlite::SqliteRow) -> ChatSummary {
+        ChatSummary {
+            id: row.get("id"),
+            session_id: row.get("session_id"),
+            through_message_order: row.get("through_message_order"),
+            summary: row.get("summary"),
+            token_estimate: row.get("token_estimate"),
+            source: row.get("source"),
+            created_at: row.get("created_at"),
+        }
+    }
 }
@@ -0,0 +1,681 @@
+// ABOUTME: Sandboxed validation runs for execution checkpoints
+// ABOUTME: Executes test/lint/build commands, records pass/fail with logs, and gates later parallel groups
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use orkee_storage::StorageError as StoreError;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::execution_tracker::{
+    AppendProgressInput, ExecutionCheckpoint, ExecutionTracker, ValidationEntryType,
+};
+
+/// Longest stdout/stderr tail kept per command
+const MAX_LOG_CHARS: usize = 8 * 1024;
+
+/// Log lines quoted in the validation history entry for each failing command
+const HISTORY_LOG_LINES: usize = 20;
+
+/// Author recorded on validation history entries written by the runner
+const RUNNER_AUTHOR: &str = "checkpoint-runner";
+
+/// Outcome of a checkpoint validation run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum CheckpointRunStatus {
+    /// Commands are still executing
+    Running,
+    /// Every command exited successfully
+    Passed,
+    /// At least one command exited non-zero
+    Failed,
+    /// A command could not be executed at all (e.g. the sandbox is gone)
+    Error,
+}
+
+/// Raw output of one command, as returned by an executor
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    pub exit_code: i64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Runs validation commands somewhere isolated from the host, normally a sandbox
+#[async_trait]
+pub trait ValidationCommandExecutor: Send + Sync {
+    /// Run a shell command to completion. `Err` means it could not be run.
+    async fn execute(&self, command: &str) -> Result<CommandOutput, String>;
+}
+
+/// Result of one validation command within a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationCommandResult {
+    pub command: String,
+    pub exit_code: Option<i64>,
+    pub passed: bool,
+    pub duration_ms: u64,
+    /// Tail of stdout, truncated to the last few KB
+    pub stdout: String,
+    /// Tail of stderr, truncated to the last few KB
+    pub stderr: String,
+    pub error: Option<String>,
+}
+
+/// A validation run against one checkpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckpointValidationRun {
+    pub id: String,
+    pub checkpoint_id: String,
+    pub epic_id: String,
+    pub sandbox_id: String,
+    pub status: CheckpointRunStatus,
+    pub commands: Vec<String>,
+    pub results: Vec<ValidationCommandResult>,
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Whether a parallel group may start, and which checkpoints hold it back
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParallelGroupGate {
+    pub group: String,
+    pub task_ids: Vec<String>,
+    pub open: bool,
+    /// Incomplete checkpoints placed before this group
+    pub blocked_by: Vec<String>,
+}
+
+/// Checkpoint validation runner
+pub struct CheckpointRunner {
+    pool: SqlitePool,
+}
+
+impl CheckpointRunner {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Record a new run for a checkpoint in the `running` state.
+    ///
+    /// Call [`CheckpointRunner::execute_run`] afterwards to run the commands;
+    /// the split lets callers return the run immediately and execute it in
+    /// the background.
+    pub async fn start_run(
+        &self,
+        checkpoint_id: &str,
+        sandbox_id: &str,
+        commands: Vec<String>,
+    ) -> Result<CheckpointValidationRun, StoreError> {
+        let commands: Vec<String> = commands
+            .into_iter()
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+        if commands.is_empty() {
+            return Err(StoreError::Validation(
+                "At least one validation command is required".to_string(),
+            ));
+        }
+
+        let checkpoint = ExecutionTracker::new(self.pool.clone())
+            .get_checkpoint(checkpoint_id)
+            .await?;
+
+        let running: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM checkpoint_validation_runs WHERE checkpoint_id = ? AND status = 'running'",
+        )
+        .bind(checkpoint_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+        if running > 0 {
+            return Err(StoreError::Validation(format!(
+                "Checkpoint {} already has a validation run in progress",
+                checkpoint_id
+            )));
+        }
+
+        let run = CheckpointValidationRun {
+            id: nanoid::nanoid!(),
+            checkpoint_id: checkpoint.id,
+            epic_id: checkpoint.epic_id,
+            sandbox_id: sandbox_id.to_string(),
+            status: CheckpointRunStatus::Running,
+            commands,
+            results: Vec::new(),
+            error: None,
+            started_at: Utc::now(),
+            completed_at: None,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO checkpoint_validation_runs (
+                id, checkpoint_id, epic_id, sandbox_id, status, commands, results, started_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&run.id)
+        .bind(&run.checkpoint_id)
+        .bind(&run.epic_id)
+        .bind(&run.sandbox_id)
+        .bind(run.status)
+        .bind(serde_json::to_string(&run.commands)?)
+        .bind(serde_json::to_string(&run.results)?)
+        .bind(run.started_at)
+        .execute(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+
+        Ok(run)
+    }
+
+    /// Execute a started run's commands in order and record the outcome.
+    ///
+    /// Every command runs even after a failure so the logs cover tests, lint
+    /// and build together; execution stops only if a command cannot be run.
+    /// The outcome is appended to the checkpoint task's validation history,
+    /// and a passing run completes the checkpoint.
+    pub async fn execute_run(
+        &self,
+        mut run: CheckpointValidationRun,
+        executor: &dyn ValidationCommandExecutor,
+    ) -> Result<CheckpointValidationRun, StoreError> {
+        run.status = CheckpointRunStatus::Passed;
+
+        for command in &run.commands {
+            let started = Instant::now();
+            let outcome = executor.execute(command).await;
+            let duration_ms = started.elapsed().as_millis() as u64;
+
+            match outcome {
+                Ok(output) => {
+                    let passed = output.exit_code == 0;
+                    if !passed {
+                        run.status = CheckpointRunStatus::Failed;
+                    }
+                    run.results.push(ValidationCommandResult {
+                        command: command.clone(),
+                        exit_code: Some(output.exit_code),
+                        passed,
+                        duration_ms,
+                        stdout: log_tail(&output.stdout),
+                        stderr: log_tail(&output.stderr),
+                        error: None,
+                    });
+                }
+                Err(e) => {
+                    run.status = CheckpointRunStatus::Error;
+                    run.error = Some(format!("Failed to run '{}': {}", command, e));
+                    run.results.push(ValidationCommandResult {
+                        command: command.clone(),
+                        exit_code: None,
+                        passed: false,
+                        duration_ms,
+                        stdout: String::new(),
+                        stderr: String::new(),
+                        error: Some(e),
+                    });
+                    break;
+                }
+            }
+        }
+
+        self.finish_run(run).await
+    }
+
+    /// Get a validation run by ID
+    pub async fn get_run(&self, run_id: &str) -> Result<CheckpointValidationRun, StoreError> {
+        let row = sqlx::query("SELECT * FROM checkpoint_validation_runs WHERE id = ?")
+            .bind(run_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StoreError::Sqlx)?
+            .ok_or(StoreError::NotFound)?;
+
+        row_to_run(&row)
+    }
+
+    /// List a checkpoint's validation runs, newest first
+    pub async fn list_runs(
+        &self,
+        checkpoint_id: &str,
+    ) -> Result<Vec<CheckpointValidationRun>, StoreError> {
+        let rows = sqlx::query(
+            "SELECT * FROM checkpoint_validation_runs WHERE checkpoint_id = ? ORDER BY started_at DESC",
+        )
+        .bind(checkpoint_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+
+        rows.iter().map(row_to_run).collect()
+    }
+
+    /// Gate state of every parallel group in an epic, in execution order
+    pub async fn parallel_group_gates(
+        &self,
+        epic_id: &str,
+    ) -> Result<Vec<ParallelGroupGate>, StoreError> {
+        let tasks: Vec<(String, Option<String>)> = sqlx::query_as(
+            "SELECT id, parallel_group FROM tasks WHERE epic_id = ? ORDER BY position, created_at",
+        )
+        .bind(epic_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+
+        let checkpoints = ExecutionTracker::new(self.pool.clone())
+            .get_epic_checkpoints(epic_id)
+            .await?;
+
+        Ok(compute_group_gates(&tasks, &checkpoints))
+    }
+
+    /// Incomplete checkpoints that must pass before a task's parallel group
+    /// may start. Tasks outside any parallel group are never gated.
+    pub async fn blocking_checkpoints(&self, task_id: &str) -> Result<Vec<String>, StoreError> {
+        let task: Option<(Option<String>, Option<String>)> =
+            sqlx::query_as("SELECT epic_id, parallel_group FROM tasks WHERE id = ?")
+                .bind(task_id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(StoreError::Sqlx)?;
+
+        let Some((Some(epic_id), Some(group))) = task else {
+            return Ok(Vec::new());
+        };
+
+        Ok(self
+            .parallel_group_gates(&epic_id)
+            .await?
+            .into_iter()
+            .find(|gate| gate.group == group)
+            .map(|gate| gate.blocked_by)
+            .unwrap_or_default())
+    }
+
+    async fn finish_run(
+        &self,
+        mut run: CheckpointValidationRun,
+    ) -> Result<CheckpointValidationRun, StoreError> {
+        run.completed_at = Some(Utc::now());
+
+        sqlx::query(
+            "UPDATE checkpoint_validation_runs SET status = ?, results = ?, error = ?, completed_at = ? WHERE id = ?",
+        )
+        .bind(run.status)
+        .bind(serde_json::to_string(&run.results)?)
+        .bind(&run.error)
+        .bind(run.completed_at)
+        .bind(&run.id)
+        .execute(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+
+        let tracker = ExecutionTracker::new(self.pool.clone());
+        let checkpoint = tracker.get_checkpoint(&run.checkpoint_id).await?;
+        tracker
+            .append_progress(AppendProgressInput {
+                task_id: checkpoint.after_task_id.clone(),
+                entry_type: ValidationEntryType::Checkpoint,
+                content: history_entry(&checkpoint, &run),
+                author: RUNNER_AUTHOR.to_string(),
+            })
+            .await?;
+
+        if run.status == CheckpointRunStatus::Passed && !checkpoint.completed {
+            tracker.complete_checkpoint(&checkpoint.id).await?;
+        }
+
+        Ok(run)
+    }
+}
+
+/// Work out which parallel groups are blocked by incomplete checkpoints.
+///
+/// Groups are ordered by the position of their first task. A checkpoint
+/// placed after a task blocks every group that starts later in the epic,
+/// other than the group that task belongs to.
+pub fn compute_group_gates(
+    tasks: &[(String, Option<String>)],
+    checkpoints: &[ExecutionCheckpoint],
+) -> Vec<ParallelGroupGate> {
+    let task_index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(idx, (id, _))| (id.as_str(), idx))
+        .collect();
+
+    let mut gates: Vec<(usize, ParallelGroupGate)> = Vec::new();
+    for (idx, (task_id, group)) in tasks.iter().enumerate() {
+        let Some(group) = group else { continue };
+        match gates.iter_mut().find(|(_, gate)| &gate.group == group) {
+            Some((_, gate)) => gate.task_ids.push(task_id.clone()),
+            None => gates.push((
+                idx,
+                ParallelGroupGate {
+                    group: group.clone(),
+                    task_ids: vec![task_id.clone()],
+                    open: true,
+                    blocked_by: Vec::new(),
+                },
+            )),
+        }
+    }
+
+    for checkpoint in checkpoints.iter().filter(|c| !c.completed) {
+        let Some(&after_idx) = task_index.get(checkpoint.after_task_id.as_str()) else {
+            continue;
+        };
+        let after_group = tasks[after_idx].1.as_deref();
+        for (first_idx, gate) in gates.iter_mut() {
+            if *first_idx > after_idx && after_group != Some(gate.group.as_str()) {
+                gate.open = false;
+                gate.blocked_by.push(checkpoint.id.clone());
+            }
+        }
+    }
+
+    gates.into_iter().map(|(_, gate)| gate).collect()
+}
+
+/// Keep the last [`MAX_LOG_CHARS`] of a log, where failures are reported
+fn log_tail(log: &str) -> String {
+    if log.len() <= MAX_LOG_CHARS {
+        return log.to_string();
+    }
+    let mut start = log.len() - MAX_LOG_CHARS;
+    while !log.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("[truncated {} bytes]…{}", start, &log[start..])
+}
+
+/// Summarize a finished run for the validation history
+fn history_entry(checkpoint: &ExecutionCheckpoint, run: &CheckpointValidationRun) -> String {
+    let verdict = match run.status {
+        CheckpointRunStatus::Passed => "passed",
+        CheckpointRunStatus::Failed => "failed",
+        CheckpointRunStatus::Error | CheckpointRunStatus::Running => "errored",
+    };
+    let mut content = format!(
+        "Checkpoint validation {} (run {}): {}",
+        verdict, run.id, checkpoint.message
+    );
+
+    for result in &run.results {
+        let outcome = match result.exit_code {
+            Some(code) => format!("exit {}", code),
+            None => "not run".to_string(),
+        };
+        content.push_str(&format!(
+            "\n- `{}`: {} ({} ms)",
+            result.command, outcome, result.duration_ms
+        ));
+
+        if !result.passed {
+            let log = if result.stderr.trim().is_empty() {
+                &result.stdout
+            } else {
+                &result.stderr
+            };
+            let lines: Vec<&str> = log.lines().collect();
+            let tail = &lines[lines.len().saturating_sub(HISTORY_LOG_LINES)..];
+            if !tail.is_empty() {
+                content.push_str(&format!("\n```\n{}\n```", tail.join("\n")));
+            }
+        }
+    }
+
+    if let Some(error) = &run.error {
+        content.push_str(&format!("\nError: {}", error));
+    }
+    content
+}
+
+fn row_to_run(row: &sqlx::sqlite::SqliteRow) -> Result<CheckpointValidationRun, StoreError> {
+    use sqlx::Row;
+
+    let commands: String = row.try_get("commands")?;
+    let results: String = row.try_get("results")?;
+
+    Ok(CheckpointValidationRun {
+        id: row.try_get("id")?,
+        checkpoint_id: row.try_get("checkpoint_id")?,
+        epic_id: row.try_get("epic_id")?,
+        sandbox_id: row.try_get("sandbox_id")?,
+        status: row.try_get("status")?,
+        commands: serde_json::from_str(&commands)?,
+        results: serde_json::from_str(&results)?,
+        error: row.try_get("error")?,
+        started_at: row.try_get("started_at")?,
+        completed_at: row.try_get("completed_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution_tracker::CheckpointType;
+
+    fn checkpoint(id: &str, after_task_id: &str, completed: bool) -> ExecutionCheckpoint {
+        ExecutionCheckpoint {
+            id: id.to_string(),
+            epic_id: "epic-1".to_string(),
+            after_task_id: after_task_id.to_string(),
+            checkpoint_type: CheckpointType::Test,
+            message: "Run tests?".to_string(),
+            required_validation: Vec::new(),
+            completed,
+            completed_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn task(id: &str, group: Option<&str>) -> (String, Option<String>) {
+        (id.to_string(), group.map(str::to_string))
+    }
+
+    #[test]
+    fn test_compute_group_gates() {
+        let tasks = vec![
+            task("t1", Some("a")),
+            task("t2", Some("a")),
+            task("t3", None),
+            task("t4", Some("b")),
+            task("t5", Some("c")),
+        ];
+
+        let gates = compute_group_gates(&tasks, &[]);
+        assert_eq!(gates.len(), 3);
+        assert!(gates.iter().all(|g| g.open));
+        assert_eq!(gates[0].task_ids, vec!["t1", "t2"]);
+
+        // A checkpoint inside group "a" blocks later groups but not "a" itself
+        let gates = compute_group_gates(&tasks, &[checkpoint("cp-a", "t1", false)]);
+        assert!(gates[0].open);
+        assert_eq!(gates[1].blocked_by, vec!["cp-a"]);
+        assert_eq!(gates[2].blocked_by, vec!["cp-a"]);
+
+        // Checkpoints after ungrouped tasks gate what follows them
+        let gates = compute_group_gates(
+            &tasks,
+            &[
+                checkpoint("cp-a", "t2", true),
+                checkpoint("cp-mid", "t3", false),
+                checkpoint("cp-b", "t4", false),
+            ],
+        );
+        assert!(gates[0].open);
+        assert_eq!(gates[1].blocked_by, vec!["cp-mid"]);
+        assert_eq!(gates[2].blocked_by, vec!["cp-mid", "cp-b"]);
+        assert!(!gates[2].open);
+    }
+
+    struct FakeExecutor;
+
+    #[async_trait]
+    impl ValidationCommandExecutor for FakeExecutor {
+        async fn execute(&self, command: &str) -> Result<CommandOutput, String> {
+            match command {
+                "cargo test" => Ok(CommandOutput {
+                    exit_code: 0,
+                    stdout: "test result: ok".to_string(),
+                    stderr: String::new(),
+                }),
+                "cargo clippy" => Ok(CommandOutput {
+                    exit_code: 1,
+                    stdout: String::new(),
+                    stderr: "error: unused variable `x`".to_string(),
+                }),
+                _ => Err("sandbox is not running".to_string()),
+            }
+        }
+    }
+
+    async fn setup_pool() -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        for statement in [
+            "CREATE TABLE tasks (
+                id TEXT PRIMARY KEY,
+                epic_id TEXT,
+                parallel_group TEXT,
+                position INTEGER NOT NULL DEFAULT 0,
+                validation_history TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                updated_at TEXT
+            )",
+            "CREATE TABLE execution_checkpoints (
+                id TEXT PRIMARY KEY,
+                epic_id TEXT NOT NULL,
+                after_task_id TEXT NOT NULL,
+                checkpoint_type TEXT,
+                message TEXT NOT NULL,
+                required_validation TEXT,
+                completed BOOLEAN NOT NULL DEFAULT FALSE,
+                completed_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            )",
+            "CREATE TABLE validation_entries (
+                id TEXT PRIMARY KEY,
+                task_id TEXT NOT NULL,
+                timestamp TEXT NOT NULL,
+                entry_type TEXT,
+                content TEXT NOT NULL,
+                author TEXT NOT NULL
+            )",
+            "CREATE TABLE checkpoint_validation_runs (
+                id TEXT PRIMARY KEY,
+                checkpoint_id TEXT NOT NULL,
+                epic_id TEXT NOT NULL,
+                sandbox_id TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'running',
+                commands TEXT NOT NULL,
+                results TEXT NOT NULL DEFAULT '[]',
+                error TEXT,
+                started_at TEXT NOT NULL,
+                completed_at TEXT
+            )",
+            "INSERT INTO tasks (id, epic_id, parallel_group, position) VALUES
+                ('t1', 'epic-1', 'a', 1), ('t2', 'epic-1', 'b', 2)",
+            "INSERT INTO execution_checkpoints (id, epic_id, after_task_id, checkpoint_type, message)
+                VALUES ('cp-1', 'epic-1', 't1', 'test', 'Run tests?')",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_run_records_outcome_and_gates_group() {
+        let pool = setup_pool().await;
+        let runner = CheckpointRunner::new(pool.clone());
+
+        assert_eq!(
+            runner.blocking_checkpoints("t2").await.unwrap(),
+            vec!["cp-1"]
+        );
+        assert!(runner.blocking_checkpoints("t1").await.unwrap().is_empty());
+        assert!(runner
+            .start_run("cp-1", "sandbox-1", vec!["  ".to_string()])
+            .await
+            .is_err());
+
+        // A failing command fails the run and keeps the group gated
+        let run = runner
+            .start_run(
+                "cp-1",
+                "sandbox-1",
+                vec!["cargo test".to_string(), "cargo clippy".to_string()],
+            )
+            .await
+            .unwrap();
+        assert!(runner
+            .start_run("cp-1", "sandbox-1", vec!["cargo test".to_string()])
+            .await
+            .is_err());
+        let run = runner.execute_run(run, &FakeExecutor).await.unwrap();
+        assert_eq!(run.status, CheckpointRunStatus::Failed);
+        assert_eq!(run.results.len(), 2);
+        assert!(run.results[0].passed);
+        assert_eq!(run.results[1].exit_code, Some(1));
+        assert_eq!(
+            runner.blocking_checkpoints("t2").await.unwrap(),
+            vec!["cp-1"]
+        );
+
+        let history = ExecutionTracker::new(pool.clone())
+            .get_task_validation_history("t1")
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].entry_type, ValidationEntryType::Checkpoint);
+        assert!(history[0].content.contains("validation failed"));
+        assert!(history[0].content.contains("unused variable"));
+
+        // An unreachable sandbox stops the run with an error
+        let run = runner
+            .start_run(
+                "cp-1",
+                "sandbox-1",
+                vec!["make build".to_string(), "cargo test".to_string()],
+            )
+            .await
+            .unwrap();
+        let run = runner.execute_run(run, &FakeExecutor).await.unwrap();
+        assert_eq!(run.status, CheckpointRunStatus::Error);
+        assert_eq!(run.results.len(), 1);
+
+        // A passing run completes the checkpoint and opens the next group
+        let run = runner
+            .start_run("cp-1", "sandbox-1", vec!["cargo test".to_string()])
+            .await
+            .unwrap();
+        let run = runner.execute_run(run, &FakeExecutor).await.unwrap();
+        assert_eq!(run.status, CheckpointRunStatus::Passed);
+        assert!(runner.blocking_checkpoints("t2").await.unwrap().is_empty());
+
+        let runs = runner.list_runs("cp-1").await.unwrap();
+        assert_eq!(runs.len(), 3);
+        let stored = runner.get_run(&run.id).await.unwrap();
+        assert_eq!(stored.status, CheckpointRunStatus::Passed);
+        assert!(stored.completed_at.is_some());
+    }
+
+    #[test]
+    fn test_log_tail_keeps_end() {
+        assert_eq!(log_tail("short"), "short");
+
+        let log = format!("{}END", "x".repeat(MAX_LOG_CHARS));
+        let tail = log_tail(&log);
+        assert!(tail.starts_with("[truncated 3 bytes]…"));
+        assert!(tail.ends_with("END"));
+    }
+}
@@ -0,0 +1,515 @@
+// ABOUTME: Research citation tracking from ideate sessions into generated PRDs
+// ABOUTME: Records which stored research source informed which PRD section so claims can be traced to evidence
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashSet;
+
+use crate::error::{IdeateError, Result};
+use crate::traceability::TraceLink;
+
+/// Longest claim excerpt stored with a citation
+const MAX_EXCERPT_CHARS: usize = 300;
+
+/// Shortest source name matched in PRD text, so names like "Go" don't cite everything
+const MIN_LABEL_CHARS: usize = 4;
+
+/// Section used for text before the first heading
+const PREAMBLE_SECTION: &str = "Introduction";
+
+/// Kind of research a citation points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CitationSourceType {
+    /// Web page stored from a research search
+    WebSource,
+    Competitor,
+    SimilarProject,
+    Reference,
+    /// Lesson learned from similar projects
+    Lesson,
+    /// UI/UX pattern extracted during research
+    Pattern,
+    /// Free-form research finding
+    Finding,
+}
+
+/// Research a PRD can cite
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CitableSource {
+    pub source_type: CitationSourceType,
+    /// ID of the stored row, for web sources
+    pub source_id: Option<String>,
+    pub label: String,
+    pub url: Option<String>,
+}
+
+/// A citation reported by the PRD generator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CitationInput {
+    /// PRD section heading the source informed
+    pub section: String,
+    pub source_type: CitationSourceType,
+    pub source_id: Option<String>,
+    /// Source name, e.g. a competitor or page title
+    pub label: String,
+    pub url: Option<String>,
+    /// The claim in the PRD that the source supports
+    pub excerpt: Option<String>,
+}
+
+/// A PRD section traced back to a research source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrdCitation {
+    pub id: String,
+    pub prd_id: String,
+    pub session_id: Option<String>,
+    pub section: String,
+    pub source_type: CitationSourceType,
+    pub source_id: Option<String>,
+    pub label: String,
+    pub url: Option<String>,
+    pub excerpt: Option<String>,
+    pub link: TraceLink,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Storage and detection of PRD citations
+pub struct CitationTracker {
+    pool: SqlitePool,
+}
+
+impl CitationTracker {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Everything a session's PRD can cite: stored web sources plus the
+    /// competitors, similar projects and reference links saved as research
+    pub async fn session_sources(&self, session_id: &str) -> Result<Vec<CitableSource>> {
+        let mut sources = Vec::new();
+
+        let rows =
+            sqlx::query("SELECT id, title, url FROM ideate_research_sources WHERE session_id = ?")
+                .bind(session_id)
+                .fetch_all(&self.pool)
+                .await?;
+        for row in rows {
+            sources.push(CitableSource {
+                source_type: CitationSourceType::WebSource,
+                source_id: Some(row.try_get("id")?),
+                label: row.try_get("title")?,
+                url: Some(row.try_get("url")?),
+            });
+        }
+
+        let research = sqlx::query(
+            "SELECT competitors, similar_projects, reference_links FROM ideate_research WHERE session_id = ?",
+        )
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+        for row in research {
+            for (column, source_type, label_key) in [
+                ("competitors", CitationSourceType::Competitor, "name"),
+                (
+                    "similar_projects",
+                    CitationSourceType::SimilarProject,
+                    "name",
+                ),
+                ("reference_links", CitationSourceType::Reference, "title"),
+            ] {
+                let json: Option<String> = row.try_get(column)?;
+                sources.extend(json_sources(json.as_deref(), source_type, label_key));
+            }
+        }
+
+        Ok(sources)
+    }
+
+    /// Record citations for a PRD generated from a session.
+    ///
+    /// Citations the generator reported are stored as explicit; every other
+    /// session source mentioned by name or URL in a section is stored as
+    /// inferred. Replaces any citations previously recorded for the PRD.
+    pub async fn record_for_prd(
+        &self,
+        prd_id: &str,
+        session_id: &str,
+        markdown: &str,
+        explicit: Vec<CitationInput>,
+    ) -> Result<Vec<PrdCitation>> {
+        for citation in &explicit {
+            if citation.section.trim().is_empty() || citation.label.trim().is_empty() {
+                return Err(IdeateError::ValidationError(
+                    "Citations need a section and a label".to_string(),
+                ));
+            }
+        }
+
+        let sources = self.session_sources(session_id).await?;
+        let mut citations: Vec<(CitationInput, TraceLink)> = explicit
+            .into_iter()
+            .map(|c| (c, TraceLink::Explicit))
+            .collect();
+
+        for inferred in detect_citations(markdown, &sources) {
+            let duplicate = citations.iter().any(|(c, _)| {
+                c.section.eq_ignore_ascii_case(&inferred.section)
+                    && c.source_type == inferred.source_type
+                    && c.label.eq_ignore_ascii_case(&inferred.label)
+            });
+            if !duplicate {
+                citations.push((inferred, TraceLink::Inferred));
+            }
+        }
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM prd_citations WHERE prd_id = ?")
+            .bind(prd_id)
+            .execute(&mut *tx)
+            .await?;
+        for (citation, link) in &citations {
+            sqlx::query(
+                r#"
+                INSERT INTO prd_citations (
+                    id, prd_id, session_id, section, source_type, source_id, label, url, excerpt, link, created_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(nanoid::nanoid!())
+            .bind(prd_id)
+            .bind(session_id)
+            .bind(citation.section.trim())
+            .bind(citation.source_type)
+            .bind(&citation.source_id)
+            .bind(citation.label.trim())
+            .bind(&citation.url)
+            .bind(citation.excerpt.as_deref().map(truncate_excerpt))
+            .bind(link_str(*link))
+            .bind(Utc::now())
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+
+        self.list_for_prd(prd_id).await
+    }
+
+    /// Citations for a PRD, grouped by section in the order they were recorded
+    pub async fn list_for_prd(&self, prd_id: &str) -> Result<Vec<PrdCitation>> {
+        let rows = sqlx::query("SELECT * FROM prd_citations WHERE prd_id = ? ORDER BY rowid")
+            .bind(prd_id)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let mut citations: Vec<PrdCitation> =
+            rows.iter().map(row_to_citation).collect::<Result<_>>()?;
+        // Stable sort keeps document order within each section
+        let mut section_order: Vec<String> = Vec::new();
+        for citation in &citations {
+            if !section_order.contains(&citation.section) {
+                section_order.push(citation.section.clone());
+            }
+        }
+        citations.sort_by_key(|c| section_order.iter().position(|s| s == &c.section));
+        Ok(citations)
+    }
+}
+
+/// Find each source mentioned in each section of a PRD.
+///
+/// A source is mentioned when a line contains its URL, or its name as a whole
+/// word (case-insensitive). Each source is cited at most once per section,
+/// with the first mentioning line as the excerpt.
+pub fn detect_citations(markdown: &str, sources: &[CitableSource]) -> Vec<CitationInput> {
+    let mut citations = Vec::new();
+    let mut cited: HashSet<(String, usize)> = HashSet::new();
+    let mut section = PREAMBLE_SECTION.to_string();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || trimmed.is_empty() {
+            continue;
+        }
+        if let Some(heading) = heading_text(trimmed) {
+            section = heading;
+            continue;
+        }
+
+        for (idx, source) in sources.iter().enumerate() {
+            if cited.contains(&(section.clone(), idx)) || !mentions(trimmed, source) {
+                continue;
+            }
+            cited.insert((section.clone(), idx));
+            citations.push(CitationInput {
+                section: section.clone(),
+                source_type: source.source_type,
+                source_id: source.source_id.clone(),
+                label: source.label.clone(),
+                url: source.url.clone(),
+                excerpt: Some(truncate_excerpt(
+                    trimmed.trim_start_matches(['-', '*', '>', ' ']),
+                )),
+            });
+        }
+    }
+
+    citations
+}
+
+fn heading_text(line: &str) -> Option<String> {
+    let text = line.strip_prefix('#')?.trim_start_matches('#');
+    text.starts_with(' ')
+        .then(|| text.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+fn mentions(line: &str, source: &CitableSource) -> bool {
+    if source
+        .url
+        .as_deref()
+        .is_some_and(|url| !url.is_empty() && line.contains(url))
+    {
+        return true;
+    }
+
+    let label = source.label.trim();
+    if label.chars().count() < MIN_LABEL_CHARS {
+        return false;
+    }
+    let haystack = line.to_lowercase();
+    let needle = label.to_lowercase();
+    haystack.match_indices(&needle).any(|(start, _)| {
+        let before = haystack[..start].chars().next_back();
+        let after = haystack[start + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+fn json_sources(
+    json: Option<&str>,
+    source_type: CitationSourceType,
+    label_key: &str,
+) -> Vec<CitableSource> {
+    let Some(items) = json.and_then(|j| serde_json::from_str::<Vec<serde_json::Value>>(j).ok())
+    else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let label = item.get(label_key)?.as_str()?.trim();
+            (!label.is_empty()).then(|| CitableSource {
+                source_type,
+                source_id: None,
+                label: label.to_string(),
+                url: item
+                    .get("url")
+                    .and_then(|u| u.as_str())
+                    .filter(|u| !u.is_empty())
+                    .map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+fn truncate_excerpt(text: &str) -> String {
+    let text = text.trim();
+    if text.chars().count() <= MAX_EXCERPT_CHARS {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(MAX_EXCERPT_CHARS).collect();
+    format!("{}…", truncated)
+}
+
+fn link_str(link: TraceLink) -> &'static str {
+    match link {
+        TraceLink::Explicit => "explicit",
+        TraceLink::Inferred => "inferred",
+    }
+}
+
+fn row_to_citation(row: &sqlx::sqlite::SqliteRow) -> Result<PrdCitation> {
+    let link: String = row.try_get("link")?;
+
+    Ok(PrdCitation {
+        id: row.try_get("id")?,
+        prd_id: row.try_get("prd_id")?,
+        session_id: row.try_get("session_id")?,
+        section: row.try_get("section")?,
+        source_type: row.try_get("source_type")?,
+        source_id: row.try_get("source_id")?,
+        label: row.try_get("label")?,
+        url: row.try_get("url")?,
+        excerpt: row.try_get("excerpt")?,
+        link: if link == "explicit" {
+            TraceLink::Explicit
+        } else {
+            TraceLink::Inferred
+        },
+        created_at: row.try_get("created_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(source_type: CitationSourceType, label: &str, url: Option<&str>) -> CitableSource {
+        CitableSource {
+            source_type,
+            source_id: None,
+            label: label.to_string(),
+            url: url.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_detect_citations_per_section() {
+        let markdown = "\
+Intro mentions Linear once.
+
+## Competitive Landscape
+- Linear has fast keyboard navigation
+- Linear again, cited only once per section
+- See https://example.com/jira-review for Jira pain points
+
+## Features
+```
+Linear inside a code block is ignored
+```
+Offline mode, unlike Jira.
+The Go SDK is optional.
+Nonlinear workflows are not a mention.
+";
+        let sources = vec![
+            source(
+                CitationSourceType::Competitor,
+                "Linear",
+                Some("https://linear.app"),
+            ),
+            source(
+                CitationSourceType::WebSource,
+                "Why teams leave Jira",
+                Some("https://example.com/jira-review"),
+            ),
+            source(CitationSourceType::Competitor, "Jira", None),
+            source(CitationSourceType::Reference, "Go", None),
+        ];
+
+        let citations = detect_citations(markdown, &sources);
+        let found: Vec<(&str, &str)> = citations
+            .iter()
+            .map(|c| (c.section.as_str(), c.label.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Introduction", "Linear"),
+                ("Competitive Landscape", "Linear"),
+                ("Competitive Landscape", "Why teams leave Jira"),
+                ("Competitive Landscape", "Jira"),
+                ("Features", "Jira"),
+            ]
+        );
+        assert_eq!(
+            citations[1].excerpt.as_deref(),
+            Some("Linear has fast keyboard navigation")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_record_for_prd() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        for statement in [
+            "INSERT INTO projects (id, name, project_root) VALUES ('project-1', 'Project', '/tmp/project-1')",
+            "INSERT INTO ideate_sessions (id, project_id, initial_description, mode)
+                VALUES ('session-1', 'project-1', 'Issue tracker', 'guided')",
+            "INSERT INTO ideate_research (id, session_id, competitors)
+                VALUES ('research-1', 'session-1', '[{\"name\":\"Linear\",\"url\":\"https://linear.app\"}]')",
+            "INSERT INTO ideate_research_sources (id, session_id, provider, query, title, url, fetched_at)
+                VALUES ('source-1', 'session-1', 'brave', 'jira', 'Jira review', 'https://example.com/jira', '2026-01-01T00:00:00Z')",
+            "INSERT INTO prds (id, project_id, title, content_markdown) VALUES ('prd-00001', 'project-1', 'PRD', '')",
+        ] {
+            sqlx::query(statement).execute(&pool).await.unwrap();
+        }
+
+        let markdown = "## Overview\nFaster than Linear.\n\n## Risks\nUsers churn ([source](https://example.com/jira)).";
+        let tracker = CitationTracker::new(pool);
+        let citations = tracker
+            .record_for_prd(
+                "prd-00001",
+                "session-1",
+                markdown,
+                vec![CitationInput {
+                    section: "Overview".to_string(),
+                    source_type: CitationSourceType::Lesson,
+                    source_id: None,
+                    label: "Keyboard-first wins power users".to_string(),
+                    url: None,
+                    excerpt: Some("Faster than Linear.".to_string()),
+                }],
+            )
+            .await
+            .unwrap();
+
+        let found: Vec<(&str, CitationSourceType, TraceLink)> = citations
+            .iter()
+            .map(|c| (c.section.as_str(), c.source_type, c.link))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("Overview", CitationSourceType::Lesson, TraceLink::Explicit),
+                (
+                    "Overview",
+                    CitationSourceType::Competitor,
+                    TraceLink::Inferred
+                ),
+                ("Risks", CitationSourceType::WebSource, TraceLink::Inferred),
+            ]
+        );
+        assert_eq!(citations[2].source_id.as_deref(), Some("source-1"));
+
+        // Recording again replaces the previous citations
+        let citations = tracker
+            .record_for_prd(
+                "prd-00001",
+                "session-1",
+                "## Overview\nNothing cited.",
+                vec![],
+            )
+            .await
+            .unwrap();
+        assert!(citations.is_empty());
+    }
+
+    #[test]
+    fn test_json_sources_skip_unnamed_entries() {
+        let json = r#"[{"name":"Linear","url":"https://linear.app"},{"name":""},{"url":"x"}]"#;
+        let sources = json_sources(Some(json), CitationSourceType::Competitor, "name");
+        assert_eq!(
+            sources,
+            vec![source(
+                CitationSourceType::Competitor,
+                "Linear",
+                Some("https://linear.app")
+            )]
+        );
+        assert!(json_sources(Some("not json"), CitationSourceType::Competitor, "name").is_empty());
+    }
+}
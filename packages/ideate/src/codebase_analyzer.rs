@@ -1,11 +1,13 @@
 // ABOUTME: Codebase context analyzer for PRD generation
 // ABOUTME: Scans project files to identify patterns, frameworks, and reusable components
 
+use crate::codebase_cache::{CachedCodebaseAnalysis, CodebaseCache};
 use crate::error::{IdeateError, Result};
 use crate::types::IdeateSession;
+use orkee_git_utils::{changed_paths_since, get_working_tree_state, WorkingTreeState};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// A code pattern detected in the codebase
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -110,6 +112,95 @@ impl Default for CodebaseContext {
     }
 }
 
+/// An independently cacheable part of the codebase analysis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisStep {
+    Patterns,
+    ReusableComponents,
+    Architecture,
+    TechStack,
+    FileStructure,
+}
+
+impl AnalysisStep {
+    pub const ALL: [AnalysisStep; 5] = [
+        AnalysisStep::Patterns,
+        AnalysisStep::ReusableComponents,
+        AnalysisStep::Architecture,
+        AnalysisStep::TechStack,
+        AnalysisStep::FileStructure,
+    ];
+
+    /// Project-relative files and directories the step reads.
+    /// `None` means the step depends on the whole tree.
+    fn inputs(self) -> Option<&'static [&'static str]> {
+        match self {
+            AnalysisStep::Patterns | AnalysisStep::TechStack => {
+                Some(&["Cargo.toml", "package.json"])
+            }
+            AnalysisStep::ReusableComponents => Some(&["src/utils/mod.rs"]),
+            AnalysisStep::Architecture => {
+                Some(&["Cargo.toml", "src/api", "src/domain", "src/infrastructure"])
+            }
+            AnalysisStep::FileStructure => None,
+        }
+    }
+
+    /// Whether any of the changed paths could alter this step's result
+    pub fn is_affected_by(self, changed_paths: &[String]) -> bool {
+        if changed_paths.is_empty() {
+            return false;
+        }
+
+        match self.inputs() {
+            None => true,
+            Some(inputs) => changed_paths.iter().any(|path| {
+                inputs.iter().any(|input| {
+                    path == input
+                        || path
+                            .strip_prefix(input)
+                            .is_some_and(|rest| rest.starts_with('/'))
+                })
+            }),
+        }
+    }
+}
+
+/// How a cached analysis was used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheStatus {
+    /// Nothing changed since the cached analysis
+    Hit,
+    /// Only steps affected by changed paths were rerun
+    Incremental,
+    /// No usable cache entry; everything was analyzed
+    Miss,
+    /// The cache was bypassed at the caller's request
+    Refreshed,
+    /// The project isn't a git repository, so results can't be cached
+    Unavailable,
+}
+
+/// Cache details reported alongside an analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisCacheInfo {
+    pub status: CacheStatus,
+    pub head_commit: Option<String>,
+    /// Number of project paths that changed since the cached analysis
+    pub changed_paths: usize,
+    /// Steps that were (re)computed for this request
+    pub reanalyzed: Vec<AnalysisStep>,
+}
+
+/// Codebase context plus how it was produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CodebaseAnalysis {
+    pub context: CodebaseContext,
+    pub cache: AnalysisCacheInfo,
+}
+
 /// Codebase analyzer
 pub struct CodebaseAnalyzer {
     project_path: PathBuf,
@@ -127,20 +218,172 @@ impl CodebaseAnalyzer {
             self.project_path, session.id
         );
 
-        let context = CodebaseContext {
-            patterns: self.identify_patterns().await?,
-            similar_features: self
-                .find_similar_features(&session.initial_description)
-                .await?,
-            reusable_components: self.find_reusable_components().await?,
-            architecture_style: self.detect_architecture().await?,
-            tech_stack: self.detect_tech_stack().await?,
-            file_structure: self.analyze_file_structure().await?,
-        };
+        let mut context = CodebaseContext::default();
+        for step in AnalysisStep::ALL {
+            self.run_step(step, &mut context).await?;
+        }
+        context.similar_features = self
+            .find_similar_features(&session.initial_description)
+            .await?;
 
         Ok(context)
     }
 
+    /// Analyze the codebase for a session, reusing the cached analysis for the
+    /// project where possible
+    ///
+    /// The cache is keyed by HEAD commit and a hash of uncommitted files. When
+    /// either changed, only the steps whose inputs were touched are rerun.
+    /// `force_refresh` ignores any cached entry and analyzes everything.
+    pub async fn analyze_with_cache(
+        &self,
+        session: &IdeateSession,
+        cache: &CodebaseCache,
+        force_refresh: bool,
+    ) -> Result<CodebaseAnalysis> {
+        let Some(state) = get_working_tree_state(&self.project_path) else {
+            info!(
+                "{:?} is not a git repository; analyzing without cache",
+                self.project_path
+            );
+            return Ok(CodebaseAnalysis {
+                context: self.analyze_for_session(session).await?,
+                cache: AnalysisCacheInfo {
+                    status: CacheStatus::Unavailable,
+                    head_commit: None,
+                    changed_paths: 0,
+                    reanalyzed: AnalysisStep::ALL.to_vec(),
+                },
+            });
+        };
+
+        let project_key = self.cache_key();
+        let cached = if force_refresh {
+            None
+        } else {
+            // A broken cache shouldn't block analysis
+            cache.get(&project_key).await.unwrap_or_else(|e| {
+                warn!(
+                    "Ignoring codebase analysis cache for {}: {}",
+                    project_key, e
+                );
+                None
+            })
+        };
+
+        let mut context = CodebaseContext::default();
+        let mut status = if force_refresh {
+            CacheStatus::Refreshed
+        } else {
+            CacheStatus::Miss
+        };
+        let mut steps = AnalysisStep::ALL.to_vec();
+        let mut changed_count = 0;
+
+        if let Some(entry) = cached {
+            if entry.head_commit == state.head_commit && entry.dirty_hash == state.dirty_hash {
+                status = CacheStatus::Hit;
+                steps.clear();
+                context = entry.context;
+            } else if let Some(changed) = self.changed_paths(&entry, &state) {
+                status = CacheStatus::Incremental;
+                steps.retain(|step| step.is_affected_by(&changed));
+                changed_count = changed.len();
+                context = entry.context;
+            }
+        }
+
+        info!(
+            "Codebase analysis for {} at {}: {:?}, rerunning {:?}",
+            project_key, state.head_commit, status, steps
+        );
+
+        for step in &steps {
+            self.run_step(*step, &mut context).await?;
+        }
+
+        if status != CacheStatus::Hit {
+            let entry = CachedCodebaseAnalysis {
+                project_path: project_key.clone(),
+                head_commit: state.head_commit.clone(),
+                dirty_hash: state.dirty_hash,
+                dirty_paths: state.dirty_paths,
+                context: context.clone(),
+                analyzed_at: chrono::Utc::now().to_rfc3339(),
+            };
+            if let Err(e) = cache.store(&entry).await {
+                warn!(
+                    "Failed to cache codebase analysis for {}: {}",
+                    project_key, e
+                );
+            }
+        }
+
+        // Similar features depend on the session description, so they're never cached
+        context.similar_features = self
+            .find_similar_features(&session.initial_description)
+            .await?;
+
+        Ok(CodebaseAnalysis {
+            context,
+            cache: AnalysisCacheInfo {
+                status,
+                head_commit: Some(state.head_commit),
+                changed_paths: changed_count,
+                reanalyzed: steps,
+            },
+        })
+    }
+
+    /// Key identifying this project in the cache
+    pub fn cache_key(&self) -> String {
+        self.project_path
+            .canonicalize()
+            .unwrap_or_else(|_| self.project_path.clone())
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Paths that may differ between a cached analysis and the current tree:
+    /// everything committed since, plus anything uncommitted then or now.
+    /// `None` if the cached commit is no longer reachable.
+    fn changed_paths(
+        &self,
+        entry: &CachedCodebaseAnalysis,
+        state: &WorkingTreeState,
+    ) -> Option<Vec<String>> {
+        let mut changed = if entry.head_commit == state.head_commit {
+            Vec::new()
+        } else {
+            changed_paths_since(&self.project_path, &entry.head_commit)?
+        };
+        changed.extend(entry.dirty_paths.iter().cloned());
+        changed.extend(state.dirty_paths.iter().cloned());
+        changed.sort();
+        changed.dedup();
+
+        Some(changed)
+    }
+
+    /// Run a single analysis step, writing its result into `context`
+    async fn run_step(&self, step: AnalysisStep, context: &mut CodebaseContext) -> Result<()> {
+        match step {
+            AnalysisStep::Patterns => context.patterns = self.identify_patterns().await?,
+            AnalysisStep::ReusableComponents => {
+                context.reusable_components = self.find_reusable_components().await?
+            }
+            AnalysisStep::Architecture => {
+                context.architecture_style = self.detect_architecture().await?
+            }
+            AnalysisStep::TechStack => context.tech_stack = self.detect_tech_stack().await?,
+            AnalysisStep::FileStructure => {
+                context.file_structure = self.analyze_file_structure().await?
+            }
+        }
+
+        Ok(())
+    }
+
     /// Identify code patterns in the project
     async fn identify_patterns(&self) -> Result<Vec<Pattern>> {
         info!("Identifying patterns in project");
@@ -340,3 +583,35 @@ impl CodebaseAnalyzer {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(items: &[&str]) -> Vec<String> {
+        items.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_step_inputs_match_changed_paths() {
+        let changed = paths(&["Cargo.toml"]);
+        assert!(AnalysisStep::Patterns.is_affected_by(&changed));
+        assert!(AnalysisStep::Architecture.is_affected_by(&changed));
+        assert!(!AnalysisStep::ReusableComponents.is_affected_by(&changed));
+
+        let changed = paths(&["src/api/handlers.rs"]);
+        assert!(AnalysisStep::Architecture.is_affected_by(&changed));
+        assert!(!AnalysisStep::TechStack.is_affected_by(&changed));
+
+        // Prefix matches only on directory boundaries
+        let changed = paths(&["src/apiary.rs", "docs/Cargo.toml"]);
+        assert!(!AnalysisStep::Architecture.is_affected_by(&changed));
+        assert!(!AnalysisStep::Patterns.is_affected_by(&changed));
+    }
+
+    #[test]
+    fn test_whole_tree_steps_rerun_on_any_change() {
+        assert!(AnalysisStep::FileStructure.is_affected_by(&paths(&["README.md"])));
+        assert!(!AnalysisStep::FileStructure.is_affected_by(&[]));
+    }
+}
@@ -0,0 +1,126 @@
+// ABOUTME: SQLite-backed cache for codebase analysis results
+// ABOUTME: Stores one analysis per project, keyed by HEAD commit and uncommitted-file hash
+
+use crate::codebase_analyzer::CodebaseContext;
+use crate::error::{IdeateError, Result};
+use sqlx::{Row, SqlitePool};
+use tracing::{error, info, warn};
+
+/// A cached codebase analysis and the working tree state it was computed from
+#[derive(Debug, Clone)]
+pub struct CachedCodebaseAnalysis {
+    pub project_path: String,
+    pub head_commit: String,
+    pub dirty_hash: String,
+    /// Uncommitted paths at analysis time, needed to detect what changed since
+    pub dirty_paths: Vec<String>,
+    pub context: CodebaseContext,
+    pub analyzed_at: String,
+}
+
+/// Persistence for codebase analysis results
+pub struct CodebaseCache {
+    pool: SqlitePool,
+}
+
+impl CodebaseCache {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Get the cached analysis for a project, if any
+    ///
+    /// Entries that no longer deserialize (e.g. after a context format change)
+    /// are treated as missing.
+    pub async fn get(&self, project_path: &str) -> Result<Option<CachedCodebaseAnalysis>> {
+        let row = sqlx::query(
+            r#"
+            SELECT project_path, head_commit, dirty_hash, dirty_paths, context, analyzed_at
+            FROM codebase_analysis_cache
+            WHERE project_path = ?
+            "#,
+        )
+        .bind(project_path)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to read codebase analysis cache: {}", e);
+            IdeateError::Database(e)
+        })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let context = match serde_json::from_str(&row.get::<String, _>("context")) {
+            Ok(context) => context,
+            Err(e) => {
+                warn!(
+                    "Discarding unreadable codebase analysis cache for {}: {}",
+                    project_path, e
+                );
+                return Ok(None);
+            }
+        };
+
+        Ok(Some(CachedCodebaseAnalysis {
+            project_path: row.get("project_path"),
+            head_commit: row.get("head_commit"),
+            dirty_hash: row.get("dirty_hash"),
+            dirty_paths: serde_json::from_str(&row.get::<String, _>("dirty_paths"))
+                .unwrap_or_default(),
+            context,
+            analyzed_at: row.get("analyzed_at"),
+        }))
+    }
+
+    /// Store an analysis, replacing any previous entry for the project
+    pub async fn store(&self, entry: &CachedCodebaseAnalysis) -> Result<()> {
+        info!(
+            "Caching codebase analysis for {} at {}",
+            entry.project_path, entry.head_commit
+        );
+
+        sqlx::query(
+            r#"
+            INSERT INTO codebase_analysis_cache (
+                project_path, head_commit, dirty_hash, dirty_paths, context, analyzed_at
+            ) VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT(project_path) DO UPDATE SET
+                head_commit = excluded.head_commit,
+                dirty_hash = excluded.dirty_hash,
+                dirty_paths = excluded.dirty_paths,
+                context = excluded.context,
+                analyzed_at = excluded.analyzed_at
+            "#,
+        )
+        .bind(&entry.project_path)
+        .bind(&entry.head_commit)
+        .bind(&entry.dirty_hash)
+        .bind(serde_json::to_string(&entry.dirty_paths)?)
+        .bind(serde_json::to_string(&entry.context)?)
+        .bind(&entry.analyzed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to write codebase analysis cache: {}", e);
+            IdeateError::Database(e)
+        })?;
+
+        Ok(())
+    }
+
+    /// Remove the cached analysis for a project; returns whether one existed
+    pub async fn invalidate(&self, project_path: &str) -> Result<bool> {
+        let result = sqlx::query("DELETE FROM codebase_analysis_cache WHERE project_path = ?")
+            .bind(project_path)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!("Failed to invalidate codebase analysis cache: {}", e);
+                IdeateError::Database(e)
+            })?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -3,6 +3,7 @@
 
 use crate::chat::QuestionCategory;
 use crate::error::{IdeateError, Result};
+use crate::question_banks::QuestionBankManager;
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
@@ -10,7 +11,7 @@ use tracing::{error, info};
 
 /// Type of question being asked
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, sqlx::Type)]
-#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 #[serde(rename_all = "lowercase")]
 pub enum QuestionType {
     Open,
@@ -179,6 +180,18 @@ impl DiscoveryManager {
         let context = self.get_session_context(session_id).await?;
         let question_number = context.total_questions_asked;
 
+        // Sessions started with a custom question bank follow it instead
+        if let Some(bank) = QuestionBankManager::new(self.pool.clone())
+            .for_session(session_id)
+            .await?
+        {
+            let (question, applicable) = bank.next_question(&context.answers);
+            let question = question.with_progress(question_number as i32 + 1, applicable as i32);
+            self.save_question(session_id, &question, question_number as i32)
+                .await?;
+            return Ok(question);
+        }
+
         // Estimate total questions (minimum 7, maximum based on discovery status)
         let estimated_total = 10;
 
@@ -0,0 +1,980 @@
+// ABOUTME: Imports existing README/PRD documents to seed an ideate session
+// ABOUTME: Parses Markdown or Docx headings into overview, UX, and technical sections
+
+use crate::error::{IdeateError, Result};
+use crate::manager::IdeateManager;
+use crate::types::{
+    CreateIdeateSessionInput, IdeateMode, IdeateOverview, IdeateSession, IdeateTechnical, IdeateUX,
+};
+use chrono::Utc;
+use flate2::read::DeflateDecoder;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Largest document accepted for import
+pub const MAX_DOCUMENT_BYTES: usize = 5 * 1024 * 1024;
+
+/// Longest text stored in a single imported section field
+const MAX_FIELD_CHARS: usize = 10_000;
+
+/// Longest initial description derived from a document
+const MAX_DESCRIPTION_CHARS: usize = 1_000;
+
+/// Files looked up in a repository, in order of preference
+const REPO_DOCUMENT_CANDIDATES: &[&str] = &[
+    "PRD.md",
+    "docs/PRD.md",
+    "prd.md",
+    "README.md",
+    "Readme.md",
+    "readme.md",
+    "README.markdown",
+    "docs/README.md",
+];
+
+/// Where a document to import comes from
+#[derive(Debug, Clone)]
+pub enum DocumentSource {
+    Markdown {
+        name: String,
+        content: String,
+    },
+    Docx {
+        name: String,
+        bytes: Vec<u8>,
+    },
+    /// A repository directory (README/PRD is located inside) or a file path
+    Path(PathBuf),
+}
+
+/// A heading and the text below it, up to the next heading
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentSection {
+    pub heading: String,
+    pub level: usize,
+    pub body: String,
+}
+
+/// Markdown document split into headed sections
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParsedDocument {
+    /// Leading level-1 heading, if the document starts with one
+    pub title: Option<String>,
+    /// First paragraph of text before the first section heading
+    pub summary: Option<String>,
+    pub sections: Vec<DocumentSection>,
+}
+
+/// Session field that a document heading maps to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SectionTarget {
+    ProblemStatement,
+    TargetAudience,
+    ValueProposition,
+    UiConsiderations,
+    UxPrinciples,
+    TechStack,
+}
+
+/// Heading keywords per target, checked in order so specific phrases win over
+/// broad ones (e.g. "design principles" before "design")
+const HEADING_KEYWORDS: &[(SectionTarget, &[&str])] = &[
+    (
+        SectionTarget::TargetAudience,
+        &[
+            "target audience",
+            "audience",
+            "target users",
+            "who is this for",
+            "who it s for",
+            "customers",
+        ],
+    ),
+    (
+        SectionTarget::ValueProposition,
+        &[
+            "value proposition",
+            "benefits",
+            "why use",
+            "key features",
+            "features",
+            "highlights",
+        ],
+    ),
+    (
+        SectionTarget::UxPrinciples,
+        &["ux principles", "design principles", "principles"],
+    ),
+    (
+        SectionTarget::UiConsiderations,
+        &[
+            "user experience",
+            "user interface",
+            "ux",
+            "ui",
+            "design",
+            "usage",
+            "user flows",
+            "user flow",
+            "workflow",
+            "screens",
+            "personas",
+        ],
+    ),
+    (
+        SectionTarget::TechStack,
+        &[
+            "architecture",
+            "technical",
+            "tech stack",
+            "technology",
+            "technologies",
+            "stack",
+            "implementation",
+            "installation",
+            "requirements",
+            "dependencies",
+            "api",
+            "data model",
+            "infrastructure",
+            "development",
+            "building",
+        ],
+    ),
+    (
+        SectionTarget::ProblemStatement,
+        &[
+            "problem statement",
+            "problem",
+            "overview",
+            "introduction",
+            "about",
+            "background",
+            "motivation",
+            "summary",
+            "description",
+            "goals",
+        ],
+    ),
+];
+
+/// Section content extracted from a document, ready to save on a session
+#[derive(Debug, Clone, Default)]
+pub struct ImportedSections {
+    pub overview: Option<IdeateOverview>,
+    pub ux: Option<IdeateUX>,
+    pub technical: Option<IdeateTechnical>,
+    /// Headings that did not map to any section
+    pub unmatched_headings: Vec<String>,
+}
+
+/// Input for starting a session from a document
+#[derive(Debug, Clone)]
+pub struct StartFromDocumentInput {
+    pub project_id: String,
+    pub mode: IdeateMode,
+    pub template_id: Option<String>,
+    pub research_tools_enabled: bool,
+    pub source: DocumentSource,
+}
+
+/// Result of starting a session from a document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentImportResult {
+    pub session: IdeateSession,
+    /// File name of the imported document
+    pub source_name: String,
+    /// Sections that were pre-filled (overview, ux, technical)
+    pub imported_sections: Vec<String>,
+    pub unmatched_headings: Vec<String>,
+}
+
+/// Seeds ideate sessions from existing documents
+pub struct DocumentImporter {
+    db: SqlitePool,
+}
+
+impl DocumentImporter {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+
+    /// Create a session and pre-fill its sections from a document
+    pub async fn start_session(
+        &self,
+        input: StartFromDocumentInput,
+    ) -> Result<DocumentImportResult> {
+        let (source_name, markdown) = load_document(input.source)?;
+        let parsed = parse_markdown(&markdown);
+
+        let initial_description = initial_description(&parsed).ok_or_else(|| {
+            IdeateError::InvalidInput(format!("{} has no readable content", source_name))
+        })?;
+
+        info!(
+            "Starting ideate session for project {} from document {}",
+            input.project_id, source_name
+        );
+
+        let manager = IdeateManager::new(self.db.clone());
+        let session = manager
+            .create_session(CreateIdeateSessionInput {
+                project_id: input.project_id,
+                initial_description,
+                mode: input.mode,
+                template_id: input.template_id,
+                research_tools_enabled: input.research_tools_enabled,
+                question_bank_id: None,
+            })
+            .await?;
+
+        let sections = map_sections(&parsed, &session.id);
+        let imported_sections = match save_sections(&manager, &session.id, &sections).await {
+            Ok(imported) => imported,
+            Err(e) => {
+                // Don't leave a half-seeded session behind
+                if let Err(cleanup) = manager.delete_session(&session.id).await {
+                    warn!(
+                        "Failed to clean up session {} after import error: {}",
+                        session.id, cleanup
+                    );
+                }
+                return Err(e);
+            }
+        };
+
+        Ok(DocumentImportResult {
+            session,
+            source_name,
+            imported_sections,
+            unmatched_headings: sections.unmatched_headings,
+        })
+    }
+}
+
+async fn save_sections(
+    manager: &IdeateManager,
+    session_id: &str,
+    sections: &ImportedSections,
+) -> Result<Vec<String>> {
+    let mut imported = Vec::new();
+    if let Some(overview) = &sections.overview {
+        manager.save_overview(session_id, overview.clone()).await?;
+        imported.push("overview".to_string());
+    }
+    if let Some(ux) = &sections.ux {
+        manager.save_ux(session_id, ux.clone()).await?;
+        imported.push("ux".to_string());
+    }
+    if let Some(technical) = &sections.technical {
+        manager
+            .save_technical(session_id, technical.clone())
+            .await?;
+        imported.push("technical".to_string());
+    }
+    Ok(imported)
+}
+
+/// Resolve a document source to its file name and Markdown text
+pub fn load_document(source: DocumentSource) -> Result<(String, String)> {
+    match source {
+        DocumentSource::Markdown { name, content } => {
+            check_size(content.len())?;
+            Ok((name, content))
+        }
+        DocumentSource::Docx { name, bytes } => {
+            check_size(bytes.len())?;
+            Ok((name, docx_to_markdown(&bytes)?))
+        }
+        DocumentSource::Path(path) => {
+            let file = find_document(&path)?;
+            let size = std::fs::metadata(&file)
+                .map_err(|e| {
+                    IdeateError::InvalidInput(format!("Cannot read {}: {}", file.display(), e))
+                })?
+                .len();
+            check_size(size as usize)?;
+
+            let name = file
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| file.display().to_string());
+            let bytes = std::fs::read(&file).map_err(|e| {
+                IdeateError::InvalidInput(format!("Cannot read {}: {}", file.display(), e))
+            })?;
+
+            if name.to_lowercase().ends_with(".docx") {
+                Ok((name, docx_to_markdown(&bytes)?))
+            } else {
+                Ok((name, String::from_utf8_lossy(&bytes).to_string()))
+            }
+        }
+    }
+}
+
+fn check_size(len: usize) -> Result<()> {
+    if len > MAX_DOCUMENT_BYTES {
+        return Err(IdeateError::InvalidInput(format!(
+            "Document is too large ({} bytes, limit {} bytes)",
+            len, MAX_DOCUMENT_BYTES
+        )));
+    }
+    Ok(())
+}
+
+/// Extensions accepted when a document file is given directly
+const DOCUMENT_EXTENSIONS: &[&str] = &["md", "markdown", "docx"];
+
+/// Whether a file given directly is a README/PRD document we may import
+fn is_importable_document(path: &Path) -> bool {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    matches!(stem.as_str(), "readme" | "prd") && DOCUMENT_EXTENSIONS.contains(&extension.as_str())
+}
+
+/// Locate the document to import: the path itself if it is a README/PRD file,
+/// otherwise the first README/PRD found in the directory
+pub fn find_document(path: &Path) -> Result<PathBuf> {
+    if path.is_file() {
+        if !is_importable_document(path) {
+            return Err(IdeateError::InvalidInput(format!(
+                "Only README or PRD documents (.md, .docx) can be imported: {}",
+                path.display()
+            )));
+        }
+        return Ok(path.to_path_buf());
+    }
+    if !path.is_dir() {
+        return Err(IdeateError::InvalidInput(format!(
+            "Path does not exist: {}",
+            path.display()
+        )));
+    }
+
+    REPO_DOCUMENT_CANDIDATES
+        .iter()
+        .map(|candidate| path.join(candidate))
+        .find(|candidate| candidate.is_file())
+        .ok_or_else(|| {
+            IdeateError::InvalidInput(format!(
+                "No README or PRD document found in {}",
+                path.display()
+            ))
+        })
+}
+
+/// Split Markdown into sections by ATX headings, ignoring fenced code blocks
+pub fn parse_markdown(markdown: &str) -> ParsedDocument {
+    let mut document = ParsedDocument::default();
+    let mut preamble = Vec::new();
+    let mut current: Option<DocumentSection> = None;
+    let mut body = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+        }
+
+        let heading = if in_code_block {
+            None
+        } else {
+            parse_heading(trimmed)
+        };
+
+        match heading {
+            Some((level, text)) => {
+                match current.take() {
+                    Some(mut section) => {
+                        section.body = body.join("\n").trim().to_string();
+                        document.sections.push(section);
+                    }
+                    None => preamble.append(&mut body),
+                }
+                body.clear();
+
+                // A leading H1 is the document title rather than a section
+                if level == 1 && document.title.is_none() && document.sections.is_empty() {
+                    document.title = Some(text);
+                } else {
+                    current = Some(DocumentSection {
+                        heading: text,
+                        level,
+                        body: String::new(),
+                    });
+                }
+            }
+            None => body.push(line),
+        }
+    }
+
+    match current {
+        Some(mut section) => {
+            section.body = body.join("\n").trim().to_string();
+            document.sections.push(section);
+        }
+        None => preamble.append(&mut body),
+    }
+
+    document.summary = first_paragraph(&preamble);
+    document
+}
+
+fn parse_heading(line: &str) -> Option<(usize, String)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') && !rest.starts_with('\t') {
+        return None;
+    }
+    let text = rest.trim().trim_end_matches('#').trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some((level, text.to_string()))
+}
+
+/// First prose paragraph, skipping badges, images, HTML, and rules
+fn first_paragraph(lines: &[&str]) -> Option<String> {
+    lines
+        .split(|line| line.trim().is_empty())
+        .map(|paragraph| {
+            paragraph
+                .iter()
+                .map(|line| line.trim())
+                .filter(|line| {
+                    !(line.starts_with("[![")
+                        || line.starts_with("![")
+                        || line.starts_with('<')
+                        || line.starts_with("---")
+                        || line.starts_with("```"))
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .find(|paragraph| !paragraph.is_empty())
+}
+
+fn classify_heading(heading: &str) -> Option<SectionTarget> {
+    let words: Vec<String> = heading
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    HEADING_KEYWORDS.iter().find_map(|(target, phrases)| {
+        phrases
+            .iter()
+            .any(|phrase| contains_phrase(&words, phrase))
+            .then_some(*target)
+    })
+}
+
+/// Whether `phrase` appears as whole consecutive words in `words`
+fn contains_phrase(words: &[String], phrase: &str) -> bool {
+    let phrase: Vec<&str> = phrase.split_whitespace().collect();
+    words
+        .windows(phrase.len())
+        .any(|window| window.iter().zip(&phrase).all(|(w, p)| w == p))
+}
+
+/// Map document sections onto session sections.
+///
+/// Sub-headings that don't match anything are folded into the closest
+/// matched parent section, so "Architecture > Backend" lands in technical.
+pub fn map_sections(document: &ParsedDocument, session_id: &str) -> ImportedSections {
+    let mut fields: Vec<(SectionTarget, Vec<String>)> = Vec::new();
+    let mut unmatched_headings = Vec::new();
+    let mut current: Option<(SectionTarget, usize)> = None;
+
+    for section in &document.sections {
+        let text = match classify_heading(&section.heading) {
+            Some(target) => {
+                current = Some((target, section.level));
+                section.body.clone()
+            }
+            None => match current {
+                Some((_, level)) if section.level > level => {
+                    format!("#### {}\n\n{}", section.heading, section.body)
+                }
+                _ => {
+                    current = None;
+                    unmatched_headings.push(section.heading.clone());
+                    continue;
+                }
+            },
+        };
+
+        let Some((target, _)) = current else {
+            continue;
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+        match fields.iter_mut().find(|(t, _)| *t == target) {
+            Some((_, parts)) => parts.push(text),
+            None => fields.push((target, vec![text])),
+        }
+    }
+
+    let field = |target: SectionTarget| -> Option<String> {
+        fields
+            .iter()
+            .find(|(t, _)| *t == target)
+            .map(|(_, parts)| truncate(&parts.join("\n\n"), MAX_FIELD_CHARS))
+    };
+
+    let now = Utc::now();
+    let problem_statement = field(SectionTarget::ProblemStatement).or_else(|| {
+        document
+            .summary
+            .as_deref()
+            .map(|s| truncate(s, MAX_FIELD_CHARS))
+    });
+    let target_audience = field(SectionTarget::TargetAudience);
+    let value_proposition = field(SectionTarget::ValueProposition);
+    let one_line_pitch = document.summary.as_deref().map(first_sentence);
+
+    let overview =
+        (problem_statement.is_some() || target_audience.is_some() || value_proposition.is_some())
+            .then(|| IdeateOverview {
+                id: String::new(),
+                session_id: session_id.to_string(),
+                problem_statement,
+                target_audience,
+                value_proposition,
+                one_line_pitch,
+                ai_generated: false,
+                created_at: now,
+            });
+
+    let ui_considerations = field(SectionTarget::UiConsiderations);
+    let ux_principles = field(SectionTarget::UxPrinciples);
+    let ux = (ui_considerations.is_some() || ux_principles.is_some()).then(|| IdeateUX {
+        id: String::new(),
+        session_id: session_id.to_string(),
+        personas: None,
+        user_flows: None,
+        ui_considerations,
+        ux_principles,
+        ai_generated: false,
+        created_at: now,
+    });
+
+    let technical = field(SectionTarget::TechStack).map(|tech_stack| IdeateTechnical {
+        id: String::new(),
+        session_id: session_id.to_string(),
+        components: None,
+        data_models: None,
+        apis: None,
+        infrastructure: None,
+        tech_stack_quick: Some(tech_stack),
+        ai_generated: false,
+        created_at: now,
+    });
+
+    ImportedSections {
+        overview,
+        ux,
+        technical,
+        unmatched_headings,
+    }
+}
+
+fn initial_description(document: &ParsedDocument) -> Option<String> {
+    let fallback = document
+        .sections
+        .iter()
+        .map(|s| s.body.trim())
+        .find(|body| !body.is_empty());
+
+    let description = match (&document.title, document.summary.as_deref().or(fallback)) {
+        (Some(title), Some(summary)) => format!("{}: {}", title, summary),
+        (Some(title), None) => title.clone(),
+        (None, Some(summary)) => summary.to_string(),
+        (None, None) => return None,
+    };
+    Some(truncate(&description, MAX_DESCRIPTION_CHARS))
+}
+
+fn first_sentence(text: &str) -> String {
+    let end = text
+        .char_indices()
+        .find(|(i, c)| {
+            matches!(c, '.' | '!' | '?')
+                && text[i + c.len_utf8()..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace)
+        })
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(text.len());
+    truncate(&text[..end], 200)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => format!("{}…", text[..index].trim_end()),
+        None => text.to_string(),
+    }
+}
+
+/// Convert a .docx file to Markdown, keeping headings, list items, and paragraphs
+pub fn docx_to_markdown(bytes: &[u8]) -> Result<String> {
+    let xml = read_zip_entry(bytes, "word/document.xml")?;
+    let xml = String::from_utf8(xml)
+        .map_err(|_| IdeateError::InvalidInput("Docx document is not valid UTF-8".to_string()))?;
+    Ok(document_xml_to_markdown(&xml))
+}
+
+fn invalid_docx(reason: &str) -> IdeateError {
+    IdeateError::InvalidInput(format!("Not a valid .docx file: {}", reason))
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<usize> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]) as usize)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<usize> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]) as usize)
+}
+
+/// Read one file from a zip archive using its central directory
+fn read_zip_entry(bytes: &[u8], entry_name: &str) -> Result<Vec<u8>> {
+    const EOCD_SIGNATURE: &[u8] = b"PK\x05\x06";
+    const CENTRAL_SIGNATURE: &[u8] = b"PK\x01\x02";
+    const LOCAL_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+    // The end-of-central-directory record sits within the last 64KB (comment) + 22 bytes
+    let search_start = bytes.len().saturating_sub(0xFFFF + 22);
+    let eocd = bytes[search_start..]
+        .windows(4)
+        .rposition(|w| w == EOCD_SIGNATURE)
+        .map(|pos| search_start + pos)
+        .ok_or_else(|| invalid_docx("missing zip directory"))?;
+
+    let entries = read_u16(bytes, eocd + 10).ok_or_else(|| invalid_docx("truncated"))?;
+    let mut offset = read_u32(bytes, eocd + 16).ok_or_else(|| invalid_docx("truncated"))?;
+
+    for _ in 0..entries {
+        if bytes.get(offset..offset + 4) != Some(CENTRAL_SIGNATURE) {
+            return Err(invalid_docx("corrupt zip directory"));
+        }
+        let field = |at: usize| read_u16(bytes, offset + at);
+        let method = field(10).ok_or_else(|| invalid_docx("truncated"))?;
+        let compressed_size =
+            read_u32(bytes, offset + 20).ok_or_else(|| invalid_docx("truncated"))?;
+        let uncompressed_size =
+            read_u32(bytes, offset + 24).ok_or_else(|| invalid_docx("truncated"))?;
+        let name_len = field(28).ok_or_else(|| invalid_docx("truncated"))?;
+        let extra_len = field(30).ok_or_else(|| invalid_docx("truncated"))?;
+        let comment_len = field(32).ok_or_else(|| invalid_docx("truncated"))?;
+        let local_offset = read_u32(bytes, offset + 42).ok_or_else(|| invalid_docx("truncated"))?;
+        let name = bytes
+            .get(offset + 46..offset + 46 + name_len)
+            .ok_or_else(|| invalid_docx("truncated"))?;
+
+        if name == entry_name.as_bytes() {
+            if uncompressed_size > MAX_DOCUMENT_BYTES * 10 {
+                return Err(invalid_docx("document body is too large"));
+            }
+            if bytes.get(local_offset..local_offset + 4) != Some(LOCAL_SIGNATURE) {
+                return Err(invalid_docx("corrupt zip entry"));
+            }
+            let local_name_len =
+                read_u16(bytes, local_offset + 26).ok_or_else(|| invalid_docx("truncated"))?;
+            let local_extra_len =
+                read_u16(bytes, local_offset + 28).ok_or_else(|| invalid_docx("truncated"))?;
+            let data_start = local_offset + 30 + local_name_len + local_extra_len;
+            let data = bytes
+                .get(data_start..data_start + compressed_size)
+                .ok_or_else(|| invalid_docx("truncated"))?;
+
+            return match method {
+                0 => Ok(data.to_vec()),
+                8 => {
+                    let mut out = Vec::with_capacity(uncompressed_size);
+                    DeflateDecoder::new(data)
+                        .take((MAX_DOCUMENT_BYTES * 10) as u64)
+                        .read_to_end(&mut out)
+                        .map_err(|e| invalid_docx(&e.to_string()))?;
+                    Ok(out)
+                }
+                _ => Err(invalid_docx("unsupported compression")),
+            };
+        }
+
+        offset += 46 + name_len + extra_len + comment_len;
+    }
+
+    Err(invalid_docx("word/document.xml not found"))
+}
+
+/// Flatten WordprocessingML paragraphs into Markdown lines
+fn document_xml_to_markdown(xml: &str) -> String {
+    let mut lines = Vec::new();
+    let mut paragraph = String::new();
+    let mut heading_level: Option<usize> = None;
+    let mut list_item = false;
+    let mut in_text = false;
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        if in_text {
+            paragraph.push_str(&unescape_xml(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+        let closing = tag.starts_with('/');
+
+        match (name, closing) {
+            ("w:p", false) => {
+                paragraph.clear();
+                heading_level = None;
+                list_item = false;
+                if tag.ends_with('/') {
+                    lines.push(String::new());
+                }
+            }
+            ("w:p", true) => {
+                let text = paragraph.trim();
+                let line = match (heading_level, list_item) {
+                    _ if text.is_empty() => String::new(),
+                    (Some(level), _) => format!("{} {}", "#".repeat(level), text),
+                    (None, true) => format!("- {}", text),
+                    (None, false) => text.to_string(),
+                };
+                lines.push(line);
+                paragraph.clear();
+            }
+            ("w:pStyle", false) => heading_level = style_heading_level(tag),
+            ("w:numPr", false) => list_item = true,
+            ("w:t", false) => in_text = !tag.ends_with('/'),
+            ("w:t", true) => in_text = false,
+            ("w:tab", false) => paragraph.push(' '),
+            ("w:br", false) | ("w:cr", false) => paragraph.push(' '),
+            _ => {}
+        }
+    }
+
+    // Separate blocks with blank lines so Markdown parsing sees paragraphs
+    let mut markdown = String::new();
+    for line in lines.iter().filter(|l| !l.is_empty()) {
+        markdown.push_str(line);
+        markdown.push_str(if line.starts_with("- ") { "\n" } else { "\n\n" });
+    }
+    markdown
+}
+
+/// Heading level from a `w:pStyle` tag such as `Heading2` or `Title`
+fn style_heading_level(tag: &str) -> Option<usize> {
+    let value = tag.split("w:val=\"").nth(1)?.split('"').next()?;
+    let lower = value.to_lowercase();
+    if lower == "title" {
+        return Some(1);
+    }
+    lower
+        .strip_prefix("heading")
+        .and_then(|n| n.trim().parse::<usize>().ok())
+        .map(|level| level.clamp(1, 6))
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Taskly
+
+[![CI](https://example.com/badge.svg)](https://example.com)
+
+Taskly is a kanban board for small teams. It syncs offline.
+
+## Problem
+
+Teams juggle tasks across chat and spreadsheets.
+
+## Who is this for?
+
+Freelancers and small agencies.
+
+## Features
+
+- Boards
+- Offline sync
+
+## Architecture
+
+Rust backend with a React frontend.
+
+### Storage
+
+```
+# not a heading
+```
+
+SQLite with WAL.
+
+## License
+
+MIT
+";
+
+    #[test]
+    fn test_parse_markdown_sections() {
+        let doc = parse_markdown(README);
+        assert_eq!(doc.title.as_deref(), Some("Taskly"));
+        assert_eq!(
+            doc.summary.as_deref(),
+            Some("Taskly is a kanban board for small teams. It syncs offline.")
+        );
+        let headings: Vec<_> = doc.sections.iter().map(|s| s.heading.as_str()).collect();
+        assert_eq!(
+            headings,
+            vec![
+                "Problem",
+                "Who is this for?",
+                "Features",
+                "Architecture",
+                "Storage",
+                "License"
+            ]
+        );
+        assert!(doc.sections[4].body.contains("# not a heading"));
+    }
+
+    #[test]
+    fn test_map_sections() {
+        let sections = map_sections(&parse_markdown(README), "session-1");
+
+        let overview = sections.overview.unwrap();
+        assert_eq!(
+            overview.problem_statement.as_deref(),
+            Some("Teams juggle tasks across chat and spreadsheets.")
+        );
+        assert_eq!(
+            overview.target_audience.as_deref(),
+            Some("Freelancers and small agencies.")
+        );
+        assert_eq!(
+            overview.value_proposition.as_deref(),
+            Some("- Boards\n- Offline sync")
+        );
+        assert_eq!(
+            overview.one_line_pitch.as_deref(),
+            Some("Taskly is a kanban board for small teams.")
+        );
+
+        // Unmatched sub-heading folds into its matched parent
+        let technical = sections.technical.unwrap();
+        let stack = technical.tech_stack_quick.unwrap();
+        assert!(stack.starts_with("Rust backend"));
+        assert!(stack.contains("#### Storage"));
+
+        assert!(sections.ux.is_none());
+        assert_eq!(sections.unmatched_headings, vec!["License".to_string()]);
+    }
+
+    #[test]
+    fn test_heading_keywords_match_whole_words() {
+        assert_eq!(classify_heading("Building"), Some(SectionTarget::TechStack));
+        assert_eq!(
+            classify_heading("Design Principles"),
+            Some(SectionTarget::UxPrinciples)
+        );
+        assert_eq!(
+            classify_heading("UI / UX"),
+            Some(SectionTarget::UiConsiderations)
+        );
+        // "guide" contains "ui" but is not the word "ui"
+        assert_eq!(classify_heading("Contributing guide"), None);
+    }
+
+    #[test]
+    fn test_document_xml_to_markdown() {
+        let xml = r#"<w:document><w:body>
+            <w:p><w:pPr><w:pStyle w:val="Title"/></w:pPr><w:r><w:t>Taskly</w:t></w:r></w:p>
+            <w:p><w:r><w:t xml:space="preserve">Boards &amp; cards </w:t></w:r><w:r><w:t>for teams.</w:t></w:r></w:p>
+            <w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr><w:r><w:t>Features</w:t></w:r></w:p>
+            <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/></w:numPr></w:pPr><w:r><w:t>Offline sync</w:t></w:r></w:p>
+            <w:p/>
+        </w:body></w:document>"#;
+
+        assert_eq!(
+            document_xml_to_markdown(xml),
+            "# Taskly\n\nBoards & cards for teams.\n\n## Features\n\n- Offline sync\n"
+        );
+    }
+
+    #[test]
+    fn test_read_zip_entry_rejects_non_zip() {
+        let err = docx_to_markdown(b"plain text").unwrap_err();
+        assert!(matches!(err, IdeateError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn test_find_document_prefers_prd() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("README.md"), "# Readme").unwrap();
+        assert_eq!(
+            find_document(dir.path()).unwrap(),
+            dir.path().join("README.md")
+        );
+
+        std::fs::create_dir(dir.path().join("docs")).unwrap();
+        std::fs::write(dir.path().join("docs/PRD.md"), "# PRD").unwrap();
+        assert_eq!(
+            find_document(dir.path()).unwrap(),
+            dir.path().join("docs/PRD.md")
+        );
+
+        assert!(find_document(&dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn test_find_document_rejects_other_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for name in ["PRD.md", "readme.docx"] {
+            std::fs::write(dir.path().join(name), "# Doc").unwrap();
+            assert!(find_document(&dir.path().join(name)).is_ok());
+        }
+        for name in ["notes.md", "README.txt", "id_rsa"] {
+            std::fs::write(dir.path().join(name), "secret").unwrap();
+            assert!(find_document(&dir.path().join(name)).is_err());
+        }
+    }
+}
@@ -5,7 +5,13 @@ use crate::epic::{
     ArchitectureDecision, CreateEpicInput, Epic, EpicStatus, ExternalDependency, SuccessCriterion,
     UpdateEpicInput,
 };
+use crate::epic_metrics::{compute_epic_metrics, EpicMetrics, StatusChange};
 use crate::error::{IdeateError, Result};
+use crate::traceability::{
+    build_traceability_matrix, extract_requirements, TaskTraceInput, TraceabilityMatrix,
+    REQUIREMENT_IDS_KEY,
+};
+use chrono::{DateTime, Utc};
 use sqlx::{Row, SqlitePool};
 
 pub struct EpicManager {
@@ -322,6 +328,129 @@ impl EpicManager {
         Ok(progress)
     }
 
+    /// Compute burndown, velocity, and projected completion from task status history
+    pub async fn calculate_metrics(&self, project_id: &str, epic_id: &str) -> Result<EpicMetrics> {
+        if self.get_epic(project_id, epic_id).await?.is_none() {
+            return Err(IdeateError::NotFound(format!("Epic {} not found", epic_id)));
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT h.task_id, h.to_status, h.changed_at
+            FROM task_status_history h
+            JOIN tasks t ON t.id = h.task_id
+            WHERE t.epic_id = ?
+            "#,
+        )
+        .bind(epic_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IdeateError::DatabaseError(e.to_string()))?;
+
+        let mut history = rows
+            .iter()
+            .map(|row| {
+                Ok(StatusChange {
+                    task_id: row.try_get("task_id")?,
+                    to_status: row.try_get("to_status")?,
+                    changed_at: row.try_get::<DateTime<Utc>, _>("changed_at")?,
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| IdeateError::DatabaseError(e.to_string()))?;
+        // Timestamps come from both triggers and application code, so sort after parsing
+        history.sort_by_key(|change| change.changed_at);
+
+        Ok(compute_epic_metrics(
+            epic_id,
+            &history,
+            Utc::now().date_naive(),
+        ))
+    }
+
+    /// Map each requirement of a PRD to the epics and tasks implementing it
+    pub async fn traceability_matrix(
+        &self,
+        project_id: &str,
+        prd_id: &str,
+    ) -> Result<TraceabilityMatrix> {
+        let prd = sqlx::query(
+            "SELECT title, content_markdown FROM prds
+             WHERE id = ? AND project_id = ? AND deleted_at IS NULL",
+        )
+        .bind(prd_id)
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| IdeateError::DatabaseError(e.to_string()))?
+        .ok_or_else(|| IdeateError::NotFound(format!("PRD {} not found", prd_id)))?;
+        let title: String = prd.get("title");
+        let content: String = prd.get("content_markdown");
+
+        let epics = sqlx::query(
+            "SELECT id, name, status FROM epics
+             WHERE project_id = ? AND prd_id = ?
+             ORDER BY created_at",
+        )
+        .bind(project_id)
+        .bind(prd_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IdeateError::DatabaseError(e.to_string()))?
+        .iter()
+        .map(|row| {
+            Ok((
+                row.try_get("id")?,
+                row.try_get("name")?,
+                row.try_get::<EpicStatus, _>("status")?,
+            ))
+        })
+        .collect::<std::result::Result<Vec<_>, sqlx::Error>>()
+        .map_err(|e| IdeateError::DatabaseError(e.to_string()))?;
+
+        let rows = sqlx::query(
+            "SELECT id, title, status, epic_id, metadata FROM tasks
+             WHERE project_id = ?
+               AND (from_prd_id = ? OR epic_id IN (SELECT id FROM epics WHERE prd_id = ?))
+             ORDER BY created_at",
+        )
+        .bind(project_id)
+        .bind(prd_id)
+        .bind(prd_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| IdeateError::DatabaseError(e.to_string()))?;
+
+        let tasks = rows
+            .iter()
+            .map(|row| {
+                let metadata: Option<String> = row.try_get("metadata")?;
+                let requirement_ids = metadata
+                    .and_then(|metadata| serde_json::from_str::<serde_json::Value>(&metadata).ok())
+                    .and_then(|metadata| {
+                        serde_json::from_value(metadata[REQUIREMENT_IDS_KEY].clone()).ok()
+                    })
+                    .unwrap_or_default();
+                Ok(TaskTraceInput {
+                    id: row.try_get("id")?,
+                    title: row.try_get("title")?,
+                    status: row.try_get("status")?,
+                    epic_id: row.try_get("epic_id")?,
+                    requirement_ids,
+                })
+            })
+            .collect::<std::result::Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| IdeateError::DatabaseError(e.to_string()))?;
+
+        Ok(build_traceability_matrix(
+            prd_id,
+            &title,
+            extract_requirements(&content),
+            &epics,
+            &tasks,
+        ))
+    }
+
     /// Helper to convert SQLite row to Epic
     pub fn row_to_epic(&self, row: &sqlx::sqlite::SqliteRow) -> Result<Epic> {
         use sqlx::Row;
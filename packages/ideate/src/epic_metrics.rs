@@ -0,0 +1,238 @@
+// ABOUTME: Burndown, velocity, and projected completion metrics for Epics
+// ABOUTME: Replays task status history day by day to chart open vs completed work
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of trailing weeks averaged for velocity
+pub const VELOCITY_WEEKS: usize = 4;
+
+/// Burndown charts never go back further than this
+pub const MAX_BURNDOWN_DAYS: i64 = 365;
+
+/// A single task status transition from `task_status_history`
+#[derive(Debug, Clone)]
+pub struct StatusChange {
+    pub task_id: String,
+    pub to_status: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Open and completed task counts at the end of a day
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BurndownPoint {
+    pub date: NaiveDate,
+    pub open: i64,
+    pub completed: i64,
+}
+
+/// Tasks completed during the week starting on `week_start` (a Monday)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeeklyVelocity {
+    pub week_start: NaiveDate,
+    pub completed: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EpicMetrics {
+    pub epic_id: String,
+    pub total_tasks: i64,
+    pub open_tasks: i64,
+    pub completed_tasks: i64,
+    pub burndown: Vec<BurndownPoint>,
+    /// Completions per week over the last [`VELOCITY_WEEKS`] weeks, oldest first
+    pub velocity: Vec<WeeklyVelocity>,
+    pub average_velocity: f64,
+    /// Date the remaining work finishes at the average velocity.
+    /// `None` when work remains but nothing has been completed recently.
+    pub projected_completion_date: Option<NaiveDate>,
+}
+
+fn is_completed(status: &str) -> bool {
+    status == "done"
+}
+
+/// Cancelled tasks drop out of scope rather than counting as open work
+fn is_out_of_scope(status: &str) -> bool {
+    status == "cancelled"
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(date.weekday().num_days_from_monday() as i64)
+}
+
+/// Compute metrics from an Epic's status history, which must be ordered by `changed_at`
+pub fn compute_epic_metrics(
+    epic_id: &str,
+    history: &[StatusChange],
+    today: NaiveDate,
+) -> EpicMetrics {
+    let first_day = history
+        .first()
+        .map(|change| change.changed_at.date_naive())
+        .unwrap_or(today)
+        .max(today - Duration::days(MAX_BURNDOWN_DAYS))
+        .min(today);
+
+    // Replay history, sampling the state of every task at the end of each day
+    let mut current: HashMap<&str, &str> = HashMap::new();
+    let mut changes = history.iter().peekable();
+    let mut burndown = Vec::new();
+    let mut day = first_day;
+    while day <= today {
+        while let Some(change) = changes.next_if(|c| c.changed_at.date_naive() <= day) {
+            current.insert(&change.task_id, &change.to_status);
+        }
+        let completed = current.values().filter(|s| is_completed(s)).count() as i64;
+        let open = current
+            .values()
+            .filter(|s| !is_completed(s) && !is_out_of_scope(s))
+            .count() as i64;
+        burndown.push(BurndownPoint {
+            date: day,
+            open,
+            completed,
+        });
+        day += Duration::days(1);
+    }
+
+    // Count transitions into "done" per week; reopened tasks count again when re-completed
+    let this_week = week_start(today);
+    let mut velocity: Vec<WeeklyVelocity> = (0..VELOCITY_WEEKS)
+        .rev()
+        .map(|weeks_ago| WeeklyVelocity {
+            week_start: this_week - Duration::weeks(weeks_ago as i64),
+            completed: 0,
+        })
+        .collect();
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    for change in history {
+        let was_completed = previous
+            .insert(&change.task_id, &change.to_status)
+            .is_some_and(is_completed);
+        if is_completed(&change.to_status) && !was_completed {
+            let week = week_start(change.changed_at.date_naive());
+            if let Some(bucket) = velocity.iter_mut().find(|v| v.week_start == week) {
+                bucket.completed += 1;
+            }
+        }
+    }
+    let average_velocity =
+        velocity.iter().map(|v| v.completed).sum::<i64>() as f64 / VELOCITY_WEEKS as f64;
+
+    let last = burndown.last().cloned().unwrap_or(BurndownPoint {
+        date: today,
+        open: 0,
+        completed: 0,
+    });
+
+    let projected_completion_date = if last.open == 0 {
+        Some(today)
+    } else if average_velocity > 0.0 {
+        let days = (last.open as f64 / average_velocity * 7.0).ceil() as i64;
+        Some(today + Duration::days(days))
+    } else {
+        None
+    };
+
+    EpicMetrics {
+        epic_id: epic_id.to_string(),
+        total_tasks: last.open + last.completed,
+        open_tasks: last.open,
+        completed_tasks: last.completed,
+        burndown,
+        velocity,
+        average_velocity,
+        projected_completion_date,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn change(task_id: &str, to_status: &str, day: u32) -> StatusChange {
+        StatusChange {
+            task_id: task_id.to_string(),
+            to_status: to_status.to_string(),
+            changed_at: Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap(),
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_burndown_replays_history_per_day() {
+        let history = vec![
+            change("a", "pending", 2),
+            change("b", "pending", 2),
+            change("c", "pending", 3),
+            change("a", "done", 4),
+            change("c", "cancelled", 4),
+        ];
+
+        let metrics = compute_epic_metrics("epic1", &history, date(5));
+        let points: Vec<_> = metrics
+            .burndown
+            .iter()
+            .map(|p| (p.date, p.open, p.completed))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                (date(2), 2, 0),
+                (date(3), 3, 0),
+                (date(4), 1, 1),
+                (date(5), 1, 1),
+            ]
+        );
+        assert_eq!(metrics.total_tasks, 2);
+        assert_eq!(metrics.open_tasks, 1);
+    }
+
+    #[test]
+    fn test_velocity_and_projection() {
+        // 2026-03-16 is a Monday
+        let history = vec![
+            change("a", "pending", 2),
+            change("b", "pending", 2),
+            change("c", "pending", 2),
+            change("d", "pending", 2),
+            change("a", "done", 10),
+            change("b", "done", 17),
+            change("b", "in-progress", 18),
+            change("b", "done", 19),
+        ];
+
+        let metrics = compute_epic_metrics("epic1", &history, date(20));
+        let weekly: Vec<_> = metrics.velocity.iter().map(|v| v.completed).collect();
+        assert_eq!(metrics.velocity[3].week_start, date(16));
+        assert_eq!(weekly, vec![0, 0, 1, 2]);
+        assert_eq!(metrics.average_velocity, 0.75);
+
+        // Two open tasks at 0.75 tasks/week is 18.67 days
+        assert_eq!(
+            metrics.projected_completion_date,
+            Some(date(20) + Duration::days(19))
+        );
+    }
+
+    #[test]
+    fn test_projection_edge_cases() {
+        let stalled = vec![change("a", "pending", 2)];
+        let metrics = compute_epic_metrics("epic1", &stalled, date(20));
+        assert_eq!(metrics.projected_completion_date, None);
+
+        let finished = vec![change("a", "pending", 2), change("a", "done", 3)];
+        let metrics = compute_epic_metrics("epic1", &finished, date(20));
+        assert_eq!(metrics.projected_completion_date, Some(date(20)));
+
+        let empty = compute_epic_metrics("epic1", &[], date(20));
+        assert_eq!(empty.burndown.len(), 1);
+        assert_eq!(empty.total_tasks, 0);
+    }
+}
@@ -35,6 +35,9 @@ pub enum IdeateError {
     #[error("AI error: {0}")]
     AI(String),
 
+    #[error("Web search error: {0}")]
+    WebSearch(String),
+
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
@@ -61,6 +64,9 @@ pub enum IdeateError {
 
     #[error("Prompt loading error: {0}")]
     PromptError(String),
+
+    #[error("Encryption error: {0}")]
+    Encryption(String),
 }
 
 pub type Result<T> = std::result::Result<T, IdeateError>;
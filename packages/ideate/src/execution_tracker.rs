@@ -262,7 +262,10 @@ impl ExecutionTracker {
     }
 
     /// Get checkpoint by ID
-    async fn get_checkpoint(&self, checkpoint_id: &str) -> Result<ExecutionCheckpoint, StoreError> {
+    pub async fn get_checkpoint(
+        &self,
+        checkpoint_id: &str,
+    ) -> Result<ExecutionCheckpoint, StoreError> {
         let row = sqlx::query("SELECT * FROM execution_checkpoints WHERE id = ?")
             .bind(checkpoint_id)
             .fetch_one(&self.pool)
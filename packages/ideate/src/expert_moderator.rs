@@ -1,9 +1,11 @@
 // ABOUTME: Expert discussion moderator utilities
-// ABOUTME: Handles user interjections and helper functions (AI moved to frontend)
+// ABOUTME: Handles discussion start, turn-taking decisions, user interjections, and helper functions (AI moved to frontend)
 
-use crate::error::Result;
+use crate::error::{IdeateError, Result};
+use crate::moderation::{self, DEFAULT_MAX_EXPERT_TURNS};
 use crate::roundtable::*;
 use crate::roundtable_manager::RoundtableManager;
+use std::collections::HashSet;
 use tracing::info;
 
 /// Expert moderator for roundtable discussion utilities
@@ -16,6 +18,99 @@ impl ExpertModerator {
         Self { manager }
     }
 
+    /// Start a discussion with the requested moderation strategy
+    ///
+    /// Adds any requested experts that are not yet participants, records the
+    /// strategy, and posts the moderator's opening statement.
+    pub async fn start_discussion(
+        &self,
+        roundtable_id: &str,
+        request: &StartRoundtableRequest,
+    ) -> Result<RoundtableWithParticipants> {
+        info!(
+            "Starting roundtable discussion: {} (strategy: {})",
+            roundtable_id,
+            request.strategy.as_str()
+        );
+
+        let existing: HashSet<String> = self
+            .manager
+            .get_participants(roundtable_id)
+            .await?
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        let new_experts: Vec<String> = request
+            .expert_ids
+            .iter()
+            .filter(|id| !existing.contains(*id))
+            .cloned()
+            .collect();
+        if !new_experts.is_empty() {
+            self.manager
+                .add_participants(roundtable_id, new_experts)
+                .await?;
+        }
+
+        let participants = self.manager.get_participants(roundtable_id).await?;
+        if participants.is_empty() {
+            return Err(IdeateError::ValidationError(
+                "Cannot start a roundtable without participants".to_string(),
+            ));
+        }
+
+        self.manager
+            .start_roundtable(roundtable_id, request.strategy, request.max_turns)
+            .await?;
+
+        let opening = format!(
+            "{} {}",
+            build_moderator_opening(&request.topic, &participants),
+            moderation::strategy_opening(request.strategy)
+        );
+        self.manager
+            .add_message(
+                roundtable_id,
+                MessageRole::Moderator,
+                None,
+                Some("Moderator".to_string()),
+                opening,
+                None,
+            )
+            .await?;
+
+        let session = self.manager.get_roundtable(roundtable_id).await?;
+        Ok(RoundtableWithParticipants {
+            session,
+            participants,
+        })
+    }
+
+    /// Decide who speaks next, or whether the discussion should conclude,
+    /// using the roundtable's moderation strategy (`None` once it has ended)
+    pub async fn next_decision(&self, roundtable_id: &str) -> Result<Option<ModerationDecision>> {
+        let session = self.manager.get_roundtable(roundtable_id).await?;
+        if !session.is_active() {
+            return Ok(None);
+        }
+
+        let participants = self.manager.get_participants(roundtable_id).await?;
+        let messages = self.manager.get_messages(roundtable_id).await?;
+
+        let policy = moderation::policy_for(session.moderation_strategy);
+        let max_turns = session
+            .max_turns
+            .map(|turns| turns.max(1) as usize)
+            .unwrap_or(DEFAULT_MAX_EXPERT_TURNS);
+
+        Ok(moderation::decide(
+            policy.as_ref(),
+            &participants,
+            &messages,
+            max_turns,
+        ))
+    }
+
     /// Handle user interjection mid-discussion
     pub async fn handle_interjection(
         &self,
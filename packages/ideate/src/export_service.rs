@@ -1,5 +1,5 @@
 // ABOUTME: Export service for PRD generation in multiple formats
-// ABOUTME: Supports Markdown, HTML, PDF (via HTML), and DOCX export formats
+// ABOUTME: Supports Markdown, HTML, PDF (via HTML), DOCX, and Notion/Confluence page formats
 
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Sqlite};
@@ -7,6 +7,7 @@ use tracing::{error, info, warn};
 
 use crate::error::{IdeateError, Result};
 use crate::prd_generator::{GeneratedPRD, PRDGenerator};
+use crate::publishing::{confluence_storage, notion_blocks, parse_markdown};
 
 /// Export format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -16,6 +17,17 @@ pub enum ExportFormat {
     Html,
     Pdf,
     Docx,
+    /// Notion blocks as JSON; publish to Notion through `PublishingService`
+    Notion,
+    /// Confluence storage format XHTML; publish to Confluence through `PublishingService`
+    Confluence,
+}
+
+impl ExportFormat {
+    /// Whether PRDs can be published straight to this format's service
+    pub fn is_publishing_target(&self) -> bool {
+        matches!(self, ExportFormat::Notion | ExportFormat::Confluence)
+    }
 }
 
 impl std::fmt::Display for ExportFormat {
@@ -25,6 +37,8 @@ impl std::fmt::Display for ExportFormat {
             ExportFormat::Html => write!(f, "html"),
             ExportFormat::Pdf => write!(f, "pdf"),
             ExportFormat::Docx => write!(f, "docx"),
+            ExportFormat::Notion => write!(f, "notion"),
+            ExportFormat::Confluence => write!(f, "confluence"),
         }
     }
 }
@@ -91,14 +105,23 @@ impl ExportService {
                 self.export_pdf_from_html(&html)?
             }
             ExportFormat::Docx => self.export_docx(prd, &options)?,
+            ExportFormat::Notion => {
+                let markdown = self.export_markdown(prd, &options)?;
+                serde_json::to_string_pretty(&notion_blocks(&parse_markdown(&markdown)))?
+            }
+            ExportFormat::Confluence => {
+                let markdown = self.export_markdown(prd, &options)?;
+                confluence_storage(&parse_markdown(&markdown))
+            }
         };
 
         let file_name = self.generate_filename(prd, &options, session_id);
         let mime_type = self.get_mime_type(&options.format);
         let size_bytes = content.len();
 
-        // Optionally save export record to database
-        if let Some(sid) = session_id {
+        // Optionally save export record to database; publishing targets are
+        // tracked as export jobs instead
+        if let Some(sid) = session_id.filter(|_| !options.format.is_publishing_target()) {
             self.save_export_record(sid, &file_name, &options.format)
                 .await
                 .ok(); // Don't fail export if record save fails
@@ -272,6 +295,8 @@ generated_by: Orkee Ideate
             ExportFormat::Html => "html",
             ExportFormat::Pdf => "pdf",
             ExportFormat::Docx => "docx",
+            ExportFormat::Notion => "json",
+            ExportFormat::Confluence => "xhtml",
         };
 
         format!("{}.{}", title, extension)
@@ -287,6 +312,8 @@ generated_by: Orkee Ideate
                 "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
                     .to_string()
             }
+            ExportFormat::Notion => "application/json".to_string(),
+            ExportFormat::Confluence => "application/xhtml+xml".to_string(),
         }
     }
 
@@ -2,16 +2,23 @@
 // ABOUTME: Provides session management, section handling, and PRD generation support
 
 pub mod approach_generator;
+pub mod approach_scoring;
 pub mod build_optimizer;
 pub mod chat;
+pub mod chat_context;
 pub mod chat_manager;
+pub mod checkpoint_runner;
 pub mod chunk_manager;
+pub mod citations;
 pub mod codebase_analyzer;
+pub mod codebase_cache;
 pub mod complexity_analyzer;
 pub mod dependency_analyzer;
 pub mod discovery_manager;
+pub mod document_importer;
 pub mod epic;
 pub mod epic_manager;
+pub mod epic_metrics;
 pub mod error;
 pub mod execution_tracker;
 pub mod expert_moderator;
@@ -19,36 +26,56 @@ pub mod export_service;
 pub mod github_sync;
 pub mod insight_extractor;
 pub mod manager;
+pub mod moderation;
 pub mod prd_aggregator;
 pub mod prd_generator;
 pub mod prompts;
+pub mod publishing;
+pub mod question_banks;
 pub mod research_analyzer;
 pub mod research_prompts;
 pub mod roundtable;
 pub mod roundtable_manager;
 pub mod task_decomposer;
 pub mod templates;
+pub mod traceability;
 pub mod types;
 pub mod validation;
 
 pub use approach_generator::{
     ApproachComparison, ApproachGenerator, ComplexityLevel, TechnicalApproach,
 };
+pub use approach_scoring::{
+    AiApproachScores, ApproachScore, ApproachScorer, CriterionLeader, CriterionScore, ScoreSource,
+    ScoringCriterion, ScoringMatrix, ScoringWeights,
+};
 pub use build_optimizer::{
-    BuildOptimizer, BuildOrderResult, CircularDependency, CircularDependencySeverity,
-    OptimizationStrategy,
+    BrokenDependency, BuildOptimizer, BuildOrderResult, BuildSimulationInput,
+    BuildSimulationResult, CircularDependency, CircularDependencySeverity, OptimizationStrategy,
+    TimelinePhase,
 };
 pub use chat::{
-    ChatInsight, ChatMessage, CreateInsightInput, DiscoveryQuestion, DiscoveryStatus,
-    GeneratePRDFromChatInput, GeneratePRDFromChatResult, InsightType, MessageRole, MessageType,
-    QualityMetrics, QuestionCategory, SendMessageInput, TopicCoverage, ValidationResult,
+    ChatContext, ChatInsight, ChatMessage, ChatSummary, CreateInsightInput, CreateSummaryInput,
+    DiscoveryQuestion, DiscoveryStatus, GeneratePRDFromChatInput, GeneratePRDFromChatResult,
+    InsightType, MessageRole, MessageType, QualityMetrics, QuestionCategory, SendMessageInput,
+    SummarySource, TokenBudget, TopicCoverage, ValidationResult,
 };
+pub use chat_context::{context_window_for, estimate_tokens};
 pub use chat_manager::ChatManager;
+pub use checkpoint_runner::{
+    CheckpointRunStatus, CheckpointRunner, CheckpointValidationRun, CommandOutput,
+    ParallelGroupGate, ValidationCommandExecutor, ValidationCommandResult,
+};
 pub use chunk_manager::{ChunkManager, ChunkStatus, PrdChunk, ValidateChunkInput};
+pub use citations::{
+    CitableSource, CitationInput, CitationSourceType, CitationTracker, PrdCitation,
+};
 pub use codebase_analyzer::{
-    ArchitectureStyle, CodebaseAnalyzer, CodebaseContext, FileStructure, Pattern, PatternType,
-    ReusableComponent, SimilarFeature, TechStack,
+    AnalysisCacheInfo, AnalysisStep, ArchitectureStyle, CacheStatus, CodebaseAnalysis,
+    CodebaseAnalyzer, CodebaseContext, FileStructure, Pattern, PatternType, ReusableComponent,
+    SimilarFeature, TechStack,
 };
+pub use codebase_cache::{CachedCodebaseAnalysis, CodebaseCache};
 pub use complexity_analyzer::{ComplexityAnalyzer, ComplexityFactors, ComplexityReport};
 pub use dependency_analyzer::{
     CreateDependencyInput, DependencyAnalysis, DependencyAnalyzer, DependencyStrength,
@@ -58,12 +85,16 @@ pub use discovery_manager::{
     AnswerFormat, DiscoveryAnswer, DiscoveryManager, FormattedOption, Question, QuestionType,
     SessionContext,
 };
+pub use document_importer::{
+    DocumentImportResult, DocumentImporter, DocumentSource, StartFromDocumentInput,
+};
 pub use epic::{
     ArchitectureDecision, ConflictAnalysis, CreateEpicInput, DependencyGraph, Epic, EpicComplexity,
     EpicStatus, EstimatedEffort, ExternalDependency, GraphEdge, GraphNode, SuccessCriterion,
     TaskConflict, UpdateEpicInput, WorkAnalysis, WorkStream,
 };
 pub use epic_manager::EpicManager;
+pub use epic_metrics::{BurndownPoint, EpicMetrics, WeeklyVelocity};
 pub use error::{IdeateError, Result};
 pub use execution_tracker::{
     AppendProgressInput, CheckpointType, CreateCheckpointInput, ExecutionCheckpoint,
@@ -77,24 +108,44 @@ pub use github_sync::{
 };
 // TODO: extract_insights_with_ai removed - AI functionality moved to frontend (chat-ai.ts:extractInsights)
 pub use manager::IdeateManager;
+pub use moderation::{policy_for, ModerationPolicy, DEFAULT_MAX_EXPERT_TURNS};
 pub use prd_aggregator::{AggregatedPRDData, CompletenessMetrics, PRDAggregator};
 pub use prd_generator::PRDGenerator;
+pub use publishing::{
+    ExportJob, ExportJobStatus, ExportJobStorage, MaskedPublishingCredentials, PublishError,
+    PublishOptions, PublishingCredentialStorage, PublishingCredentials, PublishingService,
+};
+pub use question_banks::{
+    BankQuestion, FollowUpCondition, QuestionBank, QuestionBankInput, QuestionBankManager,
+};
+pub use research_analyzer::web_search::{
+    BraveProvider, SearchResult, SearxngProvider, TavilyProvider, WebSearchProvider,
+};
 pub use research_analyzer::{
-    GapAnalysis, Lesson, Opportunity, ResearchAnalyzer, ResearchSynthesis, UIPattern,
+    GapAnalysis, Lesson, Opportunity, ResearchAnalyzer, ResearchSource, ResearchSynthesis,
+    UIPattern,
 };
 pub use roundtable::{
-    CreateExpertPersonaInput, ExpertPersona, ExpertSuggestion, ExtractInsightsRequest,
-    ExtractInsightsResponse, InsightPriority, InsightsByCategory, MessageMetadata, RoundtableEvent,
+    ConclusionReason, CreateExpertPersonaInput, ExpertPersona, ExpertSuggestion,
+    ExtractInsightsRequest, ExtractInsightsResponse, InsightPriority, InsightsByCategory,
+    MessageMetadata, ModerationDecision, ModerationStrategy, ModeratorTurn, RoundtableEvent,
     RoundtableInsight, RoundtableMessage, RoundtableParticipant, RoundtableSession,
     RoundtableStatistics, RoundtableStatus, RoundtableWithParticipants, StartRoundtableRequest,
-    SuggestExpertsRequest, SuggestExpertsResponse, UserInterjectionInput, UserInterjectionResponse,
+    SuggestExpertsRequest, SuggestExpertsResponse, TurnStance, UserInterjectionInput,
+    UserInterjectionResponse,
 };
 pub use roundtable_manager::RoundtableManager;
 pub use task_decomposer::{
-    DecomposeEpicInput, DecompositionResult, FileOperation, FileReference, ParallelGroup,
-    ParentTask, TaskCategory, TaskDecomposer, TaskStep, TaskTemplate,
+    generate_test_plan, DecomposeEpicInput, DecompositionResult, FileOperation, FileReference,
+    ParallelGroup, ParentTask, TaskCategory, TaskDecomposer, TaskStep, TaskTemplate,
+};
+pub use templates::renderer::{
+    render_template, validate_template, with_default_variables, TemplateError, TEMPLATE_VARIABLES,
 };
 pub use templates::TemplateManager;
+pub use traceability::{
+    PrdRequirement, RequirementTrace, TraceLink, TraceabilityMatrix, TracedEpic, TracedTask,
+};
 pub use types::*;
 pub use validation::{PRDSection, PRDValidator, ValidationResult as PRDValidationResult};
 
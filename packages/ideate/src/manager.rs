@@ -2,6 +2,7 @@
 // ABOUTME: Handles session lifecycle, section management, and status tracking
 
 use crate::error::{IdeateError, Result};
+use crate::question_banks::QuestionBankManager;
 use crate::types::*;
 use chrono::Utc;
 use sqlx::{Row, SqlitePool};
@@ -39,6 +40,12 @@ impl IdeateManager {
         .fetch_one(&self.db)
         .await?;
 
+        if let Some(bank_id) = &input.question_bank_id {
+            QuestionBankManager::new(self.db.clone())
+                .assign_to_session(&id, bank_id)
+                .await?;
+        }
+
         Ok(IdeateSession {
             id: session.get("id"),
             project_id: session.get("project_id"),
@@ -0,0 +1,441 @@
+// ABOUTME: Pluggable turn-taking strategies for expert roundtable discussions
+// ABOUTME: Picks the next speaker and stance, and decides when a discussion should conclude
+
+use crate::roundtable::*;
+use std::collections::HashMap;
+
+/// Default number of expert turns before the moderator concludes a discussion
+pub const DEFAULT_MAX_EXPERT_TURNS: usize = 10;
+
+/// Every Nth expert turn is a devil's-advocate challenge
+const DEVILS_ADVOCATE_INTERVAL: usize = 3;
+
+/// Share of recent turns that must agree before a discussion counts as converged
+const CONVERGENCE_THRESHOLD: f32 = 0.75;
+
+/// Phrases that signal an expert is aligning with the panel
+const AGREEMENT_MARKERS: &[&str] = &[
+    "i agree",
+    "agree with",
+    "agreed",
+    "consensus",
+    "aligned",
+    "building on",
+    "well said",
+    "exactly right",
+    "i echo",
+    "+1",
+];
+
+/// Phrases that signal an expert is pushing back on the panel
+const DISAGREEMENT_MARKERS: &[&str] = &[
+    "disagree",
+    "push back",
+    "not convinced",
+    "on the other hand",
+    "i'm concerned",
+    "i am concerned",
+    "however",
+];
+
+/// A turn-taking policy used by the moderator
+pub trait ModerationPolicy: Send + Sync {
+    /// Choose the next expert to speak and the stance they should take
+    fn next_turn(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<ModeratorTurn>;
+
+    /// Score from 0.0 to 1.0 indicating the discussion has converged, if this
+    /// policy ends discussions early
+    fn convergence_score(
+        &self,
+        _participants: &[ExpertPersona],
+        _messages: &[RoundtableMessage],
+    ) -> Option<f32> {
+        None
+    }
+}
+
+/// Build the policy that implements a moderation strategy
+pub fn policy_for(strategy: ModerationStrategy) -> Box<dyn ModerationPolicy> {
+    match strategy {
+        ModerationStrategy::RoundRobin => Box::new(RoundRobinPolicy),
+        ModerationStrategy::Debate => Box::new(DebatePolicy),
+        ModerationStrategy::DevilsAdvocate => Box::new(DevilsAdvocatePolicy),
+        ModerationStrategy::Convergence => Box::new(ConvergencePolicy),
+    }
+}
+
+/// Decide what should happen next in a discussion
+///
+/// Every strategy concludes once `max_turns` expert turns have been taken;
+/// strategies with convergence detection may conclude earlier.
+pub fn decide(
+    policy: &dyn ModerationPolicy,
+    participants: &[ExpertPersona],
+    messages: &[RoundtableMessage],
+    max_turns: usize,
+) -> Option<ModerationDecision> {
+    if expert_turns(messages).count() >= max_turns {
+        return Some(ModerationDecision::Conclude {
+            reason: ConclusionReason::MaxTurnsReached,
+            convergence_score: None,
+        });
+    }
+
+    if let Some(score) = policy.convergence_score(participants, messages) {
+        if score >= CONVERGENCE_THRESHOLD {
+            return Some(ModerationDecision::Conclude {
+                reason: ConclusionReason::Converged,
+                convergence_score: Some(score),
+            });
+        }
+    }
+
+    policy
+        .next_turn(participants, messages)
+        .map(|turn| ModerationDecision::NextTurn { turn })
+}
+
+/// Build the moderator's opening statement for a strategy
+pub fn strategy_opening(strategy: ModerationStrategy) -> &'static str {
+    match strategy {
+        ModerationStrategy::RoundRobin => {
+            "Each expert will take turns sharing their perspective."
+        }
+        ModerationStrategy::Debate => {
+            "This will be a structured debate: experts will alternate arguing for and against the proposal."
+        }
+        ModerationStrategy::DevilsAdvocate => {
+            "Periodically, an expert will be asked to play devil's advocate and challenge where the panel is heading."
+        }
+        ModerationStrategy::Convergence => {
+            "We'll wrap up as soon as the panel reaches a shared position."
+        }
+    }
+}
+
+// ============================================================================
+// POLICIES
+// ============================================================================
+
+/// Experts speak in participant order, least-spoken first
+pub struct RoundRobinPolicy;
+
+impl ModerationPolicy for RoundRobinPolicy {
+    fn next_turn(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<ModeratorTurn> {
+        least_spoken(participants, messages).map(|expert| turn(expert, TurnStance::Open, None))
+    }
+}
+
+/// Participants alternate between a pro side (even positions) and a con side
+/// (odd positions), with the least-spoken expert on each side going next
+pub struct DebatePolicy;
+
+impl ModerationPolicy for DebatePolicy {
+    fn next_turn(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<ModeratorTurn> {
+        let side_of = |expert_id: &str| {
+            participants
+                .iter()
+                .position(|p| p.id == expert_id)
+                .map(|index| {
+                    if index % 2 == 0 {
+                        TurnStance::Pro
+                    } else {
+                        TurnStance::Con
+                    }
+                })
+        };
+
+        let last_side = expert_turns(messages)
+            .last()
+            .and_then(|m| m.expert_id.as_deref())
+            .and_then(side_of);
+        let stance = match last_side {
+            Some(TurnStance::Pro) => TurnStance::Con,
+            _ => TurnStance::Pro,
+        };
+
+        let side: Vec<ExpertPersona> = participants
+            .iter()
+            .filter(|p| side_of(&p.id) == Some(stance))
+            .cloned()
+            .collect();
+        // A one-expert panel has nobody on the con side
+        let (candidates, stance) = if side.is_empty() {
+            (participants.to_vec(), TurnStance::Pro)
+        } else {
+            (side, stance)
+        };
+
+        let directive = match stance {
+            TurnStance::Con => {
+                "Argue against the proposal. Rebut the strongest point made in favor so far."
+            }
+            _ => "Argue in favor of the proposal. Rebut the strongest objection raised so far.",
+        };
+
+        least_spoken(&candidates, messages)
+            .map(|expert| turn(expert, stance, Some(directive.to_string())))
+    }
+}
+
+/// Round-robin where every few turns the speaker challenges the consensus
+pub struct DevilsAdvocatePolicy;
+
+impl ModerationPolicy for DevilsAdvocatePolicy {
+    fn next_turn(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<ModeratorTurn> {
+        let turn_number = expert_turns(messages).count() + 1;
+        let expert = least_spoken(participants, messages)?;
+
+        if turn_number.is_multiple_of(DEVILS_ADVOCATE_INTERVAL) {
+            Some(turn(
+                expert,
+                TurnStance::DevilsAdvocate,
+                Some(
+                    "Play devil's advocate: challenge the points the panel has agreed on and \
+                     surface risks or alternatives nobody has raised."
+                        .to_string(),
+                ),
+            ))
+        } else {
+            Some(turn(expert, TurnStance::Open, None))
+        }
+    }
+}
+
+/// Round-robin that reports convergence once recent turns agree
+pub struct ConvergencePolicy;
+
+impl ModerationPolicy for ConvergencePolicy {
+    fn next_turn(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<ModeratorTurn> {
+        RoundRobinPolicy.next_turn(participants, messages)
+    }
+
+    fn convergence_score(
+        &self,
+        participants: &[ExpertPersona],
+        messages: &[RoundtableMessage],
+    ) -> Option<f32> {
+        // Everyone needs a chance to state a position before the panel can
+        // agree, so the first full round never counts
+        let window = participants.len().max(2);
+        let turns: Vec<&RoundtableMessage> = expert_turns(messages).collect();
+        if turns.len() <= participants.len() || turns.len() < window {
+            return None;
+        }
+
+        let recent = &turns[turns.len() - window..];
+        let agreeing = recent
+            .iter()
+            .filter(|m| expresses_agreement(&m.content))
+            .count();
+        Some(agreeing as f32 / window as f32)
+    }
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+fn expert_turns(messages: &[RoundtableMessage]) -> impl Iterator<Item = &RoundtableMessage> {
+    messages.iter().filter(|m| m.is_expert_message())
+}
+
+/// Pick the expert who has spoken the least, breaking ties by participant order
+fn least_spoken<'a>(
+    participants: &'a [ExpertPersona],
+    messages: &[RoundtableMessage],
+) -> Option<&'a ExpertPersona> {
+    let mut speak_counts: HashMap<&str, usize> = HashMap::new();
+    for message in expert_turns(messages) {
+        if let Some(expert_id) = message.expert_id.as_deref() {
+            *speak_counts.entry(expert_id).or_default() += 1;
+        }
+    }
+
+    participants
+        .iter()
+        .min_by_key(|p| speak_counts.get(p.id.as_str()).copied().unwrap_or(0))
+}
+
+fn turn(expert: &ExpertPersona, stance: TurnStance, directive: Option<String>) -> ModeratorTurn {
+    ModeratorTurn {
+        expert_id: expert.id.clone(),
+        expert_name: expert.name.clone(),
+        stance,
+        directive,
+    }
+}
+
+fn expresses_agreement(content: &str) -> bool {
+    let content = content.to_lowercase();
+    AGREEMENT_MARKERS.iter().any(|m| content.contains(m))
+        && !DISAGREEMENT_MARKERS.iter().any(|m| content.contains(m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn expert(id: &str) -> ExpertPersona {
+        ExpertPersona {
+            id: id.to_string(),
+            name: format!("Expert {}", id),
+            role: "Engineer".to_string(),
+            expertise: vec![],
+            system_prompt: String::new(),
+            bio: None,
+            is_default: false,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn said(order: i32, expert_id: &str, content: &str) -> RoundtableMessage {
+        RoundtableMessage {
+            id: format!("msg_{}", order),
+            roundtable_id: "rt".to_string(),
+            message_order: order,
+            role: MessageRole::Expert,
+            expert_id: Some(expert_id.to_string()),
+            expert_name: None,
+            content: content.to_string(),
+            metadata: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn next_turn(strategy: ModerationStrategy, messages: &[RoundtableMessage]) -> ModeratorTurn {
+        let participants = vec![expert("a"), expert("b"), expert("c")];
+        match decide(
+            policy_for(strategy).as_ref(),
+            &participants,
+            messages,
+            DEFAULT_MAX_EXPERT_TURNS,
+        ) {
+            Some(ModerationDecision::NextTurn { turn }) => turn,
+            other => panic!("expected a turn, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_round_robin_picks_least_spoken() {
+        let messages = vec![said(1, "a", "First"), said(2, "b", "Second")];
+        let turn = next_turn(ModerationStrategy::RoundRobin, &messages);
+        assert_eq!(turn.expert_id, "c");
+        assert_eq!(turn.stance, TurnStance::Open);
+    }
+
+    #[test]
+    fn test_debate_alternates_sides() {
+        // a and c argue pro, b argues con
+        let opening = next_turn(ModerationStrategy::Debate, &[]);
+        assert_eq!(opening.expert_id, "a");
+        assert_eq!(opening.stance, TurnStance::Pro);
+
+        let rebuttal = next_turn(ModerationStrategy::Debate, &[said(1, "a", "For")]);
+        assert_eq!(rebuttal.expert_id, "b");
+        assert_eq!(rebuttal.stance, TurnStance::Con);
+
+        let messages = vec![said(1, "a", "For"), said(2, "b", "Against")];
+        let third = next_turn(ModerationStrategy::Debate, &messages);
+        assert_eq!(third.expert_id, "c");
+        assert_eq!(third.stance, TurnStance::Pro);
+        assert!(third.directive.is_some());
+    }
+
+    #[test]
+    fn test_devils_advocate_injected_periodically() {
+        let messages = vec![said(1, "a", "One"), said(2, "b", "Two")];
+        let turn = next_turn(ModerationStrategy::DevilsAdvocate, &messages);
+        assert_eq!(turn.stance, TurnStance::DevilsAdvocate);
+
+        let turn = next_turn(ModerationStrategy::DevilsAdvocate, &messages[..1]);
+        assert_eq!(turn.stance, TurnStance::Open);
+    }
+
+    #[test]
+    fn test_convergence_concludes_early() {
+        let participants = vec![expert("a"), expert("b"), expert("c")];
+        let messages = vec![
+            said(1, "a", "We should use Postgres."),
+            said(2, "b", "I agree, Postgres fits."),
+            said(3, "c", "Agreed, consensus on Postgres."),
+            said(4, "a", "Building on that, let's also add read replicas."),
+        ];
+        let policy = policy_for(ModerationStrategy::Convergence);
+
+        match decide(policy.as_ref(), &participants, &messages, 10) {
+            Some(ModerationDecision::Conclude {
+                reason: ConclusionReason::Converged,
+                convergence_score: Some(score),
+            }) => assert!(score >= CONVERGENCE_THRESHOLD),
+            other => panic!("expected convergence, got {:?}", other),
+        }
+
+        // Round-robin never concludes early on the same discussion
+        let round_robin = policy_for(ModerationStrategy::RoundRobin);
+        assert!(matches!(
+            decide(round_robin.as_ref(), &participants, &messages, 10),
+            Some(ModerationDecision::NextTurn { .. })
+        ));
+    }
+
+    #[test]
+    fn test_disagreement_prevents_convergence() {
+        let participants = vec![expert("a"), expert("b")];
+        let messages = vec![
+            said(1, "a", "We should use Postgres."),
+            said(
+                2,
+                "b",
+                "I agree with the direction, however I disagree on hosting.",
+            ),
+            said(3, "a", "I'm not convinced either way."),
+        ];
+        let policy = policy_for(ModerationStrategy::Convergence);
+        assert!(matches!(
+            decide(policy.as_ref(), &participants, &messages, 10),
+            Some(ModerationDecision::NextTurn { .. })
+        ));
+    }
+
+    #[test]
+    fn test_max_turns_concludes_every_strategy() {
+        let participants = vec![expert("a"), expert("b")];
+        let messages = vec![said(1, "a", "One"), said(2, "b", "Two")];
+        for strategy in [
+            ModerationStrategy::RoundRobin,
+            ModerationStrategy::Debate,
+            ModerationStrategy::DevilsAdvocate,
+            ModerationStrategy::Convergence,
+        ] {
+            assert!(matches!(
+                decide(policy_for(strategy).as_ref(), &participants, &messages, 2),
+                Some(ModerationDecision::Conclude {
+                    reason: ConclusionReason::MaxTurnsReached,
+                    ..
+                })
+            ));
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// ABOUTME: Publishes PRDs to Confluence Cloud as pages in a space
+// ABOUTME: Converts page blocks to Confluence storage format and creates the page through the REST API
+
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use super::document::{Block, Span};
+use super::{api_error, PublishError, PublishedPage};
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn inline(spans: &[Span]) -> String {
+    spans
+        .iter()
+        .map(|span| {
+            let mut text = escape(&span.text);
+            if span.bold {
+                text = format!("<strong>{}</strong>", text);
+            }
+            match &span.link {
+                Some(url) => format!("<a href=\"{}\">{}</a>", escape(url), text),
+                None => text,
+            }
+        })
+        .collect()
+}
+
+/// Convert page blocks to Confluence storage format (XHTML with Confluence macros)
+pub fn confluence_storage(blocks: &[Block]) -> String {
+    let mut html = String::new();
+    // Open list tag, so consecutive items share one list
+    let mut open_list: Option<&str> = None;
+
+    for block in blocks {
+        let list = match block {
+            Block::Bullet(_) => Some("ul"),
+            Block::Numbered(_) => Some("ol"),
+            _ => None,
+        };
+        if open_list != list {
+            if let Some(tag) = open_list {
+                html.push_str(&format!("</{}>", tag));
+            }
+            if let Some(tag) = list {
+                html.push_str(&format!("<{}>", tag));
+            }
+            open_list = list;
+        }
+
+        match block {
+            Block::Heading(level, spans) => {
+                html.push_str(&format!("<h{0}>{1}</h{0}>", level, inline(spans)))
+            }
+            Block::Paragraph(spans) => html.push_str(&format!("<p>{}</p>", inline(spans))),
+            Block::Bullet(spans) | Block::Numbered(spans) => {
+                html.push_str(&format!("<li>{}</li>", inline(spans)))
+            }
+            Block::Code { language, code } => {
+                html.push_str("<ac:structured-macro ac:name=\"code\">");
+                if let Some(language) = language {
+                    html.push_str(&format!(
+                        "<ac:parameter ac:name=\"language\">{}</ac:parameter>",
+                        escape(language)
+                    ));
+                }
+                // "]]>" would end the CDATA section early, so split it across two sections
+                html.push_str(&format!(
+                    "<ac:plain-text-body><![CDATA[{}]]></ac:plain-text-body></ac:structured-macro>",
+                    code.replace("]]>", "]]]]><![CDATA[>")
+                ));
+            }
+            Block::Table(rows) => {
+                html.push_str("<table><tbody>");
+                for (index, row) in rows.iter().enumerate() {
+                    let cell = if index == 0 { "th" } else { "td" };
+                    html.push_str("<tr>");
+                    for spans in row {
+                        html.push_str(&format!("<{0}>{1}</{0}>", cell, inline(spans)));
+                    }
+                    html.push_str("</tr>");
+                }
+                html.push_str("</tbody></table>");
+            }
+            Block::Divider => html.push_str("<hr />"),
+        }
+    }
+    if let Some(tag) = open_list {
+        html.push_str(&format!("</{}>", tag));
+    }
+
+    html
+}
+
+/// REST endpoint for creating content on a Confluence Cloud site
+fn content_endpoint(site_url: &str) -> String {
+    let site_url = site_url.trim_end_matches('/');
+    if site_url.ends_with("/wiki") {
+        format!("{}/rest/api/content", site_url)
+    } else {
+        format!("{}/wiki/rest/api/content", site_url)
+    }
+}
+
+/// Creates pages through the Confluence Cloud REST API with an Atlassian API token
+pub struct ConfluencePublisher {
+    client: Client,
+    site_url: String,
+    account_email: String,
+    token: String,
+}
+
+impl ConfluencePublisher {
+    pub fn new(
+        site_url: impl Into<String>,
+        account_email: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: Client::new(),
+            site_url: site_url.into(),
+            account_email: account_email.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Create a page titled `title` in `space_key`, optionally nested under a parent page
+    pub async fn publish(
+        &self,
+        title: &str,
+        space_key: &str,
+        parent_page_id: Option<&str>,
+        storage: String,
+    ) -> Result<PublishedPage, PublishError> {
+        let mut body = json!({
+            "type": "page",
+            "title": title,
+            "space": { "key": space_key },
+            "body": { "storage": { "value": storage, "representation": "storage" } },
+        });
+        if let Some(parent) = parent_page_id {
+            body["ancestors"] = json!([{ "id": parent }]);
+        }
+
+        let response = self
+            .client
+            .post(content_endpoint(&self.site_url))
+            .basic_auth(&self.account_email, Some(&self.token))
+            .json(&body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(api_error("Confluence", response).await);
+        }
+
+        let page: Value = response.json().await?;
+        let url = format!(
+            "{}{}",
+            page["_links"]["base"].as_str().unwrap_or_default(),
+            page["_links"]["webui"].as_str().unwrap_or_default()
+        );
+        Ok(PublishedPage {
+            id: page["id"].as_str().unwrap_or_default().to_string(),
+            url,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_confluence_storage() {
+        let storage = confluence_storage(&[
+            Block::Heading(1, vec![Span::plain("Risks & Mitigations")]),
+            Block::Bullet(vec![Span::plain("one")]),
+            Block::Bullet(vec![Span::link("two", "https://example.com?a=1&b=2")]),
+            Block::Paragraph(vec![Span {
+                text: "done".to_string(),
+                bold: true,
+                link: None,
+            }]),
+            Block::Code {
+                language: Some("rust".to_string()),
+                code: "let end = \"]]>\";".to_string(),
+            },
+            Block::Table(vec![
+                vec![vec![Span::plain("Field")]],
+                vec![vec![Span::plain("Value")]],
+            ]),
+        ]);
+
+        assert!(storage.starts_with("<h1>Risks &amp; Mitigations</h1><ul><li>one</li>"));
+        assert!(storage.contains(
+            "<li><a href=\"https://example.com?a=1&amp;b=2\">two</a></li></ul><p><strong>done</strong></p>"
+        ));
+        assert!(storage.contains("<ac:parameter ac:name=\"language\">rust</ac:parameter>"));
+        assert!(storage.contains("]]]]><![CDATA[>"));
+        assert!(storage.ends_with(
+            "<table><tbody><tr><th>Field</th></tr><tr><td>Value</td></tr></tbody></table>"
+        ));
+    }
+
+    #[test]
+    fn test_content_endpoint() {
+        assert_eq!(
+            content_endpoint("https://acme.atlassian.net/"),
+            "https://acme.atlassian.net/wiki/rest/api/content"
+        );
+        assert_eq!(
+            content_endpoint("https://acme.atlassian.net/wiki"),
+            "https://acme.atlassian.net/wiki/rest/api/content"
+        );
+    }
+}
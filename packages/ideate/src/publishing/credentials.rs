@@ -0,0 +1,229 @@
+// ABOUTME: Per-user credentials for publishing PRDs to Notion and Confluence
+// ABOUTME: API tokens are encrypted at rest and only decrypted to publish a page
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+
+use crate::error::{IdeateError, Result};
+use crate::export_service::ExportFormat;
+use orkee_security::ApiKeyEncryption;
+
+/// A user's connection to a publishing target
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishingCredentials {
+    pub target: ExportFormat,
+    /// Notion integration token or Atlassian API token
+    pub api_token: String,
+    /// Confluence site, e.g. `https://acme.atlassian.net`
+    pub site_url: Option<String>,
+    /// Atlassian account the Confluence token belongs to
+    pub account_email: Option<String>,
+    /// Notion page or Confluence space key new pages go under
+    pub default_parent: Option<String>,
+}
+
+impl PublishingCredentials {
+    /// Check that the target and its required fields are present
+    pub fn validate(&self) -> Result<()> {
+        if !self.target.is_publishing_target() {
+            return Err(IdeateError::InvalidInput(format!(
+                "{} is not a publishing target",
+                self.target
+            )));
+        }
+        if self.api_token.trim().is_empty() {
+            return Err(IdeateError::InvalidInput(
+                "apiToken is required".to_string(),
+            ));
+        }
+        if self.target == ExportFormat::Confluence {
+            let missing =
+                |value: &Option<String>| value.as_deref().is_none_or(|v| v.trim().is_empty());
+            if missing(&self.site_url)
+                || missing(&self.account_email)
+                || missing(&self.default_parent)
+            {
+                return Err(IdeateError::InvalidInput(
+                    "Confluence needs siteUrl, accountEmail and a space key in defaultParent"
+                        .to_string(),
+                ));
+            }
+            let site_url = self.site_url.as_deref().unwrap_or_default();
+            if !site_url.starts_with("https://") {
+                return Err(IdeateError::InvalidInput(
+                    "siteUrl must be an https:// URL".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Publishing credentials without the token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaskedPublishingCredentials {
+    pub target: ExportFormat,
+    pub site_url: Option<String>,
+    pub account_email: Option<String>,
+    pub default_parent: Option<String>,
+    pub updated_at: String,
+}
+
+pub struct PublishingCredentialStorage {
+    pool: SqlitePool,
+    encryption: ApiKeyEncryption,
+}
+
+impl PublishingCredentialStorage {
+    pub fn new(pool: SqlitePool) -> Result<Self> {
+        let encryption = ApiKeyEncryption::new().map_err(|e| {
+            IdeateError::Encryption(format!("Failed to initialize encryption: {}", e))
+        })?;
+        Ok(Self { pool, encryption })
+    }
+
+    /// List the targets a user has connected
+    pub async fn list(&self, user_id: &str) -> Result<Vec<MaskedPublishingCredentials>> {
+        let rows = sqlx::query(
+            "SELECT target, site_url, account_email, default_parent, updated_at
+             FROM user_publishing_credentials
+             WHERE user_id = ?
+             ORDER BY target",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(MaskedPublishingCredentials {
+                    target: parse_target(row.try_get("target")?)?,
+                    site_url: row.try_get("site_url")?,
+                    account_email: row.try_get("account_email")?,
+                    default_parent: row.try_get("default_parent")?,
+                    updated_at: row.try_get("updated_at")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Create or replace a user's credentials for a target
+    pub async fn set(&self, user_id: &str, credentials: &PublishingCredentials) -> Result<()> {
+        credentials.validate()?;
+        debug!(
+            "Setting {} publishing credentials for user {}",
+            credentials.target, user_id
+        );
+
+        let encrypted_token = self
+            .encryption
+            .encrypt(credentials.api_token.trim())
+            .map_err(|e| IdeateError::Encryption(e.to_string()))?;
+
+        sqlx::query(
+            "INSERT INTO user_publishing_credentials
+                (user_id, target, encrypted_token, site_url, account_email, default_parent)
+             VALUES (?, ?, ?, ?, ?, ?)
+             ON CONFLICT(user_id, target) DO UPDATE SET
+                encrypted_token = excluded.encrypted_token,
+                site_url = excluded.site_url,
+                account_email = excluded.account_email,
+                default_parent = excluded.default_parent,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+        )
+        .bind(user_id)
+        .bind(credentials.target.to_string())
+        .bind(&encrypted_token)
+        .bind(&credentials.site_url)
+        .bind(&credentials.account_email)
+        .bind(&credentials.default_parent)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get a user's credentials for a target with the token decrypted
+    pub async fn get(
+        &self,
+        user_id: &str,
+        target: ExportFormat,
+    ) -> Result<Option<PublishingCredentials>> {
+        let row = sqlx::query(
+            "SELECT encrypted_token, site_url, account_email, default_parent
+             FROM user_publishing_credentials
+             WHERE user_id = ? AND target = ?",
+        )
+        .bind(user_id)
+        .bind(target.to_string())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let encrypted_token: String = row.try_get("encrypted_token")?;
+        let api_token = self
+            .encryption
+            .decrypt(&encrypted_token)
+            .map_err(|e| IdeateError::Encryption(e.to_string()))?;
+
+        Ok(Some(PublishingCredentials {
+            target,
+            api_token,
+            site_url: row.try_get("site_url")?,
+            account_email: row.try_get("account_email")?,
+            default_parent: row.try_get("default_parent")?,
+        }))
+    }
+
+    /// Disconnect a target. Returns false if it wasn't connected.
+    pub async fn delete(&self, user_id: &str, target: ExportFormat) -> Result<bool> {
+        let result =
+            sqlx::query("DELETE FROM user_publishing_credentials WHERE user_id = ? AND target = ?")
+                .bind(user_id)
+                .bind(target.to_string())
+                .execute(&self.pool)
+                .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+fn parse_target(value: String) -> Result<ExportFormat> {
+    serde_json::from_value(serde_json::Value::String(value)).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials(target: ExportFormat) -> PublishingCredentials {
+        PublishingCredentials {
+            target,
+            api_token: "secret_abc".to_string(),
+            site_url: None,
+            account_email: None,
+            default_parent: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_credentials() {
+        assert!(credentials(ExportFormat::Notion).validate().is_ok());
+        assert!(credentials(ExportFormat::Markdown).validate().is_err());
+
+        let mut confluence = credentials(ExportFormat::Confluence);
+        assert!(confluence.validate().is_err());
+        confluence.site_url = Some("https://acme.atlassian.net".to_string());
+        confluence.account_email = Some("pm@acme.test".to_string());
+        confluence.default_parent = Some("PROD".to_string());
+        assert!(confluence.validate().is_ok());
+
+        confluence.site_url = Some("http://acme.atlassian.net".to_string());
+        assert!(confluence.validate().is_err());
+    }
+}
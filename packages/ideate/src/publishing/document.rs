@@ -0,0 +1,256 @@
+// ABOUTME: Structured view of a PRD's markdown shared by the Notion and Confluence renderers
+// ABOUTME: Parses headings, lists, tables, code blocks, and inline bold text and links
+
+/// A run of text with inline formatting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub text: String,
+    pub bold: bool,
+    pub link: Option<String>,
+}
+
+impl Span {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            bold: false,
+            link: None,
+        }
+    }
+
+    pub fn link(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            bold: false,
+            link: Some(url.into()),
+        }
+    }
+}
+
+/// A block of a published page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    /// Heading level 1 to 3; deeper markdown headings are clamped to 3
+    Heading(u8, Vec<Span>),
+    Paragraph(Vec<Span>),
+    Bullet(Vec<Span>),
+    Numbered(Vec<Span>),
+    Code {
+        language: Option<String>,
+        code: String,
+    },
+    /// Rows of cells; the first row is the header
+    Table(Vec<Vec<Vec<Span>>>),
+    Divider,
+}
+
+/// Split markdown into page blocks.
+///
+/// Front matter (a leading `---` fenced block) is dropped; publishing targets
+/// show that metadata in their own table instead.
+pub fn parse_markdown(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut lines = markdown.lines().peekable();
+
+    if lines.peek().map(|line| line.trim()) == Some("---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line.trim() == "---" {
+                break;
+            }
+        }
+    }
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if let Some(language) = trimmed.strip_prefix("```") {
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            blocks.push(Block::Code {
+                language: (!language.trim().is_empty()).then(|| language.trim().to_string()),
+                code: code.join("\n"),
+            });
+        } else if trimmed.is_empty() {
+            continue;
+        } else if trimmed == "---" || trimmed == "***" {
+            blocks.push(Block::Divider);
+        } else if let Some((level, text)) = heading(trimmed) {
+            blocks.push(Block::Heading(level, parse_inline(text)));
+        } else if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            blocks.push(Block::Bullet(parse_inline(item)));
+        } else if let Some(item) = numbered_item(trimmed) {
+            blocks.push(Block::Numbered(parse_inline(item)));
+        } else if trimmed.starts_with('|') {
+            let mut rows = vec![table_row(trimmed)];
+            while let Some(next) = lines.peek().map(|line| line.trim()) {
+                if !next.starts_with('|') {
+                    break;
+                }
+                if !is_separator_row(next) {
+                    rows.push(table_row(next));
+                }
+                lines.next();
+            }
+            blocks.push(Block::Table(rows));
+        } else {
+            blocks.push(Block::Paragraph(parse_inline(trimmed)));
+        }
+    }
+
+    blocks
+}
+
+/// Split `## Title` into its level (at most 3) and text
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if level == 0 {
+        return None;
+    }
+    let text = line[level..].strip_prefix(' ')?;
+    Some((level.min(3) as u8, text.trim()))
+}
+
+fn numbered_item(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..].strip_prefix(". ")
+}
+
+fn table_row(line: &str) -> Vec<Vec<Span>> {
+    line.trim_matches('|')
+        .split('|')
+        .map(|cell| parse_inline(cell.trim()))
+        .collect()
+}
+
+fn is_separator_row(line: &str) -> bool {
+    line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+/// Parse `**bold**` and `[text](url)` inline formatting
+pub fn parse_inline(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    let flush = |plain: &mut String, spans: &mut Vec<Span>| {
+        if !plain.is_empty() {
+            spans.push(Span::plain(std::mem::take(plain)));
+        }
+    };
+
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**") {
+            if let Some(end) = after.find("**") {
+                flush(&mut plain, &mut spans);
+                spans.push(Span {
+                    text: after[..end].to_string(),
+                    bold: true,
+                    link: None,
+                });
+                rest = &after[end + 2..];
+                continue;
+            }
+        }
+        if c == '[' {
+            if let Some((label, url, after)) = split_link(rest) {
+                flush(&mut plain, &mut spans);
+                spans.push(Span::link(label, url));
+                rest = after;
+                continue;
+            }
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    flush(&mut plain, &mut spans);
+
+    spans
+}
+
+/// Split `[label](url)rest` into its parts
+fn split_link(text: &str) -> Option<(&str, &str, &str)> {
+    let close = text.find("](")?;
+    let label = &text[1..close];
+    let after = &text[close + 2..];
+    let end = after.find(')')?;
+    Some((label, &after[..end], &after[end + 1..]))
+}
+
+/// Text of spans without formatting
+pub fn plain_text(spans: &[Span]) -> String {
+    spans.iter().map(|span| span.text.as_str()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_blocks() {
+        let markdown = "---\ntitle: PRD\n---\n# Product\n\n## 1. Overview\nSome **bold** text.\n\n- first\n- second\n1. step\n\n```rust\nfn main() {}\n```\n\n| Name | Value |\n|------|-------|\n| a | [docs](https://example.com) |\n";
+        let blocks = parse_markdown(markdown);
+
+        assert_eq!(blocks[0], Block::Heading(1, vec![Span::plain("Product")]));
+        assert_eq!(
+            blocks[1],
+            Block::Heading(2, vec![Span::plain("1. Overview")])
+        );
+        assert_eq!(
+            blocks[2],
+            Block::Paragraph(vec![
+                Span::plain("Some "),
+                Span {
+                    text: "bold".to_string(),
+                    bold: true,
+                    link: None
+                },
+                Span::plain(" text."),
+            ])
+        );
+        assert_eq!(blocks[3], Block::Bullet(vec![Span::plain("first")]));
+        assert_eq!(blocks[5], Block::Numbered(vec![Span::plain("step")]));
+        assert_eq!(
+            blocks[6],
+            Block::Code {
+                language: Some("rust".to_string()),
+                code: "fn main() {}".to_string()
+            }
+        );
+        match &blocks[7] {
+            Block::Table(rows) => {
+                assert_eq!(rows.len(), 2);
+                assert_eq!(rows[1][1], vec![Span::link("docs", "https://example.com")]);
+            }
+            other => panic!("expected a table, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deep_headings_are_clamped() {
+        let blocks = parse_markdown("#### Persona\n#hashtag");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading(3, vec![Span::plain("Persona")]),
+                Block::Paragraph(vec![Span::plain("#hashtag")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unclosed_formatting_stays_plain() {
+        assert_eq!(parse_inline("a ** b [c"), vec![Span::plain("a ** b [c")]);
+    }
+}
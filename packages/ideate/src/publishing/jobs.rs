@@ -0,0 +1,248 @@
+// ABOUTME: Background jobs that publish a session's PRD to Notion or Confluence
+// ABOUTME: Tracks each job's status so clients can poll for the published page URL
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::error::{IdeateError, Result};
+use crate::export_service::ExportFormat;
+
+/// Where a publishing job is up to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl ExportJobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportJobStatus::Queued => "queued",
+            ExportJobStatus::Running => "running",
+            ExportJobStatus::Completed => "completed",
+            ExportJobStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for ExportJobStatus {
+    type Err = IdeateError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "queued" => Ok(ExportJobStatus::Queued),
+            "running" => Ok(ExportJobStatus::Running),
+            "completed" => Ok(ExportJobStatus::Completed),
+            "failed" => Ok(ExportJobStatus::Failed),
+            _ => Err(IdeateError::InvalidStatus(s.to_string())),
+        }
+    }
+}
+
+/// A request to publish a PRD and its outcome
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportJob {
+    pub id: String,
+    pub session_id: String,
+    pub prd_id: Option<String>,
+    pub format: ExportFormat,
+    pub status: ExportJobStatus,
+    /// URL of the published page once the job completes
+    pub page_url: Option<String>,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub completed_at: Option<String>,
+}
+
+const JOB_COLUMNS: &str =
+    "id, session_id, prd_id, format, status, page_url, error, created_at, completed_at";
+
+#[derive(Clone)]
+pub struct ExportJobStorage {
+    pool: SqlitePool,
+}
+
+impl ExportJobStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Queue a job
+    pub async fn create(
+        &self,
+        session_id: &str,
+        prd_id: Option<&str>,
+        user_id: &str,
+        format: ExportFormat,
+    ) -> Result<ExportJob> {
+        let id = nanoid::nanoid!(16);
+
+        sqlx::query(
+            "INSERT INTO ideate_export_jobs (id, session_id, prd_id, user_id, format, status)
+             VALUES (?, ?, ?, ?, ?, 'queued')",
+        )
+        .bind(&id)
+        .bind(session_id)
+        .bind(prd_id)
+        .bind(user_id)
+        .bind(format.to_string())
+        .execute(&self.pool)
+        .await?;
+
+        self.get(&id)
+            .await?
+            .ok_or_else(|| IdeateError::NotFound(format!("Export job {}", id)))
+    }
+
+    pub async fn get(&self, id: &str) -> Result<Option<ExportJob>> {
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM ideate_export_jobs WHERE id = ?",
+            JOB_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(row_to_job).transpose()
+    }
+
+    /// List a session's jobs, newest first
+    pub async fn list_for_session(&self, session_id: &str) -> Result<Vec<ExportJob>> {
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM ideate_export_jobs WHERE session_id = ? ORDER BY created_at DESC",
+            JOB_COLUMNS
+        ))
+        .bind(session_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(row_to_job).collect()
+    }
+
+    pub async fn mark_running(&self, id: &str) -> Result<()> {
+        self.set_status(id, ExportJobStatus::Running, None, None)
+            .await
+    }
+
+    pub async fn complete(&self, id: &str, page_url: &str) -> Result<()> {
+        self.set_status(id, ExportJobStatus::Completed, Some(page_url), None)
+            .await
+    }
+
+    pub async fn fail(&self, id: &str, error: &str) -> Result<()> {
+        self.set_status(id, ExportJobStatus::Failed, None, Some(error))
+            .await
+    }
+
+    async fn set_status(
+        &self,
+        id: &str,
+        status: ExportJobStatus,
+        page_url: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<()> {
+        let finished = matches!(status, ExportJobStatus::Completed | ExportJobStatus::Failed);
+        sqlx::query(
+            "UPDATE ideate_export_jobs
+             SET status = ?, page_url = ?, error = ?,
+                 completed_at = CASE WHEN ? THEN strftime('%Y-%m-%dT%H:%M:%SZ', 'now') END
+             WHERE id = ?",
+        )
+        .bind(status.as_str())
+        .bind(page_url)
+        .bind(error)
+        .bind(finished)
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+fn row_to_job(row: sqlx::sqlite::SqliteRow) -> Result<ExportJob> {
+    let format: String = row.try_get("format")?;
+    let status: String = row.try_get("status")?;
+    Ok(ExportJob {
+        id: row.try_get("id")?,
+        session_id: row.try_get("session_id")?,
+        prd_id: row.try_get("prd_id")?,
+        format: serde_json::from_value(serde_json::Value::String(format))?,
+        status: status.parse()?,
+        page_url: row.try_get("page_url")?,
+        error: row.try_get("error")?,
+        created_at: row.try_get("created_at")?,
+        completed_at: row.try_get("completed_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_storage() -> ExportJobStorage {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query(
+            "CREATE TABLE ideate_export_jobs (
+                id TEXT PRIMARY KEY,
+                session_id TEXT NOT NULL,
+                prd_id TEXT,
+                user_id TEXT NOT NULL,
+                format TEXT NOT NULL,
+                status TEXT NOT NULL DEFAULT 'queued',
+                page_url TEXT,
+                error TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                completed_at TEXT
+            )",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        ExportJobStorage::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_job_lifecycle() {
+        let storage = setup_storage().await;
+        let job = storage
+            .create(
+                "session-1",
+                Some("prd-1"),
+                "default-user",
+                ExportFormat::Notion,
+            )
+            .await
+            .unwrap();
+        assert_eq!(job.status, ExportJobStatus::Queued);
+        assert_eq!(job.format, ExportFormat::Notion);
+
+        storage.mark_running(&job.id).await.unwrap();
+        storage
+            .complete(&job.id, "https://www.notion.so/Product-abc")
+            .await
+            .unwrap();
+        let done = storage.get(&job.id).await.unwrap().unwrap();
+        assert_eq!(done.status, ExportJobStatus::Completed);
+        assert_eq!(
+            done.page_url.as_deref(),
+            Some("https://www.notion.so/Product-abc")
+        );
+        assert!(done.completed_at.is_some());
+
+        let failed = storage
+            .create("session-1", None, "default-user", ExportFormat::Confluence)
+            .await
+            .unwrap();
+        storage.fail(&failed.id, "space not found").await.unwrap();
+        let jobs = storage.list_for_session("session-1").await.unwrap();
+        assert_eq!(jobs.len(), 2);
+        assert!(jobs
+            .iter()
+            .any(|job| job.error.as_deref() == Some("space not found")));
+    }
+}
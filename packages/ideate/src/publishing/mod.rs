@@ -0,0 +1,321 @@
+// ABOUTME: Publishing targets that push generated PRDs to Notion and Confluence
+// ABOUTME: Renders PRD markdown as structured pages and runs publishing as background jobs
+
+pub mod confluence;
+pub mod credentials;
+pub mod document;
+pub mod jobs;
+pub mod notion;
+
+pub use confluence::{confluence_storage, ConfluencePublisher};
+pub use credentials::{
+    MaskedPublishingCredentials, PublishingCredentialStorage, PublishingCredentials,
+};
+pub use document::{parse_markdown, Block, Span};
+pub use jobs::{ExportJob, ExportJobStatus, ExportJobStorage};
+pub use notion::{notion_blocks, NotionPublisher};
+
+use serde_json::Value;
+use sqlx::{Row, SqlitePool};
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::error::{IdeateError, Result};
+use crate::export_service::ExportFormat;
+
+#[derive(Debug, Error)]
+pub enum PublishError {
+    #[error("HTTP request failed: {0}")]
+    RequestFailed(#[from] reqwest::Error),
+
+    #[error("{target} API error ({status}): {message}")]
+    ApiError {
+        target: &'static str,
+        status: u16,
+        message: String,
+    },
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// A page created on a publishing target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedPage {
+    pub id: String,
+    pub url: String,
+}
+
+/// A PRD to publish
+#[derive(Debug, Clone)]
+pub struct PublishRequest {
+    pub title: String,
+    pub markdown: String,
+    /// Where the PRD lives in Orkee; the published page links back to it
+    pub orkee_url: String,
+    pub project_name: Option<String>,
+    /// Parent page to nest under, overriding the target's default
+    pub parent_id: Option<String>,
+}
+
+/// Turn an API error response into a `PublishError`, keeping the API's message
+async fn api_error(target: &'static str, response: reqwest::Response) -> PublishError {
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    // Notion and Confluence both explain errors in a top-level "message" field
+    let message = serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|error| error["message"].as_str().map(str::to_string))
+        .unwrap_or(body);
+    PublishError::ApiError {
+        target,
+        status,
+        message,
+    }
+}
+
+/// Blocks of a published PRD page: a summary table and link back to Orkee,
+/// followed by the PRD itself
+pub fn page_blocks(request: &PublishRequest) -> Vec<Block> {
+    let cell = |text: &str| vec![Span::plain(text)];
+    let mut summary = vec![vec![cell("Field"), cell("Value")]];
+    if let Some(project) = &request.project_name {
+        summary.push(vec![cell("Project"), cell(project)]);
+    }
+    summary.push(vec![
+        cell("Published"),
+        cell(&chrono::Utc::now().format("%Y-%m-%d").to_string()),
+    ]);
+    summary.push(vec![
+        cell("Source"),
+        vec![Span::link("Open in Orkee", &request.orkee_url)],
+    ]);
+
+    let mut blocks = vec![
+        Block::Table(summary),
+        Block::Paragraph(vec![
+            Span::plain("Generated with Orkee Ideate. Edit the PRD in "),
+            Span::link("Orkee", &request.orkee_url),
+            Span::plain(" and publish again to update it."),
+        ]),
+        Block::Divider,
+    ];
+
+    let mut prd = parse_markdown(&request.markdown);
+    // The page title already names the document
+    if matches!(prd.first(), Some(Block::Heading(1, _))) {
+        prd.remove(0);
+    }
+    blocks.extend(prd);
+    blocks
+}
+
+/// Publish a PRD with a user's credentials for the target
+pub async fn publish(
+    credentials: &PublishingCredentials,
+    request: &PublishRequest,
+) -> std::result::Result<PublishedPage, PublishError> {
+    let blocks = page_blocks(request);
+
+    match credentials.target {
+        ExportFormat::Notion => {
+            let parent = request
+                .parent_id
+                .as_deref()
+                .or(credentials.default_parent.as_deref())
+                .ok_or_else(|| {
+                    PublishError::InvalidConfig(
+                        "Notion needs a parent page; set defaultParent or pass parentId"
+                            .to_string(),
+                    )
+                })?;
+            NotionPublisher::new(&credentials.api_token)
+                .publish(&request.title, parent, notion_blocks(&blocks))
+                .await
+        }
+        ExportFormat::Confluence => {
+            let (Some(site_url), Some(email), Some(space_key)) = (
+                credentials.site_url.as_deref(),
+                credentials.account_email.as_deref(),
+                credentials.default_parent.as_deref(),
+            ) else {
+                return Err(PublishError::InvalidConfig(
+                    "Confluence needs a site URL, account email and space key".to_string(),
+                ));
+            };
+            ConfluencePublisher::new(site_url, email, &credentials.api_token)
+                .publish(
+                    &request.title,
+                    space_key,
+                    request.parent_id.as_deref(),
+                    confluence_storage(&blocks),
+                )
+                .await
+        }
+        other => Err(PublishError::InvalidConfig(format!(
+            "{} is not a publishing target",
+            other
+        ))),
+    }
+}
+
+/// What to publish and where
+#[derive(Debug, Clone)]
+pub struct PublishOptions {
+    pub format: ExportFormat,
+    /// Page title; defaults to the PRD's title
+    pub title: Option<String>,
+    pub parent_id: Option<String>,
+    /// Base URL of the Orkee dashboard, used for links back to the PRD
+    pub orkee_url: String,
+}
+
+/// Publishes ideate sessions' saved PRDs as background jobs
+pub struct PublishingService {
+    pool: SqlitePool,
+    credentials: PublishingCredentialStorage,
+    jobs: ExportJobStorage,
+}
+
+impl PublishingService {
+    pub fn new(pool: SqlitePool) -> Result<Self> {
+        Ok(Self {
+            credentials: PublishingCredentialStorage::new(pool.clone())?,
+            jobs: ExportJobStorage::new(pool.clone()),
+            pool,
+        })
+    }
+
+    pub fn credentials(&self) -> &PublishingCredentialStorage {
+        &self.credentials
+    }
+
+    pub fn jobs(&self) -> &ExportJobStorage {
+        &self.jobs
+    }
+
+    /// Queue publishing the PRD saved from a session.
+    ///
+    /// Returns the queued job straight away; poll it for the page URL.
+    pub async fn start_publish(
+        &self,
+        session_id: &str,
+        user_id: &str,
+        options: PublishOptions,
+    ) -> Result<ExportJob> {
+        if !options.format.is_publishing_target() {
+            return Err(IdeateError::InvalidInput(format!(
+                "{} is not a publishing target; use notion or confluence",
+                options.format
+            )));
+        }
+
+        let row = sqlx::query(
+            "SELECT p.id, p.title, p.content_markdown, p.project_id, pr.name AS project_name
+             FROM ideate_sessions s
+             JOIN prds p ON p.id = s.generated_prd_id
+             LEFT JOIN projects pr ON pr.id = p.project_id
+             WHERE s.id = ? AND p.deleted_at IS NULL",
+        )
+        .bind(session_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| {
+            IdeateError::NotFound(format!(
+                "No saved PRD for session {}; save the PRD before publishing",
+                session_id
+            ))
+        })?;
+        let prd_id: String = row.try_get("id")?;
+        let prd_title: String = row.try_get("title")?;
+        let project_id: String = row.try_get("project_id")?;
+
+        let credentials = self
+            .credentials
+            .get(user_id, options.format)
+            .await?
+            .ok_or_else(|| {
+                IdeateError::InvalidInput(format!(
+                    "{} is not connected; add an API token first",
+                    options.format
+                ))
+            })?;
+
+        let request = PublishRequest {
+            title: options.title.unwrap_or(prd_title),
+            markdown: row.try_get("content_markdown")?,
+            orkee_url: format!(
+                "{}/projects/{}",
+                options.orkee_url.trim_end_matches('/'),
+                project_id
+            ),
+            project_name: row.try_get("project_name")?,
+            parent_id: options.parent_id,
+        };
+
+        let job = self
+            .jobs
+            .create(session_id, Some(&prd_id), user_id, options.format)
+            .await?;
+        info!(
+            "Publishing PRD {} to {} (job {})",
+            prd_id, options.format, job.id
+        );
+
+        let jobs = self.jobs.clone();
+        let job_id = job.id.clone();
+        tokio::spawn(async move {
+            if let Err(e) = jobs.mark_running(&job_id).await {
+                warn!("Failed to mark export job {} running: {}", job_id, e);
+            }
+            let outcome = match publish(&credentials, &request).await {
+                Ok(page) => jobs.complete(&job_id, &page.url).await,
+                Err(e) => {
+                    warn!("Export job {} failed: {}", job_id, e);
+                    jobs.fail(&job_id, &e.to_string()).await
+                }
+            };
+            if let Err(e) = outcome {
+                warn!("Failed to record outcome of export job {}: {}", job_id, e);
+            }
+        });
+
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_blocks() {
+        let blocks = page_blocks(&PublishRequest {
+            title: "Checkout revamp".to_string(),
+            markdown: "# Product Requirements Document\n\n## 1. Overview\nFaster checkout."
+                .to_string(),
+            orkee_url: "http://localhost:5173/projects/p1".to_string(),
+            project_name: Some("Shop".to_string()),
+            parent_id: None,
+        });
+
+        match &blocks[0] {
+            Block::Table(rows) => {
+                assert_eq!(rows[1][1], vec![Span::plain("Shop")]);
+                assert_eq!(
+                    rows.last().unwrap()[1],
+                    vec![Span::link(
+                        "Open in Orkee",
+                        "http://localhost:5173/projects/p1"
+                    )]
+                );
+            }
+            other => panic!("expected the summary table, got {:?}", other),
+        }
+        // The PRD's own title heading is dropped in favour of the page title
+        assert_eq!(
+            blocks[3],
+            Block::Heading(2, vec![Span::plain("1. Overview")])
+        );
+    }
+}
@@ -0,0 +1,255 @@
+// ABOUTME: Publishes PRDs to Notion as pages under a parent page
+// ABOUTME: Converts page blocks to Notion block objects and creates the page through the Notion API
+
+use reqwest::Client;
+use serde_json::{json, Value};
+
+use super::document::{Block, Span};
+use super::{api_error, PublishError, PublishedPage};
+
+const NOTION_API_URL: &str = "https://api.notion.com/v1";
+const NOTION_VERSION: &str = "2022-06-28";
+
+/// Notion accepts at most 100 child blocks per request
+const MAX_BLOCKS_PER_REQUEST: usize = 100;
+
+/// Notion rejects rich text objects longer than this many characters
+const MAX_TEXT_LEN: usize = 2000;
+
+/// Code block languages Notion recognises, after mapping common aliases
+const NOTION_LANGUAGES: &[&str] = &[
+    "bash",
+    "c",
+    "c++",
+    "css",
+    "go",
+    "html",
+    "java",
+    "javascript",
+    "json",
+    "markdown",
+    "python",
+    "ruby",
+    "rust",
+    "shell",
+    "sql",
+    "typescript",
+    "yaml",
+];
+
+/// Notion rich text objects for formatted spans, split to respect Notion's length limit
+pub fn rich_text(spans: &[Span]) -> Vec<Value> {
+    let mut objects = Vec::new();
+    for span in spans {
+        let chars: Vec<char> = span.text.chars().collect();
+        for chunk in chars.chunks(MAX_TEXT_LEN) {
+            let content: String = chunk.iter().collect();
+            let link = span.link.as_ref().map(|url| json!({ "url": url }));
+            objects.push(json!({
+                "type": "text",
+                "text": { "content": content, "link": link },
+                "annotations": { "bold": span.bold },
+            }));
+        }
+    }
+    objects
+}
+
+fn text_block(kind: &str, spans: &[Span]) -> Value {
+    let mut block = json!({ "object": "block", "type": kind });
+    block[kind] = json!({ "rich_text": rich_text(spans) });
+    block
+}
+
+fn code_language(language: Option<&str>) -> &'static str {
+    let language = match language.map(str::to_lowercase).as_deref() {
+        Some("js") => "javascript".to_string(),
+        Some("ts") | Some("tsx") => "typescript".to_string(),
+        Some("sh") | Some("zsh") => "shell".to_string(),
+        Some("yml") => "yaml".to_string(),
+        Some("md") => "markdown".to_string(),
+        Some(other) => other.to_string(),
+        None => String::new(),
+    };
+    NOTION_LANGUAGES
+        .iter()
+        .find(|known| **known == language)
+        .copied()
+        .unwrap_or("plain text")
+}
+
+/// Convert page blocks to Notion block objects
+pub fn notion_blocks(blocks: &[Block]) -> Vec<Value> {
+    blocks
+        .iter()
+        .map(|block| match block {
+            Block::Heading(level, spans) => {
+                text_block(&format!("heading_{}", (*level).clamp(1, 3)), spans)
+            }
+            Block::Paragraph(spans) => text_block("paragraph", spans),
+            Block::Bullet(spans) => text_block("bulleted_list_item", spans),
+            Block::Numbered(spans) => text_block("numbered_list_item", spans),
+            Block::Code { language, code } => json!({
+                "object": "block",
+                "type": "code",
+                "code": {
+                    "rich_text": rich_text(&[Span::plain(code.clone())]),
+                    "language": code_language(language.as_deref()),
+                },
+            }),
+            Block::Table(rows) => {
+                let width = rows.iter().map(Vec::len).max().unwrap_or(1).max(1);
+                let children: Vec<Value> = rows
+                    .iter()
+                    .map(|row| {
+                        // Every row needs exactly `width` cells
+                        let cells: Vec<Value> = (0..width)
+                            .map(|i| {
+                                Value::Array(
+                                    row.get(i).map(|cell| rich_text(cell)).unwrap_or_default(),
+                                )
+                            })
+                            .collect();
+                        json!({
+                            "object": "block",
+                            "type": "table_row",
+                            "table_row": { "cells": cells },
+                        })
+                    })
+                    .collect();
+                json!({
+                    "object": "block",
+                    "type": "table",
+                    "table": {
+                        "table_width": width,
+                        "has_column_header": true,
+                        "has_row_header": false,
+                        "children": children,
+                    },
+                })
+            }
+            Block::Divider => json!({ "object": "block", "type": "divider", "divider": {} }),
+        })
+        .collect()
+}
+
+/// Creates pages through the Notion API with an integration token
+pub struct NotionPublisher {
+    client: Client,
+    token: String,
+}
+
+impl NotionPublisher {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            token: token.into(),
+        }
+    }
+
+    /// Create a page titled `title` under `parent_page_id` containing `blocks`
+    pub async fn publish(
+        &self,
+        title: &str,
+        parent_page_id: &str,
+        blocks: Vec<Value>,
+    ) -> Result<PublishedPage, PublishError> {
+        let mut batches = blocks.chunks(MAX_BLOCKS_PER_REQUEST);
+        let first = batches.next().unwrap_or_default();
+
+        let body = json!({
+            "parent": { "page_id": parent_page_id },
+            "properties": {
+                "title": { "title": [{ "type": "text", "text": { "content": title } }] },
+            },
+            "children": first,
+        });
+        let response = self
+            .client
+            .post(format!("{}/pages", NOTION_API_URL))
+            .bearer_auth(&self.token)
+            .header("Notion-Version", NOTION_VERSION)
+            .json(&body)
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(api_error("Notion", response).await);
+        }
+        let page: Value = response.json().await?;
+        let page_id = page["id"].as_str().unwrap_or_default().to_string();
+        let url = page["url"].as_str().unwrap_or_default().to_string();
+
+        // Pages longer than one request are appended to in order
+        for batch in batches {
+            let response = self
+                .client
+                .patch(format!("{}/blocks/{}/children", NOTION_API_URL, page_id))
+                .bearer_auth(&self.token)
+                .header("Notion-Version", NOTION_VERSION)
+                .json(&json!({ "children": batch }))
+                .send()
+                .await?;
+            if !response.status().is_success() {
+                return Err(api_error("Notion", response).await);
+            }
+        }
+
+        Ok(PublishedPage { id: page_id, url })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notion_blocks() {
+        let blocks = notion_blocks(&[
+            Block::Heading(2, vec![Span::plain("Overview")]),
+            Block::Bullet(vec![Span::link("Orkee", "http://localhost:5173")]),
+            Block::Code {
+                language: Some("ts".to_string()),
+                code: "let x = 1".to_string(),
+            },
+            Block::Table(vec![
+                vec![vec![Span::plain("Field")], vec![Span::plain("Value")]],
+                vec![vec![Span::plain("Status")]],
+            ]),
+        ]);
+
+        assert_eq!(blocks[0]["type"], "heading_2");
+        assert_eq!(
+            blocks[0]["heading_2"]["rich_text"][0]["text"]["content"],
+            "Overview"
+        );
+        assert_eq!(
+            blocks[1]["bulleted_list_item"]["rich_text"][0]["text"]["link"]["url"],
+            "http://localhost:5173"
+        );
+        assert_eq!(blocks[2]["code"]["language"], "typescript");
+
+        let table = &blocks[3]["table"];
+        assert_eq!(table["table_width"], 2);
+        // Short rows are padded to the table width
+        assert_eq!(
+            table["children"][1]["table_row"]["cells"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_long_text_is_split() {
+        let text = rich_text(&[Span::plain("x".repeat(MAX_TEXT_LEN + 1))]);
+        assert_eq!(text.len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_code_language_is_plain_text() {
+        assert_eq!(code_language(Some("brainfuck")), "plain text");
+        assert_eq!(code_language(None), "plain text");
+        assert_eq!(code_language(Some("Rust")), "rust");
+    }
+}
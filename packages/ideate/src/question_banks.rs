@@ -0,0 +1,598 @@
+// ABOUTME: Custom discovery question banks owned by a user or shared across the organization
+// ABOUTME: Stores ordered, categorized questions with conditional follow-ups and picks a session's next question
+
+use crate::chat::QuestionCategory;
+use crate::discovery_manager::{DiscoveryAnswer, Question, QuestionType};
+use crate::error::{IdeateError, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::collections::{HashMap, HashSet};
+use tracing::info;
+
+/// Most questions a single bank may hold
+const MAX_BANK_QUESTIONS: usize = 100;
+
+/// Asked once every applicable bank question has been answered
+const CLOSING_QUESTION: &str = "Is there anything else you'd like to add?";
+
+/// Ask a question only when an earlier question was answered a certain way
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowUpCondition {
+    /// `id` of an earlier question in the same bank
+    pub question_id: String,
+    /// Answers (compared case-insensitively) that trigger the follow-up
+    pub answer_in: Vec<String>,
+}
+
+/// A question in a custom bank
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BankQuestion {
+    /// Stable key that follow-up conditions refer to
+    pub id: String,
+    pub text: String,
+    pub question_type: QuestionType,
+    /// Choices for multiple-choice questions
+    #[serde(default)]
+    pub options: Option<Vec<String>>,
+    pub category: QuestionCategory,
+    #[serde(default)]
+    pub can_skip: bool,
+    /// Only ask this question when the condition holds
+    #[serde(default)]
+    pub ask_if: Option<FollowUpCondition>,
+}
+
+/// A named, ordered set of discovery questions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionBank {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Visible to, and selectable by, every user of this Orkee instance
+    pub shared: bool,
+    /// Questions in the order they are asked
+    pub questions: Vec<BankQuestion>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for creating or replacing a question bank
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionBankInput {
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub shared: bool,
+    pub questions: Vec<BankQuestion>,
+}
+
+impl QuestionBankInput {
+    /// Check names, question keys, choices, and that every follow-up refers
+    /// to an earlier question
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(IdeateError::ValidationError(
+                "Question bank name is required".to_string(),
+            ));
+        }
+        if self.questions.is_empty() {
+            return Err(IdeateError::ValidationError(
+                "Question bank needs at least one question".to_string(),
+            ));
+        }
+        if self.questions.len() > MAX_BANK_QUESTIONS {
+            return Err(IdeateError::ValidationError(format!(
+                "Question bank can hold at most {} questions",
+                MAX_BANK_QUESTIONS
+            )));
+        }
+
+        let mut seen_ids = HashSet::new();
+        let mut seen_texts = HashSet::new();
+        for question in &self.questions {
+            if question.id.trim().is_empty() || question.text.trim().is_empty() {
+                return Err(IdeateError::ValidationError(
+                    "Every question needs an id and text".to_string(),
+                ));
+            }
+            if let Some(condition) = &question.ask_if {
+                if !seen_ids.contains(condition.question_id.as_str()) {
+                    return Err(IdeateError::ValidationError(format!(
+                        "Question '{}' follows up on '{}', which must come earlier in the bank",
+                        question.id, condition.question_id
+                    )));
+                }
+                if condition.answer_in.is_empty() {
+                    return Err(IdeateError::ValidationError(format!(
+                        "Question '{}' needs at least one triggering answer",
+                        question.id
+                    )));
+                }
+            }
+            if !seen_ids.insert(question.id.as_str()) {
+                return Err(IdeateError::ValidationError(format!(
+                    "Duplicate question id '{}'",
+                    question.id
+                )));
+            }
+            // Answers are matched back to questions by text
+            if !seen_texts.insert(question.text.trim()) {
+                return Err(IdeateError::ValidationError(format!(
+                    "Duplicate question text '{}'",
+                    question.text
+                )));
+            }
+            let option_count = question.options.as_ref().map_or(0, Vec::len);
+            if question.question_type == QuestionType::MultipleChoice && option_count < 2 {
+                return Err(IdeateError::ValidationError(format!(
+                    "Multiple-choice question '{}' needs at least two options",
+                    question.id
+                )));
+            }
+            if option_count > 26 {
+                return Err(IdeateError::ValidationError(format!(
+                    "Question '{}' has more options than letters A-Z",
+                    question.id
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl BankQuestion {
+    fn to_question(&self) -> Question {
+        let question = match self.question_type {
+            QuestionType::Open => Question::open(&self.text, self.category.clone()),
+            QuestionType::MultipleChoice => Question::multiple_choice(
+                &self.text,
+                self.options.clone().unwrap_or_default(),
+                self.category.clone(),
+            ),
+            QuestionType::YesNo => Question::yes_no(&self.text, self.category.clone()),
+        };
+        if self.can_skip {
+            question.skippable()
+        } else {
+            question
+        }
+    }
+}
+
+impl QuestionBank {
+    /// Pick the next question given the answers so far.
+    ///
+    /// Questions are asked in bank order; a follow-up is skipped unless the
+    /// question it depends on was answered with one of its trigger answers.
+    /// Returns the question and the number of questions that apply so far.
+    pub fn next_question(&self, answers: &[DiscoveryAnswer]) -> (Question, usize) {
+        let asked: HashMap<&str, &str> = answers
+            .iter()
+            .map(|a| (a.question_text.trim(), a.user_answer.trim()))
+            .collect();
+
+        let mut answers_by_id: HashMap<&str, &str> = HashMap::new();
+        let mut next = None;
+        let mut applicable = 0;
+
+        for question in &self.questions {
+            if let Some(condition) = &question.ask_if {
+                let triggered = answers_by_id
+                    .get(condition.question_id.as_str())
+                    .is_some_and(|answer| {
+                        condition
+                            .answer_in
+                            .iter()
+                            .any(|expected| expected.trim().eq_ignore_ascii_case(answer))
+                    });
+                if !triggered {
+                    continue;
+                }
+            }
+            applicable += 1;
+
+            match asked.get(question.text.trim()) {
+                Some(answer) => {
+                    answers_by_id.insert(question.id.as_str(), answer);
+                }
+                None if next.is_none() => next = Some(question),
+                None => {}
+            }
+        }
+
+        match next {
+            Some(question) => (question.to_question(), applicable),
+            None => (
+                Question::open(CLOSING_QUESTION, QuestionCategory::Problem).skippable(),
+                applicable + 1,
+            ),
+        }
+    }
+}
+
+/// Storage for custom question banks
+pub struct QuestionBankManager {
+    pool: SqlitePool,
+}
+
+impl QuestionBankManager {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// List the user's own banks and banks shared across the organization
+    pub async fn list(&self, user_id: &str) -> Result<Vec<QuestionBank>> {
+        let rows = sqlx::query(
+            "SELECT * FROM discovery_question_banks WHERE user_id = ? OR shared = 1 ORDER BY name",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_bank).collect()
+    }
+
+    /// Get a bank the user owns or that is shared with them
+    pub async fn get(&self, user_id: &str, bank_id: &str) -> Result<QuestionBank> {
+        let row = sqlx::query(
+            "SELECT * FROM discovery_question_banks WHERE id = ? AND (user_id = ? OR shared = 1)",
+        )
+        .bind(bank_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| IdeateError::NotFound(format!("Question bank {}", bank_id)))?;
+
+        row_to_bank(&row)
+    }
+
+    /// Create a bank owned by the user
+    pub async fn create(&self, user_id: &str, input: QuestionBankInput) -> Result<QuestionBank> {
+        input.validate()?;
+        info!(
+            "Creating question bank '{}' for user {}",
+            input.name, user_id
+        );
+
+        let id = nanoid::nanoid!();
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            INSERT INTO discovery_question_banks (
+                id, user_id, name, description, shared, questions, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(user_id)
+        .bind(input.name.trim())
+        .bind(&input.description)
+        .bind(input.shared)
+        .bind(serde_json::to_string(&input.questions)?)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| duplicate_name_or(e, &input.name))?;
+
+        self.get(user_id, &id).await
+    }
+
+    /// Replace a bank's name, sharing, and questions. Only the owner may edit.
+    pub async fn update(
+        &self,
+        user_id: &str,
+        bank_id: &str,
+        input: QuestionBankInput,
+    ) -> Result<QuestionBank> {
+        input.validate()?;
+        self.ensure_owner(user_id, bank_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE discovery_question_banks
+            SET name = ?, description = ?, shared = ?, questions = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(input.name.trim())
+        .bind(&input.description)
+        .bind(input.shared)
+        .bind(serde_json::to_string(&input.questions)?)
+        .bind(Utc::now())
+        .bind(bank_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| duplicate_name_or(e, &input.name))?;
+
+        self.get(user_id, bank_id).await
+    }
+
+    /// Delete a bank. Sessions that used it fall back to the default questions.
+    pub async fn delete(&self, user_id: &str, bank_id: &str) -> Result<()> {
+        self.ensure_owner(user_id, bank_id).await?;
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "UPDATE ideate_sessions SET question_bank_id = NULL WHERE question_bank_id = ?",
+        )
+        .bind(bank_id)
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM discovery_question_banks WHERE id = ?")
+            .bind(bank_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Select the bank a session's discovery questions come from
+    pub async fn assign_to_session(&self, session_id: &str, bank_id: &str) -> Result<()> {
+        sqlx::query("UPDATE ideate_sessions SET question_bank_id = ? WHERE id = ?")
+            .bind(bank_id)
+            .bind(session_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// The bank selected for a session, if any
+    pub async fn for_session(&self, session_id: &str) -> Result<Option<QuestionBank>> {
+        let row = sqlx::query(
+            r#"
+            SELECT b.* FROM discovery_question_banks b
+            JOIN ideate_sessions s ON s.question_bank_id = b.id
+            WHERE s.id = ?
+            "#,
+        )
+        .bind(session_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_bank).transpose()
+    }
+
+    async fn ensure_owner(&self, user_id: &str, bank_id: &str) -> Result<()> {
+        let bank = self.get(user_id, bank_id).await?;
+        if bank.user_id != user_id {
+            return Err(IdeateError::Forbidden(format!(
+                "Question bank {} is shared with you but owned by another user",
+                bank_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn duplicate_name_or(error: sqlx::Error, name: &str) -> IdeateError {
+    match &error {
+        sqlx::Error::Database(db) if db.is_unique_violation() => IdeateError::ValidationError(
+            format!("A question bank named '{}' already exists", name.trim()),
+        ),
+        _ => IdeateError::Database(error),
+    }
+}
+
+fn row_to_bank(row: &sqlx::sqlite::SqliteRow) -> Result<QuestionBank> {
+    let questions: String = row.try_get("questions")?;
+
+    Ok(QuestionBank {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description")?,
+        shared: row.try_get("shared")?,
+        questions: serde_json::from_str(&questions)?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question(id: &str, text: &str, ask_if: Option<(&str, &str)>) -> BankQuestion {
+        BankQuestion {
+            id: id.to_string(),
+            text: text.to_string(),
+            question_type: QuestionType::Open,
+            options: None,
+            category: QuestionCategory::Problem,
+            can_skip: false,
+            ask_if: ask_if.map(|(question_id, answer)| FollowUpCondition {
+                question_id: question_id.to_string(),
+                answer_in: vec![answer.to_string()],
+            }),
+        }
+    }
+
+    fn answer(text: &str, user_answer: &str) -> DiscoveryAnswer {
+        DiscoveryAnswer {
+            id: "answer-1".to_string(),
+            session_id: "session-1".to_string(),
+            question_number: 0,
+            question_text: text.to_string(),
+            question_type: QuestionType::Open,
+            options: None,
+            user_answer: user_answer.to_string(),
+            asked_at: String::new(),
+            answered_at: None,
+        }
+    }
+
+    fn bank(questions: Vec<BankQuestion>) -> QuestionBank {
+        QuestionBank {
+            id: "bank-1".to_string(),
+            user_id: "user-1".to_string(),
+            name: "Intake".to_string(),
+            description: None,
+            shared: false,
+            questions,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_next_question_follows_conditions() {
+        let bank = bank(vec![
+            question("budget", "Is there a fixed budget?", None),
+            question("amount", "What is the budget?", Some(("budget", "yes"))),
+            question("timeline", "When must it ship?", None),
+        ]);
+
+        let (next, total) = bank.next_question(&[]);
+        assert_eq!(next.question_text, "Is there a fixed budget?");
+        assert_eq!(total, 2);
+
+        let (next, total) = bank.next_question(&[answer("Is there a fixed budget?", "Yes")]);
+        assert_eq!(next.question_text, "What is the budget?");
+        assert_eq!(total, 3);
+
+        let (next, _) = bank.next_question(&[answer("Is there a fixed budget?", "No")]);
+        assert_eq!(next.question_text, "When must it ship?");
+
+        let (next, total) = bank.next_question(&[
+            answer("Is there a fixed budget?", "No"),
+            answer("When must it ship?", "Q3"),
+        ]);
+        assert_eq!(next.question_text, CLOSING_QUESTION);
+        assert!(next.can_skip);
+        assert_eq!(total, 3);
+    }
+
+    #[tokio::test]
+    async fn test_bank_storage_and_session_discovery() {
+        use crate::discovery_manager::DiscoveryManager;
+        use crate::manager::IdeateManager;
+        use crate::types::{CreateIdeateSessionInput, IdeateMode};
+
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO users (id, email, name, created_at, updated_at)
+             VALUES ('other-user', 'other@localhost', 'Other', datetime('now'), datetime('now'))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO projects (id, name, project_root) VALUES ('project-1', 'Project', '/tmp/project-1')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let banks = QuestionBankManager::new(pool.clone());
+        let mut choice = question("kind", "What kind of engagement is this?", None);
+        choice.question_type = QuestionType::MultipleChoice;
+        choice.options = Some(vec!["Audit".to_string(), "Build".to_string()]);
+        let bank = banks
+            .create(
+                "default-user",
+                QuestionBankInput {
+                    name: "Consulting intake".to_string(),
+                    description: None,
+                    shared: false,
+                    questions: vec![
+                        choice,
+                        question(
+                            "scope",
+                            "Which systems are in scope?",
+                            Some(("kind", "Audit")),
+                        ),
+                        question("deadline", "When is the deadline?", None),
+                    ],
+                },
+            )
+            .await
+            .unwrap();
+
+        // Private until shared, and only the owner may edit
+        assert!(banks.list("other-user").await.unwrap().is_empty());
+        let mut input = QuestionBankInput {
+            name: bank.name.clone(),
+            description: Some("Shared intake".to_string()),
+            shared: true,
+            questions: bank.questions.clone(),
+        };
+        let bank = banks
+            .update("default-user", &bank.id, input.clone())
+            .await
+            .unwrap();
+        assert_eq!(banks.list("other-user").await.unwrap().len(), 1);
+        input.shared = false;
+        assert!(matches!(
+            banks.update("other-user", &bank.id, input).await,
+            Err(IdeateError::Forbidden(_))
+        ));
+
+        let session = IdeateManager::new(pool.clone())
+            .create_session(CreateIdeateSessionInput {
+                project_id: "project-1".to_string(),
+                initial_description: "New client".to_string(),
+                mode: IdeateMode::Guided,
+                template_id: None,
+                research_tools_enabled: false,
+                question_bank_id: Some(bank.id.clone()),
+            })
+            .await
+            .unwrap();
+
+        let discovery = DiscoveryManager::new(pool.clone());
+        let first = discovery.get_next_question(&session.id).await.unwrap();
+        assert_eq!(first.question_text, "What kind of engagement is this?");
+        assert_eq!(first.total_questions, Some(2));
+        discovery
+            .save_answer(&session.id, 0, "Audit".to_string())
+            .await
+            .unwrap();
+        let second = discovery.get_next_question(&session.id).await.unwrap();
+        assert_eq!(second.question_text, "Which systems are in scope?");
+        assert_eq!(second.total_questions, Some(3));
+
+        banks.delete("default-user", &bank.id).await.unwrap();
+        assert!(banks.for_session(&session.id).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_banks() {
+        let input = |questions| QuestionBankInput {
+            name: "Intake".to_string(),
+            description: None,
+            shared: false,
+            questions,
+        };
+
+        assert!(input(vec![question("a", "A?", None)]).validate().is_ok());
+        assert!(input(vec![]).validate().is_err());
+        assert!(
+            input(vec![question("a", "A?", None), question("a", "B?", None)])
+                .validate()
+                .is_err()
+        );
+        // Follow-ups must refer to an earlier question
+        assert!(input(vec![
+            question("a", "A?", Some(("b", "yes"))),
+            question("b", "B?", None)
+        ])
+        .validate()
+        .is_err());
+
+        let mut choice = question("c", "Pick one", None);
+        choice.question_type = QuestionType::MultipleChoice;
+        choice.options = Some(vec!["Only".to_string()]);
+        assert!(input(vec![choice]).validate().is_err());
+    }
+}
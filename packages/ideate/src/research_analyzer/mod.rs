@@ -0,0 +1,536 @@
+// ABOUTME: Research analyzer utilities for competitor and project data management
+// ABOUTME: Handles web scraping, web search sources, data storage, and CRUD operations (AI moved to frontend)
+
+pub mod web_search;
+
+use crate::error::{IdeateError, Result};
+use crate::types::{Competitor, SimilarProject};
+use chrono::{DateTime, Utc};
+use futures::stream::{self, StreamExt};
+use scraper::{Html, Selector};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+use web_search::{SearchResult, WebSearchProvider};
+
+/// Maximum number of concurrent URL analyses
+const MAX_CONCURRENT_ANALYSES: usize = 3;
+
+/// Upper bound on results requested from a web search provider
+pub const MAX_SEARCH_RESULTS: usize = 10;
+
+/// Fetched page text kept per source; prompts truncate further
+const MAX_SOURCE_CONTENT_CHARS: usize = 20_000;
+
+/// Rate limiting delay between requests (milliseconds)
+const _RATE_LIMIT_DELAY_MS: u64 = 2000;
+
+/// Cache expiration for competitor analysis (24 hours)
+const _COMPETITOR_CACHE_HOURS: i64 = 24;
+
+/// Cache expiration for pattern extraction (1 hour)
+const _PATTERN_CACHE_HOURS: i64 = 1;
+
+/// UI/UX pattern extracted from analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UIPattern {
+    pub pattern_type: String, // layout, navigation, interaction, visual, content
+    pub name: String,
+    pub description: String,
+    pub benefits: String,
+    pub adoption_notes: String,
+}
+
+/// Gap analysis opportunity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Opportunity {
+    pub opportunity_type: String, // differentiation, improvement, gap
+    pub title: String,
+    pub description: String,
+    pub competitor_context: String,
+    pub recommendation: String,
+}
+
+/// Result of gap analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GapAnalysis {
+    pub opportunities: Vec<Opportunity>,
+    pub summary: String,
+}
+
+/// Lesson learned from similar projects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lesson {
+    pub category: String, // design, implementation, feature, ux, technical
+    pub insight: String,
+    pub application: String,
+    pub priority: String, // high, medium, low
+}
+
+/// Research synthesisresult
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchSynthesis {
+    pub key_findings: Vec<String>,
+    pub market_position: String,
+    pub differentiators: Vec<String>,
+    pub risks: Vec<String>,
+    pub recommendations: Vec<String>,
+}
+
+/// Web page gathered by a search provider, kept with its attribution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResearchSource {
+    pub id: String,
+    pub session_id: String,
+    /// Search provider that found the page (tavily, brave, searxng)
+    pub provider: String,
+    /// Query that surfaced the page
+    pub query: String,
+    pub title: String,
+    pub url: String,
+    pub snippet: Option<String>,
+    /// Extracted page text, when the page could be fetched
+    pub content: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ResearchSource {
+    fn from_row(row: &SqliteRow) -> Result<Self> {
+        Ok(Self {
+            id: row.try_get("id")?,
+            session_id: row.try_get("session_id")?,
+            provider: row.try_get("provider")?,
+            query: row.try_get("query")?,
+            title: row.try_get("title")?,
+            url: row.try_get("url")?,
+            snippet: row.try_get("snippet")?,
+            content: row.try_get("content")?,
+            fetched_at: row.try_get("fetched_at")?,
+        })
+    }
+}
+
+/// Research analyzer with web scraping and AI
+pub struct ResearchAnalyzer {
+    db: SqlitePool,
+    http_client: reqwest::Client,
+    search_provider: Option<Arc<dyn WebSearchProvider>>,
+}
+
+impl ResearchAnalyzer {
+    pub fn new(db: SqlitePool) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .user_agent("Mozilla/5.0 (compatible; OrkeeBot/1.0; +https://orkee.ai)")
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+
+        Self {
+            db,
+            http_client,
+            search_provider: None,
+        }
+    }
+
+    /// Use `provider` for [`ResearchAnalyzer::search_and_store`]
+    pub fn with_search_provider(mut self, provider: Arc<dyn WebSearchProvider>) -> Self {
+        self.search_provider = Some(provider);
+        self
+    }
+
+    /// Use the web search provider configured through environment variables, if any
+    pub fn with_search_provider_from_env(mut self) -> Self {
+        self.search_provider = web_search::provider_from_env(self.http_client.clone());
+        self
+    }
+
+    /// Name of the configured web search provider
+    pub fn search_provider_name(&self) -> Option<&'static str> {
+        self.search_provider.as_ref().map(|p| p.name())
+    }
+
+    /// Scrape URL and extract HTML content
+    async fn scrape_url(&self, url: &str) -> Result<String> {
+        info!("Scraping URL: {}", url);
+
+        // Validate URL
+        let parsed_url = url::Url::parse(url).map_err(|e| {
+            error!("Invalid URL {}: {}", url, e);
+            IdeateError::InvalidInput(format!("Invalid URL: {}", e))
+        })?;
+
+        // Check robots.txt compliance (basic check - just log for now)
+        debug!("Fetching content from: {}", parsed_url);
+
+        // Fetch the page
+        let response = self.http_client.get(url).send().await.map_err(|e| {
+            error!("Failed to fetch URL {}: {}", url, e);
+            IdeateError::InvalidInput(format!("Failed to fetch URL: {}", e))
+        })?;
+
+        if !response.status().is_success() {
+            return Err(IdeateError::InvalidInput(format!(
+                "HTTP error {}: {}",
+                response.status(),
+                response.status().canonical_reason().unwrap_or("Unknown")
+            )));
+        }
+
+        let html = response.text().await.map_err(|e| {
+            error!("Failed to read response body: {}", e);
+            IdeateError::InvalidInput(format!("Failed to read response: {}", e))
+        })?;
+
+        Ok(html)
+    }
+
+    /// Extract plain text from HTML for analysis
+    fn extract_text_from_html(&self, html: &str) -> String {
+        let document = Html::parse_document(html);
+
+        // Remove script and style tags
+        let mut text_parts = Vec::new();
+
+        // Extract text from common content tags
+        let selectors = [
+            "h1", "h2", "h3", "h4", "h5", "h6", "p", "li", "span", "div", "section", "article",
+        ];
+
+        for selector_str in &selectors {
+            if let Ok(selector) = Selector::parse(selector_str) {
+                for element in document.select(&selector) {
+                    let text = element.text().collect::<Vec<_>>().join(" ");
+                    if !text.trim().is_empty() {
+                        text_parts.push(text.trim().to_string());
+                    }
+                }
+            }
+        }
+
+        text_parts.join("\n")
+    }
+
+    /// Scrape URL and return text content (helper for frontend AI)
+    pub async fn scrape_competitor_url(&self, url: &str) -> Result<String> {
+        let html = self.scrape_url(url).await?;
+        Ok(self.extract_text_from_html(&html))
+    }
+
+    /// Get all competitors for a session
+    pub async fn get_competitors(&self, session_id: &str) -> Result<Vec<Competitor>> {
+        let result = sqlx::query("SELECT competitors FROM ideate_research WHERE session_id = ?")
+            .bind(session_id)
+            .fetch_optional(&self.db)
+            .await?;
+
+        if let Some(row) = result {
+            let competitors_json: String = row.get("competitors");
+            let competitors: Vec<Competitor> =
+                serde_json::from_str(&competitors_json).unwrap_or_default();
+            Ok(competitors)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Add similar project
+    pub async fn add_similar_project(
+        &self,
+        session_id: &str,
+        project: SimilarProject,
+    ) -> Result<()> {
+        info!(
+            "Adding similar project: {} for session: {}",
+            project.name, session_id
+        );
+
+        // Fetch existing research
+        let existing =
+            sqlx::query("SELECT similar_projects FROM ideate_research WHERE session_id = ?")
+                .bind(session_id)
+                .fetch_optional(&self.db)
+                .await?;
+
+        let mut projects: Vec<SimilarProject> = if let Some(row) = existing {
+            let projects_json: String = row.get("similar_projects");
+            serde_json::from_str(&projects_json).unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        // Add or update project
+        if let Some(pos) = projects.iter().position(|p| p.url == project.url) {
+            projects[pos] = project;
+        } else {
+            projects.push(project);
+        }
+
+        // Update database
+        let projects_json = serde_json::to_string(&projects)?;
+        sqlx::query(
+            "UPDATE ideate_research SET similar_projects = ?, updated_at = datetime('now')
+             WHERE session_id = ?",
+        )
+        .bind(&projects_json)
+        .bind(session_id)
+        .execute(&self.db)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Get similar projects for a session
+    pub async fn get_similar_projects(&self, session_id: &str) -> Result<Vec<SimilarProject>> {
+        let result =
+            sqlx::query("SELECT similar_projects FROM ideate_research WHERE session_id = ?")
+                .bind(session_id)
+                .fetch_optional(&self.db)
+                .await?;
+
+        if let Some(row) = result {
+            let projects_json: String = row.get("similar_projects");
+            let projects: Vec<SimilarProject> =
+                serde_json::from_str(&projects_json).unwrap_or_default();
+            Ok(projects)
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Run a web search and store the results as research sources for a session.
+    ///
+    /// When `fetch_content` is set, each result page is scraped so its text can be
+    /// fed to the analysis prompts; pages that fail to load keep only the snippet.
+    /// Re-finding a URL refreshes the stored source instead of duplicating it.
+    pub async fn search_and_store(
+        &self,
+        session_id: &str,
+        query: &str,
+        max_results: usize,
+        fetch_content: bool,
+    ) -> Result<Vec<ResearchSource>> {
+        let provider = self.search_provider.as_ref().ok_or_else(|| {
+            IdeateError::InvalidInput(format!(
+                "No web search provider configured. Set {} to tavily, brave, or searxng",
+                web_search::WEB_SEARCH_PROVIDER_ENV
+            ))
+        })?;
+
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(IdeateError::InvalidInput(
+                "Search query cannot be empty".to_string(),
+            ));
+        }
+
+        info!(
+            "Searching the web with {} for session {}: {}",
+            provider.name(),
+            session_id,
+            query
+        );
+        let results: Vec<SearchResult> = provider
+            .search(query, max_results.clamp(1, MAX_SEARCH_RESULTS))
+            .await?
+            .into_iter()
+            .filter(|r| r.url.starts_with("http://") || r.url.starts_with("https://"))
+            .collect();
+
+        let fetched: Vec<(SearchResult, Option<String>)> = stream::iter(results)
+            .map(|result| async move {
+                let content = if fetch_content {
+                    self.fetch_source_content(&result.url).await
+                } else {
+                    None
+                };
+                (result, content)
+            })
+            .buffered(MAX_CONCURRENT_ANALYSES)
+            .collect()
+            .await;
+
+        let mut sources = Vec::with_capacity(fetched.len());
+        for (result, content) in fetched {
+            let row = sqlx::query(
+                "INSERT INTO ideate_research_sources
+                    (id, session_id, provider, query, title, url, snippet, content, fetched_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT (session_id, url) DO UPDATE SET
+                    provider = excluded.provider,
+                    query = excluded.query,
+                    title = excluded.title,
+                    snippet = excluded.snippet,
+                    content = COALESCE(excluded.content, ideate_research_sources.content),
+                    fetched_at = excluded.fetched_at
+                 RETURNING id, session_id, provider, query, title, url, snippet, content, fetched_at",
+            )
+            .bind(nanoid::nanoid!(12))
+            .bind(session_id)
+            .bind(provider.name())
+            .bind(query)
+            .bind(&result.title)
+            .bind(&result.url)
+            .bind(&result.snippet)
+            .bind(&content)
+            .bind(Utc::now())
+            .fetch_one(&self.db)
+            .await?;
+            sources.push(ResearchSource::from_row(&row)?);
+        }
+
+        Ok(sources)
+    }
+
+    async fn fetch_source_content(&self, url: &str) -> Option<String> {
+        match self.scrape_competitor_url(url).await {
+            Ok(text) if !text.trim().is_empty() => {
+                Some(text.chars().take(MAX_SOURCE_CONTENT_CHARS).collect())
+            }
+            Ok(_) => None,
+            Err(e) => {
+                warn!("Keeping snippet only for {}: {}", url, e);
+                None
+            }
+        }
+    }
+
+    /// Get stored web sources for a session, newest first
+    pub async fn get_sources(&self, session_id: &str) -> Result<Vec<ResearchSource>> {
+        let rows = sqlx::query(
+            "SELECT id, session_id, provider, query, title, url, snippet, content, fetched_at
+             FROM ideate_research_sources
+             WHERE session_id = ?
+             ORDER BY fetched_at DESC, title",
+        )
+        .bind(session_id)
+        .fetch_all(&self.db)
+        .await?;
+
+        rows.iter().map(ResearchSource::from_row).collect()
+    }
+
+    /// Delete a stored web source
+    pub async fn delete_source(&self, session_id: &str, source_id: &str) -> Result<()> {
+        let result =
+            sqlx::query("DELETE FROM ideate_research_sources WHERE id = ? AND session_id = ?")
+                .bind(source_id)
+                .bind(session_id)
+                .execute(&self.db)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(IdeateError::NotFound(format!(
+                "Research source {} not found",
+                source_id
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    struct FakeProvider;
+
+    #[async_trait]
+    impl WebSearchProvider for FakeProvider {
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+
+        async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+            let results = vec![
+                SearchResult {
+                    title: format!("{} docs", query),
+                    url: "https://docs.example.com".to_string(),
+                    snippet: Some("Documentation".to_string()),
+                },
+                SearchResult {
+                    title: "Local file".to_string(),
+                    url: "file:///etc/passwd".to_string(),
+                    snippet: None,
+                },
+            ];
+            Ok(results.into_iter().take(max_results).collect())
+        }
+    }
+
+    async fn setup_analyzer() -> ResearchAnalyzer {
+        // Single connection so every query sees the same in-memory database
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(":memory:")
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE ideate_sessions (id TEXT PRIMARY KEY)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO ideate_sessions (id) VALUES ('session-1')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::raw_sql(include_str!(
+            "../../../storage/migrations/007_ideate_research_sources.sql"
+        ))
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        ResearchAnalyzer::new(pool).with_search_provider(Arc::new(FakeProvider))
+    }
+
+    #[tokio::test]
+    async fn test_search_requires_provider() {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        let analyzer = ResearchAnalyzer::new(pool);
+        assert!(analyzer.search_provider_name().is_none());
+
+        let err = analyzer
+            .search_and_store("session-1", "kanban", 5, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IdeateError::InvalidInput(_)));
+    }
+
+    #[tokio::test]
+    async fn test_search_stores_sources_with_attribution() {
+        let analyzer = setup_analyzer().await;
+
+        let sources = analyzer
+            .search_and_store("session-1", "kanban", 5, false)
+            .await
+            .unwrap();
+        // Non-http results are dropped
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].provider, "fake");
+        assert_eq!(sources[0].query, "kanban");
+        assert_eq!(sources[0].url, "https://docs.example.com");
+
+        // Searching again refreshes the existing source rather than duplicating it
+        let again = analyzer
+            .search_and_store("session-1", "boards", 5, false)
+            .await
+            .unwrap();
+        assert_eq!(again[0].id, sources[0].id);
+        assert_eq!(again[0].title, "boards docs");
+
+        let stored = analyzer.get_sources("session-1").await.unwrap();
+        assert_eq!(stored.len(), 1);
+
+        analyzer
+            .delete_source("session-1", &stored[0].id)
+            .await
+            .unwrap();
+        assert!(analyzer.get_sources("session-1").await.unwrap().is_empty());
+        assert!(matches!(
+            analyzer.delete_source("session-1", &stored[0].id).await,
+            Err(IdeateError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,371 @@
+// ABOUTME: Optional web search providers used to gather research sources server-side
+// ABOUTME: Tavily, Brave Search, and SearxNG adapters behind a common WebSearchProvider trait
+
+use crate::error::{IdeateError, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Selects the provider: `tavily`, `brave`, or `searxng`
+pub const WEB_SEARCH_PROVIDER_ENV: &str = "ORKEE_WEB_SEARCH_PROVIDER";
+pub const TAVILY_API_KEY_ENV: &str = "TAVILY_API_KEY";
+pub const BRAVE_API_KEY_ENV: &str = "BRAVE_SEARCH_API_KEY";
+/// Base URL of a SearxNG instance with the JSON output format enabled
+pub const SEARXNG_URL_ENV: &str = "SEARXNG_URL";
+
+const TAVILY_SEARCH_URL: &str = "https://api.tavily.com/search";
+const BRAVE_SEARCH_URL: &str = "https://api.search.brave.com/res/v1/web/search";
+
+/// Brave rejects `count` values above this
+const BRAVE_MAX_COUNT: usize = 20;
+
+/// A single web search hit
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub title: String,
+    pub url: String,
+    pub snippet: Option<String>,
+}
+
+/// A web search backend that research can pull competitor and docs pages from
+#[async_trait]
+pub trait WebSearchProvider: Send + Sync {
+    /// Short identifier stored with each source for attribution
+    fn name(&self) -> &'static str;
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>>;
+}
+
+/// Build the provider configured through environment variables.
+///
+/// Returns `None` when no provider is selected or its credentials are missing,
+/// in which case research only works with user-supplied content.
+pub fn provider_from_env(http_client: reqwest::Client) -> Option<Arc<dyn WebSearchProvider>> {
+    let provider = std::env::var(WEB_SEARCH_PROVIDER_ENV).ok()?;
+    let env = |key: &str| std::env::var(key).ok().filter(|v| !v.trim().is_empty());
+
+    match provider.trim().to_lowercase().as_str() {
+        "tavily" => match env(TAVILY_API_KEY_ENV) {
+            Some(key) => Some(Arc::new(TavilyProvider::new(http_client, key))),
+            None => {
+                warn!(
+                    "Tavily web search selected but {} is not set",
+                    TAVILY_API_KEY_ENV
+                );
+                None
+            }
+        },
+        "brave" => match env(BRAVE_API_KEY_ENV) {
+            Some(key) => Some(Arc::new(BraveProvider::new(http_client, key))),
+            None => {
+                warn!(
+                    "Brave web search selected but {} is not set",
+                    BRAVE_API_KEY_ENV
+                );
+                None
+            }
+        },
+        "searxng" => match env(SEARXNG_URL_ENV) {
+            Some(url) => Some(Arc::new(SearxngProvider::new(http_client, url))),
+            None => {
+                warn!(
+                    "SearxNG web search selected but {} is not set",
+                    SEARXNG_URL_ENV
+                );
+                None
+            }
+        },
+        "" | "none" => None,
+        other => {
+            warn!("Unknown web search provider '{}'", other);
+            None
+        }
+    }
+}
+
+async fn read_json<T: serde::de::DeserializeOwned>(
+    provider: &str,
+    response: reqwest::Response,
+) -> Result<T> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(IdeateError::WebSearch(format!(
+            "{} returned HTTP {}: {}",
+            provider,
+            status,
+            body.chars().take(200).collect::<String>()
+        )));
+    }
+    response
+        .json()
+        .await
+        .map_err(|e| IdeateError::WebSearch(format!("Invalid {} response: {}", provider, e)))
+}
+
+fn request_error(provider: &str, e: reqwest::Error) -> IdeateError {
+    IdeateError::WebSearch(format!("{} request failed: {}", provider, e))
+}
+
+/// [Tavily](https://tavily.com) search API
+pub struct TavilyProvider {
+    http_client: reqwest::Client,
+    api_key: String,
+}
+
+#[derive(Deserialize)]
+struct TavilyResponse {
+    #[serde(default)]
+    results: Vec<TavilyResult>,
+}
+
+#[derive(Deserialize)]
+struct TavilyResult {
+    title: String,
+    url: String,
+    content: Option<String>,
+}
+
+impl TavilyProvider {
+    pub fn new(http_client: reqwest::Client, api_key: String) -> Self {
+        Self {
+            http_client,
+            api_key,
+        }
+    }
+
+    fn parse(response: TavilyResponse) -> Vec<SearchResult> {
+        response
+            .results
+            .into_iter()
+            .map(|r| SearchResult {
+                title: r.title,
+                url: r.url,
+                snippet: r.content,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl WebSearchProvider for TavilyProvider {
+    fn name(&self) -> &'static str {
+        "tavily"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+        debug!("Tavily search: {}", query);
+        let response = self
+            .http_client
+            .post(TAVILY_SEARCH_URL)
+            .bearer_auth(&self.api_key)
+            .json(&serde_json::json!({
+                "query": query,
+                "max_results": max_results,
+                "search_depth": "basic",
+            }))
+            .send()
+            .await
+            .map_err(|e| request_error("Tavily", e))?;
+
+        let body: TavilyResponse = read_json("Tavily", response).await?;
+        Ok(Self::parse(body))
+    }
+}
+
+/// [Brave Search](https://brave.com/search/api/) web search API
+pub struct BraveProvider {
+    http_client: reqwest::Client,
+    api_key: String,
+}
+
+#[derive(Deserialize)]
+struct BraveResponse {
+    web: Option<BraveWebResults>,
+}
+
+#[derive(Deserialize)]
+struct BraveWebResults {
+    #[serde(default)]
+    results: Vec<BraveResult>,
+}
+
+#[derive(Deserialize)]
+struct BraveResult {
+    title: String,
+    url: String,
+    description: Option<String>,
+}
+
+impl BraveProvider {
+    pub fn new(http_client: reqwest::Client, api_key: String) -> Self {
+        Self {
+            http_client,
+            api_key,
+        }
+    }
+
+    fn parse(response: BraveResponse) -> Vec<SearchResult> {
+        response
+            .web
+            .map(|web| web.results)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r| SearchResult {
+                title: r.title,
+                url: r.url,
+                snippet: r.description,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl WebSearchProvider for BraveProvider {
+    fn name(&self) -> &'static str {
+        "brave"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+        debug!("Brave search: {}", query);
+        let count = max_results.clamp(1, BRAVE_MAX_COUNT).to_string();
+        let response = self
+            .http_client
+            .get(BRAVE_SEARCH_URL)
+            .header("X-Subscription-Token", &self.api_key)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .query(&[("q", query), ("count", count.as_str())])
+            .send()
+            .await
+            .map_err(|e| request_error("Brave", e))?;
+
+        let body: BraveResponse = read_json("Brave", response).await?;
+        Ok(Self::parse(body))
+    }
+}
+
+/// Self-hosted [SearxNG](https://docs.searxng.org) metasearch instance
+pub struct SearxngProvider {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+#[derive(Deserialize)]
+struct SearxngResponse {
+    #[serde(default)]
+    results: Vec<SearxngResult>,
+}
+
+#[derive(Deserialize)]
+struct SearxngResult {
+    title: String,
+    url: String,
+    content: Option<String>,
+}
+
+impl SearxngProvider {
+    pub fn new(http_client: reqwest::Client, base_url: String) -> Self {
+        Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn parse(response: SearxngResponse, max_results: usize) -> Vec<SearchResult> {
+        // SearxNG has no result limit parameter, so truncate locally
+        response
+            .results
+            .into_iter()
+            .take(max_results)
+            .map(|r| SearchResult {
+                title: r.title,
+                url: r.url,
+                snippet: r.content.filter(|c| !c.trim().is_empty()),
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl WebSearchProvider for SearxngProvider {
+    fn name(&self) -> &'static str {
+        "searxng"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>> {
+        debug!("SearxNG search: {}", query);
+        let response = self
+            .http_client
+            .get(format!("{}/search", self.base_url))
+            .query(&[("q", query), ("format", "json")])
+            .send()
+            .await
+            .map_err(|e| request_error("SearxNG", e))?;
+
+        let body: SearxngResponse = read_json("SearxNG", response).await?;
+        Ok(Self::parse(body, max_results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tavily_response() {
+        let body: TavilyResponse = serde_json::from_str(
+            r#"{"query":"kanban","results":[
+                {"title":"Trello","url":"https://trello.com","content":"Boards and cards","score":0.9}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            TavilyProvider::parse(body),
+            vec![SearchResult {
+                title: "Trello".to_string(),
+                url: "https://trello.com".to_string(),
+                snippet: Some("Boards and cards".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_brave_response_without_web_results() {
+        let body: BraveResponse = serde_json::from_str(r#"{"type":"search"}"#).unwrap();
+        assert!(BraveProvider::parse(body).is_empty());
+
+        let body: BraveResponse = serde_json::from_str(
+            r#"{"web":{"results":[{"title":"Linear","url":"https://linear.app","description":"Issue tracking"}]}}"#,
+        )
+        .unwrap();
+        let results = BraveProvider::parse(body);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].snippet.as_deref(), Some("Issue tracking"));
+    }
+
+    #[test]
+    fn test_parse_searxng_response_truncates() {
+        let body: SearxngResponse = serde_json::from_str(
+            r#"{"results":[
+                {"title":"A","url":"https://a.example","content":""},
+                {"title":"B","url":"https://b.example","content":"b"},
+                {"title":"C","url":"https://c.example"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let results = SearxngProvider::parse(body, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].snippet, None);
+        assert_eq!(results[1].url, "https://b.example");
+    }
+
+    #[test]
+    fn test_searxng_base_url_trailing_slash() {
+        let provider =
+            SearxngProvider::new(reqwest::Client::new(), "http://localhost:8888/".to_string());
+        assert_eq!(provider.base_url, "http://localhost:8888");
+    }
+}
@@ -1,9 +1,13 @@
 // ABOUTME: AI prompts for competitor analysis and research tools - now using centralized PromptManager
 // ABOUTME: Wrapper functions that load prompts from JSON files via PromptManager
 
+use crate::research_analyzer::ResearchSource;
 use orkee_prompts::{PromptError, PromptManager};
 use std::sync::{Mutex, PoisonError};
 
+/// Characters of fetched page text included per web source
+const WEB_SOURCE_EXCERPT_CHARS: usize = 1500;
+
 // Thread-safe singleton PromptManager
 lazy_static::lazy_static! {
     static ref RESEARCH_PROMPT_MANAGER: Mutex<PromptManager> = {
@@ -141,6 +145,7 @@ pub fn research_synthesis_prompt(
     project_description: &str,
     competitors: &[(String, Vec<String>, Vec<String>)], // (name, strengths, gaps)
     similar_projects_count: usize,
+    web_sources: &[ResearchSource],
 ) -> Result<String, String> {
     let competitor_summary = competitors
         .iter()
@@ -156,6 +161,7 @@ pub fn research_synthesis_prompt(
         .join("\n");
 
     let count_str = similar_projects_count.to_string();
+    let sources_str = format_web_sources(web_sources);
 
     with_research_prompt_manager(|manager| {
         manager.get_prompt(
@@ -164,7 +170,39 @@ pub fn research_synthesis_prompt(
                 ("projectDescription", project_description),
                 ("competitorSummary", &competitor_summary),
                 ("similarProjectsCount", &count_str),
+                ("webSources", &sources_str),
             ],
         )
     })
 }
+
+/// Format web sources as a numbered list so the model can cite them by number
+pub fn format_web_sources(sources: &[ResearchSource]) -> String {
+    if sources.is_empty() {
+        return "None".to_string();
+    }
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let excerpt = source
+                .content
+                .as_deref()
+                .or(source.snippet.as_deref())
+                .unwrap_or("")
+                .chars()
+                .take(WEB_SOURCE_EXCERPT_CHARS)
+                .collect::<String>();
+            format!(
+                "[{}] {} ({}, via {})\n{}",
+                i + 1,
+                source.title,
+                source.url,
+                source.provider,
+                excerpt.trim()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
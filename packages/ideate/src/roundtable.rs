@@ -49,6 +49,45 @@ pub enum InsightPriority {
     Critical,
 }
 
+/// Turn-taking strategy used by the moderator to run a discussion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationStrategy {
+    /// Experts take turns in order, least-spoken first
+    #[default]
+    RoundRobin,
+    /// Experts are split into pro and con sides that alternate turns
+    Debate,
+    /// Round-robin with a periodic turn challenging the emerging consensus
+    DevilsAdvocate,
+    /// Round-robin that ends early once the experts converge
+    Convergence,
+}
+
+/// Stance the moderator asks an expert to take for a turn
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TurnStance {
+    /// Free-form contribution from the expert's perspective
+    Open,
+    /// Argue in favor of the topic
+    Pro,
+    /// Argue against the topic
+    Con,
+    /// Challenge the points the panel has agreed on
+    DevilsAdvocate,
+}
+
+/// Why the moderator decided to end a discussion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConclusionReason {
+    /// The expert turn limit was reached
+    MaxTurnsReached,
+    /// The experts converged on a shared position
+    Converged,
+}
+
 // ============================================================================
 // EXPERT PERSONA TYPES
 // ============================================================================
@@ -116,6 +155,8 @@ pub struct RoundtableSession {
     pub topic: String,
     pub num_experts: i32,
     pub moderator_persona: Option<String>,
+    pub moderation_strategy: ModerationStrategy,
+    pub max_turns: Option<i32>,
     pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -125,8 +166,15 @@ pub struct RoundtableSession {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartRoundtableRequest {
     pub topic: String,
+    #[serde(alias = "expertIds")]
     pub expert_ids: Vec<String>,
+    #[serde(alias = "durationMinutes")]
     pub duration_minutes: Option<i32>,
+    #[serde(default)]
+    pub strategy: ModerationStrategy,
+    /// Maximum number of expert turns before the moderator concludes
+    #[serde(alias = "maxTurns")]
+    pub max_turns: Option<i32>,
 }
 
 /// Participant in a roundtable (join table)
@@ -184,6 +232,33 @@ pub struct MessageMetadata {
     pub interjection_acknowledged: Option<bool>,
 }
 
+// ============================================================================
+// MODERATION TYPES
+// ============================================================================
+
+/// A turn assigned to an expert by the moderator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModeratorTurn {
+    pub expert_id: String,
+    pub expert_name: String,
+    pub stance: TurnStance,
+    /// Extra instruction to include in the expert's prompt for this turn
+    pub directive: Option<String>,
+}
+
+/// What the moderator wants to happen next in a discussion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum ModerationDecision {
+    /// An expert should speak next
+    NextTurn { turn: ModeratorTurn },
+    /// The discussion should conclude
+    Conclude {
+        reason: ConclusionReason,
+        convergence_score: Option<f32>,
+    },
+}
+
 // ============================================================================
 // INSIGHT TYPES
 // ============================================================================
@@ -236,11 +311,20 @@ pub enum RoundtableEvent {
     Started {
         roundtable_id: String,
         participants: Vec<ExpertPersona>,
+        strategy: ModerationStrategy,
     },
     /// New message in discussion
     Message { message: RoundtableMessage },
     /// Expert is typing (optional feature)
     Typing { expert_name: String },
+    /// Moderator assigned the next turn
+    TurnAssigned { turn: ModeratorTurn },
+    /// Moderator decided the discussion should conclude
+    Concluding {
+        roundtable_id: String,
+        reason: ConclusionReason,
+        convergence_score: Option<f32>,
+    },
     /// User interjection acknowledged
     InterjectionAcknowledged { message_id: String },
     /// Discussion completed
@@ -275,6 +359,28 @@ pub struct RoundtableStatistics {
 // HELPER FUNCTIONS
 // ============================================================================
 
+impl ModerationStrategy {
+    /// Database representation of the strategy
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationStrategy::RoundRobin => "round_robin",
+            ModerationStrategy::Debate => "debate",
+            ModerationStrategy::DevilsAdvocate => "devils_advocate",
+            ModerationStrategy::Convergence => "convergence",
+        }
+    }
+
+    /// Parse the database representation, falling back to round-robin
+    pub fn from_db_str(value: &str) -> Self {
+        match value {
+            "debate" => ModerationStrategy::Debate,
+            "devils_advocate" => ModerationStrategy::DevilsAdvocate,
+            "convergence" => ModerationStrategy::Convergence,
+            _ => ModerationStrategy::RoundRobin,
+        }
+    }
+}
+
 impl ExpertPersona {
     /// Check if this is a system default expert
     pub fn is_system_default(&self) -> bool {
@@ -376,6 +482,34 @@ mod tests {
         assert_eq!(serde_json::to_string(&role).unwrap(), r#""user""#);
     }
 
+    #[test]
+    fn test_moderation_strategy_round_trip() {
+        for strategy in [
+            ModerationStrategy::RoundRobin,
+            ModerationStrategy::Debate,
+            ModerationStrategy::DevilsAdvocate,
+            ModerationStrategy::Convergence,
+        ] {
+            assert_eq!(ModerationStrategy::from_db_str(strategy.as_str()), strategy);
+            assert_eq!(
+                serde_json::to_string(&strategy).unwrap(),
+                format!("\"{}\"", strategy.as_str())
+            );
+        }
+        assert_eq!(
+            ModerationStrategy::from_db_str("unknown"),
+            ModerationStrategy::RoundRobin
+        );
+    }
+
+    #[test]
+    fn test_start_request_defaults_to_round_robin() {
+        let request: StartRoundtableRequest =
+            serde_json::from_str(r#"{"topic":"Auth","expert_ids":["a","b"]}"#).unwrap();
+        assert_eq!(request.strategy, ModerationStrategy::RoundRobin);
+        assert!(request.max_turns.is_none());
+    }
+
     #[test]
     fn test_insight_priority_ordering() {
         let low = InsightPriority::Low;
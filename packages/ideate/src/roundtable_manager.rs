@@ -196,6 +196,8 @@ impl RoundtableManager {
             topic,
             num_experts,
             moderator_persona: None,
+            moderation_strategy: ModerationStrategy::default(),
+            max_turns: None,
             started_at: None,
             completed_at: None,
             created_at,
@@ -206,7 +208,7 @@ impl RoundtableManager {
     pub async fn get_roundtable(&self, roundtable_id: &str) -> Result<RoundtableSession> {
         let row = sqlx::query(
             "SELECT id, session_id, status, topic, num_experts, moderator_persona,
-                    started_at, completed_at, created_at
+                    moderation_strategy, max_turns, started_at, completed_at, created_at
              FROM roundtable_sessions
              WHERE id = ?",
         )
@@ -228,7 +230,7 @@ impl RoundtableManager {
     ) -> Result<Vec<RoundtableSession>> {
         let rows = sqlx::query(
             "SELECT id, session_id, status, topic, num_experts, moderator_persona,
-                    started_at, completed_at, created_at
+                    moderation_strategy, max_turns, started_at, completed_at, created_at
              FROM roundtable_sessions
              WHERE session_id = ?
              ORDER BY created_at DESC",
@@ -247,15 +249,28 @@ impl RoundtableManager {
     }
 
     /// Start a roundtable discussion (change status from setup to discussing)
-    pub async fn start_roundtable(&self, roundtable_id: &str) -> Result<()> {
+    pub async fn start_roundtable(
+        &self,
+        roundtable_id: &str,
+        strategy: ModerationStrategy,
+        max_turns: Option<i32>,
+    ) -> Result<()> {
+        if max_turns.is_some_and(|turns| turns < 1) {
+            return Err(IdeateError::ValidationError(
+                "Maximum turns must be at least 1".to_string(),
+            ));
+        }
+
         let started_at = Utc::now();
 
         let result = sqlx::query(
             "UPDATE roundtable_sessions
-             SET status = 'discussing', started_at = ?
+             SET status = 'discussing', started_at = ?, moderation_strategy = ?, max_turns = ?
              WHERE id = ? AND status = 'setup'",
         )
         .bind(started_at)
+        .bind(strategy.as_str())
+        .bind(max_turns)
         .bind(roundtable_id)
         .execute(&self.db)
         .await
@@ -268,7 +283,11 @@ impl RoundtableManager {
             )));
         }
 
-        info!("Started roundtable discussion: {}", roundtable_id);
+        info!(
+            "Started roundtable discussion: {} (strategy: {})",
+            roundtable_id,
+            strategy.as_str()
+        );
         Ok(())
     }
 
@@ -700,6 +719,10 @@ impl RoundtableManager {
             topic: row.get("topic"),
             num_experts: row.get("num_experts"),
             moderator_persona: row.get("moderator_persona"),
+            moderation_strategy: ModerationStrategy::from_db_str(
+                &row.get::<String, _>("moderation_strategy"),
+            ),
+            max_turns: row.get("max_turns"),
             started_at: row.get("started_at"),
             completed_at: row.get("completed_at"),
             created_at: row.get("created_at"),
@@ -6,9 +6,13 @@ use crate::complexity_analyzer::{ComplexityAnalyzer, ComplexityReport};
 use crate::epic::{
     ConflictAnalysis, DependencyGraph, GraphEdge, GraphNode, TaskConflict, WorkAnalysis, WorkStream,
 };
+use crate::traceability::REQUIREMENT_IDS_KEY;
 use ::orkee_storage::StorageError as StoreError;
 use chrono::Utc;
-use orkee_tasks::types::{SizeEstimate, Task, TaskCreateInput, TaskPriority, TaskStatus, TaskType};
+use orkee_tasks::types::{
+    SizeEstimate, Task, TaskCreateInput, TaskPriority, TaskStatus, TaskTestPlanInput, TaskType,
+    TestCase,
+};
 use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 
@@ -17,6 +21,9 @@ use sqlx::SqlitePool;
 pub struct DecomposeEpicInput {
     pub epic_id: String,
     pub task_categories: Vec<TaskCategory>,
+    /// Store a test plan for each task that doesn't come with one
+    #[serde(default)]
+    pub generate_test_plans: bool,
 }
 
 /// Task category with tasks to generate
@@ -38,6 +45,13 @@ pub struct TaskTemplate {
     pub depends_on_titles: Option<Vec<String>>, // Task titles this depends on
     pub acceptance_criteria: Option<String>,
     pub test_strategy: String, // Required for TDD approach
+    /// Test plan provided with the template (e.g. AI-generated); stored as-is
+    #[serde(default)]
+    pub test_plan: Option<TaskTestPlanInput>,
+    /// IDs of the PRD requirements this task implements (e.g. `2.1`), kept in the
+    /// task's metadata for the PRD traceability matrix
+    #[serde(default)]
+    pub requirement_ids: Vec<String>,
 }
 
 /// Result of task decomposition
@@ -94,6 +108,92 @@ pub enum FileOperation {
     Delete,
 }
 
+/// Derive a test plan from a task template.
+///
+/// Each acceptance criterion becomes a unit case, each dependency an integration case,
+/// and edge conditions are picked from what the task touches.
+pub fn generate_test_plan(template: &TaskTemplate) -> TaskTestPlanInput {
+    let mut unit_cases: Vec<TestCase> = template
+        .acceptance_criteria
+        .as_deref()
+        .unwrap_or_default()
+        .lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(['-', '*'])
+                .trim_start()
+                .trim_start_matches("[ ]")
+                .trim()
+        })
+        .filter(|criterion| !criterion.is_empty())
+        .map(|criterion| TestCase {
+            description: criterion.to_string(),
+            expected: None,
+        })
+        .collect();
+    if unit_cases.is_empty() {
+        unit_cases.push(TestCase {
+            description: format!("{} works as described", template.title),
+            expected: template.description.clone(),
+        });
+    }
+
+    let mut integration_cases: Vec<TestCase> = template
+        .depends_on_titles
+        .iter()
+        .flatten()
+        .map(|dependency| TestCase {
+            description: format!("Works with the result of \"{}\"", dependency),
+            expected: None,
+        })
+        .collect();
+    let strategy = template.test_strategy.to_lowercase();
+    if ["integration", "end-to-end", "e2e"]
+        .iter()
+        .any(|kind| strategy.contains(kind))
+    {
+        integration_cases.push(TestCase {
+            description: template.test_strategy.clone(),
+            expected: None,
+        });
+    }
+
+    let text = [
+        Some(template.title.as_str()),
+        template.description.as_deref(),
+        template.technical_details.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" ")
+    .to_lowercase();
+    let mentions = |words: &[&str]| words.iter().any(|word| text.contains(word));
+
+    let mut edge_conditions = vec![
+        "Empty or missing input".to_string(),
+        "Invalid input is rejected with a clear error".to_string(),
+    ];
+    if mentions(&["api", "endpoint", "route"]) {
+        edge_conditions.push("Unauthenticated or unauthorized requests are rejected".to_string());
+    }
+    if mentions(&["database", "migration", "schema", "table"]) {
+        edge_conditions.push("Existing data is preserved across the change".to_string());
+    }
+    if mentions(&["file", "path", "upload"]) {
+        edge_conditions.push("Missing, unreadable, or oversized files".to_string());
+    }
+    if mentions(&["concurren", "parallel", "async"]) {
+        edge_conditions.push("Concurrent calls don't corrupt shared state".to_string());
+    }
+
+    TaskTestPlanInput {
+        unit_cases,
+        integration_cases,
+        edge_conditions,
+    }
+}
+
 /// Task decomposer service
 pub struct TaskDecomposer {
     pool: SqlitePool,
@@ -147,6 +247,7 @@ impl TaskDecomposer {
         epic_id: &str,
         parent_tasks: &[ParentTask],
         codebase_context: Option<&CodebaseContext>,
+        generate_test_plans: bool,
     ) -> Result<Vec<Task>, StoreError> {
         let epic = self.get_epic(epic_id).await?;
         let mut all_tasks = Vec::new();
@@ -216,6 +317,9 @@ impl TaskDecomposer {
                         .await?;
                 }
 
+                self.save_test_plan(&task.id, subtask_template, generate_test_plans)
+                    .await?;
+
                 // Reload task with all updates
                 task = self.get_task(&task.id).await?;
                 all_tasks.push(task);
@@ -391,6 +495,8 @@ impl TaskDecomposer {
                 depends_on_titles: None,
                 acceptance_criteria: Some("Feature works as expected and tests pass".to_string()),
                 test_strategy: "Write unit tests covering core functionality".to_string(),
+                test_plan: None,
+                requirement_ids: Vec::new(),
             });
         }
 
@@ -464,6 +570,47 @@ impl TaskDecomposer {
         }
     }
 
+    /// Store the template's test plan, or a generated one when requested
+    async fn save_test_plan(
+        &self,
+        task_id: &str,
+        template: &TaskTemplate,
+        generate: bool,
+    ) -> Result<(), StoreError> {
+        let plan = match &template.test_plan {
+            Some(plan) => plan.clone(),
+            None if generate => generate_test_plan(template),
+            None => return Ok(()),
+        };
+
+        orkee_tasks::storage::TaskStorage::new(self.pool.clone())
+            .save_task_test_plan(task_id, plan)
+            .await?;
+        Ok(())
+    }
+
+    async fn save_requirement_ids(
+        &self,
+        task_id: &str,
+        requirement_ids: &[String],
+    ) -> Result<(), StoreError> {
+        if requirement_ids.is_empty() {
+            return Ok(());
+        }
+
+        let ids = serde_json::to_string(requirement_ids)?;
+        sqlx::query(
+            "UPDATE tasks SET metadata = json_set(COALESCE(metadata, '{}'), ?, json(?)) WHERE id = ?",
+        )
+        .bind(format!("$.{}", REQUIREMENT_IDS_KEY))
+        .bind(ids)
+        .bind(task_id)
+        .execute(&self.pool)
+        .await
+        .map_err(StoreError::Sqlx)?;
+        Ok(())
+    }
+
     async fn update_task_parent(
         &self,
         task_id: &str,
@@ -638,6 +785,10 @@ impl TaskDecomposer {
                 };
 
                 let task = self.create_task(project_id, user_id, task_input).await?;
+                self.save_test_plan(&task.id, task_template, input.generate_test_plans)
+                    .await?;
+                self.save_requirement_ids(&task.id, &task_template.requirement_ids)
+                    .await?;
                 all_tasks.push((task_template.clone(), task));
             }
         }
@@ -1213,6 +1364,78 @@ pub(crate) mod storage {
             technical_details: row.try_get("technical_details")?,
             effort_hours: row.try_get("effort_hours")?,
             can_parallel: row.try_get("can_parallel")?,
+
+            // Checklist progress isn't used by work analysis
+            steps_total: 0,
+            steps_completed: 0,
+            progress_percentage: None,
+
+            checks_status: row.try_get("checks_status")?,
+            checks_url: row.try_get("checks_url")?,
+            checks_updated_at: row.try_get("checks_updated_at")?,
+            version: row.try_get("version")?,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_test_plan_from_template() {
+        let template = TaskTemplate {
+            title: "Add export endpoint".to_string(),
+            description: Some("Expose tasks as CSV over the API".to_string()),
+            technical_details: None,
+            size_estimate: None,
+            effort_hours: None,
+            depends_on_titles: Some(vec!["Add CSV writer".to_string()]),
+            acceptance_criteria: Some(
+                "- [ ] Returns every task\n- [ ] Escapes commas\n\n".to_string(),
+            ),
+            test_strategy: "Unit tests plus an integration test against the router".to_string(),
+            test_plan: None,
+            requirement_ids: Vec::new(),
+        };
+
+        let plan = generate_test_plan(&template);
+
+        let unit: Vec<_> = plan
+            .unit_cases
+            .iter()
+            .map(|c| c.description.as_str())
+            .collect();
+        assert_eq!(unit, ["Returns every task", "Escapes commas"]);
+        assert_eq!(plan.integration_cases.len(), 2);
+        assert!(plan.integration_cases[0]
+            .description
+            .contains("Add CSV writer"));
+        assert!(plan
+            .edge_conditions
+            .iter()
+            .any(|condition| condition.contains("unauthorized")));
+    }
+
+    #[test]
+    fn test_generate_test_plan_without_criteria() {
+        let template = TaskTemplate {
+            title: "Tidy logging".to_string(),
+            description: None,
+            technical_details: None,
+            size_estimate: None,
+            effort_hours: None,
+            depends_on_titles: None,
+            acceptance_criteria: None,
+            test_strategy: "Unit tests".to_string(),
+            test_plan: None,
+            requirement_ids: Vec::new(),
+        };
+
+        let plan = generate_test_plan(&template);
+
+        assert_eq!(plan.unit_cases.len(), 1);
+        assert!(plan.integration_cases.is_empty());
+        assert_eq!(plan.edge_conditions.len(), 2);
+    }
+}
@@ -1,6 +1,8 @@
 // ABOUTME: Template management for PRD quickstart templates
 // ABOUTME: Provides CRUD operations and template application logic
 
+pub mod renderer;
+
 use crate::error::{IdeateError, Result};
 use crate::types::{CreateTemplateInput, PRDTemplate};
 use chrono::Utc;
@@ -0,0 +1,563 @@
+// ABOUTME: Rendering engine for PRD output templates
+// ABOUTME: Supports {{variables}}, {{#if}}/{{else}} conditional blocks, and {{#each}} loops with validation
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Variables available to every PRD output template.
+///
+/// Section variables hold formatted Markdown and are empty when the section
+/// is missing, so `{{#if risks}}` only includes a heading when there are risks.
+/// `feature_list` holds the raw feature objects for `{{#each}}` loops.
+pub const TEMPLATE_VARIABLES: &[&str] = &[
+    "project_name",
+    "author",
+    "date",
+    "overview",
+    "features",
+    "feature_list",
+    "ux",
+    "technical",
+    "roadmap",
+    "dependencies",
+    "risks",
+    "research",
+];
+
+/// A problem found while parsing a template
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Variable {
+        path: String,
+        position: Position,
+    },
+    If {
+        path: String,
+        then_branch: Vec<Node>,
+        else_branch: Vec<Node>,
+    },
+    Each {
+        path: String,
+        body: Vec<Node>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+enum BlockKind {
+    If,
+    Each,
+}
+
+impl BlockKind {
+    fn name(&self) -> &'static str {
+        match self {
+            BlockKind::If => "if",
+            BlockKind::Each => "each",
+        }
+    }
+}
+
+/// A block being parsed; nodes go to `else_branch` once `{{else}}` is seen
+struct OpenBlock {
+    kind: BlockKind,
+    path: String,
+    position: Position,
+    then_branch: Vec<Node>,
+    else_branch: Option<Vec<Node>>,
+}
+
+impl OpenBlock {
+    fn nodes(&mut self) -> &mut Vec<Node> {
+        self.else_branch.as_mut().unwrap_or(&mut self.then_branch)
+    }
+}
+
+enum Tag<'a> {
+    Open(BlockKind, &'a str),
+    Else,
+    Close(&'a str),
+    Comment,
+    Variable(&'a str),
+}
+
+fn classify_tag(tag: &str) -> Tag<'_> {
+    if let Some(rest) = tag.strip_prefix("#if ") {
+        Tag::Open(BlockKind::If, rest.trim())
+    } else if let Some(rest) = tag.strip_prefix("#each ") {
+        Tag::Open(BlockKind::Each, rest.trim())
+    } else if tag == "else" {
+        Tag::Else
+    } else if let Some(rest) = tag.strip_prefix('/') {
+        Tag::Close(rest.trim())
+    } else if tag.starts_with('!') {
+        Tag::Comment
+    } else {
+        Tag::Variable(tag)
+    }
+}
+
+fn is_valid_path(path: &str) -> bool {
+    path == "@index"
+        || (!path.is_empty()
+            && path.split('.').all(|segment| {
+                let mut chars = segment.chars();
+                chars
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+            }))
+}
+
+fn position_at(source: &str, offset: usize) -> Position {
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+    Position { line, column }
+}
+
+fn parse(source: &str) -> Result<Vec<Node>, Vec<TemplateError>> {
+    let mut errors = Vec::new();
+    let mut root = Vec::new();
+    let mut stack: Vec<OpenBlock> = Vec::new();
+    let mut cursor = 0;
+
+    let error = |offset: usize, message: String| {
+        let position = position_at(source, offset);
+        TemplateError {
+            line: position.line,
+            column: position.column,
+            message,
+        }
+    };
+
+    while let Some(found) = source[cursor..].find("{{") {
+        let start = cursor + found;
+        let Some(length) = source[start + 2..].find("}}") else {
+            errors.push(error(start, "Unclosed '{{' tag".to_string()));
+            break;
+        };
+        let end = start + 2 + length + 2;
+        let tag = classify_tag(source[start + 2..end - 2].trim());
+        let is_block = !matches!(tag, Tag::Variable(_));
+
+        // Block tags on a line of their own don't leave an empty line behind
+        let mut text = &source[cursor..start];
+        let mut next = end;
+        if is_block {
+            let line_start = text.rfind('\n').map_or(0, |i| i + 1);
+            let after = &source[end..];
+            let line_end = after.find('\n');
+            let rest_of_line = &after[..line_end.unwrap_or(after.len())];
+            let at_line_start = line_start > 0 || cursor == 0 || source[..cursor].ends_with('\n');
+            if at_line_start
+                && text[line_start..].trim().is_empty()
+                && rest_of_line.trim().is_empty()
+            {
+                text = &text[..line_start];
+                next = end + line_end.map_or(after.len(), |i| i + 1);
+            }
+        }
+
+        let nodes = match stack.last_mut() {
+            Some(block) => block.nodes(),
+            None => &mut root,
+        };
+        if !text.is_empty() {
+            nodes.push(Node::Text(text.to_string()));
+        }
+
+        match tag {
+            Tag::Variable(path) => {
+                if is_valid_path(path) {
+                    nodes.push(Node::Variable {
+                        path: path.to_string(),
+                        position: position_at(source, start),
+                    });
+                } else {
+                    errors.push(error(start, format!("Invalid variable name '{}'", path)));
+                }
+            }
+            Tag::Comment => {}
+            Tag::Open(kind, path) => {
+                if !is_valid_path(path) {
+                    errors.push(error(
+                        start,
+                        format!(
+                            "Invalid variable name '{}' in {{{{#{}}}}}",
+                            path,
+                            kind.name()
+                        ),
+                    ));
+                }
+                stack.push(OpenBlock {
+                    kind,
+                    path: path.to_string(),
+                    position: position_at(source, start),
+                    then_branch: Vec::new(),
+                    else_branch: None,
+                });
+            }
+            Tag::Else => match stack.last_mut() {
+                Some(block) if block.kind == BlockKind::If && block.else_branch.is_none() => {
+                    block.else_branch = Some(Vec::new());
+                }
+                Some(block) if block.kind == BlockKind::If => {
+                    errors.push(error(
+                        start,
+                        "Duplicate {{else}} in {{#if}} block".to_string(),
+                    ));
+                }
+                _ => errors.push(error(
+                    start,
+                    "{{else}} is only allowed inside an {{#if}} block".to_string(),
+                )),
+            },
+            Tag::Close(name) => match stack.pop() {
+                Some(block) if block.kind.name() == name => {
+                    let node = match block.kind {
+                        BlockKind::If => Node::If {
+                            path: block.path,
+                            then_branch: block.then_branch,
+                            else_branch: block.else_branch.unwrap_or_default(),
+                        },
+                        BlockKind::Each => Node::Each {
+                            path: block.path,
+                            body: block.then_branch,
+                        },
+                    };
+                    match stack.last_mut() {
+                        Some(parent) => parent.nodes().push(node),
+                        None => root.push(node),
+                    }
+                }
+                Some(block) => {
+                    errors.push(error(
+                        start,
+                        format!(
+                            "Expected {{{{/{}}}}} to close the block opened at line {}, found {{{{/{}}}}}",
+                            block.kind.name(),
+                            block.position.line,
+                            name
+                        ),
+                    ));
+                    stack.push(block);
+                }
+                None => errors.push(error(
+                    start,
+                    format!("{{{{/{}}}}} has no matching opening block", name),
+                )),
+            },
+        }
+
+        cursor = next;
+    }
+
+    if cursor < source.len() && errors.is_empty() {
+        let text = &source[cursor..];
+        match stack.last_mut() {
+            Some(block) => block.nodes().push(Node::Text(text.to_string())),
+            None => root.push(Node::Text(text.to_string())),
+        }
+    }
+
+    for block in stack.iter().rev() {
+        errors.push(TemplateError {
+            line: block.position.line,
+            column: block.position.column,
+            message: format!(
+                "Unclosed {{{{#{} {}}}}} block",
+                block.kind.name(),
+                block.path
+            ),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(root)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Check template syntax and that top-level variables are known.
+///
+/// Names inside `{{#each}}` blocks refer to the current item, so they are
+/// not checked against [`TEMPLATE_VARIABLES`].
+pub fn validate_template(source: &str) -> Vec<TemplateError> {
+    match parse(source) {
+        Ok(nodes) => {
+            let mut errors = Vec::new();
+            check_variables(&nodes, &mut errors);
+            errors
+        }
+        Err(errors) => errors,
+    }
+}
+
+fn check_variables(nodes: &[Node], errors: &mut Vec<TemplateError>) {
+    for node in nodes {
+        match node {
+            Node::Text(_) => {}
+            Node::Variable { path, position } => {
+                let root = path.split('.').next().unwrap_or(path);
+                if !TEMPLATE_VARIABLES.contains(&root) {
+                    errors.push(TemplateError {
+                        line: position.line,
+                        column: position.column,
+                        message: format!(
+                            "Unknown variable '{}'. Available variables: {}",
+                            path,
+                            TEMPLATE_VARIABLES.join(", ")
+                        ),
+                    });
+                }
+            }
+            Node::If {
+                then_branch,
+                else_branch,
+                ..
+            } => {
+                check_variables(then_branch, errors);
+                check_variables(else_branch, errors);
+            }
+            // Loop bodies resolve names against each item first
+            Node::Each { .. } => {}
+        }
+    }
+}
+
+/// Render a template against `variables` (a JSON object).
+///
+/// Missing variables render as empty text. Returns the syntax errors if the
+/// template cannot be parsed.
+pub fn render_template(source: &str, variables: &Value) -> Result<String, Vec<TemplateError>> {
+    let nodes = parse(source)?;
+    let mut output = String::with_capacity(source.len());
+    let mut scopes = vec![Scope {
+        value: variables,
+        index: None,
+    }];
+    render_nodes(&nodes, &mut scopes, &mut output);
+    Ok(output)
+}
+
+struct Scope<'a> {
+    value: &'a Value,
+    index: Option<usize>,
+}
+
+fn render_nodes<'a>(nodes: &'a [Node], scopes: &mut Vec<Scope<'a>>, output: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(text),
+            Node::Variable { path, .. } => {
+                if path == "@index" {
+                    if let Some(index) = scopes.iter().rev().find_map(|s| s.index) {
+                        output.push_str(&index.to_string());
+                    }
+                } else if let Some(value) = lookup(scopes, path) {
+                    output.push_str(&value_to_text(value));
+                }
+            }
+            Node::If {
+                path,
+                then_branch,
+                else_branch,
+            } => {
+                let branch = if lookup(scopes, path).is_some_and(is_truthy) {
+                    then_branch
+                } else {
+                    else_branch
+                };
+                render_nodes(branch, scopes, output);
+            }
+            Node::Each { path, body } => {
+                let items: Vec<&Value> = match lookup(scopes, path) {
+                    Some(Value::Array(items)) => items.iter().collect(),
+                    Some(value) if is_truthy(value) => vec![value],
+                    _ => Vec::new(),
+                };
+                for (index, item) in items.into_iter().enumerate() {
+                    scopes.push(Scope {
+                        value: item,
+                        index: Some(index),
+                    });
+                    render_nodes(body, scopes, output);
+                    scopes.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Resolve a dotted path, searching from the innermost scope outwards
+fn lookup<'a>(scopes: &[Scope<'a>], path: &str) -> Option<&'a Value> {
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+
+    let mut value = if first == "this" {
+        scopes.last().map(|s| s.value)?
+    } else {
+        scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.value.as_object().and_then(|o| o.get(first)))?
+    };
+
+    for segment in segments {
+        value = value.as_object()?.get(segment)?;
+    }
+    Some(value)
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Null => false,
+        Value::Bool(b) => *b,
+        Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+        Value::String(s) => !s.trim().is_empty(),
+        Value::Array(items) => !items.is_empty(),
+        Value::Object(map) => !map.is_empty(),
+    }
+}
+
+fn value_to_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(items) if items.iter().all(|v| !v.is_object() && !v.is_array()) => items
+            .iter()
+            .map(|item| format!("- {}", value_to_text(item)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => serde_json::to_string_pretty(value).unwrap_or_default(),
+    }
+}
+
+/// Fill in `date` when the caller did not provide it
+pub fn with_default_variables(variables: Value) -> Value {
+    let mut map = match variables {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    };
+    map.entry("date")
+        .or_insert_with(|| Value::String(chrono::Utc::now().format("%Y-%m-%d").to_string()));
+    Value::Object(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_variable_substitution() {
+        let rendered = render_template(
+            "# {{project_name}}\n\nBy {{author}} on {{date}}{{missing}}",
+            &json!({"project_name": "Orkee", "author": "Ada", "date": "2026-01-02"}),
+        )
+        .unwrap();
+        assert_eq!(rendered, "# Orkee\n\nBy Ada on 2026-01-02");
+    }
+
+    #[test]
+    fn test_conditional_sections_leave_no_blank_lines() {
+        let template =
+            "# PRD\n{{#if risks}}\n## Risks\n{{risks}}\n{{else}}\nNo risks.\n{{/if}}\nEnd\n";
+
+        let with_risks = render_template(template, &json!({"risks": "- Scope creep"})).unwrap();
+        assert_eq!(with_risks, "# PRD\n## Risks\n- Scope creep\nEnd\n");
+
+        let without = render_template(template, &json!({"risks": "  "})).unwrap();
+        assert_eq!(without, "# PRD\nNo risks.\nEnd\n");
+    }
+
+    #[test]
+    fn test_each_loop_over_features() {
+        let template = "{{#each feature_list}}\n### {{@index}}. {{name}} ({{project_name}})\n{{#if benefits}}\n{{benefits}}\n{{/if}}\n{{/each}}";
+        let rendered = render_template(
+            template,
+            &json!({
+                "project_name": "Orkee",
+                "feature_list": [
+                    {"name": "Boards", "benefits": ["Visual", "Fast"]},
+                    {"name": "Sync"}
+                ]
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            rendered,
+            "### 0. Boards (Orkee)\n- Visual\n- Fast\n### 1. Sync (Orkee)\n"
+        );
+
+        let scalars = render_template(
+            "{{#each tags}}[{{this}}]{{/each}}",
+            &json!({"tags": ["a", "b"]}),
+        )
+        .unwrap();
+        assert_eq!(scalars, "[a][b]");
+    }
+
+    #[test]
+    fn test_syntax_errors_have_positions() {
+        let errors = validate_template("# PRD\n{{#if risks}}\n{{risks}}\n");
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (2, 1));
+        assert!(errors[0].message.contains("Unclosed"));
+
+        let errors = validate_template("{{#each feature_list}}{{/if}}");
+        assert!(errors[0].message.contains("Expected {{/each}}"));
+
+        let errors = validate_template("{{/if}} {{else}} {{bad name}} {{overview");
+        let messages: Vec<_> = errors.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].contains("no matching opening block"));
+        assert!(messages[1].contains("only allowed inside"));
+        assert!(messages[2].contains("Invalid variable name"));
+        assert!(messages[3].contains("Unclosed '{{'"));
+    }
+
+    #[test]
+    fn test_validate_flags_unknown_top_level_variables() {
+        let errors = validate_template(
+            "{{projct_name}}\n{{#each feature_list}}{{name}}{{/each}}\n{{#if risks}}{{risks}}{{/if}}",
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("Unknown variable 'projct_name'"));
+    }
+
+    #[test]
+    fn test_default_variables_keep_explicit_date() {
+        let vars = with_default_variables(json!({"date": "today"}));
+        assert_eq!(vars["date"], "today");
+        let vars = with_default_variables(Value::Null);
+        assert_eq!(vars["date"].as_str().unwrap().len(), 10);
+    }
+}
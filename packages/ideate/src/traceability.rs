@@ -0,0 +1,393 @@
+// ABOUTME: PRD-to-epic traceability matrix mapping each PRD requirement to the work implementing it
+// ABOUTME: Extracts requirements from PRD markdown and matches them to epics and tasks from decomposition
+
+use serde::{Deserialize, Serialize};
+
+use crate::epic::EpicStatus;
+
+/// Key in a task's `metadata` JSON listing the PRD requirement IDs it implements
+pub const REQUIREMENT_IDS_KEY: &str = "requirementIds";
+
+/// A requirement or feature listed in a PRD
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrdRequirement {
+    /// Section number such as `2.1` when the PRD numbers its features,
+    /// otherwise `R1`, `R2`, ... in document order
+    pub id: String,
+    pub title: String,
+    /// Heading of the section the requirement is listed under
+    pub section: String,
+}
+
+/// How a piece of work was matched to a requirement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TraceLink {
+    /// Recorded during task decomposition
+    Explicit,
+    /// The requirement's title appears in the epic name or task title
+    Inferred,
+}
+
+/// An epic of the PRD, as traced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracedEpic {
+    pub id: String,
+    pub name: String,
+    pub status: EpicStatus,
+    pub link: TraceLink,
+}
+
+/// A task of the PRD, as traced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracedTask {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub epic_id: Option<String>,
+    pub link: TraceLink,
+}
+
+/// A task with the decomposition metadata traceability reads
+#[derive(Debug, Clone)]
+pub struct TaskTraceInput {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub epic_id: Option<String>,
+    /// Requirement IDs or titles recorded in the task's metadata
+    pub requirement_ids: Vec<String>,
+}
+
+/// One row of the matrix
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequirementTrace {
+    pub requirement: PrdRequirement,
+    pub epics: Vec<TracedEpic>,
+    pub tasks: Vec<TracedTask>,
+    pub completed_tasks: usize,
+    /// Whether any epic or task implements the requirement
+    pub covered: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceabilityMatrix {
+    pub prd_id: String,
+    pub prd_title: String,
+    pub requirements: Vec<RequirementTrace>,
+    pub covered_count: usize,
+    /// IDs of requirements no epic or task implements
+    pub uncovered: Vec<String>,
+    pub coverage_percentage: f64,
+    /// Tasks of the PRD that don't trace back to any requirement
+    pub untraced_task_ids: Vec<String>,
+}
+
+/// Pull requirements out of PRD markdown.
+///
+/// Requirements are the `###` headings under a `##` section about features or
+/// requirements, or that section's list items when it has no subheadings.
+pub fn extract_requirements(markdown: &str) -> Vec<PrdRequirement> {
+    let mut requirements = Vec::new();
+    // (section heading, subheadings, list items) for each requirements section
+    let mut sections: Vec<(String, Vec<String>, Vec<String>)> = Vec::new();
+    let mut in_section = false;
+    let mut in_code = false;
+
+    for line in markdown.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            continue;
+        }
+
+        if let Some(title) = line.strip_prefix("## ") {
+            let lower = title.to_lowercase();
+            in_section = lower.contains("feature") || lower.contains("requirement");
+            if in_section {
+                sections.push((strip_number(title).1.to_string(), Vec::new(), Vec::new()));
+            }
+        } else if line.starts_with("# ") {
+            in_section = false;
+        } else if let Some((_, subheadings, items)) = sections.last_mut().filter(|_| in_section) {
+            if let Some(title) = line.strip_prefix("### ") {
+                subheadings.push(title.trim().to_string());
+            } else if let Some(item) = list_item(line) {
+                items.push(item.to_string());
+            }
+        }
+    }
+
+    for (section, subheadings, items) in sections {
+        let titles = if subheadings.is_empty() {
+            items
+        } else {
+            subheadings
+        };
+        for title in titles {
+            let (number, title) = strip_number(&title);
+            let id = match number {
+                Some(number) => number.to_string(),
+                None => format!("R{}", requirements.len() + 1),
+            };
+            requirements.push(PrdRequirement {
+                id,
+                title: title.trim_matches('*').trim().to_string(),
+                section: section.clone(),
+            });
+        }
+    }
+
+    requirements
+}
+
+/// Split a leading section number like `2.1` or `3.` off a heading
+fn strip_number(title: &str) -> (Option<&str>, &str) {
+    let title = title.trim();
+    let end = title
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(title.len());
+    let number = title[..end].trim_end_matches('.');
+    if number.is_empty() || !title[end..].starts_with(' ') {
+        return (None, title);
+    }
+    (Some(number), title[end..].trim())
+}
+
+/// Text of a top-level bullet or numbered list item
+fn list_item(line: &str) -> Option<&str> {
+    if let Some(item) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Some(item.trim());
+    }
+    let (number, rest) = line.split_once(". ")?;
+    number
+        .chars()
+        .all(|c| c.is_ascii_digit())
+        .then(|| rest.trim())
+}
+
+/// Lowercase words, so titles match regardless of case and punctuation
+fn normalize(text: &str) -> String {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether `text` mentions `phrase` as whole words
+fn mentions(text: &str, phrase: &str) -> bool {
+    !phrase.is_empty() && format!(" {} ", text).contains(&format!(" {} ", phrase))
+}
+
+/// Build the matrix for a PRD from its requirements, epics, and tasks
+pub fn build_traceability_matrix(
+    prd_id: &str,
+    prd_title: &str,
+    requirements: Vec<PrdRequirement>,
+    epics: &[(String, String, EpicStatus)],
+    tasks: &[TaskTraceInput],
+) -> TraceabilityMatrix {
+    let mut traced_task_ids = std::collections::HashSet::new();
+
+    let traces: Vec<RequirementTrace> = requirements
+        .into_iter()
+        .map(|requirement| {
+            let title = normalize(&requirement.title);
+            let is_requirement = |reference: &String| {
+                reference.trim().eq_ignore_ascii_case(&requirement.id)
+                    || normalize(reference) == title
+            };
+
+            let tasks: Vec<TracedTask> = tasks
+                .iter()
+                .filter_map(|task| {
+                    let link = if task.requirement_ids.iter().any(is_requirement) {
+                        TraceLink::Explicit
+                    } else if mentions(&normalize(&task.title), &title) {
+                        TraceLink::Inferred
+                    } else {
+                        return None;
+                    };
+                    Some(TracedTask {
+                        id: task.id.clone(),
+                        title: task.title.clone(),
+                        status: task.status.clone(),
+                        epic_id: task.epic_id.clone(),
+                        link,
+                    })
+                })
+                .collect();
+
+            let epics: Vec<TracedEpic> = epics
+                .iter()
+                .filter_map(|(id, name, status)| {
+                    // An epic implements what its explicitly linked tasks implement
+                    let link = if tasks.iter().any(|task| {
+                        task.link == TraceLink::Explicit && task.epic_id.as_ref() == Some(id)
+                    }) {
+                        TraceLink::Explicit
+                    } else if mentions(&normalize(name), &title) {
+                        TraceLink::Inferred
+                    } else {
+                        return None;
+                    };
+                    Some(TracedEpic {
+                        id: id.clone(),
+                        name: name.clone(),
+                        status: *status,
+                        link,
+                    })
+                })
+                .collect();
+
+            traced_task_ids.extend(tasks.iter().map(|task| task.id.clone()));
+            RequirementTrace {
+                completed_tasks: tasks.iter().filter(|task| task.status == "done").count(),
+                covered: !epics.is_empty() || !tasks.is_empty(),
+                requirement,
+                epics,
+                tasks,
+            }
+        })
+        .collect();
+
+    let covered_count = traces.iter().filter(|trace| trace.covered).count();
+    let coverage_percentage = if traces.is_empty() {
+        0.0
+    } else {
+        (covered_count as f64 / traces.len() as f64 * 1000.0).round() / 10.0
+    };
+
+    TraceabilityMatrix {
+        prd_id: prd_id.to_string(),
+        prd_title: prd_title.to_string(),
+        covered_count,
+        uncovered: traces
+            .iter()
+            .filter(|trace| !trace.covered)
+            .map(|trace| trace.requirement.id.clone())
+            .collect(),
+        coverage_percentage,
+        untraced_task_ids: tasks
+            .iter()
+            .filter(|task| !traced_task_ids.contains(&task.id))
+            .map(|task| task.id.clone())
+            .collect(),
+        requirements: traces,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRD: &str = "# Product Requirements Document
+
+## 1. Overview
+### Problem Statement
+Checkout is slow.
+
+## 2. Core Features
+
+### 2.1 Saved Payment Methods
+**What:** Store cards.
+
+### 2.2 One-Click Checkout
+
+## 3. User Experience
+### Personas
+";
+
+    fn task(id: &str, title: &str, epic_id: &str, requirement_ids: &[&str]) -> TaskTraceInput {
+        TaskTraceInput {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: "pending".to_string(),
+            epic_id: Some(epic_id.to_string()),
+            requirement_ids: requirement_ids.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_extract_numbered_features() {
+        let requirements = extract_requirements(PRD);
+        assert_eq!(
+            requirements,
+            vec![
+                PrdRequirement {
+                    id: "2.1".to_string(),
+                    title: "Saved Payment Methods".to_string(),
+                    section: "Core Features".to_string(),
+                },
+                PrdRequirement {
+                    id: "2.2".to_string(),
+                    title: "One-Click Checkout".to_string(),
+                    section: "Core Features".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_list_requirements() {
+        let markdown = "## Functional Requirements\n- Export to CSV\n- **Import from Jira**\n\n```\n- not a requirement\n```\n## Out of Scope\n- Billing";
+        let requirements = extract_requirements(markdown);
+        let titles: Vec<_> = requirements.iter().map(|r| r.title.as_str()).collect();
+        assert_eq!(titles, vec!["Export to CSV", "Import from Jira"]);
+        assert_eq!(requirements[1].id, "R2");
+    }
+
+    #[test]
+    fn test_matrix_traces_and_flags_uncovered() {
+        let epics = vec![
+            (
+                "epic1".to_string(),
+                "Payments".to_string(),
+                EpicStatus::InProgress,
+            ),
+            (
+                "epic2".to_string(),
+                "One-click checkout".to_string(),
+                EpicStatus::Draft,
+            ),
+        ];
+        let tasks = vec![
+            task("t1", "Add card vault table", "epic1", &["2.1"]),
+            task("t2", "Tokenize cards", "epic1", &["saved payment methods"]),
+            task("t3", "Set up CI", "epic1", &[]),
+        ];
+
+        let matrix = build_traceability_matrix(
+            "prd1",
+            "Checkout",
+            extract_requirements(&format!("{}\n## 4. Requirements\n- Audit logging", PRD)),
+            &epics,
+            &tasks,
+        );
+
+        let saved = &matrix.requirements[0];
+        assert_eq!(saved.tasks.len(), 2);
+        assert!(saved.tasks.iter().all(|t| t.link == TraceLink::Explicit));
+        assert_eq!(saved.epics[0].id, "epic1");
+        assert_eq!(saved.epics[0].link, TraceLink::Explicit);
+
+        // Matched only by the epic's name
+        let one_click = &matrix.requirements[1];
+        assert!(one_click.tasks.is_empty());
+        assert_eq!(one_click.epics[0].link, TraceLink::Inferred);
+
+        assert_eq!(matrix.covered_count, 2);
+        assert_eq!(matrix.uncovered, vec!["R3".to_string()]);
+        assert_eq!(matrix.coverage_percentage, 66.7);
+        assert_eq!(matrix.untraced_task_ids, vec!["t3".to_string()]);
+    }
+}
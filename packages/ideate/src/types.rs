@@ -67,6 +67,9 @@ pub struct CreateIdeateSessionInput {
     pub template_id: Option<String>,
     #[serde(default)]
     pub research_tools_enabled: bool,
+    /// Custom discovery question bank to ask from instead of the built-in questions
+    #[serde(default)]
+    pub question_bank_id: Option<String>,
 }
 
 /// Input for updating a session
@@ -4,8 +4,18 @@
 //! allowing tests to use isolated in-memory storage while production
 //! code continues to use the global storage manager.
 
-use orkee_projects::ProjectsManager;
+use orkee_projects::orkee_storage::StorageError;
+use orkee_projects::{DbState, ProjectsManager};
 use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+static DB_STATE: OnceCell<DbState> = OnceCell::const_new();
+
+/// Database state for data outside the projects manager (tasks, settings),
+/// opened on first use and shared for the life of the server
+pub async fn shared_db_state() -> Result<&'static DbState, StorageError> {
+    DB_STATE.get_or_try_init(DbState::init).await
+}
 
 /// Context for tool execution that holds dependencies
 /// This enables dependency injection for testing while maintaining
@@ -1,5 +1,7 @@
 pub mod context;
 pub mod mcp;
+pub mod plugins;
+pub mod resources;
 pub mod tools;
 
 #[cfg(test)]
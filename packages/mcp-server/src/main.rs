@@ -16,9 +16,12 @@ use ctrlc;
 
 mod context;
 mod mcp;
+mod plugins;
+mod resources;
 mod tools;
 
 use mcp::*;
+use resources::{resources_list, resources_read, resources_templates_list};
 use tools::{tools_call, tools_list};
 
 #[derive(Parser)]
@@ -61,11 +64,15 @@ async fn handle_rpc_request(method: &str, params: Option<Value>) -> Result<Value
             Ok(result)
         }
         "resources/list" => {
-            let result = resources_list(params).await?;
+            let result = resources_list(params, None).await?;
+            Ok(result)
+        }
+        "resources/templates/list" => {
+            let result = resources_templates_list(params).await?;
             Ok(result)
         }
         "resources/read" => {
-            let result = resources_read(params).await?;
+            let result = resources_read(params, None).await?;
             Ok(result)
         }
         "prompts/list" => {
@@ -111,7 +118,7 @@ async fn main() -> Result<()> {
 
     if cli.resources {
         println!("Available resources:");
-        println!("- project://[id]: Access to specific project data");
+        println!("- context://project/[id]: Project context bundle (README, key symbols, active tasks, recent commits)");
         return Ok(());
     }
 
@@ -122,6 +129,7 @@ async fn main() -> Result<()> {
     }
 
     // Default behavior is to start MCP server unless showing capabilities
+    plugins::init().await;
 
     // Set up signal handling for graceful shutdown
     let running = Arc::new(AtomicBool::new(true));
@@ -110,19 +110,6 @@ pub async fn logging_set_level(_request: Option<LoggingLevel>) -> Result<Value>
     Ok(json!({}))
 }
 
-pub async fn resources_list(_request: Option<Value>) -> Result<Value> {
-    Ok(json!({
-        "resources": [],
-        "nextCursor": null
-    }))
-}
-
-pub async fn resources_read(_request: Option<Value>) -> Result<Value> {
-    Ok(json!({
-        "contents": []
-    }))
-}
-
 pub async fn prompts_list(_request: Option<Value>) -> Result<Value> {
     Ok(json!({
         "prompts": [],
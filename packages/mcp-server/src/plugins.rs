@@ -0,0 +1,93 @@
+//! WASM plugin tools for the MCP server
+//!
+//! Plugins in ~/.orkee/plugins with the `mcp_tools` capability are loaded once at
+//! startup. Their tools are listed after the built-in tools as `{plugin}__{tool}`.
+
+use orkee_plugins::{PluginHost, ProjectServices, ToolSpec};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use crate::context::shared_db_state;
+use crate::tools::{Tool, ToolInputSchema, ToolInputSchemaProperty};
+
+static PLUGINS: OnceLock<PluginHost> = OnceLock::new();
+
+/// Load plugins unless the `plugins_enabled` setting turns them off
+pub async fn init() {
+    let enabled = match shared_db_state().await {
+        Ok(db) => orkee_plugins::plugins_enabled(&db.pool).await,
+        Err(e) => {
+            eprintln!("Warning: Could not read plugin settings: {}", e);
+            true
+        }
+    };
+    let host = if enabled {
+        PluginHost::load_dir(&orkee_plugins::plugins_dir(), Arc::new(ProjectServices)).await
+    } else {
+        PluginHost::empty()
+    };
+    for failure in host.failures() {
+        eprintln!(
+            "Warning: Could not load plugin {}: {}",
+            failure.path, failure.error
+        );
+    }
+    let _ = PLUGINS.set(host);
+}
+
+/// Tools registered by loaded plugins; empty until `init` runs
+pub fn plugin_tools() -> Vec<Tool> {
+    PLUGINS
+        .get()
+        .map(|host| {
+            host.tools()
+                .into_iter()
+                .map(|(name, spec)| to_tool(name, &spec))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Call a plugin tool. Returns None when no loaded plugin owns `name`.
+pub async fn call_plugin_tool(name: &str, arguments: Value) -> Option<(String, bool)> {
+    let result = PLUGINS.get()?.call_tool(name, arguments).await?;
+    Some(match result {
+        Ok(response) => (response.text, response.is_error),
+        Err(e) => (format!("Plugin tool {} failed: {}", name, e), true),
+    })
+}
+
+/// Plugins describe arguments with JSON Schema; keep the properties our schema type can hold
+fn to_tool(name: String, spec: &ToolSpec) -> Tool {
+    let properties: HashMap<String, ToolInputSchemaProperty> = spec
+        .input_schema
+        .get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .iter()
+                .filter_map(|(key, property)| {
+                    serde_json::from_value(property.clone())
+                        .ok()
+                        .map(|property| (key.clone(), property))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let required = spec
+        .input_schema
+        .get("required")
+        .and_then(|required| serde_json::from_value(required.clone()).ok())
+        .unwrap_or_default();
+
+    Tool {
+        name,
+        description: spec.description.clone(),
+        input_schema: ToolInputSchema {
+            type_name: "object".to_string(),
+            properties,
+            required,
+        },
+    }
+}
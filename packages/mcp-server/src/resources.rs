@@ -0,0 +1,454 @@
+//! Project context resources for the MCP server
+//!
+//! `context://project/{id}` assembles a markdown bundle with a README summary, key
+//! symbols from the code graph, active tasks and recent commits so an agent gets
+//! useful project context in one read. The bundle is sized to a token budget,
+//! 4000 tokens unless the URI asks for another with `?tokens=N`.
+
+use anyhow::{anyhow, Result};
+use orkee_projects::orkee_context::{CodeGraph, GraphBuilder, NodeType};
+use orkee_projects::{Project, Task, TaskPriority, TaskStatus};
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::context::{shared_db_state, ToolContext};
+
+const URI_PREFIX: &str = "context://project/";
+const DEFAULT_TOKEN_BUDGET: u64 = 4_000;
+/// Smaller budgets can't fit more than the header
+const MIN_TOKEN_BUDGET: u64 = 200;
+const MAX_TOKEN_BUDGET: u64 = 100_000;
+const CHARS_PER_TOKEN: u64 = 4;
+
+const README_NAMES: &[&str] = &["README.md", "readme.md", "Readme.md", "README"];
+const README_SUMMARY_LINES: usize = 40;
+const SYMBOL_LIMIT: usize = 40;
+const TASK_LIMIT: usize = 30;
+const COMMIT_LIMIT: usize = 15;
+
+/// A parsed `context://project/{id}` URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextUri {
+    pub project_id: String,
+    pub token_budget: u64,
+}
+
+/// One titled section of a bundle, split into lines so it can be cut to fit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleSection {
+    pub title: String,
+    pub lines: Vec<String>,
+}
+
+/// Parse a context URI, clamping any requested budget to a sane range
+pub fn parse_context_uri(uri: &str) -> Option<ContextUri> {
+    let rest = uri.strip_prefix(URI_PREFIX)?;
+    let (project_id, query) = match rest.split_once('?') {
+        Some((id, query)) => (id, Some(query)),
+        None => (rest, None),
+    };
+    if project_id.is_empty() || project_id.contains('/') {
+        return None;
+    }
+
+    let token_budget = query
+        .into_iter()
+        .flat_map(|q| q.split('&'))
+        .find_map(|pair| pair.strip_prefix("tokens="))
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(|tokens| tokens.clamp(MIN_TOKEN_BUDGET, MAX_TOKEN_BUDGET))
+        .unwrap_or(DEFAULT_TOKEN_BUDGET);
+
+    Some(ContextUri {
+        project_id: project_id.to_string(),
+        token_budget,
+    })
+}
+
+/// Estimate the number of tokens in a piece of text
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.chars().count() as u64).div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Render sections under a header without exceeding `token_budget`
+///
+/// Each section is offered an equal share of what's left, and whatever a
+/// section doesn't use carries over to the ones after it. Empty sections are
+/// dropped, and a section cut short says how many lines were left out.
+pub fn assemble_bundle(header: &str, sections: &[BundleSection], token_budget: u64) -> String {
+    let mut output = format!("{}\n", header.trim_end());
+    let mut remaining = token_budget.saturating_sub(estimate_tokens(&output));
+
+    let sections: Vec<&BundleSection> = sections.iter().filter(|s| !s.lines.is_empty()).collect();
+    for (index, section) in sections.iter().enumerate() {
+        let share = remaining / (sections.len() - index) as u64;
+        let heading = format!("\n## {}\n\n", section.title);
+        let mut used = estimate_tokens(&heading);
+        if used >= share {
+            continue;
+        }
+
+        let mut body = String::new();
+        let mut included = 0;
+        for line in &section.lines {
+            let cost = estimate_tokens(line) + 1;
+            if used + cost > share {
+                break;
+            }
+            body.push_str(line);
+            body.push('\n');
+            used += cost;
+            included += 1;
+        }
+        if included == 0 {
+            continue;
+        }
+
+        let omitted = section.lines.len() - included;
+        if omitted > 0 {
+            let note = format!("_…{} more omitted_\n", omitted);
+            let cost = estimate_tokens(&note);
+            if used + cost <= share {
+                body.push_str(&note);
+                used += cost;
+            }
+        }
+
+        output.push_str(&heading);
+        output.push_str(&body);
+        remaining = remaining.saturating_sub(used);
+    }
+
+    output
+}
+
+/// List a context bundle resource for every project
+pub async fn resources_list(
+    _request: Option<Value>,
+    context: Option<ToolContext>,
+) -> Result<Value> {
+    let context = resolve_context(context).await?;
+    let projects = context
+        .projects_manager()
+        .list_projects()
+        .await
+        .map_err(|e| anyhow!("Failed to list projects: {}", e))?;
+
+    let resources: Vec<Value> = projects
+        .iter()
+        .map(|project| {
+            json!({
+                "uri": format!("{}{}", URI_PREFIX, project.id),
+                "name": format!("{} context", project.name),
+                "description": "README summary, key symbols, active tasks and recent commits",
+                "mimeType": "text/markdown"
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "resources": resources,
+        "nextCursor": null
+    }))
+}
+
+/// Advertise the context bundle URI template
+pub async fn resources_templates_list(_request: Option<Value>) -> Result<Value> {
+    Ok(json!({
+        "resourceTemplates": [{
+            "uriTemplate": format!("{}{{id}}", URI_PREFIX),
+            "name": "Project context",
+            "description": format!(
+                "Curated project context sized to a token budget ({} by default, override with ?tokens=N)",
+                DEFAULT_TOKEN_BUDGET
+            ),
+            "mimeType": "text/markdown"
+        }],
+        "nextCursor": null
+    }))
+}
+
+/// Read a context bundle
+pub async fn resources_read(request: Option<Value>, context: Option<ToolContext>) -> Result<Value> {
+    let uri = request
+        .as_ref()
+        .and_then(|params| params.get("uri"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("Missing resource uri"))?;
+    let parsed = parse_context_uri(uri).ok_or_else(|| anyhow!("Unknown resource: {}", uri))?;
+
+    let context = resolve_context(context).await?;
+    let project = context
+        .projects_manager()
+        .get_project(&parsed.project_id)
+        .await
+        .map_err(|e| anyhow!("Failed to get project: {}", e))?
+        .ok_or_else(|| anyhow!("Project not found: {}", parsed.project_id))?;
+
+    let sections = vec![
+        readme_section(&project),
+        symbols_section(&project).await,
+        tasks_section(&project).await,
+        commits_section(&project).await,
+    ];
+    let text = assemble_bundle(&bundle_header(&project), &sections, parsed.token_budget);
+
+    Ok(json!({
+        "contents": [{
+            "uri": uri,
+            "mimeType": "text/markdown",
+            "text": text
+        }]
+    }))
+}
+
+async fn resolve_context(context: Option<ToolContext>) -> Result<ToolContext> {
+    match context {
+        Some(ctx) => Ok(ctx),
+        None => ToolContext::new()
+            .await
+            .map_err(|e| anyhow!("Failed to create context: {}", e)),
+    }
+}
+
+fn bundle_header(project: &Project) -> String {
+    let mut header = format!("# {}\n\nRoot: `{}`\n", project.name, project.project_root);
+    if let Some(description) = project.description.as_deref().filter(|d| !d.is_empty()) {
+        header.push_str(&format!("\n{}\n", description));
+    }
+    header
+}
+
+/// Opening of the README up to its second heading, without badges or images
+fn readme_section(project: &Project) -> BundleSection {
+    let root = Path::new(&project.project_root);
+    let content = README_NAMES
+        .iter()
+        .find_map(|name| std::fs::read_to_string(root.join(name)).ok())
+        .unwrap_or_default();
+
+    let mut lines = Vec::new();
+    let mut headings = 0;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            headings += 1;
+            if headings > 1 {
+                break;
+            }
+            continue;
+        }
+        if trimmed.starts_with("![") || trimmed.starts_with("[![") || trimmed.starts_with('<') {
+            continue;
+        }
+        if trimmed.is_empty() && lines.last().is_none_or(|l: &String| l.is_empty()) {
+            continue;
+        }
+        lines.push(trimmed.to_string());
+        if lines.len() >= README_SUMMARY_LINES {
+            break;
+        }
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    BundleSection {
+        title: "README".to_string(),
+        lines,
+    }
+}
+
+async fn symbols_section(project: &Project) -> BundleSection {
+    let root = project.project_root.clone();
+    let id = project.id.clone();
+    let graph =
+        tokio::task::spawn_blocking(move || GraphBuilder::new().build_symbol_graph(&root, &id))
+            .await;
+
+    let lines = match graph {
+        Ok(Ok(graph)) => key_symbols(&graph),
+        Ok(Err(e)) => {
+            eprintln!(
+                "Warning: Could not build symbol graph for {}: {}",
+                project.id, e
+            );
+            Vec::new()
+        }
+        Err(e) => {
+            eprintln!(
+                "Warning: Symbol graph task failed for {}: {}",
+                project.id, e
+            );
+            Vec::new()
+        }
+    };
+
+    BundleSection {
+        title: "Key symbols".to_string(),
+        lines,
+    }
+}
+
+/// Classes before functions, larger definitions first, skipping test files
+fn key_symbols(graph: &CodeGraph) -> Vec<String> {
+    let mut symbols: Vec<_> = graph
+        .nodes
+        .iter()
+        .filter(|node| matches!(node.node_type, NodeType::Class | NodeType::Function))
+        .filter_map(|node| {
+            let path = node.metadata.path.as_deref()?;
+            if is_test_path(path) {
+                return None;
+            }
+            let span = match (node.metadata.line_start, node.metadata.line_end) {
+                (Some(start), Some(end)) => end.saturating_sub(start),
+                _ => 0,
+            };
+            Some((node, path, span))
+        })
+        .collect();
+    symbols.sort_by_key(|(node, path, span)| {
+        (
+            !matches!(node.node_type, NodeType::Class),
+            std::cmp::Reverse(*span),
+            path.to_string(),
+        )
+    });
+
+    symbols
+        .into_iter()
+        .take(SYMBOL_LIMIT)
+        .map(|(node, path, _)| {
+            let kind = match node.node_type {
+                NodeType::Class => "class",
+                _ => "fn",
+            };
+            match node.metadata.line_start {
+                Some(line) => format!("- `{}` ({}) — {}:{}", node.label, kind, path, line),
+                None => format!("- `{}` ({}) — {}", node.label, kind, path),
+            }
+        })
+        .collect()
+}
+
+fn is_test_path(path: &str) -> bool {
+    let path = Path::new(path);
+    let in_test_dir = path.components().any(|c| {
+        matches!(
+            c.as_os_str().to_string_lossy().as_ref(),
+            "test" | "tests" | "__tests__" | "spec"
+        )
+    });
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    in_test_dir
+        || file_name.contains(".test.")
+        || file_name.contains(".spec.")
+        || file_name.starts_with("test_")
+        || file_name.ends_with("_test.rs")
+        || file_name.ends_with("_test.go")
+}
+
+async fn tasks_section(project: &Project) -> BundleSection {
+    let tasks = match shared_db_state().await {
+        Ok(db) => db
+            .task_storage
+            .list_tasks(&project.id)
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Warning: Could not list tasks for {}: {}", project.id, e);
+                Vec::new()
+            }),
+        Err(e) => {
+            eprintln!("Warning: Could not open database: {}", e);
+            Vec::new()
+        }
+    };
+
+    BundleSection {
+        title: "Active tasks".to_string(),
+        lines: active_task_lines(&tasks),
+    }
+}
+
+/// Work in flight first, then by priority
+fn active_task_lines(tasks: &[Task]) -> Vec<String> {
+    let mut active: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| status_rank(&task.status).is_some())
+        .collect();
+    active.sort_by_key(|task| {
+        (
+            status_rank(&task.status),
+            std::cmp::Reverse(priority_rank(&task.priority)),
+            task.position,
+        )
+    });
+
+    active
+        .into_iter()
+        .take(TASK_LIMIT)
+        .map(|task| {
+            format!(
+                "- [{}] {} ({})",
+                enum_label(&task.status),
+                task.title,
+                enum_label(&task.priority)
+            )
+        })
+        .collect()
+}
+
+fn status_rank(status: &TaskStatus) -> Option<u8> {
+    match status {
+        TaskStatus::InProgress => Some(0),
+        TaskStatus::Review => Some(1),
+        TaskStatus::Blocked => Some(2),
+        TaskStatus::Pending => Some(3),
+        TaskStatus::Done | TaskStatus::Cancelled | TaskStatus::Deferred => None,
+    }
+}
+
+fn priority_rank(priority: &TaskPriority) -> u8 {
+    match priority {
+        TaskPriority::Low => 0,
+        TaskPriority::Medium => 1,
+        TaskPriority::High => 2,
+        TaskPriority::Critical => 3,
+    }
+}
+
+/// The serialized name of a unit enum variant, e.g. `in-progress`
+fn enum_label<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+async fn commits_section(project: &Project) -> BundleSection {
+    let root = project.project_root.clone();
+    let commits = tokio::task::spawn_blocking(move || {
+        orkee_git_utils::recent_commits(Path::new(&root), COMMIT_LIMIT)
+    })
+    .await
+    .ok()
+    .flatten()
+    .unwrap_or_default();
+
+    BundleSection {
+        title: "Recent commits".to_string(),
+        lines: commits
+            .iter()
+            .map(|commit| {
+                format!(
+                    "- {} {} ({})",
+                    commit.short_id, commit.summary, commit.author
+                )
+            })
+            .collect(),
+    }
+}
@@ -7,6 +7,9 @@ mod tool_tests;
 #[cfg(test)]
 mod integration_tests;
 
+#[cfg(test)]
+mod resource_tests;
+
 #[cfg(test)]
 pub mod test_helpers {
     use crate::context::ToolContext;
@@ -27,6 +30,7 @@ pub mod test_helpers {
             enable_fts: true,
             max_connections: 1,
             busy_timeout_seconds: 5,
+            read_replica: None,
         };
 
         let storage_manager = Arc::new(StorageManager::new(config).await?);
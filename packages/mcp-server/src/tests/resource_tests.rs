@@ -0,0 +1,140 @@
+use crate::resources::{
+    assemble_bundle, estimate_tokens, parse_context_uri, resources_list, resources_read,
+    resources_templates_list, BundleSection, ContextUri,
+};
+use crate::tests::test_helpers;
+use crate::tools::{self, CallToolRequest};
+use serde_json::json;
+
+fn section(title: &str, count: usize, width: usize) -> BundleSection {
+    BundleSection {
+        title: title.to_string(),
+        lines: (0..count)
+            .map(|i| format!("- {} {}", i, "x".repeat(width)))
+            .collect(),
+    }
+}
+
+#[test]
+fn test_parse_context_uri() {
+    assert_eq!(
+        parse_context_uri("context://project/abc123"),
+        Some(ContextUri {
+            project_id: "abc123".to_string(),
+            token_budget: 4_000,
+        })
+    );
+    assert_eq!(
+        parse_context_uri("context://project/abc123?tokens=1500")
+            .unwrap()
+            .token_budget,
+        1_500
+    );
+    // Budgets are clamped rather than rejected
+    assert_eq!(
+        parse_context_uri("context://project/abc123?tokens=5")
+            .unwrap()
+            .token_budget,
+        200
+    );
+    assert_eq!(
+        parse_context_uri("context://project/abc123?tokens=nope")
+            .unwrap()
+            .token_budget,
+        4_000
+    );
+
+    assert!(parse_context_uri("context://project/").is_none());
+    assert!(parse_context_uri("context://project/a/b").is_none());
+    assert!(parse_context_uri("project://abc123").is_none());
+}
+
+#[test]
+fn test_assemble_bundle_fits_everything_when_budget_allows() {
+    let sections = vec![section("README", 3, 10), section("Active tasks", 2, 10)];
+    let bundle = assemble_bundle("# Demo", &sections, 4_000);
+
+    assert!(bundle.starts_with("# Demo\n"));
+    assert!(bundle.contains("## README"));
+    assert!(bundle.contains("## Active tasks"));
+    assert!(!bundle.contains("omitted"));
+}
+
+#[test]
+fn test_assemble_bundle_respects_budget_and_shares_it() {
+    let sections = vec![
+        section("README", 100, 80),
+        section("Key symbols", 100, 80),
+        section("Recent commits", 100, 80),
+    ];
+    let bundle = assemble_bundle("# Demo", &sections, 600);
+
+    assert!(estimate_tokens(&bundle) <= 600);
+    // A long first section doesn't starve the later ones
+    assert!(bundle.contains("## README"));
+    assert!(bundle.contains("## Key symbols"));
+    assert!(bundle.contains("## Recent commits"));
+    assert!(bundle.contains("more omitted"));
+}
+
+#[test]
+fn test_assemble_bundle_skips_empty_sections() {
+    let sections = vec![section("README", 0, 0), section("Recent commits", 1, 5)];
+    let bundle = assemble_bundle("# Demo", &sections, 1_000);
+
+    assert!(!bundle.contains("## README"));
+    assert!(bundle.contains("## Recent commits"));
+}
+
+#[tokio::test]
+async fn test_resources_list_has_one_bundle_per_project() {
+    let context = test_helpers::create_test_context().await.unwrap();
+    let create_request = CallToolRequest {
+        name: "project_manage".to_string(),
+        arguments: Some(json!({
+            "action": "create",
+            "name": "Context Project",
+            "projectRoot": "/tmp/context-project"
+        })),
+    };
+    tools::tools_call(Some(create_request), Some(context.clone()))
+        .await
+        .unwrap();
+
+    let result = resources_list(None, Some(context)).await.unwrap();
+    let resources = result["resources"].as_array().unwrap();
+    assert_eq!(resources.len(), 1);
+    assert!(resources[0]["uri"]
+        .as_str()
+        .unwrap()
+        .starts_with("context://project/"));
+    assert_eq!(resources[0]["mimeType"], "text/markdown");
+}
+
+#[tokio::test]
+async fn test_resources_templates_list() {
+    let result = resources_templates_list(None).await.unwrap();
+    assert_eq!(
+        result["resourceTemplates"][0]["uriTemplate"],
+        "context://project/{id}"
+    );
+}
+
+#[tokio::test]
+async fn test_resources_read_rejects_bad_requests() {
+    let context = test_helpers::create_test_context().await.unwrap();
+
+    assert!(resources_read(None, Some(context.clone())).await.is_err());
+    assert!(resources_read(
+        Some(json!({"uri": "file:///etc/passwd"})),
+        Some(context.clone())
+    )
+    .await
+    .is_err());
+    assert!(resources_read(
+        Some(json!({"uri": "context://project/missing"})),
+        Some(context)
+    )
+    .await
+    .is_err());
+}
@@ -253,8 +253,11 @@ pub async fn tools_list(
         },
     };
 
+    let mut tools = vec![projects_tool, project_manage_tool];
+    tools.extend(crate::plugins::plugin_tools());
+
     let response = ListToolsResult {
-        tools: vec![projects_tool, project_manage_tool],
+        tools,
         next_cursor: None,
     };
 
@@ -323,13 +326,20 @@ pub async fn tools_call(
                 is_error: None,
             })
         }
-        _ => Ok(CallToolResult {
-            content: vec![ToolContent {
-                content_type: "text".to_string(),
-                text: format!("Unknown tool: {}", call_request.name),
-            }],
-            is_error: Some(true),
-        }),
+        name => {
+            let arguments = call_request.arguments.unwrap_or_else(|| json!({}));
+            let (text, is_error) = match crate::plugins::call_plugin_tool(name, arguments).await {
+                Some((text, is_error)) => (text, is_error),
+                None => (format!("Unknown tool: {}", name), true),
+            };
+            Ok(CallToolResult {
+                content: vec![ToolContent {
+                    content_type: "text".to_string(),
+                    text,
+                }],
+                is_error: Some(is_error),
+            })
+        }
     }
 }
 
@@ -543,6 +553,7 @@ async fn execute_project_manage_tool(
                     task_source: None,
                     manual_tasks: None,
                     mcp_servers: None,
+                    expected_version: None,
                 };
 
                 match context.projects_manager().update_project(id, updates).await {
@@ -571,4 +571,15 @@ mod tests {
         assert_eq!(grok3_model.name, "Grok 3");
         assert_eq!(grok3_model.provider, "xai");
     }
+
+    #[test]
+    fn test_pricing_cost() {
+        let registry = ModelRegistry::new().unwrap();
+        let pricing = &registry
+            .get_model("claude-sonnet-4-5-20250929")
+            .unwrap()
+            .pricing;
+        assert_eq!(pricing.cost(1_000_000, 0), 3.0);
+        assert_eq!(pricing.cost(2_000, 1_000), 0.021);
+    }
 }
@@ -32,6 +32,15 @@ pub struct ModelPricing {
     pub output_per_million_tokens: f64,
 }
 
+impl ModelPricing {
+    /// Cost in USD of a call with the given token counts
+    pub fn cost(&self, input_tokens: u64, output_tokens: u64) -> f64 {
+        (input_tokens as f64 * self.input_per_million_tokens
+            + output_tokens as f64 * self.output_per_million_tokens)
+            / 1_000_000.0
+    }
+}
+
 /// CLI coding agent configuration from packages/agents/config/agents.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Agent {
@@ -0,0 +1,139 @@
+// ABOUTME: Notification dispatcher that routes events to their configured channels
+// ABOUTME: Records desktop/TUI notifications in the feed and posts Slack/Discord compatible webhooks
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{Datelike, Utc};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use tracing::{debug, error, warn};
+
+use super::preferences::NotificationPreferences;
+use super::storage::NotificationStorage;
+use super::types::{
+    Notification, NotificationChannel, NotificationEventType, NotificationInput,
+    NotificationSeverity,
+};
+use orkee_storage::StorageError;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Webhook body understood by both Slack (`text`) and Discord (`content`) incoming webhooks
+pub fn webhook_payload(notification: &Notification) -> Value {
+    json!({
+        "username": "Orkee",
+        "text": format!("*{}*\n{}", notification.title, notification.message),
+        "content": format!("**{}**\n{}", notification.title, notification.message),
+    })
+}
+
+pub struct NotificationDispatcher {
+    pool: SqlitePool,
+    storage: NotificationStorage,
+    http: reqwest::Client,
+}
+
+impl NotificationDispatcher {
+    pub fn new(pool: SqlitePool) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            storage: NotificationStorage::new(pool.clone()),
+            pool,
+            http,
+        }
+    }
+
+    pub fn storage(&self) -> &NotificationStorage {
+        &self.storage
+    }
+
+    /// Route an event to the channels the user enabled for it.
+    /// Returns `None` when every channel is disabled for this event type.
+    pub async fn notify(
+        &self,
+        input: NotificationInput,
+    ) -> Result<Option<Notification>, StorageError> {
+        let preferences = NotificationPreferences::load(&self.pool).await?;
+        let channels = preferences.channels_for(input.event_type);
+        if channels.is_empty() {
+            debug!(
+                "Notifications disabled for {}, skipping",
+                input.event_type.as_str()
+            );
+            return Ok(None);
+        }
+
+        let notification = self.storage.create_notification(&input, &channels).await?;
+
+        if channels.contains(&NotificationChannel::Webhook) {
+            if let Some(url) = preferences.webhook_url {
+                let http = self.http.clone();
+                let payload = webhook_payload(&notification);
+                tokio::spawn(async move {
+                    match http.post(&url).json(&payload).send().await {
+                        Ok(response) if response.status().is_success() => {
+                            debug!("Delivered notification webhook")
+                        }
+                        Ok(response) => {
+                            warn!("Notification webhook returned HTTP {}", response.status())
+                        }
+                        Err(e) => warn!("Failed to deliver notification webhook: {}", e),
+                    }
+                });
+            }
+        }
+
+        Ok(Some(notification))
+    }
+
+    /// Notify without waiting, for event sources that must not fail because of notifications
+    pub fn notify_in_background(self: &Arc<Self>, input: NotificationInput) {
+        let dispatcher = Arc::clone(self);
+        tokio::spawn(async move {
+            if let Err(e) = dispatcher.notify(input).await {
+                error!("Failed to dispatch notification: {}", e);
+            }
+        });
+    }
+
+    /// Notify once when month-to-date AI spend crosses the configured budget.
+    /// `added_cost` is the cost of the usage that was just recorded.
+    pub async fn check_budget(
+        &self,
+        added_cost: f64,
+    ) -> Result<Option<Notification>, StorageError> {
+        let preferences = NotificationPreferences::load(&self.pool).await?;
+        let Some(budget) = preferences.budget_monthly_usd else {
+            return Ok(None);
+        };
+
+        let today = Utc::now().date_naive();
+        let month_start = format!("{}-{:02}-01", today.year(), today.month());
+        let spent: f64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(estimated_cost), 0.0) FROM ai_usage_logs WHERE created_at >= ?",
+        )
+        .bind(&month_start)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        if spent < budget || spent - added_cost >= budget {
+            return Ok(None);
+        }
+
+        self.notify(NotificationInput::new(
+            NotificationEventType::BudgetThreshold,
+            NotificationSeverity::Warning,
+            "AI budget reached",
+            format!(
+                "AI spend this month is ${:.2}, over your ${:.2} budget",
+                spent, budget
+            ),
+        ))
+        .await
+    }
+}
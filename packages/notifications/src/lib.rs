@@ -0,0 +1,16 @@
+// ABOUTME: Notification subsystem for execution, sync, budget, and server events
+// ABOUTME: Routes events to desktop, webhook, and TUI channels based on user preferences
+
+pub mod dispatcher;
+pub mod preferences;
+pub mod storage;
+pub mod types;
+
+// Re-export main types
+pub use dispatcher::{webhook_payload, NotificationDispatcher};
+pub use preferences::{parse_channels, NotificationPreferences};
+pub use storage::NotificationStorage;
+pub use types::{
+    Notification, NotificationChannel, NotificationEventType, NotificationInput,
+    NotificationSeverity,
+};
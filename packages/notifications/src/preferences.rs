@@ -0,0 +1,160 @@
+// ABOUTME: Per-event notification preferences backed by system settings
+// ABOUTME: Parses channel lists, the webhook URL, and the monthly budget threshold
+
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+use tracing::warn;
+
+use super::types::{NotificationChannel, NotificationEventType};
+use orkee_storage::StorageError;
+
+pub const WEBHOOK_URL_SETTING: &str = "notification_webhook_url";
+pub const BUDGET_SETTING: &str = "notification_budget_monthly_usd";
+
+/// Parse a comma-separated channel list such as `desktop,webhook`.
+/// `none` or an empty value disables the event.
+pub fn parse_channels(value: &str) -> Result<Vec<NotificationChannel>, String> {
+    let mut channels = Vec::new();
+    for part in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        if part == "none" {
+            continue;
+        }
+        let channel = part.parse::<NotificationChannel>()?;
+        if !channels.contains(&channel) {
+            channels.push(channel);
+        }
+    }
+    Ok(channels)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NotificationPreferences {
+    pub channels: HashMap<NotificationEventType, Vec<NotificationChannel>>,
+    pub webhook_url: Option<String>,
+    /// Monthly AI spend that triggers a budget notification; `None` when disabled
+    pub budget_monthly_usd: Option<f64>,
+}
+
+impl NotificationPreferences {
+    /// Build preferences from `(key, value)` setting pairs. Invalid values are logged and ignored.
+    pub fn from_settings<'a>(settings: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut preferences = Self::default();
+
+        for (key, value) in settings {
+            if key == WEBHOOK_URL_SETTING {
+                let url = value.trim();
+                preferences.webhook_url = (!url.is_empty()).then(|| url.to_string());
+            } else if key == BUDGET_SETTING {
+                match value.trim().parse::<f64>() {
+                    Ok(budget) if budget > 0.0 => preferences.budget_monthly_usd = Some(budget),
+                    Ok(_) => {}
+                    Err(_) => warn!("Ignoring invalid {} value: {}", BUDGET_SETTING, value),
+                }
+            } else if let Some(event_type) = NotificationEventType::CONFIGURABLE
+                .into_iter()
+                .find(|event_type| event_type.setting_key() == key)
+            {
+                match parse_channels(value) {
+                    Ok(channels) => {
+                        preferences.channels.insert(event_type, channels);
+                    }
+                    Err(e) => warn!("Ignoring invalid {} value: {}", key, e),
+                }
+            }
+        }
+
+        preferences
+    }
+
+    /// Load preferences from the `notifications` settings category
+    pub async fn load(pool: &SqlitePool) -> Result<Self, StorageError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT key, value FROM system_settings WHERE category = 'notifications'",
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(Self::from_settings(
+            rows.iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        ))
+    }
+
+    /// Channels an event should be routed to. Test notifications go everywhere.
+    pub fn channels_for(&self, event_type: NotificationEventType) -> Vec<NotificationChannel> {
+        let mut channels = match event_type {
+            NotificationEventType::Test => vec![
+                NotificationChannel::Desktop,
+                NotificationChannel::Webhook,
+                NotificationChannel::Tui,
+            ],
+            _ => self.channels.get(&event_type).cloned().unwrap_or_default(),
+        };
+
+        // A webhook channel without a URL has nowhere to deliver
+        if self.webhook_url.is_none() {
+            channels.retain(|channel| *channel != NotificationChannel::Webhook);
+        }
+        channels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channels() {
+        assert_eq!(
+            parse_channels("desktop, webhook,desktop").unwrap(),
+            vec![NotificationChannel::Desktop, NotificationChannel::Webhook]
+        );
+        assert!(parse_channels("none").unwrap().is_empty());
+        assert!(parse_channels("").unwrap().is_empty());
+        assert!(parse_channels("desktop,email").is_err());
+    }
+
+    #[test]
+    fn test_preferences_from_settings() {
+        let preferences = NotificationPreferences::from_settings([
+            ("notify_execution_finished", "desktop,tui"),
+            ("notify_sync_failed", "webhook"),
+            ("notify_server_crash", "bogus"),
+            ("notification_webhook_url", ""),
+            ("notification_budget_monthly_usd", "50"),
+        ]);
+
+        assert_eq!(
+            preferences.channels_for(NotificationEventType::ExecutionFinished),
+            vec![NotificationChannel::Desktop, NotificationChannel::Tui]
+        );
+        // Webhook channel is dropped while no URL is configured
+        assert!(preferences
+            .channels_for(NotificationEventType::SyncFailed)
+            .is_empty());
+        assert!(preferences
+            .channels_for(NotificationEventType::ServerCrash)
+            .is_empty());
+        assert_eq!(preferences.budget_monthly_usd, Some(50.0));
+
+        let with_webhook = NotificationPreferences::from_settings([
+            ("notify_sync_failed", "webhook"),
+            (
+                "notification_webhook_url",
+                "https://hooks.slack.com/services/T0/B0/X",
+            ),
+            ("notification_budget_monthly_usd", "0"),
+        ]);
+        assert_eq!(
+            with_webhook.channels_for(NotificationEventType::SyncFailed),
+            vec![NotificationChannel::Webhook]
+        );
+        assert_eq!(
+            with_webhook.channels_for(NotificationEventType::Test).len(),
+            3
+        );
+        assert_eq!(with_webhook.budget_monthly_usd, None);
+    }
+}
@@ -0,0 +1,140 @@
+// ABOUTME: Notification feed storage using SQLite
+// ABOUTME: Persists dispatched notifications so desktop and TUI clients can poll for new ones
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{sqlite::SqlitePoolOptions, Row, SqlitePool};
+use tracing::debug;
+
+use super::preferences::parse_channels;
+use super::types::{Notification, NotificationChannel, NotificationInput};
+use orkee_storage::StorageError;
+
+/// Timestamps are stored with fixed precision so they compare correctly as strings
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+pub struct NotificationStorage {
+    pool: SqlitePool,
+}
+
+impl NotificationStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Open a small pool on an existing database, for clients like the TUI that only poll the feed
+    pub async fn connect(database_path: &std::path::Path) -> Result<Self, StorageError> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(&format!("sqlite:{}", database_path.display()))
+            .await
+            .map_err(StorageError::Sqlx)?;
+        Ok(Self::new(pool))
+    }
+
+    pub async fn create_notification(
+        &self,
+        input: &NotificationInput,
+        channels: &[NotificationChannel],
+    ) -> Result<Notification, StorageError> {
+        let id = nanoid::nanoid!(12);
+        let now = Utc::now();
+        let channels_value = channels
+            .iter()
+            .map(NotificationChannel::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        debug!(
+            "Recording {} notification for channels: {}",
+            input.event_type.as_str(),
+            channels_value
+        );
+
+        sqlx::query(
+            r#"
+            INSERT INTO notifications (
+                id, event_type, severity, title, message, project_id, channels, created_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(input.event_type.as_str())
+        .bind(input.severity.as_str())
+        .bind(&input.title)
+        .bind(&input.message)
+        .bind(&input.project_id)
+        .bind(&channels_value)
+        .bind(format_timestamp(&now))
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(Notification {
+            id,
+            event_type: input.event_type,
+            severity: input.severity,
+            title: input.title.clone(),
+            message: input.message.clone(),
+            project_id: input.project_id.clone(),
+            channels: channels.to_vec(),
+            created_at: now,
+        })
+    }
+
+    /// List notifications newest first, optionally only those routed to `channel`
+    /// and created strictly after `since`
+    pub async fn list_notifications(
+        &self,
+        channel: Option<NotificationChannel>,
+        since: Option<DateTime<Utc>>,
+        limit: i64,
+    ) -> Result<Vec<Notification>, StorageError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM notifications
+            WHERE (?1 IS NULL OR (',' || channels || ',') LIKE '%,' || ?1 || ',%')
+              AND (?2 IS NULL OR created_at > ?2)
+            ORDER BY created_at DESC
+            LIMIT ?3
+            "#,
+        )
+        .bind(channel.map(|c| c.as_str()))
+        .bind(since.as_ref().map(format_timestamp))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_notification).collect()
+    }
+}
+
+fn parse_column<T: std::str::FromStr<Err = String>>(
+    field: &str,
+    value: String,
+) -> Result<T, StorageError> {
+    value
+        .parse()
+        .map_err(|e| StorageError::Database(format!("Invalid {}: {}", field, e)))
+}
+
+fn row_to_notification(row: &sqlx::sqlite::SqliteRow) -> Result<Notification, StorageError> {
+    let channels: String = row.try_get("channels")?;
+    let created_at: String = row.try_get("created_at")?;
+
+    Ok(Notification {
+        id: row.try_get("id")?,
+        event_type: parse_column("event_type", row.try_get("event_type")?)?,
+        severity: parse_column("severity", row.try_get("severity")?)?,
+        title: row.try_get("title")?,
+        message: row.try_get("message")?,
+        project_id: row.try_get("project_id")?,
+        channels: parse_channels(&channels).map_err(StorageError::Database)?,
+        created_at: DateTime::parse_from_rfc3339(&created_at)
+            .map_err(|_| StorageError::Database("Invalid created_at timestamp".to_string()))?
+            .with_timezone(&Utc),
+    })
+}
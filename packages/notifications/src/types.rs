@@ -0,0 +1,187 @@
+// ABOUTME: Notification type definitions
+// ABOUTME: Event types, delivery channels, severities, and the persisted notification record
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Events that can produce a notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEventType {
+    ExecutionFinished,
+    SyncFailed,
+    BudgetThreshold,
+    ServerCrash,
+    /// An AI provider token could not be refreshed and the user must log in again
+    ReauthRequired,
+    /// An IP address was locked out after repeated failed authentication attempts
+    AuthLockout,
+    /// The HTTPS certificate is close to expiry or was regenerated
+    CertificateExpiring,
+    /// Sent from the settings page to check channel configuration
+    Test,
+}
+
+impl NotificationEventType {
+    /// Event types users can configure preferences for
+    pub const CONFIGURABLE: [NotificationEventType; 7] = [
+        NotificationEventType::ExecutionFinished,
+        NotificationEventType::SyncFailed,
+        NotificationEventType::BudgetThreshold,
+        NotificationEventType::ServerCrash,
+        NotificationEventType::ReauthRequired,
+        NotificationEventType::AuthLockout,
+        NotificationEventType::CertificateExpiring,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationEventType::ExecutionFinished => "execution_finished",
+            NotificationEventType::SyncFailed => "sync_failed",
+            NotificationEventType::BudgetThreshold => "budget_threshold",
+            NotificationEventType::ServerCrash => "server_crash",
+            NotificationEventType::ReauthRequired => "reauth_required",
+            NotificationEventType::AuthLockout => "auth_lockout",
+            NotificationEventType::CertificateExpiring => "certificate_expiring",
+            NotificationEventType::Test => "test",
+        }
+    }
+
+    /// System setting holding the channel list for this event type
+    pub fn setting_key(&self) -> String {
+        format!("notify_{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for NotificationEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "execution_finished" => Ok(NotificationEventType::ExecutionFinished),
+            "sync_failed" => Ok(NotificationEventType::SyncFailed),
+            "budget_threshold" => Ok(NotificationEventType::BudgetThreshold),
+            "server_crash" => Ok(NotificationEventType::ServerCrash),
+            "reauth_required" => Ok(NotificationEventType::ReauthRequired),
+            "auth_lockout" => Ok(NotificationEventType::AuthLockout),
+            "certificate_expiring" => Ok(NotificationEventType::CertificateExpiring),
+            "test" => Ok(NotificationEventType::Test),
+            _ => Err(format!("Unknown notification event type: {}", s)),
+        }
+    }
+}
+
+/// Where a notification is delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationChannel {
+    /// Native desktop notification shown by the Tauri app
+    Desktop,
+    /// Slack/Discord compatible webhook
+    Webhook,
+    /// Toast in the terminal UI
+    Tui,
+}
+
+impl NotificationChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationChannel::Desktop => "desktop",
+            NotificationChannel::Webhook => "webhook",
+            NotificationChannel::Tui => "tui",
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationChannel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "desktop" => Ok(NotificationChannel::Desktop),
+            "webhook" => Ok(NotificationChannel::Webhook),
+            "tui" => Ok(NotificationChannel::Tui),
+            _ => Err(format!("Unknown notification channel: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum NotificationSeverity {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotificationSeverity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationSeverity::Info => "info",
+            NotificationSeverity::Success => "success",
+            NotificationSeverity::Warning => "warning",
+            NotificationSeverity::Error => "error",
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationSeverity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(NotificationSeverity::Info),
+            "success" => Ok(NotificationSeverity::Success),
+            "warning" => Ok(NotificationSeverity::Warning),
+            "error" => Ok(NotificationSeverity::Error),
+            _ => Err(format!("Unknown notification severity: {}", s)),
+        }
+    }
+}
+
+/// A dispatched notification, as stored in the notification feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    pub event_type: NotificationEventType,
+    pub severity: NotificationSeverity,
+    pub title: String,
+    pub message: String,
+    pub project_id: Option<String>,
+    pub channels: Vec<NotificationChannel>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An event to notify about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationInput {
+    pub event_type: NotificationEventType,
+    pub severity: NotificationSeverity,
+    pub title: String,
+    pub message: String,
+    pub project_id: Option<String>,
+}
+
+impl NotificationInput {
+    pub fn new(
+        event_type: NotificationEventType,
+        severity: NotificationSeverity,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            event_type,
+            severity,
+            title: title.into(),
+            message: message.into(),
+            project_id: None,
+        }
+    }
+
+    pub fn with_project(mut self, project_id: impl Into<String>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+}
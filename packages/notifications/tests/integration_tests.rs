@@ -0,0 +1,117 @@
+// ABOUTME: Integration tests for notification dispatch and the notification feed
+// ABOUTME: Tests channel routing from settings, feed polling by channel, and budget thresholds
+
+use orkee_notifications::{
+    NotificationChannel, NotificationDispatcher, NotificationEventType, NotificationInput,
+    NotificationSeverity,
+};
+use sqlx::SqlitePool;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+async fn set_setting(pool: &SqlitePool, key: &str, value: &str) {
+    sqlx::query("UPDATE system_settings SET value = ? WHERE key = ?")
+        .bind(value)
+        .bind(key)
+        .execute(pool)
+        .await
+        .unwrap();
+}
+
+fn run_finished() -> NotificationInput {
+    NotificationInput::new(
+        NotificationEventType::ExecutionFinished,
+        NotificationSeverity::Success,
+        "Agent run finished",
+        "Run abc completed 3 stories",
+    )
+}
+
+#[tokio::test]
+async fn test_notify_routes_by_preferences() {
+    let pool = create_test_db().await;
+    let dispatcher = NotificationDispatcher::new(pool.clone());
+
+    let notification = dispatcher.notify(run_finished()).await.unwrap().unwrap();
+    assert_eq!(
+        notification.channels,
+        vec![NotificationChannel::Desktop, NotificationChannel::Tui]
+    );
+
+    let storage = dispatcher.storage();
+    let tui = storage
+        .list_notifications(Some(NotificationChannel::Tui), None, 10)
+        .await
+        .unwrap();
+    assert_eq!(tui.len(), 1);
+    assert_eq!(tui[0].title, "Agent run finished");
+    assert!(storage
+        .list_notifications(Some(NotificationChannel::Webhook), None, 10)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // Polling after the latest notification returns nothing new
+    let newer = storage
+        .list_notifications(None, Some(notification.created_at), 10)
+        .await
+        .unwrap();
+    assert!(newer.is_empty());
+
+    set_setting(&pool, "notify_execution_finished", "none").await;
+    assert!(dispatcher.notify(run_finished()).await.unwrap().is_none());
+    assert_eq!(
+        storage
+            .list_notifications(None, None, 10)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_budget_threshold_notifies_once() {
+    let pool = create_test_db().await;
+    let dispatcher = NotificationDispatcher::new(pool.clone());
+
+    let log_usage = |id: &'static str, cost: f64| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query(
+                "INSERT INTO ai_usage_logs (id, operation, model, provider, estimated_cost)
+                 VALUES (?, 'chat', 'claude', 'anthropic', ?)",
+            )
+            .bind(id)
+            .bind(cost)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+    };
+
+    // Disabled by default
+    log_usage("usage-0001", 20.0).await;
+    assert!(dispatcher.check_budget(20.0).await.unwrap().is_none());
+
+    set_setting(&pool, "notification_budget_monthly_usd", "25").await;
+
+    log_usage("usage-0002", 4.0).await;
+    assert!(dispatcher.check_budget(4.0).await.unwrap().is_none());
+
+    log_usage("usage-0003", 2.0).await;
+    let crossed = dispatcher.check_budget(2.0).await.unwrap().unwrap();
+    assert_eq!(crossed.event_type, NotificationEventType::BudgetThreshold);
+    assert_eq!(crossed.severity, NotificationSeverity::Warning);
+
+    // Already over budget, no repeat notification
+    log_usage("usage-0004", 1.0).await;
+    assert!(dispatcher.check_budget(1.0).await.unwrap().is_none());
+}
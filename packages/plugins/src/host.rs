@@ -0,0 +1,310 @@
+// ABOUTME: Plugin host that discovers plugin directories and dispatches routes, tools, and jobs
+// ABOUTME: Load failures are collected per plugin so one broken plugin does not stop the others
+
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::manifest::PluginManifest;
+use crate::runtime::{HostServices, PluginModule, PluginRuntime};
+use crate::types::{
+    JobResponse, JobSpec, PluginLoadFailure, PluginRequest, PluginSummary, Registrations,
+    RouteResponse, ToolResponse, ToolSpec,
+};
+use crate::PluginError;
+
+/// Setting that turns plugin loading on or off
+pub const PLUGINS_ENABLED_SETTING: &str = "plugins_enabled";
+
+/// Directory scanned for plugins: ~/.orkee/plugins
+pub fn plugins_dir() -> PathBuf {
+    orkee_core::orkee_dir().join("plugins")
+}
+
+/// MCP tool name of a plugin tool
+pub fn tool_name(plugin: &str, tool: &str) -> String {
+    format!("{}__{}", plugin, tool)
+}
+
+/// Scheduled job ID of a plugin job
+pub fn job_id(plugin: &str, job: &str) -> String {
+    format!("plugin.{}.{}", plugin, job)
+}
+
+/// Whether plugins should be loaded; defaults to on when the setting is missing
+pub async fn plugins_enabled(pool: &SqlitePool) -> bool {
+    orkee_settings::SettingsStorage::new(pool.clone())
+        .get(PLUGINS_ENABLED_SETTING)
+        .await
+        .map(|setting| setting.value == "true")
+        .unwrap_or(true)
+}
+
+/// A loaded plugin and everything it registered
+pub struct Plugin {
+    pub manifest: PluginManifest,
+    pub registrations: Registrations,
+    module: Arc<PluginModule>,
+}
+
+impl Plugin {
+    pub fn name(&self) -> &str {
+        &self.manifest.name
+    }
+
+    pub fn summary(&self) -> PluginSummary {
+        PluginSummary {
+            name: self.manifest.name.clone(),
+            version: self.manifest.version.clone(),
+            description: self.manifest.description.clone(),
+            capabilities: self.manifest.capabilities.clone(),
+            routes: self.registrations.routes.clone(),
+            tools: self.registrations.tools.clone(),
+            jobs: self.registrations.jobs.clone(),
+        }
+    }
+
+    /// Dispatch an HTTP request to the first registered route that matches
+    pub async fn handle_route(
+        &self,
+        method: &str,
+        path: &str,
+        query: HashMap<String, String>,
+        body: Option<Value>,
+    ) -> Result<RouteResponse, PluginError> {
+        let (route, params) = self
+            .registrations
+            .routes
+            .iter()
+            .find_map(|route| route.matches(method, path).map(|params| (route, params)))
+            .ok_or_else(|| {
+                PluginError::NotFound(format!("{} {} in plugin {}", method, path, self.name()))
+            })?;
+
+        self.call(PluginRequest::Route {
+            method: route.method.clone(),
+            path: path.to_string(),
+            params,
+            query,
+            body,
+        })
+        .await
+    }
+
+    pub async fn call_tool(
+        &self,
+        name: &str,
+        arguments: Value,
+    ) -> Result<ToolResponse, PluginError> {
+        if !self
+            .registrations
+            .tools
+            .iter()
+            .any(|tool| tool.name == name)
+        {
+            return Err(PluginError::NotFound(format!(
+                "Tool {} in plugin {}",
+                name,
+                self.name()
+            )));
+        }
+        self.call(PluginRequest::Tool {
+            name: name.to_string(),
+            arguments,
+        })
+        .await
+    }
+
+    pub async fn run_job(&self, id: &str) -> Result<JobResponse, PluginError> {
+        if !self.registrations.jobs.iter().any(|job| job.id == id) {
+            return Err(PluginError::NotFound(format!(
+                "Job {} in plugin {}",
+                id,
+                self.name()
+            )));
+        }
+        self.call(PluginRequest::Job { id: id.to_string() }).await
+    }
+
+    async fn call<T>(&self, request: PluginRequest) -> Result<T, PluginError>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let module = self.module.clone();
+        tokio::task::spawn_blocking(move || module.call(&request))
+            .await
+            .map_err(|e| PluginError::Trap(e.to_string()))?
+    }
+}
+
+/// All plugins loaded from a directory
+#[derive(Default)]
+pub struct PluginHost {
+    plugins: Vec<Arc<Plugin>>,
+    failures: Vec<PluginLoadFailure>,
+}
+
+impl PluginHost {
+    /// A host with no plugins, used when plugins are disabled
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Load every plugin directory under `dir`. A missing directory yields
+    /// an empty host.
+    pub async fn load_dir(dir: &Path, services: Arc<dyn HostServices>) -> Self {
+        let dir = dir.to_path_buf();
+        // Compiling and running orkee_init is CPU-bound and may block on host calls
+        match tokio::task::spawn_blocking(move || Self::load_dir_blocking(&dir, services)).await {
+            Ok(host) => host,
+            Err(e) => {
+                warn!("Plugin loading panicked: {}", e);
+                Self::empty()
+            }
+        }
+    }
+
+    fn load_dir_blocking(dir: &Path, services: Arc<dyn HostServices>) -> Self {
+        let mut host = Self::empty();
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => return host,
+        };
+        let mut dirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+        if dirs.is_empty() {
+            return host;
+        }
+
+        let runtime = match PluginRuntime::new() {
+            Ok(runtime) => Arc::new(runtime),
+            Err(e) => {
+                warn!("Failed to start plugin runtime: {}", e);
+                host.failures.push(PluginLoadFailure {
+                    path: dir.display().to_string(),
+                    error: e.to_string(),
+                });
+                return host;
+            }
+        };
+
+        let mut names = HashSet::new();
+        for path in dirs {
+            let result = load_plugin(&path, &runtime, services.clone()).and_then(|plugin| {
+                if names.insert(plugin.manifest.name.clone()) {
+                    Ok(plugin)
+                } else {
+                    Err(PluginError::Manifest(format!(
+                        "Plugin '{}' is already loaded",
+                        plugin.manifest.name
+                    )))
+                }
+            });
+            match result {
+                Ok(plugin) => {
+                    info!(
+                        "Loaded plugin {} {} ({} routes, {} tools, {} jobs)",
+                        plugin.manifest.name,
+                        plugin.manifest.version,
+                        plugin.registrations.routes.len(),
+                        plugin.registrations.tools.len(),
+                        plugin.registrations.jobs.len()
+                    );
+                    host.plugins.push(Arc::new(plugin));
+                }
+                Err(e) => {
+                    warn!("Failed to load plugin from {}: {}", path.display(), e);
+                    host.failures.push(PluginLoadFailure {
+                        path: path.display().to_string(),
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+        host
+    }
+
+    pub fn plugins(&self) -> &[Arc<Plugin>] {
+        &self.plugins
+    }
+
+    pub fn failures(&self) -> &[PluginLoadFailure] {
+        &self.failures
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Arc<Plugin>> {
+        self.plugins.iter().find(|plugin| plugin.name() == name)
+    }
+
+    pub fn summaries(&self) -> Vec<PluginSummary> {
+        self.plugins.iter().map(|plugin| plugin.summary()).collect()
+    }
+
+    /// Every plugin tool, keyed by its MCP tool name
+    pub fn tools(&self) -> Vec<(String, ToolSpec)> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| {
+                plugin
+                    .registrations
+                    .tools
+                    .iter()
+                    .map(|tool| (tool_name(plugin.name(), &tool.name), tool.clone()))
+            })
+            .collect()
+    }
+
+    /// Call a tool by its MCP tool name. Returns None when no plugin owns it.
+    pub async fn call_tool(
+        &self,
+        name: &str,
+        arguments: Value,
+    ) -> Option<Result<ToolResponse, PluginError>> {
+        let (plugin_name, tool) = name.split_once("__")?;
+        let plugin = self.get(plugin_name)?;
+        if !plugin.registrations.tools.iter().any(|t| t.name == tool) {
+            return None;
+        }
+        Some(plugin.call_tool(tool, arguments).await)
+    }
+
+    /// Every plugin job with its scheduled job ID
+    pub fn jobs(&self) -> Vec<(String, Arc<Plugin>, JobSpec)> {
+        self.plugins
+            .iter()
+            .flat_map(|plugin| {
+                plugin
+                    .registrations
+                    .jobs
+                    .iter()
+                    .map(|job| (job_id(plugin.name(), &job.id), plugin.clone(), job.clone()))
+            })
+            .collect()
+    }
+}
+
+fn load_plugin(
+    dir: &Path,
+    runtime: &Arc<PluginRuntime>,
+    services: Arc<dyn HostServices>,
+) -> Result<Plugin, PluginError> {
+    let manifest = PluginManifest::load(dir)?;
+    let module = runtime.compile(
+        &manifest.module_path(dir),
+        &manifest.name,
+        manifest.capabilities.clone(),
+        services,
+    )?;
+    let registrations = module.init()?;
+    Ok(Plugin {
+        manifest,
+        registrations,
+        module: Arc::new(module),
+    })
+}
@@ -0,0 +1,38 @@
+// ABOUTME: WASM plugin host that loads community extensions from ~/.orkee/plugins
+// ABOUTME: Plugins register API routes, MCP tools, and scheduled jobs through a capability-scoped host API
+
+pub mod host;
+pub mod manifest;
+pub mod runtime;
+pub mod types;
+
+use thiserror::Error;
+
+// Re-export main types
+pub use host::{
+    job_id, plugins_dir, plugins_enabled, tool_name, Plugin, PluginHost, PLUGINS_ENABLED_SETTING,
+};
+pub use manifest::{Capability, PluginManifest};
+pub use runtime::{HostServices, ProjectServices};
+pub use types::{
+    JobResponse, JobSpec, PluginLoadFailure, PluginRequest, PluginSummary, Registrations,
+    RouteResponse, RouteSpec, ToolResponse, ToolSpec,
+};
+
+#[derive(Error, Debug)]
+pub enum PluginError {
+    #[error("Invalid plugin manifest: {0}")]
+    Manifest(String),
+    #[error("Invalid plugin module: {0}")]
+    Module(String),
+    #[error("Plugin trapped: {0}")]
+    Trap(String),
+    #[error("Plugin error: {0}")]
+    Guest(String),
+    #[error("Invalid plugin response: {0}")]
+    InvalidResponse(String),
+    #[error("Not found: {0}")]
+    NotFound(String),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
@@ -0,0 +1,131 @@
+// ABOUTME: Plugin manifest parsing and the capabilities a plugin can request
+// ABOUTME: Each plugin directory holds a plugin.json naming its WASM module and capabilities
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::PluginError;
+
+/// Manifest file name inside a plugin directory
+pub const MANIFEST_FILE: &str = "plugin.json";
+
+/// Module file used when the manifest does not name one
+pub const DEFAULT_MODULE_FILE: &str = "plugin.wasm";
+
+/// Maximum length of plugin, tool, and job names
+pub const MAX_NAME_LENGTH: usize = 64;
+
+/// Host API access a plugin must declare in its manifest before using it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Capability {
+    /// Register HTTP routes under /api/plugins/{plugin}
+    #[serde(rename = "routes")]
+    Routes,
+    /// Register tools exposed by the MCP server
+    #[serde(rename = "mcp_tools")]
+    McpTools,
+    /// Register cron-scheduled jobs
+    #[serde(rename = "jobs")]
+    Jobs,
+    /// Read the project list
+    #[serde(rename = "projects:read")]
+    ProjectsRead,
+}
+
+impl Capability {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Capability::Routes => "routes",
+            Capability::McpTools => "mcp_tools",
+            Capability::Jobs => "jobs",
+            Capability::ProjectsRead => "projects:read",
+        }
+    }
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Contents of a plugin's plugin.json
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// WASM module path relative to the plugin directory
+    #[serde(default = "default_module")]
+    pub module: String,
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+}
+
+fn default_module() -> String {
+    DEFAULT_MODULE_FILE.to_string()
+}
+
+impl PluginManifest {
+    /// Read and validate the manifest in `dir`
+    pub fn load(dir: &Path) -> Result<Self, PluginError> {
+        let path = dir.join(MANIFEST_FILE);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|e| PluginError::Manifest(format!("{}: {}", path.display(), e)))?;
+        let manifest: Self = serde_json::from_str(&contents)
+            .map_err(|e| PluginError::Manifest(format!("{}: {}", path.display(), e)))?;
+        manifest.validate()?;
+        Ok(manifest)
+    }
+
+    pub fn validate(&self) -> Result<(), PluginError> {
+        validate_name("Plugin name", &self.name).map_err(PluginError::Manifest)?;
+        if self.version.trim().is_empty() {
+            return Err(PluginError::Manifest(format!(
+                "Plugin '{}' has no version",
+                self.name
+            )));
+        }
+        // The module must stay inside the plugin directory
+        let module = Path::new(&self.module);
+        if module.is_absolute()
+            || module
+                .components()
+                .any(|c| matches!(c, std::path::Component::ParentDir))
+        {
+            return Err(PluginError::Manifest(format!(
+                "Plugin '{}' module path must be relative to its directory",
+                self.name
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn module_path(&self, dir: &Path) -> PathBuf {
+        dir.join(&self.module)
+    }
+
+    pub fn allows(&self, capability: Capability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+}
+
+/// Plugin, tool, and job names: lowercase letters, digits, '-' and '_'.
+/// Names end up in URLs, MCP tool names, and job IDs.
+pub fn validate_name(kind: &str, name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} '{}' must be 1-{} lowercase letters, digits, '-' or '_'",
+            kind, name, MAX_NAME_LENGTH
+        ))
+    }
+}
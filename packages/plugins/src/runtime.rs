@@ -0,0 +1,404 @@
+// ABOUTME: WASM runtime for plugin modules and the capability-scoped host API they import
+// ABOUTME: Every call runs in a fresh instance with fuel and memory limits, exchanging JSON through guest memory
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{debug, error, info, warn};
+use wasmtime::{
+    AsContext, AsContextMut, Caller, Config, Engine, Extern, Instance, Linker, Memory, Module,
+    Store, StoreLimits, StoreLimitsBuilder, TypedFunc,
+};
+
+use crate::manifest::Capability;
+use crate::types::{JobSpec, PluginRequest, Registrations, RouteSpec, ToolSpec};
+use crate::PluginError;
+
+/// Fuel, roughly one unit per WASM instruction, that one call may burn
+pub const FUEL_PER_CALL: u64 = 1_000_000_000;
+
+/// Linear memory one plugin instance may grow to
+pub const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+
+/// Largest JSON message accepted from a plugin
+pub const MAX_MESSAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Import module name of the host API
+const HOST_MODULE: &str = "orkee";
+
+// Results of the register_* host functions
+const REGISTERED: i32 = 0;
+const DENIED: i32 = -1;
+const INVALID: i32 = -2;
+
+/// Orkee data the host API reads on a plugin's behalf
+#[async_trait]
+pub trait HostServices: Send + Sync {
+    async fn list_projects(&self) -> Result<Value, String>;
+}
+
+/// Host services backed by the global project storage
+pub struct ProjectServices;
+
+#[async_trait]
+impl HostServices for ProjectServices {
+    async fn list_projects(&self) -> Result<Value, String> {
+        let projects = orkee_projects::get_all_projects()
+            .await
+            .map_err(|e| e.to_string())?;
+        serde_json::to_value(projects).map_err(|e| e.to_string())
+    }
+}
+
+/// Registration is only allowed while `orkee_init` runs
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Init,
+    Call,
+}
+
+struct HostState {
+    plugin: String,
+    capabilities: Vec<Capability>,
+    phase: Phase,
+    registrations: Registrations,
+    services: Arc<dyn HostServices>,
+    limits: StoreLimits,
+}
+
+/// Shared engine and host API linker
+pub(crate) struct PluginRuntime {
+    engine: Engine,
+    linker: Linker<HostState>,
+}
+
+impl PluginRuntime {
+    pub fn new() -> Result<Self, PluginError> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|e| PluginError::Module(e.to_string()))?;
+
+        let mut linker = Linker::new(&engine);
+        define_host_api(&mut linker).map_err(|e| PluginError::Module(e.to_string()))?;
+        Ok(Self { engine, linker })
+    }
+
+    /// Compile a module from a .wasm (or .wat) file
+    pub fn compile(
+        self: &Arc<Self>,
+        path: &Path,
+        plugin: &str,
+        capabilities: Vec<Capability>,
+        services: Arc<dyn HostServices>,
+    ) -> Result<PluginModule, PluginError> {
+        let module = Module::from_file(&self.engine, path)
+            .map_err(|e| PluginError::Module(format!("{}: {}", path.display(), e)))?;
+        Ok(PluginModule {
+            runtime: self.clone(),
+            module,
+            plugin: plugin.to_string(),
+            capabilities,
+            services,
+        })
+    }
+}
+
+/// A compiled plugin module. Calls block, so async callers run them with
+/// `spawn_blocking`.
+pub(crate) struct PluginModule {
+    runtime: Arc<PluginRuntime>,
+    module: Module,
+    plugin: String,
+    capabilities: Vec<Capability>,
+    services: Arc<dyn HostServices>,
+}
+
+impl PluginModule {
+    /// Run `orkee_init`, if exported, and return what it registered
+    pub fn init(&self) -> Result<Registrations, PluginError> {
+        let (mut store, instance) = self.instantiate(Phase::Init)?;
+
+        // Fail at load time rather than on the first call
+        instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, "orkee_handle")
+            .map_err(|e| PluginError::Module(format!("orkee_handle: {}", e)))?;
+
+        if let Ok(init) = instance.get_typed_func::<(), ()>(&mut store, "orkee_init") {
+            init.call(&mut store, ())
+                .map_err(|e| PluginError::Trap(e.to_string()))?;
+        }
+        Ok(std::mem::take(&mut store.data_mut().registrations))
+    }
+
+    /// Send a request to `orkee_handle` and decode the JSON reply. A reply
+    /// with a top-level `error` string is returned as `PluginError::Guest`.
+    pub fn call<T: DeserializeOwned>(&self, request: &PluginRequest) -> Result<T, PluginError> {
+        let (mut store, instance) = self.instantiate(Phase::Call)?;
+        let (memory, alloc) = instance_exports(&mut store, &instance)?;
+        let handle = instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, "orkee_handle")
+            .map_err(|e| PluginError::Module(format!("orkee_handle: {}", e)))?;
+
+        let input = serde_json::to_vec(request)?;
+        let ptr = write_guest(&mut store, &memory, &alloc, &input)
+            .map_err(|e| PluginError::Trap(e.to_string()))?;
+        let packed = handle
+            .call(&mut store, (ptr, input.len() as i32))
+            .map_err(|e| PluginError::Trap(e.to_string()))?;
+        let (ptr, len) = unpack(packed);
+        let output = read_guest(&store, &memory, ptr, len)
+            .map_err(|e| PluginError::InvalidResponse(e.to_string()))?;
+
+        let value: Value = serde_json::from_slice(&output)
+            .map_err(|e| PluginError::InvalidResponse(e.to_string()))?;
+        if let Some(message) = value.get("error").and_then(Value::as_str) {
+            return Err(PluginError::Guest(message.to_string()));
+        }
+        serde_json::from_value(value).map_err(|e| PluginError::InvalidResponse(e.to_string()))
+    }
+
+    fn instantiate(&self, phase: Phase) -> Result<(Store<HostState>, Instance), PluginError> {
+        let state = HostState {
+            plugin: self.plugin.clone(),
+            capabilities: self.capabilities.clone(),
+            phase,
+            registrations: Registrations::default(),
+            services: self.services.clone(),
+            limits: StoreLimitsBuilder::new()
+                .memory_size(MAX_MEMORY_BYTES)
+                .build(),
+        };
+        let mut store = Store::new(&self.runtime.engine, state);
+        store.limiter(|state| &mut state.limits);
+        store
+            .set_fuel(FUEL_PER_CALL)
+            .map_err(|e| PluginError::Module(e.to_string()))?;
+
+        let instance = self
+            .runtime
+            .linker
+            .instantiate(&mut store, &self.module)
+            .map_err(|e| PluginError::Module(e.to_string()))?;
+        Ok((store, instance))
+    }
+}
+
+fn instance_exports(
+    store: &mut Store<HostState>,
+    instance: &Instance,
+) -> Result<(Memory, TypedFunc<i32, i32>), PluginError> {
+    let memory = instance
+        .get_memory(&mut *store, "memory")
+        .ok_or_else(|| PluginError::Module("Plugin does not export memory".to_string()))?;
+    let alloc = instance
+        .get_typed_func::<i32, i32>(&mut *store, "orkee_alloc")
+        .map_err(|e| PluginError::Module(format!("orkee_alloc: {}", e)))?;
+    Ok((memory, alloc))
+}
+
+fn define_host_api(linker: &mut Linker<HostState>) -> wasmtime::Result<()> {
+    // log(level, ptr, len): 0 debug, 1 info, 2 warn, anything else error
+    linker.func_wrap(
+        HOST_MODULE,
+        "log",
+        |mut caller: Caller<'_, HostState>,
+         level: i32,
+         ptr: i32,
+         len: i32|
+         -> wasmtime::Result<()> {
+            let (memory, _) = caller_exports(&mut caller)?;
+            let bytes = read_guest(&caller, &memory, ptr as u32, len as u32)?;
+            let message = String::from_utf8_lossy(&bytes);
+            let plugin = &caller.data().plugin;
+            match level {
+                0 => debug!("[plugin {}] {}", plugin, message),
+                1 => info!("[plugin {}] {}", plugin, message),
+                2 => warn!("[plugin {}] {}", plugin, message),
+                _ => error!("[plugin {}] {}", plugin, message),
+            }
+            Ok(())
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "register_route",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            register::<RouteSpec>(
+                &mut caller,
+                ptr,
+                len,
+                Capability::Routes,
+                RouteSpec::validate,
+                |registrations, spec| registrations.routes.push(spec),
+            )
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "register_tool",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            register::<ToolSpec>(
+                &mut caller,
+                ptr,
+                len,
+                Capability::McpTools,
+                ToolSpec::validate,
+                |registrations, spec| registrations.tools.push(spec),
+            )
+        },
+    )?;
+
+    linker.func_wrap(
+        HOST_MODULE,
+        "register_job",
+        |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| {
+            register::<JobSpec>(
+                &mut caller,
+                ptr,
+                len,
+                Capability::Jobs,
+                JobSpec::validate,
+                |registrations, spec| registrations.jobs.push(spec),
+            )
+        },
+    )?;
+
+    // list_projects() -> packed (ptr << 32 | len) of a JSON array, or -1 when denied
+    linker.func_wrap(
+        HOST_MODULE,
+        "list_projects",
+        |mut caller: Caller<'_, HostState>| -> wasmtime::Result<i64> {
+            if !caller
+                .data()
+                .capabilities
+                .contains(&Capability::ProjectsRead)
+            {
+                warn!(
+                    "Plugin {} called list_projects without the projects:read capability",
+                    caller.data().plugin
+                );
+                return Ok(DENIED as i64);
+            }
+
+            // Plugin calls run on blocking threads, so waiting here does not stall the runtime
+            let services = caller.data().services.clone();
+            let reply = match tokio::runtime::Handle::current().block_on(services.list_projects()) {
+                Ok(projects) => projects,
+                Err(e) => serde_json::json!({ "error": e }),
+            };
+
+            let bytes = serde_json::to_vec(&reply)?;
+            let (memory, alloc) = caller_exports(&mut caller)?;
+            let ptr = write_guest(&mut caller, &memory, &alloc, &bytes)?;
+            Ok(pack(ptr as u32, bytes.len() as u32))
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Shared body of the register_* host functions
+fn register<S: DeserializeOwned>(
+    caller: &mut Caller<'_, HostState>,
+    ptr: i32,
+    len: i32,
+    capability: Capability,
+    validate: fn(&S) -> Result<(), String>,
+    add: fn(&mut Registrations, S),
+) -> wasmtime::Result<i32> {
+    let state = caller.data();
+    if state.phase != Phase::Init {
+        warn!(
+            "Plugin {} tried to register outside orkee_init",
+            state.plugin
+        );
+        return Ok(DENIED);
+    }
+    if !state.capabilities.contains(&capability) {
+        warn!(
+            "Plugin {} tried to register without the {} capability",
+            state.plugin, capability
+        );
+        return Ok(DENIED);
+    }
+
+    let (memory, _) = caller_exports(caller)?;
+    let bytes = read_guest(&*caller, &memory, ptr as u32, len as u32)?;
+    let spec = match serde_json::from_slice::<S>(&bytes)
+        .map_err(|e| e.to_string())
+        .and_then(|spec| validate(&spec).map(|_| spec))
+    {
+        Ok(spec) => spec,
+        Err(e) => {
+            warn!(
+                "Plugin {} made an invalid {} registration: {}",
+                caller.data().plugin,
+                capability,
+                e
+            );
+            return Ok(INVALID);
+        }
+    };
+
+    add(&mut caller.data_mut().registrations, spec);
+    Ok(REGISTERED)
+}
+
+fn caller_exports(
+    caller: &mut Caller<'_, HostState>,
+) -> wasmtime::Result<(Memory, TypedFunc<i32, i32>)> {
+    let memory = match caller.get_export("memory") {
+        Some(Extern::Memory(memory)) => memory,
+        _ => return Err(wasmtime::Error::msg("Plugin does not export memory")),
+    };
+    let alloc = caller
+        .get_export("orkee_alloc")
+        .and_then(Extern::into_func)
+        .ok_or_else(|| wasmtime::Error::msg("Plugin does not export orkee_alloc"))?
+        .typed::<i32, i32>(&*caller)?;
+    Ok((memory, alloc))
+}
+
+/// Copy `bytes` into a buffer the guest allocates, returning its address
+fn write_guest(
+    mut store: impl AsContextMut<Data = HostState>,
+    memory: &Memory,
+    alloc: &TypedFunc<i32, i32>,
+    bytes: &[u8],
+) -> wasmtime::Result<i32> {
+    let len = i32::try_from(bytes.len())?;
+    let ptr = alloc.call(&mut store, len)?;
+    memory.write(&mut store, ptr as u32 as usize, bytes)?;
+    Ok(ptr)
+}
+
+fn read_guest(
+    store: impl AsContext,
+    memory: &Memory,
+    ptr: u32,
+    len: u32,
+) -> wasmtime::Result<Vec<u8>> {
+    let len = len as usize;
+    if len > MAX_MESSAGE_BYTES {
+        return Err(wasmtime::Error::msg(format!(
+            "Plugin message of {} bytes exceeds the {} byte limit",
+            len, MAX_MESSAGE_BYTES
+        )));
+    }
+    let mut buffer = vec![0; len];
+    memory.read(&store, ptr as usize, &mut buffer)?;
+    Ok(buffer)
+}
+
+fn pack(ptr: u32, len: u32) -> i64 {
+    (((ptr as u64) << 32) | len as u64) as i64
+}
+
+fn unpack(packed: i64) -> (u32, u32) {
+    let packed = packed as u64;
+    ((packed >> 32) as u32, packed as u32)
+}
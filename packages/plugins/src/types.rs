@@ -0,0 +1,181 @@
+// ABOUTME: Registration specs and the JSON messages exchanged with plugin modules
+// ABOUTME: Routes, MCP tools, and jobs a plugin registers, plus the requests the host sends it
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::manifest::{validate_name, Capability};
+
+/// HTTP methods a plugin route can handle
+pub const ROUTE_METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE"];
+
+/// An HTTP route served under /api/plugins/{plugin}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteSpec {
+    pub method: String,
+    /// Path relative to the plugin's prefix; `{name}` segments capture parameters
+    pub path: String,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl RouteSpec {
+    pub fn validate(&self) -> Result<(), String> {
+        if !ROUTE_METHODS.contains(&self.method.as_str()) {
+            return Err(format!(
+                "Route method '{}' must be one of: {}",
+                self.method,
+                ROUTE_METHODS.join(", ")
+            ));
+        }
+        if !self.path.starts_with('/') {
+            return Err(format!("Route path '{}' must start with '/'", self.path));
+        }
+        Ok(())
+    }
+
+    /// Match a request path, returning the captured `{name}` parameters
+    pub fn matches(&self, method: &str, path: &str) -> Option<HashMap<String, String>> {
+        if !self.method.eq_ignore_ascii_case(method) {
+            return None;
+        }
+        let pattern: Vec<&str> = self.path.trim_matches('/').split('/').collect();
+        let actual: Vec<&str> = path.trim_matches('/').split('/').collect();
+        if pattern.len() != actual.len() {
+            return None;
+        }
+
+        let mut params = HashMap::new();
+        for (expected, segment) in pattern.iter().zip(&actual) {
+            match expected
+                .strip_prefix('{')
+                .and_then(|rest| rest.strip_suffix('}'))
+            {
+                Some(name) if !segment.is_empty() => {
+                    params.insert(name.to_string(), segment.to_string());
+                }
+                Some(_) => return None,
+                None if expected == segment => {}
+                None => return None,
+            }
+        }
+        Some(params)
+    }
+}
+
+/// A tool the MCP server exposes as `{plugin}__{name}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolSpec {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// JSON Schema for the tool's arguments
+    #[serde(default = "empty_object_schema")]
+    pub input_schema: Value,
+}
+
+fn empty_object_schema() -> Value {
+    serde_json::json!({ "type": "object", "properties": {}, "required": [] })
+}
+
+impl ToolSpec {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_name("Tool name", &self.name)
+    }
+}
+
+/// A scheduled job registered as `plugin.{plugin}.{id}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobSpec {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Default 5-field cron schedule; users can change it like any other job
+    pub cron_expression: String,
+}
+
+impl JobSpec {
+    pub fn validate(&self) -> Result<(), String> {
+        validate_name("Job ID", &self.id)?;
+        if self.name.trim().is_empty() {
+            return Err(format!("Job '{}' has no name", self.id));
+        }
+        Ok(())
+    }
+}
+
+/// Everything a plugin registered from `orkee_init`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Registrations {
+    pub routes: Vec<RouteSpec>,
+    pub tools: Vec<ToolSpec>,
+    pub jobs: Vec<JobSpec>,
+}
+
+/// Message passed to a plugin's `orkee_handle` export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PluginRequest {
+    Route {
+        method: String,
+        path: String,
+        params: HashMap<String, String>,
+        query: HashMap<String, String>,
+        body: Option<Value>,
+    },
+    Tool {
+        name: String,
+        arguments: Value,
+    },
+    Job {
+        id: String,
+    },
+}
+
+/// Plugin reply to a route request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RouteResponse {
+    #[serde(default = "default_status")]
+    pub status: u16,
+    #[serde(default)]
+    pub body: Value,
+}
+
+fn default_status() -> u16 {
+    200
+}
+
+/// Plugin reply to a tool call
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolResponse {
+    pub text: String,
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+/// Plugin reply to a job run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobResponse {
+    pub message: String,
+}
+
+/// A loaded plugin as listed by GET /api/plugins
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginSummary {
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub capabilities: Vec<Capability>,
+    pub routes: Vec<RouteSpec>,
+    pub tools: Vec<ToolSpec>,
+    pub jobs: Vec<JobSpec>,
+}
+
+/// A plugin directory that could not be loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginLoadFailure {
+    pub path: String,
+    pub error: String,
+}
@@ -0,0 +1,257 @@
+// ABOUTME: Integration tests for loading WASM plugins and dispatching to them
+// ABOUTME: Plugins are generated as WAT modules that register specs and answer with fixed JSON
+
+use async_trait::async_trait;
+use orkee_plugins::{HostServices, PluginError, PluginHost};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Host services with a fixed project list, shaped like a tool reply so a
+/// plugin can pass it straight back
+struct FakeServices;
+
+#[async_trait]
+impl HostServices for FakeServices {
+    async fn list_projects(&self) -> Result<Value, String> {
+        Ok(json!({ "text": "alpha, beta" }))
+    }
+}
+
+enum Reply {
+    /// Answer every call with this JSON
+    Fixed(Value),
+    /// Answer with the result of the list_projects host call
+    Projects,
+}
+
+/// Build a module that calls `register_{kind}` for each spec in orkee_init
+fn wat_module(registrations: &[(&str, Value)], reply: Reply) -> String {
+    let mut data = String::new();
+    let mut offset = 0usize;
+    let mut segment = |bytes: &str| {
+        let escaped = bytes.replace('\\', "\\\\").replace('"', "\\\"");
+        data.push_str(&format!(
+            "  (data (i32.const {}) \"{}\")\n",
+            offset, escaped
+        ));
+        let packed = ((offset as i64) << 32) | bytes.len() as i64;
+        offset += bytes.len();
+        packed
+    };
+
+    let mut init = String::new();
+    for (kind, spec) in registrations {
+        let spec = spec.to_string();
+        let packed = segment(&spec);
+        init.push_str(&format!(
+            "    (drop (call $register_{} (i32.const {}) (i32.const {})))\n",
+            kind,
+            packed >> 32,
+            spec.len()
+        ));
+    }
+
+    let handle = match reply {
+        Reply::Fixed(value) => format!("(i64.const {})", segment(&value.to_string())),
+        Reply::Projects => {
+            let denied = segment(r#"{"error":"denied"}"#);
+            format!(
+                "(local.set $r (call $list_projects))
+    (if (result i64) (i64.eq (local.get $r) (i64.const -1))
+      (then (i64.const {}))
+      (else (local.get $r)))",
+                denied
+            )
+        }
+    };
+
+    format!(
+        r#"(module
+  (import "orkee" "register_route" (func $register_route (param i32 i32) (result i32)))
+  (import "orkee" "register_tool" (func $register_tool (param i32 i32) (result i32)))
+  (import "orkee" "register_job" (func $register_job (param i32 i32) (result i32)))
+  (import "orkee" "list_projects" (func $list_projects (result i64)))
+  (memory (export "memory") 1)
+  (global $heap (mut i32) (i32.const 32768))
+{data}
+  (func (export "orkee_alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $heap))
+    (global.set $heap (i32.add (global.get $heap) (local.get $len)))
+    (local.get $ptr))
+  (func (export "orkee_init")
+{init}  )
+  (func (export "orkee_handle") (param i32 i32) (result i64)
+    (local $r i64)
+    {handle}))
+"#
+    )
+}
+
+fn write_plugin(root: &Path, dir: &str, manifest: Value, module: &str) {
+    let dir = root.join(dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("plugin.json"), manifest.to_string()).unwrap();
+    std::fs::write(dir.join("plugin.wat"), module).unwrap();
+}
+
+fn manifest(name: &str, capabilities: &[&str]) -> Value {
+    json!({
+        "name": name,
+        "version": "0.1.0",
+        "module": "plugin.wat",
+        "capabilities": capabilities,
+    })
+}
+
+fn all_registrations() -> Vec<(&'static str, Value)> {
+    vec![
+        ("route", json!({ "method": "GET", "path": "/hello/{name}" })),
+        (
+            "tool",
+            json!({ "name": "greet", "description": "Say hello" }),
+        ),
+        (
+            "job",
+            json!({ "id": "sync", "name": "Sync", "cron_expression": "0 * * * *" }),
+        ),
+    ]
+}
+
+async fn load(root: &Path) -> PluginHost {
+    PluginHost::load_dir(root, Arc::new(FakeServices)).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_plugin_registers_and_handles_requests() {
+    let root = tempfile::tempdir().unwrap();
+    write_plugin(
+        root.path(),
+        "greeter",
+        manifest("greeter", &["routes", "mcp_tools", "jobs"]),
+        &wat_module(
+            &all_registrations(),
+            Reply::Fixed(
+                json!({ "status": 201, "body": { "ok": true }, "text": "hi", "message": "done" }),
+            ),
+        ),
+    );
+
+    let host = load(root.path()).await;
+    assert!(host.failures().is_empty(), "{:?}", host.failures());
+    let plugin = host.get("greeter").unwrap();
+    assert_eq!(plugin.registrations.routes.len(), 1);
+
+    let response = plugin
+        .handle_route("GET", "/hello/world", HashMap::new(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, 201);
+    assert_eq!(response.body, json!({ "ok": true }));
+
+    let missing = plugin
+        .handle_route("POST", "/hello/world", HashMap::new(), None)
+        .await;
+    assert!(matches!(missing, Err(PluginError::NotFound(_))));
+
+    let tools = host.tools();
+    assert_eq!(tools.len(), 1);
+    assert_eq!(tools[0].0, "greeter__greet");
+    let tool = host
+        .call_tool("greeter__greet", json!({}))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(tool.text, "hi");
+    assert!(host
+        .call_tool("greeter__missing", json!({}))
+        .await
+        .is_none());
+
+    let jobs = host.jobs();
+    assert_eq!(jobs.len(), 1);
+    assert_eq!(jobs[0].0, "plugin.greeter.sync");
+    assert_eq!(plugin.run_job("sync").await.unwrap().message, "done");
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_registrations_require_capabilities() {
+    let root = tempfile::tempdir().unwrap();
+    write_plugin(
+        root.path(),
+        "routes-only",
+        manifest("routes-only", &["routes"]),
+        &wat_module(&all_registrations(), Reply::Fixed(json!({}))),
+    );
+
+    let host = load(root.path()).await;
+    let plugin = host.get("routes-only").unwrap();
+    assert_eq!(plugin.registrations.routes.len(), 1);
+    assert!(plugin.registrations.tools.is_empty());
+    assert!(plugin.registrations.jobs.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_project_access_requires_capability() {
+    let root = tempfile::tempdir().unwrap();
+    let tool = vec![("tool", json!({ "name": "projects" }))];
+    write_plugin(
+        root.path(),
+        "reader",
+        manifest("reader", &["mcp_tools", "projects:read"]),
+        &wat_module(&tool, Reply::Projects),
+    );
+    write_plugin(
+        root.path(),
+        "snoop",
+        manifest("snoop", &["mcp_tools"]),
+        &wat_module(&tool, Reply::Projects),
+    );
+
+    let host = load(root.path()).await;
+    let allowed = host
+        .call_tool("reader__projects", json!({}))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(allowed.text, "alpha, beta");
+
+    let denied = host.call_tool("snoop__projects", json!({})).await.unwrap();
+    assert!(matches!(denied, Err(PluginError::Guest(message)) if message == "denied"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_broken_plugins_are_reported_without_blocking_others() {
+    let root = tempfile::tempdir().unwrap();
+    let module = wat_module(&[], Reply::Fixed(json!({})));
+    write_plugin(root.path(), "a-good", manifest("good", &[]), &module);
+    write_plugin(root.path(), "b-duplicate", manifest("good", &[]), &module);
+    write_plugin(
+        root.path(),
+        "c-bad-name",
+        manifest("Bad Name", &[]),
+        &module,
+    );
+    write_plugin(
+        root.path(),
+        "d-escape",
+        json!({ "name": "escape", "version": "1", "module": "../plugin.wat" }),
+        &module,
+    );
+    write_plugin(
+        root.path(),
+        "e-invalid",
+        manifest("invalid", &[]),
+        "(module",
+    );
+
+    let host = load(root.path()).await;
+    assert_eq!(host.plugins().len(), 1);
+    assert_eq!(host.failures().len(), 4);
+    assert!(host.get("good").is_some());
+
+    let empty = load(&root.path().join("missing")).await;
+    assert!(empty.plugins().is_empty() && empty.failures().is_empty());
+}
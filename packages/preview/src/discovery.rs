@@ -8,6 +8,8 @@ use chrono::Utc;
 use futures::stream::{self, StreamExt};
 #[cfg(unix)]
 use nix::libc;
+use regex::Regex;
+use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 #[cfg(unix)]
@@ -29,6 +31,165 @@ const DEFAULT_DISCOVERY_PORTS: &[u16] = &[
     9000, 9001, // Additional common ports
 ];
 
+/// Settings keys in the `discovery` category
+const PORT_RANGES_SETTING: &str = "discovery_port_ranges";
+const SCAN_ROOTS_SETTING: &str = "discovery_scan_roots";
+const IGNORE_PATTERNS_SETTING: &str = "discovery_ignore_patterns";
+
+/// Frameworks identified by markers in the process command line, most specific first
+const FRAMEWORK_FINGERPRINTS: &[(&str, &[&str])] = &[
+    ("Next.js", &["next"]),
+    ("Vite Preview", &["vite preview"]),
+    ("Vite", &["vite"]),
+    ("React", &["react-scripts", "create-react-app"]),
+    ("Webpack", &["webpack"]),
+    ("Vue", &["vue-cli", "@vue/cli"]),
+    ("Angular", &["angular", "ng serve"]),
+    ("Flask", &["flask"]),
+    ("Django", &["django", "manage.py"]),
+    ("Rails", &["rails", "puma"]),
+    ("FastAPI", &["uvicorn", "fastapi"]),
+    ("Gunicorn", &["gunicorn"]),
+    ("Python HTTP Server", &["http.server", "simplehttpserver"]),
+    ("Deno", &["deno"]),
+];
+
+/// User-configurable discovery settings
+#[derive(Debug, Clone)]
+pub struct DiscoveryConfig {
+    /// Ports to scan
+    pub ports: Vec<u16>,
+    /// Only servers running from inside one of these directories are tracked; empty tracks all
+    pub scan_roots: Vec<PathBuf>,
+    /// Servers whose command line or working directory matches any pattern are skipped
+    pub ignore_patterns: Vec<String>,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            ports: get_discovery_ports(),
+            scan_roots: Vec::new(),
+            ignore_patterns: Vec::new(),
+        }
+    }
+}
+
+impl DiscoveryConfig {
+    /// Build a config from `(key, value)` setting pairs. Invalid values are logged and ignored.
+    ///
+    /// `ORKEE_DISCOVERY_PORTS` takes precedence over the port ranges setting.
+    pub fn from_settings<'a>(settings: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut config = Self::default();
+        let ports_from_env = std::env::var("ORKEE_DISCOVERY_PORTS").is_ok();
+
+        for (key, value) in settings {
+            match key {
+                PORT_RANGES_SETTING if !ports_from_env => match parse_port_ranges(value) {
+                    Ok(ports) => config.ports = ports,
+                    Err(e) => warn!("Ignoring invalid {} value: {}", PORT_RANGES_SETTING, e),
+                },
+                SCAN_ROOTS_SETTING => {
+                    config.scan_roots = split_list(value).map(expand_home).collect();
+                }
+                IGNORE_PATTERNS_SETTING => {
+                    config.ignore_patterns = split_list(value).map(str::to_string).collect();
+                }
+                _ => {}
+            }
+        }
+
+        config
+    }
+
+    /// Whether a discovered server passes the scan roots and ignore patterns
+    pub fn allows(&self, server: &DiscoveredServer) -> bool {
+        if !self.scan_roots.is_empty()
+            && !self
+                .scan_roots
+                .iter()
+                .any(|root| server.working_dir.starts_with(root))
+        {
+            return false;
+        }
+
+        let command = server.command.join(" ");
+        let working_dir = server.working_dir.to_string_lossy();
+        !self.ignore_patterns.iter().any(|pattern| {
+            wildcard_regex(pattern)
+                .map(|regex| regex.is_match(&command) || regex.is_match(&working_dir))
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// Load discovery settings, falling back to defaults if they can't be read
+pub async fn load_discovery_config(pool: &SqlitePool) -> DiscoveryConfig {
+    let rows: Vec<(String, String)> =
+        match sqlx::query_as("SELECT key, value FROM system_settings WHERE category = 'discovery'")
+            .fetch_all(pool)
+            .await
+        {
+            Ok(rows) => rows,
+            Err(e) => {
+                warn!("Failed to load discovery settings, using defaults: {}", e);
+                return DiscoveryConfig::default();
+            }
+        };
+
+    DiscoveryConfig::from_settings(
+        rows.iter()
+            .map(|(key, value)| (key.as_str(), value.as_str())),
+    )
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ if path == "~" => dirs::home_dir().unwrap_or_else(|| PathBuf::from(path)),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Case-insensitive regex for a pattern where `*` matches anything
+fn wildcard_regex(pattern: &str) -> Option<Regex> {
+    let escaped = regex::escape(pattern).replace(r"\*", ".*");
+    Regex::new(&format!("(?i){}", escaped)).ok()
+}
+
+/// Parse comma-separated ports and inclusive ranges such as `3000-3003,5173`.
+/// Privileged ports (below 1024) are rejected.
+fn parse_port_ranges(value: &str) -> Result<Vec<u16>, String> {
+    let mut ports = Vec::new();
+    for item in split_list(value) {
+        let (start, end) = item.split_once('-').unwrap_or((item, item));
+        let parse = |port: &str| {
+            port.trim()
+                .parse::<u16>()
+                .ok()
+                .filter(|port| *port >= 1024)
+                .ok_or_else(|| format!("'{}' is not a port or range between 1024 and 65535", item))
+        };
+        let (start, end) = (parse(start)?, parse(end)?);
+        if start > end {
+            return Err(format!("range '{}' starts after it ends", item));
+        }
+        for port in start..=end {
+            if !ports.contains(&port) {
+                ports.push(port);
+            }
+        }
+    }
+    Ok(ports)
+}
+
 /// Information about a discovered external server process
 #[derive(Debug, Clone)]
 pub struct DiscoveredServer {
@@ -44,10 +205,10 @@ pub struct DiscoveredServer {
     pub framework_name: Option<String>,
 }
 
-/// Discover external servers running on common development ports
-pub async fn discover_external_servers() -> Vec<DiscoveredServer> {
-    // Get ports to scan from environment or use defaults
-    let ports_to_scan = get_discovery_ports();
+/// Discover external servers on the configured ports, skipping ones outside the
+/// scan roots or matching an ignore pattern
+pub async fn discover_external_servers(config: &DiscoveryConfig) -> Vec<DiscoveredServer> {
+    let ports_to_scan = config.ports.clone();
 
     debug!(
         "Scanning {} ports for external servers",
@@ -60,6 +221,16 @@ pub async fn discover_external_servers() -> Vec<DiscoveredServer> {
         .map(discover_server_on_port)
         .buffer_unordered(5)
         .filter_map(|result| async move { result })
+        .filter(|server| {
+            let allowed = config.allows(server);
+            if !allowed {
+                debug!(
+                    "Skipping server on port {} excluded by discovery settings",
+                    server.port
+                );
+            }
+            async move { allowed }
+        })
         .collect()
         .await;
 
@@ -159,7 +330,7 @@ async fn discover_server_on_port(port: u16) -> Option<DiscoveredServer> {
     let expected_patterns = [
         "node", "deno", "bun", "python", "ruby", "php", "java", "dotnet", "go", "cargo", "npm",
         "yarn", "pnpm", "next", "vite", "webpack", "parcel", "rollup", "django", "flask", "rails",
-        "spring", "express",
+        "puma", "uvicorn", "gunicorn", "spring", "express",
     ];
 
     let name_matches = expected_patterns
@@ -474,39 +645,10 @@ fn is_likely_dev_server(command: &[String]) -> bool {
 fn detect_framework_from_command(command: &[String]) -> Option<String> {
     let command_str = command.join(" ").to_lowercase();
 
-    // Match frameworks in priority order (most specific first)
-    if command_str.contains("next") || command_str.contains("next.js") {
-        return Some("Next.js".to_string());
-    }
-    if command_str.contains("vite") {
-        return Some("Vite".to_string());
-    }
-    if command_str.contains("react-scripts") || command_str.contains("create-react-app") {
-        return Some("React".to_string());
-    }
-    if command_str.contains("webpack-dev-server") || command_str.contains("webpack") {
-        return Some("Webpack".to_string());
-    }
-    if command_str.contains("vue-cli") || command_str.contains("@vue/cli") {
-        return Some("Vue".to_string());
-    }
-    if command_str.contains("angular") || command_str.contains("ng serve") {
-        return Some("Angular".to_string());
-    }
-    if command_str.contains("flask") {
-        return Some("Flask".to_string());
-    }
-    if command_str.contains("django") || command_str.contains("manage.py") {
-        return Some("Django".to_string());
-    }
-    if command_str.contains("rails") || command_str.contains("puma") {
-        return Some("Rails".to_string());
-    }
-    if command_str.contains("deno") {
-        return Some("Deno".to_string());
-    }
-
-    None
+    FRAMEWORK_FINGERPRINTS
+        .iter()
+        .find(|(_, markers)| markers.iter().any(|marker| command_str.contains(marker)))
+        .map(|(framework, _)| framework.to_string())
 }
 
 /// Register a discovered server in the registry
@@ -706,7 +848,8 @@ async fn try_register_discovered_server(
 ///
 /// Spawns a background task that runs every 30 seconds (by default) to discover
 /// external servers running on common development ports and automatically registers
-/// them in the provided registry.
+/// them in the provided registry. Ports, scan roots, and ignore patterns are read
+/// from the `discovery` settings on every pass.
 ///
 /// The discovery interval can be configured via `ORKEE_DISCOVERY_INTERVAL_SECS`
 /// environment variable (default: 30 seconds, min: 10, max: 300).
@@ -739,6 +882,7 @@ async fn try_register_discovered_server(
 ///         provider: StorageProvider::Sqlite { path: PathBuf::from("test.db") },
 ///         max_connections: 5,
 ///         busy_timeout_seconds: 30,
+///         read_replica: None,
 ///         enable_wal: true,
 ///         enable_fts: true,
 ///     };
@@ -803,8 +947,9 @@ pub fn start_periodic_discovery(registry: ServerRegistry) -> Option<tokio::task:
 
             debug!("Running periodic external server discovery");
 
-            // Discover servers
-            let discovered = discover_external_servers().await;
+            // Reload settings each pass so changes apply without a restart
+            let config = load_discovery_config(registry.pool()).await;
+            let discovered = discover_external_servers(&config).await;
 
             if !discovered.is_empty() {
                 debug!(
@@ -946,6 +1091,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_non_node_frameworks() {
+        let detect = |command: &str| {
+            detect_framework_from_command(
+                &command
+                    .split_whitespace()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        assert_eq!(
+            detect("node /app/node_modules/.bin/vite preview --port 4173"),
+            Some("Vite Preview".to_string())
+        );
+        assert_eq!(
+            detect("puma 6.4.0 (tcp://localhost:3000) [blog]"),
+            Some("Rails".to_string())
+        );
+        assert_eq!(
+            detect("python3 -m flask run --port 5000"),
+            Some("Flask".to_string())
+        );
+        assert_eq!(
+            detect("python3 -m uvicorn main:app --reload"),
+            Some("FastAPI".to_string())
+        );
+        assert_eq!(
+            detect("python3 -m http.server 8000"),
+            Some("Python HTTP Server".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_port_ranges() {
+        assert_eq!(
+            parse_port_ranges("3000-3002, 5173,3001").unwrap(),
+            vec![3000, 3001, 3002, 5173]
+        );
+        assert!(parse_port_ranges("80").is_err());
+        assert!(parse_port_ranges("4000-3000").is_err());
+        assert!(parse_port_ranges("abc").is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_discovery_config_from_settings() {
+        std::env::remove_var("ORKEE_DISCOVERY_PORTS");
+
+        let config = DiscoveryConfig::from_settings([
+            ("discovery_port_ranges", "4000-4001"),
+            ("discovery_scan_roots", "/work/apps, /srv"),
+            ("discovery_ignore_patterns", "storybook,*/legacy/*"),
+        ]);
+        assert_eq!(config.ports, vec![4000, 4001]);
+
+        let server = |dir: &str, command: &str| DiscoveredServer {
+            pid: 1234,
+            port: 4000,
+            working_dir: PathBuf::from(dir),
+            command: command.split_whitespace().map(str::to_string).collect(),
+            framework_name: None,
+        };
+        assert!(config.allows(&server("/work/apps/site", "npm run dev")));
+        assert!(!config.allows(&server("/tmp/site", "npm run dev")));
+        assert!(!config.allows(&server("/work/apps/site", "npx Storybook dev")));
+        assert!(!config.allows(&server("/srv/legacy/app", "rails server")));
+
+        // Invalid ranges keep the defaults; the environment overrides the setting
+        let config = DiscoveryConfig::from_settings([("discovery_port_ranges", "80")]);
+        assert_eq!(config.ports, get_discovery_ports());
+
+        std::env::set_var("ORKEE_DISCOVERY_PORTS", "6000");
+        let config = DiscoveryConfig::from_settings([("discovery_port_ranges", "4000")]);
+        assert_eq!(config.ports, vec![6000]);
+        std::env::remove_var("ORKEE_DISCOVERY_PORTS");
+    }
+
     #[test]
     #[serial]
     fn test_get_discovery_ports_default() {
@@ -5,23 +5,28 @@
 
 pub mod discovery;
 pub mod manager;
+pub mod metrics;
+pub mod qr;
+pub mod readiness;
 pub mod registry;
+pub mod services;
 pub mod storage;
 pub mod types;
 pub mod validation;
 
 // Re-export key types and functions for easier use
 pub use discovery::{
-    discover_external_servers, load_env_from_directory, register_discovered_server,
-    start_periodic_discovery, DiscoveredServer,
+    discover_external_servers, load_discovery_config, load_env_from_directory,
+    register_discovered_server, start_periodic_discovery, DiscoveredServer, DiscoveryConfig,
 };
 pub use manager::{PreviewManager, ServerInfo};
 pub use registry::{is_process_running_validated, ServerRegistry};
 pub use types::{
     ApiResponse, DevServerConfig, DevServerInstance, DevServerLog, DevServerStatus, Framework,
     LogType, PackageManager, PreviewError, PreviewResult, ProjectDetectionResult, ProjectType,
-    ServerEvent, ServerLogsRequest, ServerLogsResponse, ServerSource, ServerStatusInfo,
-    ServerStatusResponse, ServersResponse, StartServerRequest, StartServerResponse,
+    ResourceSample, ServerEvent, ServerLogsRequest, ServerLogsResponse, ServerMetricsResponse,
+    ServerSource, ServerStatusInfo, ServerStatusResponse, ServersResponse, ServiceConfig,
+    ServiceStatusInfo, StartServerRequest, StartServerResponse,
 };
 
 /// Initialize the preview service with a SQLite-based manager.
@@ -31,6 +36,7 @@ pub use types::{
 ///
 /// This function also starts background tasks:
 /// - External server discovery: Runs every 30 seconds to find manually launched servers
+/// - Resource sampling: Records CPU and memory of managed servers every 5 seconds
 ///
 /// # Arguments
 ///
@@ -55,6 +61,7 @@ pub use types::{
 ///         },
 ///         max_connections: 5,
 ///         busy_timeout_seconds: 30,
+///         read_replica: None,
 ///         enable_wal: false,
 ///         enable_fts: true,
 ///     };
@@ -77,7 +84,9 @@ pub async fn init(storage: &orkee_storage::sqlite::SqliteStorage) -> PreviewResu
     // Clone registry for the background task
     start_periodic_discovery(registry.clone());
 
-    Ok(PreviewManager::new_with_recovery(registry).await)
+    let manager = PreviewManager::new_with_recovery(registry).await;
+    manager.start_metrics_sampler();
+    Ok(manager)
 }
 
 /// Initialize the preview manager directly from a database pool.
@@ -101,7 +110,9 @@ pub async fn init_from_pool(pool: sqlx::SqlitePool) -> PreviewResult<PreviewMana
     // Start periodic discovery and registration of external servers
     start_periodic_discovery(registry.clone());
 
-    Ok(PreviewManager::new_with_recovery(registry).await)
+    let manager = PreviewManager::new_with_recovery(registry).await;
+    manager.start_metrics_sampler();
+    Ok(manager)
 }
 
 /// Version information for the preview crate.
@@ -121,6 +132,7 @@ mod tests {
             },
             max_connections: 5,
             busy_timeout_seconds: 30,
+            read_replica: None,
             enable_wal: false, // WAL doesn't work with :memory:
             enable_fts: true,
         };
@@ -1,24 +1,30 @@
+use crate::metrics::{ResourceSampler, METRICS_SAMPLE_INTERVAL};
+use crate::readiness::{self, READINESS_POLL_INTERVAL, STARTUP_TIMEOUT};
 use crate::registry::{ServerRegistry, ServerRegistryEntry};
+use crate::services;
 use crate::types::*;
 use chrono::Utc;
 use orkee_config::constants;
 use orkee_config::env::parse_env_or_default_with_validation;
 use serde_json;
 use std::collections::hash_map::DefaultHasher;
-use std::collections::HashMap;
 use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 use std::sync::Arc;
 use tokio::fs;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use tokio::process::Child;
 use tokio::process::Command;
 use tokio::sync::{broadcast, RwLock};
 use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
+/// Setting that opens the default browser when a server becomes ready
+const OPEN_BROWSER_SETTING: &str = "preview_open_browser";
+
 /// SSE event broadcast channel capacity.
 ///
 /// This determines how many events can be buffered per subscriber before the
@@ -53,6 +59,7 @@ pub struct PreviewManager {
     registry: ServerRegistry,
     active_servers: Arc<RwLock<HashMap<String, ServerInfo>>>,
     server_logs: Arc<RwLock<HashMap<String, VecDeque<DevServerLog>>>>,
+    service_groups: Arc<RwLock<HashMap<String, ServiceGroup>>>,
     event_tx: broadcast::Sender<ServerEvent>,
 }
 
@@ -109,6 +116,28 @@ impl Clone for ServerInfo {
     }
 }
 
+/// Running services of a project started from `orkee.services.json`.
+///
+/// The last service in startup order is the previewed server and is tracked
+/// like any other server; the services before it are tracked here.
+struct ServiceGroup {
+    /// Service configuration in startup order
+    config: Vec<ServiceConfig>,
+    /// Services started before the previewed server, in startup order
+    processes: Vec<ServiceProcess>,
+}
+
+/// A supporting service process in a service group
+struct ServiceProcess {
+    name: String,
+    port: Option<u16>,
+    pid: Option<u32>,
+    status: DevServerStatus,
+    child: Option<Arc<RwLock<Child>>>,
+    /// Output capture tasks, aborted when the service stops
+    log_tasks: Vec<tokio::task::JoinHandle<()>>,
+}
+
 impl PreviewManager {
     /// Create a new preview manager without recovery.
     ///
@@ -132,6 +161,7 @@ impl PreviewManager {
             registry,
             active_servers: Arc::new(RwLock::new(HashMap::new())),
             server_logs: Arc::new(RwLock::new(HashMap::new())),
+            service_groups: Arc::new(RwLock::new(HashMap::new())),
             event_tx,
         }
     }
@@ -165,6 +195,7 @@ impl PreviewManager {
     ///         },
     ///         max_connections: 5,
     ///         busy_timeout_seconds: 30,
+    ///         read_replica: None,
     ///         enable_wal: false,
     ///         enable_fts: true,
     ///     };
@@ -209,6 +240,19 @@ impl PreviewManager {
             }
         }
 
+        // Servers recovered mid-startup have no log capture, so only the port probe can mark them ready
+        let starting: Vec<String> = manager
+            .active_servers
+            .read()
+            .await
+            .values()
+            .filter(|info| info.status == DevServerStatus::Starting)
+            .map(|info| info.project_id.clone())
+            .collect();
+        for project_id in starting {
+            manager.spawn_readiness_watcher(project_id);
+        }
+
         manager
     }
 
@@ -265,6 +309,7 @@ impl PreviewManager {
     ///         },
     ///         max_connections: 5,
     ///         busy_timeout_seconds: 30,
+    ///         read_replica: None,
     ///         enable_wal: false,
     ///         enable_fts: true,
     ///     };
@@ -406,11 +451,13 @@ impl PreviewManager {
     /// Capture logs from a child process handle (takes mutable reference)
     ///
     /// This method takes stdout/stderr from the child process and spawns tasks
-    /// to capture and log output. The child handle remains available for cleanup.
+    /// to capture and log output. Lines matching the framework's ready patterns
+    /// mark the server ready. The child handle remains available for cleanup.
     /// Returns JoinHandles for the spawned tasks so they can be aborted when stopping.
     async fn capture_process_logs_from_handle(
         &self,
         project_id: &str,
+        framework: &str,
         child: &mut Child,
     ) -> Vec<tokio::task::JoinHandle<()>> {
         let project_id_clone = project_id.to_string();
@@ -421,6 +468,7 @@ impl PreviewManager {
         if let Some(stdout) = child.stdout.take() {
             let project_id_stdout = project_id_clone.clone();
             let manager_stdout = manager.clone();
+            let framework_stdout = framework.to_string();
             let handle = tokio::spawn(async move {
                 let reader = BufReader::new(stdout);
                 let mut lines = reader.lines();
@@ -431,10 +479,18 @@ impl PreviewManager {
                             .update_server_port(&project_id_stdout, detected_port)
                             .await;
                     }
+                    if readiness::is_ready_line(&framework_stdout, &line) {
+                        manager_stdout.mark_server_ready(&project_id_stdout).await;
+                    }
                     manager_stdout
                         .add_log(&project_id_stdout, LogType::Stdout, line)
                         .await;
                 }
+
+                // Stopping aborts this task first, so reaching EOF means the process exited on its own
+                manager_stdout
+                    .report_unexpected_exit(&project_id_stdout)
+                    .await;
             });
             handles.push(handle);
         }
@@ -443,6 +499,7 @@ impl PreviewManager {
         if let Some(stderr) = child.stderr.take() {
             let project_id_stderr = project_id_clone.clone();
             let manager_stderr = manager.clone();
+            let framework_stderr = framework.to_string();
             let handle = tokio::spawn(async move {
                 let reader = BufReader::new(stderr);
                 let mut lines = reader.lines();
@@ -453,6 +510,9 @@ impl PreviewManager {
                             .update_server_port(&project_id_stderr, detected_port)
                             .await;
                     }
+                    if readiness::is_ready_line(&framework_stderr, &line) {
+                        manager_stderr.mark_server_ready(&project_id_stderr).await;
+                    }
 
                     // Filter out successful HTTP access logs from being marked as STDERR
                     let log_type = if manager_stderr.is_successful_http_log(&line) {
@@ -472,12 +532,166 @@ impl PreviewManager {
         handles
     }
 
+    /// Move a starting server to `Running` once it is ready to serve requests.
+    ///
+    /// Records a system log, persists the status to the registry, emits a
+    /// `ServerReady` event, and opens the browser if `preview_open_browser` is
+    /// set. Servers that are not starting are left alone, so this is safe to
+    /// call for every ready signal.
+    async fn mark_server_ready(&self, project_id: &str) {
+        let ready_info = {
+            let mut servers = self.active_servers.write().await;
+            match servers.get_mut(project_id) {
+                Some(info) if info.status == DevServerStatus::Starting => {
+                    info.status = DevServerStatus::Running;
+                    info.clone()
+                }
+                _ => return,
+            }
+        };
+
+        info!(
+            "Server for project {} is ready on port {}",
+            project_id, ready_info.port
+        );
+        self.add_log(
+            project_id,
+            LogType::System,
+            format!("Server is ready at http://localhost:{}", ready_info.port),
+        )
+        .await;
+
+        // Look up by project since recovered servers get a new ID in memory
+        let registry_servers = self.registry.get_all_servers().await;
+        if let Some(entry) = registry_servers
+            .iter()
+            .find(|entry| entry.project_id == project_id)
+        {
+            if let Err(e) = self
+                .registry
+                .update_server_status(&entry.id, DevServerStatus::Running, entry.pid)
+                .await
+            {
+                warn!("Failed to update server status in registry: {}", e);
+            }
+        }
+
+        let _ = self.event_tx.send(ServerEvent::ServerReady {
+            project_id: project_id.to_string(),
+            port: ready_info.port,
+        });
+
+        if self.open_browser_enabled().await {
+            let url = ready_info
+                .preview_url
+                .unwrap_or_else(|| format!("http://localhost:{}", ready_info.port));
+            info!("Opening {} in the default browser", url);
+            if let Err(e) = open::that_detached(&url) {
+                warn!("Failed to open browser for {}: {}", url, e);
+            }
+        }
+    }
+
+    /// Whether the user asked for servers to open in the browser once ready
+    async fn open_browser_enabled(&self) -> bool {
+        let value: Option<String> =
+            sqlx::query_scalar("SELECT value FROM system_settings WHERE key = ?")
+                .bind(OPEN_BROWSER_SETTING)
+                .fetch_optional(self.registry.pool())
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load {} setting: {}", OPEN_BROWSER_SETTING, e);
+                    None
+                });
+        value.as_deref() == Some("true")
+    }
+
+    /// Probe a starting server's port until it accepts connections.
+    ///
+    /// Covers servers whose ready line isn't recognized. The probe follows port
+    /// changes detected from the logs and stops once the server leaves `Starting`.
+    /// After `STARTUP_TIMEOUT` probing stops, but ready log lines still apply.
+    fn spawn_readiness_watcher(&self, project_id: String) -> tokio::task::JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+            loop {
+                let port = match manager.active_servers.read().await.get(&project_id) {
+                    Some(info) if info.status == DevServerStatus::Starting => info.port,
+                    _ => return,
+                };
+
+                if readiness::is_port_accepting(port).await {
+                    manager.mark_server_ready(&project_id).await;
+                    return;
+                }
+
+                if tokio::time::Instant::now() >= deadline {
+                    warn!(
+                        "Server for project {} did not become ready within {}s",
+                        project_id,
+                        STARTUP_TIMEOUT.as_secs()
+                    );
+                    manager
+                        .add_log(
+                            &project_id,
+                            LogType::System,
+                            format!(
+                                "Server did not become ready within {}s; still waiting for a ready log line",
+                                STARTUP_TIMEOUT.as_secs()
+                            ),
+                        )
+                        .await;
+                    return;
+                }
+
+                tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+            }
+        })
+    }
+
+    /// Mark a server whose process exited while it was still running as crashed.
+    ///
+    /// Sets the server status to `Error`, records a system log, and emits a
+    /// `ServerError` event. Servers that are already stopping are left alone.
+    async fn report_unexpected_exit(&self, project_id: &str) {
+        {
+            let mut servers = self.active_servers.write().await;
+            match servers.get_mut(project_id) {
+                Some(info)
+                    if matches!(
+                        info.status,
+                        DevServerStatus::Running | DevServerStatus::Starting
+                    ) =>
+                {
+                    info.status = DevServerStatus::Error;
+                }
+                _ => return,
+            }
+        }
+
+        warn!("Server for project {} exited unexpectedly", project_id);
+        self.add_log(
+            project_id,
+            LogType::System,
+            "Server process exited unexpectedly".to_string(),
+        )
+        .await;
+
+        let _ = self.event_tx.send(ServerEvent::ServerError {
+            project_id: project_id.to_string(),
+            error: "Server process exited unexpectedly".to_string(),
+        });
+    }
+
     /// Start a development server for a project.
     ///
     /// Spawns a new development server process for the specified project. This method:
     /// - Detects the project type and framework automatically
     /// - Allocates an available port (preferring consistent ports per project)
     /// - Starts the appropriate development command (npm run dev, vite, etc.)
+    /// - For projects with an `orkee.services.json`, first starts the services the
+    ///   previewed one depends on, waiting for each dependency to become ready
     /// - Captures stdout/stderr logs automatically
     /// - Creates persistence lock files for crash recovery
     ///
@@ -513,6 +727,7 @@ impl PreviewManager {
     ///         },
     ///         max_connections: 5,
     ///         busy_timeout_seconds: 30,
+    ///         read_replica: None,
     ///         enable_wal: false,
     ///         enable_fts: true,
     ///     };
@@ -531,6 +746,21 @@ impl PreviewManager {
         &self,
         project_id: String,
         project_root: PathBuf,
+    ) -> PreviewResult<ServerInfo> {
+        self.start_server_with_env(project_id, project_root, &HashMap::new())
+            .await
+    }
+
+    /// Start a development server with extra environment variables.
+    ///
+    /// Behaves like [`start_server`](Self::start_server), additionally passing
+    /// `environment` (e.g. decrypted project secrets) to the spawned process.
+    /// `PORT` is always set to the allocated port and cannot be overridden.
+    pub async fn start_server_with_env(
+        &self,
+        project_id: String,
+        project_root: PathBuf,
+        environment: &HashMap<String, String>,
     ) -> PreviewResult<ServerInfo> {
         info!("Starting preview server for: {}", project_id);
 
@@ -539,7 +769,7 @@ impl PreviewManager {
             let servers = self.active_servers.read().await;
             if let Some(existing) = servers.get(&project_id) {
                 match existing.status {
-                    DevServerStatus::Running => {
+                    DevServerStatus::Running | DevServerStatus::Starting => {
                         info!("Server already running for project: {}", project_id);
                         return Ok(existing.clone());
                     }
@@ -585,14 +815,24 @@ impl PreviewManager {
                         }
                     }
                     _ => {
-                        // Other states (Starting, Crashed, etc.) - allow restart
+                        // Other states (Stopped, Error) - allow restart
                     }
                 }
             }
         }
 
-        // Find available port using project-based allocation (8000-8999 range)
-        let port = self.find_available_port(&project_id).await?;
+        // Projects with orkee.services.json preview the last service to start
+        let service_group = services::load_service_group(&project_root).await?;
+        let primary_service = service_group
+            .as_ref()
+            .and_then(|group| group.last().cloned());
+
+        // Find available port using project-based allocation (8000-8999 range),
+        // unless the previewed service declares its own
+        let port = match primary_service.as_ref().and_then(|service| service.port) {
+            Some(port) => port,
+            None => self.find_available_port(&project_id).await?,
+        };
 
         // Create server info
         let server_info = ServerInfo {
@@ -610,8 +850,27 @@ impl PreviewManager {
             matched_project_id: None,
         };
 
+        // Start the services the previewed server depends on, in order
+        let mut environment = environment.clone();
+        if let Some(group) = &service_group {
+            let dependency_environment = self
+                .start_supporting_services(&project_id, &project_root, &environment, group)
+                .await?;
+            environment.extend(dependency_environment);
+        }
+
         // Try to start the server
-        match self.spawn_server(&server_info, &project_root).await {
+        let spawned = match &primary_service {
+            Some(service) => {
+                self.spawn_service_command(&server_info, &project_root, &environment, service)
+                    .await
+            }
+            None => {
+                self.spawn_server(&server_info, &project_root, &environment)
+                    .await
+            }
+        };
+        match spawned {
             Ok(spawn_result) => {
                 let pid = spawn_result.child.id();
 
@@ -622,13 +881,17 @@ impl PreviewManager {
                 // This prevents resource leaks when servers are stopped
                 let log_handles = {
                     let mut child = child_handle.write().await;
-                    self.capture_process_logs_from_handle(&project_id, &mut child)
-                        .await
+                    self.capture_process_logs_from_handle(
+                        &project_id,
+                        &spawn_result.framework,
+                        &mut child,
+                    )
+                    .await
                 };
 
+                // Status stays Starting until a ready log line or the port probe marks it Running
                 let mut updated_info = server_info;
                 updated_info.pid = pid;
-                updated_info.status = DevServerStatus::Running;
                 updated_info.child = Some(child_handle); // Store the child handle
                 updated_info.log_tasks = Some(log_handles); // Store log capture task handles
                 updated_info.actual_command = Some(spawn_result.command);
@@ -661,8 +924,10 @@ impl PreviewManager {
                     framework: updated_info.framework_name.clone(),
                 });
 
+                self.spawn_readiness_watcher(project_id.clone());
+
                 info!(
-                    "Successfully started server for project: {} on port {}",
+                    "Started server process for project: {} on port {}, waiting for it to become ready",
                     project_id, port
                 );
                 Ok(updated_info)
@@ -679,6 +944,7 @@ impl PreviewManager {
                 // Don't store failed server attempts in active_servers to avoid port allocation leaks.
                 // The port was never actually bound, so storing the error entry would mislead
                 // other parts of the system into thinking the port is in use.
+                self.stop_service_group(&project_id).await;
 
                 Err(e)
             }
@@ -689,6 +955,7 @@ impl PreviewManager {
     ///
     /// Stops the development server for the specified project by:
     /// - Sending a termination signal to the process
+    /// - Stopping the rest of its service group, in reverse startup order
     /// - Removing the server from active tracking
     /// - Cleaning up lock files
     /// - Removing from the central registry
@@ -721,6 +988,7 @@ impl PreviewManager {
     ///         },
     ///         max_connections: 5,
     ///         busy_timeout_seconds: 30,
+    ///         read_replica: None,
     ///         enable_wal: false,
     ///         enable_fts: true,
     ///     };
@@ -894,6 +1162,9 @@ impl PreviewManager {
             });
         }
 
+        // The previewed server was signalled first; its dependencies stop in reverse order
+        self.stop_service_group(project_id).await;
+
         Ok(())
     }
 
@@ -955,6 +1226,60 @@ impl PreviewManager {
         None
     }
 
+    /// Remove central registry entries for servers that are stale or no longer running.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of entries removed.
+    pub async fn cleanup_stale_servers(&self) -> Result<usize, String> {
+        self.registry
+            .cleanup_stale_entries()
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Start sampling CPU and memory usage of servers started by Orkee.
+    ///
+    /// Every `METRICS_SAMPLE_INTERVAL`, each managed server's process tree is sampled
+    /// and the result is stored in the registry's rolling history. History for
+    /// servers that are no longer running is dropped.
+    pub fn start_metrics_sampler(&self) -> tokio::task::JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut sampler = ResourceSampler::new();
+            let mut interval = tokio::time::interval(METRICS_SAMPLE_INTERVAL);
+            loop {
+                interval.tick().await;
+
+                let roots: Vec<(String, u32)> = manager
+                    .active_servers
+                    .read()
+                    .await
+                    .iter()
+                    .filter(|(_, info)| info.source == ServerSource::Orkee)
+                    .filter_map(|(project_id, info)| info.pid.map(|pid| (project_id.clone(), pid)))
+                    .collect();
+
+                let samples = sampler.sample(&roots);
+                let sampled: HashSet<String> = samples.keys().cloned().collect();
+                for (project_id, sample) in samples {
+                    manager.registry.record_metrics(&project_id, sample).await;
+                }
+                manager.registry.retain_metrics(&sampled).await;
+            }
+        })
+    }
+
+    /// Get the resource usage history of a project's server, oldest sample first.
+    pub async fn get_server_metrics(&self, project_id: &str) -> Vec<ResourceSample> {
+        self.registry.get_metrics(project_id).await
+    }
+
+    /// Get the latest resource usage sample for each sampled server, keyed by project ID.
+    pub async fn latest_resource_usage(&self) -> HashMap<String, ResourceSample> {
+        self.registry.latest_metrics().await
+    }
+
     /// List all active development servers.
     ///
     /// Returns a combined list of servers from both the local manager and the central
@@ -981,6 +1306,7 @@ impl PreviewManager {
     ///         },
     ///         max_connections: 5,
     ///         busy_timeout_seconds: 30,
+    ///         read_replica: None,
     ///         enable_wal: false,
     ///         enable_fts: true,
     ///     };
@@ -1112,17 +1438,21 @@ impl PreviewManager {
         &self,
         server_info: &ServerInfo,
         project_root: &Path,
+        environment: &HashMap<String, String>,
     ) -> PreviewResult<SpawnResult> {
         // Check for package.json first - if it has dev scripts, prefer dev commands
         if project_root.join("package.json").exists() {
             // Try development commands for Node.js projects
-            self.spawn_dev_command(server_info, project_root).await
+            self.spawn_dev_command(server_info, project_root, environment)
+                .await
         } else if project_root.join("index.html").exists() {
             // Simple static file server for pure HTML projects
-            self.spawn_static_server(server_info, project_root).await
+            self.spawn_static_server(server_info, project_root, environment)
+                .await
         } else {
             // For other projects, try common dev commands as fallback
-            self.spawn_dev_command(server_info, project_root).await
+            self.spawn_dev_command(server_info, project_root, environment)
+                .await
         }
     }
 
@@ -1131,11 +1461,13 @@ impl PreviewManager {
         &self,
         server_info: &ServerInfo,
         project_root: &Path,
+        environment: &HashMap<String, String>,
     ) -> PreviewResult<SpawnResult> {
         // Use Python's built-in HTTP server as it's reliable and simple
         let mut cmd = Command::new("python3");
         cmd.args(["-m", "http.server", &server_info.port.to_string()])
             .current_dir(project_root)
+            .envs(environment)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .stdin(Stdio::null());
@@ -1251,6 +1583,7 @@ impl PreviewManager {
         &self,
         server_info: &ServerInfo,
         project_root: &Path,
+        environment: &HashMap<String, String>,
     ) -> PreviewResult<SpawnResult> {
         let port_str = server_info.port.to_string();
 
@@ -1291,6 +1624,7 @@ impl PreviewManager {
             command
                 .args(args)
                 .current_dir(project_root)
+                .envs(environment)
                 .env("PORT", server_info.port.to_string())
                 .stdout(Stdio::piped())
                 .stderr(Stdio::piped())
@@ -1439,6 +1773,444 @@ impl PreviewManager {
         }
     }
 
+    // === SERVICE GROUP METHODS ===
+
+    /// Start the services a project's previewed server depends on.
+    ///
+    /// `group` is in startup order and its last service is the previewed one,
+    /// which is left to the caller. Each service starts once its dependencies
+    /// are ready, and the previewed service's dependencies are awaited too.
+    /// On failure everything started so far is stopped again.
+    ///
+    /// Returns the `<NAME>_PORT` variables for the started services.
+    async fn start_supporting_services(
+        &self,
+        project_id: &str,
+        project_root: &Path,
+        environment: &HashMap<String, String>,
+        group: &[ServiceConfig],
+    ) -> PreviewResult<HashMap<String, String>> {
+        self.service_groups.write().await.insert(
+            project_id.to_string(),
+            ServiceGroup {
+                config: group.to_vec(),
+                processes: Vec::new(),
+            },
+        );
+
+        let Some((primary, supporting)) = group.split_last() else {
+            return Ok(HashMap::new());
+        };
+
+        let mut started: Vec<(String, Option<u16>)> = Vec::new();
+        for service in supporting {
+            let result = match self.wait_for_dependencies(project_id, service).await {
+                Ok(()) => {
+                    let mut service_environment = environment.clone();
+                    service_environment.extend(services::dependency_environment(&started));
+                    self.spawn_supporting_service(
+                        project_id,
+                        project_root,
+                        &service_environment,
+                        service,
+                    )
+                    .await
+                }
+                Err(e) => Err(e),
+            };
+
+            if let Err(e) = result {
+                error!(
+                    "Failed to start service '{}' for project {}: {}",
+                    service.name, project_id, e
+                );
+                self.stop_service_group(project_id).await;
+                return Err(e);
+            }
+            started.push((service.name.clone(), service.port));
+        }
+
+        if let Err(e) = self.wait_for_dependencies(project_id, primary).await {
+            self.stop_service_group(project_id).await;
+            return Err(e);
+        }
+
+        Ok(services::dependency_environment(&started))
+    }
+
+    /// Wait until every service `service` depends on is ready
+    async fn wait_for_dependencies(
+        &self,
+        project_id: &str,
+        service: &ServiceConfig,
+    ) -> PreviewResult<()> {
+        for dependency in &service.depends_on {
+            self.add_log(
+                project_id,
+                LogType::System,
+                format!(
+                    "Waiting for service '{}' before starting '{}'",
+                    dependency, service.name
+                ),
+            )
+            .await;
+            self.wait_for_service_ready(project_id, dependency).await?;
+        }
+        Ok(())
+    }
+
+    /// Wait until a supporting service is ready.
+    ///
+    /// A service is ready once its port accepts connections or it logs a
+    /// ready line. Services without a port must log one within `STARTUP_TIMEOUT`.
+    async fn wait_for_service_ready(&self, project_id: &str, name: &str) -> PreviewResult<()> {
+        let deadline = tokio::time::Instant::now() + STARTUP_TIMEOUT;
+        loop {
+            let (status, port) = {
+                let groups = self.service_groups.read().await;
+                match groups
+                    .get(project_id)
+                    .and_then(|group| group.processes.iter().find(|p| p.name == name))
+                {
+                    Some(process) => (process.status.clone(), process.port),
+                    None => {
+                        return Err(PreviewError::ServiceNotReady {
+                            service: name.to_string(),
+                            reason: "service is not running".to_string(),
+                        })
+                    }
+                }
+            };
+
+            match status {
+                DevServerStatus::Running => return Ok(()),
+                DevServerStatus::Starting => {}
+                _ => {
+                    return Err(PreviewError::ServiceNotReady {
+                        service: name.to_string(),
+                        reason: "service exited during startup".to_string(),
+                    })
+                }
+            }
+
+            if let Some(port) = port {
+                if readiness::is_port_accepting(port).await {
+                    self.mark_service_ready(project_id, name).await;
+                    return Ok(());
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(PreviewError::ServiceNotReady {
+                    service: name.to_string(),
+                    reason: format!("not ready within {}s", STARTUP_TIMEOUT.as_secs()),
+                });
+            }
+
+            tokio::time::sleep(READINESS_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Spawn a supporting service and start capturing its output
+    async fn spawn_supporting_service(
+        &self,
+        project_id: &str,
+        project_root: &Path,
+        environment: &HashMap<String, String>,
+        service: &ServiceConfig,
+    ) -> PreviewResult<()> {
+        let mut command = shell_command(&service.command);
+        command
+            .current_dir(project_root)
+            .envs(environment)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null());
+        if let Some(port) = service.port {
+            command.env("PORT", port.to_string());
+        }
+
+        self.add_log(
+            project_id,
+            LogType::System,
+            format!("Starting service '{}': {}", service.name, service.command),
+        )
+        .await;
+
+        let mut child = command
+            .spawn()
+            .map_err(|e| PreviewError::ProcessSpawnError {
+                command: service.command.clone(),
+                error: e.to_string(),
+            })?;
+        let pid = child.id();
+        info!(
+            "Spawned service '{}' for project {} with PID: {:?}",
+            service.name, project_id, pid
+        );
+
+        let mut log_tasks = Vec::new();
+        if let Some(stdout) = child.stdout.take() {
+            log_tasks.push(self.capture_service_output(
+                project_id,
+                &service.name,
+                stdout,
+                LogType::Stdout,
+            ));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            log_tasks.push(self.capture_service_output(
+                project_id,
+                &service.name,
+                stderr,
+                LogType::Stderr,
+            ));
+        }
+
+        let process = ServiceProcess {
+            name: service.name.clone(),
+            port: service.port,
+            pid,
+            status: DevServerStatus::Starting,
+            child: Some(Arc::new(RwLock::new(child))),
+            log_tasks,
+        };
+        if let Some(group) = self.service_groups.write().await.get_mut(project_id) {
+            group.processes.push(process);
+        }
+
+        Ok(())
+    }
+
+    /// Forward a service's output to the project logs, prefixed with the service name.
+    ///
+    /// Ready lines mark the service ready. Stdout reaching EOF means the service
+    /// exited, since stopping aborts this task first.
+    fn capture_service_output(
+        &self,
+        project_id: &str,
+        name: &str,
+        output: impl AsyncRead + Unpin + Send + 'static,
+        log_type: LogType,
+    ) -> tokio::task::JoinHandle<()> {
+        let manager = self.clone();
+        let project_id = project_id.to_string();
+        let name = name.to_string();
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(output).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if readiness::is_ready_line("", &line) {
+                    manager.mark_service_ready(&project_id, &name).await;
+                }
+                manager
+                    .add_log(
+                        &project_id,
+                        log_type.clone(),
+                        format!("[{}] {}", name, line),
+                    )
+                    .await;
+            }
+
+            if log_type == LogType::Stdout {
+                manager.report_service_exit(&project_id, &name).await;
+            }
+        })
+    }
+
+    /// Move a starting supporting service to `Running`
+    async fn mark_service_ready(&self, project_id: &str, name: &str) {
+        let marked = {
+            let mut groups = self.service_groups.write().await;
+            match groups
+                .get_mut(project_id)
+                .and_then(|group| group.processes.iter_mut().find(|p| p.name == name))
+            {
+                Some(process) if process.status == DevServerStatus::Starting => {
+                    process.status = DevServerStatus::Running;
+                    true
+                }
+                _ => false,
+            }
+        };
+
+        if marked {
+            info!("Service '{}' for project {} is ready", name, project_id);
+            self.add_log(
+                project_id,
+                LogType::System,
+                format!("Service '{}' is ready", name),
+            )
+            .await;
+        }
+    }
+
+    /// Mark a supporting service whose process exited on its own as failed
+    async fn report_service_exit(&self, project_id: &str, name: &str) {
+        {
+            let mut groups = self.service_groups.write().await;
+            match groups
+                .get_mut(project_id)
+                .and_then(|group| group.processes.iter_mut().find(|p| p.name == name))
+            {
+                Some(process)
+                    if matches!(
+                        process.status,
+                        DevServerStatus::Running | DevServerStatus::Starting
+                    ) =>
+                {
+                    process.status = DevServerStatus::Error;
+                }
+                _ => return,
+            }
+        }
+
+        warn!(
+            "Service '{}' for project {} exited unexpectedly",
+            name, project_id
+        );
+        self.add_log(
+            project_id,
+            LogType::System,
+            format!("Service '{}' exited unexpectedly", name),
+        )
+        .await;
+    }
+
+    /// Stop a project's supporting services in reverse startup order
+    async fn stop_service_group(&self, project_id: &str) {
+        let Some(group) = self.service_groups.write().await.remove(project_id) else {
+            return;
+        };
+
+        for process in group.processes.into_iter().rev() {
+            for task in process.log_tasks {
+                task.abort();
+            }
+
+            let mut killed = false;
+            if let Some(child) = &process.child {
+                killed = child.write().await.kill().await.is_ok();
+            }
+            if !killed {
+                if let Some(pid) = process.pid {
+                    if let Err(e) = self.kill_process(pid).await {
+                        warn!(
+                            "Failed to stop service '{}' for project {}: {}",
+                            process.name, project_id, e
+                        );
+                    }
+                }
+            }
+
+            info!(
+                "Stopped service '{}' for project {}",
+                process.name, project_id
+            );
+            self.add_log(
+                project_id,
+                LogType::System,
+                format!("Stopped service '{}'", process.name),
+            )
+            .await;
+        }
+    }
+
+    /// Spawn the previewed service of a service group with its configured command
+    async fn spawn_service_command(
+        &self,
+        server_info: &ServerInfo,
+        project_root: &Path,
+        environment: &HashMap<String, String>,
+        service: &ServiceConfig,
+    ) -> PreviewResult<SpawnResult> {
+        let mut command = shell_command(&service.command);
+        command
+            .current_dir(project_root)
+            .envs(environment)
+            .env("PORT", server_info.port.to_string())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .stdin(Stdio::null());
+
+        self.add_log(
+            &server_info.project_id,
+            LogType::System,
+            format!(
+                "Starting service '{}' on port {}: {}",
+                service.name, server_info.port, service.command
+            ),
+        )
+        .await;
+
+        let child = command
+            .spawn()
+            .map_err(|e| PreviewError::ProcessSpawnError {
+                command: service.command.clone(),
+                error: e.to_string(),
+            })?;
+        let framework = self.detect_framework(&service.command, project_root).await;
+
+        Ok(SpawnResult {
+            child,
+            command: service.command.clone(),
+            framework,
+        })
+    }
+
+    /// Service configuration of a project's running service group, in startup order
+    pub async fn service_configs(&self, project_id: &str) -> Vec<ServiceConfig> {
+        self.service_groups
+            .read()
+            .await
+            .get(project_id)
+            .map(|group| group.config.clone())
+            .unwrap_or_default()
+    }
+
+    /// Status of each service in a project's service group, in startup order.
+    ///
+    /// Returns an empty list for projects without a service group. The last
+    /// entry is the previewed server.
+    pub async fn service_statuses(&self, project_id: &str) -> Vec<ServiceStatusInfo> {
+        let mut statuses: Vec<ServiceStatusInfo> = {
+            let groups = self.service_groups.read().await;
+            let Some(group) = groups.get(project_id) else {
+                return Vec::new();
+            };
+            let mut statuses: Vec<ServiceStatusInfo> = group
+                .processes
+                .iter()
+                .map(|process| ServiceStatusInfo {
+                    name: process.name.clone(),
+                    port: process.port,
+                    pid: process.pid,
+                    status: process.status.clone(),
+                })
+                .collect();
+            if let Some(primary) = group.config.last() {
+                statuses.push(ServiceStatusInfo {
+                    name: primary.name.clone(),
+                    port: primary.port,
+                    pid: None,
+                    status: DevServerStatus::Stopped,
+                });
+            }
+            statuses
+        };
+
+        // The previewed server is tracked with the other servers
+        if let (Some(primary), Some(server)) = (
+            statuses.last_mut(),
+            self.active_servers.read().await.get(project_id),
+        ) {
+            primary.port = Some(server.port);
+            primary.pid = server.pid;
+            primary.status = server.status.clone();
+        }
+
+        statuses
+    }
+
     // === PERSISTENCE METHODS ===
 
     /// Check if a process is running by PID (simple check, no validation)
@@ -1500,6 +2272,11 @@ impl PreviewManager {
         Ok(())
     }
 
+    /// Load the user's external server discovery settings
+    pub async fn discovery_config(&self) -> crate::discovery::DiscoveryConfig {
+        crate::discovery::load_discovery_config(self.registry.pool()).await
+    }
+
     /// Register an external server discovered via port scanning
     ///
     /// This allows tracking of servers that were started manually outside of Orkee.
@@ -1643,12 +2420,13 @@ impl PreviewManager {
 
         // Start new process using project configuration
         let parts: Vec<&str> = dev_command.split_whitespace().collect();
-        let (cmd_name, cmd_args) = parts.split_first().ok_or_else(|| {
-            PreviewError::ProcessSpawnError {
-                command: dev_command.to_string(),
-                error: "Empty dev command".to_string(),
-            }
-        })?;
+        let (cmd_name, cmd_args) =
+            parts
+                .split_first()
+                .ok_or_else(|| PreviewError::ProcessSpawnError {
+                    command: dev_command.to_string(),
+                    error: "Empty dev command".to_string(),
+                })?;
 
         let mut cmd = Command::new(cmd_name);
         cmd.args(cmd_args)
@@ -1770,5 +2548,22 @@ impl PreviewManager {
     }
 }
 
+/// Command running a service's command line through the platform shell
+fn shell_command(command_line: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut command = Command::new("cmd");
+        command.args(["/C", command_line]);
+        command
+    }
+
+    #[cfg(not(windows))]
+    {
+        let mut command = Command::new("sh");
+        command.args(["-c", command_line]);
+        command
+    }
+}
+
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,133 @@
+// ABOUTME: CPU and memory sampling for managed development server processes
+// ABOUTME: Sums usage across each server's process tree so child build processes are included
+
+use chrono::Utc;
+use std::collections::HashMap;
+use std::time::Duration;
+use sysinfo::{Pid, ProcessRefreshKind, System};
+
+use crate::types::ResourceSample;
+
+/// How often managed servers are sampled
+pub const METRICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Samples kept per server (30 minutes at the default interval)
+pub const MAX_METRICS_SAMPLES: usize = 360;
+
+/// Samples CPU and memory usage of process trees.
+///
+/// Keeps a `System` between samples because CPU usage is computed from the
+/// difference between two refreshes; the first sample of a process reports 0% CPU.
+pub struct ResourceSampler {
+    system: System,
+}
+
+impl Default for ResourceSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourceSampler {
+    pub fn new() -> Self {
+        Self {
+            system: System::new(),
+        }
+    }
+
+    /// Refresh process information and sample each root PID's process tree.
+    ///
+    /// Returns a sample for every root that is still running, keyed like the input.
+    pub fn sample(&mut self, roots: &[(String, u32)]) -> HashMap<String, ResourceSample> {
+        self.system
+            .refresh_processes_specifics(ProcessRefreshKind::new().with_cpu().with_memory());
+
+        // Threads are listed as processes on Linux; skip them so memory isn't counted twice
+        let parents: Vec<(Pid, Pid)> = self
+            .system
+            .processes()
+            .values()
+            .filter(|process| process.thread_kind().is_none())
+            .filter_map(|process| process.parent().map(|parent| (process.pid(), parent)))
+            .collect();
+
+        let timestamp = Utc::now();
+        roots
+            .iter()
+            .filter_map(|(key, pid)| {
+                let root = Pid::from_u32(*pid);
+                self.system.process(root)?;
+
+                let mut cpu_percent = 0.0;
+                let mut memory_bytes = 0;
+                for pid in process_tree(root, &parents) {
+                    if let Some(process) = self.system.process(pid) {
+                        cpu_percent += process.cpu_usage();
+                        memory_bytes += process.memory();
+                    }
+                }
+
+                Some((
+                    key.clone(),
+                    ResourceSample {
+                        timestamp,
+                        cpu_percent,
+                        memory_bytes,
+                    },
+                ))
+            })
+            .collect()
+    }
+}
+
+/// `root` and all of its descendants, given (child, parent) pairs
+fn process_tree(root: Pid, parents: &[(Pid, Pid)]) -> Vec<Pid> {
+    let mut tree = vec![root];
+    let mut index = 0;
+    while index < tree.len() {
+        let parent = tree[index];
+        tree.extend(
+            parents
+                .iter()
+                .filter(|(child, p)| *p == parent && !tree.contains(child))
+                .map(|(child, _)| *child)
+                .collect::<Vec<_>>(),
+        );
+        index += 1;
+    }
+    tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_tree_includes_descendants() {
+        let pid = Pid::from_u32;
+        let parents = vec![
+            (pid(2), pid(1)),
+            (pid(3), pid(2)),
+            (pid(4), pid(2)),
+            (pid(5), pid(9)),
+        ];
+
+        let mut tree = process_tree(pid(1), &parents);
+        tree.sort();
+        assert_eq!(tree, vec![pid(1), pid(2), pid(3), pid(4)]);
+        assert_eq!(process_tree(pid(5), &parents), vec![pid(5)]);
+    }
+
+    #[test]
+    fn test_sample_current_process() {
+        let mut sampler = ResourceSampler::new();
+        let roots = vec![
+            ("self".to_string(), std::process::id()),
+            ("gone".to_string(), u32::MAX),
+        ];
+
+        let samples = sampler.sample(&roots);
+        assert!(samples["self"].memory_bytes > 0);
+        assert!(!samples.contains_key("gone"));
+    }
+}
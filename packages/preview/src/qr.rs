@@ -0,0 +1,105 @@
+// ABOUTME: QR codes encoding a preview server's LAN URL so phones on the same network can open it
+// ABOUTME: Finds this machine's LAN address and renders codes as PNG images or ASCII text
+
+use crate::types::{PreviewError, PreviewResult};
+use image::{ImageFormat, Luma};
+use qrcode::QrCode;
+use serde::{Deserialize, Serialize};
+use std::io::Cursor;
+use std::net::{IpAddr, UdpSocket};
+
+/// Smallest side of a rendered PNG, in pixels
+const PNG_MIN_SIZE: u32 = 256;
+
+/// Output format of a QR code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QrFormat {
+    #[default]
+    Png,
+    Ascii,
+}
+
+/// This machine's address on the local network.
+///
+/// Connecting a UDP socket sends nothing; it only asks the OS which interface
+/// would route outbound traffic. Returns `None` without a non-loopback route.
+pub fn lan_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_loopback() && !ip.is_unspecified()).then_some(ip)
+}
+
+/// URL of a server on `port` as reached from other devices on the network
+pub fn lan_url(ip: IpAddr, port: u16) -> String {
+    match ip {
+        IpAddr::V4(ip) => format!("http://{}:{}", ip, port),
+        IpAddr::V6(ip) => format!("http://[{}]:{}", ip, port),
+    }
+}
+
+fn encode(data: &str) -> PreviewResult<QrCode> {
+    QrCode::new(data.as_bytes()).map_err(|e| PreviewError::QrCodeFailed {
+        reason: e.to_string(),
+    })
+}
+
+/// Render `data` as a PNG image
+pub fn render_png(data: &str) -> PreviewResult<Vec<u8>> {
+    let image = encode(data)?
+        .render::<Luma<u8>>()
+        .min_dimensions(PNG_MIN_SIZE, PNG_MIN_SIZE)
+        .build();
+
+    let mut png = Vec::new();
+    image
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .map_err(|e| PreviewError::QrCodeFailed {
+            reason: e.to_string(),
+        })?;
+    Ok(png)
+}
+
+/// Render `data` as text for a terminal, two characters per module so it stays square
+pub fn render_ascii(data: &str) -> PreviewResult<String> {
+    Ok(encode(data)?
+        .render::<char>()
+        .dark_color('#')
+        .light_color(' ')
+        .module_dimensions(2, 1)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    #[test]
+    fn test_lan_url() {
+        assert_eq!(
+            lan_url(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)), 5173),
+            "http://192.168.1.20:5173"
+        );
+        assert_eq!(
+            lan_url(IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)), 3000),
+            "http://[fe80::1]:3000"
+        );
+    }
+
+    #[test]
+    fn test_render_png() {
+        let png = render_png("http://192.168.1.20:5173").unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
+    #[test]
+    fn test_render_ascii() {
+        let text = render_ascii("http://192.168.1.20:5173").unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        // Square: twice as many columns as rows
+        assert_eq!(lines[0].chars().count(), lines.len() * 2);
+        assert!(text.contains('#'));
+    }
+}
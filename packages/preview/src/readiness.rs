@@ -0,0 +1,151 @@
+// ABOUTME: Startup readiness detection for managed development servers
+// ABOUTME: Matches per-framework "ready" log lines and probes the server port for connections
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::time::Duration;
+use tokio::net::TcpStream;
+
+/// How long a server may stay in `Starting` before readiness probing gives up
+pub const STARTUP_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How often the server port is probed while a server is starting
+pub const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// How long a single port probe waits for a connection
+const PROBE_TIMEOUT: Duration = Duration::from_millis(300);
+
+/// Ready patterns keyed by the framework names produced by framework detection
+const FRAMEWORK_PATTERNS: &[(&str, &[&str])] = &[
+    ("Vite", &[r"(?i)\bready in \d+", r"Local:\s+https?://"]),
+    (
+        "Next.js",
+        &[r"(?i)\bready\b.*started server on", r"(?i)✓ ready in \d+"],
+    ),
+    (
+        "Create React App",
+        &[r"(?i)compiled successfully", r"(?i)webpack compiled"],
+    ),
+    ("Vue", &[r"App running at:", r"(?i)compiled successfully"]),
+    (
+        "Angular",
+        &[
+            r"(?i)compiled successfully",
+            r"Angular Live Development Server is listening",
+        ],
+    ),
+    ("Python HTTP Server", &[r"Serving HTTP on"]),
+    ("Static HTTP Server", &[r"Serving HTTP on"]),
+];
+
+/// Patterns checked for every framework, including unrecognized ones
+const GENERIC_PATTERNS: &[&str] = &[
+    r"(?i)\bready in \d+",
+    r"(?i)compiled successfully",
+    r"(?i)\blistening (on|at)\b",
+    r"(?i)server (is )?running (on|at)\b",
+];
+
+static FRAMEWORK_REGEXES: Lazy<Vec<(&'static str, Vec<Regex>)>> = Lazy::new(|| {
+    FRAMEWORK_PATTERNS
+        .iter()
+        .map(|(framework, patterns)| (*framework, compile(patterns)))
+        .collect()
+});
+
+static GENERIC_REGEXES: Lazy<Vec<Regex>> = Lazy::new(|| compile(GENERIC_PATTERNS));
+
+static ANSI_ESCAPE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\x1b\[[0-9;?]*[A-Za-z]").expect("valid ANSI escape regex"));
+
+fn compile(patterns: &[&str]) -> Vec<Regex> {
+    patterns
+        .iter()
+        .map(|pattern| Regex::new(pattern).expect("valid readiness regex"))
+        .collect()
+}
+
+/// Whether a log line from a server of the given framework announces that it is ready.
+///
+/// Color codes are stripped first since some dev servers force colored output.
+pub fn is_ready_line(framework: &str, line: &str) -> bool {
+    let line = ANSI_ESCAPE.replace_all(line, "");
+
+    FRAMEWORK_REGEXES
+        .iter()
+        .filter(|(name, _)| *name == framework)
+        .flat_map(|(_, regexes)| regexes)
+        .chain(GENERIC_REGEXES.iter())
+        .any(|regex| regex.is_match(&line))
+}
+
+/// Whether something is accepting TCP connections on `localhost:port`.
+///
+/// Resolves `localhost` rather than connecting to 127.0.0.1 so servers that
+/// only bind the IPv6 loopback are detected too.
+pub async fn is_port_accepting(port: u16) -> bool {
+    matches!(
+        tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect(("localhost", port))).await,
+        Ok(Ok(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_framework_ready_lines() {
+        assert!(is_ready_line("Vite", "  VITE v5.0.0  ready in 312 ms"));
+        assert!(is_ready_line(
+            "Vite",
+            "  \x1b[32m➜\x1b[39m  \x1b[1mLocal\x1b[22m:   \x1b[36mhttp://localhost:5173/\x1b[39m"
+        ));
+        assert!(is_ready_line(
+            "Next.js",
+            "ready - started server on 0.0.0.0:3000, url: http://localhost:3000"
+        ));
+        assert!(is_ready_line("Next.js", " ✓ Ready in 1843ms"));
+        assert!(is_ready_line("Create React App", "Compiled successfully!"));
+        assert!(is_ready_line(
+            "Static HTTP Server",
+            "Serving HTTP on :: port 8000 (http://[::]:8000/) ..."
+        ));
+    }
+
+    #[test]
+    fn test_framework_patterns_do_not_leak() {
+        // "Local:" only means ready for Vite
+        assert!(!is_ready_line(
+            "Development Server",
+            "Local: http://localhost:3000"
+        ));
+        assert!(!is_ready_line("Vite", "  VITE v5.0.0  building..."));
+        assert!(!is_ready_line(
+            "Create React App",
+            "Starting the development server..."
+        ));
+    }
+
+    #[test]
+    fn test_generic_ready_lines() {
+        assert!(is_ready_line(
+            "Development Server",
+            "Server listening on port 4000"
+        ));
+        assert!(is_ready_line(
+            "Development Server",
+            "Express server running on port 8476"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_port_probe() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(is_port_accepting(port).await);
+
+        drop(listener);
+        assert!(!is_port_accepting(port).await);
+    }
+}
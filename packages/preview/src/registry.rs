@@ -5,11 +5,15 @@ use chrono::{DateTime, Utc};
 use orkee_config::env::parse_env_or_default_with_validation;
 use orkee_storage::sqlite::SqliteStorage;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::RwLock;
 use tracing::{debug, error, info};
 
+use crate::metrics::MAX_METRICS_SAMPLES;
 use crate::storage::{PreviewServerEntry, PreviewServerStorage};
-use crate::types::{DevServerStatus, ServerSource};
+use crate::types::{DevServerStatus, ResourceSample, ServerSource};
 
 /// Entry in the central server registry.
 ///
@@ -109,6 +113,8 @@ pub struct ServerRegistry {
     storage: PreviewServerStorage,
     /// Timeout in minutes before considering an entry stale (default: 5, configurable via ORKEE_STALE_TIMEOUT_MINUTES)
     stale_timeout_minutes: i64,
+    /// Rolling CPU/memory samples per project, kept in memory only
+    metrics: Arc<RwLock<HashMap<String, VecDeque<ResourceSample>>>>,
 }
 
 impl ServerRegistry {
@@ -138,6 +144,7 @@ impl ServerRegistry {
         Ok(Self {
             storage,
             stale_timeout_minutes,
+            metrics: Arc::new(RwLock::new(HashMap::new())),
         })
     }
 
@@ -163,6 +170,7 @@ impl ServerRegistry {
         Self {
             storage,
             stale_timeout_minutes,
+            metrics: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -304,6 +312,11 @@ impl ServerRegistry {
         Ok(())
     }
 
+    /// The database pool backing the registry, for reading related settings.
+    pub fn pool(&self) -> &sqlx::SqlitePool {
+        self.storage.pool()
+    }
+
     /// Get the stale timeout in minutes.
     ///
     /// This is the amount of time after which a server entry is considered stale
@@ -317,6 +330,50 @@ impl ServerRegistry {
         self.stale_timeout_minutes
     }
 
+    /// Record a resource usage sample for a project's server.
+    ///
+    /// Only the most recent `MAX_METRICS_SAMPLES` samples are kept per project.
+    pub async fn record_metrics(&self, project_id: &str, sample: ResourceSample) {
+        let mut metrics = self.metrics.write().await;
+        let samples = metrics.entry(project_id.to_string()).or_default();
+        samples.push_back(sample);
+        while samples.len() > MAX_METRICS_SAMPLES {
+            samples.pop_front();
+        }
+    }
+
+    /// Drop samples for projects that no longer have a sampled server.
+    pub async fn retain_metrics(&self, project_ids: &HashSet<String>) {
+        self.metrics
+            .write()
+            .await
+            .retain(|project_id, _| project_ids.contains(project_id));
+    }
+
+    /// Get a project's resource usage history, oldest sample first.
+    pub async fn get_metrics(&self, project_id: &str) -> Vec<ResourceSample> {
+        self.metrics
+            .read()
+            .await
+            .get(project_id)
+            .map(|samples| samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the most recent sample for every project with recorded metrics.
+    pub async fn latest_metrics(&self) -> HashMap<String, ResourceSample> {
+        self.metrics
+            .read()
+            .await
+            .iter()
+            .filter_map(|(project_id, samples)| {
+                samples
+                    .back()
+                    .map(|sample| (project_id.clone(), sample.clone()))
+            })
+            .collect()
+    }
+
     /// Clean up stale entries from the registry.
     ///
     /// Removes entries that haven't been seen within the stale timeout period
@@ -324,8 +381,8 @@ impl ServerRegistry {
     ///
     /// # Returns
     ///
-    /// Returns `Ok(())` on success, or an error if the operation fails.
-    pub async fn cleanup_stale_entries(&self) -> Result<(), Box<dyn std::error::Error>> {
+    /// Returns the number of entries removed, or an error if the operation fails.
+    pub async fn cleanup_stale_entries(&self) -> Result<usize, Box<dyn std::error::Error>> {
         let now = Utc::now();
         let stale_threshold = now - chrono::Duration::minutes(self.stale_timeout_minutes);
 
@@ -376,7 +433,7 @@ impl ServerRegistry {
             debug!("Cleanup complete: no stale entries found");
         }
 
-        Ok(())
+        Ok(removed_count)
     }
 }
 
@@ -487,6 +544,7 @@ mod tests {
             provider: orkee_storage::StorageProvider::Sqlite { path: db_path },
             max_connections: 5,
             busy_timeout_seconds: 30,
+            read_replica: None,
             enable_wal: false,
             enable_fts: true,
         };
@@ -557,4 +615,33 @@ mod tests {
         let after_delete = registry.get_server("test-123").await;
         assert!(after_delete.is_none());
     }
+
+    #[tokio::test]
+    async fn test_metrics_history_is_bounded() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let registry = ServerRegistry::from_storage(PreviewServerStorage::from_pool(pool));
+
+        for i in 0..MAX_METRICS_SAMPLES + 10 {
+            let sample = ResourceSample {
+                timestamp: Utc::now(),
+                cpu_percent: 1.0,
+                memory_bytes: i as u64,
+            };
+            registry.record_metrics("project-1", sample).await;
+        }
+
+        let history = registry.get_metrics("project-1").await;
+        assert_eq!(history.len(), MAX_METRICS_SAMPLES);
+        assert_eq!(history[0].memory_bytes, 10);
+
+        let latest = registry.latest_metrics().await;
+        assert_eq!(
+            latest["project-1"].memory_bytes,
+            (MAX_METRICS_SAMPLES + 9) as u64
+        );
+
+        // Servers that are no longer sampled lose their history
+        registry.retain_metrics(&HashSet::new()).await;
+        assert!(registry.get_metrics("project-1").await.is_empty());
+    }
 }
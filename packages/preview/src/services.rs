@@ -0,0 +1,254 @@
+// ABOUTME: Service groups for projects that need several processes, e.g. a database and API before the frontend
+// ABOUTME: Loads orkee.services.json, orders services by their dependencies and aggregates their status
+
+use crate::types::{DevServerStatus, PreviewError, PreviewResult, ServiceConfig};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tokio::fs;
+
+/// File in a project root declaring its service group
+pub const SERVICES_FILE: &str = "orkee.services.json";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ServicesFile {
+    services: Vec<ServiceConfig>,
+}
+
+/// Load a project's service group in startup order.
+///
+/// Returns `Ok(None)` when the project has no `orkee.services.json` or it
+/// declares no services, in which case the dev server is detected as usual.
+pub async fn load_service_group(project_root: &Path) -> PreviewResult<Option<Vec<ServiceConfig>>> {
+    let path = project_root.join(SERVICES_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path).await?;
+    let file: ServicesFile =
+        serde_json::from_str(&content).map_err(|e| PreviewError::InvalidServiceGroup {
+            reason: format!("{}: {}", SERVICES_FILE, e),
+        })?;
+    if file.services.is_empty() {
+        return Ok(None);
+    }
+
+    startup_order(&file.services).map(Some)
+}
+
+/// Order services so each one starts after everything it depends on.
+///
+/// Services without a dependency between them keep their declared order. The
+/// last service is the one that gets previewed.
+pub fn startup_order(services: &[ServiceConfig]) -> PreviewResult<Vec<ServiceConfig>> {
+    let mut names = HashSet::new();
+    for service in services {
+        if service.name.trim().is_empty() {
+            return Err(PreviewError::InvalidServiceGroup {
+                reason: "every service needs a name".to_string(),
+            });
+        }
+        if !names.insert(service.name.as_str()) {
+            return Err(PreviewError::InvalidServiceGroup {
+                reason: format!("service '{}' is declared more than once", service.name),
+            });
+        }
+    }
+    for service in services {
+        if let Some(missing) = service
+            .depends_on
+            .iter()
+            .find(|dep| !names.contains(dep.as_str()))
+        {
+            return Err(PreviewError::InvalidServiceGroup {
+                reason: format!(
+                    "service '{}' depends on unknown service '{}'",
+                    service.name, missing
+                ),
+            });
+        }
+    }
+
+    let mut ordered: Vec<ServiceConfig> = Vec::with_capacity(services.len());
+    let mut started: HashSet<&str> = HashSet::new();
+    while ordered.len() < services.len() {
+        // Take the first declared service whose dependencies have all started
+        let next = services.iter().find(|service| {
+            !started.contains(service.name.as_str())
+                && service
+                    .depends_on
+                    .iter()
+                    .all(|dep| started.contains(dep.as_str()))
+        });
+
+        match next {
+            Some(service) => {
+                started.insert(service.name.as_str());
+                ordered.push(service.clone());
+            }
+            None => {
+                let mut waiting: Vec<&str> = services
+                    .iter()
+                    .map(|service| service.name.as_str())
+                    .filter(|name| !started.contains(name))
+                    .collect();
+                waiting.sort_unstable();
+                return Err(PreviewError::InvalidServiceGroup {
+                    reason: format!("dependency cycle between {}", waiting.join(", ")),
+                });
+            }
+        }
+    }
+
+    Ok(ordered)
+}
+
+/// Environment variables telling a service where the services started before it listen.
+///
+/// A service named `api` on port 4000 becomes `API_PORT=4000`.
+pub fn dependency_environment(started: &[(String, Option<u16>)]) -> HashMap<String, String> {
+    started
+        .iter()
+        .filter_map(|(name, port)| port.map(|port| (port_variable(name), port.to_string())))
+        .collect()
+}
+
+fn port_variable(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("{}_PORT", name)
+}
+
+/// Status of a service group as a whole.
+///
+/// A failed service fails the group, and the group is only running once every
+/// service is.
+pub fn aggregate_status<'a>(
+    statuses: impl IntoIterator<Item = &'a DevServerStatus>,
+) -> DevServerStatus {
+    let statuses: Vec<&DevServerStatus> = statuses.into_iter().collect();
+    let any = |status: DevServerStatus| statuses.iter().any(|s| **s == status);
+
+    if statuses.is_empty() {
+        DevServerStatus::Stopped
+    } else if any(DevServerStatus::Error) {
+        DevServerStatus::Error
+    } else if any(DevServerStatus::Stopping) {
+        DevServerStatus::Stopping
+    } else if any(DevServerStatus::Starting) {
+        DevServerStatus::Starting
+    } else if statuses.iter().all(|s| **s == DevServerStatus::Running) {
+        DevServerStatus::Running
+    } else {
+        DevServerStatus::Stopped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(name: &str, depends_on: &[&str]) -> ServiceConfig {
+        ServiceConfig {
+            name: name.to_string(),
+            command: format!("run-{}", name),
+            port: None,
+            depends_on: depends_on.iter().map(|dep| dep.to_string()).collect(),
+        }
+    }
+
+    fn names(services: &[ServiceConfig]) -> Vec<&str> {
+        services.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_startup_order_follows_dependencies() {
+        let services = vec![
+            service("web", &["api"]),
+            service("api", &["db", "cache"]),
+            service("db", &[]),
+            service("cache", &[]),
+        ];
+
+        let ordered = startup_order(&services).unwrap();
+        assert_eq!(names(&ordered), vec!["db", "cache", "api", "web"]);
+    }
+
+    #[test]
+    fn test_independent_services_keep_declared_order() {
+        let services = vec![service("worker", &[]), service("web", &[])];
+        assert_eq!(
+            names(&startup_order(&services).unwrap()),
+            vec!["worker", "web"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_groups_are_rejected() {
+        let cycle = vec![
+            service("web", &["api"]),
+            service("api", &["web"]),
+            service("db", &[]),
+        ];
+        let err = startup_order(&cycle).unwrap_err().to_string();
+        assert!(err.contains("dependency cycle between api, web"), "{}", err);
+
+        let unknown = vec![service("web", &["api"])];
+        let err = startup_order(&unknown).unwrap_err().to_string();
+        assert!(err.contains("unknown service 'api'"), "{}", err);
+
+        let duplicate = vec![service("web", &[]), service("web", &[])];
+        assert!(startup_order(&duplicate).is_err());
+    }
+
+    #[test]
+    fn test_dependency_environment() {
+        let env = dependency_environment(&[
+            ("db".to_string(), Some(5432)),
+            ("auth-api".to_string(), Some(4000)),
+            ("worker".to_string(), None),
+        ]);
+        assert_eq!(env.len(), 2);
+        assert_eq!(env["DB_PORT"], "5432");
+        assert_eq!(env["AUTH_API_PORT"], "4000");
+    }
+
+    #[test]
+    fn test_aggregate_status() {
+        use DevServerStatus::*;
+        assert_eq!(aggregate_status(&[Running, Running]), Running);
+        assert_eq!(aggregate_status(&[Running, Starting]), Starting);
+        assert_eq!(aggregate_status(&[Starting, Error]), Error);
+        assert_eq!(aggregate_status(&[Running, Stopping]), Stopping);
+        assert_eq!(aggregate_status(&[Running, Stopped]), Stopped);
+        assert_eq!(aggregate_status(&[]), Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_load_service_group() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(load_service_group(dir.path()).await.unwrap().is_none());
+
+        std::fs::write(
+            dir.path().join(SERVICES_FILE),
+            r#"{"services": [
+                {"name": "web", "command": "npm run dev", "depends_on": ["api"]},
+                {"name": "api", "command": "cargo run", "port": 4000}
+            ]}"#,
+        )
+        .unwrap();
+        let group = load_service_group(dir.path()).await.unwrap().unwrap();
+        assert_eq!(names(&group), vec!["api", "web"]);
+        assert_eq!(group[0].port, Some(4000));
+    }
+}
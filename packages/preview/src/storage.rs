@@ -49,6 +49,11 @@ impl PreviewServerStorage {
         Self { pool }
     }
 
+    /// The underlying database pool
+    pub fn pool(&self) -> &Pool<Sqlite> {
+        &self.pool
+    }
+
     /// Insert a new preview server entry
     pub async fn insert(&self, entry: &PreviewServerEntry) -> Result<()> {
         let status_str = self.status_to_string(&entry.status);
@@ -388,6 +393,7 @@ mod tests {
             },
             max_connections: 5,
             busy_timeout_seconds: 30,
+            read_replica: None,
             enable_wal: false, // WAL doesn't work well with temporary files
             enable_fts: true,
         };
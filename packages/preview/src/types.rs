@@ -78,7 +78,9 @@ impl ServerSource {
 #[serde(rename_all = "lowercase")]
 pub enum DevServerStatus {
     Stopped,
+    /// Process spawned but not yet ready to serve requests
     Starting,
+    /// Ready to serve requests
     Running,
     Stopping,
     Error,
@@ -111,6 +113,23 @@ pub struct DevServerConfig {
     pub port: u16,
     pub package_manager: PackageManager,
     pub framework: Option<Framework>,
+    /// Services started together with this server, in startup order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<ServiceConfig>,
+}
+
+/// A service in a project's service group, declared in `orkee.services.json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceConfig {
+    pub name: String,
+    /// Shell command that starts the service
+    pub command: String,
+    /// Port the service listens on, used to probe readiness and passed as `PORT`
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// Services that must be ready before this one starts
+    #[serde(default)]
+    pub depends_on: Vec<String>,
 }
 
 /// Development server instance
@@ -214,6 +233,18 @@ pub enum PreviewError {
     #[error("Failed to kill process with PID {pid}: {error}")]
     ProcessKillError { pid: u32, error: String },
 
+    #[error("Invalid service group: {reason}")]
+    InvalidServiceGroup { reason: String },
+
+    #[error("Service '{service}' did not become ready: {reason}")]
+    ServiceNotReady { service: String, reason: String },
+
+    #[error("No LAN address found; connect to a network to open previews from other devices")]
+    NoLanAddress,
+
+    #[error("Failed to render QR code: {reason}")]
+    QrCodeFailed { reason: String },
+
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
@@ -289,6 +320,21 @@ pub struct ServerStatusInfo {
     pub framework_name: Option<String>,
     pub started_at: Option<DateTime<Utc>>,
     pub source: ServerSource,
+    /// Most recent CPU/memory sample for servers managed by Orkee
+    pub resource_usage: Option<ResourceSample>,
+    /// Status of each service for projects with a service group, in startup order.
+    /// `status` is then the aggregate across these services.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<ServiceStatusInfo>,
+}
+
+/// Status of one service in a project's service group
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceStatusInfo {
+    pub name: String,
+    pub port: Option<u16>,
+    pub pid: Option<u32>,
+    pub status: DevServerStatus,
 }
 
 /// Response containing list of servers
@@ -297,6 +343,23 @@ pub struct ServersResponse {
     pub servers: Vec<ServerStatusInfo>,
 }
 
+/// CPU and memory usage of a server's process tree at a point in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceSample {
+    pub timestamp: DateTime<Utc>,
+    /// Summed across processes, so it can exceed 100 on multi-core machines
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+}
+
+/// Rolling resource usage history for a server, oldest sample first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerMetricsResponse {
+    pub project_id: String,
+    pub interval_secs: u64,
+    pub samples: Vec<ResourceSample>,
+}
+
 /// Server events for real-time updates via SSE
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -307,6 +370,11 @@ pub enum ServerEvent {
         port: u16,
         framework: Option<String>,
     },
+    /// A started server finished starting up and is accepting requests
+    ServerReady {
+        project_id: String,
+        port: u16,
+    },
     ServerStopped {
         project_id: String,
     },
@@ -20,6 +20,7 @@ async fn setup_test_storage() -> (SqliteStorage, TempDir) {
         },
         max_connections: 5,
         busy_timeout_seconds: 30,
+        read_replica: None,
         enable_wal: false, // WAL doesn't work well with temporary files
         enable_fts: true,
     };
@@ -3,19 +3,49 @@
 
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 use std::sync::Arc;
-use tracing::{debug, info};
+use tracing::{debug, error, info, warn};
 
-use orkee_agents::UserAgentStorage;
-use orkee_ai::AiUsageLogStorage;
+use orkee_agents::{ToolPolicyStorage, UserAgentStorage};
+use orkee_ai::{AiDebugLogStorage, AiUsageLogStorage};
 use orkee_executions::ExecutionStorage;
+use orkee_notifications::NotificationDispatcher;
 use orkee_sandbox::SettingsManager as SandboxSettingsManager;
+use orkee_scheduler::Scheduler;
 use orkee_security::api_tokens::TokenStorage;
-use orkee_security::UserStorage;
+use orkee_security::{IntrusionDetector, SecretStorage, UserStorage};
 use orkee_settings::SettingsStorage;
+use orkee_storage::change_journal::ChangeJournal;
 use orkee_storage::model_preferences::ModelPreferencesStorage;
+use orkee_storage::search::SearchStorage;
+use orkee_storage::view_preferences::ViewPreferencesStorage;
 use orkee_storage::StorageError;
 use orkee_tags::TagStorage;
+use orkee_task_sources::{TaskImporter, TaskmasterSync};
 use orkee_tasks::storage::TaskStorage;
+use orkee_webhooks::WebhookReceiver;
+
+use crate::scripts::ScriptRunner;
+
+/// Install the cipher that seals sensitive columns at rest, keyed for the
+/// configured encryption mode. In password mode without the password, sensitive
+/// fields are locked; otherwise new values are written in plaintext until
+/// `orkee security encrypt-fields` runs.
+pub(crate) async fn install_field_cipher(pool: &SqlitePool) {
+    if let Err(e) = orkee_security::encryption::install_field_cipher(pool).await {
+        if orkee_storage::field_encryption::is_locked() {
+            error!(
+                "Field encryption is locked; project scripts, MCP env values and OAuth metadata cannot be read or saved: {}. Set {} to the encryption password.",
+                e,
+                orkee_security::encryption::PASSWORD_ENV_VAR
+            );
+        } else {
+            warn!(
+                "Field encryption unavailable, storing sensitive fields unencrypted: {}",
+                e
+            );
+        }
+    }
+}
 
 /// Shared database state for API handlers
 #[derive(Clone)]
@@ -23,15 +53,29 @@ pub struct DbState {
     pub pool: SqlitePool,
     pub task_storage: Arc<TaskStorage>,
     pub agent_storage: Arc<UserAgentStorage>,
+    pub tool_policy_storage: Arc<ToolPolicyStorage>,
     pub user_storage: Arc<UserStorage>,
+    pub secret_storage: Arc<SecretStorage>,
     pub tag_storage: Arc<TagStorage>,
     pub execution_storage: Arc<ExecutionStorage>,
     pub ai_usage_log_storage: Arc<AiUsageLogStorage>,
+    pub ai_debug_log_storage: Arc<AiDebugLogStorage>,
     pub settings_storage: Arc<SettingsStorage>,
     pub token_storage: Arc<TokenStorage>,
+    pub intrusion_detector: Arc<IntrusionDetector>,
     pub model_preferences_storage: Arc<ModelPreferencesStorage>,
+    pub view_preferences_storage: Arc<ViewPreferencesStorage>,
+    pub search_storage: Arc<SearchStorage>,
+    pub change_journal: Arc<ChangeJournal>,
     pub sandbox_settings: Arc<SandboxSettingsManager>,
     pub sandbox_manager: Arc<orkee_sandbox::SandboxManager>,
+    pub log_retention: Arc<orkee_sandbox::LogRetention>,
+    pub notifications: Arc<NotificationDispatcher>,
+    pub webhooks: Arc<WebhookReceiver>,
+    pub scheduler: Arc<Scheduler>,
+    pub task_sources: Arc<TaskImporter>,
+    pub taskmaster_sync: Arc<TaskmasterSync>,
+    pub script_runner: Arc<ScriptRunner>,
 }
 
 impl DbState {
@@ -39,14 +83,27 @@ impl DbState {
     pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
         let task_storage = Arc::new(TaskStorage::new(pool.clone()));
         let agent_storage = Arc::new(UserAgentStorage::new(pool.clone()));
+        let tool_policy_storage = Arc::new(ToolPolicyStorage::new(pool.clone()));
         let user_storage = Arc::new(UserStorage::new(pool.clone())?);
+        let secret_storage = Arc::new(SecretStorage::new(pool.clone())?);
         let tag_storage = Arc::new(TagStorage::new(pool.clone()));
         let execution_storage = Arc::new(ExecutionStorage::new(pool.clone()));
         let ai_usage_log_storage = Arc::new(AiUsageLogStorage::new(pool.clone()));
+        let ai_debug_log_storage = Arc::new(AiDebugLogStorage::new(pool.clone()));
         let settings_storage = Arc::new(SettingsStorage::new(pool.clone()));
         let token_storage = Arc::new(TokenStorage::new(pool.clone()));
+        let intrusion_detector = Arc::new(IntrusionDetector::new(pool.clone()));
         let model_preferences_storage = Arc::new(ModelPreferencesStorage::new(pool.clone()));
+        let view_preferences_storage = Arc::new(ViewPreferencesStorage::new(pool.clone()));
+        let search_storage = Arc::new(SearchStorage::new(pool.clone()));
+        let change_journal = Arc::new(ChangeJournal::new(pool.clone()));
         let sandbox_settings = Arc::new(SandboxSettingsManager::new(pool.clone())?);
+        let notifications = Arc::new(NotificationDispatcher::new(pool.clone()));
+        let webhooks = Arc::new(WebhookReceiver::new(pool.clone())?);
+        let scheduler = Arc::new(Scheduler::new(pool.clone()));
+        let task_sources = Arc::new(TaskImporter::new(pool.clone())?);
+        let taskmaster_sync = Arc::new(TaskmasterSync::new(pool.clone()));
+        let script_runner = Arc::new(ScriptRunner::new(pool.clone()));
 
         // Initialize sandbox manager
         let sandbox_storage = Arc::new(orkee_sandbox::SandboxStorage::new(pool.clone()));
@@ -58,6 +115,12 @@ impl DbState {
             )),
         ));
 
+        // Expired execution logs are archived under ~/.orkee/archives
+        let log_retention = Arc::new(orkee_sandbox::LogRetention::new(
+            pool.clone(),
+            orkee_core::orkee_dir().join("archives"),
+        ));
+
         // Register Docker provider if available
         match orkee_sandbox::DockerProvider::new() {
             Ok(provider) => {
@@ -90,15 +153,29 @@ impl DbState {
             pool,
             task_storage,
             agent_storage,
+            tool_policy_storage,
             user_storage,
+            secret_storage,
             tag_storage,
             execution_storage,
             ai_usage_log_storage,
+            ai_debug_log_storage,
             settings_storage,
             token_storage,
+            intrusion_detector,
             model_preferences_storage,
+            view_preferences_storage,
+            search_storage,
+            change_journal,
             sandbox_settings,
             sandbox_manager,
+            log_retention,
+            notifications,
+            webhooks,
+            scheduler,
+            task_sources,
+            taskmaster_sync,
+            script_runner,
         })
     }
 
@@ -149,14 +226,16 @@ impl DbState {
 
         info!("Database connection established");
 
-        // Run migrations
-        sqlx::migrate!("../storage/migrations")
-            .run(&pool)
-            .await
-            .map_err(StorageError::Migration)?;
+        // Run migrations, snapshotting the database first if any are pending
+        orkee_storage::migration_backup::run_migrations_with_backup(
+            &pool,
+            &sqlx::migrate!("../storage/migrations"),
+        )
+        .await?;
 
         debug!("Database migrations completed");
 
+        install_field_cipher(&pool).await;
         Self::new(pool)
     }
 
@@ -247,6 +326,14 @@ impl DbState {
         .await
         .map_err(StorageError::Sqlx)?;
 
+        // Re-encrypt sensitive fields (scripts, MCP env values, OAuth metadata)
+        orkee_storage::field_encryption::rotate_existing_rows(
+            &mut tx,
+            old_encryption,
+            new_encryption,
+        )
+        .await?;
+
         // Step 2: Update encryption settings (inline to share transaction)
         let mode_str = mode.to_string();
 
@@ -0,0 +1,178 @@
+// ABOUTME: Detection of installed editors and launching them at a target
+// ABOUTME: Looks for editor launchers on PATH and macOS application bundles
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+use tracing::{debug, info};
+
+use super::registry::{EditorDefinition, EditorFamily, EditorTarget, EDITORS};
+use super::EditorError;
+
+/// A supported editor and whether it is installed on this machine
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetectedEditor {
+    pub id: String,
+    pub name: String,
+    pub family: EditorFamily,
+    pub installed: bool,
+    /// Launcher found on PATH, if any
+    pub command: Option<String>,
+    pub supports_deep_link: bool,
+}
+
+/// Result of opening an editor
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorLaunch {
+    pub editor_id: String,
+    pub message: String,
+    pub command: String,
+    /// URL the dashboard can open itself when the editor runs on the viewer's machine
+    pub deep_link: Option<String>,
+}
+
+/// Check every supported editor for an installed launcher or application
+pub fn detect_editors() -> Vec<DetectedEditor> {
+    let path = env::var_os("PATH").unwrap_or_default();
+    let search_dirs: Vec<PathBuf> = env::split_paths(&path).collect();
+
+    EDITORS
+        .iter()
+        .map(|editor| {
+            let command = find_command(editor, &search_dirs);
+            let installed = command.is_some() || mac_app_path(editor).is_some();
+            DetectedEditor {
+                id: editor.id.to_string(),
+                name: editor.name.to_string(),
+                family: editor.family,
+                installed,
+                command: command.map(|c| c.display().to_string()),
+                supports_deep_link: editor.url_scheme.is_some(),
+            }
+        })
+        .collect()
+}
+
+/// Open the target in an editor. The launcher on PATH is preferred because
+/// it supports jumping to a line; the macOS bundle fallback only opens the file.
+pub fn open_in_editor(
+    editor: &EditorDefinition,
+    target: &EditorTarget,
+) -> Result<EditorLaunch, EditorError> {
+    if !target.project_root.exists() {
+        return Err(EditorError::InvalidPath(
+            target.project_root.display().to_string(),
+        ));
+    }
+
+    let path = env::var_os("PATH").unwrap_or_default();
+    let search_dirs: Vec<PathBuf> = env::split_paths(&path).collect();
+
+    let (program, args) = if let Some(command) = find_command(editor, &search_dirs) {
+        (command, editor.launch_args(target))
+    } else if let Some(app) = mac_app_path(editor) {
+        let opened = target.file.as_ref().unwrap_or(&target.project_root);
+        (
+            PathBuf::from("open"),
+            vec![
+                "-a".to_string(),
+                app.display().to_string(),
+                opened.display().to_string(),
+            ],
+        )
+    } else {
+        return Err(EditorError::NotInstalled(editor.name.to_string()));
+    };
+
+    debug!("Launching {} with {:?}", program.display(), args);
+    Command::new(&program)
+        .args(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| EditorError::Launch(format!("{}: {}", program.display(), e)))?;
+
+    let opened = target.file.as_ref().unwrap_or(&target.project_root);
+    info!("Opened {} in {}", opened.display(), editor.name);
+    Ok(EditorLaunch {
+        editor_id: editor.id.to_string(),
+        message: format!("Opened {} in {}", opened.display(), editor.name),
+        command: program.display().to_string(),
+        deep_link: editor.deep_link(target),
+    })
+}
+
+/// First of the editor's launchers found in the search directories
+fn find_command(editor: &EditorDefinition, search_dirs: &[PathBuf]) -> Option<PathBuf> {
+    let extensions: &[&str] = if cfg!(windows) {
+        &["exe", "cmd", "bat"]
+    } else {
+        &[""]
+    };
+
+    editor.commands.iter().find_map(|command| {
+        search_dirs.iter().find_map(|dir| {
+            extensions
+                .iter()
+                .map(|ext| dir.join(command).with_extension(ext))
+                .find(|candidate| is_executable(candidate))
+        })
+    })
+}
+
+/// Installed macOS application bundle for the editor, in /Applications or ~/Applications
+fn mac_app_path(editor: &EditorDefinition) -> Option<PathBuf> {
+    if !cfg!(target_os = "macos") {
+        return None;
+    }
+    let bundle = format!("{}.app", editor.mac_app?);
+    let mut roots = vec![PathBuf::from("/Applications")];
+    if let Some(home) = dirs::home_dir() {
+        roots.push(home.join("Applications"));
+    }
+    roots
+        .into_iter()
+        .map(|root| root.join(&bundle))
+        .find(|path| path.is_dir())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::editors::find_editor;
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    #[test]
+    fn test_find_command_requires_executable_on_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let zed = find_editor("zed").unwrap();
+        let search_dirs = vec![dir.path().to_path_buf()];
+        assert_eq!(find_command(zed, &search_dirs), None);
+
+        let launcher = dir.path().join("zeditor");
+        fs::write(&launcher, "#!/bin/sh\n").unwrap();
+        assert_eq!(find_command(zed, &search_dirs), None);
+
+        fs::set_permissions(&launcher, fs::Permissions::from_mode(0o755)).unwrap();
+        assert_eq!(find_command(zed, &search_dirs), Some(launcher));
+    }
+}
@@ -0,0 +1,33 @@
+// ABOUTME: Editor integrations for opening projects and files from Orkee
+// ABOUTME: Supports VS Code, Cursor, Zed, and JetBrains IDEs with per-user and per-project preferences
+
+pub mod launch;
+pub mod preferences;
+pub mod registry;
+
+use orkee_storage::StorageError;
+use thiserror::Error;
+
+pub use launch::{detect_editors, open_in_editor, DetectedEditor, EditorLaunch};
+pub use preferences::{EditorPreferences, PREFERRED_EDITOR_KEY, PROJECT_OVERRIDES_KEY};
+pub use registry::{
+    find_editor, EditorDefinition, EditorFamily, EditorTarget, DEFAULT_EDITOR, EDITORS,
+};
+
+#[derive(Debug, Error)]
+pub enum EditorError {
+    #[error("Unsupported editor: {0}")]
+    UnknownEditor(String),
+
+    #[error("{0} is not installed or its command-line launcher is not on PATH")]
+    NotInstalled(String),
+
+    #[error("Invalid path: {0}")]
+    InvalidPath(String),
+
+    #[error("Failed to launch editor: {0}")]
+    Launch(String),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
@@ -0,0 +1,124 @@
+// ABOUTME: Preferred editor and per-project editor overrides stored in system settings
+// ABOUTME: Resolves which editor opens a project: explicit choice, project override, user preference, default
+
+use std::collections::BTreeMap;
+
+use orkee_settings::{SettingUpdate, SettingsStorage};
+use orkee_storage::StorageError;
+use serde::Serialize;
+
+use super::registry::{find_editor, DEFAULT_EDITOR};
+use super::EditorError;
+
+/// Setting holding the user's preferred editor ID
+pub const PREFERRED_EDITOR_KEY: &str = "preferred_editor";
+
+/// Setting holding a JSON object of project ID -> editor ID
+pub const PROJECT_OVERRIDES_KEY: &str = "editor_project_overrides";
+
+const EDITOR_CATEGORY: &str = "editor";
+
+/// The user's editor choices
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EditorPreferences {
+    pub preferred_editor: String,
+    pub project_overrides: BTreeMap<String, String>,
+}
+
+impl Default for EditorPreferences {
+    fn default() -> Self {
+        Self {
+            preferred_editor: DEFAULT_EDITOR.to_string(),
+            project_overrides: BTreeMap::new(),
+        }
+    }
+}
+
+impl EditorPreferences {
+    /// Load the editor settings, using defaults for missing or unparsable values
+    pub async fn load(settings: &SettingsStorage) -> Result<Self, StorageError> {
+        let mut preferences = Self::default();
+        for setting in settings.get_by_category(EDITOR_CATEGORY).await? {
+            match setting.key.as_str() {
+                PREFERRED_EDITOR_KEY if !setting.value.is_empty() => {
+                    preferences.preferred_editor = setting.value;
+                }
+                PROJECT_OVERRIDES_KEY => {
+                    preferences.project_overrides =
+                        serde_json::from_str(&setting.value).unwrap_or_default();
+                }
+                _ => {}
+            }
+        }
+        Ok(preferences)
+    }
+
+    /// Editor ID for a project: an explicit choice wins, then the project's
+    /// override, then the user's preferred editor
+    pub fn editor_for<'a>(
+        &'a self,
+        project_id: Option<&str>,
+        explicit: Option<&'a str>,
+    ) -> &'a str {
+        explicit
+            .or_else(|| {
+                project_id
+                    .and_then(|id| self.project_overrides.get(id))
+                    .map(String::as_str)
+            })
+            .unwrap_or(&self.preferred_editor)
+    }
+
+    /// Set or clear (with `None`) the editor override for a project
+    pub async fn set_project_override(
+        settings: &SettingsStorage,
+        project_id: &str,
+        editor_id: Option<&str>,
+    ) -> Result<Self, EditorError> {
+        let mut preferences = Self::load(settings).await?;
+        match editor_id {
+            Some(editor_id) => {
+                find_editor(editor_id)?;
+                preferences
+                    .project_overrides
+                    .insert(project_id.to_string(), editor_id.to_string());
+            }
+            None => {
+                preferences.project_overrides.remove(project_id);
+            }
+        }
+
+        let value =
+            serde_json::to_string(&preferences.project_overrides).map_err(StorageError::Json)?;
+        settings
+            .update(PROJECT_OVERRIDES_KEY, SettingUpdate { value }, "user")
+            .await?;
+        Ok(preferences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_editor_resolution_order() {
+        let mut preferences = EditorPreferences {
+            preferred_editor: "zed".to_string(),
+            ..Default::default()
+        };
+        preferences
+            .project_overrides
+            .insert("p1".to_string(), "goland".to_string());
+
+        assert_eq!(preferences.editor_for(Some("p1"), Some("cursor")), "cursor");
+        assert_eq!(preferences.editor_for(Some("p1"), None), "goland");
+        assert_eq!(preferences.editor_for(Some("p2"), None), "zed");
+        assert_eq!(preferences.editor_for(None, None), "zed");
+        assert_eq!(
+            EditorPreferences::default().editor_for(None, None),
+            "vscode"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+// ABOUTME: Supported editor definitions and their command-line conventions
+// ABOUTME: Builds launch arguments and deep links for opening a project or a file at a line
+
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::EditorError;
+
+/// Editors sharing a command-line convention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EditorFamily {
+    /// VS Code and its forks: `code <root> --goto file:line:column`
+    VsCode,
+    /// `zed <root> file:line:column`
+    Zed,
+    /// JetBrains IDEs: `idea --line N --column C file`
+    JetBrains,
+}
+
+/// An editor Orkee knows how to launch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditorDefinition {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub family: EditorFamily,
+    /// Command-line launchers, in order of preference
+    pub commands: &'static [&'static str],
+    /// macOS application bundle name, used when no launcher is on PATH
+    pub mac_app: Option<&'static str>,
+    /// URL scheme accepting `<scheme>://file/<path>:<line>:<column>`
+    pub url_scheme: Option<&'static str>,
+}
+
+/// Editor used when neither the project nor the user has chosen one
+pub const DEFAULT_EDITOR: &str = "vscode";
+
+pub const EDITORS: &[EditorDefinition] = &[
+    EditorDefinition {
+        id: "vscode",
+        name: "Visual Studio Code",
+        family: EditorFamily::VsCode,
+        commands: &["code", "code-insiders"],
+        mac_app: Some("Visual Studio Code"),
+        url_scheme: Some("vscode"),
+    },
+    EditorDefinition {
+        id: "cursor",
+        name: "Cursor",
+        family: EditorFamily::VsCode,
+        commands: &["cursor"],
+        mac_app: Some("Cursor"),
+        url_scheme: Some("cursor"),
+    },
+    EditorDefinition {
+        id: "zed",
+        name: "Zed",
+        family: EditorFamily::Zed,
+        commands: &["zed", "zeditor"],
+        mac_app: Some("Zed"),
+        url_scheme: Some("zed"),
+    },
+    EditorDefinition {
+        id: "intellij",
+        name: "IntelliJ IDEA",
+        family: EditorFamily::JetBrains,
+        commands: &["idea"],
+        mac_app: Some("IntelliJ IDEA"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "webstorm",
+        name: "WebStorm",
+        family: EditorFamily::JetBrains,
+        commands: &["webstorm"],
+        mac_app: Some("WebStorm"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "pycharm",
+        name: "PyCharm",
+        family: EditorFamily::JetBrains,
+        commands: &["pycharm", "charm"],
+        mac_app: Some("PyCharm"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "goland",
+        name: "GoLand",
+        family: EditorFamily::JetBrains,
+        commands: &["goland"],
+        mac_app: Some("GoLand"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "rustrover",
+        name: "RustRover",
+        family: EditorFamily::JetBrains,
+        commands: &["rustrover"],
+        mac_app: Some("RustRover"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "rubymine",
+        name: "RubyMine",
+        family: EditorFamily::JetBrains,
+        commands: &["rubymine", "mine"],
+        mac_app: Some("RubyMine"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "phpstorm",
+        name: "PhpStorm",
+        family: EditorFamily::JetBrains,
+        commands: &["phpstorm"],
+        mac_app: Some("PhpStorm"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "clion",
+        name: "CLion",
+        family: EditorFamily::JetBrains,
+        commands: &["clion"],
+        mac_app: Some("CLion"),
+        url_scheme: None,
+    },
+    EditorDefinition {
+        id: "rider",
+        name: "Rider",
+        family: EditorFamily::JetBrains,
+        commands: &["rider"],
+        mac_app: Some("Rider"),
+        url_scheme: None,
+    },
+];
+
+/// Look up a supported editor by ID
+pub fn find_editor(id: &str) -> Result<&'static EditorDefinition, EditorError> {
+    EDITORS
+        .iter()
+        .find(|editor| editor.id == id)
+        .ok_or_else(|| EditorError::UnknownEditor(id.to_string()))
+}
+
+/// What to open: a project, optionally at a file and line within it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditorTarget {
+    pub project_root: PathBuf,
+    pub file: Option<PathBuf>,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+}
+
+impl EditorTarget {
+    /// Target a project, or a file inside it. Relative file paths are resolved
+    /// against the project root and may not escape it.
+    pub fn new(
+        project_root: impl Into<PathBuf>,
+        file: Option<&str>,
+        line: Option<u32>,
+        column: Option<u32>,
+    ) -> Result<Self, EditorError> {
+        let project_root = project_root.into();
+        let file = match file.filter(|f| !f.is_empty()) {
+            Some(file) => {
+                let path = Path::new(file);
+                let relative = path.strip_prefix(&project_root).unwrap_or(path);
+                if relative.is_absolute()
+                    || relative
+                        .components()
+                        .any(|c| matches!(c, Component::ParentDir | Component::Prefix(_)))
+                {
+                    return Err(EditorError::InvalidPath(file.to_string()));
+                }
+                Some(project_root.join(relative))
+            }
+            None => None,
+        };
+
+        Ok(Self {
+            project_root,
+            file,
+            line: line.filter(|l| *l > 0),
+            column: column.filter(|c| *c > 0),
+        })
+    }
+
+    /// `file:line:column`, omitting the parts that are not set
+    fn file_location(&self, file: &Path) -> String {
+        let mut location = file.display().to_string();
+        if let Some(line) = self.line {
+            location.push_str(&format!(":{}", line));
+            if let Some(column) = self.column {
+                location.push_str(&format!(":{}", column));
+            }
+        }
+        location
+    }
+}
+
+impl EditorDefinition {
+    /// Arguments for one of this editor's launchers
+    pub fn launch_args(&self, target: &EditorTarget) -> Vec<String> {
+        let root = target.project_root.display().to_string();
+        let Some(file) = &target.file else {
+            return vec![root];
+        };
+
+        match self.family {
+            EditorFamily::VsCode => vec![root, "--goto".to_string(), target.file_location(file)],
+            EditorFamily::Zed => vec![root, target.file_location(file)],
+            EditorFamily::JetBrains => {
+                let mut args = Vec::new();
+                if let Some(line) = target.line {
+                    args.extend(["--line".to_string(), line.to_string()]);
+                    if let Some(column) = target.column {
+                        args.extend(["--column".to_string(), column.to_string()]);
+                    }
+                }
+                args.push(file.display().to_string());
+                args
+            }
+        }
+    }
+
+    /// URL that opens the target through the editor's registered URL scheme
+    pub fn deep_link(&self, target: &EditorTarget) -> Option<String> {
+        let scheme = self.url_scheme?;
+        let location = match &target.file {
+            Some(file) => target.file_location(file),
+            None => target.project_root.display().to_string(),
+        };
+        // Windows paths need a leading slash after the authority
+        let separator = if location.starts_with('/') { "" } else { "/" };
+        Some(format!(
+            "{}://file{}{}",
+            scheme,
+            separator,
+            location.replace(' ', "%20")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(file: Option<&str>, line: Option<u32>, column: Option<u32>) -> EditorTarget {
+        EditorTarget::new("/work/app", file, line, column).unwrap()
+    }
+
+    #[test]
+    fn test_launch_args_per_family() {
+        let at_line = target(Some("src/main.rs"), Some(42), Some(7));
+
+        assert_eq!(
+            find_editor("cursor").unwrap().launch_args(&at_line),
+            vec!["/work/app", "--goto", "/work/app/src/main.rs:42:7"]
+        );
+        assert_eq!(
+            find_editor("zed").unwrap().launch_args(&at_line),
+            vec!["/work/app", "/work/app/src/main.rs:42:7"]
+        );
+        assert_eq!(
+            find_editor("goland").unwrap().launch_args(&at_line),
+            vec!["--line", "42", "--column", "7", "/work/app/src/main.rs"]
+        );
+
+        let project = target(None, None, None);
+        for editor in EDITORS {
+            assert_eq!(editor.launch_args(&project), vec!["/work/app"]);
+        }
+    }
+
+    #[test]
+    fn test_target_rejects_paths_outside_project() {
+        assert_eq!(
+            target(Some("/work/app/src/lib.rs"), Some(0), None),
+            target(Some("src/lib.rs"), None, None)
+        );
+        assert!(EditorTarget::new("/work/app", Some("../secrets"), None, None).is_err());
+        assert!(EditorTarget::new("/work/app", Some("/etc/passwd"), None, None).is_err());
+    }
+
+    #[test]
+    fn test_deep_links() {
+        let at_line = target(Some("src/my file.ts"), Some(3), None);
+        assert_eq!(
+            find_editor("vscode")
+                .unwrap()
+                .deep_link(&at_line)
+                .as_deref(),
+            Some("vscode://file/work/app/src/my%20file.ts:3")
+        );
+        assert_eq!(find_editor("rider").unwrap().deep_link(&at_line), None);
+        assert!(matches!(
+            find_editor("notepad"),
+            Err(EditorError::UnknownEditor(_))
+        ));
+    }
+}
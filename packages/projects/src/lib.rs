@@ -4,9 +4,16 @@
 //! for managing development projects with persistent storage.
 
 pub mod db;
+pub mod editors;
 pub mod manager;
+pub mod mcp;
+pub mod model_references;
+pub mod openspec;
 pub mod pagination;
 pub mod prd;
+pub mod readme;
+pub mod scripts;
+pub mod sync_queue;
 
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_utils;
@@ -21,7 +28,8 @@ pub use orkee_core::{
 pub use manager::{
     create_project, delete_project, export_database, get_all_projects, get_project,
     get_project_by_name, get_project_by_path, get_storage_manager, import_database,
-    initialize_storage, update_project, ManagerError, ManagerResult, ProjectsManager,
+    initialize_storage, update_project, validation_rules, ManagerError, ManagerResult,
+    ProjectsManager,
 };
 
 // Type alias for convenience
@@ -36,8 +44,12 @@ pub use orkee_storage::{
     read_projects_config,
     write_projects_config,
     CloudProvider,
+    ConnectionRouter,
+    PoolMetrics,
     ProjectFilter,
     ProjectStorage,
+    QueryRoute,
+    ReadReplicaConfig,
     StorageCapabilities,
     StorageConfig,
     StorageError,
@@ -47,13 +59,19 @@ pub use orkee_storage::{
 };
 
 // Re-export validator functions from core
-pub use orkee_core::{truncate, validate_project_data, validate_project_update, ValidationError};
+pub use orkee_core::{
+    truncate, validate_project_data, validate_project_update, ValidationError, ValidationReport,
+    ValidationRules,
+};
 
 // Re-export utility functions from core
 pub use orkee_core::generate_project_id;
 
 // Re-export formatter functions
-pub use orkee_formatter::{format_project_details, format_projects_table};
+pub use orkee_formatter::{
+    format_project, format_project_details, format_projects, format_projects_table, parse_columns,
+    OutputFormat,
+};
 
 // Re-export settings types for backward compatibility
 pub use orkee_settings::{
@@ -67,16 +85,19 @@ pub use orkee_core::{orkee_dir, projects_file, PROJECTS_VERSION};
 // Re-export database state
 pub use db::DbState;
 
+// Re-export catalog reference cleanup
+pub use model_references::clear_orphaned_model_references;
+
 // Re-export pagination types
-pub use pagination::{PaginatedResponse, PaginationMeta, PaginationParams};
+pub use pagination::{InvalidCursorError, PaginatedResponse, PaginationMeta, PaginationParams};
 
 // Re-export tags types
 pub use orkee_tags::{Tag, TagCreateInput, TagStorage, TagUpdateInput};
 
-// Re-export security types (API tokens, encryption, users)
+// Re-export security types (API tokens, encryption, intrusion detection, users)
 pub use orkee_security::{
-    ApiKeyEncryption, ApiToken, EncryptionError, MaskedUser, TokenGeneration, TokenStorage, User,
-    UserStorage, UserUpdateInput,
+    ApiKeyEncryption, ApiToken, AuthLockout, EncryptionError, IntrusionDetector, MaskedUser,
+    SecurityAlert, TokenGeneration, TokenStorage, User, UserStorage, UserUpdateInput,
 };
 
 // Re-export PRD types (used by API handlers and CCPM)
@@ -86,6 +107,18 @@ pub use prd::{
     PRDSource, PRDStatus, PRD,
 };
 
+// Re-export MCP server configuration types (used by API handlers)
+pub use mcp::{
+    DbError as McpDbError, McpExportFormat, McpHandshakeResult, McpServerConfig,
+    McpServerCreateInput, McpServerUpdateInput,
+};
+
+// Re-export offline sync queue types (used by API handlers and the CLI)
+pub use sync_queue::{
+    DbError as SyncQueueDbError, EnqueueInput, OperationExecutor, QueueStatus, QueueSummary,
+    QueuedOperation, ReplayOutcome, ReplaySummary, SyncOperationType,
+};
+
 // Re-export storage module (used by API handlers)
 pub use orkee_storage;
 
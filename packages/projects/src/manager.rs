@@ -1,7 +1,10 @@
 use orkee_core::types::{Project, ProjectCreateInput, ProjectStatus, ProjectUpdateInput};
-use orkee_core::{validate_project_data, validate_project_update, ValidationError};
+use orkee_core::validation::rules::RULE_SETTINGS;
+use orkee_core::{ValidationReport, ValidationRules};
 use orkee_git_utils::get_git_repository_info;
-use orkee_storage::{factory::StorageManager, StorageError};
+use orkee_settings::SettingsStorage;
+use orkee_storage::{factory::StorageManager, QueryRoute, StorageError};
+use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
 use tracing::{debug, info, warn};
@@ -11,8 +14,8 @@ use tracing::{debug, info, warn};
 pub enum ManagerError {
     #[error("Storage error: {0}")]
     Storage(#[from] StorageError),
-    #[error("Validation errors: {0:?}")]
-    Validation(Vec<ValidationError>),
+    #[error("Validation failed: {0}")]
+    Validation(ValidationReport),
     #[error("Project not found: {0}")]
     NotFound(String),
     #[error("Project with name '{0}' already exists")]
@@ -48,6 +51,9 @@ pub fn reset_storage_for_testing() {
 /// Initialize the global storage manager
 pub async fn initialize_storage() -> ManagerResult<()> {
     let storage_manager = Arc::new(StorageManager::default().await?);
+    if let Some(pool) = storage_manager.pool_for(QueryRoute::Primary) {
+        crate::db::install_field_cipher(&pool).await;
+    }
 
     #[cfg(any(test, feature = "test-utils"))]
     {
@@ -82,7 +88,7 @@ pub async fn initialize_storage() -> ManagerResult<()> {
 
 /// Initialize the global storage manager with a custom database path
 pub async fn initialize_storage_with_path(db_path: std::path::PathBuf) -> ManagerResult<()> {
-    use orkee_storage::{StorageConfig, StorageProvider};
+    use orkee_storage::{ReadReplicaConfig, StorageConfig, StorageProvider};
 
     let config = StorageConfig {
         provider: StorageProvider::Sqlite { path: db_path },
@@ -90,9 +96,13 @@ pub async fn initialize_storage_with_path(db_path: std::path::PathBuf) -> Manage
         enable_fts: true,
         max_connections: 5,
         busy_timeout_seconds: 10,
+        read_replica: Some(ReadReplicaConfig::default()),
     };
 
     let storage_manager = Arc::new(StorageManager::new(config).await?);
+    if let Some(pool) = storage_manager.pool_for(QueryRoute::Primary) {
+        crate::db::install_field_cipher(&pool).await;
+    }
 
     #[cfg(any(test, feature = "test-utils"))]
     {
@@ -240,15 +250,47 @@ pub async fn get_project_by_path(project_root: &str) -> ManagerResult<Option<Pro
     Ok(project)
 }
 
+/// The configurable project validation rules, for forms that check input
+/// before submitting it
+pub async fn validation_rules() -> ManagerResult<ValidationRules> {
+    let storage_manager = get_storage_manager().await?;
+    Ok(load_validation_rules(&storage_manager).await)
+}
+
+/// Load validation rules from system settings. Backends without SQL settings,
+/// and rules that fail to load, fall back to the built-in checks alone.
+async fn load_validation_rules(storage_manager: &StorageManager) -> ValidationRules {
+    let Some(pool) = storage_manager.pool_for(QueryRoute::Primary) else {
+        return ValidationRules::default();
+    };
+
+    let settings = SettingsStorage::new(pool);
+    let mut values = HashMap::new();
+    for key in RULE_SETTINGS {
+        if let Ok(setting) = settings.get(key).await {
+            values.insert(key.to_string(), setting.value);
+        }
+    }
+
+    ValidationRules::from_settings(&values).unwrap_or_else(|e| {
+        warn!("Ignoring invalid project validation settings: {}", e);
+        ValidationRules::default()
+    })
+}
+
 /// Creates a new project
 pub async fn create_project(data: ProjectCreateInput) -> ManagerResult<Project> {
+    let storage_manager = get_storage_manager().await?;
+
     // Validate the input
-    let validation_errors = validate_project_data(&data, true).await;
-    if !validation_errors.is_empty() {
-        return Err(ManagerError::Validation(validation_errors));
+    let report = load_validation_rules(&storage_manager)
+        .await
+        .validate_create(&data, true)
+        .await;
+    if !report.is_empty() {
+        return Err(ManagerError::Validation(report));
     }
 
-    let storage_manager = get_storage_manager().await?;
     let storage = storage_manager.storage();
 
     // Create project using storage layer (handles duplicate checks)
@@ -263,13 +305,17 @@ pub async fn create_project(data: ProjectCreateInput) -> ManagerResult<Project>
 
 /// Updates an existing project
 pub async fn update_project(id: &str, updates: ProjectUpdateInput) -> ManagerResult<Project> {
+    let storage_manager = get_storage_manager().await?;
+
     // Validate the updates
-    let validation_errors = validate_project_update(&updates, false).await;
-    if !validation_errors.is_empty() {
-        return Err(ManagerError::Validation(validation_errors));
+    let report = load_validation_rules(&storage_manager)
+        .await
+        .validate_update(&updates, false)
+        .await;
+    if !report.is_empty() {
+        return Err(ManagerError::Validation(report));
     }
 
-    let storage_manager = get_storage_manager().await?;
     let storage = storage_manager.storage();
 
     // Update project using storage layer (handles duplicate checks)
@@ -350,9 +396,12 @@ impl ProjectsManager {
 
     pub async fn create_project(&self, data: ProjectCreateInput) -> ManagerResult<Project> {
         // Validate the input
-        let validation_errors = validate_project_data(&data, true).await;
-        if !validation_errors.is_empty() {
-            return Err(ManagerError::Validation(validation_errors));
+        let report = load_validation_rules(&self.storage_manager)
+            .await
+            .validate_create(&data, true)
+            .await;
+        if !report.is_empty() {
+            return Err(ManagerError::Validation(report));
         }
 
         let storage = self.storage_manager.storage();
@@ -369,9 +418,12 @@ impl ProjectsManager {
         updates: ProjectUpdateInput,
     ) -> ManagerResult<Project> {
         // Validate the updates
-        let validation_errors = validate_project_update(&updates, false).await;
-        if !validation_errors.is_empty() {
-            return Err(ManagerError::Validation(validation_errors));
+        let report = load_validation_rules(&self.storage_manager)
+            .await
+            .validate_update(&updates, false)
+            .await;
+        if !report.is_empty() {
+            return Err(ManagerError::Validation(report));
         }
 
         let storage = self.storage_manager.storage();
@@ -558,6 +610,7 @@ mod tests {
             enable_fts: true,
             max_connections: 1,
             busy_timeout_seconds: 10,
+            read_replica: None,
         };
 
         Ok(Arc::new(StorageManager::new(config).await?))
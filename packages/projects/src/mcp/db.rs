@@ -0,0 +1,259 @@
+// ABOUTME: Database operations for per-project MCP server configurations
+// ABOUTME: Provides CRUD operations plus recording of handshake validation outcomes
+
+use super::types::*;
+use chrono::Utc;
+use orkee_storage::{field_encryption, StorageError};
+use sqlx::{Pool, Sqlite};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("Database error: {0}")]
+    SqlxError(#[from] sqlx::Error),
+
+    #[error("Entity not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("JSON serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Storage error: {0}")]
+    Storage(#[from] StorageError),
+}
+
+pub type DbResult<T> = Result<T, DbError>;
+
+/// Maximum length for an MCP server name
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Maximum number of arguments accepted for a single server
+const MAX_ARGS: usize = 100;
+
+/// Validate a server name: it becomes a key in exported client configs,
+/// so keep it to a conservative character set
+fn validate_name(name: &str) -> DbResult<()> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err(DbError::InvalidInput(
+            "MCP server name cannot be empty".to_string(),
+        ));
+    }
+    if trimmed.len() > MAX_NAME_LENGTH {
+        return Err(DbError::InvalidInput(format!(
+            "MCP server name exceeds maximum length of {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+    if !trimmed
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(DbError::InvalidInput(format!(
+            "MCP server name '{}' may only contain letters, digits, '-', '_' and '.'",
+            trimmed
+        )));
+    }
+    Ok(())
+}
+
+fn validate_command(command: &str) -> DbResult<()> {
+    if command.trim().is_empty() {
+        return Err(DbError::InvalidInput(
+            "MCP server command cannot be empty".to_string(),
+        ));
+    }
+    if command.contains('\0') {
+        return Err(DbError::InvalidInput(
+            "MCP server command contains a null byte".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_args(args: &[String]) -> DbResult<()> {
+    if args.len() > MAX_ARGS {
+        return Err(DbError::InvalidInput(format!(
+            "MCP server accepts at most {} arguments (got {})",
+            MAX_ARGS,
+            args.len()
+        )));
+    }
+    if args.iter().any(|a| a.contains('\0')) {
+        return Err(DbError::InvalidInput(
+            "MCP server arguments cannot contain null bytes".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Env values are sealed at rest; decrypt them for callers
+fn open_env(mut server: McpServerConfig) -> DbResult<McpServerConfig> {
+    server.env = field_encryption::open_map(server.env)?;
+    Ok(server)
+}
+
+/// List all MCP servers configured for a project, ordered by name
+pub async fn list_mcp_servers(
+    pool: &Pool<Sqlite>,
+    project_id: &str,
+) -> DbResult<Vec<McpServerConfig>> {
+    let servers = sqlx::query_as::<_, McpServerConfig>(
+        "SELECT * FROM project_mcp_servers WHERE project_id = ? ORDER BY name",
+    )
+    .bind(project_id)
+    .fetch_all(pool)
+    .await?;
+
+    servers.into_iter().map(open_env).collect()
+}
+
+/// Get a single MCP server scoped to its project
+pub async fn get_mcp_server(
+    pool: &Pool<Sqlite>,
+    project_id: &str,
+    id: &str,
+) -> DbResult<McpServerConfig> {
+    sqlx::query_as::<_, McpServerConfig>(
+        "SELECT * FROM project_mcp_servers WHERE id = ? AND project_id = ?",
+    )
+    .bind(id)
+    .bind(project_id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| DbError::NotFound(format!("MCP server not found: {}", id)))
+    .and_then(open_env)
+}
+
+/// Create a new MCP server configuration for a project
+pub async fn create_mcp_server(
+    pool: &Pool<Sqlite>,
+    project_id: &str,
+    input: McpServerCreateInput,
+) -> DbResult<McpServerConfig> {
+    validate_name(&input.name)?;
+    validate_command(&input.command)?;
+    validate_args(&input.args)?;
+
+    let id = orkee_core::generate_project_id();
+    let now = Utc::now();
+
+    let server = sqlx::query_as::<_, McpServerConfig>(
+        r#"
+        INSERT INTO project_mcp_servers (id, project_id, name, command, args, env, enabled, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        RETURNING *
+        "#,
+    )
+    .bind(&id)
+    .bind(project_id)
+    .bind(input.name.trim())
+    .bind(input.command.trim())
+    .bind(serde_json::to_string(&input.args)?)
+    .bind(serde_json::to_string(&field_encryption::seal_map(&input.env)?)?)
+    .bind(input.enabled)
+    .bind(now)
+    .bind(now)
+    .fetch_one(pool)
+    .await?;
+
+    open_env(server)
+}
+
+/// Update an existing MCP server configuration
+pub async fn update_mcp_server(
+    pool: &Pool<Sqlite>,
+    project_id: &str,
+    id: &str,
+    input: McpServerUpdateInput,
+) -> DbResult<McpServerConfig> {
+    let existing = get_mcp_server(pool, project_id, id).await?;
+
+    let name = input.name.unwrap_or(existing.name);
+    let command = input.command.unwrap_or(existing.command);
+    let args = input.args.unwrap_or(existing.args);
+    let env = input.env.unwrap_or(existing.env);
+    let enabled = input.enabled.unwrap_or(existing.enabled);
+
+    validate_name(&name)?;
+    validate_command(&command)?;
+    validate_args(&args)?;
+
+    let server = sqlx::query_as::<_, McpServerConfig>(
+        r#"
+        UPDATE project_mcp_servers
+        SET name = ?, command = ?, args = ?, env = ?, enabled = ?, updated_at = ?
+        WHERE id = ? AND project_id = ?
+        RETURNING *
+        "#,
+    )
+    .bind(name.trim())
+    .bind(command.trim())
+    .bind(serde_json::to_string(&args)?)
+    .bind(serde_json::to_string(&field_encryption::seal_map(&env)?)?)
+    .bind(enabled)
+    .bind(Utc::now())
+    .bind(id)
+    .bind(project_id)
+    .fetch_one(pool)
+    .await?;
+
+    open_env(server)
+}
+
+/// Delete an MCP server configuration
+pub async fn delete_mcp_server(pool: &Pool<Sqlite>, project_id: &str, id: &str) -> DbResult<()> {
+    let result = sqlx::query("DELETE FROM project_mcp_servers WHERE id = ? AND project_id = ?")
+        .bind(id)
+        .bind(project_id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(DbError::NotFound(format!("MCP server not found: {}", id)));
+    }
+
+    Ok(())
+}
+
+/// Persist the outcome of a handshake attempt against a server
+pub async fn record_validation_result(
+    pool: &Pool<Sqlite>,
+    id: &str,
+    error: Option<&str>,
+) -> DbResult<()> {
+    sqlx::query(
+        "UPDATE project_mcp_servers SET last_validated_at = ?, last_validation_error = ? WHERE id = ?",
+    )
+    .bind(Utc::now())
+    .bind(error)
+    .bind(id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name_rejects_invalid_characters() {
+        assert!(validate_name("filesystem").is_ok());
+        assert!(validate_name("brave-search_v2.1").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("has space").is_err());
+        assert!(validate_name("quote\"name").is_err());
+    }
+
+    #[test]
+    fn test_validate_command_and_args() {
+        assert!(validate_command("npx").is_ok());
+        assert!(validate_command("   ").is_err());
+        assert!(validate_args(&["-y".to_string(), "server".to_string()]).is_ok());
+        assert!(validate_args(&vec!["a".to_string(); MAX_ARGS + 1]).is_err());
+    }
+}
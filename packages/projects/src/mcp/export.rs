@@ -0,0 +1,112 @@
+// ABOUTME: Export of project MCP server configurations to client config formats
+// ABOUTME: Produces Claude Desktop and Cursor `mcpServers` JSON documents
+
+use serde::{Deserialize, Serialize};
+
+use super::types::McpServerConfig;
+
+/// Supported external client configuration formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum McpExportFormat {
+    ClaudeDesktop,
+    Cursor,
+}
+
+impl McpExportFormat {
+    /// Where the client expects to find its configuration file
+    pub fn suggested_path(&self) -> &'static str {
+        match self {
+            McpExportFormat::ClaudeDesktop => {
+                if cfg!(target_os = "macos") {
+                    "~/Library/Application Support/Claude/claude_desktop_config.json"
+                } else if cfg!(target_os = "windows") {
+                    "%APPDATA%\\Claude\\claude_desktop_config.json"
+                } else {
+                    "~/.config/Claude/claude_desktop_config.json"
+                }
+            }
+            McpExportFormat::Cursor => ".cursor/mcp.json",
+        }
+    }
+}
+
+/// An exported configuration document ready to be written to disk
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpExportResult {
+    pub format: McpExportFormat,
+    pub suggested_path: String,
+    pub config: serde_json::Value,
+}
+
+/// Build a client configuration from the enabled servers of a project.
+///
+/// Both clients read a top-level `mcpServers` object keyed by server name;
+/// Cursor additionally accepts an explicit `type` for stdio servers.
+pub fn export_mcp_config(servers: &[McpServerConfig], format: McpExportFormat) -> McpExportResult {
+    let mut entries = serde_json::Map::new();
+
+    for server in servers.iter().filter(|s| s.enabled) {
+        let mut entry = serde_json::Map::new();
+        if format == McpExportFormat::Cursor {
+            entry.insert("type".to_string(), serde_json::json!("stdio"));
+        }
+        entry.insert("command".to_string(), serde_json::json!(server.command));
+        entry.insert("args".to_string(), serde_json::json!(server.args));
+        if !server.env.is_empty() {
+            entry.insert("env".to_string(), serde_json::json!(server.env));
+        }
+        entries.insert(server.name.clone(), serde_json::Value::Object(entry));
+    }
+
+    McpExportResult {
+        format,
+        suggested_path: format.suggested_path().to_string(),
+        config: serde_json::json!({ "mcpServers": entries }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::collections::BTreeMap;
+
+    fn server(name: &str, enabled: bool) -> McpServerConfig {
+        let mut env = BTreeMap::new();
+        env.insert("API_KEY".to_string(), "secret".to_string());
+        McpServerConfig {
+            id: format!("id-{}", name),
+            project_id: "project-1".to_string(),
+            name: name.to_string(),
+            command: "npx".to_string(),
+            args: vec!["-y".to_string(), format!("@mcp/{}", name)],
+            env,
+            enabled,
+            last_validated_at: None,
+            last_validation_error: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_claude_desktop_export_skips_disabled_servers() {
+        let servers = vec![server("filesystem", true), server("search", false)];
+        let result = export_mcp_config(&servers, McpExportFormat::ClaudeDesktop);
+
+        let entries = result.config["mcpServers"].as_object().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries["filesystem"]["command"], "npx");
+        assert_eq!(entries["filesystem"]["env"]["API_KEY"], "secret");
+        assert!(entries["filesystem"].get("type").is_none());
+    }
+
+    #[test]
+    fn test_cursor_export_marks_stdio_type() {
+        let result = export_mcp_config(&[server("filesystem", true)], McpExportFormat::Cursor);
+        assert_eq!(result.config["mcpServers"]["filesystem"]["type"], "stdio");
+        assert_eq!(result.suggested_path, ".cursor/mcp.json");
+    }
+}
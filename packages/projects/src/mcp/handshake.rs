@@ -0,0 +1,232 @@
+// ABOUTME: MCP handshake validation by launching a configured stdio server
+// ABOUTME: Sends a JSON-RPC initialize request and reports the server's advertised identity
+
+use serde::{Deserialize, Serialize};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+use tracing::debug;
+
+use super::types::McpServerConfig;
+
+/// MCP protocol version advertised during the handshake
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// How long to wait for a server to answer `initialize` before giving up
+pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// JSON-RPC id used for the initialize request
+const INITIALIZE_REQUEST_ID: i64 = 1;
+
+/// Outcome of an MCP handshake attempt
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpHandshakeResult {
+    pub success: bool,
+    pub server_name: Option<String>,
+    pub server_version: Option<String>,
+    pub protocol_version: Option<String>,
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl McpHandshakeResult {
+    fn failure(error: String, started: Instant) -> Self {
+        Self {
+            success: false,
+            server_name: None,
+            server_version: None,
+            protocol_version: None,
+            error: Some(error),
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// Build the JSON-RPC initialize request line sent to the server
+fn initialize_request() -> String {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": INITIALIZE_REQUEST_ID,
+        "method": "initialize",
+        "params": {
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": {},
+            "clientInfo": {
+                "name": "orkee",
+                "version": env!("CARGO_PKG_VERSION"),
+            }
+        }
+    })
+    .to_string()
+}
+
+/// Interpret a single stdout line. Returns `None` for lines that are not the
+/// initialize response (logs, notifications) so the caller keeps reading.
+fn parse_initialize_response(line: &str, started: Instant) -> Option<McpHandshakeResult> {
+    let message: serde_json::Value = serde_json::from_str(line.trim()).ok()?;
+    if message.get("id").and_then(|id| id.as_i64()) != Some(INITIALIZE_REQUEST_ID) {
+        return None;
+    }
+
+    if let Some(error) = message.get("error") {
+        let text = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("unknown error");
+        return Some(McpHandshakeResult::failure(
+            format!("Server rejected initialize: {}", text),
+            started,
+        ));
+    }
+
+    let result = message.get("result")?;
+    let server_info = result.get("serverInfo");
+    Some(McpHandshakeResult {
+        success: true,
+        server_name: server_info
+            .and_then(|i| i.get("name"))
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        server_version: server_info
+            .and_then(|i| i.get("version"))
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        protocol_version: result
+            .get("protocolVersion")
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        error: None,
+        duration_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Launch the configured server and attempt an MCP initialize handshake.
+///
+/// The child process is always terminated afterwards; this only verifies that
+/// the configuration produces a server that speaks the protocol.
+pub async fn perform_handshake(
+    server: &McpServerConfig,
+    working_dir: Option<&std::path::Path>,
+    timeout: Duration,
+) -> McpHandshakeResult {
+    let started = Instant::now();
+    debug!(
+        "Validating MCP server '{}' ({})",
+        server.name, server.command
+    );
+
+    let mut command = Command::new(&server.command);
+    command
+        .args(&server.args)
+        .envs(&server.env)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .kill_on_drop(true);
+    if let Some(dir) = working_dir.filter(|d| d.is_dir()) {
+        command.current_dir(dir);
+    }
+
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            return McpHandshakeResult::failure(
+                format!("Failed to launch '{}': {}", server.command, e),
+                started,
+            )
+        }
+    };
+
+    let (Some(mut stdin), Some(stdout)) = (child.stdin.take(), child.stdout.take()) else {
+        let _ = child.kill().await;
+        return McpHandshakeResult::failure(
+            "Failed to attach to server stdio".to_string(),
+            started,
+        );
+    };
+
+    let exchange = async {
+        let mut request = initialize_request();
+        request.push('\n');
+        stdin
+            .write_all(request.as_bytes())
+            .await
+            .map_err(|e| format!("Failed to write initialize request: {}", e))?;
+        stdin
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to write initialize request: {}", e))?;
+
+        let mut lines = BufReader::new(stdout).lines();
+        loop {
+            match lines.next_line().await {
+                Ok(Some(line)) => {
+                    if let Some(result) = parse_initialize_response(&line, started) {
+                        return Ok(result);
+                    }
+                }
+                Ok(None) => return Err("Server exited before responding".to_string()),
+                Err(e) => return Err(format!("Failed to read server output: {}", e)),
+            }
+        }
+    };
+
+    let outcome = match tokio::time::timeout(timeout, exchange).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => McpHandshakeResult::failure(e, started),
+        Err(_) => McpHandshakeResult::failure(
+            format!(
+                "Timed out after {}s waiting for initialize response",
+                timeout.as_secs()
+            ),
+            started,
+        ),
+    };
+
+    let _ = child.kill().await;
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initialize_request_is_single_line_jsonrpc() {
+        let request = initialize_request();
+        assert!(!request.contains('\n'));
+        let parsed: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(parsed["method"], "initialize");
+        assert_eq!(parsed["params"]["protocolVersion"], PROTOCOL_VERSION);
+    }
+
+    #[test]
+    fn test_parse_initialize_response_success() {
+        let line = r#"{"jsonrpc":"2.0","id":1,"result":{"protocolVersion":"2024-11-05","serverInfo":{"name":"fs","version":"1.2.0"},"capabilities":{}}}"#;
+        let result = parse_initialize_response(line, Instant::now()).unwrap();
+        assert!(result.success);
+        assert_eq!(result.server_name.as_deref(), Some("fs"));
+        assert_eq!(result.server_version.as_deref(), Some("1.2.0"));
+        assert_eq!(result.protocol_version.as_deref(), Some("2024-11-05"));
+    }
+
+    #[test]
+    fn test_parse_initialize_response_skips_unrelated_lines() {
+        assert!(parse_initialize_response("starting server...", Instant::now()).is_none());
+        assert!(parse_initialize_response(
+            r#"{"jsonrpc":"2.0","method":"notifications/message"}"#,
+            Instant::now()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_parse_initialize_response_error() {
+        let line = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"bad version"}}"#;
+        let result = parse_initialize_response(line, Instant::now()).unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("bad version"));
+    }
+}
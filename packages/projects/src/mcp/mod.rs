@@ -0,0 +1,16 @@
+// ABOUTME: Per-project MCP (Model Context Protocol) server configuration module
+// ABOUTME: Provides types, database operations, handshake validation, and client config export
+
+pub mod db;
+pub mod export;
+pub mod handshake;
+pub mod types;
+
+// Re-export main types for convenience
+pub use db::{
+    create_mcp_server, delete_mcp_server, get_mcp_server, list_mcp_servers,
+    record_validation_result, update_mcp_server, DbError, DbResult,
+};
+pub use export::{export_mcp_config, McpExportFormat, McpExportResult};
+pub use handshake::{perform_handshake, McpHandshakeResult, DEFAULT_HANDSHAKE_TIMEOUT};
+pub use types::{McpServerConfig, McpServerCreateInput, McpServerUpdateInput};
@@ -0,0 +1,52 @@
+// ABOUTME: MCP server configuration type definitions
+// ABOUTME: Structures describing how to launch a stdio MCP server for a project
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// A launchable MCP server configured for a project
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct McpServerConfig {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub command: String,
+    #[sqlx(json)]
+    pub args: Vec<String>,
+    #[sqlx(json)]
+    pub env: BTreeMap<String, String>,
+    pub enabled: bool,
+    pub last_validated_at: Option<DateTime<Utc>>,
+    pub last_validation_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpServerCreateInput {
+    pub name: String,
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpServerUpdateInput {
+    pub name: Option<String>,
+    pub command: Option<String>,
+    pub args: Option<Vec<String>>,
+    pub env: Option<BTreeMap<String, String>>,
+    pub enabled: Option<bool>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
@@ -0,0 +1,70 @@
+// ABOUTME: Reconciles stored agent and model references with the model catalog
+// ABOUTME: Clears preferences and assignments pointing at agents or models that were removed from config
+
+use sqlx::SqlitePool;
+use tracing::{debug, warn};
+
+use orkee_models::REGISTRY;
+use orkee_storage::StorageError;
+
+#[derive(Clone, Copy)]
+enum CatalogEntry {
+    Agent,
+    Model,
+}
+
+/// Nullable columns holding catalog IDs that drive future behavior.
+/// Execution and usage history keeps its original references.
+const REFERENCES: &[(&str, &str, CatalogEntry)] = &[
+    ("user_agents", "preferred_model_id", CatalogEntry::Model),
+    ("users", "default_agent_id", CatalogEntry::Agent),
+    ("tasks", "assigned_agent_id", CatalogEntry::Agent),
+    ("tasks", "reviewed_by_agent_id", CatalogEntry::Agent),
+];
+
+/// Clear agent and model references that are no longer in the catalog.
+/// Returns the number of references cleared.
+pub async fn clear_orphaned_model_references(pool: &SqlitePool) -> Result<usize, StorageError> {
+    let mut total_cleared = 0;
+
+    for &(table, column, entry) in REFERENCES {
+        let ids: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT DISTINCT {column} FROM {table} WHERE {column} IS NOT NULL"
+        ))
+        .fetch_all(pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        for id in ids {
+            let exists = match entry {
+                CatalogEntry::Agent => REGISTRY.agent_exists(&id),
+                CatalogEntry::Model => REGISTRY.model_exists(&id),
+            };
+            if exists {
+                continue;
+            }
+
+            let result = sqlx::query(&format!(
+                "UPDATE {table} SET {column} = NULL WHERE {column} = ?"
+            ))
+            .bind(&id)
+            .execute(pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+            warn!(
+                "Cleared orphaned {}.{} '{}' from {} rows",
+                table,
+                column,
+                id,
+                result.rows_affected()
+            );
+            total_cleared += result.rows_affected() as usize;
+        }
+    }
+
+    if total_cleared == 0 {
+        debug!("No orphaned agent/model references found");
+    }
+    Ok(total_cleared)
+}
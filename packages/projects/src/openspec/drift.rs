@@ -0,0 +1,362 @@
+// ABOUTME: Spec drift detection for OpenSpec projects
+// ABOUTME: Matches spec requirements to code symbols and tests, reporting unimplemented requirements and orphaned code
+
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use chrono::Utc;
+use orkee_context::{
+    graph_builder::GraphBuilder,
+    graph_types::{CodeGraph, NodeType},
+};
+
+use super::parser::load_specs;
+use super::types::{
+    DriftReport, DriftSummary, OrphanedFile, RequirementDrift, RequirementStatus, SpecRequirement,
+    SymbolRef,
+};
+
+/// Words that carry no meaning when matching requirement names to symbols
+const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "be", "by", "for", "from", "in", "is", "of", "on", "or", "the", "to", "with",
+];
+
+/// Compare the project's OpenSpec requirements with its code.
+///
+/// A requirement matches a symbol when the symbol is named by one of the
+/// requirement's inline-code identifiers, or when every significant word of
+/// the requirement name appears in the symbol name (`Password Reset` matches
+/// `reset_password`). Test files exercise a requirement when they define a
+/// matching symbol or mention one of its matched symbols. Projects without
+/// specs get an empty report rather than having every file flagged as orphaned.
+pub fn detect_drift(project_root: &str, project_id: &str) -> Result<DriftReport, String> {
+    let root = Path::new(project_root);
+    let capabilities = load_specs(root)?;
+
+    let mut report = DriftReport {
+        project_id: project_id.to_string(),
+        summary: DriftSummary {
+            capabilities: capabilities.len(),
+            ..Default::default()
+        },
+        requirements: Vec::new(),
+        orphaned_files: Vec::new(),
+        generated_at: Utc::now(),
+    };
+    if capabilities.iter().all(|c| c.requirements.is_empty()) {
+        return Ok(report);
+    }
+
+    let graph = GraphBuilder::new().build_symbol_graph(project_root, project_id)?;
+    let symbols = code_symbols(&graph);
+    let (test_symbols, source_symbols): (Vec<_>, Vec<_>) =
+        symbols.into_iter().partition(|s| is_test_path(&s.path));
+    let test_contents = read_test_files(root, &graph);
+
+    let mut covered: HashSet<(String, String, Option<usize>)> = HashSet::new();
+    for capability in &capabilities {
+        for requirement in &capability.requirements {
+            let matcher = RequirementMatcher::new(requirement);
+            let symbols: Vec<SymbolRef> = source_symbols
+                .iter()
+                .filter(|s| matcher.matches(&s.name))
+                .cloned()
+                .collect();
+
+            let mut tests: Vec<String> = test_symbols
+                .iter()
+                .filter(|s| matcher.matches(&s.name))
+                .map(|s| s.path.clone())
+                .collect();
+            tests.extend(
+                test_contents
+                    .iter()
+                    .filter(|(_, content)| symbols.iter().any(|s| mentions(content, &s.name)))
+                    .map(|(path, _)| path.clone()),
+            );
+            tests.sort();
+            tests.dedup();
+
+            let status = match (symbols.is_empty(), tests.is_empty()) {
+                (true, _) => RequirementStatus::Unimplemented,
+                (false, true) => RequirementStatus::Untested,
+                (false, false) => RequirementStatus::Implemented,
+            };
+            match status {
+                RequirementStatus::Implemented => report.summary.implemented += 1,
+                RequirementStatus::Untested => report.summary.untested += 1,
+                RequirementStatus::Unimplemented => report.summary.unimplemented += 1,
+            }
+
+            covered.extend(symbols.iter().map(symbol_key));
+            report.requirements.push(RequirementDrift {
+                capability: capability.name.clone(),
+                requirement: requirement.name.clone(),
+                status,
+                symbols,
+                tests,
+            });
+        }
+    }
+
+    let mut files: BTreeMap<&str, Vec<&SymbolRef>> = BTreeMap::new();
+    for symbol in &source_symbols {
+        files.entry(&symbol.path).or_default().push(symbol);
+    }
+    report.orphaned_files = files
+        .into_iter()
+        .filter(|(_, symbols)| !symbols.iter().any(|s| covered.contains(&symbol_key(s))))
+        .map(|(path, symbols)| OrphanedFile {
+            path: path.to_string(),
+            symbols: symbols.iter().map(|s| s.name.clone()).collect(),
+        })
+        .collect();
+
+    report.summary.requirements = report.requirements.len();
+    report.summary.orphaned_files = report.orphaned_files.len();
+    Ok(report)
+}
+
+/// Matches symbol names against a single requirement
+struct RequirementMatcher {
+    identifiers: Vec<String>,
+    keywords: Vec<String>,
+}
+
+impl RequirementMatcher {
+    fn new(requirement: &SpecRequirement) -> Self {
+        Self {
+            identifiers: requirement
+                .identifiers
+                .iter()
+                .map(|i| i.to_lowercase())
+                .collect(),
+            keywords: name_words(&requirement.name)
+                .into_iter()
+                .filter(|w| !STOP_WORDS.contains(&w.as_str()))
+                .collect(),
+        }
+    }
+
+    fn matches(&self, symbol_name: &str) -> bool {
+        if self.identifiers.contains(&symbol_name.to_lowercase()) {
+            return true;
+        }
+        if self.keywords.is_empty() {
+            return false;
+        }
+        let words = name_words(symbol_name);
+        self.keywords.iter().all(|k| words.contains(k))
+    }
+}
+
+/// Function, class, and module symbols from a symbol graph
+fn code_symbols(graph: &CodeGraph) -> Vec<SymbolRef> {
+    graph
+        .nodes
+        .iter()
+        .filter(|n| !matches!(n.node_type, NodeType::File))
+        .filter_map(|n| {
+            Some(SymbolRef {
+                name: n.label.clone(),
+                path: n.metadata.path.clone()?,
+                line: n.metadata.line_start,
+            })
+        })
+        .collect()
+}
+
+fn symbol_key(symbol: &SymbolRef) -> (String, String, Option<usize>) {
+    (symbol.path.clone(), symbol.name.clone(), symbol.line)
+}
+
+/// Read the contents of every test file in the graph, keyed by relative path
+fn read_test_files(root: &Path, graph: &CodeGraph) -> Vec<(String, String)> {
+    graph
+        .nodes
+        .iter()
+        .filter(|n| matches!(n.node_type, NodeType::File))
+        .filter_map(|n| n.metadata.path.as_ref())
+        .filter(|path| is_test_path(path))
+        .filter_map(|path| {
+            let content = fs::read_to_string(root.join(path)).ok()?;
+            Some((path.clone(), content))
+        })
+        .collect()
+}
+
+/// Whether a relative path looks like a test file in any supported language
+fn is_test_path(path: &str) -> bool {
+    let path = Path::new(path);
+    let in_test_dir = path.parent().is_some_and(|dir| {
+        dir.components().any(|c| {
+            matches!(
+                c.as_os_str().to_string_lossy().as_ref(),
+                "test" | "tests" | "__tests__" | "spec"
+            )
+        })
+    });
+    if in_test_dir {
+        return true;
+    }
+
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let lower = stem.to_lowercase();
+    lower.starts_with("test_")
+        || [".test", "_test", ".spec", "_spec"]
+            .iter()
+            .any(|suffix| lower.ends_with(suffix))
+        || stem.ends_with("Test")
+        || stem.ends_with("Tests")
+}
+
+/// Whether `name` appears in `content` as a whole identifier
+fn mentions(content: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    content.match_indices(name).any(|(idx, _)| {
+        let before = content[..idx].chars().next_back();
+        let after = content[idx + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Lowercase words of a name, splitting on separators and camelCase boundaries
+fn name_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_name_words_splits_case_and_separators() {
+        assert_eq!(name_words("resetPassword"), vec!["reset", "password"]);
+        assert_eq!(
+            name_words("send_reset_email"),
+            vec!["send", "reset", "email"]
+        );
+        assert_eq!(name_words("Password Reset"), vec!["password", "reset"]);
+    }
+
+    #[test]
+    fn test_is_test_path() {
+        assert!(is_test_path("src/__tests__/auth.ts"));
+        assert!(is_test_path("src/auth.test.ts"));
+        assert!(is_test_path("tests/test_auth.py"));
+        assert!(is_test_path("pkg/auth_test.go"));
+        assert!(is_test_path("src/AuthServiceTest.java"));
+        assert!(!is_test_path("src/latest.ts"));
+        assert!(!is_test_path("src/auth.ts"));
+    }
+
+    #[test]
+    fn test_mentions_requires_whole_identifier() {
+        assert!(mentions("expect(resetPassword(user))", "resetPassword"));
+        assert!(!mentions("resetPasswordLater()", "resetPassword"));
+    }
+
+    #[test]
+    fn test_detect_drift_reports_requirements_and_orphans() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "openspec/specs/auth/spec.md",
+            "## Requirements\n\n\
+             ### Requirement: Password Reset\n\
+             Users SHALL be able to reset their password.\n\n\
+             ### Requirement: Session Expiry\n\
+             Sessions SHALL expire via `expireSessions`.\n\n\
+             ### Requirement: Audit Log\n\
+             Every login SHALL be recorded.\n",
+        );
+        write(
+            root,
+            "src/auth.ts",
+            "export function resetPassword(user: string) { return user; }\n\
+             export function expireSessions() { return 0; }\n",
+        );
+        write(
+            root,
+            "src/auth.test.ts",
+            "import { resetPassword } from './auth';\n\
+             test('resets', () => resetPassword('a'));\n",
+        );
+        write(root, "src/billing.ts", "export function chargeCard() {}\n");
+
+        let report = detect_drift(root.to_str().unwrap(), "p1").unwrap();
+
+        assert_eq!(report.summary.capabilities, 1);
+        assert_eq!(report.summary.requirements, 3);
+        assert_eq!(report.summary.implemented, 1);
+        assert_eq!(report.summary.untested, 1);
+        assert_eq!(report.summary.unimplemented, 1);
+
+        let status = |name: &str| {
+            report
+                .requirements
+                .iter()
+                .find(|r| r.requirement == name)
+                .unwrap()
+        };
+        let reset = status("Password Reset");
+        assert_eq!(reset.status, RequirementStatus::Implemented);
+        assert_eq!(reset.tests, vec!["src/auth.test.ts"]);
+        assert_eq!(status("Session Expiry").status, RequirementStatus::Untested);
+        assert_eq!(status("Audit Log").status, RequirementStatus::Unimplemented);
+
+        assert_eq!(
+            report.orphaned_files,
+            vec![OrphanedFile {
+                path: "src/billing.ts".to_string(),
+                symbols: vec!["chargeCard".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_detect_drift_without_specs_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "src/billing.ts",
+            "export function chargeCard() {}\n",
+        );
+
+        let report = detect_drift(dir.path().to_str().unwrap(), "p1").unwrap();
+        assert_eq!(report.summary, DriftSummary::default());
+        assert!(report.orphaned_files.is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+// ABOUTME: OpenSpec integration for projects
+// ABOUTME: Parses capability specs from the project tree and detects drift against the implementation
+
+pub mod drift;
+pub mod parser;
+pub mod types;
+
+// Re-export main types for convenience
+pub use drift::detect_drift;
+pub use parser::{load_specs, parse_spec};
+pub use types::{
+    DriftReport, DriftSummary, OrphanedFile, RequirementDrift, RequirementStatus, SpecCapability,
+    SpecRequirement, SymbolRef,
+};
@@ -0,0 +1,173 @@
+// ABOUTME: Parser for OpenSpec capability spec files
+// ABOUTME: Extracts requirements, scenarios, and referenced code identifiers from spec markdown
+
+use std::fs;
+use std::path::Path;
+
+use super::types::{SpecCapability, SpecRequirement};
+
+/// Directory holding the current capability specs, relative to the project root
+pub const SPECS_DIR: &str = "openspec/specs";
+
+const REQUIREMENT_PREFIX: &str = "### Requirement:";
+const SCENARIO_PREFIX: &str = "#### Scenario:";
+
+/// Load every capability spec under `openspec/specs` in the project.
+/// Returns an empty list when the project has no OpenSpec directory.
+pub fn load_specs(project_root: &Path) -> Result<Vec<SpecCapability>, String> {
+    let specs_dir = project_root.join(SPECS_DIR);
+    if !specs_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let entries = fs::read_dir(&specs_dir)
+        .map_err(|e| format!("Failed to read {}: {}", specs_dir.display(), e))?;
+
+    let mut capabilities = Vec::new();
+    for entry in entries.flatten() {
+        let spec_path = entry.path().join("spec.md");
+        if !spec_path.is_file() {
+            continue;
+        }
+        let content = fs::read_to_string(&spec_path)
+            .map_err(|e| format!("Failed to read {}: {}", spec_path.display(), e))?;
+        let name = entry.file_name().to_string_lossy().to_string();
+        let mut capability = parse_spec(&name, &content);
+        capability.path = format!("{}/{}/spec.md", SPECS_DIR, name);
+        capabilities.push(capability);
+    }
+
+    capabilities.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(capabilities)
+}
+
+/// Parse the markdown of a single capability spec
+pub fn parse_spec(capability: &str, content: &str) -> SpecCapability {
+    let mut requirements = Vec::new();
+    let mut current: Option<SpecRequirement> = None;
+
+    for (idx, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+        if let Some(name) = trimmed.strip_prefix(REQUIREMENT_PREFIX) {
+            requirements.extend(current.take());
+            current = Some(SpecRequirement {
+                name: name.trim().to_string(),
+                line: idx + 1,
+                identifiers: Vec::new(),
+                scenarios: Vec::new(),
+            });
+            continue;
+        }
+
+        // Any other heading at requirement level or above ends the current requirement
+        if trimmed.starts_with('#') && !trimmed.starts_with("####") {
+            requirements.extend(current.take());
+            continue;
+        }
+
+        let Some(requirement) = current.as_mut() else {
+            continue;
+        };
+        if let Some(name) = trimmed.strip_prefix(SCENARIO_PREFIX) {
+            requirement.scenarios.push(name.trim().to_string());
+        }
+        for identifier in code_identifiers(trimmed) {
+            if !requirement.identifiers.contains(&identifier) {
+                requirement.identifiers.push(identifier);
+            }
+        }
+    }
+    requirements.extend(current);
+
+    SpecCapability {
+        name: capability.to_string(),
+        path: String::new(),
+        requirements,
+    }
+}
+
+/// Identifiers written as inline code, e.g. `AuthService::login()` yields `login`
+fn code_identifiers(line: &str) -> Vec<String> {
+    line.split('`')
+        .skip(1)
+        .step_by(2)
+        .filter_map(|span| {
+            let span = span.trim().trim_end_matches("()");
+            let name = span.rsplit([':', '.']).next().unwrap_or(span);
+            let mut chars = name.chars();
+            let valid = chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+            valid.then(|| name.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AUTH_SPEC: &str = "# Auth Specification
+
+## Purpose
+Users sign in to the dashboard.
+
+## Requirements
+
+### Requirement: Password Reset
+The system SHALL email a reset link via `AuthService::send_reset_email()`.
+
+#### Scenario: Known email
+- **WHEN** a user requests a reset for a registered email
+- **THEN** `send_reset_email` is called once
+
+### Requirement: Session Expiry
+Sessions SHALL expire after 24 hours of inactivity.
+
+## Notes
+Mentions `not_a_requirement` outside any requirement.
+";
+
+    #[test]
+    fn test_parse_spec_extracts_requirements() {
+        let capability = parse_spec("auth", AUTH_SPEC);
+
+        assert_eq!(capability.name, "auth");
+        assert_eq!(capability.requirements.len(), 2);
+
+        let reset = &capability.requirements[0];
+        assert_eq!(reset.name, "Password Reset");
+        assert_eq!(reset.line, 8);
+        assert_eq!(reset.identifiers, vec!["send_reset_email"]);
+        assert_eq!(reset.scenarios, vec!["Known email"]);
+
+        let expiry = &capability.requirements[1];
+        assert_eq!(expiry.name, "Session Expiry");
+        assert!(expiry.identifiers.is_empty());
+    }
+
+    #[test]
+    fn test_code_identifiers_skips_non_identifiers() {
+        assert_eq!(
+            code_identifiers("Call `client.refresh()` with `--force` or `UserStore`"),
+            vec!["refresh", "UserStore"]
+        );
+    }
+
+    #[test]
+    fn test_load_specs_reads_capability_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let auth_dir = dir.path().join(SPECS_DIR).join("auth");
+        fs::create_dir_all(&auth_dir).unwrap();
+        fs::write(auth_dir.join("spec.md"), AUTH_SPEC).unwrap();
+        fs::create_dir_all(dir.path().join(SPECS_DIR).join("empty")).unwrap();
+
+        let specs = load_specs(dir.path()).unwrap();
+        assert_eq!(specs.len(), 1);
+        assert_eq!(specs[0].path, "openspec/specs/auth/spec.md");
+
+        let missing = tempfile::tempdir().unwrap();
+        assert!(load_specs(missing.path()).unwrap().is_empty());
+    }
+}
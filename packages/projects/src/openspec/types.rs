@@ -0,0 +1,86 @@
+// ABOUTME: OpenSpec type definitions
+// ABOUTME: Capability specs, requirements, and the drift report comparing them with code
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A capability spec read from `openspec/specs/<capability>/spec.md`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecCapability {
+    pub name: String,
+    /// Spec file path relative to the project root
+    pub path: String,
+    pub requirements: Vec<SpecRequirement>,
+}
+
+/// A `### Requirement:` section of a capability spec
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpecRequirement {
+    pub name: String,
+    pub line: usize,
+    /// Code identifiers referenced in backticks within the requirement
+    pub identifiers: Vec<String>,
+    pub scenarios: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RequirementStatus {
+    /// Matching code and tests were found
+    Implemented,
+    /// Matching code was found but no test exercises it
+    Untested,
+    /// No matching code was found
+    Unimplemented,
+}
+
+/// A code symbol located by the context analyzer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolRef {
+    pub name: String,
+    pub path: String,
+    pub line: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequirementDrift {
+    pub capability: String,
+    pub requirement: String,
+    pub status: RequirementStatus,
+    pub symbols: Vec<SymbolRef>,
+    /// Test files that exercise the requirement
+    pub tests: Vec<String>,
+}
+
+/// A source file none of whose symbols are covered by a requirement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedFile {
+    pub path: String,
+    pub symbols: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftSummary {
+    pub capabilities: usize,
+    pub requirements: usize,
+    pub implemented: usize,
+    pub untested: usize,
+    pub unimplemented: usize,
+    pub orphaned_files: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftReport {
+    pub project_id: String,
+    pub summary: DriftSummary,
+    pub requirements: Vec<RequirementDrift>,
+    pub orphaned_files: Vec<OrphanedFile>,
+    pub generated_at: DateTime<Utc>,
+}
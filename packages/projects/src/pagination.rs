@@ -1,7 +1,10 @@
 // ABOUTME: Pagination utilities for list endpoints
-// ABOUTME: Provides standardized query parameters and response wrappers
+// ABOUTME: Provides standardized query parameters and response wrappers, in offset or cursor mode
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use orkee_storage::{KeysetPage, KeysetPosition};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Default page size for paginated queries
 pub const DEFAULT_PAGE_SIZE: i64 = 20;
@@ -22,6 +25,35 @@ pub struct PaginationParams {
     /// Number of items per page (defaults to DEFAULT_PAGE_SIZE, max MAX_PAGE_SIZE)
     #[serde(default = "default_limit")]
     pub limit: i64,
+
+    /// Opaque cursor from a previous response's `nextCursor`. When present
+    /// (even empty, for the first page), the endpoint uses cursor pagination
+    /// and `page` is ignored.
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+/// A cursor that wasn't produced by [`encode_cursor`]
+#[derive(Debug, Error)]
+#[error("Invalid pagination cursor")]
+pub struct InvalidCursorError;
+
+/// Encode a keyset position as an opaque, URL-safe cursor
+pub fn encode_cursor(position: &KeysetPosition) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}\n{}", position.created_at, position.id))
+}
+
+/// Decode a cursor produced by [`encode_cursor`]
+pub fn decode_cursor(cursor: &str) -> Result<KeysetPosition, InvalidCursorError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .map_err(|_| InvalidCursorError)?;
+    let text = String::from_utf8(bytes).map_err(|_| InvalidCursorError)?;
+    let (created_at, id) = text.split_once('\n').ok_or(InvalidCursorError)?;
+    if created_at.is_empty() || id.is_empty() {
+        return Err(InvalidCursorError);
+    }
+    Ok(KeysetPosition::new(created_at, id))
 }
 
 fn default_page() -> i64 {
@@ -38,12 +70,26 @@ impl PaginationParams {
         Self {
             page: MIN_PAGE,
             limit: DEFAULT_PAGE_SIZE,
+            cursor: None,
         }
     }
 
     /// Create pagination params with custom values
     pub fn with_page_and_limit(page: i64, limit: i64) -> Self {
-        Self { page, limit }
+        Self {
+            page,
+            limit,
+            cursor: None,
+        }
+    }
+
+    /// Create cursor-mode pagination params; `None` requests the first page
+    pub fn with_cursor(cursor: Option<&KeysetPosition>, limit: i64) -> Self {
+        Self {
+            page: MIN_PAGE,
+            limit,
+            cursor: Some(cursor.map(encode_cursor).unwrap_or_default()),
+        }
     }
 
     /// Validate and normalize pagination parameters
@@ -75,6 +121,21 @@ impl PaginationParams {
     pub fn page(&self) -> i64 {
         self.page.max(MIN_PAGE)
     }
+
+    /// Whether the client asked for cursor pagination
+    pub fn is_cursor_mode(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// Decode the cursor into the position the page starts after
+    ///
+    /// Returns `None` in offset mode and for the first page in cursor mode.
+    pub fn keyset_position(&self) -> Result<Option<KeysetPosition>, InvalidCursorError> {
+        match self.cursor.as_deref() {
+            None | Some("") => Ok(None),
+            Some(cursor) => decode_cursor(cursor).map(Some),
+        }
+    }
 }
 
 impl Default for PaginationParams {
@@ -86,7 +147,7 @@ impl Default for PaginationParams {
 /// Metadata about pagination state
 #[derive(Debug, Clone, Serialize)]
 pub struct PaginationMeta {
-    /// Current page number (1-indexed)
+    /// Current page number (1-indexed; always 1 in cursor mode)
     pub page: i64,
 
     /// Items per page
@@ -108,6 +169,10 @@ pub struct PaginationMeta {
     /// Whether there is a previous page
     #[serde(rename = "hasPreviousPage")]
     pub has_previous_page: bool,
+
+    /// Cursor for the next page (cursor mode only; absent on the last page)
+    #[serde(rename = "nextCursor", skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl PaginationMeta {
@@ -128,6 +193,27 @@ impl PaginationMeta {
             total_pages,
             has_next_page: page < total_pages,
             has_previous_page: page > MIN_PAGE,
+            next_cursor: None,
+        }
+    }
+
+    /// Create cursor-mode metadata for a page that continues at `next`
+    pub fn for_cursor(
+        params: &PaginationParams,
+        total_items: i64,
+        next: Option<&KeysetPosition>,
+    ) -> Self {
+        let page_size = params.limit();
+        let total_pages = (total_items + page_size - 1) / page_size;
+
+        Self {
+            page: MIN_PAGE,
+            page_size,
+            total_items,
+            total_pages,
+            has_next_page: next.is_some(),
+            has_previous_page: matches!(params.cursor.as_deref(), Some(c) if !c.is_empty()),
+            next_cursor: next.map(encode_cursor),
         }
     }
 }
@@ -150,6 +236,14 @@ impl<T> PaginatedResponse<T> {
             pagination: PaginationMeta::new(params, total_items),
         }
     }
+
+    /// Create a cursor-mode response from a keyset page
+    pub fn from_keyset(page: KeysetPage<T>, params: &PaginationParams) -> Self {
+        Self {
+            pagination: PaginationMeta::for_cursor(params, page.total, page.next.as_ref()),
+            data: page.items,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -243,4 +337,54 @@ mod tests {
         assert_eq!(response.pagination.total_items, 50);
         assert_eq!(response.pagination.total_pages, 3);
     }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let position = KeysetPosition::new("2025-01-15T10:30:00Z", "task-1234");
+        let cursor = encode_cursor(&position);
+        assert!(!cursor.contains('\n'));
+        assert_eq!(decode_cursor(&cursor).unwrap(), position);
+
+        assert!(decode_cursor("not a cursor!").is_err());
+        assert!(decode_cursor(&URL_SAFE_NO_PAD.encode("no-separator")).is_err());
+    }
+
+    #[test]
+    fn test_cursor_mode_params() {
+        let params = PaginationParams::default();
+        assert!(!params.is_cursor_mode());
+        assert!(params.keyset_position().unwrap().is_none());
+
+        let first = PaginationParams::with_cursor(None, 10);
+        assert!(first.is_cursor_mode());
+        assert!(first.keyset_position().unwrap().is_none());
+
+        let position = KeysetPosition::new("2025-01-15T10:30:00Z", "task-1234");
+        let next = PaginationParams::with_cursor(Some(&position), 10);
+        assert_eq!(next.keyset_position().unwrap(), Some(position));
+    }
+
+    #[test]
+    fn test_paginated_response_from_keyset() {
+        let position = KeysetPosition::new("2025-01-15T10:30:00Z", "b");
+        let page = KeysetPage {
+            items: vec!["a", "b"],
+            total: 5,
+            next: Some(position.clone()),
+        };
+        let params = PaginationParams::with_cursor(None, 2);
+        let response = PaginatedResponse::from_keyset(page, &params);
+
+        assert_eq!(response.data, vec!["a", "b"]);
+        assert_eq!(response.pagination.total_pages, 3);
+        assert!(response.pagination.has_next_page);
+        assert!(!response.pagination.has_previous_page);
+        assert_eq!(
+            response.pagination.next_cursor.as_deref(),
+            Some(encode_cursor(&position).as_str())
+        );
+
+        let json = serde_json::to_value(PaginationMeta::new(&params, 5)).unwrap();
+        assert!(json.get("nextCursor").is_none());
+    }
 }
@@ -0,0 +1,802 @@
+// ABOUTME: Locates and renders a project's README and docs/ pages for the dashboard
+// ABOUTME: Produces sanitized HTML and a Markdown AST with relative links and images resolved to API URLs
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag};
+use serde::Serialize;
+use thiserror::Error;
+use walkdir::WalkDir;
+
+/// Largest Markdown file that will be rendered
+pub const MAX_DOC_BYTES: u64 = 1024 * 1024;
+
+/// Largest image served from a project's docs
+pub const MAX_ASSET_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Most files listed from a project's docs/ directory
+const MAX_DOCS_LISTED: usize = 500;
+
+/// README file names in order of preference, matched case-insensitively
+const README_NAMES: [&str; 4] = ["readme.md", "readme.markdown", "readme.txt", "readme"];
+
+/// Candidate docs/ index pages in order of preference
+const DOCS_INDEX_NAMES: [&str; 4] = [
+    "docs/index.md",
+    "docs/README.md",
+    "docs/readme.md",
+    "docs/index.markdown",
+];
+
+const MARKDOWN_EXTENSIONS: [&str; 2] = ["md", "markdown"];
+
+#[derive(Debug, Error)]
+pub enum DocsError {
+    #[error("Path is outside the project: {0}")]
+    OutsideProject(String),
+
+    #[error("File not found: {0}")]
+    NotFound(String),
+
+    #[error("Unsupported file type: {0}")]
+    UnsupportedType(String),
+
+    #[error("File exceeds {limit} bytes: {path}")]
+    TooLarge { path: String, limit: u64 },
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// A project's README, docs/ index, and the other pages under docs/
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectDocs {
+    pub readme: Option<RenderedDoc>,
+    pub docs_index: Option<RenderedDoc>,
+    /// Markdown files under docs/, relative to the project root
+    pub docs: Vec<String>,
+}
+
+/// A Markdown file rendered for display
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderedDoc {
+    /// Path relative to the project root
+    pub path: String,
+    /// Text of the first top-level heading
+    pub title: Option<String>,
+    /// Sanitized HTML
+    pub html: String,
+    /// Document tree without raw HTML
+    pub ast: Vec<DocNode>,
+}
+
+/// A node of the rendered Markdown tree
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DocNode {
+    Heading {
+        level: u8,
+        children: Vec<DocNode>,
+    },
+    Paragraph {
+        children: Vec<DocNode>,
+    },
+    BlockQuote {
+        children: Vec<DocNode>,
+    },
+    CodeBlock {
+        language: Option<String>,
+        value: String,
+    },
+    List {
+        ordered: bool,
+        start: Option<u64>,
+        children: Vec<DocNode>,
+    },
+    ListItem {
+        checked: Option<bool>,
+        children: Vec<DocNode>,
+    },
+    Table {
+        children: Vec<DocNode>,
+    },
+    TableHead {
+        children: Vec<DocNode>,
+    },
+    TableRow {
+        children: Vec<DocNode>,
+    },
+    TableCell {
+        children: Vec<DocNode>,
+    },
+    Emphasis {
+        children: Vec<DocNode>,
+    },
+    Strong {
+        children: Vec<DocNode>,
+    },
+    Strikethrough {
+        children: Vec<DocNode>,
+    },
+    Link {
+        url: String,
+        /// Project-relative path when the link points inside the project
+        path: Option<String>,
+        title: Option<String>,
+        children: Vec<DocNode>,
+    },
+    Image {
+        url: String,
+        path: Option<String>,
+        title: Option<String>,
+        alt: String,
+    },
+    Text {
+        value: String,
+    },
+    InlineCode {
+        value: String,
+    },
+    SoftBreak,
+    HardBreak,
+    ThematicBreak,
+}
+
+/// Render the README and docs/ index of the project at `root`.
+///
+/// `base_url` is the project's API prefix (e.g. `/api/projects/{id}`);
+/// relative images become `{base_url}/docs/assets/{path}` and relative
+/// Markdown links become `{base_url}/readme?path={path}`.
+pub fn project_docs(root: &Path, base_url: &str) -> Result<ProjectDocs, DocsError> {
+    let readme = match find_readme(root)? {
+        Some(path) => Some(render_doc(root, &path, base_url)?),
+        None => None,
+    };
+
+    let docs_index = match DOCS_INDEX_NAMES
+        .iter()
+        .find(|name| root.join(name).is_file())
+    {
+        Some(path) => Some(render_doc(root, path, base_url)?),
+        None => None,
+    };
+
+    Ok(ProjectDocs {
+        readme,
+        docs_index,
+        docs: list_docs(root),
+    })
+}
+
+/// Path of the project's README relative to `root`, if it has one
+pub fn find_readme(root: &Path) -> Result<Option<String>, DocsError> {
+    let mut candidates = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some(rank) = README_NAMES
+            .iter()
+            .position(|readme| name.eq_ignore_ascii_case(readme))
+        {
+            candidates.push((rank, name));
+        }
+    }
+    candidates.sort();
+    Ok(candidates.into_iter().next().map(|(_, name)| name))
+}
+
+/// Markdown files under docs/, sorted, relative to `root`
+pub fn list_docs(root: &Path) -> Vec<String> {
+    let docs_dir = root.join("docs");
+    if !docs_dir.is_dir() {
+        return Vec::new();
+    }
+
+    let mut docs: Vec<String> = WalkDir::new(&docs_dir)
+        .max_depth(4)
+        .into_iter()
+        .filter_entry(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file() && is_markdown(entry.path()))
+        .filter_map(|entry| {
+            entry
+                .path()
+                .strip_prefix(root)
+                .ok()
+                .map(|path| path.to_string_lossy().replace('\\', "/"))
+        })
+        .take(MAX_DOCS_LISTED)
+        .collect();
+    docs.sort();
+    docs
+}
+
+/// Render one Markdown file of the project
+pub fn render_doc(root: &Path, path: &str, base_url: &str) -> Result<RenderedDoc, DocsError> {
+    let relative = normalize(path).ok_or_else(|| DocsError::OutsideProject(path.to_string()))?;
+    let is_readme = README_NAMES
+        .iter()
+        .any(|name| relative.eq_ignore_ascii_case(name));
+    if !is_readme && !is_markdown(Path::new(&relative)) {
+        return Err(DocsError::UnsupportedType(relative));
+    }
+
+    let content = read_project_file(root, &relative, MAX_DOC_BYTES)?;
+    let markdown = String::from_utf8_lossy(&content);
+    Ok(render_markdown(&markdown, &relative, base_url))
+}
+
+/// Read an image referenced from the project's docs, with its content type
+pub fn read_asset(root: &Path, path: &str) -> Result<(Vec<u8>, &'static str), DocsError> {
+    let relative = normalize(path).ok_or_else(|| DocsError::OutsideProject(path.to_string()))?;
+    let content_type = image_content_type(&relative)
+        .ok_or_else(|| DocsError::UnsupportedType(relative.clone()))?;
+    let content = read_project_file(root, &relative, MAX_ASSET_BYTES)?;
+    Ok((content, content_type))
+}
+
+/// Render Markdown from `path` (relative to the project root) to sanitized
+/// HTML and an AST, resolving relative links against the file's directory
+pub fn render_markdown(markdown: &str, path: &str, base_url: &str) -> RenderedDoc {
+    let doc_dir = match path.rfind('/') {
+        Some(index) => &path[..index],
+        None => "",
+    };
+
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_FOOTNOTES;
+
+    let mut paths = Vec::new();
+    let events: Vec<Event> = Parser::new_ext(markdown, options)
+        .map(|event| match event {
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                let (url, path) = resolve_link(&dest_url, doc_dir, base_url, false);
+                paths.push(path);
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url: url.into(),
+                    title,
+                    id,
+                })
+            }
+            Event::Start(Tag::Image {
+                link_type,
+                dest_url,
+                title,
+                id,
+            }) => {
+                let (url, path) = resolve_link(&dest_url, doc_dir, base_url, true);
+                paths.push(path);
+                Event::Start(Tag::Image {
+                    link_type,
+                    dest_url: url.into(),
+                    title,
+                    id,
+                })
+            }
+            event => event,
+        })
+        .collect();
+
+    let mut unsafe_html = String::new();
+    pulldown_cmark::html::push_html(&mut unsafe_html, events.iter().cloned());
+    let html = ammonia::Builder::default()
+        .add_tag_attributes("code", &["class"])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .add_tags(&["input"])
+        .clean(&unsafe_html)
+        .to_string();
+
+    let ast = build_ast(events, paths);
+    let title = ast.iter().find_map(|node| match node {
+        DocNode::Heading { level: 1, children } => Some(plain_text(children)),
+        _ => None,
+    });
+
+    RenderedDoc {
+        path: path.to_string(),
+        title,
+        html,
+        ast,
+    }
+}
+
+/// Rewrite a link or image URL from a project file.
+///
+/// Returns the URL to use and, for links inside the project, the
+/// project-relative path they point to. External links, anchors, and links
+/// escaping the project are left unchanged.
+fn resolve_link(url: &str, doc_dir: &str, base_url: &str, image: bool) -> (String, Option<String>) {
+    if url.is_empty() || url.starts_with('#') || url.starts_with("//") || has_scheme(url) {
+        return (url.to_string(), None);
+    }
+
+    let (without_fragment, fragment) = match url.find('#') {
+        Some(index) => (&url[..index], &url[index..]),
+        None => (url, ""),
+    };
+    let target = without_fragment.split('?').next().unwrap_or_default();
+
+    let joined = match target.strip_prefix('/') {
+        Some(from_root) => from_root.to_string(),
+        None if doc_dir.is_empty() => target.to_string(),
+        None => format!("{}/{}", doc_dir, target),
+    };
+    let Some(path) = normalize(&joined) else {
+        return (url.to_string(), None);
+    };
+
+    let resolved = if image && image_content_type(&path).is_some() {
+        format!("{}/docs/assets/{}", base_url, encode_path(&path))
+    } else if !image && is_markdown(Path::new(&path)) {
+        let query: String = url::form_urlencoded::byte_serialize(path.as_bytes()).collect();
+        format!("{}/readme?path={}{}", base_url, query, fragment)
+    } else {
+        url.to_string()
+    };
+    (resolved, Some(path))
+}
+
+fn has_scheme(url: &str) -> bool {
+    match url.find(':') {
+        Some(index) => {
+            let scheme = &url[..index];
+            !scheme.is_empty()
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
+
+/// Resolve `.` and `..` in a project-relative path. Returns None for paths
+/// that escape the project root or are empty.
+fn normalize(path: &str) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    for component in Path::new(path.trim_start_matches('/')).components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                parts.pop()?;
+            }
+            Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join("/"))
+    }
+}
+
+/// Read a file by project-relative path, refusing symlinks that lead outside the project
+fn read_project_file(root: &Path, relative: &str, limit: u64) -> Result<Vec<u8>, DocsError> {
+    let root = root.canonicalize()?;
+    let full: PathBuf = match root.join(relative).canonicalize() {
+        Ok(path) => path,
+        Err(_) => return Err(DocsError::NotFound(relative.to_string())),
+    };
+    if !full.starts_with(&root) {
+        return Err(DocsError::OutsideProject(relative.to_string()));
+    }
+
+    let metadata = fs::metadata(&full)?;
+    if !metadata.is_file() {
+        return Err(DocsError::NotFound(relative.to_string()));
+    }
+    if metadata.len() > limit {
+        return Err(DocsError::TooLarge {
+            path: relative.to_string(),
+            limit,
+        });
+    }
+    Ok(fs::read(full)?)
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            MARKDOWN_EXTENSIONS
+                .iter()
+                .any(|md| ext.eq_ignore_ascii_case(md))
+        })
+}
+
+fn image_content_type(path: &str) -> Option<&'static str> {
+    let ext = Path::new(path).extension()?.to_str()?.to_ascii_lowercase();
+    match ext.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "webp" => Some("image/webp"),
+        "svg" => Some("image/svg+xml"),
+        "ico" => Some("image/x-icon"),
+        "avif" => Some("image/avif"),
+        _ => None,
+    }
+}
+
+/// Percent-encode a path for use in a URL, keeping `/` separators
+fn encode_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'/' | b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// An open container while building the AST
+enum Frame {
+    Node(DocNode),
+    /// Containers without an AST node (raw HTML, footnotes, metadata);
+    /// their children are kept in the parent
+    Transparent,
+}
+
+fn build_ast(events: Vec<Event>, paths: Vec<Option<String>>) -> Vec<DocNode> {
+    let mut paths = paths.into_iter();
+    let mut stack: Vec<(Frame, Vec<DocNode>)> = vec![(Frame::Transparent, Vec::new())];
+
+    for event in events {
+        match event {
+            Event::Start(tag) => {
+                let frame = match tag {
+                    Tag::Paragraph => Frame::Node(DocNode::Paragraph { children: vec![] }),
+                    Tag::Heading { level, .. } => Frame::Node(DocNode::Heading {
+                        level: level as u8,
+                        children: vec![],
+                    }),
+                    Tag::BlockQuote(_) => Frame::Node(DocNode::BlockQuote { children: vec![] }),
+                    Tag::CodeBlock(kind) => Frame::Node(DocNode::CodeBlock {
+                        language: match kind {
+                            CodeBlockKind::Fenced(lang) if !lang.is_empty() => {
+                                Some(lang.to_string())
+                            }
+                            _ => None,
+                        },
+                        value: String::new(),
+                    }),
+                    Tag::List(start) => Frame::Node(DocNode::List {
+                        ordered: start.is_some(),
+                        start,
+                        children: vec![],
+                    }),
+                    Tag::Item => Frame::Node(DocNode::ListItem {
+                        checked: None,
+                        children: vec![],
+                    }),
+                    Tag::Table(_) => Frame::Node(DocNode::Table { children: vec![] }),
+                    Tag::TableHead => Frame::Node(DocNode::TableHead { children: vec![] }),
+                    Tag::TableRow => Frame::Node(DocNode::TableRow { children: vec![] }),
+                    Tag::TableCell => Frame::Node(DocNode::TableCell { children: vec![] }),
+                    Tag::Emphasis => Frame::Node(DocNode::Emphasis { children: vec![] }),
+                    Tag::Strong => Frame::Node(DocNode::Strong { children: vec![] }),
+                    Tag::Strikethrough => Frame::Node(DocNode::Strikethrough { children: vec![] }),
+                    Tag::Link {
+                        dest_url, title, ..
+                    } => Frame::Node(DocNode::Link {
+                        url: dest_url.to_string(),
+                        path: paths.next().flatten(),
+                        title: non_empty(title),
+                        children: vec![],
+                    }),
+                    Tag::Image {
+                        dest_url, title, ..
+                    } => Frame::Node(DocNode::Image {
+                        url: dest_url.to_string(),
+                        path: paths.next().flatten(),
+                        title: non_empty(title),
+                        alt: String::new(),
+                    }),
+                    _ => Frame::Transparent,
+                };
+                stack.push((frame, Vec::new()));
+            }
+            Event::End(_) => {
+                let Some((frame, children)) = stack.pop() else {
+                    continue;
+                };
+                let Some((_, parent)) = stack.last_mut() else {
+                    continue;
+                };
+                match frame {
+                    Frame::Node(node) => parent.push(close(node, children)),
+                    Frame::Transparent => parent.extend(children),
+                }
+            }
+            Event::Text(text) => push_leaf(
+                &mut stack,
+                DocNode::Text {
+                    value: text.to_string(),
+                },
+            ),
+            Event::Code(code) => push_leaf(
+                &mut stack,
+                DocNode::InlineCode {
+                    value: code.to_string(),
+                },
+            ),
+            Event::SoftBreak => push_leaf(&mut stack, DocNode::SoftBreak),
+            Event::HardBreak => push_leaf(&mut stack, DocNode::HardBreak),
+            Event::Rule => push_leaf(&mut stack, DocNode::ThematicBreak),
+            Event::TaskListMarker(checked) => {
+                if let Some((Frame::Node(DocNode::ListItem { checked: slot, .. }), _)) = stack
+                    .iter_mut()
+                    .rev()
+                    .find(|(frame, _)| matches!(frame, Frame::Node(DocNode::ListItem { .. })))
+                {
+                    *slot = Some(checked);
+                }
+            }
+            // Raw HTML is only rendered through the sanitizer
+            _ => {}
+        }
+    }
+
+    stack.pop().map(|(_, nodes)| nodes).unwrap_or_default()
+}
+
+fn push_leaf(stack: &mut [(Frame, Vec<DocNode>)], node: DocNode) {
+    if let Some((frame, children)) = stack.last_mut() {
+        match frame {
+            Frame::Node(DocNode::CodeBlock { value, .. }) => {
+                if let DocNode::Text { value: text } = node {
+                    value.push_str(&text);
+                }
+            }
+            _ => children.push(node),
+        }
+    }
+}
+
+/// Attach collected children to a finished container
+fn close(node: DocNode, collected: Vec<DocNode>) -> DocNode {
+    match node {
+        DocNode::Paragraph { .. } => DocNode::Paragraph {
+            children: collected,
+        },
+        DocNode::Heading { level, .. } => DocNode::Heading {
+            level,
+            children: collected,
+        },
+        DocNode::BlockQuote { .. } => DocNode::BlockQuote {
+            children: collected,
+        },
+        DocNode::List { ordered, start, .. } => DocNode::List {
+            ordered,
+            start,
+            children: collected,
+        },
+        DocNode::ListItem { checked, .. } => DocNode::ListItem {
+            checked,
+            children: collected,
+        },
+        DocNode::Table { .. } => DocNode::Table {
+            children: collected,
+        },
+        DocNode::TableHead { .. } => DocNode::TableHead {
+            children: collected,
+        },
+        DocNode::TableRow { .. } => DocNode::TableRow {
+            children: collected,
+        },
+        DocNode::TableCell { .. } => DocNode::TableCell {
+            children: collected,
+        },
+        DocNode::Emphasis { .. } => DocNode::Emphasis {
+            children: collected,
+        },
+        DocNode::Strong { .. } => DocNode::Strong {
+            children: collected,
+        },
+        DocNode::Strikethrough { .. } => DocNode::Strikethrough {
+            children: collected,
+        },
+        DocNode::Link {
+            url, path, title, ..
+        } => DocNode::Link {
+            url,
+            path,
+            title,
+            children: collected,
+        },
+        DocNode::Image {
+            url, path, title, ..
+        } => DocNode::Image {
+            url,
+            path,
+            title,
+            alt: plain_text(&collected),
+        },
+        leaf => leaf,
+    }
+}
+
+fn non_empty(value: CowStr) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+/// Concatenated text content of a list of nodes
+fn plain_text(nodes: &[DocNode]) -> String {
+    let mut text = String::new();
+    for node in nodes {
+        match node {
+            DocNode::Text { value } | DocNode::InlineCode { value } => text.push_str(value),
+            DocNode::SoftBreak | DocNode::HardBreak => text.push(' '),
+            DocNode::Heading { children, .. }
+            | DocNode::Paragraph { children }
+            | DocNode::Emphasis { children }
+            | DocNode::Strong { children }
+            | DocNode::Strikethrough { children }
+            | DocNode::Link { children, .. } => text.push_str(&plain_text(children)),
+            DocNode::Image { alt, .. } => text.push_str(alt),
+            _ => {}
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const BASE: &str = "/api/projects/proj-1";
+
+    #[test]
+    fn test_render_resolves_links_and_sanitizes() {
+        let markdown = "# My Project\n\n\
+            See [setup](docs/setup.md#install) and [site](https://example.com).\n\n\
+            ![logo](./assets/logo.png \"Logo\")\n\n\
+            <script>alert(1)</script>\n\n\
+            - [x] done\n\
+            - [ ] todo\n\n\
+            ```rust\nfn main() {}\n```\n";
+        let doc = render_markdown(markdown, "README.md", BASE);
+
+        assert_eq!(doc.title.as_deref(), Some("My Project"));
+        assert!(!doc.html.contains("<script"));
+        assert!(doc
+            .html
+            .contains("href=\"/api/projects/proj-1/readme?path=docs%2Fsetup.md#install\""));
+        assert!(doc
+            .html
+            .contains("src=\"/api/projects/proj-1/docs/assets/assets/logo.png\""));
+        assert!(doc.html.contains("class=\"language-rust\""));
+
+        let DocNode::Paragraph { children } = &doc.ast[1] else {
+            panic!("expected paragraph, got {:?}", doc.ast[1]);
+        };
+        let links: Vec<_> = children
+            .iter()
+            .filter_map(|node| match node {
+                DocNode::Link { url, path, .. } => Some((url.as_str(), path.as_deref())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            links,
+            vec![
+                (
+                    "/api/projects/proj-1/readme?path=docs%2Fsetup.md#install",
+                    Some("docs/setup.md")
+                ),
+                ("https://example.com", None),
+            ]
+        );
+
+        assert!(matches!(
+            &doc.ast[2],
+            DocNode::Paragraph { children } if matches!(
+                &children[0],
+                DocNode::Image { alt, title: Some(title), path: Some(path), .. }
+                    if alt == "logo" && title == "Logo" && path == "assets/logo.png"
+            )
+        ));
+        assert!(matches!(
+            &doc.ast[3],
+            DocNode::List { ordered: false, children, .. } if matches!(
+                children.as_slice(),
+                [DocNode::ListItem { checked: Some(true), .. }, DocNode::ListItem { checked: Some(false), .. }]
+            )
+        ));
+        assert_eq!(
+            doc.ast[4],
+            DocNode::CodeBlock {
+                language: Some("rust".to_string()),
+                value: "fn main() {}\n".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_links_resolve_against_document_directory() {
+        let doc = render_markdown(
+            "[up](../README.md) [sibling](guide.md) [escape](../../etc/passwd) [root](/CHANGELOG.md)",
+            "docs/index.md",
+            BASE,
+        );
+        let DocNode::Paragraph { children } = &doc.ast[0] else {
+            panic!("expected paragraph");
+        };
+        let paths: Vec<_> = children
+            .iter()
+            .filter_map(|node| match node {
+                DocNode::Link { path, .. } => Some(path.as_deref()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                Some("README.md"),
+                Some("docs/guide.md"),
+                None,
+                Some("CHANGELOG.md")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_project_docs_and_assets() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("docs/guides")).unwrap();
+        fs::create_dir_all(root.join(".git")).unwrap();
+        fs::write(root.join("Readme.md"), "# Hello\n").unwrap();
+        fs::write(root.join("docs/index.md"), "# Docs\n").unwrap();
+        fs::write(root.join("docs/guides/setup.md"), "Setup\n").unwrap();
+        fs::write(root.join("docs/diagram.png"), b"\x89PNG").unwrap();
+        fs::write(root.join("secret.txt"), "hunter2").unwrap();
+
+        let docs = project_docs(root, BASE).unwrap();
+        assert_eq!(docs.readme.unwrap().path, "Readme.md");
+        assert_eq!(docs.docs_index.unwrap().title.as_deref(), Some("Docs"));
+        assert_eq!(docs.docs, vec!["docs/guides/setup.md", "docs/index.md"]);
+
+        let (bytes, content_type) = read_asset(root, "docs/diagram.png").unwrap();
+        assert_eq!(content_type, "image/png");
+        assert_eq!(bytes, b"\x89PNG");
+
+        assert!(matches!(
+            read_asset(root, "secret.txt"),
+            Err(DocsError::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            render_doc(root, "../outside.md", BASE),
+            Err(DocsError::OutsideProject(_))
+        ));
+        assert!(matches!(
+            render_doc(root, "docs/missing.md", BASE),
+            Err(DocsError::NotFound(_))
+        ));
+
+        let empty = tempdir().unwrap();
+        let docs = project_docs(empty.path(), BASE).unwrap();
+        assert!(docs.readme.is_none() && docs.docs_index.is_none() && docs.docs.is_empty());
+    }
+}
@@ -0,0 +1,782 @@
+// ABOUTME: Runs a project's setup, dev, or cleanup script in its root with streamed output
+// ABOUTME: Enforces timeouts, supports cancellation, and records each run's exit status history
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, Row, SqlitePool};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::process::{ExitStatus, Stdio};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::{broadcast, mpsc, Notify};
+use tracing::{debug, info, warn};
+
+use orkee_core::Project;
+use orkee_storage::StorageError;
+
+/// Longest timeout a caller may request for a single run
+pub const MAX_TIMEOUT_SECS: u64 = 4 * 60 * 60;
+
+/// Output lines kept in a run's history
+const OUTPUT_TAIL_LINES: usize = 200;
+
+/// Time a script gets to exit after SIGTERM before it is killed
+const KILL_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Time to wait for buffered output once the script has exited. Background
+/// processes it started can hold the pipes open indefinitely.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Which of a project's configured scripts to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptKind {
+    Setup,
+    Dev,
+    Cleanup,
+}
+
+impl ScriptKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScriptKind::Setup => "setup",
+            ScriptKind::Dev => "dev",
+            ScriptKind::Cleanup => "cleanup",
+        }
+    }
+
+    /// The project's command for this script, if one is configured
+    pub fn command(self, project: &Project) -> Option<&str> {
+        let script = match self {
+            ScriptKind::Setup => &project.setup_script,
+            ScriptKind::Dev => &project.dev_script,
+            ScriptKind::Cleanup => &project.cleanup_script,
+        };
+        script
+            .as_deref()
+            .map(str::trim)
+            .filter(|command| !command.is_empty())
+    }
+
+    /// Dev scripts usually keep running, so they get longer than one-shot scripts
+    pub fn default_timeout(self) -> Duration {
+        match self {
+            ScriptKind::Setup | ScriptKind::Cleanup => Duration::from_secs(10 * 60),
+            ScriptKind::Dev => Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl fmt::Display for ScriptKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ScriptKind {
+    type Err = ScriptError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "setup" => Ok(ScriptKind::Setup),
+            "dev" => Ok(ScriptKind::Dev),
+            "cleanup" => Ok(ScriptKind::Cleanup),
+            other => Err(ScriptError::UnknownKind(other.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScriptRunStatus {
+    Running,
+    Succeeded,
+    Failed,
+    TimedOut,
+    Cancelled,
+}
+
+impl ScriptRunStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScriptRunStatus::Running => "running",
+            ScriptRunStatus::Succeeded => "succeeded",
+            ScriptRunStatus::Failed => "failed",
+            ScriptRunStatus::TimedOut => "timed_out",
+            ScriptRunStatus::Cancelled => "cancelled",
+        }
+    }
+
+    fn parse(s: &str) -> Result<Self, StorageError> {
+        match s {
+            "running" => Ok(ScriptRunStatus::Running),
+            "succeeded" => Ok(ScriptRunStatus::Succeeded),
+            "failed" => Ok(ScriptRunStatus::Failed),
+            "timed_out" => Ok(ScriptRunStatus::TimedOut),
+            "cancelled" => Ok(ScriptRunStatus::Cancelled),
+            other => Err(StorageError::Database(format!(
+                "Unknown script run status: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// One execution of a project script
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScriptRun {
+    pub id: String,
+    pub project_id: String,
+    pub kind: ScriptKind,
+    pub command: String,
+    pub status: ScriptRunStatus,
+    /// None while running, or when the script was killed by a signal
+    pub exit_code: Option<i32>,
+    pub timeout_secs: u64,
+    /// The last lines of stdout and stderr, interleaved as they arrived
+    pub output_tail: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+/// Progress of a run, streamed to subscribers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScriptEvent {
+    Started { run: ScriptRun },
+    Output { stream: OutputStream, line: String },
+    Finished { run: ScriptRun },
+}
+
+#[derive(Debug, Error)]
+pub enum ScriptError {
+    #[error("Unknown script kind '{0}': expected setup, dev, or cleanup")]
+    UnknownKind(String),
+    #[error("Project has no {0} script configured")]
+    NotConfigured(ScriptKind),
+    #[error("A {kind} script is already running for this project (run {run_id})")]
+    AlreadyRunning { kind: ScriptKind, run_id: String },
+    #[error("Timeout must be between 1 and {MAX_TIMEOUT_SECS} seconds")]
+    InvalidTimeout,
+    #[error("Script run not found: {0}")]
+    RunNotFound(String),
+    #[error("Script run {0} is not running")]
+    NotRunning(String),
+    #[error("Failed to start script: {0}")]
+    Spawn(#[from] std::io::Error),
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+/// A run whose process is still alive
+struct ActiveRun {
+    project_id: String,
+    kind: ScriptKind,
+    events: broadcast::Sender<ScriptEvent>,
+    cancel: Arc<Notify>,
+}
+
+type ActiveRuns = Arc<Mutex<HashMap<String, ActiveRun>>>;
+
+/// How the wait for a script ended
+enum Outcome {
+    Exited(std::io::Result<ExitStatus>),
+    TimedOut,
+    Cancelled,
+}
+
+/// Runs project scripts and keeps their history in project_script_runs
+pub struct ScriptRunner {
+    pool: SqlitePool,
+    active: ActiveRuns,
+}
+
+impl ScriptRunner {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            active: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Start one of the project's scripts in its root directory.
+    ///
+    /// The returned receiver sees every event of the run, starting with
+    /// `Started`. The run continues when the receiver is dropped.
+    pub async fn start(
+        &self,
+        project: &Project,
+        kind: ScriptKind,
+        timeout_secs: Option<u64>,
+        env: HashMap<String, String>,
+    ) -> Result<(ScriptRun, broadcast::Receiver<ScriptEvent>), ScriptError> {
+        let command = kind
+            .command(project)
+            .ok_or(ScriptError::NotConfigured(kind))?
+            .to_string();
+        let timeout = match timeout_secs {
+            Some(secs) if secs == 0 || secs > MAX_TIMEOUT_SECS => {
+                return Err(ScriptError::InvalidTimeout)
+            }
+            Some(secs) => Duration::from_secs(secs),
+            None => kind.default_timeout(),
+        };
+
+        let run_id = uuid::Uuid::new_v4().to_string();
+        let (events, receiver) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let cancel = Arc::new(Notify::new());
+        self.reserve(&run_id, &project.id, kind, events.clone(), cancel.clone())?;
+
+        let started = match self
+            .spawn(project, kind, &run_id, &command, timeout, env)
+            .await
+        {
+            Ok(started) => started,
+            Err(e) => {
+                self.active.lock().unwrap().remove(&run_id);
+                return Err(e);
+            }
+        };
+        let (run, child) = started;
+
+        info!(
+            "Started {} script for project {} (run {}): {}",
+            kind, project.id, run_id, command
+        );
+        let _ = events.send(ScriptEvent::Started { run: run.clone() });
+
+        tokio::spawn(supervise(
+            self.pool.clone(),
+            self.active.clone(),
+            run_id,
+            child,
+            timeout,
+            events,
+            cancel,
+        ));
+
+        Ok((run, receiver))
+    }
+
+    /// Claim the project's script slot so the same script never runs twice at once
+    fn reserve(
+        &self,
+        run_id: &str,
+        project_id: &str,
+        kind: ScriptKind,
+        events: broadcast::Sender<ScriptEvent>,
+        cancel: Arc<Notify>,
+    ) -> Result<(), ScriptError> {
+        let mut active = self.active.lock().unwrap();
+        if let Some((existing, _)) = active
+            .iter()
+            .find(|(_, run)| run.project_id == project_id && run.kind == kind)
+        {
+            return Err(ScriptError::AlreadyRunning {
+                kind,
+                run_id: existing.clone(),
+            });
+        }
+        active.insert(
+            run_id.to_string(),
+            ActiveRun {
+                project_id: project_id.to_string(),
+                kind,
+                events,
+                cancel,
+            },
+        );
+        Ok(())
+    }
+
+    /// Record the run and start its process. A script that cannot be spawned
+    /// is still recorded as failed.
+    async fn spawn(
+        &self,
+        project: &Project,
+        kind: ScriptKind,
+        run_id: &str,
+        command: &str,
+        timeout: Duration,
+        env: HashMap<String, String>,
+    ) -> Result<(ScriptRun, Child), ScriptError> {
+        sqlx::query(
+            r#"
+            INSERT INTO project_script_runs (id, project_id, kind, command, timeout_secs)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(run_id)
+        .bind(&project.id)
+        .bind(kind.as_str())
+        .bind(command)
+        .bind(timeout.as_secs() as i64)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        match shell_command(command)
+            .current_dir(&project.project_root)
+            .envs(env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+        {
+            Ok(child) => Ok((self.get_run(run_id).await?, child)),
+            Err(e) => {
+                warn!("Failed to spawn {} script for {}: {}", kind, project.id, e);
+                finish_run(
+                    &self.pool,
+                    run_id,
+                    ScriptRunStatus::Failed,
+                    None,
+                    &format!("Failed to start script: {}", e),
+                    0,
+                )
+                .await?;
+                Err(ScriptError::Spawn(e))
+            }
+        }
+    }
+
+    /// Ask a running script to stop. It gets SIGTERM, then SIGKILL after a grace period.
+    pub async fn cancel(&self, run_id: &str) -> Result<ScriptRun, ScriptError> {
+        let cancel = self
+            .active
+            .lock()
+            .unwrap()
+            .get(run_id)
+            .map(|run| run.cancel.clone());
+
+        let run = self.get_run(run_id).await?;
+        match cancel {
+            Some(cancel) => {
+                info!("Cancelling script run {}", run_id);
+                cancel.notify_one();
+                Ok(run)
+            }
+            None => Err(ScriptError::NotRunning(run_id.to_string())),
+        }
+    }
+
+    /// Events of a run that is still going, or None once it has finished
+    pub fn subscribe(&self, run_id: &str) -> Option<broadcast::Receiver<ScriptEvent>> {
+        self.active
+            .lock()
+            .unwrap()
+            .get(run_id)
+            .map(|run| run.events.subscribe())
+    }
+
+    pub async fn get_run(&self, run_id: &str) -> Result<ScriptRun, ScriptError> {
+        let row = sqlx::query("SELECT * FROM project_script_runs WHERE id = ?")
+            .bind(run_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?
+            .ok_or_else(|| ScriptError::RunNotFound(run_id.to_string()))?;
+        Ok(run_from_row(&row)?)
+    }
+
+    /// A project's runs, newest first
+    pub async fn list_runs(
+        &self,
+        project_id: &str,
+        kind: Option<ScriptKind>,
+        limit: i64,
+    ) -> Result<Vec<ScriptRun>, ScriptError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM project_script_runs
+            WHERE project_id = ? AND (? IS NULL OR kind = ?)
+            ORDER BY started_at DESC, rowid DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(project_id)
+        .bind(kind.map(ScriptKind::as_str))
+        .bind(kind.map(ScriptKind::as_str))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter()
+            .map(|row| run_from_row(row).map_err(ScriptError::from))
+            .collect()
+    }
+
+    /// Mark runs left over from a previous server process as failed.
+    /// Their processes were killed along with that server.
+    pub async fn recover_interrupted_runs(&self) -> Result<u64, ScriptError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE project_script_runs
+            SET status = 'failed',
+                output_tail = 'Interrupted: Orkee stopped before the script finished',
+                finished_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            WHERE status = 'running'
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        if result.rows_affected() > 0 {
+            info!(
+                "Marked {} interrupted script runs as failed",
+                result.rows_affected()
+            );
+        }
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(unix)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("sh");
+    cmd.args(["-c", command]);
+    // Own process group so a timeout also stops everything the script started
+    cmd.process_group(0);
+    cmd
+}
+
+#[cfg(windows)]
+fn shell_command(command: &str) -> Command {
+    let mut cmd = Command::new("cmd");
+    cmd.args(["/C", command]);
+    cmd
+}
+
+/// Stream the script's output until it exits, times out, or is cancelled,
+/// then record the outcome
+async fn supervise(
+    pool: SqlitePool,
+    active: ActiveRuns,
+    run_id: String,
+    mut child: Child,
+    timeout: Duration,
+    events: broadcast::Sender<ScriptEvent>,
+    cancel: Arc<Notify>,
+) {
+    let started = Instant::now();
+    let (line_tx, mut lines) = mpsc::channel(256);
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, OutputStream::Stdout, line_tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, OutputStream::Stderr, line_tx);
+    }
+
+    let mut tail = VecDeque::with_capacity(OUTPUT_TAIL_LINES);
+    let mut record = |stream: OutputStream, line: String| {
+        if tail.len() == OUTPUT_TAIL_LINES {
+            tail.pop_front();
+        }
+        tail.push_back(line.clone());
+        let _ = events.send(ScriptEvent::Output { stream, line });
+    };
+
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+    let outcome = loop {
+        tokio::select! {
+            Some((stream, line)) = lines.recv() => record(stream, line),
+            status = child.wait() => break Outcome::Exited(status),
+            _ = &mut deadline => break Outcome::TimedOut,
+            _ = cancel.notified() => break Outcome::Cancelled,
+        }
+    };
+
+    let (status, exit_code) = match outcome {
+        Outcome::Exited(Ok(status)) if status.success() => {
+            (ScriptRunStatus::Succeeded, status.code())
+        }
+        Outcome::Exited(Ok(status)) => (ScriptRunStatus::Failed, status.code()),
+        Outcome::Exited(Err(e)) => {
+            warn!("Failed to wait for script run {}: {}", run_id, e);
+            (ScriptRunStatus::Failed, None)
+        }
+        Outcome::TimedOut => {
+            warn!(
+                "Script run {} timed out after {}s",
+                run_id,
+                timeout.as_secs()
+            );
+            (ScriptRunStatus::TimedOut, terminate(&mut child).await)
+        }
+        Outcome::Cancelled => (ScriptRunStatus::Cancelled, terminate(&mut child).await),
+    };
+
+    let _ = tokio::time::timeout(OUTPUT_DRAIN_TIMEOUT, async {
+        while let Some((stream, line)) = lines.recv().await {
+            record(stream, line);
+        }
+    })
+    .await;
+
+    let output_tail = Vec::from(tail).join("\n");
+    let duration_ms = started.elapsed().as_millis() as i64;
+    let finished =
+        match finish_run(&pool, &run_id, status, exit_code, &output_tail, duration_ms).await {
+            Ok(()) => fetch_run(&pool, &run_id).await,
+            Err(e) => Err(e),
+        };
+
+    // Free the slot before announcing the result so a follow-up run isn't rejected
+    active.lock().unwrap().remove(&run_id);
+    match finished {
+        Ok(run) => {
+            debug!("Script run {} finished: {:?}", run_id, run.status);
+            let _ = events.send(ScriptEvent::Finished { run });
+        }
+        Err(e) => warn!("Failed to record result of script run {}: {}", run_id, e),
+    }
+}
+
+/// Send each line the script writes to the supervisor
+fn forward_lines<R>(reader: R, stream: OutputStream, lines: mpsc::Sender<(OutputStream, String)>)
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let mut reader = BufReader::new(reader);
+        let mut buf = Vec::new();
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    let line = String::from_utf8_lossy(&buf)
+                        .trim_end_matches(['\r', '\n'])
+                        .to_string();
+                    if lines.send((stream, line)).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Stop the script and everything it started, returning its exit code if it had one
+async fn terminate(child: &mut Child) -> Option<i32> {
+    #[cfg(unix)]
+    if let Some(pid) = child.id() {
+        use nix::sys::signal::{killpg, Signal};
+        use nix::unistd::Pid;
+
+        let group = Pid::from_raw(pid as i32);
+        if let Err(e) = killpg(group, Signal::SIGTERM) {
+            debug!("Failed to send SIGTERM to process group {}: {}", pid, e);
+        }
+        if let Ok(Ok(status)) = tokio::time::timeout(KILL_GRACE_PERIOD, child.wait()).await {
+            // Reap anything the script left behind in its group
+            let _ = killpg(group, Signal::SIGKILL);
+            return status.code();
+        }
+        warn!("Script process {} ignored SIGTERM, sending SIGKILL", pid);
+        let _ = killpg(group, Signal::SIGKILL);
+    }
+
+    if let Err(e) = child.kill().await {
+        warn!("Failed to kill script process: {}", e);
+    }
+    child.wait().await.ok().and_then(|status| status.code())
+}
+
+async fn finish_run(
+    pool: &SqlitePool,
+    run_id: &str,
+    status: ScriptRunStatus,
+    exit_code: Option<i32>,
+    output_tail: &str,
+    duration_ms: i64,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        r#"
+        UPDATE project_script_runs
+        SET status = ?,
+            exit_code = ?,
+            output_tail = ?,
+            duration_ms = ?,
+            finished_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+        WHERE id = ?
+        "#,
+    )
+    .bind(status.as_str())
+    .bind(exit_code)
+    .bind(output_tail)
+    .bind(duration_ms)
+    .bind(run_id)
+    .execute(pool)
+    .await
+    .map_err(StorageError::Sqlx)?;
+    Ok(())
+}
+
+async fn fetch_run(pool: &SqlitePool, run_id: &str) -> Result<ScriptRun, StorageError> {
+    let row = sqlx::query("SELECT * FROM project_script_runs WHERE id = ?")
+        .bind(run_id)
+        .fetch_one(pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+    run_from_row(&row)
+}
+
+fn run_from_row(row: &SqliteRow) -> Result<ScriptRun, StorageError> {
+    let kind: String = row.try_get("kind")?;
+    let status: String = row.try_get("status")?;
+    let timeout_secs: i64 = row.try_get("timeout_secs")?;
+
+    Ok(ScriptRun {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        kind: kind
+            .parse()
+            .map_err(|e: ScriptError| StorageError::Database(e.to_string()))?,
+        command: row.try_get("command")?,
+        status: ScriptRunStatus::parse(&status)?,
+        exit_code: row.try_get("exit_code")?,
+        timeout_secs: timeout_secs as u64,
+        output_tail: row.try_get("output_tail")?,
+        started_at: row.try_get("started_at")?,
+        finished_at: row.try_get("finished_at")?,
+        duration_ms: row.try_get("duration_ms")?,
+    })
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    async fn setup() -> (ScriptRunner, tempfile::TempDir) {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+             VALUES ('project-1', 'Test Project', '/test/path', datetime('now'), datetime('now'))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        (ScriptRunner::new(pool), tempfile::tempdir().unwrap())
+    }
+
+    fn project(root: &std::path::Path, setup: &str, dev: &str) -> Project {
+        serde_json::from_value(serde_json::json!({
+            "id": "project-1",
+            "name": "Test Project",
+            "projectRoot": root,
+            "setupScript": setup,
+            "devScript": dev,
+            "createdAt": Utc::now(),
+            "updatedAt": Utc::now(),
+        }))
+        .unwrap()
+    }
+
+    async fn wait_for_finish(
+        mut events: broadcast::Receiver<ScriptEvent>,
+    ) -> (Vec<String>, ScriptRun) {
+        let mut lines = Vec::new();
+        loop {
+            match events.recv().await.unwrap() {
+                ScriptEvent::Output { line, .. } => lines.push(line),
+                ScriptEvent::Finished { run } => return (lines, run),
+                ScriptEvent::Started { .. } => {}
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_records_output_and_exit_status() {
+        let (runner, dir) = setup().await;
+        let project = project(dir.path(), "echo hello; echo oops >&2; exit 3", "sleep 30");
+
+        let (run, events) = runner
+            .start(&project, ScriptKind::Setup, None, HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(run.status, ScriptRunStatus::Running);
+
+        let (lines, finished) = wait_for_finish(events).await;
+        assert!(lines.contains(&"hello".to_string()));
+        assert!(lines.contains(&"oops".to_string()));
+        assert_eq!(finished.status, ScriptRunStatus::Failed);
+        assert_eq!(finished.exit_code, Some(3));
+        assert!(finished.output_tail.contains("hello"));
+
+        let history = runner
+            .list_runs("project-1", Some(ScriptKind::Setup), 10)
+            .await
+            .unwrap();
+        assert_eq!(history, vec![finished]);
+    }
+
+    #[tokio::test]
+    async fn test_timeout_and_concurrent_runs() {
+        let (runner, dir) = setup().await;
+        let project = project(dir.path(), "pwd", "sleep 30");
+
+        let (run, events) = runner
+            .start(&project, ScriptKind::Dev, Some(1), HashMap::new())
+            .await
+            .unwrap();
+        assert!(matches!(
+            runner
+                .start(&project, ScriptKind::Dev, None, HashMap::new())
+                .await,
+            Err(ScriptError::AlreadyRunning { .. })
+        ));
+
+        let (_, finished) = wait_for_finish(events).await;
+        assert_eq!(finished.id, run.id);
+        assert_eq!(finished.status, ScriptRunStatus::TimedOut);
+        assert!(runner.subscribe(&run.id).is_none());
+        assert!(matches!(
+            runner.cancel(&run.id).await,
+            Err(ScriptError::NotRunning(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_missing_script_and_bad_timeout() {
+        let (runner, dir) = setup().await;
+        let project = project(dir.path(), "true", "true");
+
+        assert!(matches!(
+            runner
+                .start(&project, ScriptKind::Cleanup, None, HashMap::new())
+                .await,
+            Err(ScriptError::NotConfigured(ScriptKind::Cleanup))
+        ));
+        assert!(matches!(
+            runner
+                .start(&project, ScriptKind::Setup, Some(0), HashMap::new())
+                .await,
+            Err(ScriptError::InvalidTimeout)
+        ));
+    }
+}
@@ -0,0 +1,212 @@
+// ABOUTME: Database operations for the offline sync operation queue
+// ABOUTME: Enqueues with coalescing, lists and inspects operations, and records replay attempts
+
+use super::types::*;
+use chrono::Utc;
+use sqlx::{Pool, Sqlite};
+
+#[derive(Debug, thiserror::Error)]
+pub enum DbError {
+    #[error("Database error: {0}")]
+    SqlxError(#[from] sqlx::Error),
+
+    #[error("Entity not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("JSON serialization error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+pub type DbResult<T> = Result<T, DbError>;
+
+/// Queue an operation. If an operation of the same type for the same target is
+/// already pending, its payload is refreshed instead of adding a duplicate so
+/// that replay sends the latest intent once.
+pub async fn enqueue_operation(
+    pool: &Pool<Sqlite>,
+    input: EnqueueInput,
+) -> DbResult<QueuedOperation> {
+    if input.target_id.trim().is_empty() {
+        return Err(DbError::InvalidInput(
+            "Queued operation target cannot be empty".to_string(),
+        ));
+    }
+
+    let now = Utc::now();
+    let payload = serde_json::to_string(&input.payload)?;
+
+    let existing = sqlx::query_as::<_, QueuedOperation>(
+        "SELECT * FROM sync_operation_queue
+         WHERE operation_type = ? AND target_id = ? AND status = 'pending'
+         ORDER BY queued_at LIMIT 1",
+    )
+    .bind(input.operation_type)
+    .bind(&input.target_id)
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(existing) = existing {
+        let operation = sqlx::query_as::<_, QueuedOperation>(
+            "UPDATE sync_operation_queue SET payload = ?, queued_at = ?, updated_at = ?
+             WHERE id = ? RETURNING *",
+        )
+        .bind(&payload)
+        .bind(now)
+        .bind(now)
+        .bind(&existing.id)
+        .fetch_one(pool)
+        .await?;
+        return Ok(operation);
+    }
+
+    let id = orkee_core::generate_project_id();
+    let operation = sqlx::query_as::<_, QueuedOperation>(
+        r#"
+        INSERT INTO sync_operation_queue (id, operation_type, target_id, project_id, payload, queued_at, created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        RETURNING *
+        "#,
+    )
+    .bind(&id)
+    .bind(input.operation_type)
+    .bind(&input.target_id)
+    .bind(&input.project_id)
+    .bind(&payload)
+    .bind(now)
+    .bind(now)
+    .bind(now)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(operation)
+}
+
+/// List queued operations in replay (FIFO) order, optionally filtered by status
+pub async fn list_operations(
+    pool: &Pool<Sqlite>,
+    status: Option<QueueStatus>,
+) -> DbResult<Vec<QueuedOperation>> {
+    let operations = match status {
+        Some(status) => {
+            sqlx::query_as::<_, QueuedOperation>(
+                "SELECT * FROM sync_operation_queue WHERE status = ? ORDER BY queued_at, id",
+            )
+            .bind(status)
+            .fetch_all(pool)
+            .await?
+        }
+        None => {
+            sqlx::query_as::<_, QueuedOperation>(
+                "SELECT * FROM sync_operation_queue ORDER BY queued_at, id",
+            )
+            .fetch_all(pool)
+            .await?
+        }
+    };
+
+    Ok(operations)
+}
+
+pub async fn get_operation(pool: &Pool<Sqlite>, id: &str) -> DbResult<QueuedOperation> {
+    sqlx::query_as::<_, QueuedOperation>("SELECT * FROM sync_operation_queue WHERE id = ?")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| DbError::NotFound(format!("Queued operation not found: {}", id)))
+}
+
+/// Discard a queued operation
+pub async fn delete_operation(pool: &Pool<Sqlite>, id: &str) -> DbResult<()> {
+    let result = sqlx::query("DELETE FROM sync_operation_queue WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(DbError::NotFound(format!(
+            "Queued operation not found: {}",
+            id
+        )));
+    }
+
+    Ok(())
+}
+
+/// Move a conflicted or failed operation back to pending after the user has
+/// reviewed it. The original queue position is kept.
+pub async fn requeue_operation(pool: &Pool<Sqlite>, id: &str) -> DbResult<QueuedOperation> {
+    let existing = get_operation(pool, id).await?;
+    if existing.status == QueueStatus::Pending {
+        return Ok(existing);
+    }
+    if existing.status == QueueStatus::Completed {
+        return Err(DbError::InvalidInput(
+            "Completed operations cannot be requeued".to_string(),
+        ));
+    }
+
+    let operation = sqlx::query_as::<_, QueuedOperation>(
+        "UPDATE sync_operation_queue SET status = 'pending', last_error = NULL, updated_at = ?
+         WHERE id = ? RETURNING *",
+    )
+    .bind(Utc::now())
+    .bind(id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(operation)
+}
+
+/// Record the result of a replay attempt
+pub async fn record_attempt(
+    pool: &Pool<Sqlite>,
+    id: &str,
+    status: QueueStatus,
+    error: Option<&str>,
+) -> DbResult<QueuedOperation> {
+    let now = Utc::now();
+    let completed_at = (status == QueueStatus::Completed).then_some(now);
+
+    sqlx::query_as::<_, QueuedOperation>(
+        r#"
+        UPDATE sync_operation_queue
+        SET status = ?, attempts = attempts + 1, last_error = ?, last_attempt_at = ?,
+            completed_at = ?, updated_at = ?
+        WHERE id = ?
+        RETURNING *
+        "#,
+    )
+    .bind(status)
+    .bind(error)
+    .bind(now)
+    .bind(completed_at)
+    .bind(now)
+    .bind(id)
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| DbError::NotFound(format!("Queued operation not found: {}", id)))
+}
+
+/// Count operations by status
+pub async fn queue_summary(pool: &Pool<Sqlite>) -> DbResult<QueueSummary> {
+    let rows: Vec<(String, i64)> =
+        sqlx::query_as("SELECT status, COUNT(*) FROM sync_operation_queue GROUP BY status")
+            .fetch_all(pool)
+            .await?;
+
+    let mut summary = QueueSummary::default();
+    for (status, count) in rows {
+        match status.as_str() {
+            "pending" => summary.pending = count,
+            "completed" => summary.completed = count,
+            "conflict" => summary.conflict = count,
+            "failed" => summary.failed = count,
+            _ => {}
+        }
+    }
+
+    Ok(summary)
+}
@@ -0,0 +1,14 @@
+// ABOUTME: Offline queue for cloud and GitHub sync operations
+// ABOUTME: Persists operations that failed for lack of connectivity and replays them with conflict detection
+
+pub mod db;
+pub mod replay;
+pub mod types;
+
+// Re-export main types for convenience
+pub use db::{
+    delete_operation, enqueue_operation, get_operation, list_operations, queue_summary,
+    record_attempt, requeue_operation, DbError, DbResult,
+};
+pub use replay::{replay_pending, OperationExecutor, ReplayOutcome, ReplaySummary};
+pub use types::{EnqueueInput, QueueStatus, QueueSummary, QueuedOperation, SyncOperationType};
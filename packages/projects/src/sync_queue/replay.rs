@@ -0,0 +1,170 @@
+// ABOUTME: Replay of queued sync operations once connectivity returns
+// ABOUTME: Detects conflicts against local state before dispatching each operation to its executor
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use super::db::{list_operations, record_attempt, DbResult};
+use super::types::{QueueStatus, QueuedOperation, SyncOperationType};
+
+/// Result of executing one queued operation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayOutcome {
+    Completed,
+    /// Remote or local state changed in a way that makes blind replay unsafe
+    Conflict(String),
+    /// Still no connectivity; the operation stays pending and replay stops
+    Offline(String),
+    /// Permanent failure (bad configuration, rejected request)
+    Failed(String),
+}
+
+/// Performs queued operations of the types it handles
+#[async_trait]
+pub trait OperationExecutor: Send + Sync {
+    fn handles(&self, operation_type: SyncOperationType) -> bool;
+
+    async fn execute(&self, operation: &QueuedOperation) -> ReplayOutcome;
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaySummary {
+    pub attempted: usize,
+    pub completed: usize,
+    pub conflicts: usize,
+    pub failed: usize,
+    pub still_pending: usize,
+    /// True when replay stopped early because connectivity is still down
+    pub offline: bool,
+}
+
+/// Check local state for changes that invalidate a queued operation
+async fn detect_conflict(
+    pool: &Pool<Sqlite>,
+    operation: &QueuedOperation,
+) -> DbResult<Option<String>> {
+    match operation.operation_type {
+        SyncOperationType::CloudSyncProject => {
+            let exists: Option<(String,)> = sqlx::query_as("SELECT id FROM projects WHERE id = ?")
+                .bind(&operation.target_id)
+                .fetch_optional(pool)
+                .await?;
+            if exists.is_none() {
+                return Ok(Some(
+                    "Project was deleted locally after the sync was queued".to_string(),
+                ));
+            }
+        }
+        SyncOperationType::GithubSyncEpic | SyncOperationType::GithubSyncTasks => {
+            let exists: Option<(String,)> = sqlx::query_as("SELECT id FROM epics WHERE id = ?")
+                .bind(&operation.target_id)
+                .fetch_optional(pool)
+                .await?;
+            if exists.is_none() {
+                return Ok(Some(
+                    "Epic was deleted locally after the sync was queued".to_string(),
+                ));
+            }
+
+            if operation.operation_type == SyncOperationType::GithubSyncEpic {
+                let last_synced: Option<(Option<String>,)> = sqlx::query_as(
+                    "SELECT last_synced_at FROM github_sync WHERE entity_type = 'epic' AND entity_id = ?",
+                )
+                .bind(&operation.target_id)
+                .fetch_optional(pool)
+                .await?;
+
+                let synced_after_queueing = last_synced
+                    .and_then(|(ts,)| ts)
+                    .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+                    .map(|ts| ts.with_timezone(&Utc) > operation.queued_at)
+                    .unwrap_or(false);
+                if synced_after_queueing {
+                    return Ok(Some(
+                        "Epic was synced to GitHub after this operation was queued".to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Replay pending operations in FIFO order.
+///
+/// Replay stops at the first `Offline` outcome so a still-disconnected client
+/// does not burn through every queued operation on each attempt.
+pub async fn replay_pending(
+    pool: &Pool<Sqlite>,
+    executors: &[Arc<dyn OperationExecutor>],
+) -> DbResult<ReplaySummary> {
+    let pending = list_operations(pool, Some(QueueStatus::Pending)).await?;
+    let mut summary = ReplaySummary::default();
+
+    if pending.is_empty() {
+        return Ok(summary);
+    }
+    info!("Replaying {} queued sync operation(s)", pending.len());
+
+    for operation in &pending {
+        if summary.offline {
+            summary.still_pending += 1;
+            continue;
+        }
+
+        if let Some(reason) = detect_conflict(pool, operation).await? {
+            warn!(
+                "Queued operation {} ({:?}) conflicts: {}",
+                operation.id, operation.operation_type, reason
+            );
+            record_attempt(pool, &operation.id, QueueStatus::Conflict, Some(&reason)).await?;
+            summary.conflicts += 1;
+            continue;
+        }
+
+        let Some(executor) = executors
+            .iter()
+            .find(|e| e.handles(operation.operation_type))
+        else {
+            debug!(
+                "No executor registered for {:?}, leaving operation {} pending",
+                operation.operation_type, operation.id
+            );
+            summary.still_pending += 1;
+            continue;
+        };
+
+        summary.attempted += 1;
+        match executor.execute(operation).await {
+            ReplayOutcome::Completed => {
+                record_attempt(pool, &operation.id, QueueStatus::Completed, None).await?;
+                summary.completed += 1;
+            }
+            ReplayOutcome::Conflict(reason) => {
+                record_attempt(pool, &operation.id, QueueStatus::Conflict, Some(&reason)).await?;
+                summary.conflicts += 1;
+            }
+            ReplayOutcome::Failed(reason) => {
+                record_attempt(pool, &operation.id, QueueStatus::Failed, Some(&reason)).await?;
+                summary.failed += 1;
+            }
+            ReplayOutcome::Offline(reason) => {
+                record_attempt(pool, &operation.id, QueueStatus::Pending, Some(&reason)).await?;
+                summary.still_pending += 1;
+                summary.offline = true;
+            }
+        }
+    }
+
+    info!(
+        "Sync queue replay finished: {} completed, {} conflicts, {} failed, {} pending",
+        summary.completed, summary.conflicts, summary.failed, summary.still_pending
+    );
+    Ok(summary)
+}
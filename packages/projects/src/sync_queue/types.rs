@@ -0,0 +1,82 @@
+// ABOUTME: Offline sync queue type definitions
+// ABOUTME: Structures describing queued cloud/GitHub operations and their replay state
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kind of sync operation waiting in the queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum SyncOperationType {
+    /// Push a local project to Orkee Cloud (target is the project id)
+    CloudSyncProject,
+    /// Create or update the GitHub issue for an Epic (target is the epic id)
+    GithubSyncEpic,
+    /// Create GitHub issues for an Epic's tasks (target is the epic id)
+    GithubSyncTasks,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum QueueStatus {
+    Pending,
+    Completed,
+    Conflict,
+    Failed,
+}
+
+impl QueueStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueueStatus::Pending => "pending",
+            QueueStatus::Completed => "completed",
+            QueueStatus::Conflict => "conflict",
+            QueueStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedOperation {
+    pub id: String,
+    pub operation_type: SyncOperationType,
+    pub target_id: String,
+    pub project_id: Option<String>,
+    #[sqlx(json)]
+    pub payload: serde_json::Value,
+    pub status: QueueStatus,
+    pub attempts: i64,
+    pub last_error: Option<String>,
+    pub queued_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnqueueInput {
+    pub operation_type: SyncOperationType,
+    pub target_id: String,
+    pub project_id: Option<String>,
+    #[serde(default = "empty_payload")]
+    pub payload: serde_json::Value,
+}
+
+fn empty_payload() -> serde_json::Value {
+    serde_json::json!({})
+}
+
+/// Per-status counts for the queue
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueSummary {
+    pub pending: i64,
+    pub completed: i64,
+    pub conflict: i64,
+    pub failed: i64,
+}
@@ -0,0 +1,89 @@
+// ABOUTME: Integration tests for the database administration API
+// ABOUTME: Tests the integrity check, storage statistics, and maintenance endpoints
+
+mod common;
+
+use common::{create_test_project, get, post_json, setup_test_server};
+use serde_json::Value;
+
+#[tokio::test]
+async fn test_integrity_check_reports_and_repairs_dangling_tasks() {
+    let ctx = setup_test_server().await;
+    create_test_project(&ctx.pool, "Admin Project", "/test/admin").await;
+
+    let response = get(&ctx.base_url, "/integrity").await;
+    assert_eq!(response.status(), 200);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["success"], true);
+    assert_eq!(body["data"]["healthy"], true);
+
+    // Foreign keys are a per-connection setting, so the orphan must be written
+    // on the same connection that disabled them
+    let mut conn = ctx.pool.acquire().await.unwrap();
+    sqlx::query("PRAGMA foreign_keys = OFF")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    sqlx::query(
+        "INSERT INTO tasks (id, project_id, title, created_at, updated_at)
+         VALUES ('task-orphan', 'proj-gone', 'Lost', datetime('now'), datetime('now'))",
+    )
+    .execute(&mut *conn)
+    .await
+    .unwrap();
+    sqlx::query("PRAGMA foreign_keys = ON")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    drop(conn);
+
+    let response = get(&ctx.base_url, "/integrity").await;
+    let body: Value = response.json().await.unwrap();
+    let report = &body["data"];
+    assert_eq!(report["healthy"], false);
+    assert!(report["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|issue| issue["kind"] == "dangling_task_project" && issue["repaired"] == false));
+
+    let response = get(&ctx.base_url, "/integrity?repair=true").await;
+    let body: Value = response.json().await.unwrap();
+    let report = &body["data"];
+    assert_eq!(report["repair_requested"], true);
+    assert_eq!(report["healthy"], true);
+    assert_eq!(report["summary"]["unresolved"], 0);
+
+    let response = get(&ctx.base_url, "/integrity").await;
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["data"]["healthy"], true);
+    assert_eq!(body["data"]["summary"]["total"], 0);
+}
+
+#[tokio::test]
+async fn test_storage_stats_and_maintenance() {
+    let ctx = setup_test_server().await;
+    create_test_project(&ctx.pool, "Storage Project", "/test/storage").await;
+
+    let response = get(&ctx.base_url, "/storage").await;
+    assert_eq!(response.status(), 200);
+    let body: Value = response.json().await.unwrap();
+    let stats = &body["data"];
+    assert!(stats["size_bytes"].as_u64().unwrap() > 0);
+    assert!(stats["tables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|table| table["name"] == "projects"));
+
+    let response = post_json(
+        &ctx.base_url,
+        "/storage/maintenance",
+        &serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["success"], true);
+    assert_eq!(body["data"]["full_vacuum"], true);
+}
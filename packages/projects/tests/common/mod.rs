@@ -2,10 +2,13 @@
 // ABOUTME: Provides test server setup, database helpers, and HTTP client utilities
 
 use orkee_api::{
+    create_admin_router,
     // create_ai_router, // Phase 6: Removed - AI calls now client-side
     create_ai_usage_router,
     create_ideate_router,
+    create_mcp_servers_router,
     create_prds_router,
+    ideate_handlers::RepoPathGuard,
 };
 use orkee_projects::DbState;
 use sqlx::SqlitePool;
@@ -17,6 +20,7 @@ static TEST_MUTEX: Mutex<()> = Mutex::const_new(());
 
 /// Test context containing server URL and database pool
 pub struct TestContext {
+    #[allow(dead_code)]
     pub base_url: String,
     #[allow(dead_code)]
     pub pool: SqlitePool,
@@ -30,13 +34,17 @@ pub async fn setup_test_server() -> TestContext {
     // Create temporary directory to keep temp_dir alive
     let temp_dir = TempDir::new().unwrap();
 
-    // Use in-memory database for tests (simpler and faster)
-    let database_url = "sqlite::memory:";
+    // Use a file-backed database: shared-cache in-memory databases deadlock
+    // PRAGMA quick_check once FTS5 holds a table lock on another connection
+    let database_url = format!(
+        "sqlite://{}?mode=rwc",
+        temp_dir.path().join("test.db").display()
+    );
 
     // Create database pool directly
     let pool = sqlx::sqlite::SqlitePoolOptions::new()
         .max_connections(5)
-        .connect(database_url)
+        .connect(&database_url)
         .await
         .expect("Failed to create database pool");
 
@@ -65,7 +73,10 @@ pub async fn setup_test_server() -> TestContext {
         .merge(create_ideate_router())
         // .merge(create_ai_router())
         .merge(create_ai_usage_router())
-        .with_state(db_state);
+        .merge(create_mcp_servers_router())
+        .merge(create_admin_router())
+        .with_state(db_state)
+        .layer(axum::Extension(temp_dir_repo_path_guard()));
 
     // Bind to random available port
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
@@ -89,7 +100,24 @@ pub async fn setup_test_server() -> TestContext {
     }
 }
 
+/// Stand-in for the CLI's path validator: only paths under the system temp
+/// directory may be imported
+fn temp_dir_repo_path_guard() -> RepoPathGuard {
+    std::sync::Arc::new(|path: &str| {
+        let resolved = std::path::Path::new(path)
+            .canonicalize()
+            .map_err(|_| "Path does not exist".to_string())?;
+        let allowed = std::env::temp_dir().canonicalize().unwrap();
+        if resolved.starts_with(&allowed) {
+            Ok(resolved)
+        } else {
+            Err("Path is outside allowed directories".to_string())
+        }
+    })
+}
+
 /// Helper to make GET requests
+#[allow(dead_code)]
 pub async fn get(base_url: &str, path: &str) -> reqwest::Response {
     let client = reqwest::Client::new();
     client
@@ -0,0 +1,158 @@
+// ABOUTME: Integration tests for seeding ideate sessions from existing documents
+// ABOUTME: Tests Markdown uploads, repository README lookup, path policy, and invalid input handling
+
+mod common;
+
+use common::{create_test_project, get, post_json, setup_test_server};
+use serde_json::{json, Value};
+
+const README: &str = "# Taskly
+
+A kanban board for small teams.
+
+## Problem
+
+Tasks get lost between chat and spreadsheets.
+
+## Architecture
+
+Rust API with a React dashboard.
+";
+
+#[tokio::test]
+async fn test_start_from_markdown_upload_prefills_sections() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "Import Project", "/test/import").await;
+
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "fileName": "README.md",
+            "content": README,
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+    let body: Value = response.json().await.unwrap();
+    let result = &body["data"];
+    assert_eq!(result["source_name"], "README.md");
+    assert_eq!(result["session"]["mode"], "guided");
+    assert_eq!(
+        result["session"]["initial_description"],
+        "Taskly: A kanban board for small teams."
+    );
+    assert_eq!(
+        result["imported_sections"],
+        json!(["overview", "technical"])
+    );
+
+    let session_id = result["session"]["id"].as_str().unwrap();
+    let response = get(&ctx.base_url, &format!("/ideate/{}/overview", session_id)).await;
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(
+        body["data"]["problem_statement"],
+        "Tasks get lost between chat and spreadsheets."
+    );
+
+    let response = get(&ctx.base_url, &format!("/ideate/{}/technical", session_id)).await;
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(
+        body["data"]["tech_stack_quick"],
+        "Rust API with a React dashboard."
+    );
+}
+
+#[tokio::test]
+async fn test_start_from_repo_path_finds_readme() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "Repo Project", "/test/repo").await;
+    let repo = tempfile::TempDir::new().unwrap();
+    std::fs::write(repo.path().join("README.md"), README).unwrap();
+
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "mode": "quick",
+            "repoPath": repo.path(),
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["data"]["source_name"], "README.md");
+    assert_eq!(body["data"]["session"]["mode"], "quick");
+}
+
+#[tokio::test]
+async fn test_start_from_document_rejects_invalid_input() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "Bad Project", "/test/bad").await;
+
+    // No document at all
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({ "projectId": project_id }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    // Not a zip archive
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "fileName": "spec.docx",
+            "contentBase64": "bm90IGEgZG9jeA==",
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    // Missing repository
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "repoPath": "/definitely/not/a/repo",
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    // Outside the allowed paths
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "repoPath": "/etc/hostname",
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    // Allowed directory, but not a README/PRD document
+    let repo = tempfile::TempDir::new().unwrap();
+    std::fs::write(repo.path().join("secrets.txt"), "token").unwrap();
+    let response = post_json(
+        &ctx.base_url,
+        "/ideate/start-from-document",
+        &json!({
+            "projectId": project_id,
+            "repoPath": repo.path().join("secrets.txt"),
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    let response = get(&ctx.base_url, &format!("/{}/ideate/sessions", project_id)).await;
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["data"].as_array().map(Vec::len), Some(0));
+}
@@ -0,0 +1,117 @@
+// ABOUTME: Integration tests for per-project MCP server configuration endpoints
+// ABOUTME: Tests CRUD operations, name validation, and client config export
+
+mod common;
+
+use common::{create_test_project, delete, get, post_json, put_json, setup_test_server};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_mcp_server_crud() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "MCP Project", "/test/mcp").await;
+
+    let response = post_json(
+        &ctx.base_url,
+        &format!("/{}/mcp-servers", project_id),
+        &json!({
+            "name": "filesystem",
+            "command": "npx",
+            "args": ["-y", "@modelcontextprotocol/server-filesystem", "."],
+            "env": { "LOG_LEVEL": "debug" }
+        }),
+    )
+    .await;
+    assert_eq!(response.status(), 201);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let server_id = body["data"]["id"].as_str().unwrap().to_string();
+    assert_eq!(body["data"]["enabled"], true);
+    assert_eq!(body["data"]["args"][0], "-y");
+    assert_eq!(body["data"]["env"]["LOG_LEVEL"], "debug");
+
+    let response = put_json(
+        &ctx.base_url,
+        &format!("/{}/mcp-servers/{}", project_id, server_id),
+        &json!({ "enabled": false }),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"]["enabled"], false);
+    assert_eq!(body["data"]["command"], "npx");
+
+    let response = get(&ctx.base_url, &format!("/{}/mcp-servers", project_id)).await;
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body["data"].as_array().unwrap().len(), 1);
+
+    let response = delete(
+        &ctx.base_url,
+        &format!("/{}/mcp-servers/{}", project_id, server_id),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+
+    let response = get(
+        &ctx.base_url,
+        &format!("/{}/mcp-servers/{}", project_id, server_id),
+    )
+    .await;
+    assert_eq!(response.status(), 404);
+}
+
+#[tokio::test]
+async fn test_mcp_server_rejects_invalid_and_duplicate_names() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "MCP Project", "/test/mcp").await;
+    let path = format!("/{}/mcp-servers", project_id);
+
+    let response = post_json(
+        &ctx.base_url,
+        &path,
+        &json!({ "name": "bad name", "command": "npx" }),
+    )
+    .await;
+    assert_eq!(response.status(), 400);
+
+    let server = json!({ "name": "search", "command": "npx" });
+    assert_eq!(post_json(&ctx.base_url, &path, &server).await.status(), 201);
+    assert_eq!(post_json(&ctx.base_url, &path, &server).await.status(), 409);
+}
+
+#[tokio::test]
+async fn test_mcp_server_export_formats() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "MCP Project", "/test/mcp").await;
+    let path = format!("/{}/mcp-servers", project_id);
+
+    post_json(
+        &ctx.base_url,
+        &path,
+        &json!({ "name": "filesystem", "command": "npx", "args": ["-y", "fs"] }),
+    )
+    .await;
+    post_json(
+        &ctx.base_url,
+        &path,
+        &json!({ "name": "disabled", "command": "npx", "enabled": false }),
+    )
+    .await;
+
+    let response = get(
+        &ctx.base_url,
+        &format!("{}/export?format=claude-desktop", path),
+    )
+    .await;
+    assert_eq!(response.status(), 200);
+    let body: serde_json::Value = response.json().await.unwrap();
+    let servers = body["data"]["config"]["mcpServers"].as_object().unwrap();
+    assert_eq!(servers.len(), 1);
+    assert_eq!(servers["filesystem"]["args"][1], "fs");
+
+    let response = get(&ctx.base_url, &format!("{}/export?format=cursor", path)).await;
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        body["data"]["config"]["mcpServers"]["filesystem"]["type"],
+        "stdio"
+    );
+}
@@ -20,6 +20,93 @@ async fn setup_migrated_db() -> Pool<Sqlite> {
     pool
 }
 
+/// Path, up SQL, and down SQL of every migration, oldest first
+fn read_migrations() -> Vec<(std::path::PathBuf, String, String)> {
+    let mut ups: Vec<std::path::PathBuf> = std::fs::read_dir("../storage/migrations")
+        .expect("Should read migrations directory")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let name = path.to_string_lossy();
+            name.ends_with(".sql") && !name.ends_with(".down.sql")
+        })
+        .collect();
+    ups.sort();
+
+    ups.into_iter()
+        .map(|up_path| {
+            let down_path = up_path.with_extension("down.sql");
+            let up = std::fs::read_to_string(&up_path).expect("Should read migration file");
+            let down = std::fs::read_to_string(&down_path)
+                .unwrap_or_else(|_| panic!("{} should have a down migration", up_path.display()));
+            (up_path, up, down)
+        })
+        .collect()
+}
+
+/// Roll back every migration, newest first, so later migrations are reverted
+/// before the initial schema drops the tables they depend on
+async fn run_down_migrations(pool: &Pool<Sqlite>) {
+    for (path, _, down) in read_migrations().iter().rev() {
+        sqlx::raw_sql(down)
+            .execute(pool)
+            .await
+            .unwrap_or_else(|e| panic!("Down migration {} failed: {}", path.display(), e));
+    }
+}
+
+/// Every schema object with enough detail to tell whether a rollback restored it:
+/// columns and foreign keys for tables, columns for indexes, and whitespace
+/// normalized SQL for triggers and views
+async fn schema_snapshot(pool: &Pool<Sqlite>) -> Vec<String> {
+    let objects: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
+        "SELECT type, name, tbl_name, sql FROM sqlite_master
+         WHERE name NOT LIKE 'sqlite_%' AND name != '_sqlx_migrations'
+         ORDER BY type, name",
+    )
+    .fetch_all(pool)
+    .await
+    .unwrap();
+
+    let mut snapshot = Vec::new();
+    for (kind, name, table, sql) in objects {
+        let detail = match kind.as_str() {
+            "table" => {
+                let columns: Vec<(String, String, i64, Option<String>, i64)> = sqlx::query_as(
+                    "SELECT name, type, \"notnull\", dflt_value, pk FROM pragma_table_info(?)",
+                )
+                .bind(&name)
+                .fetch_all(pool)
+                .await
+                .unwrap();
+                let foreign_keys: Vec<(String, String, Option<String>, String)> = sqlx::query_as(
+                    "SELECT \"table\", \"from\", \"to\", on_delete FROM pragma_foreign_key_list(?)",
+                )
+                .bind(&name)
+                .fetch_all(pool)
+                .await
+                .unwrap();
+                format!("{:?} {:?}", columns, foreign_keys)
+            }
+            "index" => {
+                let columns: Vec<Option<String>> =
+                    sqlx::query_scalar("SELECT name FROM pragma_index_info(?)")
+                        .bind(&name)
+                        .fetch_all(pool)
+                        .await
+                        .unwrap();
+                format!("on {} {:?} {:?}", table, columns, sql.is_some())
+            }
+            _ => sql
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+        };
+        snapshot.push(format!("{} {}: {}", kind, name, detail));
+    }
+    snapshot
+}
+
 #[tokio::test]
 async fn test_initial_schema_migration_succeeds() {
     let pool = setup_migrated_db().await;
@@ -1374,32 +1461,8 @@ async fn test_down_migration_removes_all_tables() {
         "Should have 30+ tables after migration"
     );
 
-    // Read and execute down migration
-    let down_sql = std::fs::read_to_string("../storage/migrations/001_initial_schema.down.sql")
-        .expect("Should read down migration file");
-
-    // Execute down migration (SQLx doesn't support this natively, so we do it manually)
-    // Parse SQL properly: remove comments and handle multi-line statements
-    for statement in down_sql.split(';') {
-        // Remove SQL comments (lines starting with --)
-        let cleaned: String = statement
-            .lines()
-            .filter(|line| !line.trim().starts_with("--"))
-            .collect::<Vec<&str>>()
-            .join("\n");
-
-        let trimmed = cleaned.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        sqlx::query(trimmed)
-            .execute(&pool)
-            .await
-            .unwrap_or_else(|e| {
-                panic!("Down migration statement failed: {}\nError: {}", trimmed, e)
-            });
-    }
+    // Execute down migrations (SQLx doesn't support this natively, so we do it manually)
+    run_down_migrations(&pool).await;
 
     // Verify all application tables are dropped
     let table_count_after: i64 = sqlx::query_scalar(
@@ -1484,28 +1547,8 @@ async fn test_down_migration_drops_tables_in_correct_order() {
             .unwrap();
     assert_eq!(task_count, 1, "Test task should exist");
 
-    // Execute down migration
-    let down_sql = std::fs::read_to_string("../storage/migrations/001_initial_schema.down.sql")
-        .expect("Should read down migration file");
-
-    for statement in down_sql.split(';') {
-        let cleaned: String = statement
-            .lines()
-            .filter(|line| !line.trim().starts_with("--"))
-            .collect::<Vec<&str>>()
-            .join("\n");
-
-        let trimmed = cleaned.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
-
-        // Should not fail even with existing data due to IF EXISTS and proper ordering
-        sqlx::query(trimmed)
-            .execute(&pool)
-            .await
-            .unwrap_or_else(|e| panic!("Down migration should handle existing data: {}", e));
-    }
+    // Should not fail even with existing data due to proper ordering
+    run_down_migrations(&pool).await;
 
     // Verify cleanup is complete
     let table_count: i64 = sqlx::query_scalar(
@@ -1527,31 +1570,15 @@ async fn test_down_migration_drops_tables_in_correct_order() {
 async fn test_down_migration_is_idempotent() {
     let pool = setup_migrated_db().await;
 
-    // Read down migration
-    let down_sql = std::fs::read_to_string("../storage/migrations/001_initial_schema.down.sql")
-        .expect("Should read down migration file");
-
-    // Execute down migration twice
-    for _attempt in 0..2 {
-        for statement in down_sql.split(';') {
-            let cleaned: String = statement
-                .lines()
-                .filter(|line| !line.trim().starts_with("--"))
-                .collect::<Vec<&str>>()
-                .join("\n");
-
-            let trimmed = cleaned.trim();
-            if trimmed.is_empty() {
-                continue;
-            }
+    run_down_migrations(&pool).await;
 
-            // Should succeed on both attempts due to IF EXISTS
-            sqlx::query(trimmed)
-                .execute(&pool)
-                .await
-                .unwrap_or_else(|e| panic!("Down migration should be idempotent: {}", e));
-        }
-    }
+    // The initial schema rollback must succeed again on an empty database
+    let initial_down = std::fs::read_to_string("../storage/migrations/001_initial_schema.down.sql")
+        .expect("Should read down migration file");
+    sqlx::raw_sql(&initial_down)
+        .execute(&pool)
+        .await
+        .unwrap_or_else(|e| panic!("Down migration should be idempotent: {}", e));
 
     // Verify final state is clean
     let table_count: i64 = sqlx::query_scalar(
@@ -1569,6 +1596,89 @@ async fn test_down_migration_is_idempotent() {
     );
 }
 
+#[tokio::test]
+async fn test_each_down_migration_restores_previous_schema() {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(":memory:")
+        .await
+        .unwrap();
+
+    // Migrate to N-1, snapshot, migrate to N, roll back to N-1, compare,
+    // then re-apply N so the next migration starts from the real schema
+    for (path, up, down) in read_migrations() {
+        let before = schema_snapshot(&pool).await;
+
+        sqlx::raw_sql(&up)
+            .execute(&pool)
+            .await
+            .unwrap_or_else(|e| panic!("Migration {} failed: {}", path.display(), e));
+        sqlx::raw_sql(&down)
+            .execute(&pool)
+            .await
+            .unwrap_or_else(|e| panic!("Down migration {} failed: {}", path.display(), e));
+
+        let after = schema_snapshot(&pool).await;
+        let missing: Vec<_> = before.iter().filter(|o| !after.contains(o)).collect();
+        let leftover: Vec<_> = after.iter().filter(|o| !before.contains(o)).collect();
+        assert!(
+            missing.is_empty() && leftover.is_empty(),
+            "{} should restore the schema it was applied to\nmissing: {:#?}\nleftover: {:#?}",
+            path.display(),
+            missing,
+            leftover
+        );
+
+        sqlx::raw_sql(&up)
+            .execute(&pool)
+            .await
+            .unwrap_or_else(|e| panic!("Re-applying {} failed: {}", path.display(), e));
+    }
+}
+
+#[tokio::test]
+async fn test_ci_webhooks_down_migration() {
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(":memory:")
+        .await
+        .unwrap();
+
+    let migrations = read_migrations();
+    let (_, _, down) = migrations
+        .iter()
+        .find(|(path, _, _)| path.ends_with("013_ci_webhooks.sql"))
+        .expect("013_ci_webhooks migration should exist");
+    for (path, up, _) in &migrations {
+        sqlx::raw_sql(up).execute(&pool).await.unwrap();
+        if path.ends_with("013_ci_webhooks.sql") {
+            break;
+        }
+    }
+
+    sqlx::raw_sql(down)
+        .execute(&pool)
+        .await
+        .expect("013 down migration should run without skipping statements");
+
+    let webhook_tables: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master
+         WHERE type='table' AND name IN ('webhook_secrets', 'webhook_deliveries')",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(webhook_tables, 0, "Webhook tables should be dropped");
+
+    let checks_columns: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM pragma_table_info('tasks') WHERE name LIKE 'checks_%'",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(checks_columns, 0, "Task checks columns should be dropped");
+}
+
 // ============================================================================
 // MODEL PREFERENCES TESTS (Phase 7.5)
 // ============================================================================
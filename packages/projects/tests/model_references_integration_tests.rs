@@ -0,0 +1,42 @@
+// ABOUTME: Integration tests for reconciling stored agent/model references with the catalog
+// ABOUTME: Tests that references to removed agents are cleared while valid ones are kept
+
+mod common;
+
+use common::{create_test_project, setup_test_server};
+use orkee_projects::clear_orphaned_model_references;
+
+#[tokio::test]
+async fn test_clear_orphaned_model_references() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "Catalog Project", "/test/catalog").await;
+
+    sqlx::query(
+        "INSERT INTO tasks (
+            id, project_id, title, assigned_agent_id, reviewed_by_agent_id, created_at, updated_at
+         )
+         VALUES (
+            'task0001', ?, 'Refresh catalog', 'removed-agent', 'claude-code',
+            datetime('now'), datetime('now')
+         )",
+    )
+    .bind(&project_id)
+    .execute(&ctx.pool)
+    .await
+    .unwrap();
+
+    let cleared = clear_orphaned_model_references(&ctx.pool).await.unwrap();
+    assert_eq!(cleared, 1);
+
+    let (assigned, reviewer): (Option<String>, Option<String>) = sqlx::query_as(
+        "SELECT assigned_agent_id, reviewed_by_agent_id FROM tasks WHERE id = 'task0001'",
+    )
+    .fetch_one(&ctx.pool)
+    .await
+    .unwrap();
+    assert_eq!(assigned, None);
+    assert_eq!(reviewer.as_deref(), Some("claude-code"));
+
+    // Nothing left to clear on the next run
+    assert_eq!(clear_orphaned_model_references(&ctx.pool).await.unwrap(), 0);
+}
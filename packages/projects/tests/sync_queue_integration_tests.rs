@@ -0,0 +1,156 @@
+// ABOUTME: Integration tests for the offline sync operation queue
+// ABOUTME: Tests enqueue coalescing, FIFO replay, offline short-circuiting, and conflict detection
+
+mod common;
+
+use async_trait::async_trait;
+use common::{create_test_project, setup_test_server};
+use orkee_projects::sync_queue::{
+    enqueue_operation, get_operation, list_operations, queue_summary, replay_pending,
+    requeue_operation,
+};
+use orkee_projects::{
+    EnqueueInput, OperationExecutor, QueueStatus, QueuedOperation, ReplayOutcome, SyncOperationType,
+};
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+
+/// Executor that returns scripted outcomes and records what it was asked to run
+struct ScriptedExecutor {
+    outcomes: Mutex<Vec<ReplayOutcome>>,
+    executed: Mutex<Vec<String>>,
+}
+
+impl ScriptedExecutor {
+    fn new(outcomes: Vec<ReplayOutcome>) -> Arc<Self> {
+        Arc::new(Self {
+            outcomes: Mutex::new(outcomes),
+            executed: Mutex::new(Vec::new()),
+        })
+    }
+}
+
+#[async_trait]
+impl OperationExecutor for ScriptedExecutor {
+    fn handles(&self, operation_type: SyncOperationType) -> bool {
+        operation_type == SyncOperationType::CloudSyncProject
+    }
+
+    async fn execute(&self, operation: &QueuedOperation) -> ReplayOutcome {
+        self.executed
+            .lock()
+            .unwrap()
+            .push(operation.target_id.clone());
+        self.outcomes.lock().unwrap().remove(0)
+    }
+}
+
+fn cloud_sync(project_id: &str) -> EnqueueInput {
+    EnqueueInput {
+        operation_type: SyncOperationType::CloudSyncProject,
+        target_id: project_id.to_string(),
+        project_id: Some(project_id.to_string()),
+        payload: json!({ "name": project_id }),
+    }
+}
+
+#[tokio::test]
+async fn test_enqueue_coalesces_pending_operations() {
+    let ctx = setup_test_server().await;
+    let project_id = create_test_project(&ctx.pool, "Queue Project", "/test/queue").await;
+
+    let first = enqueue_operation(&ctx.pool, cloud_sync(&project_id))
+        .await
+        .unwrap();
+    let mut updated = cloud_sync(&project_id);
+    updated.payload = json!({ "name": "renamed" });
+    let second = enqueue_operation(&ctx.pool, updated).await.unwrap();
+
+    assert_eq!(first.id, second.id);
+    assert_eq!(second.payload["name"], "renamed");
+    assert_eq!(second.status, QueueStatus::Pending);
+
+    let summary = queue_summary(&ctx.pool).await.unwrap();
+    assert_eq!(summary.pending, 1);
+}
+
+#[tokio::test]
+async fn test_replay_stops_when_still_offline() {
+    let ctx = setup_test_server().await;
+    let first_project = create_test_project(&ctx.pool, "First", "/test/first").await;
+    let second_project = create_test_project(&ctx.pool, "Second", "/test/second").await;
+    let first = enqueue_operation(&ctx.pool, cloud_sync(&first_project))
+        .await
+        .unwrap();
+    enqueue_operation(&ctx.pool, cloud_sync(&second_project))
+        .await
+        .unwrap();
+
+    let executor = ScriptedExecutor::new(vec![ReplayOutcome::Offline(
+        "connection refused".to_string(),
+    )]);
+    let executors: Vec<Arc<dyn OperationExecutor>> = vec![executor.clone()];
+    let summary = replay_pending(&ctx.pool, &executors).await.unwrap();
+
+    assert!(summary.offline);
+    assert_eq!(summary.attempted, 1);
+    assert_eq!(summary.still_pending, 2);
+    assert_eq!(*executor.executed.lock().unwrap(), vec![first_project]);
+
+    let first = get_operation(&ctx.pool, &first.id).await.unwrap();
+    assert_eq!(first.status, QueueStatus::Pending);
+    assert_eq!(first.attempts, 1);
+    assert_eq!(first.last_error.as_deref(), Some("connection refused"));
+}
+
+#[tokio::test]
+async fn test_replay_records_outcomes_and_detects_deleted_targets() {
+    let ctx = setup_test_server().await;
+    let kept = create_test_project(&ctx.pool, "Kept", "/test/kept").await;
+    let removed = create_test_project(&ctx.pool, "Removed", "/test/removed").await;
+    let conflicted = create_test_project(&ctx.pool, "Conflicted", "/test/conflicted").await;
+    for project_id in [&kept, &removed, &conflicted] {
+        enqueue_operation(&ctx.pool, cloud_sync(project_id))
+            .await
+            .unwrap();
+    }
+
+    sqlx::query("DELETE FROM projects WHERE id = ?")
+        .bind(&removed)
+        .execute(&ctx.pool)
+        .await
+        .unwrap();
+
+    let executor = ScriptedExecutor::new(vec![
+        ReplayOutcome::Completed,
+        ReplayOutcome::Conflict("remote copy changed".to_string()),
+    ]);
+    let executors: Vec<Arc<dyn OperationExecutor>> = vec![executor.clone()];
+    let summary = replay_pending(&ctx.pool, &executors).await.unwrap();
+
+    assert_eq!(summary.completed, 1);
+    assert_eq!(summary.conflicts, 2);
+    assert_eq!(summary.still_pending, 0);
+    // The deleted project never reaches the executor
+    assert_eq!(*executor.executed.lock().unwrap(), vec![kept, conflicted]);
+
+    let completed = list_operations(&ctx.pool, Some(QueueStatus::Completed))
+        .await
+        .unwrap();
+    assert_eq!(completed.len(), 1);
+    assert!(completed[0].completed_at.is_some());
+
+    let conflicts = list_operations(&ctx.pool, Some(QueueStatus::Conflict))
+        .await
+        .unwrap();
+    assert_eq!(conflicts.len(), 2);
+
+    let retried = requeue_operation(&ctx.pool, &conflicts[0].id)
+        .await
+        .unwrap();
+    assert_eq!(retried.status, QueueStatus::Pending);
+    assert!(retried.last_error.is_none());
+    assert!(requeue_operation(&ctx.pool, &completed[0].id)
+        .await
+        .is_err());
+}
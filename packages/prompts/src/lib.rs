@@ -49,9 +49,32 @@ pub struct Prompt {
     pub metadata: Option<PromptMetadata>,
 }
 
+/// Where a prompt is being used, so its token spend can be attributed later
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptUsageContext {
+    /// ID of the AI request the prompt is sent with, matching `ai_usage_logs.request_id`
+    #[serde(rename = "requestId")]
+    pub request_id: String,
+    pub operation: Option<String>,
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+}
+
+/// A prompt rendered under a usage context
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PromptUsage {
+    #[serde(rename = "promptId")]
+    pub prompt_id: String,
+    #[serde(rename = "promptVersion")]
+    pub prompt_version: Option<String>,
+    #[serde(flatten)]
+    pub context: PromptUsageContext,
+}
+
 pub struct PromptManager {
     prompts_dir: PathBuf,
     cache: HashMap<String, Prompt>,
+    usage: Vec<PromptUsage>,
 }
 
 impl PromptManager {
@@ -130,6 +153,7 @@ impl PromptManager {
         Ok(Self {
             prompts_dir,
             cache: HashMap::new(),
+            usage: Vec::new(),
         })
     }
 
@@ -145,6 +169,31 @@ impl PromptManager {
         self.substitute_parameters(&prompt.template, parameters, &prompt.parameters)
     }
 
+    /// Get a prompt like [`get_prompt`](Self::get_prompt) and record that it was
+    /// used under `context`. Recorded usage is collected with [`take_usage`](Self::take_usage).
+    pub fn get_prompt_with_context(
+        &mut self,
+        prompt_id: &str,
+        parameters: &[(&str, &str)],
+        context: PromptUsageContext,
+    ) -> Result<String, PromptError> {
+        let prompt = self.load_prompt(prompt_id)?;
+        let rendered =
+            self.substitute_parameters(&prompt.template, parameters, &prompt.parameters)?;
+
+        self.usage.push(PromptUsage {
+            prompt_id: prompt.id,
+            prompt_version: prompt.metadata.map(|m| m.version),
+            context,
+        });
+        Ok(rendered)
+    }
+
+    /// Drain the usage recorded since the last call, for persisting alongside AI usage logs
+    pub fn take_usage(&mut self) -> Vec<PromptUsage> {
+        std::mem::take(&mut self.usage)
+    }
+
     /// Get a system prompt by category
     pub fn get_system_prompt(&mut self, category: &str) -> Result<String, PromptError> {
         let path = self
@@ -339,6 +388,33 @@ mod tests {
         assert!(prompt.contains("Test2"));
     }
 
+    #[test]
+    fn test_prompt_usage_is_recorded_with_context() {
+        let mut manager = PromptManager::new(Some(get_test_prompts_dir())).unwrap();
+        let context = PromptUsageContext {
+            request_id: "req-1".to_string(),
+            operation: Some("prd_overview".to_string()),
+            project_id: None,
+        };
+
+        manager
+            .get_prompt("overview", &[("description", "Test")])
+            .unwrap();
+        manager
+            .get_prompt_with_context("overview", &[("description", "Test")], context.clone())
+            .unwrap();
+        // Failed renders are not recorded
+        assert!(manager
+            .get_prompt_with_context("overview", &[], context.clone())
+            .is_err());
+
+        let usage = manager.take_usage();
+        assert_eq!(usage.len(), 1);
+        assert_eq!(usage[0].prompt_id, "overview");
+        assert_eq!(usage[0].context, context);
+        assert!(manager.take_usage().is_empty());
+    }
+
     // Security tests - path traversal prevention
     #[test]
     fn test_reject_dotdot_traversal() {
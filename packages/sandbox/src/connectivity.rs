@@ -0,0 +1,713 @@
+// ABOUTME: Connection tests that verify sandbox provider settings and credentials
+// ABOUTME: Checks required fields, then calls the provider API or creates and removes a Docker container
+
+use crate::providers::{ContainerConfig, Provider as SandboxProvider};
+use crate::{Provider, ProviderSettings, PROVIDER_REGISTRY};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// How long a provider API gets to answer before the test fails as a timeout
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Image used for the Docker create/destroy check when none is configured
+const DEFAULT_TEST_IMAGE: &str = "orkee/sandbox:latest";
+
+/// Outcome of a single step of a connection test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Passed,
+    Failed,
+    /// Not run, either because an earlier step failed or it doesn't apply
+    Skipped,
+}
+
+/// One step of a connection test, e.g. "credentials" or "api"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl ConnectionCheck {
+    fn new(name: &str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+/// Why a connection test failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// The provider isn't in providers.json
+    UnknownProvider,
+    /// A credential or ID the provider needs hasn't been saved
+    MissingCredentials,
+    /// A saved value is malformed, e.g. an endpoint that isn't a URL
+    InvalidSettings,
+    /// The provider or Docker daemon couldn't be reached
+    Unreachable,
+    Timeout,
+    /// The provider rejected the credentials
+    InvalidCredentials,
+    /// The credentials are valid but lack access to the resource
+    PermissionDenied,
+    /// The configured workspace, app, project, or account doesn't exist
+    ResourceNotFound,
+    RateLimited,
+    /// The provider answered with an unexpected error
+    ProviderError,
+    /// Orkee can't verify this provider's credentials yet
+    NotSupported,
+}
+
+/// Structured description of a failed connection test
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionFailure {
+    pub kind: FailureKind,
+    pub message: String,
+    /// What the user can do about it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    /// Status code returned by the provider API, if it answered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+}
+
+impl ConnectionFailure {
+    fn new(kind: FailureKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            hint: None,
+            http_status: None,
+        }
+    }
+
+    fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    fn with_status(mut self, status: u16) -> Self {
+        self.http_status = Some(status);
+        self
+    }
+}
+
+/// Result of testing a provider's settings and connectivity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionTestReport {
+    pub provider: String,
+    pub success: bool,
+    /// Steps in the order they ran
+    pub checks: Vec<ConnectionCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ConnectionFailure>,
+    pub duration_ms: u64,
+    pub tested_at: DateTime<Utc>,
+}
+
+/// Read-only API call that succeeds only with working credentials
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiProbe {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// What a 404 means for this call, e.g. "Fly.io app 'web'"
+    pub resource: String,
+}
+
+/// Value saved for one of the provider's `auth_fields` in providers.json
+fn auth_field_value<'a>(settings: &'a ProviderSettings, field: &str) -> Option<&'a str> {
+    let value = match field {
+        "api_key" | "api_token" | "token_id" => &settings.api_key,
+        "token_secret" => &settings.api_secret,
+        "workspace_url" => &settings.api_endpoint,
+        "workspace_id" => &settings.workspace_id,
+        "project_id" => &settings.project_id,
+        "account_id" => &settings.account_id,
+        "app_name" => &settings.app_name,
+        _ => return None,
+    };
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Check that the settings hold everything the provider needs before calling it
+pub fn validate_settings(
+    provider: &Provider,
+    settings: Option<&ProviderSettings>,
+) -> Result<(), ConnectionFailure> {
+    let required = provider.auth_fields.as_deref().unwrap_or_default();
+    if !provider.requires_auth || required.is_empty() {
+        return Ok(());
+    }
+
+    let Some(settings) = settings else {
+        return Err(ConnectionFailure::new(
+            FailureKind::MissingCredentials,
+            format!("No settings saved for {}", provider.display_name),
+        )
+        .with_hint(format!("Save {} first", required.join(", "))));
+    };
+
+    let missing: Vec<&str> = required
+        .iter()
+        .filter(|field| auth_field_value(settings, field).is_none())
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(ConnectionFailure::new(
+            FailureKind::MissingCredentials,
+            format!("Missing {}", missing.join(", ")),
+        )
+        .with_hint(format!(
+            "Add the missing values in the {} provider settings",
+            provider.display_name
+        )));
+    }
+
+    if let Some(endpoint) = settings
+        .api_endpoint
+        .as_deref()
+        .filter(|e| !e.trim().is_empty())
+    {
+        if !endpoint.starts_with("https://") && !endpoint.starts_with("http://") {
+            return Err(ConnectionFailure::new(
+                FailureKind::InvalidSettings,
+                format!("API endpoint '{}' is not an http(s) URL", endpoint),
+            )
+            .with_hint("Use the full URL, e.g. https://api.example.com"));
+        }
+    }
+
+    Ok(())
+}
+
+/// API call used to verify a cloud provider's credentials, or `None` when the
+/// provider has no REST API Orkee can call (Modal and Beam use their own clients)
+pub fn api_probe(provider_id: &str, settings: &ProviderSettings) -> Option<ApiProbe> {
+    let value = |field: &str| auth_field_value(settings, field).unwrap_or_default();
+    let endpoint = |default: &str| {
+        settings
+            .api_endpoint
+            .as_deref()
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .unwrap_or(default)
+            .trim_end_matches('/')
+            .to_string()
+    };
+    let bearer = |token: &str| ("Authorization".to_string(), format!("Bearer {}", token));
+
+    let probe = match provider_id {
+        "e2b" => ApiProbe {
+            url: format!("{}/sandboxes", endpoint("https://api.e2b.dev")),
+            headers: vec![("X-API-Key".to_string(), value("api_key").to_string())],
+            resource: "E2B API".to_string(),
+        },
+        "daytona" => ApiProbe {
+            url: format!("{}/sandbox", endpoint("https://app.daytona.io/api")),
+            headers: vec![bearer(value("api_key"))],
+            resource: "Daytona API".to_string(),
+        },
+        "flyio" => ApiProbe {
+            url: format!("https://api.machines.dev/v1/apps/{}", value("app_name")),
+            headers: vec![bearer(value("api_token"))],
+            resource: format!("Fly.io app '{}'", value("app_name")),
+        },
+        "northflank" => ApiProbe {
+            url: format!(
+                "{}/projects/{}",
+                endpoint("https://api.northflank.com/v1"),
+                value("project_id")
+            ),
+            headers: vec![bearer(value("api_token"))],
+            resource: format!("Northflank project '{}'", value("project_id")),
+        },
+        "cloudflare" => ApiProbe {
+            url: format!(
+                "{}/accounts/{}",
+                endpoint("https://api.cloudflare.com/client/v4"),
+                value("account_id")
+            ),
+            headers: vec![bearer(value("api_token"))],
+            resource: format!("Cloudflare account '{}'", value("account_id")),
+        },
+        _ => return None,
+    };
+    Some(probe)
+}
+
+/// Turn a provider API status code into a failure, or `Ok` for success
+pub fn classify_status(status: u16, probe: &ApiProbe) -> Result<(), ConnectionFailure> {
+    let failure = match status {
+        200..=299 => return Ok(()),
+        401 => ConnectionFailure::new(
+            FailureKind::InvalidCredentials,
+            "The provider rejected the credentials",
+        )
+        .with_hint("Check that the API key or token is correct and hasn't been revoked"),
+        403 => ConnectionFailure::new(
+            FailureKind::PermissionDenied,
+            format!(
+                "The credentials don't have access to the {}",
+                probe.resource
+            ),
+        )
+        .with_hint("Grant the token read access or use a token with a broader scope"),
+        404 => ConnectionFailure::new(
+            FailureKind::ResourceNotFound,
+            format!("{} was not found", probe.resource),
+        )
+        .with_hint("Check the configured IDs and API endpoint"),
+        429 => ConnectionFailure::new(
+            FailureKind::RateLimited,
+            "The provider is rate limiting requests",
+        )
+        .with_hint("Wait a minute and test again"),
+        500..=599 => ConnectionFailure::new(
+            FailureKind::ProviderError,
+            format!("The provider returned a server error ({})", status),
+        )
+        .with_hint("The provider may be having an outage; try again later"),
+        _ => ConnectionFailure::new(
+            FailureKind::ProviderError,
+            format!("Unexpected response from the provider ({})", status),
+        ),
+    };
+    Err(failure.with_status(status))
+}
+
+/// Describe a request that never got a response
+fn classify_request_error(error: &reqwest::Error) -> ConnectionFailure {
+    if error.is_timeout() {
+        ConnectionFailure::new(
+            FailureKind::Timeout,
+            format!(
+                "The provider didn't answer within {}s",
+                REQUEST_TIMEOUT.as_secs()
+            ),
+        )
+        .with_hint("Check your network connection or proxy settings")
+    } else if error.is_connect() {
+        ConnectionFailure::new(
+            FailureKind::Unreachable,
+            format!("Couldn't connect to the provider: {}", error),
+        )
+        .with_hint("Check the API endpoint and your network connection")
+    } else if error.is_builder() {
+        ConnectionFailure::new(
+            FailureKind::InvalidSettings,
+            format!("Couldn't build the request: {}", error),
+        )
+        .with_hint("Check the API endpoint and credentials for invalid characters")
+    } else {
+        ConnectionFailure::new(
+            FailureKind::Unreachable,
+            format!("Request to the provider failed: {}", error),
+        )
+    }
+}
+
+/// Runs connection tests against sandbox providers
+pub struct ConnectionTester {
+    http: reqwest::Client,
+}
+
+impl Default for ConnectionTester {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConnectionTester {
+    pub fn new() -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { http }
+    }
+
+    /// Test a provider. `docker` is the registered local provider, if Docker was found at startup.
+    pub async fn test(
+        &self,
+        provider_id: &str,
+        settings: Option<&ProviderSettings>,
+        docker: Option<Arc<dyn SandboxProvider>>,
+    ) -> ConnectionTestReport {
+        let started = Instant::now();
+        let mut checks = Vec::new();
+        let result = self
+            .run_checks(provider_id, settings, docker, &mut checks)
+            .await;
+
+        let failure = result.err();
+        if let Some(failure) = &failure {
+            debug!(
+                "Connection test for {} failed: {:?}",
+                provider_id, failure.kind
+            );
+        }
+
+        ConnectionTestReport {
+            provider: provider_id.to_string(),
+            success: failure.is_none(),
+            checks,
+            failure,
+            duration_ms: started.elapsed().as_millis() as u64,
+            tested_at: Utc::now(),
+        }
+    }
+
+    async fn run_checks(
+        &self,
+        provider_id: &str,
+        settings: Option<&ProviderSettings>,
+        docker: Option<Arc<dyn SandboxProvider>>,
+        checks: &mut Vec<ConnectionCheck>,
+    ) -> Result<(), ConnectionFailure> {
+        let Some(provider) = PROVIDER_REGISTRY.get(provider_id) else {
+            return Err(ConnectionFailure::new(
+                FailureKind::UnknownProvider,
+                format!("Unknown sandbox provider '{}'", provider_id),
+            ));
+        };
+
+        if let Err(failure) = validate_settings(provider, settings) {
+            checks.push(ConnectionCheck::new(
+                "settings",
+                CheckStatus::Failed,
+                &failure.message,
+            ));
+            return Err(failure);
+        }
+        checks.push(ConnectionCheck::new(
+            "settings",
+            CheckStatus::Passed,
+            "Required settings are present",
+        ));
+
+        if provider.provider_type == "docker" {
+            let image = settings
+                .and_then(|s| s.default_image.as_deref())
+                .or_else(|| provider.default_config["image"].as_str())
+                .unwrap_or(DEFAULT_TEST_IMAGE);
+            return self.test_docker(docker, image, checks).await;
+        }
+
+        // validate_settings only passes without settings for providers needing no auth
+        let probe = settings.and_then(|settings| api_probe(provider_id, settings));
+        let Some(probe) = probe else {
+            let failure = ConnectionFailure::new(
+                FailureKind::NotSupported,
+                format!(
+                    "Orkee can't verify {} credentials yet",
+                    provider.display_name
+                ),
+            )
+            .with_hint("The settings look complete; they'll be checked when a sandbox is created");
+            checks.push(ConnectionCheck::new(
+                "api",
+                CheckStatus::Skipped,
+                &failure.message,
+            ));
+            return Err(failure);
+        };
+
+        match self.call_api(&probe).await {
+            Ok(()) => {
+                checks.push(ConnectionCheck::new(
+                    "api",
+                    CheckStatus::Passed,
+                    format!("Authenticated against the {}", probe.resource),
+                ));
+                Ok(())
+            }
+            Err(failure) => {
+                checks.push(ConnectionCheck::new(
+                    "api",
+                    CheckStatus::Failed,
+                    &failure.message,
+                ));
+                Err(failure)
+            }
+        }
+    }
+
+    async fn call_api(&self, probe: &ApiProbe) -> Result<(), ConnectionFailure> {
+        let mut request = self.http.get(&probe.url);
+        for (name, value) in &probe.headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| classify_request_error(&e))?;
+        classify_status(response.status().as_u16(), probe)
+    }
+
+    /// Ping the daemon, then create and remove a container from an image that's already pulled
+    async fn test_docker(
+        &self,
+        docker: Option<Arc<dyn SandboxProvider>>,
+        image: &str,
+        checks: &mut Vec<ConnectionCheck>,
+    ) -> Result<(), ConnectionFailure> {
+        let unreachable = || {
+            ConnectionFailure::new(FailureKind::Unreachable, "Docker daemon is not responding")
+                .with_hint("Start Docker Desktop or the Docker service, then restart Orkee")
+        };
+
+        let Some(docker) = docker else {
+            let failure = unreachable();
+            checks.push(ConnectionCheck::new(
+                "daemon",
+                CheckStatus::Failed,
+                &failure.message,
+            ));
+            return Err(failure);
+        };
+
+        if !docker.is_available().await.unwrap_or(false) {
+            let failure = unreachable();
+            checks.push(ConnectionCheck::new(
+                "daemon",
+                CheckStatus::Failed,
+                &failure.message,
+            ));
+            return Err(failure);
+        }
+        checks.push(ConnectionCheck::new(
+            "daemon",
+            CheckStatus::Passed,
+            "Docker daemon is responding",
+        ));
+
+        // Pulling could take minutes, so the container check only runs on local images
+        if !docker.image_exists(image).await.unwrap_or(false) {
+            checks.push(ConnectionCheck::new(
+                "container",
+                CheckStatus::Skipped,
+                format!(
+                    "Image '{}' is not pulled yet; run `docker pull {}` to test container creation",
+                    image, image
+                ),
+            ));
+            return Ok(());
+        }
+
+        let config = ContainerConfig {
+            image: image.to_string(),
+            name: format!("orkee-connection-test-{}", uuid::Uuid::new_v4()),
+            env_vars: HashMap::new(),
+            volumes: Vec::new(),
+            ports: Vec::new(),
+            cpu_cores: 0.5,
+            memory_mb: 128,
+            storage_gb: 1,
+            command: Some(vec!["true".to_string()]),
+            working_dir: None,
+            labels: HashMap::from([("orkee.connection_test".to_string(), "true".to_string())]),
+            gpu_device_ids: Vec::new(),
+            network_disabled: false,
+        };
+
+        let container_id = match docker.create_container(&config).await {
+            Ok(id) => id,
+            Err(e) => {
+                let failure = ConnectionFailure::new(
+                    FailureKind::ProviderError,
+                    format!("Couldn't create a test container: {}", e),
+                )
+                .with_hint("Check Docker's disk space and resource limits");
+                checks.push(ConnectionCheck::new(
+                    "container",
+                    CheckStatus::Failed,
+                    &failure.message,
+                ));
+                return Err(failure);
+            }
+        };
+
+        if let Err(e) = docker.remove_container(&container_id, true).await {
+            warn!(
+                "Failed to remove connection test container {}: {}",
+                container_id, e
+            );
+        }
+        checks.push(ConnectionCheck::new(
+            "container",
+            CheckStatus::Passed,
+            format!("Created and removed a container from '{}'", image),
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(provider: &str) -> ProviderSettings {
+        ProviderSettings {
+            provider: provider.to_string(),
+            enabled: true,
+            configured: true,
+            validated_at: None,
+            validation_error: None,
+            api_key: None,
+            api_secret: None,
+            api_endpoint: None,
+            workspace_id: None,
+            project_id: None,
+            account_id: None,
+            organization_id: None,
+            app_name: None,
+            namespace_id: None,
+            default_region: None,
+            default_instance_type: None,
+            default_image: None,
+            default_cpu_cores: None,
+            default_memory_mb: None,
+            default_disk_gb: None,
+            default_gpu_type: None,
+            cost_per_hour: None,
+            cost_per_gb_memory: None,
+            cost_per_vcpu: None,
+            cost_per_gpu_hour: None,
+            max_sandboxes: None,
+            max_runtime_hours: None,
+            max_total_cost: None,
+            custom_config: None,
+            updated_at: String::new(),
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_settings_reports_missing_fields() {
+        let flyio = PROVIDER_REGISTRY.get("flyio").unwrap();
+
+        let failure = validate_settings(flyio, None).unwrap_err();
+        assert_eq!(failure.kind, FailureKind::MissingCredentials);
+
+        let mut fly_settings = settings("flyio");
+        fly_settings.api_key = Some("token".to_string());
+        fly_settings.app_name = Some("  ".to_string());
+        let failure = validate_settings(flyio, Some(&fly_settings)).unwrap_err();
+        assert_eq!(failure.message, "Missing app_name");
+
+        fly_settings.app_name = Some("web".to_string());
+        assert!(validate_settings(flyio, Some(&fly_settings)).is_ok());
+
+        fly_settings.api_endpoint = Some("api.fly.io".to_string());
+        let failure = validate_settings(flyio, Some(&fly_settings)).unwrap_err();
+        assert_eq!(failure.kind, FailureKind::InvalidSettings);
+    }
+
+    #[test]
+    fn test_local_provider_needs_no_settings() {
+        let local = PROVIDER_REGISTRY.get("local").unwrap();
+        assert!(validate_settings(local, None).is_ok());
+    }
+
+    #[test]
+    fn test_api_probe_uses_settings() {
+        let mut e2b = settings("e2b");
+        e2b.api_key = Some("e2b-key".to_string());
+        let probe = api_probe("e2b", &e2b).unwrap();
+        assert_eq!(probe.url, "https://api.e2b.dev/sandboxes");
+        assert_eq!(
+            probe.headers,
+            vec![("X-API-Key".to_string(), "e2b-key".to_string())]
+        );
+
+        e2b.api_endpoint = Some("https://e2b.internal/".to_string());
+        assert_eq!(
+            api_probe("e2b", &e2b).unwrap().url,
+            "https://e2b.internal/sandboxes"
+        );
+
+        let mut cloudflare = settings("cloudflare");
+        cloudflare.api_key = Some("cf-token".to_string());
+        cloudflare.account_id = Some("acct".to_string());
+        let probe = api_probe("cloudflare", &cloudflare).unwrap();
+        assert_eq!(
+            probe.url,
+            "https://api.cloudflare.com/client/v4/accounts/acct"
+        );
+        assert_eq!(probe.headers[0].1, "Bearer cf-token");
+
+        assert!(api_probe("modal", &settings("modal")).is_none());
+    }
+
+    #[test]
+    fn test_classify_status() {
+        let probe = ApiProbe {
+            url: "https://api.machines.dev/v1/apps/web".to_string(),
+            headers: Vec::new(),
+            resource: "Fly.io app 'web'".to_string(),
+        };
+
+        assert!(classify_status(200, &probe).is_ok());
+
+        let unauthorized = classify_status(401, &probe).unwrap_err();
+        assert_eq!(unauthorized.kind, FailureKind::InvalidCredentials);
+        assert_eq!(unauthorized.http_status, Some(401));
+        assert!(unauthorized.hint.is_some());
+
+        let not_found = classify_status(404, &probe).unwrap_err();
+        assert_eq!(not_found.kind, FailureKind::ResourceNotFound);
+        assert_eq!(not_found.message, "Fly.io app 'web' was not found");
+
+        assert_eq!(
+            classify_status(429, &probe).unwrap_err().kind,
+            FailureKind::RateLimited
+        );
+        assert_eq!(
+            classify_status(503, &probe).unwrap_err().kind,
+            FailureKind::ProviderError
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unknown_provider_and_missing_docker() {
+        let tester = ConnectionTester::new();
+
+        let report = tester.test("nope", None, None).await;
+        assert!(!report.success);
+        assert_eq!(report.failure.unwrap().kind, FailureKind::UnknownProvider);
+
+        let report = tester.test("local", None, None).await;
+        assert!(!report.success);
+        assert_eq!(report.checks[0].name, "settings");
+        assert_eq!(report.checks[1].name, "daemon");
+        assert_eq!(report.checks[1].status, CheckStatus::Failed);
+        assert_eq!(report.failure.unwrap().kind, FailureKind::Unreachable);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_provider_is_reported() {
+        let mut modal = settings("modal");
+        modal.api_key = Some("token-id".to_string());
+        modal.api_secret = Some("token-secret".to_string());
+
+        let report = ConnectionTester::new()
+            .test("modal", Some(&modal), None)
+            .await;
+        assert!(!report.success);
+        assert_eq!(report.checks[1].status, CheckStatus::Skipped);
+        assert_eq!(report.failure.unwrap().kind, FailureKind::NotSupported);
+    }
+}
@@ -67,6 +67,8 @@ impl CommandExecutor {
                 request.sandbox_id, sandbox.status
             )));
         }
+        self.manager
+            .check_tool_permissions(&sandbox, &request.command)?;
 
         // Create execution record
         let command_str = request.command.join(" ");
@@ -154,6 +156,8 @@ impl CommandExecutor {
                 request.sandbox_id, sandbox.status
             )));
         }
+        self.manager
+            .check_tool_permissions(&sandbox, &request.command)?;
 
         // Create execution record
         let command_str = request.command.join(" ");
@@ -1,25 +1,35 @@
 // ABOUTME: Sandbox provider registry for loading and managing sandbox provider configurations
 // ABOUTME: Loads provider definitions from config/providers.json at runtime
 
+pub mod connectivity;
 pub mod cost;
 pub mod executor;
 pub mod health;
 pub mod manager;
 pub mod monitor;
 pub mod providers;
+pub mod retention;
 pub mod settings;
 pub mod storage;
 
+pub use connectivity::{
+    CheckStatus, ConnectionCheck, ConnectionFailure, ConnectionTestReport, ConnectionTester,
+    FailureKind,
+};
 pub use cost::{CostBreakdown, CostCalculator};
 pub use executor::{CommandExecutor, ExecuteCommandRequest, ExecutionResult, ExecutorError};
 pub use health::{HealthCheck, HealthChecker, HealthStatus};
 pub use manager::{CreateSandboxRequest, ManagerError, SandboxManager};
 pub use monitor::{AggregatedMetrics, ResourceMonitor, ResourceSnapshot};
 pub use providers::{DockerProvider, Provider as SandboxProvider};
+pub use retention::{
+    ExecutionArchive, LogRetention, PolicyUsage, RetentionPolicy, RetentionPolicyInput,
+    RetentionReport,
+};
 pub use settings::{ProviderSettings, SandboxSettings, SettingsManager};
 pub use storage::{
-    EnvVar, ExecutionStatus, Sandbox, SandboxExecution, SandboxStatus, SandboxStorage,
-    StorageError, Volume,
+    CacheVolume, EnvVar, ExecutionStatus, Sandbox, SandboxExecution, SandboxStatus, SandboxStorage,
+    SandboxTemplate, StorageError, Volume,
 };
 
 use serde::{Deserialize, Serialize};
@@ -2,19 +2,20 @@
 // ABOUTME: Manages complete sandbox lifecycle from creation to termination with database settings
 
 use crate::providers::{
-    ContainerConfig, ContainerInfo, PortMapping, Provider, ProviderError, VolumeMount,
+    select_gpus, ContainerConfig, ContainerInfo, PortMapping, Provider, ProviderError, VolumeMount,
 };
 use crate::settings::SettingsManager;
 use crate::storage::{
     EnvVar, ExecutionStatus, Sandbox, SandboxExecution, SandboxStatus, SandboxStorage,
-    StorageError, Volume,
+    SandboxTemplate, StorageError, Volume,
 };
 use chrono::Utc;
+use orkee_agents::{AgentRegistry, ToolPermissions, ToolPolicyStorage};
 use std::collections::HashMap;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::sync::RwLock;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, warn};
 
 #[derive(Error, Debug)]
 pub enum ManagerError {
@@ -38,10 +39,24 @@ pub enum ManagerError {
 
     #[error("Settings error: {0}")]
     SettingsError(String),
+
+    #[error("Template setup failed: {0}")]
+    SetupFailed(String),
 }
 
 pub type Result<T> = std::result::Result<T, ManagerError>;
 
+/// Sandbox metadata key recording the tool permissions the sandbox was created with
+const TOOL_PERMISSIONS_KEY: &str = "tool_permissions";
+
+/// Git config passed through the environment that rewrites every push URL to an
+/// unusable scheme, so `git push` fails even when invoked indirectly
+const GIT_PUSH_DISABLED_ENV: [(&str, &str); 3] = [
+    ("GIT_CONFIG_COUNT", "1"),
+    ("GIT_CONFIG_KEY_0", "url.orkee-push-disabled:.pushInsteadOf"),
+    ("GIT_CONFIG_VALUE_0", ""),
+];
+
 /// Request to create a new sandbox
 #[derive(Debug, Clone)]
 pub struct CreateSandboxRequest {
@@ -51,12 +66,20 @@ pub struct CreateSandboxRequest {
     pub user_id: String,
     pub project_id: Option<String>,
     pub image: Option<String>,
+    /// Execution template providing the image, environment, cache volumes and
+    /// setup commands. Explicit request values take precedence over the template.
+    pub template_id: Option<String>,
     pub cpu_cores: Option<f32>,
     pub memory_mb: Option<u32>,
     pub storage_gb: Option<u32>,
     pub gpu_enabled: bool,
     pub gpu_model: Option<String>,
+    /// GPUs to pass through when `gpu_enabled`; defaults to one
+    pub gpu_count: Option<u32>,
     pub env_vars: HashMap<String, String>,
+    /// Decrypted project secrets. Passed to the container environment between
+    /// template and request values, but never stored with the sandbox.
+    pub secret_env_vars: HashMap<String, String>,
     pub volumes: Vec<VolumeMount>,
     pub ports: Vec<PortMapping>,
     pub ssh_enabled: bool,
@@ -69,14 +92,17 @@ pub struct SandboxManager {
     storage: Arc<SandboxStorage>,
     settings: Arc<RwLock<SettingsManager>>,
     providers: Arc<RwLock<HashMap<String, Arc<dyn Provider>>>>,
+    tool_policies: ToolPolicyStorage,
 }
 
 impl SandboxManager {
     pub fn new(storage: Arc<SandboxStorage>, settings: Arc<RwLock<SettingsManager>>) -> Self {
+        let tool_policies = ToolPolicyStorage::new(storage.pool().clone());
         Self {
             storage,
             settings,
             providers: Arc::new(RwLock::new(HashMap::new())),
+            tool_policies,
         }
     }
 
@@ -149,6 +175,26 @@ impl SandboxManager {
             )));
         }
 
+        let permissions = self
+            .tool_policies
+            .get_policy(&request.agent_id)
+            .await
+            .map_err(|e| {
+                ManagerError::ConfigError(format!("Failed to load tool permissions: {}", e))
+            })?
+            .permissions;
+        if !permissions.network && !request.ports.is_empty() {
+            return Err(ManagerError::ConfigError(format!(
+                "Agent '{}' does not have network permission; ports cannot be exposed",
+                request.agent_id
+            )));
+        }
+
+        let template = match &request.template_id {
+            Some(template_id) => Some(self.storage.get_template(template_id).await?),
+            None => None,
+        };
+
         // Load settings
         let settings_guard = self.settings.read().await;
         let sandbox_settings = settings_guard
@@ -307,6 +353,37 @@ impl SandboxManager {
             }
         }
 
+        // Check GPU limits
+        let gpu_model = request
+            .gpu_model
+            .clone()
+            .or_else(|| provider_settings.default_gpu_type.clone());
+        let gpu_count = request.gpu_count.unwrap_or(1);
+        if request.gpu_enabled && gpu_count as i64 > sandbox_settings.max_gpu_per_sandbox {
+            return Err(ManagerError::ResourceLimitExceeded(format!(
+                "GPU count {} exceeds limit of {}",
+                gpu_count, sandbox_settings.max_gpu_per_sandbox
+            )));
+        }
+
+        // Local GPUs are passed through, so validate requests against the hardware
+        // Docker can see. Cloud providers provision GPUs by model instead.
+        let gpu_device_ids = if request.gpu_enabled && is_local_provider {
+            let gpus = self
+                .get_provider(&request.provider)
+                .await?
+                .detect_gpus()
+                .await?;
+            select_gpus(&gpus, gpu_model.as_deref(), gpu_count).map_err(|e| {
+                ManagerError::ConfigError(format!(
+                    "Provider {} can't provide the requested GPU: {}",
+                    request.provider, e
+                ))
+            })?
+        } else {
+            Vec::new()
+        };
+
         // Check cost limits if cost tracking is enabled
         if sandbox_settings.cost_tracking_enabled {
             // Calculate estimated cost for this sandbox (placeholder - actual implementation needed)
@@ -423,8 +500,63 @@ impl SandboxManager {
             }
         }
 
+        // Setup commands run without an interactive caller, so hold them to the
+        // same policy as commands run through the API
+        if let Some(template) = &template {
+            for command in &template.setup_commands {
+                Self::check_blocked_command(
+                    &sandbox_settings,
+                    command,
+                    command.split_whitespace(),
+                )?;
+            }
+        }
+
         drop(settings_guard);
 
+        // Template values first so the request can override them
+        let mut env_vars: HashMap<String, String> = template
+            .as_ref()
+            .map(|t| t.env_vars.clone())
+            .unwrap_or_default();
+        env_vars.extend(request.env_vars.clone());
+
+        // Cache volumes are named provider volumes shared between sandboxes, so
+        // they skip the host path checks above. A request volume at the same
+        // container path replaces the cache.
+        let mut volume_mounts = request.volumes.clone();
+        if let Some(template) = &template {
+            for cache in &template.cache_volumes {
+                if !volume_mounts
+                    .iter()
+                    .any(|v| v.container_path == cache.container_path)
+                {
+                    volume_mounts.push(VolumeMount {
+                        host_path: cache.volume_name(),
+                        container_path: cache.container_path.clone(),
+                        readonly: false,
+                    });
+                }
+            }
+        }
+        if !permissions.filesystem_write {
+            for mount in &mut volume_mounts {
+                mount.readonly = true;
+            }
+        }
+
+        let mut metadata = request
+            .metadata
+            .clone()
+            .unwrap_or_else(|| serde_json::json!({}));
+        if let Some(metadata) = metadata.as_object_mut() {
+            metadata.insert(
+                TOOL_PERMISSIONS_KEY.to_string(),
+                serde_json::to_value(permissions)
+                    .map_err(|e| ManagerError::ConfigError(e.to_string()))?,
+            );
+        }
+
         // Create sandbox record
         let sandbox = Sandbox {
             id: String::new(), // Will be generated by storage
@@ -438,7 +570,7 @@ impl SandboxManager {
             memory_mb,
             storage_gb,
             gpu_enabled: request.gpu_enabled,
-            gpu_model: request.gpu_model.clone(),
+            gpu_model: gpu_model.filter(|_| request.gpu_enabled),
             public_url: None,
             ssh_enabled: request.ssh_enabled,
             ssh_key: None,
@@ -451,12 +583,11 @@ impl SandboxManager {
             project_id: request.project_id.clone(),
             user_id: request.user_id.clone(),
             config: request.config.clone(),
-            metadata: request.metadata.clone(),
+            metadata: Some(metadata),
         };
 
         // Prepare environment variables and volumes for atomic creation
-        let env_vars: Vec<EnvVar> = request
-            .env_vars
+        let env_var_records: Vec<EnvVar> = env_vars
             .iter()
             .map(|(name, value)| EnvVar {
                 id: None,
@@ -468,8 +599,7 @@ impl SandboxManager {
             })
             .collect();
 
-        let volumes: Vec<Volume> = request
-            .volumes
+        let volumes: Vec<Volume> = volume_mounts
             .iter()
             .map(|v| Volume {
                 id: None,
@@ -485,7 +615,7 @@ impl SandboxManager {
         // This ensures all related data is created atomically
         let sandbox = self
             .storage
-            .create_sandbox_with_resources(sandbox, env_vars, volumes)
+            .create_sandbox_with_resources(sandbox, env_var_records, volumes)
             .await?;
 
         // Get provider
@@ -493,11 +623,13 @@ impl SandboxManager {
 
         // Get default image with fallback chain:
         // 1. Request image (explicit override)
-        // 2. Provider default image (provider-specific)
-        // 3. Global default image (sandbox settings)
-        // 4. Hardcoded fallback
+        // 2. Template image
+        // 3. Provider default image (provider-specific)
+        // 4. Global default image (sandbox settings)
+        // 5. Hardcoded fallback
         let image = request
             .image
+            .or_else(|| template.as_ref().map(|t| t.image.clone()))
             .or(provider_settings.default_image.clone())
             .unwrap_or_else(|| sandbox_settings.default_image.clone());
 
@@ -505,12 +637,28 @@ impl SandboxManager {
         let mut labels = HashMap::new();
         labels.insert("orkee.sandbox.id".to_string(), sandbox.id.clone());
         labels.insert("orkee.sandbox.agent".to_string(), request.agent_id.clone());
+        if let Some(template) = &template {
+            labels.insert("orkee.sandbox.template".to_string(), template.id.clone());
+        }
+
+        // Secrets go into the container only, after the persisted records were built
+        let mut container_env: HashMap<String, String> = template
+            .as_ref()
+            .map(|t| t.env_vars.clone())
+            .unwrap_or_default();
+        container_env.extend(request.secret_env_vars.clone());
+        container_env.extend(request.env_vars.clone());
+        if !permissions.git_push {
+            for (name, value) in GIT_PUSH_DISABLED_ENV {
+                container_env.insert(name.to_string(), value.to_string());
+            }
+        }
 
         let config = ContainerConfig {
             image,
             name: request.name.clone(),
-            env_vars: request.env_vars.clone(),
-            volumes: request.volumes.clone(),
+            env_vars: container_env,
+            volumes: volume_mounts,
             ports: request.ports.clone(),
             cpu_cores,
             memory_mb: memory_mb as u64,
@@ -518,6 +666,8 @@ impl SandboxManager {
             command: None,
             working_dir: Some("/workspace".to_string()),
             labels,
+            gpu_device_ids,
+            network_disabled: !permissions.network,
         };
 
         // Create container with provider
@@ -540,6 +690,16 @@ impl SandboxManager {
                     )
                     .await?;
 
+                if let Some(template) = &template {
+                    self.run_setup_commands(
+                        &updated_sandbox,
+                        &container_id,
+                        provider.as_ref(),
+                        &template.setup_commands,
+                    )
+                    .await?;
+                }
+
                 Ok(updated_sandbox)
             }
             Err(e) => {
@@ -722,6 +882,8 @@ impl SandboxManager {
             .filter(|id| !id.is_empty())
             .ok_or_else(|| ManagerError::ConfigError("No container ID".to_string()))?;
 
+        self.check_tool_permissions(&sandbox, &command)?;
+
         // Load settings to check blocked_commands
         let settings_guard = self.settings.read().await;
         let sandbox_settings = settings_guard
@@ -730,25 +892,11 @@ impl SandboxManager {
             .map_err(|e| ManagerError::SettingsError(e.to_string()))?;
 
         // Validate command against blocked_commands list
-        if let Some(blocked_commands) = &sandbox_settings.blocked_commands {
-            if let Some(blocked_list) = blocked_commands.as_array() {
-                let command_str = command.join(" ");
-                for blocked in blocked_list {
-                    if let Some(pattern) = blocked.as_str() {
-                        // Check if command starts with blocked pattern
-                        // This prevents both exact matches and commands with arguments
-                        if command_str.starts_with(pattern)
-                            || (!command.is_empty() && command[0] == pattern)
-                        {
-                            return Err(ManagerError::ConfigError(format!(
-                                "Command '{}' is blocked by security policy. Blocked pattern: '{}'",
-                                command_str, pattern
-                            )));
-                        }
-                    }
-                }
-            }
-        }
+        Self::check_blocked_command(
+            &sandbox_settings,
+            &command.join(" "),
+            command.iter().map(String::as_str),
+        )?;
 
         drop(settings_guard);
 
@@ -758,6 +906,147 @@ impl SandboxManager {
             .await?)
     }
 
+    /// Tool permissions a sandbox was created with. Sandboxes created before
+    /// permissions were recorded fall back to their agent's registry defaults.
+    pub fn tool_permissions(sandbox: &Sandbox) -> ToolPermissions {
+        sandbox
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(TOOL_PERMISSIONS_KEY))
+            .and_then(|value| serde_json::from_value(value.clone()).ok())
+            .unwrap_or_else(|| AgentRegistry::default().tool_permissions(&sandbox.agent_id))
+    }
+
+    /// Reject a command the sandbox's agent is not permitted to run
+    pub fn check_tool_permissions(&self, sandbox: &Sandbox, command: &[String]) -> Result<()> {
+        Self::tool_permissions(sandbox)
+            .check_command(command)
+            .map_err(|reason| {
+                warn!(
+                    "Blocked command in sandbox {} (agent {}): {}",
+                    sandbox.id, sandbox.agent_id, reason
+                );
+                ManagerError::ConfigError(reason)
+            })
+    }
+
+    /// Reject a command matching the blocked_commands settings. A command is
+    /// blocked if it starts with a blocked pattern or its program is one.
+    fn check_blocked_command<'a>(
+        sandbox_settings: &crate::settings::SandboxSettings,
+        command_str: &str,
+        mut args: impl Iterator<Item = &'a str>,
+    ) -> Result<()> {
+        let Some(blocked_list) = sandbox_settings
+            .blocked_commands
+            .as_ref()
+            .and_then(|b| b.as_array())
+        else {
+            return Ok(());
+        };
+
+        let program = args.next();
+        for pattern in blocked_list.iter().filter_map(|b| b.as_str()) {
+            // Check if command starts with blocked pattern
+            // This prevents both exact matches and commands with arguments
+            if command_str.starts_with(pattern) || program == Some(pattern) {
+                return Err(ManagerError::ConfigError(format!(
+                    "Command '{}' is blocked by security policy. Blocked pattern: '{}'",
+                    command_str, pattern
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run a template's setup commands in a freshly created sandbox, recording
+    /// each as an execution. The first failure puts the sandbox in the error state.
+    async fn run_setup_commands(
+        &self,
+        sandbox: &Sandbox,
+        container_id: &str,
+        provider: &dyn Provider,
+        commands: &[String],
+    ) -> Result<()> {
+        for command in commands {
+            debug!(
+                "Running setup command in sandbox {}: {}",
+                sandbox.id, command
+            );
+
+            let execution = self
+                .create_execution(
+                    &sandbox.id,
+                    command.clone(),
+                    None,
+                    Some(sandbox.user_id.clone()),
+                    None,
+                )
+                .await?;
+            self.update_execution_status(&execution.id, ExecutionStatus::Running, None, None, None)
+                .await?;
+
+            let shell_command = vec!["sh".to_string(), "-c".to_string(), command.clone()];
+            let failure = match provider
+                .exec_command(container_id, shell_command, None)
+                .await
+            {
+                Ok(result) => {
+                    let stdout = String::from_utf8_lossy(&result.stdout).to_string();
+                    let stderr = String::from_utf8_lossy(&result.stderr).to_string();
+                    let status = if result.exit_code == 0 {
+                        ExecutionStatus::Completed
+                    } else {
+                        ExecutionStatus::Failed
+                    };
+                    self.update_execution_status(
+                        &execution.id,
+                        status,
+                        Some(result.exit_code as i32),
+                        Some(stdout),
+                        Some(stderr.clone()),
+                    )
+                    .await?;
+
+                    (result.exit_code != 0).then(|| {
+                        format!(
+                            "'{}' exited with code {}: {}",
+                            command,
+                            result.exit_code,
+                            stderr.trim()
+                        )
+                    })
+                }
+                Err(e) => {
+                    self.update_execution_status(
+                        &execution.id,
+                        ExecutionStatus::Failed,
+                        None,
+                        None,
+                        Some(e.to_string()),
+                    )
+                    .await?;
+                    Some(format!("'{}' could not be run: {}", command, e))
+                }
+            };
+
+            if let Some(message) = failure {
+                error!("Setup failed for sandbox {}: {}", sandbox.id, message);
+                self.storage
+                    .update_sandbox_status(
+                        &sandbox.id,
+                        SandboxStatus::Error,
+                        Some(format!("Template setup failed: {}", message)),
+                    )
+                    .await?;
+                return Err(ManagerError::SetupFailed(message));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Create an execution record
     pub async fn create_execution(
         &self,
@@ -813,6 +1102,51 @@ impl SandboxManager {
         Ok(self.storage.list_executions(sandbox_id).await?)
     }
 
+    /// List execution templates, built-in templates first
+    pub async fn list_templates(&self) -> Result<Vec<SandboxTemplate>> {
+        Ok(self.storage.list_templates().await?)
+    }
+
+    /// Get an execution template by ID
+    pub async fn get_template(&self, template_id: &str) -> Result<SandboxTemplate> {
+        Ok(self.storage.get_template(template_id).await?)
+    }
+
+    /// Create an execution template, if custom templates are allowed
+    pub async fn create_template(&self, template: SandboxTemplate) -> Result<SandboxTemplate> {
+        self.ensure_custom_templates_allowed().await?;
+        Ok(self.storage.create_template(template).await?)
+    }
+
+    /// Update an execution template, if custom templates are allowed
+    pub async fn update_template(&self, template: SandboxTemplate) -> Result<SandboxTemplate> {
+        self.ensure_custom_templates_allowed().await?;
+        Ok(self.storage.update_template(template).await?)
+    }
+
+    /// Delete a user-created execution template
+    pub async fn delete_template(&self, template_id: &str) -> Result<()> {
+        Ok(self.storage.delete_template(template_id).await?)
+    }
+
+    async fn ensure_custom_templates_allowed(&self) -> Result<()> {
+        let sandbox_settings = self
+            .settings
+            .read()
+            .await
+            .get_sandbox_settings()
+            .await
+            .map_err(|e| ManagerError::SettingsError(e.to_string()))?;
+
+        if !sandbox_settings.allow_custom_templates {
+            return Err(ManagerError::ConfigError(
+                "Custom templates are disabled in settings".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Estimate the cost of running a sandbox
     /// Returns the estimated cost in USD per hour
     fn estimate_sandbox_cost(
@@ -977,6 +1311,7 @@ mod tests {
                     file_transfer: true,
                     metrics: true,
                 },
+                gpus: Vec::new(),
                 status: crate::providers::ProviderStatus::Ready,
             })
         }
@@ -1099,6 +1434,17 @@ mod tests {
         async fn image_exists(&self, _image: &str) -> std::result::Result<bool, ProviderError> {
             Ok(true)
         }
+
+        async fn detect_gpus(
+            &self,
+        ) -> std::result::Result<Vec<crate::providers::GpuDevice>, ProviderError> {
+            Ok(vec![crate::providers::GpuDevice {
+                index: 0,
+                uuid: "GPU-mock-0".to_string(),
+                name: "Tesla T4".to_string(),
+                memory_mb: Some(15360),
+            }])
+        }
     }
 
     async fn create_test_db() -> sqlx::SqlitePool {
@@ -1154,12 +1500,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(999.0), // Way over limit
             memory_mb: Some(1024),
             storage_gb: Some(10),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1186,12 +1535,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(2.0),
             memory_mb: Some(999_000), // Way over limit (999 GB)
             storage_gb: Some(10),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1218,12 +1570,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(2.0),
             memory_mb: Some(1024),
             storage_gb: Some(9999), // Way over limit
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1261,12 +1616,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(1.0),
             memory_mb: Some(512),
             storage_gb: Some(10),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1285,12 +1643,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(1.0),
             memory_mb: Some(512),
             storage_gb: Some(10),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1309,12 +1670,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(1.0),
             memory_mb: Some(512),
             storage_gb: Some(10),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1353,12 +1717,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(2.0),
             memory_mb: Some(1024),
             storage_gb: Some(20),
             gpu_enabled: true,
             gpu_model: Some("T4".to_string()),
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1377,12 +1744,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(2.0),
             memory_mb: Some(1024),
             storage_gb: Some(20),
             gpu_enabled: true,
             gpu_model: Some("T4".to_string()),
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1430,12 +1800,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(8.0),
             memory_mb: Some(16384),
             storage_gb: Some(100),
             gpu_enabled: true,
             gpu_model: Some("A100".to_string()),
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1462,12 +1835,15 @@ mod tests {
             user_id: "default-user".to_string(),
             project_id: None,
             image: None,
+            template_id: None,
             cpu_cores: Some(2.0),
             memory_mb: Some(2048),
             storage_gb: Some(20),
             gpu_enabled: false,
             gpu_model: None,
+            gpu_count: None,
             env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
             volumes: vec![],
             ports: vec![],
             ssh_enabled: false,
@@ -1484,6 +1860,274 @@ mod tests {
         assert_eq!(sandbox.storage_gb, 20);
     }
 
+    #[tokio::test]
+    async fn test_local_gpu_requests_validated_against_detected_hardware() {
+        let (manager, _pool) = setup_test_manager().await;
+
+        let gpu_request = |name: &str, model: &str, count: u32| CreateSandboxRequest {
+            name: name.to_string(),
+            provider: "local".to_string(),
+            agent_id: "claude-code".to_string(),
+            user_id: "default-user".to_string(),
+            project_id: None,
+            image: None,
+            template_id: None,
+            cpu_cores: Some(2.0),
+            memory_mb: Some(2048),
+            storage_gb: Some(20),
+            gpu_enabled: true,
+            gpu_model: Some(model.to_string()),
+            gpu_count: Some(count),
+            env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
+            volumes: vec![],
+            ports: vec![],
+            ssh_enabled: false,
+            config: None,
+            metadata: None,
+        };
+
+        let err = manager
+            .create_sandbox(gpu_request("a100-sandbox", "A100", 1))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ManagerError::ConfigError(_)));
+        assert!(err.to_string().contains("No A100 GPU detected"));
+
+        // The default limit is one GPU per sandbox
+        let err = manager
+            .create_sandbox(gpu_request("multi-gpu-sandbox", "T4", 2))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ManagerError::ResourceLimitExceeded(_)));
+
+        let sandbox = manager
+            .create_sandbox(gpu_request("t4-sandbox", "T4", 1))
+            .await
+            .unwrap();
+        assert!(sandbox.gpu_enabled);
+        assert_eq!(sandbox.gpu_model.as_deref(), Some("T4"));
+    }
+
+    #[tokio::test]
+    async fn test_create_sandbox_from_template() {
+        let (manager, _pool) = setup_test_manager().await;
+
+        let request = CreateSandboxRequest {
+            name: "rust-sandbox".to_string(),
+            provider: "local".to_string(),
+            agent_id: "claude-code".to_string(),
+            user_id: "default-user".to_string(),
+            project_id: None,
+            image: None,
+            template_id: Some("rust-toolchain".to_string()),
+            cpu_cores: Some(1.0),
+            memory_mb: Some(1024),
+            storage_gb: Some(10),
+            gpu_enabled: false,
+            gpu_model: None,
+            gpu_count: None,
+            env_vars: HashMap::from([("CARGO_TERM_COLOR".to_string(), "never".to_string())]),
+            secret_env_vars: HashMap::new(),
+            volumes: vec![],
+            ports: vec![],
+            ssh_enabled: false,
+            config: None,
+            metadata: None,
+        };
+
+        let sandbox = manager.create_sandbox(request).await.unwrap();
+        assert_eq!(sandbox.status, SandboxStatus::Running);
+
+        // Request values override the template's
+        let env_vars = manager.storage.list_env_vars(&sandbox.id).await.unwrap();
+        let env = |name: &str| {
+            env_vars
+                .iter()
+                .find(|v| v.name == name)
+                .map(|v| v.value.clone())
+        };
+        assert_eq!(env("CARGO_TERM_COLOR").as_deref(), Some("never"));
+        assert_eq!(env("CARGO_HOME").as_deref(), Some("/usr/local/cargo"));
+
+        let volumes = manager.storage.list_volumes(&sandbox.id).await.unwrap();
+        assert!(volumes
+            .iter()
+            .any(|v| v.host_path == "orkee-cache-cargo-registry"
+                && v.container_path == "/usr/local/cargo/registry"));
+
+        let executions = manager.list_executions(&sandbox.id).await.unwrap();
+        assert_eq!(executions.len(), 1);
+        assert_eq!(executions[0].command, "rustup component add clippy rustfmt");
+        assert_eq!(executions[0].status, ExecutionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_secret_env_vars_are_not_persisted() {
+        let (manager, _pool) = setup_test_manager().await;
+
+        let request = CreateSandboxRequest {
+            name: "secret-sandbox".to_string(),
+            provider: "local".to_string(),
+            agent_id: "claude-code".to_string(),
+            user_id: "default-user".to_string(),
+            project_id: None,
+            image: None,
+            template_id: None,
+            cpu_cores: Some(1.0),
+            memory_mb: Some(1024),
+            storage_gb: Some(10),
+            gpu_enabled: false,
+            gpu_model: None,
+            gpu_count: None,
+            env_vars: HashMap::from([("RUST_LOG".to_string(), "debug".to_string())]),
+            secret_env_vars: HashMap::from([("API_TOKEN".to_string(), "s3cret".to_string())]),
+            volumes: vec![],
+            ports: vec![],
+            ssh_enabled: false,
+            config: None,
+            metadata: None,
+        };
+
+        let sandbox = manager.create_sandbox(request).await.unwrap();
+
+        let env_vars = manager.storage.list_env_vars(&sandbox.id).await.unwrap();
+        assert!(env_vars.iter().any(|v| v.name == "RUST_LOG"));
+        assert!(!env_vars.iter().any(|v| v.name == "API_TOKEN"));
+    }
+
+    #[tokio::test]
+    async fn test_agent_tool_permissions_are_enforced() {
+        let (manager, _pool) = setup_test_manager().await;
+        manager
+            .tool_policies
+            .set_policy(
+                "claude-code",
+                ToolPermissions {
+                    filesystem_write: false,
+                    network: false,
+                    shell_exec: true,
+                    git_push: false,
+                },
+            )
+            .await
+            .unwrap();
+
+        let request = CreateSandboxRequest {
+            name: "restricted-sandbox".to_string(),
+            provider: "local".to_string(),
+            agent_id: "claude-code".to_string(),
+            user_id: "default-user".to_string(),
+            project_id: None,
+            image: None,
+            template_id: None,
+            cpu_cores: Some(1.0),
+            memory_mb: Some(1024),
+            storage_gb: Some(10),
+            gpu_enabled: false,
+            gpu_model: None,
+            gpu_count: None,
+            env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
+            volumes: vec![VolumeMount {
+                host_path: std::env::temp_dir().to_string_lossy().to_string(),
+                container_path: "/workspace".to_string(),
+                readonly: false,
+            }],
+            ports: vec![PortMapping {
+                host_port: 3000,
+                container_port: 3000,
+                protocol: "tcp".to_string(),
+            }],
+            ssh_enabled: false,
+            config: None,
+            metadata: None,
+        };
+
+        // Ports need network access
+        let err = manager.create_sandbox(request.clone()).await.unwrap_err();
+        assert!(matches!(err, ManagerError::ConfigError(_)));
+
+        let sandbox = manager
+            .create_sandbox(CreateSandboxRequest {
+                ports: vec![],
+                ..request
+            })
+            .await
+            .unwrap();
+
+        let volumes = manager.storage.list_volumes(&sandbox.id).await.unwrap();
+        assert!(volumes.iter().all(|v| v.read_only));
+        assert!(!SandboxManager::tool_permissions(&sandbox).network);
+
+        let command = |parts: &[&str]| parts.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        assert!(manager
+            .exec_sandbox_command(&sandbox.id, command(&["git", "status"]), None)
+            .await
+            .is_ok());
+        assert!(matches!(
+            manager
+                .exec_sandbox_command(&sandbox.id, command(&["git", "push"]), None)
+                .await,
+            Err(ManagerError::ConfigError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_template_is_rejected() {
+        let (manager, _pool) = setup_test_manager().await;
+
+        let request = CreateSandboxRequest {
+            name: "missing-template".to_string(),
+            provider: "local".to_string(),
+            agent_id: "claude-code".to_string(),
+            user_id: "default-user".to_string(),
+            project_id: None,
+            image: None,
+            template_id: Some("does-not-exist".to_string()),
+            cpu_cores: Some(1.0),
+            memory_mb: Some(1024),
+            storage_gb: Some(10),
+            gpu_enabled: false,
+            gpu_model: None,
+            gpu_count: None,
+            env_vars: HashMap::new(),
+            secret_env_vars: HashMap::new(),
+            volumes: vec![],
+            ports: vec![],
+            ssh_enabled: false,
+            config: None,
+            metadata: None,
+        };
+
+        let result = manager.create_sandbox(request).await;
+        assert!(matches!(
+            result,
+            Err(ManagerError::Storage(StorageError::TemplateNotFound(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_custom_templates_can_be_disabled() {
+        let (manager, _pool) = setup_test_manager().await;
+
+        let settings_guard = manager.settings.read().await;
+        let mut sandbox_settings = settings_guard.get_sandbox_settings().await.unwrap();
+        sandbox_settings.allow_custom_templates = false;
+        settings_guard
+            .update_sandbox_settings(&sandbox_settings, Some("test"))
+            .await
+            .unwrap();
+        drop(settings_guard);
+
+        let mut template = manager.get_template("node20-pnpm").await.unwrap();
+        template.id = String::new();
+        template.name = "Node copy".to_string();
+
+        let result = manager.create_template(template).await;
+        assert!(matches!(result, Err(ManagerError::ConfigError(_))));
+    }
+
     // Note: Full integration tests would require setting up test database
     // These are unit tests for the manager logic
 }
@@ -70,6 +70,7 @@ impl Provider for CloudflareProvider {
                 file_transfer: false,
                 metrics: false,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
@@ -63,6 +63,7 @@ impl Provider for DaytonaProvider {
                 file_transfer: true,
                 metrics: true,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
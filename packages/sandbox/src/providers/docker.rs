@@ -26,9 +26,9 @@
 // - Isolating the Docker daemon on a separate network/host
 
 use super::{
-    ContainerConfig, ContainerInfo, ContainerMetrics, ContainerStatus, ExecResult, OutputChunk,
-    OutputStream, Provider, ProviderCapabilities, ProviderError, ProviderInfo, ProviderStatus,
-    Result, StreamType,
+    ContainerConfig, ContainerInfo, ContainerMetrics, ContainerStatus, ExecResult, GpuDevice,
+    OutputChunk, OutputStream, Provider, ProviderCapabilities, ProviderError, ProviderInfo,
+    ProviderStatus, Result, StreamType,
 };
 use async_trait::async_trait;
 use bollard::{
@@ -45,6 +45,9 @@ use std::collections::HashMap;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
+/// Runtime and device driver the NVIDIA Container Toolkit registers with Docker
+const NVIDIA_DRIVER: &str = "nvidia";
+
 pub struct DockerProvider {
     client: Docker,
     label_prefix: String,
@@ -52,6 +55,19 @@ pub struct DockerProvider {
     pull_timeout: Duration,
 }
 
+/// Pass GPUs through by device ID, like `docker run --gpus device=<id>`
+fn gpu_device_requests(device_ids: &[String]) -> Option<Vec<bollard::models::DeviceRequest>> {
+    if device_ids.is_empty() {
+        return None;
+    }
+    Some(vec![bollard::models::DeviceRequest {
+        driver: Some(NVIDIA_DRIVER.to_string()),
+        device_ids: Some(device_ids.to_vec()),
+        capabilities: Some(vec![vec!["gpu".to_string()]]),
+        ..Default::default()
+    }])
+}
+
 impl DockerProvider {
     /// Create a new Docker provider with default timeout (10 minutes or from env)
     pub fn new() -> Result<Self> {
@@ -147,6 +163,12 @@ impl DockerProvider {
             )]));
         }
 
+        host_config.device_requests = gpu_device_requests(&config.gpu_device_ids);
+
+        if config.network_disabled {
+            host_config.network_mode = Some("none".to_string());
+        }
+
         Config {
             image: Some(config.image.clone()),
             cmd: config.command.clone(),
@@ -159,6 +181,39 @@ impl DockerProvider {
         }
     }
 
+    /// Whether Docker has the NVIDIA runtime needed for GPU passthrough
+    async fn has_nvidia_runtime(&self) -> bool {
+        match self.client.info().await {
+            Ok(info) => info
+                .runtimes
+                .is_some_and(|runtimes| runtimes.contains_key(NVIDIA_DRIVER)),
+            Err(e) => {
+                debug!("Failed to read Docker runtimes: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Parse `nvidia-smi --query-gpu=index,uuid,name,memory.total --format=csv,noheader,nounits`
+    fn parse_nvidia_smi(output: &str) -> Vec<GpuDevice> {
+        output
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+                if fields.len() < 4 {
+                    return None;
+                }
+                Some(GpuDevice {
+                    index: fields[0].parse().ok()?,
+                    uuid: fields[1].to_string(),
+                    // Names can contain commas; memory is always the last field
+                    name: fields[2..fields.len() - 1].join(", "),
+                    memory_mb: fields[fields.len() - 1].parse().ok(),
+                })
+            })
+            .collect()
+    }
+
     /// Convert bollard container status to our status
     fn convert_status(state: &str) -> ContainerStatus {
         match state.to_lowercase().as_str() {
@@ -198,13 +253,14 @@ impl Provider for DockerProvider {
         } else {
             ProviderStatus::NotAvailable("Docker daemon not responding".to_string())
         };
+        let gpus = self.detect_gpus().await?;
 
         Ok(ProviderInfo {
             name: "Docker".to_string(),
             version: version.version.unwrap_or_else(|| "unknown".to_string()),
             provider_type: "docker".to_string(),
             capabilities: ProviderCapabilities {
-                gpu_support: false, // Can be enabled with nvidia-docker
+                gpu_support: !gpus.is_empty(),
                 persistent_storage: true,
                 network_isolation: true,
                 resource_limits: true,
@@ -212,6 +268,7 @@ impl Provider for DockerProvider {
                 file_transfer: true,
                 metrics: true,
             },
+            gpus,
             status,
         })
     }
@@ -762,6 +819,40 @@ impl Provider for DockerProvider {
             Err(e) => Err(ProviderError::ImageError(e.to_string())),
         }
     }
+
+    async fn detect_gpus(&self) -> Result<Vec<GpuDevice>> {
+        if !self.has_nvidia_runtime().await {
+            return Ok(Vec::new());
+        }
+
+        // Docker runs locally, so the host's GPUs are the ones it can pass through
+        let output = tokio::process::Command::new("nvidia-smi")
+            .args([
+                "--query-gpu=index,uuid,name,memory.total",
+                "--format=csv,noheader,nounits",
+            ])
+            .output()
+            .await;
+        match output {
+            Ok(output) if output.status.success() => Ok(Self::parse_nvidia_smi(
+                &String::from_utf8_lossy(&output.stdout),
+            )),
+            Ok(output) => {
+                warn!(
+                    "nvidia-smi failed: {}",
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                Ok(Vec::new())
+            }
+            Err(e) => {
+                warn!(
+                    "NVIDIA runtime is installed but nvidia-smi can't run: {}",
+                    e
+                );
+                Ok(Vec::new())
+            }
+        }
+    }
 }
 
 // Helper functions for tar operations
@@ -834,6 +925,8 @@ mod tests {
             command: Some(vec!["echo".to_string(), "hello".to_string()]),
             working_dir: Some("/app".to_string()),
             labels: HashMap::new(),
+            gpu_device_ids: Vec::new(),
+            network_disabled: false,
         };
 
         let bollard_config = provider.to_bollard_config(&config);
@@ -842,4 +935,38 @@ mod tests {
         assert!(bollard_config.env.is_some());
         assert!(bollard_config.host_config.is_some());
     }
+
+    #[test]
+    fn test_gpu_device_requests() {
+        assert!(gpu_device_requests(&[]).is_none());
+
+        let requests = gpu_device_requests(&["GPU-5f1c2e3a".to_string()]).unwrap();
+        assert_eq!(requests[0].driver.as_deref(), Some("nvidia"));
+        assert_eq!(
+            requests[0].device_ids,
+            Some(vec!["GPU-5f1c2e3a".to_string()])
+        );
+        assert_eq!(
+            requests[0].capabilities,
+            Some(vec![vec!["gpu".to_string()]])
+        );
+    }
+
+    #[test]
+    fn test_parse_nvidia_smi() {
+        let output =
+            "0, GPU-5f1c2e3a, Tesla T4, 15360\n1, GPU-9b8d7c6e, NVIDIA A100-SXM4-40GB, 40960\n\n";
+        let gpus = DockerProvider::parse_nvidia_smi(output);
+        assert_eq!(gpus.len(), 2);
+        assert_eq!(
+            gpus[1],
+            GpuDevice {
+                index: 1,
+                uuid: "GPU-9b8d7c6e".to_string(),
+                name: "NVIDIA A100-SXM4-40GB".to_string(),
+                memory_mb: Some(40960),
+            }
+        );
+        assert!(DockerProvider::parse_nvidia_smi("No devices were found").is_empty());
+    }
 }
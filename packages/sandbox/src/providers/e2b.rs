@@ -63,6 +63,7 @@ impl Provider for E2BProvider {
                 file_transfer: true,
                 metrics: false,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
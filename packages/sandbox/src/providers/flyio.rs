@@ -69,6 +69,7 @@ impl Provider for FlyioProvider {
                 file_transfer: false,
                 metrics: true,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
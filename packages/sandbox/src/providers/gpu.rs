@@ -0,0 +1,113 @@
+// ABOUTME: GPU devices a provider can pass through to containers
+// ABOUTME: Validates GPU requests against detected hardware and picks the devices to attach
+
+use serde::{Deserialize, Serialize};
+
+/// A GPU detected on the provider's host
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GpuDevice {
+    /// Index as reported by the driver
+    pub index: u32,
+    /// Stable device ID used for passthrough
+    pub uuid: String,
+    pub name: String,
+    pub memory_mb: Option<u64>,
+}
+
+/// Pick `count` GPUs for a container, optionally of a given model.
+///
+/// Models match case-insensitively against the device name, so `T4` matches
+/// `Tesla T4`. Returns the UUIDs of the chosen devices.
+pub fn select_gpus(
+    available: &[GpuDevice],
+    model: Option<&str>,
+    count: u32,
+) -> std::result::Result<Vec<String>, String> {
+    if count == 0 {
+        return Err("GPU count must be at least 1".to_string());
+    }
+    if available.is_empty() {
+        return Err(
+            "No GPUs detected. GPU passthrough needs an NVIDIA GPU and the NVIDIA Container Toolkit"
+                .to_string(),
+        );
+    }
+
+    let model = model.map(str::trim).filter(|model| !model.is_empty());
+    let matching: Vec<&GpuDevice> = match model {
+        Some(model) => {
+            let model = model.to_lowercase();
+            available
+                .iter()
+                .filter(|gpu| gpu.name.to_lowercase().contains(&model))
+                .collect()
+        }
+        None => available.iter().collect(),
+    };
+
+    let label = model.map(|m| format!("{} ", m)).unwrap_or_default();
+    if matching.is_empty() {
+        let names: Vec<&str> = available.iter().map(|gpu| gpu.name.as_str()).collect();
+        return Err(format!(
+            "No {}GPU detected. Available: {}",
+            label,
+            names.join(", ")
+        ));
+    }
+    if matching.len() < count as usize {
+        return Err(format!(
+            "Requested {} {}GPUs but only {} detected",
+            count,
+            label,
+            matching.len()
+        ));
+    }
+
+    Ok(matching
+        .into_iter()
+        .take(count as usize)
+        .map(|gpu| gpu.uuid.clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gpu(index: u32, name: &str) -> GpuDevice {
+        GpuDevice {
+            index,
+            uuid: format!("GPU-{}", index),
+            name: name.to_string(),
+            memory_mb: Some(16384),
+        }
+    }
+
+    #[test]
+    fn test_select_by_model() {
+        let gpus = vec![gpu(0, "Tesla T4"), gpu(1, "NVIDIA A100-SXM4-40GB")];
+        assert_eq!(
+            select_gpus(&gpus, Some("a100"), 1),
+            Ok(vec!["GPU-1".to_string()])
+        );
+        assert_eq!(
+            select_gpus(&gpus, None, 2),
+            Ok(vec!["GPU-0".to_string(), "GPU-1".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_rejects_unavailable_hardware() {
+        let gpus = vec![gpu(0, "Tesla T4")];
+        assert!(select_gpus(&[], None, 1).unwrap_err().contains("No GPUs"));
+        assert_eq!(
+            select_gpus(&gpus, Some("H100"), 1).unwrap_err(),
+            "No H100 GPU detected. Available: Tesla T4"
+        );
+        assert_eq!(
+            select_gpus(&gpus, Some("T4"), 2).unwrap_err(),
+            "Requested 2 T4 GPUs but only 1 detected"
+        );
+        assert!(select_gpus(&gpus, None, 0).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 // ABOUTME: Defines abstract interface for container/VM lifecycle management
 
 use async_trait::async_trait;
+use serde::Serialize;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -11,6 +12,7 @@ pub mod daytona;
 pub mod docker;
 pub mod e2b;
 pub mod flyio;
+pub mod gpu;
 pub mod modal;
 pub mod northflank;
 
@@ -20,6 +22,7 @@ pub use daytona::DaytonaProvider;
 pub use docker::DockerProvider;
 pub use e2b::E2BProvider;
 pub use flyio::FlyioProvider;
+pub use gpu::{select_gpus, GpuDevice};
 pub use modal::ModalProvider;
 pub use northflank::NorthflankProvider;
 
@@ -66,6 +69,10 @@ pub struct ContainerConfig {
     pub command: Option<Vec<String>>,
     pub working_dir: Option<String>,
     pub labels: HashMap<String, String>,
+    /// IDs of the GPUs to pass through; empty for none
+    pub gpu_device_ids: Vec<String>,
+    /// Run without any network access
+    pub network_disabled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -210,18 +217,25 @@ pub trait Provider: Send + Sync {
 
     /// Check if an image exists locally
     async fn image_exists(&self, image: &str) -> Result<bool>;
+
+    /// GPUs containers can request. Providers without GPU passthrough have none.
+    async fn detect_gpus(&self) -> Result<Vec<GpuDevice>> {
+        Ok(Vec::new())
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ProviderInfo {
     pub name: String,
     pub version: String,
     pub provider_type: String,
     pub capabilities: ProviderCapabilities,
+    /// GPUs available for passthrough
+    pub gpus: Vec<GpuDevice>,
     pub status: ProviderStatus,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ProviderCapabilities {
     pub gpu_support: bool,
     pub persistent_storage: bool,
@@ -232,7 +246,8 @@ pub struct ProviderCapabilities {
     pub metrics: bool,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "state", content = "reason", rename_all = "snake_case")]
 pub enum ProviderStatus {
     Ready,
     NotAvailable(String),
@@ -69,6 +69,7 @@ impl Provider for ModalProvider {
                 file_transfer: false,
                 metrics: false,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
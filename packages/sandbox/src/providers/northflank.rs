@@ -70,6 +70,7 @@ impl Provider for NorthflankProvider {
                 file_transfer: false,
                 metrics: true,
             },
+            gpus: Vec::new(),
             status: ProviderStatus::NotAvailable("Not yet implemented".to_string()),
         })
     }
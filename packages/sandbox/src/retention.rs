@@ -0,0 +1,785 @@
+// ABOUTME: Retention policies for sandbox execution logs with age and size caps
+// ABOUTME: Archives expired runs to compressed files before deleting them, and restores archived runs
+
+use crate::storage::{Result, SandboxExecution, SandboxStorage, StorageError};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Directory under the archive root holding archived executions
+pub const ARCHIVE_SUBDIR: &str = "sandbox-executions";
+
+const BYTES_PER_MB: i64 = 1024 * 1024;
+
+/// Caps on how long and how much execution log output is kept
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionPolicy {
+    pub id: String,
+    pub name: String,
+    /// Sandbox the policy applies to; with `project_id` unset too, it applies to all sandboxes
+    pub sandbox_id: Option<String>,
+    /// Project whose sandboxes the policy applies to
+    pub project_id: Option<String>,
+    /// Runs older than this are removed
+    pub max_age_days: Option<i64>,
+    /// Oldest runs are removed once their combined log output exceeds this
+    pub max_total_mb: Option<i64>,
+    /// Archive runs before removing them; otherwise they're deleted outright
+    pub archive: bool,
+    pub enabled: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl RetentionPolicy {
+    /// Sandbox policies win over project policies, which win over global ones
+    fn specificity(&self) -> u8 {
+        match (&self.sandbox_id, &self.project_id) {
+            (Some(_), _) => 2,
+            (None, Some(_)) => 1,
+            (None, None) => 0,
+        }
+    }
+
+    fn applies_to(&self, entry: &ExecutionLogEntry) -> bool {
+        match (&self.sandbox_id, &self.project_id) {
+            (Some(sandbox_id), _) => *sandbox_id == entry.sandbox_id,
+            (None, Some(project_id)) => entry.project_id.as_ref() == Some(project_id),
+            (None, None) => true,
+        }
+    }
+}
+
+/// Fields for creating or replacing a retention policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetentionPolicyInput {
+    pub name: String,
+    #[serde(default)]
+    pub sandbox_id: Option<String>,
+    #[serde(default)]
+    pub project_id: Option<String>,
+    #[serde(default)]
+    pub max_age_days: Option<i64>,
+    #[serde(default)]
+    pub max_total_mb: Option<i64>,
+    #[serde(default = "default_true")]
+    pub archive: bool,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl RetentionPolicyInput {
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(StorageError::InvalidPolicy("name is required".to_string()));
+        }
+        if self.sandbox_id.is_some() && self.project_id.is_some() {
+            return Err(StorageError::InvalidPolicy(
+                "a policy applies to a sandbox or a project, not both".to_string(),
+            ));
+        }
+        if self.max_age_days.is_some_and(|days| days < 1) {
+            return Err(StorageError::InvalidPolicy(
+                "max_age_days must be at least 1".to_string(),
+            ));
+        }
+        if self.max_total_mb.is_some_and(|mb| mb < 1) {
+            return Err(StorageError::InvalidPolicy(
+                "max_total_mb must be at least 1".to_string(),
+            ));
+        }
+        if self.max_age_days.is_none() && self.max_total_mb.is_none() {
+            return Err(StorageError::InvalidPolicy(
+                "set max_age_days, max_total_mb, or both".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A finished execution's log footprint, as seen by retention
+#[derive(Debug, Clone)]
+pub struct ExecutionLogEntry {
+    pub id: String,
+    pub sandbox_id: String,
+    pub project_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Size of stdout and stderr in bytes
+    pub log_bytes: i64,
+}
+
+/// The enabled policy an execution follows: the most specific one that applies
+pub fn governing_policy<'a>(
+    policies: &'a [RetentionPolicy],
+    entry: &ExecutionLogEntry,
+) -> Option<&'a RetentionPolicy> {
+    policies
+        .iter()
+        .filter(|policy| policy.enabled && policy.applies_to(entry))
+        .max_by_key(|policy| policy.specificity())
+}
+
+/// Executions a policy removes, oldest first.
+///
+/// `entries` are the executions the policy governs. Newest runs are kept until
+/// the size cap is reached; anything past it, or older than the age cap, expires.
+pub fn expired_executions<'a>(
+    policy: &RetentionPolicy,
+    entries: &[&'a ExecutionLogEntry],
+    now: DateTime<Utc>,
+) -> Vec<&'a ExecutionLogEntry> {
+    let mut newest_first = entries.to_vec();
+    newest_first.sort_by_key(|entry| std::cmp::Reverse(entry.created_at));
+
+    let cutoff = policy.max_age_days.map(|days| now - Duration::days(days));
+    let max_bytes = policy.max_total_mb.map(|mb| mb * BYTES_PER_MB);
+    let mut kept_bytes = 0;
+
+    let mut expired: Vec<&ExecutionLogEntry> = newest_first
+        .into_iter()
+        .filter(|entry| {
+            let too_old = cutoff.is_some_and(|cutoff| entry.created_at < cutoff);
+            let over_size = max_bytes.is_some_and(|max| kept_bytes + entry.log_bytes > max);
+            if too_old || over_size {
+                return true;
+            }
+            kept_bytes += entry.log_bytes;
+            false
+        })
+        .collect();
+    expired.reverse();
+    expired
+}
+
+/// An execution archived by a retention policy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionArchive {
+    pub execution_id: String,
+    pub sandbox_id: String,
+    pub policy_id: Option<String>,
+    pub archive_path: String,
+    pub original_bytes: i64,
+    pub compressed_bytes: i64,
+    pub execution_created_at: String,
+    pub archived_at: String,
+    pub restored_at: Option<String>,
+}
+
+/// Disk used by the executions a policy governs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyUsage {
+    pub policy_id: String,
+    pub executions: usize,
+    pub log_bytes: i64,
+    /// Runs the next retention pass will remove
+    pub expired_executions: usize,
+    pub expired_bytes: i64,
+    pub archived_executions: i64,
+    /// Size of the policy's compressed archives on disk
+    pub archive_bytes: i64,
+}
+
+/// Outcome of a retention pass
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RetentionReport {
+    pub archived: usize,
+    pub deleted: usize,
+    /// Log bytes removed from the database
+    pub freed_bytes: i64,
+}
+
+/// Applies retention policies to sandbox execution logs
+pub struct LogRetention {
+    pool: SqlitePool,
+    storage: SandboxStorage,
+    archive_dir: PathBuf,
+}
+
+impl LogRetention {
+    /// `archive_root` is normally `~/.orkee/archives`
+    pub fn new(pool: SqlitePool, archive_root: impl AsRef<Path>) -> Self {
+        Self {
+            storage: SandboxStorage::new(pool.clone()),
+            archive_dir: archive_root.as_ref().join(ARCHIVE_SUBDIR),
+            pool,
+        }
+    }
+
+    pub async fn list_policies(&self) -> Result<Vec<RetentionPolicy>> {
+        let rows = sqlx::query(
+            "SELECT * FROM sandbox_retention_policies ORDER BY sandbox_id, project_id, name",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(row_to_policy).collect()
+    }
+
+    pub async fn get_policy(&self, id: &str) -> Result<RetentionPolicy> {
+        let row = sqlx::query("SELECT * FROM sandbox_retention_policies WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::PolicyNotFound(id.to_string()))?;
+        row_to_policy(&row)
+    }
+
+    pub async fn create_policy(&self, input: RetentionPolicyInput) -> Result<RetentionPolicy> {
+        input.validate()?;
+        let id = format!(
+            "policy_{}",
+            uuid::Uuid::new_v4().to_string().replace("-", "")
+        );
+
+        sqlx::query(
+            "INSERT INTO sandbox_retention_policies
+                (id, name, sandbox_id, project_id, max_age_days, max_total_mb, archive, enabled)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(input.name.trim())
+        .bind(&input.sandbox_id)
+        .bind(&input.project_id)
+        .bind(input.max_age_days)
+        .bind(input.max_total_mb)
+        .bind(input.archive)
+        .bind(input.enabled)
+        .execute(&self.pool)
+        .await
+        .map_err(duplicate_scope)?;
+
+        self.get_policy(&id).await
+    }
+
+    pub async fn update_policy(
+        &self,
+        id: &str,
+        input: RetentionPolicyInput,
+    ) -> Result<RetentionPolicy> {
+        input.validate()?;
+
+        let result = sqlx::query(
+            "UPDATE sandbox_retention_policies
+             SET name = ?, sandbox_id = ?, project_id = ?, max_age_days = ?, max_total_mb = ?,
+                 archive = ?, enabled = ?, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?",
+        )
+        .bind(input.name.trim())
+        .bind(&input.sandbox_id)
+        .bind(&input.project_id)
+        .bind(input.max_age_days)
+        .bind(input.max_total_mb)
+        .bind(input.archive)
+        .bind(input.enabled)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(duplicate_scope)?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::PolicyNotFound(id.to_string()));
+        }
+
+        self.get_policy(id).await
+    }
+
+    /// Delete a policy. Its archives stay restorable.
+    pub async fn delete_policy(&self, id: &str) -> Result<()> {
+        let result = sqlx::query("DELETE FROM sandbox_retention_policies WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(StorageError::PolicyNotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Finished executions retention can remove. Running and queued
+    /// executions and restored runs are left alone.
+    async fn log_entries(&self) -> Result<Vec<ExecutionLogEntry>> {
+        let rows = sqlx::query(
+            "SELECT e.id, e.sandbox_id, s.project_id, e.created_at,
+                    COALESCE(length(CAST(e.stdout AS BLOB)), 0)
+                        + COALESCE(length(CAST(e.stderr AS BLOB)), 0) AS log_bytes
+             FROM sandbox_executions e
+             JOIN sandboxes s ON s.id = e.sandbox_id
+             WHERE e.status IN ('completed', 'failed', 'cancelled')
+               AND NOT EXISTS (
+                   SELECT 1 FROM sandbox_execution_archives a WHERE a.execution_id = e.id
+               )",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| -> Result<ExecutionLogEntry> {
+                let created_at: Option<String> = row.try_get("created_at")?;
+                Ok(ExecutionLogEntry {
+                    id: row.try_get("id")?,
+                    sandbox_id: row.try_get("sandbox_id")?,
+                    project_id: row.try_get("project_id")?,
+                    // Runs without a readable timestamp are treated as new and kept
+                    created_at: created_at
+                        .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .unwrap_or_else(Utc::now),
+                    log_bytes: row.try_get("log_bytes")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Executions each enabled policy would remove right now
+    async fn plan(&self) -> Result<Vec<(RetentionPolicy, Vec<ExecutionLogEntry>)>> {
+        let policies = self.list_policies().await?;
+        let entries = self.log_entries().await?;
+        let now = Utc::now();
+
+        Ok(policies
+            .iter()
+            .filter(|policy| policy.enabled)
+            .map(|policy| {
+                let governed: Vec<&ExecutionLogEntry> = entries
+                    .iter()
+                    .filter(|entry| {
+                        governing_policy(&policies, entry).is_some_and(|p| p.id == policy.id)
+                    })
+                    .collect();
+                let expired = expired_executions(policy, &governed, now)
+                    .into_iter()
+                    .cloned()
+                    .collect();
+                (policy.clone(), expired)
+            })
+            .collect())
+    }
+
+    /// Apply every enabled policy, archiving or deleting expired runs
+    pub async fn enforce(&self) -> Result<RetentionReport> {
+        let mut report = RetentionReport::default();
+
+        for (policy, expired) in self.plan().await? {
+            for entry in expired {
+                let removed = if policy.archive {
+                    self.archive_execution(&policy, &entry.id).await
+                } else {
+                    self.delete_execution(&entry.id).await
+                };
+                match removed {
+                    Ok(()) => {
+                        if policy.archive {
+                            report.archived += 1;
+                        } else {
+                            report.deleted += 1;
+                        }
+                        report.freed_bytes += entry.log_bytes;
+                    }
+                    // Keep going so one unreadable run doesn't stall retention
+                    Err(e) => warn!("Failed to apply retention to execution {}: {}", entry.id, e),
+                }
+            }
+        }
+
+        if report.archived + report.deleted > 0 {
+            info!(
+                "Sandbox log retention archived {} and deleted {} executions ({} bytes)",
+                report.archived, report.deleted, report.freed_bytes
+            );
+        }
+        Ok(report)
+    }
+
+    fn archive_path(&self, sandbox_id: &str, execution_id: &str) -> PathBuf {
+        self.archive_dir
+            .join(sanitize(sandbox_id))
+            .join(format!("{}.json.gz", sanitize(execution_id)))
+    }
+
+    async fn archive_execution(&self, policy: &RetentionPolicy, execution_id: &str) -> Result<()> {
+        let execution = self.storage.get_execution(execution_id).await?;
+        let json = serde_json::to_vec(&execution)?;
+        let compressed = orkee_storage::compress_data(&json)
+            .map_err(|e| StorageError::Archive(e.to_string()))?;
+
+        let path = self.archive_path(&execution.sandbox_id, &execution.id);
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| StorageError::Archive(e.to_string()))?;
+        }
+        tokio::fs::write(&path, &compressed)
+            .await
+            .map_err(|e| StorageError::Archive(e.to_string()))?;
+
+        // The file is written first so a failure never loses the only copy
+        let mut tx = self.pool.begin().await?;
+        sqlx::query(
+            "INSERT INTO sandbox_execution_archives
+                (execution_id, sandbox_id, policy_id, archive_path, original_bytes,
+                 compressed_bytes, execution_created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&execution.id)
+        .bind(&execution.sandbox_id)
+        .bind(&policy.id)
+        .bind(path.to_string_lossy().to_string())
+        .bind(json.len() as i64)
+        .bind(compressed.len() as i64)
+        .bind(execution.created_at.to_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+        sqlx::query("DELETE FROM sandbox_executions WHERE id = ?")
+            .bind(&execution.id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_execution(&self, execution_id: &str) -> Result<()> {
+        sqlx::query("DELETE FROM sandbox_executions WHERE id = ?")
+            .bind(execution_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Archived executions, newest archive first
+    pub async fn list_archives(&self, sandbox_id: Option<&str>) -> Result<Vec<ExecutionArchive>> {
+        let rows = sqlx::query(
+            "SELECT * FROM sandbox_execution_archives
+             WHERE ?1 IS NULL OR sandbox_id = ?1
+             ORDER BY archived_at DESC",
+        )
+        .bind(sandbox_id)
+        .fetch_all(&self.pool)
+        .await?;
+        rows.iter().map(row_to_archive).collect()
+    }
+
+    /// Put an archived execution back in its sandbox's history.
+    ///
+    /// The archive is kept, and the restored run is exempt from retention so
+    /// the next pass doesn't archive it again.
+    pub async fn restore(&self, execution_id: &str) -> Result<SandboxExecution> {
+        let row = sqlx::query("SELECT * FROM sandbox_execution_archives WHERE execution_id = ?")
+            .bind(execution_id)
+            .fetch_optional(&self.pool)
+            .await?
+            .ok_or_else(|| StorageError::ArchiveNotFound(execution_id.to_string()))?;
+        let archive = row_to_archive(&row)?;
+        if archive.restored_at.is_some() {
+            return Err(StorageError::InvalidStatus(format!(
+                "execution {} is already restored",
+                execution_id
+            )));
+        }
+
+        // The sandbox may have been deleted since; its archives outlive it
+        if self.storage.get_sandbox(&archive.sandbox_id).await.is_err() {
+            return Err(StorageError::NotFound(archive.sandbox_id));
+        }
+
+        let compressed = tokio::fs::read(&archive.archive_path)
+            .await
+            .map_err(|e| StorageError::Archive(format!("{}: {}", archive.archive_path, e)))?;
+        let json = orkee_storage::decompress_data(&compressed)
+            .map_err(|e| StorageError::Archive(e.to_string()))?;
+        let execution: SandboxExecution = serde_json::from_slice(&json)?;
+
+        let execution = self.storage.create_execution(execution).await?;
+        sqlx::query(
+            "UPDATE sandbox_execution_archives
+             SET restored_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE execution_id = ?",
+        )
+        .bind(execution_id)
+        .execute(&self.pool)
+        .await?;
+
+        info!("Restored archived sandbox execution {}", execution_id);
+        Ok(execution)
+    }
+
+    /// Disk used by the executions a policy governs, and what it would remove now
+    pub async fn usage(&self, policy_id: &str) -> Result<PolicyUsage> {
+        let policies = self.list_policies().await?;
+        let policy = policies
+            .iter()
+            .find(|policy| policy.id == policy_id)
+            .ok_or_else(|| StorageError::PolicyNotFound(policy_id.to_string()))?;
+
+        let entries = self.log_entries().await?;
+        let governed: Vec<&ExecutionLogEntry> = entries
+            .iter()
+            .filter(|entry| governing_policy(&policies, entry).is_some_and(|p| p.id == policy.id))
+            .collect();
+        let expired = if policy.enabled {
+            expired_executions(policy, &governed, Utc::now())
+        } else {
+            Vec::new()
+        };
+
+        let archives = sqlx::query(
+            "SELECT COUNT(*) AS count, COALESCE(SUM(compressed_bytes), 0) AS bytes
+             FROM sandbox_execution_archives
+             WHERE policy_id = ?",
+        )
+        .bind(policy_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(PolicyUsage {
+            policy_id: policy.id.clone(),
+            executions: governed.len(),
+            log_bytes: governed.iter().map(|entry| entry.log_bytes).sum(),
+            expired_executions: expired.len(),
+            expired_bytes: expired.iter().map(|entry| entry.log_bytes).sum(),
+            archived_executions: archives.try_get("count")?,
+            archive_bytes: archives.try_get("bytes")?,
+        })
+    }
+}
+
+/// Keep IDs from escaping the archive directory
+fn sanitize(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn duplicate_scope(error: sqlx::Error) -> StorageError {
+    match &error {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            StorageError::InvalidPolicy("another policy already applies to this scope".to_string())
+        }
+        _ => StorageError::Database(error),
+    }
+}
+
+fn row_to_policy(row: &sqlx::sqlite::SqliteRow) -> Result<RetentionPolicy> {
+    Ok(RetentionPolicy {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        sandbox_id: row.try_get("sandbox_id")?,
+        project_id: row.try_get("project_id")?,
+        max_age_days: row.try_get("max_age_days")?,
+        max_total_mb: row.try_get("max_total_mb")?,
+        archive: row.try_get("archive")?,
+        enabled: row.try_get("enabled")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn row_to_archive(row: &sqlx::sqlite::SqliteRow) -> Result<ExecutionArchive> {
+    Ok(ExecutionArchive {
+        execution_id: row.try_get("execution_id")?,
+        sandbox_id: row.try_get("sandbox_id")?,
+        policy_id: row.try_get("policy_id")?,
+        archive_path: row.try_get("archive_path")?,
+        original_bytes: row.try_get("original_bytes")?,
+        compressed_bytes: row.try_get("compressed_bytes")?,
+        execution_created_at: row.try_get("execution_created_at")?,
+        archived_at: row.try_get("archived_at")?,
+        restored_at: row.try_get("restored_at")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::ExecutionStatus;
+
+    fn policy(id: &str, sandbox_id: Option<&str>, project_id: Option<&str>) -> RetentionPolicy {
+        RetentionPolicy {
+            id: id.to_string(),
+            name: id.to_string(),
+            sandbox_id: sandbox_id.map(str::to_string),
+            project_id: project_id.map(str::to_string),
+            max_age_days: Some(7),
+            max_total_mb: None,
+            archive: true,
+            enabled: true,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn entry(id: &str, days_old: i64, log_bytes: i64) -> ExecutionLogEntry {
+        ExecutionLogEntry {
+            id: id.to_string(),
+            sandbox_id: "sb1".to_string(),
+            project_id: Some("proj1".to_string()),
+            created_at: Utc::now() - Duration::days(days_old),
+            log_bytes,
+        }
+    }
+
+    fn ids(entries: &[&ExecutionLogEntry]) -> Vec<String> {
+        entries.iter().map(|entry| entry.id.clone()).collect()
+    }
+
+    #[test]
+    fn test_most_specific_enabled_policy_governs() {
+        let mut policies = vec![
+            policy("global", None, None),
+            policy("project", None, Some("proj1")),
+            policy("sandbox", Some("sb1"), None),
+        ];
+        let run = entry("e1", 0, 0);
+        assert_eq!(governing_policy(&policies, &run).unwrap().id, "sandbox");
+
+        policies[2].enabled = false;
+        assert_eq!(governing_policy(&policies, &run).unwrap().id, "project");
+
+        let other_sandbox = ExecutionLogEntry {
+            sandbox_id: "sb2".to_string(),
+            project_id: None,
+            ..run
+        };
+        assert_eq!(
+            governing_policy(&policies, &other_sandbox).unwrap().id,
+            "global"
+        );
+    }
+
+    #[test]
+    fn test_age_cap() {
+        let entries = [entry("old", 10, 100), entry("new", 1, 100)];
+        let refs: Vec<&ExecutionLogEntry> = entries.iter().collect();
+        let expired = expired_executions(&policy("p", None, None), &refs, Utc::now());
+        assert_eq!(ids(&expired), vec!["old"]);
+    }
+
+    #[test]
+    fn test_size_cap_removes_oldest_first() {
+        let mut capped = policy("p", None, None);
+        capped.max_age_days = None;
+        capped.max_total_mb = Some(1);
+
+        let entries = [
+            entry("a", 3, 400 * 1024),
+            entry("b", 2, 400 * 1024),
+            entry("c", 1, 400 * 1024),
+            entry("d", 0, 100 * 1024),
+        ];
+        let refs: Vec<&ExecutionLogEntry> = entries.iter().collect();
+        let expired = expired_executions(&capped, &refs, Utc::now());
+        // d, c and b fit in 1 MB; a doesn't
+        assert_eq!(ids(&expired), vec!["a"]);
+    }
+
+    #[test]
+    fn test_input_validation() {
+        let input = RetentionPolicyInput {
+            name: "Scratch".to_string(),
+            sandbox_id: None,
+            project_id: None,
+            max_age_days: None,
+            max_total_mb: None,
+            archive: true,
+            enabled: true,
+        };
+        assert!(input.validate().is_err());
+        assert!(RetentionPolicyInput {
+            max_age_days: Some(3),
+            ..input.clone()
+        }
+        .validate()
+        .is_ok());
+        assert!(RetentionPolicyInput {
+            max_age_days: Some(3),
+            sandbox_id: Some("sb1".to_string()),
+            project_id: Some("proj1".to_string()),
+            ..input
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_archive_and_restore() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO sandboxes (id, name, provider, agent_id, status, user_id)
+             VALUES ('sb1', 'Test', 'local', 'claude-code', 'running', 'default-user')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let storage = SandboxStorage::new(pool.clone());
+        for (id, days_old) in [("exec_old", 30), ("exec_new", 1)] {
+            storage
+                .create_execution(SandboxExecution {
+                    id: id.to_string(),
+                    sandbox_id: "sb1".to_string(),
+                    command: "npm test".to_string(),
+                    working_directory: "/".to_string(),
+                    status: ExecutionStatus::Completed,
+                    started_at: None,
+                    completed_at: None,
+                    exit_code: Some(0),
+                    stdout: Some("ok\n".repeat(100)),
+                    stderr: None,
+                    cpu_time_seconds: None,
+                    memory_peak_mb: None,
+                    created_at: Utc::now() - Duration::days(days_old),
+                    created_by: None,
+                    agent_execution_id: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let archive_root = tempfile::tempdir().unwrap();
+        let retention = LogRetention::new(pool.clone(), archive_root.path());
+
+        // The seeded default policy keeps 7 days
+        let usage = retention.usage("default-retention").await.unwrap();
+        assert_eq!(usage.executions, 2);
+        assert_eq!(usage.expired_executions, 1);
+        assert_eq!(usage.expired_bytes, 300);
+
+        let report = retention.enforce().await.unwrap();
+        assert_eq!(report.archived, 1);
+        assert_eq!(report.freed_bytes, 300);
+        assert!(storage.get_execution("exec_old").await.is_err());
+
+        let archives = retention.list_archives(Some("sb1")).await.unwrap();
+        assert_eq!(archives.len(), 1);
+        assert!(Path::new(&archives[0].archive_path).exists());
+        assert!(archives[0].archive_path.ends_with("sb1/exec_old.json.gz"));
+
+        let restored = retention.restore("exec_old").await.unwrap();
+        assert_eq!(
+            restored.stdout.as_deref(),
+            Some("ok\n".repeat(100).as_str())
+        );
+        assert!(storage.get_execution("exec_old").await.is_ok());
+
+        // Restored runs aren't archived again
+        assert_eq!(
+            retention.enforce().await.unwrap(),
+            RetentionReport::default()
+        );
+        assert!(retention.restore("exec_old").await.is_err());
+    }
+}
@@ -424,6 +424,31 @@ impl SettingsManager {
         Ok(())
     }
 
+    /// Record the outcome of a connection test. `error` is `None` when the test passed.
+    /// Providers without saved settings have nothing to record.
+    pub async fn record_provider_validation(
+        &self,
+        provider: &str,
+        error: Option<&str>,
+    ) -> Result<(), StorageError> {
+        debug!("Recording validation result for provider: {}", provider);
+
+        sqlx::query(
+            "UPDATE sandbox_provider_settings
+             SET validated_at = CASE WHEN ? IS NULL THEN datetime('now', 'utc') ELSE validated_at END,
+                 validation_error = ?
+             WHERE provider = ?",
+        )
+        .bind(error)
+        .bind(error)
+        .bind(provider)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(())
+    }
+
     fn row_to_sandbox_settings(
         &self,
         row: &sqlx::sqlite::SqliteRow,
@@ -1,10 +1,11 @@
-// ABOUTME: Storage layer for sandboxes, executions, environment variables and volumes
+// ABOUTME: Storage layer for sandboxes, executions, environment variables, volumes and templates
 // ABOUTME: Provides CRUD operations for sandbox data in SQLite database
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,6 +18,18 @@ pub enum StorageError {
     InvalidStatus(String),
     #[error("JSON serialization error: {0}")]
     Json(#[from] serde_json::Error),
+    #[error("Template not found: {0}")]
+    TemplateNotFound(String),
+    #[error("Invalid template: {0}")]
+    InvalidTemplate(String),
+    #[error("Retention policy not found: {0}")]
+    PolicyNotFound(String),
+    #[error("Invalid retention policy: {0}")]
+    InvalidPolicy(String),
+    #[error("Archived execution not found: {0}")]
+    ArchiveNotFound(String),
+    #[error("Archive error: {0}")]
+    Archive(String),
 }
 
 pub type Result<T> = std::result::Result<T, StorageError>;
@@ -179,6 +192,88 @@ pub struct Volume {
     pub created_at: DateTime<Utc>,
 }
 
+/// Named volume mounted into every sandbox created from a template, so package
+/// caches persist between sandboxes
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CacheVolume {
+    pub name: String,
+    pub container_path: String,
+}
+
+impl CacheVolume {
+    /// Name of the provider volume backing this cache
+    pub fn volume_name(&self) -> String {
+        format!("orkee-cache-{}", self.name)
+    }
+}
+
+/// Reusable execution environment: image, environment, cache volumes and setup
+/// commands applied when a sandbox is created from it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SandboxTemplate {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub image: String,
+    #[serde(default)]
+    pub env_vars: HashMap<String, String>,
+    #[serde(default)]
+    pub cache_volumes: Vec<CacheVolume>,
+    #[serde(default)]
+    pub setup_commands: Vec<String>,
+    #[serde(default)]
+    pub is_builtin: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SandboxTemplate {
+    /// Check the template is usable before it is stored
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: String| Err(StorageError::InvalidTemplate(message));
+
+        if self.name.trim().is_empty() {
+            return invalid("name is required".to_string());
+        }
+        if self.image.trim().is_empty() {
+            return invalid("image is required".to_string());
+        }
+        for name in self.env_vars.keys() {
+            if name.is_empty() || name.contains('=') {
+                return invalid(format!("invalid environment variable name '{}'", name));
+            }
+        }
+        for volume in &self.cache_volumes {
+            let valid_name = volume
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                && volume
+                    .name
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_alphanumeric());
+            if !valid_name {
+                return invalid(format!(
+                    "cache volume name '{}' must start with a letter or digit and contain only letters, digits, '-', '_' or '.'",
+                    volume.name
+                ));
+            }
+            if !volume.container_path.starts_with('/') {
+                return invalid(format!(
+                    "cache volume path '{}' must be absolute",
+                    volume.container_path
+                ));
+            }
+        }
+        if self.setup_commands.iter().any(|c| c.trim().is_empty()) {
+            return invalid("setup commands cannot be empty".to_string());
+        }
+
+        Ok(())
+    }
+}
+
 pub struct SandboxStorage {
     pool: SqlitePool,
 }
@@ -188,6 +283,10 @@ impl SandboxStorage {
         Self { pool }
     }
 
+    pub(crate) fn pool(&self) -> &SqlitePool {
+        &self.pool
+    }
+
     // ========================================================================
     // SANDBOX OPERATIONS
     // ========================================================================
@@ -538,12 +637,10 @@ impl SandboxStorage {
 
         // Create environment variables
         for env_var in env_vars.iter() {
-            let env_id = uuid::Uuid::new_v4().to_string();
             sqlx::query(
-                "INSERT INTO sandbox_env_vars (id, sandbox_id, name, value, is_secret, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "INSERT INTO sandbox_env_vars (sandbox_id, name, value, is_secret, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
             )
-            .bind(&env_id)
             .bind(&sandbox_id)
             .bind(&env_var.name)
             .bind(&env_var.value)
@@ -555,12 +652,10 @@ impl SandboxStorage {
 
         // Create volumes
         for volume in volumes.iter() {
-            let vol_id = uuid::Uuid::new_v4().to_string();
             sqlx::query(
-                "INSERT INTO sandbox_volumes (id, sandbox_id, host_path, container_path, read_only, created_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                "INSERT INTO sandbox_volumes (sandbox_id, host_path, container_path, read_only, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
             )
-            .bind(&vol_id)
             .bind(&sandbox_id)
             .bind(&volume.host_path)
             .bind(&volume.container_path)
@@ -669,6 +764,51 @@ impl SandboxStorage {
             .collect()
     }
 
+    /// Commands run on behalf of an agent execution, oldest first
+    pub async fn list_executions_for_agent(
+        &self,
+        agent_execution_id: &str,
+    ) -> Result<Vec<SandboxExecution>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, sandbox_id, command, working_directory, status,
+                   started_at, completed_at, exit_code, stdout, stderr,
+                   cpu_time_seconds, memory_peak_mb,
+                   created_at, created_by, agent_execution_id
+            FROM sandbox_executions
+            WHERE agent_execution_id = ?1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(agent_execution_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_execution(row))
+            .collect()
+    }
+
+    /// Mark an agent execution's queued and running commands as cancelled, keeping their output
+    pub async fn cancel_executions_for_agent(&self, agent_execution_id: &str) -> Result<u64> {
+        let result = sqlx::query(
+            r#"
+            UPDATE sandbox_executions
+            SET status = ?1, completed_at = ?2
+            WHERE agent_execution_id = ?3 AND status IN (?4, ?5)
+            "#,
+        )
+        .bind(ExecutionStatus::Cancelled.as_str())
+        .bind(Utc::now().to_rfc3339())
+        .bind(agent_execution_id)
+        .bind(ExecutionStatus::Queued.as_str())
+        .bind(ExecutionStatus::Running.as_str())
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn update_execution_status(
         &self,
         id: &str,
@@ -816,6 +956,119 @@ impl SandboxStorage {
         Ok(())
     }
 
+    // ========================================================================
+    // TEMPLATE OPERATIONS
+    // ========================================================================
+
+    pub async fn create_template(&self, mut template: SandboxTemplate) -> Result<SandboxTemplate> {
+        template.validate()?;
+
+        // Generate ID if not provided
+        if template.id.is_empty() {
+            template.id = format!("tpl_{}", uuid::Uuid::new_v4().to_string().replace("-", ""));
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO sandbox_templates (
+                id, name, description, image, env_vars, cache_volumes, setup_commands, is_builtin
+            ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, FALSE)
+            "#,
+        )
+        .bind(&template.id)
+        .bind(&template.name)
+        .bind(&template.description)
+        .bind(&template.image)
+        .bind(serde_json::to_string(&template.env_vars)?)
+        .bind(serde_json::to_string(&template.cache_volumes)?)
+        .bind(serde_json::to_string(&template.setup_commands)?)
+        .execute(&self.pool)
+        .await?;
+
+        self.get_template(&template.id).await
+    }
+
+    pub async fn get_template(&self, id: &str) -> Result<SandboxTemplate> {
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, description, image, env_vars, cache_volumes, setup_commands,
+                   is_builtin, created_at, updated_at
+            FROM sandbox_templates
+            WHERE id = ?1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or_else(|| StorageError::TemplateNotFound(id.to_string()))?;
+
+        self.row_to_template(row)
+    }
+
+    pub async fn list_templates(&self) -> Result<Vec<SandboxTemplate>> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, description, image, env_vars, cache_volumes, setup_commands,
+                   is_builtin, created_at, updated_at
+            FROM sandbox_templates
+            ORDER BY is_builtin DESC, name
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_template(row))
+            .collect()
+    }
+
+    pub async fn update_template(&self, template: SandboxTemplate) -> Result<SandboxTemplate> {
+        template.validate()?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE sandbox_templates
+            SET name = ?1, description = ?2, image = ?3, env_vars = ?4,
+                cache_volumes = ?5, setup_commands = ?6
+            WHERE id = ?7
+            "#,
+        )
+        .bind(&template.name)
+        .bind(&template.description)
+        .bind(&template.image)
+        .bind(serde_json::to_string(&template.env_vars)?)
+        .bind(serde_json::to_string(&template.cache_volumes)?)
+        .bind(serde_json::to_string(&template.setup_commands)?)
+        .bind(&template.id)
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(StorageError::TemplateNotFound(template.id));
+        }
+
+        self.get_template(&template.id).await
+    }
+
+    /// Delete a user-created template. Built-in templates are kept so they
+    /// are always available to select.
+    pub async fn delete_template(&self, id: &str) -> Result<()> {
+        let template = self.get_template(id).await?;
+        if template.is_builtin {
+            return Err(StorageError::InvalidTemplate(format!(
+                "built-in template '{}' cannot be deleted",
+                template.name
+            )));
+        }
+
+        sqlx::query("DELETE FROM sandbox_templates WHERE id = ?1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
     // ========================================================================
     // HELPER METHODS
     // ========================================================================
@@ -914,6 +1167,27 @@ impl SandboxStorage {
         })
     }
 
+    fn row_to_template(&self, row: sqlx::sqlite::SqliteRow) -> Result<SandboxTemplate> {
+        use sqlx::Row;
+
+        Ok(SandboxTemplate {
+            id: row.get("id"),
+            name: row.get("name"),
+            description: row.get("description"),
+            image: row.get("image"),
+            env_vars: serde_json::from_str(&row.get::<String, _>("env_vars"))?,
+            cache_volumes: serde_json::from_str(&row.get::<String, _>("cache_volumes"))?,
+            setup_commands: serde_json::from_str(&row.get::<String, _>("setup_commands"))?,
+            is_builtin: row.get("is_builtin"),
+            created_at: DateTime::parse_from_rfc3339(&row.get::<String, _>("created_at"))
+                .unwrap()
+                .with_timezone(&Utc),
+            updated_at: DateTime::parse_from_rfc3339(&row.get::<String, _>("updated_at"))
+                .unwrap()
+                .with_timezone(&Utc),
+        })
+    }
+
     fn row_to_volume(&self, row: sqlx::sqlite::SqliteRow) -> Result<Volume> {
         use sqlx::Row;
 
@@ -962,6 +1236,12 @@ mod tests {
         .await
         .expect("Failed to create test user");
 
+        let templates_sql = include_str!("../../storage/migrations/019_sandbox_templates.sql");
+        sqlx::query(templates_sql)
+            .execute(&pool)
+            .await
+            .expect("Failed to run template migration");
+
         // Make sure local provider exists
         sqlx::query(
             "INSERT OR REPLACE INTO sandbox_provider_settings (provider, enabled, updated_at)
@@ -1143,5 +1423,136 @@ mod tests {
         assert_eq!(updated.status, ExecutionStatus::Completed);
         assert_eq!(updated.exit_code, Some(0));
         assert_eq!(updated.stdout, Some("Hello World".to_string()));
+
+        // Commands run for an agent execution are cancelled with it, keeping their output
+        let agent_exec = storage
+            .create_execution(SandboxExecution {
+                id: String::new(),
+                command: "cargo test".to_string(),
+                agent_execution_id: Some("exec-agent".to_string()),
+                ..created_exec.clone()
+            })
+            .await
+            .unwrap();
+        storage
+            .update_execution_status(
+                &agent_exec.id,
+                ExecutionStatus::Running,
+                None,
+                Some("running 3 tests".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            storage
+                .cancel_executions_for_agent("exec-agent")
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            storage
+                .cancel_executions_for_agent("exec-agent")
+                .await
+                .unwrap(),
+            0
+        );
+
+        let runs = storage
+            .list_executions_for_agent("exec-agent")
+            .await
+            .unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].status, ExecutionStatus::Cancelled);
+        assert_eq!(runs[0].stdout, Some("running 3 tests".to_string()));
+        assert!(runs[0].completed_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_builtin_templates_are_seeded() {
+        let pool = setup_test_db().await;
+        let storage = SandboxStorage::new(pool);
+
+        let templates = storage.list_templates().await.unwrap();
+        let ids: Vec<&str> = templates.iter().map(|t| t.id.as_str()).collect();
+        assert!(ids.contains(&"rust-toolchain"));
+        assert!(ids.contains(&"node20-pnpm"));
+        assert!(ids.contains(&"python312-uv"));
+
+        let rust = storage.get_template("rust-toolchain").await.unwrap();
+        assert!(rust.is_builtin);
+        assert_eq!(
+            rust.cache_volumes[0].volume_name(),
+            "orkee-cache-cargo-registry"
+        );
+        assert!(storage.delete_template("rust-toolchain").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_template_crud() {
+        let pool = setup_test_db().await;
+        let storage = SandboxStorage::new(pool);
+
+        let template = SandboxTemplate {
+            id: String::new(),
+            name: "Go toolchain".to_string(),
+            description: None,
+            image: "golang:1.23".to_string(),
+            env_vars: HashMap::from([("GOFLAGS".to_string(), "-mod=mod".to_string())]),
+            cache_volumes: vec![CacheVolume {
+                name: "go-mod".to_string(),
+                container_path: "/go/pkg/mod".to_string(),
+            }],
+            setup_commands: vec!["go version".to_string()],
+            is_builtin: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        let created = storage.create_template(template).await.unwrap();
+        assert!(created.id.starts_with("tpl_"));
+        // Only the migration can create built-in templates
+        assert!(!created.is_builtin);
+        assert_eq!(created.env_vars["GOFLAGS"], "-mod=mod");
+
+        let mut changed = created.clone();
+        changed.setup_commands.push("go env".to_string());
+        let updated = storage.update_template(changed).await.unwrap();
+        assert_eq!(updated.setup_commands.len(), 2);
+
+        storage.delete_template(&created.id).await.unwrap();
+        assert!(matches!(
+            storage.get_template(&created.id).await,
+            Err(StorageError::TemplateNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_template_is_rejected() {
+        let pool = setup_test_db().await;
+        let storage = SandboxStorage::new(pool);
+
+        let template = SandboxTemplate {
+            id: String::new(),
+            name: "Bad cache".to_string(),
+            description: None,
+            image: "alpine".to_string(),
+            env_vars: HashMap::new(),
+            cache_volumes: vec![CacheVolume {
+                name: "../escape".to_string(),
+                container_path: "/cache".to_string(),
+            }],
+            setup_commands: Vec::new(),
+            is_builtin: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+
+        assert!(matches!(
+            storage.create_template(template).await,
+            Err(StorageError::InvalidTemplate(_))
+        ));
     }
 }
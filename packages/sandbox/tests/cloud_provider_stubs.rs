@@ -38,6 +38,8 @@ async fn test_beam_provider_stub_returns_not_supported() {
         memory_mb: 512,
         storage_gb: 1,
         labels: Default::default(),
+        gpu_device_ids: Vec::new(),
+        network_disabled: false,
     };
 
     let result = provider.create_container(&config).await;
@@ -120,6 +122,8 @@ async fn test_e2b_provider_stub_returns_not_supported() {
         memory_mb: 512,
         storage_gb: 1,
         labels: Default::default(),
+        gpu_device_ids: Vec::new(),
+        network_disabled: false,
     };
 
     let result = provider.create_container(&config).await;
@@ -172,6 +176,8 @@ async fn test_modal_provider_stub_returns_not_supported() {
         memory_mb: 512,
         storage_gb: 1,
         labels: Default::default(),
+        gpu_device_ids: Vec::new(),
+        network_disabled: false,
     };
 
     let result = provider.create_container(&config).await;
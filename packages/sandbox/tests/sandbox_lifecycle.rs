@@ -78,12 +78,15 @@ async fn test_complete_sandbox_lifecycle() {
         user_id: "default-user".to_string(),
         project_id: None,
         image: Some("alpine:latest".to_string()),
+        template_id: None,
         cpu_cores: Some(1.0),
         memory_mb: Some(512),
         storage_gb: Some(5),
         gpu_enabled: false,
         gpu_model: None,
+        gpu_count: None,
         env_vars: HashMap::new(),
+        secret_env_vars: HashMap::new(),
         volumes: vec![],
         ports: vec![],
         ssh_enabled: false,
@@ -156,12 +159,15 @@ async fn test_sandbox_resource_limits() {
         user_id: "default-user".to_string(),
         project_id: None,
         image: Some("alpine:latest".to_string()),
+        template_id: None,
         cpu_cores: Some(2.0),
         memory_mb: Some(1024),
         storage_gb: Some(10),
         gpu_enabled: false,
         gpu_model: None,
+        gpu_count: None,
         env_vars: HashMap::new(),
+        secret_env_vars: HashMap::new(),
         volumes: vec![],
         ports: vec![],
         ssh_enabled: false,
@@ -207,12 +213,15 @@ async fn test_sandbox_execution_with_env_vars() {
         user_id: "default-user".to_string(),
         project_id: None,
         image: Some("alpine:latest".to_string()),
+        template_id: None,
         cpu_cores: Some(1.0),
         memory_mb: Some(512),
         storage_gb: Some(5),
         gpu_enabled: false,
         gpu_model: None,
+        gpu_count: None,
         env_vars: HashMap::new(),
+        secret_env_vars: HashMap::new(),
         volumes: vec![],
         ports: vec![],
         ssh_enabled: false,
@@ -268,12 +277,15 @@ async fn test_list_sandboxes_with_filters() {
         user_id: "test-user-1".to_string(),
         project_id: None,
         image: Some("alpine:latest".to_string()),
+        template_id: None,
         cpu_cores: Some(1.0),
         memory_mb: Some(512),
         storage_gb: Some(5),
         gpu_enabled: false,
         gpu_model: None,
+        gpu_count: None,
         env_vars: HashMap::new(),
+        secret_env_vars: HashMap::new(),
         volumes: vec![],
         ports: vec![],
         ssh_enabled: false,
@@ -430,12 +442,15 @@ async fn test_concurrent_sandbox_operations() {
                 user_id: "default-user".to_string(),
                 project_id: None,
                 image: Some("alpine:latest".to_string()),
+                template_id: None,
                 cpu_cores: Some(1.0),
                 memory_mb: Some(512),
                 storage_gb: Some(5),
                 gpu_enabled: false,
                 gpu_model: None,
+                gpu_count: None,
                 env_vars: HashMap::new(),
+                secret_env_vars: HashMap::new(),
                 volumes: vec![],
                 ports: vec![],
                 ssh_enabled: false,
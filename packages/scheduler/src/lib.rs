@@ -0,0 +1,13 @@
+// ABOUTME: Cron-style scheduler for recurring background jobs
+// ABOUTME: Persists job schedules and run history, and runs registered handlers when jobs are due
+
+pub mod schedule;
+pub mod scheduler;
+pub mod storage;
+pub mod types;
+
+// Re-export main types
+pub use schedule::{next_run_after, validate_cron_expression};
+pub use scheduler::{JobHandler, Scheduler, TICK_INTERVAL};
+pub use storage::JobStorage;
+pub use types::{JobRun, JobRunStatus, JobUpdateInput, ScheduledJob};
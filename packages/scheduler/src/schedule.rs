@@ -0,0 +1,63 @@
+// ABOUTME: Cron expression parsing and next-run calculation
+// ABOUTME: Evaluates standard 5-field cron expressions in the server's local time zone
+
+use chrono::{DateTime, Local, Utc};
+use croner::Cron;
+
+fn parse(expression: &str) -> Result<Cron, String> {
+    let expression = expression.trim();
+    // Seconds are not supported; jobs are checked once per scheduler tick
+    if expression.split_whitespace().count() != 5 {
+        return Err(format!(
+            "Invalid cron expression '{}': expected 5 fields (minute hour day month weekday)",
+            expression
+        ));
+    }
+
+    Cron::new(expression)
+        .parse()
+        .map_err(|e| format!("Invalid cron expression '{}': {}", expression, e))
+}
+
+/// Check that `expression` is a valid 5-field cron expression
+pub fn validate_cron_expression(expression: &str) -> Result<(), String> {
+    parse(expression).map(|_| ())
+}
+
+/// The first time strictly after `after` that matches `expression`
+pub fn next_run_after(expression: &str, after: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    let cron = parse(expression)?;
+    cron.find_next_occurrence(&after.with_timezone(&Local), false)
+        .map(|next| next.with_timezone(&Utc))
+        .map_err(|e| format!("No upcoming run for '{}': {}", expression, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_validate_cron_expression() {
+        assert!(validate_cron_expression("0 2 * * *").is_ok());
+        assert!(validate_cron_expression("*/15 * * * *").is_ok());
+        assert!(validate_cron_expression("0 */6 * * 1-5").is_ok());
+        assert!(validate_cron_expression("0 0 2 * * *").is_err());
+        assert!(validate_cron_expression("61 * * * *").is_err());
+        assert!(validate_cron_expression("").is_err());
+    }
+
+    #[test]
+    fn test_next_run_after() {
+        let now = Utc::now();
+
+        let next = next_run_after("*/15 * * * *", now).unwrap();
+        assert!(next > now);
+        assert!(next <= now + Duration::minutes(15));
+        assert_eq!(next.timestamp() % (15 * 60), 0);
+
+        // Strictly after: asking again from the returned time moves forward
+        let following = next_run_after("*/15 * * * *", next).unwrap();
+        assert_eq!(following - next, Duration::minutes(15));
+    }
+}
@@ -0,0 +1,148 @@
+// ABOUTME: Job runner that executes registered handlers on their cron schedules
+// ABOUTME: Checks for due jobs on a fixed tick, prevents overlapping runs, and records every outcome
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
+
+use super::schedule::next_run_after;
+use super::storage::JobStorage;
+use super::types::{JobRun, JobRunStatus};
+use orkee_storage::StorageError;
+
+/// How often the scheduler checks for due jobs
+pub const TICK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Work performed by a scheduled job
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    /// Run the job, returning a short summary of what it did
+    async fn run(&self) -> Result<String, String>;
+}
+
+pub struct Scheduler {
+    storage: JobStorage,
+    handlers: RwLock<HashMap<String, Arc<dyn JobHandler>>>,
+    /// Jobs with a run in progress, so slow jobs never overlap themselves
+    running: Mutex<HashSet<String>>,
+}
+
+impl Scheduler {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            storage: JobStorage::new(pool),
+            handlers: RwLock::new(HashMap::new()),
+            running: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn storage(&self) -> &JobStorage {
+        &self.storage
+    }
+
+    /// Register the handler for a job seeded in the `scheduled_jobs` table
+    pub async fn register(&self, job_id: &str, handler: Arc<dyn JobHandler>) {
+        self.handlers
+            .write()
+            .await
+            .insert(job_id.to_string(), handler);
+    }
+
+    /// Run a job now and wait for it to finish, regardless of its schedule
+    pub async fn run_job(&self, job_id: &str) -> Result<JobRun, StorageError> {
+        // Fails with RowNotFound for unknown jobs
+        self.storage.get_job(job_id).await?;
+
+        if !self.running.lock().unwrap().insert(job_id.to_string()) {
+            return Err(StorageError::Validation(format!(
+                "Job {} is already running",
+                job_id
+            )));
+        }
+
+        let result = self.execute(job_id).await;
+        self.running.lock().unwrap().remove(job_id);
+        result
+    }
+
+    async fn execute(&self, job_id: &str) -> Result<JobRun, StorageError> {
+        let handler = self.handlers.read().await.get(job_id).cloned();
+        let run = self.storage.start_run(job_id).await?;
+        info!("Running scheduled job {}", job_id);
+
+        let outcome = match handler {
+            Some(handler) => tokio::spawn(async move { handler.run().await })
+                .await
+                .unwrap_or_else(|e| Err(format!("Job panicked: {}", e))),
+            None => Err("No handler is registered for this job".to_string()),
+        };
+
+        let (status, message) = match outcome {
+            Ok(summary) => {
+                info!("Scheduled job {} succeeded: {}", job_id, summary);
+                (JobRunStatus::Success, summary)
+            }
+            Err(e) => {
+                warn!("Scheduled job {} failed: {}", job_id, e);
+                (JobRunStatus::Failed, e)
+            }
+        };
+        self.storage.finish_run(&run, status, Some(message)).await
+    }
+
+    /// Start every job that is due at `now`, advancing each to its next scheduled time first.
+    /// Jobs run in the background; returns how many were started.
+    pub async fn run_due_jobs(self: &Arc<Self>, now: DateTime<Utc>) -> Result<usize, StorageError> {
+        let mut started = 0;
+
+        for job in self.storage.due_jobs(now).await? {
+            let next_run_at = match next_run_after(&job.cron_expression, now) {
+                Ok(next) => Some(next),
+                Err(e) => {
+                    warn!("Unscheduling job {}: {}", job.id, e);
+                    None
+                }
+            };
+            self.storage.set_next_run(&job.id, next_run_at).await?;
+
+            if self.running.lock().unwrap().contains(&job.id) {
+                debug!("Skipping job {}, previous run still in progress", job.id);
+                continue;
+            }
+
+            let scheduler = Arc::clone(self);
+            tokio::spawn(async move {
+                if let Err(e) = scheduler.run_job(&job.id).await {
+                    error!("Failed to run scheduled job {}: {}", job.id, e);
+                }
+            });
+            started += 1;
+        }
+
+        Ok(started)
+    }
+
+    /// Schedule unscheduled jobs and check for due jobs every `TICK_INTERVAL`
+    pub fn start(self: &Arc<Self>) -> tokio::task::JoinHandle<()> {
+        let scheduler = Arc::clone(self);
+        tokio::spawn(async move {
+            if let Err(e) = scheduler.storage.initialize_schedules(Utc::now()).await {
+                error!("Failed to initialize job schedules: {}", e);
+            }
+
+            let mut ticker = tokio::time::interval(TICK_INTERVAL);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = scheduler.run_due_jobs(Utc::now()).await {
+                    error!("Failed to check for due jobs: {}", e);
+                }
+            }
+        })
+    }
+}
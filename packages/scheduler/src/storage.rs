@@ -0,0 +1,351 @@
+// ABOUTME: Scheduled job storage using SQLite
+// ABOUTME: Reads and updates job schedules, tracks next run times, and records run history
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, warn};
+
+use super::schedule::{next_run_after, validate_cron_expression};
+use super::types::{JobRun, JobRunStatus, JobUpdateInput, ScheduledJob};
+use orkee_storage::StorageError;
+
+/// Run history kept per job; older runs are pruned when a run finishes
+pub const MAX_RUNS_PER_JOB: i64 = 50;
+
+/// Timestamps are stored with fixed precision so they compare correctly as strings
+fn format_timestamp(timestamp: &DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn parse_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, StorageError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| StorageError::Database(format!("Invalid {} timestamp", field)))
+}
+
+fn parse_optional_timestamp(
+    row: &sqlx::sqlite::SqliteRow,
+    field: &str,
+) -> Result<Option<DateTime<Utc>>, StorageError> {
+    let value: Option<String> = row.try_get(field)?;
+    value
+        .as_deref()
+        .map(|value| parse_timestamp(field, value))
+        .transpose()
+}
+
+pub struct JobStorage {
+    pool: SqlitePool,
+}
+
+impl JobStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn list_jobs(&self) -> Result<Vec<ScheduledJob>, StorageError> {
+        let rows = sqlx::query("SELECT * FROM scheduled_jobs ORDER BY name")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_job).collect()
+    }
+
+    pub async fn get_job(&self, job_id: &str) -> Result<ScheduledJob, StorageError> {
+        let row = sqlx::query("SELECT * FROM scheduled_jobs WHERE id = ?")
+            .bind(job_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        row_to_job(&row)
+    }
+
+    /// Enable/disable a job or change its schedule. The next run time is recalculated
+    /// from now for enabled jobs and cleared for disabled ones.
+    pub async fn update_job(
+        &self,
+        job_id: &str,
+        input: JobUpdateInput,
+    ) -> Result<ScheduledJob, StorageError> {
+        let job = self.get_job(job_id).await?;
+
+        let cron_expression = match input.cron_expression {
+            Some(expression) => {
+                let expression = expression.trim().to_string();
+                validate_cron_expression(&expression).map_err(StorageError::Validation)?;
+                expression
+            }
+            None => job.cron_expression,
+        };
+        let enabled = input.enabled.unwrap_or(job.enabled);
+        let next_run_at = if enabled {
+            Some(next_run_after(&cron_expression, Utc::now()).map_err(StorageError::Validation)?)
+        } else {
+            None
+        };
+
+        debug!(
+            "Updating scheduled job {}: enabled={}, cron='{}'",
+            job_id, enabled, cron_expression
+        );
+
+        sqlx::query(
+            r#"
+            UPDATE scheduled_jobs
+            SET cron_expression = ?, enabled = ?, next_run_at = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&cron_expression)
+        .bind(enabled)
+        .bind(next_run_at.as_ref().map(format_timestamp))
+        .bind(format_timestamp(&Utc::now()))
+        .bind(job_id)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_job(job_id).await
+    }
+
+    /// Give enabled jobs without a next run time one, e.g. after they were seeded.
+    /// Jobs with an invalid schedule are left unscheduled.
+    pub async fn initialize_schedules(&self, now: DateTime<Utc>) -> Result<(), StorageError> {
+        let unscheduled: Vec<(String, String)> = sqlx::query_as(
+            "SELECT id, cron_expression FROM scheduled_jobs WHERE enabled = 1 AND next_run_at IS NULL",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        for (job_id, cron_expression) in unscheduled {
+            match next_run_after(&cron_expression, now) {
+                Ok(next_run_at) => self.set_next_run(&job_id, Some(next_run_at)).await?,
+                Err(e) => warn!("Not scheduling job {}: {}", job_id, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Add a job registered at runtime, such as a plugin job. Existing jobs keep
+    /// the schedule and enabled state the user gave them.
+    pub async fn ensure_job(
+        &self,
+        job_id: &str,
+        name: &str,
+        description: Option<&str>,
+        cron_expression: &str,
+    ) -> Result<(), StorageError> {
+        validate_cron_expression(cron_expression).map_err(StorageError::Validation)?;
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO scheduled_jobs (id, name, description, cron_expression, enabled) VALUES (?, ?, ?, ?, 1)",
+        )
+        .bind(job_id)
+        .bind(name)
+        .bind(description)
+        .bind(cron_expression)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+        Ok(())
+    }
+
+    /// Enabled jobs whose next run time is at or before `now`
+    pub async fn due_jobs(&self, now: DateTime<Utc>) -> Result<Vec<ScheduledJob>, StorageError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM scheduled_jobs
+            WHERE enabled = 1 AND next_run_at IS NOT NULL AND next_run_at <= ?
+            ORDER BY next_run_at
+            "#,
+        )
+        .bind(format_timestamp(&now))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_job).collect()
+    }
+
+    pub async fn set_next_run(
+        &self,
+        job_id: &str,
+        next_run_at: Option<DateTime<Utc>>,
+    ) -> Result<(), StorageError> {
+        sqlx::query("UPDATE scheduled_jobs SET next_run_at = ? WHERE id = ?")
+            .bind(next_run_at.as_ref().map(format_timestamp))
+            .bind(job_id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        Ok(())
+    }
+
+    /// Record that a job started running
+    pub async fn start_run(&self, job_id: &str) -> Result<JobRun, StorageError> {
+        let id = nanoid::nanoid!(12);
+        let started_at = Utc::now();
+
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+        sqlx::query(
+            "INSERT INTO scheduled_job_runs (id, job_id, status, started_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(job_id)
+        .bind(JobRunStatus::Running.as_str())
+        .bind(format_timestamp(&started_at))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            r#"
+            UPDATE scheduled_jobs
+            SET last_run_at = ?, last_status = ?, last_message = NULL, last_duration_ms = NULL
+            WHERE id = ?
+            "#,
+        )
+        .bind(format_timestamp(&started_at))
+        .bind(JobRunStatus::Running.as_str())
+        .bind(job_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        Ok(JobRun {
+            id,
+            job_id: job_id.to_string(),
+            status: JobRunStatus::Running,
+            message: None,
+            started_at,
+            finished_at: None,
+            duration_ms: None,
+        })
+    }
+
+    /// Record a run's outcome on the run and as the job's last result
+    pub async fn finish_run(
+        &self,
+        run: &JobRun,
+        status: JobRunStatus,
+        message: Option<String>,
+    ) -> Result<JobRun, StorageError> {
+        let finished_at = Utc::now();
+        let duration_ms = (finished_at - run.started_at).num_milliseconds();
+
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+        sqlx::query(
+            r#"
+            UPDATE scheduled_job_runs
+            SET status = ?, message = ?, finished_at = ?, duration_ms = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(&message)
+        .bind(format_timestamp(&finished_at))
+        .bind(duration_ms)
+        .bind(&run.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            r#"
+            UPDATE scheduled_jobs
+            SET last_status = ?, last_message = ?, last_duration_ms = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(&message)
+        .bind(duration_ms)
+        .bind(&run.job_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM scheduled_job_runs
+            WHERE job_id = ?1 AND id NOT IN (
+                SELECT id FROM scheduled_job_runs
+                WHERE job_id = ?1
+                ORDER BY started_at DESC
+                LIMIT ?2
+            )
+            "#,
+        )
+        .bind(&run.job_id)
+        .bind(MAX_RUNS_PER_JOB)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        Ok(JobRun {
+            status,
+            message,
+            finished_at: Some(finished_at),
+            duration_ms: Some(duration_ms),
+            ..run.clone()
+        })
+    }
+
+    /// Runs of a job, newest first
+    pub async fn list_runs(&self, job_id: &str, limit: i64) -> Result<Vec<JobRun>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT * FROM scheduled_job_runs WHERE job_id = ? ORDER BY started_at DESC LIMIT ?",
+        )
+        .bind(job_id)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_run).collect()
+    }
+}
+
+fn parse_status(value: String) -> Result<JobRunStatus, StorageError> {
+    value.parse().map_err(StorageError::Database)
+}
+
+fn row_to_job(row: &sqlx::sqlite::SqliteRow) -> Result<ScheduledJob, StorageError> {
+    let created_at: String = row.try_get("created_at")?;
+    let updated_at: String = row.try_get("updated_at")?;
+    let last_status: Option<String> = row.try_get("last_status")?;
+
+    Ok(ScheduledJob {
+        id: row.try_get("id")?,
+        name: row.try_get("name")?,
+        description: row.try_get("description")?,
+        cron_expression: row.try_get("cron_expression")?,
+        enabled: row.try_get("enabled")?,
+        next_run_at: parse_optional_timestamp(row, "next_run_at")?,
+        last_run_at: parse_optional_timestamp(row, "last_run_at")?,
+        last_status: last_status.map(parse_status).transpose()?,
+        last_message: row.try_get("last_message")?,
+        last_duration_ms: row.try_get("last_duration_ms")?,
+        created_at: parse_timestamp("created_at", &created_at)?,
+        updated_at: parse_timestamp("updated_at", &updated_at)?,
+    })
+}
+
+fn row_to_run(row: &sqlx::sqlite::SqliteRow) -> Result<JobRun, StorageError> {
+    let started_at: String = row.try_get("started_at")?;
+
+    Ok(JobRun {
+        id: row.try_get("id")?,
+        job_id: row.try_get("job_id")?,
+        status: parse_status(row.try_get("status")?)?,
+        message: row.try_get("message")?,
+        started_at: parse_timestamp("started_at", &started_at)?,
+        finished_at: parse_optional_timestamp(row, "finished_at")?,
+        duration_ms: row.try_get("duration_ms")?,
+    })
+}
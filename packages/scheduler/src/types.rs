@@ -0,0 +1,73 @@
+// ABOUTME: Scheduler type definitions
+// ABOUTME: Scheduled job records, run outcomes, and job update input
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Outcome of a job run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobRunStatus {
+    Running,
+    Success,
+    Failed,
+}
+
+impl JobRunStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobRunStatus::Running => "running",
+            JobRunStatus::Success => "success",
+            JobRunStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for JobRunStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "running" => Ok(JobRunStatus::Running),
+            "success" => Ok(JobRunStatus::Success),
+            "failed" => Ok(JobRunStatus::Failed),
+            _ => Err(format!("Unknown job run status: {}", s)),
+        }
+    }
+}
+
+/// A recurring job and the result of its most recent run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Standard 5-field cron expression, evaluated in the server's local time
+    pub cron_expression: String,
+    pub enabled: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_status: Option<JobRunStatus>,
+    pub last_message: Option<String>,
+    pub last_duration_ms: Option<i64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A single execution of a scheduled job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRun {
+    pub id: String,
+    pub job_id: String,
+    pub status: JobRunStatus,
+    pub message: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobUpdateInput {
+    pub enabled: Option<bool>,
+    pub cron_expression: Option<String>,
+}
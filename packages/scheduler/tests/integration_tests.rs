@@ -0,0 +1,234 @@
+// ABOUTME: Integration tests for the recurring job scheduler
+// ABOUTME: Tests seeded jobs, schedule updates, due job execution, and run history recording
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use orkee_scheduler::{JobHandler, JobRunStatus, JobUpdateInput, Scheduler};
+use sqlx::SqlitePool;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+    pool
+}
+
+struct CountingHandler {
+    calls: AtomicUsize,
+}
+
+#[async_trait]
+impl JobHandler for CountingHandler {
+    async fn run(&self) -> Result<String, String> {
+        let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        Ok(format!("run {}", calls))
+    }
+}
+
+struct FailingHandler;
+
+#[async_trait]
+impl JobHandler for FailingHandler {
+    async fn run(&self) -> Result<String, String> {
+        Err("disk full".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_seeded_jobs_and_schedule_updates() {
+    let scheduler = Scheduler::new(create_test_db().await);
+    let storage = scheduler.storage();
+
+    let jobs = storage.list_jobs().await.unwrap();
+    let ids: Vec<&str> = jobs.iter().map(|job| job.id.as_str()).collect();
+    assert!(ids.contains(&"database_backup"));
+    assert!(ids.contains(&"cloud_sync"));
+    assert!(ids.contains(&"model_catalog_refresh"));
+    assert!(ids.contains(&"stale_server_cleanup"));
+
+    // Seeded jobs get a next run time on first initialization, unless disabled
+    storage.initialize_schedules(Utc::now()).await.unwrap();
+    assert!(storage
+        .get_job("database_backup")
+        .await
+        .unwrap()
+        .next_run_at
+        .is_some());
+    assert!(storage
+        .get_job("cloud_sync")
+        .await
+        .unwrap()
+        .next_run_at
+        .is_none());
+
+    let updated = storage
+        .update_job(
+            "cloud_sync",
+            JobUpdateInput {
+                enabled: Some(true),
+                cron_expression: Some("0 * * * *".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(updated.enabled);
+    assert_eq!(updated.cron_expression, "0 * * * *");
+    let next = updated.next_run_at.unwrap();
+    assert!(next > Utc::now() && next <= Utc::now() + Duration::hours(1));
+
+    let disabled = storage
+        .update_job(
+            "cloud_sync",
+            JobUpdateInput {
+                enabled: Some(false),
+                cron_expression: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(!disabled.enabled);
+    assert!(disabled.next_run_at.is_none());
+
+    let invalid = storage
+        .update_job(
+            "cloud_sync",
+            JobUpdateInput {
+                enabled: None,
+                cron_expression: Some("every hour".to_string()),
+            },
+        )
+        .await;
+    assert!(invalid.is_err());
+}
+
+#[tokio::test]
+async fn test_ensure_job_keeps_user_changes() {
+    let scheduler = Scheduler::new(create_test_db().await);
+    let storage = scheduler.storage();
+
+    storage
+        .ensure_job("plugin.demo.sync", "Demo sync", None, "0 * * * *")
+        .await
+        .unwrap();
+    let job = storage.get_job("plugin.demo.sync").await.unwrap();
+    assert!(job.enabled);
+    assert_eq!(job.cron_expression, "0 * * * *");
+
+    storage
+        .update_job(
+            "plugin.demo.sync",
+            JobUpdateInput {
+                enabled: Some(false),
+                cron_expression: Some("30 2 * * *".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    storage
+        .ensure_job("plugin.demo.sync", "Demo sync", None, "0 * * * *")
+        .await
+        .unwrap();
+    let job = storage.get_job("plugin.demo.sync").await.unwrap();
+    assert!(!job.enabled);
+    assert_eq!(job.cron_expression, "30 2 * * *");
+
+    assert!(storage
+        .ensure_job("plugin.demo.bad", "Bad", None, "every hour")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_run_job_records_outcomes() {
+    let scheduler = Scheduler::new(create_test_db().await);
+    scheduler
+        .register(
+            "database_backup",
+            Arc::new(CountingHandler {
+                calls: AtomicUsize::new(0),
+            }),
+        )
+        .await;
+    scheduler
+        .register("model_catalog_refresh", Arc::new(FailingHandler))
+        .await;
+
+    let run = scheduler.run_job("database_backup").await.unwrap();
+    assert_eq!(run.status, JobRunStatus::Success);
+    assert_eq!(run.message.as_deref(), Some("run 1"));
+    assert!(run.finished_at.is_some());
+
+    let run = scheduler.run_job("model_catalog_refresh").await.unwrap();
+    assert_eq!(run.status, JobRunStatus::Failed);
+    assert_eq!(run.message.as_deref(), Some("disk full"));
+
+    // Jobs without a handler record a failed run rather than silently doing nothing
+    let run = scheduler.run_job("cloud_sync").await.unwrap();
+    assert_eq!(run.status, JobRunStatus::Failed);
+
+    assert!(scheduler.run_job("no_such_job").await.is_err());
+
+    let job = scheduler
+        .storage()
+        .get_job("database_backup")
+        .await
+        .unwrap();
+    assert_eq!(job.last_status, Some(JobRunStatus::Success));
+    assert_eq!(job.last_message.as_deref(), Some("run 1"));
+    assert!(job.last_run_at.is_some());
+
+    let runs = scheduler
+        .storage()
+        .list_runs("model_catalog_refresh", 10)
+        .await
+        .unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].status, JobRunStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_due_jobs_run_and_reschedule() {
+    let scheduler = Arc::new(Scheduler::new(create_test_db().await));
+    let handler = Arc::new(CountingHandler {
+        calls: AtomicUsize::new(0),
+    });
+    scheduler
+        .register("stale_server_cleanup", handler.clone())
+        .await;
+
+    let now = Utc::now();
+    let storage = scheduler.storage();
+    storage.initialize_schedules(now).await.unwrap();
+
+    // Nothing is due yet
+    assert_eq!(scheduler.run_due_jobs(now).await.unwrap(), 0);
+
+    // Make only the cleanup job due
+    storage
+        .set_next_run("stale_server_cleanup", Some(now - Duration::minutes(1)))
+        .await
+        .unwrap();
+    assert_eq!(scheduler.run_due_jobs(now).await.unwrap(), 1);
+
+    let job = storage.get_job("stale_server_cleanup").await.unwrap();
+    assert!(job.next_run_at.unwrap() > now);
+
+    // The job runs in the background; wait for it to be recorded
+    for _ in 0..50 {
+        let job = storage.get_job("stale_server_cleanup").await.unwrap();
+        if job.last_status == Some(JobRunStatus::Success) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    }
+    assert_eq!(handler.calls.load(Ordering::SeqCst), 1);
+
+    let runs = storage.list_runs("stale_server_cleanup", 10).await.unwrap();
+    assert_eq!(runs.len(), 1);
+    assert_eq!(runs[0].status, JobRunStatus::Success);
+}
@@ -1,14 +1,19 @@
 // ABOUTME: Storage operations for API tokens
 // ABOUTME: Token generation, hashing, verification, and database operations
 
-use crate::api_tokens::types::{ApiToken, TokenGeneration};
+use crate::api_tokens::types::{token_timestamp, ApiToken, TokenGeneration};
 use base64::Engine;
+use chrono::{DateTime, Duration, Utc};
 use orkee_storage::StorageError;
 use rand::Rng;
 use sha2::{Digest, Sha256};
 use sqlx::{Row, SqlitePool};
 use uuid::Uuid;
 
+/// Columns selected for every token query
+const TOKEN_COLUMNS: &str =
+    "id, token_hash, name, created_at, last_used_at, is_active, expires_at, replaced_by";
+
 pub struct TokenStorage {
     pool: SqlitePool,
 }
@@ -48,19 +53,29 @@ impl TokenStorage {
             .into()
     }
 
-    /// Create a new API token
+    /// Create a new API token that never expires
     pub async fn create_token(&self, name: &str) -> Result<TokenGeneration, StorageError> {
+        self.create_token_with_expiry(name, None).await
+    }
+
+    /// Create a new API token that stops authenticating at `expires_at`
+    pub async fn create_token_with_expiry(
+        &self,
+        name: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<TokenGeneration, StorageError> {
         let id = Uuid::new_v4().to_string();
         let token = Self::generate_token();
         let token_hash = Self::hash_token(&token);
 
         sqlx::query(
-            "INSERT INTO api_tokens (id, token_hash, name, is_active)
-             VALUES (?, ?, ?, 1)",
+            "INSERT INTO api_tokens (id, token_hash, name, is_active, expires_at)
+             VALUES (?, ?, ?, 1, ?)",
         )
         .bind(&id)
         .bind(&token_hash)
         .bind(name)
+        .bind(expires_at.map(token_timestamp))
         .execute(&self.pool)
         .await
         .map_err(StorageError::Sqlx)?;
@@ -68,16 +83,19 @@ impl TokenStorage {
         Ok(TokenGeneration::new(token, token_hash, id))
     }
 
-    /// Verify a token and return the token record if valid
+    /// Verify a token and return the token record if it is active and unexpired
     pub async fn verify_token(&self, token: &str) -> Result<Option<ApiToken>, StorageError> {
         let token_hash = Self::hash_token(token);
 
-        let row = sqlx::query(
-            "SELECT id, token_hash, name, created_at, last_used_at, is_active
+        let row = sqlx::query(&format!(
+            "SELECT {}
              FROM api_tokens
-             WHERE token_hash = ? AND is_active = 1",
-        )
+             WHERE token_hash = ? AND is_active = 1
+               AND (expires_at IS NULL OR expires_at > ?)",
+            TOKEN_COLUMNS
+        ))
         .bind(&token_hash)
+        .bind(token_timestamp(Utc::now()))
         .fetch_optional(&self.pool)
         .await
         .map_err(StorageError::Sqlx)?;
@@ -101,7 +119,7 @@ impl TokenStorage {
     pub async fn update_last_used(&self, token_hash: &str) -> Result<(), StorageError> {
         sqlx::query(
             "UPDATE api_tokens
-             SET last_used_at = datetime('now', 'utc')
+             SET last_used_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE token_hash = ? AND is_active = 1",
         )
         .bind(token_hash)
@@ -114,11 +132,10 @@ impl TokenStorage {
 
     /// List all tokens (without hashes)
     pub async fn list_tokens(&self) -> Result<Vec<ApiToken>, StorageError> {
-        let rows = sqlx::query(
-            "SELECT id, token_hash, name, created_at, last_used_at, is_active
-             FROM api_tokens
-             ORDER BY created_at DESC",
-        )
+        let rows = sqlx::query(&format!(
+            "SELECT {} FROM api_tokens ORDER BY created_at DESC",
+            TOKEN_COLUMNS
+        ))
         .fetch_all(&self.pool)
         .await
         .map_err(StorageError::Sqlx)?;
@@ -128,11 +145,10 @@ impl TokenStorage {
 
     /// Get a token by ID
     pub async fn get_token(&self, id: &str) -> Result<Option<ApiToken>, StorageError> {
-        let row = sqlx::query(
-            "SELECT id, token_hash, name, created_at, last_used_at, is_active
-             FROM api_tokens
-             WHERE id = ?",
-        )
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM api_tokens WHERE id = ?",
+            TOKEN_COLUMNS
+        ))
         .bind(id)
         .fetch_optional(&self.pool)
         .await
@@ -155,6 +171,94 @@ impl TokenStorage {
         Ok(())
     }
 
+    /// Set or clear a token's expiry. Returns false if the token doesn't exist.
+    pub async fn set_expiry(
+        &self,
+        id: &str,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, StorageError> {
+        let result = sqlx::query("UPDATE api_tokens SET expires_at = ? WHERE id = ?")
+            .bind(expires_at.map(token_timestamp))
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Issue a replacement for a token, keeping the old one working for `grace`.
+    ///
+    /// The new token has the same name and expires at `expires_at`. The old
+    /// token's expiry is brought forward to the end of the grace period, or it
+    /// is revoked immediately when `grace` is zero. Returns `None` if the token
+    /// doesn't exist or no longer authenticates.
+    pub async fn rotate_token(
+        &self,
+        id: &str,
+        grace: Duration,
+        expires_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<TokenGeneration>, StorageError> {
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM api_tokens WHERE id = ?",
+            TOKEN_COLUMNS
+        ))
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+        let old = match row {
+            Some(row) => self.row_to_token(row)?,
+            None => return Ok(None),
+        };
+        if !old.is_usable(now) {
+            return Ok(None);
+        }
+
+        let new_id = Uuid::new_v4().to_string();
+        let token = Self::generate_token();
+        let token_hash = Self::hash_token(&token);
+        sqlx::query(
+            "INSERT INTO api_tokens (id, token_hash, name, is_active, expires_at)
+             VALUES (?, ?, ?, 1, ?)",
+        )
+        .bind(&new_id)
+        .bind(&token_hash)
+        .bind(&old.name)
+        .bind(expires_at.map(token_timestamp))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        // Never extend the old token past the expiry it already had
+        let grace_ends = now + grace;
+        let old_expiry = old
+            .expires_at
+            .as_deref()
+            .and_then(|expires_at| DateTime::parse_from_rfc3339(expires_at).ok())
+            .map(|expires_at| expires_at.with_timezone(&Utc))
+            .map_or(grace_ends, |expires_at| expires_at.min(grace_ends));
+        sqlx::query(
+            "UPDATE api_tokens
+             SET replaced_by = ?, expires_at = ?, is_active = ?
+             WHERE id = ?",
+        )
+        .bind(&new_id)
+        .bind(token_timestamp(old_expiry))
+        .bind(grace > Duration::zero())
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        Ok(Some(TokenGeneration::new(token, token_hash, new_id)))
+    }
+
     /// Count active tokens
     pub async fn count_active_tokens(&self) -> Result<i64, StorageError> {
         let row = sqlx::query("SELECT COUNT(*) as count FROM api_tokens WHERE is_active = 1")
@@ -178,6 +282,8 @@ impl TokenStorage {
                 .try_get::<i64, _>("is_active")
                 .map_err(StorageError::Sqlx)?
                 != 0,
+            expires_at: row.try_get("expires_at").map_err(StorageError::Sqlx)?,
+            replaced_by: row.try_get("replaced_by").map_err(StorageError::Sqlx)?,
         })
     }
 }
@@ -243,4 +349,108 @@ mod tests {
 
         assert!(TokenStorage::verify_token_hash(&token, &hash));
     }
+
+    async fn setup_storage() -> TokenStorage {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE api_tokens (
+                id TEXT PRIMARY KEY,
+                token_hash TEXT NOT NULL,
+                name TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                last_used_at TEXT,
+                is_active BOOLEAN NOT NULL DEFAULT TRUE,
+                expires_at TEXT,
+                replaced_by TEXT
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        TokenStorage::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_expired_tokens_do_not_verify() {
+        let storage = setup_storage().await;
+        let expired = storage
+            .create_token_with_expiry("old", Some(Utc::now() - Duration::minutes(1)))
+            .await
+            .unwrap();
+        let current = storage
+            .create_token_with_expiry("current", Some(Utc::now() + Duration::days(30)))
+            .await
+            .unwrap();
+
+        assert!(storage
+            .verify_token(&expired.token)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(storage
+            .verify_token(&current.token)
+            .await
+            .unwrap()
+            .is_some());
+
+        // Clearing the expiry makes the token work again
+        assert!(storage.set_expiry(&expired.id, None).await.unwrap());
+        assert!(storage
+            .verify_token(&expired.token)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_old_token_during_grace() {
+        let storage = setup_storage().await;
+        let original = storage.create_token("ci").await.unwrap();
+
+        let rotated = storage
+            .rotate_token(&original.id, Duration::minutes(10), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(storage
+            .verify_token(&original.token)
+            .await
+            .unwrap()
+            .is_some());
+        assert!(storage
+            .verify_token(&rotated.token)
+            .await
+            .unwrap()
+            .is_some());
+
+        let old = storage.get_token(&original.id).await.unwrap().unwrap();
+        assert_eq!(old.replaced_by.as_deref(), Some(rotated.id.as_str()));
+        assert!(old.expires_at.is_some());
+        let new = storage.get_token(&rotated.id).await.unwrap().unwrap();
+        assert_eq!(new.name, "ci");
+
+        // Without a grace period the old token stops working right away
+        let again = storage
+            .rotate_token(&rotated.id, Duration::zero(), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(storage
+            .verify_token(&rotated.token)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(storage.verify_token(&again.token).await.unwrap().is_some());
+
+        // Tokens that no longer authenticate can't be rotated
+        assert!(storage
+            .rotate_token(&rotated.id, Duration::minutes(10), None)
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
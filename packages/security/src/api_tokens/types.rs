@@ -1,6 +1,7 @@
 // ABOUTME: Type definitions for API token authentication
 // ABOUTME: Structures for token generation, storage, and validation
 
+use chrono::{DateTime, SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
 
 /// API token stored in database
@@ -12,6 +13,32 @@ pub struct ApiToken {
     pub created_at: String,
     pub last_used_at: Option<String>,
     pub is_active: bool,
+    /// When the token stops authenticating; `None` never expires
+    pub expires_at: Option<String>,
+    /// ID of the token issued when this one was rotated
+    pub replaced_by: Option<String>,
+}
+
+impl ApiToken {
+    /// Whether the token's expiry has passed. Unparseable expiries count as expired.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        match &self.expires_at {
+            Some(expires_at) => DateTime::parse_from_rfc3339(expires_at)
+                .map(|expires_at| expires_at <= now)
+                .unwrap_or(true),
+            None => false,
+        }
+    }
+
+    /// Whether the token currently authenticates requests
+    pub fn is_usable(&self, now: DateTime<Utc>) -> bool {
+        self.is_active && !self.is_expired(now)
+    }
+}
+
+/// Format a timestamp the way token timestamps are stored, so they compare as strings
+pub fn token_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
 }
 
 /// Token generation result - includes plaintext token for display
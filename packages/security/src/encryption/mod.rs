@@ -20,12 +20,15 @@
 
 use argon2::{Argon2, ParamsBuilder, Version};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use orkee_storage::field_encryption::{self, FieldCipher};
 pub use orkee_storage::EncryptionMode;
+use orkee_storage::{StorageError, StorageResult};
 use ring::{
     aead::{self, Nonce, UnboundKey},
     error::Unspecified,
     rand::{SecureRandom, SystemRandom},
 };
+use sqlx::SqlitePool;
 use std::sync::Arc;
 use subtle::ConstantTimeEq;
 
@@ -60,6 +63,9 @@ pub enum EncryptionError {
 
     #[error("Invalid password")]
     InvalidPassword,
+
+    #[error("Failed to read encryption settings: {0}")]
+    Settings(String),
 }
 
 impl From<Unspecified> for EncryptionError {
@@ -319,6 +325,83 @@ impl Default for ApiKeyEncryption {
     }
 }
 
+impl FieldCipher for ApiKeyEncryption {
+    fn encrypt(&self, plaintext: &str) -> StorageResult<String> {
+        ApiKeyEncryption::encrypt(self, plaintext)
+            .map_err(|e| StorageError::Encryption(e.to_string()))
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> StorageResult<String> {
+        ApiKeyEncryption::decrypt(self, ciphertext)
+            .map_err(|e| StorageError::Encryption(e.to_string()))
+    }
+}
+
+/// Environment variable holding the password when password-based encryption is enabled
+pub const PASSWORD_ENV_VAR: &str = "ORKEE_ENCRYPTION_PASSWORD";
+
+/// Mode, salt and verification hash as stored in `encryption_settings`
+type EncryptionSettingsRow = (String, Option<Vec<u8>>, Option<Vec<u8>>);
+
+/// Build the cipher for the encryption mode stored in `encryption_settings`.
+///
+/// Password mode reads the password from [`PASSWORD_ENV_VAR`] and checks it
+/// against the stored verification hash before deriving the key.
+pub async fn configured_encryption(pool: &SqlitePool) -> Result<ApiKeyEncryption, EncryptionError> {
+    let settings: Option<EncryptionSettingsRow> = sqlx::query_as(
+        "SELECT encryption_mode, password_salt, password_hash FROM encryption_settings WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| EncryptionError::Settings(e.to_string()))?;
+
+    let Some((mode, salt, hash)) = settings else {
+        return ApiKeyEncryption::with_machine_key();
+    };
+    match mode.parse::<EncryptionMode>() {
+        Ok(EncryptionMode::Machine) => ApiKeyEncryption::with_machine_key(),
+        Ok(EncryptionMode::Password) => {
+            let (Some(salt), Some(hash)) = (salt, hash) else {
+                return Err(EncryptionError::Settings(
+                    "password mode is enabled but no password is set".to_string(),
+                ));
+            };
+            let password =
+                std::env::var(PASSWORD_ENV_VAR).map_err(|_| EncryptionError::PasswordRequired)?;
+            if !ApiKeyEncryption::verify_password(&password, &salt, &hash)? {
+                return Err(EncryptionError::InvalidPassword);
+            }
+            ApiKeyEncryption::with_password(&password, &salt)
+        }
+        Err(e) => Err(EncryptionError::InvalidMode(e)),
+    }
+}
+
+/// Install the cipher that seals sensitive columns in storage, keyed for the
+/// configured encryption mode. Safe to call more than once; later calls are no-ops.
+///
+/// In password mode without a valid password, sensitive fields are locked so
+/// writes fail rather than storing plaintext.
+pub async fn install_field_cipher(pool: &SqlitePool) -> Result<(), EncryptionError> {
+    if field_encryption::is_installed() {
+        return Ok(());
+    }
+    match configured_encryption(pool).await {
+        Ok(encryption) => {
+            field_encryption::install(Arc::new(encryption));
+            Ok(())
+        }
+        Err(e @ (EncryptionError::PasswordRequired | EncryptionError::InvalidPassword)) => {
+            field_encryption::lock(format!(
+                "{}; set {} to the encryption password and restart",
+                e, PASSWORD_ENV_VAR
+            ));
+            Err(e)
+        }
+        Err(e) => Err(e),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -781,4 +864,57 @@ mod tests {
         let encryption_wrong_salt = ApiKeyEncryption::with_password(password, &salt2).unwrap();
         assert!(encryption_wrong_salt.decrypt(&encrypted).is_err());
     }
+
+    async fn settings_pool(mode: &str, salt: Option<&[u8]>, hash: Option<&[u8]>) -> SqlitePool {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+        sqlx::query(
+            "CREATE TABLE encryption_settings (id INTEGER PRIMARY KEY, encryption_mode TEXT NOT NULL, password_salt BLOB, password_hash BLOB)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO encryption_settings VALUES (1, ?, ?, ?)")
+            .bind(mode)
+            .bind(salt)
+            .bind(hash)
+            .execute(&pool)
+            .await
+            .unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn test_configured_encryption_follows_mode() {
+        let machine = configured_encryption(&settings_pool("machine", None, None).await)
+            .await
+            .unwrap();
+        assert_eq!(machine.mode(), EncryptionMode::Machine);
+
+        let salt = ApiKeyEncryption::generate_salt().unwrap();
+        let hash =
+            ApiKeyEncryption::hash_password_for_verification("Correct-h0rse!", &salt).unwrap();
+        let pool = settings_pool("password", Some(&salt), Some(&hash)).await;
+
+        std::env::remove_var(PASSWORD_ENV_VAR);
+        assert!(matches!(
+            configured_encryption(&pool).await,
+            Err(EncryptionError::PasswordRequired)
+        ));
+
+        std::env::set_var(PASSWORD_ENV_VAR, "wrong-password");
+        assert!(matches!(
+            configured_encryption(&pool).await,
+            Err(EncryptionError::InvalidPassword)
+        ));
+
+        std::env::set_var(PASSWORD_ENV_VAR, "Correct-h0rse!");
+        let password = configured_encryption(&pool).await.unwrap();
+        std::env::remove_var(PASSWORD_ENV_VAR);
+        assert_eq!(password.mode(), EncryptionMode::Password);
+
+        // The derived key is the one the password commands use
+        let expected = ApiKeyEncryption::with_password("Correct-h0rse!", &salt).unwrap();
+        let sealed = password.encrypt("npm run dev").unwrap();
+        assert_eq!(expected.decrypt(&sealed).unwrap(), "npm run dev");
+    }
 }
@@ -0,0 +1,8 @@
+// ABOUTME: Intrusion detection for repeated authentication failures
+// ABOUTME: Per-IP failure tracking, temporary lockouts, and the security alert feed
+
+pub mod storage;
+pub mod types;
+
+pub use storage::IntrusionDetector;
+pub use types::{AuthLockout, LockoutPolicy, SecurityAlert, SecurityAlertKind};
@@ -0,0 +1,373 @@
+// ABOUTME: Storage operations for authentication intrusion detection
+// ABOUTME: Records failed attempts per IP address, applies lockouts, and lists security alerts
+
+use crate::intrusion::types::{AuthLockout, LockoutPolicy, SecurityAlert, SecurityAlertKind};
+use chrono::{DateTime, SecondsFormat, Utc};
+use orkee_storage::StorageError;
+use sqlx::{Row, SqlitePool};
+use uuid::Uuid;
+
+/// Format a timestamp the way intrusion records are stored, so they compare as strings
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+pub struct IntrusionDetector {
+    pool: SqlitePool,
+}
+
+impl IntrusionDetector {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Load the lockout policy from the `auth_lockout_*` security settings
+    pub async fn policy(&self) -> Result<LockoutPolicy, StorageError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT key, value FROM system_settings WHERE key LIKE 'auth_lockout_%'",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(LockoutPolicy::from_settings(
+            rows.iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        ))
+    }
+
+    /// When the lockout of `ip` ends, or `None` if it isn't locked out
+    pub async fn locked_until(&self, ip: &str) -> Result<Option<DateTime<Utc>>, StorageError> {
+        let locked_until: Option<String> = sqlx::query_scalar(
+            "SELECT locked_until FROM auth_lockouts WHERE ip_address = ? AND locked_until > ?",
+        )
+        .bind(ip)
+        .bind(timestamp(Utc::now()))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(locked_until
+            .and_then(|at| DateTime::parse_from_rfc3339(&at).ok())
+            .map(|at| at.with_timezone(&Utc)))
+    }
+
+    /// Record a failed authentication attempt from `ip`.
+    ///
+    /// Returns the alert raised when this failure reaches the policy's
+    /// threshold and locks the address out. Does nothing while lockouts are
+    /// disabled.
+    pub async fn record_failure(
+        &self,
+        ip: &str,
+        path: &str,
+        reason: &str,
+    ) -> Result<Option<SecurityAlert>, StorageError> {
+        let policy = self.policy().await?;
+        if !policy.enabled {
+            return Ok(None);
+        }
+
+        let now = Utc::now();
+        let window_start = timestamp(now - policy.window);
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            "INSERT INTO auth_failures (ip_address, path, reason, created_at) VALUES (?, ?, ?, ?)",
+        )
+        .bind(ip)
+        .bind(path)
+        .bind(reason)
+        .bind(timestamp(now))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        // Failures outside the window no longer count towards a lockout
+        sqlx::query("DELETE FROM auth_failures WHERE created_at < ?")
+            .bind(&window_start)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let failures: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM auth_failures WHERE ip_address = ?")
+                .bind(ip)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(StorageError::Sqlx)?;
+        let failures = u32::try_from(failures).unwrap_or(u32::MAX);
+
+        if failures < policy.threshold {
+            tx.commit().await.map_err(StorageError::Sqlx)?;
+            return Ok(None);
+        }
+
+        let locked_until = timestamp(now + policy.lockout);
+        sqlx::query(
+            "INSERT INTO auth_lockouts (ip_address, failure_count, locked_until, created_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(ip_address) DO UPDATE SET
+                failure_count = excluded.failure_count,
+                locked_until = excluded.locked_until,
+                created_at = excluded.created_at",
+        )
+        .bind(ip)
+        .bind(failures)
+        .bind(&locked_until)
+        .bind(timestamp(now))
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        // Start counting afresh once the lockout ends
+        sqlx::query("DELETE FROM auth_failures WHERE ip_address = ?")
+            .bind(ip)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let alert = SecurityAlert {
+            id: Uuid::new_v4().to_string(),
+            kind: SecurityAlertKind::AuthLockout,
+            ip_address: ip.to_string(),
+            failure_count: failures,
+            message: format!(
+                "{} failed authentication attempts from {} within {} minutes; refusing it for {} minutes",
+                failures,
+                ip,
+                policy.window.num_minutes(),
+                policy.lockout.num_minutes()
+            ),
+            locked_until: Some(locked_until),
+            created_at: timestamp(now),
+        };
+        sqlx::query(
+            "INSERT INTO security_alerts (id, kind, ip_address, failure_count, message, locked_until, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&alert.id)
+        .bind(alert.kind.as_str())
+        .bind(&alert.ip_address)
+        .bind(alert.failure_count)
+        .bind(&alert.message)
+        .bind(&alert.locked_until)
+        .bind(&alert.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+        Ok(Some(alert))
+    }
+
+    /// Forget failed attempts from `ip` after it authenticates successfully
+    pub async fn clear_failures(&self, ip: &str) -> Result<(), StorageError> {
+        sqlx::query("DELETE FROM auth_failures WHERE ip_address = ?")
+            .bind(ip)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(())
+    }
+
+    /// List addresses that are currently locked out, most recent first
+    pub async fn list_lockouts(&self) -> Result<Vec<AuthLockout>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT ip_address, failure_count, locked_until, created_at
+             FROM auth_lockouts
+             WHERE locked_until > ?
+             ORDER BY created_at DESC",
+        )
+        .bind(timestamp(Utc::now()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(AuthLockout {
+                    ip_address: row.try_get("ip_address").map_err(StorageError::Sqlx)?,
+                    failure_count: row.try_get("failure_count").map_err(StorageError::Sqlx)?,
+                    locked_until: row.try_get("locked_until").map_err(StorageError::Sqlx)?,
+                    created_at: row.try_get("created_at").map_err(StorageError::Sqlx)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Lift the lockout of `ip`. Returns false if it wasn't locked out.
+    pub async fn unlock(&self, ip: &str) -> Result<bool, StorageError> {
+        let result =
+            sqlx::query("DELETE FROM auth_lockouts WHERE ip_address = ? AND locked_until > ?")
+                .bind(ip)
+                .bind(timestamp(Utc::now()))
+                .execute(&self.pool)
+                .await
+                .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List the most recent security alerts, newest first
+    pub async fn list_alerts(&self, limit: i64) -> Result<Vec<SecurityAlert>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT id, kind, ip_address, failure_count, message, locked_until, created_at
+             FROM security_alerts
+             ORDER BY created_at DESC
+             LIMIT ?",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.into_iter().map(|row| self.row_to_alert(row)).collect()
+    }
+
+    /// Helper to convert database row to SecurityAlert
+    fn row_to_alert(&self, row: sqlx::sqlite::SqliteRow) -> Result<SecurityAlert, StorageError> {
+        let kind: String = row.try_get("kind").map_err(StorageError::Sqlx)?;
+        Ok(SecurityAlert {
+            id: row.try_get("id").map_err(StorageError::Sqlx)?,
+            kind: kind.parse().map_err(StorageError::Validation)?,
+            ip_address: row.try_get("ip_address").map_err(StorageError::Sqlx)?,
+            failure_count: row.try_get("failure_count").map_err(StorageError::Sqlx)?,
+            message: row.try_get("message").map_err(StorageError::Sqlx)?,
+            locked_until: row.try_get("locked_until").map_err(StorageError::Sqlx)?,
+            created_at: row.try_get("created_at").map_err(StorageError::Sqlx)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_detector(settings: &[(&str, &str)]) -> IntrusionDetector {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        for table in [
+            "CREATE TABLE system_settings (key TEXT PRIMARY KEY, value TEXT NOT NULL)",
+            "CREATE TABLE auth_failures (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                ip_address TEXT NOT NULL,
+                path TEXT NOT NULL,
+                reason TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            "CREATE TABLE auth_lockouts (
+                ip_address TEXT PRIMARY KEY,
+                failure_count INTEGER NOT NULL,
+                locked_until TEXT NOT NULL,
+                created_at TEXT NOT NULL
+            )",
+            "CREATE TABLE security_alerts (
+                id TEXT PRIMARY KEY,
+                kind TEXT NOT NULL,
+                ip_address TEXT NOT NULL,
+                failure_count INTEGER NOT NULL,
+                message TEXT NOT NULL,
+                locked_until TEXT,
+                created_at TEXT NOT NULL
+            )",
+        ] {
+            sqlx::query(table).execute(&pool).await.unwrap();
+        }
+        for (key, value) in settings {
+            sqlx::query("INSERT INTO system_settings (key, value) VALUES (?, ?)")
+                .bind(key)
+                .bind(value)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        IntrusionDetector::new(pool)
+    }
+
+    #[tokio::test]
+    async fn test_repeated_failures_lock_out_address() {
+        let detector = setup_detector(&[("auth_lockout_threshold", "3")]).await;
+
+        for _ in 0..2 {
+            let alert = detector
+                .record_failure("203.0.113.7", "/api/projects", "invalid token")
+                .await
+                .unwrap();
+            assert!(alert.is_none());
+        }
+        assert!(detector
+            .locked_until("203.0.113.7")
+            .await
+            .unwrap()
+            .is_none());
+
+        let alert = detector
+            .record_failure("203.0.113.7", "/api/projects", "invalid token")
+            .await
+            .unwrap()
+            .expect("third failure should lock the address out");
+        assert_eq!(alert.kind, SecurityAlertKind::AuthLockout);
+        assert_eq!(alert.failure_count, 3);
+
+        let locked_until = detector.locked_until("203.0.113.7").await.unwrap().unwrap();
+        assert!(locked_until > Utc::now());
+        // Other addresses are unaffected
+        assert!(detector
+            .locked_until("203.0.113.8")
+            .await
+            .unwrap()
+            .is_none());
+
+        let alerts = detector.list_alerts(10).await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].ip_address, "203.0.113.7");
+        assert_eq!(detector.list_lockouts().await.unwrap().len(), 1);
+
+        assert!(detector.unlock("203.0.113.7").await.unwrap());
+        assert!(detector
+            .locked_until("203.0.113.7")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(!detector.unlock("203.0.113.7").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_successful_login_clears_failures() {
+        let detector = setup_detector(&[("auth_lockout_threshold", "2")]).await;
+
+        detector
+            .record_failure("198.51.100.4", "/api/projects", "missing token")
+            .await
+            .unwrap();
+        detector.clear_failures("198.51.100.4").await.unwrap();
+        let alert = detector
+            .record_failure("198.51.100.4", "/api/projects", "missing token")
+            .await
+            .unwrap();
+        assert!(alert.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_disabled_policy_records_nothing() {
+        let detector = setup_detector(&[
+            ("auth_lockout_enabled", "false"),
+            ("auth_lockout_threshold", "1"),
+        ])
+        .await;
+
+        let alert = detector
+            .record_failure("198.51.100.4", "/api/projects", "invalid token")
+            .await
+            .unwrap();
+        assert!(alert.is_none());
+        assert!(detector
+            .locked_until("198.51.100.4")
+            .await
+            .unwrap()
+            .is_none());
+    }
+}
@@ -0,0 +1,149 @@
+// ABOUTME: Type definitions for authentication intrusion detection
+// ABOUTME: Lockout policy parsed from security settings and the alerts raised by lockouts
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+pub const LOCKOUT_ENABLED_SETTING: &str = "auth_lockout_enabled";
+pub const LOCKOUT_THRESHOLD_SETTING: &str = "auth_lockout_threshold";
+pub const LOCKOUT_WINDOW_SETTING: &str = "auth_lockout_window_minutes";
+pub const LOCKOUT_DURATION_SETTING: &str = "auth_lockout_duration_minutes";
+
+/// When repeated authentication failures lock out an IP address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    pub enabled: bool,
+    /// Failures within `window` that trigger a lockout
+    pub threshold: u32,
+    pub window: Duration,
+    /// How long a locked out address is refused
+    pub lockout: Duration,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: 5,
+            window: Duration::minutes(10),
+            lockout: Duration::minutes(15),
+        }
+    }
+}
+
+impl LockoutPolicy {
+    /// Build a policy from `(key, value)` setting pairs. Invalid values are logged and
+    /// the default kept.
+    pub fn from_settings<'a>(settings: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut policy = Self::default();
+
+        for (key, value) in settings {
+            let value = value.trim();
+            let minutes = || value.parse::<u32>().ok().filter(|m| *m > 0);
+            match key {
+                LOCKOUT_ENABLED_SETTING => policy.enabled = value == "true",
+                LOCKOUT_THRESHOLD_SETTING => match value.parse::<u32>() {
+                    Ok(threshold) if threshold > 0 => policy.threshold = threshold,
+                    _ => warn!("Ignoring invalid {} value: {}", key, value),
+                },
+                LOCKOUT_WINDOW_SETTING => match minutes() {
+                    Some(m) => policy.window = Duration::minutes(i64::from(m)),
+                    None => warn!("Ignoring invalid {} value: {}", key, value),
+                },
+                LOCKOUT_DURATION_SETTING => match minutes() {
+                    Some(m) => policy.lockout = Duration::minutes(i64::from(m)),
+                    None => warn!("Ignoring invalid {} value: {}", key, value),
+                },
+                _ => {}
+            }
+        }
+
+        policy
+    }
+}
+
+/// What raised a security alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecurityAlertKind {
+    /// An IP address was locked out after repeated authentication failures
+    AuthLockout,
+}
+
+impl SecurityAlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SecurityAlertKind::AuthLockout => "auth_lockout",
+        }
+    }
+}
+
+impl std::str::FromStr for SecurityAlertKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auth_lockout" => Ok(SecurityAlertKind::AuthLockout),
+            _ => Err(format!("Unknown security alert kind: {}", s)),
+        }
+    }
+}
+
+/// An IP address currently refused after repeated authentication failures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthLockout {
+    pub ip_address: String,
+    pub failure_count: u32,
+    pub locked_until: String,
+    pub created_at: String,
+}
+
+/// An entry in the security alert feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityAlert {
+    pub id: String,
+    pub kind: SecurityAlertKind,
+    pub ip_address: String,
+    /// Failed attempts that led to the alert
+    pub failure_count: u32,
+    pub message: String,
+    pub locked_until: Option<String>,
+    pub created_at: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_from_settings() {
+        let policy = LockoutPolicy::from_settings([
+            (LOCKOUT_ENABLED_SETTING, "true"),
+            (LOCKOUT_THRESHOLD_SETTING, "3"),
+            (LOCKOUT_WINDOW_SETTING, "5"),
+            (LOCKOUT_DURATION_SETTING, "60"),
+        ]);
+        assert_eq!(
+            policy,
+            LockoutPolicy {
+                enabled: true,
+                threshold: 3,
+                window: Duration::minutes(5),
+                lockout: Duration::minutes(60),
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_settings_keep_defaults() {
+        let policy = LockoutPolicy::from_settings([
+            (LOCKOUT_ENABLED_SETTING, "false"),
+            (LOCKOUT_THRESHOLD_SETTING, "0"),
+            (LOCKOUT_WINDOW_SETTING, "soon"),
+        ]);
+        assert!(!policy.enabled);
+        assert_eq!(policy.threshold, 5);
+        assert_eq!(policy.window, Duration::minutes(10));
+    }
+}
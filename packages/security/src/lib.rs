@@ -1,12 +1,18 @@
 // ABOUTME: Security, authentication, and encryption functionality for Orkee
-// ABOUTME: Provides API key encryption, token management, and user authentication
+// ABOUTME: Provides API key encryption, token management, intrusion detection, and user authentication
 
 pub mod api_tokens;
 pub mod encryption;
+pub mod intrusion;
+pub mod secrets;
 pub mod users;
 
 // Re-export main types for convenience
 pub use api_tokens::{ApiToken, TokenGeneration, TokenStorage};
 pub use encryption::{ApiKeyEncryption, EncryptionError};
+pub use intrusion::{
+    AuthLockout, IntrusionDetector, LockoutPolicy, SecurityAlert, SecurityAlertKind,
+};
+pub use secrets::{ProjectSecret, SecretStorage};
 pub use users::storage::UserStorage;
-pub use users::{MaskedUser, User, UserUpdateInput};
+pub use users::{MaskedUser, User, UserCreateInput, UserUpdateInput};
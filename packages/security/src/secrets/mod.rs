@@ -0,0 +1,8 @@
+// ABOUTME: Per-project secret store module
+// ABOUTME: Encrypted environment secrets injected into preview servers and sandboxes
+
+pub mod storage;
+pub mod types;
+
+pub use storage::SecretStorage;
+pub use types::{validate_secret_name, ProjectSecret};
@@ -0,0 +1,255 @@
+// ABOUTME: Storage operations for per-project secrets
+// ABOUTME: Encrypts values before writing and decrypts them only to build launch environments
+
+use std::collections::HashMap;
+
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+use uuid::Uuid;
+
+use super::types::{validate_secret_name, ProjectSecret, MAX_SECRET_VALUE_LEN};
+use crate::encryption::ApiKeyEncryption;
+use orkee_storage::StorageError;
+
+pub struct SecretStorage {
+    pool: SqlitePool,
+    encryption: ApiKeyEncryption,
+}
+
+impl SecretStorage {
+    pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
+        let encryption = ApiKeyEncryption::new().map_err(|e| {
+            tracing::error!("Failed to initialize secret encryption: {}", e);
+            StorageError::Encryption(format!("Failed to initialize encryption: {}", e))
+        })?;
+        Ok(Self { pool, encryption })
+    }
+
+    /// List a project's secrets without their values
+    pub async fn list_secrets(&self, project_id: &str) -> Result<Vec<ProjectSecret>, StorageError> {
+        let rows = sqlx::query(
+            "SELECT id, project_id, name, created_at, updated_at
+             FROM project_secrets
+             WHERE project_id = ?
+             ORDER BY name",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.into_iter()
+            .map(|row| self.row_to_secret(row))
+            .collect()
+    }
+
+    /// Create or replace a secret
+    pub async fn set_secret(
+        &self,
+        project_id: &str,
+        name: &str,
+        value: &str,
+    ) -> Result<ProjectSecret, StorageError> {
+        validate_secret_name(name)?;
+        if value.is_empty() {
+            return Err(StorageError::Validation(
+                "Secret value cannot be empty".to_string(),
+            ));
+        }
+        if value.len() > MAX_SECRET_VALUE_LEN {
+            return Err(StorageError::Validation(format!(
+                "Secret value exceeds {} bytes",
+                MAX_SECRET_VALUE_LEN
+            )));
+        }
+
+        debug!("Setting secret {} for project {}", name, project_id);
+
+        let encrypted_value = self
+            .encryption
+            .encrypt(value)
+            .map_err(|e| StorageError::Encryption(e.to_string()))?;
+
+        sqlx::query(
+            "INSERT INTO project_secrets (id, project_id, name, encrypted_value)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(project_id, name) DO UPDATE SET
+                 encrypted_value = excluded.encrypted_value,
+                 updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(project_id)
+        .bind(name)
+        .bind(&encrypted_value)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let row = sqlx::query(
+            "SELECT id, project_id, name, created_at, updated_at
+             FROM project_secrets
+             WHERE project_id = ? AND name = ?",
+        )
+        .bind(project_id)
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.row_to_secret(row)
+    }
+
+    /// Delete a secret. Returns false if the project has no secret by that name.
+    pub async fn delete_secret(&self, project_id: &str, name: &str) -> Result<bool, StorageError> {
+        let result = sqlx::query("DELETE FROM project_secrets WHERE project_id = ? AND name = ?")
+            .bind(project_id)
+            .bind(name)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Decrypt a project's secrets into environment variables for launching a
+    /// preview server or sandbox. Never return this from an API endpoint.
+    pub async fn environment(
+        &self,
+        project_id: &str,
+    ) -> Result<HashMap<String, String>, StorageError> {
+        let rows =
+            sqlx::query("SELECT name, encrypted_value FROM project_secrets WHERE project_id = ?")
+                .bind(project_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(StorageError::Sqlx)?;
+
+        rows.into_iter()
+            .map(|row| {
+                let name: String = row.try_get("name").map_err(StorageError::Sqlx)?;
+                let encrypted: String =
+                    row.try_get("encrypted_value").map_err(StorageError::Sqlx)?;
+                let value = self.encryption.decrypt(&encrypted).map_err(|e| {
+                    StorageError::Encryption(format!("Failed to decrypt secret {}: {}", name, e))
+                })?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+    /// Helper to convert database row to ProjectSecret
+    fn row_to_secret(&self, row: sqlx::sqlite::SqliteRow) -> Result<ProjectSecret, StorageError> {
+        Ok(ProjectSecret {
+            id: row.try_get("id").map_err(StorageError::Sqlx)?,
+            project_id: row.try_get("project_id").map_err(StorageError::Sqlx)?,
+            name: row.try_get("name").map_err(StorageError::Sqlx)?,
+            created_at: row.try_get("created_at").map_err(StorageError::Sqlx)?,
+            updated_at: row.try_get("updated_at").map_err(StorageError::Sqlx)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn setup_storage() -> (SecretStorage, SqlitePool) {
+        let pool = SqlitePool::connect(":memory:").await.unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE project_secrets (
+                id TEXT PRIMARY KEY,
+                project_id TEXT NOT NULL,
+                name TEXT NOT NULL,
+                encrypted_value TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(project_id, name)
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        (SecretStorage::new(pool.clone()).unwrap(), pool)
+    }
+
+    #[tokio::test]
+    async fn test_values_are_encrypted_at_rest() {
+        let (storage, pool) = setup_storage().await;
+
+        storage
+            .set_secret("project-1", "DATABASE_URL", "postgres://user:pw@db/app")
+            .await
+            .unwrap();
+
+        let stored: String = sqlx::query_scalar(
+            "SELECT encrypted_value FROM project_secrets WHERE name = 'DATABASE_URL'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert!(!stored.contains("postgres://"));
+        assert!(ApiKeyEncryption::is_encrypted(&stored));
+
+        let env = storage.environment("project-1").await.unwrap();
+        assert_eq!(env["DATABASE_URL"], "postgres://user:pw@db/app");
+    }
+
+    #[tokio::test]
+    async fn test_set_replaces_existing_value() {
+        let (storage, _pool) = setup_storage().await;
+
+        let first = storage
+            .set_secret("project-1", "TOKEN", "old")
+            .await
+            .unwrap();
+        let second = storage
+            .set_secret("project-1", "TOKEN", "new")
+            .await
+            .unwrap();
+        assert_eq!(first.id, second.id);
+
+        let secrets = storage.list_secrets("project-1").await.unwrap();
+        assert_eq!(secrets.len(), 1);
+        assert_eq!(
+            storage.environment("project-1").await.unwrap()["TOKEN"],
+            "new"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_secrets_are_scoped_to_project() {
+        let (storage, _pool) = setup_storage().await;
+
+        storage
+            .set_secret("project-1", "TOKEN", "one")
+            .await
+            .unwrap();
+        storage
+            .set_secret("project-2", "TOKEN", "two")
+            .await
+            .unwrap();
+
+        assert!(storage.delete_secret("project-1", "TOKEN").await.unwrap());
+        assert!(!storage.delete_secret("project-1", "TOKEN").await.unwrap());
+        assert!(storage.environment("project-1").await.unwrap().is_empty());
+        assert_eq!(
+            storage.environment("project-2").await.unwrap()["TOKEN"],
+            "two"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_secrets_are_rejected() {
+        let (storage, _pool) = setup_storage().await;
+
+        assert!(storage
+            .set_secret("project-1", "BAD-NAME", "x")
+            .await
+            .is_err());
+        assert!(storage.set_secret("project-1", "EMPTY", "").await.is_err());
+    }
+}
@@ -0,0 +1,53 @@
+// ABOUTME: Type definitions for per-project secrets
+// ABOUTME: Secret metadata and environment variable name validation
+
+use orkee_storage::StorageError;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a secret value in bytes
+pub const MAX_SECRET_VALUE_LEN: usize = 64 * 1024;
+
+/// Secret metadata. The value is deliberately absent: plaintext only leaves
+/// storage as launch environment for preview servers and sandboxes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSecret {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Check that a secret name is a portable environment variable name
+/// (letters, digits and underscores, not starting with a digit)
+pub fn validate_secret_name(name: &str) -> Result<(), StorageError> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::Validation(format!(
+            "Invalid secret name '{}': use letters, digits and underscores, not starting with a digit",
+            name
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_secret_name() {
+        assert!(validate_secret_name("DATABASE_URL").is_ok());
+        assert!(validate_secret_name("_private").is_ok());
+        assert!(validate_secret_name("").is_err());
+        assert!(validate_secret_name("1PASSWORD").is_err());
+        assert!(validate_secret_name("API-KEY").is_err());
+        assert!(validate_secret_name("KEY=VALUE").is_err());
+    }
+}
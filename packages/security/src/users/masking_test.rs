@@ -22,6 +22,7 @@ mod tests {
             ai_gateway_url: Some("https://gateway.example.com".to_string()),
             ai_gateway_key: Some("gateway-super-secret-key-qwerty".to_string()),
             preferences: None,
+            is_current: true,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             last_login_at: None,
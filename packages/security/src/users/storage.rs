@@ -6,7 +6,7 @@ use std::env;
 use sqlx::{QueryBuilder, Row, SqlitePool};
 use tracing::debug;
 
-use super::types::{User, UserUpdateInput};
+use super::types::{User, UserCreateInput, UserUpdateInput};
 use crate::encryption::ApiKeyEncryption;
 use orkee_storage::StorageError;
 
@@ -40,10 +40,97 @@ impl UserStorage {
         self.row_to_user(&row)
     }
 
+    /// Get the active user, falling back to the default user if none is marked current
     pub async fn get_current_user(&self) -> Result<User, StorageError> {
-        // For now, always return the default user
-        // In future, this could be based on auth context
-        self.get_user("default-user").await
+        let row = sqlx::query("SELECT * FROM users WHERE is_current = 1")
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        match row {
+            Some(row) => self.row_to_user(&row),
+            None => self.get_user("default-user").await,
+        }
+    }
+
+    pub async fn list_users(&self) -> Result<Vec<User>, StorageError> {
+        debug!("Listing users");
+
+        let rows = sqlx::query("SELECT * FROM users ORDER BY created_at ASC, name ASC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(|row| self.row_to_user(row)).collect()
+    }
+
+    pub async fn create_user(&self, input: UserCreateInput) -> Result<User, StorageError> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        debug!("Creating user: {} ({})", input.email, user_id);
+
+        sqlx::query(
+            r#"
+            INSERT INTO users (
+                id, email, name, avatar_url, default_agent_id, theme, created_at, updated_at
+            )
+            VALUES (?, ?, ?, ?, ?, COALESCE(?, 'system'), datetime('now', 'utc'), datetime('now', 'utc'))
+            "#,
+        )
+        .bind(&user_id)
+        .bind(&input.email)
+        .bind(&input.name)
+        .bind(&input.avatar_url)
+        .bind(&input.default_agent_id)
+        .bind(&input.theme)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_user(&user_id).await
+    }
+
+    /// Make `user_id` the active user. Returns `RowNotFound` if the user doesn't exist.
+    pub async fn switch_current_user(&self, user_id: &str) -> Result<User, StorageError> {
+        debug!("Switching current user to: {}", user_id);
+
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        sqlx::query("SELECT id FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        // Clear the old flag first so the unique index on is_current is never violated
+        sqlx::query("UPDATE users SET is_current = 0 WHERE is_current = 1 AND id != ?")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            r#"
+            UPDATE users
+            SET is_current = 1, last_login_at = datetime('now', 'utc')
+            WHERE id = ?
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let row = sqlx::query("SELECT * FROM users WHERE id = ?")
+            .bind(user_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let user = self.row_to_user(&row)?;
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        Ok(user)
     }
 
     pub async fn set_default_agent(
@@ -369,6 +456,7 @@ impl UserStorage {
             preferences: row
                 .try_get::<Option<String>, _>("preferences")?
                 .and_then(|s| serde_json::from_str(&s).ok()),
+            is_current: row.try_get::<i64, _>("is_current")? != 0,
             created_at: row.try_get("created_at")?,
             updated_at: row.try_get("updated_at")?,
             last_login_at: row.try_get("last_login_at")?,
@@ -3,7 +3,7 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::{ApiKeyEncryption, UserStorage, UserUpdateInput};
+    use crate::{ApiKeyEncryption, UserCreateInput, UserStorage, UserUpdateInput};
     use sqlx::SqlitePool;
 
     async fn setup_test_db() -> SqlitePool {
@@ -26,6 +26,7 @@ mod tests {
                 ai_gateway_url TEXT,
                 ai_gateway_key TEXT,
                 preferences TEXT,
+                is_current INTEGER NOT NULL DEFAULT 0,
                 created_at TEXT NOT NULL DEFAULT (datetime('now', 'utc')),
                 updated_at TEXT NOT NULL DEFAULT (datetime('now', 'utc')),
                 last_login_at TEXT
@@ -36,6 +37,13 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            "CREATE UNIQUE INDEX idx_users_current ON users(is_current) WHERE is_current = 1",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         sqlx::query(
             r#"
             INSERT INTO users (id, email, name)
@@ -423,4 +431,66 @@ mod tests {
         assert!(user.xai_api_key.is_none());
         assert!(user.ai_gateway_key.is_none());
     }
+
+    #[tokio::test]
+    async fn test_create_and_list_users() {
+        let pool = setup_test_db().await;
+        let storage = UserStorage::new(pool).unwrap();
+
+        let created = storage
+            .create_user(UserCreateInput {
+                email: "second@example.com".to_string(),
+                name: "Second User".to_string(),
+                avatar_url: None,
+                default_agent_id: Some("claude-code".to_string()),
+                theme: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(created.default_agent_id.as_deref(), Some("claude-code"));
+        assert_eq!(created.theme.as_deref(), Some("system"));
+        assert!(!created.is_current);
+
+        let users = storage.list_users().await.unwrap();
+        let ids: Vec<_> = users.iter().map(|u| u.id.as_str()).collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&"test-user"));
+        assert!(ids.contains(&created.id.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_switch_current_user() {
+        let pool = setup_test_db().await;
+        let storage = UserStorage::new(pool).unwrap();
+
+        let second = storage
+            .create_user(UserCreateInput {
+                email: "second@example.com".to_string(),
+                name: "Second User".to_string(),
+                avatar_url: None,
+                default_agent_id: None,
+                theme: Some("dark".to_string()),
+            })
+            .await
+            .unwrap();
+
+        storage.switch_current_user("test-user").await.unwrap();
+        assert_eq!(storage.get_current_user().await.unwrap().id, "test-user");
+
+        let switched = storage.switch_current_user(&second.id).await.unwrap();
+        assert!(switched.is_current);
+        assert!(switched.last_login_at.is_some());
+
+        let current = storage.get_current_user().await.unwrap();
+        assert_eq!(current.id, second.id);
+        assert_eq!(current.theme.as_deref(), Some("dark"));
+        assert!(!storage.get_user("test-user").await.unwrap().is_current);
+
+        let missing = storage.switch_current_user("no-such-user").await;
+        assert!(matches!(
+            missing,
+            Err(orkee_storage::StorageError::Sqlx(sqlx::Error::RowNotFound))
+        ));
+        assert_eq!(storage.get_current_user().await.unwrap().id, second.id);
+    }
 }
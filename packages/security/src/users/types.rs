@@ -20,11 +20,22 @@ pub struct User {
     pub ai_gateway_url: Option<String>,
     pub ai_gateway_key: Option<String>,
     pub preferences: Option<serde_json::Value>,
+    /// Whether this is the active user that requests act on behalf of
+    pub is_current: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_login_at: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserCreateInput {
+    pub email: String,
+    pub name: String,
+    pub avatar_url: Option<String>,
+    pub default_agent_id: Option<String>,
+    pub theme: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserUpdateInput {
     pub openai_api_key: Option<String>,
@@ -52,6 +63,7 @@ pub struct MaskedUser {
     pub has_ai_gateway_key: bool,
     pub ai_gateway_url: Option<String>,
     pub preferences: Option<serde_json::Value>,
+    pub is_current: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub last_login_at: Option<DateTime<Utc>>,
@@ -74,6 +86,7 @@ impl From<User> for MaskedUser {
             has_ai_gateway_key: user.ai_gateway_key.is_some(),
             ai_gateway_url: user.ai_gateway_url,
             preferences: user.preferences,
+            is_current: user.is_current,
             created_at: user.created_at,
             updated_at: user.updated_at,
             last_login_at: user.last_login_at,
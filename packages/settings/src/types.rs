@@ -49,6 +49,11 @@ pub enum SettingCategory {
     RateLimiting,
     Cloud,
     Telemetry,
+    Notifications,
+    Discovery,
+    Preview,
+    Tui,
+    Editor,
     Advanced,
 }
 
@@ -61,6 +66,11 @@ impl SettingCategory {
             SettingCategory::RateLimiting => "rate_limiting",
             SettingCategory::Cloud => "cloud",
             SettingCategory::Telemetry => "telemetry",
+            SettingCategory::Notifications => "notifications",
+            SettingCategory::Discovery => "discovery",
+            SettingCategory::Preview => "preview",
+            SettingCategory::Tui => "tui",
+            SettingCategory::Editor => "editor",
             SettingCategory::Advanced => "advanced",
         }
     }
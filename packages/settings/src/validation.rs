@@ -30,6 +30,21 @@ pub enum ValidationError {
 
     #[error("Invalid URL: {0}")]
     InvalidUrl(String),
+
+    #[error("Invalid port range: {0}. {1}")]
+    InvalidPortRange(String, String),
+
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+
+    #[error("Invalid identity mapping: {0}. Expected CN=token-id")]
+    InvalidIdentityMapping(String),
+
+    #[error("Invalid pattern: {0}. {1}")]
+    InvalidPattern(String, String),
+
+    #[error("Invalid tag: {0}. Tags may only contain letters, numbers, '-' and '_'")]
+    InvalidTag(String),
 }
 
 impl From<ValidationError> for StorageError {
@@ -38,14 +53,40 @@ impl From<ValidationError> for StorageError {
     }
 }
 
+/// Editor IDs supported by the projects crate's editor integrations
+const EDITOR_IDS: &[&str] = &[
+    "vscode",
+    "cursor",
+    "zed",
+    "intellij",
+    "webstorm",
+    "pycharm",
+    "goland",
+    "rustrover",
+    "rubymine",
+    "phpstorm",
+    "clion",
+    "rider",
+];
+
+/// Settings where an empty value is meaningful (disables the feature or removes a restriction)
+const EMPTY_ALLOWED_KEYS: &[&str] = &[
+    "notification_webhook_url",
+    "discovery_scan_roots",
+    "discovery_ignore_patterns",
+    "tls_client_ca_path",
+    "tls_client_identities",
+    "project_name_pattern",
+    "project_required_tags",
+];
+
 /// Validate a setting value based on its key and data type
 pub fn validate_setting_value(
     key: &str,
     value: &str,
     data_type: &str,
 ) -> Result<(), ValidationError> {
-    // Check for empty value
-    if value.is_empty() {
+    if value.is_empty() && !EMPTY_ALLOWED_KEYS.contains(&key) {
         return Err(ValidationError::EmptyValue);
     }
 
@@ -59,6 +100,9 @@ pub fn validate_setting_value(
         "string" => {
             // String type passes basic validation, specific rules below
         }
+        "json" => {
+            validate_json(value)?;
+        }
         _ => {
             // Unknown data type, but allow it (forward compatibility)
         }
@@ -74,11 +118,15 @@ pub fn validate_setting_value(
         | "cloud_enabled"
         | "cors_allow_any_localhost"
         | "tls_enabled"
+        | "tls_client_auth_enabled"
         | "auto_generate_cert"
         | "rate_limit_enabled"
         | "security_headers_enabled"
         | "enable_hsts"
         | "enable_request_id"
+        | "auth_lockout_enabled"
+        | "plugins_enabled"
+        | "ai_debug_logging_enabled"
         | "telemetry_enabled" => {
             // Already validated as boolean above
         }
@@ -89,15 +137,28 @@ pub fn validate_setting_value(
         }
 
         // Path settings
-        "tls_cert_path" | "tls_key_path" => {
+        "tls_cert_path" | "tls_key_path" | "tls_client_ca_path" => {
             validate_path(value)?;
         }
 
         // Path list settings
-        "allowed_browse_paths" => {
+        "allowed_browse_paths" | "discovery_scan_roots" => {
             validate_path_list(value)?;
         }
 
+        // Project validation rules
+        "project_name_pattern" => {
+            validate_pattern(value)?;
+        }
+        "project_required_tags" => {
+            validate_tag_list(value)?;
+        }
+
+        // Discovery ports (comma-separated ports or ranges, non-privileged only)
+        "discovery_port_ranges" => {
+            validate_port_ranges(value)?;
+        }
+
         // Rate limit settings (must be >= 1, max 10,000 to prevent misconfiguration)
         "rate_limit_health_rpm"
         | "rate_limit_browse_rpm"
@@ -114,6 +175,61 @@ pub fn validate_setting_value(
             validate_url(value)?;
         }
 
+        "notification_webhook_url" if !value.is_empty() => {
+            validate_url(value)?;
+        }
+
+        // Notification channel lists (comma-separated, or "none")
+        "notify_execution_finished"
+        | "notify_sync_failed"
+        | "notify_budget_threshold"
+        | "notify_server_crash"
+        | "notify_reauth_required"
+        | "notify_auth_lockout"
+        | "notify_certificate_expiring" => {
+            validate_enum_list(value, &["desktop", "webhook", "tui", "none"])?;
+        }
+
+        // Failed authentication attempts before an IP address is locked out
+        "auth_lockout_threshold" => {
+            validate_integer(value, Some(1), Some(1_000))?;
+        }
+
+        // Lockout window and duration (at most a week)
+        "auth_lockout_window_minutes" | "auth_lockout_duration_minutes" => {
+            validate_integer(value, Some(1), Some(10_080))?;
+        }
+
+        // Hours AI proxy debug captures are kept (at most a week)
+        "ai_debug_log_retention_hours" => {
+            validate_integer(value, Some(1), Some(168))?;
+        }
+
+        // Monthly AI budget in USD (0 disables the budget notification)
+        "notification_budget_monthly_usd" => {
+            validate_integer(value, Some(0), Some(1_000_000))?;
+        }
+
+        // Client certificate CN to API token mapping (comma-separated CN=token-id)
+        "tls_client_identities" => {
+            validate_identity_map(value)?;
+        }
+
+        // Editor used to open projects; mirrors the projects crate's editor registry
+        "preferred_editor" => {
+            validate_enum(value, EDITOR_IDS)?;
+        }
+
+        // Per-project editor overrides stored as a JSON object of project ID -> editor ID
+        "editor_project_overrides" => {
+            validate_editor_overrides(value)?;
+        }
+
+        // TUI macros are stored as a JSON list
+        "tui_macros" => {
+            validate_json_array(value)?;
+        }
+
         // Unknown setting key - allow it (forward compatibility)
         _ => {}
     }
@@ -178,6 +294,14 @@ fn validate_enum(value: &str, allowed: &[&str]) -> Result<(), ValidationError> {
     }
 }
 
+/// Validate comma-separated list of enum values
+fn validate_enum_list(value: &str, allowed: &[&str]) -> Result<(), ValidationError> {
+    for item in value.split(',') {
+        validate_enum(item.trim(), allowed)?;
+    }
+    Ok(())
+}
+
 /// Validate file path (basic checks, allows tilde expansion)
 fn validate_path(value: &str) -> Result<(), ValidationError> {
     // Allow empty path (will be handled by application logic)
@@ -244,6 +368,108 @@ fn validate_path_list(value: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+/// Validate that a value is well-formed JSON
+fn validate_json(value: &str) -> Result<(), ValidationError> {
+    serde_json::from_str::<serde_json::Value>(value)
+        .map(|_| ())
+        .map_err(|e| ValidationError::InvalidJson(e.to_string()))
+}
+
+/// Validate that a value is a JSON array
+fn validate_json_array(value: &str) -> Result<(), ValidationError> {
+    match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Array(_)) => Ok(()),
+        Ok(_) => Err(ValidationError::InvalidJson("Must be a list".to_string())),
+        Err(e) => Err(ValidationError::InvalidJson(e.to_string())),
+    }
+}
+
+/// Validate a JSON object mapping project IDs to supported editor IDs
+fn validate_editor_overrides(value: &str) -> Result<(), ValidationError> {
+    let overrides = match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(serde_json::Value::Object(overrides)) => overrides,
+        Ok(_) => {
+            return Err(ValidationError::InvalidJson(
+                "Must be an object".to_string(),
+            ))
+        }
+        Err(e) => return Err(ValidationError::InvalidJson(e.to_string())),
+    };
+    for editor in overrides.values() {
+        match editor.as_str() {
+            Some(editor) => validate_enum(editor, EDITOR_IDS)?,
+            None => {
+                return Err(ValidationError::InvalidJson(
+                    "Editor IDs must be strings".to_string(),
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validate comma-separated ports or inclusive port ranges such as `3000-3003,5173`
+fn validate_port_ranges(value: &str) -> Result<(), ValidationError> {
+    for item in value.split(',').map(str::trim) {
+        let (start, end) = item.split_once('-').unwrap_or((item, item));
+        let parse = |port: &str| {
+            port.trim()
+                .parse::<u16>()
+                .ok()
+                .filter(|port| *port >= 1024)
+                .ok_or_else(|| {
+                    ValidationError::InvalidPortRange(
+                        item.to_string(),
+                        "Ports must be between 1024 and 65535".to_string(),
+                    )
+                })
+        };
+        if parse(start)? > parse(end)? {
+            return Err(ValidationError::InvalidPortRange(
+                item.to_string(),
+                "Range start must not exceed its end".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Validate comma-separated `CN=token-id` pairs
+fn validate_identity_map(value: &str) -> Result<(), ValidationError> {
+    for entry in value.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let valid = entry
+            .rsplit_once('=')
+            .is_some_and(|(cn, token_id)| !cn.trim().is_empty() && !token_id.trim().is_empty());
+        if !valid {
+            return Err(ValidationError::InvalidIdentityMapping(entry.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Validate a regular expression
+fn validate_pattern(value: &str) -> Result<(), ValidationError> {
+    if value.trim().is_empty() {
+        return Ok(());
+    }
+    regex::Regex::new(value.trim())
+        .map(|_| ())
+        .map_err(|e| ValidationError::InvalidPattern(value.to_string(), e.to_string()))
+}
+
+/// Validate comma-separated tag names
+fn validate_tag_list(value: &str) -> Result<(), ValidationError> {
+    for tag in value.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ValidationError::InvalidTag(tag.to_string()));
+        }
+    }
+    Ok(())
+}
+
 /// Validate URL (basic check)
 fn validate_url(value: &str) -> Result<(), ValidationError> {
     // Basic URL validation - must start with http:// or https://
@@ -378,6 +604,18 @@ mod tests {
         assert!(validate_setting_value("browse_sandbox_mode", "invalid", "string").is_err());
     }
 
+    #[test]
+    fn test_validate_setting_value_project_rules() {
+        assert!(validate_setting_value("project_name_pattern", "", "string").is_ok());
+        assert!(validate_setting_value("project_name_pattern", "^[a-z-]+$", "string").is_ok());
+        assert!(validate_setting_value("project_name_pattern", "(unclosed", "string").is_err());
+        assert!(validate_setting_value("project_required_tags", "", "string").is_ok());
+        assert!(validate_setting_value("project_required_tags", "owner, tier_1", "string").is_ok());
+        assert!(
+            validate_setting_value("project_required_tags", "owner, bad tag", "string").is_err()
+        );
+    }
+
     #[test]
     fn test_validate_setting_value_rate_limit() {
         assert!(validate_setting_value("rate_limit_health_rpm", "60", "integer").is_ok());
@@ -396,4 +634,100 @@ mod tests {
         assert!(validate_setting_value("cloud_api_url", "https://api.orkee.ai", "string").is_ok());
         assert!(validate_setting_value("cloud_api_url", "invalid-url", "string").is_err());
     }
+
+    #[test]
+    fn test_validate_setting_value_notifications() {
+        assert!(validate_setting_value("notify_sync_failed", "desktop,webhook", "string").is_ok());
+        assert!(validate_setting_value("notify_sync_failed", "none", "string").is_ok());
+        assert!(validate_setting_value("notify_sync_failed", "desktop,email", "string").is_err());
+        assert!(validate_setting_value("notification_webhook_url", "", "string").is_ok());
+        assert!(validate_setting_value("notification_webhook_url", "hooks", "string").is_err());
+        assert!(
+            validate_setting_value("notification_budget_monthly_usd", "-5", "integer").is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_setting_value_discovery() {
+        assert!(
+            validate_setting_value("discovery_port_ranges", "3000-3003,5173", "string").is_ok()
+        );
+        assert!(validate_setting_value("discovery_port_ranges", "80", "string").is_err());
+        assert!(validate_setting_value("discovery_port_ranges", "4000-3000", "string").is_err());
+        assert!(validate_setting_value("discovery_port_ranges", "3000,", "string").is_err());
+        assert!(validate_setting_value("discovery_port_ranges", "", "string").is_err());
+        assert!(validate_setting_value("discovery_scan_roots", "", "string").is_ok());
+        assert!(validate_setting_value("discovery_scan_roots", "~/code,../etc", "string").is_err());
+        assert!(validate_setting_value("discovery_ignore_patterns", "", "string").is_ok());
+    }
+
+    #[test]
+    fn test_validate_setting_value_client_certificates() {
+        assert!(validate_setting_value("tls_client_auth_enabled", "true", "boolean").is_ok());
+        assert!(validate_setting_value("tls_client_ca_path", "", "string").is_ok());
+        assert!(validate_setting_value("tls_client_ca_path", "../ca.pem", "string").is_err());
+        assert!(validate_setting_value(
+            "tls_client_identities",
+            "laptop=tok-1, ci-runner=tok-2",
+            "string"
+        )
+        .is_ok());
+        assert!(validate_setting_value("tls_client_identities", "", "string").is_ok());
+        assert!(validate_setting_value("tls_client_identities", "laptop", "string").is_err());
+        assert!(validate_setting_value("tls_client_identities", "laptop=", "string").is_err());
+    }
+
+    #[test]
+    fn test_validate_setting_value_auth_lockout() {
+        assert!(validate_setting_value("auth_lockout_threshold", "5", "integer").is_ok());
+        assert!(validate_setting_value("auth_lockout_threshold", "0", "integer").is_err());
+        assert!(validate_setting_value("auth_lockout_window_minutes", "10", "integer").is_ok());
+        assert!(
+            validate_setting_value("auth_lockout_duration_minutes", "20000", "integer").is_err()
+        );
+        assert!(validate_setting_value("notify_auth_lockout", "desktop,tui", "string").is_ok());
+        assert!(validate_setting_value("notify_auth_lockout", "email", "string").is_err());
+        assert!(validate_setting_value("notify_certificate_expiring", "webhook", "string").is_ok());
+    }
+
+    #[test]
+    fn test_validate_setting_value_ai_debug_logging() {
+        assert!(validate_setting_value("ai_debug_logging_enabled", "true", "boolean").is_ok());
+        assert!(validate_setting_value("ai_debug_log_retention_hours", "24", "integer").is_ok());
+        assert!(validate_setting_value("ai_debug_log_retention_hours", "0", "integer").is_err());
+        assert!(validate_setting_value("ai_debug_log_retention_hours", "169", "integer").is_err());
+    }
+
+    #[test]
+    fn test_validate_setting_value_json() {
+        assert!(validate_setting_value("tui_macros", "[]", "json").is_ok());
+        assert!(validate_setting_value(
+            "tui_macros",
+            r#"[{"name":"triage","binding":"f5","keys":["down","enter"]}]"#,
+            "json"
+        )
+        .is_ok());
+        assert!(validate_setting_value("tui_macros", "{}", "json").is_err());
+        assert!(validate_setting_value("tui_macros", "[", "json").is_err());
+        assert!(validate_setting_value("tui_macros", "", "json").is_err());
+    }
+
+    #[test]
+    fn test_validate_setting_value_editor() {
+        assert!(validate_setting_value("preferred_editor", "zed", "string").is_ok());
+        assert!(validate_setting_value("preferred_editor", "notepad", "string").is_err());
+        assert!(validate_setting_value("editor_project_overrides", "{}", "json").is_ok());
+        assert!(validate_setting_value(
+            "editor_project_overrides",
+            r#"{"p1":"cursor","p2":"goland"}"#,
+            "json"
+        )
+        .is_ok());
+        assert!(
+            validate_setting_value("editor_project_overrides", r#"{"p1":"notepad"}"#, "json")
+                .is_err()
+        );
+        assert!(validate_setting_value("editor_project_overrides", r#"{"p1":1}"#, "json").is_err());
+        assert!(validate_setting_value("editor_project_overrides", "[]", "json").is_err());
+    }
 }
@@ -1,3 +1,3 @@
--- Remove docker_username field from sandbox_settings
-
-ALTER TABLE sandbox_settings DROP COLUMN docker_username;
+-- Rollback for 002_add_docker_username
+-- NOTE: 002 is a no-op; the column is added and dropped by 051_sandbox_docker_username.
+SELECT 1;
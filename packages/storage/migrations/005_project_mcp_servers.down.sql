@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes structured MCP server configuration
+-- ABOUTME: Drops the trigger, index, and project_mcp_servers table created by 005_project_mcp_servers.sql
+
+DROP TRIGGER IF EXISTS project_mcp_servers_updated_at;
+DROP INDEX IF EXISTS idx_project_mcp_servers_project;
+DROP TABLE IF EXISTS project_mcp_servers;
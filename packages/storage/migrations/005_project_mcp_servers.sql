@@ -0,0 +1,29 @@
+-- ABOUTME: Migration to add structured MCP server configuration per project
+-- ABOUTME: Replaces the bare enabled/disabled map in projects.mcp_servers with full launch configs
+
+CREATE TABLE IF NOT EXISTS project_mcp_servers (
+    id TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    command TEXT NOT NULL,
+    args TEXT NOT NULL DEFAULT '[]',
+    env TEXT NOT NULL DEFAULT '{}',
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    last_validated_at TEXT,
+    last_validation_error TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE,
+    UNIQUE (project_id, name),
+    CHECK (json_valid(args)),
+    CHECK (json_valid(env))
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_mcp_servers_project ON project_mcp_servers(project_id);
+
+CREATE TRIGGER IF NOT EXISTS project_mcp_servers_updated_at
+AFTER UPDATE ON project_mcp_servers
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE project_mcp_servers SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
@@ -0,0 +1,7 @@
+-- ABOUTME: Rollback migration that removes the offline sync operation queue
+-- ABOUTME: Drops the trigger, indexes, and sync_operation_queue table created by 006_sync_operation_queue.sql
+
+DROP TRIGGER IF EXISTS sync_operation_queue_updated_at;
+DROP INDEX IF EXISTS idx_sync_operation_queue_target;
+DROP INDEX IF EXISTS idx_sync_operation_queue_status;
+DROP TABLE IF EXISTS sync_operation_queue;
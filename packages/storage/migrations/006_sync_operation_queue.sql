@@ -0,0 +1,30 @@
+-- ABOUTME: Migration to add the offline sync operation queue
+-- ABOUTME: Records cloud and GitHub sync operations that failed for lack of connectivity so they can be replayed
+
+CREATE TABLE IF NOT EXISTS sync_operation_queue (
+    id TEXT PRIMARY KEY,
+    operation_type TEXT NOT NULL CHECK (operation_type IN ('cloud_sync_project', 'github_sync_epic', 'github_sync_tasks')),
+    target_id TEXT NOT NULL,
+    project_id TEXT,
+    payload TEXT NOT NULL DEFAULT '{}',
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'completed', 'conflict', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    queued_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    last_attempt_at TEXT,
+    completed_at TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE SET NULL,
+    CHECK (json_valid(payload))
+);
+
+CREATE INDEX IF NOT EXISTS idx_sync_operation_queue_status ON sync_operation_queue(status, queued_at);
+CREATE INDEX IF NOT EXISTS idx_sync_operation_queue_target ON sync_operation_queue(operation_type, target_id);
+
+CREATE TRIGGER IF NOT EXISTS sync_operation_queue_updated_at
+AFTER UPDATE ON sync_operation_queue
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE sync_operation_queue SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
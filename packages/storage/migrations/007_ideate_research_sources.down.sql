@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes ideate research web sources
+-- ABOUTME: Drops the index and ideate_research_sources table created by 007_ideate_research_sources.sql
+
+DROP INDEX IF EXISTS idx_ideate_research_sources_session;
+DROP TABLE IF EXISTS ideate_research_sources;
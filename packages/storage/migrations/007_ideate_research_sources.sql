@@ -0,0 +1,18 @@
+-- ABOUTME: Migration to add web search sources for ideate research
+-- ABOUTME: Stores pages fetched by a web search provider with attribution so they can feed research prompts
+
+CREATE TABLE IF NOT EXISTS ideate_research_sources (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    session_id TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    query TEXT NOT NULL,
+    title TEXT NOT NULL,
+    url TEXT NOT NULL,
+    snippet TEXT,
+    content TEXT,
+    fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (session_id) REFERENCES ideate_sessions(id) ON DELETE CASCADE,
+    UNIQUE (session_id, url)
+);
+
+CREATE INDEX IF NOT EXISTS idx_ideate_research_sources_session ON ideate_research_sources(session_id, fetched_at);
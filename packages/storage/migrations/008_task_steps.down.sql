@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes task execution steps
+-- ABOUTME: Drops the index and task_steps table created by 008_task_steps.sql
+
+DROP INDEX IF EXISTS idx_task_steps_task_status;
+DROP TABLE IF EXISTS task_steps;
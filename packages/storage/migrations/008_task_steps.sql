@@ -0,0 +1,22 @@
+-- ABOUTME: Migration to track execution checklist steps per task
+-- ABOUTME: Stores each generated step with its status, notes, and start/completion timestamps
+
+CREATE TABLE IF NOT EXISTS task_steps (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    task_id TEXT NOT NULL,
+    step_number INTEGER NOT NULL CHECK(step_number > 0),
+    action TEXT NOT NULL,
+    test_command TEXT,
+    expected_output TEXT,
+    estimated_minutes INTEGER,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK(status IN ('pending', 'in-progress', 'completed', 'skipped')),
+    notes TEXT,
+    started_at TEXT,
+    completed_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    UNIQUE (task_id, step_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_steps_task_status ON task_steps(task_id, status);
@@ -0,0 +1,7 @@
+-- ABOUTME: Rollback migration that removes task status history
+-- ABOUTME: Drops the triggers, index, and task_status_history table created by 009_task_status_history.sql
+
+DROP TRIGGER IF EXISTS tasks_status_history_update;
+DROP TRIGGER IF EXISTS tasks_status_history_insert;
+DROP INDEX IF EXISTS idx_task_status_history_task;
+DROP TABLE IF EXISTS task_status_history;
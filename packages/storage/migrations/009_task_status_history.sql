@@ -0,0 +1,35 @@
+-- ABOUTME: Migration to record every task status transition
+-- ABOUTME: Triggers capture creations and status changes so burndown and velocity can be computed
+
+CREATE TABLE IF NOT EXISTS task_status_history (
+    id TEXT PRIMARY KEY DEFAULT (lower(hex(randomblob(16)))),
+    task_id TEXT NOT NULL,
+    from_status TEXT,  -- NULL for the entry recorded when the task was created
+    to_status TEXT NOT NULL,
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_status_history_task ON task_status_history(task_id, changed_at);
+
+CREATE TRIGGER IF NOT EXISTS tasks_status_history_insert AFTER INSERT ON tasks
+BEGIN
+    INSERT INTO task_status_history (task_id, from_status, to_status, changed_at)
+    VALUES (NEW.id, NULL, NEW.status, NEW.created_at);
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_status_history_update AFTER UPDATE OF status ON tasks
+FOR EACH ROW WHEN NEW.status IS NOT OLD.status
+BEGIN
+    INSERT INTO task_status_history (task_id, from_status, to_status)
+    VALUES (NEW.id, OLD.status, NEW.status);
+END;
+
+-- Backfill existing tasks: creation, plus completion for tasks that are already done
+INSERT INTO task_status_history (task_id, from_status, to_status, changed_at)
+SELECT id, NULL, 'pending', created_at FROM tasks;
+
+INSERT INTO task_status_history (task_id, from_status, to_status, changed_at)
+SELECT id, 'pending', status, COALESCE(completed_at, updated_at)
+FROM tasks
+WHERE status != 'pending';
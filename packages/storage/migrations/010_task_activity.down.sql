@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes the task activity log
+-- ABOUTME: Drops the indexes and task_activity table created by 010_task_activity.sql
+
+DROP INDEX IF EXISTS idx_task_activity_project;
+DROP INDEX IF EXISTS idx_task_activity_task;
+DROP TABLE IF EXISTS task_activity;
@@ -0,0 +1,18 @@
+-- ABOUTME: Migration to record task field changes for activity feeds
+-- ABOUTME: Stores each status, assignee, and priority change with the acting user and timestamp
+
+CREATE TABLE IF NOT EXISTS task_activity (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    task_id TEXT NOT NULL,
+    project_id TEXT NOT NULL,
+    field TEXT NOT NULL CHECK(field IN ('status', 'assignee', 'priority')),
+    old_value TEXT,
+    new_value TEXT,
+    actor_id TEXT,  -- References users(id); NULL for changes made by the system
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (task_id) REFERENCES tasks(id) ON DELETE CASCADE,
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_activity_task ON task_activity(task_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_task_activity_project ON task_activity(project_id, created_at);
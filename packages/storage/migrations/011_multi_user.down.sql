@@ -0,0 +1,7 @@
+-- ABOUTME: Rollback migration that removes multi-user groundwork
+-- ABOUTME: Drops the indexes and columns added by 011_multi_user.sql
+
+DROP INDEX IF EXISTS idx_projects_owner;
+ALTER TABLE projects DROP COLUMN owner_user_id;
+DROP INDEX IF EXISTS idx_users_current;
+ALTER TABLE users DROP COLUMN is_current;
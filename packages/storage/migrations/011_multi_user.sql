@@ -0,0 +1,16 @@
+-- ABOUTME: Migration adding multi-user groundwork: an active user and project ownership
+-- ABOUTME: Marks one user as current and records which user owns each project
+
+ALTER TABLE users ADD COLUMN is_current INTEGER NOT NULL DEFAULT 0;
+
+-- At most one user is active at a time
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_current ON users(is_current) WHERE is_current = 1;
+
+UPDATE users SET is_current = 1 WHERE id = 'default-user';
+
+-- References users(id); NULL for projects visible to every user
+ALTER TABLE projects ADD COLUMN owner_user_id TEXT;
+
+UPDATE projects SET owner_user_id = 'default-user' WHERE owner_user_id IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_projects_owner ON projects(owner_user_id);
@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes the notification feed and settings
+-- ABOUTME: Drops the notifications table and settings created by 012_notifications.sql
+
+DELETE FROM system_settings WHERE category = 'notifications';
+DROP INDEX IF EXISTS idx_notifications_created;
+DROP TABLE IF EXISTS notifications;
@@ -0,0 +1,24 @@
+-- ABOUTME: Migration adding the notification feed and per-event notification preferences
+-- ABOUTME: Stores dispatched notifications for desktop/TUI polling and seeds notification settings
+
+CREATE TABLE IF NOT EXISTS notifications (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    event_type TEXT NOT NULL CHECK(event_type IN ('execution_finished', 'sync_failed', 'budget_threshold', 'server_crash', 'test')),
+    severity TEXT NOT NULL DEFAULT 'info' CHECK(severity IN ('info', 'success', 'warning', 'error')),
+    title TEXT NOT NULL,
+    message TEXT NOT NULL,
+    project_id TEXT,  -- References projects(id); NULL for events not tied to a project
+    channels TEXT NOT NULL,  -- Comma-separated channels the notification was routed to
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
+
+-- Channel lists are comma-separated values from: desktop, webhook, tui (or 'none')
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('notify_execution_finished', 'desktop,tui', 'notifications', 'Channels notified when an agent run finishes', 'string', 0, 0),
+    ('notify_sync_failed', 'desktop,webhook,tui', 'notifications', 'Channels notified when a sync operation fails', 'string', 0, 0),
+    ('notify_budget_threshold', 'desktop,webhook,tui', 'notifications', 'Channels notified when monthly AI spend crosses the budget', 'string', 0, 0),
+    ('notify_server_crash', 'desktop,webhook,tui', 'notifications', 'Channels notified when a preview server crashes', 'string', 0, 0),
+    ('notification_webhook_url', '', 'notifications', 'Slack or Discord compatible webhook URL', 'string', 0, 0),
+    ('notification_budget_monthly_usd', '0', 'notifications', 'Monthly AI spend in USD that triggers a budget notification (0 disables)', 'integer', 0, 0);
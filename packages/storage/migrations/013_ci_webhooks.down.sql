@@ -0,0 +1,10 @@
+-- ABOUTME: Rollback migration that removes inbound CI webhooks
+-- ABOUTME: Drops the tables and task columns created by 013_ci_webhooks.sql
+
+DROP INDEX IF EXISTS idx_webhook_deliveries_received;
+DROP TABLE IF EXISTS webhook_deliveries;
+DROP INDEX IF EXISTS idx_webhook_secrets_provider;
+DROP TABLE IF EXISTS webhook_secrets;
+ALTER TABLE tasks DROP COLUMN checks_updated_at;
+ALTER TABLE tasks DROP COLUMN checks_url;
+ALTER TABLE tasks DROP COLUMN checks_status;
@@ -0,0 +1,33 @@
+-- ABOUTME: Migration adding inbound CI webhooks and task check status
+-- ABOUTME: Stores encrypted webhook secrets, a delivery log, and the latest CI result per task
+
+ALTER TABLE tasks ADD COLUMN checks_status TEXT CHECK(checks_status IN ('pending', 'passed', 'failed', 'cancelled'));
+ALTER TABLE tasks ADD COLUMN checks_url TEXT;
+ALTER TABLE tasks ADD COLUMN checks_updated_at TEXT;
+
+CREATE TABLE IF NOT EXISTS webhook_secrets (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    provider TEXT NOT NULL CHECK(provider IN ('github', 'generic')),
+    name TEXT NOT NULL,
+    secret_encrypted TEXT NOT NULL,
+    is_active INTEGER NOT NULL DEFAULT 1 CHECK(is_active IN (0, 1)),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    last_used_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_secrets_provider ON webhook_secrets(provider, is_active);
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    provider TEXT NOT NULL CHECK(provider IN ('github', 'generic')),
+    event_type TEXT,
+    delivery_id TEXT,  -- Provider-assigned delivery ID (e.g. X-GitHub-Delivery)
+    status TEXT NOT NULL CHECK(status IN ('processed', 'ignored', 'rejected', 'failed')),
+    message TEXT,
+    payload TEXT,  -- Raw request body, omitted for rejected deliveries
+    updated_tasks INTEGER NOT NULL DEFAULT 0,
+    updated_executions INTEGER NOT NULL DEFAULT 0,
+    received_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_received ON webhook_deliveries(received_at);
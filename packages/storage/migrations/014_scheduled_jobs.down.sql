@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes the job scheduler
+-- ABOUTME: Drops the tables and index created by 014_scheduled_jobs.sql
+
+DROP INDEX IF EXISTS idx_scheduled_job_runs_job;
+DROP TABLE IF EXISTS scheduled_job_runs;
+DROP TABLE IF EXISTS scheduled_jobs;
@@ -0,0 +1,35 @@
+-- ABOUTME: Migration adding the cron-style job scheduler
+-- ABOUTME: Stores recurring job schedules with their last result, plus a per-run history
+
+CREATE TABLE IF NOT EXISTS scheduled_jobs (
+    id TEXT PRIMARY KEY,  -- Stable job key the server registers a handler for
+    name TEXT NOT NULL,
+    description TEXT,
+    cron_expression TEXT NOT NULL,  -- Standard 5-field cron, evaluated in the server's local time
+    enabled INTEGER NOT NULL DEFAULT 1 CHECK(enabled IN (0, 1)),
+    next_run_at TEXT,
+    last_run_at TEXT,
+    last_status TEXT CHECK(last_status IN ('running', 'success', 'failed')),
+    last_message TEXT,
+    last_duration_ms INTEGER,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE TABLE IF NOT EXISTS scheduled_job_runs (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    job_id TEXT NOT NULL REFERENCES scheduled_jobs(id) ON DELETE CASCADE,
+    status TEXT NOT NULL CHECK(status IN ('running', 'success', 'failed')),
+    message TEXT,
+    started_at TEXT NOT NULL,
+    finished_at TEXT,
+    duration_ms INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_scheduled_job_runs_job ON scheduled_job_runs(job_id, started_at);
+
+INSERT OR IGNORE INTO scheduled_jobs (id, name, description, cron_expression, enabled) VALUES
+    ('database_backup', 'Database backup', 'Write a compressed database snapshot to ~/.orkee/backups, keeping the most recent 7', '0 2 * * *', 1),
+    ('cloud_sync', 'Cloud sync', 'Sync all projects to Orkee Cloud', '0 */6 * * *', 0),
+    ('model_catalog_refresh', 'Model catalog refresh', 'Clear saved agent and model references that are no longer in the model catalog', '30 3 * * *', 1),
+    ('stale_server_cleanup', 'Stale server cleanup', 'Remove preview servers that stopped running or have not been seen recently', '*/15 * * * *', 1);
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the external server discovery settings
+-- ABOUTME: Deletes the settings created by 015_discovery_settings.sql
+
+DELETE FROM system_settings WHERE category = 'discovery';
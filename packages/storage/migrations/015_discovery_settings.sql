@@ -0,0 +1,9 @@
+-- ABOUTME: Migration adding user-configurable settings for external dev server discovery
+-- ABOUTME: Seeds scan roots, port ranges, and ignore patterns in the discovery settings category
+
+-- Port ranges are comma-separated ports or inclusive ranges (e.g. 3000-3003,5173)
+-- Scan roots and ignore patterns are comma-separated; empty means no restriction
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('discovery_port_ranges', '3000-3003,4200,5000-5001,5173-5174,8000-8001,8080-8081,8888,9000-9001', 'discovery', 'Ports scanned for externally started dev servers', 'string', 0, 0),
+    ('discovery_scan_roots', '', 'discovery', 'Only track servers running from these directories (empty tracks all)', 'string', 0, 0),
+    ('discovery_ignore_patterns', '', 'discovery', 'Skip servers whose command or directory matches these patterns (* matches anything)', 'string', 0, 0);
@@ -0,0 +1,8 @@
+-- ABOUTME: Rollback migration that removes Linear and Jira task import sources
+-- ABOUTME: Drops the tables, trigger, index, and scheduled job created by 016_task_sources.sql
+
+DELETE FROM scheduled_jobs WHERE id = 'task_source_import';
+DROP TABLE IF EXISTS task_external_links;
+DROP TRIGGER IF EXISTS project_task_sources_updated_at;
+DROP INDEX IF EXISTS idx_project_task_sources_project;
+DROP TABLE IF EXISTS project_task_sources;
@@ -0,0 +1,44 @@
+-- ABOUTME: Migration adding Linear and Jira task import sources per project
+-- ABOUTME: Stores source configs with encrypted API tokens and links imported tasks to external issues
+
+CREATE TABLE IF NOT EXISTS project_task_sources (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    provider TEXT NOT NULL CHECK(provider IN ('linear', 'jira')),
+    base_url TEXT,  -- Jira site URL; Linear uses its public API unless set
+    email TEXT,  -- Jira account email used with the API token
+    api_token_encrypted TEXT NOT NULL,
+    external_project TEXT NOT NULL,  -- Linear team key or Jira project key
+    filter TEXT,  -- Linear label name or extra JQL clause
+    status_mapping TEXT NOT NULL DEFAULT '{}' CHECK(json_valid(status_mapping)),
+    enabled INTEGER NOT NULL DEFAULT 1 CHECK(enabled IN (0, 1)),
+    last_synced_at TEXT,  -- Newest external updated timestamp imported so far
+    last_import_at TEXT,
+    last_import_error TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE (project_id, provider, external_project)
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_task_sources_project ON project_task_sources(project_id);
+
+CREATE TRIGGER IF NOT EXISTS project_task_sources_updated_at
+AFTER UPDATE ON project_task_sources
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE project_task_sources SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+CREATE TABLE IF NOT EXISTS task_external_links (
+    task_id TEXT PRIMARY KEY REFERENCES tasks(id) ON DELETE CASCADE,
+    source_id TEXT NOT NULL REFERENCES project_task_sources(id) ON DELETE CASCADE,
+    external_id TEXT NOT NULL,
+    external_key TEXT NOT NULL,  -- Human-readable issue key, e.g. ENG-123
+    external_url TEXT,
+    external_updated_at TEXT NOT NULL,
+    imported_at TEXT NOT NULL,
+    UNIQUE (source_id, external_id)
+);
+
+INSERT OR IGNORE INTO scheduled_jobs (id, name, description, cron_expression, enabled) VALUES
+    ('task_source_import', 'Task source import', 'Import new and updated issues from enabled Linear and Jira task sources', '0 * * * *', 1);
@@ -0,0 +1,9 @@
+-- ABOUTME: Rollback migration that removes Taskmaster two-way sync state
+-- ABOUTME: Drops the tables and indexes created by 017_taskmaster_sync.sql
+
+DROP INDEX IF EXISTS idx_taskmaster_conflicts_open;
+DROP INDEX IF EXISTS idx_taskmaster_conflicts_project;
+DROP TABLE IF EXISTS taskmaster_conflicts;
+DROP INDEX IF EXISTS idx_taskmaster_task_links_task;
+DROP TABLE IF EXISTS taskmaster_task_links;
+DROP TABLE IF EXISTS taskmaster_sync_state;
@@ -0,0 +1,43 @@
+-- ABOUTME: Migration adding two-way sync between Orkee tasks and Taskmaster's tasks.json
+-- ABOUTME: Stores file fingerprints, task pairings with their last-synced fields, and sync conflicts
+
+CREATE TABLE IF NOT EXISTS taskmaster_sync_state (
+    project_id TEXT PRIMARY KEY REFERENCES projects(id) ON DELETE CASCADE,
+    file_hash TEXT NOT NULL,  -- SHA-256 of tasks.json as of the last sync
+    file_modified_at TEXT,
+    last_synced_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS taskmaster_task_links (
+    project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    taskmaster_id TEXT NOT NULL,
+    -- No foreign key: a task deleted in Orkee must still be seen by the next sync
+    task_id TEXT NOT NULL,
+    -- Fields both sides had at the last sync, the common ancestor for three-way merges
+    base_fields TEXT NOT NULL CHECK(json_valid(base_fields)),
+    synced_at TEXT NOT NULL,
+    PRIMARY KEY (project_id, taskmaster_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_taskmaster_task_links_task ON taskmaster_task_links(task_id);
+
+CREATE TABLE IF NOT EXISTS taskmaster_conflicts (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    task_id TEXT NOT NULL,
+    taskmaster_id TEXT NOT NULL,
+    fields TEXT NOT NULL CHECK(json_valid(fields)),  -- Names of the fields that differ
+    base_fields TEXT NOT NULL CHECK(json_valid(base_fields)),
+    orkee_fields TEXT NOT NULL CHECK(json_valid(orkee_fields)),
+    taskmaster_fields TEXT NOT NULL CHECK(json_valid(taskmaster_fields)),
+    status TEXT NOT NULL DEFAULT 'open' CHECK(status IN ('open', 'resolved')),
+    resolution TEXT CHECK(resolution IN ('orkee', 'taskmaster', 'custom', 'auto')),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    resolved_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_taskmaster_conflicts_project ON taskmaster_conflicts(project_id, status);
+
+-- At most one open conflict per task; later syncs refresh it
+CREATE UNIQUE INDEX IF NOT EXISTS idx_taskmaster_conflicts_open
+    ON taskmaster_conflicts(project_id, taskmaster_id) WHERE status = 'open';
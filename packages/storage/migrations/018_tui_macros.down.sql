@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the TUI macro setting
+-- ABOUTME: Deletes the setting created by 018_tui_macros.sql
+
+DELETE FROM system_settings WHERE category = 'tui';
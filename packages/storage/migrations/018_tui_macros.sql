@@ -0,0 +1,6 @@
+-- ABOUTME: Migration adding the setting that stores TUI input macros
+-- ABOUTME: Seeds an empty macro list in the tui settings category
+
+-- Macros are a JSON array of {name, binding, keys}; keys use names like "ctrl+r", "enter", "f5"
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('tui_macros', '[]', 'tui', 'Recorded TUI keystroke macros and the keys that replay them', 'json', 0, 0);
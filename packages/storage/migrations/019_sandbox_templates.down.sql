@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes sandbox execution templates
+-- ABOUTME: Drops the table and trigger created by 019_sandbox_templates.sql
+
+DROP TRIGGER IF EXISTS sandbox_templates_updated_at;
+DROP TABLE IF EXISTS sandbox_templates;
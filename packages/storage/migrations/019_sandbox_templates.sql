@@ -0,0 +1,55 @@
+-- ABOUTME: Migration adding sandbox execution templates with pre-configured toolchains
+-- ABOUTME: Stores image, environment, cache volumes and setup commands, seeded with built-in templates
+
+CREATE TABLE IF NOT EXISTS sandbox_templates (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 3),
+    name TEXT NOT NULL UNIQUE,
+    description TEXT,
+    image TEXT NOT NULL,
+    env_vars TEXT NOT NULL DEFAULT '{}' CHECK(json_valid(env_vars)),  -- JSON object of name -> value
+    -- JSON array of {name, container_path}; each becomes a named volume shared by every sandbox
+    -- created from the template, so package caches survive between runs
+    cache_volumes TEXT NOT NULL DEFAULT '[]' CHECK(json_valid(cache_volumes)),
+    setup_commands TEXT NOT NULL DEFAULT '[]' CHECK(json_valid(setup_commands)),  -- Run in order after start
+    is_builtin BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE TRIGGER IF NOT EXISTS sandbox_templates_updated_at AFTER UPDATE ON sandbox_templates
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE sandbox_templates SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+INSERT OR IGNORE INTO sandbox_templates (id, name, description, image, env_vars, cache_volumes, setup_commands, is_builtin) VALUES
+    (
+        'rust-toolchain',
+        'Rust toolchain',
+        'Stable Rust with clippy and rustfmt; cargo registry and git checkouts are cached',
+        'rust:1-bookworm',
+        '{"CARGO_HOME":"/usr/local/cargo","CARGO_TERM_COLOR":"always"}',
+        '[{"name":"cargo-registry","container_path":"/usr/local/cargo/registry"},{"name":"cargo-git","container_path":"/usr/local/cargo/git"}]',
+        '["rustup component add clippy rustfmt"]',
+        TRUE
+    ),
+    (
+        'node20-pnpm',
+        'Node 20 + pnpm',
+        'Node.js 20 with pnpm enabled through corepack; the pnpm store is cached',
+        'node:20-bookworm',
+        '{"PNPM_HOME":"/pnpm","COREPACK_ENABLE_DOWNLOAD_PROMPT":"0"}',
+        '[{"name":"pnpm-store","container_path":"/pnpm/store"}]',
+        '["corepack enable pnpm","pnpm config set store-dir /pnpm/store"]',
+        TRUE
+    ),
+    (
+        'python312-uv',
+        'Python 3.12 + uv',
+        'Python 3.12 with the uv package manager; the uv download cache is cached',
+        'python:3.12-bookworm',
+        '{"UV_CACHE_DIR":"/root/.cache/uv","UV_LINK_MODE":"copy"}',
+        '[{"name":"uv-cache","container_path":"/root/.cache/uv"}]',
+        '["pip install --no-cache-dir uv"]',
+        TRUE
+    );
@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes the per-project secret store
+-- ABOUTME: Drops the table and index created by 020_project_secrets.sql
+
+DROP INDEX IF EXISTS idx_project_secrets_project;
+DROP TABLE IF EXISTS project_secrets;
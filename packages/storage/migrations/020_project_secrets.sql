@@ -0,0 +1,14 @@
+-- ABOUTME: Migration adding the per-project secret store
+-- ABOUTME: Secret values are encrypted before storage and injected as environment variables at launch
+
+CREATE TABLE IF NOT EXISTS project_secrets (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,  -- Environment variable name
+    encrypted_value TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE(project_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_secrets_project ON project_secrets(project_id);
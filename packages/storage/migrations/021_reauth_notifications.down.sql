@@ -0,0 +1,21 @@
+-- ABOUTME: Rollback migration that removes the reauth_required notification event
+-- ABOUTME: Restores the notifications event_type check from 012_notifications.sql and deletes the setting
+
+DELETE FROM system_settings WHERE key = 'notify_reauth_required';
+
+CREATE TABLE notifications_old (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    event_type TEXT NOT NULL CHECK(event_type IN ('execution_finished', 'sync_failed', 'budget_threshold', 'server_crash', 'test')),
+    severity TEXT NOT NULL DEFAULT 'info' CHECK(severity IN ('info', 'success', 'warning', 'error')),
+    title TEXT NOT NULL,
+    message TEXT NOT NULL,
+    project_id TEXT,
+    channels TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+INSERT INTO notifications_old SELECT id, event_type, severity, title, message, project_id, channels, created_at FROM notifications WHERE event_type != 'reauth_required';
+DROP TABLE notifications;
+ALTER TABLE notifications_old RENAME TO notifications;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
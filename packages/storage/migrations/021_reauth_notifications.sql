@@ -0,0 +1,22 @@
+-- ABOUTME: Migration adding the reauth_required notification event
+-- ABOUTME: Rebuilds the notifications table to widen its event_type check and seeds the event's setting
+
+CREATE TABLE notifications_new (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    event_type TEXT NOT NULL CHECK(event_type IN ('execution_finished', 'sync_failed', 'budget_threshold', 'server_crash', 'reauth_required', 'test')),
+    severity TEXT NOT NULL DEFAULT 'info' CHECK(severity IN ('info', 'success', 'warning', 'error')),
+    title TEXT NOT NULL,
+    message TEXT NOT NULL,
+    project_id TEXT,  -- References projects(id); NULL for events not tied to a project
+    channels TEXT NOT NULL,  -- Comma-separated channels the notification was routed to
+    created_at TEXT NOT NULL
+);
+
+INSERT INTO notifications_new SELECT id, event_type, severity, title, message, project_id, channels, created_at FROM notifications;
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
+
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('notify_reauth_required', 'desktop,tui', 'notifications', 'Channels notified when an AI provider token cannot be refreshed and needs a new login', 'string', 0, 0);
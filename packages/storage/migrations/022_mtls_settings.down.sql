@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the mutual TLS client-certificate settings
+-- ABOUTME: Deletes the settings created by 022_mtls_settings.sql
+
+DELETE FROM system_settings WHERE key IN ('tls_client_auth_enabled', 'tls_client_ca_path', 'tls_client_identities');
@@ -0,0 +1,9 @@
+-- ABOUTME: Migration adding mutual TLS client-certificate settings for remote access
+-- ABOUTME: Seeds the client CA bundle path and certificate CN to API token mapping in the security category
+
+-- An empty CA path means ~/.orkee/certs/client-ca.pem
+-- Identities are comma-separated CN=token-id pairs mapping certificate common names to API tokens
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('tls_client_auth_enabled', 'false', 'security', 'Require client certificates on the HTTPS server (mutual TLS)', 'boolean', 1, 0),
+    ('tls_client_ca_path', '', 'security', 'PEM bundle of CAs allowed to issue client certificates', 'string', 1, 0),
+    ('tls_client_identities', '', 'security', 'Map client certificate common names to API tokens (CN=token-id, comma-separated)', 'string', 1, 0);
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the editor integration settings
+-- ABOUTME: Deletes the settings created by 023_editor_settings.sql
+
+DELETE FROM system_settings WHERE key IN ('preferred_editor', 'editor_project_overrides');
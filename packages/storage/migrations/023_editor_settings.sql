@@ -0,0 +1,7 @@
+-- ABOUTME: Migration adding editor integration settings
+-- ABOUTME: Seeds the preferred editor and per-project editor overrides in the editor category
+
+-- Overrides are a JSON object of project ID to editor ID, e.g. {"<project-id>": "goland"}
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('preferred_editor', 'vscode', 'editor', 'Editor used to open projects and files', 'string', 0, 0),
+    ('editor_project_overrides', '{}', 'editor', 'Per-project editor overrides (JSON object of project ID to editor ID)', 'json', 0, 0);
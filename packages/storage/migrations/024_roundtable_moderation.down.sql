@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes the roundtable moderation columns
+-- ABOUTME: Requires SQLite 3.35.0+ for DROP COLUMN support
+
+ALTER TABLE roundtable_sessions DROP COLUMN max_turns;
+ALTER TABLE roundtable_sessions DROP COLUMN moderation_strategy;
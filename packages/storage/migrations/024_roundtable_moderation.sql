@@ -0,0 +1,5 @@
+-- ABOUTME: Migration that stores the moderation strategy chosen for each roundtable
+-- ABOUTME: Existing roundtables keep the original round-robin turn-taking
+
+ALTER TABLE roundtable_sessions ADD COLUMN moderation_strategy TEXT NOT NULL DEFAULT 'round_robin';
+ALTER TABLE roundtable_sessions ADD COLUMN max_turns INTEGER;
@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes ideate chat summarization checkpoints
+-- ABOUTME: Drops the table created by 025_chat_summaries.sql
+
+DROP INDEX IF EXISTS idx_prd_chat_summaries_session;
+DROP TABLE IF EXISTS prd_chat_summaries;
@@ -0,0 +1,18 @@
+-- ABOUTME: Migration adding summarization checkpoints for ideate chats
+-- ABOUTME: Each checkpoint compresses every message up to a message order into a rolling summary
+
+CREATE TABLE IF NOT EXISTS prd_chat_summaries (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    session_id TEXT NOT NULL,
+    -- Messages with message_order <= this value are covered by the summary
+    through_message_order INTEGER NOT NULL,
+    summary TEXT NOT NULL,
+    token_estimate INTEGER NOT NULL,
+    source TEXT NOT NULL DEFAULT 'auto' CHECK(source IN ('auto', 'ai')),  -- ai = written by the frontend model
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+
+    FOREIGN KEY (session_id) REFERENCES ideate_sessions(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_prd_chat_summaries_session
+    ON prd_chat_summaries(session_id, through_message_order);
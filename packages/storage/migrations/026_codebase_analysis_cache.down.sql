@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the codebase analysis cache
+-- ABOUTME: Drops the table created by 026_codebase_analysis_cache.sql
+
+DROP TABLE IF EXISTS codebase_analysis_cache;
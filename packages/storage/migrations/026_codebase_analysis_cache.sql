@@ -0,0 +1,11 @@
+-- ABOUTME: Migration adding a cache of codebase analysis results for ideate sessions
+-- ABOUTME: One entry per project path, keyed by HEAD commit and a hash of uncommitted files
+
+CREATE TABLE IF NOT EXISTS codebase_analysis_cache (
+    project_path TEXT PRIMARY KEY,
+    head_commit TEXT NOT NULL,
+    dirty_hash TEXT NOT NULL,
+    dirty_paths TEXT NOT NULL DEFAULT '[]',  -- JSON array of uncommitted paths at analysis time
+    context TEXT NOT NULL,                   -- JSON-serialized CodebaseContext
+    analyzed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
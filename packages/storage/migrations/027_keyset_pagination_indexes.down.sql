@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes the cursor pagination indexes
+-- ABOUTME: Drops the indexes created by 027_keyset_pagination_indexes.sql
+
+DROP INDEX IF EXISTS idx_ai_usage_logs_created_id;
+DROP INDEX IF EXISTS idx_agent_executions_task_created_id;
+DROP INDEX IF EXISTS idx_tasks_project_created_id;
@@ -0,0 +1,6 @@
+-- ABOUTME: Migration adding (created_at, id) indexes for cursor-based pagination
+-- ABOUTME: Lets task, execution, and AI usage log lists seek directly to a cursor
+
+CREATE INDEX IF NOT EXISTS idx_tasks_project_created_id ON tasks(project_id, created_at, id);
+CREATE INDEX IF NOT EXISTS idx_agent_executions_task_created_id ON agent_executions(task_id, created_at, id);
+CREATE INDEX IF NOT EXISTS idx_ai_usage_logs_created_id ON ai_usage_logs(created_at, id);
@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes per-user table view preferences
+-- ABOUTME: Drops the table and index created by 028_view_preferences.sql
+
+DROP INDEX IF EXISTS idx_view_preferences_updated;
+DROP TABLE IF EXISTS view_preferences;
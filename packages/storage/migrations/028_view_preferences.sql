@@ -0,0 +1,15 @@
+-- ABOUTME: Migration adding per-user table view preferences (columns, sort, filters)
+-- ABOUTME: Stored server-side so dashboard and TUI layouts follow the user across machines
+
+CREATE TABLE IF NOT EXISTS view_preferences (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    view_key TEXT NOT NULL,                  -- e.g. 'tasks.list', 'projects.table'
+    columns TEXT NOT NULL DEFAULT '[]',      -- JSON array of {id, visible, width}, in display order
+    sort TEXT NOT NULL DEFAULT '[]',         -- JSON array of {column, direction}, highest priority first
+    filters TEXT NOT NULL DEFAULT '{}',      -- JSON object, view-specific
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (user_id, view_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_view_preferences_updated ON view_preferences(user_id, updated_at);
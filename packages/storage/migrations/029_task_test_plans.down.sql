@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes task test plans
+-- ABOUTME: Drops the task_test_plans table created by 029_task_test_plans.sql
+
+DROP TABLE IF EXISTS task_test_plans;
@@ -0,0 +1,11 @@
+-- ABOUTME: Migration adding a test plan per task, generated during epic decomposition
+-- ABOUTME: Holds the unit/integration cases and edge conditions an agent must verify before finishing
+
+CREATE TABLE IF NOT EXISTS task_test_plans (
+    task_id TEXT PRIMARY KEY REFERENCES tasks(id) ON DELETE CASCADE,
+    unit_cases TEXT NOT NULL DEFAULT '[]',         -- JSON array of {description, expected}
+    integration_cases TEXT NOT NULL DEFAULT '[]',  -- JSON array of {description, expected}
+    edge_conditions TEXT NOT NULL DEFAULT '[]',    -- JSON array of strings
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
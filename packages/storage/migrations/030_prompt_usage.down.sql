@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback migration that removes prompt usage attribution
+-- ABOUTME: Drops the prompt_usage table and request index created by 030_prompt_usage.sql
+
+DROP INDEX IF EXISTS idx_ai_usage_logs_request;
+DROP TABLE IF EXISTS prompt_usage;
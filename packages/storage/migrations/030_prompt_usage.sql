@@ -0,0 +1,17 @@
+-- ABOUTME: Migration recording which centralized prompt each AI request was built from
+-- ABOUTME: Joined with ai_usage_logs on request_id to attribute tokens and cost to prompts
+
+CREATE TABLE IF NOT EXISTS prompt_usage (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    prompt_id TEXT NOT NULL,
+    prompt_version TEXT,
+    request_id TEXT NOT NULL,   -- Matches ai_usage_logs.request_id
+    operation TEXT,
+    project_id TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_prompt_usage_request ON prompt_usage(request_id);
+CREATE INDEX IF NOT EXISTS idx_prompt_usage_prompt ON prompt_usage(prompt_id);
+CREATE INDEX IF NOT EXISTS idx_ai_usage_logs_request ON ai_usage_logs(request_id);
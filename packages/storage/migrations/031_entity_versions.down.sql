@@ -0,0 +1,42 @@
+-- ABOUTME: Rollback migration that removes project and task version counters
+-- ABOUTME: Drops the version triggers and columns and restores the original updated_at and FTS triggers
+
+DROP TRIGGER IF EXISTS tasks_version;
+DROP TRIGGER IF EXISTS projects_version;
+
+DROP TRIGGER IF EXISTS tasks_updated_at;
+CREATE TRIGGER tasks_updated_at AFTER UPDATE ON tasks
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE tasks SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+DROP TRIGGER IF EXISTS projects_updated_at;
+CREATE TRIGGER projects_updated_at AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at
+BEGIN
+    UPDATE projects SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+DROP TRIGGER IF EXISTS projects_fts_update;
+CREATE TRIGGER projects_fts_update AFTER UPDATE ON projects BEGIN
+    INSERT INTO projects_fts(projects_fts, rowid, id, name, description, project_root, tags)
+    VALUES ('delete', old.rowid, old.id, old.name, old.description, old.project_root, old.tags);
+    INSERT INTO projects_fts(rowid, id, name, description, project_root, tags)
+    VALUES (new.rowid, new.id, new.name, new.description, new.project_root, new.tags);
+END;
+
+DROP TRIGGER IF EXISTS tasks_fts_update;
+CREATE TRIGGER tasks_fts_update AFTER UPDATE ON tasks BEGIN
+    DELETE FROM tasks_fts WHERE rowid = old.rowid;
+    INSERT INTO tasks_fts(rowid, id, title, description, details, tags)
+    VALUES (new.rowid, new.id, new.title, new.description, new.details, new.tags);
+END;
+
+DROP TRIGGER IF EXISTS tasks_fts_delete;
+CREATE TRIGGER tasks_fts_delete AFTER DELETE ON tasks BEGIN
+    DELETE FROM tasks_fts WHERE rowid = old.rowid;
+END;
+
+ALTER TABLE tasks DROP COLUMN version;
+ALTER TABLE projects DROP COLUMN version;
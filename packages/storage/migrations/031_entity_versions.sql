@@ -0,0 +1,77 @@
+-- ABOUTME: Migration adding optimistic concurrency version counters to projects and tasks
+-- ABOUTME: Triggers bump the version on every update; FTS triggers only reindex changed search columns
+
+ALTER TABLE projects ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE tasks ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+
+-- The updated_at triggers must not fire for the version bump's own UPDATE, or
+-- they would overwrite the timestamp the application just wrote
+DROP TRIGGER IF EXISTS projects_updated_at;
+CREATE TRIGGER projects_updated_at AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at AND NEW.version = OLD.version
+BEGIN
+    UPDATE projects SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+DROP TRIGGER IF EXISTS tasks_updated_at;
+CREATE TRIGGER tasks_updated_at AFTER UPDATE ON tasks
+FOR EACH ROW WHEN NEW.updated_at = OLD.updated_at AND NEW.version = OLD.version
+BEGIN
+    UPDATE tasks SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = NEW.id;
+END;
+
+-- Bumping from OLD.version keeps the result the same when the trigger runs
+-- more than once for a single statement
+CREATE TRIGGER projects_version AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.version = OLD.version
+BEGIN
+    UPDATE projects SET version = OLD.version + 1 WHERE id = NEW.id;
+END;
+
+CREATE TRIGGER tasks_version AFTER UPDATE ON tasks
+FOR EACH ROW WHEN NEW.version = OLD.version
+BEGIN
+    UPDATE tasks SET version = OLD.version + 1 WHERE id = NEW.id;
+END;
+
+-- The version and updated_at triggers issue a nested UPDATE on the same row. The
+-- nested statement reindexed the row before the outer trigger removed the old
+-- terms, so the outer removal no longer matched the index and corrupted it.
+-- Skipping updates that leave every indexed column unchanged keeps one reindex
+-- per statement.
+
+DROP TRIGGER IF EXISTS projects_fts_update;
+CREATE TRIGGER projects_fts_update AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.name IS NOT OLD.name
+    OR NEW.description IS NOT OLD.description
+    OR NEW.project_root IS NOT OLD.project_root
+    OR NEW.tags IS NOT OLD.tags
+BEGIN
+    INSERT INTO projects_fts(projects_fts, rowid, id, name, description, project_root, tags)
+    VALUES ('delete', old.rowid, old.id, old.name, old.description, old.project_root, old.tags);
+    INSERT INTO projects_fts(rowid, id, name, description, project_root, tags)
+    VALUES (new.rowid, new.id, new.name, new.description, new.project_root, new.tags);
+END;
+
+-- External content tables need the old values to remove a row's terms
+DROP TRIGGER IF EXISTS tasks_fts_update;
+CREATE TRIGGER tasks_fts_update AFTER UPDATE ON tasks
+FOR EACH ROW WHEN NEW.title IS NOT OLD.title
+    OR NEW.description IS NOT OLD.description
+    OR NEW.details IS NOT OLD.details
+    OR NEW.tags IS NOT OLD.tags
+BEGIN
+    INSERT INTO tasks_fts(tasks_fts, rowid, id, title, description, details, tags)
+    VALUES ('delete', old.rowid, old.id, old.title, old.description, old.details, old.tags);
+    INSERT INTO tasks_fts(rowid, id, title, description, details, tags)
+    VALUES (new.rowid, new.id, new.title, new.description, new.details, new.tags);
+END;
+
+DROP TRIGGER IF EXISTS tasks_fts_delete;
+CREATE TRIGGER tasks_fts_delete AFTER DELETE ON tasks BEGIN
+    INSERT INTO tasks_fts(tasks_fts, rowid, id, title, description, details, tags)
+    VALUES ('delete', old.rowid, old.id, old.title, old.description, old.details, old.tags);
+END;
+
+INSERT INTO projects_fts(projects_fts) VALUES ('rebuild');
+INSERT INTO tasks_fts(tasks_fts) VALUES ('rebuild');
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the preview server settings
+-- ABOUTME: Deletes the settings created by 032_preview_settings.sql
+
+DELETE FROM system_settings WHERE category = 'preview';
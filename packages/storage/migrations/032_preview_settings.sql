@@ -0,0 +1,5 @@
+-- ABOUTME: Migration adding user-configurable settings for preview servers
+-- ABOUTME: Seeds the option that opens the default browser once a preview server is ready
+
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('preview_open_browser', 'false', 'preview', 'Open the default browser when a preview server becomes ready', 'boolean', 0, 0);
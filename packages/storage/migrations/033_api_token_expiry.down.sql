@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback migration that removes API token expiry and rotation tracking
+-- ABOUTME: Drops the columns added by 033_api_token_expiry.sql
+
+DROP INDEX IF EXISTS idx_api_tokens_expires_at;
+ALTER TABLE api_tokens DROP COLUMN replaced_by;
+ALTER TABLE api_tokens DROP COLUMN expires_at;
@@ -0,0 +1,11 @@
+-- ABOUTME: Migration adding expiry and rotation tracking to API tokens
+-- ABOUTME: Expired tokens stop authenticating; rotated tokens expire after a grace period
+
+-- Timestamps use the '%Y-%m-%dT%H:%M:%SZ' format so they compare as strings
+-- NULL expires_at means the token never expires
+ALTER TABLE api_tokens ADD COLUMN expires_at TEXT;
+
+-- ID of the token issued when this one was rotated
+ALTER TABLE api_tokens ADD COLUMN replaced_by TEXT;
+
+CREATE INDEX idx_api_tokens_expires_at ON api_tokens(expires_at);
@@ -0,0 +1,33 @@
+-- ABOUTME: Rollback migration that removes intrusion detection for authentication failures
+-- ABOUTME: Drops the failure, lockout, and alert tables and restores the notifications event_type check
+
+DELETE FROM system_settings WHERE key IN (
+    'auth_lockout_enabled',
+    'auth_lockout_threshold',
+    'auth_lockout_window_minutes',
+    'auth_lockout_duration_minutes',
+    'notify_auth_lockout'
+);
+
+CREATE TABLE notifications_old (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    event_type TEXT NOT NULL CHECK(event_type IN ('execution_finished', 'sync_failed', 'budget_threshold', 'server_crash', 'reauth_required', 'test')),
+    severity TEXT NOT NULL DEFAULT 'info' CHECK(severity IN ('info', 'success', 'warning', 'error')),
+    title TEXT NOT NULL,
+    message TEXT NOT NULL,
+    project_id TEXT,
+    channels TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+INSERT INTO notifications_old SELECT id, event_type, severity, title, message, project_id, channels, created_at FROM notifications WHERE event_type != 'auth_lockout';
+DROP TABLE notifications;
+ALTER TABLE notifications_old RENAME TO notifications;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
+
+DROP INDEX IF EXISTS idx_security_alerts_created;
+DROP TABLE IF EXISTS security_alerts;
+DROP TABLE IF EXISTS auth_lockouts;
+DROP INDEX IF EXISTS idx_auth_failures_ip_created;
+DROP TABLE IF EXISTS auth_failures;
@@ -0,0 +1,58 @@
+-- ABOUTME: Migration adding intrusion detection for repeated authentication failures
+-- ABOUTME: Tracks failed attempts per IP address, locks out offenders, and records security alerts
+
+-- Timestamps use the '%Y-%m-%dT%H:%M:%SZ' format so they compare as strings
+
+CREATE TABLE IF NOT EXISTS auth_failures (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ip_address TEXT NOT NULL,
+    path TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_auth_failures_ip_created ON auth_failures(ip_address, created_at);
+
+CREATE TABLE IF NOT EXISTS auth_lockouts (
+    ip_address TEXT PRIMARY KEY,
+    failure_count INTEGER NOT NULL,
+    locked_until TEXT NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS security_alerts (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    kind TEXT NOT NULL CHECK(kind IN ('auth_lockout')),
+    ip_address TEXT NOT NULL,
+    failure_count INTEGER NOT NULL,
+    message TEXT NOT NULL,
+    locked_until TEXT,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_security_alerts_created ON security_alerts(created_at);
+
+-- Widen the notifications event_type check for the auth_lockout event
+CREATE TABLE notifications_new (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    event_type TEXT NOT NULL CHECK(event_type IN ('execution_finished', 'sync_failed', 'budget_threshold', 'server_crash', 'reauth_required', 'auth_lockout', 'test')),
+    severity TEXT NOT NULL DEFAULT 'info' CHECK(severity IN ('info', 'success', 'warning', 'error')),
+    title TEXT NOT NULL,
+    message TEXT NOT NULL,
+    project_id TEXT,  -- References projects(id); NULL for events not tied to a project
+    channels TEXT NOT NULL,  -- Comma-separated channels the notification was routed to
+    created_at TEXT NOT NULL
+);
+
+INSERT INTO notifications_new SELECT id, event_type, severity, title, message, project_id, channels, created_at FROM notifications;
+DROP TABLE notifications;
+ALTER TABLE notifications_new RENAME TO notifications;
+
+CREATE INDEX IF NOT EXISTS idx_notifications_created ON notifications(created_at);
+
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('auth_lockout_enabled', 'true', 'security', 'Lock out IP addresses after repeated failed authentication attempts', 'boolean', 0, 0),
+    ('auth_lockout_threshold', '5', 'security', 'Failed authentication attempts from one IP address that trigger a lockout', 'integer', 0, 0),
+    ('auth_lockout_window_minutes', '10', 'security', 'Minutes over which failed authentication attempts are counted', 'integer', 0, 0),
+    ('auth_lockout_duration_minutes', '15', 'security', 'Minutes a locked out IP address is refused', 'integer', 0, 0),
+    ('notify_auth_lockout', 'desktop,webhook,tui', 'notifications', 'Channels notified when an IP address is locked out after repeated failed logins', 'string', 0, 0);
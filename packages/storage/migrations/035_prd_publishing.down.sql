@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback for PRD publishing
+-- ABOUTME: Drops export jobs and publishing credentials
+
+DROP INDEX IF EXISTS idx_ideate_export_jobs_session;
+DROP TABLE IF EXISTS ideate_export_jobs;
+DROP TABLE IF EXISTS user_publishing_credentials;
@@ -0,0 +1,33 @@
+-- ABOUTME: Migration adding Notion and Confluence publishing for ideate PRDs
+-- ABOUTME: Stores per-user encrypted publishing credentials and tracks background export jobs
+
+CREATE TABLE IF NOT EXISTS user_publishing_credentials (
+    user_id TEXT NOT NULL,
+    target TEXT NOT NULL CHECK(target IN ('notion', 'confluence')),
+    encrypted_token TEXT NOT NULL,
+    site_url TEXT,
+    account_email TEXT,
+    -- Notion parent page ID, or Confluence space key
+    default_parent TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (user_id, target),
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS ideate_export_jobs (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    session_id TEXT NOT NULL,
+    prd_id TEXT,
+    user_id TEXT NOT NULL,
+    format TEXT NOT NULL CHECK(format IN ('notion', 'confluence')),
+    status TEXT NOT NULL DEFAULT 'queued' CHECK(status IN ('queued', 'running', 'completed', 'failed')),
+    page_url TEXT,
+    error TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    completed_at TEXT,
+    FOREIGN KEY (session_id) REFERENCES ideate_sessions(id) ON DELETE CASCADE,
+    FOREIGN KEY (prd_id) REFERENCES prds(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_ideate_export_jobs_session ON ideate_export_jobs(session_id, created_at);
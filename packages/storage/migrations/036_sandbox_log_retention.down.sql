@@ -0,0 +1,8 @@
+-- ABOUTME: Rollback for sandbox execution log retention
+-- ABOUTME: Drops retention policies and the archive index, leaving archive files on disk in place
+
+DROP INDEX IF EXISTS idx_sandbox_execution_archives_policy;
+DROP INDEX IF EXISTS idx_sandbox_execution_archives_sandbox;
+DROP TABLE IF EXISTS sandbox_execution_archives;
+DROP INDEX IF EXISTS idx_sandbox_retention_policies_scope;
+DROP TABLE IF EXISTS sandbox_retention_policies;
@@ -0,0 +1,43 @@
+-- ABOUTME: Migration adding retention policies for sandbox execution logs
+-- ABOUTME: Policies cap log age and size; expired runs are archived to compressed files before deletion
+
+-- A policy applies to one sandbox, every sandbox of a project, or (with
+-- neither set) every sandbox. Each execution follows its most specific policy.
+CREATE TABLE IF NOT EXISTS sandbox_retention_policies (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    name TEXT NOT NULL,
+    sandbox_id TEXT REFERENCES sandboxes(id) ON DELETE CASCADE,
+    project_id TEXT REFERENCES projects(id) ON DELETE CASCADE,
+    max_age_days INTEGER CHECK(max_age_days IS NULL OR max_age_days > 0),
+    max_total_mb INTEGER CHECK(max_total_mb IS NULL OR max_total_mb > 0),
+    archive BOOLEAN NOT NULL DEFAULT TRUE,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    CHECK (sandbox_id IS NULL OR project_id IS NULL)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_sandbox_retention_policies_scope
+    ON sandbox_retention_policies(COALESCE(sandbox_id, ''), COALESCE(project_id, ''));
+
+-- Executions removed by a policy whose logs were archived. No foreign key to
+-- sandbox_executions or sandboxes: the archive outlives both.
+CREATE TABLE IF NOT EXISTS sandbox_execution_archives (
+    execution_id TEXT PRIMARY KEY,
+    sandbox_id TEXT NOT NULL,
+    policy_id TEXT REFERENCES sandbox_retention_policies(id) ON DELETE SET NULL,
+    archive_path TEXT NOT NULL,
+    original_bytes INTEGER NOT NULL,
+    compressed_bytes INTEGER NOT NULL,
+    execution_created_at TEXT NOT NULL,
+    archived_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    -- Set when the run is restored; restored runs are exempt from retention
+    restored_at TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_sandbox_execution_archives_sandbox ON sandbox_execution_archives(sandbox_id);
+CREATE INDEX IF NOT EXISTS idx_sandbox_execution_archives_policy ON sandbox_execution_archives(policy_id);
+
+-- Default policy for every sandbox, matching the existing log_retention_days default
+INSERT OR IGNORE INTO sandbox_retention_policies (id, name, max_age_days, max_total_mb, archive, enabled)
+VALUES ('default-retention', 'Default', 7, 500, TRUE, TRUE);
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback for agent tool permission overrides
+-- ABOUTME: Drops agent_tool_policies so agents fall back to registry defaults
+
+DROP TABLE IF EXISTS agent_tool_policies;
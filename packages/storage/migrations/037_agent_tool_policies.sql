@@ -0,0 +1,11 @@
+-- ABOUTME: Migration adding per-agent tool permission overrides
+-- ABOUTME: Agents without a row use the defaults from the agent registry
+
+CREATE TABLE IF NOT EXISTS agent_tool_policies (
+    agent_id TEXT PRIMARY KEY,
+    filesystem_write BOOLEAN NOT NULL,
+    network BOOLEAN NOT NULL,
+    shell_exec BOOLEAN NOT NULL,
+    git_push BOOLEAN NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback for model preference fallback chains and cost caps
+-- ABOUTME: Drops model_task_policies, leaving primary models in model_preferences untouched
+
+DROP TABLE IF EXISTS model_task_policies;
@@ -0,0 +1,17 @@
+-- ABOUTME: Migration adding fallback chains and cost caps to per-task model preferences
+-- ABOUTME: The AI proxy tries fallbacks in order and skips models whose estimated call cost exceeds the cap
+
+-- The primary model stays in model_preferences; this table holds what to try
+-- after it. fallback_models is a JSON array of {"provider", "model"} objects.
+CREATE TABLE IF NOT EXISTS model_task_policies (
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    task_type TEXT NOT NULL CHECK (task_type IN (
+        'chat', 'prd_generation', 'prd_analysis', 'insight_extraction', 'spec_generation',
+        'task_suggestions', 'task_analysis', 'spec_refinement', 'research_generation',
+        'markdown_generation'
+    )),
+    fallback_models TEXT NOT NULL DEFAULT '[]' CHECK (json_valid(fallback_models)),
+    max_cost_per_call REAL CHECK (max_cost_per_call IS NULL OR max_cost_per_call > 0),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    PRIMARY KEY (user_id, task_type)
+);
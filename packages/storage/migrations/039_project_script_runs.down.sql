@@ -0,0 +1,5 @@
+-- ABOUTME: Rollback for project script run history
+-- ABOUTME: Drops project_script_runs and its index
+
+DROP INDEX IF EXISTS idx_project_script_runs_project;
+DROP TABLE IF EXISTS project_script_runs;
@@ -0,0 +1,20 @@
+-- ABOUTME: Migration adding the exit status history of project setup/dev/cleanup script runs
+-- ABOUTME: Each row keeps the command, outcome, and the tail of the run's output
+
+CREATE TABLE IF NOT EXISTS project_script_runs (
+    id TEXT PRIMARY KEY,
+    project_id TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('setup', 'dev', 'cleanup')),
+    command TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'running'
+        CHECK (status IN ('running', 'succeeded', 'failed', 'timed_out', 'cancelled')),
+    exit_code INTEGER,
+    timeout_secs INTEGER NOT NULL CHECK (timeout_secs > 0),
+    output_tail TEXT NOT NULL DEFAULT '',
+    started_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    finished_at TEXT,
+    duration_ms INTEGER
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_script_runs_project
+    ON project_script_runs(project_id, started_at DESC);
@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback for project validation rule settings
+-- ABOUTME: Removes the project name pattern and required tag settings
+
+DELETE FROM system_settings WHERE key IN ('project_name_pattern', 'project_required_tags');
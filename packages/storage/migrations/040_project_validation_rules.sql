@@ -0,0 +1,7 @@
+-- ABOUTME: Migration adding settings for configurable project validation rules
+-- ABOUTME: Seeds an empty project name pattern and required tag list in the security category
+
+-- Empty values add no constraint. Strict browse_sandbox_mode also limits project roots to allowed_browse_paths.
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('project_name_pattern', '', 'security', 'Regular expression project names must match; empty for none', 'string', 0, 0),
+    ('project_required_tags', '', 'security', 'Comma-separated tags every project must carry; empty for none', 'string', 0, 0);
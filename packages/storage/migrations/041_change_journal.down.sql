@@ -0,0 +1,18 @@
+-- ABOUTME: Rollback for the change journal
+-- ABOUTME: Drops the journal triggers, indexes, and table
+
+DROP TRIGGER IF EXISTS projects_change_journal_insert;
+DROP TRIGGER IF EXISTS projects_change_journal_update;
+DROP TRIGGER IF EXISTS projects_change_journal_delete;
+DROP TRIGGER IF EXISTS tasks_change_journal_insert;
+DROP TRIGGER IF EXISTS tasks_change_journal_update;
+DROP TRIGGER IF EXISTS tasks_change_journal_delete;
+DROP TRIGGER IF EXISTS prds_change_journal_insert;
+DROP TRIGGER IF EXISTS prds_change_journal_update;
+DROP TRIGGER IF EXISTS prds_change_journal_delete;
+DROP TRIGGER IF EXISTS tags_change_journal_insert;
+DROP TRIGGER IF EXISTS tags_change_journal_update;
+DROP TRIGGER IF EXISTS tags_change_journal_delete;
+DROP INDEX IF EXISTS idx_change_journal_changed_at;
+DROP INDEX IF EXISTS idx_change_journal_entity;
+DROP TABLE IF EXISTS change_journal;
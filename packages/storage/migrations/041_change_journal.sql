@@ -0,0 +1,187 @@
+-- ABOUTME: Migration adding an ordered change journal of project, task, PRD, and tag mutations
+-- ABOUTME: Triggers record every insert, update, and delete so external consumers can follow a change feed
+
+-- seq is AUTOINCREMENT so sequence numbers are never reused, even after old entries are pruned.
+-- payload is the row as JSON after the change (NULL for deletes); the API exposes only its hash.
+CREATE TABLE IF NOT EXISTS change_journal (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('project', 'task', 'prd', 'tag')),
+    entity_id TEXT NOT NULL,
+    op TEXT NOT NULL CHECK(op IN ('create', 'update', 'delete')),
+    payload TEXT,
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_change_journal_entity ON change_journal(entity_type, entity_id, seq);
+CREATE INDEX IF NOT EXISTS idx_change_journal_changed_at ON change_journal(changed_at);
+
+-- Updates also fire for the UPDATEs issued by the updated_at and version triggers.
+-- Projects and tasks only record updates that change version (031_entity_versions);
+-- PRDs and tags skip updates whose payload matches the entity's previous entry.
+-- The encrypted GitHub token is left out of project payloads.
+
+CREATE TRIGGER IF NOT EXISTS projects_change_journal_insert AFTER INSERT ON projects
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('project', NEW.id, 'create', json_object(
+        'id', NEW.id, 'name', NEW.name, 'project_root', NEW.project_root,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'rank', NEW.rank, 'setup_script', NEW.setup_script, 'dev_script', NEW.dev_script,
+        'cleanup_script', NEW.cleanup_script, 'task_source', NEW.task_source, 'tags', NEW.tags,
+        'manual_tasks', NEW.manual_tasks, 'mcp_servers', NEW.mcp_servers, 'git_repository', NEW.git_repository,
+        'github_owner', NEW.github_owner, 'github_repo', NEW.github_repo, 'github_sync_enabled', NEW.github_sync_enabled,
+        'github_labels_config', NEW.github_labels_config, 'github_default_assignee', NEW.github_default_assignee, 'created_at', NEW.created_at,
+        'updated_at', NEW.updated_at, 'owner_user_id', NEW.owner_user_id, 'version', NEW.version
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_change_journal_update AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.version <> OLD.version
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('project', NEW.id, 'update', json_object(
+        'id', NEW.id, 'name', NEW.name, 'project_root', NEW.project_root,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'rank', NEW.rank, 'setup_script', NEW.setup_script, 'dev_script', NEW.dev_script,
+        'cleanup_script', NEW.cleanup_script, 'task_source', NEW.task_source, 'tags', NEW.tags,
+        'manual_tasks', NEW.manual_tasks, 'mcp_servers', NEW.mcp_servers, 'git_repository', NEW.git_repository,
+        'github_owner', NEW.github_owner, 'github_repo', NEW.github_repo, 'github_sync_enabled', NEW.github_sync_enabled,
+        'github_labels_config', NEW.github_labels_config, 'github_default_assignee', NEW.github_default_assignee, 'created_at', NEW.created_at,
+        'updated_at', NEW.updated_at, 'owner_user_id', NEW.owner_user_id, 'version', NEW.version
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_change_journal_delete AFTER DELETE ON projects
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('project', OLD.id, 'delete', NULL);
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_change_journal_insert AFTER INSERT ON tasks
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('task', NEW.id, 'create', json_object(
+        'id', NEW.id, 'project_id', NEW.project_id, 'title', NEW.title,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'created_by_user_id', NEW.created_by_user_id, 'assigned_agent_id', NEW.assigned_agent_id, 'reviewed_by_agent_id', NEW.reviewed_by_agent_id,
+        'parent_id', NEW.parent_id, 'tag_id', NEW.tag_id, 'position', NEW.position,
+        'dependencies', NEW.dependencies, 'blockers', NEW.blockers, 'due_date', NEW.due_date,
+        'estimated_hours', NEW.estimated_hours, 'actual_hours', NEW.actual_hours, 'complexity_score', NEW.complexity_score,
+        'details', NEW.details, 'test_strategy', NEW.test_strategy, 'acceptance_criteria', NEW.acceptance_criteria,
+        'prompt', NEW.prompt, 'context', NEW.context, 'output_format', NEW.output_format,
+        'validation_rules', NEW.validation_rules, 'started_at', NEW.started_at, 'completed_at', NEW.completed_at,
+        'execution_log', NEW.execution_log, 'error_log', NEW.error_log, 'retry_count', NEW.retry_count,
+        'tags', NEW.tags, 'category', NEW.category, 'metadata', NEW.metadata,
+        'spec_driven', NEW.spec_driven, 'from_prd_id', NEW.from_prd_id, 'epic_id', NEW.epic_id,
+        'github_issue_number', NEW.github_issue_number, 'github_issue_url', NEW.github_issue_url, 'parallel_group', NEW.parallel_group,
+        'depends_on', NEW.depends_on, 'conflicts_with', NEW.conflicts_with, 'task_type', NEW.task_type,
+        'size_estimate', NEW.size_estimate, 'technical_details', NEW.technical_details, 'effort_hours', NEW.effort_hours,
+        'can_parallel', NEW.can_parallel, 'spec_validation_status', NEW.spec_validation_status, 'spec_validation_result', NEW.spec_validation_result,
+        'relevant_files', NEW.relevant_files, 'similar_implementations', NEW.similar_implementations, 'execution_steps', NEW.execution_steps,
+        'validation_history', NEW.validation_history, 'codebase_references', NEW.codebase_references, 'parent_task_id', NEW.parent_task_id,
+        'created_at', NEW.created_at, 'updated_at', NEW.updated_at, 'checks_status', NEW.checks_status,
+        'checks_url', NEW.checks_url, 'checks_updated_at', NEW.checks_updated_at, 'version', NEW.version
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_change_journal_update AFTER UPDATE ON tasks
+FOR EACH ROW WHEN NEW.version <> OLD.version
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('task', NEW.id, 'update', json_object(
+        'id', NEW.id, 'project_id', NEW.project_id, 'title', NEW.title,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'created_by_user_id', NEW.created_by_user_id, 'assigned_agent_id', NEW.assigned_agent_id, 'reviewed_by_agent_id', NEW.reviewed_by_agent_id,
+        'parent_id', NEW.parent_id, 'tag_id', NEW.tag_id, 'position', NEW.position,
+        'dependencies', NEW.dependencies, 'blockers', NEW.blockers, 'due_date', NEW.due_date,
+        'estimated_hours', NEW.estimated_hours, 'actual_hours', NEW.actual_hours, 'complexity_score', NEW.complexity_score,
+        'details', NEW.details, 'test_strategy', NEW.test_strategy, 'acceptance_criteria', NEW.acceptance_criteria,
+        'prompt', NEW.prompt, 'context', NEW.context, 'output_format', NEW.output_format,
+        'validation_rules', NEW.validation_rules, 'started_at', NEW.started_at, 'completed_at', NEW.completed_at,
+        'execution_log', NEW.execution_log, 'error_log', NEW.error_log, 'retry_count', NEW.retry_count,
+        'tags', NEW.tags, 'category', NEW.category, 'metadata', NEW.metadata,
+        'spec_driven', NEW.spec_driven, 'from_prd_id', NEW.from_prd_id, 'epic_id', NEW.epic_id,
+        'github_issue_number', NEW.github_issue_number, 'github_issue_url', NEW.github_issue_url, 'parallel_group', NEW.parallel_group,
+        'depends_on', NEW.depends_on, 'conflicts_with', NEW.conflicts_with, 'task_type', NEW.task_type,
+        'size_estimate', NEW.size_estimate, 'technical_details', NEW.technical_details, 'effort_hours', NEW.effort_hours,
+        'can_parallel', NEW.can_parallel, 'spec_validation_status', NEW.spec_validation_status, 'spec_validation_result', NEW.spec_validation_result,
+        'relevant_files', NEW.relevant_files, 'similar_implementations', NEW.similar_implementations, 'execution_steps', NEW.execution_steps,
+        'validation_history', NEW.validation_history, 'codebase_references', NEW.codebase_references, 'parent_task_id', NEW.parent_task_id,
+        'created_at', NEW.created_at, 'updated_at', NEW.updated_at, 'checks_status', NEW.checks_status,
+        'checks_url', NEW.checks_url, 'checks_updated_at', NEW.checks_updated_at, 'version', NEW.version
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tasks_change_journal_delete AFTER DELETE ON tasks
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('task', OLD.id, 'delete', NULL);
+END;
+
+CREATE TRIGGER IF NOT EXISTS prds_change_journal_insert AFTER INSERT ON prds
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('prd', NEW.id, 'create', json_object(
+        'id', NEW.id, 'project_id', NEW.project_id, 'ideate_session_id', NEW.ideate_session_id,
+        'title', NEW.title, 'content_markdown', NEW.content_markdown, 'version', NEW.version,
+        'status', NEW.status, 'source', NEW.source, 'conversation_id', NEW.conversation_id,
+        'github_epic_url', NEW.github_epic_url, 'discovery_status', NEW.discovery_status, 'discovery_completed_at', NEW.discovery_completed_at,
+        'quality_score', NEW.quality_score, 'deleted_at', NEW.deleted_at, 'created_at', NEW.created_at,
+        'updated_at', NEW.updated_at, 'created_by', NEW.created_by
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS prds_change_journal_update AFTER UPDATE ON prds
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    SELECT 'prd', NEW.id, 'update', changed.payload
+    FROM (SELECT json_object(
+            'id', NEW.id, 'project_id', NEW.project_id, 'ideate_session_id', NEW.ideate_session_id,
+            'title', NEW.title, 'content_markdown', NEW.content_markdown, 'version', NEW.version,
+            'status', NEW.status, 'source', NEW.source, 'conversation_id', NEW.conversation_id,
+            'github_epic_url', NEW.github_epic_url, 'discovery_status', NEW.discovery_status, 'discovery_completed_at', NEW.discovery_completed_at,
+            'quality_score', NEW.quality_score, 'deleted_at', NEW.deleted_at, 'created_at', NEW.created_at,
+            'updated_at', NEW.updated_at, 'created_by', NEW.created_by
+        ) AS payload) AS changed
+    WHERE changed.payload IS NOT (
+        SELECT payload FROM change_journal
+        WHERE entity_type = 'prd' AND entity_id = NEW.id
+        ORDER BY seq DESC LIMIT 1
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS prds_change_journal_delete AFTER DELETE ON prds
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('prd', OLD.id, 'delete', NULL);
+END;
+
+CREATE TRIGGER IF NOT EXISTS tags_change_journal_insert AFTER INSERT ON tags
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('tag', NEW.id, 'create', json_object(
+        'id', NEW.id, 'name', NEW.name, 'color', NEW.color,
+        'description', NEW.description, 'created_at', NEW.created_at, 'archived_at', NEW.archived_at
+    ));
+END;
+
+CREATE TRIGGER IF NOT EXISTS tags_change_journal_update AFTER UPDATE ON tags
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    SELECT 'tag', NEW.id, 'update', changed.payload
+    FROM (SELECT json_object(
+            'id', NEW.id, 'name', NEW.name, 'color', NEW.color,
+            'description', NEW.description, 'created_at', NEW.created_at, 'archived_at', NEW.archived_at
+        ) AS payload) AS changed
+    WHERE changed.payload IS NOT (
+        SELECT payload FROM change_journal
+        WHERE entity_type = 'tag' AND entity_id = NEW.id
+        ORDER BY seq DESC LIMIT 1
+    );
+END;
+
+CREATE TRIGGER IF NOT EXISTS tags_change_journal_delete AFTER DELETE ON tags
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('tag', OLD.id, 'delete', NULL);
+END;
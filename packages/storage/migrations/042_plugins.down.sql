@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback for the plugin loading setting
+-- ABOUTME: Removes the plugins_enabled setting
+
+DELETE FROM system_settings WHERE key = 'plugins_enabled';
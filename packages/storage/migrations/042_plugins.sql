@@ -0,0 +1,5 @@
+-- ABOUTME: Migration adding the setting that controls WASM plugin loading
+-- ABOUTME: Plugins in ~/.orkee/plugins are loaded at startup unless this is turned off
+
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('plugins_enabled', 'true', 'server', 'Load WASM plugins from ~/.orkee/plugins at startup', 'boolean', 1, 0);
@@ -0,0 +1,7 @@
+-- ABOUTME: Rollback for the AI proxy debug log
+-- ABOUTME: Drops the debug log table and its settings
+
+DELETE FROM system_settings WHERE key IN ('ai_debug_logging_enabled', 'ai_debug_log_retention_hours');
+DROP INDEX IF EXISTS idx_ai_debug_logs_expires_at;
+DROP INDEX IF EXISTS idx_ai_debug_logs_correlation;
+DROP TABLE IF EXISTS ai_debug_logs;
@@ -0,0 +1,30 @@
+-- ABOUTME: Migration adding the opt-in AI proxy debug log
+-- ABOUTME: Stores redacted request and response bodies by correlation ID until they expire
+
+-- Bodies and headers are redacted before they are written; expires_at is set from
+-- ai_debug_log_retention_hours when the entry is recorded.
+CREATE TABLE IF NOT EXISTS ai_debug_logs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    correlation_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    method TEXT NOT NULL,
+    path TEXT NOT NULL,
+    model TEXT,
+    status_code INTEGER,
+    duration_ms INTEGER NOT NULL,
+    request_headers TEXT NOT NULL CHECK (json_valid(request_headers)),
+    request_body TEXT,
+    response_headers TEXT CHECK (json_valid(response_headers)),
+    response_body TEXT,
+    error TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    expires_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_ai_debug_logs_correlation ON ai_debug_logs(correlation_id, user_id);
+CREATE INDEX IF NOT EXISTS idx_ai_debug_logs_expires_at ON ai_debug_logs(expires_at);
+
+INSERT OR IGNORE INTO system_settings (key, value, category, description, data_type, requires_restart, is_env_only) VALUES
+    ('ai_debug_logging_enabled', 'false', 'advanced', 'Record redacted AI proxy requests and responses by correlation ID for debugging', 'boolean', 0, 0),
+    ('ai_debug_log_retention_hours', '24', 'advanced', 'Hours AI proxy debug log entries are kept', 'integer', 0, 0);
@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback for checkpoint validation runs
+-- ABOUTME: Drops the validation run table and its indexes
+
+DROP INDEX IF EXISTS idx_checkpoint_validation_runs_epic;
+DROP INDEX IF EXISTS idx_checkpoint_validation_runs_checkpoint;
+DROP TABLE IF EXISTS checkpoint_validation_runs;
@@ -0,0 +1,22 @@
+-- ABOUTME: Migration adding sandboxed validation runs for execution checkpoints
+-- ABOUTME: Records each run's commands, exit codes and log tails so checkpoints can gate the next parallel group
+
+-- results is a JSON array of per-command outcomes (command, exit_code, duration_ms,
+-- stdout and stderr tails). A passing run also completes its checkpoint.
+CREATE TABLE IF NOT EXISTS checkpoint_validation_runs (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    checkpoint_id TEXT NOT NULL,
+    epic_id TEXT NOT NULL,
+    sandbox_id TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'running' CHECK(status IN ('running', 'passed', 'failed', 'error')),
+    commands TEXT NOT NULL CHECK (json_valid(commands)),
+    results TEXT NOT NULL DEFAULT '[]' CHECK (json_valid(results)),
+    error TEXT,
+    started_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    completed_at TEXT,
+    FOREIGN KEY (checkpoint_id) REFERENCES execution_checkpoints(id) ON DELETE CASCADE,
+    FOREIGN KEY (epic_id) REFERENCES epics(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_checkpoint_validation_runs_checkpoint ON checkpoint_validation_runs(checkpoint_id, started_at);
+CREATE INDEX IF NOT EXISTS idx_checkpoint_validation_runs_epic ON checkpoint_validation_runs(epic_id);
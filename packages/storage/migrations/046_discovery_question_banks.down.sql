@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback for custom discovery question banks
+-- ABOUTME: Drops the session bank selection and the question bank table
+
+ALTER TABLE ideate_sessions DROP COLUMN question_bank_id;
+DROP INDEX IF EXISTS idx_discovery_question_banks_shared;
+DROP TABLE IF EXISTS discovery_question_banks;
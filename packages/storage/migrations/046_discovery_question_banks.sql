@@ -0,0 +1,22 @@
+-- ABOUTME: Migration adding custom discovery question banks
+-- ABOUTME: Lets users encode their own intake questionnaires and select one when starting an ideate session
+
+-- questions is a JSON array of {id, text, questionType, options, category, canSkip, askIf}
+-- in the order they are asked. shared banks are visible to every user of the instance.
+CREATE TABLE IF NOT EXISTS discovery_question_banks (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    description TEXT,
+    shared BOOLEAN NOT NULL DEFAULT FALSE,
+    questions TEXT NOT NULL DEFAULT '[]' CHECK (json_valid(questions)),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE(user_id, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_discovery_question_banks_shared ON discovery_question_banks(shared);
+
+-- Sessions without a bank use the built-in discovery questions. No foreign key so the
+-- column can be dropped on rollback; deleting a bank clears it from its sessions.
+ALTER TABLE ideate_sessions ADD COLUMN question_bank_id TEXT;
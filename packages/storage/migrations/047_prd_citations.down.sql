@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback for PRD research citations
+-- ABOUTME: Drops the citation table and its indexes
+
+DROP INDEX IF EXISTS idx_prd_citations_source;
+DROP INDEX IF EXISTS idx_prd_citations_prd;
+DROP TABLE IF EXISTS prd_citations;
@@ -0,0 +1,24 @@
+-- ABOUTME: Migration adding research citations for generated PRDs
+-- ABOUTME: Links each PRD section to the stored research source (web page, competitor, lesson, pattern) that informed it
+
+-- source_id points at ideate_research_sources for web sources and is NULL for research
+-- kept as JSON on ideate_research. link is 'explicit' when the generator reported the
+-- citation and 'inferred' when the source was found mentioned in the section.
+CREATE TABLE IF NOT EXISTS prd_citations (
+    id TEXT PRIMARY KEY CHECK(length(id) >= 8),
+    prd_id TEXT NOT NULL,
+    session_id TEXT,
+    section TEXT NOT NULL,
+    source_type TEXT NOT NULL CHECK(source_type IN ('web_source', 'competitor', 'similar_project', 'reference', 'lesson', 'pattern', 'finding')),
+    source_id TEXT,
+    label TEXT NOT NULL,
+    url TEXT,
+    excerpt TEXT,
+    link TEXT NOT NULL DEFAULT 'inferred' CHECK(link IN ('explicit', 'inferred')),
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    FOREIGN KEY (prd_id) REFERENCES prds(id) ON DELETE CASCADE,
+    FOREIGN KEY (session_id) REFERENCES ideate_sessions(id) ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_prd_citations_prd ON prd_citations(prd_id, section);
+CREATE INDEX IF NOT EXISTS idx_prd_citations_source ON prd_citations(source_id);
@@ -0,0 +1,6 @@
+-- ABOUTME: Rollback for saved searches
+-- ABOUTME: Drops the saved search table and its indexes
+
+DROP INDEX IF EXISTS idx_saved_searches_user;
+DROP INDEX IF EXISTS idx_saved_searches_shortcut;
+DROP TABLE IF EXISTS saved_searches;
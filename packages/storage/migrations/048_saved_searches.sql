@@ -0,0 +1,18 @@
+-- ABOUTME: Migration adding per-user saved searches written in the search query language
+-- ABOUTME: Lets the dashboard and TUI offer common project and task filters behind a single key
+
+CREATE TABLE IF NOT EXISTS saved_searches (
+    id TEXT PRIMARY KEY DEFAULT (lower(hex(randomblob(8)))),
+    user_id TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    query TEXT NOT NULL,                     -- e.g. 'status:active tag:backend "auth"'
+    shortcut TEXT,                           -- single key that runs the search, unique per user
+    position INTEGER NOT NULL DEFAULT 0,     -- display order in pickers
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+    UNIQUE (user_id, name)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_saved_searches_shortcut
+    ON saved_searches(user_id, shortcut) WHERE shortcut IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user ON saved_searches(user_id, position);
@@ -0,0 +1,7 @@
+-- ABOUTME: Rollback for scheduled and queued agent executions
+-- ABOUTME: Removes executions that never left the queue and drops the scheduling columns
+
+DELETE FROM agent_executions WHERE status = 'queued';
+DROP INDEX IF EXISTS idx_agent_executions_queue;
+ALTER TABLE agent_executions DROP COLUMN concurrency_class;
+ALTER TABLE agent_executions DROP COLUMN not_before;
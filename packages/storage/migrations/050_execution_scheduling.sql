@@ -0,0 +1,9 @@
+-- ABOUTME: Migration adding scheduled and queued agent executions
+-- ABOUTME: Stores a not-before start time and a concurrency class; queued executions start once both allow
+
+-- Executions are created with status 'queued' when they have either. A queued execution
+-- starts once not_before has passed and no other execution of its concurrency class runs.
+ALTER TABLE agent_executions ADD COLUMN not_before TEXT;
+ALTER TABLE agent_executions ADD COLUMN concurrency_class TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_agent_executions_queue ON agent_executions(status, not_before);
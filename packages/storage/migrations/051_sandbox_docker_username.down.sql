@@ -0,0 +1,4 @@
+-- ABOUTME: Rollback migration that removes the docker_username column from sandbox_settings
+-- ABOUTME: Requires SQLite 3.35.0+ for DROP COLUMN support
+
+ALTER TABLE sandbox_settings DROP COLUMN docker_username;
@@ -0,0 +1,4 @@
+-- ABOUTME: Migration that adds the docker_username column to sandbox_settings
+-- ABOUTME: 002 was kept as a no-op assuming 001 had the column, but 001 never defined it
+
+ALTER TABLE sandbox_settings ADD COLUMN docker_username TEXT;
@@ -0,0 +1,35 @@
+-- ABOUTME: Rollback that restores the 041 project journal triggers
+-- ABOUTME: Scripts scrubbed from existing payloads are not restored
+
+DROP TRIGGER IF EXISTS projects_change_journal_insert;
+CREATE TRIGGER IF NOT EXISTS projects_change_journal_insert AFTER INSERT ON projects
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('project', NEW.id, 'create', json_object(
+        'id', NEW.id, 'name', NEW.name, 'project_root', NEW.project_root,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'rank', NEW.rank, 'setup_script', NEW.setup_script, 'dev_script', NEW.dev_script,
+        'cleanup_script', NEW.cleanup_script, 'task_source', NEW.task_source, 'tags', NEW.tags,
+        'manual_tasks', NEW.manual_tasks, 'mcp_servers', NEW.mcp_servers, 'git_repository', NEW.git_repository,
+        'github_owner', NEW.github_owner, 'github_repo', NEW.github_repo, 'github_sync_enabled', NEW.github_sync_enabled,
+        'github_labels_config', NEW.github_labels_config, 'github_default_assignee', NEW.github_default_assignee, 'created_at', NEW.created_at,
+        'updated_at', NEW.updated_at, 'owner_user_id', NEW.owner_user_id, 'version', NEW.version
+    ));
+END;
+
+DROP TRIGGER IF EXISTS projects_change_journal_update;
+CREATE TRIGGER IF NOT EXISTS projects_change_journal_update AFTER UPDATE ON projects
+FOR EACH ROW WHEN NEW.version <> OLD.version
+BEGIN
+    INSERT INTO change_journal (entity_type, entity_id, op, payload)
+    VALUES ('project', NEW.id, 'update', json_object(
+        'id', NEW.id, 'name', NEW.name, 'project_root', NEW.project_root,
+        'description', NEW.description, 'status', NEW.status, 'priority', NEW.priority,
+        'rank', NEW.rank, 'setup_script', NEW.setup_script, 'dev_script', NEW.dev_script,
+        'cleanup_script', NEW.cleanup_script, 'task_source', NEW.task_source, 'tags', NEW.tags,
+        'manual_tasks', NEW.manual_tasks, 'mcp_servers', NEW.mcp_servers, 'git_repository', NEW.git_repository,
+        'github_owner', NEW.github_owner, 'github_repo', NEW.github_repo, 'github_sync_enabled', NEW.github_sync_enabled,
+        'github_labels_config', NEW.github_labels_config, 'github_default_assignee', NEW.github_default_assignee, 'created_at', NEW.created_at,
+        'updated_at', NEW.updated_at, 'owner_user_id', NEW.owner_user_id, 'version', NEW.version
+    ));
+END;
@@ -0,0 +1,216 @@
+// ABOUTME: Change data capture journal of project, task, PRD, and tag mutations
+// ABOUTME: Reads the trigger-maintained journal as an ordered feed of changes with payload hashes
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::{Row, SqlitePool};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{StorageError, StorageResult};
+
+/// Page size when a consumer does not ask for one
+pub const DEFAULT_CHANGE_LIMIT: i64 = 100;
+
+/// Largest page a consumer can request
+pub const MAX_CHANGE_LIMIT: i64 = 1000;
+
+/// Days journal entries are kept before maintenance prunes them
+pub const CHANGE_JOURNAL_RETENTION_DAYS: i64 = 30;
+
+/// Kind of entity a change applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeEntity {
+    Project,
+    Task,
+    Prd,
+    Tag,
+}
+
+impl ChangeEntity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ChangeEntity::Project => "project",
+            ChangeEntity::Task => "task",
+            ChangeEntity::Prd => "prd",
+            ChangeEntity::Tag => "tag",
+        }
+    }
+}
+
+impl fmt::Display for ChangeEntity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChangeEntity {
+    type Err = StorageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "project" => Ok(ChangeEntity::Project),
+            "task" => Ok(ChangeEntity::Task),
+            "prd" => Ok(ChangeEntity::Prd),
+            "tag" => Ok(ChangeEntity::Tag),
+            other => Err(StorageError::Validation(format!(
+                "Unknown entity type '{}'. Must be one of: project, task, prd, tag",
+                other
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeOp {
+    Create,
+    Update,
+    Delete,
+}
+
+impl FromStr for ChangeOp {
+    type Err = StorageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "create" => Ok(ChangeOp::Create),
+            "update" => Ok(ChangeOp::Update),
+            "delete" => Ok(ChangeOp::Delete),
+            other => Err(StorageError::Database(format!(
+                "Invalid change journal op: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// One journal entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    /// Position in the feed; strictly increasing and never reused
+    pub seq: i64,
+    pub entity_type: ChangeEntity,
+    pub entity_id: String,
+    pub op: ChangeOp,
+    /// SHA-256 of the entity's row after the change; `None` for deletes
+    pub payload_hash: Option<String>,
+    pub changed_at: String,
+}
+
+/// A page of the change feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeFeed {
+    pub changes: Vec<Change>,
+    /// Pass as `since` to read the next page
+    pub next_since: i64,
+    pub has_more: bool,
+    /// Oldest entry still in the journal. A consumer whose `since` is below
+    /// `oldest_seq - 1` missed pruned entries and must resync from a snapshot.
+    pub oldest_seq: Option<i64>,
+    pub latest_seq: i64,
+}
+
+/// Reader over the `change_journal` table, which triggers fill on every
+/// project, task, PRD, and tag insert, update, and delete
+pub struct ChangeJournal {
+    pool: SqlitePool,
+}
+
+impl ChangeJournal {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Changes after `since` in sequence order, optionally for one entity type
+    pub async fn list_since(
+        &self,
+        since: i64,
+        limit: i64,
+        entity_type: Option<ChangeEntity>,
+    ) -> StorageResult<ChangeFeed> {
+        let limit = limit.clamp(1, MAX_CHANGE_LIMIT);
+        let entity_type = entity_type.map(ChangeEntity::as_str);
+
+        // One extra row tells us whether another page follows
+        let rows = sqlx::query(
+            r#"
+            SELECT seq, entity_type, entity_id, op, payload, changed_at
+            FROM change_journal
+            WHERE seq > ? AND (? IS NULL OR entity_type = ?)
+            ORDER BY seq
+            LIMIT ?
+            "#,
+        )
+        .bind(since)
+        .bind(entity_type)
+        .bind(entity_type)
+        .bind(limit + 1)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let has_more = rows.len() as i64 > limit;
+        let changes = rows
+            .iter()
+            .take(limit as usize)
+            .map(row_to_change)
+            .collect::<StorageResult<Vec<_>>>()?;
+
+        let bounds =
+            sqlx::query("SELECT MIN(seq) AS oldest, MAX(seq) AS latest FROM change_journal")
+                .fetch_one(&self.pool)
+                .await?;
+        let oldest_seq: Option<i64> = bounds.try_get("oldest")?;
+        let latest_seq: Option<i64> = bounds.try_get("latest")?;
+
+        Ok(ChangeFeed {
+            next_since: changes.last().map_or(since, |change| change.seq),
+            changes,
+            has_more,
+            oldest_seq,
+            latest_seq: latest_seq.unwrap_or(0),
+        })
+    }
+
+    /// Delete entries older than `retention_days`, returning how many were removed.
+    /// The newest entry is always kept so `latest_seq` stays meaningful.
+    pub async fn prune(&self, retention_days: i64) -> StorageResult<u64> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM change_journal
+            WHERE changed_at < strftime('%Y-%m-%dT%H:%M:%SZ', 'now', ?)
+              AND seq < (SELECT MAX(seq) FROM change_journal)
+            "#,
+        )
+        .bind(format!("-{} days", retention_days))
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+fn row_to_change(row: &sqlx::sqlite::SqliteRow) -> StorageResult<Change> {
+    let entity_type: String = row.try_get("entity_type")?;
+    let op: String = row.try_get("op")?;
+    let payload: Option<String> = row.try_get("payload")?;
+
+    Ok(Change {
+        seq: row.try_get("seq")?,
+        entity_type: entity_type
+            .parse()
+            .map_err(|_| StorageError::Database(format!("Invalid entity type: {}", entity_type)))?,
+        entity_id: row.try_get("entity_id")?,
+        op: op.parse()?,
+        payload_hash: payload.as_deref().map(payload_hash),
+        changed_at: row.try_get("changed_at")?,
+    })
+}
+
+/// Hex SHA-256 of a journal payload
+pub fn payload_hash(payload: &str) -> String {
+    Sha256::digest(payload.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
@@ -3,8 +3,8 @@ use std::sync::Arc;
 use tracing::{debug, info};
 
 use super::{
-    sqlite::SqliteStorage, ProjectStorage, StorageConfig, StorageError, StorageProvider,
-    StorageResult,
+    sqlite::SqliteStorage, ConnectionRouter, PoolMetrics, ProjectStorage, QueryRoute,
+    StorageConfig, StorageError, StorageProvider, StorageResult,
 };
 
 /// Factory for creating storage instances
@@ -24,32 +24,32 @@ impl StorageFactory {
             }
             StorageProvider::Cloud {
                 provider,
-                local_cache: _,
-            } => {
-                // For future implementation
-                match provider {
-                    super::CloudProvider::S3 { bucket, region } => {
-                        // TODO: Implement S3-backed storage
-                        info!(
-                            "S3 storage not yet implemented (bucket: {}, region: {})",
-                            bucket, region
-                        );
-                        Err(StorageError::Database(
-                            "S3 storage not yet implemented".to_string(),
-                        ))
-                    }
-                    super::CloudProvider::Convex { deployment_url } => {
-                        // TODO: Implement Convex-backed storage
-                        info!(
-                            "Convex storage not yet implemented (deployment: {})",
-                            deployment_url
-                        );
-                        Err(StorageError::Database(
-                            "Convex storage not yet implemented".to_string(),
-                        ))
-                    }
+                local_cache,
+            } => match provider {
+                // Projects live in a local SQLite cache; snapshots are pushed to
+                // the bucket with multipart uploads by `orkee cloud sync --s3`
+                // (see `orkee_cloud::s3`)
+                super::CloudProvider::S3 { bucket, region } => {
+                    let path = local_cache.join("orkee.db");
+                    info!(
+                        "Initializing SQLite cache at {:?} for S3 bucket {} ({})",
+                        path, bucket, region
+                    );
+                    let storage = SqliteStorage::new(StorageConfig {
+                        provider: StorageProvider::Sqlite { path },
+                        ..config.clone()
+                    })
+                    .await?;
+                    storage.initialize().await?;
+                    Ok(Box::new(storage))
                 }
-            }
+                super::CloudProvider::Convex { deployment_url } => {
+                    Err(StorageError::Unsupported(format!(
+                        "Convex ({}) is not implemented; use SQLite storage and sync with `orkee cloud sync`",
+                        deployment_url
+                    )))
+                }
+            },
         }
     }
 
@@ -91,6 +91,7 @@ impl StorageFactory {
             enable_fts: true,
             max_connections: 1,
             busy_timeout_seconds: 5,
+            read_replica: None,
         };
         Self::create_storage(config).await
     }
@@ -125,6 +126,19 @@ impl StorageManager {
         &self.config
     }
 
+    /// Get the connection router, for backends that expose SQL pools
+    pub fn router(&self) -> Option<ConnectionRouter> {
+        self.storage.router()
+    }
+
+    /// Get a SQL pool for a query, following the routing hint.
+    /// Heavy read endpoints (search, lists, graphs) should pass `QueryRoute::Replica`.
+    pub fn pool_for(&self, route: QueryRoute) -> Option<sqlx::SqlitePool> {
+        self.storage
+            .router()
+            .map(|router| router.pool(route).clone())
+    }
+
     /// Recreate the storage instance with new configuration
     pub async fn reconfigure(&mut self, config: StorageConfig) -> StorageResult<()> {
         info!("Reconfiguring storage manager");
@@ -167,6 +181,7 @@ impl StorageManager {
             storage_size_bytes: info.size_bytes,
             last_modified: info.last_modified,
             provider: info.provider,
+            pools: info.pools,
             capabilities: info.capabilities,
         })
     }
@@ -181,6 +196,8 @@ pub struct StorageStats {
     pub storage_size_bytes: u64,
     pub last_modified: chrono::DateTime<chrono::Utc>,
     pub provider: String,
+    /// Connection pools with their routing metrics, primary first
+    pub pools: Vec<PoolMetrics>,
     pub capabilities: super::StorageCapabilities,
 }
 
@@ -260,6 +277,7 @@ mod tests {
             enable_fts: true,
             max_connections: 5,
             busy_timeout_seconds: 10,
+            read_replica: None,
         };
 
         let storage = StorageFactory::create_storage(config).await.unwrap();
@@ -269,6 +287,45 @@ mod tests {
         assert_eq!(projects.len(), 0);
     }
 
+    #[tokio::test]
+    async fn test_factory_s3_storage_uses_local_cache() {
+        let cache_dir = tempdir().unwrap();
+        let config = StorageConfig {
+            provider: StorageProvider::Cloud {
+                provider: crate::CloudProvider::S3 {
+                    bucket: "orkee-snapshots".to_string(),
+                    region: "us-east-1".to_string(),
+                },
+                local_cache: cache_dir.path().to_path_buf(),
+            },
+            read_replica: None,
+            ..StorageConfig::default()
+        };
+
+        let storage = StorageFactory::create_storage(config).await.unwrap();
+        assert!(storage.list_projects().await.unwrap().is_empty());
+        assert!(cache_dir.path().join("orkee.db").exists());
+    }
+
+    #[tokio::test]
+    async fn test_factory_rejects_convex_storage() {
+        let config = StorageConfig {
+            provider: StorageProvider::Cloud {
+                provider: crate::CloudProvider::Convex {
+                    deployment_url: "https://example.convex.cloud".to_string(),
+                },
+                local_cache: tempdir().unwrap().path().to_path_buf(),
+            },
+            ..StorageConfig::default()
+        };
+
+        match StorageFactory::create_storage(config).await {
+            Err(StorageError::Unsupported(message)) => assert!(message.contains("Convex")),
+            Err(e) => panic!("expected an unsupported provider error, got {}", e),
+            Ok(_) => panic!("Convex storage should not be created"),
+        }
+    }
+
     #[tokio::test]
     async fn test_factory_create_default_storage() {
         // This should create a storage in the default location
@@ -300,6 +357,7 @@ mod tests {
             enable_fts: true,
             max_connections: 5,
             busy_timeout_seconds: 10,
+            read_replica: None,
         };
 
         let manager = StorageManager::new(config).await.unwrap();
@@ -334,6 +392,42 @@ mod tests {
         assert_eq!(stats.archived_projects, 0);
     }
 
+    #[tokio::test]
+    async fn test_storage_manager_read_replica_routing() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        let config = StorageConfig {
+            provider: StorageProvider::Sqlite { path: db_path },
+            enable_wal: true,
+            enable_fts: true,
+            max_connections: 5,
+            busy_timeout_seconds: 10,
+            read_replica: Some(crate::ReadReplicaConfig::SqliteReadOnly { max_connections: 2 }),
+        };
+
+        let manager = StorageManager::new(config).await.unwrap();
+        let router = manager.router().unwrap();
+        assert!(router.has_replica());
+
+        // Lists are served by the replica
+        manager.storage().list_projects().await.unwrap();
+
+        let stats = manager.get_stats().await.unwrap();
+        assert_eq!(stats.pools.len(), 2);
+        let replica = &stats.pools[1];
+        assert_eq!(replica.route, QueryRoute::Replica);
+        assert_eq!(replica.max_connections, 2);
+        assert!(replica.queries_routed >= 1);
+
+        // The replica pool refuses writes
+        let reader = manager.pool_for(QueryRoute::Replica).unwrap();
+        assert!(sqlx::query("DELETE FROM projects")
+            .execute(&reader)
+            .await
+            .is_err());
+    }
+
     #[tokio::test]
     async fn test_storage_ext_methods() {
         let temp_dir = tempdir().unwrap();
@@ -345,6 +439,7 @@ mod tests {
             enable_fts: true,
             max_connections: 5,
             busy_timeout_seconds: 10,
+            read_replica: None,
         };
 
         let storage = StorageFactory::create_storage(config).await.unwrap();
@@ -0,0 +1,337 @@
+// ABOUTME: Transparent at-rest encryption for designated sensitive columns
+// ABOUTME: Seals values on write, opens them on read, and backfills or re-keys existing rows
+
+use serde::Serialize;
+use sqlx::{Row, SqliteConnection, SqlitePool};
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+use tracing::info;
+
+use super::{StorageError, StorageResult};
+
+/// Marks a stored value as sealed; the rest is the cipher's output
+pub const SEALED_PREFIX: &str = "enc:v1:";
+
+/// Reversible encryption of a single field value
+///
+/// Implemented by `orkee_security::ApiKeyEncryption`; storage only sees this
+/// trait so it does not depend on the security crate.
+pub trait FieldCipher: Send + Sync {
+    fn encrypt(&self, plaintext: &str) -> StorageResult<String>;
+    fn decrypt(&self, ciphertext: &str) -> StorageResult<String>;
+}
+
+/// The process-wide cipher, or why sensitive fields cannot be used
+enum CipherState {
+    /// Encryption is not configured; values are stored and read as-is
+    Unset,
+    /// Encryption is configured but its key is unavailable
+    Locked(String),
+    Installed(Arc<dyn FieldCipher>),
+}
+
+static CIPHER: RwLock<CipherState> = RwLock::new(CipherState::Unset);
+
+fn state() -> std::sync::RwLockReadGuard<'static, CipherState> {
+    CIPHER.read().unwrap_or_else(|e| e.into_inner())
+}
+
+fn installed() -> Option<Arc<dyn FieldCipher>> {
+    match &*state() {
+        CipherState::Installed(cipher) => Some(cipher.clone()),
+        _ => None,
+    }
+}
+
+/// Install the process-wide cipher. Returns false if one was already installed.
+pub fn install(cipher: Arc<dyn FieldCipher>) -> bool {
+    let mut slot = CIPHER.write().unwrap_or_else(|e| e.into_inner());
+    if matches!(*slot, CipherState::Installed(_)) {
+        return false;
+    }
+    *slot = CipherState::Installed(cipher);
+    true
+}
+
+/// Swap the process-wide cipher after the encryption key changed. Sealed rows
+/// must already have been moved to it with [`rotate_existing_rows`].
+pub fn replace(cipher: Arc<dyn FieldCipher>) {
+    *CIPHER.write().unwrap_or_else(|e| e.into_inner()) = CipherState::Installed(cipher);
+}
+
+/// Refuse to seal or open values because the configured key is unavailable,
+/// e.g. password mode without the password. Writes fail instead of storing
+/// plaintext. Does nothing once a cipher is installed.
+pub fn lock(reason: impl Into<String>) {
+    let mut slot = CIPHER.write().unwrap_or_else(|e| e.into_inner());
+    if !matches!(*slot, CipherState::Installed(_)) {
+        *slot = CipherState::Locked(reason.into());
+    }
+}
+
+/// Whether a cipher has been installed for this process
+pub fn is_installed() -> bool {
+    installed().is_some()
+}
+
+/// Whether sensitive fields were locked with [`lock`]
+pub fn is_locked() -> bool {
+    matches!(*state(), CipherState::Locked(_))
+}
+
+fn locked_error(reason: &str) -> StorageError {
+    StorageError::Encryption(format!("Sensitive fields are locked: {}", reason))
+}
+
+/// Whether a stored value carries the sealed prefix
+pub fn is_sealed(value: &str) -> bool {
+    value.starts_with(SEALED_PREFIX)
+}
+
+fn seal_with(cipher: &dyn FieldCipher, value: &str) -> StorageResult<String> {
+    Ok(format!("{}{}", SEALED_PREFIX, cipher.encrypt(value)?))
+}
+
+fn open_with(cipher: &dyn FieldCipher, value: String) -> StorageResult<String> {
+    match value.strip_prefix(SEALED_PREFIX) {
+        Some(sealed) => cipher.decrypt(sealed),
+        None => Ok(value),
+    }
+}
+
+/// Whether a value was sealed by `cipher`, checked by decrypting it. Plaintext
+/// that merely starts with the prefix does not count.
+fn is_sealed_by(cipher: &dyn FieldCipher, value: &str) -> bool {
+    value
+        .strip_prefix(SEALED_PREFIX)
+        .is_some_and(|sealed| cipher.decrypt(sealed).is_ok())
+}
+
+/// Seal a value for storage. When encryption is not configured the value is
+/// stored as-is and picked up later by [`encrypt_existing_rows`]; when it is
+/// configured but locked, the write fails.
+pub fn seal(value: &str) -> StorageResult<String> {
+    match &*state() {
+        CipherState::Installed(cipher) => seal_with(cipher.as_ref(), value),
+        CipherState::Locked(reason) => Err(locked_error(reason)),
+        CipherState::Unset => Ok(value.to_string()),
+    }
+}
+
+/// Open a stored value. Plaintext written before encryption passes through,
+/// as does every value when encryption is not configured, since plaintext may
+/// itself start with the sealed prefix.
+pub fn open(value: String) -> StorageResult<String> {
+    if !is_sealed(&value) {
+        return Ok(value);
+    }
+    match &*state() {
+        CipherState::Installed(cipher) => open_with(cipher.as_ref(), value),
+        CipherState::Locked(reason) => Err(locked_error(reason)),
+        CipherState::Unset => Ok(value),
+    }
+}
+
+pub fn seal_opt(value: Option<&String>) -> StorageResult<Option<String>> {
+    value.map(|v| seal(v)).transpose()
+}
+
+pub fn open_opt(value: Option<String>) -> StorageResult<Option<String>> {
+    value.map(open).transpose()
+}
+
+/// Seal every value of a string map, leaving keys readable
+pub fn seal_map(map: &BTreeMap<String, String>) -> StorageResult<BTreeMap<String, String>> {
+    map.iter().map(|(k, v)| Ok((k.clone(), seal(v)?))).collect()
+}
+
+/// Open every value of a string map
+pub fn open_map(map: BTreeMap<String, String>) -> StorageResult<BTreeMap<String, String>> {
+    map.into_iter().map(|(k, v)| Ok((k, open(v)?))).collect()
+}
+
+/// How a column holds its sensitive data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// The whole TEXT value is sealed
+    Text,
+    /// A JSON object whose string values are sealed, keeping the column valid JSON
+    JsonObjectValues,
+}
+
+/// A column whose values are encrypted at rest
+#[derive(Debug, Clone, Copy)]
+pub struct EncryptedField {
+    pub table: &'static str,
+    pub key_column: &'static str,
+    pub column: &'static str,
+    pub kind: FieldKind,
+}
+
+const fn field(
+    table: &'static str,
+    key_column: &'static str,
+    column: &'static str,
+    kind: FieldKind,
+) -> EncryptedField {
+    EncryptedField {
+        table,
+        key_column,
+        column,
+        kind,
+    }
+}
+
+/// Every column sealed by the storage layer and its callers
+pub const ENCRYPTED_FIELDS: &[EncryptedField] = &[
+    field("projects", "id", "setup_script", FieldKind::Text),
+    field("projects", "id", "dev_script", FieldKind::Text),
+    field("projects", "id", "cleanup_script", FieldKind::Text),
+    field(
+        "project_mcp_servers",
+        "id",
+        "env",
+        FieldKind::JsonObjectValues,
+    ),
+    field("oauth_tokens", "id", "scope", FieldKind::Text),
+    field("oauth_tokens", "id", "account_email", FieldKind::Text),
+];
+
+/// Rows sealed in one column by [`encrypt_existing_rows`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldEncryptionCount {
+    pub table: String,
+    pub column: String,
+    pub rows_encrypted: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldEncryptionReport {
+    pub fields: Vec<FieldEncryptionCount>,
+}
+
+impl FieldEncryptionReport {
+    pub fn total(&self) -> u64 {
+        self.fields.iter().map(|f| f.rows_encrypted).sum()
+    }
+}
+
+/// Seal every plaintext value in [`ENCRYPTED_FIELDS`] in a single transaction.
+/// Values the installed cipher can already open are left alone, so running it
+/// again is a no-op.
+pub async fn encrypt_existing_rows(pool: &SqlitePool) -> StorageResult<FieldEncryptionReport> {
+    let cipher = installed()
+        .ok_or_else(|| StorageError::Encryption("No field cipher is installed".to_string()))?;
+
+    let mut tx = pool.begin().await?;
+    let report = rewrite_fields(&mut tx, |value| {
+        if is_sealed_by(cipher.as_ref(), value) {
+            Ok(None)
+        } else {
+            seal_with(cipher.as_ref(), value).map(Some)
+        }
+    })
+    .await?;
+    tx.commit().await?;
+
+    info!(
+        "Encrypted {} existing rows across {} fields",
+        report.total(),
+        ENCRYPTED_FIELDS.len()
+    );
+    Ok(report)
+}
+
+/// Re-seal every sealed value in [`ENCRYPTED_FIELDS`] from one key to another,
+/// for when the encryption mode or password changes. Runs on the caller's
+/// connection so it can share the transaction that stores the new settings.
+pub async fn rotate_existing_rows(
+    conn: &mut SqliteConnection,
+    from: &dyn FieldCipher,
+    to: &dyn FieldCipher,
+) -> StorageResult<FieldEncryptionReport> {
+    let report = rewrite_fields(conn, |value| {
+        if !is_sealed(value) {
+            return Ok(None);
+        }
+        let plaintext = open_with(from, value.to_string())?;
+        seal_with(to, &plaintext).map(Some)
+    })
+    .await?;
+
+    info!(
+        "Re-encrypted {} rows across {} fields",
+        report.total(),
+        ENCRYPTED_FIELDS.len()
+    );
+    Ok(report)
+}
+
+/// Apply `rewrite` to every stored value of [`ENCRYPTED_FIELDS`], updating the
+/// rows where it returns a new value
+async fn rewrite_fields<F>(
+    conn: &mut SqliteConnection,
+    mut rewrite: F,
+) -> StorageResult<FieldEncryptionReport>
+where
+    F: FnMut(&str) -> StorageResult<Option<String>>,
+{
+    let mut report = FieldEncryptionReport::default();
+
+    for field in ENCRYPTED_FIELDS {
+        let select = format!(
+            "SELECT {key}, {col} FROM {table} WHERE {col} IS NOT NULL",
+            key = field.key_column,
+            col = field.column,
+            table = field.table
+        );
+        let update = format!(
+            "UPDATE {table} SET {col} = ? WHERE {key} = ?",
+            table = field.table,
+            col = field.column,
+            key = field.key_column
+        );
+
+        let rows = sqlx::query(&select).fetch_all(&mut *conn).await?;
+        let mut rows_encrypted = 0;
+
+        for row in rows {
+            let key: String = row.try_get(0)?;
+            let value: String = row.try_get(1)?;
+
+            let rewritten = match field.kind {
+                FieldKind::Text => rewrite(&value)?,
+                FieldKind::JsonObjectValues => {
+                    let mut map: BTreeMap<String, String> = serde_json::from_str(&value)?;
+                    let mut changed = false;
+                    for entry in map.values_mut() {
+                        if let Some(new_value) = rewrite(entry)? {
+                            *entry = new_value;
+                            changed = true;
+                        }
+                    }
+                    changed.then(|| serde_json::to_string(&map)).transpose()?
+                }
+            };
+
+            if let Some(rewritten) = rewritten {
+                sqlx::query(&update)
+                    .bind(rewritten)
+                    .bind(&key)
+                    .execute(&mut *conn)
+                    .await?;
+                rows_encrypted += 1;
+            }
+        }
+
+        report.fields.push(FieldEncryptionCount {
+            table: field.table.to_string(),
+            column: field.column.to_string(),
+            rows_encrypted,
+        });
+    }
+
+    Ok(report)
+}
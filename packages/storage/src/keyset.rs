@@ -0,0 +1,198 @@
+// ABOUTME: Keyset (cursor) pagination helpers shared by list queries
+// ABOUTME: Pages on (created_at, id) so deep pages cost the same as the first
+
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+use super::{StorageError, StorageResult};
+
+/// The (created_at, id) of the last row on a page; the next page starts after it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetPosition {
+    /// Raw `created_at` text as stored, so comparisons match the index exactly
+    pub created_at: String,
+    pub id: String,
+}
+
+impl KeysetPosition {
+    pub fn new(created_at: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            created_at: created_at.into(),
+            id: id.into(),
+        }
+    }
+
+    /// Read the position of a row selected with `created_at` and `id` columns
+    pub fn from_row(row: &SqliteRow) -> StorageResult<Self> {
+        Ok(Self {
+            created_at: row.try_get("created_at").map_err(StorageError::Sqlx)?,
+            id: row.try_get("id").map_err(StorageError::Sqlx)?,
+        })
+    }
+}
+
+/// Sort direction of a keyset-paginated query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeysetOrder {
+    Ascending,
+    Descending,
+}
+
+impl KeysetOrder {
+    /// Predicate selecting rows after a position; binds `created_at` then `id`
+    ///
+    /// `prefix` is the table alias including the dot (e.g. `"t."`), or empty.
+    pub fn after_clause(self, prefix: &str) -> String {
+        let op = match self {
+            KeysetOrder::Ascending => ">",
+            KeysetOrder::Descending => "<",
+        };
+        format!("({p}created_at, {p}id) {op} (?, ?)", p = prefix, op = op)
+    }
+
+    /// `ORDER BY` terms matching [`after_clause`](Self::after_clause)
+    pub fn order_by(self, prefix: &str) -> String {
+        let dir = match self {
+            KeysetOrder::Ascending => "ASC",
+            KeysetOrder::Descending => "DESC",
+        };
+        format!("{p}created_at {d}, {p}id {d}", p = prefix, d = dir)
+    }
+}
+
+/// One page of a keyset-paginated query
+#[derive(Debug, Clone)]
+pub struct KeysetPage<T> {
+    pub items: Vec<T>,
+    /// Total rows matching the query's filters, ignoring the position
+    pub total: i64,
+    /// Where the next page starts; `None` on the last page
+    pub next: Option<KeysetPosition>,
+}
+
+impl<T> KeysetPage<T> {
+    /// Build a page from rows fetched with `LIMIT limit + 1`
+    ///
+    /// The extra row only signals that another page exists and is dropped.
+    pub fn from_rows(mut rows: Vec<(T, KeysetPosition)>, limit: i64, total: i64) -> Self {
+        let limit = limit.max(0) as usize;
+        let has_more = rows.len() > limit;
+        rows.truncate(limit);
+
+        let next = if has_more {
+            rows.last().map(|(_, position)| position.clone())
+        } else {
+            None
+        };
+
+        Self {
+            items: rows.into_iter().map(|(item, _)| item).collect(),
+            total,
+            next,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(n: usize) -> Vec<(usize, KeysetPosition)> {
+        (0..n)
+            .map(|i| {
+                (
+                    i,
+                    KeysetPosition::new(format!("2025-01-0{}", i + 1), i.to_string()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_page_with_more_rows() {
+        let page = KeysetPage::from_rows(rows(4), 3, 10);
+        assert_eq!(page.items, vec![0, 1, 2]);
+        assert_eq!(page.total, 10);
+        assert_eq!(page.next, Some(KeysetPosition::new("2025-01-03", "2")));
+    }
+
+    #[test]
+    fn test_last_page_has_no_next() {
+        let page = KeysetPage::from_rows(rows(3), 3, 3);
+        assert_eq!(page.items.len(), 3);
+        assert!(page.next.is_none());
+    }
+
+    #[test]
+    fn test_clauses() {
+        assert_eq!(
+            KeysetOrder::Ascending.after_clause("t."),
+            "(t.created_at, t.id) > (?, ?)"
+        );
+        assert_eq!(
+            KeysetOrder::Descending.order_by(""),
+            "created_at DESC, id DESC"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_keyset_query_walks_all_rows() {
+        let pool = sqlx::SqlitePool::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE items (id TEXT PRIMARY KEY, created_at TEXT NOT NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Duplicate timestamps must still page without skipping or repeating rows
+        for (id, created_at) in [
+            ("a", "t1"),
+            ("b", "t1"),
+            ("c", "t2"),
+            ("d", "t2"),
+            ("e", "t3"),
+        ] {
+            sqlx::query("INSERT INTO items (id, created_at) VALUES (?, ?)")
+                .bind(id)
+                .bind(created_at)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let order = KeysetOrder::Descending;
+        let mut seen = Vec::new();
+        let mut position: Option<KeysetPosition> = None;
+        loop {
+            let sql = match &position {
+                Some(_) => format!(
+                    "SELECT * FROM items WHERE {} ORDER BY {} LIMIT 3",
+                    order.after_clause(""),
+                    order.order_by("")
+                ),
+                None => format!(
+                    "SELECT * FROM items ORDER BY {} LIMIT 3",
+                    order.order_by("")
+                ),
+            };
+            let mut query = sqlx::query(&sql);
+            if let Some(position) = &position {
+                query = query.bind(&position.created_at).bind(&position.id);
+            }
+            let fetched = query.fetch_all(&pool).await.unwrap();
+            let rows = fetched
+                .iter()
+                .map(|row| {
+                    let position = KeysetPosition::from_row(row).unwrap();
+                    (position.id.clone(), position)
+                })
+                .collect();
+            let page = KeysetPage::from_rows(rows, 2, 5);
+            seen.extend(page.items);
+            match page.next {
+                Some(next) => position = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen, vec!["e", "d", "c", "b", "a"]);
+    }
+}
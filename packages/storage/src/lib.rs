@@ -8,14 +8,26 @@ use std::path::PathBuf;
 use thiserror::Error;
 
 // Re-export modules
+pub mod change_journal;
 pub mod factory;
+pub mod field_encryption;
+pub mod integrity;
+pub mod keyset;
 pub mod legacy;
+pub mod maintenance;
+pub mod migration_backup;
 pub mod model_preferences;
+pub mod routing;
+pub mod search;
 pub mod sqlite;
+pub mod view_preferences;
 
 #[cfg(test)]
 pub mod test_utils;
 
+pub use keyset::{KeysetOrder, KeysetPage, KeysetPosition};
+pub use routing::{ConnectionRouter, PoolMetrics, QueryRoute, ReadReplicaConfig};
+
 /// Storage errors
 #[derive(Error, Debug)]
 pub enum StorageError {
@@ -55,6 +67,10 @@ pub enum StorageError {
     DuplicateName(String),
     #[error("Duplicate project path: {0}")]
     DuplicatePath(String),
+    #[error("Version conflict: expected version {expected}, found {actual}")]
+    VersionConflict { expected: i64, actual: i64 },
+    #[error("Unsupported storage provider: {0}")]
+    Unsupported(String),
 }
 
 pub type StorageResult<T> = Result<T, StorageError>;
@@ -98,6 +114,8 @@ pub struct StorageConfig {
     pub enable_fts: bool,
     pub max_connections: u32,
     pub busy_timeout_seconds: u64,
+    /// Where read-heavy queries go; `None` keeps everything on the primary
+    pub read_replica: Option<ReadReplicaConfig>,
 }
 
 impl Default for StorageConfig {
@@ -110,6 +128,7 @@ impl Default for StorageConfig {
             enable_fts: true,
             max_connections: 10,
             busy_timeout_seconds: 30,
+            read_replica: Some(ReadReplicaConfig::default()),
         }
     }
 }
@@ -158,6 +177,12 @@ pub trait ProjectStorage: Send + Sync {
     // Storage information
     async fn get_storage_info(&self) -> StorageResult<StorageInfo>;
 
+    /// Connection router for backends that expose SQL pools, so callers can
+    /// route their own queries with a `QueryRoute` hint
+    fn router(&self) -> Option<ConnectionRouter> {
+        None
+    }
+
     // Cloud sync operations (for future use)
     async fn export_snapshot(&self) -> StorageResult<Vec<u8>>;
     async fn import_snapshot(&self, data: &[u8]) -> StorageResult<ImportResult>;
@@ -210,6 +235,13 @@ pub struct StorageInfo {
     pub total_projects: usize,
     pub last_modified: DateTime<Utc>,
     pub size_bytes: u64,
+    /// Bytes held by unused pages that maintenance can reclaim
+    pub free_bytes: u64,
+    pub wal_size_bytes: u64,
+    /// Per-table and per-index sizes, largest first
+    pub tables: Vec<maintenance::TableSize>,
+    /// Connection pools with their routing metrics, primary first
+    pub pools: Vec<PoolMetrics>,
     pub capabilities: StorageCapabilities,
 }
 
@@ -0,0 +1,318 @@
+// ABOUTME: Scheduled SQLite maintenance and database size statistics
+// ABOUTME: Runs PRAGMA optimize, incremental vacuum, and WAL checkpoints; reports per-table sizes
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::change_journal::{ChangeJournal, CHANGE_JOURNAL_RETENTION_DAYS};
+use crate::StorageResult;
+
+/// How often the background maintenance task runs
+pub const MAINTENANCE_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// `PRAGMA auto_vacuum` value for incremental mode
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+
+/// On-disk size of a single table or index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableSize {
+    pub name: String,
+    /// Owning table for indexes; same as `name` for tables
+    pub table: String,
+    pub is_index: bool,
+    pub size_bytes: u64,
+    pub page_count: u64,
+}
+
+/// Size breakdown of the database file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseStats {
+    pub page_size: u64,
+    pub page_count: u64,
+    /// Size of the main database file (`page_size * page_count`)
+    pub size_bytes: u64,
+    /// Bytes held by unused pages that a vacuum would return to the OS
+    pub free_bytes: u64,
+    /// Size of the write-ahead log, if one exists on disk
+    pub wal_size_bytes: u64,
+    pub auto_vacuum: String,
+    /// Tables and indexes, largest first
+    pub tables: Vec<TableSize>,
+}
+
+/// Outcome of a maintenance run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceReport {
+    pub started_at: DateTime<Utc>,
+    pub duration_ms: u64,
+    pub size_before_bytes: u64,
+    pub size_after_bytes: u64,
+    pub reclaimed_bytes: u64,
+    /// True when the database was converted to incremental auto-vacuum with a full VACUUM
+    pub full_vacuum: bool,
+    /// Frames moved from the WAL into the database, when WAL mode is active
+    pub wal_frames_checkpointed: Option<i64>,
+    /// Change journal entries removed for being past the retention window
+    #[serde(default)]
+    pub change_journal_pruned: u64,
+}
+
+/// Collect page-level size statistics for the database behind `pool`
+pub async fn database_stats(pool: &SqlitePool) -> StorageResult<DatabaseStats> {
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size")
+        .fetch_one(pool)
+        .await?;
+    let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(pool)
+        .await?;
+    let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+        .fetch_one(pool)
+        .await?;
+    let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+        .fetch_one(pool)
+        .await?;
+
+    let wal_size_bytes = database_path(pool)
+        .await?
+        .map(|path| {
+            let mut wal = path.into_os_string();
+            wal.push("-wal");
+            std::fs::metadata(wal).map(|m| m.len()).unwrap_or(0)
+        })
+        .unwrap_or(0);
+
+    Ok(DatabaseStats {
+        page_size: page_size as u64,
+        page_count: page_count as u64,
+        size_bytes: (page_size * page_count) as u64,
+        free_bytes: (page_size * freelist_count) as u64,
+        wal_size_bytes,
+        auto_vacuum: match auto_vacuum {
+            1 => "full",
+            AUTO_VACUUM_INCREMENTAL => "incremental",
+            _ => "none",
+        }
+        .to_string(),
+        tables: table_sizes(pool).await?,
+    })
+}
+
+/// Path of the main database file, or None for in-memory databases
+pub async fn database_path(pool: &SqlitePool) -> StorageResult<Option<PathBuf>> {
+    let rows = sqlx::query("PRAGMA database_list").fetch_all(pool).await?;
+    for row in rows {
+        let name: String = row.try_get("name")?;
+        let file: String = row.try_get("file")?;
+        if name == "main" && !file.is_empty() {
+            return Ok(Some(PathBuf::from(file)));
+        }
+    }
+    Ok(None)
+}
+
+async fn table_sizes(pool: &SqlitePool) -> StorageResult<Vec<TableSize>> {
+    let rows = match sqlx::query(
+        "SELECT s.name, COALESCE(m.tbl_name, s.name) AS tbl_name, m.type,
+                SUM(s.pgsize) AS size_bytes, COUNT(*) AS page_count
+         FROM dbstat s
+         LEFT JOIN sqlite_master m ON m.name = s.name
+         GROUP BY s.name
+         ORDER BY size_bytes DESC, s.name",
+    )
+    .fetch_all(pool)
+    .await
+    {
+        Ok(rows) => rows,
+        Err(e) => {
+            // dbstat is a compile-time option; report totals only if it is missing
+            debug!("Per-table size stats unavailable: {}", e);
+            return Ok(Vec::new());
+        }
+    };
+
+    rows.iter()
+        .map(|row| {
+            let kind: Option<String> = row.try_get("type")?;
+            Ok(TableSize {
+                name: row.try_get("name")?,
+                table: row.try_get("tbl_name")?,
+                is_index: kind.as_deref() == Some("index"),
+                size_bytes: row.try_get::<i64, _>("size_bytes")? as u64,
+                page_count: row.try_get::<i64, _>("page_count")? as u64,
+            })
+        })
+        .collect()
+}
+
+/// Run one round of maintenance: prune old change journal entries, refresh
+/// query planner statistics, return free pages to the OS, and truncate the WAL.
+///
+/// Databases created before incremental auto-vacuum was enabled are converted
+/// with a one-time full VACUUM, since SQLite only applies the mode change then.
+pub async fn run_maintenance(pool: &SqlitePool) -> StorageResult<MaintenanceReport> {
+    let started_at = Utc::now();
+    let timer = std::time::Instant::now();
+    let size_before = database_stats(pool).await?;
+
+    let change_journal_pruned = ChangeJournal::new(pool.clone())
+        .prune(CHANGE_JOURNAL_RETENTION_DAYS)
+        .await?;
+    if change_journal_pruned > 0 {
+        debug!("Pruned {} change journal entries", change_journal_pruned);
+    }
+
+    // Pragmas below are per-connection, so keep them on one connection
+    let mut conn = pool.acquire().await?;
+
+    sqlx::query("PRAGMA optimize").execute(&mut *conn).await?;
+
+    let auto_vacuum: i64 = sqlx::query_scalar("PRAGMA auto_vacuum")
+        .fetch_one(&mut *conn)
+        .await?;
+    let full_vacuum = auto_vacuum != AUTO_VACUUM_INCREMENTAL;
+    if full_vacuum {
+        info!("Converting database to incremental auto-vacuum");
+        sqlx::query("PRAGMA auto_vacuum = INCREMENTAL")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("VACUUM").execute(&mut *conn).await?;
+    } else {
+        sqlx::query("PRAGMA incremental_vacuum")
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(&mut *conn)
+        .await?;
+    let wal_frames_checkpointed = if journal_mode.eq_ignore_ascii_case("wal") {
+        let row = sqlx::query("PRAGMA wal_checkpoint(TRUNCATE)")
+            .fetch_one(&mut *conn)
+            .await?;
+        let busy: i64 = row.try_get(0)?;
+        if busy != 0 {
+            debug!("WAL checkpoint could not complete; readers are still active");
+        }
+        Some(row.try_get::<i64, _>(2)?)
+    } else {
+        None
+    };
+    drop(conn);
+
+    let size_after = database_stats(pool).await?;
+    let before = size_before.size_bytes + size_before.wal_size_bytes;
+    let after = size_after.size_bytes + size_after.wal_size_bytes;
+
+    let report = MaintenanceReport {
+        started_at,
+        duration_ms: timer.elapsed().as_millis() as u64,
+        size_before_bytes: before,
+        size_after_bytes: after,
+        reclaimed_bytes: before.saturating_sub(after),
+        full_vacuum,
+        wal_frames_checkpointed,
+        change_journal_pruned,
+    };
+    info!(
+        "Database maintenance finished in {}ms, reclaimed {} bytes",
+        report.duration_ms, report.reclaimed_bytes
+    );
+    Ok(report)
+}
+
+/// Run maintenance every `interval`, starting one interval after launch so
+/// startup is not slowed down
+pub fn spawn_maintenance_task(pool: SqlitePool, interval: Duration) {
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now() + interval;
+        let mut ticker = tokio::time::interval_at(start, interval);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = run_maintenance(&pool).await {
+                warn!("Scheduled database maintenance failed: {}", e);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::SqliteStorage;
+    use crate::{ProjectStorage, StorageConfig, StorageProvider};
+    use tempfile::TempDir;
+
+    async fn create_test_storage(dir: &TempDir) -> SqliteStorage {
+        let config = StorageConfig {
+            provider: StorageProvider::Sqlite {
+                path: dir.path().join("orkee.db"),
+            },
+            enable_wal: true,
+            enable_fts: true,
+            max_connections: 2,
+            busy_timeout_seconds: 10,
+            read_replica: None,
+        };
+        let storage = SqliteStorage::new(config).await.unwrap();
+        storage.initialize().await.unwrap();
+        storage
+    }
+
+    #[tokio::test]
+    async fn test_database_stats_reports_table_sizes() {
+        let dir = TempDir::new().unwrap();
+        let storage = create_test_storage(&dir).await;
+
+        let stats = database_stats(storage.pool()).await.unwrap();
+        assert_eq!(stats.size_bytes, stats.page_size * stats.page_count);
+        let projects = stats
+            .tables
+            .iter()
+            .find(|t| t.name == "projects")
+            .expect("projects table should be listed");
+        assert!(!projects.is_index);
+        assert!(projects.size_bytes > 0);
+        assert!(stats
+            .tables
+            .windows(2)
+            .all(|w| w[0].size_bytes >= w[1].size_bytes));
+    }
+
+    #[tokio::test]
+    async fn test_maintenance_reclaims_free_pages() {
+        let dir = TempDir::new().unwrap();
+        let storage = create_test_storage(&dir).await;
+        let pool = storage.pool();
+
+        sqlx::query("CREATE TABLE scratch (data TEXT)")
+            .execute(pool)
+            .await
+            .unwrap();
+        for _ in 0..200 {
+            sqlx::query("INSERT INTO scratch (data) VALUES (?)")
+                .bind("x".repeat(4096))
+                .execute(pool)
+                .await
+                .unwrap();
+        }
+        sqlx::query("DROP TABLE scratch")
+            .execute(pool)
+            .await
+            .unwrap();
+
+        let first = run_maintenance(pool).await.unwrap();
+        assert!(first.full_vacuum);
+        assert!(first.wal_frames_checkpointed.is_some());
+
+        let stats = database_stats(pool).await.unwrap();
+        assert_eq!(stats.auto_vacuum, "incremental");
+        assert_eq!(stats.free_bytes, 0);
+
+        let second = run_maintenance(pool).await.unwrap();
+        assert!(!second.full_vacuum);
+    }
+}
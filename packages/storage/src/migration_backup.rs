@@ -0,0 +1,345 @@
+// ABOUTME: Database snapshots taken before schema migrations run
+// ABOUTME: Records the applied migration set with each snapshot and restores one to roll a release back
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::migrate::Migrator;
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+use crate::maintenance::database_path;
+use crate::{StorageError, StorageResult};
+
+/// Migration backups kept by default; older ones are deleted after each new backup
+pub const DEFAULT_MIGRATION_BACKUP_RETENTION: usize = 5;
+
+/// Manifest files end in this suffix next to the snapshot they describe
+const MANIFEST_SUFFIX: &str = ".json";
+
+/// A snapshot of the database taken before pending migrations were applied
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationBackup {
+    /// File stem shared by the snapshot and its manifest
+    pub id: String,
+    pub path: PathBuf,
+    pub created_at: DateTime<Utc>,
+    /// Version of Orkee that took the snapshot
+    pub orkee_version: String,
+    /// Migrations already applied in the snapshot
+    pub applied_versions: Vec<i64>,
+    /// Migrations that were about to run when the snapshot was taken
+    pub pending_versions: Vec<i64>,
+    pub size_bytes: u64,
+}
+
+/// Directory holding the migration backups of the database at `db_path`
+pub fn backup_dir(db_path: &Path) -> PathBuf {
+    db_path
+        .parent()
+        .unwrap_or_else(|| Path::new("."))
+        .join("backups")
+        .join("migrations")
+}
+
+/// Backups to keep, overridden by `ORKEE_MIGRATION_BACKUP_RETENTION`
+pub fn retention_from_env() -> usize {
+    std::env::var("ORKEE_MIGRATION_BACKUP_RETENTION")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(DEFAULT_MIGRATION_BACKUP_RETENTION)
+}
+
+/// Run `migrator` against `pool`, snapshotting the database first when there are
+/// migrations to apply to an existing database.
+///
+/// Returns the backup that was taken, if any. In-memory and freshly created
+/// databases have nothing worth restoring and are migrated without a snapshot.
+pub async fn run_migrations_with_backup(
+    pool: &SqlitePool,
+    migrator: &Migrator,
+) -> StorageResult<Option<MigrationBackup>> {
+    let backup = match database_path(pool).await? {
+        Some(db_path) => backup_if_pending(pool, migrator, &db_path).await?,
+        None => None,
+    };
+
+    migrator.run(pool).await.map_err(|e| {
+        if let Some(backup) = &backup {
+            warn!(
+                "Migrations failed; the database from before this upgrade is saved as {} (restore it with `orkee db rollback`)",
+                backup.path.display()
+            );
+        }
+        StorageError::Migration(e)
+    })?;
+
+    Ok(backup)
+}
+
+async fn backup_if_pending(
+    pool: &SqlitePool,
+    migrator: &Migrator,
+    db_path: &Path,
+) -> StorageResult<Option<MigrationBackup>> {
+    let applied = applied_versions(pool).await?;
+    if applied.is_empty() {
+        return Ok(None);
+    }
+
+    let applied_set: HashSet<i64> = applied.iter().copied().collect();
+    let pending: Vec<i64> = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| m.version)
+        .filter(|version| !applied_set.contains(version))
+        .collect();
+    if pending.is_empty() {
+        return Ok(None);
+    }
+
+    let dir = backup_dir(db_path);
+    std::fs::create_dir_all(&dir)?;
+
+    let created_at = Utc::now();
+    let id = format!(
+        "orkee-{}-v{}",
+        created_at.format("%Y%m%dT%H%M%S%.3fZ"),
+        applied.last().copied().unwrap_or_default()
+    );
+    let path = dir.join(format!("{}.db", id));
+
+    // VACUUM INTO writes a consistent copy that includes un-checkpointed WAL frames
+    sqlx::query("VACUUM INTO ?")
+        .bind(path.to_string_lossy().to_string())
+        .execute(pool)
+        .await?;
+
+    let backup = MigrationBackup {
+        id,
+        size_bytes: std::fs::metadata(&path)?.len(),
+        path,
+        created_at,
+        orkee_version: env!("CARGO_PKG_VERSION").to_string(),
+        applied_versions: applied,
+        pending_versions: pending,
+    };
+    std::fs::write(
+        manifest_path(&backup.path),
+        serde_json::to_vec_pretty(&backup)?,
+    )?;
+    info!(
+        "Backed up database to {} before applying {} migration(s)",
+        backup.path.display(),
+        backup.pending_versions.len()
+    );
+
+    prune_migration_backups(db_path, retention_from_env())?;
+    Ok(Some(backup))
+}
+
+async fn applied_versions(pool: &SqlitePool) -> StorageResult<Vec<i64>> {
+    let exists: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'",
+    )
+    .fetch_one(pool)
+    .await?;
+    if exists == 0 {
+        return Ok(Vec::new());
+    }
+
+    Ok(sqlx::query_scalar(
+        "SELECT version FROM _sqlx_migrations WHERE success = 1 ORDER BY version",
+    )
+    .fetch_all(pool)
+    .await?)
+}
+
+fn manifest_path(snapshot: &Path) -> PathBuf {
+    let mut manifest = snapshot.as_os_str().to_owned();
+    manifest.push(MANIFEST_SUFFIX);
+    PathBuf::from(manifest)
+}
+
+/// Migration backups of the database at `db_path`, newest first
+pub fn list_migration_backups(db_path: &Path) -> StorageResult<Vec<MigrationBackup>> {
+    let dir = backup_dir(db_path);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if !path.to_string_lossy().ends_with(MANIFEST_SUFFIX) {
+            continue;
+        }
+        let backup: MigrationBackup = match std::fs::read(&path)
+            .map_err(StorageError::from)
+            .and_then(|data| Ok(serde_json::from_slice(&data)?))
+        {
+            Ok(backup) => backup,
+            Err(e) => {
+                warn!(
+                    "Skipping unreadable backup manifest {}: {}",
+                    path.display(),
+                    e
+                );
+                continue;
+            }
+        };
+        if backup.path.exists() {
+            backups.push(backup);
+        }
+    }
+
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+    Ok(backups)
+}
+
+/// Delete all but the newest `keep` migration backups
+pub fn prune_migration_backups(db_path: &Path, keep: usize) -> StorageResult<usize> {
+    let stale = list_migration_backups(db_path)?
+        .into_iter()
+        .skip(keep)
+        .collect::<Vec<_>>();
+    for backup in &stale {
+        std::fs::remove_file(&backup.path)?;
+        std::fs::remove_file(manifest_path(&backup.path))?;
+    }
+    Ok(stale.len())
+}
+
+/// Replace the database at `db_path` with `backup`.
+///
+/// Nothing may have the database open while this runs. The replaced database is
+/// kept next to it as `<name>.pre-rollback` so the rollback itself can be undone.
+pub fn restore_migration_backup(
+    db_path: &Path,
+    backup: &MigrationBackup,
+) -> StorageResult<PathBuf> {
+    if !backup.path.exists() {
+        return Err(StorageError::NotFound);
+    }
+
+    let mut pre_rollback = db_path.as_os_str().to_owned();
+    pre_rollback.push(".pre-rollback");
+    let pre_rollback = PathBuf::from(pre_rollback);
+    if db_path.exists() {
+        std::fs::copy(db_path, &pre_rollback)?;
+    }
+
+    // Stale WAL and shared-memory files would be replayed over the snapshot
+    for suffix in ["-wal", "-shm"] {
+        let mut sidecar = db_path.as_os_str().to_owned();
+        sidecar.push(suffix);
+        let sidecar = PathBuf::from(sidecar);
+        if sidecar.exists() {
+            std::fs::remove_file(sidecar)?;
+        }
+    }
+
+    std::fs::copy(&backup.path, db_path)?;
+    info!(
+        "Restored database from migration backup {} (previous database saved as {})",
+        backup.id,
+        pre_rollback.display()
+    );
+    Ok(pre_rollback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use tempfile::TempDir;
+
+    async fn open(path: &Path) -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(&format!("sqlite:{}?mode=rwc", path.display()))
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_backup_taken_only_when_migrations_are_pending() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("orkee.db");
+        let migrator = sqlx::migrate!("./migrations");
+
+        // A fresh database has nothing to back up
+        let pool = open(&db_path).await;
+        assert!(run_migrations_with_backup(&pool, &migrator)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Neither does one that is already up to date
+        assert!(run_migrations_with_backup(&pool, &migrator)
+            .await
+            .unwrap()
+            .is_none());
+
+        // Forget the latest migration so it is pending again
+        let latest = migrator.iter().map(|m| m.version).max().unwrap();
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?")
+            .bind(latest)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let applied = applied_versions(&pool).await.unwrap();
+
+        let backup = backup_if_pending(&pool, &migrator, &db_path)
+            .await
+            .unwrap()
+            .expect("pending migration should trigger a backup");
+        assert_eq!(backup.pending_versions, vec![latest]);
+        assert_eq!(backup.applied_versions, applied);
+
+        let listed = list_migration_backups(&db_path).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, backup.id);
+        pool.close().await;
+
+        let pre_rollback = restore_migration_backup(&db_path, &backup).unwrap();
+        assert!(pre_rollback.exists());
+        let restored = open(&db_path).await;
+        assert_eq!(applied_versions(&restored).await.unwrap(), applied);
+    }
+
+    #[test]
+    fn test_prune_keeps_newest_backups() {
+        let dir = TempDir::new().unwrap();
+        let db_path = dir.path().join("orkee.db");
+        let backups = backup_dir(&db_path);
+        std::fs::create_dir_all(&backups).unwrap();
+
+        for i in 0..4 {
+            let backup = MigrationBackup {
+                id: format!("backup-{}", i),
+                path: backups.join(format!("backup-{}.db", i)),
+                created_at: Utc::now() + chrono::Duration::seconds(i),
+                orkee_version: "0.0.0".to_string(),
+                applied_versions: vec![1],
+                pending_versions: vec![2],
+                size_bytes: 0,
+            };
+            std::fs::write(&backup.path, b"").unwrap();
+            std::fs::write(
+                manifest_path(&backup.path),
+                serde_json::to_vec(&backup).unwrap(),
+            )
+            .unwrap();
+        }
+
+        assert_eq!(prune_migration_backups(&db_path, 2).unwrap(), 2);
+        let remaining: Vec<String> = list_migration_backups(&db_path)
+            .unwrap()
+            .into_iter()
+            .map(|b| b.id)
+            .collect();
+        assert_eq!(remaining, ["backup-3", "backup-2"]);
+    }
+}
@@ -1,13 +1,27 @@
 // ABOUTME: Model preferences type definitions and storage
-// ABOUTME: Per-task AI model configuration for Ideate, PRD, and task features
+// ABOUTME: Per-task AI model configuration for Ideate, PRD, and task features, with fallback chains and cost caps
 
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, SqlitePool};
+use sqlx::{FromRow, Row, SqlitePool};
 
 use crate::StorageError;
 
+/// Task types that have a model preference
+pub const TASK_TYPES: &[&str] = &[
+    "chat",
+    "prd_generation",
+    "prd_analysis",
+    "insight_extraction",
+    "spec_generation",
+    "task_suggestions",
+    "task_analysis",
+    "spec_refinement",
+    "research_generation",
+    "markdown_generation",
+];
+
 /// Model configuration for a specific task
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModelConfig {
     pub provider: String,
     pub model: String,
@@ -62,6 +76,41 @@ pub struct ModelPreferences {
     pub updated_at: String,
 }
 
+impl ModelPreferences {
+    /// The preferred model for a task type
+    pub fn task_model(&self, task_type: &str) -> Option<ModelConfig> {
+        let (model, provider) = match task_type {
+            "chat" => (&self.chat_model, &self.chat_provider),
+            "prd_generation" => (&self.prd_generation_model, &self.prd_generation_provider),
+            "prd_analysis" => (&self.prd_analysis_model, &self.prd_analysis_provider),
+            "insight_extraction" => (
+                &self.insight_extraction_model,
+                &self.insight_extraction_provider,
+            ),
+            "spec_generation" => (&self.spec_generation_model, &self.spec_generation_provider),
+            "task_suggestions" => (
+                &self.task_suggestions_model,
+                &self.task_suggestions_provider,
+            ),
+            "task_analysis" => (&self.task_analysis_model, &self.task_analysis_provider),
+            "spec_refinement" => (&self.spec_refinement_model, &self.spec_refinement_provider),
+            "research_generation" => (
+                &self.research_generation_model,
+                &self.research_generation_provider,
+            ),
+            "markdown_generation" => (
+                &self.markdown_generation_model,
+                &self.markdown_generation_provider,
+            ),
+            _ => return None,
+        };
+        Some(ModelConfig {
+            provider: provider.clone(),
+            model: model.clone(),
+        })
+    }
+}
+
 /// Request to update model preferences for a specific task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateTaskModelRequest {
@@ -69,6 +118,41 @@ pub struct UpdateTaskModelRequest {
     pub provider: String,
 }
 
+/// The models to try for a task type and the most one call may cost
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskModelPolicy {
+    pub task_type: String,
+    /// The task's preferred model, from `ModelPreferences`
+    pub primary: ModelConfig,
+    /// Models to try, in order, when the primary fails or costs too much
+    pub fallbacks: Vec<ModelConfig>,
+    /// Cap in USD on the estimated cost of a single call
+    pub max_cost_per_call: Option<f64>,
+    pub updated_at: Option<String>,
+}
+
+impl TaskModelPolicy {
+    /// The primary model followed by its fallbacks, skipping repeats
+    pub fn chain(&self) -> Vec<&ModelConfig> {
+        let mut chain: Vec<&ModelConfig> = Vec::with_capacity(self.fallbacks.len() + 1);
+        for config in std::iter::once(&self.primary).chain(&self.fallbacks) {
+            if !chain.contains(&config) {
+                chain.push(config);
+            }
+        }
+        chain
+    }
+}
+
+/// Request to replace a task type's fallback chain and cost cap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTaskPolicyRequest {
+    #[serde(default)]
+    pub fallbacks: Vec<ModelConfig>,
+    #[serde(default)]
+    pub max_cost_per_call: Option<f64>,
+}
+
 /// Storage layer for model preferences
 pub struct ModelPreferencesStorage {
     pool: SqlitePool,
@@ -375,4 +459,101 @@ impl ModelPreferencesStorage {
 
         Ok(())
     }
+    /// Get the fallback chain and cost cap for a task type
+    pub async fn get_task_policy(
+        &self,
+        user_id: &str,
+        task_type: &str,
+    ) -> Result<TaskModelPolicy, StorageError> {
+        let prefs = self.get_preferences(user_id).await?;
+        let primary = prefs.task_model(task_type).ok_or_else(|| {
+            StorageError::InvalidInput(format!("Invalid task type: {}", task_type))
+        })?;
+
+        let row = sqlx::query(
+            "SELECT fallback_models, max_cost_per_call, updated_at FROM model_task_policies WHERE user_id = ? AND task_type = ?",
+        )
+        .bind(user_id)
+        .bind(task_type)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let (fallbacks, max_cost_per_call, updated_at) = match row {
+            Some(row) => {
+                let fallbacks: String = row.try_get("fallback_models")?;
+                (
+                    serde_json::from_str(&fallbacks)?,
+                    row.try_get("max_cost_per_call")?,
+                    Some(row.try_get("updated_at")?),
+                )
+            }
+            None => (Vec::new(), None, None),
+        };
+
+        Ok(TaskModelPolicy {
+            task_type: task_type.to_string(),
+            primary,
+            fallbacks,
+            max_cost_per_call,
+            updated_at,
+        })
+    }
+
+    /// Get the fallback chains and cost caps of every task type
+    pub async fn list_task_policies(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<TaskModelPolicy>, StorageError> {
+        let mut policies = Vec::with_capacity(TASK_TYPES.len());
+        for task_type in TASK_TYPES {
+            policies.push(self.get_task_policy(user_id, task_type).await?);
+        }
+        Ok(policies)
+    }
+
+    /// Replace the fallback chain and cost cap for a task type
+    pub async fn update_task_policy(
+        &self,
+        user_id: &str,
+        task_type: &str,
+        request: &UpdateTaskPolicyRequest,
+    ) -> Result<TaskModelPolicy, StorageError> {
+        if !TASK_TYPES.contains(&task_type) {
+            return Err(StorageError::InvalidInput(format!(
+                "Invalid task type: {}",
+                task_type
+            )));
+        }
+        if let Some(cap) = request.max_cost_per_call {
+            if !cap.is_finite() || cap <= 0.0 {
+                return Err(StorageError::InvalidInput(
+                    "max_cost_per_call must be a positive number".to_string(),
+                ));
+            }
+        }
+
+        // Ensure preferences (and the user row they reference) exist
+        self.get_preferences(user_id).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO model_task_policies (user_id, task_type, fallback_models, max_cost_per_call)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(user_id, task_type) DO UPDATE SET
+                fallback_models = excluded.fallback_models,
+                max_cost_per_call = excluded.max_cost_per_call,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            "#,
+        )
+        .bind(user_id)
+        .bind(task_type)
+        .bind(serde_json::to_string(&request.fallbacks)?)
+        .bind(request.max_cost_per_call)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_task_policy(user_id, task_type).await
+    }
 }
@@ -0,0 +1,267 @@
+// ABOUTME: Read/write connection routing for storage backends
+// ABOUTME: Sends read-heavy queries to a read-only replica pool and writes to the primary
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use super::{StorageError, StorageResult};
+
+/// Which pool a query should run against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryRoute {
+    /// Writes and reads that must observe in-flight transactions
+    Primary,
+    /// Heavy reads (search, lists, aggregations); falls back to the primary
+    /// when no replica is configured
+    Replica,
+}
+
+/// Read replica configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReadReplicaConfig {
+    /// A read-only pool on the primary SQLite file. Requires WAL mode so
+    /// readers don't block the writer.
+    SqliteReadOnly { max_connections: u32 },
+    /// A Postgres streaming replica (not yet supported; reads stay on the primary)
+    Postgres { url: String, max_connections: u32 },
+}
+
+impl Default for ReadReplicaConfig {
+    fn default() -> Self {
+        Self::SqliteReadOnly { max_connections: 4 }
+    }
+}
+
+/// Point-in-time metrics for one connection pool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PoolMetrics {
+    pub route: QueryRoute,
+    pub read_only: bool,
+    /// Open connections, idle or in use
+    pub size: u32,
+    pub idle: usize,
+    pub max_connections: u32,
+    /// Queries routed to this pool through routing hints
+    pub queries_routed: u64,
+}
+
+/// Routes queries between the primary pool and an optional read replica
+#[derive(Clone)]
+pub struct ConnectionRouter {
+    primary: SqlitePool,
+    primary_max_connections: u32,
+    replica: Option<(SqlitePool, u32)>,
+    primary_routed: Arc<AtomicU64>,
+    replica_routed: Arc<AtomicU64>,
+}
+
+impl ConnectionRouter {
+    /// Create a router that sends everything to the primary pool
+    pub fn new(primary: SqlitePool, max_connections: u32) -> Self {
+        Self {
+            primary,
+            primary_max_connections: max_connections,
+            replica: None,
+            primary_routed: Arc::new(AtomicU64::new(0)),
+            replica_routed: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Attach a read replica pool
+    pub fn with_replica(mut self, replica: SqlitePool, max_connections: u32) -> Self {
+        self.replica = Some((replica, max_connections));
+        self
+    }
+
+    /// Whether reads are served by a separate replica pool
+    pub fn has_replica(&self) -> bool {
+        self.replica.is_some()
+    }
+
+    /// Get the pool for a query, following the routing hint
+    pub fn pool(&self, route: QueryRoute) -> &SqlitePool {
+        match (route, &self.replica) {
+            (QueryRoute::Replica, Some((replica, _))) => {
+                self.replica_routed.fetch_add(1, Ordering::Relaxed);
+                replica
+            }
+            _ => {
+                self.primary_routed.fetch_add(1, Ordering::Relaxed);
+                &self.primary
+            }
+        }
+    }
+
+    /// Shorthand for `pool(QueryRoute::Replica)`
+    pub fn reader(&self) -> &SqlitePool {
+        self.pool(QueryRoute::Replica)
+    }
+
+    /// Shorthand for `pool(QueryRoute::Primary)`
+    pub fn writer(&self) -> &SqlitePool {
+        self.pool(QueryRoute::Primary)
+    }
+
+    /// Metrics for every pool, primary first
+    pub fn metrics(&self) -> Vec<PoolMetrics> {
+        let mut metrics = vec![PoolMetrics {
+            route: QueryRoute::Primary,
+            read_only: false,
+            size: self.primary.size(),
+            idle: self.primary.num_idle(),
+            max_connections: self.primary_max_connections,
+            queries_routed: self.primary_routed.load(Ordering::Relaxed),
+        }];
+
+        if let Some((replica, max_connections)) = &self.replica {
+            metrics.push(PoolMetrics {
+                route: QueryRoute::Replica,
+                read_only: true,
+                size: replica.size(),
+                idle: replica.num_idle(),
+                max_connections: *max_connections,
+                queries_routed: self.replica_routed.load(Ordering::Relaxed),
+            });
+        }
+
+        metrics
+    }
+
+    /// Open the replica pool described by `config` for a SQLite primary at
+    /// `database_path`
+    ///
+    /// Returns `None` when the configuration can't be honoured (in-memory
+    /// databases, WAL disabled, or an unsupported backend), so reads stay on
+    /// the primary.
+    pub async fn connect_replica(
+        config: &ReadReplicaConfig,
+        database_path: &Path,
+        wal_enabled: bool,
+        busy_timeout_seconds: u64,
+    ) -> StorageResult<Option<(SqlitePool, u32)>> {
+        match config {
+            ReadReplicaConfig::SqliteReadOnly { max_connections } => {
+                if database_path.as_os_str() == ":memory:" {
+                    debug!("In-memory database; read replica disabled");
+                    return Ok(None);
+                }
+                if !wal_enabled {
+                    info!("WAL mode is disabled; read replica disabled");
+                    return Ok(None);
+                }
+
+                let options = SqliteConnectOptions::new()
+                    .filename(database_path)
+                    .read_only(true)
+                    .busy_timeout(Duration::from_secs(busy_timeout_seconds))
+                    .pragma("temp_store", "memory")
+                    .pragma("mmap_size", "268435456");
+
+                let pool = SqlitePoolOptions::new()
+                    .max_connections(*max_connections)
+                    .acquire_timeout(Duration::from_secs(busy_timeout_seconds))
+                    .connect_with(options)
+                    .await
+                    .map_err(StorageError::Sqlx)?;
+
+                info!(
+                    "Opened read-only SQLite replica pool ({} connections)",
+                    max_connections
+                );
+                Ok(Some((pool, *max_connections)))
+            }
+            ReadReplicaConfig::Postgres { .. } => {
+                // TODO: Route reads to Postgres once the Postgres backend lands
+                warn!("Postgres read replicas are not yet supported; reads use the primary");
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    async fn primary_pool(path: &Path) -> SqlitePool {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true)
+            .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal);
+        SqlitePool::connect_with(options).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_routes_reads_to_replica() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let primary = primary_pool(&db_path).await;
+
+        sqlx::query("CREATE TABLE items (name TEXT)")
+            .execute(&primary)
+            .await
+            .unwrap();
+
+        let (replica, max) =
+            ConnectionRouter::connect_replica(&ReadReplicaConfig::default(), &db_path, true, 5)
+                .await
+                .unwrap()
+                .unwrap();
+        let router = ConnectionRouter::new(primary, 5).with_replica(replica, max);
+        assert!(router.has_replica());
+
+        sqlx::query("INSERT INTO items (name) VALUES ('a')")
+            .execute(router.writer())
+            .await
+            .unwrap();
+
+        // Committed writes are visible to replica readers
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM items")
+            .fetch_one(router.reader())
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+
+        // The replica rejects writes
+        assert!(sqlx::query("INSERT INTO items (name) VALUES ('b')")
+            .execute(router.reader())
+            .await
+            .is_err());
+
+        let metrics = router.metrics();
+        assert_eq!(metrics.len(), 2);
+        assert_eq!(metrics[0].queries_routed, 1);
+        assert_eq!(metrics[1].queries_routed, 2);
+        assert!(metrics[1].read_only);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_primary_without_replica() {
+        let temp_dir = tempdir().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+
+        assert!(ConnectionRouter::connect_replica(
+            &ReadReplicaConfig::default(),
+            &db_path,
+            false,
+            5
+        )
+        .await
+        .unwrap()
+        .is_none());
+
+        let router = ConnectionRouter::new(primary_pool(&db_path).await, 5);
+        let _ = router.reader();
+        let metrics = router.metrics();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].queries_routed, 1);
+    }
+}
@@ -0,0 +1,641 @@
+// ABOUTME: Search query language and saved searches across projects and tasks
+// ABOUTME: Parses `status:active tag:backend "auth"` style queries into SQL filters and stores named searches per user
+
+use serde::{Deserialize, Serialize};
+use sqlx::query::Query;
+use sqlx::sqlite::{Sqlite, SqliteArguments, SqliteRow};
+use sqlx::{Row, SqlitePool};
+
+use crate::sqlite::VISIBLE_TO_CURRENT_USER;
+use crate::StorageError;
+
+/// Maximum length of a search query
+pub const MAX_QUERY_LENGTH: usize = 500;
+
+/// Maximum length of a saved search name
+pub const MAX_SAVED_SEARCH_NAME_LENGTH: usize = 100;
+
+/// Results returned per kind when the caller does not ask for a limit
+pub const DEFAULT_SEARCH_LIMIT: i64 = 50;
+
+/// Largest per-kind limit a caller can ask for
+pub const MAX_SEARCH_LIMIT: i64 = 500;
+
+/// Project statuses matched by `status:active`
+const ACTIVE_PROJECT_STATUSES: &[&str] = &["planning", "building", "review"];
+
+/// Task statuses matched by `status:active`
+const ACTIVE_TASK_STATUSES: &[&str] = &["pending", "in-progress", "review", "blocked"];
+
+/// Project statuses matched by `status:closed`
+const CLOSED_PROJECT_STATUSES: &[&str] = &["launched", "archived"];
+
+/// Task statuses matched by `status:closed`
+const CLOSED_TASK_STATUSES: &[&str] = &["done", "cancelled"];
+
+/// What a search hit refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchKind {
+    Project,
+    Task,
+}
+
+/// A parsed search query.
+///
+/// Filters on different fields must all match. Repeated `status:`, `priority:`,
+/// `project:`, and `type:` filters match any of their values, while repeated
+/// `tag:` filters and free-text terms must all match.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    /// Free-text terms, matched against names, titles, and descriptions
+    pub text: Vec<String>,
+    pub statuses: Vec<String>,
+    pub priorities: Vec<String>,
+    pub tags: Vec<String>,
+    /// Project IDs or names; restricts tasks to those projects
+    pub projects: Vec<String>,
+    /// Kinds to search; empty searches both
+    pub kinds: Vec<SearchKind>,
+}
+
+impl SearchQuery {
+    /// Parse a query such as `status:active tag:backend priority:high "auth"`.
+    ///
+    /// Values are case-insensitive and may be quoted to include spaces
+    /// (`tag:"needs review"`). Unknown fields are rejected rather than treated
+    /// as text so that typos do not silently widen a search.
+    pub fn parse(input: &str) -> Result<Self, StorageError> {
+        if input.len() > MAX_QUERY_LENGTH {
+            return Err(StorageError::Validation(format!(
+                "Search query exceeds maximum length of {} characters",
+                MAX_QUERY_LENGTH
+            )));
+        }
+
+        let mut query = SearchQuery::default();
+        for token in tokenize(input)? {
+            let Some(field) = token.field else {
+                query.text.push(token.value.to_lowercase());
+                continue;
+            };
+
+            let value = token.value.to_lowercase();
+            if value.is_empty() {
+                return Err(StorageError::Validation(format!(
+                    "Search filter '{}:' needs a value",
+                    field
+                )));
+            }
+
+            match field.as_str() {
+                "status" | "is" => query.statuses.push(value.replace('_', "-")),
+                "priority" => query.priorities.push(value),
+                "tag" => query.tags.push(value),
+                "project" => query.projects.push(value),
+                "type" => query.kinds.push(match value.as_str() {
+                    "project" | "projects" => SearchKind::Project,
+                    "task" | "tasks" => SearchKind::Task,
+                    _ => {
+                        return Err(StorageError::Validation(format!(
+                            "Unknown search type '{}': use 'project' or 'task'",
+                            value
+                        )))
+                    }
+                }),
+                _ => {
+                    return Err(StorageError::Validation(format!(
+                        "Unknown search field '{}': use status, priority, tag, project, or type",
+                        field
+                    )))
+                }
+            }
+        }
+
+        Ok(query)
+    }
+
+    fn includes(&self, kind: SearchKind) -> bool {
+        self.kinds.is_empty() || self.kinds.contains(&kind)
+    }
+
+    /// Status values to match for `kind`, with the `active` and `closed` aliases expanded
+    fn status_values(&self, kind: SearchKind) -> Vec<&str> {
+        let mut values = Vec::new();
+        for status in &self.statuses {
+            match (status.as_str(), kind) {
+                ("active", SearchKind::Project) => values.extend(ACTIVE_PROJECT_STATUSES),
+                ("active", SearchKind::Task) => values.extend(ACTIVE_TASK_STATUSES),
+                ("closed", SearchKind::Project) => values.extend(CLOSED_PROJECT_STATUSES),
+                ("closed", SearchKind::Task) => values.extend(CLOSED_TASK_STATUSES),
+                (status, _) => values.push(status),
+            }
+        }
+        values
+    }
+}
+
+/// One whitespace-separated piece of a query
+struct Token {
+    field: Option<String>,
+    value: String,
+}
+
+/// Split a query into `field:value` filters and free-text terms, honouring quotes
+fn tokenize(input: &str) -> Result<Vec<Token>, StorageError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        if chars.peek().is_none() {
+            break;
+        }
+
+        let mut word = String::new();
+        let mut field = None;
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() {
+                break;
+            }
+            chars.next();
+            match c {
+                '"' => word.push_str(&read_quoted(&mut chars)?),
+                ':' if field.is_none() && !word.is_empty() => {
+                    field = Some(std::mem::take(&mut word).to_lowercase());
+                }
+                _ => word.push(c),
+            }
+        }
+
+        if field.is_none() && word.is_empty() {
+            continue;
+        }
+        tokens.push(Token { field, value: word });
+    }
+
+    Ok(tokens)
+}
+
+/// Read up to the closing quote; the opening quote has already been consumed
+fn read_quoted(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> Result<String, StorageError> {
+    let mut quoted = String::new();
+    for c in chars.by_ref() {
+        if c == '"' {
+            return Ok(quoted);
+        }
+        quoted.push(c);
+    }
+    Err(StorageError::Validation(
+        "Unterminated quote in search query".to_string(),
+    ))
+}
+
+/// A project or task matching a search
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    pub id: String,
+    /// The project itself for project hits
+    pub project_id: String,
+    /// Project name or task title
+    pub title: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub priority: String,
+    pub tags: Vec<String>,
+    pub updated_at: String,
+}
+
+/// Matches for a query, most recently updated first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub query: String,
+    pub projects: Vec<SearchHit>,
+    pub tasks: Vec<SearchHit>,
+}
+
+/// A named query a user can re-run from the dashboard or TUI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSearch {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub query: String,
+    /// Single key that runs the search
+    pub shortcut: Option<String>,
+    pub position: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Request to create or replace a saved search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedSearchInput {
+    pub name: String,
+    pub query: String,
+    #[serde(default)]
+    pub shortcut: Option<String>,
+    #[serde(default)]
+    pub position: i64,
+}
+
+impl SavedSearchInput {
+    /// Validate the name and shortcut, and that the query parses
+    pub fn validate(&self) -> Result<(), StorageError> {
+        let name = self.name.trim();
+        if name.is_empty() || name.chars().count() > MAX_SAVED_SEARCH_NAME_LENGTH {
+            return Err(StorageError::Validation(format!(
+                "Saved search name must be 1-{} characters",
+                MAX_SAVED_SEARCH_NAME_LENGTH
+            )));
+        }
+        if self.query.trim().is_empty() {
+            return Err(StorageError::Validation(
+                "Saved search query cannot be empty".to_string(),
+            ));
+        }
+        SearchQuery::parse(&self.query)?;
+        if let Some(shortcut) = &self.shortcut {
+            let mut chars = shortcut.chars();
+            let single = matches!((chars.next(), chars.next()), (Some(c), None) if c.is_ascii_alphanumeric());
+            if !single {
+                return Err(StorageError::Validation(format!(
+                    "Invalid shortcut '{}': use a single letter or digit",
+                    shortcut
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Storage layer for running searches and managing saved searches
+pub struct SearchStorage {
+    pool: SqlitePool,
+}
+
+impl SearchStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Run a query over the projects visible to the current user and their tasks
+    ///
+    /// `limit` applies to projects and tasks separately.
+    pub async fn search(&self, input: &str, limit: i64) -> Result<SearchResults, StorageError> {
+        let query = SearchQuery::parse(input)?;
+        let limit = limit.clamp(1, MAX_SEARCH_LIMIT);
+
+        let projects = if query.includes(SearchKind::Project) {
+            self.search_projects(&query, limit).await?
+        } else {
+            Vec::new()
+        };
+        let tasks = if query.includes(SearchKind::Task) {
+            self.search_tasks(&query, limit).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(SearchResults {
+            query: input.to_string(),
+            projects,
+            tasks,
+        })
+    }
+
+    async fn search_projects(
+        &self,
+        query: &SearchQuery,
+        limit: i64,
+    ) -> Result<Vec<SearchHit>, StorageError> {
+        let statuses = query.status_values(SearchKind::Project);
+        let filters = Filters {
+            title: "name",
+            description: "description",
+            status: "status",
+            priority: "priority",
+            tags: "tags",
+            project_id: "id",
+            project_name: "name",
+        };
+        let sql = format!(
+            r#"
+            SELECT id, id AS project_id, name AS title, description, status, priority, tags, updated_at
+            FROM projects
+            WHERE status != 'deleted' AND {}{}
+            ORDER BY updated_at DESC
+            LIMIT ?
+            "#,
+            VISIBLE_TO_CURRENT_USER,
+            filters.sql(query, &statuses)
+        );
+
+        let rows = filters
+            .bind(sqlx::query(&sql), query, &statuses)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter()
+            .map(|row| row_to_hit(row, SearchKind::Project))
+            .collect()
+    }
+
+    async fn search_tasks(
+        &self,
+        query: &SearchQuery,
+        limit: i64,
+    ) -> Result<Vec<SearchHit>, StorageError> {
+        let statuses = query.status_values(SearchKind::Task);
+        let filters = Filters {
+            title: "t.title",
+            description: "t.description",
+            status: "t.status",
+            priority: "t.priority",
+            tags: "t.tags",
+            project_id: "p.id",
+            project_name: "p.name",
+        };
+        let sql = format!(
+            r#"
+            SELECT t.id, t.project_id, t.title, t.description, t.status, t.priority, t.tags, t.updated_at
+            FROM tasks t
+            JOIN projects p ON p.id = t.project_id
+            WHERE p.status != 'deleted'
+              AND p.id IN (SELECT id FROM projects WHERE {}){}
+            ORDER BY t.updated_at DESC
+            LIMIT ?
+            "#,
+            VISIBLE_TO_CURRENT_USER,
+            filters.sql(query, &statuses)
+        );
+
+        let rows = filters
+            .bind(sqlx::query(&sql), query, &statuses)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter()
+            .map(|row| row_to_hit(row, SearchKind::Task))
+            .collect()
+    }
+
+    /// List a user's saved searches in display order
+    pub async fn list_saved(&self, user_id: &str) -> Result<Vec<SavedSearch>, StorageError> {
+        let rows =
+            sqlx::query("SELECT * FROM saved_searches WHERE user_id = ? ORDER BY position, name")
+                .bind(user_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_saved_search).collect()
+    }
+
+    /// Get one of a user's saved searches
+    pub async fn get_saved(
+        &self,
+        user_id: &str,
+        id: &str,
+    ) -> Result<Option<SavedSearch>, StorageError> {
+        let row = sqlx::query("SELECT * FROM saved_searches WHERE user_id = ? AND id = ?")
+            .bind(user_id)
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        row.as_ref().map(row_to_saved_search).transpose()
+    }
+
+    /// Save a new search for a user
+    pub async fn create_saved(
+        &self,
+        user_id: &str,
+        input: &SavedSearchInput,
+    ) -> Result<SavedSearch, StorageError> {
+        input.validate()?;
+
+        let id: String = sqlx::query_scalar(
+            r#"
+            INSERT INTO saved_searches (user_id, name, query, shortcut, position)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .bind(input.name.trim())
+        .bind(input.query.trim())
+        .bind(&input.shortcut)
+        .bind(input.position)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(conflict_error)?;
+
+        self.get_saved(user_id, &id)
+            .await?
+            .ok_or_else(|| StorageError::Database("Saved search not found".to_string()))
+    }
+
+    /// Replace a saved search; returns `None` if the user has no search with that ID
+    pub async fn update_saved(
+        &self,
+        user_id: &str,
+        id: &str,
+        input: &SavedSearchInput,
+    ) -> Result<Option<SavedSearch>, StorageError> {
+        input.validate()?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE saved_searches SET
+                name = ?,
+                query = ?,
+                shortcut = ?,
+                position = ?,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            WHERE user_id = ? AND id = ?
+            "#,
+        )
+        .bind(input.name.trim())
+        .bind(input.query.trim())
+        .bind(&input.shortcut)
+        .bind(input.position)
+        .bind(user_id)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(conflict_error)?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        self.get_saved(user_id, id).await
+    }
+
+    /// Delete a saved search; returns whether one existed
+    pub async fn delete_saved(&self, user_id: &str, id: &str) -> Result<bool, StorageError> {
+        let result = sqlx::query("DELETE FROM saved_searches WHERE user_id = ? AND id = ?")
+            .bind(user_id)
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+/// Column names a query's filters apply to for one kind of hit
+struct Filters {
+    title: &'static str,
+    description: &'static str,
+    status: &'static str,
+    priority: &'static str,
+    tags: &'static str,
+    project_id: &'static str,
+    project_name: &'static str,
+}
+
+impl Filters {
+    /// `AND ...` conditions for a query's filters
+    fn sql(&self, query: &SearchQuery, statuses: &[&str]) -> String {
+        let mut sql = String::new();
+
+        if !statuses.is_empty() {
+            sql.push_str(&format!(
+                " AND {} IN ({})",
+                self.status,
+                placeholders(statuses.len())
+            ));
+        }
+        if !query.priorities.is_empty() {
+            sql.push_str(&format!(
+                " AND {} IN ({})",
+                self.priority,
+                placeholders(query.priorities.len())
+            ));
+        }
+        for _ in &query.tags {
+            // json_each fails on malformed JSON, so only look inside valid arrays
+            sql.push_str(&format!(
+                " AND CASE WHEN json_valid({tags}) THEN EXISTS (SELECT 1 FROM json_each({tags}) WHERE lower(json_each.value) = ?) ELSE 0 END",
+                tags = self.tags
+            ));
+        }
+        if !query.projects.is_empty() {
+            let conditions = vec![
+                format!(
+                    "({} = ? OR lower({}) = ?)",
+                    self.project_id, self.project_name
+                );
+                query.projects.len()
+            ];
+            sql.push_str(&format!(" AND ({})", conditions.join(" OR ")));
+        }
+        for _ in &query.text {
+            sql.push_str(&format!(
+                " AND (lower({}) LIKE ? ESCAPE '\\' OR lower(COALESCE({}, '')) LIKE ? ESCAPE '\\')",
+                self.title, self.description
+            ));
+        }
+
+        sql
+    }
+
+    /// Bind filter values in the same order as [`sql`](Self::sql)
+    fn bind<'q>(
+        &self,
+        mut db_query: Query<'q, Sqlite, SqliteArguments<'q>>,
+        query: &'q SearchQuery,
+        statuses: &[&'q str],
+    ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+        for status in statuses {
+            db_query = db_query.bind(*status);
+        }
+        for priority in &query.priorities {
+            db_query = db_query.bind(priority);
+        }
+        for tag in &query.tags {
+            db_query = db_query.bind(tag);
+        }
+        for project in &query.projects {
+            db_query = db_query.bind(project).bind(project);
+        }
+        for term in &query.text {
+            let pattern = like_pattern(term);
+            db_query = db_query.bind(pattern.clone()).bind(pattern);
+        }
+        db_query
+    }
+}
+
+fn placeholders(count: usize) -> String {
+    vec!["?"; count].join(", ")
+}
+
+/// `%term%` with LIKE wildcards in the term escaped
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{}%", escaped)
+}
+
+/// Turn a unique-constraint failure into a validation error naming the clash
+fn conflict_error(error: sqlx::Error) -> StorageError {
+    if let sqlx::Error::Database(db_error) = &error {
+        if db_error.is_unique_violation() {
+            let field = if db_error.message().contains("shortcut") {
+                "shortcut"
+            } else {
+                "name"
+            };
+            return StorageError::Validation(format!(
+                "A saved search with this {} already exists",
+                field
+            ));
+        }
+    }
+    StorageError::Sqlx(error)
+}
+
+fn row_to_hit(row: &SqliteRow, kind: SearchKind) -> Result<SearchHit, StorageError> {
+    let tags = row
+        .try_get::<Option<String>, _>("tags")?
+        .and_then(|tags| serde_json::from_str(&tags).ok())
+        .unwrap_or_default();
+
+    Ok(SearchHit {
+        kind,
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        title: row.try_get("title")?,
+        description: row.try_get("description")?,
+        status: row.try_get("status")?,
+        priority: row.try_get("priority")?,
+        tags,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
+
+fn row_to_saved_search(row: &SqliteRow) -> Result<SavedSearch, StorageError> {
+    Ok(SavedSearch {
+        id: row.try_get("id")?,
+        user_id: row.try_get("user_id")?,
+        name: row.try_get("name")?,
+        query: row.try_get("query")?,
+        shortcut: row.try_get("shortcut")?,
+        position: row.try_get("position")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
@@ -6,10 +6,10 @@ use sqlx::{migrate::MigrateDatabase, Row};
 use tracing::{debug, info, warn};
 
 use super::{
-    compress_data, decompress_data, generate_project_id, ConflictType, DatabaseSnapshot,
-    EncryptionMode, ImportConflict, ImportResult, PasswordLockoutStatus, ProjectFilter,
-    ProjectStorage, StorageCapabilities, StorageConfig, StorageError, StorageInfo, StorageProvider,
-    StorageResult,
+    compress_data, decompress_data, field_encryption, generate_project_id, ConflictType,
+    ConnectionRouter, DatabaseSnapshot, EncryptionMode, ImportConflict, ImportResult,
+    PasswordLockoutStatus, ProjectFilter, ProjectStorage, QueryRoute, StorageCapabilities,
+    StorageConfig, StorageError, StorageInfo, StorageProvider, StorageResult,
 };
 use orkee_core::types::{
     Priority, Project, ProjectCreateInput, ProjectStatus, ProjectUpdateInput, TaskSource,
@@ -20,9 +20,14 @@ use orkee_core::types::{
 pub const PASSWORD_MAX_ATTEMPTS: i64 = 5; // Maximum failed password attempts before lockout
 pub const PASSWORD_LOCKOUT_DURATION_MINUTES: i64 = 15; // Duration of account lockout in minutes
 
+/// Projects visible to the active user: the ones they own plus unowned, shared projects
+pub(crate) const VISIBLE_TO_CURRENT_USER: &str =
+    "(owner_user_id IS NULL OR owner_user_id = (SELECT id FROM users WHERE is_current = 1))";
+
 /// SQLite implementation of ProjectStorage
 pub struct SqliteStorage {
     pool: SqlitePool,
+    router: ConnectionRouter,
     config: StorageConfig,
 }
 
@@ -88,7 +93,25 @@ impl SqliteStorage {
             .await
             .map_err(StorageError::Sqlx)?;
 
-        let storage = Self { pool, config };
+        let mut router = ConnectionRouter::new(pool.clone(), config.max_connections);
+        if let Some(replica_config) = &config.read_replica {
+            if let Some((replica, max_connections)) = ConnectionRouter::connect_replica(
+                replica_config,
+                database_path,
+                config.enable_wal,
+                config.busy_timeout_seconds,
+            )
+            .await?
+            {
+                router = router.with_replica(replica, max_connections);
+            }
+        }
+
+        let storage = Self {
+            pool,
+            router,
+            config,
+        };
         Ok(storage)
     }
 
@@ -97,6 +120,11 @@ impl SqliteStorage {
         &self.pool
     }
 
+    /// Get the pool for a query, following the routing hint
+    pub fn pool_for(&self, route: QueryRoute) -> &SqlitePool {
+        self.router.pool(route)
+    }
+
     /// Convert a database row to a Project
     fn row_to_project(&self, row: &SqliteRow) -> StorageResult<Project> {
         let tags_json: Option<String> = row.try_get("tags")?;
@@ -175,14 +203,16 @@ impl SqliteStorage {
             status,
             priority,
             rank: row.try_get("rank")?,
-            setup_script: row.try_get("setup_script")?,
-            dev_script: row.try_get("dev_script")?,
-            cleanup_script: row.try_get("cleanup_script")?,
+            setup_script: field_encryption::open_opt(row.try_get("setup_script")?)?,
+            dev_script: field_encryption::open_opt(row.try_get("dev_script")?)?,
+            cleanup_script: field_encryption::open_opt(row.try_get("cleanup_script")?)?,
             task_source,
             tags,
             manual_tasks,
             mcp_servers,
             git_repository,
+            owner_user_id: row.try_get("owner_user_id")?,
+            version: row.try_get("version")?,
             created_at,
             updated_at,
         })
@@ -451,11 +481,12 @@ impl ProjectStorage for SqliteStorage {
     async fn initialize(&self) -> StorageResult<()> {
         info!("Initializing SQLite storage with migrations");
 
-        // Run migrations
-        sqlx::migrate!("./migrations")
-            .run(&self.pool)
-            .await
-            .map_err(StorageError::Migration)?;
+        // Run migrations, snapshotting the database first if any are pending
+        crate::migration_backup::run_migrations_with_backup(
+            &self.pool,
+            &sqlx::migrate!("./migrations"),
+        )
+        .await?;
 
         // Validate agent and model references against config files
         self.validate_agent_model_references().await?;
@@ -489,14 +520,20 @@ impl ProjectStorage for SqliteStorage {
         let status_str = Self::status_to_string(&input.status.unwrap_or(ProjectStatus::Planning));
         let priority_str = Self::priority_to_string(&input.priority.unwrap_or(Priority::Medium));
         let task_source_str = input.task_source.as_ref().map(Self::task_source_to_string);
+        let setup_script = field_encryption::seal_opt(input.setup_script.as_ref())?;
+        let dev_script = field_encryption::seal_opt(input.dev_script.as_ref())?;
+        let cleanup_script = field_encryption::seal_opt(input.cleanup_script.as_ref())?;
 
         let result = sqlx::query(
             r#"
             INSERT INTO projects (
                 id, name, project_root, description, status, priority, rank,
                 setup_script, dev_script, cleanup_script, task_source,
-                tags, manual_tasks, mcp_servers, created_at, updated_at
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                tags, manual_tasks, mcp_servers, created_at, updated_at, owner_user_id
+            ) VALUES (
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?,
+                (SELECT id FROM users WHERE is_current = 1)
+            )
             "#,
         )
         .bind(&id)
@@ -506,9 +543,9 @@ impl ProjectStorage for SqliteStorage {
         .bind(status_str)
         .bind(priority_str)
         .bind(input.rank)
-        .bind(&input.setup_script)
-        .bind(&input.dev_script)
-        .bind(&input.cleanup_script)
+        .bind(&setup_script)
+        .bind(&dev_script)
+        .bind(&cleanup_script)
         .bind(task_source_str)
         .bind(&tags_json)
         .bind(&manual_tasks_json)
@@ -582,19 +619,21 @@ impl ProjectStorage for SqliteStorage {
     }
 
     async fn list_projects(&self) -> StorageResult<Vec<Project>> {
-        let rows = sqlx::query(
+        let query_str = format!(
             r#"
             SELECT * FROM projects 
-            WHERE status != 'deleted'
+            WHERE status != 'deleted' AND {}
             ORDER BY 
                 CASE WHEN rank IS NULL THEN 1 ELSE 0 END,
                 rank ASC,
                 name ASC
             "#,
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(StorageError::Sqlx)?;
+            VISIBLE_TO_CURRENT_USER
+        );
+        let rows = sqlx::query(&query_str)
+            .fetch_all(self.router.reader())
+            .await
+            .map_err(StorageError::Sqlx)?;
 
         let mut projects = Vec::new();
         for row in rows {
@@ -649,14 +688,30 @@ impl ProjectStorage for SqliteStorage {
         }
 
         if query_parts.is_empty() {
-            return self.get_project(id).await?.ok_or(StorageError::NotFound);
+            let project = self.get_project(id).await?.ok_or(StorageError::NotFound)?;
+            return match input.expected_version {
+                Some(expected) if expected != project.version => {
+                    Err(StorageError::VersionConflict {
+                        expected,
+                        actual: project.version,
+                    })
+                }
+                _ => Ok(project),
+            };
         }
 
         query_parts.push("updated_at = ?");
 
+        // The version check is part of the UPDATE so a concurrent writer cannot
+        // slip in between reading the version and writing the row
         let query_str = format!(
-            "UPDATE projects SET {} WHERE id = ?",
-            query_parts.join(", ")
+            "UPDATE projects SET {} WHERE id = ?{}",
+            query_parts.join(", "),
+            if input.expected_version.is_some() {
+                " AND version = ?"
+            } else {
+                ""
+            }
         );
 
         let mut query = sqlx::query(&query_str);
@@ -680,13 +735,13 @@ impl ProjectStorage for SqliteStorage {
             query = query.bind(rank);
         }
         if let Some(ref setup_script) = input.setup_script {
-            query = query.bind(setup_script);
+            query = query.bind(field_encryption::seal(setup_script)?);
         }
         if let Some(ref dev_script) = input.dev_script {
-            query = query.bind(dev_script);
+            query = query.bind(field_encryption::seal(dev_script)?);
         }
         if let Some(ref cleanup_script) = input.cleanup_script {
-            query = query.bind(cleanup_script);
+            query = query.bind(field_encryption::seal(cleanup_script)?);
         }
         if let Some(ref task_source) = input.task_source {
             query = query.bind(Self::task_source_to_string(task_source));
@@ -705,13 +760,22 @@ impl ProjectStorage for SqliteStorage {
         }
 
         query = query.bind(Utc::now().to_rfc3339()).bind(id);
+        if let Some(expected) = input.expected_version {
+            query = query.bind(expected);
+        }
 
         let result = query.execute(&self.pool).await;
 
         match result {
             Ok(result) => {
                 if result.rows_affected() == 0 {
-                    return Err(StorageError::NotFound);
+                    return match (input.expected_version, self.get_project(id).await?) {
+                        (Some(expected), Some(current)) => Err(StorageError::VersionConflict {
+                            expected,
+                            actual: current.version,
+                        }),
+                        _ => Err(StorageError::NotFound),
+                    };
                 }
                 debug!("Updated project with ID {}", id);
                 self.get_project(id).await?.ok_or(StorageError::NotFound)
@@ -758,7 +822,7 @@ impl ProjectStorage for SqliteStorage {
         &self,
         filter: ProjectFilter,
     ) -> StorageResult<Vec<Project>> {
-        let mut where_conditions = vec!["status != 'deleted'"];
+        let mut where_conditions = vec!["status != 'deleted'", VISIBLE_TO_CURRENT_USER];
         let mut query_params: Vec<String> = Vec::new();
 
         if let Some(status) = &filter.status {
@@ -810,7 +874,7 @@ impl ProjectStorage for SqliteStorage {
         }
 
         let rows = query
-            .fetch_all(&self.pool)
+            .fetch_all(self.router.reader())
             .await
             .map_err(StorageError::Sqlx)?;
 
@@ -825,23 +889,25 @@ impl ProjectStorage for SqliteStorage {
     async fn search_projects(&self, query: &str) -> StorageResult<Vec<Project>> {
         if !self.config.enable_fts {
             warn!("Full-text search is disabled, falling back to LIKE search");
-            let rows = sqlx::query(
+            let query_str = format!(
                 r#"
                 SELECT * FROM projects 
                 WHERE (name LIKE ? OR description LIKE ? OR project_root LIKE ?)
-                AND status != 'deleted'
+                AND status != 'deleted' AND {}
                 ORDER BY 
                     CASE WHEN rank IS NULL THEN 1 ELSE 0 END,
                     rank ASC,
                     name ASC
                 "#,
-            )
-            .bind(format!("%{}%", query))
-            .bind(format!("%{}%", query))
-            .bind(format!("%{}%", query))
-            .fetch_all(&self.pool)
-            .await
-            .map_err(StorageError::Sqlx)?;
+                VISIBLE_TO_CURRENT_USER
+            );
+            let rows = sqlx::query(&query_str)
+                .bind(format!("%{}%", query))
+                .bind(format!("%{}%", query))
+                .bind(format!("%{}%", query))
+                .fetch_all(self.router.reader())
+                .await
+                .map_err(StorageError::Sqlx)?;
 
             let mut projects = Vec::new();
             for row in rows {
@@ -851,19 +917,21 @@ impl ProjectStorage for SqliteStorage {
         }
 
         // Use FTS5 for better search
-        let rows = sqlx::query(
+        let query_str = format!(
             r#"
             SELECT p.* FROM projects p
             JOIN projects_fts fts ON p.rowid = fts.rowid
             WHERE projects_fts MATCH ?
-            AND p.status != 'deleted'
+            AND p.status != 'deleted' AND {}
             ORDER BY fts.rank
             "#,
-        )
-        .bind(query)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(StorageError::Sqlx)?;
+            VISIBLE_TO_CURRENT_USER
+        );
+        let rows = sqlx::query(&query_str)
+            .bind(query)
+            .fetch_all(self.router.reader())
+            .await
+            .map_err(StorageError::Sqlx)?;
 
         let mut projects = Vec::new();
         for row in rows {
@@ -894,14 +962,14 @@ impl ProjectStorage for SqliteStorage {
     async fn get_storage_info(&self) -> StorageResult<StorageInfo> {
         let count_row =
             sqlx::query("SELECT COUNT(*) as count FROM projects WHERE status != 'deleted'")
-                .fetch_one(&self.pool)
+                .fetch_one(self.router.reader())
                 .await
                 .map_err(StorageError::Sqlx)?;
         let total_projects: i64 = count_row.try_get("count")?;
 
         let last_modified_row =
             sqlx::query("SELECT MAX(updated_at) as last_modified FROM projects")
-                .fetch_one(&self.pool)
+                .fetch_one(self.router.reader())
                 .await
                 .map_err(StorageError::Sqlx)?;
         let last_modified_str: Option<String> = last_modified_row.try_get("last_modified")?;
@@ -917,6 +985,7 @@ impl ProjectStorage for SqliteStorage {
         };
 
         let size_bytes = std::fs::metadata(db_path).map(|m| m.len()).unwrap_or(0);
+        let stats = crate::maintenance::database_stats(&self.pool).await?;
 
         Ok(StorageInfo {
             provider: "SQLite".to_string(),
@@ -924,6 +993,10 @@ impl ProjectStorage for SqliteStorage {
             total_projects: total_projects as usize,
             last_modified,
             size_bytes,
+            free_bytes: stats.free_bytes,
+            wal_size_bytes: stats.wal_size_bytes,
+            tables: stats.tables,
+            pools: self.router.metrics(),
             capabilities: StorageCapabilities {
                 full_text_search: self.config.enable_fts,
                 real_time_sync: false,
@@ -934,6 +1007,10 @@ impl ProjectStorage for SqliteStorage {
         })
     }
 
+    fn router(&self) -> Option<ConnectionRouter> {
+        Some(self.router.clone())
+    }
+
     async fn export_snapshot(&self) -> StorageResult<Vec<u8>> {
         let projects = self.list_projects().await?;
 
@@ -1262,6 +1339,7 @@ mod tests {
             enable_fts: true,
             max_connections: 1, // Single connection for in-memory
             busy_timeout_seconds: 10,
+            read_replica: None,
         };
 
         let storage = SqliteStorage::new(config).await.unwrap();
@@ -1350,6 +1428,77 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_update_rejects_stale_version() {
+        let storage = create_test_storage().await;
+
+        let project = storage
+            .create_project(ProjectCreateInput {
+                name: "Versioned".to_string(),
+                project_root: "/tmp/versioned".to_string(),
+                description: None,
+                status: None,
+                priority: None,
+                rank: None,
+                setup_script: None,
+                dev_script: None,
+                cleanup_script: None,
+                task_source: None,
+                tags: None,
+                manual_tasks: None,
+                mcp_servers: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(project.version, 1);
+
+        let update = |description: &str, expected_version| ProjectUpdateInput {
+            name: None,
+            project_root: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            tags: None,
+            description: Some(description.to_string()),
+            status: None,
+            rank: None,
+            priority: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            expected_version,
+        };
+
+        let updated = storage
+            .update_project(&project.id, update("First", Some(1)))
+            .await
+            .unwrap();
+        assert_eq!(updated.version, 2);
+
+        // A writer still holding version 1 must not overwrite the first edit
+        match storage
+            .update_project(&project.id, update("Stale", Some(1)))
+            .await
+        {
+            Err(StorageError::VersionConflict { expected, actual }) => {
+                assert_eq!((expected, actual), (1, 2));
+            }
+            other => panic!(
+                "Expected VersionConflict, got {:?}",
+                other.map(|p| p.version)
+            ),
+        }
+        let current = storage.get_project(&project.id).await.unwrap().unwrap();
+        assert_eq!(current.description.as_deref(), Some("First"));
+
+        // Unversioned writes still bump the version
+        let unchecked = storage
+            .update_project(&project.id, update("Unchecked", None))
+            .await
+            .unwrap();
+        assert_eq!(unchecked.version, 3);
+    }
+
     #[tokio::test]
     async fn test_search_projects() {
         let storage = create_test_storage().await;
@@ -1397,4 +1546,51 @@ mod tests {
         assert_eq!(results.len(), 1);
         assert_eq!(results[0].name, "Rust CLI");
     }
+
+    #[tokio::test]
+    async fn test_projects_are_partitioned_by_current_user() {
+        let storage = create_test_storage().await;
+
+        let input = ProjectCreateInput {
+            name: "Default User Project".to_string(),
+            project_root: "/tmp/default-user".to_string(),
+            description: None,
+            status: None,
+            priority: None,
+            rank: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            task_source: None,
+            tags: None,
+            manual_tasks: None,
+            mcp_servers: None,
+        };
+        let project = storage.create_project(input).await.unwrap();
+        assert_eq!(project.owner_user_id.as_deref(), Some("default-user"));
+
+        sqlx::query(
+            "INSERT INTO users (id, email, name, created_at, updated_at)
+             VALUES ('second-user', 'second@localhost', 'Second User', 'now', 'now')",
+        )
+        .execute(storage.pool())
+        .await
+        .unwrap();
+        sqlx::query("UPDATE users SET is_current = (id = 'second-user')")
+            .execute(storage.pool())
+            .await
+            .unwrap();
+
+        assert!(storage.list_projects().await.unwrap().is_empty());
+        assert!(storage.search_projects("Default").await.unwrap().is_empty());
+        // Direct lookups are not partitioned
+        assert!(storage.get_project(&project.id).await.unwrap().is_some());
+
+        // Unowned projects are shared with everyone
+        sqlx::query("UPDATE projects SET owner_user_id = NULL")
+            .execute(storage.pool())
+            .await
+            .unwrap();
+        assert_eq!(storage.list_projects().await.unwrap().len(), 1);
+    }
 }
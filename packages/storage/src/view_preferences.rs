@@ -0,0 +1,302 @@
+// ABOUTME: Per-user table view preferences type definitions and storage
+// ABOUTME: Column layouts, sort orders, and filters shared by the dashboard and TUI
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use crate::StorageError;
+
+/// Maximum length of a view key
+pub const MAX_VIEW_KEY_LENGTH: usize = 100;
+
+/// Maximum number of columns or sort keys in one view
+pub const MAX_VIEW_COLUMNS: usize = 100;
+
+/// Maximum serialized size of a view's filters
+pub const MAX_FILTERS_BYTES: usize = 16 * 1024;
+
+/// Layout of a single column
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnPreference {
+    pub id: String,
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+    /// Width in the client's units (pixels on the dashboard, cells in the TUI)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// One sort key; a view's sort keys apply in order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SortPreference {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+/// A user's saved layout for one table view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ViewPreference {
+    pub user_id: String,
+    /// Client-defined view identifier, e.g. `tasks.list`
+    pub view_key: String,
+    /// Columns in display order
+    pub columns: Vec<ColumnPreference>,
+    pub sort: Vec<SortPreference>,
+    /// View-specific filter state; always a JSON object
+    pub filters: serde_json::Value,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Request to save a view's layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewPreferenceInput {
+    #[serde(default)]
+    pub columns: Vec<ColumnPreference>,
+    #[serde(default)]
+    pub sort: Vec<SortPreference>,
+    #[serde(default = "empty_filters")]
+    pub filters: serde_json::Value,
+}
+
+fn empty_filters() -> serde_json::Value {
+    serde_json::Value::Object(Default::default())
+}
+
+impl ViewPreferenceInput {
+    /// Validate sizes and shapes before storing
+    pub fn validate(&self) -> Result<(), StorageError> {
+        if self.columns.len() > MAX_VIEW_COLUMNS {
+            return Err(StorageError::Validation(format!(
+                "A view can have at most {} columns",
+                MAX_VIEW_COLUMNS
+            )));
+        }
+        if self.sort.len() > MAX_VIEW_COLUMNS {
+            return Err(StorageError::Validation(format!(
+                "A view can have at most {} sort keys",
+                MAX_VIEW_COLUMNS
+            )));
+        }
+        if self.columns.iter().any(|c| c.id.trim().is_empty())
+            || self.sort.iter().any(|s| s.column.trim().is_empty())
+        {
+            return Err(StorageError::Validation(
+                "Column IDs cannot be empty".to_string(),
+            ));
+        }
+        if !self.filters.is_object() {
+            return Err(StorageError::Validation(
+                "Filters must be a JSON object".to_string(),
+            ));
+        }
+        if self.filters.to_string().len() > MAX_FILTERS_BYTES {
+            return Err(StorageError::Validation(format!(
+                "Filters exceed maximum size of {} bytes",
+                MAX_FILTERS_BYTES
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Validate a client-supplied view key
+///
+/// Keys are dotted identifiers (`tasks.list`, `projects.table`), which keeps
+/// them safe to embed in URLs and log lines.
+pub fn validate_view_key(view_key: &str) -> Result<(), StorageError> {
+    if view_key.is_empty() || view_key.len() > MAX_VIEW_KEY_LENGTH {
+        return Err(StorageError::Validation(format!(
+            "View key must be 1-{} characters",
+            MAX_VIEW_KEY_LENGTH
+        )));
+    }
+    if !view_key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_'))
+    {
+        return Err(StorageError::Validation(format!(
+            "Invalid view key '{}': use letters, digits, '.', '-', or '_'",
+            view_key
+        )));
+    }
+    Ok(())
+}
+
+/// Storage layer for view preferences
+pub struct ViewPreferencesStorage {
+    pool: SqlitePool,
+}
+
+impl ViewPreferencesStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// List a user's saved views, optionally only those updated after `since`
+    ///
+    /// `since` lets cloud sync pull just the views that changed since its last run.
+    pub async fn list_preferences(
+        &self,
+        user_id: &str,
+        since: Option<&str>,
+    ) -> Result<Vec<ViewPreference>, StorageError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM view_preferences
+            WHERE user_id = ? AND (? IS NULL OR updated_at > ?)
+            ORDER BY view_key
+            "#,
+        )
+        .bind(user_id)
+        .bind(since)
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_preference).collect()
+    }
+
+    /// Get a user's saved layout for one view
+    pub async fn get_preference(
+        &self,
+        user_id: &str,
+        view_key: &str,
+    ) -> Result<Option<ViewPreference>, StorageError> {
+        let row = sqlx::query("SELECT * FROM view_preferences WHERE user_id = ? AND view_key = ?")
+            .bind(user_id)
+            .bind(view_key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        row.as_ref().map(row_to_preference).transpose()
+    }
+
+    /// Save a view's layout, replacing any previous one
+    pub async fn save_preference(
+        &self,
+        user_id: &str,
+        view_key: &str,
+        input: &ViewPreferenceInput,
+    ) -> Result<ViewPreference, StorageError> {
+        validate_view_key(view_key)?;
+        input.validate()?;
+        self.ensure_user(user_id).await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO view_preferences (user_id, view_key, columns, sort, filters)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(user_id, view_key) DO UPDATE SET
+                columns = excluded.columns,
+                sort = excluded.sort,
+                filters = excluded.filters,
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+            "#,
+        )
+        .bind(user_id)
+        .bind(view_key)
+        .bind(serde_json::to_string(&input.columns)?)
+        .bind(serde_json::to_string(&input.sort)?)
+        .bind(input.filters.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_preference(user_id, view_key)
+            .await?
+            .ok_or_else(|| StorageError::Database("Saved view preference not found".to_string()))
+    }
+
+    /// Apply a view pulled from cloud sync, keeping whichever copy is newer
+    ///
+    /// Returns whether the remote copy was applied.
+    pub async fn merge_remote(&self, remote: &ViewPreference) -> Result<bool, StorageError> {
+        validate_view_key(&remote.view_key)?;
+        self.ensure_user(&remote.user_id).await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO view_preferences (user_id, view_key, columns, sort, filters, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(user_id, view_key) DO UPDATE SET
+                columns = excluded.columns,
+                sort = excluded.sort,
+                filters = excluded.filters,
+                updated_at = excluded.updated_at
+            WHERE excluded.updated_at > view_preferences.updated_at
+            "#,
+        )
+        .bind(&remote.user_id)
+        .bind(&remote.view_key)
+        .bind(serde_json::to_string(&remote.columns)?)
+        .bind(serde_json::to_string(&remote.sort)?)
+        .bind(remote.filters.to_string())
+        .bind(&remote.created_at)
+        .bind(&remote.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a view's saved layout, restoring client defaults; returns whether one existed
+    pub async fn delete_preference(
+        &self,
+        user_id: &str,
+        view_key: &str,
+    ) -> Result<bool, StorageError> {
+        let result = sqlx::query("DELETE FROM view_preferences WHERE user_id = ? AND view_key = ?")
+            .bind(user_id)
+            .bind(view_key)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Ensure the user row exists (required for the FK on view_preferences.user_id)
+    async fn ensure_user(&self, user_id: &str) -> Result<(), StorageError> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO users (id, email, name, created_at, updated_at)
+            VALUES (?, ?, ?, datetime('now', 'utc'), datetime('now', 'utc'))
+            "#,
+        )
+        .bind(user_id)
+        .bind(format!("{}@localhost", user_id))
+        .bind(user_id.replace('-', " ").to_uppercase())
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(())
+    }
+}
+
+fn row_to_preference(row: &sqlx::sqlite::SqliteRow) -> Result<ViewPreference, StorageError> {
+    Ok(ViewPreference {
+        user_id: row.try_get("user_id")?,
+        view_key: row.try_get("view_key")?,
+        columns: serde_json::from_str(&row.try_get::<String, _>("columns")?)?,
+        sort: serde_json::from_str(&row.try_get::<String, _>("sort")?)?,
+        filters: serde_json::from_str(&row.try_get::<String, _>("filters")?)?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+    })
+}
@@ -0,0 +1,156 @@
+// ABOUTME: Tests for the change data capture journal
+// ABOUTME: Covers trigger capture, paging, entity filters, payload hashes, and pruning
+
+use orkee_storage::change_journal::{ChangeEntity, ChangeJournal, ChangeOp};
+use sqlx::SqlitePool;
+
+async fn insert_project(pool: &SqlitePool, id: &str, name: &str) {
+    sqlx::query(
+        "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+         VALUES (?, ?, ?, '2024-01-01T00:00:00Z', '2024-01-01T00:00:00Z')",
+    )
+    .bind(id)
+    .bind(name)
+    .bind(format!("/tmp/{}", id))
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test]
+async fn test_mutations_are_journaled_in_order(pool: SqlitePool) {
+    let journal = ChangeJournal::new(pool.clone());
+
+    insert_project(&pool, "proj-0001", "Alpha").await;
+    sqlx::query(
+        "UPDATE projects SET name = 'Beta', updated_at = '2024-01-02T00:00:00Z' WHERE id = 'proj-0001'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query("DELETE FROM projects WHERE id = 'proj-0001'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let feed = journal.list_since(0, 100, None).await.unwrap();
+    let ops: Vec<ChangeOp> = feed.changes.iter().map(|c| c.op).collect();
+    assert_eq!(
+        ops,
+        vec![ChangeOp::Create, ChangeOp::Update, ChangeOp::Delete]
+    );
+    assert!(feed
+        .changes
+        .iter()
+        .all(|c| c.entity_type == ChangeEntity::Project && c.entity_id == "proj-0001"));
+    assert!(feed.changes.windows(2).all(|w| w[0].seq < w[1].seq));
+
+    let created = &feed.changes[0];
+    let updated = &feed.changes[1];
+    assert_eq!(created.payload_hash.as_ref().map(String::len), Some(64));
+    assert_ne!(created.payload_hash, updated.payload_hash);
+    assert!(feed.changes[2].payload_hash.is_none());
+
+    assert_eq!(feed.next_since, feed.latest_seq);
+    assert!(!feed.has_more);
+}
+
+#[sqlx::test]
+async fn test_paging_and_entity_filter(pool: SqlitePool) {
+    let journal = ChangeJournal::new(pool.clone());
+
+    for i in 0..3 {
+        insert_project(&pool, &format!("proj-000{}", i), &format!("Project {}", i)).await;
+    }
+    sqlx::query(
+        "INSERT INTO tags (id, name, created_at) VALUES ('tag-0001', 'backend', '2024-01-01T00:00:00Z')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let first = journal.list_since(0, 2, None).await.unwrap();
+    assert_eq!(first.changes.len(), 2);
+    assert!(first.has_more);
+
+    let rest = journal.list_since(first.next_since, 2, None).await.unwrap();
+    assert_eq!(rest.changes.len(), 2);
+    assert!(!rest.has_more);
+    assert_eq!(rest.changes[1].entity_type, ChangeEntity::Tag);
+
+    let tags = journal
+        .list_since(0, 100, Some(ChangeEntity::Tag))
+        .await
+        .unwrap();
+    assert_eq!(tags.changes.len(), 1);
+    assert_eq!(tags.changes[0].entity_id, "tag-0001");
+}
+
+#[sqlx::test]
+async fn test_no_op_tag_updates_are_skipped(pool: SqlitePool) {
+    let journal = ChangeJournal::new(pool.clone());
+
+    sqlx::query(
+        "INSERT INTO tags (id, name, created_at) VALUES ('tag-0001', 'backend', '2024-01-01T00:00:00Z')",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    for name in ["backend", "api"] {
+        sqlx::query("UPDATE tags SET name = ? WHERE id = 'tag-0001'")
+            .bind(name)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    let feed = journal.list_since(0, 100, None).await.unwrap();
+    let ops: Vec<ChangeOp> = feed.changes.iter().map(|c| c.op).collect();
+    assert_eq!(ops, vec![ChangeOp::Create, ChangeOp::Update]);
+}
+
+#[sqlx::test]
+async fn test_prune_keeps_recent_and_latest_entries(pool: SqlitePool) {
+    let journal = ChangeJournal::new(pool.clone());
+
+    insert_project(&pool, "proj-0001", "Alpha").await;
+    insert_project(&pool, "proj-0002", "Beta").await;
+    sqlx::query("UPDATE change_journal SET changed_at = '2000-01-01T00:00:00Z'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    assert_eq!(journal.prune(30).await.unwrap(), 1);
+
+    let feed = journal.list_since(0, 100, None).await.unwrap();
+    assert_eq!(feed.changes.len(), 1);
+    assert_eq!(feed.oldest_seq, Some(feed.latest_seq));
+    assert_eq!(feed.changes[0].entity_id, "proj-0002");
+}
+
+#[sqlx::test]
+async fn test_project_scripts_are_left_out_of_payloads(pool: SqlitePool) {
+    insert_project(&pool, "proj-0001", "Alpha").await;
+    sqlx::query(
+        "UPDATE projects SET setup_script = 'export TOKEN=secret', updated_at = '2024-01-02T00:00:00Z'
+         WHERE id = 'proj-0001'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let payloads: Vec<String> = sqlx::query_scalar(
+        "SELECT payload FROM change_journal WHERE entity_type = 'project' ORDER BY seq",
+    )
+    .fetch_all(&pool)
+    .await
+    .unwrap();
+    assert_eq!(payloads.len(), 2);
+    for payload in payloads {
+        let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(payload["id"], "proj-0001");
+        assert!(payload.get("setup_script").is_none());
+        assert!(payload.get("dev_script").is_none());
+        assert!(payload.get("cleanup_script").is_none());
+    }
+}
@@ -0,0 +1,58 @@
+// ABOUTME: Tests for sensitive fields before a cipher is installed and while they are locked
+// ABOUTME: Own test binary, since the field cipher state is process-wide
+
+use orkee_storage::field_encryption::{self, FieldCipher};
+use orkee_storage::{StorageError, StorageResult};
+use std::sync::Arc;
+
+struct ReverseCipher;
+
+impl FieldCipher for ReverseCipher {
+    fn encrypt(&self, plaintext: &str) -> StorageResult<String> {
+        Ok(plaintext.chars().rev().collect())
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> StorageResult<String> {
+        Ok(ciphertext.chars().rev().collect())
+    }
+}
+
+/// One test walks through every state, so the shared state is never raced
+#[test]
+fn test_unset_locked_and_installed_states() {
+    // Without configured encryption, values pass through, even ones that look sealed
+    assert_eq!(field_encryption::seal("npm test").unwrap(), "npm test");
+    assert_eq!(
+        field_encryption::open("enc:v1:echo hi".to_string()).unwrap(),
+        "enc:v1:echo hi"
+    );
+
+    // A locked key refuses writes and sealed reads, but plaintext is still readable
+    field_encryption::lock("password required");
+    assert!(field_encryption::is_locked());
+    assert!(matches!(
+        field_encryption::seal("npm test"),
+        Err(StorageError::Encryption(_))
+    ));
+    assert!(matches!(
+        field_encryption::open("enc:v1:tset mpn".to_string()),
+        Err(StorageError::Encryption(_))
+    ));
+    assert_eq!(
+        field_encryption::open("npm test".to_string()).unwrap(),
+        "npm test"
+    );
+
+    // Installing the key unlocks the fields, and a later lock does not undo it
+    assert!(field_encryption::install(Arc::new(ReverseCipher)));
+    field_encryption::lock("password required");
+    assert!(!field_encryption::is_locked());
+    assert_eq!(
+        field_encryption::seal("npm test").unwrap(),
+        "enc:v1:tset mpn"
+    );
+    assert_eq!(
+        field_encryption::open("enc:v1:tset mpn".to_string()).unwrap(),
+        "npm test"
+    );
+}
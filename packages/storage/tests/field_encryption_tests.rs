@@ -0,0 +1,188 @@
+// ABOUTME: Tests for at-rest encryption of sensitive columns
+// ABOUTME: Own test binary, since the installed field cipher is process-wide
+
+use orkee_core::types::ProjectCreateInput;
+use orkee_storage::field_encryption::{self, FieldCipher};
+use orkee_storage::sqlite::SqliteStorage;
+use orkee_storage::{ProjectStorage, StorageConfig, StorageError, StorageProvider, StorageResult};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// Reverses the text behind a key tag, and refuses values sealed under another tag
+struct TaggedCipher(&'static str);
+
+impl FieldCipher for TaggedCipher {
+    fn encrypt(&self, plaintext: &str) -> StorageResult<String> {
+        Ok(format!(
+            "{}:{}",
+            self.0,
+            plaintext.chars().rev().collect::<String>()
+        ))
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> StorageResult<String> {
+        ciphertext
+            .strip_prefix(self.0)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .map(|reversed| reversed.chars().rev().collect())
+            .ok_or_else(|| StorageError::Encryption("wrong key".to_string()))
+    }
+}
+
+/// Every test installs the same cipher, so test order does not matter
+fn install_cipher() {
+    field_encryption::install(Arc::new(TaggedCipher("a")));
+}
+
+async fn create_test_storage() -> SqliteStorage {
+    let config = StorageConfig {
+        provider: StorageProvider::Sqlite {
+            path: PathBuf::from(":memory:"),
+        },
+        enable_wal: false,
+        enable_fts: true,
+        max_connections: 1,
+        busy_timeout_seconds: 10,
+        read_replica: None,
+    };
+    let storage = SqliteStorage::new(config).await.unwrap();
+    storage.initialize().await.unwrap();
+    storage
+}
+
+fn project_input(name: &str, setup_script: &str) -> ProjectCreateInput {
+    ProjectCreateInput {
+        name: name.to_string(),
+        project_root: format!("/tmp/{}", name.to_lowercase()),
+        description: None,
+        status: None,
+        priority: None,
+        rank: None,
+        setup_script: Some(setup_script.to_string()),
+        dev_script: None,
+        cleanup_script: None,
+        task_source: None,
+        tags: None,
+        manual_tasks: None,
+        mcp_servers: None,
+    }
+}
+
+async fn raw_column(storage: &SqliteStorage, column: &str, id: &str) -> String {
+    sqlx::query_scalar(&format!("SELECT {} FROM projects WHERE id = ?", column))
+        .bind(id)
+        .fetch_one(storage.pool())
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_scripts_encrypted_at_rest() {
+    install_cipher();
+    let storage = create_test_storage().await;
+
+    let project = storage
+        .create_project(project_input("Encrypted", "npm install"))
+        .await
+        .unwrap();
+    assert_eq!(project.setup_script.as_deref(), Some("npm install"));
+    assert_eq!(
+        raw_column(&storage, "setup_script", &project.id).await,
+        "enc:v1:a:llatsni mpn"
+    );
+
+    // Rows written before encryption are readable and get sealed by the backfill
+    sqlx::query("UPDATE projects SET dev_script = 'npm run dev' WHERE id = ?")
+        .bind(&project.id)
+        .execute(storage.pool())
+        .await
+        .unwrap();
+    let project = storage.get_project(&project.id).await.unwrap().unwrap();
+    assert_eq!(project.dev_script.as_deref(), Some("npm run dev"));
+
+    let report = field_encryption::encrypt_existing_rows(storage.pool())
+        .await
+        .unwrap();
+    assert_eq!(report.total(), 1);
+    let again = field_encryption::encrypt_existing_rows(storage.pool())
+        .await
+        .unwrap();
+    assert_eq!(again.total(), 0);
+
+    assert!(field_encryption::is_sealed(
+        &raw_column(&storage, "dev_script", &project.id).await
+    ));
+    let project = storage.get_project(&project.id).await.unwrap().unwrap();
+    assert_eq!(project.dev_script.as_deref(), Some("npm run dev"));
+}
+
+#[tokio::test]
+async fn test_values_that_look_sealed_are_still_encrypted() {
+    install_cipher();
+    let storage = create_test_storage().await;
+
+    // Plaintext that happens to start with the prefix is sealed on write
+    let project = storage
+        .create_project(project_input("Lookalike", "enc:v1:echo hi"))
+        .await
+        .unwrap();
+    assert_eq!(
+        raw_column(&storage, "setup_script", &project.id).await,
+        "enc:v1:a:ih ohce:1v:cne"
+    );
+    assert_eq!(project.setup_script.as_deref(), Some("enc:v1:echo hi"));
+
+    // The backfill only skips values the cipher can actually open
+    sqlx::query("UPDATE projects SET dev_script = 'enc:v1:make dev' WHERE id = ?")
+        .bind(&project.id)
+        .execute(storage.pool())
+        .await
+        .unwrap();
+    let report = field_encryption::encrypt_existing_rows(storage.pool())
+        .await
+        .unwrap();
+    assert_eq!(report.total(), 1);
+    let project = storage.get_project(&project.id).await.unwrap().unwrap();
+    assert_eq!(project.dev_script.as_deref(), Some("enc:v1:make dev"));
+}
+
+#[tokio::test]
+async fn test_rotate_existing_rows_rekeys_sealed_values() {
+    install_cipher();
+    let storage = create_test_storage().await;
+
+    let project = storage
+        .create_project(project_input("Rotated", "cargo build"))
+        .await
+        .unwrap();
+    sqlx::query("UPDATE projects SET dev_script = 'cargo run' WHERE id = ?")
+        .bind(&project.id)
+        .execute(storage.pool())
+        .await
+        .unwrap();
+
+    let mut conn = storage.pool().acquire().await.unwrap();
+    let report =
+        field_encryption::rotate_existing_rows(&mut conn, &TaggedCipher("a"), &TaggedCipher("b"))
+            .await
+            .unwrap();
+    drop(conn);
+    assert_eq!(report.total(), 1);
+
+    assert_eq!(
+        raw_column(&storage, "setup_script", &project.id).await,
+        "enc:v1:b:dliub ograc"
+    );
+    // Plaintext is left for the backfill
+    assert_eq!(
+        raw_column(&storage, "dev_script", &project.id).await,
+        "cargo run"
+    );
+
+    // Values the old key cannot open abort the rotation
+    let mut conn = storage.pool().acquire().await.unwrap();
+    let result =
+        field_encryption::rotate_existing_rows(&mut conn, &TaggedCipher("a"), &TaggedCipher("c"))
+            .await;
+    assert!(matches!(result, Err(StorageError::Encryption(_))));
+}
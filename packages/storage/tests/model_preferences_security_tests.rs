@@ -2,7 +2,8 @@
 // ABOUTME: Tests SQL injection prevention and input validation
 
 use orkee_storage::model_preferences::{
-    ModelPreferences, ModelPreferencesStorage, UpdateTaskModelRequest,
+    ModelConfig, ModelPreferences, ModelPreferencesStorage, UpdateTaskModelRequest,
+    UpdateTaskPolicyRequest,
 };
 use sqlx::SqlitePool;
 
@@ -289,3 +290,59 @@ async fn test_unicode_and_special_chars_handled_safely(pool: SqlitePool) {
         assert_eq!(prefs.user_id, user_id);
     }
 }
+
+#[sqlx::test]
+async fn test_task_policy_fallbacks_and_cost_cap(pool: SqlitePool) {
+    let storage = ModelPreferencesStorage::new(pool.clone());
+    let user_id = "default-user";
+
+    let policy = storage.get_task_policy(user_id, "chat").await.unwrap();
+    assert!(policy.fallbacks.is_empty());
+    assert_eq!(policy.max_cost_per_call, None);
+
+    let request = UpdateTaskPolicyRequest {
+        fallbacks: vec![
+            ModelConfig {
+                provider: "anthropic".to_string(),
+                model: "claude-haiku-4-5-20251001".to_string(),
+            },
+            ModelConfig {
+                provider: "anthropic".to_string(),
+                model: policy.primary.model.clone(),
+            },
+        ],
+        max_cost_per_call: Some(0.5),
+    };
+    let updated = storage
+        .update_task_policy(user_id, "chat", &request)
+        .await
+        .unwrap();
+    assert_eq!(updated.fallbacks, request.fallbacks);
+    assert_eq!(updated.max_cost_per_call, Some(0.5));
+    // The primary model isn't repeated in the chain
+    assert_eq!(updated.chain().len(), 2);
+
+    // Injection attempts and bad caps are rejected before touching the database
+    let result = storage
+        .update_task_policy(
+            user_id,
+            "chat'; DROP TABLE model_task_policies; --",
+            &request,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(orkee_storage::StorageError::InvalidInput(_))
+    ));
+    let negative_cap = UpdateTaskPolicyRequest {
+        fallbacks: vec![],
+        max_cost_per_call: Some(-1.0),
+    };
+    assert!(storage
+        .update_task_policy(user_id, "chat", &negative_cap)
+        .await
+        .is_err());
+
+    let policies = storage.list_task_policies(user_id).await.unwrap();
+    assert_eq!(policies.len(), 10);
+}
@@ -0,0 +1,221 @@
+// ABOUTME: Tests for the search query language and saved searches
+// ABOUTME: Covers parsing, filtering projects and tasks, visibility, and saved search CRUD
+
+use orkee_storage::search::{SavedSearchInput, SearchKind, SearchQuery, SearchStorage};
+use orkee_storage::StorageError;
+use sqlx::SqlitePool;
+
+async fn seed(pool: &SqlitePool) {
+    for (id, name, status, priority, tags, owner) in [
+        (
+            "proj-api1",
+            "Billing API",
+            "building",
+            "high",
+            r#"["backend"]"#,
+            None,
+        ),
+        (
+            "proj-web1",
+            "Web Dashboard",
+            "launched",
+            "low",
+            r#"["frontend"]"#,
+            None,
+        ),
+        (
+            "proj-priv",
+            "Private Tool",
+            "building",
+            "high",
+            r#"["backend"]"#,
+            Some("someone-else"),
+        ),
+    ] {
+        sqlx::query(
+            "INSERT INTO projects (id, name, project_root, status, priority, tags, owner_user_id) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(id)
+        .bind(name)
+        .bind(format!("/tmp/{}", id))
+        .bind(status)
+        .bind(priority)
+        .bind(tags)
+        .bind(owner)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    for (id, project_id, title, status, priority, tags) in [
+        (
+            "task-001",
+            "proj-api1",
+            "Fix auth token refresh",
+            "in-progress",
+            "high",
+            Some(r#"["backend","auth"]"#),
+        ),
+        (
+            "task-002",
+            "proj-api1",
+            "Write invoice export",
+            "done",
+            "medium",
+            None,
+        ),
+        (
+            "task-003",
+            "proj-web1",
+            "Auth screen polish",
+            "pending",
+            "low",
+            Some(r#"["frontend"]"#),
+        ),
+        (
+            "task-004",
+            "proj-priv",
+            "Auth audit",
+            "pending",
+            "high",
+            Some(r#"["backend"]"#),
+        ),
+    ] {
+        sqlx::query(
+            "INSERT INTO tasks (id, project_id, title, status, priority, tags, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, datetime('now'), datetime('now'))",
+        )
+        .bind(id)
+        .bind(project_id)
+        .bind(title)
+        .bind(status)
+        .bind(priority)
+        .bind(tags)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+}
+
+fn saved(name: &str, query: &str, shortcut: Option<&str>) -> SavedSearchInput {
+    SavedSearchInput {
+        name: name.to_string(),
+        query: query.to_string(),
+        shortcut: shortcut.map(str::to_string),
+        position: 0,
+    }
+}
+
+#[test]
+fn test_parse_query() {
+    let query =
+        SearchQuery::parse(r#"status:Active tag:"needs review" priority:high "auth flow" billing"#)
+            .unwrap();
+    assert_eq!(query.statuses, vec!["active"]);
+    assert_eq!(query.tags, vec!["needs review"]);
+    assert_eq!(query.priorities, vec!["high"]);
+    assert_eq!(query.text, vec!["auth flow", "billing"]);
+
+    let query = SearchQuery::parse("type:task status:in_progress").unwrap();
+    assert_eq!(query.kinds, vec![SearchKind::Task]);
+    assert_eq!(query.statuses, vec!["in-progress"]);
+
+    for bad in ["colour:red", "status:", r#""unterminated"#, "type:epic"] {
+        assert!(
+            matches!(SearchQuery::parse(bad), Err(StorageError::Validation(_))),
+            "{} should be rejected",
+            bad
+        );
+    }
+}
+
+#[sqlx::test]
+async fn test_search_filters_projects_and_tasks(pool: SqlitePool) {
+    seed(&pool).await;
+    let storage = SearchStorage::new(pool);
+
+    let results = storage
+        .search(r#"status:active tag:backend priority:high "auth""#, 50)
+        .await
+        .unwrap();
+    // No visible project mentions auth; the private project's task is hidden
+    assert!(results.projects.is_empty());
+    let task_ids: Vec<_> = results.tasks.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(task_ids, vec!["task-001"]);
+    assert_eq!(results.tasks[0].tags, vec!["backend", "auth"]);
+
+    let results = storage.search("tag:backend", 50).await.unwrap();
+    let project_ids: Vec<_> = results.projects.iter().map(|p| p.id.as_str()).collect();
+    assert_eq!(project_ids, vec!["proj-api1"]);
+
+    let results = storage
+        .search(r#"project:"web dashboard" type:task"#, 50)
+        .await
+        .unwrap();
+    assert!(results.projects.is_empty());
+    assert_eq!(results.tasks.len(), 1);
+    assert_eq!(results.tasks[0].id, "task-003");
+
+    let results = storage.search("status:closed", 50).await.unwrap();
+    assert_eq!(results.projects.len(), 1);
+    assert_eq!(results.projects[0].id, "proj-web1");
+    assert_eq!(results.tasks.len(), 1);
+    assert_eq!(results.tasks[0].id, "task-002");
+
+    // LIKE wildcards in free text are matched literally
+    let results = storage.search("%", 50).await.unwrap();
+    assert!(results.projects.is_empty() && results.tasks.is_empty());
+}
+
+#[sqlx::test]
+async fn test_saved_search_crud(pool: SqlitePool) {
+    let storage = SearchStorage::new(pool);
+
+    let created = storage
+        .create_saved(
+            "default-user",
+            &saved("Hot backend", "tag:backend priority:high", Some("1")),
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.shortcut.as_deref(), Some("1"));
+
+    let duplicate_name = storage
+        .create_saved("default-user", &saved("Hot backend", "status:active", None))
+        .await;
+    assert!(matches!(duplicate_name, Err(StorageError::Validation(m)) if m.contains("name")));
+
+    let duplicate_key = storage
+        .create_saved("default-user", &saved("Other", "status:active", Some("1")))
+        .await;
+    assert!(matches!(duplicate_key, Err(StorageError::Validation(m)) if m.contains("shortcut")));
+
+    let bad_query = storage
+        .create_saved("default-user", &saved("Broken", "colour:red", None))
+        .await;
+    assert!(matches!(bad_query, Err(StorageError::Validation(_))));
+
+    let updated = storage
+        .update_saved(
+            "default-user",
+            &created.id,
+            &saved("Hot backend", "tag:backend", Some("2")),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.query, "tag:backend");
+    assert_eq!(updated.shortcut.as_deref(), Some("2"));
+
+    assert!(storage
+        .update_saved("someone-else", &created.id, &saved("Mine", "tag:x", None))
+        .await
+        .unwrap()
+        .is_none());
+
+    assert_eq!(storage.list_saved("default-user").await.unwrap().len(), 1);
+    assert!(storage
+        .delete_saved("default-user", &created.id)
+        .await
+        .unwrap());
+    assert!(storage.list_saved("default-user").await.unwrap().is_empty());
+}
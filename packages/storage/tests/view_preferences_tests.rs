@@ -0,0 +1,135 @@
+// ABOUTME: Tests for per-user table view preferences storage
+// ABOUTME: Covers save/replace, validation, incremental listing, and cloud merge ordering
+
+use orkee_storage::view_preferences::{
+    ColumnPreference, SortDirection, SortPreference, ViewPreference, ViewPreferenceInput,
+    ViewPreferencesStorage,
+};
+use orkee_storage::StorageError;
+use sqlx::SqlitePool;
+
+fn input(columns: &[&str]) -> ViewPreferenceInput {
+    ViewPreferenceInput {
+        columns: columns
+            .iter()
+            .map(|id| ColumnPreference {
+                id: id.to_string(),
+                visible: true,
+                width: None,
+            })
+            .collect(),
+        sort: vec![SortPreference {
+            column: "priority".to_string(),
+            direction: SortDirection::Desc,
+        }],
+        filters: serde_json::json!({ "status": ["pending"] }),
+    }
+}
+
+#[sqlx::test]
+async fn test_save_replaces_previous_layout(pool: SqlitePool) {
+    let storage = ViewPreferencesStorage::new(pool);
+
+    storage
+        .save_preference("default-user", "tasks.list", &input(&["title", "status"]))
+        .await
+        .unwrap();
+    let saved = storage
+        .save_preference("default-user", "tasks.list", &input(&["status"]))
+        .await
+        .unwrap();
+
+    assert_eq!(saved.columns.len(), 1);
+    assert_eq!(saved.sort[0].direction, SortDirection::Desc);
+    assert_eq!(saved.filters["status"][0], "pending");
+
+    let all = storage
+        .list_preferences("default-user", None)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 1);
+
+    assert!(storage
+        .delete_preference("default-user", "tasks.list")
+        .await
+        .unwrap());
+    assert!(storage
+        .get_preference("default-user", "tasks.list")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[sqlx::test]
+async fn test_save_creates_missing_user(pool: SqlitePool) {
+    let storage = ViewPreferencesStorage::new(pool);
+
+    let saved = storage
+        .save_preference("other-user", "projects.table", &input(&["name"]))
+        .await
+        .unwrap();
+    assert_eq!(saved.user_id, "other-user");
+}
+
+#[sqlx::test]
+async fn test_rejects_invalid_input(pool: SqlitePool) {
+    let storage = ViewPreferencesStorage::new(pool);
+
+    let result = storage
+        .save_preference("default-user", "tasks/../list", &input(&["title"]))
+        .await;
+    assert!(matches!(result, Err(StorageError::Validation(_))));
+
+    let mut bad_filters = input(&["title"]);
+    bad_filters.filters = serde_json::json!(["not", "an", "object"]);
+    let result = storage
+        .save_preference("default-user", "tasks.list", &bad_filters)
+        .await;
+    assert!(matches!(result, Err(StorageError::Validation(_))));
+}
+
+#[sqlx::test]
+async fn test_list_since_and_merge_remote(pool: SqlitePool) {
+    let storage = ViewPreferencesStorage::new(pool);
+
+    let local = storage
+        .save_preference("default-user", "tasks.list", &input(&["title"]))
+        .await
+        .unwrap();
+
+    assert!(storage
+        .list_preferences("default-user", Some(&local.updated_at))
+        .await
+        .unwrap()
+        .is_empty());
+
+    // An older remote copy doesn't overwrite the local one
+    let stale = ViewPreference {
+        columns: vec![],
+        updated_at: "2000-01-01T00:00:00Z".to_string(),
+        ..local.clone()
+    };
+    assert!(!storage.merge_remote(&stale).await.unwrap());
+
+    // A newer one does
+    let newer = ViewPreference {
+        columns: vec![],
+        updated_at: "2999-01-01T00:00:00Z".to_string(),
+        ..local.clone()
+    };
+    assert!(storage.merge_remote(&newer).await.unwrap());
+
+    let merged = storage
+        .get_preference("default-user", "tasks.list")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(merged.columns.is_empty());
+    assert_eq!(merged.updated_at, newer.updated_at);
+
+    let changed = storage
+        .list_preferences("default-user", Some(&local.updated_at))
+        .await
+        .unwrap();
+    assert_eq!(changed.len(), 1);
+}
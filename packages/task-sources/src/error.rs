@@ -0,0 +1,39 @@
+// ABOUTME: Error type for task source configuration and imports
+// ABOUTME: Separates bad input and missing sources from tracker API, concurrent edit, and storage failures
+
+use orkee_storage::StorageError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TaskSourceError {
+    #[error("Task source not found")]
+    NotFound,
+
+    #[error("Invalid task source: {0}")]
+    InvalidInput(String),
+
+    /// The issue tracker rejected the request or returned something unexpected
+    #[error("{provider} API error: {message}")]
+    Provider {
+        provider: &'static str,
+        message: String,
+    },
+
+    /// tasks.json changed on disk while a sync was writing it
+    #[error("{0} was modified during the sync; sync again")]
+    ConcurrentEdit(String),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+impl From<sqlx::Error> for TaskSourceError {
+    fn from(error: sqlx::Error) -> Self {
+        match error {
+            sqlx::Error::RowNotFound => TaskSourceError::NotFound,
+            error => TaskSourceError::Storage(StorageError::Sqlx(error)),
+        }
+    }
+}
+
+pub type TaskSourceResult<T> = Result<T, TaskSourceError>;
@@ -0,0 +1,235 @@
+// ABOUTME: Imports issues from configured Linear and Jira sources into Orkee tasks
+// ABOUTME: Creates tasks for new issues and updates linked tasks when the issue changed since last import
+
+use chrono::Utc;
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::error::{TaskSourceError, TaskSourceResult};
+use super::mapping::{create_input, update_input};
+use super::storage::TaskSourceStorage;
+use super::types::{ExternalIssue, ImportSummary, TaskLink, TaskSourceConfig, TaskSourceProvider};
+use super::{jira, linear};
+use orkee_storage::StorageError;
+use orkee_tasks::storage::TaskStorage;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Imported tasks are attributed to the project owner, or this user for unowned projects
+const FALLBACK_USER_ID: &str = "default-user";
+
+pub struct TaskImporter {
+    pool: SqlitePool,
+    storage: TaskSourceStorage,
+    tasks: TaskStorage,
+    client: reqwest::Client,
+}
+
+impl TaskImporter {
+    pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| StorageError::Database(format!("Failed to build HTTP client: {}", e)))?;
+
+        Ok(Self {
+            storage: TaskSourceStorage::new(pool.clone())?,
+            tasks: TaskStorage::new(pool.clone()),
+            pool,
+            client,
+        })
+    }
+
+    pub fn storage(&self) -> &TaskSourceStorage {
+        &self.storage
+    }
+
+    /// Import one source. With `full`, every issue is fetched instead of only those
+    /// updated since the last import. The outcome is recorded on the source either way.
+    pub async fn import_source(
+        &self,
+        project_id: &str,
+        source_id: &str,
+        full: bool,
+    ) -> TaskSourceResult<ImportSummary> {
+        let source = self.storage.get_source(project_id, source_id).await?;
+
+        let result = self.fetch_and_apply(&source, full).await;
+        let (last_synced_at, error) = match &result {
+            Ok(summary) => (summary.last_synced_at, None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        self.storage
+            .record_import(&source.id, last_synced_at, error.as_deref())
+            .await?;
+
+        result
+    }
+
+    /// Incrementally import every enabled source, returning each source's outcome
+    pub async fn import_all(
+        &self,
+    ) -> TaskSourceResult<Vec<(TaskSourceConfig, TaskSourceResult<ImportSummary>)>> {
+        let sources = self.storage.list_enabled_sources().await?;
+
+        let mut results = Vec::with_capacity(sources.len());
+        for source in sources {
+            let result = self
+                .import_source(&source.project_id, &source.id, false)
+                .await;
+            if let Err(e) = &result {
+                warn!(
+                    "Failed to import {} source {} for project {}: {}",
+                    source.provider.as_str(),
+                    source.id,
+                    source.project_id,
+                    e
+                );
+            }
+            results.push((source, result));
+        }
+        Ok(results)
+    }
+
+    async fn fetch_and_apply(
+        &self,
+        source: &TaskSourceConfig,
+        full: bool,
+    ) -> TaskSourceResult<ImportSummary> {
+        let token = self.storage.api_token(&source.id).await?;
+        let since = if full { None } else { source.last_synced_at };
+
+        let issues = match source.provider {
+            TaskSourceProvider::Linear => {
+                linear::fetch_issues(&self.client, source, &token, since).await?
+            }
+            TaskSourceProvider::Jira => {
+                jira::fetch_issues(&self.client, source, &token, since).await?
+            }
+        };
+
+        self.apply_issues(source, &issues).await
+    }
+
+    /// Create or update tasks for fetched issues.
+    ///
+    /// An issue already linked to a task is only written when it changed after the
+    /// last import, so local edits survive until the issue changes upstream. Tasks
+    /// deleted locally are imported again the next time their issue changes.
+    pub async fn apply_issues(
+        &self,
+        source: &TaskSourceConfig,
+        issues: &[ExternalIssue],
+    ) -> TaskSourceResult<ImportSummary> {
+        let mut summary = ImportSummary {
+            fetched: issues.len(),
+            last_synced_at: source.last_synced_at,
+            ..Default::default()
+        };
+        let owner = project_owner(&self.pool, &source.project_id).await?;
+
+        for issue in issues {
+            let task_id = match self
+                .storage
+                .get_link(&source.id, &issue.external_id)
+                .await?
+            {
+                Some(link) if link.external_updated_at >= issue.updated_at => {
+                    summary.unchanged += 1;
+                    continue;
+                }
+                Some(link) => {
+                    let input = update_input(source.provider, &source.status_mapping, issue);
+                    self.tasks.update_task(&link.task_id, input).await?;
+                    summary.updated += 1;
+                    link.task_id
+                }
+                None => {
+                    let input = create_input(source.provider, &source.status_mapping, issue);
+                    let task = self
+                        .tasks
+                        .create_task(&source.project_id, &owner, input)
+                        .await?;
+                    summary.created += 1;
+                    task.id
+                }
+            };
+
+            self.storage
+                .save_link(&TaskLink {
+                    task_id: task_id.clone(),
+                    source_id: source.id.clone(),
+                    external_id: issue.external_id.clone(),
+                    external_key: issue.key.clone(),
+                    external_url: issue.url.clone(),
+                    external_updated_at: issue.updated_at,
+                    imported_at: Utc::now(),
+                })
+                .await?;
+            self.record_external_metadata(&task_id, source.provider, issue)
+                .await?;
+
+            if summary
+                .last_synced_at
+                .is_none_or(|at| issue.updated_at > at)
+            {
+                summary.last_synced_at = Some(issue.updated_at);
+            }
+        }
+
+        info!(
+            "Imported {} source {}: {} fetched, {} created, {} updated, {} unchanged",
+            source.provider.as_str(),
+            source.id,
+            summary.fetched,
+            summary.created,
+            summary.updated,
+            summary.unchanged
+        );
+        Ok(summary)
+    }
+
+    /// Keep the issue key and URL in task metadata so clients can link back to the tracker
+    async fn record_external_metadata(
+        &self,
+        task_id: &str,
+        provider: TaskSourceProvider,
+        issue: &ExternalIssue,
+    ) -> TaskSourceResult<()> {
+        let external = json!({
+            "provider": provider.as_str(),
+            "key": issue.key,
+            "url": issue.url,
+            "state": issue.state,
+        });
+        sqlx::query(
+            "UPDATE tasks
+             SET metadata = json_set(COALESCE(metadata, '{}'), '$.external', json(?))
+             WHERE id = ?",
+        )
+        .bind(external.to_string())
+        .bind(task_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+/// The user imported tasks are created as: the project owner, or the fallback user
+pub(crate) async fn project_owner(pool: &SqlitePool, project_id: &str) -> TaskSourceResult<String> {
+    let owner: Option<Option<String>> =
+        sqlx::query_scalar("SELECT owner_user_id FROM projects WHERE id = ?")
+            .bind(project_id)
+            .fetch_optional(pool)
+            .await?;
+
+    match owner {
+        Some(owner) => Ok(owner.unwrap_or_else(|| FALLBACK_USER_ID.to_string())),
+        None => Err(TaskSourceError::InvalidInput(format!(
+            "Project {} does not exist",
+            project_id
+        ))),
+    }
+}
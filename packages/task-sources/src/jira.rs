@@ -0,0 +1,340 @@
+// ABOUTME: Jira Cloud adapter that fetches a project's issues through the REST search API
+// ABOUTME: Builds JQL for incremental imports and converts Atlassian Document Format to plain text
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde_json::{json, Value};
+
+use super::error::{TaskSourceError, TaskSourceResult};
+use super::mapping::jira_priority;
+use super::types::{ExternalIssue, TaskSourceConfig};
+
+/// Issues requested per page
+const PAGE_SIZE: u32 = 100;
+
+/// Upper bound on pages per import so a misconfigured filter can't loop forever
+const MAX_PAGES: usize = 50;
+
+/// JQL dates are minute-precision and evaluated in the Jira user's time zone, so
+/// incremental imports reach back a day; already-imported issues are skipped
+/// by comparing update times.
+const INCREMENTAL_OVERLAP: Duration = Duration::days(1);
+
+const FIELDS: &[&str] = &[
+    "summary",
+    "description",
+    "status",
+    "priority",
+    "labels",
+    "duedate",
+    "updated",
+];
+
+fn provider_error(message: impl Into<String>) -> TaskSourceError {
+    TaskSourceError::Provider {
+        provider: "Jira",
+        message: message.into(),
+    }
+}
+
+/// JQL selecting the source's project and filter, updated since `since`, oldest first
+pub fn build_jql(source: &TaskSourceConfig, since: Option<DateTime<Utc>>) -> String {
+    let mut jql = format!(
+        "project = \"{}\"",
+        source.external_project.replace('"', "\\\"")
+    );
+    if let Some(filter) = source.filter.as_deref().filter(|f| !f.trim().is_empty()) {
+        jql.push_str(&format!(" AND ({})", filter));
+    }
+    if let Some(since) = since {
+        let since = since - INCREMENTAL_OVERLAP;
+        jql.push_str(&format!(
+            " AND updated >= \"{}\"",
+            since.format("%Y/%m/%d %H:%M")
+        ));
+    }
+    jql.push_str(" ORDER BY updated ASC");
+    jql
+}
+
+/// Fetch the source's issues updated after `since`, or all issues when `since` is `None`
+pub async fn fetch_issues(
+    client: &reqwest::Client,
+    source: &TaskSourceConfig,
+    api_token: &str,
+    since: Option<DateTime<Utc>>,
+) -> TaskSourceResult<Vec<ExternalIssue>> {
+    let base_url = source
+        .base_url
+        .as_deref()
+        .ok_or_else(|| TaskSourceError::InvalidInput("Jira sources need a base_url".into()))?
+        .trim_end_matches('/');
+    let email = source
+        .email
+        .as_deref()
+        .ok_or_else(|| TaskSourceError::InvalidInput("Jira sources need an email".into()))?;
+    let url = format!("{}/rest/api/3/search/jql", base_url);
+    let jql = build_jql(source, since);
+
+    let mut issues = Vec::new();
+    let mut next_page_token: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let body = json!({
+            "jql": jql,
+            "fields": FIELDS,
+            "maxResults": PAGE_SIZE,
+            "nextPageToken": next_page_token,
+        });
+        let response = client
+            .post(&url)
+            .basic_auth(email, Some(api_token))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| provider_error(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| provider_error(format!("Invalid response (HTTP {}): {}", status, e)))?;
+        if !status.is_success() {
+            return Err(provider_error(
+                error_message(&payload).unwrap_or_else(|| format!("HTTP {}", status)),
+            ));
+        }
+
+        let (page, next) = parse_search_page(&payload, base_url).map_err(provider_error)?;
+        issues.extend(page);
+        match next {
+            Some(token) => next_page_token = Some(token),
+            None => return Ok(issues),
+        }
+    }
+
+    Err(provider_error(format!(
+        "More than {} issues to import; narrow the source with a JQL filter",
+        MAX_PAGES * PAGE_SIZE as usize
+    )))
+}
+
+/// Jira's `errorMessages` and field `errors` joined into one message
+fn error_message(payload: &Value) -> Option<String> {
+    let mut messages: Vec<String> = payload["errorMessages"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|message| message.as_str().map(str::to_string))
+        .collect();
+    if let Some(errors) = payload["errors"].as_object() {
+        messages.extend(
+            errors
+                .iter()
+                .map(|(field, message)| format!("{}: {}", field, message.as_str().unwrap_or(""))),
+        );
+    }
+    (!messages.is_empty()).then(|| messages.join("; "))
+}
+
+/// Parse one page of search results, returning the issues and the next page token
+pub fn parse_search_page(
+    payload: &Value,
+    base_url: &str,
+) -> Result<(Vec<ExternalIssue>, Option<String>), String> {
+    let issues = payload["issues"]
+        .as_array()
+        .ok_or("Response is missing issues")?
+        .iter()
+        .map(|issue| parse_issue(issue, base_url))
+        .collect::<Result<_, _>>()?;
+
+    let next = if payload["isLast"].as_bool().unwrap_or(true) {
+        None
+    } else {
+        payload["nextPageToken"].as_str().map(str::to_string)
+    };
+
+    Ok((issues, next))
+}
+
+fn parse_issue(issue: &Value, base_url: &str) -> Result<ExternalIssue, String> {
+    let fields = &issue["fields"];
+    let key = issue["key"].as_str().ok_or("Issue is missing 'key'")?;
+    let updated = fields["updated"]
+        .as_str()
+        .ok_or_else(|| format!("{} is missing 'updated'", key))?;
+    // Jira omits the colon in the offset: 2026-10-15T08:30:00.000+0000
+    let updated_at = DateTime::parse_from_str(updated, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .map_err(|e| format!("{} has an invalid 'updated' time: {}", key, e))?
+        .with_timezone(&Utc);
+    let due_date = fields["duedate"]
+        .as_str()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc());
+    let labels = fields["labels"]
+        .as_array()
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let description = match &fields["description"] {
+        Value::String(text) => text.trim().to_string(),
+        Value::Null => String::new(),
+        document => adf_to_text(document),
+    };
+
+    Ok(ExternalIssue {
+        external_id: issue["id"]
+            .as_str()
+            .ok_or_else(|| format!("{} is missing 'id'", key))?
+            .to_string(),
+        key: key.to_string(),
+        url: Some(format!("{}/browse/{}", base_url, key)),
+        title: fields["summary"].as_str().unwrap_or(key).to_string(),
+        description: (!description.is_empty()).then_some(description),
+        state: fields["status"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        state_category: fields["status"]["statusCategory"]["key"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        priority: fields["priority"]["name"].as_str().and_then(jira_priority),
+        labels,
+        due_date,
+        updated_at,
+    })
+}
+
+/// Flatten an Atlassian Document Format node into plain text, one line per block
+pub fn adf_to_text(document: &Value) -> String {
+    fn walk(node: &Value, out: &mut String) {
+        match node["type"].as_str() {
+            Some("text") => out.push_str(node["text"].as_str().unwrap_or_default()),
+            Some("hardBreak") => out.push('\n'),
+            Some("mention") => out.push_str(node["attrs"]["text"].as_str().unwrap_or_default()),
+            _ => {}
+        }
+        if let Some(children) = node["content"].as_array() {
+            if node["type"].as_str() == Some("listItem") {
+                out.push_str("- ");
+            }
+            for child in children {
+                walk(child, out);
+            }
+        }
+        if matches!(
+            node["type"].as_str(),
+            Some("paragraph" | "heading" | "codeBlock" | "blockquote" | "rule")
+        ) && !out.ends_with('\n')
+        {
+            out.push('\n');
+        }
+    }
+
+    let mut out = String::new();
+    walk(document, &mut out);
+    out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orkee_tasks::TaskPriority;
+    use std::collections::HashMap;
+
+    fn source(filter: Option<&str>) -> TaskSourceConfig {
+        TaskSourceConfig {
+            id: "source01".to_string(),
+            project_id: "proj1234".to_string(),
+            provider: super::super::types::TaskSourceProvider::Jira,
+            base_url: Some("https://acme.atlassian.net".to_string()),
+            email: Some("dev@acme.test".to_string()),
+            external_project: "OPS".to_string(),
+            filter: filter.map(str::to_string),
+            status_mapping: HashMap::new(),
+            enabled: true,
+            last_synced_at: None,
+            last_import_at: None,
+            last_import_error: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_build_jql() {
+        assert_eq!(
+            build_jql(&source(None), None),
+            "project = \"OPS\" ORDER BY updated ASC"
+        );
+
+        let since = DateTime::parse_from_rfc3339("2026-10-15T08:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            build_jql(&source(Some("labels = backend")), Some(since)),
+            "project = \"OPS\" AND (labels = backend) AND updated >= \"2026/10/14 08:30\" \
+             ORDER BY updated ASC"
+        );
+    }
+
+    #[test]
+    fn test_parse_search_page() {
+        let payload = json!({
+            "issues": [{
+                "id": "10042",
+                "key": "OPS-7",
+                "fields": {
+                    "summary": "Rotate certificates",
+                    "description": {
+                        "type": "doc",
+                        "content": [
+                            { "type": "paragraph", "content": [{ "type": "text", "text": "Before expiry." }] },
+                            { "type": "bulletList", "content": [
+                                { "type": "listItem", "content": [
+                                    { "type": "paragraph", "content": [{ "type": "text", "text": "api" }] }
+                                ]}
+                            ]}
+                        ]
+                    },
+                    "status": { "name": "In Review", "statusCategory": { "key": "indeterminate" } },
+                    "priority": { "name": "Highest" },
+                    "labels": ["security"],
+                    "duedate": null,
+                    "updated": "2026-10-15T08:30:00.000+0200"
+                }
+            }],
+            "nextPageToken": "page-2",
+            "isLast": false
+        });
+
+        let (issues, next) = parse_search_page(&payload, "https://acme.atlassian.net").unwrap();
+        assert_eq!(next.as_deref(), Some("page-2"));
+        let issue = &issues[0];
+        assert_eq!(
+            issue.url.as_deref(),
+            Some("https://acme.atlassian.net/browse/OPS-7")
+        );
+        assert_eq!(issue.description.as_deref(), Some("Before expiry.\n- api"));
+        assert_eq!(issue.state, "In Review");
+        assert!(matches!(issue.priority, Some(TaskPriority::Critical)));
+        assert_eq!(issue.updated_at.to_rfc3339(), "2026-10-15T06:30:00+00:00");
+    }
+
+    #[test]
+    fn test_error_message() {
+        let payload = json!({
+            "errorMessages": ["The value 'NOPE' does not exist for the field 'project'."],
+            "errors": {}
+        });
+        assert_eq!(
+            error_message(&payload).as_deref(),
+            Some("The value 'NOPE' does not exist for the field 'project'.")
+        );
+    }
+}
@@ -0,0 +1,21 @@
+// ABOUTME: Import adapters that pull Linear and Jira issues into Orkee tasks, and Taskmaster sync
+// ABOUTME: Per-project source configs, field mapping, incremental re-import, and two-way tasks.json sync
+
+pub mod error;
+pub mod importer;
+pub mod jira;
+pub mod linear;
+pub mod mapping;
+pub mod storage;
+pub mod taskmaster;
+pub mod types;
+
+// Re-export main types
+pub use error::TaskSourceError;
+pub use importer::TaskImporter;
+pub use storage::TaskSourceStorage;
+pub use taskmaster::{MergeStrategy, TaskmasterSync};
+pub use types::{
+    ExternalIssue, ImportSummary, TaskLink, TaskSourceConfig, TaskSourceCreateInput,
+    TaskSourceProvider, TaskSourceUpdateInput,
+};
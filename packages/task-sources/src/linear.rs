@@ -0,0 +1,239 @@
+// ABOUTME: Linear adapter that fetches a team's issues through the GraphQL API
+// ABOUTME: Pages through issues updated since the last import and normalizes them to ExternalIssue
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::{json, Value};
+
+use super::error::{TaskSourceError, TaskSourceResult};
+use super::mapping::linear_priority;
+use super::types::{ExternalIssue, TaskSourceConfig};
+
+pub const DEFAULT_API_URL: &str = "https://api.linear.app/graphql";
+
+/// Issues requested per page (Linear's maximum is 250)
+const PAGE_SIZE: u32 = 100;
+
+/// Upper bound on pages per import so a misconfigured filter can't loop forever
+const MAX_PAGES: usize = 50;
+
+const ISSUES_QUERY: &str = r#"
+query Issues($filter: IssueFilter, $first: Int!, $after: String) {
+  issues(filter: $filter, first: $first, after: $after, orderBy: updatedAt) {
+    nodes {
+      id
+      identifier
+      url
+      title
+      description
+      priority
+      dueDate
+      updatedAt
+      state { name type }
+      labels { nodes { name } }
+    }
+    pageInfo { hasNextPage endCursor }
+  }
+}"#;
+
+fn provider_error(message: impl Into<String>) -> TaskSourceError {
+    TaskSourceError::Provider {
+        provider: "Linear",
+        message: message.into(),
+    }
+}
+
+/// Issue filter for the source's team, optional label, and incremental start time
+fn issue_filter(source: &TaskSourceConfig, since: Option<DateTime<Utc>>) -> Value {
+    let mut filter = json!({ "team": { "key": { "eq": source.external_project } } });
+    if let Some(label) = &source.filter {
+        filter["labels"] = json!({ "some": { "name": { "eqIgnoreCase": label } } });
+    }
+    if let Some(since) = since {
+        filter["updatedAt"] = json!({ "gt": since.to_rfc3339() });
+    }
+    filter
+}
+
+/// Fetch the source's issues updated after `since`, or all issues when `since` is `None`
+pub async fn fetch_issues(
+    client: &reqwest::Client,
+    source: &TaskSourceConfig,
+    api_token: &str,
+    since: Option<DateTime<Utc>>,
+) -> TaskSourceResult<Vec<ExternalIssue>> {
+    let url = source.base_url.as_deref().unwrap_or(DEFAULT_API_URL);
+    let filter = issue_filter(source, since);
+
+    let mut issues = Vec::new();
+    let mut after: Option<String> = None;
+    for _ in 0..MAX_PAGES {
+        let body = json!({
+            "query": ISSUES_QUERY,
+            "variables": { "filter": filter, "first": PAGE_SIZE, "after": after },
+        });
+        // Personal API keys are sent as-is, without a Bearer prefix
+        let response = client
+            .post(url)
+            .header("Authorization", api_token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| provider_error(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let payload: Value = response
+            .json()
+            .await
+            .map_err(|e| provider_error(format!("Invalid response (HTTP {}): {}", status, e)))?;
+        if let Some(message) = graphql_error(&payload) {
+            return Err(provider_error(message));
+        }
+        if !status.is_success() {
+            return Err(provider_error(format!("HTTP {}", status)));
+        }
+
+        let (page, next) = parse_issues_page(&payload).map_err(provider_error)?;
+        issues.extend(page);
+        match next {
+            Some(cursor) => after = Some(cursor),
+            None => return Ok(issues),
+        }
+    }
+
+    Err(provider_error(format!(
+        "More than {} issues to import; narrow the source with a label filter",
+        MAX_PAGES * PAGE_SIZE as usize
+    )))
+}
+
+fn graphql_error(payload: &Value) -> Option<String> {
+    let errors = payload["errors"].as_array()?;
+    let messages: Vec<&str> = errors
+        .iter()
+        .filter_map(|error| error["message"].as_str())
+        .collect();
+    (!errors.is_empty()).then(|| {
+        if messages.is_empty() {
+            "Unknown GraphQL error".to_string()
+        } else {
+            messages.join("; ")
+        }
+    })
+}
+
+/// Parse one page of an `issues` query, returning the issues and the next page cursor
+pub fn parse_issues_page(payload: &Value) -> Result<(Vec<ExternalIssue>, Option<String>), String> {
+    let connection = &payload["data"]["issues"];
+    let nodes = connection["nodes"]
+        .as_array()
+        .ok_or("Response is missing data.issues.nodes")?;
+
+    let issues = nodes.iter().map(parse_issue).collect::<Result<_, _>>()?;
+
+    let page_info = &connection["pageInfo"];
+    let next = if page_info["hasNextPage"].as_bool().unwrap_or(false) {
+        page_info["endCursor"].as_str().map(str::to_string)
+    } else {
+        None
+    };
+
+    Ok((issues, next))
+}
+
+fn parse_issue(node: &Value) -> Result<ExternalIssue, String> {
+    let field = |name: &str| {
+        node[name]
+            .as_str()
+            .ok_or_else(|| format!("Issue is missing '{}'", name))
+    };
+
+    let updated_at = DateTime::parse_from_rfc3339(field("updatedAt")?)
+        .map_err(|e| format!("Invalid updatedAt: {}", e))?
+        .with_timezone(&Utc);
+    let due_date = node["dueDate"]
+        .as_str()
+        .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc());
+    let labels = node["labels"]["nodes"]
+        .as_array()
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|label| label["name"].as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ExternalIssue {
+        external_id: field("id")?.to_string(),
+        key: field("identifier")?.to_string(),
+        url: node["url"].as_str().map(str::to_string),
+        title: field("title")?.to_string(),
+        description: node["description"]
+            .as_str()
+            .filter(|description| !description.trim().is_empty())
+            .map(str::to_string),
+        state: node["state"]["name"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        state_category: node["state"]["type"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        priority: node["priority"].as_i64().and_then(linear_priority),
+        labels,
+        due_date,
+        updated_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use orkee_tasks::TaskPriority;
+
+    #[test]
+    fn test_parse_issues_page() {
+        let payload = json!({
+            "data": { "issues": {
+                "nodes": [{
+                    "id": "9f1c",
+                    "identifier": "ENG-42",
+                    "url": "https://linear.app/acme/issue/ENG-42",
+                    "title": "Add SSO",
+                    "description": "Support SAML",
+                    "priority": 2,
+                    "dueDate": "2026-11-01",
+                    "updatedAt": "2026-10-15T08:30:00.000Z",
+                    "state": { "name": "In Progress", "type": "started" },
+                    "labels": { "nodes": [{ "name": "auth" }] }
+                }],
+                "pageInfo": { "hasNextPage": true, "endCursor": "cursor-1" }
+            }}
+        });
+
+        let (issues, next) = parse_issues_page(&payload).unwrap();
+        assert_eq!(next.as_deref(), Some("cursor-1"));
+        let issue = &issues[0];
+        assert_eq!(issue.key, "ENG-42");
+        assert_eq!(issue.state_category, "started");
+        assert!(matches!(issue.priority, Some(TaskPriority::High)));
+        assert_eq!(issue.labels, ["auth"]);
+        assert_eq!(
+            issue.due_date.unwrap().to_rfc3339(),
+            "2026-11-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_graphql_errors_are_reported() {
+        let payload = json!({ "errors": [{ "message": "Authentication required" }] });
+        assert_eq!(
+            graphql_error(&payload).as_deref(),
+            Some("Authentication required")
+        );
+        assert!(parse_issues_page(&payload).is_err());
+    }
+}
@@ -0,0 +1,226 @@
+// ABOUTME: Field mapping from Linear and Jira issues onto Orkee task inputs
+// ABOUTME: Maps workflow states and priorities, honoring per-source status overrides
+
+use std::collections::HashMap;
+
+use orkee_tasks::{TaskCreateInput, TaskPriority, TaskStatus, TaskUpdateInput};
+
+use super::types::{ExternalIssue, TaskSourceProvider};
+
+/// Default status for a provider's state category.
+///
+/// Linear state types: triage, backlog, unstarted, started, completed, canceled.
+/// Jira status category keys: new, indeterminate, done.
+pub fn default_status(provider: TaskSourceProvider, state_category: &str) -> TaskStatus {
+    match (provider, state_category) {
+        (TaskSourceProvider::Linear, "started") => TaskStatus::InProgress,
+        (TaskSourceProvider::Linear, "completed") => TaskStatus::Done,
+        (TaskSourceProvider::Linear, "canceled") => TaskStatus::Cancelled,
+        (TaskSourceProvider::Jira, "indeterminate") => TaskStatus::InProgress,
+        (TaskSourceProvider::Jira, "done") => TaskStatus::Done,
+        _ => TaskStatus::Pending,
+    }
+}
+
+/// Status for an issue: an override for its state name (case-insensitive) wins over the default
+pub fn map_status(
+    provider: TaskSourceProvider,
+    status_mapping: &HashMap<String, TaskStatus>,
+    issue: &ExternalIssue,
+) -> TaskStatus {
+    status_mapping
+        .iter()
+        .find(|(state, _)| state.eq_ignore_ascii_case(&issue.state))
+        .map(|(_, status)| status.clone())
+        .unwrap_or_else(|| default_status(provider, &issue.state_category))
+}
+
+/// Linear priorities: 0 none, 1 urgent, 2 high, 3 medium, 4 low
+pub fn linear_priority(priority: i64) -> Option<TaskPriority> {
+    match priority {
+        1 => Some(TaskPriority::Critical),
+        2 => Some(TaskPriority::High),
+        3 => Some(TaskPriority::Medium),
+        4 => Some(TaskPriority::Low),
+        _ => None,
+    }
+}
+
+/// Jira priority names, including the older Blocker/Critical/Major/Minor/Trivial scheme
+pub fn jira_priority(name: &str) -> Option<TaskPriority> {
+    match name.to_lowercase().as_str() {
+        "highest" | "blocker" | "critical" => Some(TaskPriority::Critical),
+        "high" | "major" => Some(TaskPriority::High),
+        "medium" => Some(TaskPriority::Medium),
+        "low" | "lowest" | "minor" | "trivial" => Some(TaskPriority::Low),
+        _ => None,
+    }
+}
+
+fn tags(issue: &ExternalIssue) -> Option<Vec<String>> {
+    (!issue.labels.is_empty()).then(|| issue.labels.clone())
+}
+
+/// Task input for an issue imported for the first time
+pub fn create_input(
+    provider: TaskSourceProvider,
+    status_mapping: &HashMap<String, TaskStatus>,
+    issue: &ExternalIssue,
+) -> TaskCreateInput {
+    TaskCreateInput {
+        title: issue.title.clone(),
+        description: issue.description.clone(),
+        status: Some(map_status(provider, status_mapping, issue)),
+        priority: issue.priority.clone(),
+        assigned_agent_id: None,
+        parent_id: None,
+        position: None,
+        dependencies: None,
+        due_date: issue.due_date,
+        estimated_hours: None,
+        complexity_score: None,
+        details: None,
+        test_strategy: None,
+        acceptance_criteria: None,
+        prompt: None,
+        context: None,
+        tag_id: None,
+        tags: tags(issue),
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+    }
+}
+
+/// Task changes for an issue updated since its last import.
+///
+/// Only fields owned by the tracker are overwritten; Orkee-only fields such as
+/// the assigned agent and checklist are left alone.
+pub fn update_input(
+    provider: TaskSourceProvider,
+    status_mapping: &HashMap<String, TaskStatus>,
+    issue: &ExternalIssue,
+) -> TaskUpdateInput {
+    TaskUpdateInput {
+        title: Some(issue.title.clone()),
+        description: issue.description.clone(),
+        status: Some(map_status(provider, status_mapping, issue)),
+        priority: issue.priority.clone(),
+        assigned_agent_id: None,
+        position: None,
+        dependencies: None,
+        due_date: issue.due_date,
+        estimated_hours: None,
+        actual_hours: None,
+        complexity_score: None,
+        details: None,
+        test_strategy: None,
+        acceptance_criteria: None,
+        tags: Some(issue.labels.clone()),
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+        expected_version: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn issue(state: &str, state_category: &str) -> ExternalIssue {
+        ExternalIssue {
+            external_id: "issue-1".to_string(),
+            key: "ENG-1".to_string(),
+            url: None,
+            title: "Fix login".to_string(),
+            description: None,
+            state: state.to_string(),
+            state_category: state_category.to_string(),
+            priority: None,
+            labels: vec![],
+            due_date: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_default_status_mapping() {
+        let linear = TaskSourceProvider::Linear;
+        let jira = TaskSourceProvider::Jira;
+        let none = HashMap::new();
+
+        assert!(matches!(
+            map_status(linear, &none, &issue("In Progress", "started")),
+            TaskStatus::InProgress
+        ));
+        assert!(matches!(
+            map_status(linear, &none, &issue("Canceled", "canceled")),
+            TaskStatus::Cancelled
+        ));
+        assert!(matches!(
+            map_status(linear, &none, &issue("Backlog", "backlog")),
+            TaskStatus::Pending
+        ));
+        assert!(matches!(
+            map_status(jira, &none, &issue("Done", "done")),
+            TaskStatus::Done
+        ));
+        assert!(matches!(
+            map_status(jira, &none, &issue("To Do", "new")),
+            TaskStatus::Pending
+        ));
+    }
+
+    #[test]
+    fn test_status_overrides_match_state_name() {
+        let mapping = HashMap::from([("in review".to_string(), TaskStatus::Review)]);
+
+        assert!(matches!(
+            map_status(
+                TaskSourceProvider::Jira,
+                &mapping,
+                &issue("In Review", "indeterminate")
+            ),
+            TaskStatus::Review
+        ));
+        assert!(matches!(
+            map_status(
+                TaskSourceProvider::Jira,
+                &mapping,
+                &issue("In Progress", "indeterminate")
+            ),
+            TaskStatus::InProgress
+        ));
+    }
+
+    #[test]
+    fn test_priority_mapping() {
+        assert!(matches!(linear_priority(1), Some(TaskPriority::Critical)));
+        assert!(matches!(linear_priority(4), Some(TaskPriority::Low)));
+        assert!(linear_priority(0).is_none());
+
+        assert!(matches!(
+            jira_priority("Highest"),
+            Some(TaskPriority::Critical)
+        ));
+        assert!(matches!(jira_priority("Major"), Some(TaskPriority::High)));
+        assert!(matches!(jira_priority("Trivial"), Some(TaskPriority::Low)));
+        assert!(jira_priority("Unprioritized").is_none());
+    }
+}
@@ -0,0 +1,381 @@
+// ABOUTME: Task source storage using SQLite
+// ABOUTME: Persists per-project source configs with encrypted API tokens and imported issue links
+
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+
+use super::error::{TaskSourceError, TaskSourceResult};
+use super::types::{
+    TaskLink, TaskSourceConfig, TaskSourceCreateInput, TaskSourceProvider, TaskSourceUpdateInput,
+};
+use orkee_security::ApiKeyEncryption;
+use orkee_storage::StorageError;
+
+pub struct TaskSourceStorage {
+    pool: SqlitePool,
+    encryption: ApiKeyEncryption,
+}
+
+/// Trimmed value, with empty strings treated as unset
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// Check the fields a provider needs before anything is stored
+fn validate(
+    provider: TaskSourceProvider,
+    base_url: Option<&str>,
+    email: Option<&str>,
+    external_project: &str,
+) -> TaskSourceResult<()> {
+    let invalid = |message: &str| Err(TaskSourceError::InvalidInput(message.to_string()));
+
+    if external_project.is_empty() {
+        return invalid("external_project must not be empty");
+    }
+    if let Some(url) = base_url {
+        if !url.starts_with("https://") && !url.starts_with("http://") {
+            return invalid("base_url must be an http(s) URL");
+        }
+    }
+    if provider == TaskSourceProvider::Jira {
+        if base_url.is_none() {
+            return invalid("Jira sources need a base_url such as https://acme.atlassian.net");
+        }
+        if email.is_none() {
+            return invalid("Jira sources need the email the API token belongs to");
+        }
+    }
+    Ok(())
+}
+
+impl TaskSourceStorage {
+    pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
+        let encryption = ApiKeyEncryption::new().map_err(|e| {
+            StorageError::Encryption(format!("Failed to initialize encryption: {}", e))
+        })?;
+        Ok(Self { pool, encryption })
+    }
+
+    fn encrypt_token(&self, token: &str) -> TaskSourceResult<String> {
+        if token.trim().is_empty() {
+            return Err(TaskSourceError::InvalidInput(
+                "api_token must not be empty".to_string(),
+            ));
+        }
+        self.encryption.encrypt(token.trim()).map_err(|e| {
+            StorageError::Encryption(format!("Failed to encrypt API token: {}", e)).into()
+        })
+    }
+
+    pub async fn list_sources(&self, project_id: &str) -> TaskSourceResult<Vec<TaskSourceConfig>> {
+        let rows = sqlx::query(
+            "SELECT * FROM project_task_sources WHERE project_id = ? ORDER BY created_at",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_source).collect()
+    }
+
+    /// Enabled sources across all projects, for scheduled imports
+    pub async fn list_enabled_sources(&self) -> TaskSourceResult<Vec<TaskSourceConfig>> {
+        let rows = sqlx::query(
+            "SELECT * FROM project_task_sources WHERE enabled = 1 ORDER BY project_id, created_at",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_source).collect()
+    }
+
+    pub async fn get_source(
+        &self,
+        project_id: &str,
+        source_id: &str,
+    ) -> TaskSourceResult<TaskSourceConfig> {
+        let row = sqlx::query("SELECT * FROM project_task_sources WHERE id = ? AND project_id = ?")
+            .bind(source_id)
+            .bind(project_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        row_to_source(&row)
+    }
+
+    pub async fn create_source(
+        &self,
+        project_id: &str,
+        input: TaskSourceCreateInput,
+    ) -> TaskSourceResult<TaskSourceConfig> {
+        let base_url = non_empty(input.base_url).map(|url| url.trim_end_matches('/').to_string());
+        let email = non_empty(input.email);
+        let external_project = input.external_project.trim().to_string();
+        validate(
+            input.provider,
+            base_url.as_deref(),
+            email.as_deref(),
+            &external_project,
+        )?;
+        let token = self.encrypt_token(&input.api_token)?;
+
+        let id = nanoid::nanoid!(12);
+        debug!(
+            "Creating {} task source {} for project {}",
+            input.provider.as_str(),
+            id,
+            project_id
+        );
+
+        sqlx::query(
+            "INSERT INTO project_task_sources (
+                id, project_id, provider, base_url, email, api_token_encrypted,
+                external_project, filter, status_mapping, enabled
+             )
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&id)
+        .bind(project_id)
+        .bind(input.provider.as_str())
+        .bind(&base_url)
+        .bind(&email)
+        .bind(&token)
+        .bind(&external_project)
+        .bind(non_empty(input.filter))
+        .bind(serde_json::to_string(&input.status_mapping).map_err(StorageError::Json)?)
+        .bind(input.enabled.unwrap_or(true))
+        .execute(&self.pool)
+        .await?;
+
+        self.get_source(project_id, &id).await
+    }
+
+    pub async fn update_source(
+        &self,
+        project_id: &str,
+        source_id: &str,
+        input: TaskSourceUpdateInput,
+    ) -> TaskSourceResult<TaskSourceConfig> {
+        let mut source = self.get_source(project_id, source_id).await?;
+
+        if let Some(base_url) = input.base_url {
+            source.base_url =
+                non_empty(Some(base_url)).map(|url| url.trim_end_matches('/').to_string());
+        }
+        if let Some(email) = input.email {
+            source.email = non_empty(Some(email));
+        }
+        if let Some(external_project) = input.external_project {
+            source.external_project = external_project.trim().to_string();
+        }
+        if let Some(filter) = input.filter {
+            source.filter = non_empty(Some(filter));
+        }
+        if let Some(status_mapping) = input.status_mapping {
+            source.status_mapping = status_mapping;
+        }
+        if let Some(enabled) = input.enabled {
+            source.enabled = enabled;
+        }
+        validate(
+            source.provider,
+            source.base_url.as_deref(),
+            source.email.as_deref(),
+            &source.external_project,
+        )?;
+
+        let mut tx = self.pool.begin().await?;
+        if let Some(token) = input.api_token {
+            sqlx::query("UPDATE project_task_sources SET api_token_encrypted = ? WHERE id = ?")
+                .bind(self.encrypt_token(&token)?)
+                .bind(source_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        sqlx::query(
+            "UPDATE project_task_sources
+             SET base_url = ?, email = ?, external_project = ?, filter = ?,
+                 status_mapping = ?, enabled = ?, updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(&source.base_url)
+        .bind(&source.email)
+        .bind(&source.external_project)
+        .bind(&source.filter)
+        .bind(serde_json::to_string(&source.status_mapping).map_err(StorageError::Json)?)
+        .bind(source.enabled)
+        .bind(Utc::now().to_rfc3339())
+        .bind(source_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        self.get_source(project_id, source_id).await
+    }
+
+    /// Delete a source. Tasks it imported are kept; only their links are removed.
+    pub async fn delete_source(&self, project_id: &str, source_id: &str) -> TaskSourceResult<()> {
+        let result =
+            sqlx::query("DELETE FROM project_task_sources WHERE id = ? AND project_id = ?")
+                .bind(source_id)
+                .bind(project_id)
+                .execute(&self.pool)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(TaskSourceError::NotFound);
+        }
+        Ok(())
+    }
+
+    /// Decrypted API token for a source
+    pub async fn api_token(&self, source_id: &str) -> TaskSourceResult<String> {
+        let encrypted: String =
+            sqlx::query_scalar("SELECT api_token_encrypted FROM project_task_sources WHERE id = ?")
+                .bind(source_id)
+                .fetch_one(&self.pool)
+                .await?;
+
+        self.encryption.decrypt(&encrypted).map_err(|e| {
+            StorageError::Encryption(format!(
+                "Failed to decrypt API token; re-enter it for this source: {}",
+                e
+            ))
+            .into()
+        })
+    }
+
+    /// Record the outcome of an import. `last_synced_at` only moves forward on success.
+    pub async fn record_import(
+        &self,
+        source_id: &str,
+        last_synced_at: Option<DateTime<Utc>>,
+        error: Option<&str>,
+    ) -> TaskSourceResult<()> {
+        sqlx::query(
+            "UPDATE project_task_sources
+             SET last_synced_at = COALESCE(?, last_synced_at), last_import_at = ?,
+                 last_import_error = ?
+             WHERE id = ?",
+        )
+        .bind(last_synced_at.map(|at| at.to_rfc3339()))
+        .bind(Utc::now().to_rfc3339())
+        .bind(error)
+        .bind(source_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_link(
+        &self,
+        source_id: &str,
+        external_id: &str,
+    ) -> TaskSourceResult<Option<TaskLink>> {
+        let row = sqlx::query(
+            "SELECT * FROM task_external_links WHERE source_id = ? AND external_id = ?",
+        )
+        .bind(source_id)
+        .bind(external_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.as_ref().map(row_to_link).transpose()
+    }
+
+    pub async fn list_links(&self, source_id: &str) -> TaskSourceResult<Vec<TaskLink>> {
+        let rows = sqlx::query(
+            "SELECT * FROM task_external_links WHERE source_id = ? ORDER BY external_key",
+        )
+        .bind(source_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_link).collect()
+    }
+
+    pub async fn save_link(&self, link: &TaskLink) -> TaskSourceResult<()> {
+        sqlx::query(
+            "INSERT INTO task_external_links (
+                task_id, source_id, external_id, external_key, external_url,
+                external_updated_at, imported_at
+             )
+             VALUES (?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(task_id) DO UPDATE SET
+                external_key = excluded.external_key,
+                external_url = excluded.external_url,
+                external_updated_at = excluded.external_updated_at,
+                imported_at = excluded.imported_at",
+        )
+        .bind(&link.task_id)
+        .bind(&link.source_id)
+        .bind(&link.external_id)
+        .bind(&link.external_key)
+        .bind(&link.external_url)
+        .bind(link.external_updated_at.to_rfc3339())
+        .bind(link.imported_at.to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+pub(crate) fn parse_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, StorageError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| StorageError::Database(format!("Invalid {} timestamp", field)))
+}
+
+pub(crate) fn optional_timestamp(
+    row: &sqlx::sqlite::SqliteRow,
+    field: &str,
+) -> Result<Option<DateTime<Utc>>, StorageError> {
+    let value: Option<String> = row.try_get(field)?;
+    value
+        .as_deref()
+        .map(|value| parse_timestamp(field, value))
+        .transpose()
+}
+
+fn row_to_source(row: &sqlx::sqlite::SqliteRow) -> TaskSourceResult<TaskSourceConfig> {
+    let provider: String = row.try_get("provider")?;
+    let status_mapping: String = row.try_get("status_mapping")?;
+    let created_at: String = row.try_get("created_at")?;
+    let updated_at: String = row.try_get("updated_at")?;
+
+    Ok(TaskSourceConfig {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        provider: provider.parse().map_err(StorageError::Database)?,
+        base_url: row.try_get("base_url")?,
+        email: row.try_get("email")?,
+        external_project: row.try_get("external_project")?,
+        filter: row.try_get("filter")?,
+        status_mapping: serde_json::from_str(&status_mapping).map_err(StorageError::Json)?,
+        enabled: row.try_get("enabled")?,
+        last_synced_at: optional_timestamp(row, "last_synced_at")?,
+        last_import_at: optional_timestamp(row, "last_import_at")?,
+        last_import_error: row.try_get("last_import_error")?,
+        created_at: parse_timestamp("created_at", &created_at)?,
+        updated_at: parse_timestamp("updated_at", &updated_at)?,
+    })
+}
+
+fn row_to_link(row: &sqlx::sqlite::SqliteRow) -> TaskSourceResult<TaskLink> {
+    let external_updated_at: String = row.try_get("external_updated_at")?;
+    let imported_at: String = row.try_get("imported_at")?;
+
+    Ok(TaskLink {
+        task_id: row.try_get("task_id")?,
+        source_id: row.try_get("source_id")?,
+        external_id: row.try_get("external_id")?,
+        external_key: row.try_get("external_key")?,
+        external_url: row.try_get("external_url")?,
+        external_updated_at: parse_timestamp("external_updated_at", &external_updated_at)?,
+        imported_at: parse_timestamp("imported_at", &imported_at)?,
+    })
+}
@@ -0,0 +1,302 @@
+// ABOUTME: Conversion of Orkee tasks and tasks.json entries to the fields kept in sync
+// ABOUTME: Field-by-field three-way merge of versions edited on both sides since the last sync
+
+use std::collections::HashMap;
+
+use orkee_tasks::{Task, TaskCreateInput, TaskUpdateInput};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Map, Value};
+
+use super::types::SyncedFields;
+
+/// Taskmaster task IDs are numbers; subtask IDs are "parent.child" strings
+pub fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(number) => Some(number.to_string()),
+        Value::String(id) => Some(id.clone()),
+        _ => None,
+    }
+}
+
+fn id_value(id: &str) -> Value {
+    id.parse::<u64>()
+        .map(Value::from)
+        .unwrap_or_else(|_| Value::String(id.to_string()))
+}
+
+/// Subtask dependencies stay in tasks.json; only top-level tasks are synced
+fn is_subtask_id(id: &str) -> bool {
+    id.contains('.')
+}
+
+fn text(task: &Value, key: &str) -> String {
+    task[key].as_str().unwrap_or_default().to_string()
+}
+
+/// Name an enum serializes to, e.g. "in-progress"
+fn enum_name<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Enum for a serialized name, or `None` for names Orkee doesn't know
+fn parse_enum<T: DeserializeOwned>(name: &str) -> Option<T> {
+    serde_json::from_value(Value::String(name.to_string())).ok()
+}
+
+/// Synced fields of a tasks.json task
+pub fn from_file_task(task: &Value) -> SyncedFields {
+    SyncedFields {
+        title: text(task, "title"),
+        description: text(task, "description"),
+        details: text(task, "details"),
+        test_strategy: text(task, "testStrategy"),
+        status: task["status"].as_str().unwrap_or("pending").to_string(),
+        priority: task["priority"].as_str().unwrap_or("medium").to_string(),
+        dependencies: task["dependencies"]
+            .as_array()
+            .map(|dependencies| {
+                dependencies
+                    .iter()
+                    .filter_map(id_string)
+                    .filter(|id| !is_subtask_id(id))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Write synced fields into a tasks.json task, keeping every other key as it was
+pub fn apply_to_file_task(task: &mut Value, fields: &SyncedFields) {
+    let kept_dependencies: Vec<Value> = task["dependencies"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|id| id_string(id).is_some_and(|id| is_subtask_id(&id)))
+        .cloned()
+        .collect();
+
+    task["title"] = json!(fields.title);
+    task["description"] = json!(fields.description);
+    task["details"] = json!(fields.details);
+    task["testStrategy"] = json!(fields.test_strategy);
+    task["status"] = json!(fields.status);
+    task["priority"] = json!(fields.priority);
+    task["dependencies"] = Value::Array(
+        fields
+            .dependencies
+            .iter()
+            .map(|id| id_value(id))
+            .chain(kept_dependencies)
+            .collect(),
+    );
+}
+
+/// A new tasks.json task
+pub fn new_file_task(taskmaster_id: &str, fields: &SyncedFields) -> Value {
+    let mut task = json!({ "id": id_value(taskmaster_id) });
+    apply_to_file_task(&mut task, fields);
+    task["subtasks"] = json!([]);
+    task
+}
+
+/// Synced fields of an Orkee task. `taskmaster_ids` maps Orkee task IDs to
+/// Taskmaster IDs; dependencies on tasks that aren't synced are left out.
+pub fn from_orkee_task(task: &Task, taskmaster_ids: &HashMap<String, String>) -> SyncedFields {
+    SyncedFields {
+        title: task.title.clone(),
+        description: task.description.clone().unwrap_or_default(),
+        details: task.details.clone().unwrap_or_default(),
+        test_strategy: task.test_strategy.clone().unwrap_or_default(),
+        status: enum_name(&task.status),
+        priority: enum_name(&task.priority),
+        dependencies: task
+            .dependencies
+            .iter()
+            .flatten()
+            .filter_map(|id| taskmaster_ids.get(id).cloned())
+            .collect(),
+    }
+}
+
+/// Task input for a task first seen in tasks.json. Dependencies are set once
+/// every imported task exists.
+pub fn create_input(fields: &SyncedFields) -> TaskCreateInput {
+    TaskCreateInput {
+        title: fields.title.clone(),
+        description: Some(fields.description.clone()),
+        status: parse_enum(&fields.status),
+        priority: parse_enum(&fields.priority),
+        assigned_agent_id: None,
+        parent_id: None,
+        position: None,
+        dependencies: None,
+        due_date: None,
+        estimated_hours: None,
+        complexity_score: None,
+        details: Some(fields.details.clone()),
+        test_strategy: Some(fields.test_strategy.clone()),
+        acceptance_criteria: None,
+        prompt: None,
+        context: None,
+        tag_id: None,
+        tags: None,
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+    }
+}
+
+/// Task changes that bring an Orkee task in line with synced fields.
+/// `dependencies` are Orkee task IDs.
+pub fn update_input(fields: &SyncedFields, dependencies: Vec<String>) -> TaskUpdateInput {
+    TaskUpdateInput {
+        title: Some(fields.title.clone()),
+        description: Some(fields.description.clone()),
+        status: parse_enum(&fields.status),
+        priority: parse_enum(&fields.priority),
+        assigned_agent_id: None,
+        position: None,
+        dependencies: Some(dependencies),
+        due_date: None,
+        estimated_hours: None,
+        actual_hours: None,
+        complexity_score: None,
+        details: Some(fields.details.clone()),
+        test_strategy: Some(fields.test_strategy.clone()),
+        acceptance_criteria: None,
+        tags: None,
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+        expected_version: None,
+    }
+}
+
+fn to_map(fields: &SyncedFields) -> Map<String, Value> {
+    match serde_json::to_value(fields) {
+        Ok(Value::Object(map)) => map,
+        _ => Map::new(),
+    }
+}
+
+/// Names of the fields that differ between two versions
+pub fn differing_fields(a: &SyncedFields, b: &SyncedFields) -> Vec<String> {
+    let b = to_map(b);
+    to_map(a)
+        .into_iter()
+        .filter(|(name, value)| b.get(name) != Some(value))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Merge two versions edited since `base`, taking each field from whichever
+/// side changed it. Fails with the names of fields both sides changed differently.
+pub fn three_way_merge(
+    base: &SyncedFields,
+    orkee: &SyncedFields,
+    taskmaster: &SyncedFields,
+) -> Result<SyncedFields, Vec<String>> {
+    let base = to_map(base);
+    let taskmaster = to_map(taskmaster);
+
+    let mut merged = Map::new();
+    let mut conflicts = Vec::new();
+    for (name, ours) in to_map(orkee) {
+        let theirs = taskmaster.get(&name).cloned().unwrap_or(Value::Null);
+        let original = base.get(&name).cloned().unwrap_or(Value::Null);
+        let value = if ours == theirs || theirs == original {
+            ours
+        } else if ours == original {
+            theirs
+        } else {
+            conflicts.push(name.clone());
+            ours
+        };
+        merged.insert(name, value);
+    }
+
+    if !conflicts.is_empty() {
+        return Err(conflicts);
+    }
+    serde_json::from_value(Value::Object(merged)).map_err(|e| vec![e.to_string()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(title: &str, status: &str) -> SyncedFields {
+        SyncedFields {
+            title: title.to_string(),
+            status: status.to_string(),
+            priority: "medium".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_file_task_round_trip_keeps_other_keys() {
+        let mut task = json!({
+            "id": 3,
+            "title": "Set up CI",
+            "status": "pending",
+            "dependencies": [1, "2.1"],
+            "subtasks": [{ "id": 1, "title": "Add workflow" }],
+            "complexity": 5
+        });
+
+        let mut synced = from_file_task(&task);
+        assert_eq!(synced.dependencies, ["1"]);
+        assert_eq!(synced.priority, "medium");
+
+        synced.status = "done".to_string();
+        synced.dependencies = vec!["2".to_string()];
+        apply_to_file_task(&mut task, &synced);
+
+        assert_eq!(task["status"], "done");
+        assert_eq!(task["dependencies"], json!([2, "2.1"]));
+        assert_eq!(task["complexity"], 5);
+        assert_eq!(task["subtasks"][0]["title"], "Add workflow");
+    }
+
+    #[test]
+    fn test_three_way_merge_takes_each_sides_changes() {
+        let base = fields("Set up CI", "pending");
+        let orkee = fields("Set up CI", "in-progress");
+        let taskmaster = fields("Set up GitHub Actions", "pending");
+
+        let merged = three_way_merge(&base, &orkee, &taskmaster).unwrap();
+        assert_eq!(merged.title, "Set up GitHub Actions");
+        assert_eq!(merged.status, "in-progress");
+    }
+
+    #[test]
+    fn test_three_way_merge_reports_overlapping_edits() {
+        let base = fields("Set up CI", "pending");
+        let orkee = fields("Set up CI", "done");
+        let taskmaster = fields("Set up CI", "deferred");
+
+        assert_eq!(
+            three_way_merge(&base, &orkee, &taskmaster),
+            Err(vec!["status".to_string()])
+        );
+        assert_eq!(differing_fields(&orkee, &taskmaster), ["status"]);
+    }
+}
@@ -0,0 +1,181 @@
+// ABOUTME: Reads and writes a project's .taskmaster/tasks/tasks.json
+// ABOUTME: Fingerprints the file by mtime and content hash so concurrent edits are never overwritten
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::fields::{apply_to_file_task, id_string, new_file_task};
+use super::types::SyncedFields;
+use crate::error::{TaskSourceError, TaskSourceResult};
+use orkee_storage::StorageError;
+
+/// Location of the task list relative to the project root
+pub const TASKS_FILE: &str = ".taskmaster/tasks/tasks.json";
+
+/// Tagged files keep each tag's tasks under its name; Orkee syncs the default tag
+const DEFAULT_TAG: &str = "master";
+
+/// Identifies one version of tasks.json
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFingerprint {
+    pub modified_at: Option<DateTime<Utc>>,
+    /// Hex SHA-256 of the file contents
+    pub hash: String,
+}
+
+impl FileFingerprint {
+    fn new(content: &[u8], modified: Option<SystemTime>) -> Self {
+        Self {
+            modified_at: modified.map(DateTime::<Utc>::from),
+            hash: hex::encode(Sha256::digest(content)),
+        }
+    }
+}
+
+pub struct TaskmasterFile {
+    path: PathBuf,
+    fingerprint: FileFingerprint,
+    document: Value,
+    tasks_pointer: String,
+}
+
+impl TaskmasterFile {
+    pub async fn read(project_root: &Path) -> TaskSourceResult<Self> {
+        let path = project_root.join(TASKS_FILE);
+        let content = match tokio::fs::read(&path).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(TaskSourceError::InvalidInput(format!(
+                    "{} does not exist",
+                    path.display()
+                )))
+            }
+            Err(e) => return Err(StorageError::Io(e).into()),
+        };
+        let modified = tokio::fs::metadata(&path)
+            .await
+            .ok()
+            .and_then(|metadata| metadata.modified().ok());
+
+        let document: Value = serde_json::from_slice(&content).map_err(|e| {
+            TaskSourceError::InvalidInput(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+        // Older files keep tasks at the top level; tagged files nest them per tag
+        let tasks_pointer = if document["tasks"].is_array() {
+            "/tasks".to_string()
+        } else if document[DEFAULT_TAG]["tasks"].is_array() {
+            format!("/{}/tasks", DEFAULT_TAG)
+        } else {
+            return Err(TaskSourceError::InvalidInput(format!(
+                "{} has no task list",
+                path.display()
+            )));
+        };
+
+        Ok(Self {
+            fingerprint: FileFingerprint::new(&content, modified),
+            path,
+            document,
+            tasks_pointer,
+        })
+    }
+
+    pub fn fingerprint(&self) -> &FileFingerprint {
+        &self.fingerprint
+    }
+
+    fn task_list(&self) -> &[Value] {
+        self.document
+            .pointer(&self.tasks_pointer)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    fn task_list_mut(&mut self) -> &mut Vec<Value> {
+        self.document
+            .pointer_mut(&self.tasks_pointer)
+            .and_then(Value::as_array_mut)
+            .expect("task list is checked when the file is read")
+    }
+
+    /// Top-level tasks with their Taskmaster IDs
+    pub fn tasks(&self) -> impl Iterator<Item = (String, &Value)> {
+        self.task_list()
+            .iter()
+            .filter_map(|task| id_string(&task["id"]).map(|id| (id, task)))
+    }
+
+    pub fn get(&self, taskmaster_id: &str) -> Option<&Value> {
+        self.tasks()
+            .find(|(id, _)| id == taskmaster_id)
+            .map(|(_, task)| task)
+    }
+
+    /// Highest numeric task ID in the file
+    pub fn max_id(&self) -> u64 {
+        self.tasks()
+            .filter_map(|(id, _)| id.parse().ok())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Update a task's synced fields, appending the task if it isn't in the file
+    pub fn upsert(&mut self, taskmaster_id: &str, fields: &SyncedFields) {
+        let tasks = self.task_list_mut();
+        match tasks
+            .iter_mut()
+            .find(|task| id_string(&task["id"]).as_deref() == Some(taskmaster_id))
+        {
+            Some(task) => apply_to_file_task(task, fields),
+            None => tasks.push(new_file_task(taskmaster_id, fields)),
+        }
+    }
+
+    pub fn remove(&mut self, taskmaster_id: &str) {
+        self.task_list_mut()
+            .retain(|task| id_string(&task["id"]).as_deref() != Some(taskmaster_id));
+    }
+
+    /// Write the file, failing with `ConcurrentEdit` if it changed since it was read.
+    ///
+    /// An unchanged mtime is trusted without re-reading the file. When the mtime
+    /// moved, the content hash decides, so a `touch` alone is not an edit.
+    pub async fn write(&mut self) -> TaskSourceResult<()> {
+        let metadata = tokio::fs::metadata(&self.path)
+            .await
+            .map_err(StorageError::Io)?;
+        let modified = metadata.modified().ok().map(DateTime::<Utc>::from);
+        if modified != self.fingerprint.modified_at {
+            let current = tokio::fs::read(&self.path)
+                .await
+                .map_err(StorageError::Io)?;
+            if FileFingerprint::new(&current, None).hash != self.fingerprint.hash {
+                return Err(TaskSourceError::ConcurrentEdit(
+                    self.path.display().to_string(),
+                ));
+            }
+        }
+
+        let content = serde_json::to_vec_pretty(&self.document).map_err(StorageError::Json)?;
+        // Write beside the file and rename so readers never see a partial file
+        let temp_path = self.path.with_extension("json.orkee-tmp");
+        tokio::fs::write(&temp_path, &content)
+            .await
+            .map_err(StorageError::Io)?;
+        tokio::fs::rename(&temp_path, &self.path)
+            .await
+            .map_err(StorageError::Io)?;
+
+        let modified = tokio::fs::metadata(&self.path)
+            .await
+            .ok()
+            .and_then(|metadata| metadata.modified().ok());
+        self.fingerprint = FileFingerprint::new(&content, modified);
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+// ABOUTME: Two-way sync between Orkee tasks and Taskmaster's .taskmaster/tasks/tasks.json
+// ABOUTME: Detects edits on either side since the last sync and records conflicts for review
+
+pub mod fields;
+pub mod file;
+pub mod storage;
+pub mod sync;
+pub mod types;
+
+pub use file::{FileFingerprint, TaskmasterFile, TASKS_FILE};
+pub use storage::TaskmasterSyncStorage;
+pub use sync::TaskmasterSync;
+pub use types::{
+    ConflictResolution, ConflictStatus, MergeStrategy, SyncReport, SyncedFields,
+    TaskmasterConflict, TaskmasterLink,
+};
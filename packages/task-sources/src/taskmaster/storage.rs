@@ -0,0 +1,241 @@
+// ABOUTME: Taskmaster sync storage using SQLite
+// ABOUTME: Persists tasks.json fingerprints, task links with their last-synced fields, and conflicts
+
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+
+use super::fields::differing_fields;
+use super::file::FileFingerprint;
+use super::types::{
+    ConflictResolution, ConflictStatus, SyncedFields, TaskmasterConflict, TaskmasterLink,
+};
+use crate::error::TaskSourceResult;
+use crate::storage::{optional_timestamp, parse_timestamp};
+use orkee_storage::StorageError;
+
+pub struct TaskmasterSyncStorage {
+    pool: SqlitePool,
+}
+
+impl TaskmasterSyncStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Fingerprint of tasks.json as of the last sync
+    pub async fn get_fingerprint(
+        &self,
+        project_id: &str,
+    ) -> TaskSourceResult<Option<FileFingerprint>> {
+        let row = sqlx::query(
+            "SELECT file_hash, file_modified_at FROM taskmaster_sync_state WHERE project_id = ?",
+        )
+        .bind(project_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        Ok(Some(FileFingerprint {
+            modified_at: optional_timestamp(&row, "file_modified_at")?,
+            hash: row.try_get("file_hash")?,
+        }))
+    }
+
+    pub async fn save_fingerprint(
+        &self,
+        project_id: &str,
+        fingerprint: &FileFingerprint,
+    ) -> TaskSourceResult<()> {
+        sqlx::query(
+            "INSERT INTO taskmaster_sync_state (project_id, file_hash, file_modified_at, last_synced_at)
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(project_id) DO UPDATE SET
+                file_hash = excluded.file_hash,
+                file_modified_at = excluded.file_modified_at,
+                last_synced_at = excluded.last_synced_at",
+        )
+        .bind(project_id)
+        .bind(&fingerprint.hash)
+        .bind(fingerprint.modified_at.map(|at| at.to_rfc3339()))
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn list_links(&self, project_id: &str) -> TaskSourceResult<Vec<TaskmasterLink>> {
+        let rows = sqlx::query(
+            "SELECT * FROM taskmaster_task_links WHERE project_id = ? ORDER BY synced_at",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_link).collect()
+    }
+
+    /// Pair a task with a tasks.json task and record both sides' current fields
+    pub async fn save_link(
+        &self,
+        project_id: &str,
+        taskmaster_id: &str,
+        task_id: &str,
+        base: &SyncedFields,
+    ) -> TaskSourceResult<()> {
+        sqlx::query(
+            "INSERT INTO taskmaster_task_links (project_id, taskmaster_id, task_id, base_fields, synced_at)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(project_id, taskmaster_id) DO UPDATE SET
+                task_id = excluded.task_id,
+                base_fields = excluded.base_fields,
+                synced_at = excluded.synced_at",
+        )
+        .bind(project_id)
+        .bind(taskmaster_id)
+        .bind(task_id)
+        .bind(serde_json::to_string(base).map_err(StorageError::Json)?)
+        .bind(Utc::now().to_rfc3339())
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_link(&self, project_id: &str, taskmaster_id: &str) -> TaskSourceResult<()> {
+        sqlx::query("DELETE FROM taskmaster_task_links WHERE project_id = ? AND taskmaster_id = ?")
+            .bind(project_id)
+            .bind(taskmaster_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn list_conflicts(
+        &self,
+        project_id: &str,
+        status: Option<ConflictStatus>,
+    ) -> TaskSourceResult<Vec<TaskmasterConflict>> {
+        let rows = sqlx::query(
+            "SELECT * FROM taskmaster_conflicts
+             WHERE project_id = ? AND (? IS NULL OR status = ?)
+             ORDER BY created_at DESC",
+        )
+        .bind(project_id)
+        .bind(status.map(|status| status.as_str()))
+        .bind(status.map(|status| status.as_str()))
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter().map(row_to_conflict).collect()
+    }
+
+    pub async fn get_conflict(&self, conflict_id: &str) -> TaskSourceResult<TaskmasterConflict> {
+        let row = sqlx::query("SELECT * FROM taskmaster_conflicts WHERE id = ?")
+            .bind(conflict_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        row_to_conflict(&row)
+    }
+
+    /// Record a conflict for a linked task, refreshing the open one if there already is one
+    pub async fn save_conflict(
+        &self,
+        project_id: &str,
+        link: &TaskmasterLink,
+        orkee: &SyncedFields,
+        taskmaster: &SyncedFields,
+    ) -> TaskSourceResult<()> {
+        let fields = differing_fields(orkee, taskmaster);
+        let json = |value: &SyncedFields| serde_json::to_string(value).map_err(StorageError::Json);
+
+        sqlx::query(
+            "INSERT INTO taskmaster_conflicts (
+                id, project_id, task_id, taskmaster_id, fields, base_fields,
+                orkee_fields, taskmaster_fields
+             )
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT(project_id, taskmaster_id) WHERE status = 'open' DO UPDATE SET
+                task_id = excluded.task_id,
+                fields = excluded.fields,
+                base_fields = excluded.base_fields,
+                orkee_fields = excluded.orkee_fields,
+                taskmaster_fields = excluded.taskmaster_fields",
+        )
+        .bind(nanoid::nanoid!(8))
+        .bind(project_id)
+        .bind(&link.task_id)
+        .bind(&link.taskmaster_id)
+        .bind(serde_json::to_string(&fields).map_err(StorageError::Json)?)
+        .bind(json(&link.base)?)
+        .bind(json(orkee)?)
+        .bind(json(taskmaster)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Resolve the open conflict for a task, if it has one
+    pub async fn resolve_open_conflict(
+        &self,
+        project_id: &str,
+        taskmaster_id: &str,
+        resolution: ConflictResolution,
+    ) -> TaskSourceResult<()> {
+        sqlx::query(
+            "UPDATE taskmaster_conflicts SET status = 'resolved', resolution = ?, resolved_at = ?
+             WHERE project_id = ? AND taskmaster_id = ? AND status = 'open'",
+        )
+        .bind(resolution.as_str())
+        .bind(Utc::now().to_rfc3339())
+        .bind(project_id)
+        .bind(taskmaster_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
+
+fn json_column<T: serde::de::DeserializeOwned>(
+    row: &sqlx::sqlite::SqliteRow,
+    field: &str,
+) -> Result<T, StorageError> {
+    let value: String = row.try_get(field)?;
+    serde_json::from_str(&value).map_err(StorageError::Json)
+}
+
+fn row_to_link(row: &sqlx::sqlite::SqliteRow) -> TaskSourceResult<TaskmasterLink> {
+    let synced_at: String = row.try_get("synced_at")?;
+
+    Ok(TaskmasterLink {
+        taskmaster_id: row.try_get("taskmaster_id")?,
+        task_id: row.try_get("task_id")?,
+        base: json_column(row, "base_fields")?,
+        synced_at: parse_timestamp("synced_at", &synced_at)?,
+    })
+}
+
+fn row_to_conflict(row: &sqlx::sqlite::SqliteRow) -> TaskSourceResult<TaskmasterConflict> {
+    let status: String = row.try_get("status")?;
+    let resolution: Option<String> = row.try_get("resolution")?;
+    let created_at: String = row.try_get("created_at")?;
+
+    Ok(TaskmasterConflict {
+        id: row.try_get("id")?,
+        project_id: row.try_get("project_id")?,
+        task_id: row.try_get("task_id")?,
+        taskmaster_id: row.try_get("taskmaster_id")?,
+        fields: json_column(row, "fields")?,
+        base: json_column(row, "base_fields")?,
+        orkee: json_column(row, "orkee_fields")?,
+        taskmaster: json_column(row, "taskmaster_fields")?,
+        status: status.parse().map_err(StorageError::Database)?,
+        resolution: resolution
+            .map(|resolution| resolution.parse())
+            .transpose()
+            .map_err(StorageError::Database)?,
+        created_at: parse_timestamp("created_at", &created_at)?,
+        resolved_at: optional_timestamp(row, "resolved_at")?,
+    })
+}
@@ -0,0 +1,512 @@
+// ABOUTME: Two-way sync between a project's Orkee tasks and its .taskmaster/tasks/tasks.json
+// ABOUTME: Reconciles each task pair against its last-synced fields and settles concurrent edits by strategy
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+use super::fields::{create_input, from_file_task, from_orkee_task, three_way_merge, update_input};
+use super::file::TaskmasterFile;
+use super::storage::TaskmasterSyncStorage;
+use super::types::{
+    ConflictResolution, ConflictStatus, MergeStrategy, SyncReport, SyncedFields, TaskmasterConflict,
+};
+use crate::error::{TaskSourceError, TaskSourceResult};
+use crate::importer::project_owner;
+use orkee_storage::StorageError;
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::Task;
+
+/// Attempts before giving up on a sync whose tasks.json keeps changing underneath it
+const MAX_ATTEMPTS: usize = 3;
+
+/// How a sync reconciles one linked pair of tasks
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Unchanged,
+    /// Both sides were edited the same way; only the last-synced fields move
+    Converged(SyncedFields),
+    ToFile(SyncedFields),
+    ToOrkee(SyncedFields),
+    /// Both sides take a field-by-field merge of their edits
+    Merged(SyncedFields),
+    DeleteFromFile,
+    DeleteFromOrkee,
+    /// Deleted on both sides; the pairing is dropped
+    Forget,
+    Conflict {
+        orkee: SyncedFields,
+        taskmaster: SyncedFields,
+    },
+}
+
+/// Fields to write to an Orkee task, creating the task if it doesn't exist
+struct OrkeeWrite<'a> {
+    taskmaster_id: String,
+    existing: Option<&'a Task>,
+    fields: SyncedFields,
+    merged: bool,
+}
+
+/// Reconcile a linked pair against the fields both sides had at the last sync.
+///
+/// A missing side was deleted since then. The deletion is carried over unless
+/// the other side was edited, in which case the edit wins and restores the task.
+fn reconcile(
+    base: &SyncedFields,
+    orkee: Option<SyncedFields>,
+    taskmaster: Option<SyncedFields>,
+    strategy: MergeStrategy,
+) -> Outcome {
+    match (orkee, taskmaster) {
+        (None, None) => Outcome::Forget,
+        (Some(orkee), None) if orkee == *base => Outcome::DeleteFromOrkee,
+        (Some(orkee), None) => Outcome::ToFile(orkee),
+        (None, Some(taskmaster)) if taskmaster == *base => Outcome::DeleteFromFile,
+        (None, Some(taskmaster)) => Outcome::ToOrkee(taskmaster),
+        (Some(orkee), Some(taskmaster)) if orkee == taskmaster => {
+            if orkee == *base {
+                Outcome::Unchanged
+            } else {
+                Outcome::Converged(orkee)
+            }
+        }
+        (Some(orkee), Some(taskmaster)) if orkee == *base => Outcome::ToOrkee(taskmaster),
+        (Some(orkee), Some(taskmaster)) if taskmaster == *base => Outcome::ToFile(orkee),
+        (Some(orkee), Some(taskmaster)) => match strategy {
+            MergeStrategy::PreferOrkee => Outcome::ToFile(orkee),
+            MergeStrategy::PreferTaskmaster => Outcome::ToOrkee(taskmaster),
+            MergeStrategy::Merge => match three_way_merge(base, &orkee, &taskmaster) {
+                Ok(merged) => Outcome::Merged(merged),
+                Err(_) => Outcome::Conflict { orkee, taskmaster },
+            },
+            MergeStrategy::Manual => Outcome::Conflict { orkee, taskmaster },
+        },
+    }
+}
+
+/// Orkee task IDs for synced fields' Taskmaster dependencies. Dependencies the
+/// existing task has on tasks that aren't synced, such as subtasks, are kept.
+fn orkee_dependencies(
+    fields: &SyncedFields,
+    task_ids: &HashMap<String, String>,
+    taskmaster_ids: &HashMap<String, String>,
+    existing: Option<&Task>,
+) -> Vec<String> {
+    let mut dependencies: Vec<String> = fields
+        .dependencies
+        .iter()
+        .filter_map(|id| task_ids.get(id).cloned())
+        .collect();
+    if let Some(task) = existing {
+        dependencies.extend(
+            task.dependencies
+                .iter()
+                .flatten()
+                .filter(|id| !taskmaster_ids.contains_key(*id))
+                .cloned(),
+        );
+    }
+    dependencies
+}
+
+fn reversed(map: &HashMap<String, String>) -> HashMap<String, String> {
+    map.iter()
+        .map(|(key, value)| (value.clone(), key.clone()))
+        .collect()
+}
+
+pub struct TaskmasterSync {
+    pool: SqlitePool,
+    storage: TaskmasterSyncStorage,
+    tasks: TaskStorage,
+}
+
+impl TaskmasterSync {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            storage: TaskmasterSyncStorage::new(pool.clone()),
+            tasks: TaskStorage::new(pool.clone()),
+            pool,
+        }
+    }
+
+    pub fn storage(&self) -> &TaskmasterSyncStorage {
+        &self.storage
+    }
+
+    /// Sync a project's top-level tasks with its tasks.json in both directions.
+    /// Retried when tasks.json is edited while the sync is writing it.
+    pub async fn sync(
+        &self,
+        project_id: &str,
+        strategy: MergeStrategy,
+    ) -> TaskSourceResult<SyncReport> {
+        let mut attempt = 1;
+        loop {
+            match self.sync_once(project_id, strategy).await {
+                Err(TaskSourceError::ConcurrentEdit(path)) if attempt < MAX_ATTEMPTS => {
+                    warn!(
+                        "{} changed during sync of project {}; retrying",
+                        path, project_id
+                    );
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn sync_once(
+        &self,
+        project_id: &str,
+        strategy: MergeStrategy,
+    ) -> TaskSourceResult<SyncReport> {
+        let mut file = TaskmasterFile::read(&self.project_root(project_id).await?).await?;
+        let mut report = SyncReport {
+            file_changed: self
+                .storage
+                .get_fingerprint(project_id)
+                .await?
+                .is_some_and(|last| last.hash != file.fingerprint().hash),
+            ..Default::default()
+        };
+
+        let links = self.storage.list_links(project_id).await?;
+        let external = self.externally_imported(project_id).await?;
+        let orkee_tasks: Vec<Task> = self
+            .tasks
+            .list_tasks(project_id)
+            .await?
+            .into_iter()
+            .filter(|task| !external.contains(&task.id))
+            .collect();
+        let orkee_by_id: HashMap<&str, &Task> = orkee_tasks
+            .iter()
+            .map(|task| (task.id.as_str(), task))
+            .collect();
+
+        // Pair up new tasks: Orkee tasks get the next free Taskmaster IDs, and
+        // unlinked tasks.json tasks are imported
+        let mut taskmaster_ids: HashMap<String, String> = links
+            .iter()
+            .filter(|link| orkee_by_id.contains_key(link.task_id.as_str()))
+            .map(|link| (link.task_id.clone(), link.taskmaster_id.clone()))
+            .collect();
+        let mut next_id = links
+            .iter()
+            .filter_map(|link| link.taskmaster_id.parse::<u64>().ok())
+            .chain([file.max_id()])
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let mut exports = Vec::new();
+        for task in &orkee_tasks {
+            if !taskmaster_ids.contains_key(&task.id) {
+                let taskmaster_id = next_id.to_string();
+                next_id += 1;
+                taskmaster_ids.insert(task.id.clone(), taskmaster_id.clone());
+                exports.push((taskmaster_id, task));
+            }
+        }
+        let linked: HashSet<&str> = links
+            .iter()
+            .map(|link| link.taskmaster_id.as_str())
+            .collect();
+        let imports: Vec<(String, SyncedFields)> = file
+            .tasks()
+            .filter(|(id, _)| !linked.contains(id.as_str()))
+            .map(|(id, task)| (id, from_file_task(task)))
+            .collect();
+
+        let outcomes: Vec<_> = links
+            .into_iter()
+            .map(|link| {
+                let orkee = orkee_by_id
+                    .get(link.task_id.as_str())
+                    .map(|task| from_orkee_task(task, &taskmaster_ids));
+                let taskmaster = file.get(&link.taskmaster_id).map(from_file_task);
+                let outcome = reconcile(&link.base, orkee, taskmaster, strategy);
+                (link, outcome)
+            })
+            .collect();
+
+        // Write tasks.json first: if it changed underneath us, nothing has been
+        // applied to Orkee yet and the whole sync can be retried
+        let mut file_changes = 0;
+        for (link, outcome) in &outcomes {
+            match outcome {
+                Outcome::ToFile(fields) => {
+                    if file.get(&link.taskmaster_id).is_some() {
+                        report.updated_in_file += 1;
+                    } else {
+                        report.exported += 1;
+                    }
+                    file.upsert(&link.taskmaster_id, fields);
+                    file_changes += 1;
+                }
+                Outcome::Merged(fields) => {
+                    file.upsert(&link.taskmaster_id, fields);
+                    file_changes += 1;
+                }
+                Outcome::DeleteFromFile => {
+                    file.remove(&link.taskmaster_id);
+                    report.deleted_in_file += 1;
+                    file_changes += 1;
+                }
+                _ => {}
+            }
+        }
+        let exports: Vec<(String, &Task, SyncedFields)> = exports
+            .into_iter()
+            .map(|(id, task)| {
+                let fields = from_orkee_task(task, &taskmaster_ids);
+                file.upsert(&id, &fields);
+                (id, task, fields)
+            })
+            .collect();
+        report.exported += exports.len();
+        file_changes += exports.len();
+        if file_changes > 0 {
+            file.write().await?;
+        }
+
+        // Create Orkee tasks before updating any, so dependencies on them resolve
+        let mut to_orkee: Vec<OrkeeWrite> = Vec::new();
+        for (link, outcome) in &outcomes {
+            if let Outcome::ToOrkee(fields) | Outcome::Merged(fields) = outcome {
+                to_orkee.push(OrkeeWrite {
+                    taskmaster_id: link.taskmaster_id.clone(),
+                    existing: orkee_by_id.get(link.task_id.as_str()).copied(),
+                    fields: fields.clone(),
+                    merged: matches!(outcome, Outcome::Merged(_)),
+                });
+            }
+        }
+        to_orkee.extend(
+            imports
+                .into_iter()
+                .map(|(taskmaster_id, fields)| OrkeeWrite {
+                    taskmaster_id,
+                    existing: None,
+                    fields,
+                    merged: false,
+                }),
+        );
+
+        let owner = project_owner(&self.pool, project_id).await?;
+        let mut created: HashMap<String, String> = HashMap::new();
+        for OrkeeWrite {
+            taskmaster_id,
+            existing,
+            fields,
+            ..
+        } in &to_orkee
+        {
+            if existing.is_none() {
+                let task = self
+                    .tasks
+                    .create_task(project_id, &owner, create_input(fields))
+                    .await?;
+                taskmaster_ids.insert(task.id.clone(), taskmaster_id.clone());
+                created.insert(taskmaster_id.clone(), task.id);
+                report.imported += 1;
+            }
+        }
+
+        let task_ids = reversed(&taskmaster_ids);
+        for write in &to_orkee {
+            let task_id = match (write.existing, created.get(&write.taskmaster_id)) {
+                (Some(task), _) => task.id.clone(),
+                (None, Some(task_id)) => task_id.clone(),
+                (None, None) => continue,
+            };
+            let dependencies =
+                orkee_dependencies(&write.fields, &task_ids, &taskmaster_ids, write.existing);
+            // New tasks already have every field but their dependencies
+            if write.existing.is_some() || !dependencies.is_empty() {
+                self.tasks
+                    .update_task(&task_id, update_input(&write.fields, dependencies))
+                    .await?;
+            }
+            if write.existing.is_some() && !write.merged {
+                report.updated_in_orkee += 1;
+            }
+            self.storage
+                .save_link(project_id, &write.taskmaster_id, &task_id, &write.fields)
+                .await?;
+        }
+
+        let open_conflicts: HashSet<String> = self
+            .storage
+            .list_conflicts(project_id, Some(ConflictStatus::Open))
+            .await?
+            .into_iter()
+            .map(|conflict| conflict.taskmaster_id)
+            .collect();
+        for (link, outcome) in &outcomes {
+            match outcome {
+                Outcome::Unchanged => report.unchanged += 1,
+                Outcome::Converged(fields) | Outcome::ToFile(fields) => {
+                    self.storage
+                        .save_link(project_id, &link.taskmaster_id, &link.task_id, fields)
+                        .await?;
+                }
+                Outcome::Merged(_) => report.merged += 1,
+                Outcome::ToOrkee(_) => {}
+                Outcome::DeleteFromOrkee => {
+                    self.tasks.delete_task(&link.task_id).await?;
+                    self.storage
+                        .delete_link(project_id, &link.taskmaster_id)
+                        .await?;
+                    report.deleted_in_orkee += 1;
+                }
+                Outcome::DeleteFromFile | Outcome::Forget => {
+                    self.storage
+                        .delete_link(project_id, &link.taskmaster_id)
+                        .await?;
+                }
+                Outcome::Conflict { orkee, taskmaster } => {
+                    self.storage
+                        .save_conflict(project_id, link, orkee, taskmaster)
+                        .await?;
+                    report.conflicts += 1;
+                    continue;
+                }
+            }
+            if open_conflicts.contains(&link.taskmaster_id) {
+                self.storage
+                    .resolve_open_conflict(
+                        project_id,
+                        &link.taskmaster_id,
+                        ConflictResolution::Auto,
+                    )
+                    .await?;
+            }
+        }
+        for (taskmaster_id, task, fields) in &exports {
+            self.storage
+                .save_link(project_id, taskmaster_id, &task.id, fields)
+                .await?;
+        }
+
+        self.storage
+            .save_fingerprint(project_id, file.fingerprint())
+            .await?;
+        info!(
+            "Synced project {} with tasks.json: {:?}",
+            project_id, report
+        );
+        Ok(report)
+    }
+
+    /// Settle an open conflict by writing the chosen version to both sides.
+    /// `fields` is required for, and only used by, custom resolutions.
+    pub async fn resolve_conflict(
+        &self,
+        conflict_id: &str,
+        resolution: ConflictResolution,
+        fields: Option<SyncedFields>,
+    ) -> TaskSourceResult<TaskmasterConflict> {
+        let conflict = self.storage.get_conflict(conflict_id).await?;
+        if conflict.status != ConflictStatus::Open {
+            return Err(TaskSourceError::InvalidInput(
+                "Conflict is already resolved".to_string(),
+            ));
+        }
+        let project_id = &conflict.project_id;
+        let deleted = || {
+            TaskSourceError::InvalidInput(
+                "The task was deleted after the conflict was recorded; sync again".to_string(),
+            )
+        };
+
+        let mut file = TaskmasterFile::read(&self.project_root(project_id).await?).await?;
+        let file_task = file
+            .get(&conflict.taskmaster_id)
+            .map(from_file_task)
+            .ok_or_else(deleted)?;
+        let task = match self.tasks.get_task(&conflict.task_id).await {
+            Ok(task) => task,
+            Err(StorageError::Sqlx(sqlx::Error::RowNotFound)) => return Err(deleted()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let taskmaster_ids: HashMap<String, String> = self
+            .storage
+            .list_links(project_id)
+            .await?
+            .into_iter()
+            .map(|link| (link.task_id, link.taskmaster_id))
+            .collect();
+        let winner = match resolution {
+            ConflictResolution::Orkee => from_orkee_task(&task, &taskmaster_ids),
+            ConflictResolution::Taskmaster => file_task,
+            ConflictResolution::Custom => fields.ok_or_else(|| {
+                TaskSourceError::InvalidInput("A custom resolution needs fields".to_string())
+            })?,
+            ConflictResolution::Auto => {
+                return Err(TaskSourceError::InvalidInput(
+                    "Resolve with orkee, taskmaster, or custom".to_string(),
+                ))
+            }
+        };
+
+        // Only advance the stored fingerprint if no other edits are waiting to be synced
+        let synced_fingerprint = self.storage.get_fingerprint(project_id).await?;
+        let file_was_synced = synced_fingerprint.as_ref() == Some(file.fingerprint());
+        file.upsert(&conflict.taskmaster_id, &winner);
+        file.write().await?;
+        if file_was_synced {
+            self.storage
+                .save_fingerprint(project_id, file.fingerprint())
+                .await?;
+        }
+
+        let dependencies = orkee_dependencies(
+            &winner,
+            &reversed(&taskmaster_ids),
+            &taskmaster_ids,
+            Some(&task),
+        );
+        self.tasks
+            .update_task(&task.id, update_input(&winner, dependencies))
+            .await?;
+        self.storage
+            .save_link(project_id, &conflict.taskmaster_id, &task.id, &winner)
+            .await?;
+        self.storage
+            .resolve_open_conflict(project_id, &conflict.taskmaster_id, resolution)
+            .await?;
+
+        self.storage.get_conflict(conflict_id).await
+    }
+
+    async fn project_root(&self, project_id: &str) -> TaskSourceResult<PathBuf> {
+        let root: Option<String> =
+            sqlx::query_scalar("SELECT project_root FROM projects WHERE id = ?")
+                .bind(project_id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        root.map(PathBuf::from).ok_or_else(|| {
+            TaskSourceError::InvalidInput(format!("Project {} does not exist", project_id))
+        })
+    }
+
+    /// Tasks imported from Linear or Jira, which stay out of tasks.json
+    async fn externally_imported(&self, project_id: &str) -> TaskSourceResult<HashSet<String>> {
+        let task_ids: Vec<String> = sqlx::query_scalar(
+            "SELECT l.task_id FROM task_external_links l
+             JOIN tasks t ON t.id = l.task_id
+             WHERE t.project_id = ?",
+        )
+        .bind(project_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(task_ids.into_iter().collect())
+    }
+}
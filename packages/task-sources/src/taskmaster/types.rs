@@ -0,0 +1,150 @@
+// ABOUTME: Taskmaster sync type definitions
+// ABOUTME: Synced task fields, merge strategies, sync reports, and recorded conflicts
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The task fields kept in sync between Orkee and tasks.json.
+///
+/// Values use Taskmaster's representation: statuses and priorities are their
+/// string names, missing text is empty, and dependencies are Taskmaster IDs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedFields {
+    pub title: String,
+    pub description: String,
+    pub details: String,
+    pub test_strategy: String,
+    pub status: String,
+    pub priority: String,
+    pub dependencies: Vec<String>,
+}
+
+/// An Orkee task paired with a tasks.json task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskmasterLink {
+    pub taskmaster_id: String,
+    pub task_id: String,
+    /// Both sides as of the last sync, the common ancestor for three-way merges
+    pub base: SyncedFields,
+    pub synced_at: DateTime<Utc>,
+}
+
+/// How a sync settles a task edited differently on both sides since the last sync
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Record a conflict and leave both sides untouched
+    #[default]
+    Manual,
+    /// Merge field by field; fields edited on both sides are recorded as a conflict
+    Merge,
+    /// Overwrite tasks.json with the Orkee task
+    PreferOrkee,
+    /// Overwrite the Orkee task with tasks.json
+    PreferTaskmaster,
+}
+
+/// Outcome of one sync
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncReport {
+    /// Tasks created in Orkee from tasks.json
+    pub imported: usize,
+    /// Tasks added to tasks.json from Orkee
+    pub exported: usize,
+    pub updated_in_orkee: usize,
+    pub updated_in_file: usize,
+    pub deleted_in_orkee: usize,
+    pub deleted_in_file: usize,
+    /// Tasks edited on both sides and merged automatically
+    pub merged: usize,
+    /// Tasks with an open conflict after this sync
+    pub conflicts: usize,
+    pub unchanged: usize,
+    /// Whether tasks.json was edited outside Orkee since the last sync
+    pub file_changed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictStatus {
+    Open,
+    Resolved,
+}
+
+impl ConflictStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictStatus::Open => "open",
+            ConflictStatus::Resolved => "resolved",
+        }
+    }
+}
+
+impl std::str::FromStr for ConflictStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(ConflictStatus::Open),
+            "resolved" => Ok(ConflictStatus::Resolved),
+            _ => Err(format!("Unknown conflict status: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConflictResolution {
+    /// Both sides now hold the Orkee version
+    Orkee,
+    /// Both sides now hold the tasks.json version
+    Taskmaster,
+    /// Both sides now hold fields supplied when resolving
+    Custom,
+    /// The two sides converged on their own before anyone resolved the conflict
+    Auto,
+}
+
+impl ConflictResolution {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictResolution::Orkee => "orkee",
+            ConflictResolution::Taskmaster => "taskmaster",
+            ConflictResolution::Custom => "custom",
+            ConflictResolution::Auto => "auto",
+        }
+    }
+}
+
+impl std::str::FromStr for ConflictResolution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "orkee" => Ok(ConflictResolution::Orkee),
+            "taskmaster" => Ok(ConflictResolution::Taskmaster),
+            "custom" => Ok(ConflictResolution::Custom),
+            "auto" => Ok(ConflictResolution::Auto),
+            _ => Err(format!("Unknown conflict resolution: {}", s)),
+        }
+    }
+}
+
+/// A task edited differently in Orkee and tasks.json since their last sync
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskmasterConflict {
+    pub id: String,
+    pub project_id: String,
+    pub task_id: String,
+    pub taskmaster_id: String,
+    /// Fields that differ between the two versions
+    pub fields: Vec<String>,
+    /// Both sides as of the last successful sync
+    pub base: SyncedFields,
+    pub orkee: SyncedFields,
+    pub taskmaster: SyncedFields,
+    pub status: ConflictStatus,
+    pub resolution: Option<ConflictResolution>,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
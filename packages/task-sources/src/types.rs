@@ -0,0 +1,131 @@
+// ABOUTME: Task source type definitions
+// ABOUTME: Providers, per-project source configs, normalized external issues, and import summaries
+
+use chrono::{DateTime, Utc};
+use orkee_tasks::{TaskPriority, TaskStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Issue trackers tasks can be imported from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskSourceProvider {
+    Linear,
+    Jira,
+}
+
+impl TaskSourceProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskSourceProvider::Linear => "linear",
+            TaskSourceProvider::Jira => "jira",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskSourceProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(TaskSourceProvider::Linear),
+            "jira" => Ok(TaskSourceProvider::Jira),
+            _ => Err(format!("Unknown task source provider: {}", s)),
+        }
+    }
+}
+
+/// A configured import source. The API token is never returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSourceConfig {
+    pub id: String,
+    pub project_id: String,
+    pub provider: TaskSourceProvider,
+    /// Jira site URL such as `https://acme.atlassian.net`; Linear uses its public API unless set
+    pub base_url: Option<String>,
+    /// Jira account email the API token belongs to
+    pub email: Option<String>,
+    /// Linear team key or Jira project key issues are imported from
+    pub external_project: String,
+    /// Linear label name, or a JQL clause ANDed with the Jira project
+    pub filter: Option<String>,
+    /// External status names mapped to Orkee statuses, overriding the defaults
+    pub status_mapping: HashMap<String, TaskStatus>,
+    pub enabled: bool,
+    /// Newest external update time imported so far; the next import starts here
+    pub last_synced_at: Option<DateTime<Utc>>,
+    pub last_import_at: Option<DateTime<Utc>>,
+    pub last_import_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskSourceCreateInput {
+    pub provider: TaskSourceProvider,
+    pub base_url: Option<String>,
+    pub email: Option<String>,
+    pub api_token: String,
+    pub external_project: String,
+    pub filter: Option<String>,
+    #[serde(default)]
+    pub status_mapping: HashMap<String, TaskStatus>,
+    pub enabled: Option<bool>,
+}
+
+/// Changes to a task source. Empty strings clear `base_url`, `email`, and `filter`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskSourceUpdateInput {
+    pub base_url: Option<String>,
+    pub email: Option<String>,
+    /// Replaces the stored token when present
+    pub api_token: Option<String>,
+    pub external_project: Option<String>,
+    pub filter: Option<String>,
+    pub status_mapping: Option<HashMap<String, TaskStatus>>,
+    pub enabled: Option<bool>,
+}
+
+/// An issue fetched from Linear or Jira, normalized across providers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalIssue {
+    /// Provider's stable issue ID
+    pub external_id: String,
+    /// Human-readable key such as `ENG-123`
+    pub key: String,
+    pub url: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    /// Workflow state name as shown in the tracker, e.g. "In Review"
+    pub state: String,
+    /// Provider's state category (Linear state type or Jira status category key)
+    pub state_category: String,
+    pub priority: Option<TaskPriority>,
+    pub labels: Vec<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Link between an imported task and its external issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskLink {
+    pub task_id: String,
+    pub source_id: String,
+    pub external_id: String,
+    pub external_key: String,
+    pub external_url: Option<String>,
+    pub external_updated_at: DateTime<Utc>,
+    pub imported_at: DateTime<Utc>,
+}
+
+/// Outcome of importing one source
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportSummary {
+    /// Issues returned by the tracker
+    pub fetched: usize,
+    pub created: usize,
+    pub updated: usize,
+    /// Issues already imported with no newer changes
+    pub unchanged: usize,
+    pub last_synced_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,204 @@
+// ABOUTME: Integration tests for Linear/Jira task source configuration and issue import
+// ABOUTME: Tests token storage, validation, and creating, updating, and skipping imported tasks
+
+use chrono::{DateTime, Duration, Utc};
+use orkee_task_sources::{
+    ExternalIssue, TaskImporter, TaskSourceCreateInput, TaskSourceError, TaskSourceProvider,
+    TaskSourceUpdateInput,
+};
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::{TaskPriority, TaskStatus};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+         VALUES ('proj1234', 'Imports', '/tmp/imports', datetime('now'), datetime('now'))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn linear_input() -> TaskSourceCreateInput {
+    TaskSourceCreateInput {
+        provider: TaskSourceProvider::Linear,
+        base_url: None,
+        email: None,
+        api_token: "lin_api_secret".to_string(),
+        external_project: "ENG".to_string(),
+        filter: None,
+        status_mapping: HashMap::from([("In Review".to_string(), TaskStatus::Review)]),
+        enabled: None,
+    }
+}
+
+fn issue(id: &str, state: &str, state_category: &str, updated_at: DateTime<Utc>) -> ExternalIssue {
+    ExternalIssue {
+        external_id: id.to_string(),
+        key: format!("ENG-{}", id),
+        url: Some(format!("https://linear.app/acme/issue/ENG-{}", id)),
+        title: format!("Issue {}", id),
+        description: Some("Imported from Linear".to_string()),
+        state: state.to_string(),
+        state_category: state_category.to_string(),
+        priority: Some(TaskPriority::High),
+        labels: vec!["backend".to_string()],
+        due_date: None,
+        updated_at,
+    }
+}
+
+#[tokio::test]
+async fn test_source_configuration() {
+    let pool = create_test_db().await;
+    let importer = TaskImporter::new(pool).unwrap();
+    let storage = importer.storage();
+
+    let source = storage
+        .create_source("proj1234", linear_input())
+        .await
+        .unwrap();
+    assert!(source.enabled);
+    assert_eq!(
+        storage.api_token(&source.id).await.unwrap(),
+        "lin_api_secret"
+    );
+
+    // Jira needs a site URL and account email
+    let mut jira = linear_input();
+    jira.provider = TaskSourceProvider::Jira;
+    assert!(matches!(
+        storage.create_source("proj1234", jira.clone()).await,
+        Err(TaskSourceError::InvalidInput(_))
+    ));
+    jira.base_url = Some("https://acme.atlassian.net/".to_string());
+    jira.email = Some("dev@acme.test".to_string());
+    let jira = storage.create_source("proj1234", jira).await.unwrap();
+    assert_eq!(jira.base_url.as_deref(), Some("https://acme.atlassian.net"));
+
+    let updated = storage
+        .update_source(
+            "proj1234",
+            &source.id,
+            TaskSourceUpdateInput {
+                api_token: Some("lin_api_rotated".to_string()),
+                filter: Some("bug".to_string()),
+                enabled: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.filter.as_deref(), Some("bug"));
+    assert!(!updated.enabled);
+    assert_eq!(
+        storage.api_token(&source.id).await.unwrap(),
+        "lin_api_rotated"
+    );
+    assert_eq!(storage.list_enabled_sources().await.unwrap().len(), 1);
+
+    // Sources are scoped to their project
+    assert!(matches!(
+        storage.get_source("other-project", &source.id).await,
+        Err(TaskSourceError::NotFound)
+    ));
+    storage.delete_source("proj1234", &source.id).await.unwrap();
+    assert_eq!(storage.list_sources("proj1234").await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_import_creates_updates_and_skips_tasks() {
+    let pool = create_test_db().await;
+    let importer = TaskImporter::new(pool.clone()).unwrap();
+    let tasks = TaskStorage::new(pool.clone());
+    let source = importer
+        .storage()
+        .create_source("proj1234", linear_input())
+        .await
+        .unwrap();
+
+    let first_seen = Utc::now() - Duration::hours(2);
+    let issues = vec![
+        issue("1", "Todo", "unstarted", first_seen),
+        issue(
+            "2",
+            "In Review",
+            "started",
+            first_seen + Duration::minutes(5),
+        ),
+    ];
+    let summary = importer.apply_issues(&source, &issues).await.unwrap();
+    assert_eq!(
+        (summary.created, summary.updated, summary.unchanged),
+        (2, 0, 0)
+    );
+    assert_eq!(
+        summary.last_synced_at,
+        Some(first_seen + Duration::minutes(5))
+    );
+
+    let links = importer.storage().list_links(&source.id).await.unwrap();
+    assert_eq!(links.len(), 2);
+    let reviewed = tasks.get_task(&links[1].task_id).await.unwrap();
+    assert!(matches!(reviewed.status, TaskStatus::Review));
+    assert!(matches!(reviewed.priority, TaskPriority::High));
+    assert_eq!(reviewed.tags, Some(vec!["backend".to_string()]));
+    assert_eq!(reviewed.metadata.unwrap()["external"]["key"], "ENG-2");
+
+    // Re-importing unchanged issues leaves tasks alone
+    let summary = importer.apply_issues(&source, &issues).await.unwrap();
+    assert_eq!(
+        (summary.created, summary.updated, summary.unchanged),
+        (0, 0, 2)
+    );
+
+    // A newer upstream change overwrites tracker-owned fields
+    let mut done = issue("1", "Done", "completed", Utc::now());
+    done.title = "Issue 1 (renamed)".to_string();
+    let summary = importer.apply_issues(&source, &[done]).await.unwrap();
+    assert_eq!((summary.created, summary.updated), (0, 1));
+
+    let task = tasks.get_task(&links[0].task_id).await.unwrap();
+    assert_eq!(task.title, "Issue 1 (renamed)");
+    assert!(matches!(task.status, TaskStatus::Done));
+    assert_eq!(tasks.list_tasks("proj1234").await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_failed_import_is_recorded_on_source() {
+    let pool = create_test_db().await;
+    let importer = TaskImporter::new(pool).unwrap();
+    let mut input = linear_input();
+    // Nothing listens here, so the request fails without leaving the machine
+    input.base_url = Some("http://127.0.0.1:9/graphql".to_string());
+    let source = importer
+        .storage()
+        .create_source("proj1234", input)
+        .await
+        .unwrap();
+
+    let result = importer.import_source("proj1234", &source.id, false).await;
+    assert!(matches!(result, Err(TaskSourceError::Provider { .. })));
+
+    let source = importer
+        .storage()
+        .get_source("proj1234", &source.id)
+        .await
+        .unwrap();
+    assert!(source.last_import_at.is_some());
+    assert!(source
+        .last_import_error
+        .unwrap()
+        .contains("Linear API error"));
+    assert!(source.last_synced_at.is_none());
+}
@@ -0,0 +1,401 @@
+// ABOUTME: Integration tests for two-way sync between Orkee tasks and .taskmaster/tasks/tasks.json
+// ABOUTME: Tests import/export, one-sided edits, deletions, merge strategies, conflicts, and concurrent edits
+
+use orkee_task_sources::taskmaster::{
+    ConflictResolution, ConflictStatus, SyncedFields, TaskmasterFile, TASKS_FILE,
+};
+use orkee_task_sources::{MergeStrategy, TaskSourceError, TaskmasterSync};
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::{Task, TaskCreateInput, TaskStatus, TaskUpdateInput};
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+use std::path::Path;
+use tempfile::TempDir;
+
+async fn create_test_db(project_root: &Path) -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+         VALUES ('proj1234', 'Sync', ?, datetime('now'), datetime('now'))",
+    )
+    .bind(project_root.to_string_lossy().to_string())
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn write_tasks_file(root: &Path, tasks: Value) {
+    let path = root.join(TASKS_FILE);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let document = json!({ "tasks": tasks });
+    std::fs::write(path, serde_json::to_vec_pretty(&document).unwrap()).unwrap();
+}
+
+fn read_tasks_file(root: &Path) -> Vec<Value> {
+    let content = std::fs::read(root.join(TASKS_FILE)).unwrap();
+    let document: Value = serde_json::from_slice(&content).unwrap();
+    document["tasks"].as_array().unwrap().clone()
+}
+
+fn edit_file_task(root: &Path, id: u64, key: &str, value: Value) {
+    let mut tasks = read_tasks_file(root);
+    let task = tasks.iter_mut().find(|task| task["id"] == id).unwrap();
+    task[key] = value;
+    write_tasks_file(root, Value::Array(tasks));
+}
+
+fn file_task(root: &Path, id: u64) -> Option<Value> {
+    read_tasks_file(root)
+        .into_iter()
+        .find(|task| task["id"] == id)
+}
+
+fn create_input(title: &str) -> TaskCreateInput {
+    TaskCreateInput {
+        title: title.to_string(),
+        description: None,
+        status: None,
+        priority: None,
+        assigned_agent_id: None,
+        parent_id: None,
+        position: None,
+        dependencies: None,
+        due_date: None,
+        estimated_hours: None,
+        complexity_score: None,
+        details: None,
+        test_strategy: None,
+        acceptance_criteria: None,
+        prompt: None,
+        context: None,
+        tag_id: None,
+        tags: None,
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+    }
+}
+
+fn update_input(title: Option<&str>, status: Option<TaskStatus>) -> TaskUpdateInput {
+    TaskUpdateInput {
+        title: title.map(str::to_string),
+        description: None,
+        status,
+        priority: None,
+        assigned_agent_id: None,
+        position: None,
+        dependencies: None,
+        due_date: None,
+        estimated_hours: None,
+        actual_hours: None,
+        complexity_score: None,
+        details: None,
+        test_strategy: None,
+        acceptance_criteria: None,
+        tags: None,
+        category: None,
+        epic_id: None,
+        parallel_group: None,
+        depends_on: None,
+        conflicts_with: None,
+        task_type: None,
+        size_estimate: None,
+        technical_details: None,
+        effort_hours: None,
+        can_parallel: None,
+        expected_version: None,
+    }
+}
+
+async fn task_titled(tasks: &TaskStorage, title: &str) -> Task {
+    tasks
+        .list_tasks("proj1234")
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|task| task.title == title)
+        .unwrap()
+}
+
+/// A project whose tasks.json has "Set up CI" (1) and "Deploy" (2, depending on 1),
+/// synced once with Orkee
+async fn synced_project() -> (TempDir, TaskmasterSync, TaskStorage) {
+    let dir = TempDir::new().unwrap();
+    write_tasks_file(
+        dir.path(),
+        json!([
+            { "id": 1, "title": "Set up CI", "status": "pending", "priority": "high",
+              "dependencies": [], "subtasks": [], "complexity": 4 },
+            { "id": 2, "title": "Deploy", "status": "pending", "priority": "medium",
+              "dependencies": [1], "subtasks": [] }
+        ]),
+    );
+    let pool = create_test_db(dir.path()).await;
+    let sync = TaskmasterSync::new(pool.clone());
+    let tasks = TaskStorage::new(pool);
+
+    sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    (dir, sync, tasks)
+}
+
+#[tokio::test]
+async fn test_first_sync_imports_and_exports() {
+    let dir = TempDir::new().unwrap();
+    write_tasks_file(
+        dir.path(),
+        json!([
+            { "id": 1, "title": "Set up CI", "status": "in-progress", "priority": "high",
+              "dependencies": [], "subtasks": [] },
+            { "id": 2, "title": "Deploy", "status": "pending", "priority": "medium",
+              "dependencies": [1], "subtasks": [] }
+        ]),
+    );
+    let pool = create_test_db(dir.path()).await;
+    let sync = TaskmasterSync::new(pool.clone());
+    let tasks = TaskStorage::new(pool);
+    tasks
+        .create_task("proj1234", "default-user", create_input("Write docs"))
+        .await
+        .unwrap();
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.imported, 2);
+    assert_eq!(report.exported, 1);
+    assert!(!report.file_changed);
+
+    let ci = task_titled(&tasks, "Set up CI").await;
+    assert!(matches!(ci.status, TaskStatus::InProgress));
+    let deploy = task_titled(&tasks, "Deploy").await;
+    assert_eq!(deploy.dependencies, Some(vec![ci.id.clone()]));
+
+    // The Orkee task takes the next free Taskmaster ID
+    let docs = file_task(dir.path(), 3).unwrap();
+    assert_eq!(docs["title"], "Write docs");
+    assert_eq!(docs["status"], "pending");
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.unchanged, 3);
+    assert_eq!(report.imported + report.exported, 0);
+}
+
+#[tokio::test]
+async fn test_one_sided_edits_propagate() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    edit_file_task(dir.path(), 1, "title", json!("Set up GitHub Actions"));
+    let deploy = task_titled(&tasks, "Deploy").await;
+    tasks
+        .update_task(&deploy.id, update_input(None, Some(TaskStatus::Done)))
+        .await
+        .unwrap();
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert!(report.file_changed);
+    assert_eq!(report.updated_in_orkee, 1);
+    assert_eq!(report.updated_in_file, 1);
+    assert_eq!(report.conflicts, 0);
+
+    task_titled(&tasks, "Set up GitHub Actions").await;
+    let deploy_in_file = file_task(dir.path(), 2).unwrap();
+    assert_eq!(deploy_in_file["status"], "done");
+    // Keys Orkee doesn't sync are left alone
+    assert_eq!(file_task(dir.path(), 1).unwrap()["complexity"], 4);
+}
+
+#[tokio::test]
+async fn test_deletions_propagate() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    let mut remaining = read_tasks_file(dir.path());
+    remaining.retain(|task| task["id"] != 2);
+    write_tasks_file(dir.path(), Value::Array(remaining));
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.deleted_in_orkee, 1);
+    assert_eq!(tasks.list_tasks("proj1234").await.unwrap().len(), 1);
+
+    let ci = task_titled(&tasks, "Set up CI").await;
+    tasks.delete_task(&ci.id).await.unwrap();
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.deleted_in_file, 1);
+    assert!(read_tasks_file(dir.path()).is_empty());
+}
+
+#[tokio::test]
+async fn test_edit_wins_over_delete() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    edit_file_task(dir.path(), 1, "status", json!("in-progress"));
+    let ci = task_titled(&tasks, "Set up CI").await;
+    tasks.delete_task(&ci.id).await.unwrap();
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.imported, 1);
+    assert!(matches!(
+        task_titled(&tasks, "Set up CI").await.status,
+        TaskStatus::InProgress
+    ));
+}
+
+#[tokio::test]
+async fn test_merge_strategy_combines_edits() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    edit_file_task(dir.path(), 1, "title", json!("Set up GitHub Actions"));
+    let ci = task_titled(&tasks, "Set up CI").await;
+    tasks
+        .update_task(&ci.id, update_input(None, Some(TaskStatus::InProgress)))
+        .await
+        .unwrap();
+
+    let report = sync.sync("proj1234", MergeStrategy::Merge).await.unwrap();
+    assert_eq!(report.merged, 1);
+    assert_eq!(report.conflicts, 0);
+
+    let ci = tasks.get_task(&ci.id).await.unwrap();
+    assert_eq!(ci.title, "Set up GitHub Actions");
+    assert!(matches!(ci.status, TaskStatus::InProgress));
+    let ci_in_file = file_task(dir.path(), 1).unwrap();
+    assert_eq!(ci_in_file["title"], "Set up GitHub Actions");
+    assert_eq!(ci_in_file["status"], "in-progress");
+}
+
+#[tokio::test]
+async fn test_conflict_recorded_and_resolved() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    edit_file_task(dir.path(), 1, "title", json!("Set up GitHub Actions"));
+    let ci = task_titled(&tasks, "Set up CI").await;
+    tasks
+        .update_task(&ci.id, update_input(Some("Set up Buildkite"), None))
+        .await
+        .unwrap();
+
+    // Overlapping edits can't be merged, so both sides are left as they are
+    let report = sync.sync("proj1234", MergeStrategy::Merge).await.unwrap();
+    assert_eq!(report.conflicts, 1);
+    assert_eq!(
+        tasks.get_task(&ci.id).await.unwrap().title,
+        "Set up Buildkite"
+    );
+    assert_eq!(
+        file_task(dir.path(), 1).unwrap()["title"],
+        "Set up GitHub Actions"
+    );
+
+    // Syncing again keeps a single open conflict
+    sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    let conflicts = sync
+        .storage()
+        .list_conflicts("proj1234", Some(ConflictStatus::Open))
+        .await
+        .unwrap();
+    assert_eq!(conflicts.len(), 1);
+    let conflict = &conflicts[0];
+    assert_eq!(conflict.fields, ["title"]);
+    assert_eq!(conflict.base.title, "Set up CI");
+    assert_eq!(conflict.orkee.title, "Set up Buildkite");
+    assert_eq!(conflict.taskmaster.title, "Set up GitHub Actions");
+
+    let resolved = sync
+        .resolve_conflict(&conflict.id, ConflictResolution::Taskmaster, None)
+        .await
+        .unwrap();
+    assert_eq!(resolved.status, ConflictStatus::Resolved);
+    assert_eq!(resolved.resolution, Some(ConflictResolution::Taskmaster));
+    assert_eq!(
+        tasks.get_task(&ci.id).await.unwrap().title,
+        "Set up GitHub Actions"
+    );
+
+    let report = sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+    assert_eq!(report.unchanged, 2);
+    assert!(matches!(
+        sync.resolve_conflict(&conflict.id, ConflictResolution::Orkee, None)
+            .await,
+        Err(TaskSourceError::InvalidInput(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_custom_resolution_writes_both_sides() {
+    let (dir, sync, tasks) = synced_project().await;
+
+    edit_file_task(dir.path(), 2, "priority", json!("low"));
+    let deploy = task_titled(&tasks, "Deploy").await;
+    tasks
+        .update_task(&deploy.id, update_input(None, Some(TaskStatus::Done)))
+        .await
+        .unwrap();
+    sync.sync("proj1234", MergeStrategy::Manual).await.unwrap();
+
+    let conflict = sync
+        .storage()
+        .list_conflicts("proj1234", None)
+        .await
+        .unwrap()
+        .remove(0);
+    let fields = SyncedFields {
+        status: "review".to_string(),
+        priority: "critical".to_string(),
+        ..conflict.orkee.clone()
+    };
+    sync.resolve_conflict(&conflict.id, ConflictResolution::Custom, Some(fields))
+        .await
+        .unwrap();
+
+    let deploy_in_file = file_task(dir.path(), 2).unwrap();
+    assert_eq!(deploy_in_file["status"], "review");
+    assert_eq!(deploy_in_file["priority"], "critical");
+    assert_eq!(deploy_in_file["dependencies"], json!([1]));
+    assert!(matches!(
+        tasks.get_task(&deploy.id).await.unwrap().status,
+        TaskStatus::Review
+    ));
+}
+
+#[tokio::test]
+async fn test_write_refuses_concurrent_edit() {
+    let dir = TempDir::new().unwrap();
+    write_tasks_file(
+        dir.path(),
+        json!([{ "id": 1, "title": "Set up CI", "dependencies": [] }]),
+    );
+
+    let mut file = TaskmasterFile::read(dir.path()).await.unwrap();
+    file.upsert(
+        "2",
+        &SyncedFields {
+            title: "Deploy".to_string(),
+            ..Default::default()
+        },
+    );
+
+    // Rewriting the same content isn't an edit
+    let unchanged = std::fs::read(dir.path().join(TASKS_FILE)).unwrap();
+    std::fs::write(dir.path().join(TASKS_FILE), &unchanged).unwrap();
+    file.write().await.unwrap();
+
+    edit_file_task(dir.path(), 1, "status", json!("done"));
+    file.remove("2");
+    assert!(matches!(
+        file.write().await,
+        Err(TaskSourceError::ConcurrentEdit(_))
+    ));
+    assert_eq!(file_task(dir.path(), 1).unwrap()["status"], "done");
+}
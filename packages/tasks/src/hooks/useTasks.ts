@@ -90,8 +90,10 @@ export function useTasks({
   }, [createTaskMutation]);
 
   const updateTask = useCallback((taskId: string, updates: Partial<Task>) => {
-    return updateTaskMutation.mutate({ taskId, updates });
-  }, [updateTaskMutation]);
+    // Send the version this view last loaded so a concurrent edit is not overwritten
+    const version = updates.version ?? tasks.find((task) => task.id === taskId)?.version;
+    return updateTaskMutation.mutate({ taskId, updates: { ...updates, version } });
+  }, [updateTaskMutation, tasks]);
 
   const deleteTask = useCallback((taskId: string) => {
     return deleteTaskMutation.mutate(taskId);
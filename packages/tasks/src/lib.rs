@@ -1,6 +1,7 @@
 // ABOUTME: Task management with agent assignment and spec integration
 // ABOUTME: Provides CRUD operations and storage for tasks and subtasks
 
+pub mod parse;
 pub mod storage;
 pub mod types;
 
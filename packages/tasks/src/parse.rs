@@ -0,0 +1,490 @@
+// ABOUTME: Deterministic parser turning a one-line task description into task fields
+// ABOUTME: Extracts priority, tags, due date, and estimate from phrases like "high priority, tag auth, due Friday"
+
+use chrono::{DateTime, Datelike, Days, NaiveDate, Utc, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::types::{TaskCreateInput, TaskPriority};
+
+/// Task fields read from free text, shaped like the create task request body
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedTask {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<TaskPriority>,
+    pub tags: Vec<String>,
+    /// Midnight UTC of the due day
+    pub due_date: Option<DateTime<Utc>>,
+    pub estimated_hours: Option<f64>,
+}
+
+impl ParsedTask {
+    /// Fill fields this parse left empty from another parse of the same text
+    pub fn fill_from(&mut self, other: ParsedTask) {
+        if self.title.trim().is_empty() {
+            self.title = other.title;
+        }
+        if self.description.is_none() {
+            self.description = other.description;
+        }
+        if self.priority.is_none() {
+            self.priority = other.priority;
+        }
+        if self.tags.is_empty() {
+            self.tags = other.tags;
+        }
+        if self.due_date.is_none() {
+            self.due_date = other.due_date;
+        }
+        if self.estimated_hours.is_none() {
+            self.estimated_hours = other.estimated_hours;
+        }
+    }
+
+    /// Read the JSON object a model replied with, ignoring surrounding prose
+    /// or code fences. Unrecognised priorities and dates are dropped.
+    pub fn from_model_reply(reply: &str) -> Result<ParsedTask, String> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Reply {
+            title: Option<String>,
+            description: Option<String>,
+            priority: Option<String>,
+            #[serde(default)]
+            tags: Vec<String>,
+            due_date: Option<String>,
+            estimated_hours: Option<f64>,
+        }
+
+        let json = match (reply.find('{'), reply.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &reply[start..=end],
+            _ => return Err("reply contained no JSON object".to_string()),
+        };
+        let reply: Reply =
+            serde_json::from_str(json).map_err(|e| format!("reply was not valid: {}", e))?;
+
+        let title = reply.title.unwrap_or_default().trim().to_string();
+        if title.is_empty() {
+            return Err("reply had no title".to_string());
+        }
+
+        let mut tags = Vec::new();
+        add_tags(
+            &mut tags,
+            reply
+                .tags
+                .iter()
+                .map(|tag| tag.trim().trim_start_matches('#').to_lowercase())
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+        );
+
+        Ok(ParsedTask {
+            title,
+            description: reply
+                .description
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+            priority: reply
+                .priority
+                .and_then(|p| priority_word(p.trim().to_lowercase().as_str())),
+            tags,
+            due_date: reply.due_date.and_then(|date| {
+                let date = date.trim();
+                DateTime::parse_from_rfc3339(date)
+                    .map(|at| at.with_timezone(&Utc))
+                    .ok()
+                    .or_else(|| {
+                        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                            .ok()
+                            .and_then(|day| day.and_hms_opt(0, 0, 0))
+                            .map(|at| at.and_utc())
+                    })
+            }),
+            estimated_hours: reply.estimated_hours.filter(|hours| *hours > 0.0),
+        })
+    }
+
+    pub fn into_create_input(self) -> TaskCreateInput {
+        TaskCreateInput {
+            title: self.title,
+            description: self.description,
+            status: None,
+            priority: self.priority,
+            assigned_agent_id: None,
+            parent_id: None,
+            position: None,
+            dependencies: None,
+            due_date: self.due_date,
+            estimated_hours: self.estimated_hours,
+            complexity_score: None,
+            details: None,
+            test_strategy: None,
+            acceptance_criteria: None,
+            prompt: None,
+            context: None,
+            tag_id: None,
+            tags: (!self.tags.is_empty()).then_some(self.tags),
+            category: None,
+            epic_id: None,
+            parallel_group: None,
+            depends_on: None,
+            conflicts_with: None,
+            task_type: None,
+            size_estimate: None,
+            technical_details: None,
+            effort_hours: None,
+            can_parallel: None,
+        }
+    }
+}
+
+/// Parse text such as `fix login redirect bug, high priority, tag auth, due Friday`.
+///
+/// Comma- or semicolon-separated clauses that name a priority, tags, a due
+/// date, or an estimate fill those fields; the first remaining clause is the
+/// title and any others become the description. `#hashtags` anywhere are
+/// tags. Relative dates are resolved against `today`.
+pub fn parse_task_text(text: &str, today: NaiveDate) -> ParsedTask {
+    let mut parsed = ParsedTask::default();
+    let mut rest: Vec<String> = Vec::new();
+
+    for clause in text.split([',', ';', '\n']) {
+        let clause = clause.trim().trim_end_matches('.');
+        if clause.is_empty() {
+            continue;
+        }
+
+        let (clause, hashtags) = take_hashtags(clause);
+        add_tags(&mut parsed.tags, hashtags);
+        if clause.is_empty() {
+            continue;
+        }
+        let lower = clause.to_lowercase();
+
+        if let Some(priority) = parse_priority(&lower) {
+            parsed.priority = Some(priority);
+        } else if let Some(tags) =
+            strip_any(&lower, &["tags ", "tag ", "tagged ", "labels ", "label "])
+        {
+            let tags = tags
+                .split(|c: char| c.is_whitespace() || c == '&' || c == '/')
+                .filter(|tag| !tag.is_empty() && *tag != "and")
+                .map(str::to_string)
+                .collect();
+            add_tags(&mut parsed.tags, tags);
+        } else if let Some(due) =
+            strip_any(&lower, &["due by ", "due ", "by ", "deadline ", "before "])
+                .and_then(|phrase| parse_date(phrase, today))
+        {
+            parsed.due_date = due.and_hms_opt(0, 0, 0).map(|at| at.and_utc());
+        } else if let Some(hours) = parse_estimate(&lower) {
+            parsed.estimated_hours = Some(hours);
+        } else {
+            rest.push(clause.to_string());
+        }
+    }
+
+    let mut rest = rest.into_iter();
+    parsed.title = rest
+        .next()
+        .map(|title| capitalize(&title))
+        .unwrap_or_default();
+    let description: Vec<String> = rest.collect();
+    if !description.is_empty() {
+        parsed.description = Some(description.join(", "));
+    }
+    parsed
+}
+
+/// Remove `#tag` words from a clause
+fn take_hashtags(clause: &str) -> (String, Vec<String>) {
+    let mut tags = Vec::new();
+    let mut words = Vec::new();
+    for word in clause.split_whitespace() {
+        match word.strip_prefix('#') {
+            Some(tag) if !tag.is_empty() => tags.push(tag.to_lowercase()),
+            _ => words.push(word),
+        }
+    }
+    (words.join(" "), tags)
+}
+
+fn add_tags(tags: &mut Vec<String>, new: Vec<String>) {
+    for tag in new {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+}
+
+fn strip_any<'a>(text: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes
+        .iter()
+        .find_map(|prefix| text.strip_prefix(prefix))
+        .map(str::trim)
+        .filter(|rest| !rest.is_empty())
+}
+
+fn priority_word(word: &str) -> Option<TaskPriority> {
+    match word {
+        "low" | "p3" => Some(TaskPriority::Low),
+        "medium" | "normal" | "p2" => Some(TaskPriority::Medium),
+        "high" | "important" | "p1" => Some(TaskPriority::High),
+        "critical" | "urgent" | "asap" | "blocker" | "p0" => Some(TaskPriority::Critical),
+        _ => None,
+    }
+}
+
+/// `high priority`, `priority high`, `priority: high`, `urgent`, `p1`
+fn parse_priority(clause: &str) -> Option<TaskPriority> {
+    let words: Vec<&str> = clause
+        .split(|c: char| c.is_whitespace() || c == ':' || c == '=')
+        .filter(|word| !word.is_empty())
+        .collect();
+    match words.as_slice() {
+        [word] => priority_word(word),
+        [word, "priority" | "prio" | "pri"] | ["priority" | "prio" | "pri", word] => {
+            priority_word(word)
+        }
+        _ => None,
+    }
+}
+
+/// `3h`, `2.5 hours`, `estimate 90 minutes`, `~1 day` (8 hours)
+fn parse_estimate(clause: &str) -> Option<f64> {
+    let phrase = strip_any(
+        clause,
+        &["estimate ", "estimated ", "est ", "takes ", "about ", "~"],
+    )
+    .unwrap_or(clause)
+    .trim_start_matches('~');
+    let split = phrase
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(phrase.len());
+    let (number, unit) = phrase.split_at(split);
+    let amount: f64 = number.parse().ok().filter(|n: &f64| *n > 0.0)?;
+    let hours = match unit.trim() {
+        "h" | "hr" | "hrs" | "hour" | "hours" => amount,
+        "m" | "min" | "mins" | "minute" | "minutes" => amount / 60.0,
+        "d" | "day" | "days" => amount * 8.0,
+        _ => return None,
+    };
+    Some((hours * 100.0).round() / 100.0)
+}
+
+/// `today`, `tomorrow`, `friday`, `next friday`, `next week`, `in 3 days`,
+/// `end of week`, `2026-10-23`, `oct 23`, `23 october`
+fn parse_date(phrase: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let phrase = phrase.trim_start_matches("on ").trim();
+    match phrase {
+        "today" | "tonight" | "eod" | "end of day" => return Some(today),
+        "tomorrow" => return today.checked_add_days(Days::new(1)),
+        "next week" => return today.checked_add_days(Days::new(7)),
+        "end of week" | "eow" | "end of the week" => {
+            return Some(next_weekday(today, Weekday::Fri, false))
+        }
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(phrase, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    if let Some(weekday) = phrase.strip_prefix("next ").and_then(weekday) {
+        return Some(next_weekday(today, weekday, true));
+    }
+    if let Some(weekday) = weekday(phrase) {
+        return Some(next_weekday(today, weekday, false));
+    }
+
+    if let Some(offset) = phrase.strip_prefix("in ") {
+        let (count, unit) = offset.split_once(' ')?;
+        let count: u64 = count.parse().ok()?;
+        let days = match unit.trim_end_matches('s') {
+            "day" => count,
+            "week" => count * 7,
+            _ => return None,
+        };
+        return today.checked_add_days(Days::new(days));
+    }
+
+    month_day(phrase, today)
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// The next `weekday` on or after `today`, or strictly after it when `skip_today`
+fn next_weekday(today: NaiveDate, weekday: Weekday, skip_today: bool) -> NaiveDate {
+    let mut ahead =
+        (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+    if ahead == 0 && skip_today {
+        ahead = 7;
+    }
+    today + Days::new(u64::from(ahead))
+}
+
+/// `oct 23`, `october 23rd`, `23 oct`: the next such date on or after today
+fn month_day(phrase: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let words: Vec<&str> = phrase.split_whitespace().collect();
+    let (month, day) = match words.as_slice() {
+        [first, second] => match (month(first), month(second)) {
+            (Some(month), None) => (month, day_number(second)?),
+            (None, Some(month)) => (month, day_number(first)?),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if this_year >= today {
+        Some(this_year)
+    } else {
+        NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+fn month(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    let word = word.trim_end_matches('.');
+    if word.len() < 3 {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|month| month.starts_with(word))
+        .map(|index| index as u32 + 1)
+}
+
+fn day_number(word: &str) -> Option<u32> {
+    word.trim_end_matches(|c: char| c.is_ascii_alphabetic())
+        .parse()
+        .ok()
+        .filter(|day| (1..=31).contains(day))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A Saturday
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, 17).unwrap()
+    }
+
+    fn due(parsed: &ParsedTask) -> Option<NaiveDate> {
+        parsed.due_date.map(|at| at.date_naive())
+    }
+
+    #[test]
+    fn test_parse_full_description() {
+        let parsed = parse_task_text(
+            "fix login redirect bug, high priority, tag auth, due Friday",
+            today(),
+        );
+        assert_eq!(parsed.title, "Fix login redirect bug");
+        assert!(matches!(parsed.priority, Some(TaskPriority::High)));
+        assert_eq!(parsed.tags, vec!["auth"]);
+        assert_eq!(due(&parsed), NaiveDate::from_ymd_opt(2026, 10, 23));
+        assert!(parsed.description.is_none());
+
+        let input = parsed.into_create_input();
+        assert_eq!(input.title, "Fix login redirect bug");
+        assert_eq!(input.tags, Some(vec!["auth".to_string()]));
+    }
+
+    #[test]
+    fn test_parse_variants() {
+        let parsed = parse_task_text(
+            "Add #billing export; urgent; tags api and csv; estimate 90 minutes; users asked for it",
+            today(),
+        );
+        assert_eq!(parsed.title, "Add export");
+        assert!(matches!(parsed.priority, Some(TaskPriority::Critical)));
+        assert_eq!(parsed.tags, vec!["billing", "api", "csv"]);
+        assert_eq!(parsed.estimated_hours, Some(1.5));
+        assert_eq!(parsed.description.as_deref(), Some("users asked for it"));
+
+        let parsed = parse_task_text("write docs, priority: low, ~1 day", today());
+        assert!(matches!(parsed.priority, Some(TaskPriority::Low)));
+        assert_eq!(parsed.estimated_hours, Some(8.0));
+    }
+
+    #[test]
+    fn test_from_model_reply() {
+        let reply = "Here you go:\n```json\n{\"title\": \"Fix login redirect\", \"priority\": \"High\", \"tags\": [\"#Auth\", \"auth\"], \"dueDate\": \"2026-10-23\", \"estimatedHours\": 0}\n```";
+        let parsed = ParsedTask::from_model_reply(reply).unwrap();
+        assert_eq!(parsed.title, "Fix login redirect");
+        assert!(matches!(parsed.priority, Some(TaskPriority::High)));
+        assert_eq!(parsed.tags, vec!["auth"]);
+        assert_eq!(due(&parsed), NaiveDate::from_ymd_opt(2026, 10, 23));
+        assert!(parsed.estimated_hours.is_none());
+
+        assert!(ParsedTask::from_model_reply("no idea").is_err());
+        assert!(ParsedTask::from_model_reply("{\"title\": \" \"}").is_err());
+    }
+
+    #[test]
+    fn test_parse_due_dates() {
+        let cases = [
+            ("due today", (2026, 10, 17)),
+            ("due tomorrow", (2026, 10, 18)),
+            ("due saturday", (2026, 10, 17)),
+            ("due next saturday", (2026, 10, 24)),
+            ("due next week", (2026, 10, 24)),
+            ("due in 3 days", (2026, 10, 20)),
+            ("due by monday", (2026, 10, 19)),
+            ("due end of week", (2026, 10, 23)),
+            ("due 2026-12-01", (2026, 12, 1)),
+            ("due Nov 3rd", (2026, 11, 3)),
+            ("due 5 jan", (2027, 1, 5)),
+        ];
+        for (text, (year, month, day)) in cases {
+            let parsed = parse_task_text(&format!("ship it, {}", text), today());
+            assert_eq!(
+                due(&parsed),
+                NaiveDate::from_ymd_opt(year, month, day),
+                "{}",
+                text
+            );
+        }
+
+        // Unrecognised dates stay in the description
+        let parsed = parse_task_text("ship it, due whenever", today());
+        assert!(parsed.due_date.is_none());
+        assert_eq!(parsed.description.as_deref(), Some("due whenever"));
+    }
+}
@@ -265,6 +265,7 @@ export class ManualTaskProvider extends BaseTaskProvider {
       category: task.category,
       metadata: task.metadata,
       tagId: task.tagId,
+      version: task.version,
     };
   }
 }
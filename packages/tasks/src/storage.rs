@@ -5,8 +5,25 @@ use chrono::Utc;
 use sqlx::{Row, SqlitePool};
 use tracing::debug;
 
-use super::types::{Task, TaskCreateInput, TaskPriority, TaskStatus, TaskUpdateInput};
-use orkee_storage::StorageError;
+use super::types::{
+    step_progress_percentage, Task, TaskActivity, TaskActivityField, TaskCreateInput, TaskPriority,
+    TaskStatus, TaskStep, TaskStepCreateInput, TaskStepStatus, TaskStepUpdateInput, TaskTestPlan,
+    TaskTestPlanInput, TaskUpdateInput,
+};
+use orkee_storage::{KeysetOrder, KeysetPage, KeysetPosition, StorageError};
+
+/// Per-task step counts selected alongside `t.*` to compute checklist progress
+const STEP_COUNT_COLUMNS: &str = r#"
+            (SELECT COUNT(*) FROM task_steps s WHERE s.task_id = t.id) AS steps_total,
+            (SELECT COUNT(*) FROM task_steps s WHERE s.task_id = t.id AND s.status = 'completed') AS steps_completed,
+            (SELECT COUNT(*) FROM task_steps s WHERE s.task_id = t.id AND s.status = 'skipped') AS steps_skipped"#;
+
+/// Text stored in `task_activity` for an enum value, matching its serialized form
+fn activity_value<T: serde::Serialize>(value: &T) -> Option<String> {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+}
 
 pub struct TaskStorage {
     pool: SqlitePool,
@@ -48,14 +65,15 @@ impl TaskStorage {
         .map_err(StorageError::Sqlx)?;
 
         // Build query with optional pagination
-        let mut query_str = String::from(
+        let mut query_str = format!(
             r#"
-            SELECT t.*
+            SELECT t.*, {}
             FROM tasks t
             WHERE t.project_id = ?
             AND t.parent_id IS NULL
             ORDER BY t.position, t.created_at
             "#,
+            STEP_COUNT_COLUMNS
         );
 
         if let Some(lim) = limit {
@@ -84,16 +102,86 @@ impl TaskStorage {
         Ok((tasks, count))
     }
 
+    /// List top-level tasks by creation time, starting after `after`
+    ///
+    /// Keyset pagination on (created_at, id): unlike offset pagination, deep
+    /// pages cost the same as the first and rows aren't skipped or repeated
+    /// when tasks are added between requests.
+    pub async fn list_tasks_after(
+        &self,
+        project_id: &str,
+        after: Option<&KeysetPosition>,
+        limit: i64,
+    ) -> Result<KeysetPage<Task>, StorageError> {
+        debug!(
+            "Fetching tasks for project: {} (after: {:?}, limit: {})",
+            project_id, after, limit
+        );
+
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*)
+            FROM tasks t
+            WHERE t.project_id = ?
+            AND t.parent_id IS NULL
+            "#,
+        )
+        .bind(project_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let order = KeysetOrder::Ascending;
+        let position_clause = match after {
+            Some(_) => format!("AND {}", order.after_clause("t.")),
+            None => String::new(),
+        };
+        let query_str = format!(
+            r#"
+            SELECT t.*, {}
+            FROM tasks t
+            WHERE t.project_id = ?
+            AND t.parent_id IS NULL
+            {}
+            ORDER BY {}
+            LIMIT ?
+            "#,
+            STEP_COUNT_COLUMNS,
+            position_clause,
+            order.order_by("t.")
+        );
+
+        let mut query = sqlx::query(&query_str).bind(project_id);
+        if let Some(position) = after {
+            query = query.bind(&position.created_at).bind(&position.id);
+        }
+        let rows = query
+            .bind(limit + 1)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        let mut tasks = Vec::with_capacity(rows.len());
+        for row in rows {
+            let mut task = self.row_to_task_sync(&row)?;
+            task.subtasks = Some(self.get_subtasks(&task.id).await?);
+            tasks.push((task, KeysetPosition::from_row(&row)?));
+        }
+
+        Ok(KeysetPage::from_rows(tasks, limit, count))
+    }
+
     pub async fn get_task(&self, task_id: &str) -> Result<Task, StorageError> {
         debug!("Fetching task: {}", task_id);
 
-        let row = sqlx::query(
+        let row = sqlx::query(&format!(
             r#"
-            SELECT t.*
+            SELECT t.*, {}
             FROM tasks t
             WHERE t.id = ?
             "#,
-        )
+            STEP_COUNT_COLUMNS
+        ))
         .bind(task_id)
         .fetch_one(&self.pool)
         .await
@@ -206,10 +294,22 @@ impl TaskStorage {
         self.get_task(&task_id).await
     }
 
+    /// Update a task without attributing the change to a user
     pub async fn update_task(
         &self,
         task_id: &str,
         input: TaskUpdateInput,
+    ) -> Result<Task, StorageError> {
+        self.update_task_as(task_id, None, input).await
+    }
+
+    /// Update a task, recording status, assignee, and priority changes in
+    /// `task_activity` attributed to `actor_id`
+    pub async fn update_task_as(
+        &self,
+        task_id: &str,
+        actor_id: Option<&str>,
+        input: TaskUpdateInput,
     ) -> Result<Task, StorageError> {
         debug!("Updating task: {}", task_id);
 
@@ -320,9 +420,19 @@ impl TaskStorage {
         }
 
         query.push_str(" WHERE id = ?");
+        if input.expected_version.is_some() {
+            query.push_str(" AND version = ?");
+        }
 
         if !has_updates {
-            return self.get_task(task_id).await;
+            let task = self.get_task(task_id).await?;
+            return match input.expected_version {
+                Some(expected) if expected != task.version => Err(StorageError::VersionConflict {
+                    expected,
+                    actual: task.version,
+                }),
+                _ => Ok(task),
+            };
         }
 
         let now = Utc::now();
@@ -406,12 +516,166 @@ impl TaskStorage {
         }
 
         q = q.bind(task_id);
+        if let Some(expected) = input.expected_version {
+            q = q.bind(expected);
+        }
+
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        let before = sqlx::query(
+            "SELECT project_id, status, priority, assigned_agent_id, version FROM tasks WHERE id = ?",
+        )
+        .bind(task_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
 
-        q.execute(&self.pool).await.map_err(StorageError::Sqlx)?;
+        let result = q.execute(&mut *tx).await.map_err(StorageError::Sqlx)?;
+        if let Some(expected) = input.expected_version {
+            if result.rows_affected() == 0 {
+                return Err(StorageError::VersionConflict {
+                    expected,
+                    actual: before.try_get("version")?,
+                });
+            }
+        }
+
+        let project_id: String = before.try_get("project_id")?;
+        let old_status: String = before.try_get("status")?;
+        let old_priority: String = before.try_get("priority")?;
+        let old_assignee: Option<String> = before.try_get("assigned_agent_id")?;
+
+        let mut changes = Vec::new();
+        if let Some(status) = input.status.as_ref().and_then(activity_value) {
+            if status != old_status {
+                changes.push((TaskActivityField::Status, Some(old_status), Some(status)));
+            }
+        }
+        if let Some(priority) = input.priority.as_ref().and_then(activity_value) {
+            if priority != old_priority {
+                changes.push((
+                    TaskActivityField::Priority,
+                    Some(old_priority),
+                    Some(priority),
+                ));
+            }
+        }
+        if let Some(assignee) = &input.assigned_agent_id {
+            if old_assignee.as_ref() != Some(assignee) {
+                changes.push((
+                    TaskActivityField::Assignee,
+                    old_assignee,
+                    Some(assignee.clone()),
+                ));
+            }
+        }
+
+        for (field, old_value, new_value) in changes {
+            sqlx::query(
+                r#"
+                INSERT INTO task_activity (
+                    id, task_id, project_id, field, old_value, new_value, actor_id, created_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(nanoid::nanoid!())
+            .bind(task_id)
+            .bind(&project_id)
+            .bind(field)
+            .bind(old_value)
+            .bind(new_value)
+            .bind(actor_id)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        }
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
 
         self.get_task(task_id).await
     }
 
+    /// Activity for a single task, newest first
+    pub async fn list_task_activity(
+        &self,
+        task_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<TaskActivity>, i64), StorageError> {
+        self.list_activity("a.task_id = ?", task_id, limit, offset)
+            .await
+    }
+
+    /// Activity across every task in a project, newest first
+    pub async fn list_project_activity(
+        &self,
+        project_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<TaskActivity>, i64), StorageError> {
+        self.list_activity("a.project_id = ?", project_id, limit, offset)
+            .await
+    }
+
+    async fn list_activity(
+        &self,
+        filter: &str,
+        id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<TaskActivity>, i64), StorageError> {
+        debug!("Fetching task activity where {} ({})", filter, id);
+
+        let total: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM task_activity a WHERE {}",
+            filter
+        ))
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT a.*, t.title AS task_title, u.name AS actor_name
+            FROM task_activity a
+            JOIN tasks t ON t.id = a.task_id
+            LEFT JOIN users u ON u.id = a.actor_id
+            WHERE {}
+            ORDER BY a.created_at DESC, a.rowid DESC
+            LIMIT ? OFFSET ?
+            "#,
+            filter
+        ))
+        .bind(id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        let activity = rows
+            .iter()
+            .map(|row| {
+                Ok(TaskActivity {
+                    id: row.try_get("id")?,
+                    task_id: row.try_get("task_id")?,
+                    task_title: row.try_get("task_title")?,
+                    project_id: row.try_get("project_id")?,
+                    field: row.try_get("field")?,
+                    old_value: row.try_get("old_value")?,
+                    new_value: row.try_get("new_value")?,
+                    actor_id: row.try_get("actor_id")?,
+                    actor_name: row.try_get("actor_name")?,
+                    created_at: row.try_get("created_at")?,
+                })
+            })
+            .collect::<Result<Vec<_>, StorageError>>()?;
+
+        Ok((activity, total))
+    }
+
     pub async fn delete_task(&self, task_id: &str) -> Result<(), StorageError> {
         debug!("Deleting task: {}", task_id);
 
@@ -427,14 +691,15 @@ impl TaskStorage {
     pub async fn get_subtasks(&self, parent_id: &str) -> Result<Vec<Task>, StorageError> {
         debug!("Fetching subtasks for parent: {}", parent_id);
 
-        let rows = sqlx::query(
+        let rows = sqlx::query(&format!(
             r#"
-            SELECT t.*
+            SELECT t.*, {}
             FROM tasks t
             WHERE t.parent_id = ?
             ORDER BY t.position, t.created_at
             "#,
-        )
+            STEP_COUNT_COLUMNS
+        ))
         .bind(parent_id)
         .fetch_all(&self.pool)
         .await
@@ -453,6 +718,9 @@ impl TaskStorage {
     fn row_to_task_sync(&self, row: &sqlx::sqlite::SqliteRow) -> Result<Task, StorageError> {
         let task_id: String = row.try_get("id")?;
         let subtasks = None; // Will be populated by the caller if needed
+        let steps_total: i64 = row.try_get("steps_total")?;
+        let steps_completed: i64 = row.try_get("steps_completed")?;
+        let steps_skipped: i64 = row.try_get("steps_skipped")?;
 
         Ok(Task {
             id: task_id,
@@ -537,6 +805,610 @@ impl TaskStorage {
             technical_details: row.try_get("technical_details")?,
             effort_hours: row.try_get("effort_hours")?,
             can_parallel: row.try_get("can_parallel")?,
+
+            steps_total,
+            steps_completed,
+            progress_percentage: step_progress_percentage(
+                steps_total,
+                steps_completed,
+                steps_skipped,
+            ),
+
+            checks_status: row.try_get("checks_status")?,
+            checks_url: row.try_get("checks_url")?,
+            checks_updated_at: row.try_get("checks_updated_at")?,
+            version: row.try_get("version")?,
         })
     }
+
+    pub async fn list_task_steps(&self, task_id: &str) -> Result<Vec<TaskStep>, StorageError> {
+        debug!("Fetching steps for task: {}", task_id);
+
+        let rows = sqlx::query(
+            r#"
+            SELECT *
+            FROM task_steps
+            WHERE task_id = ?
+            ORDER BY step_number
+            "#,
+        )
+        .bind(task_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(Self::row_to_task_step).collect()
+    }
+
+    /// Replace a task's checklist with freshly generated steps, numbered from 1
+    pub async fn replace_task_steps(
+        &self,
+        task_id: &str,
+        steps: Vec<TaskStepCreateInput>,
+    ) -> Result<Vec<TaskStep>, StorageError> {
+        debug!("Replacing {} steps for task: {}", steps.len(), task_id);
+
+        let now = Utc::now();
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        sqlx::query("DELETE FROM task_steps WHERE task_id = ?")
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        for (index, step) in steps.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO task_steps (
+                    id, task_id, step_number, action, test_command, expected_output,
+                    estimated_minutes, status, created_at, updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, 'pending', ?, ?)
+                "#,
+            )
+            .bind(nanoid::nanoid!())
+            .bind(task_id)
+            .bind(index as i32 + 1)
+            .bind(&step.action)
+            .bind(&step.test_command)
+            .bind(&step.expected_output)
+            .bind(step.estimated_minutes)
+            .bind(now)
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        }
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        self.list_task_steps(task_id).await
+    }
+
+    /// Update a step's status and notes, maintaining its start/completion timestamps
+    pub async fn update_task_step(
+        &self,
+        task_id: &str,
+        step_id: &str,
+        input: TaskStepUpdateInput,
+    ) -> Result<TaskStep, StorageError> {
+        debug!("Updating step {} for task: {}", step_id, task_id);
+
+        let row = sqlx::query("SELECT * FROM task_steps WHERE id = ? AND task_id = ?")
+            .bind(step_id)
+            .bind(task_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        let mut step = Self::row_to_task_step(&row)?;
+
+        let now = Utc::now();
+        if let Some(status) = input.status {
+            if status != step.status {
+                match status {
+                    TaskStepStatus::Pending => {
+                        step.started_at = None;
+                        step.completed_at = None;
+                    }
+                    TaskStepStatus::InProgress => {
+                        step.started_at.get_or_insert(now);
+                        step.completed_at = None;
+                    }
+                    TaskStepStatus::Completed => {
+                        step.started_at.get_or_insert(now);
+                        step.completed_at = Some(now);
+                    }
+                    TaskStepStatus::Skipped => {
+                        step.completed_at = None;
+                    }
+                }
+                step.status = status;
+            }
+        }
+        if let Some(notes) = input.notes {
+            step.notes = Some(notes).filter(|n| !n.trim().is_empty());
+        }
+
+        let mut tx = self.pool.begin().await.map_err(StorageError::Sqlx)?;
+
+        sqlx::query(
+            r#"
+            UPDATE task_steps
+            SET status = ?, notes = ?, started_at = ?, completed_at = ?, updated_at = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(&step.status)
+        .bind(&step.notes)
+        .bind(step.started_at)
+        .bind(step.completed_at)
+        .bind(now)
+        .bind(step_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        // Progress is derived from steps, so the task itself changed too
+        sqlx::query("UPDATE tasks SET updated_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        tx.commit().await.map_err(StorageError::Sqlx)?;
+
+        step.updated_at = now;
+        Ok(step)
+    }
+
+    fn row_to_task_step(row: &sqlx::sqlite::SqliteRow) -> Result<TaskStep, StorageError> {
+        Ok(TaskStep {
+            id: row.try_get("id")?,
+            task_id: row.try_get("task_id")?,
+            step_number: row.try_get("step_number")?,
+            action: row.try_get("action")?,
+            test_command: row.try_get("test_command")?,
+            expected_output: row.try_get("expected_output")?,
+            estimated_minutes: row.try_get("estimated_minutes")?,
+            status: row.try_get("status")?,
+            notes: row.try_get("notes")?,
+            started_at: row.try_get("started_at")?,
+            completed_at: row.try_get("completed_at")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+
+    /// Get a task's test plan, if one was generated or saved
+    pub async fn get_task_test_plan(
+        &self,
+        task_id: &str,
+    ) -> Result<Option<TaskTestPlan>, StorageError> {
+        debug!("Fetching test plan for task: {}", task_id);
+
+        let row = sqlx::query("SELECT * FROM task_test_plans WHERE task_id = ?")
+            .bind(task_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        row.as_ref().map(Self::row_to_task_test_plan).transpose()
+    }
+
+    /// Create or replace a task's test plan
+    pub async fn save_task_test_plan(
+        &self,
+        task_id: &str,
+        input: TaskTestPlanInput,
+    ) -> Result<TaskTestPlan, StorageError> {
+        debug!("Saving test plan for task: {}", task_id);
+
+        let now = Utc::now();
+        sqlx::query(
+            r#"
+            INSERT INTO task_test_plans (
+                task_id, unit_cases, integration_cases, edge_conditions, created_at, updated_at
+            ) VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT(task_id) DO UPDATE SET
+                unit_cases = excluded.unit_cases,
+                integration_cases = excluded.integration_cases,
+                edge_conditions = excluded.edge_conditions,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(task_id)
+        .bind(serde_json::to_string(&input.unit_cases)?)
+        .bind(serde_json::to_string(&input.integration_cases)?)
+        .bind(serde_json::to_string(&input.edge_conditions)?)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        self.get_task_test_plan(task_id)
+            .await?
+            .ok_or(StorageError::Sqlx(sqlx::Error::RowNotFound))
+    }
+
+    fn row_to_task_test_plan(row: &sqlx::sqlite::SqliteRow) -> Result<TaskTestPlan, StorageError> {
+        let unit_cases: String = row.try_get("unit_cases")?;
+        let integration_cases: String = row.try_get("integration_cases")?;
+        let edge_conditions: String = row.try_get("edge_conditions")?;
+
+        Ok(TaskTestPlan {
+            task_id: row.try_get("task_id")?,
+            unit_cases: serde_json::from_str(&unit_cases)?,
+            integration_cases: serde_json::from_str(&integration_cases)?,
+            edge_conditions: serde_json::from_str(&edge_conditions)?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TestCase;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn setup() -> (TaskStorage, String) {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!("../storage/migrations")
+            .run(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+             VALUES ('proj1234', 'Steps', '/tmp/steps', datetime('now'), datetime('now'))",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let storage = TaskStorage::new(pool);
+        let task = storage
+            .create_task(
+                "proj1234",
+                "default-user",
+                TaskCreateInput {
+                    title: "Checklist".to_string(),
+                    description: None,
+                    status: None,
+                    priority: None,
+                    assigned_agent_id: None,
+                    parent_id: None,
+                    position: None,
+                    dependencies: None,
+                    due_date: None,
+                    estimated_hours: None,
+                    complexity_score: None,
+                    details: None,
+                    test_strategy: None,
+                    acceptance_criteria: None,
+                    prompt: None,
+                    context: None,
+                    tag_id: None,
+                    tags: None,
+                    category: None,
+                    epic_id: None,
+                    parallel_group: None,
+                    depends_on: None,
+                    conflicts_with: None,
+                    task_type: None,
+                    size_estimate: None,
+                    technical_details: None,
+                    effort_hours: None,
+                    can_parallel: None,
+                },
+            )
+            .await
+            .unwrap();
+        (storage, task.id)
+    }
+
+    fn step(action: &str) -> TaskStepCreateInput {
+        TaskStepCreateInput {
+            action: action.to_string(),
+            test_command: None,
+            expected_output: None,
+            estimated_minutes: Some(5),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_step_updates_drive_task_progress() {
+        let (storage, task_id) = setup().await;
+        assert_eq!(
+            storage
+                .get_task(&task_id)
+                .await
+                .unwrap()
+                .progress_percentage,
+            None
+        );
+
+        let steps = storage
+            .replace_task_steps(
+                &task_id,
+                vec![step("Write test"), step("Implement"), step("Docs")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            steps.iter().map(|s| s.step_number).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            storage
+                .get_task(&task_id)
+                .await
+                .unwrap()
+                .progress_percentage,
+            Some(0)
+        );
+
+        let started = storage
+            .update_task_step(
+                &task_id,
+                &steps[0].id,
+                TaskStepUpdateInput {
+                    status: Some(TaskStepStatus::InProgress),
+                    notes: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(started.started_at.is_some());
+        assert!(started.completed_at.is_none());
+
+        let done = storage
+            .update_task_step(
+                &task_id,
+                &steps[0].id,
+                TaskStepUpdateInput {
+                    status: Some(TaskStepStatus::Completed),
+                    notes: Some("Red then green".to_string()),
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(done.started_at, started.started_at);
+        assert!(done.completed_at.is_some());
+        assert_eq!(done.notes.as_deref(), Some("Red then green"));
+
+        storage
+            .update_task_step(
+                &task_id,
+                &steps[2].id,
+                TaskStepUpdateInput {
+                    status: Some(TaskStepStatus::Skipped),
+                    notes: None,
+                },
+            )
+            .await
+            .unwrap();
+
+        let task = storage.get_task(&task_id).await.unwrap();
+        assert_eq!((task.steps_total, task.steps_completed), (3, 1));
+        assert_eq!(task.progress_percentage, Some(50));
+
+        let listed = storage.list_tasks("proj1234").await.unwrap();
+        assert_eq!(listed[0].progress_percentage, Some(50));
+
+        let reset = storage
+            .update_task_step(
+                &task_id,
+                &steps[0].id,
+                TaskStepUpdateInput {
+                    status: Some(TaskStepStatus::Pending),
+                    notes: Some(String::new()),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(reset.started_at.is_none() && reset.completed_at.is_none());
+        assert!(reset.notes.is_none());
+    }
+
+    fn empty_update() -> TaskUpdateInput {
+        TaskUpdateInput {
+            title: None,
+            description: None,
+            status: None,
+            priority: None,
+            assigned_agent_id: None,
+            position: None,
+            dependencies: None,
+            due_date: None,
+            estimated_hours: None,
+            actual_hours: None,
+            complexity_score: None,
+            details: None,
+            test_strategy: None,
+            acceptance_criteria: None,
+            tags: None,
+            category: None,
+            epic_id: None,
+            parallel_group: None,
+            depends_on: None,
+            conflicts_with: None,
+            task_type: None,
+            size_estimate: None,
+            technical_details: None,
+            effort_hours: None,
+            can_parallel: None,
+            expected_version: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_status_changes_are_recorded_in_history() {
+        let (storage, task_id) = setup().await;
+        let update = |status| TaskUpdateInput {
+            status: Some(status),
+            ..empty_update()
+        };
+
+        storage
+            .update_task(&task_id, update(TaskStatus::InProgress))
+            .await
+            .unwrap();
+        // Re-saving the same status is not a transition
+        storage
+            .update_task(&task_id, update(TaskStatus::InProgress))
+            .await
+            .unwrap();
+        storage
+            .update_task(&task_id, update(TaskStatus::Done))
+            .await
+            .unwrap();
+
+        let history: Vec<(Option<String>, String)> = sqlx::query_as(
+            "SELECT from_status, to_status FROM task_status_history WHERE task_id = ? ORDER BY rowid",
+        )
+        .bind(&task_id)
+        .fetch_all(&storage.pool)
+        .await
+        .unwrap();
+        assert_eq!(
+            history,
+            vec![
+                (None, "pending".to_string()),
+                (Some("pending".to_string()), "in-progress".to_string()),
+                (Some("in-progress".to_string()), "done".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_field_changes_are_recorded_as_activity() {
+        let (storage, task_id) = setup().await;
+
+        let mut input = empty_update();
+        input.status = Some(TaskStatus::InProgress);
+        input.priority = Some(TaskPriority::Medium); // unchanged, not recorded
+        input.assigned_agent_id = Some("claude".to_string());
+        storage
+            .update_task_as(&task_id, Some("default-user"), input)
+            .await
+            .unwrap();
+
+        let mut input = empty_update();
+        input.priority = Some(TaskPriority::High);
+        storage.update_task(&task_id, input).await.unwrap();
+
+        let (activity, total) = storage.list_task_activity(&task_id, 10, 0).await.unwrap();
+        assert_eq!(total, 3);
+        let summary: Vec<_> = activity
+            .iter()
+            .map(|a| {
+                (
+                    a.field,
+                    a.old_value.as_deref(),
+                    a.new_value.as_deref(),
+                    a.actor_name.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    TaskActivityField::Priority,
+                    Some("medium"),
+                    Some("high"),
+                    None
+                ),
+                (
+                    TaskActivityField::Assignee,
+                    None,
+                    Some("claude"),
+                    Some("Default User")
+                ),
+                (
+                    TaskActivityField::Status,
+                    Some("pending"),
+                    Some("in-progress"),
+                    Some("Default User")
+                ),
+            ]
+        );
+        assert_eq!(activity[0].task_title, "Checklist");
+
+        let (feed, total) = storage
+            .list_project_activity("proj1234", 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(feed.len(), 2);
+        assert_eq!(feed[0].id, activity[0].id);
+    }
+
+    #[tokio::test]
+    async fn test_update_step_from_other_task_is_not_found() {
+        let (storage, task_id) = setup().await;
+        let steps = storage
+            .replace_task_steps(&task_id, vec![step("Only step")])
+            .await
+            .unwrap();
+
+        let result = storage
+            .update_task_step("othertask", &steps[0].id, TaskStepUpdateInput::default())
+            .await;
+        assert!(matches!(
+            result,
+            Err(StorageError::Sqlx(sqlx::Error::RowNotFound))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_test_plan_is_replaced_on_save() {
+        let (storage, task_id) = setup().await;
+        assert!(storage
+            .get_task_test_plan(&task_id)
+            .await
+            .unwrap()
+            .is_none());
+
+        let case = |description: &str| TestCase {
+            description: description.to_string(),
+            expected: None,
+        };
+        storage
+            .save_task_test_plan(
+                &task_id,
+                TaskTestPlanInput {
+                    unit_cases: vec![case("Parses input"), case("Rejects bad input")],
+                    integration_cases: Vec::new(),
+                    edge_conditions: vec!["Empty input".to_string()],
+                },
+            )
+            .await
+            .unwrap();
+
+        let saved = storage
+            .save_task_test_plan(
+                &task_id,
+                TaskTestPlanInput {
+                    integration_cases: vec![case("Works end to end")],
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert!(saved.unit_cases.is_empty());
+        assert_eq!(saved.integration_cases, vec![case("Works end to end")]);
+        assert!(saved.edge_conditions.is_empty());
+
+        let fetched = storage.get_task_test_plan(&task_id).await.unwrap().unwrap();
+        assert_eq!(fetched.integration_cases, saved.integration_cases);
+        assert_eq!(fetched.created_at, saved.created_at);
+    }
 }
@@ -47,6 +47,28 @@ pub enum SizeEstimate {
     XL,
 }
 
+/// Result of the latest CI run reported for a task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pending,
+    Passed,
+    Failed,
+    Cancelled,
+}
+
+impl CheckStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStatus::Pending => "pending",
+            CheckStatus::Passed => "passed",
+            CheckStatus::Failed => "failed",
+            CheckStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
@@ -123,6 +145,25 @@ pub struct Task {
     pub technical_details: Option<String>,
     pub effort_hours: Option<i32>,
     pub can_parallel: bool,
+
+    // Execution checklist progress
+    pub steps_total: i64,
+    pub steps_completed: i64,
+    /// Percentage of non-skipped steps completed, `None` when the task has no steps
+    pub progress_percentage: Option<u8>,
+
+    // CI checks reported through inbound webhooks
+    pub checks_status: Option<CheckStatus>,
+    pub checks_url: Option<String>,
+    pub checks_updated_at: Option<DateTime<Utc>>,
+
+    /// Incremented on every update; writers send it back to detect lost updates
+    #[serde(default = "default_version")]
+    pub version: i64,
+}
+
+fn default_version() -> i64 {
+    1
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -188,4 +229,131 @@ pub struct TaskUpdateInput {
     pub technical_details: Option<String>,
     pub effort_hours: Option<i32>,
     pub can_parallel: Option<bool>,
+
+    /// Version the caller last read; the update is rejected if the task has changed since
+    #[serde(skip)]
+    pub expected_version: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskStepStatus {
+    #[default]
+    Pending,
+    InProgress,
+    Completed,
+    Skipped,
+}
+
+/// A single checklist step in a task's execution plan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStep {
+    pub id: String,
+    pub task_id: String,
+    pub step_number: i32,
+    pub action: String,
+    pub test_command: Option<String>,
+    pub expected_output: Option<String>,
+    pub estimated_minutes: Option<i32>,
+    pub status: TaskStepStatus,
+    pub notes: Option<String>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskStepCreateInput {
+    pub action: String,
+    pub test_command: Option<String>,
+    pub expected_output: Option<String>,
+    pub estimated_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TaskStepUpdateInput {
+    pub status: Option<TaskStepStatus>,
+    pub notes: Option<String>,
+}
+
+/// A case the task's tests should cover
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestCase {
+    pub description: String,
+    pub expected: Option<String>,
+}
+
+/// Verification criteria an agent should meet before considering a task done
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskTestPlan {
+    pub task_id: String,
+    pub unit_cases: Vec<TestCase>,
+    pub integration_cases: Vec<TestCase>,
+    pub edge_conditions: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskTestPlanInput {
+    pub unit_cases: Vec<TestCase>,
+    pub integration_cases: Vec<TestCase>,
+    pub edge_conditions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum TaskActivityField {
+    Status,
+    Assignee,
+    Priority,
+}
+
+/// A recorded change to a task's status, assignee, or priority
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskActivity {
+    pub id: String,
+    pub task_id: String,
+    pub task_title: String,
+    pub project_id: String,
+    pub field: TaskActivityField,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    /// `None` when the change was made by the system rather than a user
+    pub actor_id: Option<String>,
+    pub actor_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Percentage of steps completed, ignoring skipped steps.
+///
+/// Returns `None` when there are no steps so callers can tell "no checklist"
+/// apart from "nothing done yet".
+pub fn step_progress_percentage(total: i64, completed: i64, skipped: i64) -> Option<u8> {
+    if total <= 0 {
+        return None;
+    }
+    let countable = total - skipped;
+    if countable <= 0 {
+        return Some(100);
+    }
+    Some(((completed.min(countable) * 100) / countable) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step_progress_percentage() {
+        assert_eq!(step_progress_percentage(0, 0, 0), None);
+        assert_eq!(step_progress_percentage(4, 0, 0), Some(0));
+        assert_eq!(step_progress_percentage(3, 1, 0), Some(33));
+        assert_eq!(step_progress_percentage(4, 3, 1), Some(100));
+        assert_eq!(step_progress_percentage(2, 0, 2), Some(100));
+    }
 }
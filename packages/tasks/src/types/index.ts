@@ -121,6 +121,8 @@ export interface Task {
   metadata?: Record<string, any>;
   createdAt: Date;
   updatedAt: Date;
+  // Incremented on every update; the server rejects updates carrying a stale one
+  version?: number;
 }
 
 export interface ExecutionStep {
@@ -1,18 +1,77 @@
-use crate::events::{AppEvent, EventHandler};
+use crate::clipboard::{preview, Clipboard, CopyMethod};
+use crate::command_prompt::{validate_args, ArgSources};
+use crate::context::ContextSource;
+use crate::events::keymap::KEYMAP_FILE;
+use crate::events::{
+    Action, ActionQueue, ActionResult, ActionSources, AppEvent, EventHandler, KeyAction, KeyStroke,
+    Keymap, MacroError, MacroSet, ProjectSaveError, ProjectSaveOrigin,
+};
+use crate::executions::{is_cancellable, is_retryable, ExecutionSource};
+use crate::frecency::{FrecencyStore, FRECENCY_FILE};
 use crate::input::InputMode;
-use crate::slash_command::SlashCommand;
-use crate::state::{AppState, CtrlCAction, EscapeAction, Screen};
+use crate::project_detail::{is_server_active, server_url, DetailSource, DetailTab, TabData};
+use crate::slash_command::{CopyTarget, MacroCommand, SlashCommand};
+use crate::state::{version_conflict_message, AppState, CtrlCAction, EscapeAction, Screen};
 use crate::ui;
+use crate::ui::widgets::Toast;
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use orkee_projects::get_all_projects;
+use orkee_notifications::{NotificationSeverity, NotificationStorage};
+use orkee_projects::orkee_dir;
+use orkee_settings::SettingsStorage;
 use ratatui::{backend::CrosstermBackend, Terminal};
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::SqlitePool;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How often the notification feed is checked for new toasts
+const NOTIFICATION_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// How often the context pane reloads servers and tasks for its project
+const CONTEXT_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the execution monitor polls while an execution is running
+const ACTIVE_EXECUTIONS_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How often the execution monitor polls when nothing is running
+const IDLE_EXECUTIONS_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Open the Orkee database for the context pane and settings
+async fn open_database(database_path: &Path) -> Result<SqlitePool, sqlx::Error> {
+    SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect(&format!("sqlite:{}", database_path.display()))
+        .await
+}
 
 /// Main TUI application struct
 pub struct App {
     pub state: AppState,
     pub should_quit: bool,
     event_sender: Option<tokio::sync::mpsc::UnboundedSender<AppEvent>>,
+    /// Performs database and server calls in the background, started by `run`
+    actions: Option<ActionQueue>,
+    /// Only notifications newer than this are shown as toasts
+    notifications_since: DateTime<Utc>,
+    last_notification_poll: Instant,
+    last_context_refresh: Instant,
+    last_executions_refresh: Instant,
+    last_cards_refresh: Instant,
+    /// Periodic polls still in the queue; a slow poll isn't queued again until it's done
+    notifications_pending: bool,
+    context_pending: bool,
+    executions_pending: bool,
+    cards_pending: bool,
+    /// `/execute` arguments waiting for fresh launch options to be validated against
+    pending_launch: Option<Vec<String>>,
+    /// A project form save is in the queue; further submits wait for its result
+    project_save_pending: bool,
+    /// Macro requested by `/macro play`, replayed once the command finishes
+    pending_replay: Option<String>,
+    /// Where copy actions put text
+    clipboard: Clipboard,
 }
 
 impl App {
@@ -21,20 +80,531 @@ impl App {
             state: AppState::new(refresh_interval),
             should_quit: false,
             event_sender: None,
+            actions: None,
+            notifications_since: Utc::now(),
+            last_notification_poll: Instant::now(),
+            last_context_refresh: Instant::now(),
+            last_executions_refresh: Instant::now(),
+            last_cards_refresh: Instant::now(),
+            notifications_pending: false,
+            context_pending: false,
+            executions_pending: false,
+            cards_pending: false,
+            pending_launch: None,
+            project_save_pending: false,
+            pending_replay: None,
+            clipboard: Clipboard::detect(),
+        }
+    }
+
+    /// Queue an action for the background worker
+    fn enqueue(&self, action: Action) {
+        if let Some(actions) = &self.actions {
+            actions.enqueue(action);
+        }
+    }
+
+    /// Check for notifications routed to the TUI since the last poll
+    fn poll_notifications(&mut self) {
+        self.last_notification_poll = Instant::now();
+        if self.notifications_pending {
+            return;
+        }
+        self.notifications_pending = true;
+        self.enqueue(Action::PollNotifications {
+            since: self.notifications_since,
+        });
+    }
+
+    /// Reload the context pane when its project changed or its data is stale.
+    /// Returns true if the screen needs to be redrawn.
+    fn refresh_context(&mut self) -> bool {
+        let Some(project) = self.state.context_project() else {
+            return self.state.conversation_context.take().is_some();
+        };
+
+        let project_changed = self
+            .state
+            .conversation_context
+            .as_ref()
+            .is_none_or(|context| context.project_id != project.id);
+        if !project_changed && self.last_context_refresh.elapsed() < CONTEXT_REFRESH_INTERVAL {
+            return false;
+        }
+        if self.context_pending {
+            return false;
+        }
+
+        let project = project.clone();
+        self.last_context_refresh = Instant::now();
+        self.context_pending = true;
+        self.enqueue(Action::LoadContext(project));
+        false
+    }
+
+    /// Fetch the project detail screen's current tab if it hasn't been loaded yet
+    fn load_project_detail(&mut self) {
+        let Some(project) = self.state.get_selected_project().cloned() else {
+            return;
+        };
+        let Some(detail) = self.state.project_detail_mut() else {
+            return;
+        };
+        if !detail.needs_load() {
+            return;
         }
+
+        detail.mark_loading();
+        let tab = detail.tab;
+        self.enqueue(Action::LoadDetailTab { project, tab });
     }
 
-    /// Load projects from local storage
-    pub async fn load_projects(&mut self) -> Result<()> {
-        match get_all_projects().await {
-            Ok(projects) => {
+    /// Run a bound action on the project detail screen. Returns true if it applied.
+    fn handle_project_detail_action(&mut self, action: KeyAction) -> bool {
+        let Some(detail) = self.state.project_detail_mut() else {
+            return false;
+        };
+
+        match action {
+            KeyAction::PreviousTab => detail.select_tab(detail.tab.previous()),
+            KeyAction::NextTab => detail.select_tab(detail.tab.next()),
+            KeyAction::MoveUp => detail.select_previous(),
+            KeyAction::MoveDown => detail.select_next(),
+            KeyAction::Refresh => detail.invalidate(),
+            KeyAction::StartServer if detail.tab == DetailTab::Servers => {
+                self.start_detail_server();
+            }
+            KeyAction::StopServer if detail.tab == DetailTab::Servers => {
+                self.stop_detail_server();
+            }
+            _ => return false,
+        }
+
+        self.load_project_detail();
+        true
+    }
+
+    /// Number keys jump straight to a project detail tab. Returns true if the key was consumed.
+    fn handle_project_detail_key(&mut self, key: KeyCode) -> bool {
+        let KeyCode::Char(c @ '1'..='9') = key else {
+            return false;
+        };
+        let (Some(detail), Some(tab)) = (self.state.project_detail_mut(), DetailTab::from_digit(c))
+        else {
+            return false;
+        };
+
+        detail.select_tab(tab);
+        self.load_project_detail();
+        true
+    }
+
+    /// Start the detail screen project's dev server
+    fn start_detail_server(&mut self) {
+        if let Some(project) = self.state.get_selected_project().cloned() {
+            self.enqueue(Action::StartServer(project));
+        }
+    }
+
+    /// Stop the dev server on the selected row of the servers tab
+    fn stop_detail_server(&mut self) {
+        let server = self
+            .state
+            .project_detail
+            .as_ref()
+            .and_then(|detail| detail.selected_server())
+            .filter(|server| is_server_active(server))
+            .cloned();
+        if let Some(server) = server {
+            self.enqueue(Action::StopServer(server));
+        }
+    }
+
+    /// Reload the execution monitor's list and the open execution's logs
+    fn refresh_executions(&mut self) {
+        self.last_executions_refresh = Instant::now();
+        self.executions_pending = true;
+        self.enqueue(Action::RefreshExecutions {
+            open_execution_id: self.state.executions.open_execution_id(),
+        });
+    }
+
+    /// Refresh the status cards of executions started from the chat that are still running
+    fn poll_execution_cards(&mut self) {
+        let execution_ids = self.state.active_execution_card_ids();
+        if execution_ids.is_empty() || self.cards_pending {
+            return;
+        }
+        self.last_cards_refresh = Instant::now();
+        self.cards_pending = true;
+        self.enqueue(Action::PollExecutionCards(execution_ids));
+    }
+
+    /// Load the tasks, agents, and templates `/execute` picks from when its prompt opens
+    fn load_prompt_options(&mut self) {
+        let execute = self
+            .state
+            .command_prompt()
+            .is_some_and(|prompt| prompt.command == SlashCommand::Execute);
+        if execute {
+            self.enqueue(Action::LoadLaunchOptions(self.state.projects.clone()));
+        }
+    }
+
+    /// Start the execution described by `/execute` arguments, once they check out
+    fn launch_execution(&mut self, args: &[String]) {
+        let sources = ArgSources {
+            projects: &self.state.projects,
+            launch: &self.state.launch_options,
+        };
+        let request = validate_args(SlashCommand::Execute, args, &sources).and_then(|args| {
+            self.state
+                .launch_options
+                .request(&args)
+                .ok_or_else(|| format!("Usage: {}", SlashCommand::Execute.usage()))
+        });
+        match request {
+            Ok(request) => self.enqueue(Action::LaunchExecution(request)),
+            Err(e) => {
+                self.state
+                    .add_system_message(format!("❌ **Command Error:** {}", e));
+            }
+        }
+    }
+
+    /// Run a bound action on the execution monitor. Returns true if it applied.
+    fn handle_executions_action(&mut self, action: KeyAction) -> bool {
+        match action {
+            KeyAction::MoveUp => self.state.executions.select_previous(),
+            KeyAction::MoveDown => self.state.executions.select_next(),
+            KeyAction::ToggleDetail => self.state.executions.toggle_detail(),
+            KeyAction::CancelExecution => self.cancel_selected_execution(),
+            KeyAction::RetryExecution => self.retry_selected_execution(),
+            _ => return false,
+        }
+
+        self.refresh_executions();
+        true
+    }
+
+    /// Cancel the selected execution if it's still running
+    fn cancel_selected_execution(&mut self) {
+        let execution_id = self
+            .state
+            .executions
+            .selected_execution()
+            .filter(|execution| is_cancellable(execution))
+            .map(|execution| execution.id.clone());
+        if let Some(execution_id) = execution_id {
+            self.enqueue(Action::CancelExecution(execution_id));
+        }
+    }
+
+    /// Retry the selected execution if it failed or was cancelled
+    fn retry_selected_execution(&mut self) {
+        let execution_id = self
+            .state
+            .executions
+            .selected_execution()
+            .filter(|execution| is_retryable(execution))
+            .map(|execution| execution.id.clone());
+        if let Some(execution_id) = execution_id {
+            self.enqueue(Action::RetryExecution(execution_id));
+        }
+    }
+
+    /// Apply the result of a background action to the app state
+    fn handle_action_result(&mut self, result: ActionResult) {
+        match result {
+            ActionResult::ProjectsLoaded(Ok(projects)) => {
                 self.state.set_projects(projects);
-                Ok(())
+                if self.state.current_screen == Screen::Projects {
+                    self.select_first_project();
+                }
             }
-            Err(e) => Err(anyhow::anyhow!("Failed to load projects: {}", e)),
+            ActionResult::ProjectsLoaded(Err(e)) => {
+                self.state
+                    .add_system_message(format!("⚠️ Failed to load projects: {}", e));
+            }
+            ActionResult::Notifications(notifications) => {
+                self.notifications_pending = false;
+                // The feed is newest first
+                if let Some(newest) = notifications.first() {
+                    self.notifications_since = newest.created_at;
+                }
+                for notification in notifications.iter().rev() {
+                    self.state.push_toast(Toast::from(notification));
+                }
+            }
+            ActionResult::ContextLoaded(context) => {
+                self.context_pending = false;
+                // Dropped if the pane moved on to another project meanwhile
+                let current = self
+                    .state
+                    .context_project()
+                    .is_some_and(|project| project.id == context.project_id);
+                if current {
+                    self.state.conversation_context = Some(context);
+                }
+            }
+            ActionResult::DetailTabLoaded { project_id, update } => {
+                if let (Some(detail), Some(update)) = (self.state.project_detail.as_mut(), update) {
+                    if detail.project_id == project_id {
+                        detail.apply(update);
+                    }
+                }
+            }
+            ActionResult::ServerStarted {
+                project_name,
+                result,
+            } => {
+                let toast = match result {
+                    Ok(url) => Toast::new(
+                        format!("Started {}", project_name),
+                        url.unwrap_or_else(|| "Dev server starting".to_string()),
+                        NotificationSeverity::Success,
+                    ),
+                    Err(e) => Toast::new(
+                        format!("Failed to start {}", project_name),
+                        e,
+                        NotificationSeverity::Error,
+                    ),
+                };
+                self.state.push_toast(toast);
+                self.reload_detail_servers();
+            }
+            ActionResult::ServerStopped { port, result } => {
+                let toast = match result {
+                    Ok(()) => Toast::new(
+                        "Server stopped".to_string(),
+                        format!("Port {}", port),
+                        NotificationSeverity::Info,
+                    ),
+                    Err(e) => Toast::new(
+                        "Failed to stop server".to_string(),
+                        e,
+                        NotificationSeverity::Error,
+                    ),
+                };
+                self.state.push_toast(toast);
+                self.reload_detail_servers();
+            }
+            ActionResult::ExecutionsLoaded(update) => {
+                self.executions_pending = false;
+                self.state.executions.apply(update);
+            }
+            ActionResult::ExecutionCancelled {
+                execution_id,
+                result,
+            } => {
+                let toast = match result {
+                    Ok(true) => Toast::new(
+                        "Execution cancelled".to_string(),
+                        execution_id,
+                        NotificationSeverity::Info,
+                    ),
+                    Ok(false) => Toast::new(
+                        "Execution already finished".to_string(),
+                        execution_id,
+                        NotificationSeverity::Warning,
+                    ),
+                    Err(e) => Toast::new(
+                        "Failed to cancel execution".to_string(),
+                        e,
+                        NotificationSeverity::Error,
+                    ),
+                };
+                self.state.push_toast(toast);
+                self.refresh_executions();
+            }
+            ActionResult::ExecutionRetried(result) => {
+                let toast = match result {
+                    Ok(retried) => {
+                        // The new attempt is the newest execution, at the top of the list
+                        self.state.executions.selected = 0;
+                        self.state.executions.runs.clear();
+                        Toast::new(
+                            format!("Retry {} started", retried.retry_attempt),
+                            retried.id,
+                            NotificationSeverity::Success,
+                        )
+                    }
+                    Err(e) => Toast::new(
+                        "Failed to retry execution".to_string(),
+                        e,
+                        NotificationSeverity::Error,
+                    ),
+                };
+                self.state.push_toast(toast);
+                self.refresh_executions();
+            }
+            ActionResult::LaunchOptionsLoaded(Ok(options)) => {
+                self.state.launch_options = options;
+                // Options of an open `/execute` prompt may have been empty until now
+                if self.state.command_prompt().is_some() {
+                    self.state.update_command_filter();
+                }
+                if let Some(args) = self.pending_launch.take() {
+                    self.launch_execution(&args);
+                }
+            }
+            ActionResult::LaunchOptionsLoaded(Err(e)) => {
+                self.state.add_system_message(format!(
+                    "⚠️ Failed to load tasks, agents, and sandbox templates: {}",
+                    e
+                ));
+                self.pending_launch = None;
+            }
+            ActionResult::ExecutionLaunched { request, result } => match result {
+                Ok(execution) => {
+                    self.state.pin_execution_card(request, *execution);
+                    self.last_cards_refresh = Instant::now();
+                }
+                Err(e) => {
+                    self.state.add_system_message(format!(
+                        "❌ Failed to start an execution of '{}': {}",
+                        request.task_title, e
+                    ));
+                }
+            },
+            ActionResult::ExecutionCardsLoaded(executions) => {
+                self.cards_pending = false;
+                self.state.update_execution_cards(executions);
+            }
+            ActionResult::ProjectSaved {
+                origin: ProjectSaveOrigin::StatusCommand,
+                result,
+                projects,
+            } => {
+                if let Some(projects) = projects {
+                    self.state.set_projects(projects);
+                }
+                match result {
+                    Ok(project) => {
+                        self.state.add_system_message(format!(
+                            "✅ **{}** is now {}.",
+                            project.name, project.status
+                        ));
+                    }
+                    Err(ProjectSaveError::Validation(report)) => {
+                        self.state
+                            .add_system_message(format!("❌ **Command Error:** {}", report));
+                    }
+                    Err(ProjectSaveError::VersionConflict { expected, actual }) => {
+                        self.state.add_system_message(
+                            version_conflict_message(expected, actual)
+                                + "\n\nRun the command again to apply it to the latest version.",
+                        );
+                    }
+                    Err(ProjectSaveError::Failed(e)) => {
+                        self.state
+                            .add_system_message(format!("❌ **Command Error:** {}", e));
+                    }
+                }
+            }
+            ActionResult::ProjectSaved {
+                origin,
+                result,
+                projects,
+            } => {
+                self.project_save_pending = false;
+                let created = origin == ProjectSaveOrigin::CreateForm;
+                if let Err(error_msg) = self.state.finish_form_submission(created, result, projects)
+                {
+                    self.state.add_system_message(error_msg);
+                }
+            }
+            ActionResult::ProjectDeleted {
+                project_id,
+                project_name,
+                result,
+                projects,
+            } => {
+                if let Err(error_msg) =
+                    self.state
+                        .finish_project_delete(&project_id, &project_name, result, projects)
+                {
+                    self.state
+                        .add_system_message(format!("❌ **Error**\n\n{}", error_msg));
+                }
+            }
+            ActionResult::MacrosLoaded(Some(value)) => match MacroSet::from_setting_value(&value) {
+                Ok(macros) => self.state.macros = macros,
+                Err(e) => {
+                    self.state
+                        .add_system_message(format!("⚠️ Ignoring saved macros: {}", e));
+                }
+            },
+            ActionResult::MacrosLoaded(None) | ActionResult::MacrosSaved(Ok(())) => {}
+            ActionResult::MacrosSaved(Err(e)) => {
+                self.state
+                    .add_system_message(format!("⚠️ Macros are kept for this session only: {}", e));
+            }
+        }
+    }
+
+    /// Fetch the servers tab again after a server was started or stopped
+    fn reload_detail_servers(&mut self) {
+        if let Some(detail) = self.state.project_detail.as_mut() {
+            detail.servers = TabData::NotLoaded;
+        }
+        self.load_project_detail();
+    }
+
+    /// Ensure the projects screen has a selection when there are projects
+    fn select_first_project(&mut self) {
+        if !self.state.projects.is_empty() && self.state.selected_project.is_none() {
+            self.state.selected_project = Some(0);
+        }
+    }
+
+    /// Save macros to settings in the background so they are available next session
+    fn save_macros(&self) {
+        self.enqueue(Action::SaveMacros(self.state.macros.to_setting_value()));
+    }
+
+    /// Stop recording and save the recorded macro
+    async fn finish_macro_recording(&mut self) {
+        match self.state.finish_macro_recording() {
+            Some(Ok(name)) => {
+                self.save_macros();
+                self.state.add_system_message(format!(
+                    "⏺️ Macro '{}' saved. Replay it with `/macro play {}` or bind it with `/macro bind {} <key>`.",
+                    name, name, name
+                ));
+            }
+            Some(Err(e)) => {
+                self.state
+                    .add_system_message(format!("⚠️ Macro not saved: {}", e));
+            }
+            None => {}
         }
     }
 
+    /// Replay a macro's keystrokes through the key handler
+    async fn replay_macro(&mut self, name: &str) -> Result<()> {
+        let Some(input_macro) = self.state.macros.get(name) else {
+            self.state
+                .add_system_message(format!("❌ {}", MacroError::NotFound(name.to_string())));
+            return Ok(());
+        };
+
+        let keys = input_macro.keys.clone();
+        for key in keys {
+            if self.should_quit {
+                break;
+            }
+            self.dispatch_key_event(key.to_event()).await?;
+        }
+
+        // Replays don't nest, so a macro can't end up replaying itself
+        if self.pending_replay.take().is_some() {
+            self.state
+                .add_system_message("⚠️ Macros can't play other macros.".to_string());
+        }
+        Ok(())
+    }
+
     pub async fn run(
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
@@ -44,12 +614,36 @@ impl App {
         // Store the event sender for quit functionality
         self.event_sender = Some(event_handler.sender().clone());
 
-        // Load projects on startup
-        if let Err(e) = self.load_projects().await {
-            self.state
-                .add_system_message(format!("Warning: Failed to load projects: {}", e));
+        // Search and mention popups rank projects by this user's access history
+        self.state.frecency = FrecencyStore::load(&orkee_dir().join(FRECENCY_FILE));
+
+        // Screen shortcuts follow the user's keymap.toml when there is one
+        match Keymap::load(&orkee_dir().join(KEYMAP_FILE)) {
+            Ok(keymap) => self.state.keymap = keymap,
+            Err(e) => {
+                self.state
+                    .add_system_message(format!("⚠️ {}. Using the default key bindings.", e));
+            }
+        }
+
+        // Notifications, context, and settings are optional; the TUI works without a database
+        let database_path = orkee_dir().join("orkee.db");
+        let mut sources = ActionSources {
+            notifications: NotificationStorage::connect(&database_path).await.ok(),
+            ..ActionSources::default()
+        };
+        if let Ok(pool) = open_database(&database_path).await {
+            sources.context = Some(ContextSource::new(pool.clone()));
+            sources.detail = Some(DetailSource::new(pool.clone()));
+            sources.executions = Some(ExecutionSource::new(pool.clone()));
+            sources.settings = Some(SettingsStorage::new(pool));
         }
 
+        // Database and server calls run in the background so slow ones never stall rendering
+        self.actions = Some(ActionQueue::spawn(sources, event_handler.sender().clone()));
+        self.enqueue(Action::LoadMacros);
+        self.enqueue(Action::LoadProjects);
+
         // Main event loop
         while !self.should_quit {
             // Render the UI
@@ -69,16 +663,39 @@ impl App {
                         }
                     }
                     AppEvent::Tick => {
-                        // Handle periodic tasks
-                        false // Tick doesn't need immediate redraw
+                        // Handle periodic tasks; only redraw when toasts change
+                        let mut changed = self.state.expire_toasts(Instant::now());
+                        if self.last_notification_poll.elapsed() >= NOTIFICATION_POLL_INTERVAL {
+                            self.poll_notifications();
+                        }
+                        if self.state.is_split_layout() {
+                            changed |= self.refresh_context();
+                        }
+                        if self.state.current_screen == Screen::Executions {
+                            let interval = if self.state.executions.has_active() {
+                                ACTIVE_EXECUTIONS_REFRESH_INTERVAL
+                            } else {
+                                IDLE_EXECUTIONS_REFRESH_INTERVAL
+                            };
+                            if !self.executions_pending
+                                && self.last_executions_refresh.elapsed() >= interval
+                            {
+                                self.refresh_executions();
+                            }
+                        }
+                        if self.last_cards_refresh.elapsed() >= ACTIVE_EXECUTIONS_REFRESH_INTERVAL {
+                            self.poll_execution_cards();
+                        }
+                        changed
                     }
                     AppEvent::Refresh => {
-                        // Handle refresh requests
-                        if let Err(e) = self.load_projects().await {
-                            self.state
-                                .add_system_message(format!("Failed to refresh projects: {}", e));
-                        }
-                        true // Redraw after refresh
+                        // Handle refresh requests in the background
+                        self.enqueue(Action::LoadProjects);
+                        false
+                    }
+                    AppEvent::Action(result) => {
+                        self.handle_action_result(result);
+                        true // Redraw with the new data
                     }
                     AppEvent::Quit => {
                         self.quit();
@@ -98,8 +715,216 @@ impl App {
         Ok(())
     }
 
-    /// Handle keyboard input
+    /// Run the action bound to a key. Returns true if it applied on the current screen.
+    fn run_key_action(&mut self, action: KeyAction) -> bool {
+        match (&self.state.current_screen, action) {
+            (_, KeyAction::Quit) => self.quit(),
+            (_, KeyAction::ToggleContextPane) => {
+                self.state.toggle_layout_mode();
+                if self.state.is_split_layout() {
+                    self.refresh_context();
+                }
+            }
+            (_, KeyAction::ShowCheatSheet) => self.state.open_cheat_sheet(),
+            (Screen::Executions, action) => return self.handle_executions_action(action),
+            (
+                Screen::ProjectDetail,
+                KeyAction::MoveUp
+                | KeyAction::MoveDown
+                | KeyAction::PreviousTab
+                | KeyAction::NextTab
+                | KeyAction::Refresh
+                | KeyAction::StartServer
+                | KeyAction::StopServer,
+            ) => return self.handle_project_detail_action(action),
+            (Screen::Projects, KeyAction::MoveUp) => {
+                self.state.select_previous_project();
+            }
+            (Screen::Projects, KeyAction::MoveDown) => {
+                self.state.select_next_project();
+            }
+            (Screen::Projects, KeyAction::NewProject) => self.state.start_project_creation(),
+            (_, KeyAction::NewProject) => {
+                let key = self
+                    .state
+                    .keymap
+                    .keys_for(&Screen::Projects, KeyAction::NewProject)
+                    .first()
+                    .map_or_else(|| "n".to_string(), KeyStroke::to_string);
+                self.state.current_screen = Screen::Projects;
+                self.state.add_system_message(format!("📁 **Switch to Projects Screen**\n\nPress '{}' again from the projects screen to create a new project.", key));
+            }
+            (_, KeyAction::EditProject) => {
+                if let Some(project) = self.state.get_selected_project() {
+                    let project_id = project.id.clone();
+                    self.state.start_project_edit(project_id);
+                } else {
+                    self.state.add_system_message("❌ **No project selected**\n\nNavigate to projects screen and select a project first.".to_string());
+                }
+            }
+            (_, KeyAction::DeleteProject) => {
+                if let Some(project) = self.state.get_selected_project() {
+                    let project_id = project.id.clone();
+                    self.state.show_delete_confirmation(project_id);
+                } else {
+                    self.state.add_system_message("❌ **No project selected**\n\nNavigate to projects screen and select a project first.".to_string());
+                }
+            }
+            (_, KeyAction::Search) => {
+                self.state.open_project_search();
+                self.state.input_mode = InputMode::ProjectSearch;
+            }
+            (Screen::Chat, KeyAction::YankMessage) if self.state.is_chat_focused() => {
+                self.copy_to_clipboard(CopyTarget::Message);
+            }
+            (_, KeyAction::CopyProjectPath) => self.copy_to_clipboard(CopyTarget::ProjectPath),
+            (Screen::ProjectDetail, KeyAction::CopyServerUrl) => {
+                self.copy_to_clipboard(CopyTarget::ServerUrl);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Text for a copy target, from whatever is in view
+    fn copy_text(&self, target: CopyTarget) -> Result<String, &'static str> {
+        match target {
+            CopyTarget::Message => self
+                .state
+                .get_selected_message()
+                .or_else(|| self.state.messages().last())
+                .map(|message| message.content.clone())
+                .ok_or("No message to copy"),
+            CopyTarget::ProjectPath => {
+                let project = if self.state.current_screen == Screen::Chat {
+                    self.state.context_project()
+                } else {
+                    self.state.get_selected_project()
+                };
+                project
+                    .map(|project| project.project_root.clone())
+                    .ok_or("No project selected")
+            }
+            CopyTarget::ServerUrl => {
+                let server = if self.state.current_screen == Screen::ProjectDetail {
+                    self.state
+                        .project_detail
+                        .as_ref()
+                        .and_then(|detail| detail.server_to_copy())
+                } else {
+                    self.state
+                        .conversation_context
+                        .as_ref()
+                        .and_then(|context| context.servers.first())
+                };
+                server.map(server_url).ok_or("No running dev server")
+            }
+        }
+    }
+
+    /// Copy a message, project path, or server URL and confirm with a toast
+    fn copy_to_clipboard(&mut self, target: CopyTarget) {
+        let result = self
+            .copy_text(target)
+            .map_err(str::to_string)
+            .and_then(|text| {
+                self.clipboard
+                    .copy(&text)
+                    .map(|method| (text, method))
+                    .map_err(|e| e.to_string())
+            });
+
+        let toast = match result {
+            Ok((text, method)) => {
+                let via = match method {
+                    CopyMethod::System(_) => "",
+                    // Nothing confirms the terminal honoured the request
+                    CopyMethod::Osc52 => " (via terminal)",
+                };
+                Toast::new(
+                    format!("Copied{}", via),
+                    preview(&text, 60),
+                    NotificationSeverity::Success,
+                )
+            }
+            Err(e) => Toast::new("Copy failed".to_string(), e, NotificationSeverity::Error),
+        };
+        self.state.push_toast(toast);
+    }
+
+    /// Handle a key while the cheat sheet is open: typing filters it, Esc or
+    /// the cheat sheet key closes it
+    fn handle_cheat_sheet_key(&mut self, key_event: KeyEvent) {
+        let closes = key_event.code == KeyCode::Esc
+            || self
+                .state
+                .keymap
+                .resolve(&self.state.current_screen, &key_event, false)
+                == Some(KeyAction::ShowCheatSheet);
+        if closes {
+            self.state.close_cheat_sheet();
+            return;
+        }
+
+        let Some(sheet) = self.state.cheat_sheet.as_mut() else {
+            return;
+        };
+        match key_event.code {
+            KeyCode::Tab => sheet.next_screen(),
+            KeyCode::BackTab => sheet.previous_screen(),
+            KeyCode::Up => sheet.scroll_up(),
+            KeyCode::Down => {
+                let entry_count = sheet.entries(&self.state.keymap).len();
+                sheet.scroll_down(entry_count);
+            }
+            KeyCode::Backspace => sheet.pop_char(),
+            KeyCode::Char(c)
+                if !key_event
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                sheet.push_char(c)
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input, recording it into or replaying it from macros
     async fn handle_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
+        if self.state.is_recording_macro() {
+            // Ctrl+R stops recording and is never part of the macro
+            if key_event.code == KeyCode::Char('r')
+                && key_event.modifiers.contains(KeyModifiers::CONTROL)
+            {
+                self.finish_macro_recording().await;
+                return Ok(());
+            }
+
+            if let Err(e) = self.state.record_macro_key(&key_event) {
+                self.state
+                    .add_system_message(format!("⚠️ Recording stopped: {}", e));
+                self.finish_macro_recording().await;
+            }
+            return self.dispatch_key_event(key_event).await;
+        }
+
+        if !self.state.is_showing_confirmation_dialog() && self.state.cheat_sheet.is_none() {
+            if let Some(input_macro) = self.state.macros.find_by_binding(&key_event) {
+                let name = input_macro.name.clone();
+                return self.replay_macro(&name).await;
+            }
+        }
+
+        self.dispatch_key_event(key_event).await?;
+        if let Some(name) = self.pending_replay.take() {
+            self.replay_macro(&name).await?;
+        }
+        Ok(())
+    }
+
+    /// Act on a single keystroke
+    async fn dispatch_key_event(&mut self, key_event: KeyEvent) -> Result<()> {
         let key = key_event.code;
         let modifiers = key_event.modifiers;
 
@@ -116,10 +941,11 @@ impl App {
             if let Some(result) = self.state.handle_dialog_key(key) {
                 match result {
                     crate::ui::widgets::DialogResult::Confirmed => {
-                        // User confirmed the action - execute it
-                        match self.state.confirm_pending_action().await {
-                            Ok(_) => {
-                                // Action completed successfully - dialog is already closed
+                        // User confirmed the action - perform it in the background
+                        match self.state.confirm_pending_action() {
+                            Ok(action) => {
+                                // The dialog is already closed; the result arrives as an event
+                                self.enqueue(action);
                             }
                             Err(error_msg) => {
                                 // Action failed - show error and close dialog
@@ -172,85 +998,59 @@ impl App {
             }
         }
 
-        // Handle Ctrl+D for immediate quit
-        if let KeyCode::Char('d') = key {
-            if modifiers.contains(KeyModifiers::CONTROL) {
-                self.quit();
+        // The cheat sheet takes every key while it's open
+        if self.state.cheat_sheet.is_some() {
+            self.handle_cheat_sheet_key(key_event);
+            return Ok(());
+        }
+
+        // The search key also closes the search it opened
+        if self.state.is_search_mode()
+            && self
+                .state
+                .keymap
+                .resolve(&self.state.current_screen, &key_event, false)
+                == Some(KeyAction::Search)
+        {
+            self.state.close_search();
+            self.state.input_mode = InputMode::Normal;
+            return Ok(());
+        }
+
+        // Esc closes the open execution's logs
+        if self.state.current_screen == Screen::Executions
+            && key == KeyCode::Esc
+            && self.state.executions.detail_open
+        {
+            self.state.executions.detail_open = false;
+            return Ok(());
+        }
+
+        // Screen shortcuts from the keymap
+        let action = self.state.keymap.resolve(
+            &self.state.current_screen,
+            &key_event,
+            self.state.is_typing(),
+        );
+        if let Some(action) = action {
+            if self.run_key_action(action) {
                 return Ok(());
             }
         }
 
+        // Number keys pick project detail tabs unless a form or search is open
+        if self.state.current_screen == Screen::ProjectDetail
+            && modifiers.is_empty()
+            && !self.state.is_typing()
+            && self.handle_project_detail_key(key)
+        {
+            return Ok(());
+        }
+
         // Handle input-related keys when in input modes or with modifiers
         match key {
             // Text input keys
             KeyCode::Char(c) => {
-                // Handle navigation shortcuts only when NOT in special modes
-                if !self.state.is_command_mode()
-                    && !self.state.is_mention_mode()
-                    && !self.state.is_form_mode()
-                    && self.state.current_screen != crate::state::Screen::Chat
-                {
-                    match (c, &self.state.current_screen) {
-                        (
-                            'd',
-                            &crate::state::Screen::Projects | &crate::state::Screen::ProjectDetail,
-                        ) => {
-                            // Delete project on projects screen
-                            if let Some(project) = self.state.get_selected_project() {
-                                // Show delete confirmation dialog
-                                let project_id = project.id.clone();
-                                self.state.show_delete_confirmation(project_id);
-                            } else {
-                                self.state.add_system_message("❌ **No project selected**\n\nNavigate to projects screen and select a project first.".to_string());
-                            }
-                            return Ok(());
-                        }
-                        ('n', &crate::state::Screen::Projects) => {
-                            // Start project creation form
-                            self.state.start_project_creation();
-                            return Ok(());
-                        }
-                        ('n', _) => {
-                            self.state.current_screen = crate::state::Screen::Projects;
-                            self.state.add_system_message("📁 **Switch to Projects Screen**\n\nPress 'n' again from the projects screen to create a new project.".to_string());
-                            return Ok(());
-                        }
-                        (
-                            'e',
-                            &crate::state::Screen::Projects | &crate::state::Screen::ProjectDetail,
-                        ) => {
-                            if let Some(project) = self.state.get_selected_project() {
-                                // Start project editing form
-                                let project_id = project.id.clone();
-                                self.state.start_project_edit(project_id);
-                            } else {
-                                self.state.add_system_message("❌ **No project selected**\n\nNavigate to projects screen and select a project first.".to_string());
-                            }
-                            return Ok(());
-                        }
-                        ('q', _) => {
-                            // Allow quit from any screen
-                            self.quit();
-                            return Ok(());
-                        }
-                        _ => {
-                            // Not a navigation shortcut on this screen, continue with normal text input
-                        }
-                    }
-                }
-
-                // Allow quit from chat screen too (but only if input is empty and not in form mode)
-                if c == 'q'
-                    && !self.state.is_command_mode()
-                    && !self.state.is_mention_mode()
-                    && !self.state.is_form_mode()
-                    && self.state.input_buffer().is_empty()
-                    && self.state.current_screen == crate::state::Screen::Chat
-                {
-                    self.quit();
-                    return Ok(());
-                }
-
                 // Handle Ctrl+J (Shift+Enter in many terminals) specially
                 if c == 'j' && modifiers.contains(KeyModifiers::CONTROL) {
                     if self.state.is_form_mode() {
@@ -276,28 +1076,6 @@ impl App {
                     return Ok(());
                 }
 
-                // Handle Shift+F (open search popup) - Project search
-                if c == 'F'
-                    && modifiers.contains(KeyModifiers::SHIFT)
-                    && matches!(
-                        self.state.current_screen,
-                        Screen::Projects | Screen::ProjectDetail
-                    )
-                {
-                    if self.state.is_search_mode() {
-                        // Close search if already open
-                        self.state.close_search();
-                        self.state.input_mode = InputMode::Normal;
-                    } else {
-                        // Open search popup
-                        self.state.open_project_search();
-                        self.state.input_mode = InputMode::ProjectSearch;
-                    }
-                    return Ok(());
-                }
-
-                // Global shortcuts are now handled above in the navigation logic
-
                 // Only process text input if input area is focused (true Codex behavior)
                 // EXCEPT in form mode where we handle input directly
                 if !self.state.is_input_focused() && !self.state.is_form_mode() {
@@ -533,7 +1311,10 @@ impl App {
                 // Exit command mode if we deleted the '/'
                 if self.state.is_command_mode() {
                     let content = self.state.input_buffer().content();
-                    if !content.starts_with('/') {
+                    if self.state.command_prompt().is_some() {
+                        // Argument prompts don't start with '/'; Esc cancels them
+                        self.state.update_command_filter();
+                    } else if !content.starts_with('/') {
                         self.state.exit_command_mode();
                     } else {
                         // Update filter as we delete characters
@@ -660,14 +1441,11 @@ impl App {
 
                     // If form widget didn't handle it, use it for field navigation
                     if !handled {
-                        self.handle_form_navigation(false).await;
+                        self.handle_form_navigation(false);
                     }
-                } else if self.state.current_screen == crate::state::Screen::Projects {
-                    // Projects screen - navigate project list
-                    self.state.select_previous_project();
                 } else if self.state.is_chat_focused() {
-                    // Chat is focused - do NOT scroll, maybe select individual messages in future
-                    // For now, do nothing when chat area has focus
+                    // Chat is focused - select messages to copy
+                    self.state.select_previous_message();
                 } else if self.state.input_mode() == &InputMode::History {
                     // Input focused and in history mode - try history navigation first
                     if !self.state.navigate_history_previous() {
@@ -708,14 +1486,11 @@ impl App {
 
                     // If form widget didn't handle it, use it for field navigation (forward)
                     if !handled {
-                        self.handle_form_navigation(true).await;
+                        self.handle_form_navigation(true);
                     }
-                } else if self.state.current_screen == crate::state::Screen::Projects {
-                    // Projects screen - navigate project list
-                    self.state.select_next_project();
                 } else if self.state.is_chat_focused() {
-                    // Chat is focused - do NOT scroll, maybe select individual messages in future
-                    // For now, do nothing when chat area has focus
+                    // Chat is focused - select messages to copy
+                    self.state.select_next_message();
                 } else if self.state.input_mode() == &InputMode::History {
                     // Input focused and in history mode
                     if !self.state.navigate_history_next() {
@@ -748,21 +1523,13 @@ impl App {
                     if self.state.form_is_review_step() {
                         // On review step, Enter should always submit
                         if self.state.form_can_submit() {
-                            match self.state.submit_form().await {
-                                Ok(_) => {
-                                    // Success - form was submitted
-                                }
-                                Err(error_msg) => {
-                                    // Show error message
-                                    self.state.add_system_message(error_msg);
-                                }
-                            }
+                            self.submit_form();
                         } else {
                             self.state.add_system_message("❌ **Form Incomplete**\n\nPlease fill in all required fields before submitting.".to_string());
                         }
                     } else {
                         // Not on review step - use normal navigation
-                        if !self.handle_form_navigation(true).await {
+                        if !self.handle_form_navigation(true) {
                             // Form validation failed or not ready for submission
                             if !self.state.form_can_submit() {
                                 self.state.add_system_message("❌ **Form Incomplete**\n\nPlease fill in all required fields before submitting.".to_string());
@@ -851,8 +1618,11 @@ impl App {
                         } else if self.state.current_screen == crate::state::Screen::ProjectDetail {
                             // Return to projects list from detail view
                             self.state.return_to_projects_list();
-                        } else if self.state.current_screen == crate::state::Screen::Projects {
-                            // Return to chat from projects list
+                        } else if matches!(
+                            self.state.current_screen,
+                            Screen::Projects | Screen::Executions
+                        ) {
+                            // Return to chat from projects list or execution monitor
                             self.state.current_screen = crate::state::Screen::Chat;
                         } else if !self.state.cancel_history_navigation() {
                             // If not canceling history, clear input buffer
@@ -868,14 +1638,14 @@ impl App {
                     // Shift+Tab - previous field in form or focus cycling
                     if self.state.is_form_mode() {
                         // Special handling for review step or normal field navigation
-                        self.handle_form_navigation(false).await;
+                        self.handle_form_navigation(false);
                     } else {
                         // Reverse cycle focus for shift+tab (not typically used but consistent)
                         self.state.cycle_focus();
                     }
                 } else if self.state.is_form_mode() {
                     // Tab in form mode - try to advance to next field or submit
-                    if !self.handle_form_navigation(true).await {
+                    if !self.handle_form_navigation(true) {
                         // Form validation failed or not ready for submission
                         if !self.state.form_can_submit() {
                             self.state.add_system_message("❌ **Form Incomplete**\n\nPlease fill in all required fields before submitting.".to_string());
@@ -886,10 +1656,16 @@ impl App {
                     if let Some(_completed_mention) = self.state.complete_selected_mention() {
                         // Mention was completed, continue typing
                     }
+                } else if self.state.command_prompt().is_some() {
+                    // Accept the current argument, like Enter
+                    if let Some((command, args)) = self.state.submit_command_prompt() {
+                        self.run_slash_command(command, args).await;
+                    }
                 } else if self.state.is_command_mode() {
                     // Complete selected command
                     if let Some(_completed_command) = self.state.complete_selected_command() {
-                        // Command was completed, cursor is positioned for arguments if needed
+                        // Command was completed; commands with arguments now prompt for them
+                        self.load_prompt_options();
                     }
                 } else if self.state.input_mode == InputMode::ProjectSearch {
                     // Cycle search modes in search popup (Text -> Status -> Priority -> Tags)
@@ -913,15 +1689,27 @@ impl App {
 
     /// Handle input submission (Enter key)
     async fn handle_input_submission(&mut self) {
-        if self.state.is_command_mode() {
+        if self.state.command_prompt().is_some() {
+            // Accept the current argument; run the command once all are in
+            if let Some((command, args)) = self.state.submit_command_prompt() {
+                self.run_slash_command(command, args).await;
+            }
+        } else if self.state.is_command_mode() {
             // In command mode, complete the selected command or execute if already complete
             let input_content = self.state.input_buffer().content().to_string();
 
-            if let Some(_completed_command) = self.state.complete_selected_command() {
-                // Command was completed, check if it needs execution
-                if !input_content.contains('<') {
-                    // No argument placeholders, execute the command
+            // Commands typed out in full with their arguments run as typed
+            let typed_in_full = SlashCommand::parse_from_input(&input_content)
+                .is_ok_and(|(command, args)| command.next_arg(&args).is_none());
+
+            if typed_in_full {
+                self.execute_slash_command().await;
+            } else if let Some(_completed_command) = self.state.complete_selected_command() {
+                // Commands with arguments prompt for them; the rest run now
+                if self.state.command_prompt().is_none() {
                     self.execute_slash_command().await;
+                } else {
+                    self.load_prompt_options();
                 }
             } else {
                 // No command selected, try to execute what we have
@@ -934,23 +1722,13 @@ impl App {
     }
 
     /// Centralized form navigation logic
-    async fn handle_form_navigation(&mut self, try_advance: bool) -> bool {
+    fn handle_form_navigation(&mut self, try_advance: bool) -> bool {
         // Special handling for review step
         if self.state.form_is_review_step() {
             if try_advance {
                 // Enter/Tab/Down on review step should submit the form
                 if self.state.form_can_submit() {
-                    match self.state.submit_form().await {
-                        Ok(_) => {
-                            // Success message already added by submit_form
-                            return true;
-                        }
-                        Err(error_msg) => {
-                            // Show error message
-                            self.state.add_system_message(error_msg);
-                            return false;
-                        }
-                    }
+                    return self.submit_form();
                 } else {
                     self.state.add_system_message("❌ **Form Incomplete**\n\nPlease fill in all required fields before submitting.".to_string());
                     return false;
@@ -989,76 +1767,45 @@ impl App {
 
         // Either not trying to advance, or on last field - try to submit if form is complete
         if self.state.form_can_submit() {
-            match self.state.submit_form().await {
-                Ok(_) => {
-                    // Success message already added by submit_form
-                    return true;
-                }
-                Err(error_msg) => {
-                    // Show error message
-                    self.state.add_system_message(error_msg);
-                    return false;
-                }
-            }
+            return self.submit_form();
         }
 
         // Form is not ready for submission
         false
     }
 
+    /// Queue the form's project save. Returns false if the form can't be saved
+    /// or a save is already in flight.
+    fn submit_form(&mut self) -> bool {
+        if self.project_save_pending {
+            self.state
+                .add_system_message("⏳ Still saving the project...".to_string());
+            return false;
+        }
+        match self.state.form_submission() {
+            Ok(action) => {
+                self.project_save_pending = true;
+                self.enqueue(action);
+                true
+            }
+            Err(error_msg) => {
+                self.state.add_system_message(error_msg);
+                false
+            }
+        }
+    }
+
     /// Execute a slash command from the input buffer
     async fn execute_slash_command(&mut self) {
         let input_content = self.state.input_buffer().content().to_string();
 
         // Parse the command from input
         match SlashCommand::parse_from_input(&input_content) {
-            Ok((command, _args)) => {
+            Ok((command, args)) => {
                 // Clear input buffer and exit command mode
                 self.state.input_buffer_mut().clear();
                 self.state.exit_command_mode();
-
-                // Execute the command
-                match command {
-                    SlashCommand::Help => {
-                        let content = "📚 **Help - Orkee TUI**\n\n**Slash Commands:**\n- `/help` - Show this help\n- `/quit` - Exit the application\n- `/clear` - Clear chat history\n- `/projects` - Open interactive projects screen\n- `/status` - Show application status\n\n**Projects Screen Navigation:**\n- `↑↓` - Navigate project list\n- `Enter` - View project details\n- `Esc` - Return to chat (or projects list from details)\n- `n` - New project • `e` - Edit • `d` - Delete\n\n**Command System:**\n- Type `/` to open command popup\n- `↑↓` - Navigate commands\n- `Tab/Enter` - Complete/execute command\n- `Esc` - Cancel command mode\n\n**Text Input:**\n- `Enter` - Submit message\n- `↑↓` - Navigate input history (when input empty)\n- `Tab` - Switch focus (chat ↔ input)\n- `q` - Quick quit (when input empty)".to_string();
-                        self.state.add_system_message(content);
-                    }
-                    SlashCommand::Quit => {
-                        self.state
-                            .add_system_message("👋 Goodbye! Exiting Orkee TUI...".to_string());
-                        self.quit();
-                    }
-                    SlashCommand::Clear => {
-                        // Clear message history but keep welcome message
-                        self.state.message_history.clear();
-                        self.state
-                            .add_system_message("🧹 Chat history cleared.".to_string());
-                    }
-                    SlashCommand::Projects => {
-                        // Switch to interactive projects screen
-                        if let Err(e) = self.load_projects().await {
-                            self.state
-                                .add_system_message(format!("⚠️ Failed to load projects: {}", e));
-                        } else {
-                            self.state.current_screen = crate::state::Screen::Projects;
-                            // Ensure we have a selection if there are projects
-                            if !self.state.projects.is_empty()
-                                && self.state.selected_project.is_none()
-                            {
-                                self.state.selected_project = Some(0);
-                            }
-                        }
-                    }
-                    SlashCommand::Status => {
-                        let content = format!("📊 **Application Status**\n\n**Projects:** {} loaded\n**Current Screen:** {:?}\n**Input Mode:** {:?}\n**Refresh Interval:** {}s\n**Command System:** ✅ Active (Phase 3)\n\n**Features:**\n- ✅ Slash commands with popup\n- ✅ Fuzzy command matching\n- ✅ Input history navigation\n- ✅ Chat message system\n\n💡 *All systems operational!*", 
-                            self.state.projects.len(),
-                            self.state.current_screen,
-                            self.state.input_mode(),
-                            self.state.refresh_interval
-                        );
-                        self.state.add_system_message(content);
-                    }
-                }
+                self.run_slash_command(command, args).await;
             }
             Err(error) => {
                 // Show error message and clear input
@@ -1072,6 +1819,169 @@ impl App {
         }
     }
 
+    /// Run a parsed slash command
+    async fn run_slash_command(&mut self, command: SlashCommand, args: Vec<String>) {
+        match command {
+            SlashCommand::Help => {
+                let content = "📚 **Help - Orkee TUI**\n\n**Slash Commands:**\n- `/help` - Show this help\n- `/quit` - Exit the application\n- `/clear` - Clear chat history\n- `/projects` - Open interactive projects screen\n- `/status` - Show application status\n- `/executions` - Monitor agent executions\n- `/execute <project> <task> <agent> <template>` - Start an agent on a task with a live status card\n- `/project-status <project> <status>` - Change a project's status\n- `/macro record <name>` - Record keystrokes until `Ctrl+R`\n- `/macro play <name>` • `/macro bind <name> <key>` • `/macro delete <name>` • `/macro list`\n\n**Projects Screen Navigation:**\n- `↑↓` - Navigate project list\n- `Enter` - View project details\n- `Esc` - Return to chat (or projects list from details)\n- `n` - New project • `e` - Edit • `d` - Delete\n\n**Command System:**\n- Type `/` to open command popup\n- `↑↓` - Navigate commands\n- `Tab/Enter` - Complete/execute command, prompting for any arguments\n- `Esc` - Cancel command mode\n\n**Text Input:**\n- `Enter` - Submit message\n- `↑↓` - Navigate input history (when input empty)\n- `Tab` - Switch focus (chat ↔ input)\n- `q` - Quick quit (when input empty)".to_string();
+                self.state.add_system_message(content);
+            }
+            SlashCommand::Quit => {
+                self.state
+                    .add_system_message("👋 Goodbye! Exiting Orkee TUI...".to_string());
+                self.quit();
+            }
+            SlashCommand::Clear => {
+                // Clear message history but keep welcome message
+                self.state.message_history.clear();
+                self.state
+                    .add_system_message("🧹 Chat history cleared.".to_string());
+            }
+            SlashCommand::Projects => {
+                // Switch to interactive projects screen; the list refreshes in the background
+                self.state.current_screen = crate::state::Screen::Projects;
+                self.select_first_project();
+                self.enqueue(Action::LoadProjects);
+            }
+            SlashCommand::Executions => {
+                self.state.push_current_screen_to_history();
+                self.state.current_screen = Screen::Executions;
+                self.refresh_executions();
+            }
+            SlashCommand::Execute => {
+                // Checked against freshly loaded tasks, agents, and templates
+                self.pending_launch = Some(args);
+                self.enqueue(Action::LoadLaunchOptions(self.state.projects.clone()));
+            }
+            SlashCommand::Status => {
+                let content = format!("📊 **Application Status**\n\n**Projects:** {} loaded\n**Current Screen:** {:?}\n**Input Mode:** {:?}\n**Refresh Interval:** {}s\n**Command System:** ✅ Active (Phase 3)\n\n**Features:**\n- ✅ Slash commands with popup\n- ✅ Fuzzy command matching\n- ✅ Input history navigation\n- ✅ Chat message system\n\n💡 *All systems operational!*", 
+                    self.state.projects.len(),
+                    self.state.current_screen,
+                    self.state.input_mode(),
+                    self.state.refresh_interval
+                );
+                self.state.add_system_message(content);
+            }
+            SlashCommand::Macro => match MacroCommand::parse(&args) {
+                Ok(macro_command) => self.execute_macro_command(macro_command).await,
+                Err(usage) => {
+                    self.state
+                        .add_system_message(format!("❌ **Command Error:** {}", usage));
+                }
+            },
+            SlashCommand::ProjectStatus => self.set_project_status(&args),
+            SlashCommand::Copy => match CopyTarget::parse(&args) {
+                Ok(target) => self.copy_to_clipboard(target),
+                Err(usage) => {
+                    self.state
+                        .add_system_message(format!("❌ **Command Error:** {}", usage));
+                }
+            },
+        }
+    }
+
+    /// Execute `/project-status <project> <status>`
+    fn set_project_status(&mut self, args: &[String]) {
+        let sources = ArgSources {
+            projects: &self.state.projects,
+            launch: &self.state.launch_options,
+        };
+        let update = validate_args(SlashCommand::ProjectStatus, args, &sources).and_then(|args| {
+            let update = self.state.project_status_update(&args[0], &args[1])?;
+            Ok((args[0].clone(), update))
+        });
+        match update {
+            Ok((project_id, update)) => self.enqueue(Action::UpdateProject {
+                project_id,
+                update,
+                origin: ProjectSaveOrigin::StatusCommand,
+            }),
+            Err(e) => {
+                self.state
+                    .add_system_message(format!("❌ **Command Error:** {}", e));
+            }
+        }
+    }
+
+    /// Execute a `/macro` action
+    async fn execute_macro_command(&mut self, command: MacroCommand) {
+        if self.state.is_recording_macro() && command != MacroCommand::List {
+            self.state.add_system_message(
+                "⚠️ Finish the current recording with Ctrl+R first.".to_string(),
+            );
+            return;
+        }
+
+        match command {
+            MacroCommand::Record(name) => {
+                self.state.add_system_message(format!(
+                    "⏺️ Recording macro '{}'. Everything you type is recorded until you press Ctrl+R.",
+                    name
+                ));
+                self.state.start_macro_recording(&name);
+            }
+            MacroCommand::Play(name) => {
+                // Replayed after this command's keystroke is fully handled
+                self.pending_replay = Some(name);
+            }
+            MacroCommand::Bind { name, key } => {
+                let result = key
+                    .parse::<KeyStroke>()
+                    .and_then(|key| self.state.macros.bind(&name, key));
+                match result {
+                    Ok(()) => {
+                        self.save_macros();
+                        self.state.add_system_message(format!(
+                            "🔗 `{}` now replays macro '{}'.",
+                            key, name
+                        ));
+                    }
+                    Err(e) => {
+                        self.state.add_system_message(format!("❌ {}", e));
+                    }
+                }
+            }
+            MacroCommand::Delete(name) => {
+                if self.state.macros.remove(&name) {
+                    self.save_macros();
+                    self.state
+                        .add_system_message(format!("🗑️ Macro '{}' deleted.", name));
+                } else {
+                    self.state
+                        .add_system_message(format!("❌ {}", MacroError::NotFound(name)));
+                }
+            }
+            MacroCommand::List => {
+                if self.state.macros.is_empty() {
+                    self.state.add_system_message(
+                        "No macros yet. Start one with `/macro record <name>`.".to_string(),
+                    );
+                    return;
+                }
+
+                let lines: Vec<String> = self
+                    .state
+                    .macros
+                    .iter()
+                    .map(|input_macro| {
+                        let binding = input_macro
+                            .binding
+                            .map(|key| format!("`{}`", key))
+                            .unwrap_or_else(|| "unbound".to_string());
+                        format!(
+                            "- **{}** ({} keys) - {}",
+                            input_macro.name,
+                            input_macro.keys.len(),
+                            binding
+                        )
+                    })
+                    .collect();
+                self.state
+                    .add_system_message(format!("⏺️ **Macros**\n\n{}", lines.join("\n")));
+            }
+        }
+    }
+
     pub fn quit(&mut self) {
         self.should_quit = true;
 
@@ -0,0 +1,180 @@
+// ABOUTME: State of the '?' cheat sheet listing the key bindings active on each screen
+// ABOUTME: Bindings are filtered by a typed query and can be browsed screen by screen
+
+use crate::events::keymap::{KeyAction, Keymap, KeymapScope};
+use crate::state::Screen;
+
+/// Screens the cheat sheet cycles through, in tab order
+const SCREENS: [Screen; 4] = [
+    Screen::Chat,
+    Screen::Projects,
+    Screen::ProjectDetail,
+    Screen::Executions,
+];
+
+/// One row of the cheat sheet
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheatSheetEntry {
+    pub scope: KeymapScope,
+    pub action: KeyAction,
+    /// Bound keys joined for display, e.g. "up, k"
+    pub keys: String,
+}
+
+/// Overlay listing the bindings of one screen
+#[derive(Debug, Clone)]
+pub struct CheatSheet {
+    screen: Screen,
+    query: String,
+    scroll: usize,
+}
+
+impl CheatSheet {
+    /// Open on the bindings of the given screen
+    pub fn new(screen: Screen) -> Self {
+        Self {
+            screen,
+            query: String::new(),
+            scroll: 0,
+        }
+    }
+
+    pub fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn scroll(&self) -> usize {
+        self.scroll
+    }
+
+    /// Show the next screen's bindings
+    pub fn next_screen(&mut self) {
+        self.cycle_screen(1);
+    }
+
+    /// Show the previous screen's bindings
+    pub fn previous_screen(&mut self) {
+        self.cycle_screen(SCREENS.len() - 1);
+    }
+
+    fn cycle_screen(&mut self, step: usize) {
+        let index = SCREENS
+            .iter()
+            .position(|screen| *screen == self.screen)
+            .unwrap_or(0);
+        self.screen = SCREENS[(index + step) % SCREENS.len()].clone();
+        self.scroll = 0;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.scroll = 0;
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.scroll = self.scroll.saturating_sub(1);
+    }
+
+    /// Scroll down, stopping at the last of `entry_count` rows
+    pub fn scroll_down(&mut self, entry_count: usize) {
+        if self.scroll + 1 < entry_count {
+            self.scroll += 1;
+        }
+    }
+
+    /// Bindings on the shown screen matching the query by description, action
+    /// name or key. Unbound actions are left out.
+    pub fn entries(&self, keymap: &Keymap) -> Vec<CheatSheetEntry> {
+        let query = self.query.to_lowercase();
+
+        keymap
+            .active_bindings(&self.screen)
+            .into_iter()
+            .filter(|binding| !binding.keys.is_empty())
+            .map(|binding| CheatSheetEntry {
+                scope: binding.scope,
+                action: binding.action,
+                keys: binding
+                    .keys
+                    .iter()
+                    .map(|key| key.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            })
+            .filter(|entry| {
+                query.is_empty()
+                    || entry.action.description().to_lowercase().contains(&query)
+                    || entry.action.name().contains(&query)
+                    || entry.keys.to_lowercase().contains(&query)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_follow_screen() {
+        let keymap = Keymap::default();
+        let mut sheet = CheatSheet::new(Screen::Chat);
+
+        let actions: Vec<KeyAction> = sheet.entries(&keymap).iter().map(|e| e.action).collect();
+        assert!(actions.contains(&KeyAction::Quit));
+        assert!(!actions.contains(&KeyAction::NewProject));
+
+        sheet.next_screen();
+        assert_eq!(sheet.screen(), &Screen::Projects);
+        let actions: Vec<KeyAction> = sheet.entries(&keymap).iter().map(|e| e.action).collect();
+        assert!(actions.contains(&KeyAction::NewProject));
+        assert!(actions.contains(&KeyAction::Quit));
+
+        sheet.previous_screen();
+        sheet.previous_screen();
+        assert_eq!(sheet.screen(), &Screen::Executions);
+    }
+
+    #[test]
+    fn test_query_filters_entries() {
+        let keymap = Keymap::default();
+        let mut sheet = CheatSheet::new(Screen::Projects);
+        for c in "delete".chars() {
+            sheet.push_char(c);
+        }
+
+        let entries = sheet.entries(&keymap);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, KeyAction::DeleteProject);
+        assert_eq!(entries[0].keys, "d");
+
+        // Keys are searchable too
+        let mut sheet = CheatSheet::new(Screen::Chat);
+        for c in "ctrl+l".chars() {
+            sheet.push_char(c);
+        }
+        let entries = sheet.entries(&keymap);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].action, KeyAction::ToggleContextPane);
+    }
+
+    #[test]
+    fn test_unbound_actions_are_hidden() {
+        let keymap = Keymap::from_toml("[projects]\ndelete_project = []").unwrap();
+        let sheet = CheatSheet::new(Screen::Projects);
+
+        assert!(!sheet
+            .entries(&keymap)
+            .iter()
+            .any(|e| e.action == KeyAction::DeleteProject));
+    }
+}
@@ -0,0 +1,242 @@
+// ABOUTME: Copies text to the OS clipboard through the platform's clipboard command
+// ABOUTME: Falls back to the OSC 52 terminal escape, which also reaches the local clipboard over SSH
+
+use base64::Engine;
+use std::fmt;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Largest text sent through OSC 52; many terminals drop longer sequences
+const MAX_OSC52_BYTES: usize = 100_000;
+
+/// A program that reads text on stdin and puts it on the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClipboardCommand {
+    pub program: &'static str,
+    pub args: &'static [&'static str],
+}
+
+impl ClipboardCommand {
+    const fn new(program: &'static str, args: &'static [&'static str]) -> Self {
+        Self { program, args }
+    }
+}
+
+/// How copied text reached the clipboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyMethod {
+    /// Through a clipboard command such as `pbcopy`
+    System(&'static str),
+    /// Through the terminal with an OSC 52 escape sequence
+    Osc52,
+}
+
+/// Errors from copying to the clipboard
+#[derive(Debug)]
+pub enum ClipboardError {
+    Empty,
+    TooLarge(usize),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ClipboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Nothing to copy"),
+            Self::TooLarge(len) => write!(
+                f,
+                "{} bytes is too much to copy through the terminal (limit {})",
+                len, MAX_OSC52_BYTES
+            ),
+            Self::Io(e) => write!(f, "Couldn't write to the terminal: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+/// Clipboard access for the environment the TUI runs in
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clipboard {
+    /// Clipboard commands to try in order before falling back to OSC 52
+    commands: Vec<ClipboardCommand>,
+    /// Whether the terminal is inside tmux, which needs OSC 52 wrapped
+    in_tmux: bool,
+}
+
+impl Clipboard {
+    /// Pick clipboard commands for the current OS and session
+    pub fn detect() -> Self {
+        Self::for_environment(std::env::consts::OS, |name| std::env::var(name).ok())
+    }
+
+    /// Pick clipboard commands for an OS and environment. Over SSH the
+    /// commands would fill the remote machine's clipboard, so only OSC 52 is used.
+    pub fn for_environment(os: &str, env: impl Fn(&str) -> Option<String>) -> Self {
+        let is_set = |name: &str| env(name).is_some_and(|value| !value.is_empty());
+        let over_ssh = is_set("SSH_TTY") || is_set("SSH_CONNECTION");
+
+        let mut commands = Vec::new();
+        if !over_ssh {
+            match os {
+                "macos" => commands.push(ClipboardCommand::new("pbcopy", &[])),
+                "windows" => commands.push(ClipboardCommand::new("clip", &[])),
+                _ => {
+                    if is_set("WAYLAND_DISPLAY") {
+                        commands.push(ClipboardCommand::new("wl-copy", &[]));
+                    }
+                    if is_set("DISPLAY") {
+                        commands.push(ClipboardCommand::new("xclip", &["-selection", "clipboard"]));
+                        commands.push(ClipboardCommand::new("xsel", &["--clipboard", "--input"]));
+                    }
+                }
+            }
+        }
+
+        Self {
+            commands,
+            in_tmux: is_set("TMUX"),
+        }
+    }
+
+    /// Clipboard commands that will be tried, in order
+    pub fn commands(&self) -> &[ClipboardCommand] {
+        &self.commands
+    }
+
+    /// Copy text, trying each clipboard command before falling back to OSC 52
+    pub fn copy(&self, text: &str) -> Result<CopyMethod, ClipboardError> {
+        if text.is_empty() {
+            return Err(ClipboardError::Empty);
+        }
+
+        for command in &self.commands {
+            if run_clipboard_command(command, text) {
+                return Ok(CopyMethod::System(command.program));
+            }
+        }
+
+        if text.len() > MAX_OSC52_BYTES {
+            return Err(ClipboardError::TooLarge(text.len()));
+        }
+        let mut stdout = std::io::stdout();
+        stdout
+            .write_all(osc52_sequence(text, self.in_tmux).as_bytes())
+            .and_then(|_| stdout.flush())
+            .map_err(ClipboardError::Io)?;
+        Ok(CopyMethod::Osc52)
+    }
+}
+
+/// Feed text to a clipboard command. Returns false if it's missing or failed.
+fn run_clipboard_command(command: &ClipboardCommand, text: &str) -> bool {
+    let child = Command::new(command.program)
+        .args(command.args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    let Ok(mut child) = child else {
+        return false;
+    };
+
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    // Dropping stdin above closes it so the command can finish
+    let succeeded = child.wait().is_ok_and(|status| status.success());
+    written && succeeded
+}
+
+/// Escape sequence asking the terminal to put text on the clipboard. Inside
+/// tmux it's wrapped in a passthrough so tmux forwards it to the terminal.
+pub fn osc52_sequence(text: &str, in_tmux: bool) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let sequence = format!("\x1b]52;c;{}\x07", encoded);
+    if in_tmux {
+        format!("\x1bPtmux;\x1b{}\x1b\\", sequence)
+    } else {
+        sequence
+    }
+}
+
+/// First line of copied text, shortened for a confirmation message
+pub fn preview(text: &str, max_chars: usize) -> String {
+    let first_line = text.lines().next().unwrap_or_default();
+    let mut preview: String = first_line.chars().take(max_chars).collect();
+    if preview.len() < text.len() {
+        preview.push('…');
+    }
+    preview
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn clipboard(os: &str, vars: &[(&str, &str)]) -> Clipboard {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        Clipboard::for_environment(os, |name| vars.get(name).cloned())
+    }
+
+    fn programs(clipboard: &Clipboard) -> Vec<&'static str> {
+        clipboard.commands().iter().map(|c| c.program).collect()
+    }
+
+    #[test]
+    fn test_detects_platform_commands() {
+        assert_eq!(programs(&clipboard("macos", &[])), vec!["pbcopy"]);
+        assert_eq!(programs(&clipboard("windows", &[])), vec!["clip"]);
+        assert_eq!(
+            programs(&clipboard(
+                "linux",
+                &[("WAYLAND_DISPLAY", "wayland-0"), ("DISPLAY", ":0")]
+            )),
+            vec!["wl-copy", "xclip", "xsel"]
+        );
+        // A headless Linux box has no clipboard command, only OSC 52
+        assert!(programs(&clipboard("linux", &[])).is_empty());
+    }
+
+    #[test]
+    fn test_ssh_sessions_only_use_osc52() {
+        assert!(programs(&clipboard("macos", &[("SSH_TTY", "/dev/ttys001")])).is_empty());
+        assert!(programs(&clipboard(
+            "linux",
+            &[
+                ("SSH_CONNECTION", "10.0.0.1 22 10.0.0.2 22"),
+                ("DISPLAY", ":0")
+            ]
+        ))
+        .is_empty());
+    }
+
+    #[test]
+    fn test_osc52_sequence() {
+        assert_eq!(osc52_sequence("hello", false), "\x1b]52;c;aGVsbG8=\x07");
+        assert_eq!(
+            osc52_sequence("hello", true),
+            "\x1bPtmux;\x1b\x1b]52;c;aGVsbG8=\x07\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_empty_text_is_refused() {
+        assert!(matches!(
+            clipboard("linux", &[]).copy(""),
+            Err(ClipboardError::Empty)
+        ));
+    }
+
+    #[test]
+    fn test_preview() {
+        assert_eq!(preview("/home/me/project", 40), "/home/me/project");
+        assert_eq!(preview("first line\nsecond", 40), "first line…");
+        assert_eq!(preview("abcdef", 3), "abc…");
+    }
+}
@@ -2,6 +2,9 @@ use crate::slash_command::SlashCommand;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 
+/// Rows the popup shows by default; enough for every built-in command
+pub const MAX_VISIBLE_COMMANDS: usize = 12;
+
 /// Represents a command item for display in the popup
 #[derive(Debug, Clone)]
 pub struct CommandItem {
@@ -80,7 +83,7 @@ impl CommandPopup {
             selected_index: 0,
             filter: String::new(),
             matcher: SkimMatcherV2::default(),
-            max_display_items: 8, // Reasonable default for UI
+            max_display_items: MAX_VISIBLE_COMMANDS,
         };
 
         // Initialize with all commands visible
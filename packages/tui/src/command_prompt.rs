@@ -0,0 +1,439 @@
+// ABOUTME: Inline argument prompts for slash commands selected from the command popup
+// ABOUTME: Walks through each argument with pickers for loaded data, fixed choices, and validation
+
+use crate::executions::LaunchOptions;
+use crate::slash_command::{ArgKind, CommandArg, SlashCommand};
+use orkee_projects::Project;
+
+/// Loaded data that arguments are picked from
+#[derive(Debug, Clone, Copy)]
+pub struct ArgSources<'a> {
+    pub projects: &'a [Project],
+    /// Tasks, agents, and templates for `/execute`
+    pub launch: &'a LaunchOptions,
+}
+
+/// A value offered for the argument being prompted
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptOption {
+    /// Value passed to the command
+    pub value: String,
+    /// Text shown in the prompt
+    pub label: String,
+}
+
+/// Outcome of submitting the current argument
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptStep {
+    /// The argument was accepted and the next one is being prompted
+    Next,
+    /// Every argument is in; run the command with these
+    Complete(Vec<String>),
+    /// The value was rejected; see [`CommandPrompt::error`]
+    Invalid,
+}
+
+/// Prompts for a command's arguments one at a time, using the input buffer for typing
+#[derive(Debug, Clone)]
+pub struct CommandPrompt {
+    pub command: SlashCommand,
+    /// Arguments accepted so far
+    pub args: Vec<String>,
+    /// Argument being prompted
+    pub arg: CommandArg,
+    /// Options matching what's typed so far, for every argument but free text
+    options: Vec<PromptOption>,
+    selected: usize,
+    /// Why the last submitted value was rejected
+    pub error: Option<String>,
+}
+
+impl CommandPrompt {
+    /// Start prompting for a command's arguments, or `None` if it takes none
+    pub fn start(command: SlashCommand, sources: &ArgSources) -> Option<Self> {
+        let arg = command.next_arg(&[])?;
+        let mut prompt = Self {
+            command,
+            args: Vec::new(),
+            arg,
+            options: Vec::new(),
+            selected: 0,
+            error: None,
+        };
+        prompt.update_filter("", sources);
+        Some(prompt)
+    }
+
+    /// Narrow the options to those matching the typed text
+    pub fn update_filter(&mut self, input: &str, sources: &ArgSources) {
+        let needle = input.trim().to_lowercase();
+        self.options = all_options(&self.arg, &self.args, sources)
+            .into_iter()
+            .filter(|option| option.label.to_lowercase().contains(&needle))
+            .collect();
+        self.selected = 0;
+        self.error = None;
+    }
+
+    pub fn options(&self) -> &[PromptOption] {
+        &self.options
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn move_up(&mut self) {
+        if !self.options.is_empty() {
+            self.selected = (self.selected + self.options.len() - 1) % self.options.len();
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if !self.options.is_empty() {
+            self.selected = (self.selected + 1) % self.options.len();
+        }
+    }
+
+    /// The command as entered so far, e.g. `/macro bind`
+    pub fn entered(&self) -> String {
+        std::iter::once(format!("/{}", self.command.command_name()))
+            .chain(self.args.iter().cloned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Accept the highlighted option, or the typed text for free-text arguments
+    pub fn submit(&mut self, input: &str, sources: &ArgSources) -> PromptStep {
+        let value = match (&self.arg.kind, self.options.get(self.selected)) {
+            (ArgKind::Text, _) | (_, None) => input.to_string(),
+            (_, Some(option)) => option.value.clone(),
+        };
+
+        let value = match validate(&self.arg, &value, &self.args, sources) {
+            Ok(value) => value,
+            Err(error) => {
+                self.error = Some(error);
+                return PromptStep::Invalid;
+            }
+        };
+
+        self.args.push(value);
+        match self.command.next_arg(&self.args) {
+            Some(arg) => {
+                self.arg = arg;
+                self.update_filter("", sources);
+                PromptStep::Next
+            }
+            None => PromptStep::Complete(self.args.clone()),
+        }
+    }
+}
+
+/// Every value an argument can take given the ones before it, empty for free text
+fn all_options(arg: &CommandArg, args: &[String], sources: &ArgSources) -> Vec<PromptOption> {
+    match arg.kind {
+        ArgKind::Project => sources
+            .projects
+            .iter()
+            .map(|project| PromptOption {
+                value: project.id.clone(),
+                label: project.name.clone(),
+            })
+            .collect(),
+        ArgKind::Choice(choices) => choices
+            .iter()
+            .map(|choice| PromptOption {
+                value: choice.to_string(),
+                label: choice.to_string(),
+            })
+            .collect(),
+        ArgKind::Text => Vec::new(),
+        ArgKind::Task => {
+            let project_id = args.first().map(String::as_str).unwrap_or_default();
+            sources
+                .launch
+                .project_tasks(project_id)
+                .map(|task| PromptOption {
+                    value: task.id.clone(),
+                    label: task.title.clone(),
+                })
+                .collect()
+        }
+        ArgKind::Agent => sources
+            .launch
+            .agents
+            .iter()
+            .map(|agent| PromptOption {
+                value: agent.id.clone(),
+                label: agent.name.clone(),
+            })
+            .collect(),
+        ArgKind::Template => sources
+            .launch
+            .templates
+            .iter()
+            .map(|template| PromptOption {
+                value: template.id.clone(),
+                label: template.name.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Check a value for an argument given the ones before it, returning the value to pass to the command
+pub fn validate(
+    arg: &CommandArg,
+    value: &str,
+    args: &[String],
+    sources: &ArgSources,
+) -> Result<String, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(format!("{} is required", arg.name));
+    }
+
+    match arg.kind {
+        ArgKind::Project => sources
+            .projects
+            .iter()
+            .find(|project| project.id == value || project.name.eq_ignore_ascii_case(value))
+            .map(|project| project.id.clone())
+            .ok_or_else(|| format!("No project named '{}'", value)),
+        ArgKind::Choice(choices) => choices
+            .iter()
+            .find(|choice| choice.eq_ignore_ascii_case(value))
+            .map(|choice| choice.to_string())
+            .ok_or_else(|| format!("{} must be one of: {}", arg.name, choices.join(", "))),
+        ArgKind::Text if value.contains(char::is_whitespace) => {
+            Err(format!("{} must be a single word", arg.name))
+        }
+        ArgKind::Text => Ok(value.to_string()),
+        ArgKind::Task => {
+            let project_id = args.first().map(String::as_str).unwrap_or_default();
+            sources
+                .launch
+                .project_tasks(project_id)
+                .find(|task| task.id == value || task.title.eq_ignore_ascii_case(value))
+                .map(|task| task.id.clone())
+                .ok_or_else(|| format!("No open task '{}' in this project", value))
+        }
+        ArgKind::Agent => sources
+            .launch
+            .agents
+            .iter()
+            .find(|agent| agent.id == value || agent.name.eq_ignore_ascii_case(value))
+            .map(|agent| agent.id.clone())
+            .ok_or_else(|| format!("No available agent named '{}'", value)),
+        ArgKind::Template => sources
+            .launch
+            .templates
+            .iter()
+            .find(|template| template.id == value || template.name.eq_ignore_ascii_case(value))
+            .map(|template| template.id.clone())
+            .ok_or_else(|| format!("No sandbox template named '{}'", value)),
+    }
+}
+
+/// Check typed-out arguments for a command, returning the values to pass to it
+pub fn validate_args(
+    command: SlashCommand,
+    args: &[String],
+    sources: &ArgSources,
+) -> Result<Vec<String>, String> {
+    let mut validated = Vec::new();
+    while let Some(arg) = command.next_arg(&validated) {
+        let value = args
+            .get(validated.len())
+            .ok_or_else(|| format!("Usage: {}", command.usage()))?;
+        validated.push(validate(&arg, value, &validated, sources)?);
+    }
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(id: &str, name: &str) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            project_root: format!("/path/{}", name),
+            status: orkee_projects::ProjectStatus::Planning,
+            priority: orkee_projects::Priority::Medium,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            tags: None,
+            description: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            git_repository: None,
+            rank: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            owner_user_id: None,
+            version: 1,
+        }
+    }
+
+    fn sources<'a>(projects: &'a [Project], launch: &'a LaunchOptions) -> ArgSources<'a> {
+        ArgSources { projects, launch }
+    }
+
+    fn launch_options() -> LaunchOptions {
+        let task = |id: &str, project_id: &str, title: &str| {
+            serde_json::from_value(serde_json::json!({
+                "id": id,
+                "project_id": project_id,
+                "title": title,
+                "status": "pending",
+                "priority": "medium",
+                "created_by_user_id": "default-user",
+                "position": 0,
+                "retry_count": 0,
+                "created_at": chrono::Utc::now(),
+                "updated_at": chrono::Utc::now(),
+                "task_type": "task",
+                "can_parallel": false,
+                "steps_total": 0,
+                "steps_completed": 0,
+            }))
+            .unwrap()
+        };
+        let template = serde_json::from_value(serde_json::json!({
+            "id": "rust-toolchain",
+            "name": "Rust toolchain",
+            "image": "rust:1",
+            "created_at": chrono::Utc::now(),
+            "updated_at": chrono::Utc::now(),
+        }))
+        .unwrap();
+
+        LaunchOptions {
+            tasks: vec![
+                task("t1", "p1", "Fix login"),
+                task("t2", "p2", "Write docs"),
+            ],
+            agents: vec![orkee_models::REGISTRY
+                .get_agent("claude-code")
+                .unwrap()
+                .clone()],
+            templates: vec![template],
+        }
+    }
+
+    #[test]
+    fn test_execute_picks_tasks_of_the_chosen_project() {
+        let projects = vec![project("p1", "Orkee"), project("p2", "Website")];
+        let launch = launch_options();
+        let sources = sources(&projects, &launch);
+        let mut prompt = CommandPrompt::start(SlashCommand::Execute, &sources).unwrap();
+
+        prompt.update_filter("orkee", &sources);
+        assert_eq!(prompt.submit("orkee", &sources), PromptStep::Next);
+        assert_eq!(prompt.arg.kind, ArgKind::Task);
+        let labels: Vec<_> = prompt.options().iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(labels, vec!["Fix login"]);
+
+        assert_eq!(prompt.submit("", &sources), PromptStep::Next);
+        assert_eq!(prompt.submit("", &sources), PromptStep::Next);
+        let args = match prompt.submit("", &sources) {
+            PromptStep::Complete(args) => args,
+            step => panic!("expected the command to be complete, got {:?}", step),
+        };
+        assert_eq!(args, vec!["p1", "t1", "claude-code", "rust-toolchain"]);
+
+        let request = launch.request(&args).unwrap();
+        assert_eq!(request.agent_name, "Claude Code");
+        assert_eq!(request.template_name, "Rust toolchain");
+
+        // A task of another project isn't accepted
+        let typed: Vec<String> = ["orkee", "t2", "claude-code", "rust-toolchain"]
+            .iter()
+            .map(|v| v.to_string())
+            .collect();
+        assert_eq!(
+            validate_args(SlashCommand::Execute, &typed, &sources),
+            Err("No open task 't2' in this project".to_string())
+        );
+    }
+
+    #[test]
+    fn test_prompts_each_argument_in_turn() {
+        let projects = vec![project("p1", "Orkee"), project("p2", "Website")];
+        let launch = LaunchOptions::default();
+        let sources = sources(&projects, &launch);
+        let mut prompt = CommandPrompt::start(SlashCommand::ProjectStatus, &sources).unwrap();
+        assert_eq!(prompt.arg.kind, ArgKind::Project);
+        assert_eq!(prompt.options().len(), 2);
+
+        prompt.update_filter("web", &sources);
+        assert_eq!(prompt.options().len(), 1);
+        assert_eq!(prompt.submit("web", &sources), PromptStep::Next);
+        assert_eq!(prompt.entered(), "/project-status p2");
+
+        prompt.update_filter("on", &sources);
+        assert_eq!(
+            prompt.submit("on", &sources),
+            PromptStep::Complete(vec!["p2".to_string(), "on-hold".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_values() {
+        let projects = vec![project("p1", "Orkee")];
+        let launch = LaunchOptions::default();
+        let sources = sources(&projects, &launch);
+        let mut prompt = CommandPrompt::start(SlashCommand::ProjectStatus, &sources).unwrap();
+
+        prompt.update_filter("missing", &sources);
+        assert_eq!(prompt.submit("missing", &sources), PromptStep::Invalid);
+        assert_eq!(prompt.error.as_deref(), Some("No project named 'missing'"));
+        assert!(prompt.args.is_empty());
+
+        let mut prompt = CommandPrompt::start(SlashCommand::Macro, &sources).unwrap();
+        assert_eq!(prompt.submit("", &sources), PromptStep::Next);
+        assert_eq!(prompt.args, vec!["record".to_string()]);
+        assert_eq!(prompt.submit("two words", &sources), PromptStep::Invalid);
+        assert_eq!(
+            prompt.submit("triage", &sources),
+            PromptStep::Complete(vec!["record".to_string(), "triage".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_validates_typed_out_arguments() {
+        let projects = vec![project("p1", "Orkee")];
+        let launch = LaunchOptions::default();
+        let sources = sources(&projects, &launch);
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            validate_args(
+                SlashCommand::ProjectStatus,
+                &args(&["orkee", "On-Hold"]),
+                &sources
+            ),
+            Ok(args(&["p1", "on-hold"]))
+        );
+        assert_eq!(
+            validate_args(SlashCommand::ProjectStatus, &args(&["orkee"]), &sources),
+            Err("Usage: /project-status <project> <status>".to_string())
+        );
+        assert!(validate_args(
+            SlashCommand::ProjectStatus,
+            &args(&["orkee", "done"]),
+            &sources
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_commands_without_arguments_need_no_prompt() {
+        let launch = LaunchOptions::default();
+        assert!(CommandPrompt::start(SlashCommand::Help, &sources(&[], &launch)).is_none());
+    }
+}
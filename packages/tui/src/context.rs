@@ -0,0 +1,73 @@
+use orkee_preview::storage::{PreviewServerEntry, PreviewServerStorage};
+use orkee_preview::types::DevServerStatus;
+use orkee_projects::Project;
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::Task;
+use sqlx::SqlitePool;
+
+/// Number of tasks shown in the context pane
+const RECENT_TASK_LIMIT: usize = 5;
+
+/// Context shown beside the chat for the project the conversation is about
+#[derive(Debug, Clone)]
+pub struct ConversationContext {
+    pub project_id: String,
+    /// Dev servers that are starting or running for the project
+    pub servers: Vec<PreviewServerEntry>,
+    /// Most recently updated top-level tasks, newest first
+    pub recent_tasks: Vec<Task>,
+}
+
+impl ConversationContext {
+    /// Context with nothing loaded yet, e.g. when the database is unavailable
+    pub fn empty(project_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            servers: Vec::new(),
+            recent_tasks: Vec::new(),
+        }
+    }
+}
+
+/// Reads servers and tasks from the Orkee database for the context pane
+pub struct ContextSource {
+    servers: PreviewServerStorage,
+    tasks: TaskStorage,
+}
+
+impl ContextSource {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            servers: PreviewServerStorage::from_pool(pool.clone()),
+            tasks: TaskStorage::new(pool),
+        }
+    }
+
+    /// Load the context for a project. Parts that fail to load are left empty
+    /// so the pane still shows whatever is available.
+    pub async fn load(&self, project: &Project) -> ConversationContext {
+        let servers = self
+            .servers
+            .get_by_project(&project.id)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|server| {
+                matches!(
+                    server.status,
+                    DevServerStatus::Starting | DevServerStatus::Running
+                )
+            })
+            .collect();
+
+        let mut recent_tasks = self.tasks.list_tasks(&project.id).await.unwrap_or_default();
+        recent_tasks.sort_by_key(|task| std::cmp::Reverse(task.updated_at));
+        recent_tasks.truncate(RECENT_TASK_LIMIT);
+
+        ConversationContext {
+            project_id: project.id.clone(),
+            servers,
+            recent_tasks,
+        }
+    }
+}
@@ -0,0 +1,388 @@
+// ABOUTME: Background action queue that keeps database and server calls off the event loop
+// ABOUTME: Key handlers enqueue actions; a worker performs them and sends results back as events
+
+use super::macros::MACROS_SETTING_KEY;
+use super::AppEvent;
+use crate::context::{ContextSource, ConversationContext};
+use crate::executions::{ExecutionSource, ExecutionsUpdate, LaunchOptions, LaunchRequest};
+use crate::project_detail::{DetailSource, DetailTab, TabData, TabUpdate};
+use chrono::{DateTime, Utc};
+use orkee_executions::AgentExecution;
+use orkee_notifications::{Notification, NotificationChannel, NotificationStorage};
+use orkee_preview::storage::PreviewServerEntry;
+use orkee_projects::orkee_storage::StorageError;
+use orkee_projects::{
+    create_project, delete_project, get_all_projects, update_project, ManagerError, Project,
+    ProjectCreateInput, ProjectUpdateInput, ValidationReport,
+};
+use orkee_settings::{SettingUpdate, SettingsStorage};
+use tokio::sync::mpsc;
+
+/// Most notifications fetched per poll
+const NOTIFICATION_BATCH: i64 = 20;
+
+const DATABASE_UNAVAILABLE: &str = "Orkee database not available";
+
+/// Work that may be slow, performed by the action worker
+#[derive(Debug, Clone)]
+pub enum Action {
+    LoadProjects,
+    /// Notifications routed to the TUI since the given time
+    PollNotifications {
+        since: DateTime<Utc>,
+    },
+    /// Servers and tasks for the context pane
+    LoadContext(Project),
+    LoadDetailTab {
+        project: Project,
+        tab: DetailTab,
+    },
+    StartServer(Project),
+    StopServer(PreviewServerEntry),
+    /// Recent executions, plus the logs of the execution whose detail pane is open
+    RefreshExecutions {
+        open_execution_id: Option<String>,
+    },
+    CancelExecution(String),
+    RetryExecution(String),
+    /// Tasks of these projects, agents, and sandbox templates for `/execute`
+    LoadLaunchOptions(Vec<Project>),
+    LaunchExecution(LaunchRequest),
+    /// Executions shown on chat status cards
+    PollExecutionCards(Vec<String>),
+    CreateProject(ProjectCreateInput),
+    UpdateProject {
+        project_id: String,
+        update: ProjectUpdateInput,
+        origin: ProjectSaveOrigin,
+    },
+    DeleteProject {
+        project_id: String,
+        project_name: String,
+    },
+    LoadMacros,
+    /// Macros as their setting value
+    SaveMacros(String),
+}
+
+/// Where a project save came from, so its outcome is reported in the same place
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectSaveOrigin {
+    CreateForm,
+    EditForm,
+    /// `/project-status`
+    StatusCommand,
+}
+
+/// Why a project could not be saved
+#[derive(Debug, Clone)]
+pub enum ProjectSaveError {
+    /// Field-level problems, shown next to the form fields
+    Validation(ValidationReport),
+    /// The project was saved elsewhere after this version was loaded
+    VersionConflict {
+        expected: i64,
+        actual: i64,
+    },
+    Failed(String),
+}
+
+impl From<ManagerError> for ProjectSaveError {
+    fn from(error: ManagerError) -> Self {
+        match error {
+            ManagerError::Validation(report) => Self::Validation(report),
+            ManagerError::Storage(StorageError::VersionConflict { expected, actual }) => {
+                Self::VersionConflict { expected, actual }
+            }
+            e => Self::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Outcome of an action, applied to the app state on the event loop
+#[derive(Debug, Clone)]
+pub enum ActionResult {
+    ProjectsLoaded(Result<Vec<Project>, String>),
+    /// New notifications, newest first
+    Notifications(Vec<Notification>),
+    ContextLoaded(ConversationContext),
+    /// Data for one detail tab; none for the overview
+    DetailTabLoaded {
+        project_id: String,
+        update: Option<TabUpdate>,
+    },
+    ServerStarted {
+        project_name: String,
+        /// Preview URL when known
+        result: Result<Option<String>, String>,
+    },
+    ServerStopped {
+        port: u16,
+        result: Result<(), String>,
+    },
+    ExecutionsLoaded(ExecutionsUpdate),
+    /// Whether the execution was still running and got cancelled
+    ExecutionCancelled {
+        execution_id: String,
+        result: Result<bool, String>,
+    },
+    ExecutionRetried(Result<Box<AgentExecution>, String>),
+    LaunchOptionsLoaded(Result<LaunchOptions, String>),
+    ExecutionLaunched {
+        request: LaunchRequest,
+        result: Result<Box<AgentExecution>, String>,
+    },
+    ExecutionCardsLoaded(Vec<AgentExecution>),
+    ProjectSaved {
+        origin: ProjectSaveOrigin,
+        result: Result<Box<Project>, ProjectSaveError>,
+        /// The reloaded project list after a save or a version conflict
+        projects: Option<Vec<Project>>,
+    },
+    ProjectDeleted {
+        project_id: String,
+        project_name: String,
+        /// Whether the project still existed
+        result: Result<bool, String>,
+        /// The reloaded project list after a deletion
+        projects: Option<Vec<Project>>,
+    },
+    /// The saved macros setting value; none when no macros have been saved
+    MacrosLoaded(Option<String>),
+    MacrosSaved(Result<(), String>),
+}
+
+/// Data sources the worker reads from, each absent when the Orkee database isn't available
+#[derive(Default)]
+pub struct ActionSources {
+    pub notifications: Option<NotificationStorage>,
+    pub context: Option<ContextSource>,
+    pub detail: Option<DetailSource>,
+    pub executions: Option<ExecutionSource>,
+    pub settings: Option<SettingsStorage>,
+}
+
+impl ActionSources {
+    /// Perform one action
+    async fn perform(&mut self, action: Action) -> ActionResult {
+        match action {
+            Action::LoadProjects => {
+                ActionResult::ProjectsLoaded(get_all_projects().await.map_err(|e| e.to_string()))
+            }
+            Action::PollNotifications { since } => {
+                let notifications = match &self.notifications {
+                    Some(storage) => storage
+                        .list_notifications(
+                            Some(NotificationChannel::Tui),
+                            Some(since),
+                            NOTIFICATION_BATCH,
+                        )
+                        .await
+                        .unwrap_or_default(),
+                    None => Vec::new(),
+                };
+                ActionResult::Notifications(notifications)
+            }
+            Action::LoadContext(project) => ActionResult::ContextLoaded(match &self.context {
+                Some(source) => source.load(&project).await,
+                None => ConversationContext::empty(&project.id),
+            }),
+            Action::LoadDetailTab { project, tab } => {
+                let update = match &self.detail {
+                    Some(source) => source.fetch(&project, tab).await,
+                    None => unavailable_tab(tab),
+                };
+                ActionResult::DetailTabLoaded {
+                    project_id: project.id,
+                    update,
+                }
+            }
+            Action::StartServer(project) => {
+                let result = match self.detail.as_mut() {
+                    Some(source) => source.start_server(&project).await,
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                };
+                ActionResult::ServerStarted {
+                    project_name: project.name,
+                    result,
+                }
+            }
+            Action::StopServer(server) => {
+                let result = match self.detail.as_mut() {
+                    Some(source) => source.stop_server(&server.project_id).await,
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                };
+                ActionResult::ServerStopped {
+                    port: server.port,
+                    result,
+                }
+            }
+            Action::RefreshExecutions { open_execution_id } => {
+                ActionResult::ExecutionsLoaded(match &self.executions {
+                    Some(source) => source.fetch(open_execution_id).await,
+                    None => ExecutionsUpdate {
+                        executions: Err(DATABASE_UNAVAILABLE.to_string()),
+                        runs: None,
+                    },
+                })
+            }
+            Action::CancelExecution(execution_id) => {
+                let result = match &self.executions {
+                    Some(source) => source.cancel(&execution_id).await,
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                };
+                ActionResult::ExecutionCancelled {
+                    execution_id,
+                    result,
+                }
+            }
+            Action::RetryExecution(execution_id) => {
+                ActionResult::ExecutionRetried(match &self.executions {
+                    Some(source) => source.retry(&execution_id).await.map(Box::new),
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                })
+            }
+            Action::LoadLaunchOptions(projects) => {
+                ActionResult::LaunchOptionsLoaded(match &self.executions {
+                    Some(source) => source.launch_options(&projects).await,
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                })
+            }
+            Action::LaunchExecution(request) => {
+                let result = match &self.executions {
+                    Some(source) => source.launch(&request).await.map(Box::new),
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                };
+                ActionResult::ExecutionLaunched { request, result }
+            }
+            Action::PollExecutionCards(execution_ids) => {
+                ActionResult::ExecutionCardsLoaded(match &self.executions {
+                    Some(source) => source.fetch_by_id(&execution_ids).await,
+                    None => Vec::new(),
+                })
+            }
+            Action::CreateProject(input) => {
+                let result = create_project(input)
+                    .await
+                    .map(Box::new)
+                    .map_err(ProjectSaveError::from);
+                ActionResult::ProjectSaved {
+                    origin: ProjectSaveOrigin::CreateForm,
+                    projects: reload_projects_if(result.is_ok()).await,
+                    result,
+                }
+            }
+            Action::UpdateProject {
+                project_id,
+                update,
+                origin,
+            } => {
+                let result = update_project(&project_id, update)
+                    .await
+                    .map(Box::new)
+                    .map_err(ProjectSaveError::from);
+                // A conflict also reloads, so the next attempt starts from the saved version
+                let changed = matches!(
+                    result,
+                    Ok(_) | Err(ProjectSaveError::VersionConflict { .. })
+                );
+                ActionResult::ProjectSaved {
+                    origin,
+                    projects: reload_projects_if(changed).await,
+                    result,
+                }
+            }
+            Action::DeleteProject {
+                project_id,
+                project_name,
+            } => {
+                let result = delete_project(&project_id).await.map_err(|e| e.to_string());
+                ActionResult::ProjectDeleted {
+                    projects: reload_projects_if(result == Ok(true)).await,
+                    project_id,
+                    project_name,
+                    result,
+                }
+            }
+            Action::LoadMacros => {
+                // A missing setting just means no macros have been saved yet
+                let value = match &self.settings {
+                    Some(settings) => settings
+                        .get(MACROS_SETTING_KEY)
+                        .await
+                        .ok()
+                        .map(|setting| setting.value),
+                    None => None,
+                };
+                ActionResult::MacrosLoaded(value)
+            }
+            Action::SaveMacros(value) => {
+                let result = match &self.settings {
+                    Some(settings) => settings
+                        .update(MACROS_SETTING_KEY, SettingUpdate { value }, "tui")
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string()),
+                    None => Err(DATABASE_UNAVAILABLE.to_string()),
+                };
+                ActionResult::MacrosSaved(result)
+            }
+        }
+    }
+}
+
+/// The project list after a change, when there was one
+async fn reload_projects_if(changed: bool) -> Option<Vec<Project>> {
+    if !changed {
+        return None;
+    }
+    get_all_projects().await.ok()
+}
+
+/// Tab data reported when there is no database to fetch it from
+fn unavailable_tab(tab: DetailTab) -> Option<TabUpdate> {
+    let reason = DATABASE_UNAVAILABLE.to_string();
+    match tab {
+        DetailTab::Overview => None,
+        DetailTab::Tasks => Some(TabUpdate::Tasks(TabData::Unavailable(reason))),
+        DetailTab::Servers => Some(TabUpdate::Servers(TabData::Unavailable(reason))),
+        DetailTab::Git => Some(TabUpdate::Commits(TabData::Unavailable(reason))),
+        DetailTab::Usage => Some(TabUpdate::Usage(TabData::Unavailable(reason))),
+    }
+}
+
+/// Queue of actions performed in order by a background worker
+pub struct ActionQueue {
+    sender: mpsc::UnboundedSender<Action>,
+    worker: tokio::task::JoinHandle<()>,
+}
+
+impl ActionQueue {
+    /// Start the worker; results are sent to the event loop as [`AppEvent::Action`]
+    pub fn spawn(mut sources: ActionSources, events: mpsc::UnboundedSender<AppEvent>) -> Self {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let worker = tokio::spawn(async move {
+            while let Some(action) = receiver.recv().await {
+                let result = sources.perform(action).await;
+                if events.send(AppEvent::Action(result)).is_err() {
+                    // The event loop is gone
+                    break;
+                }
+            }
+        });
+
+        Self { sender, worker }
+    }
+
+    /// Queue an action to be performed in the background
+    pub fn enqueue(&self, action: Action) {
+        let _ = self.sender.send(action);
+    }
+}
+
+impl Drop for ActionQueue {
+    fn drop(&mut self) {
+        self.worker.abort();
+    }
+}
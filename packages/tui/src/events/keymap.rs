@@ -0,0 +1,593 @@
+// ABOUTME: Customizable key bindings for screen shortcuts, with vim and emacs presets
+// ABOUTME: User overrides are loaded from keymap.toml in the Orkee directory
+
+use super::macros::KeyStroke;
+use crate::state::Screen;
+use crossterm::event::{KeyEvent, KeyModifiers};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+/// File in the Orkee directory holding the user's key bindings
+pub const KEYMAP_FILE: &str = "keymap.toml";
+
+/// Group of bindings that apply together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeymapScope {
+    /// Active on every screen
+    Global,
+    Chat,
+    Projects,
+    ProjectDetail,
+    Executions,
+}
+
+impl KeymapScope {
+    /// Scopes consulted on a screen, most specific first. The detail screen
+    /// inherits project actions it doesn't bind itself.
+    pub fn for_screen(screen: &Screen) -> &'static [KeymapScope] {
+        match screen {
+            Screen::Chat => &[KeymapScope::Chat, KeymapScope::Global],
+            Screen::Projects => &[KeymapScope::Projects, KeymapScope::Global],
+            Screen::ProjectDetail => &[
+                KeymapScope::ProjectDetail,
+                KeymapScope::Projects,
+                KeymapScope::Global,
+            ],
+            Screen::Executions => &[KeymapScope::Executions, KeymapScope::Global],
+        }
+    }
+
+    /// Table name in keymap.toml
+    pub fn name(self) -> &'static str {
+        match self {
+            KeymapScope::Global => "global",
+            KeymapScope::Chat => "chat",
+            KeymapScope::Projects => "projects",
+            KeymapScope::ProjectDetail => "project_detail",
+            KeymapScope::Executions => "executions",
+        }
+    }
+
+    /// Heading shown in the cheat sheet
+    pub fn title(self) -> &'static str {
+        match self {
+            KeymapScope::Global => "Global",
+            KeymapScope::Chat => "Chat",
+            KeymapScope::Projects => "Projects",
+            KeymapScope::ProjectDetail => "Project Detail",
+            KeymapScope::Executions => "Executions",
+        }
+    }
+}
+
+/// Something a bound key does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    Quit,
+    ToggleContextPane,
+    ShowCheatSheet,
+    YankMessage,
+    MoveUp,
+    MoveDown,
+    NewProject,
+    EditProject,
+    DeleteProject,
+    Search,
+    CopyProjectPath,
+    PreviousTab,
+    NextTab,
+    Refresh,
+    StartServer,
+    StopServer,
+    CopyServerUrl,
+    ToggleDetail,
+    CancelExecution,
+    RetryExecution,
+}
+
+impl KeyAction {
+    /// Key in keymap.toml
+    pub fn name(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "quit",
+            KeyAction::ToggleContextPane => "toggle_context_pane",
+            KeyAction::ShowCheatSheet => "show_cheat_sheet",
+            KeyAction::YankMessage => "yank_message",
+            KeyAction::MoveUp => "move_up",
+            KeyAction::MoveDown => "move_down",
+            KeyAction::NewProject => "new_project",
+            KeyAction::EditProject => "edit_project",
+            KeyAction::DeleteProject => "delete_project",
+            KeyAction::Search => "search",
+            KeyAction::CopyProjectPath => "copy_project_path",
+            KeyAction::PreviousTab => "previous_tab",
+            KeyAction::NextTab => "next_tab",
+            KeyAction::Refresh => "refresh",
+            KeyAction::StartServer => "start_server",
+            KeyAction::StopServer => "stop_server",
+            KeyAction::CopyServerUrl => "copy_server_url",
+            KeyAction::ToggleDetail => "toggle_detail",
+            KeyAction::CancelExecution => "cancel_execution",
+            KeyAction::RetryExecution => "retry_execution",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            KeyAction::Quit => "Quit Orkee",
+            KeyAction::ToggleContextPane => "Show or hide the context pane",
+            KeyAction::ShowCheatSheet => "Show this cheat sheet",
+            KeyAction::YankMessage => "Copy the selected message",
+            KeyAction::MoveUp => "Select the previous item",
+            KeyAction::MoveDown => "Select the next item",
+            KeyAction::NewProject => "Create a project",
+            KeyAction::EditProject => "Edit the selected project",
+            KeyAction::DeleteProject => "Delete the selected project",
+            KeyAction::Search => "Open or close project search",
+            KeyAction::CopyProjectPath => "Copy the project's path",
+            KeyAction::PreviousTab => "Previous tab",
+            KeyAction::NextTab => "Next tab",
+            KeyAction::Refresh => "Reload the current tab",
+            KeyAction::StartServer => "Start the dev server",
+            KeyAction::StopServer => "Stop the selected dev server",
+            KeyAction::CopyServerUrl => "Copy the dev server's URL",
+            KeyAction::ToggleDetail => "Show or hide execution logs",
+            KeyAction::CancelExecution => "Cancel the selected execution",
+            KeyAction::RetryExecution => "Retry the selected execution",
+        }
+    }
+}
+
+/// Every bindable action per scope, in the order the cheat sheet lists them
+const BINDABLE: &[(KeymapScope, KeyAction)] = &[
+    (KeymapScope::Global, KeyAction::Quit),
+    (KeymapScope::Global, KeyAction::ToggleContextPane),
+    (KeymapScope::Global, KeyAction::ShowCheatSheet),
+    (KeymapScope::Chat, KeyAction::YankMessage),
+    (KeymapScope::Projects, KeyAction::MoveUp),
+    (KeymapScope::Projects, KeyAction::MoveDown),
+    (KeymapScope::Projects, KeyAction::NewProject),
+    (KeymapScope::Projects, KeyAction::EditProject),
+    (KeymapScope::Projects, KeyAction::DeleteProject),
+    (KeymapScope::Projects, KeyAction::Search),
+    (KeymapScope::Projects, KeyAction::CopyProjectPath),
+    (KeymapScope::ProjectDetail, KeyAction::MoveUp),
+    (KeymapScope::ProjectDetail, KeyAction::MoveDown),
+    (KeymapScope::ProjectDetail, KeyAction::PreviousTab),
+    (KeymapScope::ProjectDetail, KeyAction::NextTab),
+    (KeymapScope::ProjectDetail, KeyAction::Refresh),
+    (KeymapScope::ProjectDetail, KeyAction::StartServer),
+    (KeymapScope::ProjectDetail, KeyAction::StopServer),
+    (KeymapScope::ProjectDetail, KeyAction::CopyServerUrl),
+    (KeymapScope::Executions, KeyAction::MoveUp),
+    (KeymapScope::Executions, KeyAction::MoveDown),
+    (KeymapScope::Executions, KeyAction::ToggleDetail),
+    (KeymapScope::Executions, KeyAction::CancelExecution),
+    (KeymapScope::Executions, KeyAction::RetryExecution),
+];
+
+/// Built-in set of bindings that user overrides are applied on top of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeymapPreset {
+    #[default]
+    Default,
+    /// Adds hjkl movement and `/` search
+    Vim,
+    /// Adds ctrl+p/n/b/f movement, ctrl+s search and alt+w copy
+    Emacs,
+}
+
+impl KeymapPreset {
+    fn keys(self, scope: KeymapScope, action: KeyAction) -> &'static [&'static str] {
+        use KeyAction::*;
+        use KeymapPreset::*;
+
+        // Lists only move on the project screen with the arrows by default;
+        // the detail and execution lists have always taken j/k as well
+        let list_screen = scope != KeymapScope::Projects;
+
+        match (action, self) {
+            (Quit, _) => &["q", "ctrl+d"],
+            (ToggleContextPane, _) => &["ctrl+l"],
+            (ShowCheatSheet, _) => &["?"],
+            (MoveUp, Default) if list_screen => &["up", "k"],
+            (MoveUp, Default) => &["up"],
+            (MoveUp, Vim) => &["up", "k"],
+            (MoveUp, Emacs) => &["up", "ctrl+p"],
+            (MoveDown, Default) if list_screen => &["down", "j"],
+            (MoveDown, Default) => &["down"],
+            (MoveDown, Vim) => &["down", "j"],
+            (MoveDown, Emacs) => &["down", "ctrl+n"],
+            (NewProject, _) => &["n"],
+            (EditProject, _) => &["e"],
+            (DeleteProject, _) => &["d"],
+            (Search, Default) => &["F"],
+            (Search, Vim) => &["F", "/"],
+            (Search, Emacs) => &["F", "ctrl+s"],
+            (PreviousTab, Default) => &["left"],
+            (PreviousTab, Vim) => &["left", "h"],
+            (PreviousTab, Emacs) => &["left", "ctrl+b"],
+            (NextTab, Default) => &["right"],
+            (NextTab, Vim) => &["right", "l"],
+            (NextTab, Emacs) => &["right", "ctrl+f"],
+            (Refresh, _) => &["r"],
+            (StartServer, _) => &["s"],
+            (StopServer, _) => &["x"],
+            (YankMessage | CopyProjectPath, Emacs) => &["y", "alt+w"],
+            (YankMessage | CopyProjectPath, _) => &["y"],
+            (CopyServerUrl, _) => &["u"],
+            (ToggleDetail, _) => &["enter"],
+            (CancelExecution, _) => &["c"],
+            (RetryExecution, _) => &["r"],
+        }
+    }
+}
+
+/// Errors from loading keymap.toml
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeymapError {
+    Read(String),
+    Parse(String),
+    UnknownAction { scope: String, action: String },
+}
+
+impl fmt::Display for KeymapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(e) => write!(f, "Couldn't read {}: {}", KEYMAP_FILE, e),
+            Self::Parse(e) => write!(f, "Invalid {}: {}", KEYMAP_FILE, e),
+            Self::UnknownAction { scope, action } => {
+                write!(f, "Unknown action '{}' in [{}]", action, scope)
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeymapError {}
+
+/// Contents of keymap.toml; each table maps action names to their keys
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct KeymapFile {
+    preset: KeymapPreset,
+    global: HashMap<String, Vec<KeyStroke>>,
+    chat: HashMap<String, Vec<KeyStroke>>,
+    projects: HashMap<String, Vec<KeyStroke>>,
+    project_detail: HashMap<String, Vec<KeyStroke>>,
+    executions: HashMap<String, Vec<KeyStroke>>,
+}
+
+impl KeymapFile {
+    fn overrides(&self, scope: KeymapScope) -> &HashMap<String, Vec<KeyStroke>> {
+        match scope {
+            KeymapScope::Global => &self.global,
+            KeymapScope::Chat => &self.chat,
+            KeymapScope::Projects => &self.projects,
+            KeymapScope::ProjectDetail => &self.project_detail,
+            KeymapScope::Executions => &self.executions,
+        }
+    }
+}
+
+/// A binding that is in effect on some screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActiveBinding<'a> {
+    pub scope: KeymapScope,
+    pub action: KeyAction,
+    pub keys: &'a [KeyStroke],
+}
+
+/// Keys bound to each action, per scope
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keymap {
+    bindings: HashMap<(KeymapScope, KeyAction), Vec<KeyStroke>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::preset(KeymapPreset::Default)
+    }
+}
+
+impl Keymap {
+    /// Bindings of a built-in preset
+    pub fn preset(preset: KeymapPreset) -> Self {
+        let bindings = BINDABLE
+            .iter()
+            .map(|&(scope, action)| {
+                let keys = preset
+                    .keys(scope, action)
+                    .iter()
+                    .map(|key| key.parse().expect("preset keys are valid"))
+                    .collect();
+                ((scope, action), keys)
+            })
+            .collect();
+        Self { bindings }
+    }
+
+    /// Load the keymap saved at `path`, using the default preset if there is none
+    pub fn load(path: &Path) -> Result<Self, KeymapError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::from_toml(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(KeymapError::Read(e.to_string())),
+        }
+    }
+
+    /// Parse keymap.toml: a preset plus per-scope tables whose entries replace
+    /// the preset's keys for an action. An empty list unbinds the action.
+    pub fn from_toml(content: &str) -> Result<Self, KeymapError> {
+        let file: KeymapFile =
+            toml::from_str(content).map_err(|e| KeymapError::Parse(e.to_string()))?;
+
+        let mut keymap = Self::preset(file.preset);
+        for scope in [
+            KeymapScope::Global,
+            KeymapScope::Chat,
+            KeymapScope::Projects,
+            KeymapScope::ProjectDetail,
+            KeymapScope::Executions,
+        ] {
+            for (name, keys) in file.overrides(scope) {
+                let action = BINDABLE
+                    .iter()
+                    .find(|&&(s, action)| s == scope && action.name() == name)
+                    .map(|&(_, action)| action)
+                    .ok_or_else(|| KeymapError::UnknownAction {
+                        scope: scope.name().to_string(),
+                        action: name.clone(),
+                    })?;
+                keymap.bindings.insert((scope, action), keys.clone());
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    /// Bindings in effect on a screen, most specific scope first. An action
+    /// bound in a more specific scope hides the same action further down.
+    pub fn active_bindings(&self, screen: &Screen) -> Vec<ActiveBinding<'_>> {
+        let scopes = KeymapScope::for_screen(screen);
+        let mut active: Vec<ActiveBinding<'_>> = Vec::new();
+
+        for &scope in scopes {
+            for &(binding_scope, action) in BINDABLE {
+                if binding_scope != scope || active.iter().any(|b| b.action == action) {
+                    continue;
+                }
+                let keys = self
+                    .bindings
+                    .get(&(scope, action))
+                    .map_or(&[][..], Vec::as_slice);
+                active.push(ActiveBinding {
+                    scope,
+                    action,
+                    keys,
+                });
+            }
+        }
+
+        active
+    }
+
+    /// Action bound to a key on a screen. While `typing`, plain keys belong to
+    /// the text being typed, so only global ctrl/alt bindings apply.
+    pub fn resolve(&self, screen: &Screen, event: &KeyEvent, typing: bool) -> Option<KeyAction> {
+        if typing
+            && !event
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return None;
+        }
+
+        self.active_bindings(screen)
+            .into_iter()
+            .filter(|binding| !typing || binding.scope == KeymapScope::Global)
+            .find(|binding| binding.keys.iter().any(|key| key.matches(event)))
+            .map(|binding| binding.action)
+    }
+
+    /// Keys for an action on a screen, for hints like "press F to search"
+    pub fn keys_for(&self, screen: &Screen, action: KeyAction) -> &[KeyStroke] {
+        self.active_bindings(screen)
+            .into_iter()
+            .find(|binding| binding.action == action)
+            .map_or(&[][..], |binding| binding.keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyCode;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    fn char_key(c: char) -> KeyEvent {
+        key(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn test_default_preset_matches_builtin_keys() {
+        let keymap = Keymap::default();
+
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('n'), false),
+            Some(KeyAction::NewProject)
+        );
+        assert_eq!(
+            keymap.resolve(
+                &Screen::Projects,
+                &key(KeyCode::Char('F'), KeyModifiers::SHIFT),
+                false
+            ),
+            Some(KeyAction::Search)
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::Executions, &char_key('r'), false),
+            Some(KeyAction::RetryExecution)
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::ProjectDetail, &char_key('r'), false),
+            Some(KeyAction::Refresh)
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('k'), false),
+            None
+        );
+        assert_eq!(
+            keymap.resolve(
+                &Screen::Chat,
+                &key(KeyCode::Char('?'), KeyModifiers::SHIFT),
+                false
+            ),
+            Some(KeyAction::ShowCheatSheet)
+        );
+    }
+
+    #[test]
+    fn test_presets() {
+        let vim = Keymap::preset(KeymapPreset::Vim);
+        assert_eq!(
+            vim.resolve(&Screen::Projects, &char_key('k'), false),
+            Some(KeyAction::MoveUp)
+        );
+        assert_eq!(
+            vim.resolve(&Screen::ProjectDetail, &char_key('l'), false),
+            Some(KeyAction::NextTab)
+        );
+        assert_eq!(
+            vim.resolve(&Screen::Projects, &char_key('/'), false),
+            Some(KeyAction::Search)
+        );
+
+        let emacs = Keymap::preset(KeymapPreset::Emacs);
+        assert_eq!(
+            emacs.resolve(
+                &Screen::Executions,
+                &key(KeyCode::Char('n'), KeyModifiers::CONTROL),
+                false
+            ),
+            Some(KeyAction::MoveDown)
+        );
+        assert_eq!(
+            emacs.resolve(&Screen::Executions, &char_key('j'), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_overrides_replace_preset_keys() {
+        let keymap = Keymap::from_toml(
+            r#"
+            preset = "vim"
+
+            [projects]
+            new_project = ["a", "ctrl+n"]
+            delete_project = []
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('a'), false),
+            Some(KeyAction::NewProject)
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('n'), false),
+            None
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('d'), false),
+            None
+        );
+        // The preset still applies to everything not overridden
+        assert_eq!(
+            keymap.resolve(&Screen::Projects, &char_key('j'), false),
+            Some(KeyAction::MoveDown)
+        );
+    }
+
+    #[test]
+    fn test_invalid_files_are_rejected() {
+        assert!(matches!(
+            Keymap::from_toml("[projects]\nlaunch_rockets = [\"l\"]"),
+            Err(KeymapError::UnknownAction { .. })
+        ));
+        // Actions are only bindable in the scopes that have them
+        assert!(matches!(
+            Keymap::from_toml("[global]\nnew_project = [\"n\"]"),
+            Err(KeymapError::UnknownAction { .. })
+        ));
+        assert!(matches!(
+            Keymap::from_toml("[projects]\nnew_project = [\"hyper+n\"]"),
+            Err(KeymapError::Parse(_))
+        ));
+        assert!(matches!(
+            Keymap::from_toml("preset = \"nano\""),
+            Err(KeymapError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_detail_screen_inherits_project_actions() {
+        let keymap = Keymap::from_toml("[project_detail]\nmove_up = [\"w\"]").unwrap();
+
+        assert_eq!(
+            keymap.resolve(&Screen::ProjectDetail, &char_key('e'), false),
+            Some(KeyAction::EditProject)
+        );
+        // The detail screen's own move_up hides the project list's
+        assert_eq!(
+            keymap.resolve(
+                &Screen::ProjectDetail,
+                &key(KeyCode::Up, KeyModifiers::NONE),
+                false
+            ),
+            None
+        );
+        assert_eq!(
+            keymap.resolve(&Screen::ProjectDetail, &char_key('w'), false),
+            Some(KeyAction::MoveUp)
+        );
+    }
+
+    #[test]
+    fn test_typing_only_allows_global_modifier_bindings() {
+        let keymap = Keymap::preset(KeymapPreset::Emacs);
+
+        assert_eq!(keymap.resolve(&Screen::Chat, &char_key('q'), true), None);
+        assert_eq!(
+            keymap.resolve(
+                &Screen::Chat,
+                &key(KeyCode::Char('d'), KeyModifiers::CONTROL),
+                true
+            ),
+            Some(KeyAction::Quit)
+        );
+        assert_eq!(
+            keymap.resolve(
+                &Screen::Projects,
+                &key(KeyCode::Char('p'), KeyModifiers::CONTROL),
+                true
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_missing_file_uses_default() {
+        let dir = std::env::temp_dir().join(format!("orkee-keymap-{}", uuid::Uuid::new_v4()));
+        assert_eq!(
+            Keymap::load(&dir.join(KEYMAP_FILE)).unwrap(),
+            Keymap::default()
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// System setting holding the saved macros as JSON
+pub const MACROS_SETTING_KEY: &str = "tui_macros";
+
+/// Longest macro that can be recorded, so a forgotten recording can't grow forever
+pub const MAX_MACRO_KEYS: usize = 500;
+
+/// Keys the TUI already uses; binding a macro to them would shadow their action
+const RESERVED_BINDINGS: &[&str] = &["ctrl+c", "ctrl+d", "ctrl+j", "ctrl+l", "ctrl+r"];
+
+/// A single keystroke as stored in a macro, written like `ctrl+r`, `enter` or `f5`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyStroke {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyStroke {
+    /// Capture a key event, or `None` for keys macros can't replay (media keys, etc.)
+    pub fn from_event(event: &KeyEvent) -> Option<Self> {
+        Self::new(event.code, event.modifiers)
+    }
+
+    fn new(code: KeyCode, modifiers: KeyModifiers) -> Option<Self> {
+        let mut modifiers =
+            modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+
+        match code {
+            // Shift is already part of the character or of BackTab
+            KeyCode::Char(_) | KeyCode::BackTab => modifiers.remove(KeyModifiers::SHIFT),
+            KeyCode::Enter
+            | KeyCode::Esc
+            | KeyCode::Tab
+            | KeyCode::Backspace
+            | KeyCode::Delete
+            | KeyCode::Insert
+            | KeyCode::Up
+            | KeyCode::Down
+            | KeyCode::Left
+            | KeyCode::Right
+            | KeyCode::Home
+            | KeyCode::End
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::F(_) => {}
+            _ => return None,
+        }
+
+        Some(Self { code, modifiers })
+    }
+
+    /// Key event to feed back through the key handler on replay
+    pub fn to_event(self) -> KeyEvent {
+        KeyEvent::new(self.code, self.modifiers)
+    }
+
+    /// Check if a key event is this keystroke
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        Self::from_event(event).is_some_and(|stroke| stroke == *self)
+    }
+
+    /// Whether a macro may be bound to this key. Plain typing keys and keys the
+    /// TUI already uses are refused so bindings never get in the way of input.
+    pub fn is_bindable(&self) -> bool {
+        let uses_modifier = self
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        let is_function_key = matches!(self.code, KeyCode::F(_));
+
+        (uses_modifier || is_function_key)
+            && !RESERVED_BINDINGS.contains(&self.to_string().as_str())
+    }
+}
+
+impl fmt::Display for KeyStroke {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "shift+")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "space"),
+            KeyCode::Char('+') => write!(f, "plus"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::F(n) => write!(f, "f{}", n),
+            KeyCode::Enter => write!(f, "enter"),
+            KeyCode::Esc => write!(f, "esc"),
+            KeyCode::Tab => write!(f, "tab"),
+            KeyCode::BackTab => write!(f, "backtab"),
+            KeyCode::Backspace => write!(f, "backspace"),
+            KeyCode::Delete => write!(f, "delete"),
+            KeyCode::Insert => write!(f, "insert"),
+            KeyCode::Up => write!(f, "up"),
+            KeyCode::Down => write!(f, "down"),
+            KeyCode::Left => write!(f, "left"),
+            KeyCode::Right => write!(f, "right"),
+            KeyCode::Home => write!(f, "home"),
+            KeyCode::End => write!(f, "end"),
+            KeyCode::PageUp => write!(f, "pageup"),
+            KeyCode::PageDown => write!(f, "pagedown"),
+            // Never constructed, see `KeyStroke::new`
+            _ => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for KeyStroke {
+    type Err = MacroError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || MacroError::InvalidKey(value.to_string());
+
+        let mut parts: Vec<&str> = value.split('+').collect();
+        let key = parts
+            .pop()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(invalid)?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_lowercase().as_str() {
+                "space" => KeyCode::Char(' '),
+                "plus" => KeyCode::Char('+'),
+                "enter" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => name
+                    .strip_prefix('f')
+                    .and_then(|n| n.parse::<u8>().ok())
+                    .filter(|n| (1..=24).contains(n))
+                    .map(KeyCode::F)
+                    .ok_or_else(invalid)?,
+            },
+        };
+
+        Self::new(code, modifiers).ok_or_else(invalid)
+    }
+}
+
+impl TryFrom<String> for KeyStroke {
+    type Error = MacroError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<KeyStroke> for String {
+    fn from(stroke: KeyStroke) -> Self {
+        stroke.to_string()
+    }
+}
+
+/// A named sequence of keystrokes, optionally bound to a key that replays it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputMacro {
+    pub name: String,
+    pub binding: Option<KeyStroke>,
+    pub keys: Vec<KeyStroke>,
+}
+
+/// Errors from recording, binding, or loading macros
+#[derive(Debug, Clone, PartialEq)]
+pub enum MacroError {
+    NotFound(String),
+    InvalidKey(String),
+    UnbindableKey(String),
+    BindingInUse { key: String, name: String },
+    Empty,
+    TooLong,
+}
+
+impl fmt::Display for MacroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(name) => write!(f, "No macro named '{}'", name),
+            Self::InvalidKey(key) => write!(f, "Unknown key '{}'", key),
+            Self::UnbindableKey(key) => write!(
+                f,
+                "Can't bind '{}': use a function key or a ctrl/alt combination the TUI doesn't already use",
+                key
+            ),
+            Self::BindingInUse { key, name } => {
+                write!(f, "'{}' already replays macro '{}'", key, name)
+            }
+            Self::Empty => write!(f, "No keys were recorded"),
+            Self::TooLong => write!(
+                f,
+                "Macros can hold at most {} keystrokes",
+                MAX_MACRO_KEYS
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MacroError {}
+
+/// Keystrokes captured while a macro is being recorded
+#[derive(Debug, Clone)]
+pub struct MacroRecorder {
+    name: String,
+    keys: Vec<KeyStroke>,
+}
+
+impl MacroRecorder {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            keys: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Record a keystroke. Keys macros can't replay are skipped.
+    pub fn record(&mut self, event: &KeyEvent) -> Result<(), MacroError> {
+        if self.keys.len() >= MAX_MACRO_KEYS {
+            return Err(MacroError::TooLong);
+        }
+        if let Some(stroke) = KeyStroke::from_event(event) {
+            self.keys.push(stroke);
+        }
+        Ok(())
+    }
+
+    /// Finish recording, producing an unbound macro
+    pub fn finish(self) -> Result<InputMacro, MacroError> {
+        if self.keys.is_empty() {
+            return Err(MacroError::Empty);
+        }
+        Ok(InputMacro {
+            name: self.name,
+            binding: None,
+            keys: self.keys,
+        })
+    }
+}
+
+/// The user's saved macros, persisted as JSON in the `tui_macros` setting
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MacroSet {
+    macros: Vec<InputMacro>,
+}
+
+impl MacroSet {
+    /// Parse the setting value
+    pub fn from_setting_value(value: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            macros: serde_json::from_str(value)?,
+        })
+    }
+
+    /// Value to store in the setting
+    pub fn to_setting_value(&self) -> String {
+        serde_json::to_string(&self.macros).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &InputMacro> {
+        self.macros.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.macros.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&InputMacro> {
+        self.macros.iter().find(|m| m.name == name)
+    }
+
+    /// Add a macro. Re-recording an existing macro keeps its key binding.
+    pub fn insert(&mut self, mut input_macro: InputMacro) {
+        match self.macros.iter_mut().find(|m| m.name == input_macro.name) {
+            Some(existing) => {
+                input_macro.binding = input_macro.binding.or(existing.binding);
+                *existing = input_macro;
+            }
+            None => self.macros.push(input_macro),
+        }
+    }
+
+    /// Bind a macro to a key, replacing its previous binding
+    pub fn bind(&mut self, name: &str, key: KeyStroke) -> Result<(), MacroError> {
+        if !key.is_bindable() {
+            return Err(MacroError::UnbindableKey(key.to_string()));
+        }
+        if let Some(other) = self
+            .macros
+            .iter()
+            .find(|m| m.name != name && m.binding == Some(key))
+        {
+            return Err(MacroError::BindingInUse {
+                key: key.to_string(),
+                name: other.name.clone(),
+            });
+        }
+
+        let input_macro = self
+            .macros
+            .iter_mut()
+            .find(|m| m.name == name)
+            .ok_or_else(|| MacroError::NotFound(name.to_string()))?;
+        input_macro.binding = Some(key);
+        Ok(())
+    }
+
+    /// Delete a macro, returning whether it existed
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.macros.len();
+        self.macros.retain(|m| m.name != name);
+        self.macros.len() != before
+    }
+
+    /// Macro bound to the pressed key, if any
+    pub fn find_by_binding(&self, event: &KeyEvent) -> Option<&InputMacro> {
+        self.macros
+            .iter()
+            .find(|m| m.binding.is_some_and(|binding| binding.matches(event)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent::new(code, modifiers)
+    }
+
+    #[test]
+    fn test_keystroke_round_trip() {
+        for name in [
+            "a",
+            "A",
+            "ctrl+r",
+            "alt+shift+up",
+            "f5",
+            "space",
+            "plus",
+            "enter",
+        ] {
+            let stroke: KeyStroke = name.parse().unwrap();
+            assert_eq!(stroke.to_string(), name);
+        }
+
+        assert!("hyper+a".parse::<KeyStroke>().is_err());
+        assert!("f99".parse::<KeyStroke>().is_err());
+        assert!("ctrl+".parse::<KeyStroke>().is_err());
+    }
+
+    #[test]
+    fn test_keystroke_ignores_shift_on_characters() {
+        let stroke = KeyStroke::from_event(&key(KeyCode::Char('A'), KeyModifiers::SHIFT)).unwrap();
+        assert_eq!(stroke.to_string(), "A");
+        assert!(stroke.matches(&key(KeyCode::Char('A'), KeyModifiers::NONE)));
+    }
+
+    #[test]
+    fn test_bindable_keys() {
+        let bindable = |name: &str| name.parse::<KeyStroke>().unwrap().is_bindable();
+
+        assert!(bindable("f5"));
+        assert!(bindable("ctrl+t"));
+        assert!(bindable("alt+1"));
+        assert!(!bindable("a"));
+        assert!(!bindable("enter"));
+        assert!(!bindable("ctrl+c"));
+        assert!(!bindable("ctrl+r"));
+    }
+
+    #[test]
+    fn test_record_and_bind() {
+        let mut recorder = MacroRecorder::new("triage");
+        recorder
+            .record(&key(KeyCode::Down, KeyModifiers::NONE))
+            .unwrap();
+        recorder
+            .record(&key(KeyCode::Enter, KeyModifiers::NONE))
+            .unwrap();
+
+        let mut macros = MacroSet::default();
+        macros.insert(recorder.finish().unwrap());
+        macros.bind("triage", "f5".parse().unwrap()).unwrap();
+
+        let replayed = macros
+            .find_by_binding(&key(KeyCode::F(5), KeyModifiers::NONE))
+            .unwrap();
+        assert_eq!(replayed.name, "triage");
+        assert_eq!(replayed.keys.len(), 2);
+
+        assert_eq!(
+            macros.bind("missing", "f6".parse().unwrap()),
+            Err(MacroError::NotFound("missing".to_string()))
+        );
+        assert!(matches!(
+            macros.bind("triage", "x".parse().unwrap()),
+            Err(MacroError::UnbindableKey(_))
+        ));
+    }
+
+    #[test]
+    fn test_binding_conflicts_and_rerecording() {
+        let mut macros = MacroSet::default();
+        for name in ["one", "two"] {
+            let mut recorder = MacroRecorder::new(name);
+            recorder
+                .record(&key(KeyCode::Char('x'), KeyModifiers::NONE))
+                .unwrap();
+            macros.insert(recorder.finish().unwrap());
+        }
+        macros.bind("one", "f2".parse().unwrap()).unwrap();
+
+        assert!(matches!(
+            macros.bind("two", "f2".parse().unwrap()),
+            Err(MacroError::BindingInUse { .. })
+        ));
+
+        // Recording over a macro keeps the key it was bound to
+        let mut recorder = MacroRecorder::new("one");
+        recorder
+            .record(&key(KeyCode::Char('y'), KeyModifiers::NONE))
+            .unwrap();
+        macros.insert(recorder.finish().unwrap());
+        assert_eq!(
+            macros.get("one").unwrap().binding,
+            Some("f2".parse().unwrap())
+        );
+        assert_eq!(macros.get("one").unwrap().keys.len(), 1);
+
+        assert!(macros.remove("one"));
+        assert!(!macros.remove("one"));
+    }
+
+    #[test]
+    fn test_recording_limits() {
+        assert_eq!(MacroRecorder::new("empty").finish(), Err(MacroError::Empty));
+
+        let mut recorder = MacroRecorder::new("long");
+        for _ in 0..MAX_MACRO_KEYS {
+            recorder
+                .record(&key(KeyCode::Char('x'), KeyModifiers::NONE))
+                .unwrap();
+        }
+        assert_eq!(
+            recorder.record(&key(KeyCode::Char('x'), KeyModifiers::NONE)),
+            Err(MacroError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_setting_value_round_trip() {
+        let value = r#"[{"name":"triage","binding":"ctrl+t","keys":["down","enter","s"]}]"#;
+        let macros = MacroSet::from_setting_value(value).unwrap();
+
+        let triage = macros.get("triage").unwrap();
+        assert_eq!(triage.binding, Some("ctrl+t".parse().unwrap()));
+        assert_eq!(triage.keys[2].to_event().code, KeyCode::Char('s'));
+        assert_eq!(macros.to_setting_value(), value);
+
+        assert!(
+            MacroSet::from_setting_value(r#"[{"name":"x","binding":null,"keys":["bogus"]}]"#)
+                .is_err()
+        );
+    }
+}
@@ -1,13 +1,25 @@
+pub mod actions;
+pub mod keymap;
+pub mod macros;
+
 use crossterm::event::{self, Event, KeyEvent};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+pub use actions::{
+    Action, ActionQueue, ActionResult, ActionSources, ProjectSaveError, ProjectSaveOrigin,
+};
+pub use keymap::{KeyAction, Keymap, KeymapError, KeymapPreset, KeymapScope};
+pub use macros::{InputMacro, KeyStroke, MacroError, MacroRecorder, MacroSet};
+
 /// Event types for the TUI application
 #[derive(Debug, Clone)]
 pub enum AppEvent {
     Key(KeyEvent),
     Tick,
     Refresh,
+    /// A queued action finished in the background
+    Action(ActionResult),
     Quit,
 }
 
@@ -0,0 +1,544 @@
+// ABOUTME: Execution monitor state and data loading for agent and sandbox executions
+// ABOUTME: Polls recent agent executions, and starts new ones from `/execute` with a live chat card
+
+use chrono::{DateTime, Utc};
+use orkee_executions::{
+    AgentExecution, AgentExecutionCreateInput, AgentExecutionUpdateInput, ExecutionStatus,
+    ExecutionStorage,
+};
+use orkee_models::{Agent, REGISTRY};
+use orkee_projects::Project;
+use orkee_sandbox::{SandboxExecution, SandboxStorage, SandboxTemplate};
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::{Task, TaskStatus};
+use sqlx::SqlitePool;
+
+/// Number of agent executions listed on the monitor
+const RECENT_EXECUTION_LIMIT: i64 = 100;
+
+/// Execution metadata key recording the sandbox template picked with `/execute`
+pub const SANDBOX_TEMPLATE_METADATA_KEY: &str = "sandboxTemplateId";
+
+/// Freshly fetched executions, and the logs of the one that was open
+#[derive(Debug, Clone)]
+pub struct ExecutionsUpdate {
+    pub executions: Result<Vec<AgentExecution>, String>,
+    /// Sandbox commands of the execution whose logs were open, by execution id
+    pub runs: Option<(String, Vec<SandboxExecution>)>,
+}
+
+/// State of the execution monitor screen
+#[derive(Debug, Default)]
+pub struct ExecutionsState {
+    /// Most recent agent executions, newest first
+    pub executions: Vec<AgentExecution>,
+    pub selected: usize,
+    /// Whether the detail pane with logs is shown for the selected execution
+    pub detail_open: bool,
+    /// Sandbox commands run by the selected execution, oldest first
+    pub runs: Vec<SandboxExecution>,
+    /// Set when the last refresh failed
+    pub error: Option<String>,
+}
+
+impl ExecutionsState {
+    pub fn selected_execution(&self) -> Option<&AgentExecution> {
+        self.executions.get(self.selected)
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.selected > 0 {
+            self.selected -= 1;
+            self.runs.clear();
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.executions.len() {
+            self.selected += 1;
+            self.runs.clear();
+        }
+    }
+
+    pub fn toggle_detail(&mut self) {
+        self.detail_open = !self.detail_open && !self.executions.is_empty();
+    }
+
+    /// Replace the execution list, keeping the same execution selected when it's still listed
+    fn set_executions(&mut self, executions: Vec<AgentExecution>) {
+        let selected_id = self
+            .selected_execution()
+            .map(|execution| execution.id.clone());
+        self.executions = executions;
+        self.selected = selected_id
+            .and_then(|id| self.executions.iter().position(|e| e.id == id))
+            .unwrap_or(0);
+        if self.executions.is_empty() {
+            self.detail_open = false;
+            self.runs.clear();
+        }
+    }
+
+    /// Id of the execution whose logs are shown, if the detail pane is open
+    pub fn open_execution_id(&self) -> Option<String> {
+        self.selected_execution()
+            .filter(|_| self.detail_open)
+            .map(|execution| execution.id.clone())
+    }
+
+    /// Store fetched executions, dropping logs fetched for an execution no longer open
+    pub fn apply(&mut self, update: ExecutionsUpdate) {
+        match update.executions {
+            Ok(executions) => {
+                self.set_executions(executions);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+
+        if let Some((execution_id, runs)) = update.runs {
+            if self.open_execution_id().as_deref() == Some(execution_id.as_str()) {
+                self.runs = runs;
+            }
+        }
+    }
+
+    /// Whether anything shown is still running and worth polling for
+    pub fn has_active(&self) -> bool {
+        self.executions.iter().any(is_cancellable)
+    }
+}
+
+/// Whether an execution can be cancelled from the monitor
+pub fn is_cancellable(execution: &AgentExecution) -> bool {
+    matches!(
+        execution.status,
+        ExecutionStatus::Queued | ExecutionStatus::Running
+    )
+}
+
+/// Whether an execution can be retried from the monitor
+pub fn is_retryable(execution: &AgentExecution) -> bool {
+    matches!(
+        execution.status,
+        ExecutionStatus::Failed | ExecutionStatus::Cancelled
+    )
+}
+
+/// Tasks, agents, and sandbox templates `/execute` picks from
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOptions {
+    /// Tasks of every project that are still open
+    pub tasks: Vec<Task>,
+    /// Available agents, by name
+    pub agents: Vec<Agent>,
+    /// Sandbox templates, built-in ones first
+    pub templates: Vec<SandboxTemplate>,
+}
+
+impl LaunchOptions {
+    /// Open tasks of a project
+    pub fn project_tasks<'a>(&'a self, project_id: &'a str) -> impl Iterator<Item = &'a Task> {
+        self.tasks
+            .iter()
+            .filter(move |task| task.project_id == project_id)
+    }
+
+    /// What to start for validated `/execute` arguments: project, task, agent, and template ids
+    pub fn request(&self, args: &[String]) -> Option<LaunchRequest> {
+        let [_, task_id, agent_id, template_id] = args else {
+            return None;
+        };
+        let task = self.tasks.iter().find(|task| &task.id == task_id)?;
+        let agent = self.agents.iter().find(|agent| &agent.id == agent_id)?;
+        let template = self
+            .templates
+            .iter()
+            .find(|template| &template.id == template_id)?;
+
+        Some(LaunchRequest {
+            task_id: task.id.clone(),
+            task_title: task.title.clone(),
+            agent_id: agent.id.clone(),
+            agent_name: agent.name.clone(),
+            template_id: template.id.clone(),
+            template_name: template.name.clone(),
+        })
+    }
+}
+
+/// An execution to start, with the names shown on its status card
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchRequest {
+    pub task_id: String,
+    pub task_title: String,
+    pub agent_id: String,
+    pub agent_name: String,
+    pub template_id: String,
+    pub template_name: String,
+}
+
+/// Live status of an execution started from the chat, shown as a message that updates in place
+#[derive(Debug, Clone)]
+pub struct ExecutionCard {
+    /// Chat message showing the card
+    pub message_id: String,
+    pub request: LaunchRequest,
+    /// Latest state of the execution
+    pub execution: AgentExecution,
+}
+
+impl ExecutionCard {
+    /// Whether the execution is still running and worth polling for
+    pub fn is_active(&self) -> bool {
+        is_cancellable(&self.execution)
+    }
+
+    /// Text of the card's chat message
+    pub fn content(&self, now: DateTime<Utc>) -> String {
+        let execution = &self.execution;
+        let (icon, status) = match execution.status {
+            ExecutionStatus::Queued => ("🕒", "Queued"),
+            ExecutionStatus::Running => ("⏳", "Running"),
+            ExecutionStatus::Completed => ("✅", "Completed"),
+            ExecutionStatus::Failed => ("❌", "Failed"),
+            ExecutionStatus::Cancelled => ("🚫", "Cancelled"),
+        };
+        let elapsed = execution.completed_at.unwrap_or(now) - execution.started_at;
+
+        let mut lines = vec![
+            format!("🚀 **Execution** `{}` · {} {}", execution.id, icon, status),
+            format!("**Task:** {}", self.request.task_title),
+            format!(
+                "**Agent:** {} • **Template:** {}",
+                self.request.agent_name, self.request.template_name
+            ),
+        ];
+        match (&execution.status, execution.not_before) {
+            (ExecutionStatus::Queued, Some(not_before)) => lines.push(format!(
+                "**Starts:** {}",
+                not_before
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+            )),
+            (ExecutionStatus::Queued, None) => lines.push(format!(
+                "**Waiting for:** {}",
+                execution
+                    .concurrency_class
+                    .as_deref()
+                    .unwrap_or("a free slot")
+            )),
+            _ => lines.push(format!(
+                "**Elapsed:** {}",
+                format_elapsed(elapsed.num_seconds())
+            )),
+        }
+        if let Some(cost) = execution.total_cost {
+            lines.push(format!("**Cost:** ${:.2}", cost));
+        }
+        if let Some(url) = &execution.pr_url {
+            lines.push(format!("**Pull request:** {}", url));
+        }
+        if let Some(error) = &execution.error_message {
+            lines.push(format!("**Error:** {}", error));
+        }
+        if self.is_active() {
+            lines.push("💡 *Open `/executions` for sandbox logs or to cancel.*".to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+/// Elapsed seconds as `1h 2m 3s`, leaving out leading zero units
+fn format_elapsed(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    match (hours, minutes) {
+        (0, 0) => format!("{}s", seconds),
+        (0, _) => format!("{}m {}s", minutes, seconds),
+        _ => format!("{}h {}m {}s", hours, minutes, seconds),
+    }
+}
+
+/// Reads and updates executions in the Orkee database for the monitor
+pub struct ExecutionSource {
+    executions: ExecutionStorage,
+    sandboxes: SandboxStorage,
+    tasks: TaskStorage,
+}
+
+impl ExecutionSource {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            executions: ExecutionStorage::new(pool.clone()),
+            sandboxes: SandboxStorage::new(pool.clone()),
+            tasks: TaskStorage::new(pool),
+        }
+    }
+
+    /// Fetch the execution list, and the logs of the execution whose detail pane is open
+    pub async fn fetch(&self, open_execution_id: Option<String>) -> ExecutionsUpdate {
+        let executions = self
+            .executions
+            .list_recent_executions(RECENT_EXECUTION_LIMIT)
+            .await
+            .map_err(|e| e.to_string());
+
+        let mut runs = None;
+        if let Some(id) = open_execution_id {
+            let execution_runs = self
+                .sandboxes
+                .list_executions_for_agent(&id)
+                .await
+                .unwrap_or_default();
+            runs = Some((id, execution_runs));
+        }
+
+        ExecutionsUpdate { executions, runs }
+    }
+
+    /// Cancel a running execution and the sandbox commands it started
+    pub async fn cancel(&self, execution_id: &str) -> Result<bool, String> {
+        let cancelled = self
+            .executions
+            .cancel_execution(execution_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        if cancelled {
+            self.sandboxes
+                .cancel_executions_for_agent(execution_id)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+        Ok(cancelled)
+    }
+
+    /// Start a new attempt of a finished execution
+    pub async fn retry(&self, execution_id: &str) -> Result<AgentExecution, String> {
+        self.executions
+            .retry_execution(execution_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Load what `/execute` offers. Tasks of projects that fail to load are left out.
+    pub async fn launch_options(&self, projects: &[Project]) -> Result<LaunchOptions, String> {
+        let mut tasks = Vec::new();
+        for project in projects {
+            let project_tasks = self.tasks.list_tasks(&project.id).await.unwrap_or_default();
+            tasks.extend(
+                project_tasks.into_iter().filter(|task| {
+                    !matches!(task.status, TaskStatus::Done | TaskStatus::Cancelled)
+                }),
+            );
+        }
+
+        let mut agents: Vec<Agent> = REGISTRY
+            .list_agents()
+            .into_iter()
+            .filter(|agent| agent.is_available)
+            .cloned()
+            .collect();
+        agents.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let templates = self
+            .sandboxes
+            .list_templates()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(LaunchOptions {
+            tasks,
+            agents,
+            templates,
+        })
+    }
+
+    /// Start an execution of a task, recording the sandbox template it runs in
+    pub async fn launch(&self, request: &LaunchRequest) -> Result<AgentExecution, String> {
+        let execution = self
+            .executions
+            .create_execution(AgentExecutionCreateInput {
+                task_id: request.task_id.clone(),
+                agent_id: Some(request.agent_id.clone()),
+                model: None,
+                prompt: None,
+                retry_attempt: None,
+                not_before: None,
+                concurrency_class: None,
+            })
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let metadata = serde_json::json!({ SANDBOX_TEMPLATE_METADATA_KEY: request.template_id });
+        self.executions
+            .update_execution(
+                &execution.id,
+                AgentExecutionUpdateInput {
+                    metadata: Some(metadata),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// Fetch the executions shown on status cards, leaving out any that can't be read
+    pub async fn fetch_by_id(&self, execution_ids: &[String]) -> Vec<AgentExecution> {
+        let mut executions = Vec::new();
+        for id in execution_ids {
+            if let Ok(execution) = self.executions.get_execution(id).await {
+                executions.push(execution);
+            }
+        }
+        executions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn execution(id: &str, status: ExecutionStatus) -> AgentExecution {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "task_id": "task-1",
+            "agent_id": null,
+            "model": null,
+            "started_at": Utc::now(),
+            "completed_at": null,
+            "status": status,
+            "execution_time_seconds": null,
+            "tokens_input": null,
+            "tokens_output": null,
+            "total_cost": null,
+            "prompt": null,
+            "response": null,
+            "error_message": null,
+            "retry_attempt": 0,
+            "files_changed": null,
+            "lines_added": null,
+            "lines_removed": null,
+            "files_created": null,
+            "files_modified": null,
+            "files_deleted": null,
+            "branch_name": null,
+            "commit_hash": null,
+            "commit_message": null,
+            "pr_number": null,
+            "pr_url": null,
+            "pr_title": null,
+            "pr_status": null,
+            "pr_created_at": null,
+            "pr_merged_at": null,
+            "pr_merge_commit": null,
+            "review_status": null,
+            "review_comments": null,
+            "test_results": null,
+            "performance_metrics": null,
+            "metadata": null,
+            "created_at": Utc::now(),
+            "updated_at": Utc::now(),
+        }))
+        .unwrap()
+    }
+
+    fn run(agent_execution_id: &str) -> SandboxExecution {
+        serde_json::from_value(serde_json::json!({
+            "id": "run-1",
+            "sandbox_id": "sandbox-1",
+            "command": "cargo test",
+            "working_directory": "/workspace",
+            "status": "completed",
+            "started_at": null,
+            "completed_at": null,
+            "exit_code": 0,
+            "stdout": "ok",
+            "stderr": null,
+            "cpu_time_seconds": null,
+            "memory_peak_mb": null,
+            "created_at": Utc::now(),
+            "created_by": null,
+            "agent_execution_id": agent_execution_id,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_refresh_keeps_selected_execution() {
+        let mut state = ExecutionsState::default();
+        state.set_executions(vec![
+            execution("exec-b", ExecutionStatus::Running),
+            execution("exec-a", ExecutionStatus::Failed),
+        ]);
+        state.select_next();
+        assert_eq!(state.selected_execution().unwrap().id, "exec-a");
+
+        // A new execution shows up at the top
+        state.set_executions(vec![
+            execution("exec-c", ExecutionStatus::Running),
+            execution("exec-b", ExecutionStatus::Completed),
+            execution("exec-a", ExecutionStatus::Failed),
+        ]);
+        assert_eq!(state.selected, 2);
+        assert_eq!(state.selected_execution().unwrap().id, "exec-a");
+    }
+
+    #[test]
+    fn test_actions_depend_on_status() {
+        let running = execution("exec-a", ExecutionStatus::Running);
+        let failed = execution("exec-b", ExecutionStatus::Failed);
+        let completed = execution("exec-c", ExecutionStatus::Completed);
+
+        assert!(is_cancellable(&running) && !is_retryable(&running));
+        assert!(!is_cancellable(&failed) && is_retryable(&failed));
+        assert!(!is_cancellable(&completed) && !is_retryable(&completed));
+    }
+
+    #[test]
+    fn test_logs_only_apply_to_open_execution() {
+        let mut state = ExecutionsState::default();
+        let executions = vec![
+            execution("exec-b", ExecutionStatus::Running),
+            execution("exec-a", ExecutionStatus::Failed),
+        ];
+        state.set_executions(executions.clone());
+        state.toggle_detail();
+
+        // Logs fetched for exec-a arrive while exec-b is open
+        state.apply(ExecutionsUpdate {
+            executions: Ok(executions.clone()),
+            runs: Some(("exec-a".to_string(), vec![run("exec-a")])),
+        });
+        assert_eq!(state.open_execution_id().as_deref(), Some("exec-b"));
+        assert!(state.runs.is_empty());
+
+        state.apply(ExecutionsUpdate {
+            executions: Ok(executions),
+            runs: Some(("exec-b".to_string(), vec![run("exec-b")])),
+        });
+        assert_eq!(state.runs.len(), 1);
+
+        state.apply(ExecutionsUpdate {
+            executions: Err("database is locked".to_string()),
+            runs: None,
+        });
+        assert_eq!(state.error.as_deref(), Some("database is locked"));
+        assert_eq!(state.executions.len(), 2);
+    }
+
+    #[test]
+    fn test_detail_closes_when_list_empties() {
+        let mut state = ExecutionsState::default();
+        state.toggle_detail();
+        assert!(!state.detail_open);
+
+        state.set_executions(vec![execution("exec-a", ExecutionStatus::Running)]);
+        state.toggle_detail();
+        assert!(state.detail_open);
+
+        state.set_executions(Vec::new());
+        assert!(!state.detail_open);
+    }
+}
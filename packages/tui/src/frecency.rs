@@ -0,0 +1,252 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File in the Orkee directory where access history is kept
+pub const FRECENCY_FILE: &str = "tui-frecency.json";
+
+/// Number of items shown in the recent section before a query is typed
+const RECENT_LIMIT: usize = 5;
+
+/// Once access counts add up to this, they are all scaled down so old
+/// favourites fade and the history stays small
+const MAX_TOTAL_COUNT: f64 = 1000.0;
+
+/// Scale applied to every count when the total passes `MAX_TOTAL_COUNT`
+const AGING_FACTOR: f64 = 0.9;
+
+/// Points added to a fuzzy match score per point of frecency
+const BOOST_PER_POINT: f64 = 5.0;
+
+/// Upper bound on the boost so a frecent item never beats a much better match
+const MAX_BOOST: i64 = 60;
+
+/// Access history of a single item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FrecencyEntry {
+    /// Number of accesses, decayed by aging
+    count: f64,
+    last_accessed: DateTime<Utc>,
+}
+
+impl FrecencyEntry {
+    /// Frequency weighted by how recently the item was last used
+    fn score(&self, now: DateTime<Utc>) -> f64 {
+        let age = now - self.last_accessed;
+        let weight = if age < Duration::hours(1) {
+            4.0
+        } else if age < Duration::days(1) {
+            2.0
+        } else if age < Duration::weeks(1) {
+            1.0
+        } else {
+            0.25
+        };
+        self.count * weight
+    }
+}
+
+/// Per-user access history for ranking search and mention results by
+/// frecency (frequency + recency of access)
+#[derive(Debug, Clone, Default)]
+pub struct FrecencyStore {
+    entries: HashMap<String, FrecencyEntry>,
+    /// Where the history is saved; `None` keeps it in memory only
+    path: Option<PathBuf>,
+}
+
+impl FrecencyStore {
+    /// Load the history saved at `path`, starting empty if it is missing or unreadable
+    pub fn load(path: &Path) -> Self {
+        let entries = std::fs::read(path)
+            .ok()
+            .and_then(|content| serde_json::from_slice(&content).ok())
+            .unwrap_or_default();
+
+        Self {
+            entries,
+            path: Some(path.to_path_buf()),
+        }
+    }
+
+    /// Save the history, if it has a file
+    pub fn save(&self) -> std::io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_vec(&self.entries)?;
+        std::fs::write(path, content)
+    }
+
+    /// Record an access to an item now
+    pub fn record(&mut self, id: &str) {
+        self.record_at(id, Utc::now());
+    }
+
+    /// Record an access to an item at the given time
+    pub fn record_at(&mut self, id: &str, now: DateTime<Utc>) {
+        let entry = self.entries.entry(id.to_string()).or_insert(FrecencyEntry {
+            count: 0.0,
+            last_accessed: now,
+        });
+        entry.count += 1.0;
+        entry.last_accessed = now;
+
+        let total: f64 = self.entries.values().map(|entry| entry.count).sum();
+        if total > MAX_TOTAL_COUNT {
+            for entry in self.entries.values_mut() {
+                entry.count *= AGING_FACTOR;
+            }
+            self.entries.retain(|_, entry| entry.count >= 1.0);
+        }
+    }
+
+    /// Forget an item, e.g. after it was deleted
+    pub fn remove(&mut self, id: &str) {
+        self.entries.remove(id);
+    }
+
+    /// Frecency score of an item at the given time (0 if never accessed)
+    pub fn score_at(&self, id: &str, now: DateTime<Utc>) -> f64 {
+        self.entries
+            .get(id)
+            .map(|entry| entry.score(now))
+            .unwrap_or(0.0)
+    }
+
+    /// Snapshot of the current ranking for a popup
+    pub fn ranking(&self) -> FrecencyRanking {
+        self.ranking_at(Utc::now())
+    }
+
+    /// Snapshot of the ranking at the given time
+    pub fn ranking_at(&self, now: DateTime<Utc>) -> FrecencyRanking {
+        let boosts = self
+            .entries
+            .iter()
+            .map(|(id, entry)| {
+                let boost = (entry.score(now) * BOOST_PER_POINT).round() as i64;
+                (id.clone(), boost.min(MAX_BOOST))
+            })
+            .collect();
+
+        let mut recent: Vec<(&String, &FrecencyEntry)> = self.entries.iter().collect();
+        recent.sort_by(|a, b| {
+            b.1.last_accessed
+                .cmp(&a.1.last_accessed)
+                .then_with(|| a.0.cmp(b.0))
+        });
+        let recent = recent
+            .into_iter()
+            .take(RECENT_LIMIT)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        FrecencyRanking { boosts, recent }
+    }
+}
+
+/// Frecency boosts and the most recently used items, as of when a popup opened
+#[derive(Debug, Clone, Default)]
+pub struct FrecencyRanking {
+    boosts: HashMap<String, i64>,
+    /// Most recently accessed first
+    recent: Vec<String>,
+}
+
+impl FrecencyRanking {
+    /// Points to add to an item's match score
+    pub fn boost(&self, id: &str) -> i64 {
+        self.boosts.get(id).copied().unwrap_or(0)
+    }
+
+    /// Position of an item in the recent section, if it is in it
+    pub fn recent_position(&self, id: &str) -> Option<usize> {
+        self.recent.iter().position(|recent| recent == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frequent_and_recent_items_score_higher() {
+        let now = Utc::now();
+        let mut store = FrecencyStore::default();
+
+        store.record_at("old", now - Duration::weeks(2));
+        store.record_at("old", now - Duration::weeks(2));
+        store.record_at("often", now - Duration::days(2));
+        store.record_at("often", now - Duration::days(2));
+        store.record_at("today", now - Duration::minutes(5));
+
+        assert!(store.score_at("today", now) > store.score_at("often", now));
+        assert!(store.score_at("often", now) > store.score_at("old", now));
+        assert_eq!(store.score_at("never", now), 0.0);
+    }
+
+    #[test]
+    fn test_ranking_lists_most_recent_first() {
+        let now = Utc::now();
+        let mut store = FrecencyStore::default();
+
+        for minutes in 0..8 {
+            store.record_at(
+                &format!("project-{}", minutes),
+                now - Duration::minutes(minutes),
+            );
+        }
+
+        let ranking = store.ranking_at(now);
+        assert_eq!(ranking.recent_position("project-0"), Some(0));
+        assert_eq!(ranking.recent_position("project-4"), Some(4));
+        assert_eq!(ranking.recent_position("project-5"), None);
+        assert!(ranking.boost("project-0") > 0);
+        assert_eq!(ranking.boost("unknown"), 0);
+    }
+
+    #[test]
+    fn test_boost_is_capped() {
+        let now = Utc::now();
+        let mut store = FrecencyStore::default();
+        for _ in 0..100 {
+            store.record_at("favourite", now);
+        }
+
+        assert_eq!(store.ranking_at(now).boost("favourite"), MAX_BOOST);
+    }
+
+    #[test]
+    fn test_counts_age_once_total_is_large() {
+        let now = Utc::now();
+        let mut store = FrecencyStore::default();
+        store.record_at("stale", now);
+        for _ in 0..1000 {
+            store.record_at("busy", now);
+        }
+
+        // The single access decayed below one and was dropped
+        assert_eq!(store.score_at("stale", now), 0.0);
+        assert!(store.score_at("busy", now) > 0.0);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = std::env::temp_dir().join(format!("orkee-frecency-{}", uuid::Uuid::new_v4()));
+        let path = dir.join(FRECENCY_FILE);
+
+        let mut store = FrecencyStore::load(&path);
+        store.record("project-1");
+        store.save().unwrap();
+
+        let loaded = FrecencyStore::load(&path);
+        assert_eq!(loaded.ranking().recent_position("project-1"), Some(0));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -5,10 +5,17 @@
 
 pub mod app;
 pub mod chat;
+pub mod cheat_sheet;
+pub mod clipboard;
 pub mod command_popup;
+pub mod command_prompt;
+pub mod context;
 pub mod events;
+pub mod executions;
+pub mod frecency;
 pub mod input;
 pub mod mention_popup;
+pub mod project_detail;
 pub mod search_popup;
 pub mod slash_command;
 pub mod state;
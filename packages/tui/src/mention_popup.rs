@@ -1,3 +1,4 @@
+use crate::frecency::FrecencyRanking;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use orkee_projects::Project;
@@ -63,6 +64,8 @@ pub struct MentionMatch {
     pub item: MentionItem,
     pub score: i64,
     pub match_indices: Vec<usize>,
+    /// Whether the item is in the recent section shown before any filter is typed
+    pub recent: bool,
 }
 
 /// Mention popup that provides fuzzy matching and selection for @ mentions
@@ -81,6 +84,8 @@ pub struct MentionPopup {
     max_display_items: usize,
     /// The position in the input where @ was typed
     mention_start_position: usize,
+    /// Frecency of items, used to rank matches and list recent items
+    frecency: FrecencyRanking,
 }
 
 impl std::fmt::Debug for MentionPopup {
@@ -93,6 +98,7 @@ impl std::fmt::Debug for MentionPopup {
             .field("matcher", &"<SkimMatcherV2>")
             .field("max_display_items", &self.max_display_items)
             .field("mention_start_position", &self.mention_start_position)
+            .field("frecency", &self.frecency)
             .finish()
     }
 }
@@ -114,6 +120,7 @@ impl MentionPopup {
             matcher: SkimMatcherV2::default(),
             max_display_items: 6, // Reasonable default for UI
             mention_start_position,
+            frecency: FrecencyRanking::default(),
         };
 
         // Initialize with all items visible
@@ -128,23 +135,42 @@ impl MentionPopup {
         Self::new(items, mention_start_position)
     }
 
+    /// Rank matches using the user's access history
+    pub fn with_frecency(mut self, frecency: FrecencyRanking) -> Self {
+        self.frecency = frecency;
+        let filter = std::mem::take(&mut self.filter);
+        self.update_filter(&filter);
+        self
+    }
+
     /// Update the filter text and refresh the filtered results
     pub fn update_filter(&mut self, text: &str) {
         self.filter = text.to_string();
         self.filtered.clear();
 
         if text.is_empty() {
-            // Show all items when no filter
+            // Show all items when no filter, recently used ones first
             self.filtered = self
                 .items
                 .iter()
                 .cloned()
                 .map(|item| MentionMatch {
+                    score: self.frecency.boost(&item.id),
+                    recent: self.frecency.recent_position(&item.id).is_some(),
                     item,
-                    score: 0,
                     match_indices: vec![],
                 })
                 .collect();
+
+            let frecency = &self.frecency;
+            self.filtered.sort_by_key(|m| {
+                let recent_position = frecency.recent_position(&m.item.id);
+                (
+                    recent_position.is_none(),
+                    recent_position,
+                    std::cmp::Reverse(m.score),
+                )
+            });
         } else {
             // Perform fuzzy matching on both name and path
             for item in &self.items {
@@ -154,10 +180,12 @@ impl MentionPopup {
 
                 // Use the better match or name match if both exist
                 if let Some((score, indices)) = name_match.or(display_match) {
+                    // Frecently used items rank above equally good matches
                     self.filtered.push(MentionMatch {
                         item: item.clone(),
-                        score,
+                        score: score + self.frecency.boost(&item.id),
                         match_indices: indices,
+                        recent: false,
                     });
                 }
             }
@@ -227,6 +255,11 @@ impl MentionPopup {
         self.selected_index
     }
 
+    /// Check if the results start with a recent items section
+    pub fn has_recent_section(&self) -> bool {
+        self.filtered.first().is_some_and(|m| m.recent)
+    }
+
     /// Check if there are any filtered results
     pub fn has_results(&self) -> bool {
         !self.filtered.is_empty()
@@ -262,6 +295,7 @@ impl MentionPopup {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::frecency::FrecencyStore;
     use orkee_projects::Project;
 
     fn create_test_project(id: &str, name: &str, path: &str) -> Project {
@@ -283,6 +317,8 @@ mod tests {
             task_source: None,
             manual_tasks: None,
             mcp_servers: None,
+            owner_user_id: None,
+            version: 1,
         }
     }
 
@@ -386,6 +422,36 @@ mod tests {
         assert_eq!(selected.unwrap().name, "project-one");
     }
 
+    #[test]
+    fn test_recent_items_listed_first() {
+        let projects = vec![
+            create_test_project("1", "project-one", "/path/one"),
+            create_test_project("2", "project-two", "/path/two"),
+            create_test_project("3", "project-three", "/path/three"),
+        ];
+        let now = chrono::Utc::now();
+        let mut frecency = FrecencyStore::default();
+        frecency.record_at("1", now - chrono::Duration::days(3));
+        frecency.record_at("3", now);
+
+        let mut popup =
+            MentionPopup::from_projects(&projects, 0).with_frecency(frecency.ranking_at(now));
+
+        assert!(popup.has_recent_section());
+        let names: Vec<&str> = popup
+            .filtered_matches()
+            .iter()
+            .map(|m| m.item.name.as_str())
+            .collect();
+        assert_eq!(names, ["project-three", "project-one", "project-two"]);
+        assert!(!popup.filtered_matches()[2].recent);
+
+        // Once a filter is typed, frecency breaks ties between equal matches
+        popup.update_filter("project");
+        assert!(!popup.has_recent_section());
+        assert_eq!(popup.selected_item().unwrap().name, "project-three");
+    }
+
     #[test]
     fn test_replacement_range() {
         let popup = MentionPopup::from_projects(&[], 10);
@@ -0,0 +1,433 @@
+// ABOUTME: Tabbed project detail screen state and data loading
+// ABOUTME: Each tab (tasks, dev servers, git commits, AI spend) is fetched the first time it is shown
+
+use orkee_ai::{AiUsageLogStorage, AiUsageQuery, AiUsageStats};
+use orkee_git_utils::{recent_commits, CommitSummary};
+use orkee_preview::storage::{PreviewServerEntry, PreviewServerStorage};
+use orkee_preview::types::DevServerStatus;
+use orkee_preview::{PreviewManager, ServerRegistry};
+use orkee_projects::Project;
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::Task;
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+
+/// Number of commits shown on the git tab
+const COMMIT_LIMIT: usize = 50;
+
+/// Tabs of the project detail screen, in display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetailTab {
+    #[default]
+    Overview,
+    Tasks,
+    Servers,
+    Git,
+    Usage,
+}
+
+impl DetailTab {
+    pub const ALL: [DetailTab; 5] = [
+        DetailTab::Overview,
+        DetailTab::Tasks,
+        DetailTab::Servers,
+        DetailTab::Git,
+        DetailTab::Usage,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            DetailTab::Overview => "Overview",
+            DetailTab::Tasks => "Tasks",
+            DetailTab::Servers => "Servers",
+            DetailTab::Git => "Git",
+            DetailTab::Usage => "Usage",
+        }
+    }
+
+    /// Position in [`DetailTab::ALL`]
+    pub fn index(self) -> usize {
+        Self::ALL.iter().position(|tab| *tab == self).unwrap_or(0)
+    }
+
+    /// The tab to the right, wrapping around
+    pub fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    /// The tab to the left, wrapping around
+    pub fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    /// Tab selected by a number key ('1' is the first tab)
+    pub fn from_digit(c: char) -> Option<Self> {
+        let index = c.to_digit(10)?.checked_sub(1)? as usize;
+        Self::ALL.get(index).copied()
+    }
+}
+
+/// Data behind one tab
+#[derive(Debug, Clone, Default)]
+pub enum TabData<T> {
+    /// Not fetched yet; fetched when the tab is shown
+    #[default]
+    NotLoaded,
+    /// Being fetched in the background
+    Loading,
+    Loaded(T),
+    /// Couldn't be fetched (no database, not a git repository, ...)
+    Unavailable(String),
+}
+
+impl<T> TabData<T> {
+    pub fn loaded(&self) -> Option<&T> {
+        match self {
+            TabData::Loaded(data) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+/// Freshly fetched data for one tab
+#[derive(Debug, Clone)]
+pub enum TabUpdate {
+    Tasks(TabData<Vec<Task>>),
+    Servers(TabData<Vec<PreviewServerEntry>>),
+    Commits(TabData<Vec<CommitSummary>>),
+    Usage(TabData<AiUsageStats>),
+}
+
+impl TabUpdate {
+    /// The tab this data belongs to
+    pub fn tab(&self) -> DetailTab {
+        match self {
+            TabUpdate::Tasks(_) => DetailTab::Tasks,
+            TabUpdate::Servers(_) => DetailTab::Servers,
+            TabUpdate::Commits(_) => DetailTab::Git,
+            TabUpdate::Usage(_) => DetailTab::Usage,
+        }
+    }
+}
+
+/// State of the project detail screen for one project
+#[derive(Debug, Default)]
+pub struct ProjectDetailState {
+    pub project_id: String,
+    pub tab: DetailTab,
+    /// Selected row on list tabs
+    pub selected: usize,
+    pub tasks: TabData<Vec<Task>>,
+    pub servers: TabData<Vec<PreviewServerEntry>>,
+    pub commits: TabData<Vec<CommitSummary>>,
+    pub usage: TabData<AiUsageStats>,
+}
+
+impl ProjectDetailState {
+    pub fn new(project_id: &str) -> Self {
+        Self {
+            project_id: project_id.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Switch tabs, resetting the row selection
+    pub fn select_tab(&mut self, tab: DetailTab) {
+        if self.tab != tab {
+            self.tab = tab;
+            self.selected = 0;
+        }
+    }
+
+    /// Whether the current tab's data still has to be fetched
+    pub fn needs_load(&self) -> bool {
+        match self.tab {
+            DetailTab::Overview => false,
+            DetailTab::Tasks => matches!(self.tasks, TabData::NotLoaded),
+            DetailTab::Servers => matches!(self.servers, TabData::NotLoaded),
+            DetailTab::Git => matches!(self.commits, TabData::NotLoaded),
+            DetailTab::Usage => matches!(self.usage, TabData::NotLoaded),
+        }
+    }
+
+    /// Drop the current tab's data so it is fetched again
+    pub fn invalidate(&mut self) {
+        match self.tab {
+            DetailTab::Overview => {}
+            DetailTab::Tasks => self.tasks = TabData::NotLoaded,
+            DetailTab::Servers => self.servers = TabData::NotLoaded,
+            DetailTab::Git => self.commits = TabData::NotLoaded,
+            DetailTab::Usage => self.usage = TabData::NotLoaded,
+        }
+    }
+
+    /// Record that the current tab's data is being fetched
+    pub fn mark_loading(&mut self) {
+        match self.tab {
+            DetailTab::Overview => {}
+            DetailTab::Tasks => self.tasks = TabData::Loading,
+            DetailTab::Servers => self.servers = TabData::Loading,
+            DetailTab::Git => self.commits = TabData::Loading,
+            DetailTab::Usage => self.usage = TabData::Loading,
+        }
+    }
+
+    /// Record that the current tab's data can't be fetched
+    pub fn mark_unavailable(&mut self, reason: &str) {
+        let reason = reason.to_string();
+        match self.tab {
+            DetailTab::Overview => {}
+            DetailTab::Tasks => self.tasks = TabData::Unavailable(reason),
+            DetailTab::Servers => self.servers = TabData::Unavailable(reason),
+            DetailTab::Git => self.commits = TabData::Unavailable(reason),
+            DetailTab::Usage => self.usage = TabData::Unavailable(reason),
+        }
+    }
+
+    /// Store fetched tab data, keeping the row selection within bounds
+    pub fn apply(&mut self, update: TabUpdate) {
+        let tab = update.tab();
+        match update {
+            TabUpdate::Tasks(tasks) => self.tasks = tasks,
+            TabUpdate::Servers(servers) => self.servers = servers,
+            TabUpdate::Commits(commits) => self.commits = commits,
+            TabUpdate::Usage(usage) => self.usage = usage,
+        }
+
+        if tab == self.tab {
+            let rows = self.row_count();
+            if self.selected >= rows {
+                self.selected = rows.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Number of selectable rows on the current tab
+    fn row_count(&self) -> usize {
+        match self.tab {
+            DetailTab::Tasks => self.tasks.loaded().map_or(0, Vec::len),
+            DetailTab::Servers => self.servers.loaded().map_or(0, Vec::len),
+            DetailTab::Git => self.commits.loaded().map_or(0, Vec::len),
+            DetailTab::Overview | DetailTab::Usage => 0,
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.row_count() {
+            self.selected += 1;
+        }
+    }
+
+    /// Server on the selected row of the servers tab
+    pub fn selected_server(&self) -> Option<&PreviewServerEntry> {
+        self.servers.loaded()?.get(self.selected)
+    }
+
+    /// Server whose URL gets copied: the selected row on the servers tab,
+    /// otherwise the first running server
+    pub fn server_to_copy(&self) -> Option<&PreviewServerEntry> {
+        if self.tab == DetailTab::Servers {
+            return self.selected_server();
+        }
+        self.servers
+            .loaded()?
+            .iter()
+            .find(|server| is_server_active(server))
+    }
+}
+
+/// Fetches detail tab data from the Orkee database and the project's repository
+pub struct DetailSource {
+    pool: SqlitePool,
+    tasks: TaskStorage,
+    servers: PreviewServerStorage,
+    usage: AiUsageLogStorage,
+    /// Created on the first start/stop so browsing the screen spawns nothing
+    preview_manager: Option<PreviewManager>,
+}
+
+impl DetailSource {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            tasks: TaskStorage::new(pool.clone()),
+            servers: PreviewServerStorage::from_pool(pool.clone()),
+            usage: AiUsageLogStorage::new(pool.clone()),
+            preview_manager: None,
+            pool,
+        }
+    }
+
+    /// Fetch the data for a tab; the overview has none
+    pub async fn fetch(&self, project: &Project, tab: DetailTab) -> Option<TabUpdate> {
+        let update = match tab {
+            DetailTab::Overview => return None,
+            DetailTab::Tasks => TabUpdate::Tasks(match self.tasks.list_tasks(&project.id).await {
+                Ok(tasks) => TabData::Loaded(tasks),
+                Err(e) => TabData::Unavailable(e.to_string()),
+            }),
+            DetailTab::Servers => {
+                TabUpdate::Servers(match self.servers.get_by_project(&project.id).await {
+                    Ok(servers) => TabData::Loaded(servers),
+                    Err(e) => TabData::Unavailable(e.to_string()),
+                })
+            }
+            DetailTab::Git => {
+                let path = PathBuf::from(&project.project_root);
+                let commits =
+                    tokio::task::spawn_blocking(move || recent_commits(&path, COMMIT_LIMIT))
+                        .await
+                        .ok()
+                        .flatten();
+                TabUpdate::Commits(match commits {
+                    Some(commits) => TabData::Loaded(commits),
+                    None => TabData::Unavailable("Not a git repository".to_string()),
+                })
+            }
+            DetailTab::Usage => {
+                let query = AiUsageQuery {
+                    project_id: Some(project.id.clone()),
+                    start_date: None,
+                    end_date: None,
+                    operation: None,
+                    model: None,
+                    provider: None,
+                    limit: None,
+                    offset: None,
+                };
+                TabUpdate::Usage(match self.usage.get_stats(query).await {
+                    Ok(stats) => TabData::Loaded(stats),
+                    Err(e) => TabData::Unavailable(e.to_string()),
+                })
+            }
+        };
+        Some(update)
+    }
+
+    /// Start the project's dev server, returning its URL when known
+    pub async fn start_server(&mut self, project: &Project) -> Result<Option<String>, String> {
+        let info = self
+            .preview_manager()
+            .await
+            .start_server(project.id.clone(), PathBuf::from(&project.project_root))
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(info.preview_url)
+    }
+
+    /// Stop the project's dev server
+    pub async fn stop_server(&mut self, project_id: &str) -> Result<(), String> {
+        self.preview_manager()
+            .await
+            .stop_server(project_id)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    /// The preview manager, adopting servers already recorded in the registry
+    async fn preview_manager(&mut self) -> &PreviewManager {
+        if self.preview_manager.is_none() {
+            let registry =
+                ServerRegistry::from_storage(PreviewServerStorage::from_pool(self.pool.clone()));
+            self.preview_manager = Some(PreviewManager::new_with_recovery(registry).await);
+        }
+        self.preview_manager
+            .as_ref()
+            .expect("preview manager initialized above")
+    }
+}
+
+/// Whether a server entry can be stopped from the servers tab
+pub fn is_server_active(server: &PreviewServerEntry) -> bool {
+    matches!(
+        server.status,
+        DevServerStatus::Starting | DevServerStatus::Running
+    )
+}
+
+/// Address the dev server is reachable at
+pub fn server_url(server: &PreviewServerEntry) -> String {
+    server
+        .preview_url
+        .clone()
+        .unwrap_or_else(|| format!("http://localhost:{}", server.port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tab_navigation_wraps() {
+        assert_eq!(DetailTab::Overview.next(), DetailTab::Tasks);
+        assert_eq!(DetailTab::Usage.next(), DetailTab::Overview);
+        assert_eq!(DetailTab::Overview.previous(), DetailTab::Usage);
+        assert_eq!(DetailTab::from_digit('4'), Some(DetailTab::Git));
+        assert_eq!(DetailTab::from_digit('0'), None);
+        assert_eq!(DetailTab::from_digit('9'), None);
+    }
+
+    #[test]
+    fn test_tabs_load_lazily() {
+        let mut state = ProjectDetailState::new("proj-1");
+        assert!(!state.needs_load());
+
+        state.select_tab(DetailTab::Git);
+        assert!(state.needs_load());
+
+        state.commits = TabData::Unavailable("Not a git repository".to_string());
+        assert!(!state.needs_load());
+
+        state.invalidate();
+        assert!(state.needs_load());
+
+        // Tabs being fetched aren't fetched again
+        state.mark_loading();
+        assert!(!state.needs_load());
+    }
+
+    #[test]
+    fn test_apply_clamps_selection_on_current_tab() {
+        let mut state = ProjectDetailState::new("proj-1");
+        state.select_tab(DetailTab::Servers);
+        state.selected = 3;
+
+        // Data for another tab leaves the selection alone
+        state.apply(TabUpdate::Commits(TabData::Loaded(Vec::new())));
+        assert_eq!(state.selected, 3);
+
+        state.apply(TabUpdate::Servers(TabData::Unavailable("gone".to_string())));
+        assert_eq!(state.selected, 0);
+        assert!(state.commits.loaded().is_some());
+    }
+
+    #[test]
+    fn test_row_selection_is_bounded() {
+        let mut state = ProjectDetailState::new("proj-1");
+        state.select_tab(DetailTab::Git);
+        state.commits = TabData::Loaded(vec![
+            CommitSummary {
+                id: "a".repeat(40),
+                short_id: "aaaaaaa".to_string(),
+                summary: "first".to_string(),
+                author: "Test".to_string(),
+                timestamp: 0,
+            };
+            2
+        ]);
+
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected, 1);
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected, 0);
+
+        state.selected = 1;
+        state.select_tab(DetailTab::Tasks);
+        assert_eq!(state.selected, 0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::frecency::FrecencyRanking;
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use orkee_projects::{Priority, Project, ProjectStatus};
@@ -26,6 +27,8 @@ pub struct ProjectMatch {
     pub match_indices: Vec<usize>,
     /// Which field was matched
     pub matched_field: MatchedField,
+    /// Whether the project is in the recent section shown before a query is typed
+    pub recent: bool,
 }
 
 /// Current search mode
@@ -71,6 +74,8 @@ pub struct SearchPopup {
     debounce_duration: Duration,
     /// Pending search update flag
     pending_update: bool,
+    /// Frecency of projects, used to rank results and list recent projects
+    frecency: FrecencyRanking,
 }
 
 impl std::fmt::Debug for SearchPopup {
@@ -114,9 +119,16 @@ impl SearchPopup {
             last_update_time: None,
             debounce_duration: Duration::from_millis(100), // 100ms debounce
             pending_update: false,
+            frecency: FrecencyRanking::default(),
         }
     }
 
+    /// Rank results using the user's project access history
+    pub fn with_frecency(mut self, frecency: FrecencyRanking) -> Self {
+        self.frecency = frecency;
+        self
+    }
+
     /// Get current search query
     pub fn search_query(&self) -> &str {
         self.search_input.value()
@@ -281,8 +293,19 @@ impl SearchPopup {
             }
         }
 
-        // Sort by score (best matches first)
-        self.filtered_results.sort_by(|a, b| b.score.cmp(&a.score));
+        // Recent projects first when there is no query, then by score (best matches first)
+        let frecency = &self.frecency;
+        self.filtered_results.sort_by_key(|r| {
+            let recent_position = r
+                .recent
+                .then(|| frecency.recent_position(&r.project.id))
+                .flatten();
+            (
+                recent_position.is_none(),
+                recent_position,
+                std::cmp::Reverse(r.score),
+            )
+        });
 
         // Limit to max display items for performance
         self.filtered_results.truncate(self.max_display_items * 2);
@@ -299,14 +322,17 @@ impl SearchPopup {
             return None;
         }
 
+        let boost = self.frecency.boost(&project.id);
+
         // If no text query, include all projects that pass filters
         if query.is_empty() {
             return Some(ProjectMatch {
                 project_index: index,
                 project: project.clone(),
-                score: 100, // Default score for filter-only matches
+                score: 100 + boost, // Default score for filter-only matches
                 match_indices: Vec::new(),
                 matched_field: MatchedField::Name, // Default field
+                recent: self.frecency.recent_position(&project.id).is_some(),
             });
         }
 
@@ -326,6 +352,7 @@ impl SearchPopup {
                     score: weighted_score,
                     match_indices: indices,
                     matched_field: MatchedField::Name,
+                    recent: false,
                 });
             }
         }
@@ -343,6 +370,7 @@ impl SearchPopup {
                     score: weighted_score,
                     match_indices: indices,
                     matched_field: MatchedField::Path,
+                    recent: false,
                 });
             }
         }
@@ -361,6 +389,7 @@ impl SearchPopup {
                         score: weighted_score,
                         match_indices: indices,
                         matched_field: MatchedField::Description,
+                        recent: false,
                     });
                 }
             }
@@ -381,13 +410,18 @@ impl SearchPopup {
                             score: weighted_score,
                             match_indices: indices,
                             matched_field: MatchedField::Tag(tag.clone()),
+                            recent: false,
                         });
                     }
                 }
             }
         }
 
-        best_match
+        // Frecently used projects rank above equally good matches
+        best_match.map(|mut project_match| {
+            project_match.score += boost;
+            project_match
+        })
     }
 
     /// Check if project passes current filters
@@ -13,8 +13,116 @@ pub enum SlashCommand {
     Clear,
     /// List all projects
     Projects,
+    /// Monitor agent executions
+    Executions,
+    /// Start an agent execution for a task
+    Execute,
     /// Show current application status
     Status,
+    /// Record, bind, and replay keystroke macros
+    Macro,
+    /// Change a project's status
+    ProjectStatus,
+    /// Copy a chat message, project path, or server URL to the clipboard
+    Copy,
+}
+
+/// Actions accepted by `/macro`
+pub const MACRO_ACTIONS: &[&str] = &["record", "play", "bind", "delete", "list"];
+
+/// Statuses accepted by `/project-status`, as they appear in the API
+pub const PROJECT_STATUSES: &[&str] = &[
+    "planning", "building", "review", "launched", "on-hold", "archived",
+];
+
+/// What `/copy` can put on the clipboard
+pub const COPY_TARGETS: &[&str] = &["message", "path", "url"];
+
+/// Kind of value a command argument takes, which decides how it's prompted for
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgKind {
+    /// One of the loaded projects, picked by name
+    Project,
+    /// One of a fixed set of values
+    Choice(&'static [&'static str]),
+    /// A single word of free text
+    Text,
+    /// A task of the project given as the command's first argument
+    Task,
+    /// One of the available agents
+    Agent,
+    /// One of the sandbox templates
+    Template,
+}
+
+/// An argument of a slash command
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandArg {
+    pub name: &'static str,
+    pub kind: ArgKind,
+}
+
+impl CommandArg {
+    const fn new(name: &'static str, kind: ArgKind) -> Self {
+        Self { name, kind }
+    }
+}
+
+/// Actions of the `/macro` command
+#[derive(Debug, Clone, PartialEq)]
+pub enum MacroCommand {
+    /// Start recording keystrokes into a macro (Ctrl+R stops)
+    Record(String),
+    /// Replay a macro
+    Play(String),
+    /// Bind a macro to a key that replays it
+    Bind { name: String, key: String },
+    /// Delete a macro
+    Delete(String),
+    /// List saved macros
+    List,
+}
+
+impl MacroCommand {
+    /// Parse the arguments following `/macro`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let usage = || format!("Usage: {}", SlashCommand::Macro.usage());
+
+        match args {
+            [action] if action == "list" => Ok(Self::List),
+            [action, name] if action == "record" => Ok(Self::Record(name.clone())),
+            [action, name] if action == "play" => Ok(Self::Play(name.clone())),
+            [action, name] if action == "delete" => Ok(Self::Delete(name.clone())),
+            [action, name, key] if action == "bind" => Ok(Self::Bind {
+                name: name.clone(),
+                key: key.clone(),
+            }),
+            _ => Err(usage()),
+        }
+    }
+}
+
+/// Text copied by `/copy` and the copy key bindings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CopyTarget {
+    /// The selected chat message, or the newest one
+    Message,
+    /// Root path of the project in view
+    ProjectPath,
+    /// URL of the project's dev server
+    ServerUrl,
+}
+
+impl CopyTarget {
+    /// Parse the argument following `/copy`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        match args {
+            [target] if target == "message" => Ok(Self::Message),
+            [target] if target == "path" => Ok(Self::ProjectPath),
+            [target] if target == "url" => Ok(Self::ServerUrl),
+            _ => Err(format!("Usage: {}", SlashCommand::Copy.usage())),
+        }
+    }
 }
 
 impl SlashCommand {
@@ -25,7 +133,12 @@ impl SlashCommand {
             Self::Quit => "Exit the application",
             Self::Clear => "Clear the chat history",
             Self::Projects => "Open interactive projects screen",
+            Self::Executions => "Monitor agent executions and their sandbox logs",
+            Self::Execute => "Run an agent on a task in a sandbox template",
             Self::Status => "Show current application status and information",
+            Self::Macro => "Record, bind, and replay keystroke macros",
+            Self::ProjectStatus => "Change a project's status",
+            Self::Copy => "Copy a chat message, project path, or server URL",
         }
     }
 
@@ -36,13 +149,49 @@ impl SlashCommand {
             Self::Quit => "/quit",
             Self::Clear => "/clear",
             Self::Projects => "/projects",
+            Self::Executions => "/executions",
+            Self::Execute => "/execute <project> <task> <agent> <template>",
             Self::Status => "/status",
+            Self::Macro => "/macro <record|play|bind|delete|list> [name] [key]",
+            Self::ProjectStatus => "/project-status <project> <status>",
+            Self::Copy => "/copy <message|path|url>",
         }
     }
 
     /// Check if the command requires arguments
     pub fn requires_args(&self) -> bool {
-        false // No commands require arguments anymore
+        self.next_arg(&[]).is_some()
+    }
+
+    /// The next argument to prompt for, given the ones entered so far.
+    /// Returns `None` once the command has everything it needs.
+    pub fn next_arg(&self, args: &[String]) -> Option<CommandArg> {
+        match self {
+            Self::Macro => match args {
+                [] => Some(CommandArg::new("action", ArgKind::Choice(MACRO_ACTIONS))),
+                [action] if action == "list" => None,
+                [_] => Some(CommandArg::new("name", ArgKind::Text)),
+                [action, _] if action == "bind" => Some(CommandArg::new("key", ArgKind::Text)),
+                _ => None,
+            },
+            Self::ProjectStatus => match args {
+                [] => Some(CommandArg::new("project", ArgKind::Project)),
+                [_] => Some(CommandArg::new("status", ArgKind::Choice(PROJECT_STATUSES))),
+                _ => None,
+            },
+            Self::Copy => match args {
+                [] => Some(CommandArg::new("target", ArgKind::Choice(COPY_TARGETS))),
+                _ => None,
+            },
+            Self::Execute => match args {
+                [] => Some(CommandArg::new("project", ArgKind::Project)),
+                [_] => Some(CommandArg::new("task", ArgKind::Task)),
+                [_, _] => Some(CommandArg::new("agent", ArgKind::Agent)),
+                [_, _, _] => Some(CommandArg::new("template", ArgKind::Template)),
+                _ => None,
+            },
+            _ => None,
+        }
     }
 
     /// Check if command is available during active task execution
@@ -81,16 +230,15 @@ impl SlashCommand {
         let args: Vec<String> = parts.into_iter().skip(1).map(|s| s.to_string()).collect();
 
         // Validate arguments
-        match command {
-            Self::Help | Self::Quit | Self::Clear | Self::Projects | Self::Status
-                if !args.is_empty() =>
-            {
-                Err(format!(
-                    "Command /{} does not accept arguments",
-                    command.as_ref()
-                ))
-            }
-            _ => Ok((command, args)),
+        if !command.requires_args() && !args.is_empty() {
+            Err(format!(
+                "Command /{} does not accept arguments",
+                command.as_ref()
+            ))
+        } else if command.requires_args() && args.is_empty() {
+            Err(format!("Usage: {}", command.usage()))
+        } else {
+            Ok((command, args))
         }
     }
 
@@ -137,6 +285,96 @@ mod tests {
         assert!(!projects.requires_args());
     }
 
+    #[test]
+    fn test_macro_command_parsing() {
+        let (cmd, args) = SlashCommand::parse_from_input("/macro bind triage f5").unwrap();
+        assert_eq!(cmd, SlashCommand::Macro);
+        assert_eq!(
+            MacroCommand::parse(&args),
+            Ok(MacroCommand::Bind {
+                name: "triage".to_string(),
+                key: "f5".to_string()
+            })
+        );
+
+        let (_, args) = SlashCommand::parse_from_input("/macro record triage").unwrap();
+        assert_eq!(
+            MacroCommand::parse(&args),
+            Ok(MacroCommand::Record("triage".to_string()))
+        );
+
+        assert!(SlashCommand::parse_from_input("/macro").is_err());
+        let (_, args) = SlashCommand::parse_from_input("/macro play").unwrap();
+        assert!(MacroCommand::parse(&args).is_err());
+        assert!(SlashCommand::Macro.requires_args());
+    }
+
+    #[test]
+    fn test_next_arg_follows_earlier_answers() {
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        let first = SlashCommand::Macro.next_arg(&[]).unwrap();
+        assert_eq!(first.kind, ArgKind::Choice(MACRO_ACTIONS));
+        assert!(SlashCommand::Macro.next_arg(&args(&["list"])).is_none());
+        assert_eq!(
+            SlashCommand::Macro
+                .next_arg(&args(&["bind", "triage"]))
+                .unwrap()
+                .name,
+            "key"
+        );
+        assert!(SlashCommand::Macro
+            .next_arg(&args(&["play", "triage"]))
+            .is_none());
+
+        assert_eq!(
+            SlashCommand::ProjectStatus.next_arg(&[]).unwrap().kind,
+            ArgKind::Project
+        );
+        assert!(SlashCommand::parse_from_input("/project-status").is_err());
+        assert!(SlashCommand::parse_from_input("/executions now").is_err());
+    }
+
+    #[test]
+    fn test_copy_command_parsing() {
+        let (cmd, args) = SlashCommand::parse_from_input("/copy path").unwrap();
+        assert_eq!(cmd, SlashCommand::Copy);
+        assert_eq!(CopyTarget::parse(&args), Ok(CopyTarget::ProjectPath));
+
+        assert!(SlashCommand::parse_from_input("/copy").is_err());
+        let (_, args) = SlashCommand::parse_from_input("/copy clipboard").unwrap();
+        assert!(CopyTarget::parse(&args).is_err());
+        assert_eq!(
+            SlashCommand::Copy.next_arg(&[]).unwrap().kind,
+            ArgKind::Choice(COPY_TARGETS)
+        );
+    }
+
+    #[test]
+    fn test_execute_prompts_for_task_agent_and_template() {
+        let args = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        let kinds: Vec<_> = (0..4)
+            .map(|answered| {
+                SlashCommand::Execute
+                    .next_arg(&args(&["p1", "task-1", "claude-code"][..answered]))
+                    .map(|arg| arg.kind)
+            })
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                Some(ArgKind::Project),
+                Some(ArgKind::Task),
+                Some(ArgKind::Agent),
+                Some(ArgKind::Template),
+            ]
+        );
+        assert!(SlashCommand::Execute
+            .next_arg(&args(&["p1", "task-1", "claude-code", "rust"]))
+            .is_none());
+        assert!(SlashCommand::parse_from_input("/execute").is_err());
+    }
+
     #[test]
     fn test_built_in_commands() {
         let commands = SlashCommand::built_in_commands();
@@ -1,19 +1,35 @@
 use crate::chat::{ChatMessage, MessageHistory};
+use crate::cheat_sheet::CheatSheet;
 use crate::command_popup::CommandPopup;
+use crate::command_prompt::{ArgSources, CommandPrompt, PromptStep};
+use crate::context::ConversationContext;
+use crate::events::{
+    Action, Keymap, MacroError, MacroRecorder, MacroSet, ProjectSaveError, ProjectSaveOrigin,
+};
+use crate::executions::{ExecutionCard, ExecutionsState, LaunchOptions, LaunchRequest};
+use crate::frecency::FrecencyStore;
 use crate::input::{InputBuffer, InputHistory, InputMode};
-use crate::mention_popup::MentionPopup;
+use crate::mention_popup::{MentionPopup, MentionTarget};
+use crate::project_detail::ProjectDetailState;
 use crate::search_popup::SearchPopup;
+use crate::slash_command::SlashCommand;
 use crate::ui::widgets::dialog::{ConfirmationDialog, DialogResult};
 use crate::ui::widgets::form::FieldValue;
-use crate::ui::widgets::{FormField, FormStep, FormWidget};
+use crate::ui::widgets::{FormField, FormStep, FormWidget, Toast};
+use crate::ui::LayoutMode;
+use chrono::Utc;
+use crossterm::event::KeyEvent;
+use orkee_executions::AgentExecution;
 use orkee_projects::{
-    create_project, delete_project, update_project, Priority, Project, ProjectCreateInput,
-    ProjectStatus, ProjectUpdateInput,
+    Priority, Project, ProjectCreateInput, ProjectStatus, ProjectUpdateInput, ValidationReport,
 };
 use std::time::{Duration, Instant};
 use tui_input::Input;
 use tui_textarea::TextArea;
 
+/// Maximum number of notification toasts shown at once
+const MAX_TOASTS: usize = 3;
+
 /// Application state management
 #[derive(Debug)]
 pub struct AppState {
@@ -23,10 +39,14 @@ pub struct AppState {
     pub refresh_interval: u64,
     pub message_history: MessageHistory,
     pub scroll_offset: usize,
+    /// Index of the chat message picked with the arrows while the chat is focused
+    pub selected_message: Option<usize>,
     pub input_buffer: InputBuffer,
     pub input_history: InputHistory,
     pub input_mode: InputMode,
     pub command_popup: Option<CommandPopup>,
+    /// Argument prompt for the command picked from the popup
+    pub command_prompt: Option<CommandPrompt>,
     pub mention_popup: Option<MentionPopup>,
     /// Form state for project creation/editing
     pub form_state: Option<FormState>,
@@ -44,6 +64,30 @@ pub struct AppState {
     pub filtered_project_indices: Option<Vec<usize>>,
     /// Whether search filter is currently active
     pub search_active: bool,
+    /// Project access history for ranking search and mention results
+    pub frecency: FrecencyStore,
+    /// Whether the chat is shown alone or beside the context pane
+    pub layout_mode: LayoutMode,
+    /// Project most recently @-mentioned in the chat
+    pub mentioned_project_id: Option<String>,
+    /// Servers and tasks of the project shown in the context pane
+    pub conversation_context: Option<ConversationContext>,
+    /// Saved keystroke macros
+    pub macros: MacroSet,
+    /// Macro being recorded, if any
+    pub macro_recorder: Option<MacroRecorder>,
+    /// Key bindings for screen shortcuts
+    pub keymap: Keymap,
+    /// Key binding cheat sheet, when open
+    pub cheat_sheet: Option<CheatSheet>,
+    /// Tab and loaded data of the project detail screen
+    pub project_detail: Option<ProjectDetailState>,
+    /// Agent executions shown on the execution monitor
+    pub executions: ExecutionsState,
+    /// Tasks, agents, and sandbox templates `/execute` picks from
+    pub launch_options: LaunchOptions,
+    /// Status cards of executions started from the chat, oldest first
+    pub execution_cards: Vec<ExecutionCard>,
     /// Track last escape key press for double-escape detection
     last_escape_time: Option<Instant>,
     /// Timeout for double-escape detection (500ms)
@@ -56,6 +100,8 @@ pub struct AppState {
     editing_message_id: Option<String>,
     /// Current focus area (chat or input)
     focus_area: FocusArea,
+    /// Notification toasts currently on screen, oldest first
+    pub toasts: Vec<Toast>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -63,6 +109,7 @@ pub enum Screen {
     Projects,
     ProjectDetail,
     Chat,
+    Executions,
 }
 
 /// Focus areas within the chat interface
@@ -82,6 +129,9 @@ pub struct FormState {
     pub total_steps: usize,
     pub can_submit: bool,
     pub form_mode: FormMode,
+    /// Version of the edited project when the form was opened, sent with the
+    /// update so a concurrent save is detected instead of overwritten
+    pub loaded_version: Option<i64>,
 }
 
 /// Mode for form operation
@@ -120,10 +170,12 @@ impl AppState {
             refresh_interval,
             message_history: MessageHistory::new(),
             scroll_offset: 0,
+            selected_message: None,
             input_buffer: InputBuffer::new(),
             input_history: InputHistory::new(),
             input_mode: InputMode::Normal,
             command_popup: None,
+            command_prompt: None,
             mention_popup: None,
             form_state: None,
             confirmation_dialog: None,
@@ -133,12 +185,25 @@ impl AppState {
             search_popup: None,
             filtered_project_indices: None,
             search_active: false,
+            frecency: FrecencyStore::default(),
+            layout_mode: LayoutMode::default(),
+            mentioned_project_id: None,
+            conversation_context: None,
+            macros: MacroSet::default(),
+            macro_recorder: None,
+            keymap: Keymap::default(),
+            cheat_sheet: None,
+            project_detail: None,
+            executions: ExecutionsState::default(),
+            launch_options: LaunchOptions::default(),
+            execution_cards: Vec::new(),
             last_escape_time: None,
             escape_timeout: Duration::from_millis(500),
             last_ctrl_c_time: None,
             ctrl_c_timeout: Duration::from_millis(1000),
             editing_message_id: None,
             focus_area: FocusArea::Input, // Start with input focused
+            toasts: Vec::new(),
         };
 
         // Add welcome message
@@ -162,6 +227,60 @@ impl AppState {
         }
     }
 
+    /// Show a notification toast, dropping the oldest when too many are on screen
+    pub fn push_toast(&mut self, toast: Toast) {
+        self.toasts.push(toast);
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+    }
+
+    /// Remove expired toasts. Returns true if any were removed.
+    pub fn expire_toasts(&mut self, now: Instant) -> bool {
+        let before = self.toasts.len();
+        self.toasts.retain(|toast| !toast.is_expired(now));
+        self.toasts.len() != before
+    }
+
+    /// Post a status card for an execution started from the chat
+    pub fn pin_execution_card(&mut self, request: LaunchRequest, execution: AgentExecution) {
+        let mut card = ExecutionCard {
+            message_id: String::new(),
+            request,
+            execution,
+        };
+        card.message_id = self.add_system_message(card.content(Utc::now())).id.clone();
+        self.execution_cards.push(card);
+    }
+
+    /// Ids of the executions whose cards still show them running
+    pub fn active_execution_card_ids(&self) -> Vec<String> {
+        self.execution_cards
+            .iter()
+            .filter(|card| card.is_active())
+            .map(|card| card.execution.id.clone())
+            .collect()
+    }
+
+    /// Rewrite status cards with freshly fetched executions. Cards whose
+    /// message was cleared from the chat are dropped.
+    pub fn update_execution_cards(&mut self, executions: Vec<AgentExecution>) {
+        let now = Utc::now();
+        let history = &mut self.message_history;
+        self.execution_cards.retain_mut(|card| {
+            if let Some(execution) = executions.iter().find(|e| e.id == card.execution.id) {
+                card.execution = execution.clone();
+            }
+            match history.get_message_mut(&card.message_id) {
+                Some(message) => {
+                    message.content = card.content(now);
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
     /// Navigate to previous project in list
     pub fn select_previous_project(&mut self) -> bool {
         if self.projects.is_empty() {
@@ -217,6 +336,7 @@ impl AppState {
         if self.selected_project.is_some() && !self.projects.is_empty() {
             self.push_current_screen_to_history();
             self.current_screen = Screen::ProjectDetail;
+            self.project_detail = None;
             self.update_project_context();
             true
         } else {
@@ -224,10 +344,24 @@ impl AppState {
         }
     }
 
+    /// Detail screen state for the selected project, starting fresh when the project changed
+    pub fn project_detail_mut(&mut self) -> Option<&mut ProjectDetailState> {
+        let project_id = self.get_selected_project()?.id.clone();
+        if self
+            .project_detail
+            .as_ref()
+            .is_none_or(|detail| detail.project_id != project_id)
+        {
+            self.project_detail = Some(ProjectDetailState::new(&project_id));
+        }
+        self.project_detail.as_mut()
+    }
+
     /// Return to projects list from detail view
     pub fn return_to_projects_list(&mut self) {
         if self.current_screen == Screen::ProjectDetail {
             self.current_screen = Screen::Projects;
+            self.project_detail = None;
             self.update_project_context();
         }
     }
@@ -238,6 +372,7 @@ impl AppState {
             Screen::Projects => Screen::Chat,
             Screen::ProjectDetail => Screen::Projects, // Return to projects list
             Screen::Chat => Screen::Projects,
+            Screen::Executions => Screen::Chat,
         };
         self.update_project_context();
     }
@@ -285,6 +420,7 @@ impl AppState {
         match screen {
             Screen::Chat => "Chat".to_string(),
             Screen::Projects => "Projects".to_string(),
+            Screen::Executions => "Executions".to_string(),
             Screen::ProjectDetail => {
                 if let Some(ref context) = self.current_project_context {
                     context.project_name.clone()
@@ -361,6 +497,31 @@ impl AppState {
         }
     }
 
+    /// Select the message before the selected one, starting from the newest
+    pub fn select_previous_message(&mut self) {
+        let count = self.message_history.len();
+        self.selected_message = match self.selected_message {
+            _ if count == 0 => None,
+            Some(index) if index < count => Some(index.saturating_sub(1)),
+            _ => Some(count - 1),
+        };
+    }
+
+    /// Select the message after the selected one; moving past the newest
+    /// clears the selection
+    pub fn select_next_message(&mut self) {
+        self.selected_message = self
+            .selected_message
+            .map(|index| index + 1)
+            .filter(|&index| index < self.message_history.len());
+    }
+
+    /// The selected chat message, if it still exists
+    pub fn get_selected_message(&self) -> Option<&ChatMessage> {
+        self.selected_message
+            .and_then(|index| self.message_history.messages().get(index))
+    }
+
     /// Reset scroll to bottom (most recent messages)
     pub fn scroll_to_bottom(&mut self) {
         self.scroll_offset = 0;
@@ -527,6 +688,7 @@ impl AppState {
         if self.input_mode == InputMode::Command {
             self.input_mode = InputMode::Normal;
             self.command_popup = None;
+            self.command_prompt = None;
         }
     }
 
@@ -537,6 +699,14 @@ impl AppState {
 
     /// Update command popup filter when typing in command mode
     pub fn update_command_filter(&mut self) {
+        if let Some(ref mut prompt) = self.command_prompt {
+            let sources = ArgSources {
+                projects: &self.projects,
+                launch: &self.launch_options,
+            };
+            prompt.update_filter(self.input_buffer.content(), &sources);
+            return;
+        }
         if let Some(ref mut popup) = self.command_popup {
             let input_content = self.input_buffer.content();
             let command_text = input_content.strip_prefix('/').unwrap_or("");
@@ -546,7 +716,10 @@ impl AppState {
 
     /// Navigate command popup up
     pub fn command_popup_up(&mut self) -> bool {
-        if let Some(ref mut popup) = self.command_popup {
+        if let Some(ref mut prompt) = self.command_prompt {
+            prompt.move_up();
+            true
+        } else if let Some(ref mut popup) = self.command_popup {
             popup.move_up();
             true
         } else {
@@ -556,7 +729,10 @@ impl AppState {
 
     /// Navigate command popup down  
     pub fn command_popup_down(&mut self) -> bool {
-        if let Some(ref mut popup) = self.command_popup {
+        if let Some(ref mut prompt) = self.command_prompt {
+            prompt.move_down();
+            true
+        } else if let Some(ref mut popup) = self.command_popup {
             popup.move_down();
             true
         } else {
@@ -578,11 +754,17 @@ impl AppState {
                 if !item.command.requires_args() {
                     self.exit_command_mode();
                     return Some(usage);
-                } else {
-                    // Keep in command mode for argument entry, but hide popup
-                    self.command_popup = None;
                 }
 
+                // Stay in command mode and prompt for each argument in place of the popup
+                let sources = ArgSources {
+                    projects: &self.projects,
+                    launch: &self.launch_options,
+                };
+                self.command_prompt = CommandPrompt::start(item.command.clone(), &sources);
+                self.command_popup = None;
+                self.input_buffer.clear();
+
                 return Some(usage);
             }
         }
@@ -594,13 +776,42 @@ impl AppState {
         self.command_popup.as_ref()
     }
 
+    /// Get reference to the argument prompt for UI rendering
+    pub fn command_prompt(&self) -> Option<&CommandPrompt> {
+        self.command_prompt.as_ref()
+    }
+
+    /// Submit the typed argument to the command prompt.
+    /// Returns the command and its arguments once every argument is in.
+    pub fn submit_command_prompt(&mut self) -> Option<(SlashCommand, Vec<String>)> {
+        let prompt = self.command_prompt.as_mut()?;
+        let sources = ArgSources {
+            projects: &self.projects,
+            launch: &self.launch_options,
+        };
+        match prompt.submit(self.input_buffer.content(), &sources) {
+            PromptStep::Next => {
+                self.input_buffer.clear();
+                None
+            }
+            PromptStep::Invalid => None,
+            PromptStep::Complete(args) => {
+                let command = prompt.command.clone();
+                self.input_buffer.clear();
+                self.exit_command_mode();
+                Some((command, args))
+            }
+        }
+    }
+
     // Mention popup methods
 
     /// Enter mention mode and show mention popup
     pub fn enter_mention_mode(&mut self, mention_start_position: usize) {
         self.input_mode = InputMode::Search;
         self.focus_input(); // Force focus to input when entering mention mode
-        let popup = MentionPopup::from_projects(&self.projects, mention_start_position);
+        let popup = MentionPopup::from_projects(&self.projects, mention_start_position)
+            .with_frecency(self.frecency.ranking());
         self.mention_popup = Some(popup);
     }
 
@@ -657,6 +868,8 @@ impl AppState {
     pub fn complete_selected_mention(&mut self) -> Option<String> {
         if let Some(ref popup) = self.mention_popup {
             if let Some(item) = popup.selected_item() {
+                let item_id = item.id.clone();
+                let is_project = item.target_type == MentionTarget::Projects;
                 let insertion_text = item.insertion_text();
                 let current_cursor = self.input_buffer.cursor_position();
                 let (start, end) = popup.replacement_range(current_cursor);
@@ -677,6 +890,10 @@ impl AppState {
 
                 // Exit mention mode
                 self.exit_mention_mode();
+                self.record_access(&item_id);
+                if is_project {
+                    self.mentioned_project_id = Some(item_id);
+                }
 
                 return Some(insertion_text);
             }
@@ -908,6 +1125,7 @@ impl AppState {
             total_steps: 4,
             can_submit: false,
             form_mode: FormMode::Create,
+            loaded_version: None,
         });
 
         self.input_mode = InputMode::Form;
@@ -920,6 +1138,7 @@ impl AppState {
         // Find the project to edit
         if let Some(project) = self.projects.iter().find(|p| p.id == project_id) {
             let form = self.create_edit_form(project);
+            let loaded_version = Some(project.version);
 
             self.form_state = Some(FormState {
                 form,
@@ -927,6 +1146,7 @@ impl AppState {
                 total_steps: 4,
                 can_submit: false,
                 form_mode: FormMode::Edit(project_id),
+                loaded_version,
             });
 
             self.input_mode = InputMode::Form;
@@ -1518,83 +1738,153 @@ impl AppState {
                     task_source: None,
                     manual_tasks: None,
                     mcp_servers: None,
+                    expected_version: form_state.loaded_version,
                 });
             }
         }
         None
     }
 
-    /// Submit the form and create or update project
-    pub async fn submit_form(&mut self) -> Result<(), String> {
-        if let Some(ref form_state) = self.form_state {
-            match &form_state.form_mode {
-                FormMode::Create => {
-                    // Handle project creation
-                    if let Some(project_input) = self.form_to_project_create_input() {
-                        match create_project(project_input).await {
-                            Ok(project) => {
-                                // Project created successfully
-                                self.add_system_message(format!("✅ **Project Created Successfully**\n\n📁 **{}** has been created at `{}`", project.name, project.project_root));
-
-                                // Refresh projects list
-                                if let Ok(projects) = orkee_projects::get_all_projects().await {
-                                    self.set_projects(projects);
-                                }
-
-                                // Cancel form and return to projects list
-                                self.cancel_form();
-
-                                Ok(())
-                            }
-                            Err(e) => {
-                                let error_msg = format!("❌ **Failed to Create Project**\n\n{}", e);
-                                Err(error_msg)
-                            }
-                        }
-                    } else {
-                        Err(
-                            "❌ **Invalid Form Data**\n\nPlease fill in all required fields."
-                                .to_string(),
-                        )
-                    }
+    /// Build the update that changes a project's status (e.g. `on-hold`),
+    /// checked against the version of the project shown on screen
+    pub fn project_status_update(
+        &self,
+        project_id: &str,
+        status: &str,
+    ) -> Result<ProjectUpdateInput, String> {
+        let status: ProjectStatus = serde_json::from_value(serde_json::json!(status))
+            .map_err(|_| format!("Unknown project status '{}'", status))?;
+        let expected_version = self
+            .projects
+            .iter()
+            .find(|p| p.id == project_id)
+            .map(|p| p.version);
+        Ok(ProjectUpdateInput {
+            name: None,
+            project_root: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            tags: None,
+            description: None,
+            status: Some(status),
+            rank: None,
+            priority: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            expected_version,
+        })
+    }
+
+    /// The action that saves the form: creating or updating a project
+    pub fn form_submission(&self) -> Result<Action, String> {
+        let Some(ref form_state) = self.form_state else {
+            return Err("❌ **No Form Data**\n\nForm is not initialized.".to_string());
+        };
+        match &form_state.form_mode {
+            FormMode::Create => self
+                .form_to_project_create_input()
+                .map(Action::CreateProject)
+                .ok_or_else(|| {
+                    "❌ **Invalid Form Data**\n\nPlease fill in all required fields.".to_string()
+                }),
+            FormMode::Edit(project_id) => self
+                .form_to_project_update_input()
+                .map(|update| Action::UpdateProject {
+                    project_id: project_id.clone(),
+                    update,
+                    origin: ProjectSaveOrigin::EditForm,
+                })
+                .ok_or_else(|| {
+                    "❌ **Invalid Form Data**\n\nPlease check your input and try again.".to_string()
+                }),
+        }
+    }
+
+    /// Apply the outcome of a form save: close the form on success, or keep it
+    /// open with the errors shown
+    pub fn finish_form_submission(
+        &mut self,
+        created: bool,
+        result: Result<Box<Project>, ProjectSaveError>,
+        projects: Option<Vec<Project>>,
+    ) -> Result<(), String> {
+        let action = if created { "Create" } else { "Update" };
+        match result {
+            Ok(project) => {
+                if created {
+                    self.add_system_message(format!(
+                        "✅ **Project Created Successfully**\n\n📁 **{}** has been created at `{}`",
+                        project.name, project.project_root
+                    ));
+                } else {
+                    self.add_system_message(format!(
+                        "✅ **Project Updated Successfully**\n\n📁 **{}** has been updated",
+                        project.name
+                    ));
                 }
-                FormMode::Edit(project_id) => {
-                    // Handle project update
-                    if let Some(project_update) = self.form_to_project_update_input() {
-                        match update_project(project_id, project_update).await {
-                            Ok(project) => {
-                                // Project updated successfully
-                                self.add_system_message(format!("✅ **Project Updated Successfully**\n\n📁 **{}** has been updated", project.name));
-
-                                // Refresh projects list
-                                if let Ok(projects) = orkee_projects::get_all_projects().await {
-                                    self.set_projects(projects);
-                                }
 
-                                // Cancel form and return to projects list
-                                self.cancel_form();
+                if let Some(projects) = projects {
+                    self.set_projects(projects);
+                }
 
-                                Ok(())
-                            }
-                            Err(e) => {
-                                let error_msg = format!("❌ **Failed to Update Project**\n\n{}", e);
-                                Err(error_msg)
-                            }
-                        }
-                    } else {
-                        Err(
-                            "❌ **Invalid Form Data**\n\nPlease check your input and try again."
-                                .to_string(),
-                        )
-                    }
+                // Cancel form and return to projects list
+                self.cancel_form();
+                Ok(())
+            }
+            Err(ProjectSaveError::Validation(report)) => {
+                Err(self.apply_validation_report(action, &report))
+            }
+            Err(ProjectSaveError::VersionConflict { expected, actual }) => {
+                if let Some(projects) = projects {
+                    self.set_projects(projects);
+                }
+                // Submitting again deliberately overwrites the newer version
+                if let Some(ref mut form_state) = self.form_state {
+                    form_state.loaded_version = Some(actual);
                 }
+                Err(version_conflict_message(expected, actual)
+                    + "\n\nSubmit again to overwrite those changes, or press Esc to discard yours.")
+            }
+            Err(ProjectSaveError::Failed(e)) => {
+                Err(format!("❌ **Failed to {} Project**\n\n{}", action, e))
+            }
+        }
+    }
+
+    /// Show server-side validation errors next to their form fields and
+    /// build the message listing them
+    fn apply_validation_report(&mut self, action: &str, report: &ValidationReport) -> String {
+        let mut message = format!("❌ **Failed to {} Project**\n", action);
+        for (field, messages) in report.by_field() {
+            // The form calls the project root field "path"
+            let form_field = match field {
+                "projectRoot" => "path",
+                other => other,
+            };
+            if let Some(ref mut form_state) = self.form_state {
+                form_state
+                    .form
+                    .validation_errors
+                    .insert(form_field.to_string(), messages.join("; "));
+            }
+            for text in messages {
+                message.push_str(&format!("\n• **{}**: {}", form_field, text));
             }
-        } else {
-            Err("❌ **No Form Data**\n\nForm is not initialized.".to_string())
         }
+        message
     }
 }
 
+/// Explain that a project changed since it was loaded
+pub fn version_conflict_message(expected: i64, actual: i64) -> String {
+    format!(
+        "⚠️ **Project Changed Elsewhere**\n\nThe project was saved by someone else since it was loaded (version {}, now {}). Your change was not applied.",
+        expected, actual
+    )
+}
+
 /// Action to take when escape key is pressed
 #[derive(Debug, Clone, PartialEq)]
 pub enum EscapeAction {
@@ -1649,60 +1939,65 @@ impl AppState {
         self.confirmation_dialog.is_some()
     }
 
-    /// Confirm the pending action and execute it
-    pub async fn confirm_pending_action(&mut self) -> Result<(), String> {
-        if let Some(action) = self.pending_action.take() {
-            self.confirmation_dialog = None;
+    /// Confirm the pending action, returning the work to perform in the background
+    pub fn confirm_pending_action(&mut self) -> Result<Action, String> {
+        let Some(action) = self.pending_action.take() else {
+            return Err("No pending action to confirm".to_string());
+        };
+        self.confirmation_dialog = None;
 
-            match action {
-                PendingAction::DeleteProject(project_id) => {
-                    self.delete_project_by_id(project_id).await
-                }
+        match action {
+            PendingAction::DeleteProject(project_id) => {
+                // Find the project name for the result message
+                let project_name = self
+                    .projects
+                    .iter()
+                    .find(|p| p.id == project_id)
+                    .map(|p| p.name.clone())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                Ok(Action::DeleteProject {
+                    project_id,
+                    project_name,
+                })
             }
-        } else {
-            Err("No pending action to confirm".to_string())
         }
     }
 
-    /// Delete a project by ID
-    pub async fn delete_project_by_id(&mut self, project_id: String) -> Result<(), String> {
-        // Find the project name for the success message
-        let project_name = self
-            .projects
-            .iter()
-            .find(|p| p.id == project_id)
-            .map(|p| p.name.clone())
-            .unwrap_or_else(|| "Unknown".to_string());
-
-        // Call the delete function from the projects library
-        match delete_project(&project_id).await {
+    /// Apply the outcome of a project deletion
+    pub fn finish_project_delete(
+        &mut self,
+        project_id: &str,
+        project_name: &str,
+        result: Result<bool, String>,
+        projects: Option<Vec<Project>>,
+    ) -> Result<(), String> {
+        match result {
             Ok(true) => {
                 // Project was deleted successfully
-                // Refresh the projects list
-                match orkee_projects::get_all_projects().await {
-                    Ok(updated_projects) => {
-                        self.set_projects(updated_projects);
-                        self.add_system_message(format!(
-                            "✅ **Project Deleted**\n\nSuccessfully deleted project \"{}\".",
-                            project_name
-                        ));
-
-                        // Clear selection if the deleted project was selected
-                        if let Some(selected_idx) = self.selected_project {
-                            if selected_idx >= self.projects.len() {
-                                // If the selected index is now out of bounds, clear or adjust selection
-                                self.selected_project = if self.projects.is_empty() {
-                                    None
-                                } else {
-                                    Some(self.projects.len().saturating_sub(1))
-                                };
-                            }
-                        }
-
-                        Ok(())
+                self.frecency.remove(project_id);
+                let _ = self.frecency.save();
+                let Some(updated_projects) = projects else {
+                    return Err("Project deleted but failed to refresh list".to_string());
+                };
+                self.set_projects(updated_projects);
+                self.add_system_message(format!(
+                    "✅ **Project Deleted**\n\nSuccessfully deleted project \"{}\".",
+                    project_name
+                ));
+
+                // Clear selection if the deleted project was selected
+                if let Some(selected_idx) = self.selected_project {
+                    if selected_idx >= self.projects.len() {
+                        // If the selected index is now out of bounds, clear or adjust selection
+                        self.selected_project = if self.projects.is_empty() {
+                            None
+                        } else {
+                            Some(self.projects.len().saturating_sub(1))
+                        };
                     }
-                    Err(e) => Err(format!("Project deleted but failed to refresh list: {}", e)),
                 }
+
+                Ok(())
             }
             Ok(false) => {
                 // Project was not found (already deleted?)
@@ -1721,6 +2016,27 @@ impl AppState {
         }
     }
 
+    // === Key Binding Methods ===
+
+    /// Whether plain keys are going into text: a form, a popup, or a chat message
+    /// being typed. Screen shortcuts stay out of the way while this is true.
+    pub fn is_typing(&self) -> bool {
+        self.is_form_mode()
+            || self.is_command_mode()
+            || self.is_mention_mode()
+            || self.is_search_mode()
+            || (self.current_screen == Screen::Chat && !self.input_buffer().is_empty())
+    }
+
+    /// Open the cheat sheet on the current screen's bindings
+    pub fn open_cheat_sheet(&mut self) {
+        self.cheat_sheet = Some(CheatSheet::new(self.current_screen.clone()));
+    }
+
+    pub fn close_cheat_sheet(&mut self) {
+        self.cheat_sheet = None;
+    }
+
     // === Search Methods ===
 
     /// Open project search popup
@@ -1729,7 +2045,7 @@ impl AppState {
             self.current_screen,
             Screen::Projects | Screen::ProjectDetail
         ) {
-            self.search_popup = Some(SearchPopup::new());
+            self.search_popup = Some(SearchPopup::new().with_frecency(self.frecency.ranking()));
             self.search_active = false; // Will be set true when search is applied
         }
     }
@@ -1789,9 +2105,11 @@ impl AppState {
         if let Some(ref search_popup) = self.search_popup {
             if let Some(selected_match) = search_popup.selected_result() {
                 let project_index = selected_match.project_index;
+                let project_id = selected_match.project.id.clone();
 
                 // Close search
                 self.close_search();
+                self.record_access(&project_id);
 
                 // Set selection to the chosen project
                 self.selected_project = Some(project_index);
@@ -1829,6 +2147,60 @@ impl AppState {
             .as_ref()
             .is_some_and(|s| s.has_active_filters())
     }
+
+    /// Switch between the single and split layouts
+    pub fn toggle_layout_mode(&mut self) {
+        self.layout_mode = self.layout_mode.toggled();
+    }
+
+    /// Check if the chat is shown beside the context pane
+    pub fn is_split_layout(&self) -> bool {
+        self.layout_mode == LayoutMode::Split
+    }
+
+    /// Project the context pane describes: the last @-mentioned project,
+    /// falling back to the selected one
+    pub fn context_project(&self) -> Option<&Project> {
+        self.mentioned_project_id
+            .as_ref()
+            .and_then(|id| self.projects.iter().find(|project| &project.id == id))
+            .or_else(|| self.get_selected_project())
+    }
+
+    /// Start recording keystrokes into a macro
+    pub fn start_macro_recording(&mut self, name: &str) {
+        self.macro_recorder = Some(MacroRecorder::new(name));
+    }
+
+    /// Check if a macro is being recorded
+    pub fn is_recording_macro(&self) -> bool {
+        self.macro_recorder.is_some()
+    }
+
+    /// Record a keystroke into the macro being recorded
+    pub fn record_macro_key(&mut self, key_event: &KeyEvent) -> Result<(), MacroError> {
+        match self.macro_recorder.as_mut() {
+            Some(recorder) => recorder.record(key_event),
+            None => Ok(()),
+        }
+    }
+
+    /// Stop recording and save the macro, returning its name
+    pub fn finish_macro_recording(&mut self) -> Option<Result<String, MacroError>> {
+        let recorder = self.macro_recorder.take()?;
+        Some(recorder.finish().map(|input_macro| {
+            let name = input_macro.name.clone();
+            self.macros.insert(input_macro);
+            name
+        }))
+    }
+
+    /// Record that an item was picked from a search or mention popup
+    fn record_access(&mut self, id: &str) {
+        self.frecency.record(id);
+        // Ranking is a convenience; a history that can't be saved isn't worth an error
+        let _ = self.frecency.save();
+    }
 }
 
 /// Actions that can result from Ctrl+C key press
@@ -1847,6 +2219,197 @@ mod tests {
     use std::thread;
     use std::time::Duration;
 
+    fn create_test_project(id: &str, name: &str) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            project_root: format!("/path/{}", name),
+            status: orkee_projects::ProjectStatus::Planning,
+            priority: orkee_projects::Priority::Medium,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            tags: None,
+            description: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            git_repository: None,
+            rank: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            owner_user_id: None,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_message_selection() {
+        let mut state = AppState::new(20);
+        state.message_history.clear();
+        state.select_previous_message();
+        assert_eq!(state.selected_message, None);
+
+        state.add_user_message("first".to_string());
+        state.add_system_message("second".to_string());
+
+        // Selection starts from the newest message
+        state.select_previous_message();
+        assert_eq!(state.get_selected_message().unwrap().content, "second");
+        state.select_previous_message();
+        state.select_previous_message();
+        assert_eq!(state.get_selected_message().unwrap().content, "first");
+
+        state.select_next_message();
+        state.select_next_message();
+        assert_eq!(state.selected_message, None);
+    }
+
+    #[test]
+    fn test_toggle_layout_mode() {
+        let mut state = AppState::new(20);
+        assert!(!state.is_split_layout());
+
+        state.toggle_layout_mode();
+        assert!(state.is_split_layout());
+
+        state.toggle_layout_mode();
+        assert_eq!(state.layout_mode, LayoutMode::Single);
+    }
+
+    #[test]
+    fn test_context_project_follows_mentions() {
+        let mut state = AppState::new(20);
+        state.projects = vec![
+            create_test_project("1", "storefront"),
+            create_test_project("2", "billing"),
+        ];
+        assert!(state.context_project().is_none());
+
+        // Falls back to the selected project
+        state.selected_project = Some(0);
+        assert_eq!(state.context_project().unwrap().id, "1");
+
+        // Completing a mention switches the context to the mentioned project
+        state.input_buffer.insert_str("deploy @bill");
+        state.enter_mention_mode(7);
+        state.update_mention_filter();
+        state.complete_selected_mention();
+        assert_eq!(state.mentioned_project_id.as_deref(), Some("2"));
+        assert_eq!(state.context_project().unwrap().id, "2");
+    }
+
+    #[test]
+    fn test_project_delete_is_queued_and_applied() {
+        let mut state = AppState::new(20);
+        state.projects = vec![
+            create_test_project("1", "storefront"),
+            create_test_project("2", "billing"),
+        ];
+        state.selected_project = Some(1);
+
+        state.show_delete_confirmation("2".to_string());
+        match state.confirm_pending_action() {
+            Ok(Action::DeleteProject {
+                project_id,
+                project_name,
+            }) => {
+                assert_eq!(project_id, "2");
+                assert_eq!(project_name, "billing");
+            }
+            other => panic!("expected a delete action, got {:?}", other),
+        }
+        assert!(!state.is_showing_confirmation_dialog());
+        assert!(state.confirm_pending_action().is_err());
+
+        let remaining = vec![create_test_project("1", "storefront")];
+        state
+            .finish_project_delete("2", "billing", Ok(true), Some(remaining))
+            .unwrap();
+        assert_eq!(state.projects.len(), 1);
+        assert_eq!(state.selected_project, Some(0));
+
+        let missing = state.finish_project_delete("2", "billing", Ok(false), None);
+        assert!(missing.unwrap_err().contains("was not found"));
+    }
+
+    #[test]
+    fn test_project_updates_carry_loaded_version() {
+        let mut state = AppState::new(20);
+        let mut project = create_test_project("1", "storefront");
+        project.version = 4;
+        state.projects = vec![project];
+
+        let update = state.project_status_update("1", "archived").unwrap();
+        assert_eq!(update.expected_version, Some(4));
+
+        state.start_project_edit("1".to_string());
+        match state.form_submission() {
+            Ok(Action::UpdateProject { update, .. }) => {
+                assert_eq!(update.expected_version, Some(4))
+            }
+            other => panic!("expected an update action, got {:?}", other),
+        }
+
+        let conflict = ProjectSaveError::VersionConflict {
+            expected: 4,
+            actual: 5,
+        };
+        let message = state
+            .finish_form_submission(false, Err(conflict), None)
+            .unwrap_err();
+        assert!(message.contains("Changed Elsewhere"));
+        assert_eq!(state.form_state.as_ref().unwrap().loaded_version, Some(5));
+    }
+
+    #[test]
+    fn test_macro_recording() {
+        use crossterm::event::{KeyCode, KeyModifiers};
+
+        let mut state = AppState::new(20);
+        assert!(state.finish_macro_recording().is_none());
+
+        state.start_macro_recording("triage");
+        assert!(state.is_recording_macro());
+        for code in [KeyCode::Down, KeyCode::Enter] {
+            state
+                .record_macro_key(&KeyEvent::new(code, KeyModifiers::NONE))
+                .unwrap();
+        }
+
+        assert_eq!(
+            state.finish_macro_recording(),
+            Some(Ok("triage".to_string()))
+        );
+        assert!(!state.is_recording_macro());
+        assert_eq!(state.macros.get("triage").unwrap().keys.len(), 2);
+
+        // An empty recording is discarded
+        state.start_macro_recording("empty");
+        assert_eq!(state.finish_macro_recording(), Some(Err(MacroError::Empty)));
+        assert!(state.macros.get("empty").is_none());
+    }
+
+    #[test]
+    fn test_toasts_are_capped_and_expire() {
+        use orkee_notifications::NotificationSeverity;
+
+        let mut state = AppState::new(20);
+        for i in 0..5 {
+            state.push_toast(Toast::new(
+                format!("Toast {}", i),
+                "message".to_string(),
+                NotificationSeverity::Info,
+            ));
+        }
+        assert_eq!(state.toasts.len(), MAX_TOASTS);
+        assert_eq!(state.toasts[0].title, "Toast 2");
+
+        assert!(!state.expire_toasts(Instant::now()));
+        assert!(state.expire_toasts(Instant::now() + Duration::from_secs(60)));
+        assert!(state.toasts.is_empty());
+    }
+
     #[test]
     fn test_single_escape_detection() {
         let mut state = AppState::new(20);
@@ -2007,6 +2570,54 @@ mod tests {
         assert_eq!(state.input_buffer.content(), "Third message");
     }
 
+    #[test]
+    fn test_execution_card_updates_in_place() {
+        let mut state = AppState::new(20);
+        let execution = |status: &str| -> AgentExecution {
+            serde_json::from_value(serde_json::json!({
+                "id": "exec-1",
+                "task_id": "t1",
+                "agent_id": "claude-code",
+                "started_at": Utc::now(),
+                "status": status,
+                "retry_attempt": 0,
+                "created_at": Utc::now(),
+                "updated_at": Utc::now(),
+            }))
+            .unwrap()
+        };
+        let request = LaunchRequest {
+            task_id: "t1".to_string(),
+            task_title: "Fix login".to_string(),
+            agent_id: "claude-code".to_string(),
+            agent_name: "Claude Code".to_string(),
+            template_id: "rust-toolchain".to_string(),
+            template_name: "Rust toolchain".to_string(),
+        };
+
+        state.pin_execution_card(request, execution("Running"));
+        state.add_system_message("Later message".to_string());
+        assert_eq!(
+            state.active_execution_card_ids(),
+            vec!["exec-1".to_string()]
+        );
+        let card_index = state.message_history.len() - 2;
+        assert!(state.message_history.messages()[card_index]
+            .content
+            .contains("Running"));
+
+        state.update_execution_cards(vec![execution("Completed")]);
+        let card = &state.message_history.messages()[card_index];
+        assert!(card.content.contains("Completed"));
+        assert!(card.content.contains("Fix login"));
+        assert!(state.active_execution_card_ids().is_empty());
+
+        // Clearing the chat drops the card
+        state.message_history.clear();
+        state.update_execution_cards(Vec::new());
+        assert!(state.execution_cards.is_empty());
+    }
+
     #[test]
     fn test_focus_cycling() {
         let mut state = AppState::new(20);
@@ -1,7 +1,8 @@
+use crate::command_popup::MAX_VISIBLE_COMMANDS;
 use crate::state::{AppState, FocusArea};
 use crate::ui::widgets::command_popup::{CommandHintWidget, CommandPopupWidget};
 use crate::ui::widgets::{calculate_mention_popup_area, MentionPopupWidget};
-use crate::ui::widgets::{ChatWidget, InputWidget};
+use crate::ui::widgets::{ChatWidget, CommandPromptWidget, InputWidget};
 use ratatui::prelude::*;
 
 /// Render the chat interface
@@ -35,7 +36,8 @@ pub fn render_with_area(frame: &mut Frame, state: &AppState, area: Rect) {
     let chat_widget = ChatWidget::new(state.messages())
         .scroll_offset(state.scroll_offset())
         .show_timestamps(false)
-        .focused(state.focus_area() == &FocusArea::Chat);
+        .focused(state.focus_area() == &FocusArea::Chat)
+        .selected(state.selected_message);
 
     frame.render_widget(chat_widget, chunks[0]);
 
@@ -52,12 +54,39 @@ pub fn render_with_area(frame: &mut Frame, state: &AppState, area: Rect) {
         render_command_popup_overlay(frame, command_popup, chunks[1]);
     }
 
+    // Render the argument prompt once a command with arguments is picked
+    if let Some(prompt) = state.command_prompt() {
+        render_command_prompt_overlay(frame, prompt, chunks[1]);
+    }
+
     // Render mention popup overlay if in mention mode
     if let Some(mention_popup) = state.mention_popup() {
         render_mention_popup_overlay(frame, mention_popup, chunks[1]);
     }
 }
 
+/// Render the argument prompt as an overlay above the input area
+fn render_command_prompt_overlay(
+    frame: &mut Frame,
+    prompt: &crate::command_prompt::CommandPrompt,
+    input_area: Rect,
+) {
+    let area = frame.area();
+    let widget = CommandPromptWidget::new(prompt);
+    let height = widget.height().min(input_area.y.saturating_sub(area.y));
+    if height == 0 {
+        return;
+    }
+
+    let prompt_area = Rect {
+        x: area.x + 2,
+        y: input_area.y - height,
+        width: area.width.saturating_sub(4).min(80),
+        height,
+    };
+    frame.render_widget(widget, prompt_area);
+}
+
 /// Render the command popup as an overlay above the input area
 fn render_command_popup_overlay(
     frame: &mut Frame,
@@ -67,7 +96,7 @@ fn render_command_popup_overlay(
     let area = frame.area();
 
     // Calculate popup position (above the input area)
-    let popup_height = (popup.result_count() as u16).clamp(1, 8) + 2; // +2 for borders
+    let popup_height = (popup.result_count() as u16).clamp(1, MAX_VISIBLE_COMMANDS as u16) + 2; // +2 for borders
     let popup_width = area.width.saturating_sub(4).min(80); // Leave some margin
 
     // Position popup above input area
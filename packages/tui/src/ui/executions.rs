@@ -0,0 +1,246 @@
+// ABOUTME: Execution monitor rendering with status badges and a log detail pane
+// ABOUTME: Lists recent agent executions; the detail pane tails their sandbox command output
+
+use crate::executions::{is_cancellable, is_retryable, ExecutionsState};
+use crate::state::AppState;
+use orkee_executions::{AgentExecution, ExecutionStatus};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+/// Render the execution monitor with specific area
+pub fn render_with_area(frame: &mut Frame, state: &AppState, area: Rect) {
+    let executions = &state.executions;
+
+    let (list_area, detail_area) = if executions.detail_open {
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(area);
+        (chunks[0], Some(chunks[1]))
+    } else {
+        (area, None)
+    };
+
+    render_list(frame, executions, list_area);
+    if let (Some(detail_area), Some(execution)) = (detail_area, executions.selected_execution()) {
+        render_detail(frame, executions, execution, detail_area);
+    }
+}
+
+fn render_list(frame: &mut Frame, executions: &ExecutionsState, area: Rect) {
+    let block = Block::default()
+        .title(format!("Executions ({})", executions.executions.len()))
+        .title_style(Style::default().fg(Color::Cyan))
+        .borders(Borders::ALL);
+
+    if let Some(error) = &executions.error {
+        let paragraph = Paragraph::new(format!("Failed to load executions: {}", error))
+            .block(block)
+            .style(Style::default().fg(Color::Red))
+            .wrap(Wrap { trim: true });
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    if executions.executions.is_empty() {
+        let paragraph = Paragraph::new("No agent executions yet.")
+            .block(block)
+            .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = executions
+        .executions
+        .iter()
+        .map(|execution| {
+            let (label, color) = status_badge(&execution.status);
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:<11}", label),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    execution.started_at.format("%m-%d %H:%M ").to_string(),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::styled(
+                    execution
+                        .agent_id
+                        .clone()
+                        .unwrap_or_else(|| "agent".to_string()),
+                    Style::default().fg(Color::White),
+                ),
+            ];
+            if let Some(model) = &execution.model {
+                spans.push(Span::styled(
+                    format!(" ({})", model),
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            if execution.retry_attempt > 0 {
+                spans.push(Span::styled(
+                    format!(" retry {}", execution.retry_attempt),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    );
+    let mut list_state = ListState::default();
+    list_state.select(Some(executions.selected));
+    frame.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn render_detail(
+    frame: &mut Frame,
+    executions: &ExecutionsState,
+    execution: &AgentExecution,
+    area: Rect,
+) {
+    let mut actions = Vec::new();
+    if is_cancellable(execution) {
+        actions.push("c Cancel");
+    }
+    if is_retryable(execution) {
+        actions.push("r Retry");
+    }
+    let title = if actions.is_empty() {
+        format!("Execution {}", execution.id)
+    } else {
+        format!("Execution {} - {}", execution.id, actions.join(" • "))
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(Style::default().fg(Color::Cyan))
+        .borders(Borders::ALL);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let summary = summary_lines(execution);
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(summary.len() as u16), Constraint::Min(0)])
+        .split(inner);
+    frame.render_widget(Paragraph::new(Text::from(summary)), chunks[0]);
+
+    let logs = log_lines(executions, execution);
+    // Follow the tail so new output stays in view while the execution runs;
+    // the top border takes one row
+    let visible = chunks[1].height.saturating_sub(1);
+    let scroll = (logs.len() as u16).saturating_sub(visible);
+    let paragraph = Paragraph::new(Text::from(logs))
+        .block(Block::default().title("Logs").borders(Borders::TOP))
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, chunks[1]);
+}
+
+/// Key facts about an execution, above its logs
+fn summary_lines(execution: &AgentExecution) -> Vec<Line<'static>> {
+    let label = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let (status, color) = status_badge(&execution.status);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Status: ", label),
+            Span::styled(status, Style::default().fg(color)),
+        ]),
+        Line::from(vec![
+            Span::styled("Task: ", label),
+            Span::raw(execution.task_id.clone()),
+        ]),
+        Line::from(vec![
+            Span::styled("Started: ", label),
+            Span::raw(
+                execution
+                    .started_at
+                    .format("%Y-%m-%d %H:%M:%S UTC")
+                    .to_string(),
+            ),
+        ]),
+    ];
+
+    if let Some(cost) = execution.total_cost {
+        lines.push(Line::from(vec![
+            Span::styled("Cost: ", label),
+            Span::styled(format!("${:.4}", cost), Style::default().fg(Color::Green)),
+        ]));
+    }
+    if let Some(pr_url) = &execution.pr_url {
+        lines.push(Line::from(vec![
+            Span::styled("PR: ", label),
+            Span::styled(pr_url.clone(), Style::default().fg(Color::Blue)),
+        ]));
+    }
+    if let Some(error) = &execution.error_message {
+        lines.push(Line::from(vec![
+            Span::styled("Error: ", label),
+            Span::styled(error.clone(), Style::default().fg(Color::Red)),
+        ]));
+    }
+
+    lines
+}
+
+/// Output of the sandbox commands run by an execution, falling back to the agent's response
+fn log_lines(executions: &ExecutionsState, execution: &AgentExecution) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    for run in &executions.runs {
+        lines.push(Line::from(Span::styled(
+            format!("$ {}", run.command),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for line in run.stdout.as_deref().unwrap_or("").lines() {
+            lines.push(Line::raw(line.to_string()));
+        }
+        for line in run.stderr.as_deref().unwrap_or("").lines() {
+            lines.push(Line::styled(
+                line.to_string(),
+                Style::default().fg(Color::Red),
+            ));
+        }
+        if let Some(exit_code) = run.exit_code {
+            lines.push(Line::styled(
+                format!("exit {}", exit_code),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+    }
+
+    if lines.is_empty() {
+        match &execution.response {
+            Some(response) => {
+                lines.extend(response.lines().map(|line| Line::raw(line.to_string())));
+            }
+            None => lines.push(Line::styled(
+                "No output yet.",
+                Style::default().fg(Color::Gray),
+            )),
+        }
+    }
+
+    lines
+}
+
+/// Badge text and color for an execution status
+fn status_badge(status: &ExecutionStatus) -> (&'static str, Color) {
+    match status {
+        ExecutionStatus::Queued => ("◷ QUEUED", Color::Yellow),
+        ExecutionStatus::Running => ("● RUNNING", Color::Cyan),
+        ExecutionStatus::Completed => ("✓ DONE", Color::Green),
+        ExecutionStatus::Failed => ("✗ FAILED", Color::Red),
+        ExecutionStatus::Cancelled => ("○ CANCELLED", Color::Gray),
+    }
+}
@@ -1,11 +1,54 @@
 pub mod chat;
+pub mod executions;
 pub mod projects;
 pub mod widgets;
 
-use crate::state::AppState;
+use crate::state::{AppState, Screen};
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::*;
-use widgets::{ConfirmationDialogWidget, StatusBarWidget};
+use widgets::{
+    CheatSheetWidget, ConfirmationDialogWidget, ContextPaneWidget, StatusBarWidget, ToastWidget,
+};
+
+/// Narrowest main area that still fits the chat and the context pane side by side
+const MIN_SPLIT_WIDTH: u16 = 80;
+
+/// How the main content area is divided
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LayoutMode {
+    /// The current screen fills the main area
+    #[default]
+    Single,
+    /// The chat shares the main area with a context pane on the right
+    Split,
+}
+
+impl LayoutMode {
+    /// The other layout mode
+    pub fn toggled(self) -> Self {
+        match self {
+            LayoutMode::Single => LayoutMode::Split,
+            LayoutMode::Split => LayoutMode::Single,
+        }
+    }
+}
+
+/// Divide the main area between the current screen and, when split, the context pane.
+/// Only the chat screen is split, and only when both panes fit.
+pub fn split_main_area(area: Rect, mode: LayoutMode, screen: &Screen) -> (Rect, Option<Rect>) {
+    if mode != LayoutMode::Split || *screen != Screen::Chat || area.width < MIN_SPLIT_WIDTH {
+        return (area, None);
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(60), // Chat
+            Constraint::Percentage(40), // Context pane
+        ])
+        .split(area);
+    (chunks[0], Some(chunks[1]))
+}
 
 /// Main UI rendering function
 pub fn render(frame: &mut Frame, state: &AppState) {
@@ -20,26 +63,44 @@ pub fn render(frame: &mut Frame, state: &AppState) {
 
     let main_area = chunks[0];
     let status_area = chunks[1];
+    let (screen_area, context_area) =
+        split_main_area(main_area, state.layout_mode, &state.current_screen);
 
     // Render the main screen content
     match state.current_screen {
-        crate::state::Screen::Chat => chat::render_with_area(frame, state, main_area),
-        crate::state::Screen::Projects => {
+        Screen::Chat => chat::render_with_area(frame, state, screen_area),
+        Screen::Projects => {
             if state.is_form_mode() {
-                projects::render_form_with_area(frame, state, main_area);
+                projects::render_form_with_area(frame, state, screen_area);
             } else {
-                projects::render_with_area(frame, state, main_area);
+                projects::render_with_area(frame, state, screen_area);
             }
         }
-        crate::state::Screen::ProjectDetail => {
-            projects::render_detail_with_area(frame, state, main_area)
-        }
+        Screen::ProjectDetail => projects::render_detail_with_area(frame, state, screen_area),
+        Screen::Executions => executions::render_with_area(frame, state, screen_area),
+    }
+
+    // Render the context pane beside the chat in split layout
+    if let Some(context_area) = context_area {
+        let context_pane =
+            ContextPaneWidget::new(state.context_project(), state.conversation_context.as_ref());
+        frame.render_widget(context_pane, context_area);
     }
 
     // Render status bar at bottom
     let status_bar = StatusBarWidget::new(state);
     frame.render_widget(status_bar, status_area);
 
+    // Render notification toasts over the main content
+    if !state.toasts.is_empty() {
+        frame.render_widget(ToastWidget::new(&state.toasts), main_area);
+    }
+
+    // Render the key binding cheat sheet over the screen it describes
+    if let Some(sheet) = &state.cheat_sheet {
+        frame.render_widget(CheatSheetWidget::new(sheet, &state.keymap), main_area);
+    }
+
     // Render confirmation dialog on top if one is active
     if let Some(dialog) = &state.confirmation_dialog {
         let dialog_widget = ConfirmationDialogWidget::new(dialog);
@@ -1,8 +1,13 @@
+use crate::project_detail::{is_server_active, DetailTab, ProjectDetailState, TabData};
 use crate::state::AppState;
 use crate::ui::widgets::{calculate_search_popup_area, SearchPopupWidget};
+use chrono::{DateTime, Utc};
+use orkee_ai::AiUsageStats;
+use orkee_projects::Project;
+use orkee_tasks::CheckStatus;
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Tabs, Wrap};
 
 /// Render the projects screen
 pub fn render(frame: &mut Frame, state: &AppState) {
@@ -198,211 +203,428 @@ pub fn render_detail_with_area(frame: &mut Frame, state: &AppState, area: Rect)
             .title(title)
             .title_style(Style::default().fg(Color::Cyan))
             .borders(Borders::ALL);
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
 
-        // Format project details
-        let mut details = vec![
-            Line::from(vec![
-                Span::styled(
-                    "Name: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(project.name.clone(), Style::default().fg(Color::White)),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "ID: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(project.id.clone(), Style::default().fg(Color::Gray)),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "Path: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    project.project_root.clone(),
-                    Style::default().fg(Color::Green),
-                ),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "Status: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    format!("{:?}", project.status),
-                    match format!("{:?}", project.status).to_lowercase().as_str() {
-                        "active" => Style::default().fg(Color::Green),
-                        "inactive" => Style::default().fg(Color::Gray),
-                        "archived" => Style::default().fg(Color::Yellow),
-                        _ => Style::default().fg(Color::White),
-                    },
-                ),
-            ]),
-            Line::from(vec![
-                Span::styled(
-                    "Priority: ",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    format!("{:?}", project.priority),
-                    Style::default().fg(Color::White),
-                ),
-            ]),
-            Line::raw(""),
-        ];
-
-        // Add description if present
-        if let Some(description) = &project.description {
-            details.push(Line::from(vec![Span::styled(
-                "Description:",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
-            )]));
-            details.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(description.clone(), Style::default().fg(Color::White)),
-            ]));
-            details.push(Line::raw(""));
-        }
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(2), Constraint::Min(0)])
+            .split(inner);
 
-        // Add tags if present
-        if let Some(tags) = &project.tags {
-            if !tags.is_empty() {
-                details.push(Line::from(vec![
-                    Span::styled(
-                        "Tags: ",
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(tags.join(", "), Style::default().fg(Color::Magenta)),
-                ]));
-                details.push(Line::raw(""));
-            }
-        }
+        let detail = state
+            .project_detail
+            .as_ref()
+            .filter(|detail| detail.project_id == project.id);
+        let tab = detail.map_or(DetailTab::Overview, |detail| detail.tab);
 
-        // Add scripts if present
-        if project.setup_script.is_some()
-            || project.dev_script.is_some()
-            || project.cleanup_script.is_some()
-        {
-            details.push(Line::from(vec![Span::styled(
-                "Scripts:",
+        let titles: Vec<Line> = DetailTab::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, tab)| Line::from(format!("{} {}", i + 1, tab.title())))
+            .collect();
+        let tabs = Tabs::new(titles)
+            .select(tab.index())
+            .style(Style::default().fg(Color::Gray))
+            .highlight_style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .fg(Color::Cyan)
                     .add_modifier(Modifier::BOLD),
-            )]));
-
-            if let Some(setup) = &project.setup_script {
-                details.push(Line::from(vec![
-                    Span::raw("  Setup: "),
-                    Span::styled(setup.clone(), Style::default().fg(Color::Cyan)),
-                ]));
+            )
+            .block(Block::default().borders(Borders::BOTTOM));
+        frame.render_widget(tabs, chunks[0]);
+
+        match (tab, detail) {
+            (DetailTab::Overview, _) | (_, None) => {
+                let paragraph =
+                    Paragraph::new(Text::from(overview_lines(project))).wrap(Wrap { trim: true });
+                frame.render_widget(paragraph, chunks[1]);
             }
-
-            if let Some(dev) = &project.dev_script {
-                details.push(Line::from(vec![
-                    Span::raw("  Dev: "),
-                    Span::styled(dev.clone(), Style::default().fg(Color::Cyan)),
-                ]));
+            (DetailTab::Tasks, Some(detail)) => {
+                render_tab_list(frame, chunks[1], detail, &detail.tasks, |task| {
+                    let mut spans = vec![
+                        Span::styled(
+                            format!("{:<12}", format!("{:?}", task.status)),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                        Span::styled(
+                            format!("{:<8}", format!("{:?}", task.priority)),
+                            Style::default().fg(Color::Gray),
+                        ),
+                        Span::styled(task.title.clone(), Style::default().fg(Color::White)),
+                    ];
+                    if let Some(checks) = task.checks_status {
+                        let (label, color) = checks_badge(checks);
+                        spans.push(Span::styled(
+                            format!("  {}", label),
+                            Style::default().fg(color),
+                        ));
+                    }
+                    ListItem::new(Line::from(spans))
+                });
             }
-
-            if let Some(cleanup) = &project.cleanup_script {
-                details.push(Line::from(vec![
-                    Span::raw("  Cleanup: "),
-                    Span::styled(cleanup.clone(), Style::default().fg(Color::Cyan)),
-                ]));
+            (DetailTab::Servers, Some(detail)) => {
+                render_tab_list(frame, chunks[1], detail, &detail.servers, |server| {
+                    let status_color = if is_server_active(server) {
+                        Color::Green
+                    } else {
+                        Color::Gray
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("{:<10}", format!("{:?}", server.status)),
+                            Style::default().fg(status_color),
+                        ),
+                        Span::styled(
+                            format!("{:<7}", server.port),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                        Span::styled(
+                            server
+                                .preview_url
+                                .clone()
+                                .unwrap_or_else(|| "-".to_string()),
+                            Style::default().fg(Color::Blue),
+                        ),
+                        Span::styled(
+                            server
+                                .framework_name
+                                .as_ref()
+                                .map(|name| format!("  {}", name))
+                                .unwrap_or_default(),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ]))
+                });
+            }
+            (DetailTab::Git, Some(detail)) => {
+                render_tab_list(frame, chunks[1], detail, &detail.commits, |commit| {
+                    let date = DateTime::<Utc>::from_timestamp(commit.timestamp, 0)
+                        .map(|time| time.format("%Y-%m-%d").to_string())
+                        .unwrap_or_default();
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("{} ", commit.short_id),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                        Span::styled(format!("{} ", date), Style::default().fg(Color::Gray)),
+                        Span::styled(commit.summary.clone(), Style::default().fg(Color::White)),
+                        Span::styled(
+                            format!("  {}", commit.author),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ]))
+                });
+            }
+            (DetailTab::Usage, Some(detail)) => {
+                let paragraph = match &detail.usage {
+                    TabData::Loaded(stats) => Paragraph::new(Text::from(usage_lines(stats))),
+                    other => tab_placeholder(other),
+                };
+                frame.render_widget(paragraph, chunks[1]);
             }
-
-            details.push(Line::raw(""));
         }
+    } else {
+        // No project selected - this shouldn't happen, but handle it gracefully
+        let block = Block::default()
+            .title("Project Details - No Project Selected")
+            .title_style(Style::default().fg(Color::Red))
+            .borders(Borders::ALL);
 
-        // Add git info if available
-        if let Some(git_info) = &project.git_repository {
-            details.push(Line::from(vec![Span::styled(
-                "Git Repository:",
+        let paragraph =
+            Paragraph::new("No project selected. Press Esc to return to projects list.")
+                .block(block)
+                .style(Style::default().fg(Color::Gray));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Render one list tab, or a placeholder while its data isn't loaded
+fn render_tab_list<T>(
+    frame: &mut Frame,
+    area: Rect,
+    detail: &ProjectDetailState,
+    data: &TabData<Vec<T>>,
+    to_item: impl Fn(&T) -> ListItem<'static>,
+) {
+    match data {
+        TabData::Loaded(rows) if !rows.is_empty() => {
+            let items: Vec<ListItem> = rows.iter().map(to_item).collect();
+            let list = List::new(items).highlight_style(
                 Style::default()
-                    .fg(Color::Yellow)
+                    .bg(Color::DarkGray)
                     .add_modifier(Modifier::BOLD),
-            )]));
-            details.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(git_info.url.clone(), Style::default().fg(Color::Blue)),
-            ]));
-            details.push(Line::from(vec![
-                Span::raw("  Branch: "),
+            );
+            let mut list_state = ListState::default();
+            list_state.select(Some(detail.selected));
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+        TabData::Loaded(_) => {
+            let paragraph =
+                Paragraph::new("Nothing here yet.").style(Style::default().fg(Color::Gray));
+            frame.render_widget(paragraph, area);
+        }
+        other => frame.render_widget(tab_placeholder(other), area),
+    }
+}
+
+/// Badge text and color for a task's latest CI result
+fn checks_badge(status: CheckStatus) -> (&'static str, Color) {
+    match status {
+        CheckStatus::Pending => ("● CI running", Color::Yellow),
+        CheckStatus::Passed => ("✓ CI passed", Color::Green),
+        CheckStatus::Failed => ("✗ CI failed", Color::Red),
+        CheckStatus::Cancelled => ("○ CI cancelled", Color::Gray),
+    }
+}
+
+/// Message shown while a tab is loading or when its data is unavailable
+fn tab_placeholder<T>(data: &TabData<T>) -> Paragraph<'static> {
+    match data {
+        TabData::Unavailable(reason) => Paragraph::new(reason.clone())
+            .style(Style::default().fg(Color::Red))
+            .wrap(Wrap { trim: true }),
+        _ => Paragraph::new("Loading...").style(Style::default().fg(Color::Gray)),
+    }
+}
+
+/// AI spend summary for the usage tab
+fn usage_lines(stats: &AiUsageStats) -> Vec<Line<'static>> {
+    let label = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("Total cost: ", label),
+            Span::styled(
+                format!("${:.4}", stats.total_cost),
+                Style::default().fg(Color::Green),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled("Requests: ", label),
+            Span::raw(format!(
+                "{} ({} failed)",
+                stats.total_requests, stats.failed_requests
+            )),
+        ]),
+        Line::from(vec![
+            Span::styled("Tokens: ", label),
+            Span::raw(format!(
+                "{} ({} in / {} out)",
+                stats.total_tokens, stats.total_input_tokens, stats.total_output_tokens
+            )),
+        ]),
+        Line::raw(""),
+    ];
+
+    if !stats.by_model.is_empty() {
+        lines.push(Line::from(Span::styled("By model:", label)));
+        for model in &stats.by_model {
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<32}", model.model)),
                 Span::styled(
-                    git_info.branch.as_deref().unwrap_or("unknown").to_string(),
+                    format!("${:<10.4}", model.total_cost),
                     Style::default().fg(Color::Green),
                 ),
+                Span::styled(
+                    format!("{} requests", model.count),
+                    Style::default().fg(Color::Gray),
+                ),
             ]));
-            details.push(Line::raw(""));
         }
+    }
 
-        // Add timestamps
-        details.push(Line::from(vec![
+    lines
+}
+
+/// Project info shown on the overview tab
+fn overview_lines(project: &Project) -> Vec<Line<'static>> {
+    // Format project details
+    let mut details = vec![
+        Line::from(vec![
             Span::styled(
-                "Created: ",
+                "Name: ",
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
+            Span::styled(project.name.clone(), Style::default().fg(Color::White)),
+        ]),
+        Line::from(vec![
             Span::styled(
-                project
-                    .created_at
-                    .format("%Y-%m-%d %H:%M:%S UTC")
-                    .to_string(),
-                Style::default().fg(Color::Gray),
+                "ID: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
             ),
-        ]));
-        details.push(Line::from(vec![
+            Span::styled(project.id.clone(), Style::default().fg(Color::Gray)),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Path: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                project.project_root.clone(),
+                Style::default().fg(Color::Green),
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                "Status: ",
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{:?}", project.status),
+                match format!("{:?}", project.status).to_lowercase().as_str() {
+                    "active" => Style::default().fg(Color::Green),
+                    "inactive" => Style::default().fg(Color::Gray),
+                    "archived" => Style::default().fg(Color::Yellow),
+                    _ => Style::default().fg(Color::White),
+                },
+            ),
+        ]),
+        Line::from(vec![
             Span::styled(
-                "Updated: ",
+                "Priority: ",
                 Style::default()
                     .fg(Color::Yellow)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(
-                project
-                    .updated_at
-                    .format("%Y-%m-%d %H:%M:%S UTC")
-                    .to_string(),
-                Style::default().fg(Color::Gray),
+                format!("{:?}", project.priority),
+                Style::default().fg(Color::White),
             ),
+        ]),
+        Line::raw(""),
+    ];
+
+    // Add description if present
+    if let Some(description) = &project.description {
+        details.push(Line::from(vec![Span::styled(
+            "Description:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]));
+        details.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(description.clone(), Style::default().fg(Color::White)),
         ]));
+        details.push(Line::raw(""));
+    }
 
-        let text = Text::from(details);
-        let paragraph = Paragraph::new(text)
-            .block(block)
-            .wrap(ratatui::widgets::Wrap { trim: true });
+    // Add tags if present
+    if let Some(tags) = &project.tags {
+        if !tags.is_empty() {
+            details.push(Line::from(vec![
+                Span::styled(
+                    "Tags: ",
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(tags.join(", "), Style::default().fg(Color::Magenta)),
+            ]));
+            details.push(Line::raw(""));
+        }
+    }
 
-        frame.render_widget(paragraph, area);
-    } else {
-        // No project selected - this shouldn't happen, but handle it gracefully
-        let block = Block::default()
-            .title("Project Details - No Project Selected")
-            .title_style(Style::default().fg(Color::Red))
-            .borders(Borders::ALL);
+    // Add scripts if present
+    if project.setup_script.is_some()
+        || project.dev_script.is_some()
+        || project.cleanup_script.is_some()
+    {
+        details.push(Line::from(vec![Span::styled(
+            "Scripts:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]));
+
+        if let Some(setup) = &project.setup_script {
+            details.push(Line::from(vec![
+                Span::raw("  Setup: "),
+                Span::styled(setup.clone(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
 
-        let paragraph =
-            Paragraph::new("No project selected. Press Esc to return to projects list.")
-                .block(block)
-                .style(Style::default().fg(Color::Gray));
-        frame.render_widget(paragraph, area);
+        if let Some(dev) = &project.dev_script {
+            details.push(Line::from(vec![
+                Span::raw("  Dev: "),
+                Span::styled(dev.clone(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+
+        if let Some(cleanup) = &project.cleanup_script {
+            details.push(Line::from(vec![
+                Span::raw("  Cleanup: "),
+                Span::styled(cleanup.clone(), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+
+        details.push(Line::raw(""));
     }
+
+    // Add git info if available
+    if let Some(git_info) = &project.git_repository {
+        details.push(Line::from(vec![Span::styled(
+            "Git Repository:",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )]));
+        details.push(Line::from(vec![
+            Span::raw("  "),
+            Span::styled(git_info.url.clone(), Style::default().fg(Color::Blue)),
+        ]));
+        details.push(Line::from(vec![
+            Span::raw("  Branch: "),
+            Span::styled(
+                git_info.branch.as_deref().unwrap_or("unknown").to_string(),
+                Style::default().fg(Color::Green),
+            ),
+        ]));
+        details.push(Line::raw(""));
+    }
+
+    // Add timestamps
+    details.push(Line::from(vec![
+        Span::styled(
+            "Created: ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            project
+                .created_at
+                .format("%Y-%m-%d %H:%M:%S UTC")
+                .to_string(),
+            Style::default().fg(Color::Gray),
+        ),
+    ]));
+    details.push(Line::from(vec![
+        Span::styled(
+            "Updated: ",
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            project
+                .updated_at
+                .format("%Y-%m-%d %H:%M:%S UTC")
+                .to_string(),
+            Style::default().fg(Color::Gray),
+        ),
+    ]));
+
+    details
 }
 
 /// Render the project creation form
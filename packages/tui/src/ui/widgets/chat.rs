@@ -11,6 +11,7 @@ pub struct ChatWidget<'a> {
     scroll_offset: usize,
     show_timestamps: bool,
     focused: bool,
+    selected: Option<usize>,
 }
 
 impl<'a> ChatWidget<'a> {
@@ -21,6 +22,7 @@ impl<'a> ChatWidget<'a> {
             scroll_offset: 0,
             show_timestamps: false,
             focused: false,
+            selected: None,
         }
     }
 
@@ -42,8 +44,14 @@ impl<'a> ChatWidget<'a> {
         self
     }
 
+    /// Highlight the message at this index
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
     /// Format a message for display
-    fn format_message<'b>(&self, message: &'b ChatMessage) -> Vec<Line<'b>> {
+    fn format_message<'b>(&self, message: &'b ChatMessage, selected: bool) -> Vec<Line<'b>> {
         let mut lines = Vec::new();
 
         // Create author line with styling
@@ -59,7 +67,16 @@ impl<'a> ChatWidget<'a> {
                 .add_modifier(Modifier::BOLD),
         };
 
-        let mut author_line = vec![Span::styled(message.author_label(), author_style)];
+        let mut author_line = Vec::new();
+        if selected {
+            author_line.push(Span::styled("▶ ", Style::default().fg(Color::Yellow)));
+        }
+        let author_style = if selected {
+            author_style.add_modifier(Modifier::REVERSED)
+        } else {
+            author_style
+        };
+        author_line.push(Span::styled(message.author_label(), author_style));
 
         if message.edited {
             author_line.push(Span::styled(" (edited)", Style::default().fg(Color::Gray)));
@@ -95,7 +112,7 @@ impl<'a> ChatWidget<'a> {
 impl<'a> Widget for ChatWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let (title, border_color) = if self.focused {
-            ("Messages (↑/↓ to scroll, Tab to switch)", Color::Yellow)
+            ("Messages (↑/↓ to select, Tab to switch)", Color::Yellow)
         } else {
             ("Messages (Tab to focus)", Color::Gray)
         };
@@ -109,8 +126,8 @@ impl<'a> Widget for ChatWidget<'a> {
 
         // Collect all formatted message lines
         let mut all_lines = Vec::new();
-        for message in self.messages.iter().rev() {
-            let mut message_lines = self.format_message(message);
+        for (index, message) in self.messages.iter().enumerate().rev() {
+            let mut message_lines = self.format_message(message, self.selected == Some(index));
             message_lines.reverse(); // Since we're iterating in reverse
             all_lines.extend(message_lines);
         }
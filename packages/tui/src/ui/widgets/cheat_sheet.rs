@@ -0,0 +1,128 @@
+use crate::cheat_sheet::CheatSheet;
+use crate::events::keymap::{Keymap, KeymapScope};
+use crate::state::Screen;
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Width of the key column
+const KEYS_WIDTH: usize = 18;
+
+/// Widget for rendering the key binding cheat sheet
+pub struct CheatSheetWidget<'a> {
+    sheet: &'a CheatSheet,
+    keymap: &'a Keymap,
+}
+
+impl<'a> CheatSheetWidget<'a> {
+    pub fn new(sheet: &'a CheatSheet, keymap: &'a Keymap) -> Self {
+        Self { sheet, keymap }
+    }
+
+    fn screen_title(screen: &Screen) -> &'static str {
+        match screen {
+            Screen::Chat => "Chat",
+            Screen::Projects => "Projects",
+            Screen::ProjectDetail => "Project Detail",
+            Screen::Executions => "Executions",
+        }
+    }
+
+    /// Binding rows with a heading before each scope
+    fn lines(&self) -> Vec<Line<'static>> {
+        let mut lines = Vec::new();
+        let mut current_scope: Option<KeymapScope> = None;
+
+        for entry in self.sheet.entries(self.keymap) {
+            if current_scope != Some(entry.scope) {
+                current_scope = Some(entry.scope);
+                lines.push(Line::from(Span::styled(
+                    entry.scope.title(),
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )));
+            }
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}", entry.keys, width = KEYS_WIDTH),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::styled(
+                    entry.action.description(),
+                    Style::default().fg(Color::White),
+                ),
+                // Name to use in keymap.toml
+                Span::styled(
+                    format!("  {}", entry.action.name()),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+
+        lines
+    }
+}
+
+impl<'a> Widget for CheatSheetWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let popup_area = calculate_cheat_sheet_area(area);
+        Clear.render(popup_area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                " Key Bindings: {} ",
+                Self::screen_title(self.sheet.screen())
+            ))
+            .style(Style::default().fg(Color::White));
+        let inner = block.inner(popup_area);
+        block.render(popup_area, buf);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2), // Query
+                Constraint::Min(1),    // Bindings
+                Constraint::Length(1), // Help
+            ])
+            .split(inner);
+
+        Paragraph::new(format!("Search: {}", self.sheet.query()))
+            .style(Style::default().fg(Color::Yellow))
+            .render(chunks[0], buf);
+
+        let lines = self.lines();
+        if lines.is_empty() {
+            Paragraph::new("No bindings match your search")
+                .style(Style::default().fg(Color::Gray))
+                .render(chunks[1], buf);
+        } else {
+            // Headings add rows, so keep the last rows reachable when scrolled
+            let max_scroll = lines.len().saturating_sub(chunks[1].height as usize);
+            let scroll = self.sheet.scroll().min(max_scroll);
+            Paragraph::new(lines)
+                .scroll((scroll as u16, 0))
+                .render(chunks[1], buf);
+        }
+
+        Paragraph::new("Type to search • ↑↓ Scroll • Tab Next Screen • Esc Close")
+            .style(Style::default().fg(Color::Gray))
+            .render(chunks[2], buf);
+    }
+}
+
+/// Calculate the area of the cheat sheet (centered overlay)
+pub fn calculate_cheat_sheet_area(full_area: Rect) -> Rect {
+    let width = std::cmp::min(70, full_area.width.saturating_sub(4));
+    let height = std::cmp::min(24, full_area.height.saturating_sub(4));
+
+    Rect {
+        x: full_area.x + full_area.width.saturating_sub(width) / 2,
+        y: full_area.y + full_area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    }
+}
@@ -1,4 +1,4 @@
-use crate::command_popup::CommandPopup;
+use crate::command_popup::{CommandPopup, MAX_VISIBLE_COMMANDS};
 use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Paragraph},
@@ -15,7 +15,7 @@ impl<'a> CommandPopupWidget<'a> {
     pub fn new(popup: &'a CommandPopup) -> Self {
         Self {
             popup,
-            max_rows: 8, // Default max rows
+            max_rows: MAX_VISIBLE_COMMANDS as u16,
         }
     }
 
@@ -137,8 +137,7 @@ impl<'a> Widget for CommandPopupWidget<'a> {
             let indicator_y = inner.y + display_count;
 
             if indicator_y < inner.y + inner.height {
-                let mut x = inner.x;
-                for ch in indicator.chars() {
+                for (x, ch) in (inner.x..).zip(indicator.chars()) {
                     if x >= inner.x + inner.width {
                         break;
                     }
@@ -147,7 +146,6 @@ impl<'a> Widget for CommandPopupWidget<'a> {
                             .fg(Color::DarkGray)
                             .add_modifier(Modifier::ITALIC),
                     );
-                    x += 1;
                 }
             }
         }
@@ -194,7 +192,7 @@ mod tests {
         let widget = CommandPopupWidget::new(&popup);
 
         // Should be able to create widget
-        assert_eq!(widget.max_rows, 8);
+        assert_eq!(widget.max_rows, MAX_VISIBLE_COMMANDS as u16);
     }
 
     #[test]
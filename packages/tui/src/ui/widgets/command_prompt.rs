@@ -0,0 +1,101 @@
+// ABOUTME: Widget for the inline argument prompt shown after picking a command with arguments
+// ABOUTME: Lists the options for the current argument along with any validation error
+
+use crate::command_prompt::CommandPrompt;
+use crate::slash_command::ArgKind;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+};
+
+/// Widget for rendering the argument prompt above the input
+pub struct CommandPromptWidget<'a> {
+    prompt: &'a CommandPrompt,
+    max_rows: u16,
+}
+
+impl<'a> CommandPromptWidget<'a> {
+    /// Create a new command prompt widget
+    pub fn new(prompt: &'a CommandPrompt) -> Self {
+        Self {
+            prompt,
+            max_rows: 6,
+        }
+    }
+
+    /// Set the maximum number of options to display
+    pub fn max_rows(mut self, max_rows: u16) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+
+    /// Height needed to show the prompt, including borders
+    pub fn height(&self) -> u16 {
+        let options = match self.prompt.arg.kind {
+            ArgKind::Text => 0,
+            _ => (self.prompt.options().len() as u16).clamp(1, self.max_rows),
+        };
+        // Options, the error or hint line, and borders
+        options + 1 + 2
+    }
+}
+
+impl<'a> Widget for CommandPromptWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "{} › {}",
+                self.prompt.entered(),
+                self.prompt.arg.name
+            ))
+            .title_style(
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::Cyan));
+
+        let mut lines = Vec::new();
+        if self.prompt.arg.kind != ArgKind::Text {
+            let options = self.prompt.options();
+            if options.is_empty() {
+                lines.push(Line::styled(
+                    "No matching options",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+
+            // Keep the selected option in view
+            let rows = self.max_rows as usize;
+            let first = (self.prompt.selected_index() + 1).saturating_sub(rows);
+            for (index, option) in options.iter().enumerate().skip(first).take(rows) {
+                let style = if index == self.prompt.selected_index() {
+                    Style::default()
+                        .bg(Color::Blue)
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Green)
+                };
+                lines.push(Line::styled(option.label.clone(), style));
+            }
+        }
+
+        lines.push(match &self.prompt.error {
+            Some(error) => Line::styled(error.clone(), Style::default().fg(Color::Red)),
+            None if self.prompt.arg.kind == ArgKind::Text => Line::styled(
+                "Type a value • Enter/Tab Accept • Esc Cancel",
+                Style::default().fg(Color::DarkGray),
+            ),
+            None => Line::styled(
+                "Type to filter • ↑↓ Select • Enter/Tab Accept • Esc Cancel",
+                Style::default().fg(Color::DarkGray),
+            ),
+        });
+
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+}
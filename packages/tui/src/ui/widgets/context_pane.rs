@@ -0,0 +1,234 @@
+use crate::context::ConversationContext;
+use crate::project_detail::server_url;
+use orkee_projects::Project;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Wrap},
+};
+
+/// Widget showing context for the project the conversation is about:
+/// its details, running dev servers, and recently updated tasks
+pub struct ContextPaneWidget<'a> {
+    project: Option<&'a Project>,
+    context: Option<&'a ConversationContext>,
+}
+
+impl<'a> ContextPaneWidget<'a> {
+    pub fn new(project: Option<&'a Project>, context: Option<&'a ConversationContext>) -> Self {
+        // Context loaded for a different project is stale until the next refresh
+        let context = context
+            .filter(|context| project.is_some_and(|project| project.id == context.project_id));
+        Self { project, context }
+    }
+
+    fn section_header(title: &str) -> Line<'static> {
+        Line::from(Span::styled(
+            title.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ))
+    }
+
+    fn placeholder(text: &str) -> Line<'static> {
+        Line::from(Span::styled(
+            format!("  {}", text),
+            Style::default().fg(Color::DarkGray),
+        ))
+    }
+
+    /// Lines describing the project itself
+    fn project_lines(project: &Project) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                project.project_root.clone(),
+                Style::default().fg(Color::Green),
+            )),
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(Color::Gray)),
+                Span::raw(format!("{:?}", project.status).to_lowercase()),
+                Span::styled("  Priority: ", Style::default().fg(Color::Gray)),
+                Span::raw(format!("{:?}", project.priority).to_lowercase()),
+            ]),
+        ];
+
+        if let Some(tags) = project.tags.as_ref().filter(|tags| !tags.is_empty()) {
+            lines.push(Line::from(vec![
+                Span::styled("Tags: ", Style::default().fg(Color::Gray)),
+                Span::styled(tags.join(", "), Style::default().fg(Color::Cyan)),
+            ]));
+        }
+
+        if let Some(description) = &project.description {
+            lines.push(Line::raw(""));
+            lines.push(Line::from(Span::styled(
+                description.clone(),
+                Style::default().fg(Color::White),
+            )));
+        }
+
+        lines
+    }
+
+    /// Lines listing running servers and recent tasks
+    fn activity_lines(&self) -> Vec<Line<'static>> {
+        let mut lines = vec![Line::raw(""), Self::section_header("Servers")];
+
+        match self.context {
+            None => lines.push(Self::placeholder("Loading...")),
+            Some(context) if context.servers.is_empty() => {
+                lines.push(Self::placeholder("None running"))
+            }
+            Some(context) => {
+                for server in &context.servers {
+                    let url = server_url(server);
+                    let mut spans = vec![
+                        Span::styled("  ● ", Style::default().fg(Color::Green)),
+                        Span::styled(url, Style::default().fg(Color::White)),
+                        Span::styled(
+                            format!(" {}", server.status.as_str()),
+                            Style::default().fg(Color::Gray),
+                        ),
+                    ];
+                    if let Some(framework) = &server.framework_name {
+                        spans.push(Span::styled(
+                            format!(" ({})", framework),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    lines.push(Line::from(spans));
+                }
+            }
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Self::section_header("Recent tasks"));
+
+        match self.context {
+            None => lines.push(Self::placeholder("Loading...")),
+            Some(context) if context.recent_tasks.is_empty() => {
+                lines.push(Self::placeholder("No tasks yet"))
+            }
+            Some(context) => {
+                for task in &context.recent_tasks {
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("  [{}] ", format!("{:?}", task.status).to_lowercase()),
+                            Style::default().fg(Color::Cyan),
+                        ),
+                        Span::styled(task.title.clone(), Style::default().fg(Color::White)),
+                    ]));
+                }
+            }
+        }
+
+        lines
+    }
+}
+
+impl<'a> Widget for ContextPaneWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let title = match self.project {
+            Some(project) => format!("Context: {}", project.name),
+            None => "Context".to_string(),
+        };
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::DarkGray));
+
+        let lines = match self.project {
+            Some(project) => {
+                let mut lines = Self::project_lines(project);
+                lines.extend(self.activity_lines());
+                lines
+            }
+            None => vec![Line::from(Span::styled(
+                "Mention a project with @ or select one on the projects screen to see its context here.",
+                Style::default().fg(Color::Gray),
+            ))],
+        };
+
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_project(id: &str, name: &str) -> Project {
+        Project {
+            id: id.to_string(),
+            name: name.to_string(),
+            project_root: format!("/path/{}", name),
+            status: orkee_projects::ProjectStatus::Building,
+            priority: orkee_projects::Priority::High,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            tags: Some(vec!["web".to_string()]),
+            description: None,
+            setup_script: None,
+            dev_script: None,
+            cleanup_script: None,
+            git_repository: None,
+            rank: None,
+            task_source: None,
+            manual_tasks: None,
+            mcp_servers: None,
+            owner_user_id: None,
+            version: 1,
+        }
+    }
+
+    fn render_to_string(widget: ContextPaneWidget) -> String {
+        let area = Rect::new(0, 0, 60, 16);
+        let mut buf = Buffer::empty(area);
+        widget.render(area, &mut buf);
+
+        (0..area.height)
+            .map(|y| {
+                (0..area.width)
+                    .map(|x| buf[(x, y)].symbol())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_prompt_without_project() {
+        let output = render_to_string(ContextPaneWidget::new(None, None));
+        assert!(output.contains("Mention a project with @"));
+    }
+
+    #[test]
+    fn test_project_with_empty_context() {
+        let project = create_test_project("1", "storefront");
+        let context = ConversationContext::empty("1");
+        let output = render_to_string(ContextPaneWidget::new(Some(&project), Some(&context)));
+
+        assert!(output.contains("Context: storefront"));
+        assert!(output.contains("Tags: web"));
+        assert!(output.contains("None running"));
+        assert!(output.contains("No tasks yet"));
+    }
+
+    #[test]
+    fn test_context_for_another_project_is_ignored() {
+        let project = create_test_project("1", "storefront");
+        let context = ConversationContext::empty("2");
+        let output = render_to_string(ContextPaneWidget::new(Some(&project), Some(&context)));
+
+        assert!(output.contains("Loading..."));
+    }
+}
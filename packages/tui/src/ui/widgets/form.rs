@@ -326,21 +326,15 @@ impl FormWidget {
                         // Handle selection navigation with arrow keys
                         if let Event::Key(key_event) = event {
                             match key_event.code {
-                                crossterm::event::KeyCode::Up => {
-                                    if *selected > 0 {
-                                        *selected -= 1;
-                                        true
-                                    } else {
-                                        false
-                                    }
+                                crossterm::event::KeyCode::Up if *selected > 0 => {
+                                    *selected -= 1;
+                                    true
                                 }
-                                crossterm::event::KeyCode::Down => {
-                                    if *selected < options.len().saturating_sub(1) {
-                                        *selected += 1;
-                                        true
-                                    } else {
-                                        false
-                                    }
+                                crossterm::event::KeyCode::Down
+                                    if *selected < options.len().saturating_sub(1) =>
+                                {
+                                    *selected += 1;
+                                    true
                                 }
                                 crossterm::event::KeyCode::Home => {
                                     *selected = 0;
@@ -33,9 +33,16 @@ impl<'a> Widget for MentionPopupWidget<'a> {
             return;
         }
 
+        // Before anything is typed after @, the list starts with recently used items
+        let title = if self.popup.has_recent_section() {
+            format!("Recent mentions ({} items)", self.popup.result_count())
+        } else {
+            format!("Mentions ({} matches)", self.popup.result_count())
+        };
+
         let block = Block::default()
             .borders(Borders::ALL)
-            .title(format!("Mentions ({} matches)", self.popup.result_count()))
+            .title(title)
             .title_style(
                 Style::default()
                     .fg(Color::White)
@@ -1,14 +1,22 @@
 pub mod chat;
+pub mod cheat_sheet;
 pub mod command_popup;
+pub mod command_prompt;
+pub mod context_pane;
 pub mod dialog;
 pub mod form;
 pub mod mention_popup;
 pub mod search_popup;
 pub mod status_bar;
+pub mod toast;
 
 pub use chat::{ChatWidget, InputWidget};
+pub use cheat_sheet::{calculate_cheat_sheet_area, CheatSheetWidget};
+pub use command_prompt::CommandPromptWidget;
+pub use context_pane::ContextPaneWidget;
 pub use dialog::{ConfirmationDialog, ConfirmationDialogWidget, DialogResult};
 pub use form::{FieldType, FormField, FormStep, FormWidget};
 pub use mention_popup::{calculate_mention_popup_area, MentionPopupWidget};
 pub use search_popup::{calculate_search_popup_area, SearchPopupWidget};
 pub use status_bar::StatusBarWidget;
+pub use toast::{Toast, ToastWidget};
@@ -374,24 +374,17 @@ impl<'a> SearchPopupWidget<'a> {
             return;
         }
 
-        // Render results header
+        // Render results header; before a query is typed, recent projects come first
         let results_count = self.popup.filtered_results().len();
-        let header_text = format!("Results ({} projects):", results_count);
+        let has_recent = results.first().is_some_and(|r| r.recent);
+        let header_text = if has_recent {
+            "Recent:".to_string()
+        } else {
+            format!("Results ({} projects):", results_count)
+        };
 
         if area.height > 0 {
-            let header_paragraph = Paragraph::new(header_text).style(
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
-            );
-
-            let header_area = Rect {
-                x: area.x,
-                y: area.y,
-                width: area.width,
-                height: 1,
-            };
-            header_paragraph.render(header_area, buf);
+            self.render_section_header(&header_text, area.x, area.y, area.width, buf);
         }
 
         // Render individual results
@@ -402,16 +395,27 @@ impl<'a> SearchPopupWidget<'a> {
             height: area.height.saturating_sub(1),
         };
 
-        let display_count = (results.len() as u16)
-            .min(self.max_rows)
-            .min(results_area.height);
+        let mut y = results_area.y;
+        for (row_idx, project_match) in results.iter().enumerate().take(self.max_rows as usize) {
+            // Separate the recent section from the rest of the projects
+            if has_recent && !project_match.recent && row_idx > 0 && results[row_idx - 1].recent {
+                if y >= results_area.bottom() {
+                    break;
+                }
+                self.render_section_header(
+                    "Other projects:",
+                    results_area.x,
+                    y,
+                    results_area.width,
+                    buf,
+                );
+                y += 1;
+            }
 
-        for (row_idx, project_match) in results.iter().enumerate().take(display_count as usize) {
-            if row_idx as u16 >= results_area.height {
+            if y >= results_area.bottom() {
                 break;
             }
 
-            let y = results_area.y + row_idx as u16;
             let is_selected = row_idx == self.popup.selected_index();
 
             self.render_project_result(
@@ -422,9 +426,27 @@ impl<'a> SearchPopupWidget<'a> {
                 results_area.width,
                 buf,
             );
+            y += 1;
         }
     }
 
+    /// Render a results section header line
+    fn render_section_header(&self, text: &str, x: u16, y: u16, width: u16, buf: &mut Buffer) {
+        let header_paragraph = Paragraph::new(text.to_string()).style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        );
+
+        let header_area = Rect {
+            x,
+            y,
+            width,
+            height: 1,
+        };
+        header_paragraph.render(header_area, buf);
+    }
+
     /// Render a single project result
     fn render_project_result(
         &self,
@@ -549,9 +571,7 @@ impl<'a> SearchPopupWidget<'a> {
         buf: &mut Buffer,
     ) {
         let chars: Vec<char> = text.chars().collect();
-        let mut current_x = params.start_x;
-
-        for (char_idx, ch) in chars.iter().enumerate() {
+        for (current_x, (char_idx, ch)) in (params.start_x..).zip(chars.iter().enumerate()) {
             if current_x >= params.max_x {
                 break;
             }
@@ -572,8 +592,6 @@ impl<'a> SearchPopupWidget<'a> {
                     .set_char(*ch)
                     .set_style(char_style);
             }
-
-            current_x += 1;
         }
     }
 
@@ -1,4 +1,6 @@
+use crate::executions::is_cancellable;
 use crate::input::InputMode;
+use crate::project_detail::DetailTab;
 use crate::state::{AppState, FocusArea, Screen};
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::*;
@@ -16,6 +18,14 @@ impl<'a> StatusBarWidget<'a> {
 
     /// Get the current mode indicator text and style (only when meaningful)
     fn get_mode_info(&self) -> Option<(String, Style)> {
+        // Recording applies to every mode, so it takes over the indicator
+        if self.state.is_recording_macro() {
+            return Some((
+                "REC".to_string(),
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ));
+        }
+
         match self.state.input_mode() {
             InputMode::Normal => {
                 // Only show focus areas on Chat screen where it's relevant
@@ -123,6 +133,14 @@ impl<'a> StatusBarWidget<'a> {
                     " Project Detail".to_string()
                 }
             }
+            Screen::Executions => {
+                let executions = &self.state.executions.executions;
+                let running = executions
+                    .iter()
+                    .filter(|execution| is_cancellable(execution))
+                    .count();
+                format!(" Executions ({} running)", running)
+            }
         }
     }
 
@@ -152,20 +170,45 @@ impl<'a> StatusBarWidget<'a> {
             }
             (&Screen::Chat, InputMode::Edit) => "Enter: Save • Esc: Cancel".to_string(),
             (&Screen::Chat, _) => match self.state.focus_area() {
-                FocusArea::Chat => "↑↓: Scroll • Tab: Focus Input • q: Quit".to_string(),
+                FocusArea::Chat => "↑↓: Scroll • Tab: Focus Input • ?: Keys • q: Quit".to_string(),
                 FocusArea::Input => {
-                    "Enter: Send • /: Commands • @: Mentions • Tab: Focus Chat".to_string()
+                    "Enter: Send • /: Commands • @: Mentions • Ctrl+L: Context • Tab: Focus Chat"
+                        .to_string()
                 }
             },
             (&Screen::Projects, _) => {
                 if self.state.projects.is_empty() {
-                    "n: New Project • Tab: Navigate • q: Quit".to_string()
+                    "n: New Project • Tab: Navigate • ?: Keys • q: Quit".to_string()
                 } else {
-                    "↑↓: Navigate • Enter: Details • n: New • e: Edit • d: Delete • Tab: Switch Screen".to_string()
+                    "↑↓: Navigate • Enter: Details • n: New • e: Edit • d: Delete • ?: Keys • Tab: Switch Screen".to_string()
                 }
             }
             (&Screen::ProjectDetail, _) => {
-                "e: Edit • d: Delete • Esc: Back to List • Tab: Navigate".to_string()
+                let tab = self
+                    .state
+                    .project_detail
+                    .as_ref()
+                    .map_or(DetailTab::Overview, |detail| detail.tab);
+                match tab {
+                    DetailTab::Overview => {
+                        "←→/1-5: Tabs • e: Edit • d: Delete • Esc: Back to List".to_string()
+                    }
+                    DetailTab::Servers => {
+                        "←→: Tabs • ↑↓: Select • s: Start • x: Stop • r: Reload • Esc: Back"
+                            .to_string()
+                    }
+                    DetailTab::Usage => "←→: Tabs • r: Reload • Esc: Back to List".to_string(),
+                    DetailTab::Tasks | DetailTab::Git => {
+                        "←→: Tabs • ↑↓: Select • r: Reload • Esc: Back to List".to_string()
+                    }
+                }
+            }
+            (&Screen::Executions, _) => {
+                if self.state.executions.detail_open {
+                    "↑↓: Select • c: Cancel • r: Retry • Enter/Esc: Close Logs".to_string()
+                } else {
+                    "↑↓: Navigate • Enter: Logs • c: Cancel • r: Retry • Esc: Back".to_string()
+                }
             }
         }
     }
@@ -195,6 +238,17 @@ impl<'a> StatusBarWidget<'a> {
         }
     }
 
+    /// Get macro recording progress (while recording)
+    fn get_macro_recording(&self) -> Option<String> {
+        self.state.macro_recorder.as_ref().map(|recorder| {
+            format!(
+                "Recording '{}' ({} keys) • Ctrl+R: Stop",
+                recorder.name(),
+                recorder.len()
+            )
+        })
+    }
+
     /// Get input history position indicator
     fn get_history_position(&self) -> Option<String> {
         if let Some((current, total)) = self.state.input_history_position() {
@@ -212,6 +266,7 @@ impl<'a> Widget for StatusBarWidget<'a> {
         let shortcuts = self.get_shortcuts();
         let project_context = self.get_project_context();
         let history_position = self.get_history_position();
+        let macro_recording = self.get_macro_recording();
 
         // Create layout for status bar sections based on whether we have mode info
         let chunks = if let Some((ref mode_text, _)) = mode_info {
@@ -257,6 +312,11 @@ impl<'a> Widget for StatusBarWidget<'a> {
             middle_content = format!("{} • {}", middle_content, history);
         }
 
+        // Add macro recording progress if recording
+        if let Some(recording) = macro_recording {
+            middle_content = format!("{} • {}", middle_content, recording);
+        }
+
         // Render middle section (breadcrumb + context)
         let middle_paragraph = Paragraph::new(middle_content)
             .style(Style::default().fg(Color::Gray))
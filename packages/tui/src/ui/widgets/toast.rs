@@ -0,0 +1,104 @@
+use std::time::{Duration, Instant};
+
+use orkee_notifications::{Notification, NotificationSeverity};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+};
+
+/// How long a toast stays on screen
+const TOAST_DURATION: Duration = Duration::from_secs(6);
+/// Width of a toast, including borders
+const TOAST_WIDTH: u16 = 48;
+/// Height of a toast: title border, two message lines, bottom border
+const TOAST_HEIGHT: u16 = 4;
+
+/// A short-lived notification shown in the corner of the screen
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub title: String,
+    pub message: String,
+    pub severity: NotificationSeverity,
+    pub expires_at: Instant,
+}
+
+impl Toast {
+    pub fn new(title: String, message: String, severity: NotificationSeverity) -> Self {
+        Self {
+            title,
+            message,
+            severity,
+            expires_at: Instant::now() + TOAST_DURATION,
+        }
+    }
+
+    pub fn is_expired(&self, now: Instant) -> bool {
+        now >= self.expires_at
+    }
+
+    fn color(&self) -> Color {
+        match self.severity {
+            NotificationSeverity::Info => Color::Cyan,
+            NotificationSeverity::Success => Color::Green,
+            NotificationSeverity::Warning => Color::Yellow,
+            NotificationSeverity::Error => Color::Red,
+        }
+    }
+}
+
+impl From<&Notification> for Toast {
+    fn from(notification: &Notification) -> Self {
+        Self::new(
+            notification.title.clone(),
+            notification.message.clone(),
+            notification.severity,
+        )
+    }
+}
+
+/// Widget that stacks toasts in the top-right corner, newest first
+pub struct ToastWidget<'a> {
+    toasts: &'a [Toast],
+}
+
+impl<'a> ToastWidget<'a> {
+    pub fn new(toasts: &'a [Toast]) -> Self {
+        Self { toasts }
+    }
+}
+
+impl<'a> Widget for ToastWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let width = TOAST_WIDTH.min(area.width);
+        let x = area.x + area.width.saturating_sub(width + 1);
+        let mut y = area.y + 1;
+
+        for toast in self.toasts.iter().rev() {
+            if y + TOAST_HEIGHT > area.y + area.height {
+                break;
+            }
+
+            let toast_area = Rect {
+                x,
+                y,
+                width,
+                height: TOAST_HEIGHT,
+            };
+            Clear.render(toast_area, buf);
+
+            let color = toast.color();
+            let block = Block::default()
+                .title(toast.title.as_str())
+                .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(color));
+
+            Paragraph::new(toast.message.as_str())
+                .block(block)
+                .wrap(Wrap { trim: true })
+                .render(toast_area, buf);
+
+            y += TOAST_HEIGHT;
+        }
+    }
+}
@@ -0,0 +1,119 @@
+// ABOUTME: Webhook delivery log storage using SQLite
+// ABOUTME: Records every inbound delivery with its outcome for troubleshooting CI integrations
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::{Row, SqlitePool};
+
+use super::types::{DeliveryStatus, WebhookDelivery, WebhookProvider};
+use orkee_storage::StorageError;
+
+/// Fields recorded for a new delivery
+#[derive(Debug, Clone)]
+pub struct DeliveryRecord<'a> {
+    pub provider: WebhookProvider,
+    pub event_type: Option<&'a str>,
+    pub delivery_id: Option<&'a str>,
+    pub status: DeliveryStatus,
+    pub message: Option<String>,
+    pub payload: Option<&'a str>,
+    pub updated_tasks: i64,
+    pub updated_executions: i64,
+}
+
+pub struct DeliveryStorage {
+    pool: SqlitePool,
+}
+
+impl DeliveryStorage {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn record_delivery(
+        &self,
+        record: DeliveryRecord<'_>,
+    ) -> Result<WebhookDelivery, StorageError> {
+        let id = nanoid::nanoid!(12);
+        let now = Utc::now();
+
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (
+                id, provider, event_type, delivery_id, status, message, payload,
+                updated_tasks, updated_executions, received_at
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(&id)
+        .bind(record.provider.as_str())
+        .bind(record.event_type)
+        .bind(record.delivery_id)
+        .bind(record.status.as_str())
+        .bind(&record.message)
+        .bind(record.payload)
+        .bind(record.updated_tasks)
+        .bind(record.updated_executions)
+        .bind(now.to_rfc3339_opts(SecondsFormat::Micros, true))
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(WebhookDelivery {
+            id,
+            provider: record.provider,
+            event_type: record.event_type.map(str::to_string),
+            delivery_id: record.delivery_id.map(str::to_string),
+            status: record.status,
+            message: record.message,
+            payload: record.payload.map(str::to_string),
+            updated_tasks: record.updated_tasks,
+            updated_executions: record.updated_executions,
+            received_at: now,
+        })
+    }
+
+    /// List deliveries newest first, optionally for a single provider
+    pub async fn list_deliveries(
+        &self,
+        provider: Option<WebhookProvider>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, StorageError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE (?1 IS NULL OR provider = ?1)
+            ORDER BY received_at DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(provider.map(|p| p.as_str()))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_delivery).collect()
+    }
+}
+
+fn row_to_delivery(row: &sqlx::sqlite::SqliteRow) -> Result<WebhookDelivery, StorageError> {
+    let provider: String = row.try_get("provider")?;
+    let status: String = row.try_get("status")?;
+    let received_at: String = row.try_get("received_at")?;
+
+    Ok(WebhookDelivery {
+        id: row.try_get("id")?,
+        provider: provider.parse().map_err(StorageError::Database)?,
+        event_type: row.try_get("event_type")?,
+        delivery_id: row.try_get("delivery_id")?,
+        status: status.parse().map_err(StorageError::Database)?,
+        message: row.try_get("message")?,
+        payload: row.try_get("payload")?,
+        updated_tasks: row.try_get("updated_tasks")?,
+        updated_executions: row.try_get("updated_executions")?,
+        received_at: DateTime::parse_from_rfc3339(&received_at)
+            .map_err(|_| StorageError::Database("Invalid received_at timestamp".to_string()))?
+            .with_timezone(&Utc),
+    })
+}
@@ -0,0 +1,27 @@
+// ABOUTME: Inbound CI webhooks for GitHub Actions and generic CI providers
+// ABOUTME: Verifies HMAC signatures, polls pull request checks, and maps CI results onto task check status
+
+pub mod deliveries;
+pub mod pr_checks;
+pub mod processor;
+pub mod providers;
+pub mod receiver;
+pub mod secrets;
+pub mod signature;
+pub mod types;
+
+// Re-export main types
+pub use deliveries::DeliveryStorage;
+pub use pr_checks::{
+    combined_status, open_pull_requests, poll_pr_checks, record_pr_checks, spawn_pr_check_poller,
+    LinkedPullRequest, PrCheckPollSummary, PR_CHECK_POLL_INTERVAL,
+};
+pub use processor::{apply_ci_result, CiUpdateSummary};
+pub use providers::{parse_event, ParsedEvent};
+pub use receiver::{WebhookReceiver, WebhookRequest};
+pub use secrets::WebhookSecretStorage;
+pub use signature::{compute_signature, verify_signature};
+pub use types::{
+    CiRunResult, DeliveryStatus, WebhookDelivery, WebhookProvider, WebhookSecret,
+    WebhookSecretGeneration,
+};
@@ -0,0 +1,215 @@
+// ABOUTME: Polls GitHub for check runs on pull requests opened by agent executions
+// ABOUTME: Records the combined check status on the linked tasks, for repositories without CI webhooks
+
+use std::time::Duration;
+
+use orkee_git_utils::{GhCheck, GitHubCli};
+use orkee_storage::StorageError;
+use orkee_tasks::CheckStatus;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, warn};
+
+use super::processor::apply_ci_result;
+use super::types::{CiRunResult, WebhookProvider};
+
+/// How often open pull requests are checked
+pub const PR_CHECK_POLL_INTERVAL: Duration = Duration::from_secs(120);
+
+/// Name recorded for polled results, in place of a workflow name
+const PR_CHECKS_NAME: &str = "Pull request checks";
+
+/// An open pull request opened by an agent execution
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkedPullRequest {
+    pub execution_id: String,
+    pub task_id: String,
+    pub pr_url: String,
+    pub branch: Option<String>,
+    pub commit_hash: Option<String>,
+    /// Check status currently recorded on the task
+    pub checks_status: Option<CheckStatus>,
+}
+
+/// What a poll found and changed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrCheckPollSummary {
+    /// Pull requests with check runs
+    pub checked: usize,
+    pub updated_tasks: i64,
+}
+
+/// Open or draft pull requests linked to tasks, one per pull request, newest execution first
+pub async fn open_pull_requests(pool: &SqlitePool) -> Result<Vec<LinkedPullRequest>, StorageError> {
+    let rows = sqlx::query(
+        "SELECT e.id, e.task_id, e.pr_url, e.branch_name, e.commit_hash, t.checks_status
+         FROM agent_executions e
+         JOIN tasks t ON t.id = e.task_id
+         WHERE e.pr_url IS NOT NULL
+           AND (e.pr_status IS NULL OR e.pr_status IN ('open', 'draft'))
+         ORDER BY e.updated_at DESC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(StorageError::Sqlx)?;
+
+    let mut pull_requests: Vec<LinkedPullRequest> = Vec::new();
+    for row in rows {
+        let pr_url: String = row.try_get("pr_url")?;
+        // Retries reuse the pull request; the newest execution speaks for it
+        if pull_requests.iter().any(|pr| pr.pr_url == pr_url) {
+            continue;
+        }
+        pull_requests.push(LinkedPullRequest {
+            execution_id: row.try_get("id")?,
+            task_id: row.try_get("task_id")?,
+            pr_url,
+            branch: row.try_get("branch_name")?,
+            commit_hash: row.try_get("commit_hash")?,
+            checks_status: row.try_get("checks_status")?,
+        });
+    }
+    Ok(pull_requests)
+}
+
+/// Combine a pull request's check runs into one status; `None` when it has none.
+///
+/// Any failure fails the pull request, then anything still running keeps it pending.
+/// Skipped checks count as passed.
+pub fn combined_status(checks: &[GhCheck]) -> Option<CheckStatus> {
+    if checks.is_empty() {
+        return None;
+    }
+
+    let any = |bucket: &str| checks.iter().any(|check| check.bucket == bucket);
+    let status = if any("fail") {
+        CheckStatus::Failed
+    } else if any("pending") {
+        CheckStatus::Pending
+    } else if any("cancel") {
+        CheckStatus::Cancelled
+    } else {
+        CheckStatus::Passed
+    };
+    Some(status)
+}
+
+/// Record a pull request's check runs on its task and executions when the status changed.
+/// Returns how many tasks were updated.
+pub async fn record_pr_checks(
+    pool: &SqlitePool,
+    pull_request: &LinkedPullRequest,
+    checks: &[GhCheck],
+) -> Result<i64, StorageError> {
+    let Some(status) = combined_status(checks) else {
+        return Ok(0);
+    };
+    if pull_request.checks_status == Some(status) {
+        return Ok(0);
+    }
+
+    // Link straight to the failing check when there is one
+    let url = checks
+        .iter()
+        .find(|check| check.bucket == "fail")
+        .and_then(|check| check.link.clone())
+        .unwrap_or_else(|| pull_request.pr_url.clone());
+
+    let result = CiRunResult {
+        provider: WebhookProvider::Github,
+        name: PR_CHECKS_NAME.to_string(),
+        status,
+        branch: pull_request.branch.clone(),
+        commit_sha: pull_request.commit_hash.clone(),
+        url: Some(url),
+        task_id: Some(pull_request.task_id.clone()),
+    };
+    Ok(apply_ci_result(pool, &result).await?.updated_tasks)
+}
+
+/// Fetch check runs for every open pull request and record any status changes
+pub async fn poll_pr_checks(
+    pool: &SqlitePool,
+    cli: &GitHubCli,
+) -> Result<PrCheckPollSummary, StorageError> {
+    let mut summary = PrCheckPollSummary::default();
+
+    for pull_request in open_pull_requests(pool).await? {
+        let checks = match cli.pr_checks(&pull_request.pr_url).await {
+            Ok(checks) => checks,
+            Err(e) => {
+                debug!("Could not fetch checks for {}: {}", pull_request.pr_url, e);
+                continue;
+            }
+        };
+        if checks.is_empty() {
+            continue;
+        }
+
+        summary.checked += 1;
+        summary.updated_tasks += record_pr_checks(pool, &pull_request, &checks).await?;
+    }
+
+    Ok(summary)
+}
+
+/// Poll pull request checks every `interval`, starting one interval after launch.
+/// Polls are skipped while the gh CLI is missing or not authenticated.
+pub fn spawn_pr_check_poller(pool: SqlitePool, interval: Duration) {
+    tokio::spawn(async move {
+        let start = tokio::time::Instant::now() + interval;
+        let mut ticker = tokio::time::interval_at(start, interval);
+        loop {
+            ticker.tick().await;
+
+            let cli = match GitHubCli::new() {
+                Ok(cli) => cli,
+                Err(e) => {
+                    debug!("Skipping pull request check poll: {}", e);
+                    continue;
+                }
+            };
+            match poll_pr_checks(&pool, &cli).await {
+                Ok(summary) => debug!(
+                    "Polled checks for {} pull requests, updated {} tasks",
+                    summary.checked, summary.updated_tasks
+                ),
+                Err(e) => warn!("Pull request check poll failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(bucket: &str) -> GhCheck {
+        GhCheck {
+            name: format!("{} check", bucket),
+            state: bucket.to_uppercase(),
+            bucket: bucket.to_string(),
+            link: None,
+        }
+    }
+
+    #[test]
+    fn test_combined_status() {
+        assert_eq!(combined_status(&[]), None);
+        assert_eq!(
+            combined_status(&[check("pass"), check("skipping")]),
+            Some(CheckStatus::Passed)
+        );
+        assert_eq!(
+            combined_status(&[check("pass"), check("pending")]),
+            Some(CheckStatus::Pending)
+        );
+        assert_eq!(
+            combined_status(&[check("pending"), check("fail")]),
+            Some(CheckStatus::Failed)
+        );
+        assert_eq!(
+            combined_status(&[check("pass"), check("cancel")]),
+            Some(CheckStatus::Cancelled)
+        );
+    }
+}
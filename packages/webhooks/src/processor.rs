@@ -0,0 +1,108 @@
+// ABOUTME: Applies normalized CI run results to agent executions and tasks
+// ABOUTME: Matches executions by commit or branch and records the latest check status on their tasks
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::{Row, SqlitePool};
+use tracing::debug;
+
+use super::types::CiRunResult;
+use orkee_storage::StorageError;
+
+/// How many rows a CI result touched
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CiUpdateSummary {
+    pub updated_tasks: i64,
+    pub updated_executions: i64,
+}
+
+/// Record a CI result on matching executions and their tasks.
+///
+/// Executions are matched by commit hash first, falling back to branch name when no
+/// execution has that commit. A `task_id` in the result is updated even without a
+/// matching execution.
+pub async fn apply_ci_result(
+    pool: &SqlitePool,
+    result: &CiRunResult,
+) -> Result<CiUpdateSummary, StorageError> {
+    let mut tx = pool.begin().await.map_err(StorageError::Sqlx)?;
+
+    let mut executions = Vec::new();
+    if let Some(commit_sha) = &result.commit_sha {
+        executions = sqlx::query("SELECT id, task_id FROM agent_executions WHERE commit_hash = ?")
+            .bind(commit_sha)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+    }
+    if executions.is_empty() {
+        if let Some(branch) = &result.branch {
+            executions =
+                sqlx::query("SELECT id, task_id FROM agent_executions WHERE branch_name = ?")
+                    .bind(branch)
+                    .fetch_all(&mut *tx)
+                    .await
+                    .map_err(StorageError::Sqlx)?;
+        }
+    }
+
+    let now = Utc::now();
+    let test_results = json!({
+        "provider": result.provider.as_str(),
+        "name": result.name,
+        "status": result.status.as_str(),
+        "url": result.url,
+        "commit_sha": result.commit_sha,
+        "branch": result.branch,
+        "reported_at": now.to_rfc3339(),
+    })
+    .to_string();
+
+    let mut task_ids: Vec<String> = result.task_id.iter().cloned().collect();
+    for row in &executions {
+        let execution_id: String = row.try_get("id")?;
+        let task_id: String = row.try_get("task_id")?;
+
+        sqlx::query("UPDATE agent_executions SET test_results = ? WHERE id = ?")
+            .bind(&test_results)
+            .bind(&execution_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        if !task_ids.contains(&task_id) {
+            task_ids.push(task_id);
+        }
+    }
+
+    let mut updated_tasks = 0;
+    for task_id in &task_ids {
+        let updated = sqlx::query(
+            "UPDATE tasks SET checks_status = ?, checks_url = ?, checks_updated_at = ? WHERE id = ?",
+        )
+        .bind(result.status)
+        .bind(&result.url)
+        .bind(now)
+        .bind(task_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(StorageError::Sqlx)?;
+        updated_tasks += updated.rows_affected() as i64;
+    }
+
+    tx.commit().await.map_err(StorageError::Sqlx)?;
+
+    let summary = CiUpdateSummary {
+        updated_tasks,
+        updated_executions: executions.len() as i64,
+    };
+    debug!(
+        "Applied {} result '{}' to {} tasks and {} executions",
+        result.status.as_str(),
+        result.name,
+        summary.updated_tasks,
+        summary.updated_executions
+    );
+    Ok(summary)
+}
@@ -0,0 +1,245 @@
+// ABOUTME: Provider payload parsing for inbound CI webhooks
+// ABOUTME: Normalizes GitHub workflow_run/check_suite events and generic CI payloads into CI run results
+
+use orkee_tasks::CheckStatus;
+use serde::Deserialize;
+
+use super::types::{CiRunResult, WebhookProvider};
+
+/// A parsed webhook event
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedEvent {
+    Run(CiRunResult),
+    /// The event is valid but carries no CI result, with the reason why
+    Ignored(String),
+}
+
+/// Parse a verified webhook body. `event_type` is the provider's event header, if any.
+pub fn parse_event(
+    provider: WebhookProvider,
+    event_type: Option<&str>,
+    body: &[u8],
+) -> Result<ParsedEvent, String> {
+    match provider {
+        WebhookProvider::Github => parse_github(event_type.unwrap_or_default(), body),
+        WebhookProvider::Generic => parse_generic(body),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRun {
+    name: Option<String>,
+    head_branch: Option<String>,
+    head_sha: Option<String>,
+    status: Option<String>,
+    conclusion: Option<String>,
+    html_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubApp {
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCheckSuite {
+    head_branch: Option<String>,
+    head_sha: Option<String>,
+    status: Option<String>,
+    conclusion: Option<String>,
+    app: Option<GithubApp>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubWorkflowRunEvent {
+    workflow_run: GithubRun,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubCheckSuiteEvent {
+    check_suite: GithubCheckSuite,
+}
+
+/// Map GitHub's run status and conclusion to a check status
+fn github_check_status(status: Option<&str>, conclusion: Option<&str>) -> CheckStatus {
+    if status != Some("completed") {
+        return CheckStatus::Pending;
+    }
+    match conclusion {
+        Some("success") | Some("neutral") | Some("skipped") => CheckStatus::Passed,
+        Some("cancelled") | Some("stale") => CheckStatus::Cancelled,
+        _ => CheckStatus::Failed,
+    }
+}
+
+fn parse_github(event_type: &str, body: &[u8]) -> Result<ParsedEvent, String> {
+    match event_type {
+        "ping" => Ok(ParsedEvent::Ignored("Ping event".to_string())),
+        "workflow_run" => {
+            let event: GithubWorkflowRunEvent = serde_json::from_slice(body)
+                .map_err(|e| format!("Invalid workflow_run payload: {}", e))?;
+            let run = event.workflow_run;
+            Ok(ParsedEvent::Run(CiRunResult {
+                provider: WebhookProvider::Github,
+                name: run.name.unwrap_or_else(|| "GitHub Actions".to_string()),
+                status: github_check_status(run.status.as_deref(), run.conclusion.as_deref()),
+                branch: run.head_branch,
+                commit_sha: run.head_sha,
+                url: run.html_url,
+                task_id: None,
+            }))
+        }
+        "check_suite" => {
+            let event: GithubCheckSuiteEvent = serde_json::from_slice(body)
+                .map_err(|e| format!("Invalid check_suite payload: {}", e))?;
+            let suite = event.check_suite;
+            Ok(ParsedEvent::Run(CiRunResult {
+                provider: WebhookProvider::Github,
+                name: suite
+                    .app
+                    .and_then(|app| app.name)
+                    .unwrap_or_else(|| "Check suite".to_string()),
+                status: github_check_status(suite.status.as_deref(), suite.conclusion.as_deref()),
+                branch: suite.head_branch,
+                commit_sha: suite.head_sha,
+                url: None,
+                task_id: None,
+            }))
+        }
+        "" => Err("Missing X-GitHub-Event header".to_string()),
+        other => Ok(ParsedEvent::Ignored(format!(
+            "Unsupported GitHub event: {}",
+            other
+        ))),
+    }
+}
+
+/// Generic payload: `{"status": "failed", "name": "CI", "branch": "...", "commit": "...", "task_id": "...", "url": "..."}`
+#[derive(Debug, Deserialize)]
+struct GenericPayload {
+    status: String,
+    name: Option<String>,
+    branch: Option<String>,
+    commit: Option<String>,
+    task_id: Option<String>,
+    url: Option<String>,
+}
+
+fn generic_check_status(status: &str) -> Result<CheckStatus, String> {
+    match status.to_ascii_lowercase().as_str() {
+        "passed" | "success" | "succeeded" => Ok(CheckStatus::Passed),
+        "failed" | "failure" | "error" => Ok(CheckStatus::Failed),
+        "pending" | "queued" | "running" | "in_progress" => Ok(CheckStatus::Pending),
+        "cancelled" | "canceled" => Ok(CheckStatus::Cancelled),
+        other => Err(format!("Unknown CI status: {}", other)),
+    }
+}
+
+fn parse_generic(body: &[u8]) -> Result<ParsedEvent, String> {
+    let payload: GenericPayload =
+        serde_json::from_slice(body).map_err(|e| format!("Invalid payload: {}", e))?;
+
+    if payload.task_id.is_none() && payload.commit.is_none() && payload.branch.is_none() {
+        return Err("Payload must include task_id, commit, or branch".to_string());
+    }
+
+    Ok(ParsedEvent::Run(CiRunResult {
+        provider: WebhookProvider::Generic,
+        name: payload.name.unwrap_or_else(|| "CI".to_string()),
+        status: generic_check_status(&payload.status)?,
+        branch: payload.branch,
+        commit_sha: payload.commit,
+        url: payload.url,
+        task_id: payload.task_id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_github_workflow_run() {
+        let body = br#"{
+            "action": "completed",
+            "workflow_run": {
+                "name": "CI",
+                "head_branch": "feature/login",
+                "head_sha": "abc123",
+                "status": "completed",
+                "conclusion": "failure",
+                "html_url": "https://github.com/acme/app/actions/runs/1"
+            }
+        }"#;
+
+        let ParsedEvent::Run(run) =
+            parse_event(WebhookProvider::Github, Some("workflow_run"), body).unwrap()
+        else {
+            panic!("expected a run result");
+        };
+        assert_eq!(run.name, "CI");
+        assert_eq!(run.status, CheckStatus::Failed);
+        assert_eq!(run.branch.as_deref(), Some("feature/login"));
+        assert_eq!(run.commit_sha.as_deref(), Some("abc123"));
+        assert_eq!(
+            run.url.as_deref(),
+            Some("https://github.com/acme/app/actions/runs/1")
+        );
+    }
+
+    #[test]
+    fn test_github_check_status() {
+        assert_eq!(
+            github_check_status(Some("in_progress"), None),
+            CheckStatus::Pending
+        );
+        assert_eq!(
+            github_check_status(Some("completed"), Some("skipped")),
+            CheckStatus::Passed
+        );
+        assert_eq!(
+            github_check_status(Some("completed"), Some("cancelled")),
+            CheckStatus::Cancelled
+        );
+        assert_eq!(
+            github_check_status(Some("completed"), Some("timed_out")),
+            CheckStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_parse_github_ignored_events() {
+        assert!(matches!(
+            parse_event(WebhookProvider::Github, Some("ping"), b"{}").unwrap(),
+            ParsedEvent::Ignored(_)
+        ));
+        assert!(matches!(
+            parse_event(WebhookProvider::Github, Some("push"), b"{}").unwrap(),
+            ParsedEvent::Ignored(_)
+        ));
+        assert!(parse_event(WebhookProvider::Github, None, b"{}").is_err());
+    }
+
+    #[test]
+    fn test_parse_generic() {
+        let ParsedEvent::Run(run) = parse_event(
+            WebhookProvider::Generic,
+            None,
+            br#"{"status": "SUCCESS", "task_id": "task-0001"}"#,
+        )
+        .unwrap() else {
+            panic!("expected a run result");
+        };
+        assert_eq!(run.status, CheckStatus::Passed);
+        assert_eq!(run.task_id.as_deref(), Some("task-0001"));
+        assert_eq!(run.name, "CI");
+
+        assert!(parse_event(WebhookProvider::Generic, None, br#"{"status": "failed"}"#).is_err());
+        assert!(parse_event(
+            WebhookProvider::Generic,
+            None,
+            br#"{"status": "exploded", "branch": "main"}"#
+        )
+        .is_err());
+    }
+}
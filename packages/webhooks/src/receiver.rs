@@ -0,0 +1,144 @@
+// ABOUTME: Entry point for inbound webhook deliveries
+// ABOUTME: Verifies signatures against active secrets, applies CI results, and logs the outcome
+
+use sqlx::SqlitePool;
+use tracing::{info, warn};
+
+use super::deliveries::{DeliveryRecord, DeliveryStorage};
+use super::processor::{apply_ci_result, CiUpdateSummary};
+use super::providers::{parse_event, ParsedEvent};
+use super::secrets::WebhookSecretStorage;
+use super::signature::verify_signature;
+use super::types::{DeliveryStatus, WebhookDelivery, WebhookProvider};
+use orkee_storage::StorageError;
+
+/// An inbound webhook request with the headers the receiver cares about
+#[derive(Debug, Clone)]
+pub struct WebhookRequest {
+    pub provider: WebhookProvider,
+    pub event_type: Option<String>,
+    pub delivery_id: Option<String>,
+    pub signature: Option<String>,
+    pub body: Vec<u8>,
+}
+
+pub struct WebhookReceiver {
+    pool: SqlitePool,
+    secrets: WebhookSecretStorage,
+    deliveries: DeliveryStorage,
+}
+
+impl WebhookReceiver {
+    pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
+        Ok(Self {
+            secrets: WebhookSecretStorage::new(pool.clone())?,
+            deliveries: DeliveryStorage::new(pool.clone()),
+            pool,
+        })
+    }
+
+    pub fn secrets(&self) -> &WebhookSecretStorage {
+        &self.secrets
+    }
+
+    pub fn deliveries(&self) -> &DeliveryStorage {
+        &self.deliveries
+    }
+
+    /// Handle a delivery end to end. Every delivery is logged, including rejected ones;
+    /// the returned record's status tells the caller how to respond.
+    pub async fn receive(&self, request: WebhookRequest) -> Result<WebhookDelivery, StorageError> {
+        let record = |status, message: String| DeliveryRecord {
+            provider: request.provider,
+            event_type: request.event_type.as_deref(),
+            delivery_id: request.delivery_id.as_deref(),
+            status,
+            message: Some(message),
+            payload: None,
+            updated_tasks: 0,
+            updated_executions: 0,
+        };
+
+        if let Err(reason) = self.verify(&request).await? {
+            warn!(
+                "Rejected {} webhook delivery: {}",
+                request.provider.as_str(),
+                reason
+            );
+            return self
+                .deliveries
+                .record_delivery(record(DeliveryStatus::Rejected, reason))
+                .await;
+        }
+
+        let payload = String::from_utf8_lossy(&request.body);
+        let (status, message, summary) = match parse_event(
+            request.provider,
+            request.event_type.as_deref(),
+            &request.body,
+        ) {
+            Ok(ParsedEvent::Run(result)) => match apply_ci_result(&self.pool, &result).await {
+                Ok(summary) => (
+                    DeliveryStatus::Processed,
+                    format!("{} {}", result.name, result.status.as_str()),
+                    summary,
+                ),
+                Err(e) => (
+                    DeliveryStatus::Failed,
+                    format!("Failed to apply CI result: {}", e),
+                    CiUpdateSummary::default(),
+                ),
+            },
+            Ok(ParsedEvent::Ignored(reason)) => {
+                (DeliveryStatus::Ignored, reason, CiUpdateSummary::default())
+            }
+            Err(e) => (DeliveryStatus::Failed, e, CiUpdateSummary::default()),
+        };
+
+        info!(
+            "{} webhook delivery {}: {}",
+            request.provider.as_str(),
+            status.as_str(),
+            message
+        );
+
+        self.deliveries
+            .record_delivery(DeliveryRecord {
+                payload: Some(&payload),
+                updated_tasks: summary.updated_tasks,
+                updated_executions: summary.updated_executions,
+                ..record(status, message)
+            })
+            .await
+    }
+
+    /// Check the request signature against every active secret for its provider.
+    /// The inner error is the rejection reason.
+    async fn verify(&self, request: &WebhookRequest) -> Result<Result<(), String>, StorageError> {
+        let Some(signature) = request.signature.as_deref() else {
+            return Ok(Err(format!(
+                "Missing {} header",
+                request.provider.signature_header()
+            )));
+        };
+
+        let secrets = self.secrets.active_secrets(request.provider).await?;
+        if secrets.is_empty() {
+            return Ok(Err(format!(
+                "No active {} webhook secret configured",
+                request.provider.as_str()
+            )));
+        }
+
+        match secrets
+            .iter()
+            .find(|(_, secret)| verify_signature(secret, &request.body, signature))
+        {
+            Some((id, _)) => {
+                self.secrets.mark_used(id).await?;
+                Ok(Ok(()))
+            }
+            None => Ok(Err("Invalid signature".to_string())),
+        }
+    }
+}
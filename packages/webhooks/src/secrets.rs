@@ -0,0 +1,161 @@
+// ABOUTME: Webhook secret storage using SQLite
+// ABOUTME: Generates per-provider signing secrets and stores them encrypted at rest
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use sqlx::{Row, SqlitePool};
+use tracing::{debug, warn};
+
+use super::types::{WebhookProvider, WebhookSecret, WebhookSecretGeneration};
+use orkee_security::ApiKeyEncryption;
+use orkee_storage::StorageError;
+
+pub struct WebhookSecretStorage {
+    pool: SqlitePool,
+    encryption: ApiKeyEncryption,
+}
+
+impl WebhookSecretStorage {
+    pub fn new(pool: SqlitePool) -> Result<Self, StorageError> {
+        let encryption = ApiKeyEncryption::new().map_err(|e| {
+            StorageError::Encryption(format!("Failed to initialize encryption: {}", e))
+        })?;
+        Ok(Self { pool, encryption })
+    }
+
+    /// Generate a random 32-byte secret, hex-encoded for pasting into CI settings
+    pub fn generate_secret() -> String {
+        let mut rng = rand::thread_rng();
+        let random_bytes: [u8; 32] = rng.gen();
+        hex::encode(random_bytes)
+    }
+
+    /// Create a secret for `provider`. The plaintext is only returned here.
+    pub async fn create_secret(
+        &self,
+        provider: WebhookProvider,
+        name: &str,
+    ) -> Result<WebhookSecretGeneration, StorageError> {
+        let id = nanoid::nanoid!(12);
+        let secret = Self::generate_secret();
+        let encrypted = self.encryption.encrypt(&secret).map_err(|e| {
+            StorageError::Encryption(format!("Failed to encrypt webhook secret: {}", e))
+        })?;
+
+        debug!("Creating {} webhook secret: {}", provider.as_str(), name);
+
+        sqlx::query(
+            "INSERT INTO webhook_secrets (id, provider, name, secret_encrypted, is_active)
+             VALUES (?, ?, ?, ?, 1)",
+        )
+        .bind(&id)
+        .bind(provider.as_str())
+        .bind(name)
+        .bind(&encrypted)
+        .execute(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(WebhookSecretGeneration {
+            secret,
+            webhook_secret: self.get_secret(&id).await?,
+        })
+    }
+
+    pub async fn get_secret(&self, id: &str) -> Result<WebhookSecret, StorageError> {
+        let row = sqlx::query("SELECT * FROM webhook_secrets WHERE id = ?")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        row_to_secret(&row)
+    }
+
+    pub async fn list_secrets(&self) -> Result<Vec<WebhookSecret>, StorageError> {
+        let rows = sqlx::query("SELECT * FROM webhook_secrets ORDER BY provider, created_at DESC")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        rows.iter().map(row_to_secret).collect()
+    }
+
+    pub async fn delete_secret(&self, id: &str) -> Result<(), StorageError> {
+        let result = sqlx::query("DELETE FROM webhook_secrets WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+
+        if result.rows_affected() == 0 {
+            return Err(StorageError::Sqlx(sqlx::Error::RowNotFound));
+        }
+        Ok(())
+    }
+
+    /// Decrypted `(id, secret)` pairs for every active secret of `provider`.
+    /// Secrets that fail to decrypt (e.g. created on another machine) are skipped.
+    pub async fn active_secrets(
+        &self,
+        provider: WebhookProvider,
+    ) -> Result<Vec<(String, String)>, StorageError> {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT id, secret_encrypted FROM webhook_secrets WHERE provider = ? AND is_active = 1",
+        )
+        .bind(provider.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(StorageError::Sqlx)?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(
+                |(id, encrypted)| match self.encryption.decrypt(&encrypted) {
+                    Ok(secret) => Some((id, secret)),
+                    Err(e) => {
+                        warn!(
+                            "Skipping webhook secret {} that failed to decrypt: {}",
+                            id, e
+                        );
+                        None
+                    }
+                },
+            )
+            .collect())
+    }
+
+    pub async fn mark_used(&self, id: &str) -> Result<(), StorageError> {
+        sqlx::query("UPDATE webhook_secrets SET last_used_at = ? WHERE id = ?")
+            .bind(Utc::now().to_rfc3339())
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(StorageError::Sqlx)?;
+        Ok(())
+    }
+}
+
+fn parse_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, StorageError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| StorageError::Database(format!("Invalid {} timestamp", field)))
+}
+
+fn row_to_secret(row: &sqlx::sqlite::SqliteRow) -> Result<WebhookSecret, StorageError> {
+    let provider: String = row.try_get("provider")?;
+    let created_at: String = row.try_get("created_at")?;
+    let last_used_at: Option<String> = row.try_get("last_used_at")?;
+
+    Ok(WebhookSecret {
+        id: row.try_get("id")?,
+        provider: provider.parse().map_err(StorageError::Database)?,
+        name: row.try_get("name")?,
+        is_active: row.try_get("is_active")?,
+        created_at: parse_timestamp("created_at", &created_at)?,
+        last_used_at: last_used_at
+            .as_deref()
+            .map(|value| parse_timestamp("last_used_at", value))
+            .transpose()?,
+    })
+}
@@ -0,0 +1,60 @@
+// ABOUTME: HMAC-SHA256 request signature computation and verification
+// ABOUTME: Accepts hex signatures with or without GitHub's `sha256=` prefix
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Hex-encoded HMAC-SHA256 of `body` keyed by `secret`
+pub fn compute_signature(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Verify a signature header value against `body` using constant-time comparison
+pub fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let Ok(expected) = hex::decode(signature) else {
+        return false;
+    };
+
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_signature() {
+        let body = br#"{"status":"failed"}"#;
+        let signature = compute_signature("s3cret", body);
+
+        assert!(verify_signature("s3cret", body, &signature));
+        assert!(verify_signature(
+            "s3cret",
+            body,
+            &format!("sha256={}", signature)
+        ));
+        assert!(!verify_signature("wrong", body, &signature));
+        assert!(!verify_signature("s3cret", b"{}", &signature));
+        assert!(!verify_signature("s3cret", body, "sha256=not-hex"));
+        assert!(!verify_signature("s3cret", body, ""));
+    }
+
+    #[test]
+    fn test_compute_signature_matches_github_example() {
+        // Example from GitHub's webhook validation documentation
+        assert_eq!(
+            compute_signature("It's a Secret to Everybody", b"Hello, World!"),
+            "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17"
+        );
+    }
+}
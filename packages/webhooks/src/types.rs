@@ -0,0 +1,148 @@
+// ABOUTME: Webhook type definitions
+// ABOUTME: Providers, delivery statuses, secret records, and the normalized CI run result
+
+use chrono::{DateTime, Utc};
+use orkee_tasks::CheckStatus;
+use serde::{Deserialize, Serialize};
+
+/// CI systems that can deliver webhooks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookProvider {
+    /// GitHub Actions `workflow_run` and `check_suite` events
+    Github,
+    /// Any CI system posting Orkee's generic JSON payload
+    Generic,
+}
+
+impl WebhookProvider {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookProvider::Github => "github",
+            WebhookProvider::Generic => "generic",
+        }
+    }
+
+    /// Header carrying the HMAC-SHA256 signature of the request body
+    pub fn signature_header(&self) -> &'static str {
+        match self {
+            WebhookProvider::Github => "x-hub-signature-256",
+            WebhookProvider::Generic => "x-orkee-signature",
+        }
+    }
+
+    /// Header naming the event type, if the provider sends one
+    pub fn event_header(&self) -> Option<&'static str> {
+        match self {
+            WebhookProvider::Github => Some("x-github-event"),
+            WebhookProvider::Generic => None,
+        }
+    }
+
+    /// Header carrying the provider's delivery ID, if the provider sends one
+    pub fn delivery_header(&self) -> Option<&'static str> {
+        match self {
+            WebhookProvider::Github => Some("x-github-delivery"),
+            WebhookProvider::Generic => None,
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "github" => Ok(WebhookProvider::Github),
+            "generic" => Ok(WebhookProvider::Generic),
+            _ => Err(format!("Unknown webhook provider: {}", s)),
+        }
+    }
+}
+
+/// Outcome of handling a webhook delivery
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    /// Signature verified and the CI result was applied
+    Processed,
+    /// Signature verified but the event carries nothing to apply
+    Ignored,
+    /// Missing secret or invalid signature
+    Rejected,
+    /// Signature verified but the payload could not be parsed or applied
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Processed => "processed",
+            DeliveryStatus::Ignored => "ignored",
+            DeliveryStatus::Rejected => "rejected",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "processed" => Ok(DeliveryStatus::Processed),
+            "ignored" => Ok(DeliveryStatus::Ignored),
+            "rejected" => Ok(DeliveryStatus::Rejected),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(format!("Unknown delivery status: {}", s)),
+        }
+    }
+}
+
+/// A configured webhook secret. The secret value itself is never returned after creation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSecret {
+    pub id: String,
+    pub provider: WebhookProvider,
+    pub name: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A newly created webhook secret, including the plaintext value shown once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSecretGeneration {
+    pub secret: String,
+    #[serde(flatten)]
+    pub webhook_secret: WebhookSecret,
+}
+
+/// A logged webhook delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub provider: WebhookProvider,
+    pub event_type: Option<String>,
+    pub delivery_id: Option<String>,
+    pub status: DeliveryStatus,
+    pub message: Option<String>,
+    pub payload: Option<String>,
+    pub updated_tasks: i64,
+    pub updated_executions: i64,
+    pub received_at: DateTime<Utc>,
+}
+
+/// A CI run result normalized across providers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CiRunResult {
+    pub provider: WebhookProvider,
+    /// Workflow or pipeline name
+    pub name: String,
+    pub status: CheckStatus,
+    pub branch: Option<String>,
+    pub commit_sha: Option<String>,
+    pub url: Option<String>,
+    /// Task named explicitly by the payload, in addition to tasks matched through executions
+    pub task_id: Option<String>,
+}
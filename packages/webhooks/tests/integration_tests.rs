@@ -0,0 +1,278 @@
+// ABOUTME: Integration tests for inbound CI webhook handling
+// ABOUTME: Tests signature rejection, delivery logging, polled PR checks, and check status updates
+
+use orkee_git_utils::GhCheck;
+use orkee_tasks::storage::TaskStorage;
+use orkee_tasks::CheckStatus;
+use orkee_webhooks::{
+    compute_signature, open_pull_requests, record_pr_checks, DeliveryStatus, WebhookProvider,
+    WebhookReceiver, WebhookRequest,
+};
+use sqlx::SqlitePool;
+
+async fn create_test_db() -> SqlitePool {
+    let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+    sqlx::migrate!("../storage/migrations")
+        .run(&pool)
+        .await
+        .unwrap();
+
+    sqlx::query(
+        "INSERT INTO projects (id, name, project_root, created_at, updated_at)
+         VALUES ('proj1234', 'Webhooks', '/tmp/webhooks', datetime('now'), datetime('now'))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO tasks (id, project_id, title, created_at, updated_at)
+         VALUES ('task0001', 'proj1234', 'Add login', datetime('now'), datetime('now'))",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "INSERT INTO agent_executions (
+            id, task_id, started_at, status, branch_name, commit_hash, created_at, updated_at
+         )
+         VALUES (
+            'exec0001', 'task0001', datetime('now'), 'completed', 'feature/login', 'abc123',
+            datetime('now'), datetime('now')
+         )",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    pool
+}
+
+fn github_request(secret: &str, body: &str) -> WebhookRequest {
+    WebhookRequest {
+        provider: WebhookProvider::Github,
+        event_type: Some("workflow_run".to_string()),
+        delivery_id: Some("delivery-1".to_string()),
+        signature: Some(format!(
+            "sha256={}",
+            compute_signature(secret, body.as_bytes())
+        )),
+        body: body.as_bytes().to_vec(),
+    }
+}
+
+const FAILED_RUN: &str = r#"{
+    "action": "completed",
+    "workflow_run": {
+        "name": "CI",
+        "head_branch": "feature/login",
+        "head_sha": "abc123",
+        "status": "completed",
+        "conclusion": "failure",
+        "html_url": "https://github.com/acme/app/actions/runs/1"
+    }
+}"#;
+
+#[tokio::test]
+async fn test_rejects_unsigned_and_unconfigured_deliveries() {
+    let pool = create_test_db().await;
+    let receiver = WebhookReceiver::new(pool).unwrap();
+
+    // No secret configured yet
+    let delivery = receiver
+        .receive(github_request("anything", FAILED_RUN))
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Rejected);
+    assert!(delivery.payload.is_none());
+
+    receiver
+        .secrets()
+        .create_secret(WebhookProvider::Github, "acme/app")
+        .await
+        .unwrap();
+
+    let delivery = receiver
+        .receive(github_request("wrong-secret", FAILED_RUN))
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Rejected);
+    assert_eq!(delivery.message.as_deref(), Some("Invalid signature"));
+
+    let mut unsigned = github_request("wrong-secret", FAILED_RUN);
+    unsigned.signature = None;
+    let delivery = receiver.receive(unsigned).await.unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Rejected);
+
+    let logged = receiver
+        .deliveries()
+        .list_deliveries(Some(WebhookProvider::Github), 10)
+        .await
+        .unwrap();
+    assert_eq!(logged.len(), 3);
+}
+
+#[tokio::test]
+async fn test_failed_run_marks_task_checks_failed() {
+    let pool = create_test_db().await;
+    let receiver = WebhookReceiver::new(pool.clone()).unwrap();
+
+    let generated = receiver
+        .secrets()
+        .create_secret(WebhookProvider::Github, "acme/app")
+        .await
+        .unwrap();
+
+    let delivery = receiver
+        .receive(github_request(&generated.secret, FAILED_RUN))
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Processed);
+    assert_eq!(delivery.updated_tasks, 1);
+    assert_eq!(delivery.updated_executions, 1);
+
+    let task = TaskStorage::new(pool.clone())
+        .get_task("task0001")
+        .await
+        .unwrap();
+    assert_eq!(task.checks_status, Some(CheckStatus::Failed));
+    assert_eq!(
+        task.checks_url.as_deref(),
+        Some("https://github.com/acme/app/actions/runs/1")
+    );
+    assert!(task.checks_updated_at.is_some());
+
+    let test_results: String =
+        sqlx::query_scalar("SELECT test_results FROM agent_executions WHERE id = 'exec0001'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    let test_results: serde_json::Value = serde_json::from_str(&test_results).unwrap();
+    assert_eq!(test_results["status"], "failed");
+
+    let secret = receiver
+        .secrets()
+        .get_secret(&generated.webhook_secret.id)
+        .await
+        .unwrap();
+    assert!(secret.last_used_at.is_some());
+}
+
+#[tokio::test]
+async fn test_generic_provider_updates_task_by_id() {
+    let pool = create_test_db().await;
+    let receiver = WebhookReceiver::new(pool.clone()).unwrap();
+
+    let generated = receiver
+        .secrets()
+        .create_secret(WebhookProvider::Generic, "buildkite")
+        .await
+        .unwrap();
+
+    let body = r#"{"status": "passed", "task_id": "task0001", "name": "Buildkite"}"#;
+    let delivery = receiver
+        .receive(WebhookRequest {
+            provider: WebhookProvider::Generic,
+            event_type: None,
+            delivery_id: None,
+            signature: Some(compute_signature(&generated.secret, body.as_bytes())),
+            body: body.as_bytes().to_vec(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Processed);
+    assert_eq!(delivery.updated_tasks, 1);
+    assert_eq!(delivery.updated_executions, 0);
+
+    let task = TaskStorage::new(pool).get_task("task0001").await.unwrap();
+    assert_eq!(task.checks_status, Some(CheckStatus::Passed));
+
+    // A GitHub secret doesn't authenticate generic deliveries
+    receiver
+        .secrets()
+        .delete_secret(&generated.webhook_secret.id)
+        .await
+        .unwrap();
+    let github = receiver
+        .secrets()
+        .create_secret(WebhookProvider::Github, "acme/app")
+        .await
+        .unwrap();
+    let delivery = receiver
+        .receive(WebhookRequest {
+            provider: WebhookProvider::Generic,
+            event_type: None,
+            delivery_id: None,
+            signature: Some(compute_signature(&github.secret, body.as_bytes())),
+            body: body.as_bytes().to_vec(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(delivery.status, DeliveryStatus::Rejected);
+}
+
+#[tokio::test]
+async fn test_polled_pr_checks_update_task() {
+    let pool = create_test_db().await;
+    sqlx::query(
+        "UPDATE agent_executions
+         SET pr_url = 'https://github.com/acme/app/pull/7', pr_status = 'open'
+         WHERE id = 'exec0001'",
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let pull_requests = open_pull_requests(&pool).await.unwrap();
+    assert_eq!(pull_requests.len(), 1);
+    let pull_request = &pull_requests[0];
+    assert_eq!(pull_request.task_id, "task0001");
+    assert_eq!(pull_request.checks_status, None);
+
+    let checks = vec![
+        GhCheck {
+            name: "lint".to_string(),
+            state: "SUCCESS".to_string(),
+            bucket: "pass".to_string(),
+            link: None,
+        },
+        GhCheck {
+            name: "test".to_string(),
+            state: "FAILURE".to_string(),
+            bucket: "fail".to_string(),
+            link: Some("https://github.com/acme/app/actions/runs/1".to_string()),
+        },
+    ];
+    assert_eq!(
+        record_pr_checks(&pool, pull_request, &checks)
+            .await
+            .unwrap(),
+        1
+    );
+
+    let task = TaskStorage::new(pool.clone())
+        .get_task("task0001")
+        .await
+        .unwrap();
+    assert_eq!(task.checks_status, Some(CheckStatus::Failed));
+    assert_eq!(
+        task.checks_url.as_deref(),
+        Some("https://github.com/acme/app/actions/runs/1")
+    );
+
+    // An unchanged status isn't written again
+    let pull_request = &open_pull_requests(&pool).await.unwrap()[0];
+    assert_eq!(pull_request.checks_status, Some(CheckStatus::Failed));
+    assert_eq!(
+        record_pr_checks(&pool, pull_request, &checks)
+            .await
+            .unwrap(),
+        0
+    );
+
+    // Merged pull requests are no longer polled
+    sqlx::query("UPDATE agent_executions SET pr_status = 'merged' WHERE id = 'exec0001'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert!(open_pull_requests(&pool).await.unwrap().is_empty());
+}